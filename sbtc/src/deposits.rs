@@ -7,6 +7,7 @@ use bitcoin::OutPoint;
 use bitcoin::Script;
 use bitcoin::ScriptBuf;
 use bitcoin::Transaction;
+use bitcoin::TxOut;
 use bitcoin::XOnlyPublicKey;
 use bitcoin::locktime::relative::LockTime;
 use bitcoin::opcodes::all as opcodes;
@@ -128,6 +129,56 @@ impl CreateDepositRequest {
     /// * That the Stacks network for the recipient address matches the one
     ///   given as input to this function.
     pub fn validate_tx(&self, tx: &Transaction, is_mainnet: bool) -> Result<DepositInfo, Error> {
+        let tx_out = self.deposit_tx_out(tx)?;
+        // Validate that the deposit and reclaim scripts in the request
+        // match the expected formats for deposit transactions.
+        let scripts = self.parse_scripts()?;
+        self.validate_tx_out(tx_out, &scripts, is_mainnet)
+    }
+
+    /// Validate this deposit request using deposit and reclaim scripts
+    /// that have already been parsed from this request.
+    ///
+    /// This performs the same checks as [`CreateDepositRequest::validate_tx`],
+    /// except that the scripts are not parsed again. The given scripts
+    /// must match the ones in this request, which is checked.
+    pub fn validate_tx_with_scripts(
+        &self,
+        tx: &Transaction,
+        scripts: &ParsedDepositScripts,
+        is_mainnet: bool,
+    ) -> Result<DepositInfo, Error> {
+        let tx_out = self.deposit_tx_out(tx)?;
+        if !scripts.matches(self) {
+            return Err(Error::InvalidDepositScript);
+        }
+        self.validate_tx_out(tx_out, scripts, is_mainnet)
+    }
+
+    /// Parse the deposit and reclaim scripts in this request, checking
+    /// that they match the expected formats for deposit transactions.
+    pub fn parse_scripts(&self) -> Result<ParsedDepositScripts, Error> {
+        let deposit = DepositScriptInputs::parse(&self.deposit_script)?;
+        let reclaim = ReclaimScriptInputs::parse(&self.reclaim_script)?;
+        // Okay, the deposit and reclaim scripts are valid. Now make sure
+        // that they are exactly the scripts implied by the parsed inputs.
+        if deposit.deposit_script() != self.deposit_script {
+            return Err(Error::InvalidDepositScript);
+        }
+        if reclaim.reclaim_script() != self.reclaim_script {
+            return Err(Error::InvalidReclaimScript);
+        }
+
+        Ok(ParsedDepositScripts {
+            deposit_script: self.deposit_script.clone(),
+            reclaim_script: self.reclaim_script.clone(),
+            deposit,
+            reclaim,
+        })
+    }
+
+    /// Return the output of the transaction that this request refers to.
+    fn deposit_tx_out<'a>(&self, tx: &'a Transaction) -> Result<&'a TxOut, Error> {
         if tx.compute_txid() != self.outpoint.txid {
             // The expectation is that the transaction was fetched from the
             // blockchain using the txid, so in practice this should never
@@ -138,25 +189,20 @@ impl CreateDepositRequest {
             });
         }
 
-        let tx_out = tx
-            .tx_out(self.outpoint.vout as usize)
-            .map_err(|err| Error::OutpointIndex(err, self.outpoint))?;
-        // Validate that the deposit and reclaim scripts in the request
-        // match the expected formats for deposit transactions.
-        let deposit = DepositScriptInputs::parse(&self.deposit_script)?;
-        let reclaim = ReclaimScriptInputs::parse(&self.reclaim_script)?;
-        // Okay, the deposit and reclaim scripts are valid. Now make sure
-        // that the ScriptPubKey in the transaction matches the one implied
-        // by the given scripts. So now create the expected ScriptPubKey.
-        let deposit_script = deposit.deposit_script();
-        let reclaim_script = reclaim.reclaim_script();
+        tx.tx_out(self.outpoint.vout as usize)
+            .map_err(|err| Error::OutpointIndex(err, self.outpoint))
+    }
 
-        if deposit_script != self.deposit_script {
-            return Err(Error::InvalidDepositScript);
-        }
-        if reclaim_script != self.reclaim_script {
-            return Err(Error::InvalidReclaimScript);
-        }
+    /// Check that the output is locked by the given scripts and that the
+    /// recipient is on the expected network.
+    fn validate_tx_out(
+        &self,
+        tx_out: &TxOut,
+        scripts: &ParsedDepositScripts,
+        is_mainnet: bool,
+    ) -> Result<DepositInfo, Error> {
+        let deposit_script = scripts.deposit_script.clone();
+        let reclaim_script = scripts.reclaim_script.clone();
 
         let expected_script_pubkey =
             to_script_pubkey(deposit_script.clone(), reclaim_script.clone());
@@ -167,8 +213,9 @@ impl CreateDepositRequest {
         }
 
         // Check that the recipient network matches what we expect
+        let deposit = &scripts.deposit;
         if principal_is_mainnet(deposit.recipient.clone()) != is_mainnet {
-            return Err(Error::RecipientNetworkMismatch(deposit.recipient));
+            return Err(Error::RecipientNetworkMismatch(deposit.recipient.clone()));
         }
 
         Ok(DepositInfo {
@@ -176,14 +223,37 @@ impl CreateDepositRequest {
             deposit_script,
             reclaim_script,
             signers_public_key: deposit.signers_public_key,
-            recipient: deposit.recipient,
-            lock_time: reclaim.lock_time,
+            recipient: deposit.recipient.clone(),
+            lock_time: scripts.reclaim.lock_time,
             amount: tx_out.value.to_sat(),
             outpoint: self.outpoint,
         })
     }
 }
 
+/// The deposit and reclaim scripts of a deposit request, along with the
+/// inputs parsed from them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedDepositScripts {
+    /// The deposit script that was parsed.
+    pub deposit_script: ScriptBuf,
+    /// The reclaim script that was parsed.
+    pub reclaim_script: ScriptBuf,
+    /// The inputs parsed from the deposit script.
+    pub deposit: DepositScriptInputs,
+    /// The inputs parsed from the reclaim script.
+    pub reclaim: ReclaimScriptInputs,
+}
+
+impl ParsedDepositScripts {
+    /// Whether these scripts were parsed from the scripts in the given
+    /// request.
+    pub fn matches(&self, request: &CreateDepositRequest) -> bool {
+        self.deposit_script == request.deposit_script
+            && self.reclaim_script == request.reclaim_script
+    }
+}
+
 /// Return whether the given principal address is a mainnet address.
 fn principal_is_mainnet(principal: PrincipalData) -> bool {
     let standard_address = match principal {
@@ -858,6 +928,44 @@ mod tests {
         assert_eq!(parsed.recipient, setup.deposits.first().unwrap().recipient);
     }
 
+    #[test]
+    fn tx_validation_with_parsed_scripts() {
+        let setup: TxSetup = testing::deposits::tx_setup(150, 15000, &[500_000, 600_000]);
+
+        let request = CreateDepositRequest {
+            outpoint: OutPoint::new(setup.tx.compute_txid(), 0),
+            reclaim_script: setup.reclaims[0].reclaim_script(),
+            deposit_script: setup.deposits[0].deposit_script(),
+        };
+
+        let scripts = request.parse_scripts().unwrap();
+        assert!(scripts.matches(&request));
+        assert_eq!(scripts.deposit, setup.deposits[0]);
+
+        let parsed = request.validate_tx(&setup.tx, false).unwrap();
+        let parsed_with_scripts = request
+            .validate_tx_with_scripts(&setup.tx, &scripts, false)
+            .unwrap();
+        assert_eq!(parsed_with_scripts.deposit_script, parsed.deposit_script);
+        assert_eq!(parsed_with_scripts.reclaim_script, parsed.reclaim_script);
+        assert_eq!(parsed_with_scripts.amount, parsed.amount);
+        assert_eq!(parsed_with_scripts.lock_time, parsed.lock_time);
+
+        // Scripts parsed from another request are rejected.
+        let other_request = CreateDepositRequest {
+            outpoint: OutPoint::new(setup.tx.compute_txid(), 1),
+            reclaim_script: setup.reclaims[1].reclaim_script(),
+            deposit_script: setup.deposits[1].deposit_script(),
+        };
+        let other_scripts = other_request.parse_scripts().unwrap();
+        assert!(!other_scripts.matches(&request));
+
+        let error = request
+            .validate_tx_with_scripts(&setup.tx, &other_scripts, false)
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidDepositScript));
+    }
+
     #[test_case(true ; "is mainnet address")]
     #[test_case(false ; "is testnet address")]
     fn tx_validation_network(is_mainnet: bool) {
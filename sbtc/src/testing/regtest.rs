@@ -53,6 +53,18 @@ pub const BITCOIN_CORE_FALLBACK_FEE: Amount = Amount::from_sat(1000);
 /// rewards.
 pub const MIN_BLOCKCHAIN_HEIGHT: u64 = 101;
 
+/// The default RPC endpoint of a bitcoin-core node on testnet4.
+pub const BITCOIN_CORE_TESTNET4_RPC_URL: &str = "http://localhost:48332";
+
+/// The environment variable with the RPC endpoint of the bitcoin-core
+/// node on testnet4. The node must accept the devnet username and
+/// password.
+pub const TESTNET4_RPC_URL_ENV: &str = "SBTC_TESTNET4_RPC_URL";
+
+/// The environment variable with the hex encoded secret key of the
+/// faucet on testnet4.
+pub const TESTNET4_FAUCET_SECRET_KEY_ENV: &str = "SBTC_TESTNET4_FAUCET_SECRET_KEY";
+
 /// The name of our wallet on bitcoin-core
 const BITCOIN_CORE_WALLET_NAME: &str = "integration-tests-wallet";
 
@@ -84,7 +96,12 @@ pub fn initialize_blockchain() -> (&'static Client, &'static Faucet) {
 
     let faucet = FAUCET.get_or_init(|| {
         get_or_create_wallet(rpc, BITCOIN_CORE_WALLET_NAME);
-        let faucet = Faucet::new(FAUCET_SECRET_KEY, AddressType::P2wpkh, rpc);
+        let faucet = Faucet::new(
+            FAUCET_SECRET_KEY,
+            AddressType::P2wpkh,
+            Network::Regtest,
+            rpc,
+        );
         faucet.track_address(FAUCET_LABEL);
 
         let amount = rpc.get_received_by_address(&faucet.address, None).unwrap();
@@ -118,7 +135,12 @@ pub fn initialize_blockchain_devenv() -> (&'static Client, &'static Faucet) {
 
     let faucet = FAUCET.get_or_init(|| {
         get_or_create_wallet(rpc, BITCOIN_CORE_WALLET_NAME);
-        let faucet = Faucet::new(FAUCET_SECRET_KEY, AddressType::P2wpkh, rpc);
+        let faucet = Faucet::new(
+            FAUCET_SECRET_KEY,
+            AddressType::P2wpkh,
+            Network::Regtest,
+            rpc,
+        );
         faucet.track_address(FAUCET_LABEL);
 
         // We cannot create 100 blocks here, as it may break the Stacks signers.
@@ -131,6 +153,42 @@ pub fn initialize_blockchain_devenv() -> (&'static Client, &'static Faucet) {
     (rpc, faucet)
 }
 
+/// Similar to `initialize_blockchain`, but for a bitcoin-core node on
+/// testnet4.
+///
+/// Blocks cannot be generated on demand on testnet4, so the faucet must
+/// be funded beforehand, say from a public testnet4 faucet, and
+/// [`Faucet::generate_blocks`] cannot be used with it. Coins sent to a
+/// well known key are taken by others on testnet4, so the secret key of
+/// the faucet is read from the [`TESTNET4_FAUCET_SECRET_KEY_ENV`]
+/// environment variable. The RPC endpoint is read from the
+/// [`TESTNET4_RPC_URL_ENV`] environment variable, and defaults to
+/// [`BITCOIN_CORE_TESTNET4_RPC_URL`].
+pub fn initialize_blockchain_testnet4() -> (&'static Client, &'static Faucet) {
+    static BTC_CLIENT: OnceLock<Client> = OnceLock::new();
+    static FAUCET: OnceLock<Faucet> = OnceLock::new();
+    let rpc = BTC_CLIENT.get_or_init(|| {
+        let url = std::env::var(TESTNET4_RPC_URL_ENV)
+            .unwrap_or_else(|_| BITCOIN_CORE_TESTNET4_RPC_URL.to_string());
+        let username = BITCOIN_CORE_RPC_USERNAME.to_string();
+        let password = BITCOIN_CORE_RPC_PASSWORD.to_string();
+        let auth = Auth::UserPass(username, password);
+        Client::new(&url, auth).unwrap()
+    });
+
+    let faucet = FAUCET.get_or_init(|| {
+        let secret_key = std::env::var(TESTNET4_FAUCET_SECRET_KEY_ENV)
+            .unwrap_or_else(|_| panic!("{TESTNET4_FAUCET_SECRET_KEY_ENV} must be set"));
+
+        get_or_create_wallet(rpc, BITCOIN_CORE_WALLET_NAME);
+        let faucet = Faucet::new(&secret_key, AddressType::P2wpkh, Network::Testnet4, rpc);
+        faucet.track_address(FAUCET_LABEL);
+        faucet
+    });
+
+    (rpc, faucet)
+}
+
 fn get_or_create_wallet(rpc: &Client, wallet: &str) {
     match rpc.load_wallet(wallet) {
         // Success
@@ -190,6 +248,13 @@ impl Recipient {
     /// Generate a new public-private key pair and address of the given
     /// kind using the given random number generator.
     pub fn new_with_rng<R: rand::Rng>(kind: AddressType, rng: &mut R) -> Self {
+        Self::new_on_network(kind, Network::Regtest, rng)
+    }
+
+    /// Generate a new public-private key pair and an address of the given
+    /// kind on the given network, using the given random number
+    /// generator.
+    pub fn new_on_network<R: rand::Rng>(kind: AddressType, network: Network, rng: &mut R) -> Self {
         let keypair = secp256k1::Keypair::new_global(rng);
         let pk = keypair.public_key();
         let script_pubkey = match kind {
@@ -202,8 +267,7 @@ impl Recipient {
             _ => unimplemented!(),
         };
 
-        let params = Network::Regtest.params();
-        let address = Address::from_script(&script_pubkey, params).unwrap();
+        let address = Address::from_script(&script_pubkey, network.params()).unwrap();
 
         Recipient {
             keypair,
@@ -242,15 +306,15 @@ impl Recipient {
 }
 
 impl Faucet {
-    fn new(secret_key: &str, kind: AddressType, rpc: &'static Client) -> Self {
+    fn new(secret_key: &str, kind: AddressType, network: Network, rpc: &'static Client) -> Self {
         let keypair = secp256k1::Keypair::from_seckey_str_global(secret_key).unwrap();
         let pk = keypair.public_key();
         let address = match kind {
-            AddressType::P2wpkh => Address::p2wpkh(&CompressedPublicKey(pk), Network::Regtest),
-            AddressType::P2pkh => Address::p2pkh(PublicKey::new(pk), Network::Regtest),
+            AddressType::P2wpkh => Address::p2wpkh(&CompressedPublicKey(pk), network),
+            AddressType::P2pkh => Address::p2pkh(PublicKey::new(pk), network),
             AddressType::P2tr => {
                 let (internal_key, _) = pk.x_only_public_key();
-                Address::p2tr(SECP256K1, internal_key, None, Network::Regtest)
+                Address::p2tr(SECP256K1, internal_key, None, network)
             }
             _ => unimplemented!(),
         };
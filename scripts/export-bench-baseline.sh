@@ -0,0 +1,27 @@
+#!/bin/bash
+
+# Collects the estimates of a saved criterion baseline into a single JSON
+# array on stdout, with one object per benchmark:
+#
+#   {"id": "sighash_construction/construct_digests/10", "mean_ns": ..., "median_ns": ..., "std_dev_ns": ...}
+#
+# Usage: export-bench-baseline.sh <baseline> [criterion-dir]
+
+set -euo pipefail
+
+BASELINE="${1:?usage: $0 <baseline> [criterion-dir]}"
+GIT_REPO_ROOT=$(git rev-parse --show-toplevel)
+CRITERION_DIR="${2:-${GIT_REPO_ROOT}/target/criterion}"
+
+find "${CRITERION_DIR}" -path "*/${BASELINE}/benchmark.json" | sort | while read -r benchmark; do
+  estimates="$(dirname "${benchmark}")/estimates.json"
+  jq -n \
+    --slurpfile benchmark "${benchmark}" \
+    --slurpfile estimates "${estimates}" \
+    '{
+      id: $benchmark[0].full_id,
+      mean_ns: $estimates[0].mean.point_estimate,
+      median_ns: $estimates[0].median.point_estimate,
+      std_dev_ns: $estimates[0].std_dev.point_estimate
+    }'
+done | jq -s '.'
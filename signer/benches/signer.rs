@@ -0,0 +1,350 @@
+//! Benchmarks for the signer's hot paths.
+//!
+//! Every signer runs these paths at least once per tenure, so a regression
+//! in any of them prolongs the time that it takes the signers to sweep in
+//! requests. Run them with `make bench`, which saves the results as a
+//! criterion baseline and exports the estimates as JSON, so that they can
+//! be compared across CI runs.
+
+use std::hint::black_box;
+
+use bitcoin::Amount;
+use bitcoin::OutPoint;
+use bitcoin::ScriptBuf;
+use bitcoin::Txid;
+use bitcoin::WPubkeyHash;
+use bitcoin::XOnlyPublicKey;
+use bitcoin::hashes::Hash as _;
+use bitcoin::relative::LockTime;
+use bitvec::array::BitArray;
+use clarity::vm::types::PrincipalData;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use fake::Fake as _;
+use rand::RngCore as _;
+use rand::SeedableRng as _;
+use rand::rngs::StdRng;
+use sbtc::deposits::CreateDepositRequest;
+use sbtc::deposits::DepositScriptInputs;
+use sbtc::deposits::ReclaimScriptInputs;
+use signer::bitcoin::units::FeeRate;
+use signer::bitcoin::utxo::DepositRequest;
+use signer::bitcoin::utxo::RequestRef;
+use signer::bitcoin::utxo::Requests;
+use signer::bitcoin::utxo::SignerBtcState;
+use signer::bitcoin::utxo::SignerUtxo;
+use signer::bitcoin::utxo::UnsignedTransaction;
+use signer::bitcoin::utxo::WithdrawalRequest;
+use signer::bitcoin::validation::BitcoinTxValidationData;
+use signer::bitcoin::validation::DepositConfirmationStatus;
+use signer::bitcoin::validation::DepositRequestReport;
+use signer::bitcoin::validation::SbtcReports;
+use signer::bitcoin::validation::WithdrawalRequestReport;
+use signer::bitcoin::validation::WithdrawalRequestStatus;
+use signer::codec::Decode as _;
+use signer::codec::Encode as _;
+use signer::context::SbtcLimits;
+use signer::ecdsa::SignEcdsa as _;
+use signer::keys::PrivateKey;
+use signer::message::Payload;
+use signer::message::WstsMessage;
+use signer::message::WstsMessageId;
+use signer::network::Msg;
+use signer::storage::model::BitcoinBlockHash;
+use signer::storage::model::DkgSharesStatus;
+use signer::storage::model::QualifiedRequestId;
+use signer::storage::model::StacksBlockHash;
+use signer::storage::model::StacksTxId;
+use signer::storage::model::TaprootScriptHash;
+use signer::testing::dummy::Unit;
+use stacks_common::types::chainstate::StacksAddress;
+
+/// The numbers of deposits and withdrawals in the benchmarked sweeps.
+const SWEEP_SIZES: [usize; 3] = [1, 10, 25];
+
+/// The height of the bitcoin chain tip used for validation.
+const CHAIN_TIP_HEIGHT: u64 = 1_000;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(46)
+}
+
+fn bytes(rng: &mut StdRng) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    rng.fill_bytes(&mut bytes);
+    bytes
+}
+
+fn signers_public_key(rng: &mut StdRng) -> XOnlyPublicKey {
+    let keypair: secp256k1::Keypair = Unit.fake_with_rng(rng);
+    keypair.x_only_public_key().0
+}
+
+fn deposit_request(rng: &mut StdRng, signers_public_key: XOnlyPublicKey) -> DepositRequest {
+    let deposit_inputs = DepositScriptInputs {
+        signers_public_key,
+        max_fee: 100_000,
+        recipient: PrincipalData::from(StacksAddress::burn_address(false)),
+    };
+    let reclaim_inputs = ReclaimScriptInputs::try_new(10_000, ScriptBuf::new()).unwrap();
+
+    DepositRequest {
+        outpoint: OutPoint {
+            txid: Txid::from_byte_array(bytes(rng)),
+            vout: 0,
+        },
+        max_fee: deposit_inputs.max_fee,
+        signer_bitmap: BitArray::ZERO,
+        amount: 1_000_000,
+        deposit_script: deposit_inputs.deposit_script(),
+        reclaim_script: reclaim_inputs.reclaim_script(),
+        reclaim_script_hash: Some(TaprootScriptHash::zeros()),
+        signers_public_key,
+    }
+}
+
+fn withdrawal_request(rng: &mut StdRng, request_id: u64) -> WithdrawalRequest {
+    let mut recipient_hash = [0; 20];
+    rng.fill_bytes(&mut recipient_hash);
+    let script_pubkey = ScriptBuf::new_p2wpkh(&WPubkeyHash::from_byte_array(recipient_hash));
+
+    WithdrawalRequest {
+        request_id,
+        txid: StacksTxId::from(bytes(rng)),
+        block_hash: StacksBlockHash::from(bytes(rng)),
+        amount: 100_000,
+        max_fee: 100_000,
+        script_pubkey: script_pubkey.into(),
+        signer_bitmap: BitArray::ZERO,
+    }
+}
+
+fn signer_btc_state(rng: &mut StdRng, public_key: XOnlyPublicKey) -> SignerBtcState {
+    SignerBtcState {
+        utxo: SignerUtxo {
+            outpoint: OutPoint {
+                txid: Txid::from_byte_array(bytes(rng)),
+                vout: 0,
+            },
+            amount: Amount::ONE_BTC.to_sat() * 100,
+            public_key,
+        },
+        fee_rate: FeeRate::new(10.0).unwrap(),
+        public_key,
+        last_fees: None,
+        magic_bytes: [b'T', b'3'],
+    }
+}
+
+/// Deposits and withdrawals for a sweep with `size` of each.
+struct SweepFixture {
+    deposits: Vec<DepositRequest>,
+    withdrawals: Vec<WithdrawalRequest>,
+    signer_state: SignerBtcState,
+}
+
+impl SweepFixture {
+    fn new(size: usize) -> Self {
+        let mut rng = rng();
+        let public_key = signers_public_key(&mut rng);
+        Self {
+            deposits: (0..size)
+                .map(|_| deposit_request(&mut rng, public_key))
+                .collect(),
+            withdrawals: (0..size as u64)
+                .map(|id| withdrawal_request(&mut rng, id))
+                .collect(),
+            signer_state: signer_btc_state(&mut rng, public_key),
+        }
+    }
+
+    fn requests(&self) -> Requests<'_> {
+        let deposits = self.deposits.iter().map(RequestRef::Deposit);
+        let withdrawals = self.withdrawals.iter().map(RequestRef::Withdrawal);
+        Requests::new(deposits.chain(withdrawals).collect())
+    }
+
+    /// The reports that the signer would have fetched from its database
+    /// for the requests in this sweep.
+    fn reports(&self) -> SbtcReports {
+        let deposits = self
+            .deposits
+            .iter()
+            .map(|request| {
+                let report = DepositRequestReport {
+                    outpoint: request.outpoint,
+                    status: DepositConfirmationStatus::Confirmed(
+                        (CHAIN_TIP_HEIGHT - 10).into(),
+                        BitcoinBlockHash::from([1; 32]),
+                    ),
+                    can_sign: Some(true),
+                    can_accept: Some(true),
+                    amount: request.amount,
+                    max_fee: request.max_fee,
+                    lock_time: LockTime::from_height(10_000),
+                    deposit_script: request.deposit_script.clone(),
+                    reclaim_script: request.reclaim_script.clone(),
+                    reclaim_script_hash: request.reclaim_script_hash.clone(),
+                    signers_public_key: request.signers_public_key,
+                    dkg_shares_status: Some(DkgSharesStatus::Verified),
+                };
+                (request.clone(), report)
+            })
+            .collect();
+
+        let withdrawals = self
+            .withdrawals
+            .iter()
+            .map(|request| {
+                let report = WithdrawalRequestReport {
+                    id: QualifiedRequestId {
+                        request_id: request.request_id,
+                        txid: request.txid,
+                        block_hash: request.block_hash,
+                    },
+                    status: WithdrawalRequestStatus::Confirmed,
+                    amount: request.amount,
+                    max_fee: request.max_fee,
+                    recipient: request.script_pubkey.clone().into(),
+                    is_accepted: Some(true),
+                    bitcoin_block_height: (CHAIN_TIP_HEIGHT - 10).into(),
+                };
+                (request.clone(), report)
+            })
+            .collect();
+
+        SbtcReports {
+            deposits,
+            withdrawals,
+            signer_state: self.signer_state,
+        }
+    }
+}
+
+/// Validation of deposit requests against the transactions that created
+/// them, which every signer does for every deposit that it learns about
+/// from Emily.
+fn deposit_script_validation(c: &mut Criterion) {
+    let setup = sbtc::testing::deposits::tx_setup(10_000, 100_000, &[1_000_000]);
+    let request = CreateDepositRequest {
+        outpoint: OutPoint::new(setup.tx.compute_txid(), 0),
+        deposit_script: setup.deposits[0].deposit_script(),
+        reclaim_script: setup.reclaims[0].reclaim_script(),
+    };
+
+    let mut group = c.benchmark_group("deposit_script_validation");
+    group.bench_function("parse_deposit_script", |b| {
+        b.iter(|| DepositScriptInputs::parse(black_box(&request.deposit_script)))
+    });
+    group.bench_function("parse_reclaim_script", |b| {
+        b.iter(|| ReclaimScriptInputs::parse(black_box(&request.reclaim_script)))
+    });
+    group.bench_function("validate_tx", |b| {
+        b.iter(|| request.validate_tx(black_box(&setup.tx), false))
+    });
+    group.finish();
+}
+
+/// Construction of sweep transactions and their sighashes, which the
+/// coordinator does before each signing round and every signer does when
+/// validating a presign request.
+fn sighash_construction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sighash_construction");
+    for size in SWEEP_SIZES {
+        let fixture = SweepFixture::new(size);
+        let unsigned = UnsignedTransaction::new(fixture.requests(), &fixture.signer_state).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("new_transaction", size),
+            &fixture,
+            |b, f| b.iter(|| UnsignedTransaction::new(f.requests(), black_box(&f.signer_state))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("construct_digests", size),
+            &unsigned,
+            |b, tx| b.iter(|| black_box(tx).construct_digests()),
+        );
+    }
+    group.finish();
+}
+
+/// Serialization of the WSTS messages that make up the bulk of the
+/// traffic between signers during DKG and signing rounds.
+fn wsts_message_codec(c: &mut Criterion) {
+    let mut rng = rng();
+    let private_key = PrivateKey::new(&mut rng);
+    let chain_tip = BitcoinBlockHash::from([2; 32]);
+
+    let messages = [
+        (
+            "dkg_public_shares",
+            wsts::net::Message::DkgPublicShares(Unit.fake_with_rng(&mut rng)),
+        ),
+        (
+            "signature_share_response",
+            wsts::net::Message::SignatureShareResponse(Unit.fake_with_rng(&mut rng)),
+        ),
+    ];
+
+    let mut group = c.benchmark_group("wsts_message_codec");
+    for (name, inner) in messages {
+        let wsts = WstsMessage {
+            id: WstsMessageId::Dkg([3; 32]),
+            inner,
+        };
+        let msg: Msg = Payload::from(wsts)
+            .to_message(chain_tip)
+            .sign_ecdsa(&private_key);
+        let bytes = msg.clone().encode_to_vec();
+
+        group.bench_with_input(BenchmarkId::new("encode", name), &msg, |b, msg| {
+            b.iter(|| black_box(msg.clone()).encode_to_vec())
+        });
+        group.bench_with_input(BenchmarkId::new("decode", name), &bytes, |b, bytes| {
+            b.iter(|| Msg::decode(black_box(bytes.as_slice())))
+        });
+    }
+    group.finish();
+}
+
+/// The CPU bound part of presign request validation: building the sweep
+/// transaction from the signer's reports, computing its sighashes and
+/// validating each request against the transaction.
+fn presign_validation(c: &mut Criterion) {
+    let limits = SbtcLimits::unlimited();
+
+    let mut group = c.benchmark_group("presign_validation");
+    for size in SWEEP_SIZES {
+        let fixture = SweepFixture::new(size);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &fixture, |b, f| {
+            b.iter(|| {
+                let reports = f.reports();
+                let tx = reports.create_transaction().unwrap();
+                let sighashes = tx.construct_digests().unwrap();
+                let validation = BitcoinTxValidationData {
+                    signer_sighash: sighashes.signer_sighash(),
+                    deposit_sighashes: sighashes.deposit_sighashes(),
+                    chain_tip: BitcoinBlockHash::from([1; 32]),
+                    tx: tx.tx.clone(),
+                    tx_fee: Amount::from_sat(tx.tx_fee),
+                    reports,
+                    chain_tip_height: CHAIN_TIP_HEIGHT.into(),
+                    sbtc_limits: limits.clone(),
+                };
+                (validation.to_input_rows(), validation.to_withdrawal_rows())
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    deposit_script_validation,
+    sighash_construction,
+    wsts_message_codec,
+    presign_validation,
+);
+criterion_main!(benches);
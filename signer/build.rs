@@ -50,6 +50,7 @@ pub fn compile_protos() {
         "protobufs/stacks/signer/v1/decisions.proto",
         "protobufs/stacks/signer/v1/requests.proto",
         "protobufs/stacks/signer/v1/messages.proto",
+        "protobufs/stacks/signer/v1/control.proto",
     ]
     .map(|path| workingdir.join(path));
 
@@ -0,0 +1,35 @@
+//! Fuzz the packaging of deposit and withdrawal requests into sweep
+//! transactions.
+//!
+//! The fuzzer input seeds the generators in `signer::testing::sweep`, so
+//! the fuzzer explores the same space of requests and signer UTXO states
+//! as the property tests, and every package of sweep transactions is
+//! checked against the same invariants.
+//!
+//! Run it from the `signer` directory with
+//! `cargo +nightly fuzz run sweep_packaging`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use proptest::strategy::Strategy as _;
+use proptest::strategy::ValueTree as _;
+use proptest::test_runner::Config;
+use proptest::test_runner::RngAlgorithm;
+use proptest::test_runner::TestRng;
+use proptest::test_runner::TestRunner;
+use signer::testing::sweep;
+
+fuzz_target!(|seed: [u8; 32]| {
+    let rng = TestRng::from_seed(RngAlgorithm::ChaCha, &seed);
+    let mut runner = TestRunner::new_with_rng(Config::default(), rng);
+    // The generators only reject the rare byte strings that are not valid
+    // secret keys, so skipping those seeds loses nothing.
+    let Ok(tree) = sweep::sbtc_requests().new_tree(&mut runner) else {
+        return;
+    };
+
+    if let Err(violation) = sweep::construct_and_check_sweeps(&tree.current()) {
+        panic!("{violation}");
+    }
+});
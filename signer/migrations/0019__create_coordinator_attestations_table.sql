@@ -0,0 +1,17 @@
+
+CREATE TABLE sbtc_signer.coordinator_attestations (
+    -- The bitcoin chain tip of the coordinator's tenure.
+    bitcoin_chain_tip BYTEA NOT NULL,
+    -- The public key of the coordinator that made the attestation.
+    coordinator_public_key BYTEA NOT NULL,
+    -- The IDs of the sweep transactions that the coordinator claims to
+    -- have broadcast during its tenure.
+    sweep_txids BYTEA[] NOT NULL,
+    -- The protobuf encoded signed message containing the attestation.
+    -- This is kept so that the coordinator's signature can be verified
+    -- after the fact.
+    message BYTEA NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (bitcoin_chain_tip, coordinator_public_key)
+);
@@ -0,0 +1,17 @@
+
+CREATE TABLE sbtc_signer.deposit_decision_receipts (
+    -- The transaction ID of the deposit request.
+    txid BYTEA NOT NULL,
+    -- The output index of the deposit request.
+    output_index INTEGER NOT NULL,
+    -- The public key of the signer that accepted the deposit request.
+    signer_pub_key BYTEA NOT NULL,
+    -- The signer's compact encoded ECDSA signature over the receipt
+    -- digest of the deposit request. Receipts for a deposit request from
+    -- enough signers form a quorum certificate for the request.
+    signature BYTEA NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (txid, output_index, signer_pub_key),
+    FOREIGN KEY (txid, output_index) REFERENCES sbtc_signer.deposit_requests(txid, output_index) ON DELETE CASCADE
+);
@@ -0,0 +1,28 @@
+
+CREATE TABLE sbtc_signer.tenure_plans (
+    id BIGSERIAL PRIMARY KEY,
+    -- The bitcoin chain tip that started the tenure.
+    bitcoin_chain_tip BYTEA NOT NULL,
+    -- The public key of the coordinator for the tenure.
+    coordinator_public_key BYTEA NOT NULL,
+    -- Whether the coordinator intends to deploy the sBTC smart contracts.
+    deploy_contracts BOOLEAN NOT NULL,
+    -- Whether the coordinator intends to submit a rotate-keys contract
+    -- call.
+    rotate_keys BOOLEAN NOT NULL,
+    -- The number of deposit requests that are eligible for a sweep.
+    deposits_to_sweep INTEGER NOT NULL,
+    -- The number of withdrawal requests that are eligible for a sweep.
+    withdrawals_to_sweep INTEGER NOT NULL,
+    -- The number of swept deposit requests that may need a
+    -- complete-deposit contract call.
+    deposits_to_complete INTEGER NOT NULL,
+    -- The number of swept withdrawal requests that may need an
+    -- accept-withdrawal-request contract call.
+    withdrawals_to_accept INTEGER NOT NULL,
+    -- The number of withdrawal requests that may need a
+    -- reject-withdrawal-request contract call.
+    withdrawals_to_reject INTEGER NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
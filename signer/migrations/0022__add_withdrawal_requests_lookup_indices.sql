@@ -0,0 +1,4 @@
+-- Indices to serve lookups of withdrawal requests by recipient and by
+-- sender, which are used to answer support questions about withdrawals.
+CREATE INDEX ix_withdrawal_requests_recipient ON sbtc_signer.withdrawal_requests(recipient);
+CREATE INDEX ix_withdrawal_requests_sender_address ON sbtc_signer.withdrawal_requests(sender_address);
@@ -0,0 +1,3 @@
+-- Index to serve lookups of deposit requests by their recipient, which
+-- are used to answer support questions about deposits.
+CREATE INDEX ix_deposit_requests_recipient ON sbtc_signer.deposit_requests(recipient);
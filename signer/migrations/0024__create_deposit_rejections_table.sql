@@ -0,0 +1,17 @@
+CREATE TYPE sbtc_signer.deposit_rejection_reason AS ENUM (
+    'below_minimum_amount'
+);
+
+CREATE TABLE sbtc_signer.deposit_rejections (
+    -- The transaction ID of the deposit request.
+    txid BYTEA NOT NULL,
+    -- The output index of the deposit request.
+    output_index INTEGER NOT NULL,
+    -- Why this signer rejected the deposit request because of its own
+    -- local policy.
+    reason sbtc_signer.deposit_rejection_reason NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (txid, output_index),
+    FOREIGN KEY (txid, output_index) REFERENCES sbtc_signer.deposit_requests(txid, output_index) ON DELETE CASCADE
+);
@@ -0,0 +1,20 @@
+CREATE TABLE sbtc_signer.deposit_expirations (
+    -- The transaction ID of the deposit request.
+    txid BYTEA NOT NULL,
+    -- The output index of the deposit request.
+    output_index INTEGER NOT NULL,
+    -- The bitcoin chain tip when the deposit request was marked as
+    -- expired.
+    bitcoin_block_hash BYTEA NOT NULL,
+    -- The height of the bitcoin chain tip when the deposit request was
+    -- marked as expired.
+    bitcoin_block_height BIGINT NOT NULL,
+    -- Whether an operator has re-activated the deposit request after it
+    -- expired. Re-activated deposit requests are considered for sweeping
+    -- again and are not expired a second time.
+    reactivated BOOLEAN NOT NULL DEFAULT FALSE,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (txid, output_index),
+    FOREIGN KEY (txid, output_index) REFERENCES sbtc_signer.deposit_requests(txid, output_index) ON DELETE CASCADE
+);
@@ -0,0 +1,37 @@
+CREATE TYPE sbtc_signer.stacks_tx_status AS ENUM (
+    'pending',
+    'confirmed',
+    'failed',
+    'rejected',
+    'dropped'
+);
+
+CREATE TABLE sbtc_signer.stacks_tx_submissions (
+    -- The transaction ID of the submitted stacks transaction.
+    txid BYTEA PRIMARY KEY,
+    -- The kind of transaction that was submitted, like complete-deposit.
+    tx_kind TEXT NOT NULL,
+    -- Identifiers of the requests that are fulfilled by the transaction.
+    request_keys TEXT[] NOT NULL,
+    -- The nonce of the transaction.
+    nonce BIGINT NOT NULL,
+    -- The fee of the transaction, in microSTX.
+    tx_fee BIGINT NOT NULL,
+    -- The current status of the transaction, as far as this signer knows.
+    status sbtc_signer.stacks_tx_status NOT NULL,
+    -- The reason that the stacks node gave for rejecting the transaction.
+    rejection_reason TEXT,
+    -- The bitcoin chain tip when the transaction was submitted.
+    bitcoin_block_hash BYTEA NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    -- a timestamp of when the status of this record was last updated.
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE INDEX ix_stacks_tx_submissions_request_keys
+    ON sbtc_signer.stacks_tx_submissions USING GIN (request_keys);
+
+CREATE INDEX ix_stacks_tx_submissions_pending
+    ON sbtc_signer.stacks_tx_submissions (created_at)
+    WHERE status = 'pending';
@@ -0,0 +1,13 @@
+CREATE TABLE sbtc_signer.quarantined_stacks_blocks (
+    -- The block ID of the stacks block that failed verification.
+    block_hash BYTEA PRIMARY KEY,
+    -- The bitcoin block that the stacks node said anchors the tenure of
+    -- the stacks block.
+    bitcoin_anchor BYTEA NOT NULL,
+    -- Why the stacks block failed verification.
+    reason TEXT NOT NULL,
+    -- a timestamp of when the stacks block first failed verification.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    -- a timestamp of when the stacks block last failed verification.
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
@@ -0,0 +1,13 @@
+CREATE TABLE sbtc_signer.signed_sighashes (
+    -- The txid that created the output that is being spent.
+    prevout_txid BYTEA NOT NULL,
+    -- The index of the vout from the transaction that created this output.
+    prevout_output_index INTEGER NOT NULL,
+    -- The bitcoin chain tip when the sign request was submitted.
+    chain_tip BYTEA NOT NULL,
+    -- The sighash that this signer produced a signature share for.
+    sighash BYTEA NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (prevout_txid, prevout_output_index, chain_tip)
+);
@@ -0,0 +1,16 @@
+CREATE TABLE sbtc_signer.prevout_locks (
+    -- The txid that created the output that is being spent.
+    prevout_txid BYTEA NOT NULL,
+    -- The index of the vout from the transaction that created this output.
+    prevout_output_index INTEGER NOT NULL,
+    -- The bitcoin chain tip of the signing round holding the lock.
+    chain_tip BYTEA NOT NULL,
+    -- The ID of the bitcoin transaction being signed that spends the
+    -- output.
+    txid BYTEA NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (prevout_txid, prevout_output_index, chain_tip)
+);
+
+CREATE INDEX ix_prevout_locks_txid ON sbtc_signer.prevout_locks(txid);
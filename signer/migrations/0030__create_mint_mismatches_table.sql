@@ -0,0 +1,24 @@
+CREATE TABLE sbtc_signer.mint_mismatches (
+    id BIGSERIAL PRIMARY KEY,
+    -- The transaction ID of the deposit request.
+    txid BYTEA NOT NULL,
+    -- The output index of the deposit request.
+    output_index INTEGER NOT NULL,
+    -- The bitcoin chain tip when the sign request was validated.
+    chain_tip BYTEA NOT NULL,
+    -- The public key of the coordinator that sent the sign request.
+    origin BYTEA NOT NULL,
+    -- The recipient of the mint in the sign request.
+    recipient TEXT NOT NULL,
+    -- The recipient derived from the deposit script of the deposit
+    -- request, if the script could be parsed.
+    expected_recipient TEXT,
+    -- The amount to mint in the sign request.
+    amount BIGINT NOT NULL,
+    -- The amount of the deposit request less the assessed fee.
+    expected_amount BIGINT NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE INDEX ix_mint_mismatches_outpoint ON sbtc_signer.mint_mismatches(txid, output_index);
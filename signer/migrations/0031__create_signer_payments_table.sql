@@ -0,0 +1,28 @@
+CREATE TYPE sbtc_signer.signer_payment_status AS ENUM (
+    'unreviewed',
+    'donation',
+    'refund_approved'
+);
+
+CREATE TABLE sbtc_signer.signer_payments (
+    -- The ID of the bitcoin transaction that paid the signers.
+    txid BYTEA NOT NULL,
+    -- The index of the output that pays the signers' scriptPubKey.
+    output_index INTEGER NOT NULL,
+    -- The amount paid to the signers, in sats.
+    amount BIGINT NOT NULL,
+    -- The signers' scriptPubKey that locks the output.
+    script_pubkey BYTEA NOT NULL,
+    -- The scriptPubKey that locked all of the inputs of the transaction,
+    -- if they were all locked by the same one. Funds can only be refunded
+    -- to this scriptPubKey.
+    sender_script_pubkey BYTEA,
+    -- Whether this signer's operator has reviewed the payment, and what
+    -- they decided.
+    status sbtc_signer.signer_payment_status NOT NULL DEFAULT 'unreviewed',
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (txid, output_index)
+);
+
+CREATE INDEX ix_signer_payments_status ON sbtc_signer.signer_payments(status);
@@ -0,0 +1,14 @@
+CREATE TABLE sbtc_signer.bitcoin_block_processing (
+    -- The hash of the bitcoin block.
+    block_hash BYTEA PRIMARY KEY,
+    -- When the block observer observed the block.
+    observed_at TIMESTAMPTZ,
+    -- When the block observer fetched the deposit requests from Emily.
+    deposits_fetched_at TIMESTAMPTZ,
+    -- When the request decider sent its decisions.
+    decisions_completed_at TIMESTAMPTZ,
+    -- When this signer completed its tenure as the coordinator.
+    tenure_completed_at TIMESTAMPTZ,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
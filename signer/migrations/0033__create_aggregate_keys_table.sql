@@ -0,0 +1,47 @@
+-- A registry of every aggregate key that came out of a DKG round that this
+-- signer took part in, and of the range of bitcoin blocks over which it
+-- was the aggregate key in the sBTC registry contract.
+CREATE TABLE sbtc_signer.aggregate_keys (
+    -- The aggregate key, in compressed form.
+    aggregate_key BYTEA PRIMARY KEY,
+    -- The bitcoin chain tip when the DKG round for the key started.
+    dkg_block_hash BYTEA NOT NULL,
+    -- The height of the bitcoin chain tip when the DKG round started.
+    dkg_block_height BIGINT NOT NULL,
+    -- The verification status of the DKG shares for the key.
+    dkg_shares_status sbtc_signer.dkg_shares_status NOT NULL,
+    -- The ID of the rotate-keys stacks transaction for the key, if any.
+    rotate_keys_txid BYTEA,
+    -- The height of the first bitcoin block at which the key was the
+    -- aggregate key in the registry contract.
+    activation_height BIGINT,
+    -- The height of the first bitcoin block at which another key
+    -- replaced this one in the registry contract.
+    retirement_height BIGINT,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+INSERT INTO sbtc_signer.aggregate_keys (
+    aggregate_key
+  , dkg_block_hash
+  , dkg_block_height
+  , dkg_shares_status
+)
+SELECT
+    aggregate_key
+  , started_at_bitcoin_block_hash
+  , started_at_bitcoin_block_height
+  , dkg_shares_status
+FROM sbtc_signer.dkg_shares;
+
+UPDATE sbtc_signer.aggregate_keys
+SET rotate_keys_txid = rotations.txid
+FROM (
+    SELECT DISTINCT ON (aggregate_key)
+        aggregate_key
+      , txid
+    FROM sbtc_signer.rotate_keys_transactions
+    ORDER BY aggregate_key, created_at DESC
+) AS rotations
+WHERE aggregate_keys.aggregate_key = rotations.aggregate_key;
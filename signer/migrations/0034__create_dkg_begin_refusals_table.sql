@@ -0,0 +1,31 @@
+CREATE TYPE sbtc_signer.dkg_begin_refusal_reason AS ENUM (
+    'latest_shares_unverified',
+    'target_rounds_reached',
+    'below_minimum_height',
+    'rerun_height_not_configured'
+);
+
+-- A record of each time that this signer refused to begin a DKG round,
+-- so that operators can see why DKG is not running when they expect it
+-- to.
+CREATE TABLE sbtc_signer.dkg_begin_refusals (
+    id BIGSERIAL PRIMARY KEY,
+    -- Why this signer refused to begin the DKG round.
+    reason sbtc_signer.dkg_begin_refusal_reason NOT NULL,
+    -- The bitcoin chain tip when the DKG round was requested.
+    bitcoin_chain_tip BYTEA NOT NULL,
+    -- The height of the above bitcoin chain tip.
+    bitcoin_chain_tip_height BIGINT NOT NULL,
+    -- The verification status of the latest DKG shares of this signer,
+    -- if it has any.
+    latest_dkg_shares_status sbtc_signer.dkg_shares_status,
+    -- The number of DKG shares that this signer had stored.
+    dkg_shares_count INTEGER NOT NULL,
+    -- The configured target number of DKG rounds.
+    dkg_target_rounds INTEGER NOT NULL,
+    -- The configured minimum bitcoin block height for rerunning DKG, if
+    -- any.
+    dkg_min_bitcoin_block_height BIGINT,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
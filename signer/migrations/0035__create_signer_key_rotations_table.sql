@@ -0,0 +1,35 @@
+CREATE TYPE sbtc_signer.signer_key_rotation_status AS ENUM (
+    'generated',
+    'announced',
+    'config_updated',
+    'completed',
+    'cancelled'
+);
+
+-- The rotations of the key of this signer that its operator started, as
+-- opposed to rotations of the aggregate key of the signer set.
+CREATE TABLE sbtc_signer.signer_key_rotations (
+    id BIGSERIAL PRIMARY KEY,
+    -- The public key that is being replaced.
+    old_public_key BYTEA NOT NULL,
+    -- The public key that replaces the old one.
+    new_public_key BYTEA NOT NULL UNIQUE,
+    -- How far along the rotation is.
+    status sbtc_signer.signer_key_rotation_status NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+-- The endorsements of new keys that this signer received from the other
+-- signers, each one signed with the key that it replaces.
+CREATE TABLE sbtc_signer.signer_key_endorsements (
+    -- The public key of the signer that sent the endorsement.
+    old_public_key BYTEA NOT NULL,
+    -- The public key that the sending signer endorsed as its new key.
+    new_public_key BYTEA NOT NULL,
+    -- The bitcoin chain tip in the message with the endorsement.
+    bitcoin_chain_tip BYTEA NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (old_public_key, new_public_key)
+);
@@ -0,0 +1,15 @@
+-- The approvals of the emergency recovery of the funds of the signers,
+-- from this signer and from the other members of the signer set.
+CREATE TABLE sbtc_signer.emergency_recovery_approvals (
+    -- The public key of the signer that approved the recovery.
+    signer_public_key BYTEA NOT NULL,
+    -- The scriptPubKey that the approval sweeps the funds to.
+    recovery_script_pubkey BYTEA NOT NULL,
+    -- The bitcoin chain tip of this signer when it recorded the approval.
+    bitcoin_block_hash BYTEA NOT NULL,
+    -- The height of the above chain tip.
+    bitcoin_block_height BIGINT NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (signer_public_key, recovery_script_pubkey)
+);
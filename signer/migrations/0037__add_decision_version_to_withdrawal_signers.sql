@@ -0,0 +1,6 @@
+-- The version of a signer's decision on a withdrawal request. A signer
+-- starts at zero and bumps the version each time it re-validates the
+-- request and changes its mind, so that the other signers can tell which
+-- of its decisions is the latest one.
+ALTER TABLE sbtc_signer.withdrawal_signers
+    ADD COLUMN decision_version INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,19 @@
+-- The deposit requests that were spent by the depositor through the
+-- reclaim path of the deposit script, instead of being swept by the
+-- signers.
+CREATE TABLE sbtc_signer.deposit_reclaims (
+    -- The transaction ID of the deposit request.
+    txid BYTEA NOT NULL,
+    -- The output index of the deposit request.
+    output_index INTEGER NOT NULL,
+    -- The transaction ID of the transaction that reclaimed the deposit.
+    reclaim_txid BYTEA NOT NULL,
+    -- The bitcoin block that confirmed the reclaim transaction.
+    bitcoin_block_hash BYTEA NOT NULL,
+    -- The height of the above bitcoin block.
+    bitcoin_block_height BIGINT NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (txid, output_index, bitcoin_block_hash),
+    FOREIGN KEY (txid, output_index) REFERENCES sbtc_signer.deposit_requests(txid, output_index) ON DELETE CASCADE
+);
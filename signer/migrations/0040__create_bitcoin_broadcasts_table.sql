@@ -0,0 +1,39 @@
+CREATE TYPE sbtc_signer.bitcoin_broadcast_status AS ENUM (
+    'pending',
+    'broadcast',
+    'confirmed',
+    'failed'
+);
+
+-- The signed sweep transactions that the coordinator handed to the
+-- broadcaster, which broadcasts them in the background.
+CREATE TABLE sbtc_signer.bitcoin_broadcasts (
+    -- The transaction ID of the signed transaction.
+    txid BYTEA PRIMARY KEY,
+    -- The transaction ID of the first transaction in the package that
+    -- this transaction was signed with. Packages are broadcast together.
+    package_txid BYTEA NOT NULL,
+    -- The position of this transaction in its package.
+    package_position INTEGER NOT NULL,
+    -- The consensus encoded signed transaction.
+    tx BYTEA NOT NULL,
+    -- The fee rate that the transaction pays, in sats per vbyte.
+    fee_rate DOUBLE PRECISION NOT NULL,
+    -- The bitcoin chain tip when the transaction was signed.
+    bitcoin_block_hash BYTEA NOT NULL,
+    -- The current status of the transaction, as far as this signer knows.
+    status sbtc_signer.bitcoin_broadcast_status NOT NULL,
+    -- The number of times that the broadcaster tried to broadcast the
+    -- transaction.
+    attempts INTEGER NOT NULL DEFAULT 0,
+    -- The error from the last failed attempt to broadcast the transaction.
+    last_error TEXT,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    -- a timestamp of when the status of this record was last updated.
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE INDEX ix_bitcoin_broadcasts_unsettled
+    ON sbtc_signer.bitcoin_broadcasts (created_at, package_position)
+    WHERE status IN ('pending', 'broadcast');
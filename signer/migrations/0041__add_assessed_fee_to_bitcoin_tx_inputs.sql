@@ -0,0 +1,8 @@
+-- The part of the sweep transaction fee that was assessed to a swept
+-- deposit, in sats. This is the fee that the coordinator puts in the
+-- complete-deposit contract call and that the other signers check it
+-- against, so both read it from here instead of asking bitcoin-core for
+-- the sweep transaction again. It is NULL for the signers' inputs and for
+-- deposits that were swept before we recorded the assessed fee.
+ALTER TABLE sbtc_signer.bitcoin_tx_inputs
+    ADD COLUMN assessed_fee BIGINT;
@@ -0,0 +1,34 @@
+-- The acknowledgements, from the other signers, of this signer's decisions
+-- on deposit requests. This signer stops re-sending a decision once every
+-- other signer in the signer set has acknowledged it.
+CREATE TABLE sbtc_signer.deposit_decision_acknowledgements (
+    -- The transaction ID of the deposit request.
+    txid BYTEA NOT NULL,
+    -- The output index of the deposit request.
+    output_index INTEGER NOT NULL,
+    -- The public key of the signer that acknowledged the decision.
+    signer_pub_key BYTEA NOT NULL,
+    -- The latest version of the decision that the signer acknowledged.
+    decision_version INTEGER NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (txid, output_index, signer_pub_key)
+);
+
+-- The acknowledgements, from the other signers, of this signer's decisions
+-- on withdrawal requests.
+CREATE TABLE sbtc_signer.withdrawal_decision_acknowledgements (
+    -- The id of the withdrawal request.
+    request_id BIGINT NOT NULL,
+    -- The stacks transaction id of the withdrawal request.
+    txid BYTEA NOT NULL,
+    -- The block hash of the stacks block which `txid` was included in.
+    block_hash BYTEA NOT NULL,
+    -- The public key of the signer that acknowledged the decision.
+    signer_pub_key BYTEA NOT NULL,
+    -- The latest version of the decision that the signer acknowledged.
+    decision_version INTEGER NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (request_id, block_hash, signer_pub_key)
+);
@@ -0,0 +1,16 @@
+-- Whether the signer revoked its approval of the emergency recovery. Each
+-- signer has a single row for each recovery scriptPubKey, holding its
+-- latest approval or revocation.
+ALTER TABLE sbtc_signer.emergency_recovery_approvals
+    ADD COLUMN revoked BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- The emergency recovery sweeps that this signer agreed to sign. The
+-- emergency recovery is over once one of them is confirmed.
+CREATE TABLE sbtc_signer.emergency_recovery_sweeps (
+    -- The ID of the sweep transaction.
+    txid BYTEA PRIMARY KEY,
+    -- The scriptPubKey that the sweep pays the funds to.
+    recovery_script_pubkey BYTEA NOT NULL,
+    -- a timestamp of when this record was created in the database.
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
@@ -0,0 +1,41 @@
+-- Whether the operator paused the coordinator duties of this signer
+-- through the control API. The table holds at most a single row, and a
+-- missing row means that the coordinator duties are not paused.
+CREATE TABLE sbtc_signer.coordinator_pause (
+    -- Always TRUE, so that there is at most one row.
+    id BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    -- Whether the coordinator duties are paused.
+    paused BOOLEAN NOT NULL,
+    -- a timestamp of when this record was last updated in the database.
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+-- The operator overrides of this signer's decisions on deposit requests,
+-- set through the control API. Removing an override deletes its row.
+CREATE TABLE sbtc_signer.deposit_decision_overrides (
+    -- The transaction ID of the deposit request.
+    txid BYTEA NOT NULL,
+    -- The output index of the deposit request.
+    output_index INTEGER NOT NULL,
+    -- Whether this signer accepts the deposit request.
+    can_accept BOOLEAN NOT NULL,
+    -- a timestamp of when this record was last updated in the database.
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (txid, output_index)
+);
+
+-- The operator overrides of this signer's decisions on withdrawal
+-- requests, set through the control API.
+CREATE TABLE sbtc_signer.withdrawal_decision_overrides (
+    -- The id of the withdrawal request.
+    request_id BIGINT NOT NULL,
+    -- The stacks transaction id of the withdrawal request.
+    txid BYTEA NOT NULL,
+    -- The block hash of the stacks block which `txid` was included in.
+    block_hash BYTEA NOT NULL,
+    -- Whether this signer accepts the withdrawal request.
+    accept BOOLEAN NOT NULL,
+    -- a timestamp of when this record was last updated in the database.
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY (request_id, block_hash)
+);
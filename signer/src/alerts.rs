@@ -0,0 +1,625 @@
+//! Alerting of operators on critical signer events.
+//!
+//! The [`AlertDispatcherEventLoop`] listens on the signal channel of the
+//! [`Context`] and turns the events that need the attention of an
+//! operator into [`Alert`]s:
+//!
+//! * The coordinator failed to run DKG.
+//! * The signers' UTXO was spent without the signers knowing about it,
+//!   or the balance of the signers' wallet does not match the database.
+//! * The stacks node rejected several consecutive transactions from the
+//!   coordinator.
+//! * No new bitcoin block was observed for a configured amount of time.
+//! * The chain tip watchdog could not get the chain tip of the signer
+//!   back in line with bitcoin-core.
+//! * An event loop ran into an error with [`Severity::Critical`].
+//!
+//! Alerts are sent to the Slack and PagerDuty channels in the
+//! configuration, depending on their severity. The same alert is sent at
+//! most once within the deduplication window, so that a condition that
+//! persists does not flood the channels.
+
+use std::time::Duration;
+
+use futures::StreamExt as _;
+use hashbrown::HashMap;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::time::Instant;
+use url::Url;
+
+use crate::broadcaster::BroadcasterEvent;
+use crate::config::AlertsConfig;
+use crate::context::Context;
+use crate::context::SignerCommand;
+use crate::context::SignerEvent;
+use crate::context::SignerSignal;
+use crate::context::TxCoordinatorEvent;
+use crate::emergency_recovery::EmergencyRecoveryEvent;
+use crate::error::Error;
+use crate::error::Severity;
+use crate::keys::PublicKey;
+use crate::reconciliation::UtxoReconciliation;
+use crate::watchdog::ChainTipHealth;
+
+/// The endpoint of the PagerDuty Events API v2.
+pub const PAGERDUTY_EVENTS_ENDPOINT: &str = "https://events.pagerduty.com/v2/enqueue";
+
+/// How severe an alert is, from lowest to highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertSeverity {
+    /// Informational, nothing needs to be done.
+    Info,
+    /// Something may need attention.
+    Warning,
+    /// Something is not working and needs attention.
+    Error,
+    /// The signer cannot do its job, or funds may be at risk.
+    Critical,
+}
+
+impl AlertSeverity {
+    /// The name of the severity, as understood by PagerDuty.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertSeverity::Info => "info",
+            AlertSeverity::Warning => "warning",
+            AlertSeverity::Error => "error",
+            AlertSeverity::Critical => "critical",
+        }
+    }
+}
+
+impl From<Severity> for AlertSeverity {
+    fn from(value: Severity) -> Self {
+        match value {
+            Severity::Warning => AlertSeverity::Warning,
+            Severity::Error => AlertSeverity::Error,
+            Severity::Critical => AlertSeverity::Critical,
+        }
+    }
+}
+
+/// An alert for the operators of the signer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alert {
+    /// Identifies the condition that the alert is about. Alerts with the
+    /// same key are deduplicated.
+    pub key: String,
+    /// How severe the alert is.
+    pub severity: AlertSeverity,
+    /// A human readable description of the alert.
+    pub summary: String,
+}
+
+impl Alert {
+    /// Create a new alert.
+    pub fn new(
+        key: impl Into<String>,
+        severity: AlertSeverity,
+        summary: impl Into<String>,
+    ) -> Self {
+        Self {
+            key: key.into(),
+            severity,
+            summary: summary.into(),
+        }
+    }
+}
+
+/// Keeps track of the alerts that were sent recently, so that the same
+/// alert is not sent again within the deduplication window.
+#[derive(Debug, Clone)]
+pub struct AlertDeduplicator {
+    /// The amount of time during which an alert is not sent again.
+    window: Duration,
+    /// When each recently sent alert was sent, by alert key.
+    sent_at: HashMap<String, Instant>,
+}
+
+impl AlertDeduplicator {
+    /// Create a deduplicator with the given window.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            sent_at: HashMap::new(),
+        }
+    }
+
+    /// Whether the given alert should be sent at the given time. If so,
+    /// the alert is recorded as sent.
+    pub fn should_send(&mut self, alert: &Alert, now: Instant) -> bool {
+        let window = self.window;
+        self.sent_at
+            .retain(|_, sent_at| now.saturating_duration_since(*sent_at) < window);
+
+        if self.sent_at.contains_key(&alert.key) {
+            return false;
+        }
+        self.sent_at.insert(alert.key.clone(), now);
+        true
+    }
+}
+
+/// The channels that alerts are sent to.
+#[derive(Debug, Clone)]
+pub struct AlertChannels {
+    /// The public key of this signer, which identifies the source of the
+    /// alerts.
+    source: PublicKey,
+    /// The incoming webhook of the Slack channel.
+    slack_webhook_url: Option<Url>,
+    /// The minimum severity of the alerts posted to Slack.
+    slack_min_severity: AlertSeverity,
+    /// The routing key of the PagerDuty service.
+    pagerduty_routing_key: Option<String>,
+    /// The minimum severity of the alerts sent to PagerDuty.
+    pagerduty_min_severity: AlertSeverity,
+    /// The client used to send the alerts.
+    client: reqwest::Client,
+}
+
+impl AlertChannels {
+    /// Create the channels in the given configuration, for alerts from
+    /// the signer with the given public key.
+    pub fn new(config: &AlertsConfig, source: PublicKey) -> Self {
+        Self {
+            source,
+            slack_webhook_url: config.slack_webhook_url.clone(),
+            slack_min_severity: config.slack_min_severity,
+            pagerduty_routing_key: config.pagerduty_routing_key.clone(),
+            pagerduty_min_severity: config.pagerduty_min_severity,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Whether there is any channel to send alerts to.
+    pub fn is_enabled(&self) -> bool {
+        self.slack_webhook_url.is_some() || self.pagerduty_routing_key.is_some()
+    }
+
+    /// Send the alert to each channel that accepts alerts of its
+    /// severity.
+    pub async fn send(&self, alert: &Alert) -> Result<(), Error> {
+        if let Some(url) = &self.slack_webhook_url {
+            if alert.severity >= self.slack_min_severity {
+                let body = serde_json::json!({
+                    "text": format!(
+                        "[{}] sBTC signer {}: {}",
+                        alert.severity.as_str().to_uppercase(),
+                        self.source,
+                        alert.summary
+                    ),
+                });
+                self.client
+                    .post(url.clone())
+                    .json(&body)
+                    .send()
+                    .await?
+                    .error_for_status()?;
+            }
+        }
+
+        if let Some(routing_key) = &self.pagerduty_routing_key {
+            if alert.severity >= self.pagerduty_min_severity {
+                let body = serde_json::json!({
+                    "routing_key": routing_key,
+                    "event_action": "trigger",
+                    "dedup_key": format!("{}:{}", self.source, alert.key),
+                    "payload": {
+                        "summary": alert.summary,
+                        "source": self.source.to_string(),
+                        "severity": alert.severity.as_str(),
+                    },
+                });
+                self.client
+                    .post(PAGERDUTY_EVENTS_ENDPOINT)
+                    .json(&body)
+                    .send()
+                    .await?
+                    .error_for_status()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// This function defines which messages this event loop is interested
+/// in.
+fn run_loop_message_filter(signal: &SignerSignal) -> bool {
+    matches!(
+        signal,
+        SignerSignal::Command(SignerCommand::Shutdown)
+            | SignerSignal::Event(SignerEvent::BitcoinBlockObserved)
+            | SignerSignal::Event(SignerEvent::SignerUtxoMismatch(_))
+            | SignerSignal::Event(SignerEvent::ChainTipUnhealthy(_))
+            | SignerSignal::Event(SignerEvent::CriticalError(_))
+            | SignerSignal::Event(SignerEvent::EmergencyRecovery(_))
+            | SignerSignal::Event(SignerEvent::Broadcaster(
+                BroadcasterEvent::SweepDropped { .. }
+            ))
+            | SignerSignal::Event(SignerEvent::TxCoordinator(
+                TxCoordinatorEvent::DkgFailed(_)
+                    | TxCoordinatorEvent::StacksSubmissionAccepted { .. }
+                    | TxCoordinatorEvent::StacksSubmissionFailed(_)
+            ))
+    )
+}
+
+/// The event loop that sends alerts to operators on critical events.
+#[derive(Debug)]
+pub struct AlertDispatcherEventLoop<C> {
+    /// The signer context.
+    pub context: C,
+    /// The channels that alerts are sent to.
+    pub channels: AlertChannels,
+    /// Deduplicates the alerts that are sent.
+    pub deduplicator: AlertDeduplicator,
+    /// The amount of time without a new bitcoin block after which the
+    /// chain tip is considered stale. Zero disables the alert.
+    pub stale_chain_tip_threshold: Duration,
+    /// The number of consecutive failed stacks transaction submissions
+    /// after which an alert is sent.
+    pub stacks_submission_failure_threshold: u16,
+    /// The number of stacks transaction submissions that failed since
+    /// the last one that was accepted.
+    pub stacks_submission_failures: u16,
+}
+
+impl<C> AlertDispatcherEventLoop<C>
+where
+    C: Context,
+{
+    /// Create the event loop from the alerts configuration of the
+    /// context.
+    pub fn new(context: C) -> Self {
+        let config = context.config();
+        let alerts = &config.signer.alerts;
+        Self {
+            channels: AlertChannels::new(alerts, config.signer.public_key()),
+            deduplicator: AlertDeduplicator::new(alerts.dedup_window),
+            stale_chain_tip_threshold: alerts.stale_chain_tip_threshold,
+            stacks_submission_failure_threshold: alerts.stacks_submission_failure_threshold.get(),
+            stacks_submission_failures: 0,
+            context,
+        }
+    }
+
+    /// Run the alert dispatcher event loop.
+    #[tracing::instrument(skip_all, name = "alert-dispatcher")]
+    pub async fn run(mut self) -> Result<(), Error> {
+        if !self.channels.is_enabled() {
+            tracing::debug!("no alert channels configured; alerting is disabled");
+            return Ok(());
+        }
+
+        let mut signal_stream = self.context.as_signal_stream(run_loop_message_filter);
+        let clock = self.context.clock().clone();
+        let threshold = self.stale_chain_tip_threshold;
+        let mut stale_check_at = clock.now() + threshold;
+
+        loop {
+            let clock_ref = &clock;
+            let stale_timer = async move {
+                if threshold.is_zero() {
+                    std::future::pending::<()>().await;
+                }
+                clock_ref
+                    .sleep(stale_check_at.saturating_duration_since(clock_ref.now()))
+                    .await
+            };
+
+            let alert = tokio::select! {
+                signal = signal_stream.next() => match signal {
+                    None | Some(SignerSignal::Command(SignerCommand::Shutdown)) => break,
+                    Some(SignerSignal::Event(SignerEvent::BitcoinBlockObserved)) => {
+                        stale_check_at = clock.now() + threshold;
+                        None
+                    }
+                    Some(SignerSignal::Event(event)) => self.alert_for_event(&event),
+                    Some(_) => None,
+                },
+                _ = stale_timer => {
+                    stale_check_at = clock.now() + threshold;
+                    Some(self.stale_chain_tip_alert())
+                }
+            };
+
+            let Some(alert) = alert else {
+                continue;
+            };
+
+            if !self.deduplicator.should_send(&alert, clock.now()) {
+                tracing::debug!(key = %alert.key, "suppressing duplicate alert");
+                continue;
+            }
+
+            tracing::warn!(key = %alert.key, severity = alert.severity.as_str(), summary = %alert.summary, "sending alert");
+            if let Err(error) = self.channels.send(&alert).await {
+                tracing::warn!(%error, key = %alert.key, "could not send alert");
+            }
+        }
+
+        tracing::info!("alert dispatcher event loop is stopping");
+        Ok(())
+    }
+
+    /// Map the given event to an alert, if it warrants one.
+    pub fn alert_for_event(&mut self, event: &SignerEvent) -> Option<Alert> {
+        match event {
+            SignerEvent::TxCoordinator(TxCoordinatorEvent::DkgFailed(error)) => Some(Alert::new(
+                "dkg-failed",
+                AlertSeverity::Critical,
+                format!("the coordinator failed to run DKG: {error}"),
+            )),
+            SignerEvent::TxCoordinator(TxCoordinatorEvent::StacksSubmissionAccepted { .. }) => {
+                self.stacks_submission_failures = 0;
+                None
+            }
+            SignerEvent::TxCoordinator(TxCoordinatorEvent::StacksSubmissionFailed(error)) => {
+                self.stacks_submission_failures = self.stacks_submission_failures.saturating_add(1);
+                if self.stacks_submission_failures < self.stacks_submission_failure_threshold {
+                    return None;
+                }
+                Some(Alert::new(
+                    "stacks-submission-failing",
+                    AlertSeverity::Error,
+                    format!(
+                        "{} consecutive stacks transaction submissions failed, the last one with: {error}",
+                        self.stacks_submission_failures
+                    ),
+                ))
+            }
+            SignerEvent::SignerUtxoMismatch(UtxoReconciliation::MissingUtxo(outpoint)) => {
+                Some(Alert::new(
+                    format!("unexpected-signer-utxo-spend:{outpoint}"),
+                    AlertSeverity::Critical,
+                    format!(
+                        "the signers' UTXO {outpoint} was spent by a transaction unknown to the signers"
+                    ),
+                ))
+            }
+            SignerEvent::SignerUtxoMismatch(UtxoReconciliation::AmountMismatch {
+                stored,
+                scanned,
+            }) => Some(Alert::new(
+                "signer-utxo-amount-mismatch",
+                AlertSeverity::Warning,
+                format!(
+                    "the signers' wallet holds {} sats but the UTXO in the database holds {} sats",
+                    scanned.to_sat(),
+                    stored.to_sat()
+                ),
+            )),
+            SignerEvent::ChainTipUnhealthy(ChainTipHealth::Lagging {
+                signer_height,
+                node_height,
+            }) => Some(Alert::new(
+                "lagging-chain-tip",
+                AlertSeverity::Error,
+                format!(
+                    "the bitcoin chain tip of the signer is at height {signer_height} but bitcoin-core is at height {node_height}, even after resyncing"
+                ),
+            )),
+            SignerEvent::ChainTipUnhealthy(ChainTipHealth::Stalled {
+                seconds_since_last_block,
+            }) => Some(Alert::new(
+                "stale-chain-tip",
+                AlertSeverity::Error,
+                format!(
+                    "the bitcoin chain tip of the signer has not changed in the last {} minutes, even after resyncing",
+                    seconds_since_last_block / 60
+                ),
+            )),
+            SignerEvent::CriticalError(report) => Some(Alert::new(
+                format!("critical-error:{}:{}", report.event_loop, report.code),
+                Severity::Critical.into(),
+                format!(
+                    "[{}] {}: {}",
+                    report.code, report.event_loop, report.message
+                ),
+            )),
+            SignerEvent::EmergencyRecovery(EmergencyRecoveryEvent::ApprovalRecorded {
+                approver,
+                tally,
+            }) => Some(Alert::new(
+                format!("emergency-recovery-approval:{approver}"),
+                AlertSeverity::Critical,
+                match tally.sweep_height {
+                    Some(height) => format!(
+                        "signer {approver} approved the emergency recovery; {} of {} signers approved it, and the signers' funds will be swept from bitcoin block height {height}",
+                        tally.approvals, tally.threshold
+                    ),
+                    None => format!(
+                        "signer {approver} approved the emergency recovery; {} of {} required signers approved it so far",
+                        tally.approvals, tally.threshold
+                    ),
+                },
+            )),
+            SignerEvent::EmergencyRecovery(EmergencyRecoveryEvent::ApprovalRevoked {
+                revoker,
+                tally,
+            }) => Some(Alert::new(
+                format!("emergency-recovery-revocation:{revoker}"),
+                AlertSeverity::Critical,
+                format!(
+                    "signer {revoker} revoked its approval of the emergency recovery; {} of {} required signers still approve it",
+                    tally.approvals, tally.threshold
+                ),
+            )),
+            SignerEvent::EmergencyRecovery(EmergencyRecoveryEvent::SweepBroadcast { txid }) => {
+                Some(Alert::new(
+                    "emergency-recovery-sweep",
+                    AlertSeverity::Critical,
+                    format!(
+                        "broadcast the emergency recovery sweep of the signers' funds in transaction {txid}"
+                    ),
+                ))
+            }
+            SignerEvent::Broadcaster(BroadcasterEvent::SweepDropped { txid, error }) => {
+                Some(Alert::new(
+                    format!("sweep-dropped:{txid}"),
+                    AlertSeverity::Error,
+                    format!("gave up on broadcasting the sweep transaction {txid}: {error}"),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// The alert for a chain tip that has not moved for the configured
+    /// threshold.
+    fn stale_chain_tip_alert(&self) -> Alert {
+        let chain_tip = self
+            .context
+            .state()
+            .bitcoin_chain_tip()
+            .map(|tip| tip.block_hash.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        Alert::new(
+            "stale-chain-tip",
+            AlertSeverity::Error,
+            format!(
+                "no new bitcoin block was observed in the last {} minutes; the chain tip is {chain_tip}",
+                self.stale_chain_tip_threshold.as_secs() / 60
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::Amount;
+    use bitcoin::OutPoint;
+    use fake::Fake as _;
+    use fake::Faker;
+
+    use super::*;
+    use crate::emergency_recovery::EmergencyRecoveryTally;
+    use crate::supervisor::ErrorReport;
+    use crate::testing::context::*;
+
+    #[test]
+    fn deduplicator_suppresses_alerts_within_the_window() {
+        let mut deduplicator = AlertDeduplicator::new(Duration::from_secs(60));
+        let alert = Alert::new("dkg-failed", AlertSeverity::Critical, "DKG failed");
+        let other = Alert::new("stale-chain-tip", AlertSeverity::Error, "stale");
+        let start = Instant::now();
+
+        assert!(deduplicator.should_send(&alert, start));
+        assert!(!deduplicator.should_send(&alert, start + Duration::from_secs(59)));
+        assert!(deduplicator.should_send(&other, start + Duration::from_secs(59)));
+        assert!(deduplicator.should_send(&alert, start + Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn severities_are_ordered() {
+        assert!(AlertSeverity::Info < AlertSeverity::Warning);
+        assert!(AlertSeverity::Warning < AlertSeverity::Error);
+        assert!(AlertSeverity::Error < AlertSeverity::Critical);
+    }
+
+    #[tokio::test]
+    async fn events_map_to_alerts() {
+        let ctx = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .build();
+        let mut dispatcher = AlertDispatcherEventLoop::new(ctx);
+        dispatcher.stacks_submission_failure_threshold = 2;
+
+        let event = SignerEvent::TxCoordinator(TxCoordinatorEvent::DkgFailed("timeout".into()));
+        let alert = dispatcher.alert_for_event(&event).unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+
+        let event =
+            SignerEvent::SignerUtxoMismatch(UtxoReconciliation::MissingUtxo(OutPoint::null()));
+        let alert = dispatcher.alert_for_event(&event).unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+
+        let event = SignerEvent::SignerUtxoMismatch(UtxoReconciliation::AmountMismatch {
+            stored: Amount::from_sat(1),
+            scanned: Amount::from_sat(2),
+        });
+        let alert = dispatcher.alert_for_event(&event).unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Warning);
+
+        let event = SignerEvent::ChainTipUnhealthy(ChainTipHealth::Lagging {
+            signer_height: 100u64.into(),
+            node_height: 110u64.into(),
+        });
+        let alert = dispatcher.alert_for_event(&event).unwrap();
+        assert_eq!(alert.key, "lagging-chain-tip");
+        assert_eq!(alert.severity, AlertSeverity::Error);
+
+        // Only consecutive failures raise an alert.
+        let failed =
+            SignerEvent::TxCoordinator(TxCoordinatorEvent::StacksSubmissionFailed("nope".into()));
+        let accepted = SignerEvent::TxCoordinator(TxCoordinatorEvent::StacksSubmissionAccepted {
+            txid: Faker.fake(),
+            kind: "complete-deposit",
+        });
+        assert!(dispatcher.alert_for_event(&failed).is_none());
+        assert!(dispatcher.alert_for_event(&accepted).is_none());
+        assert!(dispatcher.alert_for_event(&failed).is_none());
+        let alert = dispatcher.alert_for_event(&failed).unwrap();
+        assert_eq!(alert.key, "stacks-submission-failing");
+        assert_eq!(alert.severity, AlertSeverity::Error);
+
+        let event = SignerEvent::CriticalError(ErrorReport {
+            event_loop: "block-observer",
+            code: Error::InvalidConfiguration.code(),
+            message: "could not run event loop".to_string(),
+        });
+        let alert = dispatcher.alert_for_event(&event).unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+        assert!(alert.summary.starts_with("[E9017] block-observer"));
+
+        let event = SignerEvent::EmergencyRecovery(EmergencyRecoveryEvent::ApprovalRecorded {
+            approver: Faker.fake(),
+            tally: EmergencyRecoveryTally {
+                approvals: 1,
+                threshold: 2,
+                sweep_height: None,
+            },
+        });
+        let alert = dispatcher.alert_for_event(&event).unwrap();
+        assert!(alert.key.starts_with("emergency-recovery-approval:"));
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+
+        let event = SignerEvent::EmergencyRecovery(EmergencyRecoveryEvent::ApprovalRevoked {
+            revoker: Faker.fake(),
+            tally: EmergencyRecoveryTally {
+                approvals: 0,
+                threshold: 2,
+                sweep_height: None,
+            },
+        });
+        let alert = dispatcher.alert_for_event(&event).unwrap();
+        assert!(alert.key.starts_with("emergency-recovery-revocation:"));
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+
+        let event = SignerEvent::EmergencyRecovery(EmergencyRecoveryEvent::SweepBroadcast {
+            txid: Faker.fake(),
+        });
+        let alert = dispatcher.alert_for_event(&event).unwrap();
+        assert_eq!(alert.key, "emergency-recovery-sweep");
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+
+        let event = SignerEvent::Broadcaster(BroadcasterEvent::SweepDropped {
+            txid: Faker.fake(),
+            error: "min relay fee not met".to_string(),
+        });
+        let alert = dispatcher.alert_for_event(&event).unwrap();
+        assert!(alert.key.starts_with("sweep-dropped:"));
+        assert_eq!(alert.severity, AlertSeverity::Error);
+
+        assert!(
+            dispatcher
+                .alert_for_event(&SignerEvent::BitcoinBlockObserved)
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,354 @@
+//! Handlers for the `/deposits` endpoints, which look up the lifecycle of
+//! deposit requests by their outpoint or by their recipient.
+//!
+//! The lifecycle of a deposit joins the deposit request with the votes
+//! of the signers, the sweep transaction that moved the funds into the
+//! signers' wallet, and the stacks transaction that minted the sBTC.
+//!
+//! There is also an endpoint for estimating the fee that a prospective
+//! deposit would be charged, so that wallets can check the max fee of a
+//! deposit before the user makes it.
+
+use std::str::FromStr as _;
+
+use axum::Json;
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Serialize;
+
+use crate::bitcoin::utxo;
+use crate::bitcoin::utxo::RequestFeeEstimate;
+use crate::bitcoin::validation::DepositConfirmationStatus;
+use crate::context::Context;
+use crate::error::Error;
+use crate::storage::DbRead;
+use crate::storage::model::BitcoinTxId;
+use crate::storage::model::DepositRequest;
+use crate::storage::model::StacksPrincipal;
+
+use super::ApiState;
+use super::pending_requests;
+use super::pending_requests_status;
+
+#[derive(Debug, Serialize)]
+pub struct DepositLifecycle {
+    pub txid: String,
+    pub output_index: u32,
+    pub recipient: String,
+    pub amount: u64,
+    pub max_fee: u64,
+    pub lock_time: u32,
+    pub signers_public_key: String,
+    pub votes: Vec<DepositVote>,
+    pub sweep_txid: Option<String>,
+    pub mint_txid: Option<String>,
+    pub status: DepositLifecycleStatus,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DepositVote {
+    pub signer_public_key: String,
+    pub can_accept: bool,
+    pub can_sign: bool,
+}
+
+/// Where a deposit request is in its lifecycle, from the point of view
+/// of this signer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DepositLifecycleStatus {
+    /// The signer does not know the bitcoin chain tip yet.
+    Unknown,
+    /// The deposit transaction is not on the canonical bitcoin
+    /// blockchain.
+    Unconfirmed,
+    /// The deposit transaction is confirmed, but the funds have not been
+    /// swept yet.
+    Pending,
+    /// The funds have been swept into the signers' wallet, but the sBTC
+    /// has not been minted yet.
+    Swept,
+    /// The sBTC has been minted to the recipient.
+    Completed,
+    /// The depositor reclaimed the funds before they were swept.
+    Reclaimed,
+}
+
+/// Assemble the lifecycle of the given deposit request.
+async fn deposit_lifecycle<C: Context>(
+    ctx: &C,
+    request: DepositRequest,
+) -> Result<DepositLifecycle, Error> {
+    let storage = ctx.get_storage();
+    let votes = storage
+        .get_deposit_signers(&request.txid, request.output_index)
+        .await?
+        .into_iter()
+        .map(|vote| DepositVote {
+            signer_public_key: vote.signer_pub_key.to_string(),
+            can_accept: vote.can_accept,
+            can_sign: vote.can_sign,
+        })
+        .collect();
+
+    // Events from stacks forks are returned too, so we take the most
+    // recent one.
+    let mint = storage
+        .get_completed_deposit_events(&request.txid, request.output_index)
+        .await?
+        .pop();
+
+    let confirmation = match ctx.state().bitcoin_chain_tip() {
+        Some(chain_tip) => {
+            let signer_public_key = ctx.config().signer.public_key();
+            storage
+                .get_deposit_request_report(
+                    &chain_tip.block_hash,
+                    &request.txid,
+                    request.output_index,
+                    &signer_public_key,
+                )
+                .await?
+                .map(|report| report.status)
+        }
+        None => None,
+    };
+
+    let sweep_txid = match (&confirmation, &mint) {
+        (Some(DepositConfirmationStatus::Spent(txid)), _) => Some(*txid),
+        (_, Some(event)) => Some(event.sweep_txid),
+        _ => None,
+    };
+
+    let status = match (&confirmation, &mint) {
+        (_, Some(_)) => DepositLifecycleStatus::Completed,
+        (None, None) => DepositLifecycleStatus::Unknown,
+        (Some(DepositConfirmationStatus::Unconfirmed), None) => DepositLifecycleStatus::Unconfirmed,
+        (Some(DepositConfirmationStatus::Confirmed(..)), None) => DepositLifecycleStatus::Pending,
+        (Some(DepositConfirmationStatus::Spent(_)), None) => DepositLifecycleStatus::Swept,
+        (Some(DepositConfirmationStatus::Reclaimed(_)), None) => DepositLifecycleStatus::Reclaimed,
+    };
+
+    Ok(DepositLifecycle {
+        txid: request.txid.to_string(),
+        output_index: request.output_index,
+        recipient: request.recipient.to_string(),
+        amount: request.amount,
+        max_fee: request.max_fee,
+        lock_time: request.lock_time,
+        signers_public_key: request.signers_public_key.to_string(),
+        votes,
+        sweep_txid: sweep_txid.map(|txid| txid.to_string()),
+        mint_txid: mint.map(|event| event.txid.to_string()),
+        status,
+    })
+}
+
+/// Handler for `GET /deposits/{txid}/{vout}`. Returns the lifecycle of
+/// the deposit request identified by the given outpoint, or `404 Not
+/// Found` if we do not have a record of it.
+pub async fn deposit_by_outpoint_handler<C: Context>(
+    state: State<ApiState<C>>,
+    Path((txid, output_index)): Path<(String, u32)>,
+) -> Result<Json<DepositLifecycle>, StatusCode> {
+    let txid = bitcoin::Txid::from_str(&txid).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let txid = BitcoinTxId::from(txid);
+
+    let request = state
+        .ctx
+        .get_storage()
+        .get_deposit_request(&txid, output_index)
+        .await
+        .map_err(|error| {
+            tracing::error!(%error, "could not fetch deposit request");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    deposit_lifecycle(&state.ctx, request)
+        .await
+        .map(Json)
+        .map_err(|error| {
+            tracing::error!(%error, "could not fetch the deposit lifecycle");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
+/// Handler for `GET /deposits/recipient/{principal}`. Returns the
+/// lifecycle of all deposit requests minting sBTC to the given stacks
+/// principal.
+pub async fn deposits_by_recipient_handler<C: Context>(
+    state: State<ApiState<C>>,
+    Path(principal): Path<String>,
+) -> Result<Json<Vec<DepositLifecycle>>, StatusCode> {
+    let recipient = StacksPrincipal::from_str(&principal).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let requests = state
+        .ctx
+        .get_storage()
+        .get_deposit_requests_by_recipient(&recipient)
+        .await
+        .map_err(|error| {
+            tracing::error!(%error, "could not fetch deposit requests by recipient");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let mut lifecycles = Vec::with_capacity(requests.len());
+    for request in requests {
+        let lifecycle = deposit_lifecycle(&state.ctx, request)
+            .await
+            .map_err(|error| {
+                tracing::error!(%error, "could not fetch the deposit lifecycle");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+        lifecycles.push(lifecycle);
+    }
+
+    Ok(Json(lifecycles))
+}
+
+/// Handler for `GET /deposits/fee-estimate/{principal}/{amount}/{max_fee}`.
+/// Estimates the fee that a deposit of the given amount to the given
+/// stacks principal would be charged if it were swept with the requests
+/// that are pending now, and whether the given max fee covers it.
+///
+/// Returns `503 Service Unavailable` if the signer does not know enough
+/// about the current state of the signers to make an estimate.
+pub async fn deposit_fee_estimate_handler<C: Context>(
+    state: State<ApiState<C>>,
+    Path((principal, amount, max_fee)): Path<(String, u64, u64)>,
+) -> Result<Json<RequestFeeEstimate>, StatusCode> {
+    let recipient = StacksPrincipal::from_str(&principal).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let requests = pending_requests(&state.ctx)
+        .await
+        .map_err(pending_requests_status)?;
+
+    // The prospective deposit gets the votes of all signers, and it spends
+    // an outpoint that no real deposit can have.
+    let signers_public_key = requests.signer_state.public_key;
+    let deposit_inputs = sbtc::deposits::DepositScriptInputs {
+        signers_public_key,
+        recipient: recipient.into(),
+        max_fee,
+    };
+    let deposit = utxo::DepositRequest {
+        outpoint: bitcoin::OutPoint::null(),
+        max_fee,
+        signer_bitmap: Default::default(),
+        amount,
+        deposit_script: deposit_inputs.deposit_script(),
+        reclaim_script: bitcoin::ScriptBuf::new(),
+        reclaim_script_hash: None,
+        signers_public_key,
+    };
+
+    requests
+        .simulate_deposit(deposit)
+        .map(Json)
+        .map_err(|error| {
+            tracing::error!(%error, "could not simulate the deposit");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::Method;
+    use axum::http::Request;
+    use fake::Fake as _;
+    use tower::ServiceExt as _;
+
+    use crate::api::router::get_router;
+    use crate::storage::DbWrite as _;
+    use crate::storage::model::CompletedDepositEvent;
+    use crate::storage::model::DepositSigner;
+    use crate::testing::context::TestContext;
+
+    use super::*;
+
+    async fn get(app: Router, uri: &str) -> (StatusCode, serde_json::Value) {
+        let request = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        if status != StatusCode::OK {
+            return (status, serde_json::Value::Null);
+        }
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn deposit_lifecycle_includes_votes_and_mint() {
+        let ctx = TestContext::default_mocked();
+        let storage = ctx.get_storage_mut();
+
+        let mut requests: [DepositRequest; 2] = fake::Faker.fake();
+        requests[1].recipient = requests[0].recipient.clone();
+        for request in requests.iter() {
+            storage.write_deposit_request(request).await.unwrap();
+        }
+
+        let mut vote: DepositSigner = fake::Faker.fake();
+        vote.txid = requests[0].txid;
+        vote.output_index = requests[0].output_index;
+        storage.write_deposit_signer_decision(&vote).await.unwrap();
+
+        let event = CompletedDepositEvent {
+            txid: fake::Faker.fake(),
+            block_id: fake::Faker.fake(),
+            amount: requests[0].amount,
+            outpoint: bitcoin::OutPoint::new(requests[0].txid.into(), requests[0].output_index),
+            sweep_block_hash: fake::Faker.fake(),
+            sweep_block_height: 7u64.into(),
+            sweep_txid: fake::Faker.fake(),
+        };
+        storage.write_completed_deposit_event(&event).await.unwrap();
+
+        let state = ApiState { ctx: ctx.clone() };
+        let app: Router = get_router().with_state(state);
+
+        let uri = format!(
+            "/deposits/{}/{}",
+            requests[0].txid, requests[0].output_index
+        );
+        let (status, body) = get(app.clone(), &uri).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "completed");
+        assert_eq!(body["mint_txid"], event.txid.to_string());
+        assert_eq!(body["sweep_txid"], event.sweep_txid.to_string());
+        assert_eq!(body["votes"].as_array().unwrap().len(), 1);
+        assert_eq!(
+            body["votes"][0]["signer_public_key"],
+            vote.signer_pub_key.to_string()
+        );
+
+        // Without a chain tip we cannot tell whether the other deposit
+        // has been swept.
+        let uri = format!("/deposits/recipient/{}", requests[0].recipient);
+        let (status, body) = get(app.clone(), &uri).await;
+        assert_eq!(status, StatusCode::OK);
+        let lifecycles = body.as_array().unwrap();
+        assert_eq!(lifecycles.len(), 2);
+        let other = lifecycles
+            .iter()
+            .find(|lifecycle| lifecycle["txid"] == requests[1].txid.to_string())
+            .unwrap();
+        assert_eq!(other["status"], "unknown");
+        assert!(other["mint_txid"].is_null());
+
+        let uri = format!("/deposits/{}/{}", requests[0].txid, 1000);
+        let (status, _) = get(app, &uri).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,203 @@
+//! Handler for the `GET /events` endpoint, which streams the public events
+//! of this signer as server-sent events.
+//!
+//! Monitoring dashboards and the Emily sidecar can subscribe to this
+//! stream instead of polling the database. Each event is sent as a JSON
+//! object with a `type` field, and the name of the server-sent event is
+//! the same as that type. Events that happen while a client is lagging
+//! behind are dropped for that client.
+
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::extract::State;
+use axum::response::sse::Event;
+use axum::response::sse::KeepAlive;
+use axum::response::sse::Sse;
+use futures::Stream;
+use serde::Serialize;
+use tokio_stream::StreamExt as _;
+use tokio_stream::wrappers::BroadcastStream;
+
+use crate::broadcaster::BroadcasterEvent;
+use crate::context::Context;
+use crate::context::SignerCommand;
+use crate::context::SignerEvent;
+use crate::context::SignerSignal;
+use crate::context::TxCoordinatorEvent;
+use crate::storage::model::BitcoinBlockHash;
+use crate::storage::model::BitcoinBlockHeight;
+
+use super::ApiState;
+
+/// How often a comment is sent on an idle stream, so that proxies do not
+/// close the connection.
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// The events of this signer that are published on the `/events`
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ApiEvent {
+    /// The signer processed a new bitcoin block.
+    BitcoinBlockProcessed {
+        /// The hash of the new bitcoin chain tip.
+        block_hash: BitcoinBlockHash,
+        /// The height of the new bitcoin chain tip.
+        block_height: BitcoinBlockHeight,
+    },
+    /// This signer, as coordinator, broadcast a sweep transaction.
+    SweepBroadcast {
+        /// The ID of the sweep transaction.
+        txid: String,
+    },
+    /// A sweep transaction that this signer broadcast as coordinator was
+    /// confirmed in a bitcoin block.
+    SweepConfirmed {
+        /// The ID of the sweep transaction.
+        txid: String,
+        /// The bitcoin block that confirmed the sweep transaction.
+        block_hash: BitcoinBlockHash,
+    },
+    /// This signer, as coordinator, submitted a stacks transaction that
+    /// the stacks node accepted.
+    StacksTransactionSubmitted {
+        /// The ID of the stacks transaction.
+        txid: String,
+        /// The kind of contract call in the transaction, like
+        /// `complete-deposit`.
+        kind: &'static str,
+    },
+    /// This signer, as coordinator, ran DKG successfully.
+    DkgCompleted {
+        /// The new aggregate key.
+        aggregate_key: String,
+    },
+    /// This signer, as coordinator, failed to run DKG.
+    DkgFailed {
+        /// A description of the error.
+        error: String,
+    },
+}
+
+impl ApiEvent {
+    /// Map the given signal to a public event, if it is one.
+    pub fn from_signal<C: Context>(ctx: &C, signal: &SignerSignal) -> Option<Self> {
+        let SignerSignal::Event(event) = signal else {
+            return None;
+        };
+
+        match event {
+            SignerEvent::BitcoinBlockObserved => {
+                let chain_tip = ctx.state().bitcoin_chain_tip()?;
+                Some(ApiEvent::BitcoinBlockProcessed {
+                    block_hash: chain_tip.block_hash,
+                    block_height: chain_tip.block_height,
+                })
+            }
+            SignerEvent::TxCoordinator(TxCoordinatorEvent::SweepBroadcast(txid)) => {
+                Some(ApiEvent::SweepBroadcast { txid: txid.to_string() })
+            }
+            SignerEvent::Broadcaster(BroadcasterEvent::SweepConfirmed { txid, block_hash }) => {
+                Some(ApiEvent::SweepConfirmed {
+                    txid: txid.to_string(),
+                    block_hash: *block_hash,
+                })
+            }
+            SignerEvent::TxCoordinator(TxCoordinatorEvent::StacksSubmissionAccepted {
+                txid,
+                kind,
+            }) => Some(ApiEvent::StacksTransactionSubmitted {
+                txid: txid.to_string(),
+                kind: *kind,
+            }),
+            SignerEvent::TxCoordinator(TxCoordinatorEvent::DkgCompleted(aggregate_key)) => {
+                Some(ApiEvent::DkgCompleted {
+                    aggregate_key: aggregate_key.to_string(),
+                })
+            }
+            SignerEvent::TxCoordinator(TxCoordinatorEvent::DkgFailed(error)) => {
+                Some(ApiEvent::DkgFailed { error: error.clone() })
+            }
+            _ => None,
+        }
+    }
+
+    /// The name of the event, which is the same as its `type` field.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ApiEvent::BitcoinBlockProcessed { .. } => "bitcoin_block_processed",
+            ApiEvent::SweepBroadcast { .. } => "sweep_broadcast",
+            ApiEvent::SweepConfirmed { .. } => "sweep_confirmed",
+            ApiEvent::StacksTransactionSubmitted { .. } => "stacks_transaction_submitted",
+            ApiEvent::DkgCompleted { .. } => "dkg_completed",
+            ApiEvent::DkgFailed { .. } => "dkg_failed",
+        }
+    }
+}
+
+/// Handler for `GET /events`. Streams the public events of this signer
+/// until the client disconnects or the signer shuts down.
+pub async fn events_handler<C: Context + 'static>(
+    state: State<ApiState<C>>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let ctx = state.ctx.clone();
+    let stream = BroadcastStream::new(ctx.get_signal_receiver())
+        .take_while(|signal| !matches!(signal, Ok(SignerSignal::Command(SignerCommand::Shutdown))))
+        .filter_map(move |signal| {
+            let event = ApiEvent::from_signal(&ctx, &signal.ok()?)?;
+            let sse_event = Event::default()
+                .event(event.name())
+                .json_data(&event)
+                .inspect_err(|error| tracing::warn!(%error, "could not serialize API event"))
+                .ok()?;
+            Some(Ok(sse_event))
+        });
+
+    Sse::new(stream).keep_alive(KeepAlive::new().interval(KEEP_ALIVE_INTERVAL))
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+    use fake::Faker;
+
+    use super::*;
+    use crate::storage::model::BitcoinBlockRef;
+    use crate::testing::context::*;
+
+    #[test]
+    fn block_observed_event_includes_the_chain_tip() {
+        let ctx = TestContext::default_mocked();
+        let signal = SignerSignal::Event(SignerEvent::BitcoinBlockObserved);
+
+        // Without a chain tip there is nothing to publish.
+        assert_eq!(ApiEvent::from_signal(&ctx, &signal), None);
+
+        let chain_tip: BitcoinBlockRef = Faker.fake();
+        ctx.state().set_bitcoin_chain_tip(chain_tip);
+
+        let event = ApiEvent::from_signal(&ctx, &signal).unwrap();
+        assert_eq!(
+            event,
+            ApiEvent::BitcoinBlockProcessed {
+                block_hash: chain_tip.block_hash,
+                block_height: chain_tip.block_height,
+            }
+        );
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], event.name());
+    }
+
+    #[test]
+    fn internal_signals_are_not_published() {
+        let ctx = TestContext::default_mocked();
+        let signal = SignerSignal::from(TxCoordinatorEvent::TenureCompleted);
+        assert_eq!(ApiEvent::from_signal(&ctx, &signal), None);
+
+        let signal = SignerSignal::from(TxCoordinatorEvent::DkgFailed("timeout".into()));
+        let event = ApiEvent::from_signal(&ctx, &signal).unwrap();
+        assert_eq!(event.name(), "dkg_failed");
+    }
+}
@@ -0,0 +1,1191 @@
+//! This module contains the gRPC service for programmatically querying
+//! and controlling a running signer.
+//!
+//! The service definition lives in
+//! `protobufs/stacks/signer/v1/control.proto`, so downstream tooling can
+//! generate typed clients from the same definitions.
+
+use emily_client::models::DepositStatus;
+use emily_client::models::DepositUpdate;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+
+use crate::bitcoin::descriptor;
+use crate::context::Context;
+use crate::context::SignerCommand;
+use crate::ecdsa::SignEcdsa as _;
+use crate::emergency_recovery;
+use crate::emily_client::EmilyApiKey;
+use crate::emily_client::EmilyApiKeys;
+use crate::emily_client::EmilyInteract as _;
+use crate::error::Error;
+use crate::keys::PublicKey;
+use crate::limit_override;
+use crate::message::EmergencyRecoveryApproval;
+use crate::message::EmergencyRecoveryRevocation;
+use crate::message::LimitOverrideVote;
+use crate::message::Payload;
+use crate::proto;
+use crate::proto::DecisionOverride;
+use crate::proto::set_decision_override_request::Request as OverrideTarget;
+use crate::proto::signer_control_server::SignerControl;
+use crate::proto::signer_control_server::SignerControlServer;
+use crate::storage::DbRead;
+use crate::storage::DbWrite as _;
+use crate::storage::model::DkgSharesStatus;
+use crate::storage::model::QualifiedRequestId;
+use crate::storage::model::SignerPayment;
+use crate::storage::model::SignerPaymentStatus;
+
+/// The implementation of the `SignerControl` gRPC service.
+#[derive(Debug, Clone)]
+pub struct SignerControlService<C> {
+    /// The signer context.
+    pub ctx: C,
+}
+
+impl<C: Context + 'static> SignerControlService<C> {
+    /// Create a new service from the given context.
+    pub fn new(ctx: C) -> Self {
+        Self { ctx }
+    }
+
+    /// Wrap this service in the generated tonic server type.
+    pub fn into_server(self) -> SignerControlServer<Self> {
+        SignerControlServer::new(self)
+    }
+
+    fn emily_api_keys(&self) -> Result<EmilyApiKeys, Status> {
+        self.ctx
+            .get_emily_client()
+            .api_keys()
+            .ok_or_else(|| Status::unimplemented("the Emily client does not support API keys"))
+    }
+
+    async fn pending_requests(&self) -> Result<proto::GetPendingRequestsResponse, Error> {
+        let mut response = proto::GetPendingRequestsResponse::default();
+
+        let Some(chain_tip) = self.ctx.state().bitcoin_chain_tip() else {
+            return Ok(response);
+        };
+
+        let config = &self.ctx.config().signer;
+        let storage = self.ctx.get_storage();
+        let threshold = config.bootstrap_signatures_required;
+
+        response.deposits = storage
+            .get_pending_accepted_deposit_requests(&chain_tip, config.context_window, threshold)
+            .await?
+            .iter()
+            .map(|req| req.outpoint().into())
+            .collect();
+
+        let Some(stacks_chain_tip) = storage.get_stacks_chain_tip(&chain_tip.block_hash).await?
+        else {
+            return Ok(response);
+        };
+
+        let min_bitcoin_height = chain_tip
+            .block_height
+            .saturating_sub(crate::WITHDRAWAL_BLOCKS_EXPIRY);
+
+        response.withdrawals = storage
+            .get_pending_accepted_withdrawal_requests(
+                &chain_tip.block_hash,
+                &stacks_chain_tip.block_hash,
+                min_bitcoin_height,
+                threshold,
+            )
+            .await?
+            .iter()
+            .map(|req| req.qualified_id().into())
+            .collect();
+
+        Ok(response)
+    }
+
+    async fn wallet_descriptors(&self) -> Result<proto::GetWalletDescriptorsResponse, Error> {
+        // Failed DKG shares never made it into the registry contract, so
+        // no funds were ever locked by their aggregate keys.
+        let descriptors = self
+            .ctx
+            .get_storage()
+            .get_aggregate_key_records()
+            .await?
+            .into_iter()
+            .filter(|record| record.dkg_shares_status != DkgSharesStatus::Failed)
+            .map(|record| proto::WalletDescriptor {
+                descriptor: descriptor::signers_descriptor(&record.aggregate_key),
+                aggregate_key: Some(record.aggregate_key.into()),
+                started_at_bitcoin_block_height: *record.dkg_block_height,
+                active: record.is_active(),
+            })
+            .collect();
+
+        Ok(proto::GetWalletDescriptorsResponse { descriptors })
+    }
+
+    async fn reactivate_deposit_request(&self, outpoint: bitcoin::OutPoint) -> Result<bool, Error> {
+        let txid = outpoint.txid.into();
+        let was_expired = self
+            .ctx
+            .get_storage_mut()
+            .reactivate_deposit_request(&txid, outpoint.vout)
+            .await?;
+
+        if !was_expired {
+            return Ok(false);
+        }
+
+        // The deposit was reported as failed when it expired, so we let
+        // Emily know that it is pending again.
+        let update = DepositUpdate {
+            bitcoin_tx_output_index: outpoint.vout,
+            bitcoin_txid: outpoint.txid.to_string(),
+            status: DepositStatus::Pending,
+            fulfillment: None,
+            status_message: "re-activated by the signer operator".to_string(),
+            replaced_by_tx: None,
+        };
+        let _ = self
+            .ctx
+            .get_emily_client()
+            .update_deposits(vec![update])
+            .await
+            .inspect_err(|error| {
+                tracing::warn!(%error, "could not report the re-activated deposit to Emily")
+            });
+
+        Ok(true)
+    }
+
+    async fn update_signer_payment_status(
+        &self,
+        outpoint: bitcoin::OutPoint,
+        status: SignerPaymentStatus,
+    ) -> Result<(), Status> {
+        let txid = outpoint.txid.into();
+        let storage = self.ctx.get_storage_mut();
+        let payment = storage
+            .get_signer_payment(&txid, outpoint.vout)
+            .await
+            .map_err(|error| Status::internal(error.to_string()))?
+            .ok_or_else(|| Status::not_found("the payment to the signers is not recorded"))?;
+
+        if status == SignerPaymentStatus::RefundApproved && payment.sender_script_pubkey.is_none() {
+            return Err(Status::failed_precondition(
+                "the sender of the payment is unknown, so it cannot be refunded",
+            ));
+        }
+
+        storage
+            .set_signer_payment_status(&txid, outpoint.vout, status)
+            .await
+            .map_err(|error| Status::internal(error.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Pause or resume the coordinator duties of this signer, storing the
+    /// setting so that it survives a restart. Returns whether the duties
+    /// were paused before the call.
+    async fn set_coordinator_paused(&self, paused: bool) -> Result<bool, Status> {
+        self.ctx
+            .get_storage_mut()
+            .set_coordinator_paused(paused)
+            .await
+            .map_err(|error| Status::internal(error.to_string()))?;
+
+        Ok(self.ctx.state().set_coordinator_paused(paused))
+    }
+}
+
+/// Convert an error from recording an approval or revocation of the
+/// emergency recovery into a gRPC status.
+fn emergency_recovery_status(error: Error) -> Status {
+    match error {
+        Error::EmergencyRecoveryScriptMismatch(_) => Status::invalid_argument(error.to_string()),
+        Error::EmergencyRecoveryNotConfigured
+        | Error::EmergencyRecoveryApproverNotInSignerSet(_) => {
+            Status::failed_precondition(error.to_string())
+        }
+        error => Status::internal(error.to_string()),
+    }
+}
+
+/// Convert the review status of a payment to the signers from its
+/// protobuf representation.
+fn signer_payment_status_from_proto(status: i32) -> Result<SignerPaymentStatus, Status> {
+    match proto::SignerPaymentStatus::try_from(status) {
+        Ok(proto::SignerPaymentStatus::Unreviewed) => Ok(SignerPaymentStatus::Unreviewed),
+        Ok(proto::SignerPaymentStatus::Donation) => Ok(SignerPaymentStatus::Donation),
+        Ok(proto::SignerPaymentStatus::RefundApproved) => Ok(SignerPaymentStatus::RefundApproved),
+        Ok(proto::SignerPaymentStatus::Unspecified) | Err(_) => {
+            Err(Status::invalid_argument("unknown signer payment status"))
+        }
+    }
+}
+
+impl From<&EmilyApiKey> for proto::EmilyApiKey {
+    fn from(value: &EmilyApiKey) -> Self {
+        proto::EmilyApiKey {
+            name: value.name.clone(),
+            priority: value.priority.into(),
+            masked_key: value.masked_key(),
+        }
+    }
+}
+
+impl From<SignerPaymentStatus> for proto::SignerPaymentStatus {
+    fn from(value: SignerPaymentStatus) -> Self {
+        match value {
+            SignerPaymentStatus::Unreviewed => proto::SignerPaymentStatus::Unreviewed,
+            SignerPaymentStatus::Donation => proto::SignerPaymentStatus::Donation,
+            SignerPaymentStatus::RefundApproved => proto::SignerPaymentStatus::RefundApproved,
+        }
+    }
+}
+
+impl From<SignerPayment> for proto::SignerPayment {
+    fn from(value: SignerPayment) -> Self {
+        proto::SignerPayment {
+            outpoint: Some(value.outpoint().into()),
+            amount: value.amount,
+            sender_script_pubkey: value
+                .sender_script_pubkey
+                .map(|script_pubkey| script_pubkey.to_bytes()),
+            status: proto::SignerPaymentStatus::from(value.status).into(),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl<C: Context + 'static> SignerControl for SignerControlService<C> {
+    async fn get_status(
+        &self,
+        _: Request<proto::GetStatusRequest>,
+    ) -> Result<Response<proto::GetStatusResponse>, Status> {
+        let state = self.ctx.state();
+        let chain_tip = state.bitcoin_chain_tip();
+        let num_signers = state.current_signer_set().num_signers();
+
+        let latest_tenure_plan = self
+            .ctx
+            .get_storage()
+            .get_latest_tenure_plan()
+            .await
+            .map_err(|error| {
+                tracing::error!(%error, "could not fetch the latest tenure plan");
+                Status::internal(error.to_string())
+            })?;
+
+        let latest_dkg_begin_refusal = self
+            .ctx
+            .get_storage()
+            .get_latest_dkg_begin_refusal()
+            .await
+            .map_err(|error| {
+                tracing::error!(%error, "could not fetch the latest DKG begin refusal");
+                Status::internal(error.to_string())
+            })?;
+
+        let response = proto::GetStatusResponse {
+            signer_public_key: Some(self.ctx.config().signer.public_key().into()),
+            bitcoin_chain_tip: chain_tip.as_ref().map(|tip| tip.block_hash.into()),
+            bitcoin_chain_tip_height: chain_tip.map(|tip| *tip.block_height).unwrap_or(0),
+            sbtc_contracts_deployed: state.sbtc_contracts_deployed(),
+            num_signers: u32::try_from(num_signers).unwrap_or(u32::MAX),
+            paused: state.is_coordinator_paused(),
+            git_revision: crate::GIT_COMMIT.to_string(),
+            latest_tenure_plan: latest_tenure_plan.map(Into::into),
+            deposits_paused: state.is_deposits_paused(),
+            withdrawals_paused: state.is_withdrawals_paused(),
+            latest_dkg_begin_refusal: latest_dkg_begin_refusal.map(Into::into),
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn get_pending_requests(
+        &self,
+        _: Request<proto::GetPendingRequestsRequest>,
+    ) -> Result<Response<proto::GetPendingRequestsResponse>, Status> {
+        self.pending_requests()
+            .await
+            .map(Response::new)
+            .map_err(|error| {
+                tracing::error!(%error, "could not fetch pending requests");
+                Status::internal(error.to_string())
+            })
+    }
+
+    async fn set_decision_override(
+        &self,
+        request: Request<proto::SetDecisionOverrideRequest>,
+    ) -> Result<Response<proto::SetDecisionOverrideResponse>, Status> {
+        let request = request.into_inner();
+
+        let decision = match DecisionOverride::try_from(request.decision) {
+            Ok(DecisionOverride::Unspecified) => None,
+            Ok(DecisionOverride::Accept) => Some(true),
+            Ok(DecisionOverride::Reject) => Some(false),
+            Err(_) => return Err(Status::invalid_argument("unknown decision override")),
+        };
+
+        // The override is stored before it takes effect, so that it
+        // survives a restart of the signer.
+        let state = self.ctx.state();
+        let storage = self.ctx.get_storage_mut();
+        match request.request {
+            Some(OverrideTarget::Deposit(outpoint)) => {
+                let outpoint = bitcoin::OutPoint::try_from(outpoint)
+                    .map_err(|error| Status::invalid_argument(error.to_string()))?;
+                tracing::info!(%outpoint, ?decision, "setting deposit decision override");
+                storage
+                    .set_deposit_decision_override(&outpoint, decision)
+                    .await
+                    .map_err(|error| Status::internal(error.to_string()))?;
+                state.set_deposit_decision_override(outpoint, decision);
+            }
+            Some(OverrideTarget::Withdrawal(id)) => {
+                let id = QualifiedRequestId::try_from(id)
+                    .map_err(|error| Status::invalid_argument(error.to_string()))?;
+                tracing::info!(%id, ?decision, "setting withdrawal decision override");
+                storage
+                    .set_withdrawal_decision_override(&id, decision)
+                    .await
+                    .map_err(|error| Status::internal(error.to_string()))?;
+                state.set_withdrawal_decision_override(id, decision);
+            }
+            None => return Err(Status::invalid_argument("missing request identifier")),
+        }
+
+        Ok(Response::new(proto::SetDecisionOverrideResponse {}))
+    }
+
+    async fn pause(
+        &self,
+        _: Request<proto::PauseRequest>,
+    ) -> Result<Response<proto::PauseResponse>, Status> {
+        tracing::warn!("pausing coordinator duties");
+        let was_paused = self.set_coordinator_paused(true).await?;
+        Ok(Response::new(proto::PauseResponse { was_paused }))
+    }
+
+    async fn resume(
+        &self,
+        _: Request<proto::ResumeRequest>,
+    ) -> Result<Response<proto::ResumeResponse>, Status> {
+        tracing::info!("resuming coordinator duties");
+        let was_paused = self.set_coordinator_paused(false).await?;
+        Ok(Response::new(proto::ResumeResponse { was_paused }))
+    }
+
+    async fn get_wallet_descriptors(
+        &self,
+        _: Request<proto::GetWalletDescriptorsRequest>,
+    ) -> Result<Response<proto::GetWalletDescriptorsResponse>, Status> {
+        self.wallet_descriptors()
+            .await
+            .map(Response::new)
+            .map_err(|error| {
+                tracing::error!(%error, "could not fetch wallet descriptors");
+                Status::internal(error.to_string())
+            })
+    }
+
+    async fn reactivate_deposit(
+        &self,
+        request: Request<proto::ReactivateDepositRequest>,
+    ) -> Result<Response<proto::ReactivateDepositResponse>, Status> {
+        let outpoint = request
+            .into_inner()
+            .deposit
+            .ok_or_else(|| Status::invalid_argument("missing deposit outpoint"))?;
+        let outpoint = bitcoin::OutPoint::try_from(outpoint)
+            .map_err(|error| Status::invalid_argument(error.to_string()))?;
+
+        tracing::info!(%outpoint, "re-activating expired deposit request");
+        self.reactivate_deposit_request(outpoint)
+            .await
+            .map(|was_expired| Response::new(proto::ReactivateDepositResponse { was_expired }))
+            .map_err(|error| {
+                tracing::error!(%error, "could not re-activate deposit request");
+                Status::internal(error.to_string())
+            })
+    }
+
+    async fn set_deposits_paused(
+        &self,
+        request: Request<proto::SetDepositsPausedRequest>,
+    ) -> Result<Response<proto::SetDepositsPausedResponse>, Status> {
+        let paused = request.into_inner().paused;
+        if paused {
+            tracing::warn!("pausing deposits");
+        } else {
+            tracing::info!("resuming deposits");
+        }
+        let was_paused = self.ctx.state().set_deposits_paused(paused);
+        Ok(Response::new(proto::SetDepositsPausedResponse {
+            was_paused,
+        }))
+    }
+
+    async fn set_withdrawals_paused(
+        &self,
+        request: Request<proto::SetWithdrawalsPausedRequest>,
+    ) -> Result<Response<proto::SetWithdrawalsPausedResponse>, Status> {
+        let paused = request.into_inner().paused;
+        if paused {
+            tracing::warn!("pausing withdrawals");
+        } else {
+            tracing::info!("resuming withdrawals");
+        }
+        let was_paused = self.ctx.state().set_withdrawals_paused(paused);
+        Ok(Response::new(proto::SetWithdrawalsPausedResponse {
+            was_paused,
+        }))
+    }
+
+    async fn list_signer_payments(
+        &self,
+        request: Request<proto::ListSignerPaymentsRequest>,
+    ) -> Result<Response<proto::ListSignerPaymentsResponse>, Status> {
+        let status = signer_payment_status_from_proto(request.into_inner().status)?;
+        self.ctx
+            .get_storage()
+            .get_signer_payments(status)
+            .await
+            .map(|payments| {
+                let payments = payments.into_iter().map(Into::into).collect();
+                Response::new(proto::ListSignerPaymentsResponse { payments })
+            })
+            .map_err(|error| {
+                tracing::error!(%error, "could not fetch payments to the signers");
+                Status::internal(error.to_string())
+            })
+    }
+
+    async fn set_signer_payment_status(
+        &self,
+        request: Request<proto::SetSignerPaymentStatusRequest>,
+    ) -> Result<Response<proto::SetSignerPaymentStatusResponse>, Status> {
+        let request = request.into_inner();
+        let status = signer_payment_status_from_proto(request.status)?;
+        let outpoint = request
+            .outpoint
+            .ok_or_else(|| Status::invalid_argument("missing payment outpoint"))?;
+        let outpoint = bitcoin::OutPoint::try_from(outpoint)
+            .map_err(|error| Status::invalid_argument(error.to_string()))?;
+
+        tracing::info!(%outpoint, %status, "setting the status of a payment to the signers");
+        self.update_signer_payment_status(outpoint, status).await?;
+
+        Ok(Response::new(proto::SetSignerPaymentStatusResponse {}))
+    }
+
+    async fn propose_limit_override(
+        &self,
+        request: Request<proto::ProposeLimitOverrideRequest>,
+    ) -> Result<Response<proto::ProposeLimitOverrideResponse>, Status> {
+        let request = request.into_inner();
+        let vote = LimitOverrideVote {
+            per_deposit_cap: request.per_deposit_cap,
+            per_withdrawal_cap: request.per_withdrawal_cap,
+            expires_at_height: request.expires_at_height.into(),
+        };
+
+        let chain_tip = self.ctx.state().bitcoin_chain_tip().ok_or_else(|| {
+            Status::unavailable("the signer has not observed a bitcoin block yet")
+        })?;
+        let private_key = self.ctx.config().signer.private_key;
+        let public_key = PublicKey::from_private_key(&private_key);
+
+        tracing::info!(?vote, "voting for a limit override");
+        let tally =
+            limit_override::record_vote(&self.ctx, vote, public_key).map_err(
+                |error| match error {
+                    Error::InvalidLimitOverride(_) => Status::invalid_argument(error.to_string()),
+                    error => Status::failed_precondition(error.to_string()),
+                },
+            )?;
+
+        let msg = Payload::from(vote)
+            .to_message(chain_tip.block_hash)
+            .sign_ecdsa(&private_key);
+        self.ctx
+            .signal(SignerCommand::P2PPublish(Box::new(msg)).into())
+            .map_err(|error| Status::internal(error.to_string()))?;
+
+        Ok(Response::new(proto::ProposeLimitOverrideResponse {
+            votes: u32::try_from(tally.votes).unwrap_or(u32::MAX),
+            threshold: tally.threshold.into(),
+            active: tally.active,
+        }))
+    }
+
+    async fn approve_emergency_recovery(
+        &self,
+        request: Request<proto::ApproveEmergencyRecoveryRequest>,
+    ) -> Result<Response<proto::ApproveEmergencyRecoveryResponse>, Status> {
+        let request = request.into_inner();
+        let approval = EmergencyRecoveryApproval {
+            recovery_script_pubkey: bitcoin::ScriptBuf::from_bytes(request.recovery_script_pubkey),
+        };
+
+        let chain_tip = self.ctx.state().bitcoin_chain_tip().ok_or_else(|| {
+            Status::unavailable("the signer has not observed a bitcoin block yet")
+        })?;
+        let private_key = self.ctx.config().signer.private_key;
+        let public_key = PublicKey::from_private_key(&private_key);
+
+        tracing::warn!(
+            recovery_script_pubkey = %approval.recovery_script_pubkey,
+            "approving the emergency recovery of the signers' funds"
+        );
+        let sent_at = chain_tip.block_height;
+        let tally = emergency_recovery::record_approval(
+            &self.ctx, &approval, public_key, sent_at, chain_tip,
+        )
+        .await
+        .map_err(emergency_recovery_status)?;
+
+        let msg = Payload::from(approval)
+            .to_message(chain_tip.block_hash)
+            .sign_ecdsa(&private_key);
+        self.ctx
+            .signal(SignerCommand::P2PPublish(Box::new(msg)).into())
+            .map_err(|error| Status::internal(error.to_string()))?;
+
+        Ok(Response::new(proto::ApproveEmergencyRecoveryResponse {
+            approvals: u32::try_from(tally.approvals).unwrap_or(u32::MAX),
+            threshold: tally.threshold.into(),
+            sweep_height: tally.sweep_height.map(|height| *height),
+        }))
+    }
+
+    async fn revoke_emergency_recovery(
+        &self,
+        request: Request<proto::RevokeEmergencyRecoveryRequest>,
+    ) -> Result<Response<proto::RevokeEmergencyRecoveryResponse>, Status> {
+        let request = request.into_inner();
+        let revocation = EmergencyRecoveryRevocation {
+            recovery_script_pubkey: bitcoin::ScriptBuf::from_bytes(request.recovery_script_pubkey),
+        };
+
+        let chain_tip = self.ctx.state().bitcoin_chain_tip().ok_or_else(|| {
+            Status::unavailable("the signer has not observed a bitcoin block yet")
+        })?;
+        let private_key = self.ctx.config().signer.private_key;
+        let public_key = PublicKey::from_private_key(&private_key);
+
+        tracing::warn!(
+            recovery_script_pubkey = %revocation.recovery_script_pubkey,
+            "revoking the approval of the emergency recovery of the signers' funds"
+        );
+        let sent_at = chain_tip.block_height;
+        let tally = emergency_recovery::record_revocation(
+            &self.ctx,
+            &revocation,
+            public_key,
+            sent_at,
+            chain_tip,
+        )
+        .await
+        .map_err(emergency_recovery_status)?;
+
+        let msg = Payload::from(revocation)
+            .to_message(chain_tip.block_hash)
+            .sign_ecdsa(&private_key);
+        self.ctx
+            .signal(SignerCommand::P2PPublish(Box::new(msg)).into())
+            .map_err(|error| Status::internal(error.to_string()))?;
+
+        Ok(Response::new(proto::RevokeEmergencyRecoveryResponse {
+            approvals: u32::try_from(tally.approvals).unwrap_or(u32::MAX),
+            threshold: tally.threshold.into(),
+            sweep_height: tally.sweep_height.map(|height| *height),
+        }))
+    }
+
+    async fn list_emily_api_keys(
+        &self,
+        _: Request<proto::ListEmilyApiKeysRequest>,
+    ) -> Result<Response<proto::ListEmilyApiKeysResponse>, Status> {
+        let keys = self
+            .emily_api_keys()?
+            .list()
+            .iter()
+            .map(Into::into)
+            .collect();
+        Ok(Response::new(proto::ListEmilyApiKeysResponse { keys }))
+    }
+
+    async fn set_emily_api_key(
+        &self,
+        request: Request<proto::SetEmilyApiKeyRequest>,
+    ) -> Result<Response<proto::SetEmilyApiKeyResponse>, Status> {
+        let request = request.into_inner();
+        if request.name.is_empty() {
+            return Err(Status::invalid_argument("missing API key name"));
+        }
+        if request.key.is_empty() {
+            return Err(Status::invalid_argument("missing API key"));
+        }
+        let priority = u16::try_from(request.priority)
+            .map_err(|_| Status::invalid_argument("API key priority is too large"))?;
+        let api_key = EmilyApiKey {
+            name: request.name,
+            key: request.key,
+            priority,
+        };
+
+        let api_keys = self.emily_api_keys()?;
+        tracing::info!(?api_key, "setting Emily API key");
+        let replaced = api_keys.set(api_key);
+        Ok(Response::new(proto::SetEmilyApiKeyResponse { replaced }))
+    }
+
+    async fn remove_emily_api_key(
+        &self,
+        request: Request<proto::RemoveEmilyApiKeyRequest>,
+    ) -> Result<Response<proto::RemoveEmilyApiKeyResponse>, Status> {
+        let name = request.into_inner().name;
+        let api_keys = self.emily_api_keys()?;
+        tracing::info!(%name, "removing Emily API key");
+        let removed = api_keys.remove(&name);
+        Ok(Response::new(proto::RemoveEmilyApiKeyResponse { removed }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+
+    use super::*;
+    use crate::context::SignerEvent;
+    use crate::context::SignerSignal;
+    use crate::emily_client::EmilyClient;
+    use crate::storage::DbWrite as _;
+    use crate::storage::model::BitcoinBlockRef;
+    use crate::storage::model::DepositExpiration;
+    use crate::storage::model::DepositRequest;
+    use crate::storage::model::DkgBeginRefusal;
+    use crate::storage::model::EncryptedDkgShares;
+    use crate::storage::model::ScriptPubKey;
+    use crate::storage::model::TenurePlan;
+    use crate::testing::context::*;
+
+    #[tokio::test]
+    async fn pause_and_resume_toggle_coordinator_duties() {
+        let ctx = TestContext::default_mocked();
+        let service = SignerControlService::new(ctx.clone());
+
+        let response = service.pause(Request::new(proto::PauseRequest {})).await;
+        assert!(!response.unwrap().into_inner().was_paused);
+        assert!(ctx.state().is_coordinator_paused());
+        assert!(ctx.get_storage().is_coordinator_paused().await.unwrap());
+
+        let response = service.pause(Request::new(proto::PauseRequest {})).await;
+        assert!(response.unwrap().into_inner().was_paused);
+
+        let response = service.resume(Request::new(proto::ResumeRequest {})).await;
+        assert!(response.unwrap().into_inner().was_paused);
+        assert!(!ctx.state().is_coordinator_paused());
+        assert!(!ctx.get_storage().is_coordinator_paused().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn set_deposits_paused_toggles_deposits() {
+        let ctx = TestContext::default_mocked();
+        let service = SignerControlService::new(ctx.clone());
+
+        let request = proto::SetDepositsPausedRequest { paused: true };
+        let response = service.set_deposits_paused(Request::new(request)).await;
+        assert!(!response.unwrap().into_inner().was_paused);
+        assert!(ctx.state().is_deposits_paused());
+
+        let status = service
+            .get_status(Request::new(proto::GetStatusRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(status.deposits_paused);
+        assert!(!status.paused);
+
+        let request = proto::SetDepositsPausedRequest { paused: false };
+        let response = service.set_deposits_paused(Request::new(request)).await;
+        assert!(response.unwrap().into_inner().was_paused);
+        assert!(!ctx.state().is_deposits_paused());
+    }
+
+    #[tokio::test]
+    async fn set_withdrawals_paused_toggles_withdrawals() {
+        let ctx = TestContext::default_mocked();
+        let service = SignerControlService::new(ctx.clone());
+
+        let request = proto::SetWithdrawalsPausedRequest { paused: true };
+        let response = service.set_withdrawals_paused(Request::new(request)).await;
+        assert!(!response.unwrap().into_inner().was_paused);
+        assert!(ctx.state().is_withdrawals_paused());
+
+        let status = service
+            .get_status(Request::new(proto::GetStatusRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(status.withdrawals_paused);
+        assert!(!status.deposits_paused);
+
+        let request = proto::SetWithdrawalsPausedRequest { paused: false };
+        let response = service.set_withdrawals_paused(Request::new(request)).await;
+        assert!(response.unwrap().into_inner().was_paused);
+        assert!(!ctx.state().is_withdrawals_paused());
+    }
+
+    #[tokio::test]
+    async fn status_reports_chain_tip_and_pause_state() {
+        let ctx = TestContext::default_mocked();
+        let service = SignerControlService::new(ctx.clone());
+
+        let chain_tip: BitcoinBlockRef = fake::Faker.fake();
+        ctx.state().set_bitcoin_chain_tip(chain_tip);
+        ctx.state().set_coordinator_paused(true);
+
+        let status = service
+            .get_status(Request::new(proto::GetStatusRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert!(status.paused);
+        assert_eq!(status.bitcoin_chain_tip, Some(chain_tip.block_hash.into()));
+        assert_eq!(status.bitcoin_chain_tip_height, *chain_tip.block_height);
+        assert_eq!(status.latest_tenure_plan, None);
+        assert_eq!(status.latest_dkg_begin_refusal, None);
+    }
+
+    #[tokio::test]
+    async fn status_reports_latest_tenure_plan() {
+        let ctx = TestContext::default_mocked();
+        let service = SignerControlService::new(ctx.clone());
+
+        let plans: [TenurePlan; 2] = fake::Faker.fake();
+        for plan in plans.iter() {
+            ctx.get_storage_mut().write_tenure_plan(plan).await.unwrap();
+        }
+
+        let status = service
+            .get_status(Request::new(proto::GetStatusRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(status.latest_tenure_plan, Some(plans[1].clone().into()));
+    }
+
+    #[tokio::test]
+    async fn status_reports_latest_dkg_begin_refusal() {
+        let ctx = TestContext::default_mocked();
+        let service = SignerControlService::new(ctx.clone());
+
+        let refusals: [DkgBeginRefusal; 2] = fake::Faker.fake();
+        for refusal in refusals.iter() {
+            ctx.get_storage_mut()
+                .write_dkg_begin_refusal(refusal)
+                .await
+                .unwrap();
+        }
+
+        let status = service
+            .get_status(Request::new(proto::GetStatusRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let expected = proto::DkgBeginRefusal::from(refusals[1].clone());
+        assert_eq!(status.latest_dkg_begin_refusal, Some(expected));
+    }
+
+    #[tokio::test]
+    async fn wallet_descriptors_skip_failed_dkg_shares() {
+        let ctx = TestContext::default_mocked();
+        let service = SignerControlService::new(ctx.clone());
+
+        let mut shares: [EncryptedDkgShares; 3] = fake::Faker.fake();
+        shares[0].dkg_shares_status = DkgSharesStatus::Verified;
+        shares[1].dkg_shares_status = DkgSharesStatus::Failed;
+        shares[2].dkg_shares_status = DkgSharesStatus::Unverified;
+        let storage = ctx.get_storage_mut();
+        for shares in shares.iter() {
+            storage.write_encrypted_dkg_shares(shares).await.unwrap();
+            storage.register_aggregate_key(shares).await.unwrap();
+        }
+        storage
+            .activate_aggregate_key(&shares[0].aggregate_key, 100u64.into())
+            .await
+            .unwrap();
+
+        let descriptors = service
+            .get_wallet_descriptors(Request::new(proto::GetWalletDescriptorsRequest {}))
+            .await
+            .unwrap()
+            .into_inner()
+            .descriptors;
+
+        let expected = [(&shares[0], true), (&shares[2], false)];
+        assert_eq!(descriptors.len(), expected.len());
+        for (shares, active) in expected {
+            let descriptor = descriptors
+                .iter()
+                .find(|desc| desc.aggregate_key == Some(shares.aggregate_key.into()))
+                .unwrap();
+            let expected = descriptor::signers_descriptor(&shares.aggregate_key);
+            assert_eq!(descriptor.descriptor, expected);
+            assert_eq!(descriptor.active, active);
+        }
+    }
+
+    #[tokio::test]
+    async fn decision_overrides_are_set_and_cleared() {
+        let ctx = TestContext::default_mocked();
+        let service = SignerControlService::new(ctx.clone());
+
+        let outpoint = bitcoin::OutPoint::null();
+        let mut request = proto::SetDecisionOverrideRequest {
+            decision: DecisionOverride::Reject.into(),
+            request: Some(OverrideTarget::Deposit(outpoint.into())),
+        };
+
+        service
+            .set_decision_override(Request::new(request.clone()))
+            .await
+            .unwrap();
+        let state = ctx.state();
+        assert_eq!(state.deposit_decision_override(&outpoint), Some(false));
+        let stored = ctx.get_storage().get_deposit_decision_overrides().await;
+        let stored = stored.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].outpoint(), outpoint);
+        assert!(!stored[0].can_accept);
+
+        request.decision = DecisionOverride::Unspecified.into();
+        service
+            .set_decision_override(Request::new(request.clone()))
+            .await
+            .unwrap();
+        assert_eq!(state.deposit_decision_override(&outpoint), None);
+        let stored = ctx.get_storage().get_deposit_decision_overrides().await;
+        assert!(stored.unwrap().is_empty());
+
+        request.request = None;
+        let result = service.set_decision_override(Request::new(request)).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn expired_deposits_are_reactivated_once() {
+        let ctx = TestContext::default_mocked();
+        let service = SignerControlService::new(ctx.clone());
+
+        ctx.with_emily_client(|client| {
+            client
+                .expect_update_deposits()
+                .withf(|updates| updates[0].status == DepositStatus::Pending)
+                .times(1)
+                .returning(|_| {
+                    Box::pin(async {
+                        Ok(emily_client::models::UpdateDepositsResponse { deposits: vec![] })
+                    })
+                });
+        })
+        .await;
+
+        let request: DepositRequest = fake::Faker.fake();
+        let storage = ctx.get_storage_mut();
+        storage.write_deposit_request(&request).await.unwrap();
+        let expiration = DepositExpiration {
+            txid: request.txid,
+            output_index: request.output_index,
+            bitcoin_block_hash: fake::Faker.fake(),
+            bitcoin_block_height: 10u64.into(),
+            reactivated: false,
+        };
+        storage.write_deposit_expiration(&expiration).await.unwrap();
+
+        let reactivate = proto::ReactivateDepositRequest {
+            deposit: Some(request.outpoint().into()),
+        };
+        let response = service
+            .reactivate_deposit(Request::new(reactivate))
+            .await
+            .unwrap();
+        assert!(response.into_inner().was_expired);
+
+        let expiration = storage
+            .get_deposit_expiration(&request.txid, request.output_index)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(expiration.reactivated);
+
+        let response = service
+            .reactivate_deposit(Request::new(reactivate))
+            .await
+            .unwrap();
+        assert!(!response.into_inner().was_expired);
+
+        let reactivate = proto::ReactivateDepositRequest { deposit: None };
+        let result = service.reactivate_deposit(Request::new(reactivate)).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn limit_override_votes_are_validated_and_broadcast() {
+        let mut ctx = TestContext::default_mocked();
+        ctx.config_mut().signer.limit_override_max_per_deposit_cap = Some(1_000_000);
+        let service = SignerControlService::new(ctx.clone());
+
+        let public_key = PublicKey::from_private_key(&ctx.config().signer.private_key);
+        ctx.state()
+            .update_current_signer_set(std::iter::once(public_key).collect());
+        let chain_tip = BitcoinBlockRef {
+            block_hash: fake::Faker.fake(),
+            block_height: 100u64.into(),
+        };
+        ctx.state().set_bitcoin_chain_tip(chain_tip);
+
+        let mut signals = ctx.get_signal_receiver();
+
+        // Overrides outside of the bounds of this signer are rejected.
+        let request = proto::ProposeLimitOverrideRequest {
+            per_deposit_cap: Some(1_000_001),
+            per_withdrawal_cap: None,
+            expires_at_height: 110,
+        };
+        let result = service.propose_limit_override(Request::new(request)).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        let request = proto::ProposeLimitOverrideRequest {
+            per_deposit_cap: Some(1_000_000),
+            ..request
+        };
+        let response = service
+            .propose_limit_override(Request::new(request))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.votes, 1);
+
+        let signal = signals.recv().await.unwrap();
+        let SignerSignal::Command(SignerCommand::P2PPublish(msg)) = signal else {
+            panic!("expected the vote to be published, got {signal:?}");
+        };
+        assert_eq!(msg.signer_public_key, public_key);
+        assert!(matches!(msg.inner.payload, Payload::LimitOverrideVote(_)));
+    }
+
+    #[tokio::test]
+    async fn emergency_recovery_approvals_and_revocations_are_validated_and_broadcast() {
+        let recovery_script_pubkey: ScriptPubKey = fake::Faker.fake();
+        let mut ctx = TestContext::default_mocked();
+        ctx.config_mut().signer.emergency_recovery_script_pubkey =
+            Some(recovery_script_pubkey.clone().into());
+        ctx.config_mut().signer.bootstrap_signatures_required = 1;
+        let service = SignerControlService::new(ctx.clone());
+
+        let public_key = PublicKey::from_private_key(&ctx.config().signer.private_key);
+        ctx.state()
+            .update_current_signer_set(std::iter::once(public_key).collect());
+        let chain_tip = BitcoinBlockRef {
+            block_hash: fake::Faker.fake(),
+            block_height: 100u64.into(),
+        };
+        ctx.state().set_bitcoin_chain_tip(chain_tip);
+
+        let mut signals = ctx.get_signal_receiver();
+
+        // Approvals of another recovery scriptPubKey are rejected.
+        let request = proto::ApproveEmergencyRecoveryRequest {
+            recovery_script_pubkey: vec![0x51],
+        };
+        let result = service
+            .approve_emergency_recovery(Request::new(request))
+            .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        let request = proto::ApproveEmergencyRecoveryRequest {
+            recovery_script_pubkey: recovery_script_pubkey.to_bytes(),
+        };
+        let response = service
+            .approve_emergency_recovery(Request::new(request))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.approvals, 1);
+        assert_eq!(response.threshold, 1);
+        let delay = u64::from(ctx.config().signer.emergency_recovery_delay_blocks.get());
+        assert_eq!(response.sweep_height, Some(100 + delay));
+
+        let signal = signals.recv().await.unwrap();
+        assert!(matches!(
+            signal,
+            SignerSignal::Event(SignerEvent::EmergencyRecovery(_))
+        ));
+        let signal = signals.recv().await.unwrap();
+        let SignerSignal::Command(SignerCommand::P2PPublish(msg)) = signal else {
+            panic!("expected the approval to be published, got {signal:?}");
+        };
+        assert_eq!(msg.signer_public_key, public_key);
+        assert!(matches!(
+            msg.inner.payload,
+            Payload::EmergencyRecoveryApproval(_)
+        ));
+
+        let request = proto::RevokeEmergencyRecoveryRequest {
+            recovery_script_pubkey: recovery_script_pubkey.to_bytes(),
+        };
+        let response = service
+            .revoke_emergency_recovery(Request::new(request))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.approvals, 0);
+        assert_eq!(response.sweep_height, None);
+
+        let signal = signals.recv().await.unwrap();
+        assert!(matches!(
+            signal,
+            SignerSignal::Event(SignerEvent::EmergencyRecovery(_))
+        ));
+        let signal = signals.recv().await.unwrap();
+        let SignerSignal::Command(SignerCommand::P2PPublish(msg)) = signal else {
+            panic!("expected the revocation to be published, got {signal:?}");
+        };
+        assert!(matches!(
+            msg.inner.payload,
+            Payload::EmergencyRecoveryRevocation(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn signer_payments_are_reviewed() {
+        let ctx = TestContext::default_mocked();
+        let service = SignerControlService::new(ctx.clone());
+        let storage = ctx.get_storage_mut();
+
+        let mut refundable: SignerPayment = fake::Faker.fake();
+        refundable.status = SignerPaymentStatus::Unreviewed;
+        refundable.sender_script_pubkey = Some(ScriptPubKey::from_bytes(vec![0x51]));
+        let mut anonymous: SignerPayment = fake::Faker.fake();
+        anonymous.status = SignerPaymentStatus::Unreviewed;
+        anonymous.sender_script_pubkey = None;
+        storage.write_signer_payment(&refundable).await.unwrap();
+        storage.write_signer_payment(&anonymous).await.unwrap();
+
+        let list = proto::ListSignerPaymentsRequest {
+            status: proto::SignerPaymentStatus::Unreviewed.into(),
+        };
+        let response = service.list_signer_payments(Request::new(list)).await;
+        assert_eq!(response.unwrap().into_inner().payments.len(), 2);
+
+        // A payment without a known sender cannot be refunded.
+        let request = proto::SetSignerPaymentStatusRequest {
+            outpoint: Some(anonymous.outpoint().into()),
+            status: proto::SignerPaymentStatus::RefundApproved.into(),
+        };
+        let result = service
+            .set_signer_payment_status(Request::new(request))
+            .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::FailedPrecondition);
+
+        let request = proto::SetSignerPaymentStatusRequest {
+            outpoint: Some(refundable.outpoint().into()),
+            status: proto::SignerPaymentStatus::RefundApproved.into(),
+        };
+        service
+            .set_signer_payment_status(Request::new(request))
+            .await
+            .unwrap();
+
+        let list = proto::ListSignerPaymentsRequest {
+            status: proto::SignerPaymentStatus::RefundApproved.into(),
+        };
+        let payments = service
+            .list_signer_payments(Request::new(list))
+            .await
+            .unwrap()
+            .into_inner()
+            .payments;
+        assert_eq!(payments.len(), 1);
+        assert_eq!(payments[0].outpoint, Some(refundable.outpoint().into()));
+        assert_eq!(payments[0].sender_script_pubkey, Some(vec![0x51]));
+
+        // Payments that were never recorded cannot be reviewed.
+        let unknown: SignerPayment = fake::Faker.fake();
+        let request = proto::SetSignerPaymentStatusRequest {
+            outpoint: Some(unknown.outpoint().into()),
+            status: proto::SignerPaymentStatus::Donation.into(),
+        };
+        let result = service
+            .set_signer_payment_status(Request::new(request))
+            .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn emily_api_keys_are_rotated() {
+        let url = url::Url::parse("http://localhost:3031").unwrap();
+        let emily_client =
+            EmilyClient::try_new(&url, std::time::Duration::from_secs(1), None).unwrap();
+        let ctx = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_bitcoin_client()
+            .with_mocked_stacks_client()
+            .with_emily_client(emily_client)
+            .build();
+        let service = SignerControlService::new(ctx);
+
+        let request = proto::SetEmilyApiKeyRequest {
+            name: "primary".to_string(),
+            key: "1234567890abcdef".to_string(),
+            priority: 1,
+        };
+        let response = service.set_emily_api_key(Request::new(request)).await;
+        assert!(!response.unwrap().into_inner().replaced);
+
+        let request = proto::SetEmilyApiKeyRequest {
+            name: "backup".to_string(),
+            key: "fedcba0987654321".to_string(),
+            priority: 0,
+        };
+        service
+            .set_emily_api_key(Request::new(request))
+            .await
+            .unwrap();
+
+        let keys = service
+            .list_emily_api_keys(Request::new(proto::ListEmilyApiKeysRequest {}))
+            .await
+            .unwrap()
+            .into_inner()
+            .keys;
+        let names: Vec<_> = keys.iter().map(|key| key.name.as_str()).collect();
+        assert_eq!(names, ["backup", "primary"]);
+        assert_eq!(keys[1].masked_key, "****cdef");
+
+        let request = proto::RemoveEmilyApiKeyRequest { name: "backup".to_string() };
+        let response = service.remove_emily_api_key(Request::new(request)).await;
+        assert!(response.unwrap().into_inner().removed);
+
+        // Keys without a name cannot be replaced or removed later.
+        let request = proto::SetEmilyApiKeyRequest {
+            name: String::new(),
+            key: "1234567890abcdef".to_string(),
+            priority: 0,
+        };
+        let result = service.set_emily_api_key(Request::new(request)).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+    }
+}
@@ -13,6 +13,7 @@ use crate::{
         DbRead,
         model::{BitcoinBlockHash, BitcoinBlockHeight, StacksBlockHash, StacksBlockHeight},
     },
+    watchdog::ChainTipHealth,
 };
 
 use super::ApiState;
@@ -42,6 +43,7 @@ pub struct BitcoinInfo {
     pub node_chain: Option<String>,
     pub node_version: Option<usize>,
     pub node_subversion: Option<String>,
+    pub signer_tip_health: ChainTipHealth,
 }
 
 #[derive(Debug, Default, Serialize)]
@@ -166,6 +168,7 @@ impl InfoResponse {
     /// Populates the local Bitcoin and Stacks chain tip information.
     async fn populate_local_chain_info<C: Context, R: DbRead>(&mut self, storage: &R, ctx: &C) {
         let bitcoin_tip = ctx.state().bitcoin_chain_tip();
+        self.bitcoin.signer_tip_health = ctx.state().chain_tip_health();
 
         match bitcoin_tip {
             Some(bitcoin_block) => {
@@ -1,11 +1,35 @@
 //! This module contains functions and structs for the Signer API.
 //!
 
+mod deposits;
+mod events;
+mod grpc;
 mod info;
 mod new_block;
+mod peers;
 mod router;
 mod status;
+mod transcripts;
+mod votes;
+mod withdrawals;
 
+use axum::http::StatusCode;
+
+use crate::WITHDRAWAL_BLOCKS_EXPIRY;
+use crate::WITHDRAWAL_EXPIRY_BUFFER;
+use crate::WITHDRAWAL_MIN_CONFIRMATIONS;
+use crate::bitcoin::BitcoinInteract as _;
+use crate::bitcoin::units::FeeRate;
+use crate::bitcoin::utxo::SbtcRequests;
+use crate::bitcoin::utxo::SignerBtcState;
+use crate::context::Context;
+use crate::error::Error;
+use crate::network::P2PNetwork;
+use crate::storage::DbRead as _;
+use crate::transaction_coordinator::GetPendingRequestsParams;
+use crate::transaction_coordinator::TxCoordinatorEventLoop;
+
+pub use grpc::SignerControlService;
 pub use new_block::new_block_handler;
 pub use router::get_router;
 
@@ -18,3 +42,110 @@ pub struct ApiState<C> {
 
 /// The name of the sbtc registry smart contract.
 const SBTC_REGISTRY_CONTRACT_NAME: &str = "sbtc-registry";
+
+/// Collect the requests that the coordinator would sweep at the current
+/// bitcoin chain tip, along with the state of the signers' UTXO.
+///
+/// Unlike the coordinator, this does not look for a sweep transaction in
+/// the mempool that the next sweep would replace, so the estimate can be
+/// low while a sweep is waiting to be confirmed.
+async fn pending_requests<C: Context>(ctx: &C) -> Result<SbtcRequests, Error> {
+    let storage = ctx.get_storage();
+    let state = ctx.state();
+
+    let bitcoin_chain_tip = state.bitcoin_chain_tip().ok_or(Error::NoChainTip)?;
+    let stacks_chain_tip = storage
+        .get_stacks_chain_tip(&bitcoin_chain_tip.block_hash)
+        .await?
+        .ok_or(Error::NoStacksChainTip)?;
+    let signer_set_info = state
+        .registry_signer_set_info()
+        .ok_or(Error::MissingAggregateKey(*bitcoin_chain_tip.block_hash))?;
+
+    let sbtc_limits = state.get_current_limits();
+    let params = GetPendingRequestsParams {
+        bitcoin_chain_tip: &bitcoin_chain_tip,
+        stacks_chain_tip: &stacks_chain_tip.block_hash,
+        aggregate_key: &signer_set_info.aggregate_key,
+        signature_threshold: signer_set_info.signatures_required,
+        sbtc_limits: &sbtc_limits,
+    };
+
+    // The network type of the coordinator does not matter here, since
+    // fetching the pending requests only reads from the database.
+    type Coordinator<C> = TxCoordinatorEventLoop<C, P2PNetwork>;
+
+    let deposits = if state.is_deposits_paused() {
+        Vec::new()
+    } else {
+        let context_window = ctx.config().signer.context_window;
+        Coordinator::<C>::get_eligible_pending_deposit_requests(
+            &storage,
+            state,
+            context_window,
+            &params,
+        )
+        .await?
+    };
+    let withdrawals = if state.is_withdrawals_paused() {
+        Vec::new()
+    } else {
+        Coordinator::<C>::get_eligible_pending_withdrawal_requests(
+            &storage,
+            WITHDRAWAL_BLOCKS_EXPIRY,
+            WITHDRAWAL_EXPIRY_BUFFER,
+            WITHDRAWAL_MIN_CONFIRMATIONS,
+            &params,
+        )
+        .await?
+    };
+
+    let utxo = storage
+        .get_signer_utxo(&bitcoin_chain_tip.block_hash)
+        .await?
+        .ok_or(Error::MissingSignerUtxo)?;
+    let fee_rate = ctx.get_bitcoin_client().estimate_fee_rate().await?;
+    let fee_rate = FeeRate::new(fee_rate)?;
+
+    Ok(SbtcRequests {
+        deposits,
+        withdrawals,
+        signer_state: SignerBtcState {
+            utxo,
+            fee_rate,
+            public_key: bitcoin::XOnlyPublicKey::from(&signer_set_info.aggregate_key),
+            last_fees: None,
+            magic_bytes: ctx.config().signer.network.magic_bytes(),
+            metadata_chain_tip: ctx
+                .config()
+                .signer
+                .proposed_sweep_template()
+                .metadata_chain_tip(bitcoin_chain_tip.block_hash),
+        },
+        accept_threshold: signer_set_info.signatures_required,
+        num_signers: signer_set_info
+            .signer_set
+            .len()
+            .try_into()
+            .map_err(|_| Error::TypeConversion)?,
+        sbtc_limits,
+        max_deposits_per_bitcoin_tx: ctx.config().signer.max_deposits_per_bitcoin_tx.get(),
+        withdrawal_fee_subsidy: ctx.config().signer.withdrawal_fee_subsidy(),
+    })
+}
+
+/// The status code for an error from [`pending_requests`]. Errors caused
+/// by the signer not knowing the current state of the signers yet are
+/// reported as `503 Service Unavailable`.
+fn pending_requests_status(error: Error) -> StatusCode {
+    match error {
+        Error::NoChainTip
+        | Error::NoStacksChainTip
+        | Error::MissingAggregateKey(_)
+        | Error::MissingSignerUtxo => StatusCode::SERVICE_UNAVAILABLE,
+        error => {
+            tracing::error!(%error, "could not fetch the pending requests");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
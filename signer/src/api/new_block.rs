@@ -284,8 +284,10 @@ async fn handle_withdrawal_reject(
     aggregate_key = %event.aggregate_key
 ))]
 async fn handle_key_rotation(ctx: &impl Context, event: KeyRotationEvent) -> Result<(), Error> {
-    ctx.get_storage_mut()
-        .write_rotate_keys_transaction(&event)
+    let storage = ctx.get_storage_mut();
+    storage.write_rotate_keys_transaction(&event).await?;
+    storage
+        .write_aggregate_key_rotation(&event.aggregate_key, &event.txid)
         .await?;
 
     tracing::debug!(topic = "key-rotation", "handled stacks event");
@@ -0,0 +1,86 @@
+//! Handler for the `/debug/peers` endpoint.
+
+use std::collections::BTreeMap;
+
+use axum::Json;
+use axum::extract::State;
+use libp2p::PeerId;
+use serde::Serialize;
+
+use crate::context::Context;
+use crate::keys::PublicKey;
+use crate::network::peers::PeerStats;
+
+use super::ApiState;
+
+#[derive(Debug, Serialize)]
+pub struct PeersResponse {
+    /// The peer ID of this signer.
+    pub local_peer_id: String,
+    /// The statistics of each peer, keyed by the peer ID.
+    pub peers: BTreeMap<String, PeerStats>,
+}
+
+/// Handler for the `/debug/peers` endpoint. Dumps the peer table of this
+/// signer, including the gossipsub mesh and the messages exchanged with
+/// each peer.
+pub async fn peers_handler<C: Context>(state: State<ApiState<C>>) -> Json<PeersResponse> {
+    let public_key = PublicKey::from_private_key(&state.ctx.config().signer.private_key);
+    let local_peer_id = PeerId::from(public_key).to_string();
+
+    let peers = state
+        .ctx
+        .state()
+        .peer_table()
+        .snapshot()
+        .into_iter()
+        .map(|(peer_id, stats)| (peer_id.to_string(), stats))
+        .collect();
+
+    Json(PeersResponse { local_peer_id, peers })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::http::StatusCode;
+    use tower::ServiceExt as _;
+
+    use crate::api::get_router;
+    use crate::testing::context::TestContext;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn peers_endpoint_dumps_the_peer_table() {
+        let ctx = TestContext::default_mocked();
+        let peer_id: PeerId = PublicKey::from_private_key(&ctx.config().signer.private_key).into();
+        ctx.state().peer_table().record_message_received(
+            peer_id,
+            "wsts_nonce_response",
+            ctx.clock().now(),
+        );
+
+        let app: Router = get_router().with_state(ApiState { ctx: ctx.clone() });
+        let request = Request::builder()
+            .uri("/debug/peers")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        let peer_id = peer_id.to_string();
+        assert_eq!(body["local_peer_id"], peer_id);
+        let stats = &body["peers"][&peer_id];
+        assert_eq!(stats["messages_received"]["wsts_nonce_response"], 1);
+        assert_eq!(stats["connected"], false);
+    }
+}
@@ -11,7 +11,9 @@ use crate::context::Context;
 
 use axum::http::StatusCode;
 
-use super::{ApiState, info, new_block, status};
+use super::{
+    ApiState, deposits, events, info, new_block, peers, status, transcripts, votes, withdrawals,
+};
 
 async fn new_attachment_handler() -> StatusCode {
     StatusCode::OK
@@ -22,6 +24,45 @@ pub fn get_router<C: Context + 'static>() -> Router<ApiState<C>> {
     Router::new()
         .route("/", get(status::status_handler))
         .route("/info", get(info::info_handler))
+        .route("/events", get(events::events_handler))
+        .route("/debug/peers", get(peers::peers_handler))
+        .route("/transcripts", get(transcripts::tenures_handler))
+        .route(
+            "/transcripts/{block_hash}",
+            get(transcripts::transcript_handler),
+        )
+        .route(
+            "/deposits/{txid}/{vout}",
+            get(deposits::deposit_by_outpoint_handler),
+        )
+        .route(
+            "/deposits/fee-estimate/{principal}/{amount}/{max_fee}",
+            get(deposits::deposit_fee_estimate_handler),
+        )
+        .route(
+            "/deposits/recipient/{principal}",
+            get(deposits::deposits_by_recipient_handler),
+        )
+        .route(
+            "/withdrawals/fee-estimate/{address}/{amount}/{max_fee}",
+            get(withdrawals::withdrawal_fee_estimate_handler),
+        )
+        .route(
+            "/withdrawals/recipient/{address}",
+            get(withdrawals::withdrawals_by_recipient_handler),
+        )
+        .route(
+            "/withdrawals/sender/{principal}",
+            get(withdrawals::withdrawals_by_sender_handler),
+        )
+        .route(
+            "/votes/deposits/{txid}/{vout}",
+            get(votes::deposit_votes_handler),
+        )
+        .route(
+            "/votes/withdrawals/{request_id}/{block_hash}",
+            get(votes::withdrawal_votes_handler),
+        )
         .route(
             "/new_block",
             post(new_block::new_block_handler)
@@ -0,0 +1,115 @@
+//! Handlers for the `/transcripts` endpoints, which export the
+//! transcripts of the signing rounds of recent tenures.
+
+use std::str::FromStr as _;
+
+use axum::Json;
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+
+use crate::context::Context;
+use crate::storage::model::BitcoinBlockHash;
+use crate::transcript::SignedTranscript;
+
+use super::ApiState;
+
+/// Handler for `GET /transcripts`. Returns the bitcoin chain tips of the
+/// tenures that this signer has a transcript for, from the oldest to the
+/// most recent.
+pub async fn tenures_handler<C: Context>(state: State<ApiState<C>>) -> Json<Vec<String>> {
+    let tenures = state
+        .ctx
+        .state()
+        .transcripts()
+        .tenures()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    Json(tenures)
+}
+
+/// Handler for `GET /transcripts/{block_hash}`. Returns the transcript of
+/// the tenure with the given bitcoin chain tip, signed by this signer, or
+/// `404 Not Found` if we do not have a transcript for it.
+pub async fn transcript_handler<C: Context>(
+    state: State<ApiState<C>>,
+    Path(block_hash): Path<String>,
+) -> Result<Json<SignedTranscript>, StatusCode> {
+    let block_hash =
+        bitcoin::BlockHash::from_str(&block_hash).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let block_hash = BitcoinBlockHash::from(block_hash);
+
+    let transcript = state
+        .ctx
+        .state()
+        .transcripts()
+        .transcript(&block_hash)
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    SignedTranscript::new(transcript, &state.ctx.config().signer.private_key)
+        .map(Json)
+        .map_err(|error| {
+            tracing::error!(%error, "could not sign the transcript");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::Request;
+    use fake::Fake as _;
+    use fake::Faker;
+    use rand::rngs::OsRng;
+    use tower::ServiceExt as _;
+
+    use crate::api::get_router;
+    use crate::ecdsa::SignEcdsa as _;
+    use crate::keys::PrivateKey;
+    use crate::keys::PublicKey;
+    use crate::message::SignerMessage;
+    use crate::message::WstsMessage;
+    use crate::testing::context::TestContext;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn transcript_endpoint_exports_a_signed_transcript() {
+        let ctx = TestContext::default_mocked();
+        let msg = SignerMessage::random_with_payload_type::<WstsMessage, _>(&mut OsRng)
+            .sign_ecdsa(&PrivateKey::new(&mut OsRng));
+        ctx.state().transcripts().record_message_received(&msg);
+
+        let app: Router = get_router().with_state(ApiState { ctx: ctx.clone() });
+        let request = Request::builder()
+            .uri(format!("/transcripts/{}", msg.bitcoin_chain_tip))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let signed: SignedTranscript = serde_json::from_slice(&body).unwrap();
+        signed.verify().unwrap();
+
+        let public_key = PublicKey::from_private_key(&ctx.config().signer.private_key);
+        assert_eq!(signed.signer_public_key, public_key);
+        assert_eq!(signed.transcript.bitcoin_chain_tip, msg.bitcoin_chain_tip);
+        assert_eq!(signed.transcript.entries.len(), 1);
+
+        // Tenures without a transcript are not found.
+        let block_hash: BitcoinBlockHash = Faker.fake_with_rng(&mut OsRng);
+        let request = Request::builder()
+            .uri(format!("/transcripts/{block_hash}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,310 @@
+//! Handlers for the `/votes` endpoints, which show how each signer voted
+//! on a deposit or withdrawal request.
+//!
+//! The votes are taken from the decisions that this signer has recorded,
+//! both its own and the ones it received from the other signers. Every
+//! signer in the current signer set is listed, and signers that we have
+//! no decision from are reported as missing. Both endpoints return
+//! `404 Not Found` for requests that we do not have a record of.
+
+use std::collections::BTreeMap;
+use std::str::FromStr as _;
+
+use axum::Json;
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use clarity::types::chainstate::StacksBlockId;
+use serde::Serialize;
+
+use crate::context::Context;
+use crate::keys::PublicKey;
+use crate::storage::DbRead as _;
+use crate::storage::model::BitcoinTxId;
+use crate::storage::model::StacksBlockHash;
+
+use super::ApiState;
+
+/// How a signer voted on a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Vote {
+    /// The signer accepted the request.
+    Accepted,
+    /// The signer rejected the request.
+    Rejected,
+    /// We do not have a decision from the signer on the request.
+    Missing,
+}
+
+impl From<Option<bool>> for Vote {
+    fn from(is_accepted: Option<bool>) -> Self {
+        match is_accepted {
+            Some(true) => Vote::Accepted,
+            Some(false) => Vote::Rejected,
+            None => Vote::Missing,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SignerVote {
+    pub signer_public_key: String,
+    pub vote: Vote,
+}
+
+/// List the vote of every signer in the current signer set, and of any
+/// other signer that we have a decision from, ordered by public key.
+fn signer_votes<C: Context>(
+    ctx: &C,
+    decisions: impl IntoIterator<Item = (PublicKey, bool)>,
+) -> Vec<SignerVote> {
+    let mut votes: BTreeMap<PublicKey, Option<bool>> = ctx
+        .state()
+        .current_signer_set()
+        .get_signers()
+        .into_iter()
+        .map(|signer| (*signer.public_key(), None))
+        .collect();
+
+    for (public_key, is_accepted) in decisions {
+        votes.insert(public_key, Some(is_accepted));
+    }
+
+    votes
+        .into_iter()
+        .map(|(public_key, is_accepted)| SignerVote {
+            signer_public_key: public_key.to_string(),
+            vote: Vote::from(is_accepted),
+        })
+        .collect()
+}
+
+/// Handler for `GET /votes/deposits/{txid}/{vout}`. Returns the vote of
+/// each signer on the deposit request identified by the given outpoint,
+/// or `404 Not Found` if we do not have a record of it.
+pub async fn deposit_votes_handler<C: Context>(
+    state: State<ApiState<C>>,
+    Path((txid, output_index)): Path<(String, u32)>,
+) -> Result<Json<Vec<SignerVote>>, StatusCode> {
+    let txid = bitcoin::Txid::from_str(&txid).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let txid = BitcoinTxId::from(txid);
+
+    let storage = state.ctx.get_storage();
+    storage
+        .get_deposit_request(&txid, output_index)
+        .await
+        .map_err(|error| {
+            tracing::error!(%error, "could not fetch deposit request");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let decisions = storage
+        .get_deposit_signers(&txid, output_index)
+        .await
+        .map_err(|error| {
+            tracing::error!(%error, "could not fetch deposit decisions");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let decisions = decisions
+        .into_iter()
+        .map(|decision| (decision.signer_pub_key, decision.can_accept));
+    Ok(Json(signer_votes(&state.ctx, decisions)))
+}
+
+/// Handler for `GET /votes/withdrawals/{request_id}/{block_hash}`. Returns
+/// the vote of each signer on the withdrawal request with the given ID
+/// that was created in the stacks block with the given ID, or `404 Not
+/// Found` if we do not have a record of it.
+pub async fn withdrawal_votes_handler<C: Context>(
+    state: State<ApiState<C>>,
+    Path((request_id, block_hash)): Path<(u64, String)>,
+) -> Result<Json<Vec<SignerVote>>, StatusCode> {
+    let block_hash = StacksBlockId::from_hex(&block_hash).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let block_hash = StacksBlockHash::from(block_hash);
+
+    let storage = state.ctx.get_storage();
+    storage
+        .get_withdrawal_request(request_id, &block_hash)
+        .await
+        .map_err(|error| {
+            tracing::error!(%error, "could not fetch withdrawal request");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let decisions = storage
+        .get_withdrawal_signers(request_id, &block_hash)
+        .await
+        .map_err(|error| {
+            tracing::error!(%error, "could not fetch withdrawal decisions");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let decisions = decisions
+        .into_iter()
+        .map(|decision| (decision.signer_pub_key, decision.is_accepted));
+    Ok(Json(signer_votes(&state.ctx, decisions)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::Method;
+    use axum::http::Request;
+    use fake::Fake as _;
+    use tower::ServiceExt as _;
+
+    use crate::api::router::get_router;
+    use crate::storage::DbWrite as _;
+    use crate::storage::model::DepositRequest;
+    use crate::storage::model::DepositSigner;
+    use crate::storage::model::WithdrawalRequest;
+    use crate::storage::model::WithdrawalSigner;
+    use crate::testing::context::TestContext;
+
+    use super::*;
+
+    async fn get(app: Router, uri: &str) -> (StatusCode, serde_json::Value) {
+        let request = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        if status != StatusCode::OK {
+            return (status, serde_json::Value::Null);
+        }
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    fn vote_of(body: &serde_json::Value, public_key: &PublicKey) -> serde_json::Value {
+        body.as_array()
+            .unwrap()
+            .iter()
+            .find(|vote| vote["signer_public_key"] == public_key.to_string())
+            .map(|vote| vote["vote"].clone())
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn votes_of_every_signer_are_listed() {
+        let ctx = TestContext::default_mocked();
+        let storage = ctx.get_storage_mut();
+
+        let signers: [PublicKey; 3] = fake::Faker.fake();
+        ctx.state().update_current_signer_set(signers.into());
+
+        let request: DepositRequest = fake::Faker.fake();
+        storage.write_deposit_request(&request).await.unwrap();
+        for (public_key, can_accept) in signers.iter().zip([true, false]) {
+            let mut decision: DepositSigner = fake::Faker.fake();
+            decision.txid = request.txid;
+            decision.output_index = request.output_index;
+            decision.signer_pub_key = *public_key;
+            decision.can_accept = can_accept;
+            storage
+                .write_deposit_signer_decision(&decision)
+                .await
+                .unwrap();
+        }
+
+        let withdrawal_request: WithdrawalRequest = fake::Faker.fake();
+        storage
+            .write_withdrawal_request(&withdrawal_request)
+            .await
+            .unwrap();
+        let withdrawal = WithdrawalSigner {
+            request_id: withdrawal_request.request_id,
+            block_hash: withdrawal_request.block_hash,
+            signer_pub_key: signers[2],
+            is_accepted: true,
+            ..fake::Faker.fake()
+        };
+        storage
+            .write_withdrawal_signer_decision(&withdrawal)
+            .await
+            .unwrap();
+
+        let state = ApiState { ctx: ctx.clone() };
+        let app: Router = get_router().with_state(state);
+
+        let uri = format!("/votes/deposits/{}/{}", request.txid, request.output_index);
+        let (status, body) = get(app.clone(), &uri).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.as_array().unwrap().len(), 3);
+        assert_eq!(vote_of(&body, &signers[0]), "accepted");
+        assert_eq!(vote_of(&body, &signers[1]), "rejected");
+        assert_eq!(vote_of(&body, &signers[2]), "missing");
+
+        let uri = format!(
+            "/votes/withdrawals/{}/{}",
+            withdrawal.request_id, withdrawal.block_hash
+        );
+        let (status, body) = get(app.clone(), &uri).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(vote_of(&body, &signers[0]), "missing");
+        assert_eq!(vote_of(&body, &signers[2]), "accepted");
+
+        let uri = format!("/votes/deposits/{}/{}", request.txid, 1000);
+        let (status, _) = get(app.clone(), &uri).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let uri = format!(
+            "/votes/withdrawals/{}/not-a-block-hash",
+            withdrawal.request_id
+        );
+        let (status, _) = get(app, &uri).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn votes_on_unknown_requests_are_not_found() {
+        let ctx = TestContext::default_mocked();
+        let storage = ctx.get_storage_mut();
+
+        let signers: [PublicKey; 3] = fake::Faker.fake();
+        ctx.state().update_current_signer_set(signers.into());
+
+        // We have decisions on these requests, but no record of the
+        // requests themselves.
+        let deposit = DepositSigner {
+            signer_pub_key: signers[0],
+            ..fake::Faker.fake()
+        };
+        storage
+            .write_deposit_signer_decision(&deposit)
+            .await
+            .unwrap();
+        let withdrawal = WithdrawalSigner {
+            signer_pub_key: signers[0],
+            ..fake::Faker.fake()
+        };
+        storage
+            .write_withdrawal_signer_decision(&withdrawal)
+            .await
+            .unwrap();
+
+        let state = ApiState { ctx: ctx.clone() };
+        let app: Router = get_router().with_state(state);
+
+        let uri = format!("/votes/deposits/{}/{}", deposit.txid, deposit.output_index);
+        let (status, _) = get(app.clone(), &uri).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let uri = format!(
+            "/votes/withdrawals/{}/{}",
+            withdrawal.request_id, withdrawal.block_hash
+        );
+        let (status, _) = get(app, &uri).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,262 @@
+//! Handlers for the `/withdrawals` endpoints, which look up withdrawal
+//! requests by their recipient or by their sender.
+//!
+//! These are meant for operators answering support questions, like
+//! "where is my withdrawal to bc1…?", without querying the database
+//! directly.
+//!
+//! There is also an endpoint for checking whether the max fee of a
+//! prospective withdrawal would be enough for the signers to sweep it, so
+//! that users do not create withdrawal requests that will expire.
+
+use std::str::FromStr as _;
+
+use axum::Json;
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde::Serialize;
+
+use crate::bitcoin::utxo;
+use crate::bitcoin::utxo::RequestFeeEstimate;
+use crate::context::Context;
+use crate::storage::DbRead as _;
+use crate::storage::model::BitcoinBlockHeight;
+use crate::storage::model::ScriptPubKey;
+use crate::storage::model::StacksBlockHash;
+use crate::storage::model::StacksPrincipal;
+use crate::storage::model::StacksTxId;
+use crate::storage::model::WithdrawalRequest;
+
+use super::ApiState;
+use super::pending_requests;
+use super::pending_requests_status;
+
+#[derive(Debug, Serialize)]
+pub struct WithdrawalInfo {
+    pub request_id: u64,
+    pub txid: String,
+    pub block_hash: StacksBlockHash,
+    pub recipient: String,
+    pub amount: u64,
+    pub max_fee: u64,
+    pub sender_address: String,
+    pub bitcoin_block_height: BitcoinBlockHeight,
+}
+
+impl From<WithdrawalRequest> for WithdrawalInfo {
+    fn from(value: WithdrawalRequest) -> Self {
+        Self {
+            request_id: value.request_id,
+            txid: value.txid.to_string(),
+            block_hash: value.block_hash,
+            recipient: value.recipient.to_hex_string(),
+            amount: value.amount,
+            max_fee: value.max_fee,
+            sender_address: value.sender_address.to_string(),
+            bitcoin_block_height: value.bitcoin_block_height,
+        }
+    }
+}
+
+/// Handler for `GET /withdrawals/recipient/{address}`. Returns all
+/// withdrawal requests paying out to the given bitcoin address, which
+/// must be valid for the network that the signer is running on.
+pub async fn withdrawals_by_recipient_handler<C: Context>(
+    state: State<ApiState<C>>,
+    Path(address): Path<String>,
+) -> Result<Json<Vec<WithdrawalInfo>>, StatusCode> {
+    let network = bitcoin::Network::from(state.ctx.config().signer.network);
+    let address = bitcoin::Address::from_str(&address)
+        .and_then(|address| address.require_network(network))
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+    let recipient = ScriptPubKey::from(address.script_pubkey());
+
+    let requests = state
+        .ctx
+        .get_storage()
+        .get_withdrawal_requests_by_recipient(&recipient)
+        .await
+        .map_err(|error| {
+            tracing::error!(%error, "could not fetch withdrawal requests by recipient");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(Json(
+        requests.into_iter().map(WithdrawalInfo::from).collect(),
+    ))
+}
+
+/// Handler for `GET /withdrawals/sender/{principal}`. Returns all
+/// withdrawal requests initiated by the given stacks principal.
+pub async fn withdrawals_by_sender_handler<C: Context>(
+    state: State<ApiState<C>>,
+    Path(principal): Path<String>,
+) -> Result<Json<Vec<WithdrawalInfo>>, StatusCode> {
+    let sender = StacksPrincipal::from_str(&principal).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let requests = state
+        .ctx
+        .get_storage()
+        .get_withdrawal_requests_by_sender(&sender)
+        .await
+        .map_err(|error| {
+            tracing::error!(%error, "could not fetch withdrawal requests by sender");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(Json(
+        requests.into_iter().map(WithdrawalInfo::from).collect(),
+    ))
+}
+
+/// Handler for `GET /withdrawals/fee-estimate/{address}/{amount}/{max_fee}`.
+/// Estimates the fee that a withdrawal of the given amount to the given
+/// bitcoin address would be charged if it were swept with the requests
+/// that are pending now, and whether the given max fee covers it.
+///
+/// Returns `503 Service Unavailable` if the signer does not know enough
+/// about the current state of the signers to make an estimate.
+pub async fn withdrawal_fee_estimate_handler<C: Context>(
+    state: State<ApiState<C>>,
+    Path((address, amount, max_fee)): Path<(String, u64, u64)>,
+) -> Result<Json<RequestFeeEstimate>, StatusCode> {
+    let network = bitcoin::Network::from(state.ctx.config().signer.network);
+    let address = bitcoin::Address::from_str(&address)
+        .and_then(|address| address.require_network(network))
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let requests = pending_requests(&state.ctx)
+        .await
+        .map_err(pending_requests_status)?;
+
+    // The prospective withdrawal gets the votes of all signers, and it
+    // has a request ID that the smart contract will not reach.
+    let withdrawal = utxo::WithdrawalRequest {
+        request_id: u64::MAX,
+        txid: StacksTxId::from([0; 32]),
+        block_hash: StacksBlockHash::from([0; 32]),
+        amount,
+        max_fee,
+        script_pubkey: ScriptPubKey::from(address.script_pubkey()),
+        signer_bitmap: Default::default(),
+    };
+
+    requests
+        .simulate_withdrawal(withdrawal)
+        .map(Json)
+        .map_err(|error| {
+            tracing::error!(%error, "could not simulate the withdrawal");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::Method;
+    use axum::http::Request;
+    use fake::Fake as _;
+    use tower::ServiceExt as _;
+
+    use crate::api::router::get_router;
+    use crate::storage::DbWrite as _;
+    use crate::testing::context::TestContext;
+
+    use super::*;
+
+    async fn get(app: Router, uri: &str) -> (StatusCode, Vec<serde_json::Value>) {
+        let request = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        let status = response.status();
+        if status != StatusCode::OK {
+            return (status, Vec::new());
+        }
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn withdrawals_can_be_found_by_recipient_and_sender() {
+        let ctx = TestContext::default_mocked();
+        let network = bitcoin::Network::from(ctx.config().signer.network);
+        let address = bitcoin::Address::p2tr(
+            secp256k1::SECP256K1,
+            crate::keys::PublicKey::from_private_key(&crate::keys::PrivateKey::new(
+                &mut rand::rngs::OsRng,
+            ))
+            .into(),
+            None,
+            network,
+        );
+
+        let mut requests: [WithdrawalRequest; 3] = fake::Faker.fake();
+        requests[0].recipient = address.script_pubkey().into();
+        requests[1].recipient = address.script_pubkey().into();
+        requests[1].sender_address = requests[2].sender_address.clone();
+        for request in requests.iter() {
+            let storage = ctx.get_storage_mut();
+            storage.write_withdrawal_request(request).await.unwrap();
+        }
+
+        let state = ApiState { ctx: ctx.clone() };
+        let app: Router = get_router().with_state(state);
+
+        let (status, body) = get(app.clone(), &format!("/withdrawals/recipient/{address}")).await;
+        assert_eq!(status, StatusCode::OK);
+        let mut ids: Vec<u64> = body
+            .iter()
+            .map(|info| info["request_id"].as_u64().unwrap())
+            .collect();
+        ids.sort();
+        let mut expected = vec![requests[0].request_id, requests[1].request_id];
+        expected.sort();
+        assert_eq!(ids, expected);
+
+        let sender = requests[2].sender_address.to_string();
+        let (status, body) = get(app.clone(), &format!("/withdrawals/sender/{sender}")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.len(), 2);
+        assert!(
+            body.iter()
+                .all(|info| info["sender_address"] == sender.as_str())
+        );
+
+        let (status, _) = get(app, "/withdrawals/recipient/not-an-address").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn withdrawal_fee_estimate_needs_the_signers_state() {
+        let ctx = TestContext::default_mocked();
+        let network = bitcoin::Network::from(ctx.config().signer.network);
+        let address = bitcoin::Address::p2tr(
+            secp256k1::SECP256K1,
+            crate::keys::PublicKey::from_private_key(&crate::keys::PrivateKey::new(
+                &mut rand::rngs::OsRng,
+            ))
+            .into(),
+            None,
+            network,
+        );
+
+        let state = ApiState { ctx: ctx.clone() };
+        let app: Router = get_router().with_state(state);
+
+        let (status, _) = get(app.clone(), "/withdrawals/fee-estimate/nope/10000/1000").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        // The signer does not know the bitcoin chain tip yet.
+        let uri = format!("/withdrawals/fee-estimate/{address}/10000/1000");
+        let (status, _) = get(app, &uri).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
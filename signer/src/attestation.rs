@@ -0,0 +1,231 @@
+//! Verification of coordinator attestations.
+//!
+//! At the end of each tenure the coordinator broadcasts a signed
+//! [`CoordinatorAttestation`](crate::message::CoordinatorAttestation)
+//! summarizing the sweep transactions that it broadcast, which is empty
+//! when it broadcast none. The coordinator and the signers persist these
+//! attestations along with the signed message, so that anyone with
+//! access to the database can later check what the coordinator claimed
+//! to do against what actually landed on the canonical bitcoin chain.
+
+use crate::codec::Encode as _;
+use crate::ecdsa::Signed;
+use crate::error::Error;
+use crate::message;
+use crate::message::Payload;
+use crate::message::SignerMessage;
+use crate::network;
+use crate::storage::DbRead;
+use crate::storage::DbWrite;
+use crate::storage::model;
+use crate::storage::model::BitcoinBlockRef;
+use crate::storage::model::BitcoinTxId;
+
+/// The outcome of comparing a coordinator attestation with the bitcoin
+/// blockchain.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AttestationReport {
+    /// The sweep transactions that the coordinator claimed to broadcast
+    /// and that have been confirmed on the canonical bitcoin blockchain.
+    pub confirmed: Vec<BitcoinTxId>,
+    /// The sweep transactions that the coordinator claimed to broadcast
+    /// but that have not been confirmed on the canonical bitcoin
+    /// blockchain.
+    pub unconfirmed: Vec<BitcoinTxId>,
+}
+
+impl AttestationReport {
+    /// Whether all of the sweep transactions in the attestation have been
+    /// confirmed on the canonical bitcoin blockchain.
+    pub fn all_confirmed(&self) -> bool {
+        self.unconfirmed.is_empty()
+    }
+}
+
+/// Store the given attestation of the coordinator, along with the signed
+/// message that carried it.
+///
+/// An error is returned if the attestation does not have a request
+/// package for each of its sweep transactions.
+pub async fn store_attestation<D>(
+    db: &D,
+    attestation: &message::CoordinatorAttestation,
+    msg: &network::Msg,
+) -> Result<(), Error>
+where
+    D: DbWrite,
+{
+    let num_packages = attestation.request_package.len();
+    let num_txids = attestation.sweep_txids.len();
+    if num_packages != num_txids {
+        return Err(Error::MalformedCoordinatorAttestation(
+            num_packages,
+            num_txids,
+        ));
+    }
+
+    let record = model::CoordinatorAttestation {
+        bitcoin_chain_tip: msg.bitcoin_chain_tip,
+        coordinator_public_key: msg.signer_public_key,
+        sweep_txids: attestation.sweep_txids.clone(),
+        message: msg.clone().encode_to_vec(),
+    };
+
+    tracing::debug!(num_transactions = %num_txids, "storing coordinator attestation");
+    db.write_coordinator_attestation(&record).await
+}
+
+/// Verify that the stored attestation was signed by the coordinator and
+/// check which of its sweep transactions have been confirmed on the
+/// canonical bitcoin blockchain identified by the given chain tip.
+///
+/// An error is returned if the signature over the stored message is
+/// invalid, or if the message does not match the other fields of the
+/// attestation.
+pub async fn verify_attestation<D>(
+    db: &D,
+    chain_tip: &BitcoinBlockRef,
+    attestation: &model::CoordinatorAttestation,
+) -> Result<AttestationReport, Error>
+where
+    D: DbRead,
+{
+    let (msg, digest) = Signed::<SignerMessage>::decode_with_digest(&attestation.message)?;
+    msg.verify_digest(digest)?;
+
+    let matches_message = match &msg.inner.payload {
+        Payload::CoordinatorAttestation(payload) => {
+            msg.signer_public_key == attestation.coordinator_public_key
+                && msg.inner.bitcoin_chain_tip == attestation.bitcoin_chain_tip
+                && payload.sweep_txids == attestation.sweep_txids
+        }
+        _ => false,
+    };
+    if !matches_message {
+        return Err(Error::CoordinatorAttestationMismatch(
+            attestation.bitcoin_chain_tip,
+        ));
+    }
+
+    let mut report = AttestationReport::default();
+    for txid in attestation.sweep_txids.iter() {
+        let mut is_confirmed = false;
+        for block_hash in db.get_bitcoin_blocks_with_transaction(txid).await? {
+            let Some(block) = db.get_bitcoin_block(&block_hash).await? else {
+                continue;
+            };
+            let block_ref = BitcoinBlockRef::from(block);
+            if db
+                .in_canonical_bitcoin_blockchain(chain_tip, &block_ref)
+                .await?
+            {
+                is_confirmed = true;
+                break;
+            }
+        }
+
+        if is_confirmed {
+            report.confirmed.push(*txid);
+        } else {
+            report.unconfirmed.push(*txid);
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+    use rand::SeedableRng as _;
+
+    use super::*;
+    use crate::bitcoin::validation::TxRequestIds;
+    use crate::codec::Encode as _;
+    use crate::ecdsa::SignEcdsa as _;
+    use crate::keys::PrivateKey;
+    use crate::keys::PublicKey;
+    use crate::message::CoordinatorAttestation;
+    use crate::storage::DbWrite as _;
+    use crate::storage::memory::Store;
+
+    fn signed_attestation(
+        private_key: &PrivateKey,
+        chain_tip: &model::BitcoinBlockHash,
+        sweep_txids: Vec<BitcoinTxId>,
+    ) -> model::CoordinatorAttestation {
+        let request_ids = TxRequestIds {
+            deposits: Vec::new(),
+            withdrawals: Vec::new(),
+        };
+        let payload = CoordinatorAttestation {
+            request_package: vec![request_ids; sweep_txids.len()],
+            sweep_txids: sweep_txids.clone(),
+        };
+        let msg = Payload::from(payload)
+            .to_message(*chain_tip)
+            .sign_ecdsa(private_key);
+
+        model::CoordinatorAttestation {
+            bitcoin_chain_tip: *chain_tip,
+            coordinator_public_key: PublicKey::from_private_key(private_key),
+            sweep_txids,
+            message: msg.encode_to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn verify_attestation_reports_confirmed_sweeps() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(51);
+        let db = Store::new_shared();
+        let private_key = PrivateKey::new(&mut rng);
+
+        let block: model::BitcoinBlock = fake::Faker.fake_with_rng(&mut rng);
+        db.write_bitcoin_block(&block).await.unwrap();
+        let chain_tip = BitcoinBlockRef::from(&block);
+
+        let confirmed: BitcoinTxId = fake::Faker.fake_with_rng(&mut rng);
+        let unconfirmed: BitcoinTxId = fake::Faker.fake_with_rng(&mut rng);
+        let tx_ref = model::BitcoinTxRef {
+            txid: confirmed,
+            block_hash: block.block_hash,
+        };
+        db.write_bitcoin_transaction(&tx_ref).await.unwrap();
+
+        let attestation = signed_attestation(
+            &private_key,
+            &block.block_hash,
+            vec![confirmed, unconfirmed],
+        );
+
+        let report = verify_attestation(&db, &chain_tip, &attestation)
+            .await
+            .unwrap();
+
+        assert_eq!(report.confirmed, vec![confirmed]);
+        assert_eq!(report.unconfirmed, vec![unconfirmed]);
+        assert!(!report.all_confirmed());
+    }
+
+    #[tokio::test]
+    async fn verify_attestation_rejects_tampered_attestations() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(51);
+        let db = Store::new_shared();
+        let private_key = PrivateKey::new(&mut rng);
+
+        let block: model::BitcoinBlock = fake::Faker.fake_with_rng(&mut rng);
+        let chain_tip = BitcoinBlockRef::from(&block);
+
+        let txid: BitcoinTxId = fake::Faker.fake_with_rng(&mut rng);
+        let mut attestation = signed_attestation(&private_key, &block.block_hash, vec![txid]);
+        attestation
+            .sweep_txids
+            .push(fake::Faker.fake_with_rng(&mut rng));
+
+        let result = verify_attestation(&db, &chain_tip, &attestation).await;
+        assert!(matches!(
+            result,
+            Err(Error::CoordinatorAttestationMismatch(_))
+        ));
+    }
+}
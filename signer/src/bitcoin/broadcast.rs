@@ -0,0 +1,172 @@
+//! Classification of the reasons that bitcoin-core gives for rejecting
+//! the transactions that we broadcast, and how the coordinator handles
+//! each of them.
+//!
+//! bitcoin-core reports rejections of `sendrawtransaction` calls with one
+//! of three RPC error codes, and rejections of `submitpackage` calls with
+//! a reject reason for each transaction. In both cases the reject reason
+//! is the only thing that tells the rejections apart, so we match on the
+//! reasons that bitcoin-core uses in its mempool acceptance code.
+//!
+//! <https://github.com/bitcoin/bitcoin/blob/v28.0/src/rpc/protocol.h>
+//! <https://github.com/bitcoin/bitcoin/blob/v28.0/src/validation.cpp>
+
+use bitcoincore_rpc::jsonrpc::error::Error as JsonRpcError;
+use bitcoincore_rpc::jsonrpc::error::RpcError;
+
+use crate::error::Error;
+
+/// The RPC error code for transactions that failed verification, which
+/// includes transactions with missing or spent inputs.
+const RPC_VERIFY_ERROR: i32 = -25;
+/// The RPC error code for transactions that were rejected by the
+/// consensus or policy rules of the mempool.
+const RPC_VERIFY_REJECTED: i32 = -26;
+/// The RPC error code for transactions that are already confirmed.
+const RPC_VERIFY_ALREADY_IN_CHAIN: i32 = -27;
+
+/// Why bitcoin-core did not accept a transaction that we broadcast.
+///
+/// The variants are ordered by precedence, so that the rejection of a
+/// package of transactions is the smallest rejection of any of them.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display, strum::IntoStaticStr,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum BroadcastRejection {
+    /// An input of the transaction is missing or already spent. This
+    /// usually means that our view of the UTXOs is behind bitcoin-core.
+    MissingInputs,
+    /// The transaction does not pay enough, either for the minimum relay
+    /// fee rate, for the minimum fee rate of a full mempool, or for the
+    /// rules on replacing transactions in the mempool.
+    FeeTooLow,
+    /// The transaction broke another consensus or policy rule.
+    Policy,
+    /// bitcoin-core already has the transaction, either in a block or in
+    /// its mempool.
+    AlreadyKnown,
+}
+
+/// What the coordinator does when bitcoin-core rejects a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BroadcastHandling {
+    /// Carry on as if the transaction had been accepted.
+    Ignore,
+    /// Leave the requests for the next tenure, which constructs the
+    /// transactions again from the UTXOs at that time.
+    Rebuild,
+    /// Leave the requests for the next tenure, and have it pay a higher
+    /// fee rate than the one that was rejected.
+    FeeBump,
+    /// Give up on the transaction and return the error.
+    Abort,
+}
+
+impl BroadcastRejection {
+    /// Classify the given reject reason from bitcoin-core.
+    pub fn from_reject_reason(reason: &str) -> Self {
+        let reason = reason.to_lowercase();
+        let matches_any = |patterns: &[&str]| patterns.iter().any(|p| reason.contains(p));
+
+        if matches_any(&["missingorspent", "missing inputs", "missing-inputs"]) {
+            BroadcastRejection::MissingInputs
+        } else if matches_any(&[
+            "already in block chain",
+            "already in utxo set",
+            "txn-already-in-mempool",
+            "txn-already-known",
+        ]) {
+            BroadcastRejection::AlreadyKnown
+        } else if matches_any(&["fee not met", "insufficient fee", "fee-too-low"]) {
+            BroadcastRejection::FeeTooLow
+        } else {
+            BroadcastRejection::Policy
+        }
+    }
+
+    /// Classify the given error from a `sendrawtransaction` call. This
+    /// returns `None` for errors that are not rejections of the
+    /// transaction, like failing to reach bitcoin-core.
+    pub fn from_rpc_error(error: &bitcoincore_rpc::Error) -> Option<Self> {
+        let bitcoincore_rpc::Error::JsonRpc(JsonRpcError::Rpc(RpcError { code, message, .. })) =
+            error
+        else {
+            return None;
+        };
+
+        match *code {
+            RPC_VERIFY_ALREADY_IN_CHAIN => Some(BroadcastRejection::AlreadyKnown),
+            RPC_VERIFY_ERROR | RPC_VERIFY_REJECTED => Some(Self::from_reject_reason(message)),
+            _ => None,
+        }
+    }
+
+    /// Return the rejection of a transaction in the given error, if the
+    /// error is one.
+    pub fn of(error: &Error) -> Option<Self> {
+        match error {
+            Error::BitcoinTransactionRejected(rejection, _)
+            | Error::BitcoinPackageRejected(rejection, _, _) => Some(*rejection),
+            _ => None,
+        }
+    }
+
+    /// Return how the coordinator handles this rejection.
+    pub fn handling(self) -> BroadcastHandling {
+        match self {
+            BroadcastRejection::MissingInputs => BroadcastHandling::Rebuild,
+            BroadcastRejection::FeeTooLow => BroadcastHandling::FeeBump,
+            BroadcastRejection::Policy => BroadcastHandling::Abort,
+            BroadcastRejection::AlreadyKnown => BroadcastHandling::Ignore,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("bad-txns-inputs-missingorspent", BroadcastRejection::MissingInputs)]
+    #[test_case("min relay fee not met, 100 < 141", BroadcastRejection::FeeTooLow)]
+    #[test_case("mempool min fee not met, 141 < 282", BroadcastRejection::FeeTooLow)]
+    #[test_case(
+        "insufficient fee, rejecting replacement",
+        BroadcastRejection::FeeTooLow
+    )]
+    #[test_case("Transaction already in block chain", BroadcastRejection::AlreadyKnown)]
+    #[test_case("txn-already-in-mempool", BroadcastRejection::AlreadyKnown)]
+    #[test_case("dust", BroadcastRejection::Policy)]
+    #[test_case("too-long-mempool-chain", BroadcastRejection::Policy)]
+    fn reject_reasons_are_classified(reason: &str, expected: BroadcastRejection) {
+        assert_eq!(BroadcastRejection::from_reject_reason(reason), expected);
+    }
+
+    #[test]
+    fn rpc_errors_are_classified_by_code_and_message() {
+        let rpc_error = |code: i32, message: &str| {
+            bitcoincore_rpc::Error::JsonRpc(JsonRpcError::Rpc(RpcError {
+                code,
+                message: message.to_string(),
+                data: None,
+            }))
+        };
+
+        let error = rpc_error(RPC_VERIFY_ERROR, "bad-txns-inputs-missingorspent");
+        let rejection = BroadcastRejection::from_rpc_error(&error);
+        assert_eq!(rejection, Some(BroadcastRejection::MissingInputs));
+
+        let error = rpc_error(
+            RPC_VERIFY_ALREADY_IN_CHAIN,
+            "Transaction outputs already in utxo set",
+        );
+        let rejection = BroadcastRejection::from_rpc_error(&error);
+        assert_eq!(rejection, Some(BroadcastRejection::AlreadyKnown));
+
+        // Other RPC errors are not rejections of the transaction.
+        let error = rpc_error(-28, "Loading block index...");
+        assert_eq!(BroadcastRejection::from_rpc_error(&error), None);
+    }
+}
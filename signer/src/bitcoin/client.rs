@@ -19,16 +19,41 @@ use bitcoin::Txid;
 use bitcoincore_rpc_json::GetTxOutResult;
 use url::Url;
 
+use crate::util::RetryContext;
 use crate::{error::Error, util::ApiFallbackClient};
 
 use super::BitcoinInteract;
 use super::TransactionLookupHint;
+use super::broadcast::BroadcastRejection;
 use super::rpc::BitcoinBlockHeader;
 use super::rpc::BitcoinBlockInfo;
 use super::rpc::BitcoinCoreClient;
 use super::rpc::BitcoinTxInfo;
 use super::rpc::GetTxResponse;
 
+/// Another endpoint may still have the data of a block that one endpoint
+/// has pruned, so those errors are retried like any other. But if the
+/// last attempt fails because the block was pruned, we return that error
+/// instead of a generic failure, so that callers can fall back to what
+/// they have stored.
+fn abort_if_pruned_on_last_attempt<T>(retry: &RetryContext, result: &Result<T, Error>) {
+    retry.abort_if(|| {
+        retry.current_attempt() == retry.total_attempts()
+            && matches!(result, Err(Error::BitcoinCoreBlockPruned(_)))
+    });
+}
+
+/// Other endpoints would reject a transaction for the same reasons that
+/// one endpoint did, so we return the rejection right away instead of
+/// trying them and ending up with a generic failure.
+fn abort_if_rejected(retry: &RetryContext, result: &Result<(), Error>) {
+    retry.abort_if(|| {
+        result
+            .as_ref()
+            .is_err_and(|error| BroadcastRejection::of(error).is_some())
+    });
+}
+
 /// Implement the [`TryFrom`] trait for a slice of [`Url`]s to allow for a
 /// [`ApiFallbackClient`] to be implicitly created from a list of URLs.
 impl TryFrom<&[Url]> for ApiFallbackClient<BitcoinCoreClient> {
@@ -48,8 +73,12 @@ impl BitcoinInteract for ApiFallbackClient<BitcoinCoreClient> {
         &self,
         block_hash: &bitcoin::BlockHash,
     ) -> Result<Option<BitcoinBlockInfo>, Error> {
-        self.exec(|client, _| BitcoinInteract::get_block(client, block_hash))
-            .await
+        self.exec(|client, retry| async move {
+            let result = BitcoinInteract::get_block(client, block_hash).await;
+            abort_if_pruned_on_last_attempt(&retry, &result);
+            result
+        })
+        .await
     }
 
     async fn get_block_header(
@@ -70,8 +99,12 @@ impl BitcoinInteract for ApiFallbackClient<BitcoinCoreClient> {
         txid: &Txid,
         block_hash: &BlockHash,
     ) -> Result<Option<BitcoinTxInfo>, Error> {
-        self.exec(|client, _| BitcoinInteract::get_tx_info(client, txid, block_hash))
-            .await
+        self.exec(|client, retry| async move {
+            let result = BitcoinInteract::get_tx_info(client, txid, block_hash).await;
+            abort_if_pruned_on_last_attempt(&retry, &result);
+            result
+        })
+        .await
     }
 
     async fn estimate_fee_rate(&self) -> Result<f64, Error> {
@@ -81,8 +114,21 @@ impl BitcoinInteract for ApiFallbackClient<BitcoinCoreClient> {
     }
 
     async fn broadcast_transaction(&self, tx: &bitcoin::Transaction) -> Result<(), Error> {
-        self.exec(|client, _| client.broadcast_transaction(tx))
-            .await
+        self.exec(|client, retry| async move {
+            let result = client.broadcast_transaction(tx).await;
+            abort_if_rejected(&retry, &result);
+            result
+        })
+        .await
+    }
+
+    async fn broadcast_package(&self, txs: &[bitcoin::Transaction]) -> Result<(), Error> {
+        self.exec(|client, retry| async move {
+            let result = client.broadcast_package(txs).await;
+            abort_if_rejected(&retry, &result);
+            result
+        })
+        .await
     }
 
     async fn find_mempool_transactions_spending_output(
@@ -135,4 +181,12 @@ impl BitcoinInteract for ApiFallbackClient<BitcoinCoreClient> {
         self.exec(|client, _| async { client.get_network_info() })
             .await
     }
+
+    async fn scan_tx_out_set(
+        &self,
+        descriptors: &[String],
+    ) -> Result<bitcoincore_rpc_json::ScanTxOutResult, Error> {
+        self.exec(|client, _| async { client.scan_tx_out_set(descriptors) })
+            .await
+    }
 }
@@ -0,0 +1,136 @@
+//! Output descriptors for the signers' wallet.
+//!
+//! The signers lock their UTXO in a key-path only taproot output whose
+//! internal key is the aggregate key from DKG, see
+//! [`SignerScriptPubKey`](crate::keys::SignerScriptPubKey). The functions
+//! here describe those outputs as BIP-386 `tr()` descriptors, so that
+//! operators can import them into a watch-only wallet and track the
+//! signers' funds independently of the signers.
+
+use crate::keys::PublicKey;
+
+/// The characters that may appear in a descriptor, in the order used for
+/// computing the checksum, see BIP-380.
+const INPUT_CHARSET: &str = "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+
+/// The characters of the descriptor checksum.
+const CHECKSUM_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Return the descriptor, with its checksum, for the scriptPubKey that
+/// the signers use when their aggregate key is the given key.
+pub fn signers_descriptor(aggregate_key: &PublicKey) -> String {
+    let internal_key = secp256k1::XOnlyPublicKey::from(aggregate_key);
+    let descriptor = format!("tr({internal_key})");
+
+    // The descriptor only contains hex characters and parentheses, which
+    // are all in the input character set.
+    #[allow(clippy::expect_used)]
+    let checksum = descriptor_checksum(&descriptor).expect("BUG: invalid descriptor character");
+    format!("{descriptor}#{checksum}")
+}
+
+/// Return the internal key of a descriptor of the form returned by
+/// [`signers_descriptor`], or `None` if the descriptor has any other form.
+/// The checksum may be omitted, but it must be valid if it is present.
+pub fn parse_signers_descriptor(descriptor: &str) -> Option<secp256k1::XOnlyPublicKey> {
+    let (body, checksum) = match descriptor.split_once('#') {
+        Some((body, checksum)) => (body, Some(checksum)),
+        None => (descriptor, None),
+    };
+    if checksum.is_some_and(|checksum| descriptor_checksum(body).as_deref() != Some(checksum)) {
+        return None;
+    }
+
+    let internal_key = body.strip_prefix("tr(")?.strip_suffix(')')?;
+    internal_key.parse().ok()
+}
+
+/// Compute the BIP-380 checksum of the given descriptor, returning `None`
+/// if it contains a character outside of the input character set.
+fn descriptor_checksum(descriptor: &str) -> Option<String> {
+    fn polymod(chk: u64, value: u64) -> u64 {
+        const GENERATOR: [u64; 5] = [
+            0xf5dee51989,
+            0xa9fdca3312,
+            0x1bab10e32d,
+            0x3706b1677a,
+            0x644d626ffd,
+        ];
+        let top = chk >> 35;
+        let mut chk = ((chk & 0x7ffffffff) << 5) ^ value;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+        chk
+    }
+
+    let mut chk = 1;
+    let mut class = 0;
+    let mut class_count = 0;
+    for ch in descriptor.chars() {
+        let position = INPUT_CHARSET.find(ch)? as u64;
+        chk = polymod(chk, position & 31);
+        class = class * 3 + (position >> 5);
+        class_count += 1;
+        if class_count == 3 {
+            chk = polymod(chk, class);
+            class = 0;
+            class_count = 0;
+        }
+    }
+    if class_count > 0 {
+        chk = polymod(chk, class);
+    }
+    for _ in 0..8 {
+        chk = polymod(chk, 0);
+    }
+    chk ^= 1;
+
+    let checksum = (0..8)
+        .map(|i| CHECKSUM_CHARSET[((chk >> (5 * (7 - i))) & 31) as usize] as char)
+        .collect();
+    Some(checksum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum_matches_bip380_test_vector() {
+        assert_eq!(descriptor_checksum("raw(deadbeef)").unwrap(), "89f8spxm");
+        assert!(descriptor_checksum("raw(deadbeef)\u{e9}").is_none());
+    }
+
+    #[test]
+    fn signers_descriptor_uses_the_x_only_aggregate_key() {
+        let secret_key =
+            secp256k1::SecretKey::from_slice(&[[0; 31].as_slice(), &[1]].concat()).unwrap();
+        let aggregate_key =
+            PublicKey::from(secp256k1::PublicKey::from_secret_key_global(&secret_key));
+
+        assert_eq!(
+            signers_descriptor(&aggregate_key),
+            "tr(79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798)#gxjkeue2"
+        );
+    }
+
+    #[test]
+    fn signers_descriptor_round_trips() {
+        let secret_key =
+            secp256k1::SecretKey::from_slice(&[[0; 31].as_slice(), &[1]].concat()).unwrap();
+        let aggregate_key =
+            PublicKey::from(secp256k1::PublicKey::from_secret_key_global(&secret_key));
+        let internal_key = secp256k1::XOnlyPublicKey::from(&aggregate_key);
+
+        let descriptor = signers_descriptor(&aggregate_key);
+        assert_eq!(parse_signers_descriptor(&descriptor), Some(internal_key));
+
+        let (body, _) = descriptor.split_once('#').unwrap();
+        assert_eq!(parse_signers_descriptor(body), Some(internal_key));
+        assert_eq!(parse_signers_descriptor(&format!("{body}#qqqqqqqq")), None);
+        assert_eq!(parse_signers_descriptor("raw(deadbeef)#89f8spxm"), None);
+    }
+}
@@ -0,0 +1,748 @@
+//! A client for the esplora HTTP API, for read-only bitcoin queries.
+//!
+//! Esplora, and the electrs indexer that serves it, index every
+//! transaction and output, so they can answer lookups that a pruned
+//! bitcoin-core node no longer can. The [`EsploraBackedClient`] uses
+//! esplora alongside bitcoin-core: blocks, transactions, the outputs of
+//! the signers' descriptors, and fee estimates may come from esplora,
+//! while broadcasts and mempool queries always go through bitcoin-core.
+//!
+//! The API is documented at
+//! <https://github.com/Blockstream/esplora/blob/master/API.md>.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+
+use bitcoin::Amount;
+use bitcoin::BlockHash;
+use bitcoin::CompactTarget;
+use bitcoin::OutPoint;
+use bitcoin::ScriptBuf;
+use bitcoin::Transaction;
+use bitcoin::TxMerkleNode;
+use bitcoin::Txid;
+use bitcoin::hashes::Hash as _;
+use bitcoin::hashes::sha256;
+use bitcoincore_rpc_json::GetMempoolEntryResult;
+use bitcoincore_rpc_json::GetTxOutResult;
+use bitcoincore_rpc_json::ScanTxOutResult;
+use bitcoincore_rpc_json::Utxo;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::config::BitcoinConfig;
+use crate::config::EsploraRole;
+use crate::error::Error;
+use crate::keys::SignerScriptPubKey as _;
+use crate::storage::model::BitcoinBlockHeight;
+use crate::util::ApiFallbackClient;
+
+use super::BitcoinInteract;
+use super::GetTransactionFeeResult;
+use super::TransactionLookupHint;
+use super::descriptor;
+use super::rpc::BitcoinBlockHeader;
+use super::rpc::BitcoinBlockInfo;
+use super::rpc::BitcoinCoreClient;
+use super::rpc::BitcoinTxInfo;
+use super::rpc::BitcoinTxVin;
+use super::rpc::BitcoinTxVinPrevout;
+use super::rpc::GetTxResponse;
+use super::rpc::OutputScriptPubKey;
+
+/// The number of transactions in each page of the `/block/:hash/txs`
+/// endpoint.
+const TXS_PAGE_SIZE: usize = 25;
+
+/// The confirmation status of a transaction.
+#[derive(Debug, Clone, Deserialize)]
+struct EsploraTxStatus {
+    /// Whether the transaction is in a block on the best chain.
+    confirmed: bool,
+    /// The height of the block that confirmed the transaction.
+    block_height: Option<BitcoinBlockHeight>,
+    /// The hash of the block that confirmed the transaction.
+    block_hash: Option<BlockHash>,
+    /// The time in the header of the block that confirmed the
+    /// transaction.
+    block_time: Option<u64>,
+}
+
+/// A transaction, as returned by the `/tx/:txid` and `/block/:hash/txs`
+/// endpoints. Only the fields that bitcoin-core does not return along
+/// with the raw transaction are kept.
+#[derive(Debug, Clone, Deserialize)]
+struct EsploraTx {
+    /// The fee paid by the transaction, in sats.
+    fee: u64,
+    /// The inputs of the transaction.
+    vin: Vec<EsploraVin>,
+    /// The confirmation status of the transaction.
+    status: EsploraTxStatus,
+}
+
+/// An input of an [`EsploraTx`].
+#[derive(Debug, Clone, Deserialize)]
+struct EsploraVin {
+    /// The transaction ID of the output that is spent.
+    txid: Txid,
+    /// The index of the output that is spent.
+    vout: u32,
+    /// The output that is spent. Missing for coinbase inputs.
+    prevout: Option<EsploraPrevout>,
+    /// Whether this is the input of a coinbase transaction.
+    is_coinbase: bool,
+}
+
+/// The output spent by an [`EsploraVin`].
+#[derive(Debug, Clone, Deserialize)]
+struct EsploraPrevout {
+    /// The scriptPubKey of the output.
+    scriptpubkey: ScriptBuf,
+    /// The value of the output, in sats.
+    value: u64,
+}
+
+/// A block, as returned by the `/block/:hash` and `/blocks` endpoints.
+#[derive(Debug, Clone, Deserialize)]
+struct EsploraBlock {
+    /// The block hash.
+    id: BlockHash,
+    /// The height of the block.
+    height: BitcoinBlockHeight,
+    /// The version field of the block header.
+    version: i32,
+    /// The time in the block header.
+    timestamp: u64,
+    /// The median time of the past 11 blocks.
+    mediantime: Option<u64>,
+    /// The hash of the parent block. Missing for the genesis block.
+    previousblockhash: Option<BlockHash>,
+    /// The merkle root of the transactions in the block.
+    merkle_root: TxMerkleNode,
+    /// The proof-of-work target of the block, in compact form.
+    bits: u32,
+    /// The nonce of the block header.
+    nonce: u32,
+    /// The number of transactions in the block.
+    tx_count: usize,
+}
+
+/// An unspent output, as returned by the `/scripthash/:hash/utxo`
+/// endpoint.
+#[derive(Debug, Clone, Deserialize)]
+struct EsploraUtxo {
+    /// The transaction ID of the output.
+    txid: Txid,
+    /// The index of the output.
+    vout: u32,
+    /// The value of the output, in sats.
+    value: u64,
+    /// The confirmation status of the transaction of the output.
+    status: EsploraTxStatus,
+}
+
+/// Combine a raw transaction with what esplora returns about it into the
+/// form that bitcoin-core returns.
+fn tx_info(tx: Transaction, info: EsploraTx) -> BitcoinTxInfo {
+    // Like bitcoin-core, we leave out the fee and the outpoints of
+    // coinbase transactions.
+    let fee = (!tx.is_coinbase()).then(|| Amount::from_sat(info.fee));
+    let vin = info
+        .vin
+        .into_iter()
+        .map(|vin| BitcoinTxVin {
+            txid: (!vin.is_coinbase).then_some(vin.txid),
+            vout: (!vin.is_coinbase).then_some(vin.vout),
+            prevout: vin.prevout.map(|prevout| BitcoinTxVinPrevout {
+                value: Amount::from_sat(prevout.value),
+                script_pubkey: OutputScriptPubKey { script: prevout.scriptpubkey },
+            }),
+        })
+        .collect();
+
+    BitcoinTxInfo { fee, tx, vin }
+}
+
+/// A client for an esplora API.
+#[derive(Debug, Clone)]
+pub struct EsploraClient {
+    /// The base URL of the API, with a trailing slash so that the paths of
+    /// the endpoints can be joined to it.
+    endpoint: Url,
+    /// The client used to make the requests.
+    client: reqwest::Client,
+}
+
+impl TryFrom<&Url> for EsploraClient {
+    type Error = Error;
+
+    fn try_from(url: &Url) -> Result<Self, Self::Error> {
+        Self::new(url.clone())
+    }
+}
+
+/// Implement the [`TryFrom`] trait for a slice of [`Url`]s to allow for a
+/// [`ApiFallbackClient`] to be implicitly created from a list of URLs.
+impl TryFrom<&[Url]> for ApiFallbackClient<EsploraClient> {
+    type Error = Error;
+
+    fn try_from(urls: &[Url]) -> Result<Self, Self::Error> {
+        let clients = urls
+            .iter()
+            .map(EsploraClient::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(clients).map_err(Into::into)
+    }
+}
+
+impl EsploraClient {
+    /// Create a new client for the esplora API at the given URL, for
+    /// example `https://blockstream.info/api`.
+    pub fn new(mut endpoint: Url) -> Result<Self, Error> {
+        if !endpoint.path().ends_with('/') {
+            let path = format!("{}/", endpoint.path());
+            endpoint.set_path(&path);
+        }
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .map_err(Error::EsploraRequest)?;
+
+        Ok(Self { endpoint, client })
+    }
+
+    /// Make a GET request to the given path, returning `None` if the API
+    /// responds with a 404 Not Found.
+    async fn get(&self, path: String) -> Result<Option<reqwest::Response>, Error> {
+        let url = self
+            .endpoint
+            .join(&path)
+            .map_err(|err| Error::PathJoin(err, self.endpoint.clone(), Cow::Owned(path)))?;
+
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(Error::EsploraRequest)?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        response
+            .error_for_status()
+            .map(Some)
+            .map_err(Error::EsploraResponse)
+    }
+
+    /// Make a GET request to the given path and parse the JSON response.
+    async fn get_json<T: DeserializeOwned>(&self, path: String) -> Result<Option<T>, Error> {
+        let Some(response) = self.get(path).await? else {
+            return Ok(None);
+        };
+        response
+            .json()
+            .await
+            .map(Some)
+            .map_err(Error::EsploraResponse)
+    }
+
+    /// Make a GET request to the given path and return the raw bytes of
+    /// the response.
+    async fn get_bytes(&self, path: String) -> Result<Option<Vec<u8>>, Error> {
+        let Some(response) = self.get(path).await? else {
+            return Ok(None);
+        };
+        let bytes = response.bytes().await.map_err(Error::EsploraResponse)?;
+        Ok(Some(bytes.to_vec()))
+    }
+
+    /// Get the block at the tip of the best chain.
+    async fn get_tip(&self) -> Result<EsploraBlock, Error> {
+        // The `/blocks` endpoint returns the ten most recent blocks,
+        // newest first.
+        let blocks: Vec<EsploraBlock> = self
+            .get_json("blocks".to_string())
+            .await?
+            .unwrap_or_default();
+        blocks.into_iter().next().ok_or(Error::NoChainTip)
+    }
+
+    /// Get the raw transaction with the given ID.
+    async fn get_raw_tx(&self, txid: &Txid) -> Result<Option<Transaction>, Error> {
+        let Some(bytes) = self.get_bytes(format!("tx/{txid}/raw")).await? else {
+            return Ok(None);
+        };
+        bitcoin::consensus::deserialize(&bytes)
+            .map(Some)
+            .map_err(Error::DecodeBitcoinTransaction)
+    }
+
+    /// Get the block with the given hash, along with the fee and the
+    /// spent outputs of each of its transactions.
+    pub async fn get_block(
+        &self,
+        block_hash: &BlockHash,
+    ) -> Result<Option<BitcoinBlockInfo>, Error> {
+        let Some(info) = self
+            .get_json::<EsploraBlock>(format!("block/{block_hash}"))
+            .await?
+        else {
+            return Ok(None);
+        };
+        let Some(bytes) = self.get_bytes(format!("block/{block_hash}/raw")).await? else {
+            return Ok(None);
+        };
+        let block: bitcoin::Block =
+            bitcoin::consensus::deserialize(&bytes).map_err(Error::DecodeBitcoinBlock)?;
+
+        let mut txs = Vec::with_capacity(info.tx_count);
+        for start_index in (0..info.tx_count).step_by(TXS_PAGE_SIZE) {
+            let page: Vec<EsploraTx> = self
+                .get_json(format!("block/{block_hash}/txs/{start_index}"))
+                .await?
+                .unwrap_or_default();
+            txs.extend(page);
+        }
+
+        let transactions = block
+            .txdata
+            .into_iter()
+            .zip(txs)
+            .map(|(tx, info)| tx_info(tx, info))
+            .collect();
+
+        Ok(Some(BitcoinBlockInfo {
+            block_hash: info.id,
+            height: info.height,
+            time: u64::from(block.header.time),
+            median_time: info.mediantime,
+            previous_block_hash: block.header.prev_blockhash,
+            transactions,
+        }))
+    }
+
+    /// Get the header of the block with the given hash.
+    pub async fn get_block_header(
+        &self,
+        block_hash: &BlockHash,
+    ) -> Result<Option<BitcoinBlockHeader>, Error> {
+        let info = self
+            .get_json::<EsploraBlock>(format!("block/{block_hash}"))
+            .await?;
+
+        Ok(info.map(|info| BitcoinBlockHeader {
+            hash: info.id,
+            height: info.height,
+            time: info.timestamp,
+            previous_block_hash: info.previousblockhash.unwrap_or_else(BlockHash::all_zeros),
+            version: info.version,
+            merkle_root: info.merkle_root,
+            bits: CompactTarget::from_consensus(info.bits),
+            nonce: info.nonce,
+        }))
+    }
+
+    /// Get the transaction with the given ID, along with the block that
+    /// confirmed it, if any.
+    pub async fn get_tx(&self, txid: &Txid) -> Result<Option<GetTxResponse>, Error> {
+        let Some(tx) = self.get_raw_tx(txid).await? else {
+            return Ok(None);
+        };
+        let Some(status) = self
+            .get_json::<EsploraTxStatus>(format!("tx/{txid}/status"))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let confirmations = match status.block_height {
+            Some(height) if status.confirmed => {
+                let tip = self.get_tip().await?;
+                let confirmations = tip.height.blocks_since(height) + 1;
+                Some(u32::try_from(confirmations).unwrap_or(u32::MAX))
+            }
+            _ => None,
+        };
+
+        Ok(Some(GetTxResponse {
+            tx,
+            block_hash: status.block_hash,
+            confirmations,
+            block_time: status.block_time,
+        }))
+    }
+
+    /// Get the transaction with the given ID, along with its fee and the
+    /// outputs that it spends, if it was confirmed in the given block.
+    pub async fn get_tx_info(
+        &self,
+        txid: &Txid,
+        block_hash: &BlockHash,
+    ) -> Result<Option<BitcoinTxInfo>, Error> {
+        let Some(info) = self.get_json::<EsploraTx>(format!("tx/{txid}")).await? else {
+            return Ok(None);
+        };
+        if info.status.block_hash != Some(*block_hash) {
+            return Ok(None);
+        }
+        let Some(tx) = self.get_raw_tx(txid).await? else {
+            return Ok(None);
+        };
+
+        Ok(Some(tx_info(tx, info)))
+    }
+
+    /// Estimate the fee rate, in sats per vbyte, for a transaction to be
+    /// confirmed in the next block, or as soon as possible after it.
+    pub async fn estimate_fee_rate(&self) -> Result<f64, Error> {
+        let estimates: HashMap<String, f64> = self
+            .get_json("fee-estimates".to_string())
+            .await?
+            .unwrap_or_default();
+
+        estimates
+            .into_iter()
+            .filter_map(|(target, fee_rate)| Some((target.parse::<u16>().ok()?, fee_rate)))
+            .min_by_key(|(target, _)| *target)
+            .map(|(_, fee_rate)| fee_rate)
+            .ok_or(Error::NoGoodFeeEstimates)
+    }
+
+    /// Find the confirmed unspent outputs of the given descriptors, which
+    /// must be of the form returned by
+    /// [`signers_descriptor`](descriptor::signers_descriptor).
+    pub async fn scan_tx_out_set(&self, descriptors: &[String]) -> Result<ScanTxOutResult, Error> {
+        let tip = self.get_tip().await?;
+
+        let mut unspents = Vec::new();
+        for desc in descriptors {
+            let internal_key = descriptor::parse_signers_descriptor(desc)
+                .ok_or_else(|| Error::UnsupportedDescriptor(desc.clone()))?;
+            let script_pubkey = internal_key.signers_script_pubkey();
+            let script_hash = sha256::Hash::hash(script_pubkey.as_bytes());
+
+            let utxos: Vec<EsploraUtxo> = self
+                .get_json(format!("scripthash/{script_hash}/utxo"))
+                .await?
+                .unwrap_or_default();
+
+            // Like bitcoin-core, we only scan the UTXO set of the chain
+            // tip, so outputs in the mempool are left out.
+            let confirmed = utxos
+                .into_iter()
+                .filter(|utxo| utxo.status.confirmed)
+                .map(|utxo| Utxo {
+                    txid: utxo.txid,
+                    vout: utxo.vout,
+                    script_pub_key: script_pubkey.clone(),
+                    descriptor: desc.clone(),
+                    amount: Amount::from_sat(utxo.value),
+                    height: utxo.status.block_height.map_or(0, |height| *height),
+                });
+            unspents.extend(confirmed);
+        }
+
+        Ok(ScanTxOutResult {
+            success: Some(true),
+            tx_outs: None,
+            height: Some(*tip.height),
+            best_block_hash: Some(tip.id),
+            total_amount: unspents.iter().map(|utxo| utxo.amount).sum(),
+            unspents,
+        })
+    }
+}
+
+/// A [`BitcoinInteract`] implementation that can answer read-only queries
+/// with esplora as well as bitcoin-core. Broadcasts, mempool queries, and
+/// queries about the bitcoin-core node itself always go to bitcoin-core.
+#[derive(Clone)]
+pub struct EsploraBackedClient<BC> {
+    /// The client for bitcoin-core.
+    bitcoin_core: BC,
+    /// The client for esplora, if any esplora endpoints are configured.
+    esplora: Option<ApiFallbackClient<EsploraClient>>,
+    /// Whether esplora is asked before or after bitcoin-core.
+    role: EsploraRole,
+}
+
+impl TryFrom<&BitcoinConfig> for EsploraBackedClient<ApiFallbackClient<BitcoinCoreClient>> {
+    type Error = Error;
+
+    fn try_from(config: &BitcoinConfig) -> Result<Self, Self::Error> {
+        let bitcoin_core =
+            ApiFallbackClient::<BitcoinCoreClient>::try_from(config.rpc_endpoints.as_slice())?;
+        let esplora = if config.esplora_endpoints.is_empty() {
+            None
+        } else {
+            Some(ApiFallbackClient::<EsploraClient>::try_from(
+                config.esplora_endpoints.as_slice(),
+            )?)
+        };
+
+        Ok(Self::new(bitcoin_core, esplora, config.esplora_role))
+    }
+}
+
+impl<BC> EsploraBackedClient<BC> {
+    /// Create a new client from the given clients.
+    pub fn new(
+        bitcoin_core: BC,
+        esplora: Option<ApiFallbackClient<EsploraClient>>,
+        role: EsploraRole,
+    ) -> Self {
+        Self { bitcoin_core, esplora, role }
+    }
+
+    /// Answer a read-only query with the given bitcoin-core and esplora
+    /// lookups, in the order given by the role of esplora. The second
+    /// lookup is only made if the first one fails. If both fail, the
+    /// error from bitcoin-core is returned, so that callers can still act
+    /// on it, say when bitcoin-core has pruned a block.
+    async fn read<T>(
+        &self,
+        bitcoin_core: impl Future<Output = Result<T, Error>>,
+        esplora: Option<impl Future<Output = Result<T, Error>>>,
+    ) -> Result<T, Error> {
+        let Some(esplora) = esplora else {
+            return bitcoin_core.await;
+        };
+
+        match self.role {
+            EsploraRole::Secondary => match bitcoin_core.await {
+                Ok(value) => Ok(value),
+                Err(error) => {
+                    tracing::debug!(%error, "bitcoin-core lookup failed; trying esplora");
+                    esplora.await.map_err(|esplora_error| {
+                        tracing::warn!(error = %esplora_error, "esplora lookup failed");
+                        error
+                    })
+                }
+            },
+            EsploraRole::Primary => match esplora.await {
+                Ok(value) => Ok(value),
+                Err(error) => {
+                    tracing::warn!(%error, "esplora lookup failed; trying bitcoin-core");
+                    bitcoin_core.await
+                }
+            },
+        }
+    }
+}
+
+impl<BC: BitcoinInteract> BitcoinInteract for EsploraBackedClient<BC> {
+    async fn get_block(&self, block_hash: &BlockHash) -> Result<Option<BitcoinBlockInfo>, Error> {
+        let esplora = self
+            .esplora
+            .as_ref()
+            .map(|esplora| esplora.exec(|client, _| client.get_block(block_hash)));
+        self.read(self.bitcoin_core.get_block(block_hash), esplora)
+            .await
+    }
+
+    async fn get_block_header(
+        &self,
+        block_hash: &BlockHash,
+    ) -> Result<Option<BitcoinBlockHeader>, Error> {
+        let esplora = self
+            .esplora
+            .as_ref()
+            .map(|esplora| esplora.exec(|client, _| client.get_block_header(block_hash)));
+        self.read(self.bitcoin_core.get_block_header(block_hash), esplora)
+            .await
+    }
+
+    async fn get_tx(&self, txid: &Txid) -> Result<Option<GetTxResponse>, Error> {
+        let esplora = self
+            .esplora
+            .as_ref()
+            .map(|esplora| esplora.exec(|client, _| client.get_tx(txid)));
+        self.read(self.bitcoin_core.get_tx(txid), esplora).await
+    }
+
+    async fn get_tx_info(
+        &self,
+        txid: &Txid,
+        block_hash: &BlockHash,
+    ) -> Result<Option<BitcoinTxInfo>, Error> {
+        let esplora = self
+            .esplora
+            .as_ref()
+            .map(|esplora| esplora.exec(|client, _| client.get_tx_info(txid, block_hash)));
+        self.read(self.bitcoin_core.get_tx_info(txid, block_hash), esplora)
+            .await
+    }
+
+    async fn estimate_fee_rate(&self) -> Result<f64, Error> {
+        let esplora = self
+            .esplora
+            .as_ref()
+            .map(|esplora| esplora.exec(|client, _| client.estimate_fee_rate()));
+        self.read(self.bitcoin_core.estimate_fee_rate(), esplora)
+            .await
+    }
+
+    async fn broadcast_transaction(&self, tx: &Transaction) -> Result<(), Error> {
+        self.bitcoin_core.broadcast_transaction(tx).await
+    }
+
+    async fn broadcast_package(&self, txs: &[Transaction]) -> Result<(), Error> {
+        self.bitcoin_core.broadcast_package(txs).await
+    }
+
+    async fn find_mempool_transactions_spending_output(
+        &self,
+        outpoint: &OutPoint,
+    ) -> Result<Vec<Txid>, Error> {
+        self.bitcoin_core
+            .find_mempool_transactions_spending_output(outpoint)
+            .await
+    }
+
+    async fn find_mempool_descendants(&self, txid: &Txid) -> Result<Vec<Txid>, Error> {
+        self.bitcoin_core.find_mempool_descendants(txid).await
+    }
+
+    async fn get_transaction_output(
+        &self,
+        outpoint: &OutPoint,
+        include_mempool: bool,
+    ) -> Result<Option<GetTxOutResult>, Error> {
+        self.bitcoin_core
+            .get_transaction_output(outpoint, include_mempool)
+            .await
+    }
+
+    async fn get_transaction_fee(
+        &self,
+        txid: &Txid,
+        lookup_hint: Option<TransactionLookupHint>,
+    ) -> Result<GetTransactionFeeResult, Error> {
+        self.bitcoin_core
+            .get_transaction_fee(txid, lookup_hint)
+            .await
+    }
+
+    async fn get_mempool_entry(&self, txid: &Txid) -> Result<Option<GetMempoolEntryResult>, Error> {
+        self.bitcoin_core.get_mempool_entry(txid).await
+    }
+
+    async fn get_blockchain_info(
+        &self,
+    ) -> Result<bitcoincore_rpc_json::GetBlockchainInfoResult, Error> {
+        self.bitcoin_core.get_blockchain_info().await
+    }
+
+    async fn get_network_info(&self) -> Result<bitcoincore_rpc_json::GetNetworkInfoResult, Error> {
+        self.bitcoin_core.get_network_info().await
+    }
+
+    async fn scan_tx_out_set(&self, descriptors: &[String]) -> Result<ScanTxOutResult, Error> {
+        let esplora = self
+            .esplora
+            .as_ref()
+            .map(|esplora| esplora.exec(|client, _| client.scan_tx_out_set(descriptors)));
+        self.read(self.bitcoin_core.scan_tx_out_set(descriptors), esplora)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::Sequence;
+    use bitcoin::TxIn;
+    use bitcoin::TxOut;
+    use bitcoin::Witness;
+    use bitcoin::absolute::LockTime;
+    use bitcoin::transaction::Version;
+
+    use crate::bitcoin::MockBitcoinInteract;
+
+    use super::*;
+
+    #[test]
+    fn esplora_transactions_convert_to_bitcoin_core_form() {
+        let previous_output = OutPoint {
+            txid: Txid::from_byte_array([1; 32]),
+            vout: 3,
+        };
+        let tx = Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn {
+                previous_output,
+                script_sig: ScriptBuf::new(),
+                sequence: Sequence::ZERO,
+                witness: Witness::new(),
+            }],
+            output: vec![TxOut {
+                value: Amount::from_sat(9_000),
+                script_pubkey: ScriptBuf::new(),
+            }],
+        };
+
+        let info: EsploraTx = serde_json::from_value(serde_json::json!({
+            "txid": tx.compute_txid(),
+            "fee": 1_000,
+            "vin": [{
+                "txid": previous_output.txid,
+                "vout": previous_output.vout,
+                "prevout": {
+                    "scriptpubkey": "51",
+                    "scriptpubkey_type": "unknown",
+                    "value": 10_000,
+                },
+                "is_coinbase": false,
+                "sequence": 0,
+            }],
+            "status": { "confirmed": false },
+        }))
+        .unwrap();
+
+        let tx_info = tx_info(tx, info);
+        tx_info.validate().unwrap();
+        assert_eq!(tx_info.fee, Some(Amount::from_sat(1_000)));
+
+        let prevout = tx_info.vin[0].prevout.as_ref().unwrap();
+        assert_eq!(prevout.value, Amount::from_sat(10_000));
+        assert_eq!(
+            prevout.script_pubkey.script,
+            ScriptBuf::from_bytes(vec![0x51])
+        );
+    }
+
+    #[tokio::test]
+    async fn esplora_is_asked_in_the_order_of_its_role() {
+        let ok = |value: u32| async move { Ok::<_, Error>(value) };
+        let err = |error: Error| async move { Err::<u32, _>(error) };
+        let pruned = || Error::BitcoinCoreBlockPruned(BlockHash::all_zeros());
+
+        let client =
+            EsploraBackedClient::new(MockBitcoinInteract::default(), None, EsploraRole::Secondary);
+        let value = client.read(ok(1), Some(ok(2))).await.unwrap();
+        assert_eq!(value, 1);
+        let value = client.read(err(pruned()), Some(ok(2))).await.unwrap();
+        assert_eq!(value, 2);
+        let error = client
+            .read(err(pruned()), Some(err(Error::NoChainTip)))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::BitcoinCoreBlockPruned(_)));
+
+        let client =
+            EsploraBackedClient::new(MockBitcoinInteract::default(), None, EsploraRole::Primary);
+        let value = client.read(ok(1), Some(ok(2))).await.unwrap();
+        assert_eq!(value, 2);
+        let value = client
+            .read(ok(1), Some(err(Error::NoChainTip)))
+            .await
+            .unwrap();
+        assert_eq!(value, 1);
+    }
+}
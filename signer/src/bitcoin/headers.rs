@@ -0,0 +1,161 @@
+//! Verification of bitcoin block headers independent of bitcoin-core.
+//!
+//! The block observer trusts bitcoin-core to tell it which blocks are on
+//! the canonical bitcoin chain. A compromised bitcoin-core, or one that
+//! was pointed at the wrong network by mistake, could hand the signers
+//! blocks that were never mined on the network that they are securing.
+//! The checks here are cheap and only need the headers themselves:
+//!
+//! * The hash of each header commits to its contents, including the hash
+//!   of its parent.
+//! * Each header has enough proof-of-work for the target that it claims,
+//!   and that target is not easier than the proof-of-work limit of the
+//!   network.
+//! * The heights of the headers increase by one from the parent block.
+//!
+//! These checks do not follow difficulty adjustments, so they do not
+//! replace a full node, but producing headers that pass them costs real
+//! hash power.
+
+use bitcoin::BlockHash;
+use bitcoin::Target;
+use bitcoin::block::Header;
+use bitcoin::block::Version;
+use bitcoin::params::Params;
+
+use crate::bitcoin::rpc::BitcoinBlockHeader;
+use crate::error::Error;
+use crate::storage::model::BitcoinBlockHeight;
+
+/// Verify that the contents of the header hash to the claimed block hash
+/// and that the header has enough proof-of-work for the given network.
+pub fn verify_header(header: &BitcoinBlockHeader, network: bitcoin::Network) -> Result<(), Error> {
+    let time = u32::try_from(header.time)
+        .map_err(|_| Error::BitcoinHeaderInvalidTime(header.hash, header.time))?;
+
+    let consensus_header = Header {
+        version: Version::from_consensus(header.version),
+        prev_blockhash: header.previous_block_hash,
+        merkle_root: header.merkle_root,
+        time,
+        bits: header.bits,
+        nonce: header.nonce,
+    };
+
+    let computed = consensus_header.block_hash();
+    if computed != header.hash {
+        return Err(Error::BitcoinHeaderHashMismatch { claimed: header.hash, computed });
+    }
+
+    let target = Target::from_compact(header.bits);
+    if target > Params::new(network).max_attainable_target {
+        return Err(Error::BitcoinHeaderTargetAboveLimit(header.hash, network));
+    }
+
+    consensus_header
+        .validate_pow(target)
+        .map_err(|_| Error::BitcoinHeaderInsufficientWork(header.hash))?;
+
+    Ok(())
+}
+
+/// Verify each of the headers, which must be ordered by height, and
+/// that they form a chain starting from the given parent block, if any.
+pub fn verify_header_chain(
+    headers: &[BitcoinBlockHeader],
+    parent: Option<(BlockHash, BitcoinBlockHeight)>,
+    network: bitcoin::Network,
+) -> Result<(), Error> {
+    let mut parent = parent;
+    for header in headers {
+        verify_header(header, network)?;
+
+        if let Some((parent_hash, parent_height)) = parent {
+            if header.previous_block_hash != parent_hash || header.height != parent_height + 1 {
+                return Err(Error::BitcoinHeaderDiscontinuity(header.hash));
+            }
+        }
+        parent = Some((header.hash, header.height));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::CompactTarget;
+    use bitcoin::TxMerkleNode;
+    use bitcoin::hashes::Hash as _;
+
+    use super::*;
+
+    /// Mine a header on top of the given parent with the regtest
+    /// proof-of-work limit, which takes a couple of attempts at most.
+    fn mine_header(parent: BlockHash, height: u64) -> BitcoinBlockHeader {
+        let mut header = Header {
+            version: Version::TWO,
+            prev_blockhash: parent,
+            merkle_root: TxMerkleNode::all_zeros(),
+            time: 1_700_000_000,
+            bits: CompactTarget::from_consensus(0x207fffff),
+            nonce: 0,
+        };
+        while header.validate_pow(header.target()).is_err() {
+            header.nonce += 1;
+        }
+
+        BitcoinBlockHeader {
+            hash: header.block_hash(),
+            height: height.into(),
+            time: header.time.into(),
+            previous_block_hash: parent,
+            version: header.version.to_consensus(),
+            merkle_root: header.merkle_root,
+            bits: header.bits,
+            nonce: header.nonce,
+        }
+    }
+
+    #[test]
+    fn mined_header_chain_verifies() {
+        let first = mine_header(BlockHash::all_zeros(), 1);
+        let second = mine_header(first.hash, 2);
+
+        let parent = Some((BlockHash::all_zeros(), 0u64.into()));
+        let headers = [first, second];
+        verify_header_chain(&headers, parent, bitcoin::Network::Regtest).unwrap();
+    }
+
+    #[test]
+    fn regtest_headers_fail_on_mainnet() {
+        let header = mine_header(BlockHash::all_zeros(), 1);
+        let result = verify_header(&header, bitcoin::Network::Bitcoin);
+        assert!(matches!(
+            result,
+            Err(Error::BitcoinHeaderTargetAboveLimit(..))
+        ));
+    }
+
+    #[test]
+    fn tampered_header_fails() {
+        let mut header = mine_header(BlockHash::all_zeros(), 1);
+        header.nonce = header.nonce.wrapping_add(1);
+        let result = verify_header(&header, bitcoin::Network::Regtest);
+        assert!(matches!(
+            result,
+            Err(Error::BitcoinHeaderHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn header_chain_with_height_gap_fails() {
+        let first = mine_header(BlockHash::all_zeros(), 1);
+        let second = mine_header(first.hash, 3);
+
+        let headers = [first, second.clone()];
+        let result = verify_header_chain(&headers, None, bitcoin::Network::Regtest);
+        assert!(
+            matches!(result, Err(Error::BitcoinHeaderDiscontinuity(hash)) if hash == second.hash)
+        );
+    }
+}
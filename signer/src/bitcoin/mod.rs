@@ -12,11 +12,18 @@ use rpc::BitcoinBlockInfo;
 use rpc::BitcoinTxInfo;
 use rpc::GetTxResponse;
 
+use crate::context::Context;
 use crate::error::Error;
 
+pub mod broadcast;
 pub mod client;
+pub mod descriptor;
+pub mod esplora;
+pub mod headers;
 pub mod packaging;
+pub mod psbt_export;
 pub mod rpc;
+pub mod units;
 pub mod utxo;
 pub mod validation;
 pub mod zmq;
@@ -82,6 +89,16 @@ pub trait BitcoinInteract: Sync + Send {
         tx: &bitcoin::Transaction,
     ) -> impl Future<Output = Result<(), Error>> + Send;
 
+    /// Broadcast a chain of transactions, where each transaction spends an
+    /// output of the one before it. The transactions are submitted as
+    /// packages when the node supports package relay, so that a parent
+    /// with a low fee rate is not rejected on its own. Otherwise they are
+    /// broadcast one at a time.
+    fn broadcast_package(
+        &self,
+        txs: &[bitcoin::Transaction],
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
     /// Find transactions in the mempool which spend the given output. `txid`
     /// must be a known confirmed transaction.
     ///
@@ -147,4 +164,43 @@ pub trait BitcoinInteract: Sync + Send {
     fn get_network_info(
         &self,
     ) -> impl Future<Output = Result<bitcoincore_rpc_json::GetNetworkInfoResult, Error>> + Send;
+
+    /// Scan the UTXO set of the Bitcoin node for the outputs matching the
+    /// given output descriptors.
+    fn scan_tx_out_set(
+        &self,
+        descriptors: &[String],
+    ) -> impl Future<Output = Result<bitcoincore_rpc_json::ScanTxOutResult, Error>> + Send;
+}
+
+/// Get a transaction with additional information about it, reusing the
+/// result of an earlier lookup of the same transaction in the same block
+/// if it is younger than the configured `tx_info_cache_ttl`.
+///
+/// The Stacks contract calls for the requests serviced by a sweep
+/// transaction each need the sweep transaction, so this saves us from
+/// asking bitcoin-core for it once per request.
+pub async fn get_tx_info_cached<C>(
+    ctx: &C,
+    txid: &Txid,
+    block_hash: &BlockHash,
+) -> Result<Option<BitcoinTxInfo>, Error>
+where
+    C: Context,
+{
+    let state = ctx.state();
+    let ttl = ctx.config().signer.tx_info_cache_ttl;
+    let now = ctx.clock().now();
+    if let Some(tx_info) = state.cached_tx_info(txid, block_hash, now, ttl) {
+        return Ok(Some(tx_info));
+    }
+
+    let tx_info = ctx
+        .get_bitcoin_client()
+        .get_tx_info(txid, block_hash)
+        .await?;
+    if let Some(tx_info) = &tx_info {
+        state.set_cached_tx_info(*txid, *block_hash, tx_info.clone(), now, ttl);
+    }
+    Ok(tx_info)
 }
@@ -39,7 +39,8 @@ const PACKAGE_MAX_VSIZE: u64 =
 /// 1. The combined votes against cannot exceed `max_votes_against`
 /// 2. The number of items requiring signatures cannot exceed
 ///    `max_needs_signature`
-/// 3. Withdrawal IDs must fit within the OP_RETURN size limit (~77 bytes)
+/// 3. Withdrawal IDs must fit within `max_op_return_size` bytes of the
+///    OP_RETURN output
 /// 4. The total virtual size across all bags must not exceed
 ///    [`PACKAGE_MAX_VSIZE`]
 ///
@@ -48,6 +49,9 @@ const PACKAGE_MAX_VSIZE: u64 =
 /// - `max_votes_against`: Maximum allowed votes against for any bag
 /// - `max_needs_signature`: Maximum number of items requiring signatures in a
 ///   bag
+/// - `max_op_return_size`: Maximum available size for encoding withdrawal
+///   IDs in the OP_RETURN output, which is at most
+///   `OP_RETURN_AVAILABLE_SIZE`
 ///
 /// ## Notes
 /// - Items that exceed constraints individually are silently ignored
@@ -59,6 +63,7 @@ pub fn compute_optimal_packages<I, T>(
     items: I,
     max_votes_against: u32,
     max_needs_signature: u16,
+    max_op_return_size: usize,
 ) -> impl Iterator<Item = Vec<T>>
 where
     I: IntoIterator<Item = T>,
@@ -67,7 +72,10 @@ where
     // Now we just add each item into a bag, and return the
     // collection of bags afterward.
     // Create config and packager
-    let config = PackagerConfig::new(max_votes_against, max_needs_signature);
+    let config = PackagerConfig {
+        max_op_return_size: max_op_return_size.min(OP_RETURN_AVAILABLE_SIZE),
+        ..PackagerConfig::new(max_votes_against, max_needs_signature)
+    };
     let mut packager = BestFitPackager::new(config);
 
     for item in items {
@@ -742,8 +750,12 @@ mod tests {
         expected_bag_vsizes: [0, 0],
     } ; "votes-against-placement")]
     fn returns_optimal_placements<const N: usize>(case: VotesTestCase<N>) {
-        let ans =
-            compute_optimal_packages(case.items, case.max_votes_against, case.max_needs_signature);
+        let ans = compute_optimal_packages(
+            case.items,
+            case.max_votes_against,
+            case.max_needs_signature,
+            OP_RETURN_AVAILABLE_SIZE,
+        );
         let collection = ans.collect::<Vec<_>>();
         let iter = collection
             .iter()
@@ -788,14 +800,23 @@ mod tests {
 
         let max_needs_signature = 100;
         let max_votes_against = 3;
-        let packages1 =
-            compute_optimal_packages(items.clone(), max_votes_against, max_needs_signature)
-                .collect::<Vec<_>>();
+        let packages1 = compute_optimal_packages(
+            items.clone(),
+            max_votes_against,
+            max_needs_signature,
+            OP_RETURN_AVAILABLE_SIZE,
+        )
+        .collect::<Vec<_>>();
 
         items.shuffle(&mut rng);
 
-        let packages2 = compute_optimal_packages(items, max_votes_against, max_needs_signature)
-            .collect::<Vec<_>>();
+        let packages2 = compute_optimal_packages(
+            items,
+            max_votes_against,
+            max_needs_signature,
+            OP_RETURN_AVAILABLE_SIZE,
+        )
+        .collect::<Vec<_>>();
 
         assert_ne!(packages1, packages2);
     }
@@ -1150,7 +1171,8 @@ mod tests {
         items.push(RequestItem::with_vote(1).wid(3000)); // Different vote pattern
         items.push(RequestItem::no_votes().wid(10000)); // Large ID
 
-        let bags = compute_optimal_packages(items, 1, 5).collect::<Vec<_>>();
+        let bags =
+            compute_optimal_packages(items, 1, 5, OP_RETURN_AVAILABLE_SIZE).collect::<Vec<_>>();
 
         // Verify multiple bags were created due to both vote and withdrawal ID constraints
         assert!(bags.len() > 1);
@@ -0,0 +1,119 @@
+//! Export of unsigned sweep transactions as PSBTs.
+//!
+//! Before the coordinator starts the signing round for a sweep
+//! transaction, it can export the transaction as a BIP-174 PSBT, so that
+//! compliance tooling or a manual review process outside of the signer
+//! can inspect exactly what is about to be signed. The PSBT is written to
+//! a directory, posted to an HTTP endpoint, or both, depending on the
+//! configuration.
+
+use std::path::PathBuf;
+
+use bitcoin::Psbt;
+use url::Url;
+
+use crate::config::SignerConfig;
+use crate::error::Error;
+
+/// Exports PSBTs to the sinks in the signer configuration.
+#[derive(Debug, Clone)]
+pub struct PsbtExporter {
+    /// The directory that PSBTs are written to.
+    directory: Option<PathBuf>,
+    /// The endpoint that PSBTs are posted to.
+    endpoint: Option<Url>,
+    /// The client used to post PSBTs to the endpoint.
+    client: reqwest::Client,
+}
+
+impl PsbtExporter {
+    /// Create an exporter for the sinks in the given configuration.
+    pub fn new(config: &SignerConfig) -> Self {
+        Self {
+            directory: config.psbt_export_directory.clone(),
+            endpoint: config.psbt_export_endpoint.clone(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Whether there is any sink to export PSBTs to.
+    pub fn is_enabled(&self) -> bool {
+        self.directory.is_some() || self.endpoint.is_some()
+    }
+
+    /// Export the given PSBT to each of the configured sinks. PSBTs are
+    /// written to the directory as `<txid>.psbt` files and posted to the
+    /// endpoint with the `application/octet-stream` content type, in the
+    /// binary PSBT format.
+    pub async fn export(&self, psbt: &Psbt) -> Result<(), Error> {
+        let txid = psbt.unsigned_tx.compute_txid();
+        let bytes = psbt.serialize();
+
+        if let Some(directory) = &self.directory {
+            let path = directory.join(format!("{txid}.psbt"));
+            std::fs::write(&path, &bytes).map_err(|error| Error::PsbtExportWrite(error, path))?;
+        }
+
+        if let Some(endpoint) = &self.endpoint {
+            self.client
+                .post(endpoint.clone())
+                .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+                .body(bytes)
+                .send()
+                .await?
+                .error_for_status()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::Amount;
+    use bitcoin::OutPoint;
+    use bitcoin::ScriptBuf;
+    use bitcoin::Sequence;
+    use bitcoin::Transaction;
+    use bitcoin::TxIn;
+    use bitcoin::TxOut;
+    use bitcoin::Witness;
+    use bitcoin::absolute::LockTime;
+    use bitcoin::transaction::Version;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn psbts_are_written_to_the_export_directory() {
+        let directory = tempfile::tempdir().unwrap();
+        let exporter = PsbtExporter {
+            directory: Some(directory.path().to_path_buf()),
+            endpoint: None,
+            client: reqwest::Client::new(),
+        };
+        assert!(exporter.is_enabled());
+
+        let tx = Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: OutPoint::null(),
+                script_sig: ScriptBuf::new(),
+                sequence: Sequence::ZERO,
+                witness: Witness::new(),
+            }],
+            output: vec![TxOut {
+                value: Amount::from_sat(1_000),
+                script_pubkey: ScriptBuf::new(),
+            }],
+        };
+        let psbt = Psbt::from_unsigned_tx(tx).unwrap();
+        exporter.export(&psbt).await.unwrap();
+
+        let path = directory
+            .path()
+            .join(format!("{}.psbt", psbt.unsigned_tx.compute_txid()));
+        let bytes = std::fs::read(path).unwrap();
+        assert_eq!(Psbt::deserialize(&bytes).unwrap(), psbt);
+    }
+}
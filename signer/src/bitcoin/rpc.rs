@@ -1,9 +1,11 @@
 //! Contains client wrappers for bitcoin core and electrum.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use bitcoin::Amount;
 use bitcoin::BlockHash;
+use bitcoin::CompactTarget;
 use bitcoin::Denomination;
 use bitcoin::OutPoint;
 use bitcoin::ScriptBuf;
@@ -19,16 +21,65 @@ use bitcoincore_rpc_json::GetBlockchainInfoResult;
 use bitcoincore_rpc_json::GetMempoolEntryResult;
 use bitcoincore_rpc_json::GetNetworkInfoResult;
 use bitcoincore_rpc_json::GetTxOutResult;
+use bitcoincore_rpc_json::ScanTxOutRequest;
+use bitcoincore_rpc_json::ScanTxOutResult;
 use serde::Deserialize;
 use url::Url;
 
 use crate::bitcoin::BitcoinInteract;
+use crate::bitcoin::broadcast::BroadcastRejection;
 use crate::error::Error;
 use crate::storage::model::BitcoinBlockHeight;
 
 use super::GetTransactionFeeResult;
 use super::TransactionLookupHint;
 
+/// The earliest version of bitcoin-core, as reported by the
+/// getnetworkinfo RPC, whose submitpackage RPC accepts packages into the
+/// mempool for relay.
+const MIN_SUBMIT_PACKAGE_VERSION: usize = 280000;
+
+/// The error code that bitcoin-core returns for miscellaneous errors,
+/// including requests for the data of blocks that it has pruned.
+const RPC_MISC_ERROR: i32 = -1;
+
+/// Whether the error from bitcoin-core means that it knows about the
+/// block in the request but has pruned its data. Both `getblock` and
+/// `getrawtransaction` report this with a message saying that the block
+/// is not available.
+fn is_pruned_block_error(error: &BtcRpcError) -> bool {
+    match error {
+        BtcRpcError::JsonRpc(JsonRpcError::Rpc(RpcError { code, message, .. })) => {
+            *code == RPC_MISC_ERROR && message.contains("not available")
+        }
+        _ => false,
+    }
+}
+
+/// A slimmed down type representing a response from bitcoin-core's
+/// submitpackage RPC.
+///
+/// The docs for the submitpackage RPC call can be found here:
+/// <https://bitcoincore.org/en/doc/28.0.0/rpc/rawtransactions/submitpackage/>.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubmitPackageResponse {
+    /// The result of evaluating the package as a whole. This is
+    /// "success" if all transactions were accepted into the mempool.
+    pub package_msg: String,
+    /// The results for each transaction in the package, keyed by wtxid.
+    #[serde(rename = "tx-results")]
+    pub tx_results: HashMap<String, SubmitPackageTxResult>,
+}
+
+/// The result of a single transaction in a call to the submitpackage RPC.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubmitPackageTxResult {
+    /// The ID of the transaction.
+    pub txid: Txid,
+    /// The reason that the transaction was not accepted, if it was not.
+    pub error: Option<String>,
+}
+
 /// A slimmed down type representing a response from bitcoin-core's
 /// getrawtransaction RPC.
 ///
@@ -314,6 +365,26 @@ pub struct BitcoinBlockHeader {
     /// The block hash of this blocks parent block.
     #[serde(rename = "previousblockhash")]
     pub previous_block_hash: BlockHash,
+    /// The version field of the block header.
+    pub version: i32,
+    /// The root of the merkle tree of the transactions in the block.
+    #[serde(rename = "merkleroot")]
+    pub merkle_root: bitcoin::TxMerkleNode,
+    /// The proof-of-work target of the block, in compact form.
+    #[serde(deserialize_with = "compact_target_deserializer")]
+    pub bits: CompactTarget,
+    /// The nonce of the block header.
+    pub nonce: u32,
+}
+
+/// Deserialize the hex encoded `bits` field of a `getblockheader`
+/// response.
+fn compact_target_deserializer<'de, D>(deserializer: D) -> Result<CompactTarget, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let bits = String::deserialize(deserializer)?;
+    CompactTarget::from_unprefixed_hex(&bits).map_err(serde::de::Error::custom)
 }
 
 /// A struct representing the recommended fee, in sats per vbyte, from a
@@ -373,7 +444,8 @@ impl BitcoinCoreClient {
     /// Fetch the block identified by the given block hash with additional
     /// information about each transaction included in the block, including
     /// prevout information for inputs, but only for unpruned blocks in the
-    /// current best chain.
+    /// current best chain. Returns [`Error::BitcoinCoreBlockPruned`] if
+    /// bitcoin-core has pruned the block.
     pub fn get_block(&self, block_hash: &BlockHash) -> Result<Option<BitcoinBlockInfo>, Error> {
         let args = [
             serde_json::to_value(block_hash).map_err(Error::JsonSerialize)?,
@@ -385,6 +457,9 @@ impl BitcoinCoreClient {
         match self.inner.call("getblock", &args) {
             Ok(block) => Ok(Some(block)),
             Err(BtcRpcError::JsonRpc(JsonRpcError::Rpc(RpcError { code: -5, .. }))) => Ok(None),
+            Err(error) if is_pruned_block_error(&error) => {
+                Err(Error::BitcoinCoreBlockPruned(*block_hash))
+            }
             Err(error) => Err(Error::BitcoinCoreGetBlock(error, *block_hash)),
         }
     }
@@ -477,6 +552,9 @@ impl BitcoinCoreClient {
             // in the provided block. Use `gettransaction` for wallet
             // transactions." In both cases the code is the same.
             Err(BtcRpcError::JsonRpc(JsonRpcError::Rpc(RpcError { code: -5, .. }))) => Ok(None),
+            Err(err) if is_pruned_block_error(&err) => {
+                Err(Error::BitcoinCoreBlockPruned(*block_hash))
+            }
             Err(err) => Err(Error::BitcoinCoreGetTransaction(err, *txid)),
         }
     }
@@ -655,14 +733,106 @@ impl BitcoinCoreClient {
     pub fn get_network_info(&self) -> Result<GetNetworkInfoResult, Error> {
         self.inner.get_network_info().map_err(Error::BitcoinCoreRpc)
     }
+
+    /// Submit a package of transactions to the mempool, so that they are
+    /// evaluated together. The transactions must be topologically sorted,
+    /// and the last one must be a child of all the others.
+    ///
+    /// <https://bitcoincore.org/en/doc/28.0.0/rpc/rawtransactions/submitpackage/>
+    pub fn submit_package(&self, txs: &[Transaction]) -> Result<(), Error> {
+        let raw_txs: Vec<String> = txs
+            .iter()
+            .map(bitcoin::consensus::encode::serialize_hex)
+            .collect();
+        let args = [serde_json::to_value(raw_txs).map_err(Error::JsonSerialize)?];
+
+        let response = self
+            .inner
+            .call::<SubmitPackageResponse>("submitpackage", &args)
+            .map_err(Error::BitcoinCoreRpc)?;
+
+        if response.package_msg == "success" {
+            return Ok(());
+        }
+
+        let reasons = response
+            .tx_results
+            .values()
+            .filter_map(|result| Some(format!("{}: {}", result.txid, result.error.as_ref()?)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        // Package-wide failures, like the package as a whole paying too
+        // little, have no reasons for the individual transactions.
+        let rejection = response
+            .tx_results
+            .values()
+            .filter_map(|result| result.error.as_deref())
+            .map(BroadcastRejection::from_reject_reason)
+            .min()
+            .unwrap_or_else(|| BroadcastRejection::from_reject_reason(&response.package_msg));
+        Err(Error::BitcoinPackageRejected(
+            rejection,
+            response.package_msg,
+            reasons,
+        ))
+    }
+
+    /// Whether the bitcoin-core node relays packages of transactions that
+    /// are submitted with the submitpackage RPC.
+    pub fn supports_package_relay(&self) -> Result<bool, Error> {
+        let network_info = self.get_network_info()?;
+        Ok(network_info.version >= MIN_SUBMIT_PACKAGE_VERSION)
+    }
+
+    /// Scan the UTXO set for the outputs matching the given descriptors.
+    /// This blocks until the scan completes, which can take minutes on
+    /// mainnet.
+    pub fn scan_tx_out_set(&self, descriptors: &[String]) -> Result<ScanTxOutResult, Error> {
+        let requests: Vec<ScanTxOutRequest> = descriptors
+            .iter()
+            .cloned()
+            .map(ScanTxOutRequest::Single)
+            .collect();
+
+        self.inner
+            .scan_tx_out_set_blocking(&requests)
+            .map_err(Error::BitcoinCoreRpc)
+    }
 }
 
 impl BitcoinInteract for BitcoinCoreClient {
     async fn broadcast_transaction(&self, tx: &Transaction) -> Result<(), Error> {
-        self.inner
-            .send_raw_transaction(tx)
-            .map_err(Error::BitcoinCoreRpc)
-            .map(|_| ())
+        match self.inner.send_raw_transaction(tx) {
+            Ok(_) => Ok(()),
+            Err(err) => match BroadcastRejection::from_rpc_error(&err) {
+                Some(rejection) => Err(Error::BitcoinTransactionRejected(
+                    rejection,
+                    err.to_string(),
+                )),
+                None => Err(Error::BitcoinCoreRpc(err)),
+            },
+        }
+    }
+
+    async fn broadcast_package(&self, txs: &[Transaction]) -> Result<(), Error> {
+        // Nodes that cannot evaluate packages get the transactions one at
+        // a time, which works as long as each parent pays enough on its
+        // own.
+        if txs.len() < 2 || !self.supports_package_relay()? {
+            for tx in txs {
+                self.broadcast_transaction(tx).await?;
+            }
+            return Ok(());
+        }
+
+        // bitcoin-core only accepts packages of a child with its parents,
+        // so a chain of transactions is submitted as overlapping pairs of
+        // a parent and its child. Transactions that are already in the
+        // mempool are skipped by bitcoin-core.
+        for pair in txs.windows(2) {
+            self.submit_package(pair)?;
+        }
+        Ok(())
     }
 
     async fn get_block(&self, block_hash: &BlockHash) -> Result<Option<BitcoinBlockInfo>, Error> {
@@ -804,6 +974,16 @@ impl BitcoinInteract for BitcoinCoreClient {
     async fn get_network_info(&self) -> Result<bitcoincore_rpc_json::GetNetworkInfoResult, Error> {
         self.get_network_info()
     }
+
+    async fn scan_tx_out_set(&self, descriptors: &[String]) -> Result<ScanTxOutResult, Error> {
+        // The scan can take minutes, so we keep it off of the threads of
+        // the async runtime.
+        let client = self.clone();
+        let descriptors = descriptors.to_vec();
+        tokio::task::spawn_blocking(move || client.scan_tx_out_set(&descriptors))
+            .await
+            .map_err(Error::ScanTxOutSetTask)?
+    }
 }
 
 #[cfg(test)]
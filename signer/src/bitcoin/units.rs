@@ -0,0 +1,213 @@
+//! Types for amounts of bitcoin and fee rates.
+//!
+//! Amounts in sats and fee rates in sats per virtual byte used to be
+//! passed around as bare `u64`s and `f64`s, which made it easy to pass a
+//! fee where a fee rate was expected, or to let a negative fee rate slip
+//! through. The [`Satoshis`] and [`FeeRate`] types keep the units apart.
+
+use std::fmt;
+
+use bitcoin::Amount;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::error::Error;
+
+/// An amount of bitcoin, in sats.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Satoshis(u64);
+
+impl Satoshis {
+    /// Zero sats.
+    pub const ZERO: Self = Self(0);
+
+    /// Create an amount from the given number of sats.
+    pub const fn from_sat(sats: u64) -> Self {
+        Self(sats)
+    }
+
+    /// The number of sats in this amount.
+    pub const fn to_sat(self) -> u64 {
+        self.0
+    }
+
+    /// Add the given amount, returning `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Subtract the given amount, returning `None` on underflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Multiply by the given factor, returning `None` on overflow.
+    pub fn checked_mul(self, rhs: u64) -> Option<Self> {
+        self.0.checked_mul(rhs).map(Self)
+    }
+
+    /// Divide by the given divisor, returning `None` if it is zero.
+    pub fn checked_div(self, rhs: u64) -> Option<Self> {
+        self.0.checked_div(rhs).map(Self)
+    }
+
+    /// Add the given amount, stopping at the maximum amount.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtract the given amount, stopping at zero.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl From<u64> for Satoshis {
+    fn from(sats: u64) -> Self {
+        Self(sats)
+    }
+}
+
+impl From<Satoshis> for u64 {
+    fn from(amount: Satoshis) -> Self {
+        amount.0
+    }
+}
+
+impl From<Amount> for Satoshis {
+    fn from(amount: Amount) -> Self {
+        Self(amount.to_sat())
+    }
+}
+
+impl From<Satoshis> for Amount {
+    fn from(amount: Satoshis) -> Self {
+        Amount::from_sat(amount.0)
+    }
+}
+
+impl fmt::Display for Satoshis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} sats", self.0)
+    }
+}
+
+/// A fee rate, in sats per virtual byte.
+///
+/// Fee rates are always finite and never negative.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "f64", into = "f64")]
+pub struct FeeRate(f64);
+
+impl FeeRate {
+    /// A fee rate of zero sats per virtual byte.
+    pub const ZERO: Self = Self(0.0);
+
+    /// Create a fee rate from the given number of sats per virtual byte,
+    /// which must be finite and not negative.
+    pub fn new(sats_per_vbyte: f64) -> Result<Self, Error> {
+        if !sats_per_vbyte.is_finite() || sats_per_vbyte < 0.0 {
+            return Err(Error::InvalidFeeRate(sats_per_vbyte));
+        }
+        Ok(Self(sats_per_vbyte))
+    }
+
+    /// The fee rate of a transaction of the given virtual size that pays
+    /// the given fee, or `None` if the virtual size is zero.
+    pub fn from_fee(fee: Satoshis, vsize: u64) -> Option<Self> {
+        if vsize == 0 {
+            return None;
+        }
+        Some(Self(fee.to_sat() as f64 / vsize as f64))
+    }
+
+    /// The number of sats per virtual byte.
+    pub const fn to_sat_per_vb(self) -> f64 {
+        self.0
+    }
+
+    /// The fee, rounded up to the nearest sat, for a transaction of the
+    /// given virtual size at this fee rate.
+    pub fn fee_for_vsize(self, vsize: f64) -> Satoshis {
+        // Casting a float to an integer saturates, and the fee rate is
+        // never negative, so this never wraps around.
+        Satoshis((vsize * self.0).ceil() as u64)
+    }
+
+    /// The larger of the two fee rates.
+    pub fn max(self, other: Self) -> Self {
+        Self(self.0.max(other.0))
+    }
+}
+
+impl TryFrom<f64> for FeeRate {
+    type Error = Error;
+
+    fn try_from(sats_per_vbyte: f64) -> Result<Self, Self::Error> {
+        Self::new(sats_per_vbyte)
+    }
+}
+
+impl From<FeeRate> for f64 {
+    fn from(fee_rate: FeeRate) -> Self {
+        fee_rate.0
+    }
+}
+
+impl fmt::Display for FeeRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} sat/vB", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn satoshi_arithmetic_is_checked() {
+        let max = Satoshis::from_sat(u64::MAX);
+        let one = Satoshis::from_sat(1);
+
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(Satoshis::ZERO.checked_sub(one), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(one.checked_div(0), None);
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(Satoshis::ZERO.saturating_sub(one), Satoshis::ZERO);
+        assert_eq!(one.checked_add(one), Some(Satoshis::from_sat(2)));
+    }
+
+    #[test_case::test_case(0.0 => true; "zero")]
+    #[test_case::test_case(12.5 => true; "positive")]
+    #[test_case::test_case(-1.0 => false; "negative")]
+    #[test_case::test_case(f64::NAN => false; "not a number")]
+    #[test_case::test_case(f64::INFINITY => false; "infinite")]
+    fn fee_rates_must_be_finite_and_not_negative(rate: f64) -> bool {
+        FeeRate::new(rate).is_ok()
+    }
+
+    #[test]
+    fn fees_are_rounded_up() {
+        let fee_rate = FeeRate::new(1.5).unwrap();
+        assert_eq!(fee_rate.fee_for_vsize(3.0), Satoshis::from_sat(5));
+        assert_eq!(FeeRate::ZERO.fee_for_vsize(100.0), Satoshis::ZERO);
+
+        let fee_rate = FeeRate::from_fee(Satoshis::from_sat(500), 200).unwrap();
+        assert_eq!(fee_rate.to_sat_per_vb(), 2.5);
+        assert_eq!(FeeRate::from_fee(Satoshis::from_sat(500), 0), None);
+    }
+
+    #[test]
+    fn serde_uses_bare_numbers() {
+        let json = serde_json::to_string(&Satoshis::from_sat(1000)).unwrap();
+        assert_eq!(json, "1000");
+
+        let fee_rate: FeeRate = serde_json::from_str("2.5").unwrap();
+        assert_eq!(fee_rate.to_sat_per_vb(), 2.5);
+        assert!(serde_json::from_str::<FeeRate>("-2.5").is_err());
+    }
+}
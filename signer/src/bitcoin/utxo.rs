@@ -5,6 +5,7 @@ use std::sync::LazyLock;
 
 use bitcoin::Amount;
 use bitcoin::OutPoint;
+use bitcoin::Psbt;
 use bitcoin::ScriptBuf;
 use bitcoin::Sequence;
 use bitcoin::TapLeafHash;
@@ -18,7 +19,10 @@ use bitcoin::Weight;
 use bitcoin::Witness;
 use bitcoin::absolute::LockTime;
 use bitcoin::consensus::Encodable as _;
+use bitcoin::consensus::encode::VarInt;
+use bitcoin::hashes::Hash as _;
 use bitcoin::opcodes::all::OP_RETURN;
+use bitcoin::psbt;
 use bitcoin::script::Instruction;
 use bitcoin::script::PushBytesBuf;
 use bitcoin::sighash::Prevouts;
@@ -26,6 +30,8 @@ use bitcoin::sighash::SighashCache;
 use bitcoin::taproot::LeafVersion;
 use bitcoin::taproot::NodeInfo;
 use bitcoin::taproot::Signature;
+use bitcoin::taproot::TAPROOT_CONTROL_BASE_SIZE;
+use bitcoin::taproot::TAPROOT_CONTROL_NODE_SIZE;
 use bitcoin::taproot::TaprootSpendInfo;
 use bitcoin::transaction::Version;
 use bitvec::array::BitArray;
@@ -39,16 +45,21 @@ use secp256k1::SECP256K1;
 use secp256k1::XOnlyPublicKey;
 use serde::Deserialize;
 use serde::Serialize;
+use sha2::Digest as _;
+use sha2::Sha256;
 
 use crate::DEPOSIT_DUST_LIMIT;
 use crate::MAX_MEMPOOL_PACKAGE_TX_COUNT;
 use crate::bitcoin::packaging::Weighted;
 use crate::bitcoin::packaging::compute_optimal_packages;
 use crate::bitcoin::rpc::BitcoinTxInfo;
+use crate::bitcoin::units::FeeRate;
+use crate::bitcoin::units::Satoshis;
 use crate::context::SbtcLimits;
 use crate::error::Error;
 use crate::keys::SignerScriptPubKey as _;
 use crate::storage::model;
+use crate::storage::model::BitcoinBlockHash;
 use crate::storage::model::BitcoinTxId;
 use crate::storage::model::QualifiedRequestId;
 use crate::storage::model::ScriptPubKey;
@@ -67,13 +78,6 @@ use crate::storage::model::WithdrawalTxOutput;
 const DEFAULT_INCREMENTAL_RELAY_FEE_RATE: f64 =
     bitcoin::policy::DEFAULT_INCREMENTAL_RELAY_FEE as f64 / 1000.0;
 
-/// This constant represents the virtual size (in vBytes) of a BTC
-/// transaction that includes two inputs and one output. The inputs consist
-/// of the signers' input UTXO and a UTXO for a deposit request. The output
-/// is the signers' new UTXO. The deposit request is such that the sweep
-/// transaction has the largest size of solo deposit sweep transactions.
-const SOLO_DEPOSIT_TX_VSIZE: f64 = 249.0;
-
 /// This constant represents the virtual size (in vBytes) of a BTC
 /// transaction servicing only one withdrawal request, except the
 /// withdrawal output is not in the transaction. This way the sweep
@@ -85,6 +89,54 @@ const BASE_WITHDRAWAL_TX_VSIZE: f64 = MAX_BASE_TX_VSIZE as f64;
 /// transaction excluding withdrawals outputs and deposit inputs.
 pub const MAX_BASE_TX_VSIZE: u64 = 137;
 
+/// The weight of the parts of a segwit transaction that do not depend on
+/// its inputs and outputs, when it has fewer than 253 of each: the
+/// version, the input and output counts, and the lock time, along with
+/// the segwit marker and flag, which count as witness data.
+const TX_OVERHEAD_WEIGHT: Weight = Weight::from_wu(4 * (4 + 1 + 1 + 4) + 2);
+
+/// The weight of the non-witness part of a transaction input with an
+/// empty scriptSig: the outpoint, the length of the scriptSig, and the
+/// sequence number.
+const TX_IN_BASE_WEIGHT: Weight = Weight::from_non_witness_data_size(32 + 4 + 1 + 4);
+
+/// The size of the signatures of the signers, which are Schnorr
+/// signatures with an explicit `SIGHASH_ALL` byte.
+const TAPROOT_SIGNATURE_SIZE: usize = secp256k1::constants::SCHNORR_SIGNATURE_SIZE + 1;
+
+/// The size of the control block for spending the deposit script. The
+/// taproot tree of a deposit has two leaves, so the control block has
+/// one node in its merkle path.
+const DEPOSIT_CONTROL_BLOCK_SIZE: usize = TAPROOT_CONTROL_BASE_SIZE + TAPROOT_CONTROL_NODE_SIZE;
+
+/// The weight of a sweep transaction that services a single deposit,
+/// excluding the weight of the deposit input. The transaction spends the
+/// signers' UTXO, and has the signers' new UTXO and an OP_RETURN output
+/// without metadata or withdrawal IDs.
+static SOLO_DEPOSIT_BASE_TX_WEIGHT: LazyLock<Weight> = LazyLock::new(|| {
+    let signers_output = SignerUtxo::new_tx_output(*sbtc::UNSPENDABLE_TAPROOT_KEY, 0);
+    let op_return_output = TxOut {
+        value: Amount::ZERO,
+        script_pubkey: ScriptBuf::new_op_return([0; OP_RETURN_HEADER_SIZE]),
+    };
+
+    TX_OVERHEAD_WEIGHT
+        + SignerUtxo::input_weight()
+        + signers_output.weight()
+        + op_return_output.weight()
+});
+
+/// Compute the weight of a witness whose items have the given sizes. This
+/// includes the number of items and the length prefix of each item.
+fn witness_weight(item_sizes: &[usize]) -> Weight {
+    let items_size: usize = item_sizes
+        .iter()
+        .map(|size| VarInt(*size as u64).size() + size)
+        .sum();
+    let size = VarInt(item_sizes.len() as u64).size() + items_size;
+    Weight::from_witness_data_size(size as u64)
+}
+
 /// It appears that bitcoin-core tracks fee rates in sats per kilo-vbyte
 /// (or BTC per kilo-vbyte). Since we work in sats per vbyte, this constant
 /// is the smallest detectable increment for bumping the fee rate in sats
@@ -95,15 +147,176 @@ const SATS_PER_VBYTE_INCREMENT: f64 = 0.001;
 /// transactions.
 const OP_RETURN_VERSION: u8 = 1;
 
+/// The OP_RETURN version byte for sweep transactions that include
+/// [`SweepMetadata`] right after the header.
+const OP_RETURN_METADATA_VERSION: u8 = 2;
+
 /// The OP_RETURN header size (magic bytes + version)
 const OP_RETURN_HEADER_SIZE: usize = 3;
 
+/// The size of the encoded [`SweepMetadata`] in the OP_RETURN output.
+const SWEEP_METADATA_SIZE: usize = 16;
+
 /// The maximum total size of an OP_RETURN output
 const OP_RETURN_MAX_SIZE: usize = 80;
 
 /// The available size for encoded withdrawal IDs in OP_RETURN
 pub(super) const OP_RETURN_AVAILABLE_SIZE: usize = OP_RETURN_MAX_SIZE - OP_RETURN_HEADER_SIZE;
 
+/// The versions of the template that the signers follow when they
+/// construct sweep transactions.
+///
+/// Signers only sign sweeps that they would have constructed themselves,
+/// so the coordinator states the version that it used in its
+/// [`BitcoinPreSignRequest`], and each signer checks that it accepts that
+/// version before reconstructing the sweep with it. This way signers can
+/// roll out a new version by first accepting it everywhere and only then
+/// proposing it.
+///
+/// [`BitcoinPreSignRequest`]: crate::message::BitcoinPreSignRequest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u32", into = "u32")]
+pub enum SweepTemplateVersion {
+    /// The OP_RETURN output has the version byte 1 and is followed by the
+    /// encoded withdrawal request IDs.
+    V1,
+    /// The OP_RETURN output has the version byte 2 and includes
+    /// [`SweepMetadata`] before the encoded withdrawal request IDs.
+    V2,
+}
+
+impl SweepTemplateVersion {
+    /// All versions of the template that this signer knows about.
+    pub const ALL: [Self; 2] = [Self::V1, Self::V2];
+
+    /// Whether sweeps constructed with this version include
+    /// [`SweepMetadata`] in their OP_RETURN output.
+    pub fn includes_metadata(self) -> bool {
+        match self {
+            Self::V1 => false,
+            Self::V2 => true,
+        }
+    }
+
+    /// The chain tip that sweeps constructed with this version during the
+    /// tenure of the given chain tip commit to, if any. This is the value
+    /// for [`SignerBtcState::metadata_chain_tip`].
+    pub fn metadata_chain_tip(self, chain_tip: BitcoinBlockHash) -> Option<BitcoinBlockHash> {
+        self.includes_metadata().then_some(chain_tip)
+    }
+}
+
+impl From<SweepTemplateVersion> for u32 {
+    fn from(version: SweepTemplateVersion) -> Self {
+        match version {
+            SweepTemplateVersion::V1 => 1,
+            SweepTemplateVersion::V2 => 2,
+        }
+    }
+}
+
+impl TryFrom<u32> for SweepTemplateVersion {
+    type Error = Error;
+    fn try_from(version: u32) -> Result<Self, Self::Error> {
+        match version {
+            1 => Ok(Self::V1),
+            2 => Ok(Self::V2),
+            _ => Err(Error::UnsupportedSweepTemplateVersion(version)),
+        }
+    }
+}
+
+impl std::fmt::Display for SweepTemplateVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "v{}", u32::from(*self))
+    }
+}
+
+/// Metadata that sweep transactions may include in their OP_RETURN
+/// output, so that external indexers have an unambiguous way to identify
+/// and verify the sweeps of the signers.
+///
+/// It is encoded right after the OP_RETURN header as the chain tip
+/// commitment followed by the request digest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SweepMetadata {
+    /// The first 8 bytes of the bitcoin chain tip of the tenure in which
+    /// the sweep was constructed, in internal byte order.
+    pub chain_tip_commitment: [u8; 8],
+    /// The first 8 bytes of the SHA256 digest of the requests serviced by
+    /// the sweep. See [`SweepMetadata::request_digest`] for how the
+    /// digest is computed.
+    pub request_digest: [u8; 8],
+}
+
+impl SweepMetadata {
+    /// Create the metadata for a sweep that services the given requests
+    /// during the tenure of the given bitcoin chain tip.
+    fn new(chain_tip: &BitcoinBlockHash, reqs: &Requests) -> Self {
+        let deposits: Vec<OutPoint> = reqs
+            .iter()
+            .filter_map(|req| Some(req.as_deposit()?.outpoint))
+            .collect();
+        let withdrawal_ids: Vec<u64> = reqs.iter().filter_map(|req| req.withdrawal_id()).collect();
+
+        let mut chain_tip_commitment = [0; 8];
+        chain_tip_commitment.copy_from_slice(&chain_tip.into_bytes()[..8]);
+
+        Self {
+            chain_tip_commitment,
+            request_digest: Self::request_digest(&deposits, &withdrawal_ids),
+        }
+    }
+
+    /// Compute the digest of the requests serviced by a sweep.
+    ///
+    /// The digest only uses data that is in the sweep itself, the
+    /// outpoints of the deposit inputs in the order of the inputs and the
+    /// IDs of the withdrawal requests in the order of the outputs, so
+    /// that anyone can verify it.
+    pub fn request_digest(deposits: &[OutPoint], withdrawal_ids: &[u64]) -> [u8; 8] {
+        let mut hasher = Sha256::new_with_prefix("SBTC_SWEEP_REQUESTS");
+        for outpoint in deposits {
+            hasher.update(outpoint.txid.to_byte_array());
+            hasher.update(outpoint.vout.to_be_bytes());
+        }
+        for id in withdrawal_ids {
+            hasher.update(id.to_be_bytes());
+        }
+        let digest: [u8; 32] = hasher.finalize().into();
+
+        let mut request_digest = [0; 8];
+        request_digest.copy_from_slice(&digest[..8]);
+        request_digest
+    }
+
+    /// Whether the metadata commits to the given requests.
+    pub fn commits_to(&self, deposits: &[OutPoint], withdrawal_ids: &[u64]) -> bool {
+        self.request_digest == Self::request_digest(deposits, withdrawal_ids)
+    }
+
+    /// Encode the metadata for the OP_RETURN output.
+    fn encode(&self) -> [u8; SWEEP_METADATA_SIZE] {
+        let mut bytes = [0; SWEEP_METADATA_SIZE];
+        bytes[..8].copy_from_slice(&self.chain_tip_commitment);
+        bytes[8..].copy_from_slice(&self.request_digest);
+        bytes
+    }
+
+    /// Decode the metadata from the start of the given bytes, which
+    /// follow the OP_RETURN header.
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        let bytes = bytes.get(..SWEEP_METADATA_SIZE)?;
+        let mut metadata = Self {
+            chain_tip_commitment: [0; 8],
+            request_digest: [0; 8],
+        };
+        metadata.chain_tip_commitment.copy_from_slice(&bytes[..8]);
+        metadata.request_digest.copy_from_slice(&bytes[8..]);
+        Some(metadata)
+    }
+}
+
 /// A dummy Schnorr signature.
 static DUMMY_SIGNATURE: LazyLock<Signature> = LazyLock::new(|| Signature {
     signature: secp256k1::schnorr::Signature::from_slice(&[0; 64]).unwrap(),
@@ -131,31 +344,77 @@ pub trait GetFees {
     fn get_fees(&self) -> Result<Option<Fees>, Error>;
 }
 
+/// The rules for the signers paying for part of the bitcoin fee of small
+/// withdrawals.
+///
+/// A withdrawal whose max fee does not cover its share of the fees may
+/// still be swept if its amount is below the threshold. The shortfall is
+/// absorbed by the signers' UTXO, and the accept-withdrawal contract call
+/// only charges the user their max fee. The signers subsidize at most
+/// `budget` sats in a tenure.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WithdrawalFeeSubsidy {
+    /// Withdrawals of fewer than this many sats are eligible for a
+    /// subsidy.
+    pub threshold: u64,
+    /// The number of sats that are left for subsidizing fees.
+    pub budget: u64,
+}
+
+impl WithdrawalFeeSubsidy {
+    /// Subsidize the `shortfall` between the fee assessed to a withdrawal
+    /// of `amount` sats and its max fee, taking it out of the budget.
+    ///
+    /// Returns `false`, leaving the budget untouched, if the withdrawal is
+    /// not eligible or if the budget cannot cover the shortfall. There is
+    /// nothing to subsidize when the shortfall is zero.
+    pub fn try_subsidize(&mut self, amount: u64, shortfall: u64) -> bool {
+        if shortfall == 0 {
+            return true;
+        }
+        if amount >= self.threshold || shortfall > self.budget {
+            return false;
+        }
+        self.budget -= shortfall;
+        true
+    }
+}
+
 /// Filter out the deposit and withdrawal requests that do not meet the
 /// amount or fee requirements.
 pub struct RequestPreprocessor<'a> {
     /// The current sBTC limits on deposits and withdrawals.
     sbtc_limits: &'a SbtcLimits,
-    /// The current market fee rate in sat/vByte.
-    fee_rate: f64,
+    /// The current market fee rate.
+    fee_rate: FeeRate,
     /// The total fee amount and the fee rate for the last transaction that
     /// used this UTXO as an input.
     last_fees: Option<Fees>,
+    /// The rules for subsidizing the fees of small withdrawals.
+    fee_subsidy: WithdrawalFeeSubsidy,
 }
 
 impl<'a> RequestPreprocessor<'a> {
     /// Create a new [`DepositFilter`] instance.
-    pub fn new(sbtc_limits: &'a SbtcLimits, fee_rate: f64, last_fees: Option<Fees>) -> Self {
+    pub fn new(sbtc_limits: &'a SbtcLimits, fee_rate: FeeRate, last_fees: Option<Fees>) -> Self {
         Self {
             sbtc_limits,
             fee_rate,
             last_fees,
+            fee_subsidy: WithdrawalFeeSubsidy::default(),
         }
     }
 
+    /// Let the signers subsidize the fees of small withdrawals according
+    /// to the given rules.
+    pub fn with_fee_subsidy(mut self, fee_subsidy: WithdrawalFeeSubsidy) -> Self {
+        self.fee_subsidy = fee_subsidy;
+        self
+    }
+
     /// Validate deposit requests based on four constraints:
     /// 1. The user's max fee must be >= our minimum required fee for deposits
-    ///    (based on fixed deposit tx size)
+    ///    (based on the size of a transaction sweeping only the deposit)
     /// 2. The deposit amount must be greater than or equal to the per-deposit minimum
     /// 3. The deposit amount must be less than or equal to the per-deposit cap
     /// 4. The total amount being minted must stay under the peg cap
@@ -165,7 +424,7 @@ impl<'a> RequestPreprocessor<'a> {
         req: &'a DepositRequest,
     ) -> Option<RequestRef<'a>> {
         let minimum_fee =
-            compute_transaction_fee(SOLO_DEPOSIT_TX_VSIZE, self.fee_rate, self.last_fees);
+            compute_transaction_fee(req.solo_tx_vsize(), self.fee_rate, self.last_fees).to_sat();
 
         let is_fee_valid = req.max_fee.min(req.amount) >= minimum_fee;
         let is_above_dust = req.amount.saturating_sub(minimum_fee) >= DEPOSIT_DUST_LIMIT;
@@ -195,14 +454,19 @@ impl<'a> RequestPreprocessor<'a> {
     /// Validate withdrawal requests based on three constraints:
     /// 1. The user's max fee must be >= our minimum required fee for
     ///    withdrawals (based on the max transaction size for the allowed
-    ///    scriptPubKeys).
+    ///    scriptPubKeys), unless the signers can subsidize the shortfall.
     /// 2. The withdrawal amount must be less than or equal to the
     ///    per-withdrawal cap.
     /// 3. The total amount being withdrawn must stay under the rolling
     ///    withdrawal limits.
+    ///
+    /// The minimum fee is the fee for sweeping the withdrawal on its own,
+    /// which is never less than the fee that it is assessed when swept
+    /// with other requests, so the subsidy set aside here is enough.
     fn validate_withdrawal_amounts(
         &self,
         withdrawal_amounts: &mut u64,
+        fee_subsidy: &mut WithdrawalFeeSubsidy,
         req: &'a WithdrawalRequest,
     ) -> Option<RequestRef<'a>> {
         let rolling_limits = self.sbtc_limits.rolling_withdrawal_limits();
@@ -219,10 +483,16 @@ impl<'a> RequestPreprocessor<'a> {
         let is_above_minimum = req.script_pubkey.minimal_non_dust().to_sat() <= req.amount;
 
         let tx_vsize = BASE_WITHDRAWAL_TX_VSIZE + req.vsize() as f64;
-        let is_fee_valid =
-            req.max_fee >= compute_transaction_fee(tx_vsize, self.fee_rate, self.last_fees);
-
-        if is_within_rolling_limits && is_fee_valid && is_within_cap && is_above_minimum {
+        let minimum_fee = compute_transaction_fee(tx_vsize, self.fee_rate, self.last_fees);
+        let shortfall = minimum_fee.to_sat().saturating_sub(req.max_fee);
+
+        // We only set aside a subsidy for withdrawals that we would
+        // otherwise sweep.
+        if is_within_rolling_limits
+            && is_within_cap
+            && is_above_minimum
+            && fee_subsidy.try_subsidize(req.amount, shortfall)
+        {
             *withdrawal_amounts = new_cumulative_total;
             Some(RequestRef::Withdrawal(req))
         } else {
@@ -256,9 +526,12 @@ impl<'a> RequestPreprocessor<'a> {
 
         reqs.iter()
             .filter_map(RequestRef::as_withdrawal)
-            .scan(withdrawn_total, |withdrawal_amounts, req| {
-                Some(self.validate_withdrawal_amounts(withdrawal_amounts, req))
-            })
+            .scan(
+                (withdrawn_total, self.fee_subsidy),
+                |(withdrawal_amounts, fee_subsidy), req| {
+                    Some(self.validate_withdrawal_amounts(withdrawal_amounts, fee_subsidy, req))
+                },
+            )
             .flatten()
             .collect()
     }
@@ -270,8 +543,8 @@ impl<'a> RequestPreprocessor<'a> {
 pub struct SignerBtcState {
     /// The outstanding signer UTXO.
     pub utxo: SignerUtxo,
-    /// The current market fee rate in sat/vByte.
-    pub fee_rate: f64,
+    /// The current market fee rate.
+    pub fee_rate: FeeRate,
     /// The current public key of the signers
     pub public_key: XOnlyPublicKey,
     /// The total fee amount and the fee rate for the last transaction that
@@ -280,6 +553,21 @@ pub struct SignerBtcState {
     /// Two byte prefix for BTC transactions that are related to the Stacks
     /// blockchain.
     pub magic_bytes: [u8; 2],
+    /// The bitcoin chain tip that sweep transactions commit to in their
+    /// OP_RETURN output. Sweep transactions only include
+    /// [`SweepMetadata`] when this is set.
+    pub metadata_chain_tip: Option<BitcoinBlockHash>,
+}
+
+impl SignerBtcState {
+    /// The number of bytes in the OP_RETURN output that are available for
+    /// encoding withdrawal request IDs.
+    fn op_return_available_size(&self) -> usize {
+        match self.metadata_chain_tip {
+            Some(_) => OP_RETURN_AVAILABLE_SIZE - SWEEP_METADATA_SIZE,
+            None => OP_RETURN_AVAILABLE_SIZE,
+        }
+    }
 }
 
 /// The set of sBTC requests with additional relevant
@@ -305,6 +593,9 @@ pub struct SbtcRequests {
     /// that there is enough time for the signers to sign all the inputs
     /// during the tenure of a single bitcoin block.
     pub max_deposits_per_bitcoin_tx: u16,
+    /// The rules for the signers subsidizing the fees of small
+    /// withdrawals in this tenure.
+    pub withdrawal_fee_subsidy: WithdrawalFeeSubsidy,
 }
 
 impl SbtcRequests {
@@ -314,6 +605,23 @@ impl SbtcRequests {
     /// This function can fail if the output amounts are greater than the
     /// input amounts.
     pub fn construct_transactions(&self) -> Result<Vec<UnsignedTransaction>, Error> {
+        self.construct_transactions_by_locking_key(&HashSet::new())
+    }
+
+    /// Construct the next transaction package given requests and the
+    /// signers' UTXO, sweeping the deposits locked by each of the given
+    /// keys in transactions of their own.
+    ///
+    /// The transactions sweeping the deposits locked by the given keys
+    /// come after the ones for the rest of the requests. Since the
+    /// transactions in a package are signed in order, the signers can
+    /// still sweep the other requests when they cannot sign for the
+    /// deposits locked by one of these keys, say because the DKG shares
+    /// for it have not been verified yet.
+    pub fn construct_transactions_by_locking_key(
+        &self,
+        separate_keys: &HashSet<XOnlyPublicKey>,
+    ) -> Result<Vec<UnsignedTransaction>, Error> {
         if self.deposits.is_empty() && self.withdrawals.is_empty() {
             tracing::info!("No deposits or withdrawals so no BTC transaction");
             return Ok(Vec::new());
@@ -323,16 +631,51 @@ impl SbtcRequests {
             sbtc_limits: &self.sbtc_limits,
             fee_rate: self.signer_state.fee_rate,
             last_fees: self.signer_state.last_fees,
+            fee_subsidy: self.withdrawal_fee_subsidy,
         };
         let deposits = request_preprocessor.filter_deposits(&self.deposits);
         let withdrawals = request_preprocessor.preprocess_withdrawals(&self.withdrawals);
 
+        let is_separate = |req: &RequestRef| {
+            req.as_deposit()
+                .is_some_and(|deposit| separate_keys.contains(&deposit.signers_public_key))
+        };
+        let (separate_deposits, deposits): (Vec<_>, Vec<_>) =
+            deposits.into_iter().partition(is_separate);
+
         // Create a list of requests where each request can be approved on its own.
         let items = deposits.into_iter().chain(withdrawals);
 
         let max_votes_against = self.reject_capacity();
         let max_needs_signature = self.max_deposits_per_bitcoin_tx;
-        compute_optimal_packages(items, max_votes_against, max_needs_signature)
+        let max_op_return_size = self.signer_state.op_return_available_size();
+        let compute_packages = |items: Vec<_>| {
+            compute_optimal_packages(
+                items,
+                max_votes_against,
+                max_needs_signature,
+                max_op_return_size,
+            )
+        };
+
+        // The deposits locked by each of the separate keys are packaged on
+        // their own, in the order of the keys, so that the package is the
+        // same each time that it is constructed.
+        let mut separate_keys: Vec<_> = separate_keys.iter().collect();
+        separate_keys.sort();
+        let separate_packages = separate_keys.into_iter().flat_map(|key| {
+            let items = separate_deposits
+                .iter()
+                .filter(|req| {
+                    req.as_deposit().map(|deposit| &deposit.signers_public_key) == Some(key)
+                })
+                .copied()
+                .collect();
+            compute_packages(items)
+        });
+
+        compute_packages(items.collect())
+            .chain(separate_packages)
             .scan(self.signer_state, |state, request_refs| {
                 let requests = Requests::new(request_refs);
                 let tx = UnsignedTransaction::new(requests, state);
@@ -356,6 +699,127 @@ impl SbtcRequests {
     fn reject_capacity(&self) -> u32 {
         self.num_signers.saturating_sub(self.accept_threshold) as u32
     }
+
+    /// Estimate the fee that the given prospective deposit would be
+    /// charged if it were swept along with these requests.
+    ///
+    /// The deposit is packaged with the other requests exactly like the
+    /// coordinator would package it, so the deposit should have a unique
+    /// outpoint and the votes that it is expected to get.
+    pub fn simulate_deposit(&self, deposit: DepositRequest) -> Result<RequestFeeEstimate, Error> {
+        let outpoint = deposit.outpoint;
+        let max_fee = deposit.max_fee;
+        let state = &self.signer_state;
+        let minimum_fee =
+            compute_transaction_fee(deposit.solo_tx_vsize(), state.fee_rate, state.last_fees);
+
+        let requests = SbtcRequests {
+            deposits: self.deposits.iter().cloned().chain([deposit]).collect(),
+            withdrawals: self.withdrawals.clone(),
+            ..self.clone_without_requests()
+        };
+
+        let assessed_fee = requests
+            .construct_transactions()?
+            .iter()
+            .find_map(|tx| {
+                FeeAssessment::assess_input_fee(tx, &outpoint, Amount::from_sat(tx.tx_fee))
+            })
+            .map(Amount::to_sat);
+
+        Ok(RequestFeeEstimate::new(
+            minimum_fee.to_sat(),
+            assessed_fee,
+            max_fee,
+        ))
+    }
+
+    /// Estimate the fee that the given prospective withdrawal would be
+    /// charged if it were swept along with these requests.
+    ///
+    /// Like with [`SbtcRequests::simulate_deposit`], the withdrawal should
+    /// have a unique request ID and the votes that it is expected to get.
+    pub fn simulate_withdrawal(
+        &self,
+        withdrawal: WithdrawalRequest,
+    ) -> Result<RequestFeeEstimate, Error> {
+        let id = withdrawal.qualified_id();
+        let max_fee = withdrawal.max_fee;
+        let state = &self.signer_state;
+        let tx_vsize = BASE_WITHDRAWAL_TX_VSIZE + withdrawal.vsize() as f64;
+        let minimum_fee = compute_transaction_fee(tx_vsize, state.fee_rate, state.last_fees);
+
+        let requests = SbtcRequests {
+            deposits: self.deposits.clone(),
+            withdrawals: self
+                .withdrawals
+                .iter()
+                .cloned()
+                .chain([withdrawal])
+                .collect(),
+            ..self.clone_without_requests()
+        };
+
+        // The withdrawal outputs come right after the signers' UTXO and
+        // the OP_RETURN output, in the order of the requests.
+        let assessed_fee = requests
+            .construct_transactions()?
+            .iter()
+            .find_map(|tx| {
+                let index = tx
+                    .requests
+                    .iter()
+                    .filter_map(RequestRef::as_withdrawal)
+                    .position(|req| req.qualified_id() == id)?;
+                FeeAssessment::assess_output_fee(tx, index + 2, Amount::from_sat(tx.tx_fee))
+            })
+            .map(Amount::to_sat);
+
+        Ok(RequestFeeEstimate::new(
+            minimum_fee.to_sat(),
+            assessed_fee,
+            max_fee,
+        ))
+    }
+
+    /// Copy everything except for the deposit and withdrawal requests.
+    fn clone_without_requests(&self) -> Self {
+        SbtcRequests {
+            deposits: Vec::new(),
+            withdrawals: Vec::new(),
+            signer_state: self.signer_state,
+            accept_threshold: self.accept_threshold,
+            num_signers: self.num_signers,
+            sbtc_limits: self.sbtc_limits.clone(),
+            max_deposits_per_bitcoin_tx: self.max_deposits_per_bitcoin_tx,
+            withdrawal_fee_subsidy: self.withdrawal_fee_subsidy,
+        }
+    }
+}
+
+/// The fees that a prospective deposit or withdrawal request would be
+/// charged, as estimated by [`SbtcRequests::simulate_deposit`] and
+/// [`SbtcRequests::simulate_withdrawal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct RequestFeeEstimate {
+    /// The smallest max fee that the request can have under the current
+    /// fee conditions, which is the fee for sweeping it on its own.
+    pub minimum_fee: u64,
+    /// The fee that the request would be assessed in the next sweep, or
+    /// `None` if the request would not be part of it.
+    pub assessed_fee: Option<u64>,
+    /// Whether the max fee of the request covers the assessed fee.
+    pub max_fee_sufficient: bool,
+}
+
+impl RequestFeeEstimate {
+    fn new(minimum_fee: u64, assessed_fee: Option<u64>, max_fee: u64) -> Self {
+        Self {
+            minimum_fee,
+            assessed_fee,
+            max_fee_sufficient: assessed_fee.is_some_and(|fee| fee <= max_fee),
+        }
+    }
 }
 
 /// Calculate the total fee necessary for a transaction of the given size
@@ -384,18 +848,21 @@ impl SbtcRequests {
 ///
 /// RBF: https://bitcoinops.org/en/topics/replace-by-fee/
 /// BIP-125: https://github.com/bitcoin/bips/blob/master/bip-0125.mediawiki#implementation-details
-fn compute_transaction_fee(tx_vsize: f64, fee_rate: f64, last_fees: Option<Fees>) -> u64 {
+fn compute_transaction_fee(tx_vsize: f64, fee_rate: FeeRate, last_fees: Option<Fees>) -> Satoshis {
     match last_fees {
         Some(Fees { total, rate }) => {
             // The requirement for an RBF transaction is that the new fee
             // amount be greater than the old fee amount.
-            let minimum_fee_rate = fee_rate.max(rate + rate * SATS_PER_VBYTE_INCREMENT);
-            let fee_increment = tx_vsize * DEFAULT_INCREMENTAL_RELAY_FEE_RATE;
-            (total as f64 + fee_increment)
-                .max(tx_vsize * minimum_fee_rate)
-                .ceil() as u64
+            let bumped_rate =
+                FeeRate::new(rate + rate * SATS_PER_VBYTE_INCREMENT).unwrap_or(FeeRate::ZERO);
+            let minimum_fee = fee_rate.max(bumped_rate).fee_for_vsize(tx_vsize);
+            let fee_increment = FeeRate::new(DEFAULT_INCREMENTAL_RELAY_FEE_RATE)
+                .map_or(Satoshis::ZERO, |rate| rate.fee_for_vsize(tx_vsize));
+            Satoshis::from_sat(total)
+                .saturating_add(fee_increment)
+                .max(minimum_fee)
         }
-        None => (tx_vsize * fee_rate).ceil() as u64,
+        None => fee_rate.fee_for_vsize(tx_vsize),
     }
 }
 
@@ -446,6 +913,26 @@ impl DepositRequest {
         }
     }
 
+    /// The weight of the input that spends the deposit UTXO with the
+    /// deposit script. It is computed from the sizes of the signature,
+    /// the deposit script, and the control block in the witness, so it
+    /// is the weight of the signed input.
+    pub fn input_weight(&self) -> Weight {
+        let witness = witness_weight(&[
+            TAPROOT_SIGNATURE_SIZE,
+            self.deposit_script.len(),
+            DEPOSIT_CONTROL_BLOCK_SIZE,
+        ]);
+        TX_IN_BASE_WEIGHT + witness
+    }
+
+    /// The virtual size of a sweep transaction that services only this
+    /// deposit request.
+    fn solo_tx_vsize(&self) -> f64 {
+        let weight = *SOLO_DEPOSIT_BASE_TX_WEIGHT + self.input_weight();
+        weight.to_vbytes_ceil() as f64
+    }
+
     /// Construct the deposit UTXO associated with this deposit request.
     fn as_tx_out(&self) -> TxOut {
         let ver = LeafVersion::TapScript;
@@ -494,6 +981,30 @@ impl DepositRequest {
         Witness::from_slice(&witness_data)
     }
 
+    /// Construct the PSBT input for spending the deposit UTXO with the
+    /// deposit script, with the taproot scripts of both spend paths.
+    fn as_psbt_input(&self) -> psbt::Input {
+        let ver = LeafVersion::TapScript;
+        let taproot = self.construct_taproot_info(ver);
+
+        let tap_scripts = [&self.deposit_script, &self.reclaim_script]
+            .into_iter()
+            .filter_map(|script| {
+                let control_block = taproot.control_block(&(script.clone(), ver))?;
+                Some((control_block, (script.clone(), ver)))
+            })
+            .collect();
+
+        psbt::Input {
+            witness_utxo: Some(self.as_tx_out()),
+            sighash_type: Some(TapSighashType::All.into()),
+            tap_internal_key: Some(taproot.internal_key()),
+            tap_merkle_root: taproot.merkle_root(),
+            tap_scripts,
+            ..Default::default()
+        }
+    }
+
     /// Constructs the taproot spending information for the UTXO associated
     /// with this deposit request.
     fn construct_taproot_info(&self, ver: LeafVersion) -> TaprootSpendInfo {
@@ -535,9 +1046,7 @@ impl Weighted for DepositRequest {
         self.signer_bitmap.load_le()
     }
     fn vsize(&self) -> u64 {
-        self.as_tx_input(*DUMMY_SIGNATURE)
-            .segwit_weight()
-            .to_vbytes_ceil()
+        self.input_weight().to_vbytes_ceil()
     }
 }
 
@@ -747,6 +1256,12 @@ impl SignerUtxo {
         }
     }
 
+    /// The weight of the input that spends the signers' UTXO. It is a
+    /// key-spend, so the witness only has the signature.
+    pub fn input_weight() -> Weight {
+        TX_IN_BASE_WEIGHT + witness_weight(&[TAPROOT_SIGNATURE_SIZE])
+    }
+
     /// Construct the UTXO associated with this outpoint.
     fn as_tx_output(&self) -> TxOut {
         Self::new_tx_output(self.public_key, self.amount)
@@ -778,6 +1293,49 @@ pub struct UnsignedMockTransaction {
     utxo: SignerUtxo,
 }
 
+/// A transaction that returns a payment to the signers' scriptPubKey to
+/// the sender.
+///
+/// The Bitcoin transaction has the following layout:
+/// 1. The only input spends the output that paid the signers.
+/// 2. The only output pays the sender the amount of the payment, less
+///    the transaction fee.
+///
+/// The refund does not touch the signers' UTXO, and since its output is
+/// not locked by the signers, it cannot be mistaken for a sweep
+/// transaction.
+#[derive(Debug, Clone)]
+pub struct UnsignedRefundTransaction {
+    /// The Bitcoin transaction that needs to be signed.
+    pub tx: Transaction,
+    /// The output that paid the signers, which is spent by this
+    /// transaction.
+    pub utxo: SignerUtxo,
+    /// The fee paid by the transaction.
+    pub tx_fee: u64,
+}
+
+/// The emergency recovery sweep of the outputs locked by the signers'
+/// key to the recovery scriptPubKey that the operators agreed on. See
+/// [`crate::emergency_recovery`] for when the signers sign it.
+///
+/// The Bitcoin transaction has the following layout:
+/// 1. The first input spends the signers' UTXO, and the other inputs
+///    spend the other payments to the signers' scriptPubKey.
+/// 2. The only output pays the recovery scriptPubKey the amount of all
+///    of the inputs, less the transaction fee.
+#[derive(Debug, Clone)]
+pub struct UnsignedRecoveryTransaction {
+    /// The Bitcoin transaction that needs to be signed.
+    pub tx: Transaction,
+    /// The outputs locked by the signers' key that are spent by this
+    /// transaction, in the order of the inputs. The first one is the
+    /// signers' UTXO.
+    pub utxos: Vec<SignerUtxo>,
+    /// The fee paid by the transaction.
+    pub tx_fee: u64,
+}
+
 /// Given a set of requests, create a BTC transaction that can be signed.
 ///
 /// This BTC transaction in this struct has correct amounts but no witness
@@ -956,6 +1514,171 @@ impl UnsignedMockTransaction {
     }
 }
 
+impl UnsignedRefundTransaction {
+    /// Construct the refund of the given payment to the signers' UTXO,
+    /// paying the given fee rate.
+    ///
+    /// This fails if the payment is not locked by the given aggregate key,
+    /// if we do not know who sent it, or if the payment is too small to
+    /// pay for the fee and still leave a non-dust output.
+    pub fn new(
+        payment: &model::SignerPayment,
+        aggregate_key: XOnlyPublicKey,
+        fee_rate: FeeRate,
+    ) -> Result<Self, Error> {
+        let outpoint = payment.outpoint();
+        if *payment.script_pubkey != aggregate_key.signers_script_pubkey() {
+            return Err(Error::RefundAggregateKeyMismatch(outpoint, aggregate_key));
+        }
+        let sender = payment
+            .sender_script_pubkey
+            .clone()
+            .ok_or(Error::RefundSenderUnknown(outpoint))?;
+
+        let utxo = SignerUtxo {
+            outpoint,
+            amount: payment.amount,
+            public_key: aggregate_key,
+        };
+
+        let mut tx = Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![utxo.as_tx_input(&DUMMY_SIGNATURE)],
+            output: vec![TxOut {
+                value: Amount::from_sat(payment.amount),
+                script_pubkey: sender.into(),
+            }],
+        };
+
+        let tx_vsize = tx.vsize() as f64;
+        let tx_fee = compute_transaction_fee(tx_vsize, fee_rate, None).to_sat();
+        let amount = payment.amount.saturating_sub(tx_fee);
+        if amount < tx.output[0].script_pubkey.minimal_non_dust().to_sat() {
+            return Err(Error::RefundAmountTooLow {
+                outpoint,
+                amount: payment.amount,
+                fee: tx_fee,
+            });
+        }
+
+        tx.output[0].value = Amount::from_sat(amount);
+        tx.input[0].witness = Witness::new();
+
+        Ok(Self { tx, utxo, tx_fee })
+    }
+
+    /// Gets the sighash for the input which needs to be signed before the
+    /// transaction can be broadcast.
+    pub fn compute_sighash(&self) -> Result<TapSighash, Error> {
+        let prevouts = [self.utxo.as_tx_output()];
+        let mut sighasher = SighashCache::new(&self.tx);
+
+        sighasher
+            .taproot_key_spend_signature_hash(0, &Prevouts::All(&prevouts), TapSighashType::All)
+            .map_err(Into::into)
+    }
+
+    /// Get the signature hash of the input of the transaction.
+    pub fn signature_hash(&self) -> Result<SignatureHash, Error> {
+        Ok(SignatureHash {
+            txid: self.tx.compute_txid(),
+            outpoint: self.utxo.outpoint,
+            sighash: self.compute_sighash()?,
+            prevout_type: TxPrevoutType::SignersInput,
+            aggregate_key: self.utxo.public_key,
+        })
+    }
+}
+
+impl UnsignedRecoveryTransaction {
+    /// Construct the sweep of the given signers' UTXO, along with the
+    /// given other outputs locked by the signers' key, to the given
+    /// recovery scriptPubKey, paying the given fee rate.
+    ///
+    /// This fails if the outputs are too small to pay for the fee and
+    /// still leave a non-dust output.
+    pub fn new(
+        utxo: SignerUtxo,
+        payments: Vec<SignerUtxo>,
+        recovery_script_pubkey: ScriptBuf,
+        fee_rate: FeeRate,
+    ) -> Result<Self, Error> {
+        let utxos: Vec<SignerUtxo> = std::iter::once(utxo).chain(payments).collect();
+        let total_amount: u64 = utxos.iter().map(|utxo| utxo.amount).sum();
+
+        let mut tx = Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: utxos
+                .iter()
+                .map(|utxo| utxo.as_tx_input(&DUMMY_SIGNATURE))
+                .collect(),
+            output: vec![TxOut {
+                value: Amount::from_sat(total_amount),
+                script_pubkey: recovery_script_pubkey,
+            }],
+        };
+
+        let tx_vsize = tx.vsize() as f64;
+        let tx_fee = compute_transaction_fee(tx_vsize, fee_rate, None).to_sat();
+        let amount = total_amount.saturating_sub(tx_fee);
+        if amount < tx.output[0].script_pubkey.minimal_non_dust().to_sat() {
+            return Err(Error::EmergencyRecoveryAmountTooLow {
+                outpoint: utxo.outpoint,
+                amount: total_amount,
+                fee: tx_fee,
+            });
+        }
+
+        tx.output[0].value = Amount::from_sat(amount);
+        tx.input
+            .iter_mut()
+            .for_each(|tx_in| tx_in.witness = Witness::new());
+
+        Ok(Self { tx, utxos, tx_fee })
+    }
+
+    /// Gets the sighashes for the inputs, in order, which need to be
+    /// signed before the transaction can be broadcast.
+    pub fn compute_sighashes(&self) -> Result<Vec<TapSighash>, Error> {
+        let prevouts: Vec<TxOut> = self.utxos.iter().map(SignerUtxo::as_tx_output).collect();
+        let mut sighasher = SighashCache::new(&self.tx);
+
+        (0..prevouts.len())
+            .map(|index| {
+                sighasher
+                    .taproot_key_spend_signature_hash(
+                        index,
+                        &Prevouts::All(&prevouts),
+                        TapSighashType::All,
+                    )
+                    .map_err(Into::into)
+            })
+            .collect()
+    }
+
+    /// Get the signature hashes of the inputs of the transaction, in
+    /// order.
+    pub fn signature_hashes(&self) -> Result<Vec<SignatureHash>, Error> {
+        let txid = self.tx.compute_txid();
+        let sighashes = self.compute_sighashes()?;
+
+        Ok(self
+            .utxos
+            .iter()
+            .zip(sighashes)
+            .map(|(utxo, sighash)| SignatureHash {
+                txid,
+                outpoint: utxo.outpoint,
+                sighash,
+                prevout_type: TxPrevoutType::SignersInput,
+                aggregate_key: utxo.public_key,
+            })
+            .collect())
+    }
+}
+
 impl<'a> UnsignedTransaction<'a> {
     /// Construct an unsigned transaction.
     ///
@@ -1005,7 +1728,8 @@ impl<'a> UnsignedTransaction<'a> {
         // We now compute the total fees for the transaction.
         let tx_vsize: u32 = tx.vsize().try_into().map_err(|_| Error::TypeConversion)?;
 
-        let tx_fee = compute_transaction_fee(tx_vsize as f64, state.fee_rate, state.last_fees);
+        let tx_fee =
+            compute_transaction_fee(tx_vsize as f64, state.fee_rate, state.last_fees).to_sat();
         // Now adjust the amount for the signers UTXO for the transaction
         // fee.
         Self::adjust_amounts(&mut tx, tx_fee);
@@ -1079,6 +1803,37 @@ impl<'a> UnsignedTransaction<'a> {
         })
     }
 
+    /// Construct a PSBT of this transaction, for review by tools outside
+    /// of the signer.
+    ///
+    /// Each input of the PSBT has the UTXO that it spends and the taproot
+    /// information needed to compute its signature hash. The signers'
+    /// input is spent using the key-spend path, and each deposit input is
+    /// spent using the deposit script.
+    pub fn to_psbt(&self) -> Result<Psbt, Error> {
+        let mut psbt = Psbt::from_unsigned_tx(self.tx.clone()).map_err(Error::PsbtConstruction)?;
+
+        let signer_input = psbt::Input {
+            witness_utxo: Some(self.signer_utxo.utxo.as_tx_output()),
+            sighash_type: Some(TapSighashType::All.into()),
+            tap_internal_key: Some(self.signer_utxo.utxo.public_key),
+            ..Default::default()
+        };
+        let deposit_inputs = self
+            .requests
+            .iter()
+            .filter_map(RequestRef::as_deposit)
+            .map(DepositRequest::as_psbt_input);
+
+        // The signers' UTXO is always the first input, followed by the
+        // deposit inputs in the order of the requests.
+        psbt.inputs = std::iter::once(signer_input)
+            .chain(deposit_inputs)
+            .collect();
+
+        Ok(psbt)
+    }
+
     /// Compute the sum of the input amounts of the transaction
     pub fn input_amounts(&self) -> u64 {
         self.requests
@@ -1143,14 +1898,17 @@ impl<'a> UnsignedTransaction<'a> {
     /// The layout of the OP_RETURN output is as follows:
     ///
     /// ```text
-    ///  0       2    3                                           X<80
-    ///  |-------|----|--------------------------------------------|
-    ///    magic   op   [encoded withdrawal IDs (variable-length)]
+    ///  0       2    3              19                            X<80
+    ///  |-------|----|--------------|-----------------------------|
+    ///    magic   op   [metadata]     [encoded withdrawal IDs]
     /// ```
     ///
     /// In the above layout:
     /// - magic: UTF-8 encoded string indicator (2 bytes)
-    /// - op: version byte (1 byte)
+    /// - op: version byte (1 byte), which is 2 if the metadata is included
+    ///   and 1 otherwise
+    /// - metadata: the [`SweepMetadata`] of the transaction (16 bytes, if
+    ///   the signers are configured to include it)
     /// - encoded IDs: withdrawal request IDs encoded using idpack (variable
     ///   length, if there are withdrawals serviced by the transaction)
     ///
@@ -1160,7 +1918,13 @@ impl<'a> UnsignedTransaction<'a> {
         // Create OP_RETURN data
         let mut data = PushBytesBuf::with_capacity(OP_RETURN_MAX_SIZE);
         data.extend_from_slice(&state.magic_bytes)?;
-        data.push(OP_RETURN_VERSION)?;
+        match state.metadata_chain_tip {
+            Some(chain_tip) => {
+                data.push(OP_RETURN_METADATA_VERSION)?;
+                data.extend_from_slice(&SweepMetadata::new(&chain_tip, reqs).encode())?;
+            }
+            None => data.push(OP_RETURN_VERSION)?,
+        }
 
         // Extract all withdrawal request IDs
         let withdrawal_ids: Vec<u64> = reqs.iter().filter_map(|req| req.withdrawal_id()).collect();
@@ -1382,6 +2146,37 @@ impl BitcoinTxInfo {
     pub fn assess_output_fee(&self, vout: usize) -> Option<Amount> {
         FeeAssessment::assess_output_fee(self, vout, self.fee?)
     }
+
+    /// Return the payments to the signers made by this transaction, given
+    /// the outputs of this transaction that are related to the signers.
+    ///
+    /// Every donation output is a payment, and it is up to the operator
+    /// to decide whether it was an intended donation. The sender is only
+    /// known if all inputs of the transaction were locked by the same
+    /// scriptPubKey.
+    pub fn to_signer_payments(&self, tx_outputs: &[TxOutput]) -> Vec<model::SignerPayment> {
+        let mut prevout_scripts = (0..self.inputs().len())
+            .map(|index| self.prevout(index).map(|prevout| prevout.script_pubkey));
+        let sender_script_pubkey = match prevout_scripts.next() {
+            Some(Some(first)) if prevout_scripts.all(|script| script == Some(first)) => {
+                Some(ScriptPubKey::from(first.clone()))
+            }
+            _ => None,
+        };
+
+        tx_outputs
+            .iter()
+            .filter(|output| output.output_type == TxOutputType::Donation)
+            .map(|output| model::SignerPayment {
+                txid: output.txid,
+                output_index: output.output_index,
+                amount: output.amount,
+                script_pubkey: output.script_pubkey.clone(),
+                sender_script_pubkey: sender_script_pubkey.clone(),
+                status: model::SignerPaymentStatus::Unreviewed,
+            })
+            .collect()
+    }
 }
 
 /// An output used as an input into a transaction, a previous output.
@@ -1407,6 +2202,9 @@ pub trait TxDeconstructor: BitcoinInputsOutputs {
     /// otherwise.
     fn prevout(&self, index: usize) -> Option<PrevoutRef>;
 
+    /// Returns the fee paid by this transaction, if it is known.
+    fn tx_fee(&self) -> Option<Amount>;
+
     /// Return all inputs in this transaction if it is an sBTC transaction.
     ///
     /// This function returns an empty vector if it was not generated by
@@ -1420,13 +2218,22 @@ pub trait TxDeconstructor: BitcoinInputsOutputs {
         };
 
         // This is a transaction that the signers have created. It follows
-        // a layout described in the description of `UnsignedTransaction`.
+        // a layout described in the description of `UnsignedTransaction`,
+        // except for the emergency recovery sweep, whose other inputs are
+        // also locked by the signers' key.
         self.inputs()
             .iter()
             .enumerate()
-            .filter_map(|(index, _)| match index {
-                0 => self.vin_to_prevout(index, TxPrevoutType::SignersInput),
-                _ => self.vin_to_prevout(index, TxPrevoutType::Deposit),
+            .filter_map(|(index, _)| {
+                let is_signers_input = index == 0
+                    || self.prevout(index).is_some_and(|prevout| {
+                        signer_script_pubkeys.contains(prevout.script_pubkey)
+                    });
+                if is_signers_input {
+                    self.vin_to_prevout(index, TxPrevoutType::SignersInput)
+                } else {
+                    self.vin_to_prevout(index, TxPrevoutType::Deposit)
+                }
             })
             .collect()
     }
@@ -1465,6 +2272,14 @@ pub trait TxDeconstructor: BitcoinInputsOutputs {
                 .collect();
         }
 
+        // Refunds are the only transactions that the signers create with
+        // a single output that is not locked by the signers. They follow
+        // the layout described in `UnsignedRefundTransaction`, and none of
+        // their outputs belong to the signers.
+        if self.is_signer_refund(signer_script_pubkeys) {
+            return Vec::new();
+        }
+
         self.outputs()
             .iter()
             .enumerate()
@@ -1535,19 +2350,21 @@ pub trait TxDeconstructor: BitcoinInputsOutputs {
         // SAFETY: 2 < OP_RETURN_HEADER_SIZE (3)
         let version = raw_bytes[2];
 
-        if version == 0 {
-            // In version 0 we didn't store withdrawal ids
-            return Ok(Vec::new());
-        } else if version != OP_RETURN_VERSION {
-            // Unknown version byte
-            return Err(Error::SbtcTxOpReturnFormatError);
-        }
-
         // SAFETY: We've verified raw_bytes.len() >= OP_RETURN_HEADER_SIZE (3),
         // so starting a slice at index 3 is safe due to slice behavior.
         // If raw_bytes.len() is exactly 3, this produces an empty slice rather
         // than panicking.
-        let encoded_withdrawal_ids = &raw_bytes[OP_RETURN_HEADER_SIZE..];
+        let encoded_withdrawal_ids = match version {
+            // In version 0 we didn't store withdrawal ids
+            0 => return Ok(Vec::new()),
+            OP_RETURN_VERSION => &raw_bytes[OP_RETURN_HEADER_SIZE..],
+            // The sweep metadata comes before the withdrawal ids.
+            OP_RETURN_METADATA_VERSION => raw_bytes
+                .get(OP_RETURN_HEADER_SIZE + SWEEP_METADATA_SIZE..)
+                .ok_or(Error::SbtcTxOpReturnFormatError)?,
+            // Unknown version byte
+            _ => return Err(Error::SbtcTxOpReturnFormatError),
+        };
         let withdrawal_ids: Vec<_> = Segments::decode(encoded_withdrawal_ids)
             .map_err(Error::IdPackDecode)?
             .values()
@@ -1570,6 +2387,30 @@ pub trait TxDeconstructor: BitcoinInputsOutputs {
             .collect())
     }
 
+    /// Return the [`SweepMetadata`] in the OP_RETURN output of this
+    /// transaction, if it is a sweep transaction that includes it.
+    ///
+    /// The given outputs must be the ones returned by
+    /// [`TxDeconstructor::to_tx_outputs`].
+    fn to_sweep_metadata(&self, tx_outputs: &[TxOutput]) -> Option<SweepMetadata> {
+        let op_return_output = tx_outputs
+            .get(1)
+            .filter(|output| output.output_type == TxOutputType::SignersOpReturn)?;
+
+        let mut instructions = op_return_output.script_pubkey.as_script().instructions();
+        let (Some(Ok(Instruction::Op(OP_RETURN))), Some(Ok(Instruction::PushBytes(push_bytes)))) =
+            (instructions.next(), instructions.next())
+        else {
+            return None;
+        };
+
+        let raw_bytes = push_bytes.as_bytes();
+        if raw_bytes.get(2) != Some(&OP_RETURN_METADATA_VERSION) {
+            return None;
+        }
+        SweepMetadata::decode(&raw_bytes[OP_RETURN_HEADER_SIZE..])
+    }
+
     /// Take an output index and the known output type and return the
     /// output.
     fn vout_to_output(&self, index: usize, output_type: TxOutputType) -> Option<TxOutput> {
@@ -1584,8 +2425,18 @@ pub trait TxDeconstructor: BitcoinInputsOutputs {
     }
 
     /// Take an input index and the known output type and return a prevout.
+    ///
+    /// Deposit prevouts carry the part of the transaction fee that was
+    /// assessed to them, if the fee of the transaction is known.
     fn vin_to_prevout(&self, index: usize, input_type: TxPrevoutType) -> Option<TxPrevout> {
         let prevout = self.prevout(index)?;
+        let assessed_fee = match input_type {
+            TxPrevoutType::Deposit => self.tx_fee().and_then(|tx_fee| {
+                let outpoint = OutPoint::new(*prevout.txid, prevout.output_index);
+                FeeAssessment::assess_input_fee(self, &outpoint, tx_fee)
+            }),
+            TxPrevoutType::SignersInput => None,
+        };
         Some(TxPrevout {
             txid: self.tx_ref().compute_txid().into(),
             prevout_txid: BitcoinTxId::from(*prevout.txid),
@@ -1593,6 +2444,7 @@ pub trait TxDeconstructor: BitcoinInputsOutputs {
             script_pubkey: prevout.script_pubkey.clone().into(),
             amount: prevout.amount.to_sat(),
             prevout_type: input_type,
+            assessed_fee: assessed_fee.map(Amount::to_sat),
         })
     }
 
@@ -1608,6 +2460,21 @@ pub trait TxDeconstructor: BitcoinInputsOutputs {
 
         signer_script_pubkeys.contains(signer_input.script_pubkey)
     }
+
+    /// Whether this transaction is a refund of a payment to the signers.
+    ///
+    /// Sweep transactions always have at least two outputs, the signers'
+    /// output and the `OP_RETURN` output, while refunds have a single
+    /// output paying the sender.
+    fn is_signer_refund(&self, signer_script_pubkeys: &HashSet<ScriptBuf>) -> bool {
+        match self.outputs() {
+            [tx_out] => {
+                self.is_signer_created(signer_script_pubkeys)
+                    && !signer_script_pubkeys.contains(&tx_out.script_pubkey)
+            }
+            _ => false,
+        }
+    }
 }
 
 impl TxDeconstructor for BitcoinTxInfo {
@@ -1621,6 +2488,10 @@ impl TxDeconstructor for BitcoinTxInfo {
             output_index: vin.vout?,
         })
     }
+
+    fn tx_fee(&self) -> Option<Amount> {
+        self.fee
+    }
 }
 
 #[cfg(test)]
@@ -1630,6 +2501,9 @@ mod tests {
     use std::sync::atomic::AtomicU64;
 
     use super::*;
+    use crate::bitcoin::rpc::BitcoinTxVin;
+    use crate::bitcoin::rpc::BitcoinTxVinPrevout;
+    use crate::bitcoin::rpc::OutputScriptPubKey;
     use bitcoin::CompressedPublicKey;
     use bitcoin::Txid;
     use bitcoin::hashes::Hash as _;
@@ -1658,11 +2532,20 @@ mod tests {
     /// The maximum virtual size of a transaction package in v-bytes.
     const MEMPOOL_MAX_PACKAGE_SIZE: u32 = 101000;
 
+    /// The virtual size of a sweep transaction that services only the
+    /// deposit returned by [`create_deposit`], which has the largest
+    /// deposit script.
+    const SOLO_DEPOSIT_TX_VSIZE: f64 = 249.0;
+
     const X_ONLY_PUBLIC_KEY1: &str =
         "2e58afe51f9ed8ad3cc7897f634d881fdbe49a81564629ded8156bebd2ffd1af";
 
     static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(0);
 
+    fn fee_rate(sats_per_vbyte: f64) -> FeeRate {
+        FeeRate::new(sats_per_vbyte).unwrap()
+    }
+
     fn generate_x_only_public_key() -> XOnlyPublicKey {
         let secret_key = SecretKey::new(&mut OsRng);
         secret_key.x_only_public_key(SECP256K1).0
@@ -1775,6 +2658,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn sweep_template_versions_round_trip() {
+        for version in SweepTemplateVersion::ALL {
+            assert_eq!(
+                SweepTemplateVersion::try_from(u32::from(version)).unwrap(),
+                version
+            );
+        }
+        assert!(SweepTemplateVersion::try_from(0).is_err());
+        assert!(SweepTemplateVersion::try_from(3).is_err());
+
+        let chain_tip = BitcoinBlockHash::from([1; 32]);
+        assert_eq!(SweepTemplateVersion::V1.metadata_chain_tip(chain_tip), None);
+        assert_eq!(
+            SweepTemplateVersion::V2.metadata_chain_tip(chain_tip),
+            Some(chain_tip)
+        );
+    }
+
     /// This test verifies that our implementation of Bitcoin script
     /// verification using [`bitcoinconsensus`] works as expected. This
     /// functionality is used in the verification of WSTS signing after a new
@@ -1869,15 +2771,17 @@ mod tests {
                     amount: 550_000_000,
                     public_key: generate_x_only_public_key(),
                 },
-                fee_rate: 5.0,
+                fee_rate: fee_rate(5.0),
                 public_key: generate_x_only_public_key(),
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 2,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
         let keypair = Keypair::new_global(&mut OsRng);
 
@@ -1892,6 +2796,12 @@ mod tests {
             unsigned.tx.vsize(),
             "solo deposit vsize needs updating"
         );
+        let deposit = &requests.deposits[0];
+        assert_eq!(deposit.solo_tx_vsize(), SOLO_DEPOSIT_TX_VSIZE);
+        assert_eq!(
+            *SOLO_DEPOSIT_BASE_TX_WEIGHT + deposit.input_weight(),
+            unsigned.tx.weight()
+        );
 
         // For solo withdrawals. We set the withdrawal ID to be u64::MAX so
         // that the withdrawal ID encoding takes up the maximum amount of
@@ -1918,18 +2828,38 @@ mod tests {
         );
     }
 
-    #[ignore = "this is for generating the MIN_BITCOIN_INPUT_VSIZE constant"]
     #[test]
-    fn create_taproot_utxo_min_size() {
-        // This generates a UTXO with the same vsize as the signers input
-        // or donation. This is smaller than the min deposit vsize.
+    fn computed_input_weights_match_signed_inputs() {
+        // The signers' input, and any donation, is a key-spend taproot
+        // input. This is smaller than the min deposit vsize.
         let utxo = TxIn {
             previous_output: OutPoint::null(),
             sequence: Sequence::ZERO,
             witness: Witness::p2tr_key_spend(&DUMMY_SIGNATURE),
             script_sig: ScriptBuf::new(),
         };
-        println!("Min input vsize: {}", utxo.segwit_weight().to_vbytes_ceil());
+        assert_eq!(SignerUtxo::input_weight(), utxo.segwit_weight());
+        assert_eq!(
+            SignerUtxo::input_weight().to_vbytes_ceil(),
+            crate::MIN_BITCOIN_INPUT_VSIZE
+        );
+
+        // Deposit scripts vary in size with the recipient, and so does the
+        // weight of the deposit input.
+        let mut deposit = create_deposit(10_000, 10_000, 0);
+        let input = deposit.as_tx_input(*DUMMY_SIGNATURE);
+        assert_eq!(deposit.input_weight(), input.segwit_weight());
+
+        deposit.deposit_script = DepositScriptInputs {
+            signers_public_key: deposit.signers_public_key,
+            max_fee: 10_000,
+            recipient: PrincipalData::parse(&StacksAddress::burn_address(false).to_string())
+                .unwrap(),
+        }
+        .deposit_script();
+        let input = deposit.as_tx_input(*DUMMY_SIGNATURE);
+        assert_eq!(deposit.input_weight(), input.segwit_weight());
+        more_asserts::assert_lt!(deposit.solo_tx_vsize(), SOLO_DEPOSIT_TX_VSIZE);
     }
 
     #[test_case(&[true, true, false, true, false, false, false], 3; "case 1")]
@@ -1982,6 +2912,78 @@ mod tests {
         assert!(tx_in.script_sig.is_empty());
     }
 
+    #[test]
+    fn deposits_locked_by_separate_keys_are_swept_on_their_own() {
+        let deposits = vec![
+            create_deposit(123456, 0, 0),
+            create_deposit(234567, 0, 0),
+            create_deposit(345678, 0, 0),
+        ];
+        let separate_key = deposits[1].signers_public_key;
+        let requests = SbtcRequests {
+            deposits: deposits.clone(),
+            withdrawals: vec![create_withdrawal(1000, 0, 0)],
+            signer_state: SignerBtcState {
+                utxo: SignerUtxo {
+                    outpoint: generate_outpoint(5500, 0),
+                    amount: 5500,
+                    public_key: generate_x_only_public_key(),
+                },
+                fee_rate: fee_rate(0.0),
+                public_key: generate_x_only_public_key(),
+                last_fees: None,
+                magic_bytes: [0; 2],
+                metadata_chain_tip: None,
+            },
+            num_signers: 10,
+            accept_threshold: 0,
+            sbtc_limits: SbtcLimits::unlimited(),
+            max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
+        };
+
+        // Everything fits in one transaction when the deposits can be
+        // swept together.
+        let transactions = requests.construct_transactions().unwrap();
+        assert_eq!(transactions.len(), 1);
+
+        let separate_keys = HashSet::from([separate_key]);
+        let transactions = requests
+            .construct_transactions_by_locking_key(&separate_keys)
+            .unwrap();
+        assert_eq!(transactions.len(), 2);
+
+        // The deposit locked by the separate key is swept last, in a
+        // transaction of its own that spends the signers' UTXO created by
+        // the first one.
+        let [first, second] = transactions.as_slice() else {
+            panic!("BUG: there should be exactly two transactions");
+        };
+        let first_outpoints: HashSet<OutPoint> = first
+            .requests
+            .iter()
+            .filter_map(RequestRef::as_deposit)
+            .map(|deposit| deposit.outpoint)
+            .collect();
+        let expected = HashSet::from([deposits[0].outpoint, deposits[2].outpoint]);
+        assert_eq!(first_outpoints, expected);
+        assert_eq!(
+            first
+                .requests
+                .iter()
+                .filter_map(RequestRef::as_withdrawal)
+                .count(),
+            1
+        );
+
+        assert_eq!(second.requests.len(), 1);
+        assert_eq!(second.requests[0].as_deposit(), Some(&deposits[1]));
+        assert_eq!(
+            second.tx.input[0].previous_output,
+            first.new_signer_utxo().outpoint
+        );
+    }
+
     /// The first input and output are related to the signers' UTXO. The
     /// second output is a data output.
     #[test]
@@ -1995,15 +2997,17 @@ mod tests {
                     amount: 5500,
                     public_key: generate_x_only_public_key(),
                 },
-                fee_rate: 0.0,
+                fee_rate: fee_rate(0.0),
                 public_key: generate_x_only_public_key(),
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 0,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         // This should all be in one transaction since there are no votes
@@ -2048,6 +3052,66 @@ mod tests {
         assert_eq!(new_utxo.public_key, requests.signer_state.public_key);
     }
 
+    #[test]
+    fn simulated_deposit_fees_follow_the_packaging() {
+        let requests = SbtcRequests {
+            deposits: vec![create_deposit(100_000, 50_000, 0)],
+            withdrawals: vec![create_withdrawal(10_000, 50_000, 0)],
+            signer_state: SignerBtcState {
+                utxo: SignerUtxo {
+                    outpoint: generate_outpoint(5500, 0),
+                    amount: 5500,
+                    public_key: generate_x_only_public_key(),
+                },
+                fee_rate: fee_rate(5.0),
+                public_key: generate_x_only_public_key(),
+                last_fees: None,
+                magic_bytes: [0; 2],
+                metadata_chain_tip: None,
+            },
+            num_signers: 10,
+            accept_threshold: 8,
+            sbtc_limits: SbtcLimits::unlimited(),
+            max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
+        };
+
+        // A deposit that shares the sweep with other requests pays for
+        // its own weight, which is less than the fee of a solo sweep.
+        let estimate = requests
+            .simulate_deposit(create_deposit(200_000, 50_000, 0))
+            .unwrap();
+        let assessed_fee = estimate.assessed_fee.unwrap();
+        assert!(assessed_fee > 0);
+        assert!(assessed_fee <= estimate.minimum_fee);
+        assert!(estimate.max_fee_sufficient);
+
+        // A deposit whose max fee is below the minimum is not swept.
+        let estimate = requests
+            .simulate_deposit(create_deposit(200_000, 1, 0))
+            .unwrap();
+        assert!(estimate.minimum_fee > 1);
+        assert_eq!(estimate.assessed_fee, None);
+        assert!(!estimate.max_fee_sufficient);
+
+        // The simulation does not change the pending requests.
+        assert_eq!(requests.deposits.len(), 1);
+
+        let estimate = requests
+            .simulate_withdrawal(create_withdrawal(20_000, 50_000, 0))
+            .unwrap();
+        let assessed_fee = estimate.assessed_fee.unwrap();
+        assert!(assessed_fee > 0);
+        assert!(assessed_fee <= estimate.minimum_fee);
+        assert!(estimate.max_fee_sufficient);
+
+        let estimate = requests
+            .simulate_withdrawal(create_withdrawal(20_000, 1, 0))
+            .unwrap();
+        assert_eq!(estimate.assessed_fee, None);
+        assert!(!estimate.max_fee_sufficient);
+    }
+
     /// You cannot create sweep transactions that do not service requests.
     #[test]
     fn no_requests_no_sweep() {
@@ -2058,10 +3122,11 @@ mod tests {
                 amount: 55,
                 public_key,
             },
-            fee_rate: 0.0,
+            fee_rate: fee_rate(0.0),
             public_key,
             last_fees: None,
             magic_bytes: [0; 2],
+            metadata_chain_tip: None,
         };
 
         let requests = Requests::new(Vec::new());
@@ -2091,15 +3156,17 @@ mod tests {
                     amount: 500_000_000,
                     public_key,
                 },
-                fee_rate: 1.0,
+                fee_rate: fee_rate(1.0),
                 public_key,
                 last_fees: None,
                 magic_bytes: [b'S', b'T'],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 8,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         // Generate transactions
@@ -2205,15 +3272,17 @@ mod tests {
                     amount: 55,
                     public_key,
                 },
-                fee_rate: 1.0,
+                fee_rate: fee_rate(1.0),
                 public_key,
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 0,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         // This should all be in one transaction since there are no votes
@@ -2250,15 +3319,17 @@ mod tests {
                     amount: 55,
                     public_key,
                 },
-                fee_rate: 0.0,
+                fee_rate: fee_rate(0.0),
                 public_key,
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 0,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         // This should all be in one transaction since there are no votes
@@ -2301,15 +3372,17 @@ mod tests {
                     amount: 9500,
                     public_key,
                 },
-                fee_rate: 0.0,
+                fee_rate: fee_rate(0.0),
                 public_key,
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 0,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         let mut transactions = requests.construct_transactions().unwrap();
@@ -2349,15 +3422,17 @@ mod tests {
                     amount: 300_000,
                     public_key,
                 },
-                fee_rate: 0.0,
+                fee_rate: fee_rate(0.0),
                 public_key,
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 8,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         let transactions = requests.construct_transactions().unwrap();
@@ -2409,15 +3484,17 @@ mod tests {
                     amount: 300_000,
                     public_key,
                 },
-                fee_rate: 0.0,
+                fee_rate: fee_rate(0.0),
                 public_key,
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 8,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         let transactions = requests.construct_transactions().unwrap();
@@ -2509,15 +3586,17 @@ mod tests {
                     amount: 300_000_000,
                     public_key,
                 },
-                fee_rate: 25.0,
+                fee_rate: fee_rate(25.0),
                 public_key,
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 8,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         let mut transactions = requests.construct_transactions().unwrap();
@@ -2549,7 +3628,7 @@ mod tests {
 
             // The final fee rate should still be greater than the market fee rate
             let fee_rate = (input_amounts - output_amounts) as f64 / signed_vsize as f64;
-            more_asserts::assert_le!(requests.signer_state.fee_rate, fee_rate);
+            more_asserts::assert_le!(requests.signer_state.fee_rate.to_sat_per_vb(), fee_rate);
         });
     }
 
@@ -2575,15 +3654,17 @@ mod tests {
                     amount: 300_000_000,
                     public_key,
                 },
-                fee_rate: 25.0,
+                fee_rate: fee_rate(25.0),
                 public_key,
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 8,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         // In the below code, we need to make sure that we take the _first_
@@ -2633,7 +3714,7 @@ mod tests {
         more_asserts::assert_lt!(utx.tx.vsize(), signed_vsize);
         // The final fee rate should still be greater than the market fee rate
         let fee_rate = (input_amounts - output_amounts) as f64 / signed_vsize as f64;
-        more_asserts::assert_le!(requests.signer_state.fee_rate, fee_rate);
+        more_asserts::assert_le!(requests.signer_state.fee_rate.to_sat_per_vb(), fee_rate);
     }
 
     #[test_case(2, false; "some deposits, single tx")]
@@ -2656,15 +3737,17 @@ mod tests {
                     amount: 300_000_000,
                     public_key,
                 },
-                fee_rate: 25.0,
+                fee_rate: fee_rate(25.0),
                 public_key,
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 8,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
         // If multiple_txs is specified, we add a withdrawal that will
         // cause the transaction to be split into two.
@@ -2680,7 +3763,41 @@ mod tests {
         let unsigned = transactions.first().unwrap();
         let sighashes = unsigned.construct_digests().unwrap();
 
-        assert_eq!(sighashes.deposits.len(), num_deposits)
+        assert_eq!(sighashes.deposits.len(), num_deposits);
+
+        // The PSBT has everything needed to compute the same signature
+        // hashes that the signers sign.
+        let psbt = unsigned.to_psbt().unwrap();
+        assert_eq!(psbt.inputs.len(), num_deposits + 1);
+        assert_eq!(psbt.unsigned_tx, unsigned.tx);
+
+        let prevouts: Vec<TxOut> = psbt
+            .inputs
+            .iter()
+            .map(|input| input.witness_utxo.clone().unwrap())
+            .collect();
+        let prevouts = Prevouts::All(&prevouts);
+        let mut sighasher = SighashCache::new(&psbt.unsigned_tx);
+        let signer_sighash = sighasher
+            .taproot_key_spend_signature_hash(0, &prevouts, TapSighashType::All)
+            .unwrap();
+        assert_eq!(signer_sighash, sighashes.signers);
+
+        for (index, (deposit, sighash)) in sighashes.deposits.iter().enumerate() {
+            let input = &psbt.inputs[index + 1];
+            assert_eq!(input.tap_scripts.len(), 2);
+            let leaf_hash =
+                TapLeafHash::from_script(&deposit.deposit_script, LeafVersion::TapScript);
+            let psbt_sighash = sighasher
+                .taproot_script_spend_signature_hash(
+                    index + 1,
+                    &prevouts,
+                    leaf_hash,
+                    TapSighashType::All,
+                )
+                .unwrap();
+            assert_eq!(&psbt_sighash, sighash);
+        }
     }
 
     /// If the signer's UTXO does not have enough to cover the requests
@@ -2701,15 +3818,17 @@ mod tests {
                     amount: 3000,
                     public_key,
                 },
-                fee_rate: 0.0,
+                fee_rate: fee_rate(0.0),
                 public_key,
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 0,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         let transactions = requests.construct_transactions();
@@ -2759,15 +3878,17 @@ mod tests {
                     amount: 300_000_000,
                     public_key,
                 },
-                fee_rate,
+                fee_rate: self::fee_rate(fee_rate),
                 public_key,
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 10,
             accept_threshold: 8,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         let mut transactions = requests.construct_transactions().unwrap();
@@ -2962,6 +4083,55 @@ mod tests {
         assert_eq!(assessed_fee2, fee / 2);
     }
 
+    #[test]
+    fn deposit_prevouts_carry_their_assessed_fee() {
+        let signers_script_pubkey = ScriptBuf::new_op_return([1; 10]);
+        let deposit_outpoint = OutPoint::new(Txid::from_byte_array([1; 32]), 0);
+
+        let mut tx = base_signer_transaction();
+        tx.input.push(bitcoin::TxIn {
+            previous_output: deposit_outpoint,
+            script_sig: ScriptBuf::new(),
+            sequence: bitcoin::Sequence::ZERO,
+            witness: bitcoin::Witness::new(),
+        });
+        let vin = |outpoint: OutPoint, script: &ScriptBuf| BitcoinTxVin {
+            txid: Some(outpoint.txid),
+            vout: Some(outpoint.vout),
+            prevout: Some(BitcoinTxVinPrevout {
+                value: Amount::from_sat(100_000),
+                script_pubkey: OutputScriptPubKey { script: script.clone() },
+            }),
+        };
+        let fee = Amount::from_sat(500_000);
+        let tx_info = BitcoinTxInfo {
+            fee: Some(fee),
+            vin: vec![
+                vin(OutPoint::null(), &signers_script_pubkey),
+                vin(deposit_outpoint, &ScriptBuf::new()),
+            ],
+            tx,
+        };
+
+        // The recorded fee is the one that the complete-deposit contract
+        // call is built and validated with.
+        let signer_script_pubkeys = HashSet::from([signers_script_pubkey]);
+        let inputs = tx_info.to_inputs(&signer_script_pubkeys);
+        assert_eq!(inputs.len(), 2);
+        assert_eq!(inputs[0].assessed_fee, None);
+        assert_eq!(inputs[1].prevout_type, TxPrevoutType::Deposit);
+        assert_eq!(inputs[1].assessed_fee, Some(fee.to_sat()));
+        assert_eq!(
+            inputs[1].assessed_fee.map(Amount::from_sat),
+            tx_info.assess_input_fee(&deposit_outpoint)
+        );
+
+        // Without the fee of the transaction there is nothing to record.
+        let tx_info = BitcoinTxInfo { fee: None, ..tx_info };
+        let inputs = tx_info.to_inputs(&signer_script_pubkeys);
+        assert_eq!(inputs[1].assessed_fee, None);
+    }
+
     #[test]
     fn two_withdrawals_same_weight_split_the_fee() {
         let mut tx = base_signer_transaction();
@@ -3062,15 +4232,17 @@ mod tests {
                     amount: 300_000_000,
                     public_key,
                 },
-                fee_rate: 1.0,
+                fee_rate: fee_rate(1.0),
                 public_key,
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             num_signers: 11,
             accept_threshold: 6,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         // Let's construct the unsigned transaction and check to see if we
@@ -3108,15 +4280,17 @@ mod tests {
                     amount: 1000000,
                     public_key: generate_x_only_public_key(),
                 },
-                fee_rate: 1.0,
+                fee_rate: fee_rate(1.0),
                 public_key: generate_x_only_public_key(),
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             accept_threshold: 127,
             num_signers: 128,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         let transactions = requests.construct_transactions().unwrap();
@@ -3164,15 +4338,17 @@ mod tests {
                     amount: 100000000,
                     public_key: generate_x_only_public_key(),
                 },
-                fee_rate: 1.0,
+                fee_rate: fee_rate(1.0),
                 public_key: generate_x_only_public_key(),
                 last_fees: None,
                 magic_bytes: [0; 2],
+                metadata_chain_tip: None,
             },
             accept_threshold: 10,
             num_signers: 14,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         let mut transactions = requests.construct_transactions().unwrap();
@@ -3312,7 +4488,7 @@ mod tests {
         num_accepted_deposits: usize,
         accepted_amount: u64,
     ) {
-        let filter = RequestPreprocessor::new(sbtc_limits, fee_rate, None);
+        let filter = RequestPreprocessor::new(sbtc_limits, self::fee_rate(fee_rate), None);
 
         let deposits = filter.filter_deposits(deposits);
         // Each deposit and withdrawal has a max fee greater than the current market fee rate
@@ -3451,7 +4627,7 @@ mod tests {
     fn test_withdrawal_request_filtering(case: WithdrawalLimitTestCase) {
         let limits =
             SbtcLimits::from_withdrawal_limits(case.per_withdrawal_cap, case.rolling_limits);
-        let preprocessor = RequestPreprocessor::new(&limits, case.fee_rate, None);
+        let preprocessor = RequestPreprocessor::new(&limits, fee_rate(case.fee_rate), None);
 
         let withdrawals = preprocessor.preprocess_withdrawals(&case.withdrawals);
         let total_amount: u64 = withdrawals
@@ -3464,6 +4640,202 @@ mod tests {
         assert!(withdrawals.is_sorted())
     }
 
+    #[test]
+    fn small_withdrawals_are_subsidized_within_budget() {
+        let limits = SbtcLimits::unlimited();
+        let rate = fee_rate(10.0);
+        let withdrawals = vec![
+            create_withdrawal(50_000, 500, 0), // rejected (not eligible)
+            create_withdrawal(5_000, 500, 0),  // accepted (subsidized)
+            create_withdrawal(6_000, 500, 0),  // rejected (budget is spent)
+        ];
+
+        let req = &withdrawals[1];
+        let tx_vsize = BASE_WITHDRAWAL_TX_VSIZE + req.vsize() as f64;
+        let minimum_fee = compute_transaction_fee(tx_vsize, rate, None).to_sat();
+        let shortfall = minimum_fee - req.max_fee;
+
+        let fee_subsidy = WithdrawalFeeSubsidy {
+            threshold: 10_000,
+            budget: shortfall * 3 / 2,
+        };
+        let preprocessor =
+            RequestPreprocessor::new(&limits, rate, None).with_fee_subsidy(fee_subsidy);
+        let accepted = preprocessor.preprocess_withdrawals(&withdrawals);
+
+        assert_eq!(accepted.len(), 1);
+        assert_eq!(accepted[0].as_withdrawal(), Some(req));
+
+        // Without a subsidy none of them have a high enough max fee.
+        let preprocessor = RequestPreprocessor::new(&limits, rate, None);
+        assert!(preprocessor.preprocess_withdrawals(&withdrawals).is_empty());
+    }
+
+    #[test]
+    fn refunds_return_payments_to_the_sender() {
+        let aggregate_key = generate_x_only_public_key();
+        let signers_script_pubkey = aggregate_key.signers_script_pubkey();
+        let sender = generate_address();
+        let fee_rate = 10.0;
+
+        let mut payment = model::SignerPayment {
+            txid: Txid::from_byte_array([1; 32]).into(),
+            output_index: 1,
+            amount: 100_000,
+            script_pubkey: signers_script_pubkey.clone().into(),
+            sender_script_pubkey: Some(sender.clone()),
+            status: model::SignerPaymentStatus::RefundApproved,
+        };
+
+        let refund =
+            UnsignedRefundTransaction::new(&payment, aggregate_key, self::fee_rate(fee_rate))
+                .unwrap();
+        assert_eq!(refund.tx.input.len(), 1);
+        assert_eq!(refund.tx.input[0].previous_output, payment.outpoint());
+        assert_eq!(refund.tx.output.len(), 1);
+        assert_eq!(refund.tx.output[0].script_pubkey, *sender);
+        assert_eq!(
+            refund.tx.output[0].value.to_sat() + refund.tx_fee,
+            payment.amount
+        );
+        assert_ge!(refund.tx_fee as f64, fee_rate * refund.tx.vsize() as f64);
+
+        let sighash = refund.signature_hash().unwrap();
+        assert_eq!(sighash.outpoint, payment.outpoint());
+        assert_eq!(sighash.prevout_type, TxPrevoutType::SignersInput);
+
+        // Once confirmed, the signers do not mistake the refund for a
+        // sweep, so its output is not recorded as theirs.
+        let signer_script_pubkeys = HashSet::from([signers_script_pubkey.clone()]);
+        let tx_info = BitcoinTxInfo {
+            fee: Some(Amount::from_sat(refund.tx_fee)),
+            tx: refund.tx.clone(),
+            vin: vec![BitcoinTxVin {
+                txid: Some(payment.txid.into()),
+                vout: Some(payment.output_index),
+                prevout: Some(BitcoinTxVinPrevout {
+                    value: Amount::from_sat(payment.amount),
+                    script_pubkey: OutputScriptPubKey { script: signers_script_pubkey },
+                }),
+            }],
+        };
+        assert!(tx_info.to_tx_outputs(&signer_script_pubkeys).is_empty());
+        let inputs = tx_info.to_inputs(&signer_script_pubkeys);
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].prevout_type, TxPrevoutType::SignersInput);
+        assert_eq!(inputs[0].assessed_fee, None);
+
+        // We cannot refund payments from unknown senders, payments locked
+        // by another key, or payments that are too small.
+        let other_key = generate_x_only_public_key();
+        let result = UnsignedRefundTransaction::new(&payment, other_key, self::fee_rate(fee_rate));
+        assert!(matches!(result, Err(Error::RefundAggregateKeyMismatch(..))));
+
+        payment.amount = 1_000;
+        let result =
+            UnsignedRefundTransaction::new(&payment, aggregate_key, self::fee_rate(fee_rate));
+        assert!(matches!(result, Err(Error::RefundAmountTooLow { .. })));
+
+        payment.sender_script_pubkey = None;
+        let result =
+            UnsignedRefundTransaction::new(&payment, aggregate_key, self::fee_rate(fee_rate));
+        assert!(matches!(result, Err(Error::RefundSenderUnknown(_))));
+    }
+
+    #[test]
+    fn recovery_sweeps_the_signers_outputs_to_the_recovery_script() {
+        let recovery_script_pubkey: ScriptBuf = generate_address().into();
+        let fee_rate = 10.0;
+        let public_key = generate_x_only_public_key();
+        let mut utxo = SignerUtxo {
+            outpoint: generate_outpoint(100_000, 0),
+            amount: 100_000,
+            public_key,
+        };
+        let payment = SignerUtxo {
+            outpoint: generate_outpoint(20_000, 1),
+            amount: 20_000,
+            public_key,
+        };
+
+        let recovery = UnsignedRecoveryTransaction::new(
+            utxo,
+            vec![payment],
+            recovery_script_pubkey.clone(),
+            self::fee_rate(fee_rate),
+        )
+        .unwrap();
+        assert_eq!(recovery.tx.input.len(), 2);
+        assert_eq!(recovery.tx.input[0].previous_output, utxo.outpoint);
+        assert_eq!(recovery.tx.input[1].previous_output, payment.outpoint);
+        assert!(
+            recovery
+                .tx
+                .input
+                .iter()
+                .all(|tx_in| tx_in.witness.is_empty())
+        );
+        assert_eq!(recovery.tx.output.len(), 1);
+        assert_eq!(recovery.tx.output[0].script_pubkey, recovery_script_pubkey);
+        assert_eq!(
+            recovery.tx.output[0].value.to_sat() + recovery.tx_fee,
+            utxo.amount + payment.amount
+        );
+        assert_ge!(
+            recovery.tx_fee as f64,
+            fee_rate * recovery.tx.vsize() as f64
+        );
+
+        let sighashes = recovery.signature_hashes().unwrap();
+        assert_eq!(sighashes.len(), 2);
+        assert_eq!(sighashes[0].outpoint, utxo.outpoint);
+        assert_eq!(sighashes[1].outpoint, payment.outpoint);
+        assert_ne!(sighashes[0].sighash, sighashes[1].sighash);
+        for sighash in sighashes {
+            assert_eq!(sighash.prevout_type, TxPrevoutType::SignersInput);
+            assert_eq!(sighash.aggregate_key, public_key);
+        }
+
+        // Once confirmed, all of the inputs are recorded as the signers'.
+        let signers_script_pubkey = public_key.signers_script_pubkey();
+        let signer_script_pubkeys = HashSet::from([signers_script_pubkey.clone()]);
+        let vin = |utxo: &SignerUtxo| BitcoinTxVin {
+            txid: Some(utxo.outpoint.txid),
+            vout: Some(utxo.outpoint.vout),
+            prevout: Some(BitcoinTxVinPrevout {
+                value: Amount::from_sat(utxo.amount),
+                script_pubkey: OutputScriptPubKey {
+                    script: signers_script_pubkey.clone(),
+                },
+            }),
+        };
+        let tx_info = BitcoinTxInfo {
+            fee: Some(Amount::from_sat(recovery.tx_fee)),
+            tx: recovery.tx.clone(),
+            vin: recovery.utxos.iter().map(vin).collect(),
+        };
+        let inputs = tx_info.to_inputs(&signer_script_pubkeys);
+        assert_eq!(inputs.len(), 2);
+        assert!(
+            inputs
+                .iter()
+                .all(|input| input.prevout_type == TxPrevoutType::SignersInput)
+        );
+
+        // Outputs that cannot pay for the fee are not swept.
+        utxo.amount = 1_000;
+        let result = UnsignedRecoveryTransaction::new(
+            utxo,
+            Vec::new(),
+            recovery_script_pubkey,
+            self::fee_rate(fee_rate),
+        );
+        assert!(matches!(
+            result,
+            Err(Error::EmergencyRecoveryAmountTooLow { .. })
+        ));
+    }
+
     #[derive(Default)]
     struct TestTxOut {
         pub tx_outputs: Vec<TxOutput>,
@@ -3598,6 +4970,15 @@ mod tests {
         }))
         .output(TxOutputType::Withdrawal)
     ; "wrong version")]
+    #[test_case(&TestTxOut::default()
+        .output(TxOutputType::SignersOutput)
+        .op_return(ScriptBuf::new_op_return({
+            let mut pb = PushBytesBuf::new();
+            pb.extend_from_slice(&[0, 0, 2, 1, 2, 3]).unwrap();
+            pb
+        }))
+        .output(TxOutputType::Withdrawal)
+    ; "short metadata")]
     fn test_to_withdrawal_outputs_malformed_opreturn(tx: &TestTxOut) {
         let tx_info = tx.tx_info();
         let withdrawal_outs = tx_info.to_withdrawal_outputs(&tx.tx_outputs).unwrap_err();
@@ -3634,4 +5015,85 @@ mod tests {
         ];
         assert_eq!(withdrawal_outs, expected);
     }
+
+    #[test]
+    fn test_to_withdrawal_outputs_with_sweep_metadata() {
+        let metadata = SweepMetadata {
+            chain_tip_commitment: [7; 8],
+            request_digest: SweepMetadata::request_digest(&[], &[42, 51]),
+        };
+        let mut pb = PushBytesBuf::new();
+        pb.extend_from_slice(&[0, 0, 2]).unwrap();
+        pb.extend_from_slice(&metadata.encode()).unwrap();
+        pb.extend_from_slice(&BitmapSegmenter.package(&[42, 51]).unwrap().encode())
+            .unwrap();
+
+        let mut tx = TestTxOut::default();
+        tx.output(TxOutputType::SignersOutput)
+            .op_return(ScriptBuf::new_op_return(pb))
+            .output(TxOutputType::Withdrawal)
+            .output(TxOutputType::Withdrawal);
+
+        let tx_info = tx.tx_info();
+        let withdrawal_outs = tx_info.to_withdrawal_outputs(&tx.tx_outputs).unwrap();
+        let request_ids: Vec<u64> = withdrawal_outs.iter().map(|out| out.request_id).collect();
+        assert_eq!(request_ids, [42, 51]);
+
+        let parsed = tx_info.to_sweep_metadata(&tx.tx_outputs).unwrap();
+        assert_eq!(parsed, metadata);
+        assert!(parsed.commits_to(&[], &request_ids));
+        assert!(!parsed.commits_to(&[], &[42]));
+    }
+
+    /// Sweeps constructed with a metadata chain tip commit to the chain
+    /// tip and to the requests that they service, and have less room for
+    /// withdrawal IDs.
+    #[test]
+    fn sweeps_include_metadata_when_configured() {
+        let public_key = XOnlyPublicKey::from_str(X_ONLY_PUBLIC_KEY1).unwrap();
+        let chain_tip: BitcoinBlockHash = fake::Faker.fake();
+        let requests = SbtcRequests {
+            deposits: vec![create_deposit(123456, 0, 0)],
+            withdrawals: vec![create_withdrawal(1000, 0, 0)],
+            signer_state: SignerBtcState {
+                utxo: SignerUtxo {
+                    outpoint: OutPoint::null(),
+                    amount: 5500,
+                    public_key,
+                },
+                fee_rate: fee_rate(0.0),
+                public_key,
+                last_fees: None,
+                magic_bytes: [0; 2],
+                metadata_chain_tip: Some(chain_tip),
+            },
+            num_signers: 10,
+            accept_threshold: 0,
+            sbtc_limits: SbtcLimits::unlimited(),
+            max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
+        };
+        assert_eq!(
+            requests.signer_state.op_return_available_size(),
+            OP_RETURN_AVAILABLE_SIZE - SWEEP_METADATA_SIZE
+        );
+
+        let transactions = requests.construct_transactions().unwrap();
+        assert_eq!(transactions.len(), 1);
+        let tx = &transactions[0].tx;
+
+        let Some(Ok(Instruction::PushBytes(push_bytes))) =
+            tx.output[1].script_pubkey.instructions().nth(1)
+        else {
+            panic!("BUG: the OP_RETURN output does not push any data");
+        };
+        let raw_bytes = push_bytes.as_bytes();
+        assert_eq!(raw_bytes[2], OP_RETURN_METADATA_VERSION);
+
+        let metadata = SweepMetadata::decode(&raw_bytes[OP_RETURN_HEADER_SIZE..]).unwrap();
+        assert_eq!(metadata.chain_tip_commitment, chain_tip.into_bytes()[..8]);
+
+        let deposits = [tx.input[1].previous_output];
+        assert!(metadata.commits_to(&deposits, &[requests.withdrawals[0].request_id]));
+    }
 }
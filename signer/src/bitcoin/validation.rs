@@ -8,27 +8,39 @@ use bitcoin::OutPoint;
 use bitcoin::ScriptBuf;
 use bitcoin::XOnlyPublicKey;
 use bitcoin::relative::LockTime;
+use futures::StreamExt as _;
+use futures::TryStreamExt as _;
+use sbtc::deposits::CreateDepositRequest;
 
 use crate::DEPOSIT_DUST_LIMIT;
 use crate::DEPOSIT_LOCKTIME_BLOCK_BUFFER;
 use crate::WITHDRAWAL_BLOCKS_EXPIRY;
 use crate::WITHDRAWAL_MIN_CONFIRMATIONS;
+use crate::bitcoin::units::FeeRate;
 use crate::bitcoin::utxo::FeeAssessment;
 use crate::bitcoin::utxo::SignerBtcState;
+use crate::bitcoin::utxo::SweepTemplateVersion;
+use crate::bitcoin::utxo::WithdrawalFeeSubsidy;
 use crate::context::Context;
 use crate::context::SbtcLimits;
+use crate::context::SignerState;
+use crate::emergency_recovery;
 use crate::error::Error;
 use crate::keys::PublicKey;
+use crate::keys::PublicKeyXOnly;
 use crate::message::BitcoinPreSignRequest;
 use crate::storage::DbRead;
 use crate::storage::model::BitcoinBlockHash;
 use crate::storage::model::BitcoinBlockHeight;
+use crate::storage::model::BitcoinBlockRef;
 use crate::storage::model::BitcoinTxId;
 use crate::storage::model::BitcoinTxRef;
 use crate::storage::model::BitcoinTxSigHash;
 use crate::storage::model::BitcoinWithdrawalOutput;
 use crate::storage::model::DkgSharesStatus;
 use crate::storage::model::QualifiedRequestId;
+use crate::storage::model::SignerPayment;
+use crate::storage::model::SignerVote;
 use crate::storage::model::SignerVotes;
 use crate::storage::model::TaprootScriptHash;
 
@@ -36,9 +48,15 @@ use super::utxo::DepositRequest;
 use super::utxo::RequestRef;
 use super::utxo::Requests;
 use super::utxo::SignatureHash;
+use super::utxo::UnsignedRecoveryTransaction;
+use super::utxo::UnsignedRefundTransaction;
 use super::utxo::UnsignedTransaction;
 use super::utxo::WithdrawalRequest;
 
+/// The maximum number of concurrent database reads when fetching the
+/// reports for the requests in a presign request.
+const MAX_CONCURRENT_REPORT_READS: usize = 16;
+
 /// Cached validation data to avoid repeated DB queries
 #[derive(Default)]
 struct ValidationCache<'a> {
@@ -118,11 +136,22 @@ impl BitcoinPreSignRequest {
             .iter()
             .any(|x| x.deposits.is_empty() && x.withdrawals.is_empty());
 
-        if no_requests || self.request_package.is_empty() {
+        let has_requests = !self.request_package.is_empty() || !self.refunds.is_empty();
+
+        // The emergency recovery sweep is a transaction of its own, and
+        // the coordinator must not slip anything else in with it.
+        if self.emergency_recovery && has_requests {
+            return Err(Error::InvalidEmergencyRecoveryRequest);
+        }
+
+        // A request with refunds alone is fine, since refunds are
+        // separate transactions from the ones in the request package.
+        if no_requests || (!has_requests && !self.emergency_recovery) {
             return Err(Error::PreSignContainsNoRequests);
         }
 
-        if !is_unique(&self.request_package) {
+        let mut refunds = HashSet::new();
+        if !is_unique(&self.request_package) || !self.refunds.iter().all(|x| refunds.insert(x)) {
             return Err(Error::DuplicateRequests);
         }
 
@@ -133,50 +162,174 @@ impl BitcoinPreSignRequest {
         Ok(())
     }
 
+    /// The version of the sweep transaction template to reconstruct the
+    /// sweep transactions with.
+    ///
+    /// This is the version that the coordinator used, which must be one
+    /// that we accept. Coordinators that do not state the version they
+    /// used are assumed to use the same version that we would propose.
+    fn sweep_template<C: Context>(&self, ctx: &C) -> Result<SweepTemplateVersion, Error> {
+        let config = &ctx.config().signer;
+        let version = self
+            .sweep_template_version
+            .unwrap_or_else(|| config.proposed_sweep_template());
+
+        if !config.accepts_sweep_template(version) {
+            return Err(Error::UnsupportedSweepTemplateVersion(version.into()));
+        }
+
+        Ok(version)
+    }
+
+    /// Check that the request package does not contain any requests of
+    /// a kind that has been paused on this signer.
+    fn assert_no_paused_requests(&self, state: &SignerState) -> Result<(), Error> {
+        let has_deposits = self
+            .request_package
+            .iter()
+            .any(|reqs| !reqs.deposits.is_empty());
+        let has_withdrawals = self
+            .request_package
+            .iter()
+            .any(|reqs| !reqs.withdrawals.is_empty());
+
+        if state.is_deposits_paused() && has_deposits {
+            return Err(Error::PreSignContainsPausedDeposits);
+        }
+
+        if state.is_withdrawals_paused() && has_withdrawals {
+            return Err(Error::PreSignContainsPausedWithdrawals);
+        }
+
+        Ok(())
+    }
+
+    /// Verify the quorum certificates attached to the request.
+    ///
+    /// Each deposit request in the request package must come with exactly
+    /// one certificate, and each certificate must hold valid receipts from
+    /// at least a threshold of the signing set associated with the
+    /// aggregate key. The returned votes of that signing set on each
+    /// deposit request are taken from the receipts in the certificates,
+    /// where a signer without a receipt has not voted.
+    async fn verify_deposit_certificates<D>(
+        &self,
+        db: &D,
+        btc_ctx: &BitcoinTxContext,
+    ) -> Result<HashMap<OutPoint, SignerVotes>, Error>
+    where
+        D: DbRead,
+    {
+        let deposits: HashSet<&OutPoint> = self
+            .request_package
+            .iter()
+            .flat_map(|reqs| &reqs.deposits)
+            .collect();
+
+        if deposits.is_empty() && self.deposit_certificates.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let aggregate_key = PublicKeyXOnly::from(btc_ctx.aggregate_key);
+        let dkg_shares = db
+            .get_encrypted_dkg_shares(aggregate_key)
+            .await?
+            .ok_or(Error::MissingDkgShares(aggregate_key))?;
+        let signer_set = dkg_shares.signer_set_public_keys();
+
+        let mut deposit_votes = HashMap::with_capacity(deposits.len());
+        for certificate in self.deposit_certificates.iter() {
+            if !deposits.contains(&certificate.outpoint) {
+                return Err(Error::InvalidDepositQuorumCertificate(certificate.outpoint));
+            }
+            let signers = certificate.verify(&signer_set, dkg_shares.signature_share_threshold)?;
+            let votes: Vec<SignerVote> = signer_set
+                .iter()
+                .map(|public_key| SignerVote {
+                    signer_public_key: *public_key,
+                    is_accepted: signers.contains(public_key).then_some(true),
+                })
+                .collect();
+
+            let previous = deposit_votes.insert(certificate.outpoint, SignerVotes::from(votes));
+            if previous.is_some() {
+                return Err(Error::MissingDepositQuorumCertificate(certificate.outpoint));
+            }
+        }
+
+        if let Some(outpoint) = deposits.iter().find(|o| !deposit_votes.contains_key(*o)) {
+            return Err(Error::MissingDepositQuorumCertificate(**outpoint));
+        }
+
+        Ok(deposit_votes)
+    }
+
+    /// Fetch the reports and votes for all requests in the request
+    /// package.
+    ///
+    /// The reports are fetched concurrently, with at most
+    /// [`MAX_CONCURRENT_REPORT_READS`] reads in flight. The votes on the
+    /// deposit requests are the ones given by their quorum certificates,
+    /// see [`Self::verify_deposit_certificates`].
     async fn fetch_all_reports<D>(
         &self,
         db: &D,
         btc_ctx: &BitcoinTxContext,
+        mut deposit_votes: HashMap<OutPoint, SignerVotes>,
     ) -> Result<ValidationCache, Error>
     where
         D: DbRead,
     {
-        let mut cache = ValidationCache::default();
-
         let bitcoin_chain_tip = &btc_ctx.chain_tip;
         let maybe_stacks_chain_tip = db.get_stacks_chain_tip(bitcoin_chain_tip).await?;
         let Some(stacks_chain_tip) = maybe_stacks_chain_tip.map(|b| b.block_hash) else {
             return Err(Error::NoStacksChainTip);
         };
+        let stacks_chain_tip = &stacks_chain_tip;
 
-        for requests in &self.request_package {
-            // Fetch all deposit reports and votes
-            for outpoint in &requests.deposits {
-                let txid = outpoint.txid.into();
-                let output_index = outpoint.vout;
+        let deposits = self.request_package.iter().flat_map(|reqs| &reqs.deposits);
+        let withdrawals = self
+            .request_package
+            .iter()
+            .flat_map(|reqs| &reqs.withdrawals);
 
+        // Fetch all deposit reports
+        let deposit_reports: Vec<_> = futures::stream::iter(deposits)
+            .map(|outpoint| async move {
+                let txid = outpoint.txid.into();
                 let report_future = db.get_deposit_request_report(
                     bitcoin_chain_tip,
                     &txid,
-                    output_index,
+                    outpoint.vout,
                     &btc_ctx.signer_public_key,
                 );
-                let Some(report) = report_future.await? else {
-                    return Err(InputValidationResult::Unknown.into_error(btc_ctx));
-                };
-
-                let votes = db
-                    .get_deposit_request_signer_votes(&txid, output_index, &btc_ctx.aggregate_key)
-                    .await?;
+                match report_future.await? {
+                    Some(report) => Ok::<_, Error>((outpoint, report)),
+                    None => Err(InputValidationResult::Unknown.into_error(btc_ctx)),
+                }
+            })
+            .buffer_unordered(MAX_CONCURRENT_REPORT_READS)
+            .try_collect()
+            .await?;
 
-                cache.deposit_reports.insert(outpoint, (report, votes));
-            }
+        // The request package has been checked for duplicate requests, so
+        // each outpoint takes its votes out of the map exactly once.
+        let deposit_reports = deposit_reports
+            .into_iter()
+            .map(|(outpoint, report)| {
+                let votes = deposit_votes
+                    .remove(outpoint)
+                    .unwrap_or_else(|| SignerVotes::from(Vec::new()));
+                (outpoint, (report, votes))
+            })
+            .collect();
 
-            // Fetch all withdrawal reports and votes
-            for qualified_id in &requests.withdrawals {
+        // Fetch all withdrawal reports and votes
+        let withdrawal_reports = futures::stream::iter(withdrawals)
+            .map(|qualified_id| async move {
                 let report = db.get_withdrawal_request_report(
                     bitcoin_chain_tip,
-                    &stacks_chain_tip,
+                    stacks_chain_tip,
                     qualified_id,
                     &btc_ctx.signer_public_key,
                 );
@@ -188,12 +341,16 @@ impl BitcoinPreSignRequest {
                     .get_withdrawal_request_signer_votes(qualified_id, &btc_ctx.aggregate_key)
                     .await?;
 
-                cache
-                    .withdrawal_reports
-                    .insert(qualified_id, (report, votes));
-            }
-        }
-        Ok(cache)
+                Ok::<_, Error>((qualified_id, (report, votes)))
+            })
+            .buffer_unordered(MAX_CONCURRENT_REPORT_READS)
+            .try_collect()
+            .await?;
+
+        Ok(ValidationCache {
+            deposit_reports,
+            withdrawal_reports,
+        })
     }
 
     fn assert_request_amount_limits(
@@ -257,8 +414,16 @@ impl BitcoinPreSignRequest {
     {
         // Let's do basic validation of the request object itself.
         self.pre_validation()?;
+        // The emergency recovery sweep is handled separately, see
+        // `construct_recovery_sighashes`.
+        if self.emergency_recovery {
+            return Ok(Vec::new());
+        }
+        self.assert_no_paused_requests(ctx.state())?;
+        let sweep_template = self.sweep_template(ctx)?;
         let db = ctx.get_storage();
-        let cache = self.fetch_all_reports(&db, btc_ctx).await?;
+        let deposit_votes = self.verify_deposit_certificates(&db, btc_ctx).await?;
+        let cache = self.fetch_all_reports(&db, btc_ctx, deposit_votes).await?;
 
         // We now check that the withdrawal amounts adhere to the rolling
         // limits. We check the individual withdrawal caps later.
@@ -271,25 +436,197 @@ impl BitcoinPreSignRequest {
             .ok_or(Error::MissingSignerUtxo)?;
 
         let mut signer_state = SignerBtcState {
-            fee_rate: self.fee_rate,
+            fee_rate: FeeRate::new(self.fee_rate)?,
             utxo: signer_utxo,
             public_key: bitcoin::XOnlyPublicKey::from(btc_ctx.aggregate_key),
             last_fees: self.last_fees,
-            magic_bytes: [b'T', b'3'], //TODO(#472): Use the correct magic bytes.
+            magic_bytes: ctx.config().signer.network.magic_bytes(),
+            metadata_chain_tip: sweep_template.metadata_chain_tip(btc_ctx.chain_tip),
         };
+        // The fee subsidy budget is shared by all transactions in the
+        // package.
+        let mut fee_subsidy = ctx.config().signer.withdrawal_fee_subsidy();
         let mut outputs = Vec::new();
 
         for requests in self.request_package.iter() {
             let (output, new_signer_state) = self
-                .construct_tx_sighashes(ctx, btc_ctx, requests, signer_state, &cache)
+                .construct_tx_sighashes(ctx, btc_ctx, requests, signer_state, fee_subsidy, &cache)
                 .await?;
             signer_state = new_signer_state;
+            fee_subsidy = output.remaining_fee_subsidy();
             outputs.push(output);
         }
 
         Ok(outputs)
     }
 
+    /// Construct the sighashes of the refunds in the request.
+    ///
+    /// We only sign a refund if the operator of this signer approved it,
+    /// and if the payment is confirmed and unspent on the canonical
+    /// bitcoin blockchain. We never sign a refund that spends the signers'
+    /// UTXO. Refunds of payments that we do not know about, or that we
+    /// cannot construct, are left out, so we will not sign them either.
+    pub async fn construct_refund_sighashes<C>(
+        &self,
+        ctx: &C,
+        btc_ctx: &BitcoinTxContext,
+    ) -> Result<Vec<BitcoinTxSigHash>, Error>
+    where
+        C: Context + Send + Sync,
+    {
+        if self.refunds.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let db = ctx.get_storage();
+        let pending_refunds: HashSet<OutPoint> = db
+            .get_pending_refunds(&btc_ctx.chain_tip)
+            .await?
+            .iter()
+            .map(SignerPayment::outpoint)
+            .collect();
+        let signer_utxo = db.get_signer_utxo(&btc_ctx.chain_tip).await?;
+        let aggregate_key = XOnlyPublicKey::from(btc_ctx.aggregate_key);
+        let fee_rate = FeeRate::new(self.fee_rate)?;
+
+        let mut sighashes = Vec::with_capacity(self.refunds.len());
+        for outpoint in self.refunds.iter() {
+            if signer_utxo.is_some_and(|utxo| utxo.outpoint == *outpoint) {
+                tracing::warn!(%outpoint, "refusing to refund the signers' UTXO");
+                continue;
+            }
+
+            let payment = db
+                .get_signer_payment(&outpoint.txid.into(), outpoint.vout)
+                .await?;
+            let Some(payment) = payment else {
+                tracing::warn!(%outpoint, "refund requested for an unknown payment");
+                continue;
+            };
+
+            let refund = match UnsignedRefundTransaction::new(&payment, aggregate_key, fee_rate) {
+                Ok(refund) => refund,
+                Err(error) => {
+                    tracing::warn!(%error, %outpoint, "could not construct the refund");
+                    continue;
+                }
+            };
+
+            let sighash = refund.signature_hash()?;
+            let is_approved = pending_refunds.contains(outpoint);
+            let validation_result = if is_approved {
+                InputValidationResult::Ok
+            } else {
+                InputValidationResult::RefundNotApproved
+            };
+
+            sighashes.push(BitcoinTxSigHash {
+                txid: sighash.txid.into(),
+                chain_tip: btc_ctx.chain_tip,
+                prevout_txid: sighash.outpoint.txid.into(),
+                prevout_output_index: sighash.outpoint.vout,
+                aggregate_key: sighash.aggregate_key.into(),
+                sighash: sighash.sighash.into(),
+                prevout_type: sighash.prevout_type,
+                validation_result,
+                is_valid_tx: is_approved,
+                will_sign: is_approved,
+            });
+        }
+
+        Ok(sighashes)
+    }
+
+    /// Construct the sighashes of the inputs of the emergency recovery
+    /// sweep, if the request asks for one.
+    ///
+    /// We rebuild the sweep from our own view of the signers' UTXO, the
+    /// other payments to the signers and our own configured recovery
+    /// scriptPubKey, and only sign it if the sweep is allowed at the chain
+    /// tip and the fee rate is within the bound in our config. See
+    /// [`crate::emergency_recovery`] for when the sweep is allowed. Once
+    /// we agree to sign it, we record the sweep, so that the recovery ends
+    /// when it is confirmed.
+    pub async fn construct_recovery_sighashes<C>(
+        &self,
+        ctx: &C,
+        btc_ctx: &BitcoinTxContext,
+    ) -> Result<Vec<BitcoinTxSigHash>, Error>
+    where
+        C: Context + Send + Sync,
+    {
+        if !self.emergency_recovery {
+            return Ok(Vec::new());
+        }
+
+        let config = &ctx.config().signer;
+        let recovery_script_pubkey = config
+            .emergency_recovery_script_pubkey
+            .clone()
+            .ok_or(Error::EmergencyRecoveryNotConfigured)?;
+        let signer_utxo = ctx
+            .get_storage()
+            .get_signer_utxo(&btc_ctx.chain_tip)
+            .await?
+            .ok_or(Error::MissingSignerUtxo)?;
+        let payments =
+            emergency_recovery::swept_payments(ctx, &btc_ctx.chain_tip, &signer_utxo).await?;
+        let fee_rate = FeeRate::new(self.fee_rate)?;
+
+        let recovery = UnsignedRecoveryTransaction::new(
+            signer_utxo,
+            payments,
+            recovery_script_pubkey,
+            fee_rate,
+        )?;
+        let txid = recovery.tx.compute_txid();
+        let sighashes = recovery.signature_hashes()?;
+
+        let chain_tip = BitcoinBlockRef {
+            block_hash: btc_ctx.chain_tip,
+            block_height: btc_ctx.chain_tip_height,
+        };
+        let sweep_allowed = emergency_recovery::sweep_script(ctx, &chain_tip)
+            .await?
+            .is_some();
+        let max_fee_rate = config.emergency_recovery_max_fee_rate.get() as f64;
+        let will_sign = sweep_allowed && self.fee_rate <= max_fee_rate;
+        let validation_result = if will_sign {
+            tracing::warn!(
+                %txid,
+                inputs = %sighashes.len(),
+                fee_rate = %self.fee_rate,
+                "agreeing to sign the emergency recovery sweep of the signers' funds"
+            );
+            emergency_recovery::record_sweep(ctx, txid.into()).await?;
+            InputValidationResult::Ok
+        } else {
+            tracing::warn!(
+                %sweep_allowed,
+                fee_rate = %self.fee_rate,
+                "refusing to sign an emergency recovery sweep of the signers' funds"
+            );
+            InputValidationResult::EmergencyRecoveryNotActive
+        };
+
+        Ok(sighashes
+            .into_iter()
+            .map(|sighash| BitcoinTxSigHash {
+                txid: sighash.txid.into(),
+                chain_tip: btc_ctx.chain_tip,
+                prevout_txid: sighash.outpoint.txid.into(),
+                prevout_output_index: sighash.outpoint.vout,
+                aggregate_key: sighash.aggregate_key.into(),
+                sighash: sighash.sighash.into(),
+                prevout_type: sighash.prevout_type,
+                validation_result,
+                is_valid_tx: will_sign,
+                will_sign,
+            })
+            .collect())
+    }
+
     /// Construct the validation for each request that this transaction
     /// will service.
     ///
@@ -302,6 +639,7 @@ impl BitcoinPreSignRequest {
         btc_ctx: &BitcoinTxContext,
         requests: &'a TxRequestIds,
         signer_state: SignerBtcState,
+        fee_subsidy: WithdrawalFeeSubsidy,
         cache: &ValidationCache<'a>,
     ) -> Result<(BitcoinTxValidationData, SignerBtcState), Error>
     where
@@ -316,6 +654,9 @@ impl BitcoinPreSignRequest {
                 .get(outpoint)
                 // This should never happen because we have already validated that we have all the reports.
                 .ok_or_else(|| InputValidationResult::Unknown.into_error(btc_ctx))?;
+            if !report.scripts_match(ctx.state()) {
+                return Err(InputValidationResult::InvalidDepositScripts.into_error(btc_ctx));
+            }
             deposits.push((report.to_deposit_request(votes), report.clone()));
         }
 
@@ -356,6 +697,7 @@ impl BitcoinPreSignRequest {
             reports,
             chain_tip_height: btc_ctx.chain_tip_height,
             sbtc_limits: ctx.state().get_current_limits(),
+            fee_subsidy,
         };
 
         Ok((out, signer_state))
@@ -384,6 +726,9 @@ pub struct BitcoinTxValidationData {
     pub chain_tip_height: BitcoinBlockHeight,
     /// The current sBTC limits.
     pub sbtc_limits: SbtcLimits,
+    /// The rules for subsidizing withdrawal fees, with what is left of
+    /// the budget after the preceding transactions in the package.
+    pub fee_subsidy: WithdrawalFeeSubsidy,
 }
 
 impl BitcoinTxValidationData {
@@ -454,6 +799,7 @@ impl BitcoinTxValidationData {
         let bitcoin_txid = self.tx.compute_txid().into();
 
         let is_valid_tx = self.is_valid_tx();
+        let (validation_results, _) = self.validate_withdrawals();
         // If we ever construct a transaction with more than u32::MAX then
         // we are dealing with a very different Bitcoin and Stacks than we
         // started with, and there are other things that we need to change
@@ -461,24 +807,52 @@ impl BitcoinTxValidationData {
         self.reports
             .withdrawals
             .iter()
+            .zip(validation_results)
             .enumerate()
-            .map(|(output_index, (_, report))| BitcoinWithdrawalOutput {
-                bitcoin_txid,
-                bitcoin_chain_tip: self.chain_tip,
-                output_index: output_index as u32 + 2,
-                request_id: report.id.request_id,
-                stacks_txid: report.id.txid,
-                stacks_block_hash: report.id.block_hash,
-                validation_result: report.validate(
+            .map(
+                |(output_index, ((_, report), validation_result))| BitcoinWithdrawalOutput {
+                    bitcoin_txid,
+                    bitcoin_chain_tip: self.chain_tip,
+                    output_index: output_index as u32 + 2,
+                    request_id: report.id.request_id,
+                    stacks_txid: report.id.txid,
+                    stacks_block_hash: report.id.block_hash,
+                    validation_result,
+                    is_valid_tx,
+                },
+            )
+            .collect()
+    }
+
+    /// Validate the withdrawals in the order of their outputs in the
+    /// transaction, returning the results along with what is left of the
+    /// fee subsidy budget afterward.
+    fn validate_withdrawals(&self) -> (Vec<WithdrawalValidationResult>, WithdrawalFeeSubsidy) {
+        let mut fee_subsidy = self.fee_subsidy;
+        let results = self
+            .reports
+            .withdrawals
+            .iter()
+            .enumerate()
+            .map(|(index, (_, report))| {
+                report.validate(
                     self.chain_tip_height,
-                    output_index + 2,
+                    index + 2,
                     &self.tx,
                     self.tx_fee,
                     &self.sbtc_limits,
-                ),
-                is_valid_tx,
+                    &mut fee_subsidy,
+                )
             })
-            .collect()
+            .collect();
+
+        (results, fee_subsidy)
+    }
+
+    /// The fee subsidy budget that is left for the transactions that
+    /// follow this one in the package.
+    pub fn remaining_fee_subsidy(&self) -> WithdrawalFeeSubsidy {
+        self.validate_withdrawals().1
     }
 
     /// Check whether the transaction is valid. This determines whether
@@ -513,20 +887,54 @@ impl BitcoinTxValidationData {
             )
         });
 
-        let withdrawal_validation_results =
-            self.reports
-                .withdrawals
-                .iter()
-                .enumerate()
-                .all(|(index, (_, report))| {
-                    let output_index = index + 2;
-                    let result =
-                        report.validate(chain_tip_height, output_index, tx, tx_fee, sbtc_limits);
-                    result == WithdrawalValidationResult::Ok
-                });
+        let (withdrawal_results, _) = self.validate_withdrawals();
+        let withdrawal_validation_results = withdrawal_results
+            .iter()
+            .all(|result| *result == WithdrawalValidationResult::Ok);
 
         deposit_validation_results && withdrawal_validation_results
     }
+
+    /// The deposit and withdrawal requests that fail validation, making
+    /// the transaction invalid according to [`Self::is_valid_tx`].
+    ///
+    /// The signer tells the coordinator about these requests when it
+    /// refuses a presign request, so that the coordinator can try again
+    /// without them.
+    pub fn contested_requests(&self) -> TxRequestIds {
+        let chain_tip_height = self.chain_tip_height;
+        let tx = &self.tx;
+        let tx_fee = self.tx_fee;
+        let sbtc_limits = &self.sbtc_limits;
+
+        let deposits = self
+            .reports
+            .deposits
+            .iter()
+            .filter(|(_, report)| {
+                !matches!(
+                    report.validate(chain_tip_height, tx, tx_fee, sbtc_limits),
+                    InputValidationResult::Ok
+                        | InputValidationResult::CannotSignUtxo
+                        | InputValidationResult::DkgSharesUnverified
+                        | InputValidationResult::DkgSharesVerifyFailed
+                )
+            })
+            .map(|(request, _)| request.outpoint)
+            .collect();
+
+        let (withdrawal_results, _) = self.validate_withdrawals();
+        let withdrawals = self
+            .reports
+            .withdrawals
+            .iter()
+            .zip(withdrawal_results)
+            .filter(|(_, result)| *result != WithdrawalValidationResult::Ok)
+            .map(|((_, report), _)| report.id)
+            .collect();
+
+        TxRequestIds { deposits, withdrawals }
+    }
 }
 
 /// The set of sBTC requests with additional relevant
@@ -591,6 +999,8 @@ pub enum InputValidationResult {
     TxNotOnBestChain,
     /// The deposit UTXO has already been spent.
     DepositUtxoSpent,
+    /// The depositor has reclaimed the deposit UTXO.
+    DepositReclaimed,
     /// The DKG shares associated with the aggregate key locking the
     /// deposit spend path of the deposit UTXO has failed verification.
     DkgSharesVerifyFailed,
@@ -613,6 +1023,17 @@ pub enum InputValidationResult {
     /// supported. This shouldn't happen, since we will not put it in our
     /// database is this is the case.
     UnsupportedLockTime,
+    /// The input spends a payment to the signers that the operator of
+    /// this signer has not approved for a refund.
+    RefundNotApproved,
+    /// The input is the signers' UTXO in an emergency recovery sweep that
+    /// this signer does not allow, because the signer set has not approved
+    /// it, the delay has not passed, or the fee rate is too high.
+    EmergencyRecoveryNotActive,
+    /// The deposit or reclaim script of the deposit request does not
+    /// parse, or parses into values that differ from the ones that we
+    /// have stored for the request.
+    InvalidDepositScripts,
 }
 
 impl InputValidationResult {
@@ -742,6 +1163,10 @@ pub enum DepositConfirmationStatus {
     /// in another bitcoin transaction that has been confirmed on the
     /// canonical bitcoin blockchain.
     Spent(BitcoinTxId),
+    /// The depositor spent the deposit UTXO through the reclaim path of
+    /// the deposit script, in the transaction with the given ID, which has
+    /// been confirmed on the canonical bitcoin blockchain.
+    Reclaimed(BitcoinTxId),
     /// We have a record of the deposit request transaction, and it has not
     /// been confirmed on the canonical bitcoin blockchain.
     ///
@@ -818,6 +1243,11 @@ impl DepositRequestReport {
             DepositConfirmationStatus::Spent(_) => {
                 return InputValidationResult::DepositUtxoSpent;
             }
+            // The depositor took their funds back, so there is nothing
+            // left for us to sweep.
+            DepositConfirmationStatus::Reclaimed(_) => {
+                return InputValidationResult::DepositReclaimed;
+            }
             // The deposit has been confirmed on the canonical bitcoin
             // blockchain and remains unspent by us.
             DepositConfirmationStatus::Confirmed(block_height, _) => block_height,
@@ -833,14 +1263,12 @@ impl DepositRequestReport {
 
         // We only sweep a deposit if the depositor cannot reclaim the
         // deposit within the next DEPOSIT_LOCKTIME_BLOCK_BUFFER blocks.
-        let deposit_age = chain_tip_height.saturating_sub(confirmed_block_height);
+        let deposit_age = chain_tip_height.blocks_since(confirmed_block_height);
 
         match self.lock_time {
             LockTime::Blocks(height) => {
-                let max_age = height
-                    .value()
-                    .saturating_sub(DEPOSIT_LOCKTIME_BLOCK_BUFFER)
-                    .into();
+                let max_age =
+                    u64::from(height.value().saturating_sub(DEPOSIT_LOCKTIME_BLOCK_BUFFER));
                 if deposit_age >= max_age {
                     return InputValidationResult::LockTimeExpiry;
                 }
@@ -897,6 +1325,24 @@ impl DepositRequestReport {
         InputValidationResult::Ok
     }
 
+    /// Whether the deposit and reclaim scripts in this report parse into
+    /// the max fee, lock-time and signers' public key of the report. The
+    /// scripts are parsed through the cache in the given signer state.
+    fn scripts_match(&self, state: &SignerState) -> bool {
+        let request = CreateDepositRequest {
+            outpoint: self.outpoint,
+            deposit_script: self.deposit_script.clone(),
+            reclaim_script: self.reclaim_script.clone(),
+        };
+        let Ok(scripts) = state.deposit_scripts(&request) else {
+            return false;
+        };
+
+        scripts.deposit.signers_public_key == self.signers_public_key
+            && scripts.deposit.max_fee == self.max_fee
+            && scripts.reclaim.lock_time() == self.lock_time.to_consensus_u32()
+    }
+
     /// As deposit request.
     fn to_deposit_request(&self, votes: &SignerVotes) -> DepositRequest {
         DepositRequest {
@@ -960,7 +1406,9 @@ impl WithdrawalRequestReport {
     /// Validate that the withdrawal request is okay given the report.
     ///
     /// See https://github.com/stacks-network/sbtc/issues/741 for the
-    /// validation rules for withdrawal requests.
+    /// validation rules for withdrawal requests. A fee above the max fee
+    /// is okay if the signers subsidize the difference, in which case it
+    /// is taken out of the given subsidy budget.
     pub fn validate<F>(
         &self,
         bitcoin_chain_tip_height: BitcoinBlockHeight,
@@ -968,6 +1416,7 @@ impl WithdrawalRequestReport {
         tx: &F,
         tx_fee: Amount,
         sbtc_limits: &SbtcLimits,
+        fee_subsidy: &mut WithdrawalFeeSubsidy,
     ) -> WithdrawalValidationResult
     where
         F: FeeAssessment,
@@ -996,7 +1445,7 @@ impl WithdrawalRequestReport {
             return WithdrawalValidationResult::AmountIsDust;
         }
 
-        let block_wait = *bitcoin_chain_tip_height.saturating_sub(self.bitcoin_block_height);
+        let block_wait = bitcoin_chain_tip_height.blocks_since(self.bitcoin_block_height);
         if block_wait < WITHDRAWAL_MIN_CONFIRMATIONS {
             return WithdrawalValidationResult::RequestNotFinal;
         }
@@ -1010,7 +1459,8 @@ impl WithdrawalRequestReport {
             return WithdrawalValidationResult::Unknown;
         };
 
-        if assessed_fee.to_sat() > self.max_fee {
+        let shortfall = assessed_fee.to_sat().saturating_sub(self.max_fee);
+        if !fee_subsidy.try_subsidize(self.amount, shortfall) {
             return WithdrawalValidationResult::FeeTooHigh;
         }
 
@@ -1041,14 +1491,24 @@ mod tests {
     use bitcoin::Txid;
     use bitcoin::Witness;
     use bitcoin::hashes::Hash as _;
+    use fake::Fake as _;
+    use rand::SeedableRng as _;
     use secp256k1::SECP256K1;
     use test_case::test_case;
 
     use crate::context::RollingWithdrawalLimits;
     use crate::context::SbtcLimits;
+    use crate::keys::PrivateKey;
+    use crate::keys::SignerScriptPubKey as _;
+    use crate::message::DecisionReceipt;
+    use crate::message::DepositQuorumCertificate;
+    use crate::storage::DbWrite as _;
+    use crate::storage::model;
     use crate::storage::model::BitcoinBlockHeight;
+    use crate::storage::model::EncryptedDkgShares;
     use crate::storage::model::StacksBlockHash;
     use crate::storage::model::StacksTxId;
+    use crate::testing::context::*;
 
     use super::*;
 
@@ -1101,6 +1561,25 @@ mod tests {
         chain_tip_height: 2u64.into(),
         limits: SbtcLimits::new_per_deposit(0, u64::MAX),
     }; "deposit-spent")]
+    #[test_case(DepositReportErrorMapping {
+        report: DepositRequestReport {
+            status: DepositConfirmationStatus::Reclaimed(BitcoinTxId::from([1; 32])),
+            can_sign: Some(true),
+            can_accept: Some(true),
+            amount: 100_000_000,
+            max_fee: u64::MAX,
+            lock_time: LockTime::from_height(u16::MAX),
+            outpoint: OutPoint::null(),
+            deposit_script: ScriptBuf::new(),
+            reclaim_script: ScriptBuf::new(),
+            reclaim_script_hash: Some(TaprootScriptHash::zeros()),
+            signers_public_key: *sbtc::UNSPENDABLE_TAPROOT_KEY,
+            dkg_shares_status: Some(DkgSharesStatus::Verified),
+        },
+        status: InputValidationResult::DepositReclaimed,
+        chain_tip_height: 2u64.into(),
+        limits: SbtcLimits::new_per_deposit(0, u64::MAX),
+    }; "deposit-reclaimed")]
     #[test_case(DepositReportErrorMapping {
         report: DepositRequestReport {
             status: DepositConfirmationStatus::Confirmed(0u64.into(), BitcoinBlockHash::from([0; 32])),
@@ -1696,13 +2175,77 @@ mod tests {
         let chain_tip_height = mapping.chain_tip_height;
         let limits = &mapping.limits;
 
-        let status = mapping
-            .report
-            .validate(chain_tip_height, output_index, &tx, TX_FEE, limits);
+        let mut fee_subsidy = WithdrawalFeeSubsidy::default();
+
+        let status = mapping.report.validate(
+            chain_tip_height,
+            output_index,
+            &tx,
+            TX_FEE,
+            limits,
+            &mut fee_subsidy,
+        );
 
         assert_eq!(status, mapping.status);
     }
 
+    #[test]
+    fn withdrawal_fee_shortfall_is_subsidized_within_budget() {
+        let report = WithdrawalRequestReport {
+            status: WithdrawalRequestStatus::Confirmed,
+            id: QualifiedRequestId {
+                request_id: 0,
+                txid: StacksTxId::from([0; 32]),
+                block_hash: StacksBlockHash::from([0; 32]),
+            },
+            is_accepted: Some(true),
+            amount: 10_000,
+            max_fee: TX_FEE.to_sat() - 100,
+            recipient: TEST_RECIPIENT.clone(),
+            bitcoin_block_height: 0u64.into(),
+        };
+        let mut tx = crate::testing::btc::base_signer_transaction();
+        tx.output.push(TxOut {
+            value: Amount::from_sat(report.amount),
+            script_pubkey: report.recipient.clone(),
+        });
+
+        let output_index = tx.output.len() - 1;
+        let chain_tip_height = WITHDRAWAL_MIN_CONFIRMATIONS.into();
+        let limits = &SbtcLimits::unlimited();
+        let validate = |fee_subsidy: &mut WithdrawalFeeSubsidy| {
+            report.validate(
+                chain_tip_height,
+                output_index,
+                &tx,
+                TX_FEE,
+                limits,
+                fee_subsidy,
+            )
+        };
+
+        // The withdrawal is small enough and the budget covers the 100
+        // sats that the max fee falls short by, so it is subsidized.
+        let mut fee_subsidy = WithdrawalFeeSubsidy { threshold: 20_000, budget: 150 };
+        assert_eq!(validate(&mut fee_subsidy), WithdrawalValidationResult::Ok);
+        assert_eq!(fee_subsidy.budget, 50);
+
+        // What is left of the budget does not cover the shortfall.
+        assert_eq!(
+            validate(&mut fee_subsidy),
+            WithdrawalValidationResult::FeeTooHigh
+        );
+        assert_eq!(fee_subsidy.budget, 50);
+
+        // Withdrawals at or above the threshold are not subsidized.
+        let mut fee_subsidy = WithdrawalFeeSubsidy { threshold: 10_000, budget: 150 };
+        assert_eq!(
+            validate(&mut fee_subsidy),
+            WithdrawalValidationResult::FeeTooHigh
+        );
+        assert_eq!(fee_subsidy.budget, 150);
+    }
+
     #[test]
     fn withdrawal_report_validation_unknown() {
         let report = WithdrawalRequestReport {
@@ -1732,7 +2275,16 @@ mod tests {
         let bitcoin_chain_tip_height = WITHDRAWAL_MIN_CONFIRMATIONS.into();
         let limits = &SbtcLimits::unlimited();
 
-        let status = report.validate(bitcoin_chain_tip_height, output_index, &tx, TX_FEE, limits);
+        let mut fee_subsidy = WithdrawalFeeSubsidy::default();
+
+        let status = report.validate(
+            bitcoin_chain_tip_height,
+            output_index,
+            &tx,
+            TX_FEE,
+            limits,
+            &mut fee_subsidy,
+        );
 
         assert_eq!(status, WithdrawalValidationResult::Unknown);
     }
@@ -1765,6 +2317,10 @@ mod tests {
             }],
             fee_rate: 1.0,
             last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
         }, true; "unique-requests")]
     #[test_case(
         BitcoinPreSignRequest {
@@ -1794,6 +2350,10 @@ mod tests {
             }],
             fee_rate: 0.0,
             last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
         }, false; "unique-requests-zero-fee-rate")]
     #[test_case(
         BitcoinPreSignRequest {
@@ -1823,6 +2383,10 @@ mod tests {
             }],
             fee_rate: -1.0,
             last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
         }, false; "unique-requests-negative-fee-rate")]
     #[test_case(
         BitcoinPreSignRequest {
@@ -1852,6 +2416,10 @@ mod tests {
             }],
             fee_rate: 1.0,
             last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
         }, false; "duplicate-deposits-in-same-tx")]
     #[test_case(
         BitcoinPreSignRequest {
@@ -1881,6 +2449,10 @@ mod tests {
             }],
             fee_rate: 1.0,
             last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
         }, false; "duplicate-withdrawals-in-same-tx")]
     #[test_case(
         BitcoinPreSignRequest {
@@ -1912,6 +2484,10 @@ mod tests {
             ],
             fee_rate: 1.0,
             last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
         }, false; "duplicate-withdrawal-request-ids-in-same-tx")]
     #[test_case(
         BitcoinPreSignRequest {
@@ -1950,12 +2526,20 @@ mod tests {
             ],
             fee_rate: 1.0,
             last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
         }, false; "duplicate-requests-in-different-txs")]
     #[test_case(
         BitcoinPreSignRequest {
             request_package: Vec::new(),
             fee_rate: 1.0,
             last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
         }, false; "empty-package_requests")]
     #[test_case(
         BitcoinPreSignRequest {
@@ -1971,6 +2555,10 @@ mod tests {
             ],
             fee_rate: 1.0,
             last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
         }, false; "basically-empty-package_requests")]
     #[test_case(
         BitcoinPreSignRequest {
@@ -2006,11 +2594,345 @@ mod tests {
             ],
             fee_rate: 1.0,
             last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
         }, false; "contains-empty-tx-requests")]
+    #[test_case(
+        BitcoinPreSignRequest {
+            request_package: Vec::new(),
+            fee_rate: 1.0,
+            last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: true,
+        }, true; "emergency-recovery-alone")]
+    #[test_case(
+        BitcoinPreSignRequest {
+            request_package: Vec::new(),
+            fee_rate: 1.0,
+            last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: vec![OutPoint {
+                txid: Txid::from_byte_array([1; 32]),
+                vout: 0,
+            }],
+            sweep_template_version: None,
+            emergency_recovery: true,
+        }, false; "emergency-recovery-with-refunds")]
     fn test_pre_validation(requests: BitcoinPreSignRequest, result: bool) {
         assert_eq!(requests.pre_validation().is_ok(), result);
     }
 
+    #[test]
+    fn paused_deposits_are_rejected() {
+        let withdrawal = QualifiedRequestId {
+            request_id: 0,
+            txid: StacksTxId::from([1; 32]),
+            block_hash: StacksBlockHash::from([1; 32]),
+        };
+        let mut request = BitcoinPreSignRequest {
+            request_package: vec![TxRequestIds {
+                deposits: Vec::new(),
+                withdrawals: vec![withdrawal],
+            }],
+            fee_rate: 1.0,
+            last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
+        };
+
+        let state = SignerState::default();
+        state.set_deposits_paused(true);
+        request.assert_no_paused_requests(&state).unwrap();
+
+        request.request_package.push(TxRequestIds {
+            deposits: vec![OutPoint::new(Txid::from_byte_array([1; 32]), 0)],
+            withdrawals: Vec::new(),
+        });
+        let result = request.assert_no_paused_requests(&state);
+        assert!(matches!(result, Err(Error::PreSignContainsPausedDeposits)));
+
+        state.set_deposits_paused(false);
+        request.assert_no_paused_requests(&state).unwrap();
+    }
+
+    #[test]
+    fn paused_withdrawals_are_rejected() {
+        let mut request = BitcoinPreSignRequest {
+            request_package: vec![TxRequestIds {
+                deposits: vec![OutPoint::new(Txid::from_byte_array([1; 32]), 0)],
+                withdrawals: Vec::new(),
+            }],
+            fee_rate: 1.0,
+            last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
+        };
+
+        let state = SignerState::default();
+        state.set_withdrawals_paused(true);
+        request.assert_no_paused_requests(&state).unwrap();
+
+        request.request_package.push(TxRequestIds {
+            deposits: Vec::new(),
+            withdrawals: vec![QualifiedRequestId {
+                request_id: 0,
+                txid: StacksTxId::from([1; 32]),
+                block_hash: StacksBlockHash::from([1; 32]),
+            }],
+        });
+        let result = request.assert_no_paused_requests(&state);
+        assert!(matches!(
+            result,
+            Err(Error::PreSignContainsPausedWithdrawals)
+        ));
+
+        state.set_withdrawals_paused(false);
+        request.assert_no_paused_requests(&state).unwrap();
+    }
+
+    #[test]
+    fn sweep_template_must_be_accepted() {
+        let ctx = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .modify_settings(|settings| {
+                settings.signer.sweep_template_version = SweepTemplateVersion::V2;
+                settings.signer.accepted_sweep_template_versions = vec![SweepTemplateVersion::V2];
+            })
+            .build();
+
+        let mut request: BitcoinPreSignRequest = fake::Faker.fake();
+
+        // Coordinators that do not state the version are assumed to use
+        // the version that we would propose.
+        request.sweep_template_version = None;
+        let version = request.sweep_template(&ctx).unwrap();
+        assert_eq!(version, SweepTemplateVersion::V2);
+
+        request.sweep_template_version = Some(SweepTemplateVersion::V2);
+        let version = request.sweep_template(&ctx).unwrap();
+        assert_eq!(version, SweepTemplateVersion::V2);
+
+        request.sweep_template_version = Some(SweepTemplateVersion::V1);
+        let result = request.sweep_template(&ctx);
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedSweepTemplateVersion(1))
+        ));
+    }
+
+    #[tokio::test]
+    async fn deposits_require_exactly_one_certificate() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(51);
+        let db = crate::storage::memory::Store::new_shared();
+        let private_keys: Vec<PrivateKey> = std::iter::repeat_with(|| PrivateKey::new(&mut rng))
+            .take(3)
+            .collect();
+        let signer_set: Vec<PublicKey> = private_keys
+            .iter()
+            .map(PublicKey::from_private_key)
+            .collect();
+
+        let mut shares: EncryptedDkgShares = fake::Faker.fake_with_rng(&mut rng);
+        shares.signer_set_public_keys = signer_set.clone();
+        shares.signature_share_threshold = 2;
+        db.write_encrypted_dkg_shares(&shares).await.unwrap();
+
+        let btc_ctx = BitcoinTxContext {
+            chain_tip: fake::Faker.fake_with_rng(&mut rng),
+            chain_tip_height: 0u64.into(),
+            signer_public_key: signer_set[0],
+            aggregate_key: shares.aggregate_key,
+        };
+
+        let outpoint = OutPoint::new(Txid::from_byte_array([1; 32]), 0);
+        let certificate = DepositQuorumCertificate {
+            outpoint,
+            receipts: private_keys[..2]
+                .iter()
+                .map(|private_key| DecisionReceipt {
+                    signer_public_key: PublicKey::from_private_key(private_key),
+                    signature: crate::quorum::sign_deposit_receipt(private_key, &outpoint),
+                })
+                .collect(),
+        };
+        let mut request = BitcoinPreSignRequest {
+            request_package: vec![TxRequestIds {
+                deposits: vec![outpoint],
+                withdrawals: Vec::new(),
+            }],
+            fee_rate: 1.0,
+            last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            sweep_template_version: None,
+            emergency_recovery: false,
+        };
+
+        // A deposit request without a certificate is rejected, regardless
+        // of what is in our database.
+        let result = request.verify_deposit_certificates(&db, &btc_ctx).await;
+        assert!(matches!(
+            result,
+            Err(Error::MissingDepositQuorumCertificate(op)) if op == outpoint
+        ));
+
+        // With a certificate the votes come from its receipts.
+        request.deposit_certificates.push(certificate.clone());
+        let votes = request
+            .verify_deposit_certificates(&db, &btc_ctx)
+            .await
+            .unwrap();
+        let votes = &votes[&outpoint];
+        assert_eq!(votes.len(), 3);
+        for vote in votes.iter() {
+            let is_accepted = signer_set[..2].contains(&vote.signer_public_key);
+            assert_eq!(vote.is_accepted, is_accepted.then_some(true));
+        }
+
+        // Duplicate certificates for the same deposit are rejected.
+        request.deposit_certificates.push(certificate);
+        let result = request.verify_deposit_certificates(&db, &btc_ctx).await;
+        assert!(matches!(
+            result,
+            Err(Error::MissingDepositQuorumCertificate(op)) if op == outpoint
+        ));
+    }
+
+    #[tokio::test]
+    async fn refunds_are_only_signed_when_approved() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(52);
+        let ctx = TestContext::default_mocked();
+        let db = ctx.get_storage_mut();
+
+        let mut shares: EncryptedDkgShares = fake::Faker.fake_with_rng(&mut rng);
+        shares.dkg_shares_status = DkgSharesStatus::Verified;
+        db.write_encrypted_dkg_shares(&shares).await.unwrap();
+        let aggregate_key = shares.aggregate_key;
+
+        let chain_tip: model::BitcoinBlock = fake::Faker.fake_with_rng(&mut rng);
+        db.write_bitcoin_block(&chain_tip).await.unwrap();
+        let btc_ctx = BitcoinTxContext {
+            chain_tip: chain_tip.block_hash,
+            chain_tip_height: chain_tip.block_height,
+            signer_public_key: fake::Faker.fake_with_rng(&mut rng),
+            aggregate_key,
+        };
+
+        // A donation that is the signers' UTXO.
+        let donation = model::TxOutput {
+            output_index: 0,
+            script_pubkey: aggregate_key.signers_script_pubkey().into(),
+            output_type: model::TxOutputType::Donation,
+            ..fake::Faker.fake_with_rng(&mut rng)
+        };
+        let tx_ref = BitcoinTxRef {
+            txid: donation.txid,
+            block_hash: chain_tip.block_hash,
+        };
+        db.write_bitcoin_transaction(&tx_ref).await.unwrap();
+        db.write_tx_output(&donation).await.unwrap();
+        let signer_utxo = db
+            .get_signer_utxo(&chain_tip.block_hash)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let sender_script_pubkey = ScriptBuf::new_p2wpkh(&bitcoin::WPubkeyHash::all_zeros());
+        let mut new_payment = |status| SignerPayment {
+            amount: 1_000_000,
+            script_pubkey: aggregate_key.signers_script_pubkey().into(),
+            sender_script_pubkey: Some(sender_script_pubkey.clone().into()),
+            status,
+            ..fake::Faker.fake_with_rng(&mut rng)
+        };
+        let approved = model::SignerPaymentStatus::RefundApproved;
+        let approved_payment = new_payment(approved);
+        let unreviewed_payment = new_payment(model::SignerPaymentStatus::Unreviewed);
+        let unknown_sender_payment = SignerPayment {
+            sender_script_pubkey: None,
+            ..new_payment(approved)
+        };
+        let signer_utxo_payment = SignerPayment {
+            txid: signer_utxo.outpoint.txid.into(),
+            output_index: signer_utxo.outpoint.vout,
+            ..new_payment(approved)
+        };
+        let unknown_payment = new_payment(approved);
+
+        let payments = [
+            &approved_payment,
+            &unreviewed_payment,
+            &unknown_sender_payment,
+            &signer_utxo_payment,
+        ];
+        for payment in payments {
+            let tx_ref = BitcoinTxRef {
+                txid: payment.txid,
+                block_hash: chain_tip.block_hash,
+            };
+            db.write_bitcoin_transaction(&tx_ref).await.unwrap();
+            db.write_signer_payment(payment).await.unwrap();
+        }
+
+        let request = BitcoinPreSignRequest {
+            request_package: Vec::new(),
+            fee_rate: 1.0,
+            last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: vec![
+                approved_payment.outpoint(),
+                unreviewed_payment.outpoint(),
+                unknown_sender_payment.outpoint(),
+                signer_utxo_payment.outpoint(),
+                unknown_payment.outpoint(),
+            ],
+            sweep_template_version: None,
+            emergency_recovery: false,
+        };
+
+        let sighashes = request
+            .construct_refund_sighashes(&ctx, &btc_ctx)
+            .await
+            .unwrap();
+
+        // We never sign a refund of the signers' UTXO, or of payments that
+        // we do not know about or cannot refund, so they are left out.
+        // Refunds that our operator did not approve are recorded as such.
+        assert_eq!(sighashes.len(), 2);
+        let find = |payment: &SignerPayment| {
+            sighashes
+                .iter()
+                .find(|sighash| {
+                    sighash.prevout_txid == payment.txid
+                        && sighash.prevout_output_index == payment.output_index
+                })
+                .unwrap()
+        };
+
+        let sighash = find(&approved_payment);
+        assert_eq!(sighash.validation_result, InputValidationResult::Ok);
+        assert!(sighash.will_sign);
+        assert!(sighash.is_valid_tx);
+        assert_eq!(sighash.chain_tip, chain_tip.block_hash);
+
+        let sighash = find(&unreviewed_payment);
+        assert_eq!(
+            sighash.validation_result,
+            InputValidationResult::RefundNotApproved
+        );
+        assert!(!sighash.will_sign);
+        assert!(!sighash.is_valid_tx);
+    }
+
     fn create_deposit_report(idx: u8, amount: u64) -> (DepositRequestReport, SignerVotes) {
         (
             DepositRequestReport {
@@ -2034,6 +2956,30 @@ mod tests {
         )
     }
 
+    #[test]
+    fn deposit_report_scripts_must_match_the_report() {
+        let request: crate::storage::model::DepositRequest = fake::Faker.fake();
+        let (mut report, _) = create_deposit_report(1, request.amount);
+        report.outpoint = request.outpoint();
+        report.max_fee = request.max_fee;
+        report.lock_time = LockTime::from_consensus(request.lock_time).unwrap();
+        report.deposit_script = ScriptBuf::from_bytes(request.spend_script.clone());
+        report.reclaim_script = ScriptBuf::from_bytes(request.reclaim_script.clone());
+        report.signers_public_key = request.signers_public_key.into();
+
+        let state = SignerState::default();
+        assert!(report.scripts_match(&state));
+        assert_eq!(state.num_cached_deposit_scripts(), 1);
+
+        let mut mismatched = report.clone();
+        mismatched.max_fee += 1;
+        assert!(!mismatched.scripts_match(&state));
+
+        let mut unparsable = report.clone();
+        unparsable.deposit_script = ScriptBuf::new();
+        assert!(!unparsable.scripts_match(&state));
+    }
+
     fn create_withdrawal_report(idx: u8, amount: u64) -> (WithdrawalRequestReport, SignerVotes) {
         let report = WithdrawalRequestReport {
             id: QualifiedRequestId {
@@ -10,6 +10,7 @@
 //! - Stacks blocks
 //! - Deposit requests
 //! - sBTC transactions
+//! - Deposit reclaim transactions
 //! - Withdraw requests
 //! - Deposit accept transactions
 //! - Withdraw accept transactions
@@ -21,9 +22,11 @@ use std::future::Future;
 use std::time::Duration;
 
 use crate::bitcoin::BitcoinInteract;
+use crate::bitcoin::headers;
 use crate::bitcoin::rpc::BitcoinBlockHeader;
 use crate::bitcoin::rpc::BitcoinTxInfo;
 use crate::bitcoin::utxo::TxDeconstructor as _;
+use crate::block_processing;
 use crate::context::Context;
 use crate::context::SbtcLimits;
 use crate::context::SignerEvent;
@@ -31,27 +34,45 @@ use crate::emily_client::EmilyInteract;
 use crate::error::Error;
 use crate::keys::PublicKey;
 use crate::keys::SignerScriptPubKey as _;
+use crate::limit_override;
 use crate::metrics::BITCOIN_BLOCKCHAIN;
 use crate::metrics::Metrics;
 use crate::stacks::api::GetNakamotoStartHeight as _;
 use crate::stacks::api::SignerSetInfo;
 use crate::stacks::api::StacksInteract;
 use crate::stacks::api::TenureBlockHeaders;
+use crate::stacks::verification;
+use crate::stacks::verification::StacksBlockVerificationError;
 use crate::storage::DbRead;
 use crate::storage::DbWrite;
 use crate::storage::Transactable;
 use crate::storage::TransactionHandle;
 use crate::storage::model;
+use crate::storage::model::BlockProcessingStage;
 use crate::storage::model::EncryptedDkgShares;
+use crate::supervisor::report_error;
+use crate::transaction_coordinator::given_key_is_coordinator;
 use bitcoin::Amount;
 use bitcoin::BlockHash;
 use bitcoin::ScriptBuf;
+use bitcoincore_rpc_json::GetBlockchainInfoResult;
+use emily_client::models::DepositStatus;
+use emily_client::models::DepositUpdate;
 use futures::stream::Stream;
 use futures::stream::StreamExt;
 use sbtc::deposits::CreateDepositRequest;
 use sbtc::deposits::DepositInfo;
+use sbtc::deposits::ParsedDepositScripts;
+use std::collections::HashMap;
 use std::collections::HashSet;
 
+/// The number of deposit requests from Emily that are validated and
+/// stored together, when the Emily page size is not configured.
+const DEFAULT_DEPOSIT_REQUEST_BATCH_SIZE: usize = 100;
+
+/// The name of this event loop, as used by the supervisor.
+const EVENT_LOOP: &str = "block-observer";
+
 /// Block observer
 #[derive(Debug)]
 pub struct BlockObserver<Context, BlockHashStream> {
@@ -80,40 +101,80 @@ impl DepositRequestValidator for CreateDepositRequest {
     where
         C: BitcoinInteract,
     {
-        // Fetch the transaction from either a block or from the mempool
-        let Some(response) = client.get_tx(&self.outpoint.txid).await? else {
+        let Some((tx_info, block_hash)) = fetch_confirmed_deposit_tx(self, client).await? else {
             return Ok(None);
         };
 
-        // If the transaction has not been confirmed yet, then the block
-        // hash will be None. The transaction has not failed validation,
-        // let's try again when it gets confirmed.
-        let Some(block_hash) = response.block_hash else {
-            return Ok(None);
-        };
-
-        if response.tx.is_coinbase() {
-            return Err(Error::BitcoinTxCoinbase(self.outpoint.txid));
-        }
+        Ok(Some(Deposit {
+            info: self.validate_tx(&tx_info.tx, is_mainnet)?,
+            tx_info,
+            block_hash,
+        }))
+    }
 
-        // The `get_tx_info` call here should not return None, we know that
-        // it has been included in a block.
-        let Some(tx_info) = client.get_tx_info(&self.outpoint.txid, &block_hash).await? else {
+    async fn validate_with_scripts<C>(
+        &self,
+        client: &C,
+        scripts: &ParsedDepositScripts,
+        is_mainnet: bool,
+    ) -> Result<Option<Deposit>, Error>
+    where
+        C: BitcoinInteract,
+    {
+        let Some((tx_info, block_hash)) = fetch_confirmed_deposit_tx(self, client).await? else {
             return Ok(None);
         };
 
-        // Check that the necessary data is present for the transaction
-        // info struct.
-        tx_info.validate()?;
-
         Ok(Some(Deposit {
-            info: self.validate_tx(&tx_info.tx, is_mainnet)?,
+            info: self.validate_tx_with_scripts(&tx_info.tx, scripts, is_mainnet)?,
             tx_info,
             block_hash,
         }))
     }
 }
 
+/// Fetch the transaction of the deposit request along with the hash of
+/// the block that confirmed it. Returns `None` if the transaction is
+/// unknown or has not been confirmed yet.
+async fn fetch_confirmed_deposit_tx<C>(
+    request: &CreateDepositRequest,
+    client: &C,
+) -> Result<Option<(BitcoinTxInfo, BlockHash)>, Error>
+where
+    C: BitcoinInteract,
+{
+    // Fetch the transaction from either a block or from the mempool
+    let Some(response) = client.get_tx(&request.outpoint.txid).await? else {
+        return Ok(None);
+    };
+
+    // If the transaction has not been confirmed yet, then the block
+    // hash will be None. The transaction has not failed validation,
+    // let's try again when it gets confirmed.
+    let Some(block_hash) = response.block_hash else {
+        return Ok(None);
+    };
+
+    if response.tx.is_coinbase() {
+        return Err(Error::BitcoinTxCoinbase(request.outpoint.txid));
+    }
+
+    // The `get_tx_info` call here should not return None, we know that
+    // it has been included in a block.
+    let Some(tx_info) = client
+        .get_tx_info(&request.outpoint.txid, &block_hash)
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    // Check that the necessary data is present for the transaction
+    // info struct.
+    tx_info.validate()?;
+
+    Ok(Some((tx_info, block_hash)))
+}
+
 /// A trait to add validation functionality to the [`CreateDepositRequest`]
 /// type.
 pub trait DepositRequestValidator {
@@ -129,6 +190,18 @@ pub trait DepositRequestValidator {
     ) -> impl Future<Output = Result<Option<Deposit>, Error>>
     where
         C: BitcoinInteract;
+
+    /// Validate this deposit request from the transaction, using deposit
+    /// and reclaim scripts that have already been parsed from this
+    /// request.
+    fn validate_with_scripts<C>(
+        &self,
+        client: &C,
+        scripts: &ParsedDepositScripts,
+        is_mainnet: bool,
+    ) -> impl Future<Output = Result<Option<Deposit>, Error>>
+    where
+        C: BitcoinInteract;
 }
 
 impl<C, S> BlockObserver<C, S>
@@ -158,29 +231,62 @@ where
                         "blockchain" => BITCOIN_BLOCKCHAIN,
                     )
                     .increment(1);
+                    let processed_block = model::BitcoinBlockHash::from(block_hash);
+                    let stage = BlockProcessingStage::Observed;
+                    block_processing::record_stage(&self.context, &processed_block, stage).await;
+
+                    let processing_delay = self
+                        .context
+                        .config()
+                        .signer
+                        .block_observer_delay()
+                        .sample(&mut rand::rngs::OsRng);
+                    if processing_delay > Duration::ZERO {
+                        tracing::debug!("sleeping before processing new bitcoin block");
+                        self.context.clock().sleep(processing_delay).await;
+                    }
 
                     if let Err(error) = self.process_bitcoin_blocks_until(block_hash).await {
-                        tracing::warn!(%error, %block_hash, "could not process bitcoin blocks");
+                        let doing = format!("process bitcoin blocks up to {block_hash}");
+                        report_error(&self.context, EVENT_LOOP, &error, &doing);
                     }
 
                     if let Err(error) = self.process_stacks_blocks().await {
-                        tracing::warn!(%error, "could not process stacks blocks");
+                        report_error(&self.context, EVENT_LOOP, &error, "process stacks blocks");
                     }
 
                     if let Err(error) = self.check_pending_dkg_shares(block_hash).await {
-                        tracing::warn!(%error, "could not check pending dkg shares");
+                        report_error(
+                            &self.context,
+                            EVENT_LOOP,
+                            &error,
+                            "check pending dkg shares",
+                        );
                         continue;
                     }
 
                     tracing::debug!("updating the signer state");
                     if let Err(error) = self.update_signer_state(block_hash).await {
-                        tracing::warn!(%error, "could not update the signer state");
+                        report_error(&self.context, EVENT_LOOP, &error, "update the signer state");
                         continue;
                     }
 
+                    if let Err(error) = self.update_aggregate_key_registry().await {
+                        let doing = "update the registry of aggregate keys";
+                        report_error(&self.context, EVENT_LOOP, &error, doing);
+                    }
+
                     tracing::info!("loading latest deposit requests from Emily");
-                    if let Err(error) = self.load_latest_deposit_requests().await {
-                        tracing::warn!(%error, "could not load latest deposit requests from Emily");
+                    match self.load_latest_deposit_requests().await {
+                        Ok(()) => {
+                            let stage = BlockProcessingStage::DepositsFetched;
+                            let ctx = &self.context;
+                            block_processing::record_stage(ctx, &processed_block, stage).await;
+                        }
+                        Err(error) => {
+                            let doing = "load latest deposit requests from Emily";
+                            report_error(&self.context, EVENT_LOOP, &error, doing);
+                        }
                     }
 
                     self.context
@@ -203,10 +309,54 @@ where
 impl<C: Context, B> BlockObserver<C, B> {
     /// Fetch deposit requests from Emily and store the ones that pass
     /// validation into the database.
+    ///
+    /// Deposit requests are validated and stored in batches as they are
+    /// streamed from Emily, so that we never hold all pending deposit
+    /// requests in memory at once.
     #[tracing::instrument(skip_all)]
     async fn load_latest_deposit_requests(&self) -> Result<(), Error> {
-        let requests = self.context.get_emily_client().get_deposits().await?;
-        self.load_requests(&requests).await
+        let emily_client = self.context.get_emily_client();
+        let batch_size = self
+            .context
+            .config()
+            .emily
+            .page_size
+            .map_or(DEFAULT_DEPOSIT_REQUEST_BATCH_SIZE, usize::from);
+
+        let mut batches = std::pin::pin!(emily_client.get_deposits().chunks(batch_size));
+        let mut outpoints = HashSet::new();
+        let mut first_error = None;
+
+        while let Some(batch) = batches.next().await {
+            let mut requests = Vec::with_capacity(batch.len());
+            for request in batch {
+                match request {
+                    Ok(request) => requests.push(request),
+                    Err(error) => {
+                        tracing::warn!(%error, "could not fetch deposit requests from Emily");
+                        first_error.get_or_insert(error);
+                    }
+                }
+            }
+
+            outpoints.extend(requests.iter().map(|req| req.outpoint));
+            self.load_requests(&requests).await?;
+        }
+
+        match first_error {
+            // If we could not fetch anything then Emily is probably down,
+            // so we report the error instead.
+            Some(error) if outpoints.is_empty() => Err(error),
+            // Drop the parsed scripts of requests that we are no longer
+            // asked to validate, so that the cache does not grow without
+            // bound. We only do this if we have seen all of them.
+            None => {
+                let state = self.context.state();
+                state.retain_deposit_scripts(|outpoint| outpoints.contains(outpoint));
+                Ok(())
+            }
+            Some(_) => Ok(()),
+        }
     }
 
     /// Validate the given deposit requests and store the ones that pass
@@ -228,10 +378,36 @@ impl<C: Context, B> BlockObserver<C, B> {
         let bitcoin_client = self.context.get_bitcoin_client();
         let is_mainnet = self.context.config().signer.network.is_mainnet();
 
+        let state = self.context.state();
+
         for request in requests {
-            let deposit = request
-                .validate(&bitcoin_client, is_mainnet)
-                .await
+            let deposit = match state.deposit_scripts(request) {
+                Ok(scripts) => {
+                    request
+                        .validate_with_scripts(&bitcoin_client, &scripts, is_mainnet)
+                        .await
+                }
+                Err(error) => Err(error.into()),
+            };
+            // bitcoin-core cannot give us the deposit transaction if it
+            // has pruned the block that confirmed it, so we rely on what
+            // we have stored about the deposit, say from an import.
+            if let Err(Error::BitcoinCoreBlockPruned(_)) = deposit {
+                let txid = request.outpoint.txid.into();
+                let db = self.context.get_storage();
+                if db
+                    .get_deposit_request(&txid, request.outpoint.vout)
+                    .await?
+                    .is_some()
+                {
+                    tracing::debug!(
+                        outpoint = %request.outpoint,
+                        "deposit block is pruned, using the stored request"
+                    );
+                    continue;
+                }
+            }
+            let deposit = deposit
                 .inspect_err(|error| tracing::warn!(%error, "could not validate deposit request"));
 
             // We log the error above, so we just need to extract the
@@ -328,7 +504,21 @@ impl<C: Context, B> BlockObserver<C, B> {
             }
         }
 
-        Ok(headers.into())
+        let headers: Vec<BitcoinBlockHeader> = headers.into();
+        if self.context.config().signer.verify_bitcoin_headers {
+            // The loop above stopped at the parent of the first header,
+            // which we may already know about.
+            let parent = db
+                .get_bitcoin_block(&block_hash.into())
+                .await?
+                .map(|block| (block_hash, block.block_height));
+            let network = self.context.config().signer.network.into();
+            headers::verify_header_chain(&headers, parent, network).inspect_err(|error| {
+                tracing::error!(%error, "bitcoin block headers from bitcoin-core failed verification");
+            })?;
+        }
+
+        Ok(headers)
     }
 
     /// Process bitcoin blocks until we get caught up to the given
@@ -361,12 +551,18 @@ impl<C: Context, B> BlockObserver<C, B> {
     /// the signers `scriptPubKey`s to the database.
     #[tracing::instrument(skip_all, fields(block_hash = %block_header.hash))]
     async fn process_bitcoin_block(&self, block_header: BitcoinBlockHeader) -> Result<(), Error> {
-        let block = self
+        let block = match self
             .context
             .get_bitcoin_client()
             .get_block(&block_header.hash)
-            .await?
-            .ok_or(Error::BitcoinCoreMissingBlock(block_header.hash))?;
+            .await
+        {
+            Ok(block) => block.ok_or(Error::BitcoinCoreMissingBlock(block_header.hash))?,
+            Err(Error::BitcoinCoreBlockPruned(_)) => {
+                return self.process_pruned_bitcoin_block(&block_header).await;
+            }
+            Err(error) => return Err(error),
+        };
         let db_block = model::BitcoinBlock::from(&block);
 
         let storage = self.context.get_storage_mut();
@@ -393,13 +589,86 @@ impl<C: Context, B> BlockObserver<C, B> {
         )
         .await?;
 
+        let block_ref = model::BitcoinBlockRef::from(&db_block);
+        let reclaims =
+            extract_deposit_reclaims(&storage_tx, &block_ref, &block.transactions).await?;
+
         // Commit the storage transaction.
         storage_tx.commit().await?;
 
+        if let Err(error) = self.report_deposit_reclaims(&block_ref, reclaims).await {
+            tracing::warn!(%error, "could not report reclaimed deposits to Emily");
+        }
+
         tracing::debug!("finished processing bitcoin block");
         Ok(())
     }
 
+    /// Tell Emily that the given deposit requests have been reclaimed by
+    /// their depositors, so that they are no longer shown as pending.
+    ///
+    /// Every signer records the reclaims in its own database, but only
+    /// the coordinator for the block confirming them reports them.
+    async fn report_deposit_reclaims(
+        &self,
+        block_ref: &model::BitcoinBlockRef,
+        reclaims: Vec<model::DepositReclaim>,
+    ) -> Result<(), Error> {
+        let public_key = self.context.config().signer.public_key();
+        let signer_set = &self.context.config().signer.bootstrap_signing_set;
+        let is_coordinator =
+            given_key_is_coordinator(public_key, &block_ref.block_hash, signer_set);
+        if reclaims.is_empty() || !is_coordinator {
+            return Ok(());
+        }
+
+        let updates = reclaims
+            .into_iter()
+            .map(|reclaim| DepositUpdate {
+                bitcoin_tx_output_index: reclaim.output_index,
+                bitcoin_txid: reclaim.txid.to_string(),
+                status: DepositStatus::Failed,
+                fulfillment: None,
+                status_message: format!("reclaimed by the depositor in {}", reclaim.reclaim_txid),
+                replaced_by_tx: None,
+            })
+            .collect();
+
+        self.context
+            .get_emily_client()
+            .update_deposits(updates)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Write a bitcoin block that bitcoin-core has pruned to the database
+    /// using only its header.
+    ///
+    /// We cannot look for sBTC transactions in a pruned block, so we rely
+    /// on the data that we have stored for it, say from an import. This
+    /// only happens for blocks that are older than what a pruned node
+    /// keeps, which is checked to be deeper than the context window of
+    /// the signer, see [`check_prune_depth`].
+    async fn process_pruned_bitcoin_block(
+        &self,
+        block_header: &BitcoinBlockHeader,
+    ) -> Result<(), Error> {
+        tracing::warn!(
+            block_height = %block_header.height,
+            "bitcoin-core has pruned the block, relying on stored data for its transactions"
+        );
+        let db_block = model::BitcoinBlock {
+            block_hash: block_header.hash.into(),
+            block_height: block_header.height,
+            parent_hash: block_header.previous_block_hash.into(),
+        };
+        self.context
+            .get_storage_mut()
+            .write_bitcoin_block(&db_block)
+            .await
+    }
+
     /// Process all recent stacks blocks.
     #[tracing::instrument(skip_all)]
     async fn process_stacks_blocks(&self) -> Result<(), Error> {
@@ -409,12 +678,35 @@ impl<C: Context, B> BlockObserver<C, B> {
         let tenure_info = stacks_client.get_tenure_info().await?;
 
         tracing::debug!("fetching unknown ancestral blocks from stacks-core");
-        let stacks_block_headers = crate::stacks::api::fetch_unknown_ancestors(
+        let verify = self.context.config().signer.verify_stacks_blocks;
+        let fetch_result = crate::stacks::api::fetch_verified_unknown_ancestors(
             &stacks_client,
             &db,
             tenure_info.tip_block_id,
+            |tenure| {
+                if !verify {
+                    return Ok(());
+                }
+                verification::verify_tenure(tenure).map_err(|(block_id, error)| {
+                    Error::StacksBlockVerification(block_id, tenure.anchor_block_hash, error)
+                })
+            },
         )
-        .await?;
+        .await;
+
+        let mut stacks_block_headers = match fetch_result {
+            Err(Error::StacksBlockVerification(block_id, anchor, error)) => {
+                self.quarantine_stacks_blocks(&[block_id.into()], anchor, &error)
+                    .await?;
+                return Err(Error::StacksBlockVerification(block_id, anchor, error));
+            }
+            result => result?,
+        };
+
+        if verify {
+            self.verify_stacks_anchors(&mut stacks_block_headers)
+                .await?;
+        }
 
         let headers = stacks_block_headers
             .into_iter()
@@ -427,9 +719,126 @@ impl<C: Context, B> BlockObserver<C, B> {
         Ok(())
     }
 
+    /// Verify that the bitcoin blocks anchoring the given tenures are on
+    /// the bitcoin chain that we have observed.
+    ///
+    /// Tenures anchored to bitcoin blocks above our chain tip are dropped,
+    /// since we may not have processed the bitcoin block yet. They are
+    /// fetched again after the next bitcoin block.
+    async fn verify_stacks_anchors(
+        &self,
+        tenures: &mut Vec<TenureBlockHeaders>,
+    ) -> Result<(), Error> {
+        let db = self.context.get_storage();
+        let start_height = self.context.state().get_sbtc_bitcoin_start_height();
+        let Some(chain_tip) = self.context.state().bitcoin_chain_tip() else {
+            return Ok(());
+        };
+
+        // The tenures are ordered by height, so tenures after the first
+        // one above the chain tip are also above the chain tip.
+        if let Some(index) = tenures
+            .iter()
+            .position(|tenure| tenure.anchor_block_height > chain_tip.block_height)
+        {
+            tracing::debug!(
+                dropped = tenures.len() - index,
+                "dropping stacks tenures anchored above our bitcoin chain tip"
+            );
+            tenures.truncate(index);
+        }
+
+        for tenure in tenures.iter() {
+            // We do not store bitcoin blocks below the start height.
+            if tenure.anchor_block_height < start_height {
+                continue;
+            }
+            let anchor = db.get_bitcoin_block(&tenure.anchor_block_hash).await?;
+            if let Err(error) = verification::verify_anchor(tenure, anchor.as_ref()) {
+                let block_hashes: Vec<model::StacksBlockHash> = tenure
+                    .headers()
+                    .iter()
+                    .map(|header| header.block_id.into())
+                    .collect();
+                self.quarantine_stacks_blocks(&block_hashes, tenure.anchor_block_hash, &error)
+                    .await?;
+
+                let block_id = tenure.headers()[0].block_id;
+                return Err(Error::StacksBlockVerification(
+                    block_id,
+                    tenure.anchor_block_hash,
+                    error,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Record the given stacks blocks as having failed verification.
+    async fn quarantine_stacks_blocks(
+        &self,
+        block_hashes: &[model::StacksBlockHash],
+        bitcoin_anchor: model::BitcoinBlockHash,
+        error: &StacksBlockVerificationError,
+    ) -> Result<(), Error> {
+        let db = self.context.get_storage_mut();
+        for block_hash in block_hashes {
+            tracing::error!(%block_hash, %bitcoin_anchor, %error, "quarantining stacks block that failed verification");
+            let block = model::QuarantinedStacksBlock {
+                block_hash: *block_hash,
+                bitcoin_anchor,
+                reason: error.to_string(),
+            };
+            db.write_quarantined_stacks_block(&block).await?;
+        }
+        Ok(())
+    }
+
+    /// Fetch the sBTC peg limits from Emily, falling back to the last
+    /// limits that we fetched if they are younger than the configured TTL.
+    ///
+    /// If we cannot fetch the limits and have no fresh cached limits, the
+    /// current limits are set to [`SbtcLimits::zero`], so that we do not
+    /// act on limits that may be out of date.
+    async fn fetch_emily_limits(&self) -> Result<SbtcLimits, Error> {
+        let state = self.context.state();
+        let now = self.context.clock().now();
+        let error = match self.context.get_emily_client().get_limits().await {
+            Ok(limits) => {
+                state.set_cached_emily_limits(limits.clone(), now);
+                return Ok(limits);
+            }
+            Err(error) => error,
+        };
+
+        let ttl = self.context.config().signer.sbtc_limits_cache_ttl;
+        match state.cached_emily_limits(now, ttl) {
+            Some(limits) => {
+                tracing::warn!(
+                    %error,
+                    "could not fetch sBTC limits from Emily; using cached limits"
+                );
+                metrics::counter!(Metrics::SbtcLimitsFetchFailuresTotal, "fallback" => "cached")
+                    .increment(1);
+                Ok(limits)
+            }
+            None => {
+                tracing::warn!(
+                    %error,
+                    "could not fetch sBTC limits from Emily; no fresh cached limits"
+                );
+                metrics::counter!(Metrics::SbtcLimitsFetchFailuresTotal, "fallback" => "zero")
+                    .increment(1);
+                state.update_current_limits(SbtcLimits::zero());
+                Err(error)
+            }
+        }
+    }
+
     /// Update the sBTC peg limits from Emily
     async fn update_sbtc_limits(&self, chain_tip: BlockHash) -> Result<(), Error> {
-        let limits = self.context.get_emily_client().get_limits().await?;
+        let limits = self.fetch_emily_limits().await?;
         let sbtc_deployed = self.context.state().sbtc_contracts_deployed();
 
         let max_mintable = if limits.total_cap_exists() && sbtc_deployed {
@@ -465,7 +874,16 @@ impl<C: Context, B> BlockObserver<C, B> {
             Some(withdrawn_total),
             Some(max_mintable),
         );
+        let chain_tip_height = self
+            .context
+            .get_storage()
+            .get_bitcoin_block(&chain_tip.into())
+            .await?
+            .ok_or(Error::UnknownBitcoinBlock(chain_tip))?
+            .block_height;
+
         let signer_state = self.context.state();
+        let limits = limit_override::apply_active_override(signer_state, limits, chain_tip_height);
         if limits == signer_state.get_current_limits() {
             tracing::trace!(%limits, "sBTC limits have not changed");
         } else {
@@ -506,7 +924,21 @@ impl<C: Context, B> BlockObserver<C, B> {
             .map(model::BitcoinBlockRef::from)
             .ok_or_else(|| Error::UnknownBitcoinBlock(chain_tip))?;
 
-        self.context.state().set_bitcoin_chain_tip(chain_tip);
+        // If the previous chain tip is no longer on the canonical chain
+        // then there has been a reorg, so we drop the cached deposit
+        // scripts.
+        let state = self.context.state();
+        if let Some(previous_chain_tip) = state.bitcoin_chain_tip() {
+            let is_canonical = db
+                .in_canonical_bitcoin_blockchain(&chain_tip, &previous_chain_tip)
+                .await?;
+            if !is_canonical {
+                tracing::info!("bitcoin reorg detected; clearing the cached deposit scripts");
+                state.clear_deposit_scripts();
+            }
+        }
+
+        state.set_bitcoin_chain_tip(chain_tip);
         Ok(())
     }
 
@@ -531,6 +963,24 @@ impl<C: Context, B> BlockObserver<C, B> {
         self.update_bitcoin_chain_tip(chain_tip).await
     }
 
+    /// Bring the registry of aggregate keys up to date with our DKG shares
+    /// and with the aggregate key in the registry contract as of the
+    /// current bitcoin chain tip.
+    async fn update_aggregate_key_registry(&self) -> Result<(), Error> {
+        let db = self.context.get_storage_mut();
+        for shares in db.get_all_encrypted_dkg_shares().await? {
+            db.register_aggregate_key(&shares).await?;
+        }
+
+        let state = self.context.state();
+        let info = state.registry_signer_set_info();
+        let Some((info, chain_tip)) = info.zip(state.bitcoin_chain_tip()) else {
+            return Ok(());
+        };
+        db.activate_aggregate_key(&info.aggregate_key, chain_tip.block_height)
+            .await
+    }
+
     /// Checks if the latest dkg share is pending and is no longer valid
     async fn check_pending_dkg_shares(&self, chain_tip: BlockHash) -> Result<(), Error> {
         let db = self.context.get_storage_mut();
@@ -577,6 +1027,42 @@ impl<C: Context, B> BlockObserver<C, B> {
     }
 }
 
+/// The number of blocks up to and including the chain tip that
+/// bitcoin-core has the data of, or `None` if it is not a pruned node.
+pub fn retained_blocks(info: &GetBlockchainInfoResult) -> Option<u64> {
+    if !info.pruned {
+        return None;
+    }
+    let prune_height = info.prune_height.unwrap_or_default();
+    Some((info.blocks + 1).saturating_sub(prune_height))
+}
+
+/// Check that bitcoin-core, if it is a pruned node, keeps the data of
+/// enough blocks for the signer.
+///
+/// The signer looks up transactions in the blocks of its context window,
+/// so a pruned node must keep at least that many blocks. Pruning
+/// progresses as new blocks arrive, so this keeps holding once it holds
+/// when the signer starts.
+pub async fn check_prune_depth<C: Context>(ctx: &C) -> Result<(), Error> {
+    let info = ctx.get_bitcoin_client().get_blockchain_info().await?;
+    let Some(retained) = retained_blocks(&info) else {
+        return Ok(());
+    };
+
+    let required = u64::from(ctx.config().signer.context_window);
+    if retained < required {
+        return Err(Error::BitcoinCorePruneDepthTooShallow { retained, required });
+    }
+
+    tracing::info!(
+        retained,
+        required,
+        "bitcoin-core is a pruned node that keeps enough blocks"
+    );
+    Ok(())
+}
+
 /// Extract all BTC transactions from the block where one of the UTXOs
 /// can be spent by the signers.
 ///
@@ -668,6 +1154,28 @@ where
             }
 
             let (tx_outputs, withdrawal_outputs) = tx_info.to_outputs(&signer_script_pubkeys)?;
+            // Sweeps may include metadata that commits to the requests
+            // that they service. External indexers rely on it, so we flag
+            // sweeps where it does not match the sweep itself.
+            if let Some(metadata) = tx_info.to_sweep_metadata(&tx_outputs) {
+                let deposits: Vec<_> = tx_info
+                    .tx
+                    .input
+                    .iter()
+                    .skip(1)
+                    .map(|tx_in| tx_in.previous_output)
+                    .collect();
+                let withdrawal_ids: Vec<_> = withdrawal_outputs
+                    .iter()
+                    .map(|output| output.request_id)
+                    .collect();
+                if !metadata.commits_to(&deposits, &withdrawal_ids) {
+                    tracing::warn!(%txid, "sweep metadata does not match the serviced requests");
+                }
+            }
+            for payment in tx_info.to_signer_payments(&tx_outputs) {
+                db.write_signer_payment(&payment).await?;
+            }
             for output in tx_outputs {
                 db.write_tx_output(&output).await?;
             }
@@ -694,6 +1202,66 @@ where
     extract_fut().await
 }
 
+/// Find the deposit requests that the given transactions reclaim and
+/// write the reclaims to the database, returning them.
+///
+/// Deposits are locked in taproot outputs that can only be spent through
+/// one of two scripts: the deposit script, which the signers use when
+/// sweeping the deposit, and the reclaim script of the depositor. So a
+/// deposit is reclaimed when it is spent with any other script than the
+/// deposit script.
+pub async fn extract_deposit_reclaims<Storage>(
+    db: &Storage,
+    block_ref: &model::BitcoinBlockRef,
+    txs: &[BitcoinTxInfo],
+) -> Result<Vec<model::DepositReclaim>, Error>
+where
+    Storage: DbRead + DbWrite,
+{
+    // Only script-path spends of taproot outputs can spend a deposit.
+    let spends: HashMap<bitcoin::OutPoint, (bitcoin::Txid, &bitcoin::Script)> = txs
+        .iter()
+        .filter(|tx_info| !tx_info.tx.is_coinbase())
+        .flat_map(|tx_info| {
+            let txid = tx_info.compute_txid();
+            tx_info.tx.input.iter().filter_map(move |tx_in| {
+                let script = tx_in.witness.tapscript()?;
+                Some((tx_in.previous_output, (txid, script)))
+            })
+        })
+        .collect();
+    if spends.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let outpoints: Vec<bitcoin::OutPoint> = spends.keys().copied().collect();
+    let deposit_requests = db.get_deposit_requests_for_outpoints(&outpoints).await?;
+
+    let mut reclaims = Vec::new();
+    for request in deposit_requests {
+        let outpoint = request.outpoint();
+        let Some((reclaim_txid, script)) = spends.get(&outpoint) else {
+            continue;
+        };
+        if script.as_bytes() == request.spend_script.as_slice() {
+            continue;
+        }
+
+        tracing::info!(%outpoint, %reclaim_txid, "deposit request reclaimed by the depositor");
+        let reclaim = model::DepositReclaim {
+            txid: request.txid,
+            output_index: request.output_index,
+            reclaim_txid: (*reclaim_txid).into(),
+            bitcoin_block_hash: block_ref.block_hash,
+            bitcoin_block_height: block_ref.block_height,
+        };
+        db.write_deposit_reclaim(&reclaim).await?;
+        reclaims.push(reclaim);
+    }
+
+    Ok(reclaims)
+}
+
 /// Return the signing set that can make sBTC related contract calls along
 /// with the current aggregate key to use for locking UTXOs on bitcoin.
 ///
@@ -1110,4 +1678,93 @@ mod tests {
         assert_eq!(tx_ids.len(), 1);
         assert!(tx_ids.contains(&expected_tx_id));
     }
+
+    /// Return a transaction that spends the given outpoint through the
+    /// given tapscript.
+    fn script_path_spend(outpoint: bitcoin::OutPoint, script: &[u8]) -> bitcoin::Transaction {
+        let signature = vec![1; 64];
+        let control_block = vec![0xc0; 33];
+        bitcoin::Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: vec![bitcoin::TxIn {
+                previous_output: outpoint,
+                script_sig: ScriptBuf::new(),
+                sequence: bitcoin::Sequence::ZERO,
+                witness: bitcoin::Witness::from_slice(&[signature, script.to_vec(), control_block]),
+            }],
+            output: vec![TxOut {
+                value: Amount::from_sat(50_000),
+                script_pubkey: ScriptBuf::new(),
+            }],
+        }
+    }
+
+    #[test(tokio::test)]
+    async fn deposits_spent_through_the_reclaim_script_are_recorded() {
+        let mut rng = get_rng();
+        let storage = storage::memory::Store::new_shared();
+
+        let reclaimed: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
+        let swept: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
+        storage.write_deposit_request(&reclaimed).await.unwrap();
+        storage.write_deposit_request(&swept).await.unwrap();
+
+        let reclaim_tx = script_path_spend(reclaimed.outpoint(), &reclaimed.reclaim_script);
+        // The signers sweep deposits through the deposit script, and
+        // spends of outpoints that are not deposits are none of our
+        // business.
+        let sweep_tx = script_path_spend(swept.outpoint(), &swept.spend_script);
+        let unrelated: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
+        let unrelated_tx = script_path_spend(unrelated.outpoint(), &unrelated.reclaim_script);
+
+        let block_ref = model::BitcoinBlockRef {
+            block_hash: fake::Faker.fake_with_rng(&mut rng),
+            block_height: 100u64.into(),
+        };
+        let txs = [
+            reclaim_tx.fake_with_rng(&mut rng),
+            sweep_tx.fake_with_rng(&mut rng),
+            unrelated_tx.fake_with_rng(&mut rng),
+        ];
+        let reclaims = extract_deposit_reclaims(&storage, &block_ref, &txs)
+            .await
+            .unwrap();
+
+        let expected = model::DepositReclaim {
+            txid: reclaimed.txid,
+            output_index: reclaimed.output_index,
+            reclaim_txid: reclaim_tx.compute_txid().into(),
+            bitcoin_block_hash: block_ref.block_hash,
+            bitcoin_block_height: block_ref.block_height,
+        };
+        assert_eq!(reclaims, vec![expected.clone()]);
+
+        // Processing the same block again does not record the reclaim
+        // twice.
+        extract_deposit_reclaims(&storage, &block_ref, &txs)
+            .await
+            .unwrap();
+        let store = storage.lock().await;
+        let stored = store
+            .deposit_reclaims
+            .get(&(reclaimed.txid, reclaimed.output_index));
+        assert_eq!(stored, Some(&vec![expected]));
+        assert_eq!(store.deposit_reclaims.len(), 1);
+    }
+
+    #[test]
+    fn retained_blocks_counts_blocks_above_the_prune_height() {
+        let json = include_str!("../tests/fixtures/bitcoind-getblockchaininfo-data.json");
+        let mut info: GetBlockchainInfoResult = serde_json::from_str(json).unwrap();
+        assert_eq!(retained_blocks(&info), None);
+
+        info.pruned = true;
+        info.blocks = 1370;
+        info.prune_height = Some(371);
+        assert_eq!(retained_blocks(&info), Some(1000));
+
+        info.prune_height = Some(1371);
+        assert_eq!(retained_blocks(&info), Some(0));
+    }
 }
@@ -0,0 +1,98 @@
+//! Audit records of how long this signer takes to process bitcoin blocks.
+//!
+//! Each event loop records when it is done with its part of the work on a
+//! new bitcoin block: the block observer when it observes the block and
+//! when it has fetched the deposit requests from Emily, the request
+//! decider when it has sent its decisions, and the coordinator when it
+//! has completed its tenure. The records are kept in the database so that
+//! slow tenures can be diagnosed after the fact, and the time from
+//! observing the block to each later stage is recorded in the
+//! [`Metrics::BitcoinBlockProcessingSeconds`] histogram.
+
+use crate::context::Context;
+use crate::metrics::Metrics;
+use crate::storage::DbRead as _;
+use crate::storage::DbWrite as _;
+use crate::storage::model::BitcoinBlockHash;
+use crate::storage::model::BlockProcessingStage;
+use crate::storage::model::Timestamp;
+
+/// Record that this signer reached the given stage in the processing of
+/// the bitcoin block with the given hash just now.
+///
+/// Failures are logged and otherwise ignored, since the records are only
+/// used for diagnostics and must not hold up the processing itself.
+pub async fn record_stage(
+    ctx: &impl Context,
+    block_hash: &BitcoinBlockHash,
+    stage: BlockProcessingStage,
+) {
+    let db = ctx.get_storage_mut();
+    let at = Timestamp::from(time::OffsetDateTime::now_utc());
+    let stage_name: &'static str = stage.into();
+
+    if let Err(error) = db.write_block_processing_stage(block_hash, stage, at).await {
+        tracing::warn!(
+            %error,
+            %block_hash,
+            stage = stage_name,
+            "could not record block processing stage"
+        );
+        return;
+    }
+    if stage == BlockProcessingStage::Observed {
+        return;
+    }
+
+    let processing = match db.get_bitcoin_block_processing(block_hash).await {
+        Ok(processing) => processing,
+        Err(error) => {
+            tracing::warn!(%error, %block_hash, "could not read block processing record");
+            return;
+        }
+    };
+    let elapsed = processing.and_then(|processing| processing.time_since_observed(stage));
+    if let Some(elapsed) = elapsed {
+        metrics::histogram!(Metrics::BitcoinBlockProcessingSeconds, "stage" => stage_name)
+            .record(elapsed.as_secs_f64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+    use fake::Faker;
+
+    use super::*;
+    use crate::testing::context::*;
+
+    #[tokio::test]
+    async fn stages_are_recorded_once() {
+        let ctx = TestContext::default_mocked();
+        let block_hash: BitcoinBlockHash = Faker.fake();
+
+        record_stage(&ctx, &block_hash, BlockProcessingStage::Observed).await;
+        record_stage(&ctx, &block_hash, BlockProcessingStage::DecisionsCompleted).await;
+
+        let db = ctx.get_storage();
+        let processing = db
+            .get_bitcoin_block_processing(&block_hash)
+            .await
+            .unwrap()
+            .unwrap();
+        let observed_at = processing.observed_at.unwrap();
+        assert!(processing.deposits_fetched_at.is_none());
+        assert!(processing.decisions_completed_at.is_some());
+        assert!(processing.tenure_completed_at.is_none());
+
+        // Observing the block again, say after a reorg back onto it, keeps
+        // the time that it was first observed.
+        record_stage(&ctx, &block_hash, BlockProcessingStage::Observed).await;
+        let processing = db
+            .get_bitcoin_block_processing(&block_hash)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(processing.observed_at, Some(observed_at));
+    }
+}
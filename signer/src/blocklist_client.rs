@@ -12,6 +12,7 @@ use std::future::Future;
 use std::time::Duration;
 
 use crate::config::BlocklistClientConfig;
+use crate::context::Clock;
 use crate::error::Error;
 
 /// Blocklist client error variants.
@@ -34,6 +35,7 @@ pub trait BlocklistChecker {
 pub struct BlocklistClient {
     config: Configuration,
     retry_delay: Duration,
+    clock: Clock,
 }
 
 impl BlocklistChecker for BlocklistClient {
@@ -41,7 +43,7 @@ impl BlocklistChecker for BlocklistClient {
         let response = self.check_address(address).await;
         if let Err(error) = response {
             tracing::error!(%error, "blocklist client error, sleeping and retrying once");
-            tokio::time::sleep(self.retry_delay).await;
+            self.clock.sleep(self.retry_delay).await;
             self.check_address(address).await
         } else {
             response
@@ -68,9 +70,16 @@ impl BlocklistClient {
         BlocklistClient {
             config,
             retry_delay: client_config.retry_delay,
+            clock: Clock::system(),
         }
     }
 
+    /// Use the given clock when sleeping before retrying a failed request.
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Construct a new [`BlocklistClient`] from a base url
     #[cfg(any(test, feature = "testing"))]
     pub fn with_base_url(base_url: String) -> Self {
@@ -82,6 +91,7 @@ impl BlocklistClient {
         BlocklistClient {
             config,
             retry_delay: Duration::ZERO,
+            clock: Clock::system(),
         }
     }
 
@@ -0,0 +1,610 @@
+//! Broadcasting of the signed sweep transactions of the coordinator in
+//! the background.
+//!
+//! Broadcasting a sweep means calling bitcoin-core, possibly through each
+//! of the fallback clients, and a slow or unreachable bitcoin-core eats
+//! into the tenure of the coordinator. When the `async_sweep_broadcast`
+//! option is enabled, the coordinator instead adds its signed sweeps to a
+//! queue in the database, signals [`TxCoordinatorEvent::SweepsQueued`],
+//! and waits for the outcome no longer than the rest of its tenure. It
+//! only attests to the sweeps, and accepts their requests on Emily, once
+//! the broadcaster signals [`TxCoordinatorEvent::SweepBroadcast`] for
+//! them. The broadcaster picks the sweeps up from
+//! the queue and broadcasts them, retrying failures to reach bitcoin-core
+//! up to [`MAX_BROADCAST_ATTEMPTS`] times and handling rejections the same
+//! way that the coordinator does, see [`handle_broadcast_error`]. Since
+//! the queue lives in the database, sweeps that were queued before a
+//! restart are still broadcast afterward.
+//!
+//! Once bitcoin-core accepts a sweep, the broadcaster follows it until it
+//! is confirmed, at which point it signals a
+//! [`BroadcasterEvent::SweepConfirmed`] event. A sweep that is still in
+//! the mempool [`SWEEP_FEE_BUMP_BLOCKS`] blocks after it was queued has
+//! its fee rate raised by [`BROADCAST_FEE_BUMP_FACTOR`], which the next
+//! coordinator picks up when it replaces the sweep through RBF, and is
+//! signalled with a [`BroadcasterEvent::SweepFeeBumped`] event. Sweeps
+//! that the broadcaster gives up on, or that bitcoin-core drops from its
+//! mempool, are signalled with a [`BroadcasterEvent::SweepDropped`] event.
+
+use std::time::Duration;
+
+use bitcoin::Transaction;
+use futures::StreamExt as _;
+
+use crate::BROADCAST_FEE_BUMP_FACTOR;
+use crate::MAX_BROADCAST_ATTEMPTS;
+use crate::SWEEP_FEE_BUMP_BLOCKS;
+use crate::bitcoin::BitcoinInteract as _;
+use crate::bitcoin::broadcast::BroadcastHandling;
+use crate::bitcoin::broadcast::BroadcastRejection;
+use crate::bitcoin::units::FeeRate;
+use crate::context::Context;
+use crate::context::SignerCommand;
+use crate::context::SignerEvent;
+use crate::context::SignerSignal;
+use crate::context::TxCoordinatorEvent;
+use crate::error::Error;
+use crate::metrics::BITCOIN_BLOCKCHAIN;
+use crate::metrics::Metrics;
+use crate::storage::DbRead as _;
+use crate::storage::DbWrite as _;
+use crate::storage::model::BitcoinBlockHash;
+use crate::storage::model::BitcoinBroadcast;
+use crate::storage::model::BitcoinBroadcastStatus;
+use crate::storage::model::BitcoinTxId;
+
+/// How often the broadcaster goes over its queue when it is not woken up
+/// by newly queued sweeps or a new bitcoin block.
+pub const BROADCASTER_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Events raised by the broadcaster.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BroadcasterEvent {
+    /// A sweep transaction that bitcoin-core accepted was confirmed in a
+    /// bitcoin block.
+    SweepConfirmed {
+        /// The ID of the sweep transaction.
+        txid: BitcoinTxId,
+        /// The bitcoin block that confirmed the sweep transaction.
+        block_hash: BitcoinBlockHash,
+    },
+    /// A sweep transaction sat in the mempool for too long, so the fee
+    /// rate of the next sweep was raised to replace it.
+    SweepFeeBumped {
+        /// The ID of the sweep transaction.
+        txid: BitcoinTxId,
+        /// The fee rate that the next sweep pays at least, in sats per
+        /// vbyte.
+        min_fee_rate: f64,
+    },
+    /// The broadcaster gave up on a sweep transaction, so its requests
+    /// are left for a later tenure.
+    SweepDropped {
+        /// The ID of the sweep transaction.
+        txid: BitcoinTxId,
+        /// A description of why the sweep was dropped.
+        error: String,
+    },
+}
+
+/// This function defines which messages the broadcaster is interested
+/// in.
+fn run_loop_message_filter(signal: &SignerSignal) -> bool {
+    matches!(
+        signal,
+        SignerSignal::Command(SignerCommand::Shutdown)
+            | SignerSignal::Event(SignerEvent::BitcoinBlockObserved)
+            | SignerSignal::Event(SignerEvent::TxCoordinator(TxCoordinatorEvent::SweepsQueued))
+    )
+}
+
+/// Broadcast the queued sweep transactions of the coordinator and follow
+/// them until they are confirmed, until shutdown.
+///
+/// The queue is processed whenever the coordinator queues new sweeps,
+/// whenever a new bitcoin block is observed, and otherwise every
+/// [`BROADCASTER_INTERVAL`]. Failures to process the queue are logged and
+/// the task carries on, since bitcoin-core may be unavailable for a while.
+pub async fn run_sweep_broadcaster(ctx: impl Context) -> Result<(), Error> {
+    if !ctx.config().signer.async_sweep_broadcast {
+        return Ok(());
+    }
+
+    let mut signal_stream = ctx.as_signal_stream(run_loop_message_filter);
+    loop {
+        if let Err(error) = process_broadcast_queue(&ctx).await {
+            tracing::warn!(%error, "could not process the queue of the broadcaster");
+        }
+
+        tokio::select! {
+            signal = signal_stream.next() => match signal {
+                None | Some(SignerSignal::Command(SignerCommand::Shutdown)) => return Ok(()),
+                Some(_) => {}
+            },
+            _ = ctx.clock().sleep(BROADCASTER_INTERVAL) => {}
+        }
+    }
+}
+
+/// Go over the queue of the broadcaster once, broadcasting the packages
+/// of sweeps that are pending and following the ones that bitcoin-core
+/// accepted.
+///
+/// Packages are handled in the order that they were queued, since later
+/// packages spend the signers' UTXO created by earlier ones. Failing to
+/// handle one package is logged and does not hold up the others.
+pub async fn process_broadcast_queue(ctx: &impl Context) -> Result<(), Error> {
+    let broadcasts = ctx.get_storage().get_unsettled_bitcoin_broadcasts().await?;
+
+    let mut packages: Vec<Vec<BitcoinBroadcast>> = Vec::new();
+    for broadcast in broadcasts {
+        match packages.last_mut() {
+            Some(package) if package[0].package_txid == broadcast.package_txid => {
+                package.push(broadcast)
+            }
+            _ => packages.push(vec![broadcast]),
+        }
+    }
+
+    for package in packages {
+        let is_pending = package
+            .iter()
+            .any(|broadcast| broadcast.status == BitcoinBroadcastStatus::Pending);
+        let result = if is_pending {
+            broadcast_package(ctx, &package).await
+        } else {
+            follow_package(ctx, &package).await
+        };
+        if let Err(error) = result {
+            let package_txid = package[0].package_txid;
+            tracing::warn!(%error, %package_txid, "could not handle a queued package of sweeps");
+        }
+    }
+
+    Ok(())
+}
+
+/// Broadcast the given package of sweeps, where each one spends the
+/// signers' UTXO created by the one before it, and record the outcome.
+#[tracing::instrument(skip_all, fields(package_txid = %package[0].package_txid))]
+async fn broadcast_package(ctx: &impl Context, package: &[BitcoinBroadcast]) -> Result<(), Error> {
+    let transactions = package
+        .iter()
+        .map(|broadcast| bitcoin::consensus::deserialize::<Transaction>(&broadcast.tx))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(Error::DecodeBitcoinTransaction)?;
+
+    tracing::info!(
+        num_transactions = transactions.len(),
+        "broadcasting queued bitcoin transactions"
+    );
+    let bitcoin_client = ctx.get_bitcoin_client();
+    let response = match transactions.as_slice() {
+        [transaction] => bitcoin_client.broadcast_transaction(transaction).await,
+        _ => bitcoin_client.broadcast_package(&transactions).await,
+    };
+
+    let attempts = package
+        .iter()
+        .map(|broadcast| broadcast.attempts)
+        .max()
+        .unwrap_or_default()
+        .saturating_add(1);
+    let (status, last_error) = match response {
+        Ok(()) => (BitcoinBroadcastStatus::Broadcast, None),
+        Err(error) => {
+            let message = error.to_string();
+            let fee_rate = FeeRate::new(package[0].fee_rate)?;
+            match handle_broadcast_error(ctx, error, fee_rate) {
+                Ok(true) => (BitcoinBroadcastStatus::Broadcast, None),
+                Ok(false) => (BitcoinBroadcastStatus::Failed, Some(message)),
+                // Failing to reach bitcoin-core is worth another try, but
+                // rejections that we cannot do anything about are not.
+                Err(error)
+                    if BroadcastRejection::of(&error).is_none()
+                        && attempts < MAX_BROADCAST_ATTEMPTS =>
+                {
+                    tracing::warn!(%error, attempts, "could not broadcast, will retry");
+                    (BitcoinBroadcastStatus::Pending, Some(message))
+                }
+                Err(error) => {
+                    tracing::warn!(%error, attempts, "giving up on broadcasting");
+                    (BitcoinBroadcastStatus::Failed, Some(message))
+                }
+            }
+        }
+    };
+
+    let db = ctx.get_storage_mut();
+    for broadcast in package {
+        db.record_bitcoin_broadcast_attempt(&broadcast.txid, status, last_error.as_deref())
+            .await?;
+    }
+
+    let metric_status = match status {
+        BitcoinBroadcastStatus::Broadcast => "success",
+        _ => "failure",
+    };
+    metrics::counter!(
+        Metrics::TransactionsSubmittedTotal,
+        "blockchain" => BITCOIN_BLOCKCHAIN,
+        "status" => metric_status,
+    )
+    .increment(package.len() as u64);
+
+    match (status, last_error) {
+        (BitcoinBroadcastStatus::Broadcast, _) => {
+            tracing::info!("queued bitcoin transactions accepted by bitcoin-core");
+            ctx.state().set_min_fee_rate(None);
+            for broadcast in package {
+                let txid = broadcast.txid.into();
+                ctx.state()
+                    .transcripts()
+                    .record_bitcoin_transaction(&broadcast.bitcoin_block_hash, txid);
+                ctx.signal(TxCoordinatorEvent::SweepBroadcast(txid).into())?;
+            }
+        }
+        (BitcoinBroadcastStatus::Failed, Some(error)) => {
+            for broadcast in package {
+                let event = BroadcasterEvent::SweepDropped {
+                    txid: broadcast.txid,
+                    error: error.clone(),
+                };
+                ctx.signal(SignerEvent::Broadcaster(event).into())?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Check whether the sweeps in the given package that bitcoin-core
+/// accepted have been confirmed or dropped, and record the outcome.
+///
+/// Sweeps that are stuck in the mempool have their fee rate bumped, see
+/// [`bump_stuck_sweep_fee`].
+async fn follow_package(ctx: &impl Context, package: &[BitcoinBroadcast]) -> Result<(), Error> {
+    let bitcoin_client = ctx.get_bitcoin_client();
+    let db = ctx.get_storage_mut();
+    let mut fee_bumped = false;
+
+    for broadcast in package {
+        let Some(response) = bitcoin_client.get_tx(&broadcast.txid).await? else {
+            tracing::warn!(txid = %broadcast.txid, "bitcoin-core dropped a broadcast sweep");
+            let error = "bitcoin-core dropped or replaced the transaction".to_string();
+            db.record_bitcoin_broadcast_attempt(
+                &broadcast.txid,
+                BitcoinBroadcastStatus::Failed,
+                Some(&error),
+            )
+            .await?;
+
+            let event = BroadcasterEvent::SweepDropped { txid: broadcast.txid, error };
+            ctx.signal(SignerEvent::Broadcaster(event).into())?;
+            continue;
+        };
+
+        // Sweeps in the mempool have no block hash. The sweeps in a
+        // package confirm together, so bumping the first one is enough.
+        let Some(block_hash) = response.block_hash else {
+            if !fee_bumped {
+                fee_bumped = bump_stuck_sweep_fee(ctx, broadcast).await?;
+            }
+            continue;
+        };
+
+        tracing::info!(txid = %broadcast.txid, %block_hash, "broadcast sweep confirmed");
+        db.set_bitcoin_broadcast_status(&broadcast.txid, BitcoinBroadcastStatus::Confirmed)
+            .await?;
+
+        let event = BroadcasterEvent::SweepConfirmed {
+            txid: broadcast.txid,
+            block_hash: block_hash.into(),
+        };
+        ctx.signal(SignerEvent::Broadcaster(event).into())?;
+    }
+
+    Ok(())
+}
+
+/// Raise the fee rate of the next sweep if the given sweep has been in
+/// the mempool for at least [`SWEEP_FEE_BUMP_BLOCKS`] blocks, returning
+/// whether it did.
+///
+/// The broadcaster cannot sign a replacement itself. The next coordinator
+/// finds the sweep in the mempool and replaces it through RBF, paying at
+/// least the raised fee rate.
+async fn bump_stuck_sweep_fee(
+    ctx: &impl Context,
+    broadcast: &BitcoinBroadcast,
+) -> Result<bool, Error> {
+    let Some(chain_tip) = ctx.state().bitcoin_chain_tip() else {
+        return Ok(false);
+    };
+    let Some(queued_at) = ctx
+        .get_storage()
+        .get_bitcoin_block(&broadcast.bitcoin_block_hash)
+        .await?
+    else {
+        return Ok(false);
+    };
+
+    let blocks_in_mempool = (*chain_tip.block_height).saturating_sub(*queued_at.block_height);
+    if blocks_in_mempool < SWEEP_FEE_BUMP_BLOCKS {
+        return Ok(false);
+    }
+
+    let fee_rate = FeeRate::new(broadcast.fee_rate)?;
+    let min_fee_rate = FeeRate::new(fee_rate.to_sat_per_vb() * BROADCAST_FEE_BUMP_FACTOR)?;
+    if ctx.state().min_fee_rate() >= Some(min_fee_rate) {
+        return Ok(false);
+    }
+
+    tracing::warn!(
+        txid = %broadcast.txid,
+        blocks_in_mempool,
+        %fee_rate,
+        %min_fee_rate,
+        "broadcast sweep is stuck in the mempool, raising the fee rate for the next tenure"
+    );
+    ctx.state().set_min_fee_rate(Some(min_fee_rate));
+
+    let event = BroadcasterEvent::SweepFeeBumped {
+        txid: broadcast.txid,
+        min_fee_rate: min_fee_rate.to_sat_per_vb(),
+    };
+    ctx.signal(SignerEvent::Broadcaster(event).into())?;
+    Ok(true)
+}
+
+/// Apply the handling for the rejection in the given error from
+/// broadcasting transactions that pay the given fee rate, returning
+/// whether bitcoin-core has the transactions.
+///
+/// Errors that are not rejections of the transactions, and rejections
+/// that we cannot do anything about, are returned as they are.
+pub fn handle_broadcast_error(
+    ctx: &impl Context,
+    error: Error,
+    fee_rate: FeeRate,
+) -> Result<bool, Error> {
+    let Some(rejection) = BroadcastRejection::of(&error) else {
+        return Err(error);
+    };
+
+    let reason: &'static str = rejection.into();
+    metrics::counter!(Metrics::BitcoinBroadcastRejectionsTotal, "reason" => reason).increment(1);
+
+    match rejection.handling() {
+        BroadcastHandling::Ignore => {
+            tracing::info!(%error, "bitcoin-core already has the transactions");
+            Ok(true)
+        }
+        BroadcastHandling::Rebuild => {
+            tracing::warn!(
+                %error,
+                "the transactions spend missing inputs, leaving them for the next tenure"
+            );
+            Ok(false)
+        }
+        BroadcastHandling::FeeBump => {
+            let min_fee_rate = FeeRate::new(fee_rate.to_sat_per_vb() * BROADCAST_FEE_BUMP_FACTOR)?;
+            tracing::warn!(
+                %error,
+                %fee_rate,
+                %min_fee_rate,
+                "the transactions pay too little, raising the fee rate for the next tenure"
+            );
+            ctx.state().set_min_fee_rate(Some(min_fee_rate));
+            Ok(false)
+        }
+        BroadcastHandling::Abort => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use bitcoin::hashes::Hash as _;
+
+    use super::*;
+    use crate::bitcoin::rpc::GetTxResponse;
+    use crate::storage::model::BitcoinBlock;
+    use crate::storage::model::BitcoinBlockRef;
+    use crate::testing::context::*;
+
+    fn sweep() -> Transaction {
+        Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: vec![bitcoin::TxIn::default()],
+            output: vec![bitcoin::TxOut {
+                value: bitcoin::Amount::from_sat(10_000),
+                script_pubkey: bitcoin::ScriptBuf::new(),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn queued_sweeps_are_retried_and_followed_until_confirmed() {
+        let ctx = TestContext::default_mocked();
+        let mut signals = ctx.get_signal_receiver();
+
+        let tx = sweep();
+        let txid: BitcoinTxId = tx.compute_txid().into();
+        let block_hash = bitcoin::BlockHash::from_byte_array([7; 32]);
+        let broadcast = BitcoinBroadcast {
+            txid,
+            package_txid: txid,
+            package_position: 0,
+            tx: bitcoin::consensus::serialize(&tx),
+            fee_rate: 10.0,
+            bitcoin_block_hash: BitcoinBlockHash::from([1; 32]),
+            status: BitcoinBroadcastStatus::Pending,
+            attempts: 0,
+            last_error: None,
+        };
+        ctx.get_storage_mut()
+            .write_bitcoin_broadcasts(&[broadcast])
+            .await
+            .unwrap();
+
+        // bitcoin-core cannot be reached on the first attempt, and
+        // accepts the sweep on the second one.
+        let calls = Arc::new(AtomicUsize::new(0));
+        ctx.with_bitcoin_client(|client| {
+            client.expect_broadcast_transaction().returning(move |_| {
+                let call = calls.fetch_add(1, Ordering::SeqCst);
+                Box::pin(async move { if call == 0 { Err(Error::Dummy) } else { Ok(()) } })
+            });
+            client.expect_get_tx().returning(move |_| {
+                let response = GetTxResponse {
+                    tx: sweep(),
+                    block_hash: Some(block_hash),
+                    confirmations: Some(1),
+                    block_time: None,
+                };
+                Box::pin(async move { Ok(Some(response)) })
+            });
+        })
+        .await;
+
+        process_broadcast_queue(&ctx).await.unwrap();
+        let queued = ctx
+            .get_storage()
+            .get_unsettled_bitcoin_broadcasts()
+            .await
+            .unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].status, BitcoinBroadcastStatus::Pending);
+        assert_eq!(queued[0].attempts, 1);
+        assert!(queued[0].last_error.is_some());
+
+        process_broadcast_queue(&ctx).await.unwrap();
+        let queued = ctx
+            .get_storage()
+            .get_unsettled_bitcoin_broadcasts()
+            .await
+            .unwrap();
+        assert_eq!(queued[0].status, BitcoinBroadcastStatus::Broadcast);
+        assert_eq!(queued[0].last_error, None);
+        assert_eq!(
+            signals.recv().await.unwrap(),
+            TxCoordinatorEvent::SweepBroadcast(*txid).into()
+        );
+
+        // The next pass finds the sweep in a block and settles it.
+        process_broadcast_queue(&ctx).await.unwrap();
+        let queued = ctx
+            .get_storage()
+            .get_unsettled_bitcoin_broadcasts()
+            .await
+            .unwrap();
+        assert!(queued.is_empty());
+
+        let event = BroadcasterEvent::SweepConfirmed {
+            txid,
+            block_hash: block_hash.into(),
+        };
+        assert_eq!(
+            signals.recv().await.unwrap(),
+            SignerEvent::Broadcaster(event).into()
+        );
+    }
+
+    #[tokio::test]
+    async fn sweeps_stuck_in_the_mempool_raise_the_fee_rate_of_the_next_sweep() {
+        let ctx = TestContext::default_mocked();
+        let mut signals = ctx.get_signal_receiver();
+
+        let queued_at = BitcoinBlock {
+            block_hash: BitcoinBlockHash::from([1; 32]),
+            block_height: 10u64.into(),
+            parent_hash: BitcoinBlockHash::from([0; 32]),
+        };
+        ctx.get_storage_mut()
+            .write_bitcoin_block(&queued_at)
+            .await
+            .unwrap();
+
+        // A package that cannot be decoded must not hold up the others.
+        let undecodable = BitcoinBroadcast {
+            txid: BitcoinTxId::from([2; 32]),
+            package_txid: BitcoinTxId::from([2; 32]),
+            package_position: 0,
+            tx: vec![0xff],
+            fee_rate: 10.0,
+            bitcoin_block_hash: queued_at.block_hash,
+            status: BitcoinBroadcastStatus::Pending,
+            attempts: 0,
+            last_error: None,
+        };
+        let tx = sweep();
+        let txid: BitcoinTxId = tx.compute_txid().into();
+        let broadcast = BitcoinBroadcast {
+            txid,
+            package_txid: txid,
+            tx: bitcoin::consensus::serialize(&tx),
+            status: BitcoinBroadcastStatus::Broadcast,
+            ..undecodable.clone()
+        };
+        ctx.get_storage_mut()
+            .write_bitcoin_broadcasts(&[undecodable, broadcast])
+            .await
+            .unwrap();
+
+        ctx.with_bitcoin_client(|client| {
+            client.expect_get_tx().returning(move |_| {
+                let response = GetTxResponse {
+                    tx: sweep(),
+                    block_hash: None,
+                    confirmations: None,
+                    block_time: None,
+                };
+                Box::pin(async move { Ok(Some(response)) })
+            });
+        })
+        .await;
+
+        // The sweep has not been in the mempool for long enough yet.
+        let chain_tip = BitcoinBlockRef {
+            block_hash: BitcoinBlockHash::from([3; 32]),
+            block_height: (10 + SWEEP_FEE_BUMP_BLOCKS - 1).into(),
+        };
+        ctx.state().set_bitcoin_chain_tip(chain_tip);
+        process_broadcast_queue(&ctx).await.unwrap();
+        assert_eq!(ctx.state().min_fee_rate(), None);
+
+        let chain_tip = BitcoinBlockRef {
+            block_hash: BitcoinBlockHash::from([4; 32]),
+            block_height: (10 + SWEEP_FEE_BUMP_BLOCKS).into(),
+        };
+        ctx.state().set_bitcoin_chain_tip(chain_tip);
+        process_broadcast_queue(&ctx).await.unwrap();
+
+        let min_fee_rate = 10.0 * BROADCAST_FEE_BUMP_FACTOR;
+        assert_eq!(
+            ctx.state().min_fee_rate(),
+            Some(FeeRate::new(min_fee_rate).unwrap())
+        );
+        let event = BroadcasterEvent::SweepFeeBumped { txid, min_fee_rate };
+        assert_eq!(
+            signals.recv().await.unwrap(),
+            SignerEvent::Broadcaster(event).into()
+        );
+
+        // The sweep is bumped once, and is still followed.
+        process_broadcast_queue(&ctx).await.unwrap();
+        assert!(signals.try_recv().is_err());
+        let queued = ctx
+            .get_storage()
+            .get_unsettled_bitcoin_broadcasts()
+            .await
+            .unwrap();
+        assert_eq!(queued.len(), 2);
+    }
+}
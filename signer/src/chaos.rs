@@ -0,0 +1,444 @@
+//! Chaos mode for signers running against regtest.
+//!
+//! When the signer is started with `--chaos` on regtest, a [`ChaosMonkey`]
+//! randomly restarts the signer's event loops, delays responses from
+//! Emily, and triggers small bitcoin reorgs using the regtest faucet
+//! address. All of these decisions are drawn from random number generators
+//! derived from a single seed, which is logged on startup, so that a run
+//! which uncovers a failure can be replayed with `--chaos-seed`.
+//!
+//! Replays are only as deterministic as the rest of the environment: the
+//! same seed yields the same sequence of restart intervals, Emily delays
+//! and reorg depths, but not necessarily the same interleaving with the
+//! rest of the signers.
+
+use std::future::Future;
+use std::ops::RangeInclusive;
+use std::str::FromStr as _;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use bitcoin::hashes::Hash as _;
+use bitcoin::hashes::sha256;
+use bitcoincore_rpc::RpcApi as _;
+use emily_client::models::DepositStatus;
+use emily_client::models::DepositUpdate;
+use emily_client::models::UpdateDepositsResponse;
+use emily_client::models::UpdateWithdrawalsResponse;
+use emily_client::models::WithdrawalUpdate;
+use futures::Stream;
+use futures::StreamExt as _;
+use rand::Rng as _;
+use rand::SeedableRng as _;
+use rand::rngs::StdRng;
+use sbtc::deposits::CreateDepositRequest;
+
+use crate::bitcoin::rpc::BitcoinCoreClient;
+use crate::bitcoin::utxo::UnsignedTransaction;
+use crate::config::NetworkKind;
+use crate::context::Clock;
+use crate::context::Context;
+use crate::context::SbtcLimits;
+use crate::emily_client::EmilyApiKeys;
+use crate::emily_client::EmilyInteract;
+use crate::error::Error;
+use crate::storage::model::BitcoinTxId;
+
+/// The address that blocks mined by the chaos monkey pay out to. This is
+/// the same faucet address used by the integration tests and the demo CLI.
+pub const REGTEST_FAUCET_ADDRESS: &str = "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080";
+
+static REGTEST_FAUCET: LazyLock<bitcoin::Address> = LazyLock::new(|| {
+    bitcoin::Address::from_str(REGTEST_FAUCET_ADDRESS)
+        .expect("the regtest faucet address is a valid address")
+        .assume_checked()
+});
+
+/// Settings for chaos mode.
+#[derive(Debug, Clone)]
+pub struct ChaosConfig {
+    /// The seed that all random decisions are derived from.
+    pub seed: u64,
+    /// The range of how long an event loop runs before it is restarted.
+    pub restart_interval: RangeInclusive<Duration>,
+    /// The probability that a call to Emily is delayed.
+    pub emily_delay_probability: f64,
+    /// The range of delays applied to calls to Emily.
+    pub emily_delay: RangeInclusive<Duration>,
+    /// The range of time between reorgs.
+    pub reorg_interval: RangeInclusive<Duration>,
+    /// The range of the number of blocks replaced by a reorg.
+    pub reorg_depth: RangeInclusive<u64>,
+}
+
+impl ChaosConfig {
+    /// Create the default chaos settings with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            restart_interval: Duration::from_secs(60)..=Duration::from_secs(600),
+            emily_delay_probability: 0.25,
+            emily_delay: Duration::from_millis(100)..=Duration::from_secs(10),
+            reorg_interval: Duration::from_secs(300)..=Duration::from_secs(1800),
+            reorg_depth: 1..=3,
+        }
+    }
+}
+
+/// Makes the random decisions for chaos mode.
+#[derive(Debug, Clone)]
+pub struct ChaosMonkey {
+    config: Arc<ChaosConfig>,
+}
+
+impl ChaosMonkey {
+    /// Create a new chaos monkey.
+    pub fn new(config: ChaosConfig) -> Self {
+        Self { config: Arc::new(config) }
+    }
+
+    /// Create a chaos monkey for the signer, if chaos mode can be enabled
+    /// on the given network. Chaos mode is only ever enabled on regtest.
+    pub fn for_network(network: NetworkKind, seed: Option<u64>) -> Option<Self> {
+        if network != NetworkKind::Regtest {
+            tracing::warn!(
+                ?network,
+                "chaos mode is only supported on regtest; ignoring"
+            );
+            return None;
+        }
+
+        let seed = seed.unwrap_or_else(rand::random);
+        tracing::warn!(
+            %seed,
+            "chaos mode enabled; pass --chaos-seed {seed} to replay this run",
+        );
+        Some(Self::new(ChaosConfig::new(seed)))
+    }
+
+    /// The settings of this chaos monkey.
+    pub fn config(&self) -> &ChaosConfig {
+        &self.config
+    }
+
+    /// Return a random number generator for the component with the given
+    /// name. The generator only depends on the seed and the name, so each
+    /// component makes the same sequence of decisions in every run with
+    /// the same seed, regardless of what the other components do.
+    pub fn rng(&self, component: &str) -> StdRng {
+        let preimage = format!("{}:{component}", self.config.seed);
+        StdRng::from_seed(sha256::Hash::hash(preimage.as_bytes()).to_byte_array())
+    }
+
+    /// Run the event loop returned by `f`, restarting it at random
+    /// intervals until it returns on its own.
+    ///
+    /// Restarting drops the running event loop, so any state that it keeps
+    /// in memory is lost, just as if the signer had crashed and come back
+    /// up. The event loop is not restarted once shutdown has been
+    /// signalled.
+    pub async fn run_with_restarts<C, F, Fut>(&self, name: &str, f: F, ctx: C) -> Result<(), Error>
+    where
+        C: Context,
+        F: Fn(C) -> Fut,
+        Fut: Future<Output = Result<(), Error>>,
+    {
+        let mut rng = self.rng(name);
+        let term = ctx.get_termination_handle();
+        loop {
+            let interval = random_duration(&mut rng, &self.config.restart_interval);
+            tokio::select! {
+                result = f(ctx.clone()) => return result,
+                _ = ctx.clock().sleep(interval) => {}
+            }
+
+            if term.shutdown_signalled() {
+                return Ok(());
+            }
+            tracing::warn!(event_loop = %name, "chaos: restarting event loop");
+        }
+    }
+
+    /// Trigger small reorgs on the regtest bitcoin node at random intervals
+    /// until shutdown is signalled.
+    ///
+    /// Each reorg invalidates the last few blocks and mines one more block
+    /// than it invalidated to the faucet address, so that the new chain
+    /// becomes the best chain.
+    pub async fn run_reorgs(&self, ctx: impl Context) -> Result<(), Error> {
+        let Some(endpoint) = ctx.config().bitcoin.rpc_endpoints.first() else {
+            tracing::warn!("chaos: no bitcoin RPC endpoint configured; not triggering reorgs");
+            return Ok(());
+        };
+        let client = BitcoinCoreClient::try_from(endpoint)?;
+
+        let mut rng = self.rng("reorgs");
+        let mut term = ctx.get_termination_handle();
+        loop {
+            let interval = random_duration(&mut rng, &self.config.reorg_interval);
+            tokio::select! {
+                _ = term.wait_for_shutdown() => return Ok(()),
+                _ = ctx.clock().sleep(interval) => {}
+            }
+
+            let depth = rng.gen_range(self.config.reorg_depth.clone());
+            if let Err(error) = trigger_reorg(&client, depth) {
+                tracing::warn!(%error, %depth, "chaos: could not trigger a reorg");
+            }
+        }
+    }
+}
+
+/// Replace the top `depth` blocks of the node's best chain.
+fn trigger_reorg(client: &BitcoinCoreClient, depth: u64) -> Result<(), Error> {
+    let rpc = client.inner_client();
+    let height = rpc.get_block_count()?;
+    let depth = depth.min(height);
+    if depth == 0 {
+        return Ok(());
+    }
+
+    let fork_point = rpc.get_block_hash(height + 1 - depth)?;
+    rpc.invalidate_block(&fork_point)?;
+    rpc.generate_to_address(depth + 1, &REGTEST_FAUCET)?;
+
+    tracing::warn!(%depth, %height, %fork_point, "chaos: triggered a bitcoin reorg");
+    Ok(())
+}
+
+/// Pick a duration uniformly at random from the given range.
+fn random_duration(rng: &mut StdRng, range: &RangeInclusive<Duration>) -> Duration {
+    if range.is_empty() {
+        return *range.start();
+    }
+    rng.gen_range(range.clone())
+}
+
+/// An Emily client that delays a random subset of its calls.
+#[derive(Debug, Clone)]
+pub struct ChaosEmilyClient<E> {
+    inner: E,
+    monkey: ChaosMonkey,
+    rng: Arc<Mutex<StdRng>>,
+    clock: Clock,
+}
+
+impl<E> ChaosEmilyClient<E> {
+    /// Wrap the given Emily client.
+    pub fn new(inner: E, monkey: ChaosMonkey) -> Self {
+        let rng = Arc::new(Mutex::new(monkey.rng("emily")));
+        Self {
+            inner,
+            monkey,
+            rng,
+            clock: Clock::system(),
+        }
+    }
+
+    /// Use the given clock for the delays. This should be the clock of
+    /// the context that the client is used with.
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Return how long to delay the next call, if at all.
+    fn next_delay(&self) -> Option<Duration> {
+        let config = self.monkey.config();
+        let mut rng = self.rng.lock().ok()?;
+        if !rng.gen_bool(config.emily_delay_probability) {
+            return None;
+        }
+        Some(random_duration(&mut rng, &config.emily_delay))
+    }
+
+    /// Delay the call to the Emily method with the given name, if the
+    /// chaos monkey decides to.
+    async fn maybe_delay(&self, method: &'static str) {
+        if let Some(delay) = self.next_delay() {
+            tracing::debug!(%method, delay_ms = delay.as_millis(), "chaos: delaying Emily call");
+            self.clock.sleep(delay).await;
+        }
+    }
+}
+
+impl<E> EmilyInteract for ChaosEmilyClient<E>
+where
+    E: EmilyInteract,
+{
+    async fn get_deposit(
+        &self,
+        txid: &BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<CreateDepositRequest>, Error> {
+        self.maybe_delay("get_deposit").await;
+        self.inner.get_deposit(txid, output_index).await
+    }
+
+    fn get_deposits(&self) -> impl Stream<Item = Result<CreateDepositRequest, Error>> + Send {
+        futures::stream::once(self.maybe_delay("get_deposits"))
+            .flat_map(move |()| self.inner.get_deposits())
+    }
+
+    async fn get_deposits_with_status(
+        &self,
+        status: DepositStatus,
+    ) -> Result<Vec<CreateDepositRequest>, Error> {
+        self.maybe_delay("get_deposits_with_status").await;
+        self.inner.get_deposits_with_status(status).await
+    }
+
+    async fn accept_deposits<'a>(
+        &'a self,
+        transaction: &'a UnsignedTransaction<'a>,
+    ) -> Result<UpdateDepositsResponse, Error> {
+        self.maybe_delay("accept_deposits").await;
+        self.inner.accept_deposits(transaction).await
+    }
+
+    async fn accept_withdrawals<'a>(
+        &'a self,
+        transaction: &'a UnsignedTransaction<'a>,
+    ) -> Result<UpdateWithdrawalsResponse, Error> {
+        self.maybe_delay("accept_withdrawals").await;
+        self.inner.accept_withdrawals(transaction).await
+    }
+
+    async fn update_deposits(
+        &self,
+        update_deposits: Vec<DepositUpdate>,
+    ) -> Result<UpdateDepositsResponse, Error> {
+        self.maybe_delay("update_deposits").await;
+        self.inner.update_deposits(update_deposits).await
+    }
+
+    async fn update_withdrawals(
+        &self,
+        update_withdrawals: Vec<WithdrawalUpdate>,
+    ) -> Result<UpdateWithdrawalsResponse, Error> {
+        self.maybe_delay("update_withdrawals").await;
+        self.inner.update_withdrawals(update_withdrawals).await
+    }
+
+    async fn get_limits(&self) -> Result<SbtcLimits, Error> {
+        self.maybe_delay("get_limits").await;
+        self.inner.get_limits().await
+    }
+
+    fn api_keys(&self) -> Option<EmilyApiKeys> {
+        self.inner.api_keys()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use rand::RngCore as _;
+
+    use crate::emily_client::MockEmilyInteract;
+    use crate::testing::context::*;
+
+    use super::*;
+
+    #[test]
+    fn same_seed_same_decisions() {
+        let monkey1 = ChaosMonkey::new(ChaosConfig::new(42));
+        let monkey2 = ChaosMonkey::new(ChaosConfig::new(42));
+        let monkey3 = ChaosMonkey::new(ChaosConfig::new(43));
+
+        let draw = |monkey: &ChaosMonkey, component| -> Vec<u64> {
+            let mut rng = monkey.rng(component);
+            (0..8).map(|_| rng.next_u64()).collect()
+        };
+
+        assert_eq!(draw(&monkey1, "emily"), draw(&monkey2, "emily"));
+        assert_ne!(draw(&monkey1, "emily"), draw(&monkey1, "reorgs"));
+        assert_ne!(draw(&monkey1, "emily"), draw(&monkey3, "emily"));
+    }
+
+    #[test]
+    fn chaos_mode_is_only_enabled_on_regtest() {
+        assert!(ChaosMonkey::for_network(NetworkKind::Mainnet, Some(1)).is_none());
+        assert!(ChaosMonkey::for_network(NetworkKind::Testnet, Some(1)).is_none());
+
+        let monkey = ChaosMonkey::for_network(NetworkKind::Regtest, Some(1)).unwrap();
+        assert_eq!(monkey.config().seed, 1);
+    }
+
+    #[tokio::test]
+    async fn event_loops_are_restarted_until_they_return() {
+        let mut ctx = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .build();
+        let clock = Clock::manual();
+        ctx.set_clock(clock.clone());
+
+        let mut config = ChaosConfig::new(7);
+        config.restart_interval = Duration::from_secs(10)..=Duration::from_secs(10);
+        let monkey = ChaosMonkey::new(config);
+
+        let starts = Arc::new(AtomicUsize::new(0));
+        let event_loop = {
+            let starts = starts.clone();
+            move |ctx: TestContext<_, _, _, _>| {
+                let starts = starts.clone();
+                async move {
+                    // The third run returns on its own.
+                    if starts.fetch_add(1, Ordering::SeqCst) == 2 {
+                        return Ok(());
+                    }
+                    ctx.clock().sleep(Duration::from_secs(60)).await;
+                    Ok(())
+                }
+            }
+        };
+
+        let handle =
+            tokio::spawn(async move { monkey.run_with_restarts("test", event_loop, ctx).await });
+
+        // Each advance ends the current run once the restart timer has
+        // been registered, so keep advancing until the third run returns.
+        while !handle.is_finished() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            clock.advance(Duration::from_secs(10));
+        }
+
+        handle.await.unwrap().unwrap();
+        assert_eq!(starts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn emily_delays_follow_the_clock() {
+        let clock = Clock::manual();
+
+        let mut config = ChaosConfig::new(7);
+        config.emily_delay_probability = 1.0;
+        config.emily_delay = Duration::from_secs(5)..=Duration::from_secs(5);
+        let monkey = ChaosMonkey::new(config);
+
+        let mut inner = MockEmilyInteract::new();
+        inner
+            .expect_get_limits()
+            .returning(|| Box::pin(async { Ok(SbtcLimits::unlimited()) }));
+        let client = ChaosEmilyClient::new(inner, monkey).with_clock(clock.clone());
+
+        let handle = tokio::spawn(async move { client.get_limits().await });
+
+        tokio::task::yield_now().await;
+        clock.advance(Duration::from_secs(4));
+        tokio::task::yield_now().await;
+        assert!(!handle.is_finished());
+
+        clock.advance(Duration::from_secs(1));
+        tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+    }
+}
@@ -178,7 +178,19 @@ mod tests {
     use crate::keys::PublicKey;
     use crate::message::BitcoinPreSignAck;
     use crate::message::BitcoinPreSignRequest;
+    use crate::message::CoordinatorAttestation;
+    use crate::message::DatabaseDigest;
+    use crate::message::DecisionAcknowledgement;
+    use crate::message::DepositDecisionRetryRequest;
+    use crate::message::EmergencyRecoveryApproval;
+    use crate::message::EmergencyRecoveryRevocation;
+    use crate::message::LimitOverrideVote;
+    use crate::message::MessageRedeliveryRequest;
+    use crate::message::RedeliveredMessages;
+    use crate::message::SignRequestRefusal;
+    use crate::message::SignerDecisionBatch;
     use crate::message::SignerDepositDecision;
+    use crate::message::SignerKeyEndorsement;
     use crate::message::SignerMessage;
     use crate::message::SignerWithdrawalDecision;
     use crate::message::StacksTransactionSignRequest;
@@ -186,7 +198,9 @@ mod tests {
     use crate::message::WstsMessage;
     use crate::proto;
     use crate::stacks::contracts::AcceptWithdrawalV1;
+    use crate::stacks::contracts::AcceptWithdrawalsV1;
     use crate::stacks::contracts::CompleteDepositV1;
+    use crate::stacks::contracts::CompleteDepositsV1;
     use crate::stacks::contracts::RejectWithdrawalV1;
     use crate::stacks::contracts::RotateKeysV1;
     use crate::storage::model::BitcoinBlockHash;
@@ -231,7 +245,9 @@ mod tests {
     #[test_case(PhantomData::<(SignerWithdrawalDecision, proto::SignerWithdrawalDecision)>; "SignerWithdrawalDecision")]
     #[test_case(PhantomData::<(StacksTransactionSignature, proto::StacksTransactionSignature)>; "StacksTransactionSignature")]
     #[test_case(PhantomData::<(CompleteDepositV1, proto::CompleteDeposit)>; "CompleteDeposit")]
+    #[test_case(PhantomData::<(CompleteDepositsV1, proto::CompleteDeposits)>; "CompleteDeposits")]
     #[test_case(PhantomData::<(AcceptWithdrawalV1, proto::AcceptWithdrawal)>; "AcceptWithdrawal")]
+    #[test_case(PhantomData::<(AcceptWithdrawalsV1, proto::AcceptWithdrawals)>; "AcceptWithdrawals")]
     #[test_case(PhantomData::<(RejectWithdrawalV1, proto::RejectWithdrawal)>; "RejectWithdrawal")]
     #[test_case(PhantomData::<(RotateKeysV1, proto::RotateKeys)>; "RotateKeys")]
     #[test_case(PhantomData::<(StacksTransactionSignRequest, proto::StacksTransactionSignRequest)>; "StacksTransactionSignRequest")]
@@ -243,6 +259,18 @@ mod tests {
     #[test_case(PhantomData::<(Fees, proto::Fees)>; "Fees")]
     #[test_case(PhantomData::<(BitcoinPreSignRequest, proto::BitcoinPreSignRequest)>; "BitcoinPreSignRequest")]
     #[test_case(PhantomData::<(BitcoinPreSignAck, proto::BitcoinPreSignAck)>; "BitcoinPreSignAck")]
+    #[test_case(PhantomData::<(CoordinatorAttestation, proto::CoordinatorAttestation)>; "CoordinatorAttestation")]
+    #[test_case(PhantomData::<(DatabaseDigest, proto::DatabaseDigest)>; "DatabaseDigest")]
+    #[test_case(PhantomData::<(DepositDecisionRetryRequest, proto::DepositDecisionRetryRequest)>; "DepositDecisionRetryRequest")]
+    #[test_case(PhantomData::<(MessageRedeliveryRequest, proto::MessageRedeliveryRequest)>; "MessageRedeliveryRequest")]
+    #[test_case(PhantomData::<(RedeliveredMessages, proto::RedeliveredMessages)>; "RedeliveredMessages")]
+    #[test_case(PhantomData::<(SignerDecisionBatch, proto::SignerDecisionBatch)>; "SignerDecisionBatch")]
+    #[test_case(PhantomData::<(SignRequestRefusal, proto::SignRequestRefusal)>; "SignRequestRefusal")]
+    #[test_case(PhantomData::<(LimitOverrideVote, proto::LimitOverrideVote)>; "LimitOverrideVote")]
+    #[test_case(PhantomData::<(SignerKeyEndorsement, proto::SignerKeyEndorsement)>; "SignerKeyEndorsement")]
+    #[test_case(PhantomData::<(EmergencyRecoveryApproval, proto::EmergencyRecoveryApproval)>; "EmergencyRecoveryApproval")]
+    #[test_case(PhantomData::<(DecisionAcknowledgement, proto::DecisionAcknowledgement)>; "DecisionAcknowledgement")]
+    #[test_case(PhantomData::<(EmergencyRecoveryRevocation, proto::EmergencyRecoveryRevocation)>; "EmergencyRecoveryRevocation")]
     fn sbtc_protobuf_message_codec_tag_order<T, U, E>(_: PhantomData<(T, U)>)
     where
         // `.unwrap()` requires that `E` implement `std::fmt::Debug` and
@@ -368,6 +396,18 @@ mod tests {
     #[test_case(PhantomData::<proto::Fees>; "Fees")]
     #[test_case(PhantomData::<proto::BitcoinPreSignRequest>; "BitcoinPreSignRequest")]
     #[test_case(PhantomData::<proto::BitcoinPreSignAck>; "BitcoinPreSignAck")]
+    #[test_case(PhantomData::<proto::CoordinatorAttestation>; "CoordinatorAttestation")]
+    #[test_case(PhantomData::<proto::DatabaseDigest>; "DatabaseDigest")]
+    #[test_case(PhantomData::<proto::DepositDecisionRetryRequest>; "DepositDecisionRetryRequest")]
+    #[test_case(PhantomData::<proto::MessageRedeliveryRequest>; "MessageRedeliveryRequest")]
+    #[test_case(PhantomData::<proto::RedeliveredMessages>; "RedeliveredMessages")]
+    #[test_case(PhantomData::<proto::SignerDecisionBatch>; "SignerDecisionBatch")]
+    #[test_case(PhantomData::<proto::SignRequestRefusal>; "SignRequestRefusal")]
+    #[test_case(PhantomData::<proto::LimitOverrideVote>; "LimitOverrideVote")]
+    #[test_case(PhantomData::<proto::SignerKeyEndorsement>; "SignerKeyEndorsement")]
+    #[test_case(PhantomData::<proto::EmergencyRecoveryApproval>; "EmergencyRecoveryApproval")]
+    #[test_case(PhantomData::<proto::DecisionAcknowledgement>; "DecisionAcknowledgement")]
+    #[test_case(PhantomData::<proto::EmergencyRecoveryRevocation>; "EmergencyRecoveryRevocation")]
     #[test_case(PhantomData::<proto::OutPoint>; "OutPoint")]
     #[test_case(PhantomData::<proto::RecoverableSignature>; "RecoverableSignature")]
     #[test_case(PhantomData::<proto::EcdsaSignature>; "EcdsaSignature")]
@@ -1,5 +1,9 @@
+use std::num::NonZeroU16;
+
 use libp2p::Multiaddr;
 
+use crate::bitcoin::utxo::SweepTemplateVersion;
+
 /// Configuration error variants.
 #[derive(Debug, thiserror::Error)]
 pub enum SignerConfigError {
@@ -22,6 +26,17 @@ pub enum SignerConfigError {
     #[error("The network set in the config must match the network kind of the deployer address")]
     NetworkDeployerMismatch,
 
+    /// The deployer private key in the config must be the key of the
+    /// deployer address.
+    #[error("The deployer private key must be the key for the deployer address {0}")]
+    DeployerKeyMismatch(String),
+
+    /// Contract upgrades need the approval of every signer, which is not
+    /// possible when the contracts are deployed from a separate deployer
+    /// account.
+    #[error("Contract upgrades cannot be enabled when a deployer private key is set")]
+    DeployerKeyWithContractUpgrades,
+
     /// Invalid P2P URI
     #[error("Invalid P2P URI: Only schemes 'tcp' and 'quic-v1' are supported; got '{0}'")]
     InvalidP2PScheme(String),
@@ -50,10 +65,11 @@ pub enum SignerConfigError {
     #[error("Invalid P2P URI: Host is required")]
     P2PHostRequired,
 
-    /// When the network kind is 'mainnet' or 'testnet', at least one P2P seed peer is required.
-    /// Otherwise, we'll allow mDNS to discover any local peers (for testing).
+    /// When the network kind is 'mainnet', 'testnet' or 'testnet4', at least one P2P seed peer
+    /// is required. Otherwise, we'll allow mDNS to discover any local peers (for testing).
     #[error(
-        "At least one P2P seed peer is required when the network kind is 'mainnet' or 'testnet'."
+        "At least one P2P seed peer is required when the network kind is 'mainnet', 'testnet' or \
+        'testnet4'."
     )]
     P2PSeedPeerRequired,
 
@@ -73,11 +89,36 @@ pub enum SignerConfigError {
     #[error("The provided Bitcoin processing delay must be small than {0}s, got {1}s")]
     InvalidBitcoinProcessingDelay(u64, u64),
 
+    /// An error for a max_deposits_per_complete_call value that exceeded
+    /// the [`crate::stacks::contracts::MAX_DEPOSITS_PER_COMPLETE_CALL`].
+    #[error("The provided max_deposits_per_complete_call must be at most {0}, got {1}")]
+    InvalidMaxDepositsPerCompleteCall(usize, usize),
+
+    /// An error for a max_withdrawals_per_accept_call value that exceeded
+    /// the [`crate::stacks::contracts::MAX_WITHDRAWALS_PER_ACCEPT_CALL`].
+    #[error("The provided max_withdrawals_per_accept_call must be at most {0}, got {1}")]
+    InvalidMaxWithdrawalsPerAcceptCall(usize, usize),
+
     /// An error for a requests_processing_delay value that exceeded the
     /// [`crate::config::MAX_REQUESTS_PROCESSING_DELAY_SECONDS`].
     #[error("The provided requests processing delay must be smaller than {0}s, got {1}s")]
     InvalidRequestsProcessingDelay(u64, u64),
 
+    /// An error for a block_observer_processing_delay value that exceeded
+    /// the [`crate::config::MAX_BLOCK_OBSERVER_PROCESSING_DELAY_SECONDS`].
+    #[error("The provided block observer processing delay must be smaller than {0}s, got {1}s")]
+    InvalidBlockObserverProcessingDelay(u64, u64),
+
+    /// An error for a processing jitter value that exceeded the
+    /// [`crate::config::MAX_PROCESSING_JITTER_MILLISECONDS`].
+    #[error("The provided {0} must be smaller than {1}ms, got {2}ms")]
+    InvalidProcessingJitter(&'static str, u64, u64),
+
+    /// An error for an in-memory cache size that is too small for the
+    /// rest of the configuration.
+    #[error("The provided {0} must be at least {1}, got {2}")]
+    InvalidCacheSize(&'static str, usize, usize),
+
     /// An error returned for duration parameters that must be positive.
     #[error("Duration for {0} must be nonzero")]
     ZeroDurationForbidden(&'static str),
@@ -92,4 +133,36 @@ pub enum SignerConfigError {
     /// See https://github.com/stacks-sbtc/sbtc/issues/1694
     #[error("Bootstrap signer set must be at most 16 signers, but it contains {0} signers")]
     TooManySigners(usize),
+
+    /// An error returned if a policy plugin is configured but the signer
+    /// was built without support for them.
+    #[error("Policy plugins require a signer built with the `wasm-policy` feature")]
+    PolicyPluginsUnsupported,
+
+    /// An error returned if the sweep transaction template version that
+    /// the signer proposes is not one of the versions that it accepts.
+    #[error("The proposed sweep template version {0} must be one of the accepted versions")]
+    UnacceptedSweepTemplateVersion(SweepTemplateVersion),
+
+    /// An error returned if only one of the threshold and the budget of
+    /// the withdrawal fee subsidy is set.
+    #[error("The withdrawal fee subsidy threshold and budget must be set together")]
+    IncompleteWithdrawalFeeSubsidy,
+
+    /// An error returned if the emergency recovery scriptPubKey could not
+    /// hold the recovered funds.
+    #[error("The emergency recovery scriptPubKey must not be empty or an OP_RETURN output")]
+    InvalidEmergencyRecoveryScript,
+
+    /// An error returned if approvals of the emergency recovery expire
+    /// before the sweep would be allowed.
+    #[error(
+        "emergency_recovery_approval_expiry_blocks is {0}, but it must be larger than emergency_recovery_delay_blocks, which is {1}"
+    )]
+    EmergencyRecoveryApprovalExpiryTooShort(NonZeroU16, NonZeroU16),
+
+    /// An error returned if the signer must be connected to more signers
+    /// than there are other signers in the bootstrap signer set.
+    #[error("min_connected_signers is {0}, but there are only {1} other signers")]
+    TooManyConnectedSignersRequired(u16, usize),
 }
@@ -14,18 +14,29 @@ use std::num::NonZeroU64;
 use std::path::Path;
 use url::Url;
 
+use crate::DEFAULT_DEPOSIT_EXPIRY_BLOCKS;
 use crate::DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX;
+use crate::alerts::AlertSeverity;
+use crate::bitcoin::utxo::SweepTemplateVersion;
+use crate::bitcoin::utxo::WithdrawalFeeSubsidy;
 use crate::config::error::SignerConfigError;
 use crate::config::serialization::duration_milliseconds_deserializer;
 use crate::config::serialization::duration_seconds_deserializer;
+use crate::config::serialization::optional_private_key_deserializer;
 use crate::config::serialization::p2p_multiaddr_deserializer_vec;
 use crate::config::serialization::parse_stacks_address;
 use crate::config::serialization::private_key_deserializer;
+use crate::config::serialization::url_deserializer_optional;
 use crate::config::serialization::url_deserializer_single;
 use crate::config::serialization::url_deserializer_vec;
+use crate::emily_client::EmilyApiKey;
+use crate::emily_client::mask_api_key;
 use crate::keys::PrivateKey;
 use crate::keys::PublicKey;
 use crate::network::libp2p::MultiaddrExt as _;
+use crate::stacks::contracts::MAX_DEPOSITS_PER_COMPLETE_CALL;
+use crate::stacks::contracts::MAX_WITHDRAWALS_PER_ACCEPT_CALL;
+use crate::stacks::contracts::SmartContractsVersion;
 use crate::stacks::wallet::SignerWallet;
 use crate::storage::model::BitcoinBlockHeight;
 
@@ -38,6 +49,14 @@ pub const MAX_BITCOIN_PROCESSING_DELAY_SECONDS: u64 = 300;
 /// Maximum configurable delay (in seconds) before processing new SBTC requests.
 pub const MAX_REQUESTS_PROCESSING_DELAY_SECONDS: u64 = 300;
 
+/// Maximum configurable delay (in seconds) before the block observer
+/// processes a new Bitcoin block.
+pub const MAX_BLOCK_OBSERVER_PROCESSING_DELAY_SECONDS: u64 = 300;
+
+/// Maximum configurable jitter (in milliseconds) added to the processing
+/// delay of an event loop.
+pub const MAX_PROCESSING_JITTER_MILLISECONDS: u64 = 60_000;
+
 /// Maximum amount of signers supported by our smart contracts
 /// See https://github.com/stacks-sbtc/sbtc/issues/1694
 pub const MAX_SIGNERS: usize = 16;
@@ -57,6 +76,9 @@ pub enum NetworkKind {
     Mainnet,
     /// The testnet network
     Testnet,
+    /// The testnet4 network. This is equivalent to Testnet when
+    /// constructing Stacks addresses and transactions.
+    Testnet4,
     /// The regtest network. This is equivalent to Testnet when
     /// constructing Stacks addresses and transactions.
     Regtest,
@@ -76,6 +98,7 @@ impl std::fmt::Display for NetworkKind {
         match self {
             NetworkKind::Mainnet => write!(f, "mainnet"),
             NetworkKind::Testnet => write!(f, "testnet"),
+            NetworkKind::Testnet4 => write!(f, "testnet4"),
             NetworkKind::Regtest => write!(f, "regtest"),
         }
     }
@@ -85,7 +108,7 @@ impl From<NetworkKind> for bitcoin::KnownHrp {
     fn from(value: NetworkKind) -> Self {
         match value {
             NetworkKind::Mainnet => bitcoin::KnownHrp::Mainnet,
-            NetworkKind::Testnet => bitcoin::KnownHrp::Testnets,
+            NetworkKind::Testnet | NetworkKind::Testnet4 => bitcoin::KnownHrp::Testnets,
             NetworkKind::Regtest => bitcoin::KnownHrp::Regtest,
         }
     }
@@ -96,6 +119,7 @@ impl From<NetworkKind> for bitcoin::Network {
         match network {
             NetworkKind::Mainnet => bitcoin::Network::Bitcoin,
             NetworkKind::Testnet => bitcoin::Network::Testnet,
+            NetworkKind::Testnet4 => bitcoin::Network::Testnet4,
             NetworkKind::Regtest => bitcoin::Network::Regtest,
         }
     }
@@ -106,6 +130,64 @@ impl NetworkKind {
     pub fn is_mainnet(&self) -> bool {
         self == &NetworkKind::Mainnet
     }
+
+    /// Returns the magic bytes at the start of the data in the `OP_RETURN`
+    /// output of the signers' sweep transactions on this network.
+    ///
+    /// No magic bytes have been defined for testnet4 yet, so it uses the
+    /// same ones as the other networks.
+    pub fn magic_bytes(&self) -> [u8; 2] {
+        //TODO(#472): Use the correct magic bytes.
+        [b'T', b'3']
+    }
+
+    /// Returns the kind of Emily deployment that signers on this network
+    /// use. Only mainnet signers use a mainnet Emily deployment.
+    pub fn emily_environment(&self) -> EmilyEnvironment {
+        match self {
+            NetworkKind::Mainnet => EmilyEnvironment::Mainnet,
+            NetworkKind::Testnet | NetworkKind::Testnet4 | NetworkKind::Regtest => {
+                EmilyEnvironment::Testnet
+            }
+        }
+    }
+}
+
+/// The kind of an Emily deployment. Emily checks that the Stacks
+/// recipients of deposit requests are mainnet addresses in a mainnet
+/// deployment and testnet addresses otherwise, so signers must use a
+/// deployment that matches their network.
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EmilyEnvironment {
+    /// An Emily deployment for mainnet.
+    Mainnet,
+    /// An Emily deployment for testnet, testnet4 or regtest.
+    Testnet,
+}
+
+impl std::fmt::Display for EmilyEnvironment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmilyEnvironment::Mainnet => write!(f, "mainnet"),
+            EmilyEnvironment::Testnet => write!(f, "testnet"),
+        }
+    }
+}
+
+/// How the signer uses the esplora API for read-only bitcoin queries.
+#[derive(serde::Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EsploraRole {
+    /// Queries go to bitcoin-core first, and esplora is only asked when
+    /// bitcoin-core cannot answer, for example because it has pruned the
+    /// block.
+    #[default]
+    Secondary,
+    /// Queries go to esplora first, and bitcoin-core is only asked when
+    /// esplora cannot answer. This suits watchers, which do not need to
+    /// depend on a bitcoin-core node for reads.
+    Primary,
 }
 
 /// Top-level configuration for the signer
@@ -133,6 +215,16 @@ pub struct BitcoinConfig {
     /// Bitcoin ZeroMQ block-hash stream endpoint.
     #[serde(deserialize_with = "url_deserializer_vec")]
     pub block_hash_stream_endpoints: Vec<Url>,
+
+    /// Esplora API endpoints for read-only bitcoin queries. Transactions
+    /// are always broadcast through bitcoin-core.
+    #[serde(default, deserialize_with = "url_deserializer_vec")]
+    pub esplora_endpoints: Vec<Url>,
+
+    /// Whether the esplora API is asked before or after bitcoin-core.
+    /// Ignored if there are no esplora endpoints.
+    #[serde(default)]
+    pub esplora_role: EsploraRole,
 }
 
 /// Signer network configuration
@@ -156,6 +248,17 @@ pub struct P2PNetworkConfig {
     /// testing and development.
     #[serde(default)]
     pub enable_mdns: bool,
+    /// Only connect to the signers in the bootstrap signing set. When
+    /// enabled, connections with peers whose peer IDs are not derived
+    /// from the public keys in `signer.bootstrap_signing_set` are
+    /// rejected as part of the connection handshake.
+    #[serde(default)]
+    pub pin_bootstrap_signers: bool,
+    /// How long decision and pre-sign messages are kept for redelivery to
+    /// signers that reconnect after a restart. A value of zero disables
+    /// the store-and-forward of messages.
+    #[serde(default, deserialize_with = "duration_seconds_deserializer")]
+    pub message_retention: std::time::Duration,
 }
 
 impl P2PNetworkConfig {
@@ -171,9 +274,12 @@ impl P2PNetworkConfig {
 
 impl Validatable for P2PNetworkConfig {
     fn validate(&self, cfg: &Settings) -> Result<(), ConfigError> {
-        if [NetworkKind::Mainnet, NetworkKind::Testnet].contains(&cfg.signer.network)
-            && self.seeds.is_empty()
-        {
+        let public_networks = [
+            NetworkKind::Mainnet,
+            NetworkKind::Testnet,
+            NetworkKind::Testnet4,
+        ];
+        if public_networks.contains(&cfg.signer.network) && self.seeds.is_empty() {
             return Err(ConfigError::Message(
                 SignerConfigError::P2PSeedPeerRequired.to_string(),
             ));
@@ -234,24 +340,100 @@ impl BlocklistClientConfig {
     }
 }
 /// Emily API configuration.
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Deserialize, Clone)]
 pub struct EmilyClientConfig {
     /// Emily API endpoints.
     #[serde(deserialize_with = "url_deserializer_vec")]
     pub endpoints: Vec<Url>,
+    /// The API keys to use for Emily, in addition to any API keys in the
+    /// endpoint URLs. These can be rotated over the control API while the
+    /// signer is running.
+    #[serde(default)]
+    pub api_keys: Vec<EmilyApiKey>,
     /// Pagination timeout in seconds.
     #[serde(deserialize_with = "duration_seconds_deserializer")]
     pub pagination_timeout: std::time::Duration,
+    /// The maximum number of deposits requested from Emily per page. If
+    /// not set then Emily's default page size is used.
+    #[serde(default)]
+    pub page_size: Option<u16>,
+    /// The kind of Emily deployment at the endpoints. If not set then it
+    /// is the kind used on the signer's network, see
+    /// [`NetworkKind::emily_environment`].
+    #[serde(default)]
+    pub environment: Option<EmilyEnvironment>,
+}
+
+impl EmilyClientConfig {
+    /// Returns the kind of Emily deployment that the signer uses on the
+    /// given network.
+    pub fn environment(&self, network: NetworkKind) -> EmilyEnvironment {
+        self.environment
+            .unwrap_or_else(|| network.emily_environment())
+    }
+}
+
+// The API keys in the endpoint URLs and in `api_keys` are masked, so that
+// the config can be logged.
+impl std::fmt::Debug for EmilyClientConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let endpoints: Vec<String> = self
+            .endpoints
+            .iter()
+            .map(|endpoint| {
+                let mut endpoint = endpoint.clone();
+                if !endpoint.username().is_empty() {
+                    let masked = mask_api_key(endpoint.username());
+                    let _ = endpoint.set_username(&masked);
+                }
+                endpoint.to_string()
+            })
+            .collect();
+
+        f.debug_struct("EmilyClientConfig")
+            .field("endpoints", &endpoints)
+            .field("api_keys", &self.api_keys)
+            .field("pagination_timeout", &self.pagination_timeout)
+            .field("page_size", &self.page_size)
+            .field("environment", &self.environment)
+            .finish()
+    }
 }
 
 impl Validatable for EmilyClientConfig {
-    fn validate(&self, _: &Settings) -> Result<(), ConfigError> {
+    fn validate(&self, cfg: &Settings) -> Result<(), ConfigError> {
         // At least one endpoint must be provided.
         if self.endpoints.is_empty() {
             return Err(ConfigError::Message(
                 "[emily_client] At least one Emily API endpoint must be provided".to_string(),
             ));
         }
+        let mut api_key_names = BTreeSet::new();
+        for api_key in &self.api_keys {
+            if api_key.key.is_empty() {
+                return Err(ConfigError::Message(
+                    "[emily_client.api_keys] API keys must not be empty".to_string(),
+                ));
+            }
+            if !api_key_names.insert(api_key.name.as_str()) {
+                return Err(ConfigError::Message(format!(
+                    "[emily_client.api_keys] Duplicate API key name: {}",
+                    api_key.name
+                )));
+            }
+        }
+        if self.page_size == Some(0) {
+            return Err(ConfigError::Message(
+                "[emily_client] The page size must be greater than zero".to_string(),
+            ));
+        }
+        let network = cfg.signer.network;
+        let environment = self.environment(network);
+        if environment != network.emily_environment() {
+            return Err(ConfigError::Message(format!(
+                "[emily_client] A {environment} Emily deployment cannot be used on {network}"
+            )));
+        }
         // Validate each endpoint configuration.
         for endpoint in &self.endpoints {
             if !["http", "https"].contains(&endpoint.scheme()) {
@@ -284,14 +466,33 @@ pub struct SignerConfig {
     pub network: NetworkKind,
     /// Event observer server configuration
     pub event_observer: EventObserverConfig,
+    /// Configuration of the alerts sent to operators
+    pub alerts: AlertsConfig,
     /// The address of the deployer of the sBTC smart contracts.
     #[serde(deserialize_with = "parse_stacks_address")]
     pub deployer: StacksAddress,
+    /// The private key of a separate account that deploys the sBTC smart
+    /// contracts. When it is set, the coordinator deploys the contracts
+    /// from this account without a signing round, instead of from the
+    /// signers' multi-sig wallet, and the deployer address must be the
+    /// address of this account.
+    #[serde(default, deserialize_with = "optional_private_key_deserializer")]
+    pub deployer_private_key: Option<PrivateKey>,
+    /// The release of the sBTC smart contracts that the signers should
+    /// have deployed.
+    pub contracts_target_version: SmartContractsVersion,
+    /// Whether the coordinator may deploy releases of the sBTC smart
+    /// contracts that upgrade the contracts deployed during bootstrapping,
+    /// and whether this signer will sign such deployments.
+    pub contract_upgrades_enabled: bool,
     /// The postgres database endpoint
     #[serde(deserialize_with = "url_deserializer_single")]
     pub db_endpoint: Url,
     /// The scrape endpoint for exporting metrics for Prometheus.
     pub prometheus_exporter_endpoint: Option<std::net::SocketAddr>,
+    /// The address to bind the gRPC control service to. The service is
+    /// not started if this is not set.
+    pub grpc_endpoint: Option<std::net::SocketAddr>,
     /// The public keys of the signer sit during the bootstrapping phase of
     /// the signers.
     pub bootstrap_signing_set: BTreeSet<PublicKey>,
@@ -308,8 +509,26 @@ pub struct SignerConfig {
     /// (allowing the bitcoin block to propagate to the others signers)
     #[serde(deserialize_with = "duration_seconds_deserializer")]
     pub requests_processing_delay: std::time::Duration,
+    /// The number of seconds the block observer will wait before
+    /// processing a new Bitcoin block (allowing the stacks node to catch
+    /// up with the bitcoin node).
+    #[serde(deserialize_with = "duration_seconds_deserializer")]
+    pub block_observer_processing_delay: std::time::Duration,
+    /// The maximum number of milliseconds of random delay added to the
+    /// `bitcoin_processing_delay` of the coordinator.
+    #[serde(deserialize_with = "duration_milliseconds_deserializer")]
+    pub bitcoin_processing_jitter: std::time::Duration,
+    /// The maximum number of milliseconds of random delay added to the
+    /// `requests_processing_delay` of the request decider.
+    #[serde(deserialize_with = "duration_milliseconds_deserializer")]
+    pub requests_processing_jitter: std::time::Duration,
+    /// The maximum number of milliseconds of random delay added to the
+    /// `block_observer_processing_delay` of the block observer.
+    #[serde(deserialize_with = "duration_milliseconds_deserializer")]
+    pub block_observer_processing_jitter: std::time::Duration,
     /// How many bitcoin blocks back from the chain tip the signer will
-    /// look for requests.
+    /// look for requests. A pruned bitcoin-core node must keep at least
+    /// this many blocks.
     pub context_window: u16,
     /// How many bitcoin blocks back from the chain tip the signer will
     /// look for deposit decisions to retry to propagate.
@@ -329,10 +548,166 @@ pub struct SignerConfig {
     /// coordinator will time out and return an error.
     #[serde(deserialize_with = "duration_seconds_deserializer")]
     pub dkg_max_duration: std::time::Duration,
+    /// The minimum number of other signers in the signer set that this
+    /// signer must be connected to, or have recently heard from, for it to
+    /// start a tenure as coordinator. Zero disables the check.
+    pub min_connected_signers: u16,
+    /// Whether the coordinator hands its signed sweep transactions to the
+    /// broadcaster, which broadcasts them in the background, instead of
+    /// broadcasting them itself during its tenure.
+    pub async_sweep_broadcast: bool,
+    /// The expected amount of time between bitcoin blocks. The coordinator
+    /// derives the wall-clock budget of its tenure from this value.
+    #[serde(deserialize_with = "duration_seconds_deserializer")]
+    pub expected_bitcoin_block_interval: std::time::Duration,
+    /// Whether the block observer verifies the proof-of-work and the
+    /// continuity of the bitcoin block headers that it gets from
+    /// bitcoin-core before storing the blocks.
+    pub verify_bitcoin_headers: bool,
+    /// Whether the block observer verifies the signatures and the bitcoin
+    /// anchors of the stacks blocks that it gets from the stacks node
+    /// before storing them.
+    pub verify_stacks_blocks: bool,
+    /// Whether deposits are paused when the signer starts. While deposits
+    /// are paused, this signer does not sweep deposit requests when it is
+    /// the coordinator and does not sign transactions that sweep them.
+    /// This can be changed at runtime through the control API.
+    pub deposits_paused: bool,
+    /// Whether withdrawals are paused when the signer starts. While
+    /// withdrawals are paused, this signer does not vote on new withdrawal
+    /// requests, does not sweep them when it is the coordinator, and does
+    /// not sign transactions that sweep them. This can be changed at
+    /// runtime through the control API.
+    pub withdrawals_paused: bool,
+    /// Whether sweep transactions include [`SweepMetadata`] in their
+    /// OP_RETURN output, which commits to the bitcoin chain tip and the
+    /// requests that they service. Signers only sign sweeps that they
+    /// would have constructed themselves, so this must be the same for
+    /// all signers.
+    ///
+    /// [`SweepMetadata`]: crate::bitcoin::utxo::SweepMetadata
+    pub include_sweep_metadata: bool,
+    /// The version of the sweep transaction template that this signer
+    /// uses to construct sweep transactions when it is the coordinator.
+    /// See [`SignerConfig::proposed_sweep_template`] for how this
+    /// interacts with `include_sweep_metadata`.
+    pub sweep_template_version: SweepTemplateVersion,
+    /// The versions of the sweep transaction template that this signer
+    /// accepts when it validates the sweep transactions of a coordinator.
+    /// To upgrade the template, all signers first accept the new version
+    /// and then start proposing it.
+    pub accepted_sweep_template_versions: Vec<SweepTemplateVersion>,
+    /// Whether the signer periodically reconciles the signers' UTXO in
+    /// the database with the UTXO set of bitcoin-core.
+    pub utxo_reconciliation_enabled: bool,
+    /// The amount of time between reconciliations of the signers' UTXO in
+    /// the database with the UTXO set of bitcoin-core.
+    #[serde(deserialize_with = "duration_seconds_deserializer")]
+    pub utxo_reconciliation_interval: std::time::Duration,
+    /// The amount of time without a change to the bitcoin chain tip of
+    /// this signer after which the chain tip watchdog considers the chain
+    /// tip stalled.
+    #[serde(deserialize_with = "duration_seconds_deserializer")]
+    pub chain_tip_stall_timeout: std::time::Duration,
+    /// The number of blocks that the bitcoin chain tip of this signer may
+    /// be behind the chain tip of bitcoin-core before the chain tip
+    /// watchdog considers it lagging.
+    pub chain_tip_max_lag: NonZeroU16,
+    /// How long the sBTC limits fetched from Emily are used for when later
+    /// fetches fail. Once the cached limits are older than this, the
+    /// signer falls back to limits that block all minting and withdrawals.
+    /// A value of zero disables the cache.
+    #[serde(deserialize_with = "duration_seconds_deserializer")]
+    pub sbtc_limits_cache_ttl: std::time::Duration,
+    /// How long the transactions fetched from bitcoin-core while
+    /// validating Stacks sign requests are reused for. The contract calls
+    /// for the requests serviced by a sweep transaction all look up the
+    /// same transaction. A value of zero disables the cache.
+    #[serde(deserialize_with = "duration_seconds_deserializer")]
+    pub tx_info_cache_ttl: std::time::Duration,
     /// The amount of time, in seconds, the signer should pause for after
     /// receiving a DKG begin message before relaying to give the other
     /// signers time to catch up.
     pub dkg_begin_pause: Option<u64>,
+    /// The minimum amount, in sats, of a deposit request that this signer
+    /// will accept. This is enforced in addition to the limits in
+    /// [`SbtcLimits`](crate::context::SbtcLimits), which apply to the
+    /// whole network.
+    pub min_deposit_amount: Option<u64>,
+    /// The maximum amount, in sats, that this signer will sign a single
+    /// `complete-deposit` contract call to mint. There is no limit when
+    /// this is not set.
+    pub max_mint_per_call: Option<u64>,
+    /// The largest per-deposit cap, in sats, that this signer accepts in a
+    /// limit override voted on by the signer set. When this is not set,
+    /// the signer does not accept overrides of the per-deposit cap.
+    pub limit_override_max_per_deposit_cap: Option<u64>,
+    /// The largest per-withdrawal cap, in sats, that this signer accepts
+    /// in a limit override voted on by the signer set. When this is not
+    /// set, the signer does not accept overrides of the per-withdrawal
+    /// cap.
+    pub limit_override_max_per_withdrawal_cap: Option<u64>,
+    /// Withdrawals of fewer than this many sats may have part of their
+    /// bitcoin fee paid for by the signers. The signers only subsidize
+    /// fees when this and `withdrawal_fee_subsidy_budget` are both set.
+    pub withdrawal_fee_subsidy_threshold: Option<u64>,
+    /// The total amount, in sats, of withdrawal fees that the signers may
+    /// subsidize in a single tenure.
+    pub withdrawal_fee_subsidy_budget: Option<u64>,
+    /// The maximum number of bitcoin blocks that a limit override voted on
+    /// by the signer set may last for this signer to accept it.
+    pub limit_override_max_blocks: NonZeroU16,
+    /// The scriptPubKey, hex encoded, that the operators agreed to sweep
+    /// all the funds of the signers to in an emergency. This signer does
+    /// not take part in an emergency recovery when this is not set. See
+    /// [`crate::emergency_recovery`] for details.
+    pub emergency_recovery_script_pubkey: Option<bitcoin::ScriptBuf>,
+    /// The number of bitcoin blocks that the signers wait for after
+    /// enough operators approved the emergency recovery, before they
+    /// sweep the funds to the recovery scriptPubKey.
+    pub emergency_recovery_delay_blocks: NonZeroU16,
+    /// The highest fee rate, in sats per vbyte, that this signer accepts
+    /// for the emergency recovery sweep.
+    pub emergency_recovery_max_fee_rate: NonZeroU64,
+    /// The number of bitcoin blocks that an approval of the emergency
+    /// recovery counts for. It must be larger than
+    /// `emergency_recovery_delay_blocks`, so that the approvals are still
+    /// in force when the sweep is allowed.
+    pub emergency_recovery_approval_expiry_blocks: NonZeroU16,
+    /// The number of bitcoin blocks that the chain tip in a message with
+    /// an approval or a revocation of the emergency recovery may be away
+    /// from our own chain tip. Other messages are ignored.
+    pub emergency_recovery_approval_window_blocks: u16,
+    /// The number of bitcoin blocks after a deposit is confirmed that the
+    /// signers will wait for it to be swept. Deposits that are still
+    /// unswept after this many blocks are marked as expired and are no
+    /// longer considered for sweeping. The default here is controlled by
+    /// the [`DEFAULT_DEPOSIT_EXPIRY_BLOCKS`] constant.
+    pub deposit_expiry_blocks: NonZeroU16,
+    /// Every this many bitcoin blocks after a withdrawal request is
+    /// confirmed, the signer checks whether it would still make the same
+    /// decision on the request if it is still pending, and tells the
+    /// other signers when its decision changed. The signer never
+    /// re-validates its decisions when this is not set.
+    pub withdrawal_revalidation_blocks: Option<NonZeroU16>,
+    /// The maximum number of swept withdrawal requests that the
+    /// coordinator will accept in a single `complete-withdrawals` contract
+    /// call. When this is one, each swept withdrawal request is accepted
+    /// in its own `accept-withdrawal-request` contract call. This must not
+    /// exceed [`MAX_WITHDRAWALS_PER_ACCEPT_CALL`].
+    pub max_withdrawals_per_accept_call: NonZeroU16,
+    /// The maximum number of swept deposit requests that the coordinator
+    /// will complete in a single `complete-deposits-wrapper` contract
+    /// call. When this is one, each swept deposit request is completed in
+    /// its own `complete-deposit-wrapper` contract call. This must not
+    /// exceed [`MAX_DEPOSITS_PER_COMPLETE_CALL`].
+    pub max_deposits_per_complete_call: NonZeroU16,
+    /// The number of confirmations that a sweep transaction must have
+    /// before the signers complete the swept deposits or accept the swept
+    /// withdrawals on stacks. A value of one means that the sweep only
+    /// needs to be included in a block on the canonical bitcoin
+    /// blockchain.
+    pub sweep_confirmation_depth: NonZeroU16,
     /// The minimum bitcoin block height for which the sbtc signers will
     /// backfill bitcoin blocks to.
     pub sbtc_bitcoin_start_height: Option<BitcoinBlockHeight>,
@@ -343,6 +718,19 @@ pub struct SignerConfig {
     /// arrives. The default here is controlled by the
     /// [`MAX_DEPOSITS_PER_BITCOIN_TX`] constant
     pub max_deposits_per_bitcoin_tx: NonZeroU16,
+    /// The number of WSTS state machines for signing and DKG rounds that
+    /// the transaction signer keeps in memory. Signing a sweep transaction
+    /// takes one state machine for each of its inputs, so this must be at
+    /// least `max_deposits_per_bitcoin_tx` plus two, one for the signers'
+    /// UTXO and one for DKG. The default here is controlled by the
+    /// [`crate::MAX_SIGNER_STATE_MACHINES`] constant.
+    pub wsts_state_machines_cache_size: NonZeroU16,
+    /// The number of DKG verification state machines that the transaction
+    /// signer keeps in memory.
+    pub dkg_verification_state_machines_cache_size: NonZeroU16,
+    /// The number of bitcoin tenures for which the transaction signer
+    /// keeps track of the stacks transactions that it has signed.
+    pub stacks_sign_request_cache_size: NonZeroU16,
     /// Configures a DKG re-run Bitcoin block height. If this is set and DKG has
     /// already been run, the coordinator will attempt to re-run DKG after this
     /// block height is met if `dkg_target_rounds` has not been reached. If DKG
@@ -359,9 +747,33 @@ pub struct SignerConfig {
     pub dkg_verification_window: u16,
     /// The maximum stacks fee in microSTX that the signer will accept for any stacks transaction.
     pub stacks_fees_max_ustx: NonZeroU64,
+    /// The maximum number of stacks transactions that the coordinator
+    /// will submit for the same request before giving up on it.
+    pub stacks_tx_max_attempts: NonZeroU16,
     /// The aggregate key constructed during the signers' first DKG. It was
     /// used to lock the first UTXO created by the signers.
     pub bootstrap_aggregate_key: Option<PublicKey>,
+    /// A directory where the coordinator writes each unsigned sweep
+    /// transaction as a PSBT before it starts the signing round.
+    pub psbt_export_directory: Option<std::path::PathBuf>,
+    /// An endpoint that the coordinator posts each unsigned sweep
+    /// transaction to as a PSBT before it starts the signing round.
+    #[serde(default, deserialize_with = "url_deserializer_optional")]
+    pub psbt_export_endpoint: Option<Url>,
+    /// A WebAssembly module that the request decider consults on each
+    /// deposit and withdrawal request, in addition to the blocklist
+    /// client. See [`crate::policy_plugin`] for the interface that the
+    /// module must implement. Loading it requires the `wasm-policy`
+    /// feature.
+    pub policy_plugin_path: Option<std::path::PathBuf>,
+    /// The amount of time, in milliseconds, that the policy plugin may
+    /// take to decide on a request.
+    #[serde(deserialize_with = "duration_milliseconds_deserializer")]
+    pub policy_plugin_timeout: std::time::Duration,
+    /// The amount of fuel that the policy plugin may consume to decide on
+    /// a request. Each WebAssembly instruction consumes roughly one unit
+    /// of fuel.
+    pub policy_plugin_fuel: NonZeroU64,
 }
 
 impl Validatable for SignerConfig {
@@ -382,6 +794,20 @@ impl Validatable for SignerConfig {
             let err = SignerConfigError::NetworkDeployerMismatch;
             return Err(ConfigError::Message(err.to_string()));
         }
+
+        match SignerWallet::load_deployer_wallet(self) {
+            Ok(Some(wallet)) if wallet.address() != &self.deployer => {
+                let err = SignerConfigError::DeployerKeyMismatch(self.deployer.to_string());
+                return Err(ConfigError::Message(err.to_string()));
+            }
+            Ok(_) => {}
+            Err(err) => return Err(ConfigError::Message(err.to_string())),
+        }
+
+        if self.deployer_private_key.is_some() && self.contract_upgrades_enabled {
+            let err = SignerConfigError::DeployerKeyWithContractUpgrades;
+            return Err(ConfigError::Message(err.to_string()));
+        }
         // At least perform a simple check to see if the database endpoint is
         // valid for the supported database drivers. We only support PostgreSQL
         // for now. The rest of the URI we delegate to the database driver for
@@ -421,6 +847,80 @@ impl Validatable for SignerConfig {
             ));
         }
 
+        let delay_secs = cfg.signer.block_observer_processing_delay.as_secs();
+        if delay_secs > MAX_BLOCK_OBSERVER_PROCESSING_DELAY_SECONDS {
+            return Err(ConfigError::Message(
+                SignerConfigError::InvalidBlockObserverProcessingDelay(
+                    MAX_BLOCK_OBSERVER_PROCESSING_DELAY_SECONDS,
+                    delay_secs,
+                )
+                .to_string(),
+            ));
+        }
+
+        let max_withdrawals = cfg.signer.max_withdrawals_per_accept_call.get() as usize;
+        if max_withdrawals > MAX_WITHDRAWALS_PER_ACCEPT_CALL {
+            return Err(ConfigError::Message(
+                SignerConfigError::InvalidMaxWithdrawalsPerAcceptCall(
+                    MAX_WITHDRAWALS_PER_ACCEPT_CALL,
+                    max_withdrawals,
+                )
+                .to_string(),
+            ));
+        }
+
+        let max_deposits = cfg.signer.max_deposits_per_complete_call.get() as usize;
+        if max_deposits > MAX_DEPOSITS_PER_COMPLETE_CALL {
+            return Err(ConfigError::Message(
+                SignerConfigError::InvalidMaxDepositsPerCompleteCall(
+                    MAX_DEPOSITS_PER_COMPLETE_CALL,
+                    max_deposits,
+                )
+                .to_string(),
+            ));
+        }
+
+        let min_state_machines = cfg.signer.max_deposits_per_bitcoin_tx.get() as usize + 2;
+        let state_machines = cfg.signer.wsts_state_machines_cache_size.get() as usize;
+        if state_machines < min_state_machines {
+            return Err(ConfigError::Message(
+                SignerConfigError::InvalidCacheSize(
+                    "wsts_state_machines_cache_size",
+                    min_state_machines,
+                    state_machines,
+                )
+                .to_string(),
+            ));
+        }
+
+        let jitters = [
+            (
+                "bitcoin_processing_jitter",
+                cfg.signer.bitcoin_processing_jitter,
+            ),
+            (
+                "requests_processing_jitter",
+                cfg.signer.requests_processing_jitter,
+            ),
+            (
+                "block_observer_processing_jitter",
+                cfg.signer.block_observer_processing_jitter,
+            ),
+        ];
+        for (name, jitter) in jitters {
+            let jitter_millis = u64::try_from(jitter.as_millis()).unwrap_or(u64::MAX);
+            if jitter_millis > MAX_PROCESSING_JITTER_MILLISECONDS {
+                return Err(ConfigError::Message(
+                    SignerConfigError::InvalidProcessingJitter(
+                        name,
+                        MAX_PROCESSING_JITTER_MILLISECONDS,
+                        jitter_millis,
+                    )
+                    .to_string(),
+                ));
+            }
+        }
+
         // All durations should be non-zero
         let zero = std::time::Duration::ZERO;
         if cfg.signer.dkg_max_duration == zero {
@@ -439,6 +939,69 @@ impl Validatable for SignerConfig {
                 SignerConfigError::ZeroDurationForbidden("signer_round_max_duration").to_string(),
             ));
         }
+        if cfg.signer.expected_bitcoin_block_interval == zero {
+            return Err(ConfigError::Message(
+                SignerConfigError::ZeroDurationForbidden("expected_bitcoin_block_interval")
+                    .to_string(),
+            ));
+        }
+        if cfg.signer.utxo_reconciliation_interval == zero {
+            return Err(ConfigError::Message(
+                SignerConfigError::ZeroDurationForbidden("utxo_reconciliation_interval")
+                    .to_string(),
+            ));
+        }
+        if cfg.signer.policy_plugin_timeout == zero {
+            return Err(ConfigError::Message(
+                SignerConfigError::ZeroDurationForbidden("policy_plugin_timeout").to_string(),
+            ));
+        }
+        if cfg.signer.policy_plugin_path.is_some() && !cfg!(feature = "wasm-policy") {
+            return Err(ConfigError::Message(
+                SignerConfigError::PolicyPluginsUnsupported.to_string(),
+            ));
+        }
+        let proposed_sweep_template = cfg.signer.proposed_sweep_template();
+        if !cfg.signer.accepts_sweep_template(proposed_sweep_template) {
+            return Err(ConfigError::Message(
+                SignerConfigError::UnacceptedSweepTemplateVersion(proposed_sweep_template)
+                    .to_string(),
+            ));
+        }
+        if cfg.signer.chain_tip_stall_timeout == zero {
+            return Err(ConfigError::Message(
+                SignerConfigError::ZeroDurationForbidden("chain_tip_stall_timeout").to_string(),
+            ));
+        }
+        let threshold = cfg.signer.withdrawal_fee_subsidy_threshold;
+        let budget = cfg.signer.withdrawal_fee_subsidy_budget;
+        if threshold.is_some() != budget.is_some() {
+            return Err(ConfigError::Message(
+                SignerConfigError::IncompleteWithdrawalFeeSubsidy.to_string(),
+            ));
+        }
+        // The other signers are all that this signer can be connected to,
+        // so asking for more of them would stop it from ever coordinating.
+        let num_other_signers = cfg.signer.bootstrap_signing_set.len().saturating_sub(1);
+        if usize::from(cfg.signer.min_connected_signers) > num_other_signers {
+            let err = SignerConfigError::TooManyConnectedSignersRequired(
+                cfg.signer.min_connected_signers,
+                num_other_signers,
+            );
+            return Err(ConfigError::Message(err.to_string()));
+        }
+        let recovery_script = cfg.signer.emergency_recovery_script_pubkey.as_ref();
+        if recovery_script.is_some_and(|script| script.is_empty() || script.is_op_return()) {
+            return Err(ConfigError::Message(
+                SignerConfigError::InvalidEmergencyRecoveryScript.to_string(),
+            ));
+        }
+        let expiry = cfg.signer.emergency_recovery_approval_expiry_blocks;
+        let delay = cfg.signer.emergency_recovery_delay_blocks;
+        if expiry <= delay {
+            let err = SignerConfigError::EmergencyRecoveryApprovalExpiryTooShort(expiry, delay);
+            return Err(ConfigError::Message(err.to_string()));
+        }
         // db_endpoint note: we don't validate the host because we will never
         // get here; the URL deserializer will fail if the host is empty.
         Ok(())
@@ -450,6 +1013,124 @@ impl SignerConfig {
     pub fn public_key(&self) -> PublicKey {
         PublicKey::from_private_key(&self.private_key)
     }
+
+    /// The delay of the transaction coordinator before it processes a new
+    /// bitcoin block.
+    pub fn coordinator_delay(&self) -> ProcessingDelay {
+        ProcessingDelay {
+            delay: self.bitcoin_processing_delay,
+            jitter: self.bitcoin_processing_jitter,
+        }
+    }
+
+    /// The wall-clock budget of a coordinator tenure, measured from when
+    /// the coordinator observes the new bitcoin block.
+    ///
+    /// This is half of the expected bitcoin block interval, which leaves
+    /// the coordinator plenty of room to wrap up before the next bitcoin
+    /// block arrives and a new coordinator starts its tenure.
+    pub fn coordinator_tenure_budget(&self) -> std::time::Duration {
+        self.expected_bitcoin_block_interval / 2
+    }
+
+    /// The version of the sweep transaction template that this signer
+    /// proposes when it is the coordinator.
+    ///
+    /// Setting `include_sweep_metadata` predates template versions, so
+    /// it still asks for at least the first version that includes
+    /// [`SweepMetadata`].
+    ///
+    /// [`SweepMetadata`]: crate::bitcoin::utxo::SweepMetadata
+    pub fn proposed_sweep_template(&self) -> SweepTemplateVersion {
+        if self.include_sweep_metadata {
+            self.sweep_template_version.max(SweepTemplateVersion::V2)
+        } else {
+            self.sweep_template_version
+        }
+    }
+
+    /// The rules for subsidizing the bitcoin fees of small withdrawals.
+    /// Nothing is subsidized unless the subsidy is fully configured.
+    pub fn withdrawal_fee_subsidy(&self) -> WithdrawalFeeSubsidy {
+        WithdrawalFeeSubsidy {
+            threshold: self.withdrawal_fee_subsidy_threshold.unwrap_or_default(),
+            budget: self.withdrawal_fee_subsidy_budget.unwrap_or_default(),
+        }
+    }
+
+    /// Whether this signer accepts sweep transactions that were
+    /// constructed with the given version of the template.
+    pub fn accepts_sweep_template(&self, version: SweepTemplateVersion) -> bool {
+        self.accepted_sweep_template_versions.contains(&version)
+    }
+
+    /// The delay of the request decider before it processes new requests.
+    pub fn request_decider_delay(&self) -> ProcessingDelay {
+        ProcessingDelay {
+            delay: self.requests_processing_delay,
+            jitter: self.requests_processing_jitter,
+        }
+    }
+
+    /// The delay of the block observer before it processes a new bitcoin
+    /// block.
+    pub fn block_observer_delay(&self) -> ProcessingDelay {
+        ProcessingDelay {
+            delay: self.block_observer_processing_delay,
+            jitter: self.block_observer_processing_jitter,
+        }
+    }
+}
+
+/// The delay that an event loop waits for before processing a new bitcoin
+/// block or new requests.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcessingDelay {
+    /// The fixed part of the delay.
+    pub delay: std::time::Duration,
+    /// The maximum random delay added on top of the fixed delay.
+    pub jitter: std::time::Duration,
+}
+
+impl ProcessingDelay {
+    /// Return the duration to wait for, which is the fixed delay plus a
+    /// uniformly random jitter.
+    pub fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> std::time::Duration {
+        let max_jitter_millis = u64::try_from(self.jitter.as_millis()).unwrap_or(u64::MAX);
+        if max_jitter_millis == 0 {
+            return self.delay;
+        }
+        let jitter_millis = rng.gen_range(0..=max_jitter_millis);
+        self.delay + std::time::Duration::from_millis(jitter_millis)
+    }
+}
+
+/// Configuration of the alerts that the signer sends to operators when
+/// critical events occur.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AlertsConfig {
+    /// The incoming webhook of the Slack channel that alerts are posted
+    /// to.
+    #[serde(default, deserialize_with = "url_deserializer_optional")]
+    pub slack_webhook_url: Option<Url>,
+    /// The minimum severity of the alerts posted to Slack.
+    pub slack_min_severity: AlertSeverity,
+    /// The routing key of the PagerDuty service that alerts are sent to.
+    #[serde(default)]
+    pub pagerduty_routing_key: Option<String>,
+    /// The minimum severity of the alerts sent to PagerDuty.
+    pub pagerduty_min_severity: AlertSeverity,
+    /// The amount of time during which an alert is not sent again after
+    /// it has been sent once.
+    #[serde(deserialize_with = "duration_seconds_deserializer")]
+    pub dedup_window: std::time::Duration,
+    /// The amount of time without a new bitcoin block after which the
+    /// chain tip is considered stale. A value of zero disables the alert.
+    #[serde(deserialize_with = "duration_seconds_deserializer")]
+    pub stale_chain_tip_threshold: std::time::Duration,
+    /// The number of consecutive failed stacks transaction submissions
+    /// after which an alert is sent.
+    pub stacks_submission_failure_threshold: NonZeroU16,
 }
 
 /// Configuration for the Stacks event observer server (hosted within the signer).
@@ -495,8 +1176,10 @@ impl Settings {
             .with_list_parse_key("signer.p2p.seeds")
             .with_list_parse_key("signer.p2p.listen_on")
             .with_list_parse_key("signer.p2p.public_endpoints")
+            .with_list_parse_key("signer.accepted_sweep_template_versions")
             .with_list_parse_key("bitcoin.rpc_endpoints")
             .with_list_parse_key("bitcoin.block_hash_stream_endpoints")
+            .with_list_parse_key("bitcoin.esplora_endpoints")
             .with_list_parse_key("stacks.endpoints")
             .with_list_parse_key("emily.endpoints")
             .prefix_separator("_");
@@ -507,19 +1190,73 @@ impl Settings {
         // after https://github.com/stacks-network/sbtc/issues/1004 gets
         // done.
         cfg_builder = cfg_builder.set_default("signer.context_window", 1000)?;
+        cfg_builder = cfg_builder.set_default("signer.block_observer_processing_delay", 0)?;
+        cfg_builder = cfg_builder.set_default("signer.bitcoin_processing_jitter", 0)?;
+        cfg_builder = cfg_builder.set_default("signer.requests_processing_jitter", 0)?;
+        cfg_builder = cfg_builder.set_default("signer.block_observer_processing_jitter", 0)?;
         cfg_builder = cfg_builder.set_default("signer.deposit_decisions_retry_window", 3)?;
         cfg_builder = cfg_builder.set_default("signer.withdrawal_decisions_retry_window", 3)?;
         cfg_builder = cfg_builder.set_default("signer.dkg_max_duration", 120)?;
+        cfg_builder = cfg_builder.set_default("signer.min_connected_signers", 0)?;
+        cfg_builder = cfg_builder.set_default("signer.async_sweep_broadcast", false)?;
         cfg_builder = cfg_builder.set_default("signer.bitcoin_presign_request_max_duration", 30)?;
         cfg_builder = cfg_builder.set_default("signer.signer_round_max_duration", 30)?;
+        cfg_builder = cfg_builder.set_default("signer.expected_bitcoin_block_interval", 600)?;
+        cfg_builder = cfg_builder.set_default("signer.utxo_reconciliation_enabled", false)?;
+        cfg_builder = cfg_builder.set_default("signer.utxo_reconciliation_interval", 3600)?;
+        cfg_builder = cfg_builder.set_default("signer.chain_tip_stall_timeout", 3600)?;
+        cfg_builder = cfg_builder.set_default("signer.chain_tip_max_lag", 2)?;
+        cfg_builder = cfg_builder.set_default("signer.sbtc_limits_cache_ttl", 1800)?;
+        cfg_builder = cfg_builder.set_default("signer.tx_info_cache_ttl", 600)?;
+        cfg_builder = cfg_builder.set_default("signer.limit_override_max_blocks", 144)?;
+        cfg_builder = cfg_builder.set_default("signer.emergency_recovery_delay_blocks", 144)?;
+        cfg_builder = cfg_builder.set_default("signer.emergency_recovery_max_fee_rate", 100)?;
+        cfg_builder =
+            cfg_builder.set_default("signer.emergency_recovery_approval_expiry_blocks", 1008)?;
+        cfg_builder =
+            cfg_builder.set_default("signer.emergency_recovery_approval_window_blocks", 6)?;
+        cfg_builder = cfg_builder.set_default("signer.verify_bitcoin_headers", false)?;
+        cfg_builder = cfg_builder.set_default("signer.verify_stacks_blocks", false)?;
+        cfg_builder = cfg_builder.set_default("signer.deposits_paused", false)?;
+        cfg_builder = cfg_builder.set_default("signer.withdrawals_paused", false)?;
+        cfg_builder = cfg_builder.set_default("signer.include_sweep_metadata", false)?;
+        cfg_builder = cfg_builder.set_default("signer.sweep_template_version", 1)?;
+        cfg_builder =
+            cfg_builder.set_default("signer.accepted_sweep_template_versions", vec![1, 2])?;
         cfg_builder = cfg_builder.set_default(
             "signer.max_deposits_per_bitcoin_tx",
             DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
         )?;
+        cfg_builder = cfg_builder.set_default(
+            "signer.wsts_state_machines_cache_size",
+            crate::MAX_SIGNER_STATE_MACHINES,
+        )?;
+        cfg_builder =
+            cfg_builder.set_default("signer.dkg_verification_state_machines_cache_size", 5)?;
+        cfg_builder = cfg_builder.set_default("signer.stacks_sign_request_cache_size", 2)?;
+        cfg_builder = cfg_builder.set_default(
+            "signer.deposit_expiry_blocks",
+            DEFAULT_DEPOSIT_EXPIRY_BLOCKS,
+        )?;
+        cfg_builder = cfg_builder.set_default("signer.max_withdrawals_per_accept_call", 1)?;
+        cfg_builder = cfg_builder.set_default("signer.max_deposits_per_complete_call", 1)?;
+        cfg_builder = cfg_builder.set_default("signer.sweep_confirmation_depth", 1)?;
         cfg_builder = cfg_builder.set_default("signer.dkg_target_rounds", 1)?;
         cfg_builder = cfg_builder.set_default("emily.pagination_timeout", 10)?;
         cfg_builder = cfg_builder.set_default("signer.dkg_verification_window", 10)?;
         cfg_builder = cfg_builder.set_default("signer.stacks_fees_max_ustx", 1_500_000)?;
+        cfg_builder = cfg_builder.set_default("signer.stacks_tx_max_attempts", 10)?;
+        cfg_builder = cfg_builder.set_default("signer.contracts_target_version", "v1")?;
+        cfg_builder = cfg_builder.set_default("signer.contract_upgrades_enabled", false)?;
+        cfg_builder = cfg_builder.set_default("signer.alerts.slack_min_severity", "warning")?;
+        cfg_builder =
+            cfg_builder.set_default("signer.alerts.pagerduty_min_severity", "critical")?;
+        cfg_builder = cfg_builder.set_default("signer.alerts.dedup_window", 3600)?;
+        cfg_builder = cfg_builder.set_default("signer.alerts.stale_chain_tip_threshold", 1800)?;
+        cfg_builder =
+            cfg_builder.set_default("signer.alerts.stacks_submission_failure_threshold", 3)?;
+        cfg_builder = cfg_builder.set_default("signer.policy_plugin_timeout", 500)?;
+        cfg_builder = cfg_builder.set_default("signer.policy_plugin_fuel", 100_000_000)?;
 
         if let Some(path) = config_path {
             cfg_builder = cfg_builder.add_source(File::from(path.as_ref()));
@@ -633,6 +1370,8 @@ mod tests {
         );
         assert_eq!(settings.bitcoin.rpc_endpoints[0].username(), "devnet");
         assert_eq!(settings.bitcoin.rpc_endpoints[0].password(), Some("devnet"));
+        assert!(settings.bitcoin.esplora_endpoints.is_empty());
+        assert_eq!(settings.bitcoin.esplora_role, EsploraRole::Secondary);
         assert_eq!(
             settings.signer.event_observer.bind,
             "0.0.0.0:8801".parse::<SocketAddr>().unwrap()
@@ -643,6 +1382,65 @@ mod tests {
         );
         assert!(!settings.signer.bootstrap_signing_set.is_empty());
         assert!(settings.signer.dkg_begin_pause.is_none());
+        assert!(settings.signer.min_deposit_amount.is_none());
+        assert!(settings.signer.policy_plugin_path.is_none());
+        assert_eq!(
+            settings.signer.policy_plugin_timeout,
+            Duration::from_millis(500)
+        );
+        assert_eq!(settings.signer.policy_plugin_fuel.get(), 100_000_000);
+        assert!(settings.signer.limit_override_max_per_deposit_cap.is_none());
+        assert!(
+            settings
+                .signer
+                .limit_override_max_per_withdrawal_cap
+                .is_none()
+        );
+        assert_eq!(settings.signer.limit_override_max_blocks.get(), 144);
+        assert!(settings.signer.emergency_recovery_script_pubkey.is_none());
+        assert_eq!(settings.signer.emergency_recovery_delay_blocks.get(), 144);
+        assert_eq!(settings.signer.emergency_recovery_max_fee_rate.get(), 100);
+        assert_eq!(
+            settings
+                .signer
+                .emergency_recovery_approval_expiry_blocks
+                .get(),
+            1008
+        );
+        assert_eq!(settings.signer.emergency_recovery_approval_window_blocks, 6);
+        assert!(settings.signer.max_mint_per_call.is_none());
+        assert!(settings.signer.withdrawal_fee_subsidy_threshold.is_none());
+        assert!(settings.signer.withdrawal_fee_subsidy_budget.is_none());
+        assert!(settings.signer.withdrawal_revalidation_blocks.is_none());
+        assert_eq!(settings.signer.min_connected_signers, 0);
+        assert!(!settings.signer.async_sweep_broadcast);
+        assert!(!settings.signer.utxo_reconciliation_enabled);
+        assert_eq!(
+            settings.signer.deposit_expiry_blocks,
+            NonZeroU16::new(DEFAULT_DEPOSIT_EXPIRY_BLOCKS).unwrap()
+        );
+        assert_eq!(
+            settings.signer.max_withdrawals_per_accept_call,
+            NonZeroU16::new(1).unwrap()
+        );
+        assert_eq!(
+            settings.signer.sweep_confirmation_depth,
+            NonZeroU16::new(1).unwrap()
+        );
+        assert_eq!(
+            settings.signer.max_deposits_per_complete_call,
+            NonZeroU16::new(1).unwrap()
+        );
+        assert_eq!(
+            settings.signer.stacks_tx_max_attempts,
+            NonZeroU16::new(10).unwrap()
+        );
+        assert_eq!(
+            settings.signer.contracts_target_version,
+            SmartContractsVersion::V1
+        );
+        assert!(!settings.signer.contract_upgrades_enabled);
+        assert!(settings.signer.deployer_private_key.is_none());
         assert_eq!(
             settings.signer.sbtc_bitcoin_start_height,
             Some(101u64.into())
@@ -652,6 +1450,7 @@ mod tests {
         assert_eq!(settings.signer.deposit_decisions_retry_window, 3);
         assert_eq!(settings.signer.withdrawal_decisions_retry_window, 3);
         assert!(settings.signer.prometheus_exporter_endpoint.is_none());
+        assert!(settings.signer.grpc_endpoint.is_none());
         assert_eq!(
             settings.signer.bitcoin_presign_request_max_duration,
             Duration::from_secs(30)
@@ -661,6 +1460,31 @@ mod tests {
             Duration::from_secs(30)
         );
         assert_eq!(settings.signer.dkg_max_duration, Duration::from_secs(120));
+        assert_eq!(
+            settings.signer.expected_bitcoin_block_interval,
+            Duration::from_secs(600)
+        );
+        assert_eq!(
+            settings.signer.coordinator_tenure_budget(),
+            Duration::from_secs(300)
+        );
+        assert_eq!(
+            settings.signer.utxo_reconciliation_interval,
+            Duration::from_secs(3600)
+        );
+        assert_eq!(
+            settings.signer.chain_tip_stall_timeout,
+            Duration::from_secs(3600)
+        );
+        assert_eq!(
+            settings.signer.chain_tip_max_lag,
+            NonZeroU16::new(2).unwrap()
+        );
+        assert_eq!(
+            settings.signer.sbtc_limits_cache_ttl,
+            Duration::from_secs(1800)
+        );
+        assert_eq!(settings.signer.tx_info_cache_ttl, Duration::from_secs(600));
         assert_eq!(
             settings.signer.dkg_target_rounds,
             NonZeroU32::new(1).unwrap()
@@ -668,6 +1492,8 @@ mod tests {
         assert_eq!(settings.signer.dkg_verification_window, 10);
         assert_eq!(settings.signer.dkg_min_bitcoin_block_height, None);
         assert_eq!(settings.emily.pagination_timeout, Duration::from_secs(10));
+        assert_eq!(settings.emily.page_size, None);
+        assert!(settings.emily.api_keys.is_empty());
     }
 
     #[test]
@@ -702,6 +1528,8 @@ mod tests {
             "tcp://seed-1:4122,tcp://seed-2:4122",
         );
         set_var("SIGNER_SIGNER__P2P__LISTEN_ON", "tcp://1.2.3.4:1234");
+        set_var("SIGNER_SIGNER__P2P__PIN_BOOTSTRAP_SIGNERS", "true");
+        set_var("SIGNER_SIGNER__P2P__MESSAGE_RETENTION", "600");
 
         let settings = Settings::new_from_default_config().unwrap();
 
@@ -716,6 +1544,11 @@ mod tests {
             settings.signer.p2p.listen_on,
             vec![multiaddr("tcp://1.2.3.4:1234")]
         );
+        assert!(settings.signer.p2p.pin_bootstrap_signers);
+        assert_eq!(
+            settings.signer.p2p.message_retention,
+            Duration::from_secs(600)
+        );
     }
 
     #[test]
@@ -732,8 +1565,22 @@ mod tests {
             "tcp://localhost:1234,tcp://localhost:5678",
         );
 
+        set_var(
+            "SIGNER_BITCOIN__ESPLORA_ENDPOINTS",
+            "https://esplora-1.example/api,https://esplora-2.example/api",
+        );
+        set_var("SIGNER_BITCOIN__ESPLORA_ROLE", "primary");
+
         let settings = Settings::new_from_default_config().unwrap();
 
+        assert_eq!(
+            settings.bitcoin.esplora_endpoints,
+            vec![
+                url("https://esplora-1.example/api"),
+                url("https://esplora-2.example/api")
+            ]
+        );
+        assert_eq!(settings.bitcoin.esplora_role, EsploraRole::Primary);
         assert_eq!(settings.bitcoin.rpc_endpoints.len(), 2);
         assert!(
             settings
@@ -900,6 +1747,10 @@ mod tests {
         let settings = Settings::new_from_default_config().unwrap();
         assert_eq!(settings.signer.network, NetworkKind::Testnet);
 
+        set_var("SIGNER_SIGNER__NETWORK", "testnet4");
+        let settings = Settings::new_from_default_config().unwrap();
+        assert_eq!(settings.signer.network, NetworkKind::Testnet4);
+
         // We unset the p2p seeds here as they're not required for regtest.
         set_var("SIGNER_SIGNER__P2P__SEEDS", "");
         let new = "regtest";
@@ -1012,6 +1863,23 @@ mod tests {
             settings.signer.requests_processing_delay,
             std::time::Duration::from_secs(delay),
         );
+
+        set_var("SIGNER_SIGNER__BLOCK_OBSERVER_PROCESSING_DELAY", "7");
+        set_var("SIGNER_SIGNER__BLOCK_OBSERVER_PROCESSING_JITTER", "1500");
+
+        let settings = Settings::new_from_default_config().unwrap();
+        assert_eq!(
+            settings.signer.block_observer_delay(),
+            ProcessingDelay {
+                delay: std::time::Duration::from_secs(7),
+                jitter: std::time::Duration::from_millis(1500),
+            }
+        );
+        // The other event loops are not affected.
+        assert_eq!(
+            settings.signer.request_decider_delay().jitter,
+            std::time::Duration::ZERO,
+        );
     }
 
     #[test]
@@ -1038,7 +1906,17 @@ mod tests {
         remove_parameter("signer", "signer_round_max_duration");
         remove_parameter("signer", "bitcoin_presign_request_max_duration");
         remove_parameter("signer", "dkg_max_duration");
+        remove_parameter("signer", "expected_bitcoin_block_interval");
+        remove_parameter("signer", "utxo_reconciliation_interval");
+        remove_parameter("signer", "chain_tip_stall_timeout");
         remove_parameter("signer", "max_deposits_per_bitcoin_tx");
+        remove_parameter("signer", "deposit_expiry_blocks");
+        remove_parameter("signer", "max_withdrawals_per_accept_call");
+        remove_parameter("signer", "max_deposits_per_complete_call");
+        remove_parameter("signer", "sweep_confirmation_depth");
+        remove_parameter("signer", "stacks_tx_max_attempts");
+        remove_parameter("signer", "contracts_target_version");
+        remove_parameter("signer", "contract_upgrades_enabled");
 
         remove_parameter("emily", "pagination_timeout");
 
@@ -1060,10 +1938,61 @@ mod tests {
             Duration::from_secs(30)
         );
         assert_eq!(settings.signer.dkg_max_duration, Duration::from_secs(120));
+        assert_eq!(
+            settings.signer.expected_bitcoin_block_interval,
+            Duration::from_secs(600)
+        );
+        assert_eq!(
+            settings.signer.utxo_reconciliation_interval,
+            Duration::from_secs(3600)
+        );
+        assert_eq!(
+            settings.signer.chain_tip_stall_timeout,
+            Duration::from_secs(3600)
+        );
+        assert_eq!(
+            settings.signer.chain_tip_max_lag,
+            NonZeroU16::new(2).unwrap()
+        );
+        assert_eq!(
+            settings.signer.sbtc_limits_cache_ttl,
+            Duration::from_secs(1800)
+        );
+        assert_eq!(settings.signer.tx_info_cache_ttl, Duration::from_secs(600));
 
         assert_eq!(settings.emily.pagination_timeout, Duration::from_secs(10));
     }
 
+    #[test]
+    fn emily_page_size_can_be_loaded_from_environment() {
+        clear_env();
+        set_var("SIGNER_EMILY__PAGE_SIZE", "250");
+        let settings = Settings::new_from_default_config().unwrap();
+        assert_eq!(settings.emily.page_size, Some(250));
+
+        set_var("SIGNER_EMILY__PAGE_SIZE", "0");
+        assert!(Settings::new_from_default_config().is_err());
+        clear_env();
+    }
+
+    #[test]
+    fn emily_config_debug_masks_api_keys() {
+        clear_env();
+        let mut settings = Settings::new_from_default_config().unwrap();
+        settings.emily.endpoints = vec![Url::parse("http://1234567890abcdef@localhost").unwrap()];
+        settings.emily.api_keys = vec![EmilyApiKey {
+            name: "primary".to_string(),
+            key: "fedcba0987654321".to_string(),
+            priority: 0,
+        }];
+
+        let debug = format!("{:?}", settings.emily);
+        assert!(!debug.contains("1234567890abcdef"));
+        assert!(!debug.contains("fedcba0987654321"));
+        assert!(debug.contains("****cdef"));
+        assert!(debug.contains("****4321"));
+    }
+
     #[test]
     fn stacks_fees_max_ustx_can_be_loaded_from_environment() {
         clear_env();
@@ -1084,6 +2013,9 @@ mod tests {
     #[test_case("dkg_max_duration" ; "dkg_max_duration")]
     #[test_case("bitcoin_presign_request_max_duration" ; "bitcoin_presign_request_max_duration")]
     #[test_case("signer_round_max_duration" ; "signer_round_max_duration")]
+    #[test_case("expected_bitcoin_block_interval" ; "expected_bitcoin_block_interval")]
+    #[test_case("utxo_reconciliation_interval" ; "utxo_reconciliation_interval")]
+    #[test_case("chain_tip_stall_timeout" ; "chain_tip_stall_timeout")]
     #[test_case("stacks_fees_max_ustx" ; "stacks_fees_max_ustx")]
     fn zero_values_for_nonzero_fields_fail_in_signer_config(field: &str) {
         clear_env();
@@ -1167,29 +2099,310 @@ mod tests {
     }
 
     #[test]
-    fn invalid_bitcoin_processing_delay_returns_correct_error() {
+    fn invalid_max_deposits_per_complete_call_returns_correct_error() {
         clear_env();
 
-        let delay = MAX_BITCOIN_PROCESSING_DELAY_SECONDS + 1;
-        set_var("SIGNER_SIGNER__BITCOIN_PROCESSING_DELAY", delay.to_string());
+        let max_deposits = MAX_DEPOSITS_PER_COMPLETE_CALL + 1;
+        set_var(
+            "SIGNER_SIGNER__MAX_DEPOSITS_PER_COMPLETE_CALL",
+            max_deposits.to_string(),
+        );
 
         let settings = Settings::new_from_default_config();
         assert!(settings.is_err());
         assert!(matches!(
             settings.unwrap_err(),
-            ConfigError::Message(msg) if msg == SignerConfigError::InvalidBitcoinProcessingDelay(MAX_BITCOIN_PROCESSING_DELAY_SECONDS, delay).to_string()
+            ConfigError::Message(msg) if msg == SignerConfigError::InvalidMaxDepositsPerCompleteCall(MAX_DEPOSITS_PER_COMPLETE_CALL, max_deposits).to_string()
         ));
     }
 
     #[test]
-    fn invalid_requests_processing_delay_returns_correct_error() {
+    fn cache_sizes_load_defaults_and_environment() {
         clear_env();
 
-        let delay = MAX_REQUESTS_PROCESSING_DELAY_SECONDS + 1;
-        set_var(
-            "SIGNER_SIGNER__REQUESTS_PROCESSING_DELAY",
-            delay.to_string(),
-        );
+        let settings = Settings::new_from_default_config().unwrap();
+        assert_eq!(
+            settings.signer.wsts_state_machines_cache_size.get() as u64,
+            crate::MAX_SIGNER_STATE_MACHINES
+        );
+        assert_eq!(
+            settings
+                .signer
+                .dkg_verification_state_machines_cache_size
+                .get(),
+            5
+        );
+        assert_eq!(settings.signer.stacks_sign_request_cache_size.get(), 2);
+
+        set_var("SIGNER_SIGNER__STACKS_SIGN_REQUEST_CACHE_SIZE", "4");
+        let settings = Settings::new_from_default_config().unwrap();
+        assert_eq!(settings.signer.stacks_sign_request_cache_size.get(), 4);
+
+        set_var("SIGNER_SIGNER__STACKS_SIGN_REQUEST_CACHE_SIZE", "0");
+        assert!(Settings::new_from_default_config().is_err());
+    }
+
+    #[test]
+    fn too_small_wsts_state_machines_cache_returns_correct_error() {
+        clear_env();
+
+        let min_state_machines = DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX as usize + 2;
+        let state_machines = min_state_machines - 1;
+        set_var(
+            "SIGNER_SIGNER__WSTS_STATE_MACHINES_CACHE_SIZE",
+            state_machines.to_string(),
+        );
+
+        let settings = Settings::new_from_default_config();
+        assert!(matches!(
+            settings.unwrap_err(),
+            ConfigError::Message(msg) if msg == SignerConfigError::InvalidCacheSize("wsts_state_machines_cache_size", min_state_machines, state_machines).to_string()
+        ));
+    }
+
+    #[test]
+    fn alerts_config_loads_defaults_and_environment() {
+        clear_env();
+
+        let settings = Settings::new_from_default_config().unwrap();
+        let alerts = &settings.signer.alerts;
+        assert!(alerts.slack_webhook_url.is_none());
+        assert!(alerts.pagerduty_routing_key.is_none());
+        assert_eq!(alerts.slack_min_severity, AlertSeverity::Warning);
+        assert_eq!(alerts.pagerduty_min_severity, AlertSeverity::Critical);
+        assert_eq!(alerts.dedup_window, Duration::from_secs(3600));
+        assert_eq!(alerts.stale_chain_tip_threshold, Duration::from_secs(1800));
+        assert_eq!(alerts.stacks_submission_failure_threshold.get(), 3);
+
+        set_var(
+            "SIGNER_SIGNER__ALERTS__SLACK_WEBHOOK_URL",
+            "https://hooks.slack.com/services/T000/B000/XXXX",
+        );
+        set_var("SIGNER_SIGNER__ALERTS__SLACK_MIN_SEVERITY", "error");
+        set_var(
+            "SIGNER_SIGNER__ALERTS__PAGERDUTY_ROUTING_KEY",
+            "abcdef0123456789",
+        );
+        set_var("SIGNER_SIGNER__ALERTS__STALE_CHAIN_TIP_THRESHOLD", "0");
+
+        let settings = Settings::new_from_default_config().unwrap();
+        let alerts = &settings.signer.alerts;
+        assert_eq!(
+            alerts.slack_webhook_url,
+            Some(Url::parse("https://hooks.slack.com/services/T000/B000/XXXX").unwrap())
+        );
+        assert_eq!(alerts.slack_min_severity, AlertSeverity::Error);
+        assert_eq!(
+            alerts.pagerduty_routing_key.as_deref(),
+            Some("abcdef0123456789")
+        );
+        assert!(alerts.stale_chain_tip_threshold.is_zero());
+    }
+
+    #[test]
+    fn verify_bitcoin_headers_loads_from_environment() {
+        clear_env();
+
+        let settings = Settings::new_from_default_config().unwrap();
+        assert!(!settings.signer.verify_bitcoin_headers);
+
+        set_var("SIGNER_SIGNER__VERIFY_BITCOIN_HEADERS", "true");
+        let settings = Settings::new_from_default_config().unwrap();
+        assert!(settings.signer.verify_bitcoin_headers);
+    }
+
+    #[test]
+    fn verify_stacks_blocks_loads_from_environment() {
+        clear_env();
+
+        let settings = Settings::new_from_default_config().unwrap();
+        assert!(!settings.signer.verify_stacks_blocks);
+
+        set_var("SIGNER_SIGNER__VERIFY_STACKS_BLOCKS", "true");
+        let settings = Settings::new_from_default_config().unwrap();
+        assert!(settings.signer.verify_stacks_blocks);
+    }
+
+    #[test]
+    fn deposits_paused_loads_from_environment() {
+        clear_env();
+
+        let settings = Settings::new_from_default_config().unwrap();
+        assert!(!settings.signer.deposits_paused);
+
+        set_var("SIGNER_SIGNER__DEPOSITS_PAUSED", "true");
+        let settings = Settings::new_from_default_config().unwrap();
+        assert!(settings.signer.deposits_paused);
+    }
+
+    #[test]
+    fn withdrawals_paused_loads_from_environment() {
+        clear_env();
+
+        let settings = Settings::new_from_default_config().unwrap();
+        assert!(!settings.signer.withdrawals_paused);
+
+        set_var("SIGNER_SIGNER__WITHDRAWALS_PAUSED", "true");
+        let settings = Settings::new_from_default_config().unwrap();
+        assert!(settings.signer.withdrawals_paused);
+        assert!(!settings.signer.deposits_paused);
+    }
+
+    #[test]
+    fn include_sweep_metadata_loads_from_environment() {
+        clear_env();
+
+        let settings = Settings::new_from_default_config().unwrap();
+        assert!(!settings.signer.include_sweep_metadata);
+
+        set_var("SIGNER_SIGNER__INCLUDE_SWEEP_METADATA", "true");
+        let settings = Settings::new_from_default_config().unwrap();
+        assert!(settings.signer.include_sweep_metadata);
+    }
+
+    #[test]
+    fn sweep_template_versions_load_from_environment() {
+        clear_env();
+
+        let settings = Settings::new_from_default_config().unwrap();
+        assert_eq!(
+            settings.signer.sweep_template_version,
+            SweepTemplateVersion::V1
+        );
+        assert_eq!(
+            settings.signer.accepted_sweep_template_versions,
+            SweepTemplateVersion::ALL
+        );
+        assert_eq!(
+            settings.signer.proposed_sweep_template(),
+            SweepTemplateVersion::V1
+        );
+
+        // The legacy flag asks for a version that includes the metadata.
+        set_var("SIGNER_SIGNER__INCLUDE_SWEEP_METADATA", "true");
+        let settings = Settings::new_from_default_config().unwrap();
+        assert_eq!(
+            settings.signer.proposed_sweep_template(),
+            SweepTemplateVersion::V2
+        );
+
+        clear_env();
+        set_var("SIGNER_SIGNER__SWEEP_TEMPLATE_VERSION", "2");
+        set_var("SIGNER_SIGNER__ACCEPTED_SWEEP_TEMPLATE_VERSIONS", "2");
+        let settings = Settings::new_from_default_config().unwrap();
+        assert_eq!(
+            settings.signer.proposed_sweep_template(),
+            SweepTemplateVersion::V2
+        );
+        assert!(
+            !settings
+                .signer
+                .accepts_sweep_template(SweepTemplateVersion::V1)
+        );
+
+        set_var("SIGNER_SIGNER__SWEEP_TEMPLATE_VERSION", "3");
+        assert!(Settings::new_from_default_config().is_err());
+    }
+
+    #[test]
+    fn unaccepted_sweep_template_version_is_rejected() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__ACCEPTED_SWEEP_TEMPLATE_VERSIONS", "2");
+        let result = Settings::new_from_default_config();
+        assert!(matches!(
+            result.unwrap_err(),
+            ConfigError::Message(msg) if msg == SignerConfigError::UnacceptedSweepTemplateVersion(SweepTemplateVersion::V1).to_string()
+        ));
+    }
+
+    #[test]
+    fn psbt_export_sinks_load_from_environment() {
+        clear_env();
+
+        let settings = Settings::new_from_default_config().unwrap();
+        assert!(settings.signer.psbt_export_directory.is_none());
+        assert!(settings.signer.psbt_export_endpoint.is_none());
+
+        set_var(
+            "SIGNER_SIGNER__PSBT_EXPORT_DIRECTORY",
+            "/var/lib/sbtc/psbts",
+        );
+        set_var(
+            "SIGNER_SIGNER__PSBT_EXPORT_ENDPOINT",
+            "http://review.local:8080/psbts",
+        );
+
+        let settings = Settings::new_from_default_config().unwrap();
+        assert_eq!(
+            settings.signer.psbt_export_directory,
+            Some("/var/lib/sbtc/psbts".into())
+        );
+        assert_eq!(
+            settings.signer.psbt_export_endpoint,
+            Some(Url::parse("http://review.local:8080/psbts").unwrap())
+        );
+    }
+
+    #[test]
+    fn deployer_private_key_for_another_address_returns_correct_error() {
+        clear_env();
+
+        set_var(
+            "SIGNER_SIGNER__DEPLOYER_PRIVATE_KEY",
+            "41634762d89dfa09133a4a8e9c1378d0161d29cd0a9433b51f1e3d32947a73dc",
+        );
+
+        let settings = Settings::new_from_default_config();
+        let deployer = "SN3R84XZYA63QS28932XQF3G1J8R9PC3W76P9CSQS".to_string();
+        assert!(matches!(
+            settings.unwrap_err(),
+            ConfigError::Message(msg) if msg == SignerConfigError::DeployerKeyMismatch(deployer).to_string()
+        ));
+    }
+
+    #[test]
+    fn invalid_max_withdrawals_per_accept_call_returns_correct_error() {
+        clear_env();
+
+        let max_withdrawals = MAX_WITHDRAWALS_PER_ACCEPT_CALL + 1;
+        set_var(
+            "SIGNER_SIGNER__MAX_WITHDRAWALS_PER_ACCEPT_CALL",
+            max_withdrawals.to_string(),
+        );
+
+        let settings = Settings::new_from_default_config();
+        assert!(settings.is_err());
+        assert!(matches!(
+            settings.unwrap_err(),
+            ConfigError::Message(msg) if msg == SignerConfigError::InvalidMaxWithdrawalsPerAcceptCall(MAX_WITHDRAWALS_PER_ACCEPT_CALL, max_withdrawals).to_string()
+        ));
+    }
+
+    #[test]
+    fn invalid_bitcoin_processing_delay_returns_correct_error() {
+        clear_env();
+
+        let delay = MAX_BITCOIN_PROCESSING_DELAY_SECONDS + 1;
+        set_var("SIGNER_SIGNER__BITCOIN_PROCESSING_DELAY", delay.to_string());
+
+        let settings = Settings::new_from_default_config();
+        assert!(settings.is_err());
+        assert!(matches!(
+            settings.unwrap_err(),
+            ConfigError::Message(msg) if msg == SignerConfigError::InvalidBitcoinProcessingDelay(MAX_BITCOIN_PROCESSING_DELAY_SECONDS, delay).to_string()
+        ));
+    }
+
+    #[test]
+    fn invalid_requests_processing_delay_returns_correct_error() {
+        clear_env();
+
+        let delay = MAX_REQUESTS_PROCESSING_DELAY_SECONDS + 1;
+        set_var(
+            "SIGNER_SIGNER__REQUESTS_PROCESSING_DELAY",
+            delay.to_string(),
+        );
 
         let settings = Settings::new_from_default_config();
         assert!(settings.is_err());
@@ -1199,6 +2412,44 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn invalid_processing_jitter_returns_correct_error() {
+        clear_env();
+
+        let jitter = MAX_PROCESSING_JITTER_MILLISECONDS + 1;
+        set_var(
+            "SIGNER_SIGNER__REQUESTS_PROCESSING_JITTER",
+            jitter.to_string(),
+        );
+
+        let settings = Settings::new_from_default_config();
+        assert!(settings.is_err());
+        assert!(matches!(
+            settings.unwrap_err(),
+            ConfigError::Message(msg) if msg == SignerConfigError::InvalidProcessingJitter("requests_processing_jitter", MAX_PROCESSING_JITTER_MILLISECONDS, jitter).to_string()
+        ));
+    }
+
+    #[test]
+    fn processing_delay_jitter_is_bounded() {
+        let mut rng = get_rng();
+        let delay = ProcessingDelay {
+            delay: std::time::Duration::from_secs(2),
+            jitter: std::time::Duration::from_millis(500),
+        };
+        for _ in 0..100 {
+            let sampled = delay.sample(&mut rng);
+            assert!(sampled >= delay.delay);
+            assert!(sampled <= delay.delay + delay.jitter);
+        }
+
+        let delay = ProcessingDelay {
+            jitter: std::time::Duration::ZERO,
+            ..delay
+        };
+        assert_eq!(delay.sample(&mut rng), delay.delay);
+    }
+
     #[test]
     fn invalid_private_key_compression_byte_marker_returns_correct_error() {
         clear_env();
@@ -1259,6 +2510,207 @@ mod tests {
         assert_eq!(config.signer.dkg_begin_pause, Some(1234));
     }
 
+    #[test]
+    fn max_mint_per_call_env_variable_works() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__MAX_MINT_PER_CALL", "250000");
+        let config = Settings::new_from_default_config().unwrap();
+        assert_eq!(config.signer.max_mint_per_call, Some(250_000));
+    }
+
+    #[test]
+    fn sweep_confirmation_depth_env_variable_works() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__SWEEP_CONFIRMATION_DEPTH", "6");
+        let config = Settings::new_from_default_config().unwrap();
+        assert_eq!(config.signer.sweep_confirmation_depth.get(), 6);
+    }
+
+    #[test]
+    fn chain_tip_max_lag_env_variable_works() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__CHAIN_TIP_MAX_LAG", "5");
+        let config = Settings::new_from_default_config().unwrap();
+        assert_eq!(config.signer.chain_tip_max_lag.get(), 5);
+    }
+
+    #[test]
+    fn sbtc_limits_cache_ttl_env_variable_works() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__SBTC_LIMITS_CACHE_TTL", "0");
+        let config = Settings::new_from_default_config().unwrap();
+        assert_eq!(config.signer.sbtc_limits_cache_ttl, Duration::ZERO);
+    }
+
+    #[test]
+    fn tx_info_cache_ttl_env_variable_works() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__TX_INFO_CACHE_TTL", "0");
+        let config = Settings::new_from_default_config().unwrap();
+        assert_eq!(config.signer.tx_info_cache_ttl, Duration::ZERO);
+
+        clear_env();
+    }
+
+    #[test]
+    fn limit_override_env_variables_work() {
+        clear_env();
+
+        set_var(
+            "SIGNER_SIGNER__LIMIT_OVERRIDE_MAX_PER_DEPOSIT_CAP",
+            "500000000",
+        );
+        set_var(
+            "SIGNER_SIGNER__LIMIT_OVERRIDE_MAX_PER_WITHDRAWAL_CAP",
+            "100000000",
+        );
+        set_var("SIGNER_SIGNER__LIMIT_OVERRIDE_MAX_BLOCKS", "6");
+        let config = Settings::new_from_default_config().unwrap();
+        let signer = config.signer;
+        assert_eq!(signer.limit_override_max_per_deposit_cap, Some(500_000_000));
+        assert_eq!(
+            signer.limit_override_max_per_withdrawal_cap,
+            Some(100_000_000)
+        );
+        assert_eq!(signer.limit_override_max_blocks.get(), 6);
+    }
+
+    #[test]
+    fn emergency_recovery_env_variables_work() {
+        clear_env();
+
+        let script = "0014000102030405060708090a0b0c0d0e0f10111213";
+        set_var("SIGNER_SIGNER__EMERGENCY_RECOVERY_SCRIPT_PUBKEY", script);
+        set_var("SIGNER_SIGNER__EMERGENCY_RECOVERY_DELAY_BLOCKS", "12");
+        set_var("SIGNER_SIGNER__EMERGENCY_RECOVERY_MAX_FEE_RATE", "25");
+        set_var(
+            "SIGNER_SIGNER__EMERGENCY_RECOVERY_APPROVAL_EXPIRY_BLOCKS",
+            "36",
+        );
+        set_var(
+            "SIGNER_SIGNER__EMERGENCY_RECOVERY_APPROVAL_WINDOW_BLOCKS",
+            "3",
+        );
+        let config = Settings::new_from_default_config().unwrap();
+        let signer = config.signer;
+        assert_eq!(
+            signer.emergency_recovery_script_pubkey,
+            Some(bitcoin::ScriptBuf::from_hex(script).unwrap())
+        );
+        assert_eq!(signer.emergency_recovery_delay_blocks.get(), 12);
+        assert_eq!(signer.emergency_recovery_max_fee_rate.get(), 25);
+        assert_eq!(signer.emergency_recovery_approval_expiry_blocks.get(), 36);
+        assert_eq!(signer.emergency_recovery_approval_window_blocks, 3);
+
+        // Approvals that expire before the sweep is allowed would never
+        // lead to a sweep.
+        set_var(
+            "SIGNER_SIGNER__EMERGENCY_RECOVERY_APPROVAL_EXPIRY_BLOCKS",
+            "12",
+        );
+        let settings = Settings::new_from_default_config();
+        let expected = SignerConfigError::EmergencyRecoveryApprovalExpiryTooShort(
+            NonZeroU16::new(12).unwrap(),
+            NonZeroU16::new(12).unwrap(),
+        );
+        assert!(matches!(
+            settings.unwrap_err(),
+            ConfigError::Message(msg) if msg == expected.to_string()
+        ));
+        set_var(
+            "SIGNER_SIGNER__EMERGENCY_RECOVERY_APPROVAL_EXPIRY_BLOCKS",
+            "36",
+        );
+
+        // Sweeping the funds to an OP_RETURN output would burn them.
+        set_var("SIGNER_SIGNER__EMERGENCY_RECOVERY_SCRIPT_PUBKEY", "6a00");
+        let settings = Settings::new_from_default_config();
+        assert!(matches!(
+            settings.unwrap_err(),
+            ConfigError::Message(msg) if msg == SignerConfigError::InvalidEmergencyRecoveryScript.to_string()
+        ));
+
+        clear_env();
+    }
+
+    #[test]
+    fn async_sweep_broadcast_env_variable_works() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__ASYNC_SWEEP_BROADCAST", "true");
+        let config = Settings::new_from_default_config().unwrap();
+        assert!(config.signer.async_sweep_broadcast);
+
+        clear_env();
+    }
+
+    #[test]
+    fn utxo_reconciliation_enabled_env_variable_works() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__UTXO_RECONCILIATION_ENABLED", "true");
+        let config = Settings::new_from_default_config().unwrap();
+        assert!(config.signer.utxo_reconciliation_enabled);
+
+        clear_env();
+    }
+
+    #[test]
+    fn min_connected_signers_env_variable_works() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__MIN_CONNECTED_SIGNERS", "2");
+        let config = Settings::new_from_default_config().unwrap();
+        assert_eq!(config.signer.min_connected_signers, 2);
+
+        // The default config has three signers, so this signer can only
+        // ever be connected to two others.
+        set_var("SIGNER_SIGNER__MIN_CONNECTED_SIGNERS", "3");
+        let settings = Settings::new_from_default_config();
+        assert!(matches!(
+            settings.unwrap_err(),
+            ConfigError::Message(msg)
+                if msg == SignerConfigError::TooManyConnectedSignersRequired(3, 2).to_string()
+        ));
+
+        clear_env();
+    }
+
+    #[test]
+    fn withdrawal_fee_subsidy_env_variables_work() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__WITHDRAWAL_FEE_SUBSIDY_THRESHOLD", "50000");
+        set_var("SIGNER_SIGNER__WITHDRAWAL_FEE_SUBSIDY_BUDGET", "20000");
+        let config = Settings::new_from_default_config().unwrap();
+        let subsidy = config.signer.withdrawal_fee_subsidy();
+        assert_eq!(subsidy.threshold, 50_000);
+        assert_eq!(subsidy.budget, 20_000);
+
+        clear_env();
+
+        set_var("SIGNER_SIGNER__WITHDRAWAL_FEE_SUBSIDY_THRESHOLD", "50000");
+        let settings = Settings::new_from_default_config();
+        assert!(matches!(
+            settings.unwrap_err(),
+            ConfigError::Message(msg) if msg == SignerConfigError::IncompleteWithdrawalFeeSubsidy.to_string()
+        ));
+    }
+
+    #[test]
+    fn min_deposit_amount_env_variables_work() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__MIN_DEPOSIT_AMOUNT", "10000");
+        let config = Settings::new_from_default_config().unwrap();
+        assert_eq!(config.signer.min_deposit_amount, Some(10_000));
+    }
+
     #[test]
     fn invalid_p2p_uri_scheme_returns_correct_error() {
         clear_env();
@@ -1433,6 +2885,7 @@ mod tests {
 
     #[test_case::test_case(NetworkKind::Mainnet; "mainnet network, testnet deployer")]
     #[test_case::test_case(NetworkKind::Testnet; "testnet network, mainnet deployer")]
+    #[test_case::test_case(NetworkKind::Testnet4; "testnet4 network, mainnet deployer")]
     fn network_mismatch_network_of_deployer(network: NetworkKind) {
         clear_env();
 
@@ -1444,6 +2897,7 @@ mod tests {
         let network = match network {
             NetworkKind::Mainnet => "mainnet",
             NetworkKind::Testnet => "testnet",
+            NetworkKind::Testnet4 => "testnet4",
             NetworkKind::Regtest => "regtest",
         };
         set_var("SIGNER_SIGNER__NETWORK", network);
@@ -1458,6 +2912,7 @@ mod tests {
 
     #[test_case::test_case(NetworkKind::Mainnet; "mainnet")]
     #[test_case::test_case(NetworkKind::Testnet; "testnet")]
+    #[test_case::test_case(NetworkKind::Testnet4; "testnet4")]
     #[test_case::test_case(NetworkKind::Regtest; "regtest")]
     fn network_matches_network_of_deployer(network: NetworkKind) {
         clear_env();
@@ -1470,6 +2925,7 @@ mod tests {
         let network = match network {
             NetworkKind::Mainnet => "mainnet",
             NetworkKind::Testnet => "testnet",
+            NetworkKind::Testnet4 => "testnet4",
             NetworkKind::Regtest => "regtest",
         };
         set_var("SIGNER_SIGNER__NETWORK", network);
@@ -1479,6 +2935,54 @@ mod tests {
         assert!(Settings::new_from_default_config().is_ok());
     }
 
+    #[test]
+    fn testnet4_network_uses_testnet4_parameters() {
+        let network = NetworkKind::Testnet4;
+        assert_eq!(bitcoin::Network::from(network), bitcoin::Network::Testnet4);
+        assert_eq!(
+            bitcoin::NetworkKind::from(network),
+            bitcoin::NetworkKind::Test
+        );
+        assert_eq!(
+            bitcoin::KnownHrp::from(network),
+            bitcoin::KnownHrp::Testnets
+        );
+        assert_eq!(network.to_string(), "testnet4");
+        assert!(!network.is_mainnet());
+        assert_eq!(network.emily_environment(), EmilyEnvironment::Testnet);
+    }
+
+    #[test]
+    fn emily_environment_defaults_to_the_signer_network() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__NETWORK", "testnet4");
+        set_var("SIGNER_SIGNER__P2P__SEEDS", "tcp://localhost:4122");
+        let settings = Settings::new_from_default_config().unwrap();
+        assert_eq!(settings.emily.environment, None);
+        let environment = settings.emily.environment(settings.signer.network);
+        assert_eq!(environment, EmilyEnvironment::Testnet);
+
+        set_var("SIGNER_EMILY__ENVIRONMENT", "testnet");
+        let settings = Settings::new_from_default_config().unwrap();
+        assert_eq!(settings.emily.environment, Some(EmilyEnvironment::Testnet));
+
+        clear_env();
+    }
+
+    #[test]
+    fn emily_environment_must_match_the_signer_network() {
+        clear_env();
+
+        set_var("SIGNER_SIGNER__NETWORK", "testnet4");
+        set_var("SIGNER_SIGNER__P2P__SEEDS", "tcp://localhost:4122");
+        set_var("SIGNER_EMILY__ENVIRONMENT", "mainnet");
+        let error = Settings::new_from_default_config().unwrap_err();
+        assert!(error.to_string().contains("Emily deployment"));
+
+        clear_env();
+    }
+
     #[test]
     fn bootstrap_wallet_signatures_required() {
         clear_env();
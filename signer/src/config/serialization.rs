@@ -33,6 +33,18 @@ where
         .map_err(serde::de::Error::custom)
 }
 
+/// A deserializer for an optional url::Url. See [`url_deserializer_single`].
+pub fn url_deserializer_optional<'de, D>(deserializer: D) -> Result<Option<url::Url>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct OptionalUrl(#[serde(deserialize_with = "url_deserializer_single")] Url);
+
+    let url = Option::<OptionalUrl>::deserialize(deserializer)?;
+    Ok(url.map(|OptionalUrl(url)| url))
+}
+
 /// A deserializer for the std::time::Duration type.
 /// Serde includes a default deserializer, but it expects a struct.
 pub fn duration_seconds_deserializer<'de, D>(
@@ -97,6 +109,21 @@ where
     }
 }
 
+/// A deserializer for an optional [`PrivateKey`]. See
+/// [`private_key_deserializer`] for the checks on the private key.
+pub fn optional_private_key_deserializer<'de, D>(
+    deserializer: D,
+) -> Result<Option<PrivateKey>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Key(#[serde(deserialize_with = "private_key_deserializer")] PrivateKey);
+
+    let key = Option::<Key>::deserialize(deserializer)?;
+    Ok(key.map(|Key(private_key)| private_key))
+}
+
 pub fn try_parse_p2p_multiaddr(s: &str) -> Result<Multiaddr, SignerConfigError> {
     // Keeping these local here as this is the only place these should need to be used.
     use SignerConfigError::{
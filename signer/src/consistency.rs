@@ -0,0 +1,230 @@
+//! Detection of drift between the databases of different signers.
+//!
+//! Signers assume that their databases hold the same view of recent
+//! deposit requests, the decisions made on them, and the DKG shares
+//! metadata. When that assumption breaks, the first symptom is usually a
+//! signing round that fails validation. To catch drift earlier, each
+//! signer broadcasts a [`DatabaseDigest`] after processing a new bitcoin
+//! block, and compares digests received from its peers with its own.
+//!
+//! Each digest is the root of a Merkle tree whose leaves are the hashes of
+//! the rows of a table, sorted so that the order that rows were written
+//! in does not matter.
+
+use sha2::Digest as _;
+use sha2::Sha256;
+
+use crate::error::Error;
+use crate::message::DatabaseDigest;
+use crate::metrics::Metrics;
+use crate::storage::DbRead;
+use crate::storage::model;
+use crate::storage::model::BitcoinBlockHash;
+
+/// The number of bitcoin blocks, ending at a chain tip, that are covered
+/// by the digests exchanged between signers.
+pub const DIGEST_BLOCK_RANGE: u16 = 6;
+
+/// The tables covered by a [`DatabaseDigest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum DigestTable {
+    /// The deposit requests table.
+    DepositRequests,
+    /// The signers' decisions on deposit requests.
+    DepositDecisions,
+    /// The DKG shares metadata.
+    DkgShares,
+}
+
+/// Compute the digests of the signer's database for the `num_blocks`
+/// bitcoin blocks ending at the given chain tip.
+pub async fn compute_database_digest<D>(
+    db: &D,
+    chain_tip: &BitcoinBlockHash,
+    num_blocks: u16,
+) -> Result<DatabaseDigest, Error>
+where
+    D: DbRead,
+{
+    let deposit_requests = db.get_deposit_requests(chain_tip, num_blocks).await?;
+
+    let mut decision_leaves = Vec::new();
+    for request in deposit_requests.iter() {
+        let decisions = db
+            .get_deposit_signers(&request.txid, request.output_index)
+            .await?;
+        decision_leaves.extend(decisions.iter().map(deposit_decision_leaf));
+    }
+
+    let request_leaves = deposit_requests.iter().map(deposit_request_leaf).collect();
+
+    let dkg_shares_count = db.get_encrypted_dkg_shares_count().await?;
+    let dkg_shares = db.get_latest_encrypted_dkg_shares().await?;
+    let dkg_shares_leaves = vec![dkg_shares_leaf(dkg_shares_count, dkg_shares.as_ref())];
+
+    Ok(DatabaseDigest {
+        num_blocks,
+        deposit_requests: merkle_root(request_leaves),
+        deposit_decisions: merkle_root(decision_leaves),
+        dkg_shares: merkle_root(dkg_shares_leaves),
+    })
+}
+
+/// Return the tables where the two digests disagree.
+///
+/// Digests that cover a different number of blocks are not comparable,
+/// so an empty list is returned for them.
+pub fn diverging_tables(ours: &DatabaseDigest, theirs: &DatabaseDigest) -> Vec<DigestTable> {
+    if ours.num_blocks != theirs.num_blocks {
+        return Vec::new();
+    }
+
+    let mut tables = Vec::new();
+    if ours.deposit_requests != theirs.deposit_requests {
+        tables.push(DigestTable::DepositRequests);
+    }
+    if ours.deposit_decisions != theirs.deposit_decisions {
+        tables.push(DigestTable::DepositDecisions);
+    }
+    if ours.dkg_shares != theirs.dkg_shares {
+        tables.push(DigestTable::DkgShares);
+    }
+    tables
+}
+
+/// Record the given divergences in the signer's metrics.
+pub fn record_divergences(tables: &[DigestTable]) {
+    for table in tables {
+        let table: &'static str = table.into();
+        metrics::counter!(Metrics::DatabaseDigestDivergencesTotal, "table" => table).increment(1);
+    }
+}
+
+fn deposit_request_leaf(request: &model::DepositRequest) -> [u8; 32] {
+    Sha256::new_with_prefix("DEPOSIT_REQUEST")
+        .chain_update(request.txid.into_bytes())
+        .chain_update(request.output_index.to_le_bytes())
+        .chain_update(request.amount.to_le_bytes())
+        .chain_update(request.max_fee.to_le_bytes())
+        .chain_update(request.lock_time.to_le_bytes())
+        .chain_update(request.signers_public_key.serialize())
+        .chain_update(&request.spend_script)
+        .chain_update(&request.reclaim_script)
+        .finalize()
+        .into()
+}
+
+fn deposit_decision_leaf(decision: &model::DepositSigner) -> [u8; 32] {
+    Sha256::new_with_prefix("DEPOSIT_DECISION")
+        .chain_update(decision.txid.into_bytes())
+        .chain_update(decision.output_index.to_le_bytes())
+        .chain_update(decision.signer_pub_key.serialize())
+        .chain_update([u8::from(decision.can_accept), u8::from(decision.can_sign)])
+        .finalize()
+        .into()
+}
+
+/// The leaf for the DKG shares metadata. The encrypted private shares
+/// differ between signers, so they are not part of the digest.
+fn dkg_shares_leaf(count: u32, latest: Option<&model::EncryptedDkgShares>) -> [u8; 32] {
+    let mut hasher = Sha256::new_with_prefix("DKG_SHARES").chain_update(count.to_le_bytes());
+    if let Some(shares) = latest {
+        hasher.update(shares.aggregate_key.serialize());
+        hasher.update(shares.signature_share_threshold.to_le_bytes());
+        hasher.update(shares.started_at_bitcoin_block_hash.into_bytes());
+        for public_key in shares.signer_set_public_keys.iter() {
+            hasher.update(public_key.serialize());
+        }
+    }
+    hasher.finalize().into()
+}
+
+/// Compute the root of a Merkle tree over the given leaves.
+///
+/// The leaves are sorted first, and the last node of a level is paired
+/// with itself when the level has an odd number of nodes. The root of an
+/// empty tree is all zeros.
+fn merkle_root(mut leaves: Vec<[u8; 32]>) -> [u8; 32] {
+    leaves.sort();
+
+    let mut level = leaves;
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                let left = pair[0];
+                let right = pair.get(1).copied().unwrap_or(left);
+                Sha256::new()
+                    .chain_update(left)
+                    .chain_update(right)
+                    .finalize()
+                    .into()
+            })
+            .collect();
+    }
+
+    level.pop().unwrap_or([0; 32])
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+    use rand::SeedableRng as _;
+
+    use super::*;
+    use crate::storage::DbWrite as _;
+    use crate::storage::memory::Store;
+
+    #[test]
+    fn merkle_root_does_not_depend_on_leaf_order() {
+        let leaves: Vec<[u8; 32]> = (0..5u8).map(|i| [i; 32]).collect();
+        let mut reversed = leaves.clone();
+        reversed.reverse();
+
+        assert_eq!(merkle_root(leaves.clone()), merkle_root(reversed));
+        assert_ne!(merkle_root(leaves[..4].to_vec()), merkle_root(leaves));
+        assert_eq!(merkle_root(Vec::new()), [0; 32]);
+    }
+
+    #[tokio::test]
+    async fn digests_detect_missing_decisions() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(46);
+        let db1 = Store::new_shared();
+        let db2 = Store::new_shared();
+
+        let block: model::BitcoinBlock = fake::Faker.fake_with_rng(&mut rng);
+        let request: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
+        let tx_ref = model::BitcoinTxRef {
+            txid: request.txid,
+            block_hash: block.block_hash,
+        };
+        let mut decision: model::DepositSigner = fake::Faker.fake_with_rng(&mut rng);
+        decision.txid = request.txid;
+        decision.output_index = request.output_index;
+
+        for db in [&db1, &db2] {
+            db.write_bitcoin_block(&block).await.unwrap();
+            db.write_bitcoin_transaction(&tx_ref).await.unwrap();
+            db.write_deposit_request(&request).await.unwrap();
+        }
+        db1.write_deposit_signer_decision(&decision).await.unwrap();
+
+        let chain_tip = block.block_hash;
+        let digest1 = compute_database_digest(&db1, &chain_tip, DIGEST_BLOCK_RANGE)
+            .await
+            .unwrap();
+        let digest2 = compute_database_digest(&db2, &chain_tip, DIGEST_BLOCK_RANGE)
+            .await
+            .unwrap();
+
+        let tables = diverging_tables(&digest1, &digest2);
+        assert_eq!(tables, vec![DigestTable::DepositDecisions]);
+
+        db2.write_deposit_signer_decision(&decision).await.unwrap();
+        let digest2 = compute_database_digest(&db2, &chain_tip, DIGEST_BLOCK_RANGE)
+            .await
+            .unwrap();
+        assert!(diverging_tables(&digest1, &digest2).is_empty());
+    }
+}
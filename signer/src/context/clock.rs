@@ -0,0 +1,154 @@
+//! The source of time for the signer's event loops.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time::Instant;
+
+/// The error returned when a future passed to [`Clock::timeout`] did not
+/// complete in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("deadline has elapsed")]
+pub struct Elapsed;
+
+/// The clock used by the signer's event loops for delays and timeouts.
+///
+/// The default clock follows the tokio runtime's clock. A manual clock
+/// only moves forward when [`Clock::advance`] is called, which allows
+/// tests to step through processing delays and signing round timeouts
+/// without waiting on real time.
+#[derive(Debug, Clone, Default)]
+pub struct Clock {
+    manual: Option<Arc<ManualTime>>,
+}
+
+/// The state backing a manual clock.
+#[derive(Debug)]
+struct ManualTime {
+    /// The instant that the clock was created.
+    start: Instant,
+    /// How much time has been advanced since the clock was created.
+    elapsed: watch::Sender<Duration>,
+}
+
+impl Clock {
+    /// Create a clock that follows the tokio runtime's clock.
+    pub fn system() -> Self {
+        Self::default()
+    }
+
+    /// Create a clock that only moves forward when [`Clock::advance`] is
+    /// called.
+    pub fn manual() -> Self {
+        let (elapsed, _) = watch::channel(Duration::ZERO);
+        let manual = ManualTime { start: Instant::now(), elapsed };
+        Self { manual: Some(Arc::new(manual)) }
+    }
+
+    /// Whether this clock only moves forward when [`Clock::advance`] is
+    /// called.
+    pub fn is_manual(&self) -> bool {
+        self.manual.is_some()
+    }
+
+    /// Return the current instant according to this clock.
+    pub fn now(&self) -> Instant {
+        match &self.manual {
+            Some(manual) => manual.start + *manual.elapsed.borrow(),
+            None => Instant::now(),
+        }
+    }
+
+    /// Move a manual clock forward by the given duration, waking any
+    /// sleeps whose deadline has passed. This has no effect on a system
+    /// clock.
+    pub fn advance(&self, duration: Duration) {
+        if let Some(manual) = &self.manual {
+            manual.elapsed.send_modify(|elapsed| *elapsed += duration);
+        }
+    }
+
+    /// Wait until the given duration has elapsed according to this clock.
+    pub async fn sleep(&self, duration: Duration) {
+        let Some(manual) = &self.manual else {
+            return tokio::time::sleep(duration).await;
+        };
+
+        let mut receiver = manual.elapsed.subscribe();
+        let deadline = *receiver.borrow() + duration;
+        // This only returns an error if the sender has been dropped, and
+        // we hold a reference to the sender for as long as we wait.
+        let _ = receiver.wait_for(|elapsed| *elapsed >= deadline).await;
+    }
+
+    /// Require the given future to complete before the duration has
+    /// elapsed according to this clock.
+    pub async fn timeout<F>(&self, duration: Duration, future: F) -> Result<F::Output, Elapsed>
+    where
+        F: Future,
+    {
+        if self.manual.is_none() {
+            return tokio::time::timeout(duration, future)
+                .await
+                .map_err(|_| Elapsed);
+        }
+
+        tokio::select! {
+            output = future => Ok(output),
+            _ = self.sleep(duration) => Err(Elapsed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn manual_clock_sleeps_until_advanced() {
+        let clock = Clock::manual();
+        let start = clock.now();
+
+        let sleeper = clock.clone();
+        let handle = tokio::spawn(async move { sleeper.sleep(Duration::from_secs(30)).await });
+
+        tokio::task::yield_now().await;
+        clock.advance(Duration::from_secs(10));
+        tokio::task::yield_now().await;
+        assert!(!handle.is_finished());
+
+        clock.advance(Duration::from_secs(20));
+        tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(clock.now() - start, Duration::from_secs(30));
+    }
+
+    #[tokio::test]
+    async fn manual_clock_timeout_elapses_when_advanced() {
+        let clock = Clock::manual();
+
+        let timer = clock.clone();
+        let handle = tokio::spawn(async move {
+            timer
+                .timeout(Duration::from_secs(5), std::future::pending::<()>())
+                .await
+        });
+
+        tokio::task::yield_now().await;
+        clock.advance(Duration::from_secs(5));
+
+        let result = tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(result, Err(Elapsed));
+
+        let result = clock.timeout(Duration::from_secs(5), async { 4 }).await;
+        assert_eq!(result, Ok(4));
+    }
+}
@@ -28,6 +28,10 @@ pub enum SignerCommand {
     P2PPublish(Box<crate::network::Msg>),
     /// Signal to shut down the application
     Shutdown,
+    /// Signals to the block observer to process the bitcoin blocks up to
+    /// the given block hash, which is the chain tip of bitcoin-core,
+    /// as if it had just been observed.
+    ResyncBitcoinBlocks(bitcoin::BlockHash),
 }
 
 /// Events that can be received on the signalling channel.
@@ -43,6 +47,18 @@ pub enum SignerEvent {
     TxSigner(TxSignerEvent),
     /// Transaction coordinator events
     TxCoordinator(TxCoordinatorEvent),
+    /// The signers' UTXO in the database did not match the UTXO set of
+    /// bitcoin-core during reconciliation.
+    SignerUtxoMismatch(crate::reconciliation::UtxoReconciliation),
+    /// The bitcoin chain tip of the signer was still unhealthy after the
+    /// chain tip watchdog asked the block observer to resync.
+    ChainTipUnhealthy(crate::watchdog::ChainTipHealth),
+    /// An event loop ran into a critical error.
+    CriticalError(crate::supervisor::ErrorReport),
+    /// The emergency recovery of the signers' funds moved along.
+    EmergencyRecovery(crate::emergency_recovery::EmergencyRecoveryEvent),
+    /// The broadcaster learned the fate of a queued sweep transaction.
+    Broadcaster(crate::broadcaster::BroadcasterEvent),
 }
 
 /// Events that can be triggered from the P2P network.
@@ -78,6 +94,14 @@ pub enum RequestDeciderEvent {
     /// Event which occurs when the transaction signer has started its event
     /// loop.
     EventLoopStarted,
+    /// The database digest received from another signer did not match
+    /// the digest of this signer's database.
+    DatabaseDigestDiverged {
+        /// The public key of the signer that sent the digest.
+        sender: crate::keys::PublicKey,
+        /// The tables whose digests did not match.
+        tables: Vec<crate::consistency::DigestTable>,
+    },
 }
 
 /// Events that can be triggered from the transaction signer.
@@ -97,9 +121,35 @@ pub enum TxCoordinatorEvent {
     /// Event which occurs when the transaction coordinator has sent a message
     /// to the P2P network.
     MessageGenerated(Box<crate::network::Msg>),
+    /// The coordinator has planned the work for its tenure, and is about
+    /// to start on it.
+    TenurePlanned(crate::storage::model::TenurePlan),
     /// The coordinator is finished processing requests for the bitcoin
     /// block.
     TenureCompleted,
+    /// The coordinator ran DKG successfully. Holds the new aggregate key.
+    DkgCompleted(crate::keys::PublicKey),
+    /// The coordinator failed to run DKG. Holds the error message.
+    DkgFailed(String),
+    /// Bitcoin-core accepted a sweep transaction broadcast by the
+    /// coordinator, or by the broadcaster on its behalf.
+    SweepBroadcast(bitcoin::Txid),
+    /// The coordinator added signed sweep transactions to the queue of
+    /// the broadcaster.
+    SweepsQueued,
+    /// The stacks node accepted a transaction submitted by the
+    /// coordinator.
+    StacksSubmissionAccepted {
+        /// The ID of the accepted transaction.
+        txid: crate::storage::model::StacksTxId,
+        /// The kind of contract call in the transaction, like
+        /// `complete-deposit`.
+        kind: &'static str,
+    },
+    /// A transaction submitted by the coordinator was rejected by the
+    /// stacks node, or could not be submitted at all. Holds the error
+    /// message.
+    StacksSubmissionFailed(String),
 }
 
 impl From<SignerCommand> for SignerSignal {
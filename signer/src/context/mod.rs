@@ -1,5 +1,6 @@
 //! Context module for the signer binary.
 
+mod clock;
 mod messaging;
 mod signer_context;
 mod signer_state;
@@ -13,11 +14,13 @@ use crate::bitcoin::BitcoinInteract;
 use crate::config::Settings;
 use crate::emily_client::EmilyInteract;
 use crate::error::Error;
+use crate::metrics::Metrics;
 use crate::stacks::api::StacksInteract;
 use crate::storage::DbRead;
 use crate::storage::DbWrite;
 use crate::storage::Transactable;
 
+pub use clock::*;
 pub use messaging::*;
 pub use signer_context::SignerContext;
 pub use signer_state::*;
@@ -29,6 +32,8 @@ pub trait Context: Clone + Sync + Send {
     fn config(&self) -> &Settings;
     /// Get the current state for the signer.
     fn state(&self) -> &SignerState;
+    /// Get the clock used for delays and timeouts in the event loops.
+    fn clock(&self) -> &Clock;
     /// Subscribe to the application signalling channel, returning a receiver
     /// which can be used to listen for events.
     fn get_signal_receiver(&self) -> tokio::sync::broadcast::Receiver<SignerSignal>;
@@ -36,6 +41,11 @@ pub trait Context: Clone + Sync + Send {
     fn get_signal_sender(&self) -> tokio::sync::broadcast::Sender<SignerSignal>;
     /// Send a signal to the application signalling channel.
     fn signal(&self, signal: SignerSignal) -> Result<(), Error>;
+    /// Subscribe to the number of [`SignerEvent::BitcoinBlockObserved`]
+    /// signals that have been sent. Unlike the signalling channel, which
+    /// drops signals for receivers that lag behind, this always holds the
+    /// latest count.
+    fn get_block_observed_receiver(&self) -> tokio::sync::watch::Receiver<u64>;
     /// Returns a handle to the application's termination signal.
     fn get_termination_handle(&self) -> TerminationHandle;
     /// Get a read-only handle to the signer storage.
@@ -62,12 +72,34 @@ pub trait Context: Clone + Sync + Send {
     /// later return `Some(_)`. But if [`StreamExt::next`] yields `None`
     /// three times then the stream is "fused" and will return `None`
     /// forever after.
+    ///
+    /// The returned stream is bounded, and signals are only pulled from
+    /// the signalling channel as the subscriber makes room for them. A
+    /// subscriber that falls too far behind misses signals, which is
+    /// recorded in the [`Metrics::SignalsDroppedTotal`] metric under the
+    /// name of the current tracing span. Two signals are never lost this
+    /// way: the shutdown signal, which comes from the termination handle,
+    /// and the [`SignerEvent::BitcoinBlockObserved`] signal, which is sent
+    /// again once the subscriber catches up if one was dropped.
     fn as_signal_stream<F>(&self, predicate: F) -> ReceiverStream<SignerSignal>
     where
         F: Fn(&SignerSignal) -> bool + Send + Sync + 'static,
     {
         let (sender, receiver) = tokio::sync::mpsc::channel(SIGNER_CHANNEL_CAPACITY);
 
+        let subscriber = tracing::Span::current()
+            .metadata()
+            .map_or("unknown", |metadata| metadata.name());
+        let block_observed = SignerSignal::Event(SignerEvent::BitcoinBlockObserved);
+        let wants_block_observed = predicate(&block_observed);
+        // We read the count of blocks observed before subscribing to the
+        // signalling channel, so that we err on the side of sending a
+        // block observed signal again.
+        let block_observed_receiver = self.get_block_observed_receiver();
+        let blocks_observed_at_start = *block_observed_receiver.borrow();
+        let mut blocks_observed_seen = 0;
+        let mut lagged = false;
+
         let mut watch_receiver = self.get_termination_handle();
         let mut signal_stream = self.get_signal_receiver();
 
@@ -85,22 +117,53 @@ pub trait Context: Clone + Sync + Send {
                     }
                     item = signal_stream.recv() => {
                         match item {
-                            Ok(signal) if predicate(&signal) => {
+                            Ok(signal) => {
+                                if signal == block_observed {
+                                    blocks_observed_seen += 1;
+                                }
                                 // See comment above, we can bail.
-                                if sender.send(signal).await.is_err() {
+                                if predicate(&signal) && sender.send(signal).await.is_err() {
                                     break;
                                 }
                             }
-                            Ok(_) => continue,
                             Err(RecvError::Closed) => {
                                 tracing::warn!("internal signal stream closed");
                                 break;
                             }
-                            Err(error @ RecvError::Lagged(_)) => {
-                                tracing::warn!(%error, "internal signal stream lagging");
-                                continue
+                            Err(error @ RecvError::Lagged(dropped)) => {
+                                tracing::warn!(
+                                    %error,
+                                    subscriber,
+                                    "internal signal stream lagging"
+                                );
+                                metrics::counter!(
+                                    Metrics::SignalsDroppedTotal,
+                                    "subscriber" => subscriber
+                                )
+                                .increment(dropped);
+                                lagged = true;
                             }
                         }
+
+                        // Once we have caught up after lagging, we can
+                        // tell whether a block observed signal was among
+                        // the dropped ones, and send it again if so.
+                        if !lagged || !signal_stream.is_empty() {
+                            continue;
+                        }
+                        lagged = false;
+                        let blocks_observed = *block_observed_receiver.borrow();
+                        if blocks_observed_at_start + blocks_observed_seen >= blocks_observed {
+                            continue;
+                        }
+                        blocks_observed_seen = blocks_observed - blocks_observed_at_start;
+                        if !wants_block_observed {
+                            continue;
+                        }
+                        tracing::info!(subscriber, "resending a dropped block observed signal");
+                        if sender.send(block_observed.clone()).await.is_err() {
+                            break;
+                        }
                     }
                 }
             }
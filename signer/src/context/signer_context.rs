@@ -12,7 +12,7 @@ use crate::{
     storage::{DbRead, DbWrite, Transactable},
 };
 
-use super::{Context, SignerSignal, SignerState, TerminationHandle};
+use super::{Clock, Context, SignerEvent, SignerSignal, SignerState, TerminationHandle};
 
 /// Signer context which is passed to different components within the
 /// signer binary.
@@ -23,8 +23,13 @@ pub struct SignerContext<S, BC, ST, EM> {
     // for the duration of the program and is used both to send messages
     // and to hand out new receivers.
     signal_tx: Sender<SignerSignal>,
+    /// The number of [`SignerEvent::BitcoinBlockObserved`] signals that
+    /// have been sent on the signalling channel.
+    block_observed_tx: tokio::sync::watch::Sender<u64>,
     /// The internal state of the signer.
     state: Arc<SignerState>,
+    /// The clock used for delays and timeouts in the event loops.
+    clock: Clock,
     /// Handle to the app termination channel. This keeps the channel alive
     /// for the duration of the program and is used to provide new senders
     /// and receivers for a [`TerminationHandle`].
@@ -80,20 +85,25 @@ where
         stacks_client: ST,
         emily_client: EM,
     ) -> Self {
-        // TODO: Decide on the channel capacity and how we should handle slow consumers.
-        // NOTE: Ideally consumers which require processing time should pull the relevent
-        // messages into a local VecDequeue and process them in their own time.
+        // Slow consumers miss signals once they are more than the channel
+        // capacity behind; see `Context::as_signal_stream` for how the
+        // critical ones are still delivered.
         let (signal_tx, _) = tokio::sync::broadcast::channel(SIGNER_CHANNEL_CAPACITY);
+        let (block_observed_tx, _) = tokio::sync::watch::channel(0);
         let (term_tx, _) = tokio::sync::watch::channel(false);
         let state = SignerState::default();
         if let Some(height) = config.signer.sbtc_bitcoin_start_height {
             state.set_sbtc_bitcoin_start_height(height);
         }
+        state.set_deposits_paused(config.signer.deposits_paused);
+        state.set_withdrawals_paused(config.signer.withdrawals_paused);
 
         Self {
             config,
             state: Arc::new(state),
+            clock: Clock::system(),
             signal_tx,
+            block_observed_tx,
             term_tx,
             storage: db,
             bitcoin_client,
@@ -118,6 +128,10 @@ where
         &self.state
     }
 
+    fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
     fn get_signal_receiver(&self) -> tokio::sync::broadcast::Receiver<SignerSignal> {
         self.signal_tx.subscribe()
     }
@@ -128,16 +142,26 @@ where
 
     /// Send a signal to the application signalling channel.
     fn signal(&self, signal: SignerSignal) -> Result<(), Error> {
-        self.signal_tx
-            .send(signal)
-            .map_err(|_| {
-                // This realistically shouldn't ever happen
-                tracing::warn!("failed to send signal to the application, no receivers present.");
-                // Send a shutdown signal, just in-case.
-                self.get_termination_handle().signal_shutdown();
-                Error::SignerShutdown
-            })
-            .map(|_| ())
+        let is_block_observed = signal == SignerSignal::Event(SignerEvent::BitcoinBlockObserved);
+        self.signal_tx.send(signal).map_err(|_| {
+            // This realistically shouldn't ever happen
+            tracing::warn!("failed to send signal to the application, no receivers present.");
+            // Send a shutdown signal, just in-case.
+            self.get_termination_handle().signal_shutdown();
+            Error::SignerShutdown
+        })?;
+
+        // We only count the signal after it has been sent, so that
+        // subscribers never count a signal that they have yet to receive
+        // as dropped.
+        if is_block_observed {
+            self.block_observed_tx.send_modify(|count| *count += 1);
+        }
+        Ok(())
+    }
+
+    fn get_block_observed_receiver(&self) -> tokio::sync::watch::Receiver<u64> {
+        self.block_observed_tx.subscribe()
     }
 
     fn get_termination_handle(&self) -> TerminationHandle {
@@ -174,6 +198,11 @@ impl<Storage, Bitcoin, Stacks, Emily> SignerContext<Storage, Bitcoin, Stacks, Em
         &mut self.config
     }
 
+    /// Replace the clock used for delays and timeouts in the event loops.
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = clock;
+    }
+
     /// Resets the termination signal for this context.
     ///
     /// This sets the underlying termination state to `false`, allowing
@@ -264,4 +293,59 @@ mod tests {
         // Ensure that the signal was received.
         assert_eq!(recv_count.load(std::sync::atomic::Ordering::Relaxed), 1);
     }
+
+    /// This test checks that a subscriber that lags so far behind that it
+    /// misses a block observed signal still gets one once it catches up.
+    #[tokio::test]
+    async fn dropped_block_observed_signals_are_resent() {
+        use futures::StreamExt as _;
+
+        use crate::SIGNER_CHANNEL_CAPACITY;
+        use crate::context::TxCoordinatorEvent;
+
+        let context = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .build();
+
+        let mut stream = context.as_signal_stream(|_| true);
+        let filler = || SignerSignal::from(TxCoordinatorEvent::TenureCompleted);
+
+        // Fill up the stream so that the subscriber stops pulling signals
+        // from the signalling channel.
+        for _ in 0..SIGNER_CHANNEL_CAPACITY + 10 {
+            context.signal(filler()).unwrap();
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        // The block observed signal is followed by enough signals to push
+        // it out of the signalling channel before the subscriber gets to
+        // it.
+        context
+            .signal(SignerEvent::BitcoinBlockObserved.into())
+            .unwrap();
+        for _ in 0..SIGNER_CHANNEL_CAPACITY * 2 {
+            context.signal(filler()).unwrap();
+        }
+
+        let mut received = 0;
+        let block_observed = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while let Some(signal) = stream.next().await {
+                if signal == SignerSignal::Event(SignerEvent::BitcoinBlockObserved) {
+                    return signal;
+                }
+                received += 1;
+            }
+            panic!("signal stream ended");
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            block_observed,
+            SignerSignal::Event(SignerEvent::BitcoinBlockObserved)
+        );
+        // Some of the other signals were dropped along the way.
+        assert!(received < SIGNER_CHANNEL_CAPACITY * 3 + 10);
+    }
 }
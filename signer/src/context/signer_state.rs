@@ -1,19 +1,39 @@
 //! Module for signer state
 
 use std::collections::BTreeSet;
+use std::sync::Arc;
 use std::sync::{
     RwLock,
     atomic::{AtomicBool, AtomicU64, Ordering},
 };
+use std::time::Duration;
 
 use bitcoin::Amount;
+use bitcoin::BlockHash;
+use bitcoin::OutPoint;
+use bitcoin::Txid;
+use hashbrown::HashMap;
 use hashbrown::HashSet;
 use libp2p::PeerId;
+use sbtc::deposits::CreateDepositRequest;
+use sbtc::deposits::ParsedDepositScripts;
+use tokio::time::Instant;
 
+use crate::bitcoin::rpc::BitcoinTxInfo;
+use crate::bitcoin::units::FeeRate;
+use crate::error::Error;
 use crate::keys::PublicKey;
+use crate::keys::PublicKeyXOnly;
+use crate::message::LimitOverrideVote;
+use crate::network::peers::PeerTable;
 use crate::stacks::api::SignerSetInfo;
+use crate::storage::model::BitcoinBlockHash;
 use crate::storage::model::BitcoinBlockHeight;
 use crate::storage::model::BitcoinBlockRef;
+use crate::storage::model::DepositRequest;
+use crate::storage::model::QualifiedRequestId;
+use crate::transcript::TranscriptRecorder;
+use crate::watchdog::ChainTipHealth;
 
 /// A struct for holding internal signer state. This struct is served by
 /// the [`SignerContext`] and can be used to cache global state instead of
@@ -29,6 +49,53 @@ pub struct SignerState {
     // The current bitcoin chain tip. This gets updated at the end of the
     // block observer's duties when it observes a new bitcoin block.
     bitcoin_chain_tip: RwLock<Option<BitcoinBlockRef>>,
+    // Whether the operator has paused the coordinator duties of this
+    // signer.
+    coordinator_paused: AtomicBool,
+    // Whether the operator has paused the sweeping of deposit requests.
+    deposits_paused: AtomicBool,
+    // Whether the operator has paused the servicing of withdrawal
+    // requests.
+    withdrawals_paused: AtomicBool,
+    // The bitcoin chain tip for which another signer also acted as
+    // coordinator and won the tie-break against this signer.
+    coordinator_tie_break_lost: RwLock<Option<BitcoinBlockHash>>,
+    // The lowest fee rate that the coordinator uses for its bitcoin
+    // transactions, set after bitcoin-core rejected one of them for
+    // paying too little.
+    min_fee_rate: RwLock<Option<FeeRate>>,
+    // Operator overrides for this signer's decisions on deposit and
+    // withdrawal requests. A value of `true` means that the request is
+    // always accepted, while `false` means that it is always rejected.
+    deposit_decision_overrides: RwLock<HashMap<OutPoint, bool>>,
+    withdrawal_decision_overrides: RwLock<HashMap<QualifiedRequestId, bool>>,
+    // The deposit and reclaim scripts parsed from deposit requests, keyed
+    // by the outpoint of the deposit. Deposit requests are validated
+    // again on each new bitcoin block, so this saves us from parsing the
+    // same scripts over and over. It is cleared on bitcoin reorgs.
+    deposit_scripts: RwLock<HashMap<OutPoint, Arc<ParsedDepositScripts>>>,
+    // The prevouts spent by the bitcoin transactions that the coordinator
+    // is currently running signing rounds for, mapped to the ID of the
+    // transaction spending them.
+    prevout_locks: RwLock<HashMap<OutPoint, Txid>>,
+    // Statistics about the peers of this signer in the p2p network.
+    peer_table: PeerTable,
+    // Transcripts of the signing rounds of the most recent tenures.
+    transcripts: TranscriptRecorder,
+    // The health of the bitcoin chain tip at the last check of the chain
+    // tip watchdog.
+    chain_tip_health: RwLock<ChainTipHealth>,
+    // The last sBTC limits fetched from Emily, and when they were
+    // fetched. These are used when a later fetch fails.
+    cached_emily_limits: RwLock<Option<(SbtcLimits, Instant)>>,
+    // Transactions fetched from bitcoin-core, keyed by their ID and the
+    // hash of the block that they were fetched from, and when they were
+    // fetched.
+    cached_tx_infos: RwLock<HashMap<(Txid, BlockHash), (BitcoinTxInfo, Instant)>>,
+    // The latest unexpired vote for a limit override from each member of
+    // the signer set, and the override that the signer set agreed on.
+    limit_override_votes: RwLock<HashMap<PublicKey, LimitOverrideVote>>,
+    active_limit_override: RwLock<Option<LimitOverrideVote>>,
 }
 
 impl SignerState {
@@ -38,6 +105,27 @@ impl SignerState {
         &self.current_signer_set
     }
 
+    /// Get the statistics about the peers of this signer in the p2p
+    /// network.
+    pub fn peer_table(&self) -> &PeerTable {
+        &self.peer_table
+    }
+
+    /// Return the number of members of the current signer set that this
+    /// signer can reach in the p2p network at the given instant, see
+    /// [`PeerTable::num_reachable`].
+    pub fn num_reachable_signers(&self, now: tokio::time::Instant) -> usize {
+        let signer_set = &self.current_signer_set;
+        self.peer_table
+            .num_reachable(|peer_id| signer_set.is_allowed_peer(peer_id), now)
+    }
+
+    /// Get the transcripts of the signing rounds of the most recent
+    /// tenures.
+    pub fn transcripts(&self) -> &TranscriptRecorder {
+        &self.transcripts
+    }
+
     /// Set the set of signers that this signer is allow us to communicate
     /// with.
     #[cfg(any(test, feature = "testing"))]
@@ -81,6 +169,23 @@ impl SignerState {
             .replace(chain_tip);
     }
 
+    /// Get the health of the bitcoin chain tip at the last check of the
+    /// chain tip watchdog.
+    pub fn chain_tip_health(&self) -> ChainTipHealth {
+        *self
+            .chain_tip_health
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+    }
+
+    /// Set the health of the bitcoin chain tip.
+    pub fn set_chain_tip_health(&self, health: ChainTipHealth) {
+        *self
+            .chain_tip_health
+            .write()
+            .expect("BUG: Failed to acquire write lock") = health;
+    }
+
     /// Get the current sBTC limits.
     pub fn get_current_limits(&self) -> SbtcLimits {
         // We should never fail to acquire a lock from the RwLock so that it panics.
@@ -100,6 +205,121 @@ impl SignerState {
         *limits = new_limits;
     }
 
+    /// Cache the sBTC limits fetched from Emily at the given instant.
+    pub fn set_cached_emily_limits(&self, limits: SbtcLimits, fetched_at: Instant) {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.cached_emily_limits
+            .write()
+            .expect("BUG: Failed to acquire write lock")
+            .replace((limits, fetched_at));
+    }
+
+    /// Get the sBTC limits that were last fetched from Emily, if they were
+    /// fetched less than `ttl` before `now`.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn cached_emily_limits(&self, now: Instant, ttl: Duration) -> Option<SbtcLimits> {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.cached_emily_limits
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+            .as_ref()
+            .filter(|(_, fetched_at)| now.saturating_duration_since(*fetched_at) < ttl)
+            .map(|(limits, _)| limits.clone())
+    }
+
+    /// Cache the transaction with the given ID, fetched from the block
+    /// with the given hash at the given instant. Cached transactions that
+    /// were fetched `ttl` or more before `fetched_at` are evicted.
+    pub fn set_cached_tx_info(
+        &self,
+        txid: Txid,
+        block_hash: BlockHash,
+        tx_info: BitcoinTxInfo,
+        fetched_at: Instant,
+        ttl: Duration,
+    ) {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        let mut tx_infos = self
+            .cached_tx_infos
+            .write()
+            .expect("BUG: Failed to acquire write lock");
+        tx_infos.retain(|_, (_, cached_at)| fetched_at.saturating_duration_since(*cached_at) < ttl);
+        if !ttl.is_zero() {
+            tx_infos.insert((txid, block_hash), (tx_info, fetched_at));
+        }
+    }
+
+    /// Get the transaction with the given ID that was fetched from the
+    /// block with the given hash, if it was fetched less than `ttl` before
+    /// `now`.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn cached_tx_info(
+        &self,
+        txid: &Txid,
+        block_hash: &BlockHash,
+        now: Instant,
+        ttl: Duration,
+    ) -> Option<BitcoinTxInfo> {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.cached_tx_infos
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+            .get(&(*txid, *block_hash))
+            .filter(|(_, fetched_at)| now.saturating_duration_since(*fetched_at) < ttl)
+            .map(|(tx_info, _)| tx_info.clone())
+    }
+
+    /// Record the vote of the given signer for a limit override, replacing
+    /// any earlier vote of theirs, and return the number of signers that
+    /// voted for the same override.
+    pub fn record_limit_override_vote(&self, signer: PublicKey, vote: LimitOverrideVote) -> usize {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        let mut votes = self
+            .limit_override_votes
+            .write()
+            .expect("BUG: Failed to acquire write lock");
+        votes.insert(signer, vote);
+        votes.values().filter(|other| **other == vote).count()
+    }
+
+    /// Get the limit override that the signer set agreed on, if any.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn active_limit_override(&self) -> Option<LimitOverrideVote> {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        *self
+            .active_limit_override
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+    }
+
+    /// Set the limit override that the signer set agreed on.
+    pub fn set_active_limit_override(&self, vote: LimitOverrideVote) {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.active_limit_override
+            .write()
+            .expect("BUG: Failed to acquire write lock")
+            .replace(vote);
+    }
+
+    /// Drop the limit override votes, and the active limit override, that
+    /// expire at or before the given bitcoin block height.
+    pub fn prune_limit_override_votes(&self, chain_tip_height: BitcoinBlockHeight) {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.limit_override_votes
+            .write()
+            .expect("BUG: Failed to acquire write lock")
+            .retain(|_, vote| vote.expires_at_height > chain_tip_height);
+
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        let mut active = self
+            .active_limit_override
+            .write()
+            .expect("BUG: Failed to acquire write lock");
+        if active.is_some_and(|vote| vote.expires_at_height <= chain_tip_height) {
+            *active = None;
+        }
+    }
+
     /// Returns true if sbtc smart contracts are deployed
     pub fn sbtc_contracts_deployed(&self) -> bool {
         self.sbtc_contracts_deployed.load(Ordering::SeqCst)
@@ -127,6 +347,293 @@ impl SignerState {
     pub fn is_sbtc_bitcoin_start_height_set(&self) -> bool {
         self.is_sbtc_bitcoin_start_height_set.load(Ordering::SeqCst)
     }
+
+    /// Return whether coordinator duties have been paused by the operator.
+    pub fn is_coordinator_paused(&self) -> bool {
+        self.coordinator_paused.load(Ordering::SeqCst)
+    }
+
+    /// Pause or resume coordinator duties, returning whether they were
+    /// paused before the call.
+    pub fn set_coordinator_paused(&self, paused: bool) -> bool {
+        self.coordinator_paused.swap(paused, Ordering::SeqCst)
+    }
+
+    /// Return whether the sweeping of deposit requests has been paused by
+    /// the operator.
+    pub fn is_deposits_paused(&self) -> bool {
+        self.deposits_paused.load(Ordering::SeqCst)
+    }
+
+    /// Pause or resume the sweeping of deposit requests, returning whether
+    /// they were paused before the call.
+    pub fn set_deposits_paused(&self, paused: bool) -> bool {
+        self.deposits_paused.swap(paused, Ordering::SeqCst)
+    }
+
+    /// Return whether the servicing of withdrawal requests has been paused
+    /// by the operator.
+    pub fn is_withdrawals_paused(&self) -> bool {
+        self.withdrawals_paused.load(Ordering::SeqCst)
+    }
+
+    /// Pause or resume the servicing of withdrawal requests, returning
+    /// whether they were paused before the call.
+    pub fn set_withdrawals_paused(&self, paused: bool) -> bool {
+        self.withdrawals_paused.swap(paused, Ordering::SeqCst)
+    }
+
+    /// Return the lowest fee rate that the coordinator should use for its
+    /// bitcoin transactions, if bitcoin-core rejected one of them for
+    /// paying too little.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn min_fee_rate(&self) -> Option<FeeRate> {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        *self
+            .min_fee_rate
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+    }
+
+    /// Set or clear the lowest fee rate that the coordinator should use
+    /// for its bitcoin transactions.
+    pub fn set_min_fee_rate(&self, fee_rate: Option<FeeRate>) {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        *self
+            .min_fee_rate
+            .write()
+            .expect("BUG: Failed to acquire write lock") = fee_rate;
+    }
+
+    /// Record that another signer acting as coordinator for the given
+    /// bitcoin chain tip won the tie-break against this signer.
+    pub fn set_coordinator_tie_break_lost(&self, chain_tip: BitcoinBlockHash) {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.coordinator_tie_break_lost
+            .write()
+            .expect("BUG: Failed to acquire write lock")
+            .replace(chain_tip);
+    }
+
+    /// Return whether this signer lost the coordinator tie-break for the
+    /// given bitcoin chain tip.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn lost_coordinator_tie_break(&self, chain_tip: &BitcoinBlockHash) -> bool {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.coordinator_tie_break_lost
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+            .as_ref()
+            == Some(chain_tip)
+    }
+
+    /// Return the operator override for the decision on the deposit
+    /// request with the given outpoint, if one has been set.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn deposit_decision_override(&self, outpoint: &OutPoint) -> Option<bool> {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.deposit_decision_overrides
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+            .get(outpoint)
+            .copied()
+    }
+
+    /// Return all of the operator overrides for decisions on deposit
+    /// requests.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn deposit_decision_overrides(&self) -> Vec<(OutPoint, bool)> {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.deposit_decision_overrides
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+            .iter()
+            .map(|(outpoint, can_accept)| (*outpoint, *can_accept))
+            .collect()
+    }
+
+    /// Set the operator override for the decision on the deposit request
+    /// with the given outpoint. Passing `None` removes any override.
+    pub fn set_deposit_decision_override(&self, outpoint: OutPoint, can_accept: Option<bool>) {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        let mut overrides = self
+            .deposit_decision_overrides
+            .write()
+            .expect("BUG: Failed to acquire write lock");
+        match can_accept {
+            Some(can_accept) => overrides.insert(outpoint, can_accept),
+            None => overrides.remove(&outpoint),
+        };
+    }
+
+    /// Return the operator override for the decision on the withdrawal
+    /// request with the given identifier, if one has been set.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn withdrawal_decision_override(&self, id: &QualifiedRequestId) -> Option<bool> {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.withdrawal_decision_overrides
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+            .get(id)
+            .copied()
+    }
+
+    /// Return all of the operator overrides for decisions on withdrawal
+    /// requests.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn withdrawal_decision_overrides(&self) -> Vec<(QualifiedRequestId, bool)> {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.withdrawal_decision_overrides
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+            .iter()
+            .map(|(id, accept)| (*id, *accept))
+            .collect()
+    }
+
+    /// Set the operator override for the decision on the withdrawal
+    /// request with the given identifier. Passing `None` removes any
+    /// override.
+    pub fn set_withdrawal_decision_override(&self, id: QualifiedRequestId, accept: Option<bool>) {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        let mut overrides = self
+            .withdrawal_decision_overrides
+            .write()
+            .expect("BUG: Failed to acquire write lock");
+        match accept {
+            Some(accept) => overrides.insert(id, accept),
+            None => overrides.remove(&id),
+        };
+    }
+
+    /// Return the parsed deposit and reclaim scripts of the given deposit
+    /// request, parsing and caching them if they have not been parsed
+    /// before.
+    ///
+    /// Scripts that fail to parse are not cached.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn deposit_scripts(
+        &self,
+        request: &CreateDepositRequest,
+    ) -> Result<Arc<ParsedDepositScripts>, sbtc::error::Error> {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        let cached = self
+            .deposit_scripts
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+            .get(&request.outpoint)
+            .filter(|scripts| scripts.matches(request))
+            .cloned();
+
+        if let Some(scripts) = cached {
+            return Ok(scripts);
+        }
+
+        let scripts = Arc::new(request.parse_scripts()?);
+        self.deposit_scripts
+            .write()
+            .expect("BUG: Failed to acquire write lock")
+            .insert(request.outpoint, scripts.clone());
+        Ok(scripts)
+    }
+
+    /// Return the parsed scripts of a deposit request that we have in
+    /// the database, using the cache in [`Self::deposit_scripts`].
+    ///
+    /// Returns `None` if the scripts fail to parse or if the parsed
+    /// inputs disagree with the values stored alongside the scripts.
+    pub fn stored_deposit_scripts(
+        &self,
+        request: &DepositRequest,
+    ) -> Option<Arc<ParsedDepositScripts>> {
+        let create_request = CreateDepositRequest {
+            outpoint: request.outpoint(),
+            deposit_script: request.spend_script.clone().into(),
+            reclaim_script: request.reclaim_script.clone().into(),
+        };
+        let scripts = self
+            .deposit_scripts(&create_request)
+            .inspect_err(|error| tracing::warn!(%error, "could not parse deposit scripts"))
+            .ok()?;
+
+        let signers_public_key = PublicKeyXOnly::from(scripts.deposit.signers_public_key);
+        let matches_stored = signers_public_key == request.signers_public_key
+            && scripts.deposit.max_fee == request.max_fee
+            && scripts.reclaim.lock_time() == request.lock_time;
+
+        if !matches_stored {
+            tracing::warn!(
+                outpoint = %request.outpoint(),
+                "parsed deposit scripts disagree with the stored deposit request"
+            );
+            return None;
+        }
+        Some(scripts)
+    }
+
+    /// Remove the parsed scripts of all deposit requests whose outpoints
+    /// do not satisfy the predicate.
+    pub fn retain_deposit_scripts<F>(&self, mut predicate: F)
+    where
+        F: FnMut(&OutPoint) -> bool,
+    {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.deposit_scripts
+            .write()
+            .expect("BUG: Failed to acquire write lock")
+            .retain(|outpoint, _| predicate(outpoint));
+    }
+
+    /// Remove the parsed scripts of all deposit requests.
+    pub fn clear_deposit_scripts(&self) {
+        self.retain_deposit_scripts(|_| false);
+    }
+
+    /// The number of deposit requests with parsed scripts in the cache.
+    pub fn num_cached_deposit_scripts(&self) -> usize {
+        self.deposit_scripts
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+            .len()
+    }
+
+    /// Lock the given prevouts for the signing rounds of the bitcoin
+    /// transaction with the given ID. Either all of the prevouts are
+    /// locked, or none of them are and an error is returned naming a
+    /// prevout that another transaction holds.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn lock_prevouts(&self, txid: Txid, prevouts: &[OutPoint]) -> Result<(), Error> {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        let mut locks = self
+            .prevout_locks
+            .write()
+            .expect("BUG: Failed to acquire write lock");
+
+        let conflict = prevouts.iter().find_map(|outpoint| {
+            locks
+                .get(outpoint)
+                .filter(|holder| **holder != txid)
+                .map(|holder| (*outpoint, *holder))
+        });
+        if let Some((outpoint, holder)) = conflict {
+            return Err(Error::PrevoutSpendConflict { outpoint, txid: holder });
+        }
+
+        for outpoint in prevouts {
+            locks.insert(*outpoint, txid);
+        }
+        Ok(())
+    }
+
+    /// Release the prevouts locked by the bitcoin transaction with the
+    /// given ID.
+    pub fn unlock_prevouts(&self, txid: &Txid) {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.prevout_locks
+            .write()
+            .expect("BUG: Failed to acquire write lock")
+            .retain(|_, holder| holder != txid);
+    }
 }
 
 impl Default for SignerState {
@@ -141,6 +648,22 @@ impl Default for SignerState {
             // The block hash here is often used as the parent block hash
             // of the genesis block on bitcoin.
             bitcoin_chain_tip: RwLock::new(None),
+            coordinator_paused: AtomicBool::new(false),
+            deposits_paused: AtomicBool::new(false),
+            withdrawals_paused: AtomicBool::new(false),
+            coordinator_tie_break_lost: RwLock::new(None),
+            min_fee_rate: RwLock::new(None),
+            deposit_decision_overrides: RwLock::new(HashMap::new()),
+            withdrawal_decision_overrides: RwLock::new(HashMap::new()),
+            deposit_scripts: RwLock::new(HashMap::new()),
+            prevout_locks: RwLock::new(HashMap::new()),
+            peer_table: PeerTable::default(),
+            transcripts: TranscriptRecorder::default(),
+            chain_tip_health: RwLock::new(ChainTipHealth::Healthy),
+            cached_emily_limits: RwLock::new(None),
+            cached_tx_infos: RwLock::new(HashMap::new()),
+            limit_override_votes: RwLock::new(HashMap::new()),
+            active_limit_override: RwLock::new(None),
         }
     }
 }
@@ -287,6 +810,18 @@ impl SbtcLimits {
         self.max_mintable_cap.unwrap_or(Amount::MAX_MONEY)
     }
 
+    /// Return these limits with the caps set by the given limit override
+    /// vote replacing the ones in these limits.
+    pub fn with_override(mut self, vote: &LimitOverrideVote) -> Self {
+        if let Some(cap) = vote.per_deposit_cap {
+            self.per_deposit_cap = Some(Amount::from_sat(cap));
+        }
+        if let Some(cap) = vote.per_withdrawal_cap {
+            self.per_withdrawal_cap = Some(Amount::from_sat(cap));
+        }
+        self
+    }
+
     /// Get the rolling withdrawal limits.
     pub fn rolling_withdrawal_limits(&self) -> RollingWithdrawalLimits {
         let withdrawn_total = self.withdrawn_total.unwrap_or(0);
@@ -532,6 +1067,7 @@ impl SignerSet {
 
 #[cfg(test)]
 mod tests {
+    use fake::Fake as _;
     use rand::rngs::OsRng;
 
     use crate::keys::PrivateKey;
@@ -563,4 +1099,222 @@ mod tests {
         signer_set.remove_signer(&public_key);
         assert!(!signer_set.is_allowed_peer(&public_key.into()));
     }
+
+    #[test]
+    fn test_deposit_decision_overrides() {
+        use super::*;
+
+        let state = SignerState::default();
+        let outpoint = OutPoint::null();
+
+        assert_eq!(state.deposit_decision_override(&outpoint), None);
+        state.set_deposit_decision_override(outpoint, Some(false));
+        assert_eq!(state.deposit_decision_override(&outpoint), Some(false));
+        state.set_deposit_decision_override(outpoint, Some(true));
+        assert_eq!(state.deposit_decision_override(&outpoint), Some(true));
+        state.set_deposit_decision_override(outpoint, None);
+        assert_eq!(state.deposit_decision_override(&outpoint), None);
+    }
+
+    #[test]
+    fn test_coordinator_paused() {
+        use super::*;
+
+        let state = SignerState::default();
+
+        assert!(!state.is_coordinator_paused());
+        assert!(!state.set_coordinator_paused(true));
+        assert!(state.is_coordinator_paused());
+        assert!(state.set_coordinator_paused(false));
+        assert!(!state.is_coordinator_paused());
+    }
+
+    #[test]
+    fn test_cached_emily_limits() {
+        use super::*;
+
+        let state = SignerState::default();
+        let ttl = Duration::from_secs(60);
+        let fetched_at = Instant::now();
+
+        assert_eq!(state.cached_emily_limits(fetched_at, ttl), None);
+
+        let limits = SbtcLimits::unlimited();
+        state.set_cached_emily_limits(limits.clone(), fetched_at);
+        let now = fetched_at + Duration::from_secs(59);
+        assert_eq!(state.cached_emily_limits(now, ttl), Some(limits));
+
+        // Limits that are older than the TTL are not used.
+        let now = fetched_at + ttl;
+        assert_eq!(state.cached_emily_limits(now, ttl), None);
+    }
+
+    #[test]
+    fn test_cached_tx_infos() {
+        use super::*;
+        use bitcoin::hashes::Hash as _;
+
+        let state = SignerState::default();
+        let ttl = Duration::from_secs(60);
+        let fetched_at = Instant::now();
+
+        let tx_info = BitcoinTxInfo {
+            fee: None,
+            tx: crate::testing::btc::base_signer_transaction(),
+            vin: Vec::new(),
+        };
+        let txid = tx_info.tx.compute_txid();
+        let block_hash = BlockHash::from_byte_array([1; 32]);
+        let other_block_hash = BlockHash::from_byte_array([2; 32]);
+
+        assert_eq!(
+            state.cached_tx_info(&txid, &block_hash, fetched_at, ttl),
+            None
+        );
+
+        state.set_cached_tx_info(txid, block_hash, tx_info.clone(), fetched_at, ttl);
+        let now = fetched_at + Duration::from_secs(59);
+        let cached = state.cached_tx_info(&txid, &block_hash, now, ttl);
+        assert_eq!(cached, Some(tx_info.clone()));
+        // The transaction is only cached for the block that it was
+        // fetched from.
+        let cached = state.cached_tx_info(&txid, &other_block_hash, now, ttl);
+        assert_eq!(cached, None);
+
+        // Transactions that are older than the TTL are not used, and are
+        // evicted when another transaction is cached.
+        let now = fetched_at + ttl;
+        assert_eq!(state.cached_tx_info(&txid, &block_hash, now, ttl), None);
+        state.set_cached_tx_info(txid, other_block_hash, tx_info, now, ttl);
+        assert_eq!(state.cached_tx_infos.read().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_limit_override_votes() {
+        use super::*;
+
+        let state = SignerState::default();
+        let vote = LimitOverrideVote {
+            per_deposit_cap: Some(1_000_000),
+            per_withdrawal_cap: None,
+            expires_at_height: 110u64.into(),
+        };
+        let other_vote = LimitOverrideVote { per_deposit_cap: None, ..vote };
+        let signer1 = PublicKey::from_private_key(&PrivateKey::new(&mut OsRng));
+        let signer2 = PublicKey::from_private_key(&PrivateKey::new(&mut OsRng));
+
+        assert_eq!(state.record_limit_override_vote(signer1, vote), 1);
+        assert_eq!(state.record_limit_override_vote(signer2, other_vote), 1);
+        // A new vote from a signer replaces its earlier vote.
+        assert_eq!(state.record_limit_override_vote(signer2, vote), 2);
+
+        state.set_active_limit_override(vote);
+        state.prune_limit_override_votes(109u64.into());
+        assert_eq!(state.active_limit_override(), Some(vote));
+        assert_eq!(state.record_limit_override_vote(signer1, vote), 2);
+
+        state.prune_limit_override_votes(110u64.into());
+        assert_eq!(state.active_limit_override(), None);
+        assert_eq!(state.record_limit_override_vote(signer1, vote), 1);
+    }
+
+    #[test]
+    fn test_deposits_paused() {
+        use super::*;
+
+        let state = SignerState::default();
+
+        assert!(!state.is_deposits_paused());
+        assert!(!state.set_deposits_paused(true));
+        assert!(state.is_deposits_paused());
+        assert!(state.set_deposits_paused(false));
+        assert!(!state.is_deposits_paused());
+    }
+
+    #[test]
+    fn test_withdrawals_paused() {
+        use super::*;
+
+        let state = SignerState::default();
+
+        assert!(!state.is_withdrawals_paused());
+        assert!(!state.set_withdrawals_paused(true));
+        assert!(state.is_withdrawals_paused());
+        assert!(!state.is_deposits_paused());
+        assert!(state.set_withdrawals_paused(false));
+        assert!(!state.is_withdrawals_paused());
+    }
+
+    #[test]
+    fn test_prevout_locks() {
+        use super::*;
+        use bitcoin::hashes::Hash as _;
+
+        let state = SignerState::default();
+        let first = Txid::from_byte_array([1; 32]);
+        let second = Txid::from_byte_array([2; 32]);
+        let shared = OutPoint::new(Txid::from_byte_array([3; 32]), 0);
+        let other = OutPoint::new(Txid::from_byte_array([4; 32]), 1);
+
+        state.lock_prevouts(first, &[shared]).unwrap();
+        // Locking again for the same transaction is fine.
+        state.lock_prevouts(first, &[shared]).unwrap();
+
+        let result = state.lock_prevouts(second, &[other, shared]);
+        assert!(matches!(
+            result,
+            Err(Error::PrevoutSpendConflict { outpoint, txid })
+                if outpoint == shared && txid == first
+        ));
+
+        // None of the prevouts were locked on conflict, so the other
+        // prevout is still free.
+        state.lock_prevouts(first, &[other]).unwrap();
+
+        state.unlock_prevouts(&first);
+        state.lock_prevouts(second, &[other, shared]).unwrap();
+    }
+
+    #[test]
+    fn test_coordinator_tie_break_lost() {
+        use super::*;
+
+        let state = SignerState::default();
+        let chain_tip: BitcoinBlockHash = fake::Faker.fake();
+        let next_chain_tip: BitcoinBlockHash = fake::Faker.fake();
+
+        assert!(!state.lost_coordinator_tie_break(&chain_tip));
+        state.set_coordinator_tie_break_lost(chain_tip);
+        assert!(state.lost_coordinator_tie_break(&chain_tip));
+        assert!(!state.lost_coordinator_tie_break(&next_chain_tip));
+    }
+
+    #[test]
+    fn test_deposit_scripts_cache() {
+        use super::*;
+
+        let state = SignerState::default();
+        let setup = sbtc::testing::deposits::tx_setup(150, 2500, &[100_000]);
+        let mut request = CreateDepositRequest {
+            outpoint: OutPoint::new(setup.tx.compute_txid(), 0),
+            deposit_script: setup.deposits[0].deposit_script(),
+            reclaim_script: setup.reclaims[0].reclaim_script(),
+        };
+
+        let scripts = state.deposit_scripts(&request).unwrap();
+        assert_eq!(scripts.deposit, setup.deposits[0]);
+        assert_eq!(state.num_cached_deposit_scripts(), 1);
+
+        // The second lookup returns the cached scripts.
+        let cached = state.deposit_scripts(&request).unwrap();
+        assert!(Arc::ptr_eq(&scripts, &cached));
+
+        // Invalid scripts for the same outpoint are parsed again and
+        // rejected, rather than served from the cache.
+        request.deposit_script = bitcoin::ScriptBuf::new();
+        assert!(state.deposit_scripts(&request).is_err());
+
+        state.clear_deposit_scripts();
+        assert_eq!(state.num_cached_deposit_scripts(), 0);
+    }
 }
@@ -287,6 +287,18 @@ mod tests {
     #[test_case(PhantomData::<message::WstsMessage> ; "WstsMessage")]
     #[test_case(PhantomData::<message::BitcoinPreSignRequest> ; "BitcoinPreSignRequest")]
     #[test_case(PhantomData::<message::BitcoinPreSignAck> ; "BitcoinPreSignAck")]
+    #[test_case(PhantomData::<message::CoordinatorAttestation> ; "CoordinatorAttestation")]
+    #[test_case(PhantomData::<message::DatabaseDigest> ; "DatabaseDigest")]
+    #[test_case(PhantomData::<message::DepositDecisionRetryRequest> ; "DepositDecisionRetryRequest")]
+    #[test_case(PhantomData::<message::MessageRedeliveryRequest> ; "MessageRedeliveryRequest")]
+    #[test_case(PhantomData::<message::RedeliveredMessages> ; "RedeliveredMessages")]
+    #[test_case(PhantomData::<message::SignerDecisionBatch> ; "SignerDecisionBatch")]
+    #[test_case(PhantomData::<message::SignRequestRefusal> ; "SignRequestRefusal")]
+    #[test_case(PhantomData::<message::LimitOverrideVote> ; "LimitOverrideVote")]
+    #[test_case(PhantomData::<message::SignerKeyEndorsement> ; "SignerKeyEndorsement")]
+    #[test_case(PhantomData::<message::EmergencyRecoveryApproval> ; "EmergencyRecoveryApproval")]
+    #[test_case(PhantomData::<message::DecisionAcknowledgement> ; "DecisionAcknowledgement")]
+    #[test_case(PhantomData::<message::EmergencyRecoveryRevocation> ; "EmergencyRecoveryRevocation")]
     fn payload_signing_recovery<T>(_: PhantomData<T>)
     where
         T: Into<message::Payload> + fake::Dummy<Faker>,
@@ -345,6 +357,18 @@ mod tests {
     #[test_case(PhantomData::<message::WstsMessage> ; "WstsMessage")]
     #[test_case(PhantomData::<message::BitcoinPreSignRequest> ; "BitcoinPreSignRequest")]
     #[test_case(PhantomData::<message::BitcoinPreSignAck> ; "BitcoinPreSignAck")]
+    #[test_case(PhantomData::<message::CoordinatorAttestation> ; "CoordinatorAttestation")]
+    #[test_case(PhantomData::<message::DatabaseDigest> ; "DatabaseDigest")]
+    #[test_case(PhantomData::<message::DepositDecisionRetryRequest> ; "DepositDecisionRetryRequest")]
+    #[test_case(PhantomData::<message::MessageRedeliveryRequest> ; "MessageRedeliveryRequest")]
+    #[test_case(PhantomData::<message::RedeliveredMessages> ; "RedeliveredMessages")]
+    #[test_case(PhantomData::<message::SignerDecisionBatch> ; "SignerDecisionBatch")]
+    #[test_case(PhantomData::<message::SignRequestRefusal> ; "SignRequestRefusal")]
+    #[test_case(PhantomData::<message::LimitOverrideVote> ; "LimitOverrideVote")]
+    #[test_case(PhantomData::<message::SignerKeyEndorsement> ; "SignerKeyEndorsement")]
+    #[test_case(PhantomData::<message::EmergencyRecoveryApproval> ; "EmergencyRecoveryApproval")]
+    #[test_case(PhantomData::<message::DecisionAcknowledgement> ; "DecisionAcknowledgement")]
+    #[test_case(PhantomData::<message::EmergencyRecoveryRevocation> ; "EmergencyRecoveryRevocation")]
     fn payload_signing_failing_validation<T>(_: PhantomData<T>)
     where
         T: Into<message::Payload> + fake::Dummy<Faker>,
@@ -431,6 +455,18 @@ mod tests {
     #[test_case(PhantomData::<message::WstsMessage> ; "WstsMessage")]
     #[test_case(PhantomData::<message::BitcoinPreSignRequest> ; "BitcoinPreSignRequest")]
     #[test_case(PhantomData::<message::BitcoinPreSignAck> ; "BitcoinPreSignAck")]
+    #[test_case(PhantomData::<message::CoordinatorAttestation> ; "CoordinatorAttestation")]
+    #[test_case(PhantomData::<message::DatabaseDigest> ; "DatabaseDigest")]
+    #[test_case(PhantomData::<message::DepositDecisionRetryRequest> ; "DepositDecisionRetryRequest")]
+    #[test_case(PhantomData::<message::MessageRedeliveryRequest> ; "MessageRedeliveryRequest")]
+    #[test_case(PhantomData::<message::RedeliveredMessages> ; "RedeliveredMessages")]
+    #[test_case(PhantomData::<message::SignerDecisionBatch> ; "SignerDecisionBatch")]
+    #[test_case(PhantomData::<message::SignRequestRefusal> ; "SignRequestRefusal")]
+    #[test_case(PhantomData::<message::LimitOverrideVote> ; "LimitOverrideVote")]
+    #[test_case(PhantomData::<message::SignerKeyEndorsement> ; "SignerKeyEndorsement")]
+    #[test_case(PhantomData::<message::EmergencyRecoveryApproval> ; "EmergencyRecoveryApproval")]
+    #[test_case(PhantomData::<message::DecisionAcknowledgement> ; "DecisionAcknowledgement")]
+    #[test_case(PhantomData::<message::EmergencyRecoveryRevocation> ; "EmergencyRecoveryRevocation")]
     fn backwards_compatible_updates<T>(_: PhantomData<T>)
     where
         T: Into<message::Payload> + fake::Dummy<Faker>,
@@ -0,0 +1,585 @@
+//! Break-glass recovery of all of the funds of the signers.
+//!
+//! When the signer set faces a catastrophe, say the imminent compromise
+//! of its key, the operators can move everything that the signers hold
+//! to a recovery scriptPubKey that they agreed on beforehand and put in
+//! the `emergency_recovery_script_pubkey` field of each of their configs.
+//!
+//! To do so, each operator approves the recovery over the control API of
+//! their signer, which records the approval and broadcasts it to the
+//! other signers in a signed [`EmergencyRecoveryApproval`] message. Each
+//! signer only counts approvals for its own configured recovery
+//! scriptPubKey from members of the current signer set, and only accepts
+//! messages sent within `emergency_recovery_approval_window_blocks` of
+//! its own chain tip. An approval lapses after
+//! `emergency_recovery_approval_expiry_blocks` bitcoin blocks, and an
+//! operator can withdraw it earlier with an
+//! [`EmergencyRecoveryRevocation`].
+//!
+//! Once at least `signatures_required` approvals are in, the signers wait
+//! another `emergency_recovery_delay_blocks` bitcoin blocks, which gives
+//! anyone watching the alerts time to intervene. After that, the
+//! coordinator stops all other bitcoin work and asks the signers to sign
+//! an [`UnsignedRecoveryTransaction`](crate::bitcoin::utxo::UnsignedRecoveryTransaction)
+//! that sweeps the signers' UTXO, along with every other confirmed output
+//! locked by the signers' key, to the recovery scriptPubKey. Each signer
+//! rebuilds the transaction on its own and only signs it if the sweep is
+//! allowed from its point of view. The recovery is over once one of the
+//! sweeps that the signer agreed to sign is confirmed, so that deposits
+//! made afterwards are not swept as well.
+//!
+//! Every approval, revocation and the broadcast of the sweep raise
+//! critical alerts.
+
+use bitcoin::ScriptBuf;
+
+use crate::bitcoin::utxo::SignerUtxo;
+use crate::context::Context;
+use crate::context::SignerEvent;
+use crate::error::Error;
+use crate::keys::PublicKey;
+use crate::keys::SignerScriptPubKey as _;
+use crate::message::EmergencyRecoveryApproval;
+use crate::message::EmergencyRecoveryRevocation;
+use crate::storage::DbRead as _;
+use crate::storage::DbWrite as _;
+use crate::storage::model;
+use crate::storage::model::BitcoinBlockHeight;
+use crate::storage::model::BitcoinBlockRef;
+use crate::storage::model::BitcoinTxId;
+
+/// Events raised during an emergency recovery.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EmergencyRecoveryEvent {
+    /// A member of the signer set approved the emergency recovery.
+    ApprovalRecorded {
+        /// The public key of the signer that approved the recovery.
+        approver: PublicKey,
+        /// The tally of the approvals after recording this one.
+        tally: EmergencyRecoveryTally,
+    },
+    /// A member of the signer set revoked its approval of the emergency
+    /// recovery.
+    ApprovalRevoked {
+        /// The public key of the signer that revoked its approval.
+        revoker: PublicKey,
+        /// The tally of the approvals after recording the revocation.
+        tally: EmergencyRecoveryTally,
+    },
+    /// The coordinator broadcast the sweep of the signers' UTXOs to the
+    /// recovery scriptPubKey.
+    SweepBroadcast {
+        /// The transaction ID of the sweep.
+        txid: BitcoinTxId,
+    },
+}
+
+/// The approvals for the emergency recovery to the configured recovery
+/// scriptPubKey.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmergencyRecoveryTally {
+    /// The number of members of the signer set with an approval of the
+    /// recovery that was neither revoked nor expired.
+    pub approvals: usize,
+    /// The number of approvals needed for the recovery to go ahead.
+    pub threshold: u16,
+    /// The bitcoin block height from which the signers will sign the
+    /// sweep, if enough signers approved the recovery.
+    pub sweep_height: Option<BitcoinBlockHeight>,
+}
+
+/// Return the recovery scriptPubKey in the config of this signer.
+fn configured_script(ctx: &impl Context) -> Result<ScriptBuf, Error> {
+    ctx.config()
+        .signer
+        .emergency_recovery_script_pubkey
+        .clone()
+        .ok_or(Error::EmergencyRecoveryNotConfigured)
+}
+
+/// Check that an approval or revocation from the given member of the
+/// signer set is for the configured recovery scriptPubKey, and that it
+/// was sent within the configured window around our chain tip.
+fn validate_message(
+    ctx: &impl Context,
+    recovery_script_pubkey: &ScriptBuf,
+    signer: PublicKey,
+    sent_at: BitcoinBlockHeight,
+    chain_tip: &BitcoinBlockRef,
+) -> Result<ScriptBuf, Error> {
+    let configured = configured_script(ctx)?;
+    if recovery_script_pubkey != &configured {
+        let script = recovery_script_pubkey.clone();
+        return Err(Error::EmergencyRecoveryScriptMismatch(script));
+    }
+    if !ctx.state().current_signer_set().is_signer(&signer) {
+        return Err(Error::EmergencyRecoveryApproverNotInSignerSet(signer));
+    }
+
+    let window = u64::from(
+        ctx.config()
+            .signer
+            .emergency_recovery_approval_window_blocks,
+    );
+    let chain_tip_height = chain_tip.block_height;
+    if sent_at.saturating_add(window) < chain_tip_height
+        || chain_tip_height.saturating_add(window) < sent_at
+    {
+        return Err(Error::EmergencyRecoveryMessageOutOfWindow { sent_at, chain_tip_height });
+    }
+
+    Ok(configured)
+}
+
+/// Record the latest approval or revocation of the given signer against
+/// our bitcoin chain tip, and return whether anything changed.
+///
+/// An approval sent before a block at which we recorded a revocation is
+/// ignored, so an approval delivered late cannot undo the revocation.
+/// Approving again while an earlier approval is still in force keeps the
+/// earlier one, so that rebroadcasts do not push the sweep back.
+async fn write_statement(
+    ctx: &impl Context,
+    recovery_script_pubkey: ScriptBuf,
+    signer: PublicKey,
+    revoked: bool,
+    sent_at: BitcoinBlockHeight,
+    chain_tip: BitcoinBlockRef,
+) -> Result<bool, Error> {
+    let expiry = u64::from(
+        ctx.config()
+            .signer
+            .emergency_recovery_approval_expiry_blocks
+            .get(),
+    );
+    let recovery_script_pubkey = model::ScriptPubKey::from(recovery_script_pubkey);
+
+    let storage = ctx.get_storage_mut();
+    let existing = storage
+        .get_emergency_recovery_approvals(&recovery_script_pubkey)
+        .await?
+        .into_iter()
+        .find(|approval| approval.signer_public_key == signer);
+
+    if let Some(existing) = existing {
+        let in_force = !existing.revoked
+            && chain_tip.block_height < existing.bitcoin_block_height.saturating_add(expiry);
+        let stale = existing.revoked && existing.bitcoin_block_height > sent_at;
+        let unchanged = if revoked {
+            existing.revoked
+        } else {
+            in_force || stale
+        };
+        if unchanged {
+            return Ok(false);
+        }
+    }
+
+    let approval = model::EmergencyRecoveryApproval {
+        signer_public_key: signer,
+        recovery_script_pubkey,
+        bitcoin_block_hash: chain_tip.block_hash,
+        bitcoin_block_height: chain_tip.block_height,
+        revoked,
+    };
+    storage.write_emergency_recovery_approval(&approval).await?;
+
+    Ok(true)
+}
+
+/// Validate the approval of the given member of the signer set, sent at
+/// the given bitcoin block height, and record it against our bitcoin
+/// chain tip.
+pub async fn record_approval(
+    ctx: &impl Context,
+    approval: &EmergencyRecoveryApproval,
+    approver: PublicKey,
+    sent_at: BitcoinBlockHeight,
+    chain_tip: BitcoinBlockRef,
+) -> Result<EmergencyRecoveryTally, Error> {
+    let script = &approval.recovery_script_pubkey;
+    let script = validate_message(ctx, script, approver, sent_at, &chain_tip)?;
+    let recorded = write_statement(ctx, script, approver, false, sent_at, chain_tip).await?;
+
+    let tally = tally(ctx, chain_tip.block_height).await?;
+    if !recorded {
+        return Ok(tally);
+    }
+    tracing::warn!(
+        %approver,
+        approvals = %tally.approvals,
+        threshold = %tally.threshold,
+        sweep_height = ?tally.sweep_height,
+        "recorded an approval of the emergency recovery"
+    );
+
+    let event = EmergencyRecoveryEvent::ApprovalRecorded { approver, tally };
+    if let Err(error) = ctx.signal(SignerEvent::EmergencyRecovery(event).into()) {
+        tracing::warn!(%error, "could not signal the emergency recovery approval");
+    }
+
+    Ok(tally)
+}
+
+/// Validate the revocation of the approval of the given member of the
+/// signer set, sent at the given bitcoin block height, and record it
+/// against our bitcoin chain tip.
+pub async fn record_revocation(
+    ctx: &impl Context,
+    revocation: &EmergencyRecoveryRevocation,
+    revoker: PublicKey,
+    sent_at: BitcoinBlockHeight,
+    chain_tip: BitcoinBlockRef,
+) -> Result<EmergencyRecoveryTally, Error> {
+    let script = &revocation.recovery_script_pubkey;
+    let script = validate_message(ctx, script, revoker, sent_at, &chain_tip)?;
+    let recorded = write_statement(ctx, script, revoker, true, sent_at, chain_tip).await?;
+
+    let tally = tally(ctx, chain_tip.block_height).await?;
+    if !recorded {
+        return Ok(tally);
+    }
+    tracing::warn!(
+        %revoker,
+        approvals = %tally.approvals,
+        threshold = %tally.threshold,
+        sweep_height = ?tally.sweep_height,
+        "recorded a revocation of an approval of the emergency recovery"
+    );
+
+    let event = EmergencyRecoveryEvent::ApprovalRevoked { revoker, tally };
+    if let Err(error) = ctx.signal(SignerEvent::EmergencyRecovery(event).into()) {
+        tracing::warn!(%error, "could not signal the emergency recovery revocation");
+    }
+
+    Ok(tally)
+}
+
+/// Count the approvals of the current signer set for the configured
+/// recovery scriptPubKey that are in force at the given bitcoin chain tip
+/// height, that is, approvals that were neither revoked nor expired.
+///
+/// The sweep height is the height of the bitcoin block at which the
+/// approval that met the threshold was recorded, plus the configured
+/// delay.
+pub async fn tally(
+    ctx: &impl Context,
+    chain_tip_height: BitcoinBlockHeight,
+) -> Result<EmergencyRecoveryTally, Error> {
+    let config = &ctx.config().signer;
+    let recovery_script_pubkey = configured_script(ctx)?;
+    let expiry = u64::from(config.emergency_recovery_approval_expiry_blocks.get());
+
+    let state = ctx.state();
+    let signer_set = state.current_signer_set();
+    let approval_heights: Vec<BitcoinBlockHeight> = ctx
+        .get_storage()
+        .get_emergency_recovery_approvals(&recovery_script_pubkey.into())
+        .await?
+        .into_iter()
+        .filter(|approval| signer_set.is_signer(&approval.signer_public_key))
+        .filter(|approval| !approval.revoked)
+        .filter(|approval| chain_tip_height < approval.bitcoin_block_height.saturating_add(expiry))
+        .map(|approval| approval.bitcoin_block_height)
+        .collect();
+
+    let threshold = state
+        .registry_signer_set_info()
+        .map_or(config.bootstrap_signatures_required, |info| {
+            info.signatures_required
+        });
+    let delay = u64::from(config.emergency_recovery_delay_blocks.get());
+    let sweep_height = usize::from(threshold)
+        .checked_sub(1)
+        .and_then(|index| approval_heights.get(index))
+        .map(|height| height.saturating_add(delay));
+
+    Ok(EmergencyRecoveryTally {
+        approvals: approval_heights.len(),
+        threshold,
+        sweep_height,
+    })
+}
+
+/// Return the recovery scriptPubKey if the signers should sweep their
+/// UTXOs to it at the given bitcoin chain tip.
+///
+/// Nothing is swept once one of the recovery sweeps that this signer
+/// agreed to sign is confirmed on the blockchain identified by the chain
+/// tip.
+pub async fn sweep_script(
+    ctx: &impl Context,
+    chain_tip: &BitcoinBlockRef,
+) -> Result<Option<ScriptBuf>, Error> {
+    let Some(recovery_script_pubkey) = ctx.config().signer.emergency_recovery_script_pubkey.clone()
+    else {
+        return Ok(None);
+    };
+
+    let tally = tally(ctx, chain_tip.block_height).await?;
+    let sweep_allowed = tally
+        .sweep_height
+        .is_some_and(|sweep_height| chain_tip.block_height >= sweep_height);
+    if !sweep_allowed {
+        return Ok(None);
+    }
+
+    let script = model::ScriptPubKey::from(recovery_script_pubkey.clone());
+    let swept = ctx
+        .get_storage()
+        .is_emergency_recovery_swept(&chain_tip.block_hash, &script)
+        .await?;
+
+    Ok((!swept).then_some(recovery_script_pubkey))
+}
+
+/// Return the payments to the signers' scriptPubKey, other than the given
+/// signers' UTXO, that the recovery sweep spends along with it.
+///
+/// These are the outputs locked by the same key as the signers' UTXO
+/// that were confirmed but not spent on the blockchain identified by the
+/// given chain tip.
+pub async fn swept_payments(
+    ctx: &impl Context,
+    chain_tip: &model::BitcoinBlockHash,
+    signer_utxo: &SignerUtxo,
+) -> Result<Vec<SignerUtxo>, Error> {
+    let signers_script_pubkey = signer_utxo.public_key.signers_script_pubkey();
+    let payments = ctx
+        .get_storage()
+        .get_unspent_signer_payments(chain_tip)
+        .await?
+        .into_iter()
+        .filter(|payment| *payment.script_pubkey == signers_script_pubkey)
+        .filter(|payment| payment.outpoint() != signer_utxo.outpoint)
+        .map(|payment| SignerUtxo {
+            outpoint: payment.outpoint(),
+            amount: payment.amount,
+            public_key: signer_utxo.public_key,
+        })
+        .collect();
+
+    Ok(payments)
+}
+
+/// Record that this signer agreed to sign the recovery sweep with the
+/// given transaction ID, so that the recovery stops once it is confirmed.
+pub async fn record_sweep(ctx: &impl Context, txid: BitcoinTxId) -> Result<(), Error> {
+    let sweep = model::EmergencyRecoverySweep {
+        txid,
+        recovery_script_pubkey: configured_script(ctx)?.into(),
+    };
+    ctx.get_storage_mut()
+        .write_emergency_recovery_sweep(&sweep)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU16;
+
+    use fake::Fake as _;
+    use fake::Faker;
+
+    use super::*;
+    use crate::testing::context::*;
+
+    fn chain_tip(height: u64) -> BitcoinBlockRef {
+        BitcoinBlockRef {
+            block_hash: Faker.fake(),
+            block_height: height.into(),
+        }
+    }
+
+    /// Set up a context with three signers, where two approvals are
+    /// needed, the sweep is delayed by 6 blocks, approvals expire after
+    /// 20 blocks and messages are accepted within 3 blocks of our tip.
+    fn setup() -> (impl Context, Vec<PublicKey>, ScriptBuf) {
+        let recovery_script_pubkey: ScriptBuf = Faker.fake::<model::ScriptPubKey>().into();
+        let mut ctx = TestContext::default_mocked();
+        let config = &mut ctx.config_mut().signer;
+        config.emergency_recovery_script_pubkey = Some(recovery_script_pubkey.clone());
+        config.emergency_recovery_delay_blocks = NonZeroU16::new(6).unwrap();
+        config.emergency_recovery_approval_expiry_blocks = NonZeroU16::new(20).unwrap();
+        config.emergency_recovery_approval_window_blocks = 3;
+        config.bootstrap_signatures_required = 2;
+
+        let signers: Vec<PublicKey> = std::iter::repeat_with(|| Faker.fake()).take(3).collect();
+        ctx.state()
+            .update_current_signer_set(signers.iter().copied().collect());
+
+        (ctx, signers, recovery_script_pubkey)
+    }
+
+    async fn approve(
+        ctx: &impl Context,
+        script: &ScriptBuf,
+        approver: PublicKey,
+        height: u64,
+    ) -> Result<EmergencyRecoveryTally, Error> {
+        let approval = EmergencyRecoveryApproval {
+            recovery_script_pubkey: script.clone(),
+        };
+        record_approval(ctx, &approval, approver, height.into(), chain_tip(height)).await
+    }
+
+    async fn revoke(
+        ctx: &impl Context,
+        script: &ScriptBuf,
+        revoker: PublicKey,
+        height: u64,
+    ) -> Result<EmergencyRecoveryTally, Error> {
+        let revocation = EmergencyRecoveryRevocation {
+            recovery_script_pubkey: script.clone(),
+        };
+        record_revocation(ctx, &revocation, revoker, height.into(), chain_tip(height)).await
+    }
+
+    #[tokio::test]
+    async fn sweep_is_allowed_after_the_delay_once_enough_signers_approve() {
+        let (ctx, signers, script) = setup();
+
+        let tally = approve(&ctx, &script, signers[0], 100).await.unwrap();
+        assert_eq!(tally.approvals, 1);
+        assert_eq!(tally.sweep_height, None);
+
+        // Approvals from outside of the signer set are not counted.
+        let outsider: PublicKey = Faker.fake();
+        let result = approve(&ctx, &script, outsider, 101).await;
+        assert!(matches!(
+            result,
+            Err(Error::EmergencyRecoveryApproverNotInSignerSet(_))
+        ));
+
+        // Neither are approvals of another recovery scriptPubKey.
+        let other: ScriptBuf = Faker.fake::<model::ScriptPubKey>().into();
+        let result = approve(&ctx, &other, signers[1], 101).await;
+        assert!(matches!(
+            result,
+            Err(Error::EmergencyRecoveryScriptMismatch(_))
+        ));
+
+        // Approving twice does not count twice, nor does it move the
+        // approval to a later block.
+        let tally = approve(&ctx, &script, signers[0], 101).await.unwrap();
+        assert_eq!(tally.approvals, 1);
+
+        let tally = approve(&ctx, &script, signers[1], 102).await.unwrap();
+        assert_eq!(tally.approvals, 2);
+        assert_eq!(tally.sweep_height, Some(108u64.into()));
+
+        let swept = sweep_script(&ctx, &chain_tip(107)).await.unwrap();
+        assert_eq!(swept, None);
+        let swept = sweep_script(&ctx, &chain_tip(108)).await.unwrap();
+        assert_eq!(swept, Some(script));
+    }
+
+    #[tokio::test]
+    async fn revoked_and_expired_approvals_are_not_counted() {
+        let (ctx, signers, script) = setup();
+
+        approve(&ctx, &script, signers[0], 100).await.unwrap();
+        let tally = approve(&ctx, &script, signers[1], 101).await.unwrap();
+        assert_eq!(tally.sweep_height, Some(107u64.into()));
+
+        let tally = revoke(&ctx, &script, signers[1], 102).await.unwrap();
+        assert_eq!(tally.approvals, 1);
+        assert_eq!(tally.sweep_height, None);
+        let swept = sweep_script(&ctx, &chain_tip(107)).await.unwrap();
+        assert_eq!(swept, None);
+
+        // An approval delivered after the revocation, but sent before it,
+        // does not undo it.
+        let approval = EmergencyRecoveryApproval {
+            recovery_script_pubkey: script.clone(),
+        };
+        let tally = record_approval(&ctx, &approval, signers[1], 101u64.into(), chain_tip(103))
+            .await
+            .unwrap();
+        assert_eq!(tally.approvals, 1);
+
+        // Approving again after the revocation counts from the new block.
+        let tally = approve(&ctx, &script, signers[1], 110).await.unwrap();
+        assert_eq!(tally.approvals, 2);
+        assert_eq!(tally.sweep_height, Some(116u64.into()));
+
+        // The first approval expires 20 blocks after it was recorded.
+        let before_expiry = tally(&ctx, 119u64.into()).await.unwrap();
+        assert_eq!(before_expiry.approvals, 2);
+        let after_expiry = tally(&ctx, 120u64.into()).await.unwrap();
+        assert_eq!(after_expiry.approvals, 1);
+        assert_eq!(after_expiry.sweep_height, None);
+
+        // Approving once expired renews the approval.
+        let tally = approve(&ctx, &script, signers[0], 120).await.unwrap();
+        assert_eq!(tally.approvals, 2);
+        assert_eq!(tally.sweep_height, Some(126u64.into()));
+    }
+
+    #[tokio::test]
+    async fn messages_outside_of_the_window_are_rejected() {
+        let (ctx, signers, script) = setup();
+        let approval = EmergencyRecoveryApproval {
+            recovery_script_pubkey: script.clone(),
+        };
+
+        for sent_at in [96u64, 104] {
+            let result =
+                record_approval(&ctx, &approval, signers[0], sent_at.into(), chain_tip(100)).await;
+            assert!(matches!(
+                result,
+                Err(Error::EmergencyRecoveryMessageOutOfWindow { .. })
+            ));
+        }
+
+        for sent_at in [97u64, 103] {
+            record_approval(&ctx, &approval, signers[0], sent_at.into(), chain_tip(100))
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn nothing_is_swept_once_a_recovery_sweep_is_confirmed() {
+        let (ctx, signers, script) = setup();
+
+        approve(&ctx, &script, signers[0], 100).await.unwrap();
+        approve(&ctx, &script, signers[1], 100).await.unwrap();
+
+        let block = model::BitcoinBlock {
+            block_hash: Faker.fake(),
+            block_height: 106u64.into(),
+            parent_hash: Faker.fake(),
+        };
+        let chain_tip = BitcoinBlockRef::from(&block);
+        let storage = ctx.get_storage_mut();
+        storage.write_bitcoin_block(&block).await.unwrap();
+
+        let txid: BitcoinTxId = Faker.fake();
+        record_sweep(&ctx, txid).await.unwrap();
+        // The sweep is not confirmed yet.
+        let swept = sweep_script(&ctx, &chain_tip).await.unwrap();
+        assert_eq!(swept, Some(script));
+
+        let tx_ref = model::BitcoinTxRef {
+            txid,
+            block_hash: block.block_hash,
+        };
+        storage.write_bitcoin_transaction(&tx_ref).await.unwrap();
+        let swept = sweep_script(&ctx, &chain_tip).await.unwrap();
+        assert_eq!(swept, None);
+    }
+
+    #[tokio::test]
+    async fn nothing_is_swept_without_a_recovery_script() {
+        let ctx = TestContext::default_mocked();
+        assert_eq!(ctx.config().signer.emergency_recovery_script_pubkey, None);
+
+        let script = sweep_script(&ctx, &chain_tip(1_000)).await.unwrap();
+        assert_eq!(script, None);
+        assert!(matches!(
+            tally(&ctx, 1_000u64.into()).await,
+            Err(Error::EmergencyRecoveryNotConfigured)
+        ));
+    }
+}
@@ -1,6 +1,10 @@
 //! Emily API client module
 
+use std::fmt;
+use std::future::Future;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::RwLock;
 use std::time::Duration;
 use std::time::Instant;
 
@@ -23,7 +27,10 @@ use emily_client::models::UpdateWithdrawalsRequestBody;
 use emily_client::models::UpdateWithdrawalsResponse;
 use emily_client::models::WithdrawalUpdate;
 use emily_client::models::{DepositStatus, WithdrawalStatus};
+use futures::Stream;
+use futures::StreamExt as _;
 use sbtc::deposits::CreateDepositRequest;
+use serde::Deserialize;
 use url::Url;
 
 use crate::bitcoin::utxo::RequestRef;
@@ -77,9 +84,12 @@ pub trait EmilyInteract: Sync + Send {
     ) -> impl std::future::Future<Output = Result<Option<CreateDepositRequest>, Error>> + Send;
 
     /// Get pending and accepted deposits to process from Emily.
-    fn get_deposits(
-        &self,
-    ) -> impl std::future::Future<Output = Result<Vec<CreateDepositRequest>, Error>> + Send;
+    ///
+    /// The deposits are fetched from Emily one page at a time as the
+    /// stream is polled. If a page cannot be fetched then an error is
+    /// returned in its place, and no more deposits with the same status
+    /// are fetched.
+    fn get_deposits(&self) -> impl Stream<Item = Result<CreateDepositRequest, Error>> + Send;
 
     /// Get pending deposits with a specific status from Emily.
     fn get_deposits_with_status(
@@ -117,12 +127,147 @@ pub trait EmilyInteract: Sync + Send {
 
     /// Gets the current sBTC-cap limits from Emily.
     fn get_limits(&self) -> impl std::future::Future<Output = Result<SbtcLimits, Error>> + Send;
+
+    /// The rotatable API keys of the client, if it supports them.
+    fn api_keys(&self) -> Option<EmilyApiKeys> {
+        None
+    }
+}
+
+/// The number of trailing characters of an API key that are left
+/// unmasked, so that operators can tell keys apart in logs.
+const UNMASKED_API_KEY_CHARS: usize = 4;
+
+/// Mask all but the last few characters of the given API key, or all of
+/// it if it is too short for those to be safe to show.
+pub fn mask_api_key(key: &str) -> String {
+    let num_chars = key.chars().count();
+    if num_chars <= 2 * UNMASKED_API_KEY_CHARS {
+        return "****".to_string();
+    }
+    let suffix: String = key
+        .chars()
+        .skip(num_chars - UNMASKED_API_KEY_CHARS)
+        .collect();
+    format!("****{suffix}")
+}
+
+/// An API key for Emily.
+///
+/// The key itself is masked when the key is formatted with `Debug`.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+pub struct EmilyApiKey {
+    /// The name of the key, which identifies it when it is rotated.
+    pub name: String,
+    /// The key itself.
+    pub key: String,
+    /// The priority of the key. Keys with a lower priority are tried
+    /// first.
+    #[serde(default)]
+    pub priority: u16,
+}
+
+impl EmilyApiKey {
+    /// The key, with all but its last few characters masked.
+    pub fn masked_key(&self) -> String {
+        mask_api_key(&self.key)
+    }
+}
+
+impl fmt::Debug for EmilyApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmilyApiKey")
+            .field("name", &self.name)
+            .field("key", &self.masked_key())
+            .field("priority", &self.priority)
+            .finish()
+    }
+}
+
+/// The API keys that the Emily clients use, which can be rotated while
+/// the signer is running.
+///
+/// Clones share the same keys, so a key set through one of them is used
+/// by all of the clients from then on.
+#[derive(Debug, Clone, Default)]
+pub struct EmilyApiKeys {
+    keys: Arc<RwLock<Vec<EmilyApiKey>>>,
+}
+
+impl EmilyApiKeys {
+    /// Create a set of API keys from the given keys. If several keys have
+    /// the same name then the last one wins.
+    pub fn new(keys: impl IntoIterator<Item = EmilyApiKey>) -> Self {
+        let api_keys = Self::default();
+        for key in keys {
+            api_keys.set(key);
+        }
+        api_keys
+    }
+
+    /// The keys in the order in which they are tried, which is by
+    /// priority, and then by name for keys with the same priority.
+    pub fn list(&self) -> Vec<EmilyApiKey> {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        self.keys
+            .read()
+            .expect("BUG: Failed to acquire read lock")
+            .clone()
+    }
+
+    /// Add the given key, replacing the key with the same name if there
+    /// is one. Returns whether a key was replaced.
+    pub fn set(&self, key: EmilyApiKey) -> bool {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        let mut keys = self
+            .keys
+            .write()
+            .expect("BUG: Failed to acquire write lock");
+        let len = keys.len();
+        keys.retain(|existing| existing.name != key.name);
+        let replaced = keys.len() != len;
+        keys.push(key);
+        keys.sort_by(|a, b| {
+            a.priority
+                .cmp(&b.priority)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        replaced
+    }
+
+    /// Remove the key with the given name. Returns whether there was such
+    /// a key.
+    pub fn remove(&self, name: &str) -> bool {
+        // We should never fail to acquire a lock from the RwLock so that it panics.
+        let mut keys = self
+            .keys
+            .write()
+            .expect("BUG: Failed to acquire write lock");
+        let len = keys.len();
+        keys.retain(|key| key.name != name);
+        keys.len() != len
+    }
+}
+
+/// Whether the given error is Emily rejecting the API key of the request.
+fn is_api_key_rejected<E>(error: &EmilyError<E>) -> bool {
+    match error {
+        EmilyError::ResponseError(ResponseContent { status, .. }) => {
+            matches!(status.as_u16(), 401 | 403)
+        }
+        _ => false,
+    }
 }
 
 /// Emily API client.
 #[derive(Clone)]
 pub struct EmilyClient {
+    /// The client config, with the API key from the endpoint URL, if
+    /// there was one.
     config: EmilyApiConfig,
+    /// The rotatable API keys, which are tried before the API key from
+    /// the endpoint URL.
+    api_keys: EmilyApiKeys,
     pagination_timeout: Duration,
     /// Maximum items returned per page. When set, responses will be limited to this many items.
     /// Regardless of the page_size setting, responses are always capped at 1 MB total size.
@@ -136,6 +281,12 @@ impl EmilyClient {
         &self.config
     }
 
+    /// Use the given rotatable API keys for all requests from now on.
+    pub fn with_api_keys(mut self, api_keys: EmilyApiKeys) -> Self {
+        self.api_keys = api_keys;
+        self
+    }
+
     /// Initialize a new Emily client and validate the url.
     pub fn try_new(
         url: &Url,
@@ -175,6 +326,7 @@ impl EmilyClient {
 
         Ok(Self {
             config,
+            api_keys: EmilyApiKeys::default(),
             pagination_timeout,
             // Page size must be u16 despite autogenerated client using u32.
             // This limitation exists because Emily needs to pass the parameter
@@ -183,6 +335,52 @@ impl EmilyClient {
         })
     }
 
+    /// The client configs to try a request with, in order: one for each
+    /// of the rotatable API keys, followed by the one with the API key
+    /// from the endpoint URL, if there was one or if there are no
+    /// rotatable API keys.
+    fn api_configs(&self) -> Vec<EmilyApiConfig> {
+        let api_keys = self.api_keys.list();
+        let mut configs: Vec<EmilyApiConfig> = api_keys
+            .into_iter()
+            .map(|api_key| EmilyApiConfig {
+                api_key: Some(ApiKey { prefix: None, key: api_key.key }),
+                ..self.config.clone()
+            })
+            .collect();
+
+        if configs.is_empty() || self.config.api_key.is_some() {
+            configs.push(self.config.clone());
+        }
+        configs
+    }
+
+    /// Make a request to Emily with each of the client configs in turn,
+    /// until Emily does not reject the API key of the request.
+    async fn try_api_keys<T, E, F, Fut>(&self, request: F) -> Result<T, EmilyError<E>>
+    where
+        F: Fn(EmilyApiConfig) -> Fut,
+        Fut: Future<Output = Result<T, EmilyError<E>>>,
+    {
+        let mut configs = self.api_configs().into_iter().peekable();
+        loop {
+            let config = configs
+                .next()
+                .expect("BUG: there is always a client config to try");
+            let api_key = config
+                .api_key
+                .as_ref()
+                .map(|api_key| mask_api_key(&api_key.key));
+
+            match request(config).await {
+                Err(error) if configs.peek().is_some() && is_api_key_rejected(&error) => {
+                    tracing::warn!(?api_key, "Emily rejected the API key; trying the next one");
+                }
+                result => return result,
+            }
+        }
+    }
+
     fn parse_deposit(deposit: &DepositInfo) -> Result<CreateDepositRequest, Error> {
         Ok(CreateDepositRequest {
             outpoint: OutPoint {
@@ -195,6 +393,105 @@ impl EmilyClient {
                 .map_err(Error::DecodeHexScript)?,
         })
     }
+
+    /// Fetch a single page of the deposits with the given status,
+    /// returning the deposits that could be parsed along with the token
+    /// of the next page, if there is one.
+    async fn get_deposits_page(
+        &self,
+        status: DepositStatus,
+        token: Option<String>,
+    ) -> Result<DepositsPage, Error> {
+        let token = token.as_deref();
+        let page_size = self.page_size;
+        let resp = self
+            .try_api_keys(|config| async move {
+                deposit_api::get_deposits(&config, status, token, page_size).await
+            })
+            .await
+            .map_err(|error| Error::EmilyApi(EmilyClientError::GetDeposits(error)))?;
+
+        // Convert each DepositInfo to our CreateDepositRequest
+        let deposits = resp
+            .deposits
+            .iter()
+            .filter_map(|deposit| match Self::parse_deposit(deposit) {
+                Ok(req) => Some(req),
+                Err(e) => {
+                    tracing::warn!(
+                        "Skipping corrupted deposit (txid: {}): {:?}",
+                        deposit.bitcoin_txid,
+                        e
+                    );
+                    None
+                }
+            })
+            .collect();
+
+        Ok((deposits, resp.next_token.flatten()))
+    }
+
+    /// Return a stream of the deposits with the given status.
+    ///
+    /// See [`paginate_deposits`] for how the pages are fetched.
+    fn deposits_with_status(
+        &self,
+        status: DepositStatus,
+    ) -> impl Stream<Item = Result<CreateDepositRequest, Error>> + Send {
+        paginate_deposits(
+            move |token| self.get_deposits_page(status, token),
+            self.pagination_timeout,
+        )
+    }
+}
+
+/// A page of deposits from Emily along with the pagination token of the
+/// next page, if there is one.
+type DepositsPage = (Vec<CreateDepositRequest>, Option<String>);
+
+/// Return a stream of deposits which follows Emily's pagination tokens,
+/// fetching the next page with `fetch_page` only once all deposits from
+/// the previous page have been consumed. The first page is fetched with
+/// no token.
+///
+/// The stream ends after an error is returned, or after the pagination
+/// timeout has elapsed.
+fn paginate_deposits<F, Fut>(
+    fetch_page: F,
+    pagination_timeout: Duration,
+) -> impl Stream<Item = Result<CreateDepositRequest, Error>> + Send
+where
+    F: Fn(Option<String>) -> Fut + Send,
+    Fut: Future<Output = Result<DepositsPage, Error>> + Send,
+{
+    let start_time = Instant::now();
+    // The second element of the state is the token of the next page to
+    // fetch, where `None` means that there are no more pages and
+    // `Some(None)` means the first page.
+    futures::stream::unfold(
+        (fetch_page, Some(None)),
+        move |(fetch_page, next_token): (F, Option<Option<String>>)| async move {
+            let token = next_token?;
+            let (deposits, next_token) = match fetch_page(token).await {
+                Ok(page) => page,
+                Err(error) => return Some((vec![Err(error)], (fetch_page, None))),
+            };
+
+            // If more pages exist, fetch them next; otherwise stop
+            let next_token = match next_token {
+                Some(token) if start_time.elapsed() > pagination_timeout => {
+                    tracing::warn!("timeout fetching deposits, breaking at page {:?}", token);
+                    None
+                }
+                Some(token) => Some(Some(token)),
+                None => None,
+            };
+
+            let deposits: Vec<_> = deposits.into_iter().map(Ok).collect();
+            Some((deposits, (fetch_page, next_token)))
+        },
+    )
+    .flat_map(futures::stream::iter)
 }
 
 impl EmilyInteract for EmilyClient {
@@ -206,7 +503,12 @@ impl EmilyInteract for EmilyClient {
         let txid_str = txid.to_string();
         let index = output_index.to_string();
 
-        let resp = deposit_api::get_deposit(&self.config, &txid_str, &index).await;
+        let resp = self
+            .try_api_keys(|config| {
+                let (txid_str, index) = (&txid_str, &index);
+                async move { deposit_api::get_deposit(&config, txid_str, index).await }
+            })
+            .await;
 
         let deposit = match resp {
             Ok(deposit) => deposit,
@@ -230,31 +532,9 @@ impl EmilyInteract for EmilyClient {
         }))
     }
 
-    async fn get_deposits(&self) -> Result<Vec<CreateDepositRequest>, Error> {
-        let pending_deposits = self.get_deposits_with_status(DepositStatus::Pending).await;
-        let accepted_deposits = self.get_deposits_with_status(DepositStatus::Accepted).await;
-
-        match (pending_deposits, accepted_deposits) {
-            (Err(pending_err), Err(_accepted_err)) => {
-                // If both calls fail, return the error from the first call
-                Err(pending_err)
-            }
-            (Ok(pending), Err(accepted_err)) => {
-                // If the pending call succeeds, return the pending deposits
-                tracing::warn!("failed to fetch accepted deposits: {:?}", accepted_err);
-                Ok(pending)
-            }
-            (Err(pending_err), Ok(accepted)) => {
-                // If the pending call fails, return the accepted deposits
-                tracing::warn!("failed to fetch pending deposits: {:?}", pending_err);
-                Ok(accepted)
-            }
-            (Ok(mut pending), Ok(mut accepted)) => {
-                // Combine the results
-                pending.append(&mut accepted);
-                Ok(pending)
-            }
-        }
+    fn get_deposits(&self) -> impl Stream<Item = Result<CreateDepositRequest, Error>> + Send {
+        self.deposits_with_status(DepositStatus::Pending)
+            .chain(self.deposits_with_status(DepositStatus::Accepted))
     }
 
     async fn get_deposits_with_status(
@@ -262,51 +542,16 @@ impl EmilyInteract for EmilyClient {
         status: DepositStatus,
     ) -> Result<Vec<CreateDepositRequest>, Error> {
         let mut all_deposits = Vec::new();
-        let mut next_token: Option<String> = None;
-        let start_time = Instant::now();
-        loop {
-            let resp = match deposit_api::get_deposits(
-                &self.config,
-                status,
-                next_token.as_deref(),
-                self.page_size,
-            )
-            .await
-            {
-                Ok(resp) => resp,
-                Err(e) => {
-                    if all_deposits.is_empty() {
-                        return Err(Error::EmilyApi(EmilyClientError::GetDeposits(e)));
-                    }
-                    tracing::warn!("failed to fetch page of deposits: {:?}", e);
+        let mut deposits = std::pin::pin!(self.deposits_with_status(status));
+
+        while let Some(deposit) = deposits.next().await {
+            match deposit {
+                Ok(req) => all_deposits.push(req),
+                Err(error) if all_deposits.is_empty() => return Err(error),
+                Err(error) => {
+                    tracing::warn!("failed to fetch page of deposits: {:?}", error);
                     break;
                 }
-            };
-            // Convert each DepositInfo to our CreateDepositRequest
-            for deposit in resp.deposits.iter() {
-                match Self::parse_deposit(deposit) {
-                    Ok(req) => all_deposits.push(req),
-                    Err(e) => tracing::warn!(
-                        "Skipping corrupted deposit (txid: {}): {:?}",
-                        deposit.bitcoin_txid,
-                        e
-                    ),
-                }
-            }
-
-            // If more pages exist, loop again; otherwise stop
-            match resp.next_token.flatten() {
-                Some(token) => next_token = Some(token),
-                None => break,
-            }
-
-            if start_time.elapsed() > self.pagination_timeout {
-                tracing::warn!(
-                    "timeout fetching deposits, breaking at page {:?}, fetched {} deposits",
-                    next_token,
-                    all_deposits.len()
-                );
-                break;
             }
         }
 
@@ -322,10 +567,13 @@ impl EmilyInteract for EmilyClient {
         }
 
         let update_request = UpdateDepositsRequestBody { deposits: update_deposits };
-        deposit_api::update_deposits_signer(&self.config, update_request)
-            .await
-            .map_err(EmilyClientError::UpdateDeposits)
-            .map_err(Error::EmilyApi)
+        self.try_api_keys(|config| {
+            let update_request = update_request.clone();
+            async move { deposit_api::update_deposits_signer(&config, update_request).await }
+        })
+        .await
+        .map_err(EmilyClientError::UpdateDeposits)
+        .map_err(Error::EmilyApi)
     }
 
     async fn accept_withdrawals<'a>(
@@ -383,14 +631,18 @@ impl EmilyInteract for EmilyClient {
         let update_request = UpdateWithdrawalsRequestBody {
             withdrawals: update_withdrawals,
         };
-        withdrawal_api::update_withdrawals_signer(&self.config, update_request)
-            .await
-            .map_err(EmilyClientError::UpdateWithdrawals)
-            .map_err(Error::EmilyApi)
+        self.try_api_keys(|config| {
+            let update_request = update_request.clone();
+            async move { withdrawal_api::update_withdrawals_signer(&config, update_request).await }
+        })
+        .await
+        .map_err(EmilyClientError::UpdateWithdrawals)
+        .map_err(Error::EmilyApi)
     }
 
     async fn get_limits(&self) -> Result<SbtcLimits, Error> {
-        let limits = limits_api::get_limits(&self.config)
+        let limits = self
+            .try_api_keys(|config| async move { limits_api::get_limits(&config).await })
             .await
             .map_err(EmilyClientError::GetLimits)
             .map_err(Error::EmilyApi)?;
@@ -416,6 +668,10 @@ impl EmilyInteract for EmilyClient {
             None,
         ))
     }
+
+    fn api_keys(&self) -> Option<EmilyApiKeys> {
+        Some(self.api_keys.clone())
+    }
 }
 
 impl EmilyInteract for ApiFallbackClient<EmilyClient> {
@@ -428,8 +684,20 @@ impl EmilyInteract for ApiFallbackClient<EmilyClient> {
             .await
     }
 
-    async fn get_deposits(&self) -> Result<Vec<CreateDepositRequest>, Error> {
-        self.exec(|client, _| client.get_deposits()).await
+    /// Stream the deposits, fetching each page through [`Self::exec`] so
+    /// that a failed page fetch falls back to the other clients.
+    fn get_deposits(&self) -> impl Stream<Item = Result<CreateDepositRequest, Error>> + Send {
+        let pagination_timeout = self.get_client().pagination_timeout;
+        let deposits_with_status = move |status: DepositStatus| {
+            let fetch_page = move |token: Option<String>| async move {
+                self.exec(|client, _| client.get_deposits_page(status, token.clone()))
+                    .await
+            };
+            paginate_deposits(fetch_page, pagination_timeout)
+        };
+
+        deposits_with_status(DepositStatus::Pending)
+            .chain(deposits_with_status(DepositStatus::Accepted))
     }
 
     async fn get_deposits_with_status(
@@ -475,16 +743,27 @@ impl EmilyInteract for ApiFallbackClient<EmilyClient> {
     async fn get_limits(&self) -> Result<SbtcLimits, Error> {
         self.exec(|client, _| client.get_limits()).await
     }
+
+    /// All of the clients share the same rotatable API keys, so these are
+    /// the API keys of the current client.
+    fn api_keys(&self) -> Option<EmilyApiKeys> {
+        self.get_client().api_keys()
+    }
 }
 
 impl TryFrom<&EmilyClientConfig> for ApiFallbackClient<EmilyClient> {
     type Error = Error;
 
     fn try_from(config: &EmilyClientConfig) -> Result<Self, Self::Error> {
+        // All of the clients share the same rotatable API keys.
+        let api_keys = EmilyApiKeys::new(config.api_keys.iter().cloned());
         let clients = config
             .endpoints
             .iter()
-            .map(|url| EmilyClient::try_new(url, config.pagination_timeout, None))
+            .map(|url| {
+                EmilyClient::try_new(url, config.pagination_timeout, config.page_size)
+                    .map(|client| client.with_api_keys(api_keys.clone()))
+            })
             .collect::<Result<Vec<_>, _>>()?;
 
         Self::new(clients).map_err(Into::into)
@@ -493,6 +772,11 @@ impl TryFrom<&EmilyClientConfig> for ApiFallbackClient<EmilyClient> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use bitcoin::hashes::Hash as _;
+
     use super::*;
 
     #[test]
@@ -516,4 +800,84 @@ mod tests {
         assert_eq!(client.config.base_path, "http://localhost:8080");
         assert!(client.config.api_key.is_none());
     }
+
+    fn api_key(name: &str, key: &str, priority: u16) -> EmilyApiKey {
+        EmilyApiKey {
+            name: name.to_string(),
+            key: key.to_string(),
+            priority,
+        }
+    }
+
+    #[test_case::test_case("1234567890abcdef" => "****cdef"; "long key")]
+    #[test_case::test_case("12345678" => "****"; "short key")]
+    #[test_case::test_case("" => "****"; "empty key")]
+    fn api_keys_are_masked(key: &str) -> String {
+        mask_api_key(key)
+    }
+
+    #[test]
+    fn api_keys_are_tried_by_priority_before_the_url_key() {
+        let url = Url::parse("http://url_key@localhost:8080").unwrap();
+        let api_keys = EmilyApiKeys::new([api_key("a", "key_a", 2), api_key("b", "key_b", 1)]);
+        let client = EmilyClient::try_new(&url, Duration::from_secs(1), None)
+            .unwrap()
+            .with_api_keys(api_keys.clone());
+
+        let keys_of = |client: &EmilyClient| -> Vec<Option<String>> {
+            client
+                .api_configs()
+                .into_iter()
+                .map(|config| config.api_key.map(|api_key| api_key.key))
+                .collect()
+        };
+        let expected = ["key_b", "key_a", "url_key"].map(|key| Some(key.to_string()));
+        assert_eq!(keys_of(&client), expected);
+
+        // Rotating a key through a clone of the keys affects the client.
+        assert!(api_keys.set(api_key("a", "key_c", 0)));
+        let expected = ["key_c", "key_b", "url_key"].map(|key| Some(key.to_string()));
+        assert_eq!(keys_of(&client), expected);
+
+        assert!(api_keys.remove("a"));
+        assert!(!api_keys.remove("a"));
+        assert!(api_keys.remove("b"));
+        assert_eq!(keys_of(&client), [Some("url_key".to_string())]);
+    }
+
+    #[test]
+    fn api_key_debug_is_masked() {
+        let debug = format!("{:?}", api_key("primary", "1234567890abcdef", 0));
+        assert!(!debug.contains("1234567890abcdef"));
+        assert!(debug.contains("****cdef"));
+    }
+
+    #[tokio::test]
+    async fn paginated_deposits_follow_tokens_and_stop_after_an_error() {
+        let deposit = |vout| CreateDepositRequest {
+            outpoint: OutPoint { txid: Txid::all_zeros(), vout },
+            reclaim_script: ScriptBuf::new(),
+            deposit_script: ScriptBuf::new(),
+        };
+        let num_fetches = AtomicUsize::new(0);
+        let fetch_page = |token: Option<String>| {
+            num_fetches.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match token.as_deref() {
+                    None => Ok(((0..2).map(deposit).collect(), Some("1".to_string()))),
+                    Some("1") => Ok(((0..3).map(deposit).collect(), Some("2".to_string()))),
+                    _ => Err(Error::InvalidStacksResponse("page failed")),
+                }
+            }
+        };
+
+        let deposits: Vec<_> = paginate_deposits(fetch_page, Duration::from_secs(60))
+            .collect()
+            .await;
+
+        assert_eq!(num_fetches.load(Ordering::SeqCst), 3);
+        assert_eq!(deposits.len(), 6);
+        assert!(deposits[..5].iter().all(Result::is_ok));
+        assert!(deposits[5].is_err());
+    }
 }
@@ -12,11 +12,14 @@ use crate::emily_client::EmilyClientError;
 use crate::keys::PublicKey;
 use crate::keys::PublicKeyXOnly;
 use crate::stacks::contracts::DepositValidationError;
+use crate::stacks::contracts::DepositsValidationError;
 use crate::stacks::contracts::RotateKeysValidationError;
 use crate::stacks::contracts::WithdrawalAcceptValidationError;
 use crate::stacks::contracts::WithdrawalRejectValidationError;
+use crate::stacks::contracts::WithdrawalsAcceptValidationError;
 use crate::storage::model::BitcoinBlockHash;
 use crate::storage::model::SigHash;
+use crate::storage::model::StacksTxId;
 use crate::transaction_signer::StacksSignRequestId;
 use crate::wsts_state_machine::StateMachineId;
 
@@ -160,6 +163,72 @@ pub enum Error {
     #[error("bitcoin-core getblockheader RPC error for hash {1}: {0}")]
     BitcoinCoreGetBlockHeader(#[source] bitcoincore_rpc::Error, bitcoin::BlockHash),
 
+    /// bitcoin-core knows about the block but no longer has its data,
+    /// because it is a pruned node.
+    #[error("bitcoin-core has pruned the data of block {0}")]
+    BitcoinCoreBlockPruned(bitcoin::BlockHash),
+
+    /// bitcoin-core is a pruned node that does not keep enough blocks for
+    /// the context window of the signer.
+    #[error(
+        "bitcoin-core only keeps the last {retained} blocks, but at least {required} are required"
+    )]
+    BitcoinCorePruneDepthTooShallow {
+        /// The number of blocks up to the chain tip that bitcoin-core has
+        /// the data of.
+        retained: u64,
+        /// The minimum number of blocks that bitcoin-core must keep.
+        required: u64,
+    },
+
+    /// Could not make a request to an esplora API.
+    #[error("failed to make a request to the esplora API: {0}")]
+    EsploraRequest(#[source] reqwest::Error),
+
+    /// An esplora API returned an error status or a malformed response.
+    #[error("received an unexpected response from the esplora API: {0}")]
+    EsploraResponse(#[source] reqwest::Error),
+
+    /// The esplora client can only scan for the outputs of descriptors of
+    /// the form used for the signers' wallet.
+    #[error("unsupported output descriptor for the esplora API: {0}")]
+    UnsupportedDescriptor(String),
+
+    /// The contents of a bitcoin block header do not hash to the block
+    /// hash that bitcoin-core reported for it.
+    #[error("bitcoin block header hashes to {computed}, not to the reported hash {claimed}")]
+    BitcoinHeaderHashMismatch {
+        /// The block hash reported by bitcoin-core.
+        claimed: bitcoin::BlockHash,
+        /// The hash of the contents of the header.
+        computed: bitcoin::BlockHash,
+    },
+
+    /// The proof-of-work target of a bitcoin block header is easier than
+    /// the proof-of-work limit of the network.
+    #[error("the target of bitcoin block {0} is above the proof-of-work limit of {1}")]
+    BitcoinHeaderTargetAboveLimit(bitcoin::BlockHash, bitcoin::Network),
+
+    /// The hash of a bitcoin block header does not meet its target.
+    #[error("bitcoin block {0} does not have enough proof-of-work for its target")]
+    BitcoinHeaderInsufficientWork(bitcoin::BlockHash),
+
+    /// A bitcoin block header does not follow its parent.
+    #[error("bitcoin block {0} does not follow its parent block")]
+    BitcoinHeaderDiscontinuity(bitcoin::BlockHash),
+
+    /// The time of a bitcoin block header does not fit in the header.
+    #[error("bitcoin block {0} has an invalid time {1}")]
+    BitcoinHeaderInvalidTime(bitcoin::BlockHash, u64),
+
+    /// A stacks block fetched by the block observer failed verification.
+    #[error("stacks block {0} anchored to bitcoin block {1} failed verification: {2}")]
+    StacksBlockVerification(
+        blockstack_lib::types::chainstate::StacksBlockId,
+        crate::storage::model::BitcoinBlockHash,
+        #[source] crate::stacks::verification::StacksBlockVerificationError,
+    ),
+
     /// Bitcoin block header is unknown to bitcoin-core. This is only
     /// triggered if bitcoin-core does not know about the block hash.
     #[error("Unknown block hash response from bitcoin-core getblockheader RPC call: {0}")]
@@ -229,6 +298,28 @@ pub enum Error {
     #[error("the given sighash is unknown: {0}")]
     UnknownSigHash(SigHash),
 
+    /// This happens when the coordinator tries to start the signing rounds
+    /// of a bitcoin transaction that spends a prevout that another signing
+    /// round is already spending.
+    #[error("prevout {outpoint} is already being spent by transaction {txid}")]
+    PrevoutSpendConflict {
+        /// The prevout that both transactions spend.
+        outpoint: bitcoin::OutPoint,
+        /// The transaction holding the lock on the prevout.
+        txid: bitcoin::Txid,
+    },
+
+    /// This happens when the tx-signer is asked to sign a sighash that
+    /// spends the same prevout at the same chain tip as a sighash that it
+    /// has already signed.
+    #[error("sighash {sighash} conflicts with already signed sighash {signed}")]
+    ConflictingSigHash {
+        /// The sighash that we were asked to sign.
+        sighash: SigHash,
+        /// The sighash that we have already signed.
+        signed: SigHash,
+    },
+
     /// This should never happen
     #[error("observed a tenure identified by a StacksBlockId with with no blocks")]
     EmptyStacksTenure,
@@ -255,6 +346,26 @@ pub enum Error {
     #[error("bitcoin RPC error: {0}")]
     BitcoinCoreRpc(#[from] bitcoincore_rpc::Error),
 
+    /// The blocking task that scans the UTXO set of bitcoin-core panicked
+    /// or was cancelled.
+    #[error("the scan of the bitcoin UTXO set did not complete: {0}")]
+    ScanTxOutSetTask(#[source] tokio::task::JoinError),
+
+    /// bitcoin-core did not accept a package of transactions into its
+    /// mempool. The third field has the reasons for each rejected
+    /// transaction.
+    #[error("bitcoin-core rejected the transaction package ({0}): {1}; {2}")]
+    BitcoinPackageRejected(
+        crate::bitcoin::broadcast::BroadcastRejection,
+        String,
+        String,
+    ),
+
+    /// bitcoin-core did not accept a transaction into its mempool. The
+    /// second field is the error message from bitcoin-core.
+    #[error("bitcoin-core rejected the transaction ({0}): {1}")]
+    BitcoinTransactionRejected(crate::bitcoin::broadcast::BroadcastRejection, String),
+
     /// An error propagated from the sBTC library.
     #[error("sBTC lib error: {0}")]
     SbtcLib(#[from] sbtc::error::Error),
@@ -263,11 +374,44 @@ pub enum Error {
     #[error("an error occurred running the libp2p swarm: {0}")]
     SignerSwarm(#[from] crate::network::libp2p::SignerSwarmError),
 
+    /// Error incurred while running the gRPC control service.
+    #[error("an error occurred running the gRPC service: {0}")]
+    GrpcServer(#[source] tonic::transport::Error),
+
+    /// The stored signed message for a coordinator attestation does not
+    /// match the attestation that it was stored with.
+    #[error("coordinator attestation does not match its signed message; chain tip {0}")]
+    CoordinatorAttestationMismatch(BitcoinBlockHash),
+
+    /// A coordinator attestation contained a different number of request
+    /// packages than sweep transaction IDs.
+    #[error("coordinator attestation is malformed; {0} request packages but {1} sweep txids")]
+    MalformedCoordinatorAttestation(usize, usize),
+
     /// The requested operation is not allowed in the current state as the
     /// signer is being shut down.
     #[error("the signer is shutting down")]
     SignerShutdown,
 
+    /// An event loop kept exiting unexpectedly, so the supervisor gave up
+    /// on restarting it.
+    #[error("the {0} event loop is crash looping")]
+    EventLoopCrashLoop(&'static str),
+
+    /// An event loop was built with an invalid setting.
+    #[error("invalid event loop setting: {0}")]
+    InvalidEventLoopSetting(&'static str),
+
+    /// The policy plugin of the request decider could not be loaded or
+    /// could not decide on a request.
+    #[error("policy plugin error: {0}")]
+    PolicyPlugin(String),
+
+    /// The policy plugin of the request decider did not decide on a
+    /// request within the given amount of time.
+    #[error("the policy plugin did not decide within {0:?}")]
+    PolicyPluginTimeout(std::time::Duration),
+
     /// I/O Error raised by the Tokio runtime.
     #[error("tokio i/o error: {0}")]
     TokioIo(#[from] tokio::io::Error),
@@ -330,6 +474,11 @@ pub enum Error {
     #[error("deposit validation error: {0}")]
     DepositValidation(#[from] Box<DepositValidationError>),
 
+    /// Failed to validate the batch level checks of a
+    /// complete-deposits-wrapper contract call transaction.
+    #[error("deposits validation error: {0}")]
+    DepositsValidation(#[source] Box<DepositsValidationError>),
+
     /// An error when serializing an object to JSON
     #[error("JSON serialization error: {0}")]
     JsonSerialize(#[source] serde_json::Error),
@@ -481,6 +630,12 @@ pub enum Error {
     #[error("took too long to receive enough signatures for transaction: {0}")]
     SignatureTimeout(blockstack_lib::burnchains::Txid),
 
+    /// Enough signers refused a sign request from the coordinator for the
+    /// signing threshold to be out of reach. This holds the refusals sent
+    /// by the signers that refused.
+    #[error("{count} signers refused the sign request: {0:?}", count = .0.len())]
+    SignRequestRefused(Vec<crate::message::SignRequestRefusal>),
+
     /// An error when attempting to generically decode bytes using the
     /// trait implementation.
     #[error("got an error wen attempting to call StacksMessageCodec::consensus_deserialize {0}")]
@@ -519,14 +674,36 @@ pub enum Error {
     #[error("invalid stacks response: {0}")]
     InvalidStacksResponse(&'static str),
 
+    /// The coordinator decided not to submit a stacks transaction, given
+    /// the transactions that it previously submitted for the same requests.
+    #[error("not submitting stacks transaction {0}: {1}")]
+    StacksTxResubmissionSkipped(StacksTxId, &'static str),
+
     /// The stacks request was already signed in this tenure
     #[error("stacks request for {0} was already signed in tenure {1}")]
     StacksRequestAlreadySigned(StacksSignRequestId, bitcoin::BlockHash),
 
+    /// The queue of stacks sign requests from the coordinator is full.
+    #[error("the queue of stacks sign requests from coordinator {0} is full")]
+    StacksSignRequestQueueFull(PublicKey),
+
+    /// The stacks sign request was received on a bitcoin chain tip that
+    /// is no longer the canonical one.
+    #[error("the stacks sign request for bitcoin chain tip {0} is stale")]
+    StaleStacksSignRequest(BitcoinBlockHash),
+
     /// Taproot error
     #[error("an error occurred when constructing the taproot signing digest: {0}")]
     Taproot(#[from] bitcoin::sighash::TaprootError),
 
+    /// An error when constructing a PSBT from an unsigned transaction.
+    #[error("could not construct a PSBT from the unsigned transaction: {0}")]
+    PsbtConstruction(#[source] bitcoin::psbt::Error),
+
+    /// An error when writing an exported PSBT to a file.
+    #[error("could not write the PSBT to {}: {0}", .1.display())]
+    PsbtExportWrite(#[source] std::io::Error, std::path::PathBuf),
+
     /// Key error
     #[error("key error: {0}")]
     KeyError(#[from] p256k1::keys::Error),
@@ -606,6 +783,11 @@ pub enum Error {
     #[error("invalid signature")]
     InvalidSignature,
 
+    /// A P2P message was received on a topic other than the topic of its
+    /// payload.
+    #[error("message received on a topic other than {0}")]
+    WrongP2PTopic(crate::network::Topic),
+
     /// Invalid ECDSA signature
     #[error("invalid ECDSA signature")]
     InvalidEcdsaSignature(#[source] secp256k1::Error),
@@ -668,6 +850,12 @@ pub enum Error {
     #[error("withdrawal accept validation error: {0}")]
     WithdrawalAcceptValidation(#[source] Box<WithdrawalAcceptValidationError>),
 
+    /// The error for when the request to sign a complete-withdrawals
+    /// transaction accepting several withdrawals fails at the batch
+    /// validation step.
+    #[error("withdrawals accept validation error: {0}")]
+    WithdrawalsAcceptValidation(#[source] Box<WithdrawalsAcceptValidationError>),
+
     /// The error for when the request to sign a withdrawal-reject
     /// transaction fails at the validation step.
     #[error("withdrawal reject validation error: {0}")]
@@ -694,6 +882,29 @@ pub enum Error {
     #[error("no stacks chain tip")]
     NoStacksChainTip,
 
+    /// The checksum of a state snapshot does not match its contents.
+    #[error("the checksum of the state snapshot does not match its contents")]
+    StateSnapshotChecksumMismatch,
+
+    /// The state snapshot was written in a format that this signer cannot
+    /// read.
+    #[error("unsupported state snapshot version {0}")]
+    UnsupportedStateSnapshotVersion(u32),
+
+    /// The state snapshot was taken by a signer with a different config.
+    #[error("the state snapshot has config fingerprint {snapshot} but the config has {config}")]
+    StateSnapshotConfigMismatch {
+        /// The config fingerprint in the snapshot.
+        snapshot: String,
+        /// The fingerprint of the config of this signer.
+        config: String,
+    },
+
+    /// A state snapshot can only be imported into a database without DKG
+    /// shares.
+    #[error("the database already holds DKG shares, refusing to import the state snapshot")]
+    StateSnapshotTargetNotEmpty,
+
     /// Bitcoin error when attempting to construct an address from a
     /// scriptPubKey.
     #[error("bitcoin address parse error: {0}; txid {txid}, vout: {vout}", txid = .1.txid, vout = .1.vout)]
@@ -737,6 +948,24 @@ pub enum Error {
     #[error("smart contract already deployed, contract name: {0}")]
     ContractAlreadyDeployed(&'static str),
 
+    /// A deployer account wallet was requested, but no deployer private
+    /// key is configured.
+    #[error("no deployer private key is configured")]
+    MissingDeployerPrivateKey,
+
+    /// The smart contract belongs to a release that upgrades the sBTC
+    /// smart contracts, but contract upgrades are not enabled.
+    #[error("smart contract upgrades are not enabled, contract name: {0}")]
+    ContractUpgradesDisabled(&'static str),
+
+    /// The smart contract belongs to a release other than the target
+    /// release of this signer.
+    #[error("smart contract release {0} does not match the target release {1}")]
+    ContractVersionMismatch(
+        crate::stacks::contracts::SmartContractsVersion,
+        crate::stacks::contracts::SmartContractsVersion,
+    ),
+
     /// Received coordinator message wasn't from coordinator for this chain tip
     #[error("not chain tip coordinator")]
     NotChainTipCoordinator,
@@ -745,11 +974,92 @@ pub enum Error {
     #[error("the request packages contain duplicate deposit or withdrawal entries.")]
     DuplicateRequests,
 
+    /// A deposit quorum certificate in a BitcoinPreSignRequest does not
+    /// hold valid receipts from enough members of the signing set, or is
+    /// for a deposit request that is not in the request package.
+    #[error("invalid quorum certificate for deposit request {0}")]
+    InvalidDepositQuorumCertificate(bitcoin::OutPoint),
+
+    /// A deposit request in a BitcoinPreSignRequest does not come with
+    /// exactly one quorum certificate.
+    #[error("missing or duplicate quorum certificate for deposit request {0}")]
+    MissingDepositQuorumCertificate(bitcoin::OutPoint),
+
+    /// A vote for a limit override is malformed, has expired, or is
+    /// outside of the bounds that this signer accepts.
+    #[error("rejected limit override vote: {0}")]
+    InvalidLimitOverride(&'static str),
+
+    /// A vote for a limit override came from a signer that is not in the
+    /// current signer set.
+    #[error("limit override vote from a signer outside of the signer set: {0}")]
+    LimitOverrideVoterNotInSignerSet(PublicKey),
+
+    /// An endorsement of a new signer key came from a signer that is not
+    /// in the current signer set.
+    #[error("signer key endorsement from a signer outside of the signer set: {0}")]
+    KeyEndorsementSignerNotInSignerSet(PublicKey),
+
+    /// A signer endorsed its own public key as its new key.
+    #[error("signer {0} endorsed its own public key as its new key")]
+    InvalidKeyEndorsement(PublicKey),
+
+    /// The operator tried to start a rotation of the key of this signer
+    /// while another one is in progress.
+    #[error("a rotation of the signer key to {0} is already in progress")]
+    SignerKeyRotationInProgress(PublicKey),
+
+    /// This signer was asked to take part in an emergency recovery, but
+    /// its operator has not configured a recovery scriptPubKey.
+    #[error("no emergency recovery scriptPubKey is configured")]
+    EmergencyRecoveryNotConfigured,
+
+    /// An emergency recovery approval is for a scriptPubKey other than
+    /// the configured one.
+    #[error("emergency recovery approval for unexpected scriptPubKey {0}")]
+    EmergencyRecoveryScriptMismatch(bitcoin::ScriptBuf),
+
+    /// An emergency recovery approval came from a signer that is not in
+    /// the current signer set.
+    #[error("emergency recovery approval from a signer outside of the signer set: {0}")]
+    EmergencyRecoveryApproverNotInSignerSet(PublicKey),
+
+    /// An emergency recovery approval or revocation was sent at a bitcoin
+    /// block height too far from our own chain tip, so it may be a replay
+    /// of an old message.
+    #[error(
+        "emergency recovery message sent at height {sent_at} is outside of the window \
+        around our chain tip at height {chain_tip_height}"
+    )]
+    EmergencyRecoveryMessageOutOfWindow {
+        /// The height of the bitcoin chain tip of the sender of the message.
+        sent_at: crate::storage::model::BitcoinBlockHeight,
+        /// The height of our bitcoin chain tip.
+        chain_tip_height: crate::storage::model::BitcoinBlockHeight,
+    },
+
+    /// A presign request for the emergency recovery sweep also has sBTC
+    /// requests or refunds.
+    #[error("the emergency recovery presign request must not have requests or refunds")]
+    InvalidEmergencyRecoveryRequest,
+
     /// Indicates that the BitcoinPreSignRequest object does not contain
     /// any deposit or withdrawal requests.
     #[error("the BitcoinPreSignRequest object does not contain deposit or withdrawal requests")]
     PreSignContainsNoRequests,
 
+    /// Indicates that the BitcoinPreSignRequest object contains deposit
+    /// requests while deposits are paused on this signer.
+    #[error("the BitcoinPreSignRequest object contains deposit requests while deposits are paused")]
+    PreSignContainsPausedDeposits,
+
+    /// Indicates that the BitcoinPreSignRequest object contains withdrawal
+    /// requests while withdrawals are paused on this signer.
+    #[error(
+        "the BitcoinPreSignRequest object contains withdrawal requests while withdrawals are paused"
+    )]
+    PreSignContainsPausedWithdrawals,
+
     /// Indicates that we tried to create an UnsignedTransaction object
     /// without any deposit or withdrawal requests.
     #[error("the UnsignedTransaction must contain deposit or withdrawal requests")]
@@ -760,6 +1070,11 @@ pub enum Error {
     #[error("the fee rate in the BitcoinPreSignRequest object is not greater than zero: {0}")]
     PreSignInvalidFeeRate(f64),
 
+    /// Indicates that a sweep transaction template version is unknown or
+    /// not accepted by this signer.
+    #[error("the sweep transaction template version is not supported: {0}")]
+    UnsupportedSweepTemplateVersion(u32),
+
     /// Error when deposit requests would exceed sBTC supply cap
     #[error(
         "total deposit amount ({total_amount} sats) would exceed sBTC supply cap (current max mintable is {max_mintable} sats)"
@@ -779,12 +1094,60 @@ pub enum Error {
     #[error("sbtc transaction op return format error")]
     SbtcTxOpReturnFormatError,
 
+    /// The payment to the signers cannot be refunded because its inputs
+    /// were not all locked by the same scriptPubKey, so we do not know
+    /// who sent it.
+    #[error("the sender of the payment to the signers at {0} is unknown")]
+    RefundSenderUnknown(bitcoin::OutPoint),
+
+    /// The payment to the signers cannot be refunded with the given
+    /// aggregate key, because it is locked by a different scriptPubKey.
+    #[error("the payment to the signers at {0} is not locked by the aggregate key {1}")]
+    RefundAggregateKeyMismatch(bitcoin::OutPoint, secp256k1::XOnlyPublicKey),
+
+    /// The payment to the signers is too small to pay for the fee of its
+    /// refund and still leave a non-dust output.
+    #[error(
+        "the payment to the signers at {outpoint} of {amount} sats cannot pay the refund fee of {fee} sats"
+    )]
+    RefundAmountTooLow {
+        /// The outpoint of the payment.
+        outpoint: bitcoin::OutPoint,
+        /// The amount of the payment.
+        amount: u64,
+        /// The fee of the refund transaction.
+        fee: u64,
+    },
+
+    /// The signers' UTXO is too small to pay for the fee of the emergency
+    /// recovery sweep and still leave a non-dust output.
+    #[error(
+        "the signers' UTXO at {outpoint} of {amount} sats cannot pay the recovery fee of {fee} sats"
+    )]
+    EmergencyRecoveryAmountTooLow {
+        /// The outpoint of the signers' UTXO.
+        outpoint: bitcoin::OutPoint,
+        /// The amount of the signers' UTXO.
+        amount: u64,
+        /// The fee of the recovery sweep.
+        fee: u64,
+    },
+
+    /// Bitcoin-core reports that the signers' UTXO, as recorded in the
+    /// database, has already been spent in a confirmed transaction.
+    #[error("the signers' UTXO at {0} has already been spent on bitcoin")]
+    SignerUtxoSpent(bitcoin::OutPoint),
+
     /// Error when withdrawal requests would exceed sBTC's rolling withdrawal caps
     #[error("total withdrawal amounts ({amounts}) exceeds rolling caps ({cap} over
             {cap_blocks}) with the currently withdrawn total {withdrawn_total})",
             amounts = .0.amounts, cap = .0.cap, cap_blocks = .0.cap_blocks, withdrawn_total = .0.withdrawn_total)]
     ExceedsWithdrawalCap(WithdrawalCapContext),
 
+    /// Indicates that a fee rate is negative or not a finite number.
+    #[error("the fee rate is negative or not finite: {0}")]
+    InvalidFeeRate(f64),
+
     /// An error was raised by the in-memory database.
     #[cfg(any(test, feature = "testing"))]
     #[error("In-memory database error: {0}")]
@@ -809,9 +1172,435 @@ impl From<std::convert::Infallible> for Error {
     }
 }
 
+/// The part of the signer, or the system that it talks to, that an
+/// [`Error`] comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum ErrorCategory {
+    /// A request, message or transaction failed validation.
+    Validation,
+    /// Talking to other signers or to other services failed.
+    Network,
+    /// Reading from or writing to the database failed, or the database
+    /// is missing data that the signer needs.
+    Storage,
+    /// Talking to bitcoin-core, or working with bitcoin blocks and
+    /// transactions, failed.
+    Bitcoin,
+    /// Talking to the stacks node, or working with stacks blocks and
+    /// transactions, failed.
+    Stacks,
+    /// Talking to Emily failed.
+    Emily,
+    /// Running DKG or a signing round with WSTS failed.
+    Wsts,
+    /// An error in the signer itself, such as an invalid configuration or
+    /// a failure to encode or decode data.
+    Internal,
+}
+
+impl ErrorCategory {
+    /// The error codes of each category are numbered from the base code of
+    /// the category, so the category of an error can be read off of the
+    /// thousands digit of its code.
+    pub const fn base_code(&self) -> u16 {
+        match self {
+            ErrorCategory::Validation => 1000,
+            ErrorCategory::Network => 2000,
+            ErrorCategory::Storage => 3000,
+            ErrorCategory::Bitcoin => 4000,
+            ErrorCategory::Stacks => 5000,
+            ErrorCategory::Emily => 6000,
+            ErrorCategory::Wsts => 7000,
+            ErrorCategory::Internal => 9000,
+        }
+    }
+}
+
+/// A stable number that identifies the kind of an [`Error`], for use in
+/// logs, metrics and alerts. Codes are displayed like `E4012`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ErrorCode(u16);
+
+impl ErrorCode {
+    /// The number of the error code.
+    pub fn as_u16(&self) -> u16 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "E{:04}", self.0)
+    }
+}
+
+/// How serious an [`Error`] is, from lowest to highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The error is expected to happen now and then, and resolves itself.
+    Warning,
+    /// The signer could not do some of its work.
+    Error,
+    /// The signer may be misconfigured, or may be under attack.
+    Critical,
+}
+
 impl Error {
     /// Convert a coordinator error to an `error::Error`
     pub fn wsts_coordinator(err: wsts::state_machine::coordinator::Error) -> Self {
         Error::WstsCoordinator(Box::new(err))
     }
+
+    /// The code of the error.
+    pub fn code(&self) -> ErrorCode {
+        let (category, number) = self.classify();
+        ErrorCode(category.base_code() + number)
+    }
+
+    /// The category of the error.
+    pub fn category(&self) -> ErrorCategory {
+        self.classify().0
+    }
+
+    /// The category of the error and its number within the category.
+    fn classify(&self) -> (ErrorCategory, u16) {
+        match self {
+            Error::BitcoinValidation { .. } => (ErrorCategory::Validation, 1),
+            Error::InvalidSigHash { .. } => (ErrorCategory::Validation, 2),
+            Error::UnknownSigHash { .. } => (ErrorCategory::Validation, 3),
+            Error::PrevoutSpendConflict { .. } => (ErrorCategory::Validation, 4),
+            Error::ConflictingSigHash { .. } => (ErrorCategory::Validation, 5),
+            Error::CoordinatorAttestationMismatch { .. } => (ErrorCategory::Validation, 6),
+            Error::MalformedCoordinatorAttestation { .. } => (ErrorCategory::Validation, 7),
+            Error::InvalidAmount { .. } => (ErrorCategory::Validation, 8),
+            Error::DepositValidation { .. } => (ErrorCategory::Validation, 9),
+            Error::DepositsValidation { .. } => (ErrorCategory::Validation, 10),
+            Error::InvalidAggregateKey { .. } => (ErrorCategory::Validation, 11),
+            Error::DisabledLockTime { .. } => (ErrorCategory::Validation, 12),
+            Error::InvalidPublicKey { .. } => (ErrorCategory::Validation, 13),
+            Error::InvalidXOnlyPublicKey { .. } => (ErrorCategory::Validation, 14),
+            Error::InvalidPublicKeyTweak { .. } => (ErrorCategory::Validation, 15),
+            Error::InvalidPublicKeyTweakCheck { .. } => (ErrorCategory::Validation, 16),
+            Error::InvalidPrivateKey { .. } => (ErrorCategory::Validation, 17),
+            Error::InvalidPrivateKeyLength { .. } => (ErrorCategory::Validation, 18),
+            Error::InvalidEcdsaSignatureBytes { .. } => (ErrorCategory::Validation, 19),
+            Error::InvalidRecoverableSignatureBytes { .. } => (ErrorCategory::Validation, 20),
+            Error::InvalidRecoverableSignature { .. } => (ErrorCategory::Validation, 21),
+            Error::InvalidPresignRequest { .. } => (ErrorCategory::Validation, 22),
+            Error::InvalidWalletDefinition { .. } => (ErrorCategory::Validation, 23),
+            Error::SignRequestRefused { .. } => (ErrorCategory::Validation, 24),
+            Error::StacksRequestAlreadySigned { .. } => (ErrorCategory::Validation, 25),
+            Error::StaleStacksSignRequest { .. } => (ErrorCategory::Validation, 26),
+            Error::PublicKeyMismatch { .. } => (ErrorCategory::Validation, 27),
+            Error::InvalidSignature { .. } => (ErrorCategory::Validation, 28),
+            Error::WrongP2PTopic { .. } => (ErrorCategory::Validation, 29),
+            Error::InvalidEcdsaSignature { .. } => (ErrorCategory::Validation, 30),
+            Error::SignerCoordinatorTxidMismatch { .. } => (ErrorCategory::Validation, 31),
+            Error::RequiredProtobufFieldMissing { .. } => (ErrorCategory::Validation, 32),
+            Error::RotateKeysValidation { .. } => (ErrorCategory::Validation, 33),
+            Error::UnknownPublicKey { .. } => (ErrorCategory::Validation, 34),
+            Error::UnknownAggregateKey { .. } => (ErrorCategory::Validation, 35),
+            Error::WithdrawalAcceptValidation { .. } => (ErrorCategory::Validation, 36),
+            Error::WithdrawalsAcceptValidation { .. } => (ErrorCategory::Validation, 37),
+            Error::WithdrawalRejectValidation { .. } => (ErrorCategory::Validation, 38),
+            Error::ValidationSignerSet { .. } => (ErrorCategory::Validation, 39),
+            Error::NotChainTipCoordinator { .. } => (ErrorCategory::Validation, 40),
+            Error::DuplicateRequests { .. } => (ErrorCategory::Validation, 41),
+            Error::InvalidDepositQuorumCertificate { .. } => (ErrorCategory::Validation, 42),
+            Error::InvalidLimitOverride { .. } => (ErrorCategory::Validation, 43),
+            Error::LimitOverrideVoterNotInSignerSet { .. } => (ErrorCategory::Validation, 44),
+            Error::PreSignContainsNoRequests { .. } => (ErrorCategory::Validation, 45),
+            Error::PreSignContainsPausedDeposits { .. } => (ErrorCategory::Validation, 46),
+            Error::PreSignContainsPausedWithdrawals { .. } => (ErrorCategory::Validation, 47),
+            Error::PreSignInvalidFeeRate { .. } => (ErrorCategory::Validation, 48),
+            Error::ExceedsSbtcSupplyCap { .. } => (ErrorCategory::Validation, 49),
+            Error::SbtcTxMalformed { .. } => (ErrorCategory::Validation, 50),
+            Error::SbtcTxOpReturnFormatError { .. } => (ErrorCategory::Validation, 51),
+            Error::ExceedsWithdrawalCap { .. } => (ErrorCategory::Validation, 52),
+            Error::InvalidFeeRate { .. } => (ErrorCategory::Validation, 53),
+            Error::UnsupportedSweepTemplateVersion { .. } => (ErrorCategory::Validation, 54),
+            Error::KeyEndorsementSignerNotInSignerSet { .. } => (ErrorCategory::Validation, 55),
+            Error::InvalidKeyEndorsement { .. } => (ErrorCategory::Validation, 56),
+            Error::SignerKeyRotationInProgress { .. } => (ErrorCategory::Validation, 57),
+            Error::EmergencyRecoveryNotConfigured { .. } => (ErrorCategory::Validation, 58),
+            Error::EmergencyRecoveryScriptMismatch { .. } => (ErrorCategory::Validation, 59),
+            Error::EmergencyRecoveryApproverNotInSignerSet { .. } => {
+                (ErrorCategory::Validation, 60)
+            }
+            Error::InvalidEmergencyRecoveryRequest { .. } => (ErrorCategory::Validation, 61),
+            Error::MissingDepositQuorumCertificate { .. } => (ErrorCategory::Validation, 62),
+            Error::EmergencyRecoveryMessageOutOfWindow { .. } => (ErrorCategory::Validation, 63),
+            Error::BlocklistClient { .. } => (ErrorCategory::Network, 1),
+            Error::SignerSwarm { .. } => (ErrorCategory::Network, 2),
+            Error::GrpcServer { .. } => (ErrorCategory::Network, 3),
+            Error::TokioIo { .. } => (ErrorCategory::Network, 4),
+            Error::SendMessage { .. } => (ErrorCategory::Network, 5),
+            Error::Reqwest { .. } => (ErrorCategory::Network, 6),
+            Error::MissingSweepTransaction { .. } => (ErrorCategory::Storage, 1),
+            Error::MissingDepositRequest { .. } => (ErrorCategory::Storage, 2),
+            Error::ConversionDatabaseInt { .. } => (ErrorCategory::Storage, 3),
+            Error::SqlxQuery { .. } => (ErrorCategory::Storage, 4),
+            Error::SqlxConnect { .. } => (ErrorCategory::Storage, 5),
+            Error::SqlxMigrate { .. } => (ErrorCategory::Storage, 6),
+            Error::SqlxBeginTransaction { .. } => (ErrorCategory::Storage, 7),
+            Error::SqlxCommitTransaction { .. } => (ErrorCategory::Storage, 8),
+            Error::SqlxRollbackTransaction { .. } => (ErrorCategory::Storage, 9),
+            Error::SqlxAcquireConnection { .. } => (ErrorCategory::Storage, 10),
+            Error::ReadSqlMigration { .. } => (ErrorCategory::Storage, 11),
+            Error::MissingBitcoinBlock { .. } => (ErrorCategory::Storage, 12),
+            Error::MissingBlock { .. } => (ErrorCategory::Storage, 13),
+            Error::MissingDkgShares { .. } => (ErrorCategory::Storage, 14),
+            Error::MissingPublicKey { .. } => (ErrorCategory::Storage, 15),
+            Error::MissingKeyRotation { .. } => (ErrorCategory::Storage, 16),
+            Error::MissingSignerUtxo { .. } => (ErrorCategory::Storage, 17),
+            Error::NoDkgShares { .. } => (ErrorCategory::Storage, 18),
+            Error::NoKeyRotationEvent { .. } => (ErrorCategory::Storage, 19),
+            Error::NoVerifiedDkgShares { .. } => (ErrorCategory::Storage, 20),
+            Error::TooManySignerUtxos { .. } => (ErrorCategory::Storage, 21),
+            Error::NoChainTip { .. } => (ErrorCategory::Storage, 22),
+            Error::UnknownBitcoinBlock { .. } => (ErrorCategory::Storage, 23),
+            Error::NoStacksChainTip { .. } => (ErrorCategory::Storage, 24),
+            #[cfg(any(test, feature = "testing"))]
+            Error::InMemoryDatabase { .. } => (ErrorCategory::Storage, 25),
+            Error::StateSnapshotChecksumMismatch { .. } => (ErrorCategory::Storage, 26),
+            Error::UnsupportedStateSnapshotVersion { .. } => (ErrorCategory::Storage, 27),
+            Error::StateSnapshotConfigMismatch { .. } => (ErrorCategory::Storage, 28),
+            Error::StateSnapshotTargetNotEmpty { .. } => (ErrorCategory::Storage, 29),
+            Error::OpReturnSizeLimitExceeded { .. } => (ErrorCategory::Bitcoin, 1),
+            Error::BitcoinIo { .. } => (ErrorCategory::Bitcoin, 2),
+            Error::BitcoinConsensus { .. } => (ErrorCategory::Bitcoin, 3),
+            Error::BitcoinCoreZmqConnectTimeout { .. } => (ErrorCategory::Bitcoin, 4),
+            Error::BitcoinCoreZmq { .. } => (ErrorCategory::Bitcoin, 5),
+            Error::BitcoinCoreGetTxOut { .. } => (ErrorCategory::Bitcoin, 6),
+            Error::BitcoinCoreGetMempoolDescendants { .. } => (ErrorCategory::Bitcoin, 7),
+            Error::BitcoinCoreGetTxSpendingPrevout { .. } => (ErrorCategory::Bitcoin, 8),
+            Error::BitcoinCoreGetBlock { .. } => (ErrorCategory::Bitcoin, 9),
+            Error::BitcoinCoreGetBlockHeader { .. } => (ErrorCategory::Bitcoin, 10),
+            Error::BitcoinHeaderHashMismatch { .. } => (ErrorCategory::Bitcoin, 11),
+            Error::BitcoinHeaderTargetAboveLimit { .. } => (ErrorCategory::Bitcoin, 12),
+            Error::BitcoinHeaderInsufficientWork { .. } => (ErrorCategory::Bitcoin, 13),
+            Error::BitcoinHeaderDiscontinuity { .. } => (ErrorCategory::Bitcoin, 14),
+            Error::BitcoinHeaderInvalidTime { .. } => (ErrorCategory::Bitcoin, 15),
+            Error::BitcoinCoreUnknownBlockHeader { .. } => (ErrorCategory::Bitcoin, 16),
+            Error::BitcoinCoreGetTransaction { .. } => (ErrorCategory::Bitcoin, 17),
+            Error::BitcoinCoreRpcClient { .. } => (ErrorCategory::Bitcoin, 18),
+            Error::BitcoinTxMissing { .. } => (ErrorCategory::Bitcoin, 19),
+            Error::BitcoinTxCoinbase { .. } => (ErrorCategory::Bitcoin, 20),
+            Error::BitcoinTxMissingData { .. } => (ErrorCategory::Bitcoin, 21),
+            Error::BitcoinTxNoOutputs { .. } => (ErrorCategory::Bitcoin, 22),
+            Error::BitcoinTxInvalidData { .. } => (ErrorCategory::Bitcoin, 23),
+            Error::BitcoinTxMissingFields { .. } => (ErrorCategory::Bitcoin, 24),
+            Error::BitcoinPushBytes { .. } => (ErrorCategory::Bitcoin, 25),
+            Error::SigHashConversion { .. } => (ErrorCategory::Bitcoin, 26),
+            Error::EstimateSmartFee { .. } => (ErrorCategory::Bitcoin, 27),
+            Error::EstimateSmartFeeResponse { .. } => (ErrorCategory::Bitcoin, 28),
+            Error::FallbackClient { .. } => (ErrorCategory::Bitcoin, 29),
+            Error::BitcoinCoreRpc { .. } => (ErrorCategory::Bitcoin, 30),
+            Error::BitcoinPackageRejected { .. } => (ErrorCategory::Bitcoin, 31),
+            Error::OldFeeEstimate { .. } => (ErrorCategory::Bitcoin, 32),
+            Error::NoGoodFeeEstimates { .. } => (ErrorCategory::Bitcoin, 33),
+            Error::DecodeBitcoinBlock { .. } => (ErrorCategory::Bitcoin, 34),
+            Error::DecodeBitcoinTransaction { .. } => (ErrorCategory::Bitcoin, 35),
+            Error::OutPointMissing { .. } => (ErrorCategory::Bitcoin, 36),
+            Error::VoutMissing { .. } => (ErrorCategory::Bitcoin, 37),
+            Error::Taproot { .. } => (ErrorCategory::Bitcoin, 38),
+            Error::PsbtConstruction { .. } => (ErrorCategory::Bitcoin, 39),
+            Error::PsbtExportWrite { .. } => (ErrorCategory::Bitcoin, 40),
+            Error::BitcoinCoreMissingBlock { .. } => (ErrorCategory::Bitcoin, 41),
+            Error::DepositBitcoinAddressFromScript { .. } => (ErrorCategory::Bitcoin, 42),
+            Error::WithdrawalBitcoinAddressFromScript { .. } => (ErrorCategory::Bitcoin, 43),
+            Error::DecodeHexScript { .. } => (ErrorCategory::Bitcoin, 44),
+            Error::DecodeHexTxid { .. } => (ErrorCategory::Bitcoin, 45),
+            Error::BitcoinNoRequests { .. } => (ErrorCategory::Bitcoin, 46),
+            Error::RefundSenderUnknown { .. } => (ErrorCategory::Bitcoin, 47),
+            Error::RefundAggregateKeyMismatch { .. } => (ErrorCategory::Bitcoin, 48),
+            Error::RefundAmountTooLow { .. } => (ErrorCategory::Bitcoin, 49),
+            Error::BitcoinCoreBlockPruned { .. } => (ErrorCategory::Bitcoin, 50),
+            Error::BitcoinCorePruneDepthTooShallow { .. } => (ErrorCategory::Bitcoin, 51),
+            Error::EsploraRequest { .. } => (ErrorCategory::Bitcoin, 52),
+            Error::EsploraResponse { .. } => (ErrorCategory::Bitcoin, 53),
+            Error::UnsupportedDescriptor { .. } => (ErrorCategory::Bitcoin, 54),
+            Error::EmergencyRecoveryAmountTooLow { .. } => (ErrorCategory::Bitcoin, 55),
+            Error::SignerUtxoSpent(_) => (ErrorCategory::Bitcoin, 56),
+            Error::BitcoinTransactionRejected { .. } => (ErrorCategory::Bitcoin, 57),
+            Error::ScanTxOutSetTask { .. } => (ErrorCategory::Bitcoin, 58),
+            Error::MissingNakamotoStartHeight { .. } => (ErrorCategory::Stacks, 1),
+            Error::StacksBlockVerification { .. } => (ErrorCategory::Stacks, 2),
+            Error::EmptyStacksTenure { .. } => (ErrorCategory::Stacks, 3),
+            Error::GetTenureRawMismatch { .. } => (ErrorCategory::Stacks, 4),
+            Error::SbtcLib { .. } => (ErrorCategory::Stacks, 5),
+            Error::ParsePrincipalData { .. } => (ErrorCategory::Stacks, 6),
+            Error::ClarityValueSerialization { .. } => (ErrorCategory::Stacks, 7),
+            Error::DecodeNakamotoBlock { .. } => (ErrorCategory::Stacks, 8),
+            Error::DecodeNakamotoTenure { .. } => (ErrorCategory::Stacks, 9),
+            Error::StacksCodec { .. } => (ErrorCategory::Stacks, 10),
+            Error::StacksMultiSig { .. } => (ErrorCategory::Stacks, 11),
+            Error::StacksApiConfig { .. } => (ErrorCategory::Stacks, 12),
+            Error::StacksNodeResponse { .. } => (ErrorCategory::Stacks, 13),
+            Error::StacksNodeRequest { .. } => (ErrorCategory::Stacks, 14),
+            Error::StacksTxRejection { .. } => (ErrorCategory::Stacks, 15),
+            Error::StacksFeeLimitExceeded { .. } => (ErrorCategory::Stacks, 16),
+            Error::UnexpectedStacksResponse { .. } => (ErrorCategory::Stacks, 17),
+            Error::InvalidStacksResponse { .. } => (ErrorCategory::Stacks, 18),
+            Error::StacksTxResubmissionSkipped { .. } => (ErrorCategory::Stacks, 19),
+            Error::StacksSignRequestQueueFull { .. } => (ErrorCategory::Stacks, 20),
+            Error::ContractAlreadyDeployed { .. } => (ErrorCategory::Stacks, 21),
+            Error::MissingDeployerPrivateKey { .. } => (ErrorCategory::Stacks, 22),
+            Error::ContractUpgradesDisabled { .. } => (ErrorCategory::Stacks, 23),
+            Error::ContractVersionMismatch { .. } => (ErrorCategory::Stacks, 24),
+            Error::EmilyApi { .. } => (ErrorCategory::Emily, 1),
+            Error::IdPackSegmenter { .. } => (ErrorCategory::Wsts, 1),
+            Error::IdPackDecode { .. } => (ErrorCategory::Wsts, 2),
+            Error::DkgVerification { .. } => (ErrorCategory::Wsts, 3),
+            Error::UnexpectedStateMachineId { .. } => (ErrorCategory::Wsts, 4),
+            Error::InvalidSigningOperation { .. } => (ErrorCategory::Wsts, 5),
+            Error::DkgVerificationEnded { .. } => (ErrorCategory::Wsts, 6),
+            Error::DkgVerificationFailed { .. } => (ErrorCategory::Wsts, 7),
+            Error::DkgVerificationWindowElapsed { .. } => (ErrorCategory::Wsts, 8),
+            Error::AggregateKeyMismatch { .. } => (ErrorCategory::Wsts, 9),
+            Error::MissingAggregateKey { .. } => (ErrorCategory::Wsts, 10),
+            Error::SignatureTimeout { .. } => (ErrorCategory::Wsts, 11),
+            Error::KeyError { .. } => (ErrorCategory::Wsts, 12),
+            Error::MissingStateMachine { .. } => (ErrorCategory::Wsts, 13),
+            Error::DkgHasAlreadyRun { .. } => (ErrorCategory::Wsts, 14),
+            Error::WstsEncrypt { .. } => (ErrorCategory::Wsts, 15),
+            Error::WstsDecrypt { .. } => (ErrorCategory::Wsts, 16),
+            Error::Wsts { .. } => (ErrorCategory::Wsts, 17),
+            Error::WstsCoordinator { .. } => (ErrorCategory::Wsts, 18),
+            Error::CoordinatorTimeout { .. } => (ErrorCategory::Wsts, 19),
+            Error::UnexpectedOperationResult { .. } => (ErrorCategory::Wsts, 20),
+            Error::DecodeProtobuf { .. } => (ErrorCategory::Internal, 1),
+            Error::ProtobufTagCodec { .. } => (ErrorCategory::Internal, 2),
+            Error::DivideByZero { .. } => (ErrorCategory::Internal, 3),
+            Error::ArithmeticOverflow { .. } => (ErrorCategory::Internal, 4),
+            Error::SignerShutdown { .. } => (ErrorCategory::Internal, 5),
+            Error::EventLoopCrashLoop { .. } => (ErrorCategory::Internal, 6),
+            Error::ChannelReceive { .. } => (ErrorCategory::Internal, 7),
+            Error::JsonSerialize { .. } => (ErrorCategory::Internal, 8),
+            Error::PathJoin { .. } => (ErrorCategory::Internal, 9),
+            Error::InvalidUrl { .. } => (ErrorCategory::Internal, 10),
+            Error::ParseHexInt { .. } => (ErrorCategory::Internal, 11),
+            Error::PortRequired { .. } => (ErrorCategory::Internal, 12),
+            Error::DecodeHexBytes { .. } => (ErrorCategory::Internal, 13),
+            Error::SignerConfig { .. } => (ErrorCategory::Internal, 14),
+            Error::Codec { .. } => (ErrorCategory::Internal, 15),
+            Error::TypeConversion { .. } => (ErrorCategory::Internal, 16),
+            Error::InvalidConfiguration { .. } => (ErrorCategory::Internal, 17),
+            Error::ObserverDropped { .. } => (ErrorCategory::Internal, 18),
+            Error::InvalidEventLoopSetting { .. } => (ErrorCategory::Internal, 21),
+            Error::PolicyPlugin { .. } => (ErrorCategory::Internal, 22),
+            Error::PolicyPluginTimeout { .. } => (ErrorCategory::Internal, 23),
+            #[cfg(test)]
+            Error::Dummy { .. } => (ErrorCategory::Internal, 19),
+            #[cfg(any(test, feature = "testing"))]
+            Error::TestUtility { .. } => (ErrorCategory::Internal, 20),
+        }
+    }
+
+    /// Whether the operation that failed with this error may succeed if
+    /// it is tried again later, because the error comes from a service
+    /// that is temporarily unavailable or from a timeout.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Error::BitcoinCoreZmqConnectTimeout { .. }
+                | Error::BitcoinCoreZmq { .. }
+                | Error::BitcoinCoreGetTxOut { .. }
+                | Error::BitcoinCoreGetMempoolDescendants { .. }
+                | Error::BitcoinCoreGetTxSpendingPrevout { .. }
+                | Error::EmilyApi { .. }
+                | Error::BlocklistClient { .. }
+                | Error::PolicyPluginTimeout { .. }
+                | Error::BitcoinCoreGetBlock { .. }
+                | Error::BitcoinCoreGetBlockHeader { .. }
+                | Error::BitcoinCoreGetTransaction { .. }
+                | Error::BitcoinCoreRpcClient { .. }
+                | Error::EstimateSmartFee { .. }
+                | Error::FallbackClient { .. }
+                | Error::BitcoinCoreRpc { .. }
+                | Error::EsploraRequest { .. }
+                | Error::EsploraResponse { .. }
+                | Error::TokioIo { .. }
+                | Error::OldFeeEstimate { .. }
+                | Error::NoGoodFeeEstimates { .. }
+                | Error::SendMessage { .. }
+                | Error::Reqwest { .. }
+                | Error::SqlxConnect { .. }
+                | Error::SqlxBeginTransaction { .. }
+                | Error::SqlxCommitTransaction { .. }
+                | Error::SqlxRollbackTransaction { .. }
+                | Error::SqlxAcquireConnection { .. }
+                | Error::SignatureTimeout { .. }
+                | Error::StacksNodeResponse { .. }
+                | Error::StacksNodeRequest { .. }
+                | Error::StacksSignRequestQueueFull { .. }
+                | Error::MissingBitcoinBlock { .. }
+                | Error::NoChainTip { .. }
+                | Error::NoStacksChainTip { .. }
+                | Error::CoordinatorTimeout { .. }
+        )
+    }
+
+    /// How serious the error is for the operator of the signer.
+    ///
+    /// Errors that may mean that the signer is misconfigured, or that
+    /// another signer or bitcoin-core is misbehaving, are critical.
+    /// Otherwise, failed validations and retryable errors are warnings,
+    /// and everything else is an error.
+    pub fn severity(&self) -> Severity {
+        let is_critical = matches!(
+            self,
+            Error::DkgVerificationFailed { .. }
+                | Error::AggregateKeyMismatch { .. }
+                | Error::BitcoinHeaderHashMismatch { .. }
+                | Error::BitcoinHeaderTargetAboveLimit { .. }
+                | Error::BitcoinHeaderInsufficientWork { .. }
+                | Error::BitcoinHeaderDiscontinuity { .. }
+                | Error::BitcoinHeaderInvalidTime { .. }
+                | Error::StacksBlockVerification { .. }
+                | Error::ConflictingSigHash { .. }
+                | Error::CoordinatorAttestationMismatch { .. }
+                | Error::EventLoopCrashLoop { .. }
+                | Error::InvalidEventLoopSetting { .. }
+                | Error::SignerConfig { .. }
+                | Error::SqlxMigrate { .. }
+                | Error::ReadSqlMigration { .. }
+                | Error::TooManySignerUtxos { .. }
+                | Error::InvalidConfiguration { .. }
+                | Error::SignerCoordinatorTxidMismatch { .. }
+                | Error::ObserverDropped { .. }
+        );
+        if is_critical {
+            Severity::Critical
+        } else if self.is_retryable() || self.category() == ErrorCategory::Validation {
+            Severity::Warning
+        } else {
+            Severity::Error
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test_case::test_case(Error::InvalidLimitOverride("expired"), "E1043", false, Severity::Warning; "failed validation")]
+    #[test_case::test_case(Error::NoChainTip, "E3022", true, Severity::Warning; "transient storage error")]
+    #[test_case::test_case(Error::NoGoodFeeEstimates, "E4033", true, Severity::Warning; "transient bitcoin error")]
+    #[test_case::test_case(Error::DivideByZero, "E9003", false, Severity::Error; "internal error")]
+    #[test_case::test_case(Error::EventLoopCrashLoop("test"), "E9006", false, Severity::Critical; "critical error")]
+    fn errors_are_classified(error: Error, code: &str, is_retryable: bool, severity: Severity) {
+        assert_eq!(error.code().to_string(), code);
+        assert_eq!(error.is_retryable(), is_retryable);
+        assert_eq!(error.severity(), severity);
+    }
+
+    #[test]
+    fn error_codes_start_with_the_base_code_of_their_category() {
+        let error = Error::SignerShutdown;
+        let base_code = error.category().base_code();
+        assert_eq!(error.code().as_u16() / 1000, base_code / 1000);
+        assert_eq!(error.category(), ErrorCategory::Internal);
+    }
 }
@@ -0,0 +1,390 @@
+//! Import of historical bridge data into the signer's database.
+//!
+//! A signer learns about deposits and sweeps by following bitcoin blocks
+//! with bitcoin-core, and it backfills the blocks that it missed by
+//! fetching them from bitcoin-core. A new signer that runs against a
+//! pruned bitcoin-core node cannot backfill past the pruning height, so
+//! the `import` command of the signer binary pre-populates its database
+//! with an export of the historical data instead. The export can come
+//! from another signer or from an indexer, and is a JSON document in the
+//! format of [`HistoricalData`].
+//!
+//! Imported data is validated the same way as data fetched from the
+//! blockchains: deposit transactions must match their deposit and reclaim
+//! scripts, and sweeps are recognized by the scriptPubKeys of the signers
+//! that are in the database, along with the bootstrap aggregate key.
+
+use bitcoin::BlockHash;
+use bitcoin::OutPoint;
+use bitcoin::ScriptBuf;
+use sbtc::deposits::CreateDepositRequest;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::bitcoin::rpc::BitcoinTxInfo;
+use crate::block_observer::Deposit;
+use crate::block_observer::extract_sbtc_transactions;
+use crate::error::Error;
+use crate::keys::PublicKey;
+use crate::storage::DbRead;
+use crate::storage::DbWrite;
+use crate::storage::Transactable;
+use crate::storage::TransactionHandle as _;
+use crate::storage::model;
+use crate::storage::model::StacksBlockHash;
+use crate::storage::model::StacksTxId;
+
+/// Historical bitcoin blocks, stacks blocks, deposits, sweeps and
+/// withdrawals to import into the signer's database.
+///
+/// Every field may be omitted. Bitcoin transactions use the format of the
+/// transactions in the response to bitcoin-core's `getblock` RPC with
+/// verbosity 3, so they include the previous outputs of their inputs.
+/// Stacks block and transaction IDs are hex encoded.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HistoricalData {
+    /// The bitcoin blocks that confirm the imported transactions, along
+    /// with the blocks that connect them to the chain tip of the signer.
+    #[serde(default)]
+    pub bitcoin_blocks: Vec<ImportedBitcoinBlock>,
+    /// The stacks blocks that include the imported withdrawal requests.
+    #[serde(default)]
+    pub stacks_blocks: Vec<ImportedStacksBlock>,
+    /// The confirmed deposit requests.
+    #[serde(default)]
+    pub deposits: Vec<ImportedDeposit>,
+    /// The confirmed transactions of the signers, in the order that they
+    /// were confirmed, so that each one spends outputs that are known
+    /// from the ones before it.
+    #[serde(default)]
+    pub sweeps: Vec<ImportedTransaction>,
+    /// The withdrawal requests.
+    #[serde(default)]
+    pub withdrawals: Vec<ImportedWithdrawal>,
+}
+
+/// A historical bitcoin block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportedBitcoinBlock {
+    /// The hash of the block.
+    pub block_hash: BlockHash,
+    /// The height of the block.
+    pub block_height: u64,
+    /// The hash of the parent of the block.
+    pub parent_hash: BlockHash,
+}
+
+/// A historical stacks block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportedStacksBlock {
+    /// The hex encoded ID of the block.
+    pub block_hash: String,
+    /// The height of the block.
+    pub block_height: u64,
+    /// The hex encoded ID of the parent of the block.
+    pub parent_hash: String,
+    /// The bitcoin block that the stacks block is anchored to.
+    pub bitcoin_anchor: BlockHash,
+}
+
+/// A confirmed deposit request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportedDeposit {
+    /// The bitcoin block that confirmed the deposit transaction.
+    pub block_hash: BlockHash,
+    /// The deposit transaction.
+    pub tx: BitcoinTxInfo,
+    /// The index of the deposit output in the transaction.
+    pub output_index: u32,
+    /// The deposit script of the deposit output.
+    pub deposit_script: ScriptBuf,
+    /// The reclaim script of the deposit output.
+    pub reclaim_script: ScriptBuf,
+}
+
+/// A confirmed bitcoin transaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportedTransaction {
+    /// The bitcoin block that confirmed the transaction.
+    pub block_hash: BlockHash,
+    /// The transaction.
+    pub tx: BitcoinTxInfo,
+}
+
+/// A withdrawal request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportedWithdrawal {
+    /// The ID of the withdrawal request.
+    pub request_id: u64,
+    /// The hex encoded ID of the stacks transaction that created the
+    /// request.
+    pub txid: String,
+    /// The hex encoded ID of the stacks block that includes the
+    /// transaction.
+    pub block_hash: String,
+    /// The scriptPubKey that should receive the withdrawn bitcoin.
+    pub recipient: ScriptBuf,
+    /// The amount to withdraw, in sats.
+    pub amount: u64,
+    /// The maximum portion of the amount that may be used for fees.
+    pub max_fee: u64,
+    /// The stacks principal that initiated the request.
+    pub sender_address: String,
+    /// The height of the bitcoin chain tip when the request was created.
+    pub bitcoin_block_height: u64,
+}
+
+/// The number of records of each kind that were imported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// The number of bitcoin blocks.
+    pub bitcoin_blocks: usize,
+    /// The number of stacks blocks.
+    pub stacks_blocks: usize,
+    /// The number of deposit requests.
+    pub deposits: usize,
+    /// The number of transactions that were checked for sweeps.
+    pub sweeps: usize,
+    /// The number of withdrawal requests.
+    pub withdrawals: usize,
+}
+
+impl std::fmt::Display for ImportSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} bitcoin blocks, {} stacks blocks, {} deposits, {} sweeps, {} withdrawals",
+            self.bitcoin_blocks, self.stacks_blocks, self.deposits, self.sweeps, self.withdrawals
+        )
+    }
+}
+
+/// Parse a hex encoded stacks block ID or transaction ID.
+pub(crate) fn parse_stacks_id(hex: &str) -> Result<[u8; 32], Error> {
+    let bytes = hex::decode(hex.trim_start_matches("0x")).map_err(Error::DecodeHexBytes)?;
+    bytes.try_into().map_err(|_| Error::TypeConversion)
+}
+
+impl TryFrom<&ImportedStacksBlock> for model::StacksBlock {
+    type Error = Error;
+    fn try_from(block: &ImportedStacksBlock) -> Result<Self, Self::Error> {
+        Ok(model::StacksBlock {
+            block_hash: StacksBlockHash::from(parse_stacks_id(&block.block_hash)?),
+            block_height: block.block_height.into(),
+            parent_hash: StacksBlockHash::from(parse_stacks_id(&block.parent_hash)?),
+            bitcoin_anchor: block.bitcoin_anchor.into(),
+        })
+    }
+}
+
+impl TryFrom<&ImportedWithdrawal> for model::WithdrawalRequest {
+    type Error = Error;
+    fn try_from(request: &ImportedWithdrawal) -> Result<Self, Self::Error> {
+        Ok(model::WithdrawalRequest {
+            request_id: request.request_id,
+            txid: StacksTxId::from(parse_stacks_id(&request.txid)?),
+            block_hash: StacksBlockHash::from(parse_stacks_id(&request.block_hash)?),
+            recipient: request.recipient.clone().into(),
+            amount: request.amount,
+            max_fee: request.max_fee,
+            sender_address: request.sender_address.parse()?,
+            bitcoin_block_height: request.bitcoin_block_height.into(),
+        })
+    }
+}
+
+impl ImportedDeposit {
+    /// Validate the deposit transaction against the deposit and reclaim
+    /// scripts, the same way that deposit requests from Emily are
+    /// validated.
+    fn validate(&self, is_mainnet: bool) -> Result<Deposit, Error> {
+        self.tx.validate()?;
+        let request = CreateDepositRequest {
+            outpoint: OutPoint::new(self.tx.compute_txid(), self.output_index),
+            deposit_script: self.deposit_script.clone(),
+            reclaim_script: self.reclaim_script.clone(),
+        };
+
+        Ok(Deposit {
+            info: request.validate_tx(&self.tx.tx, is_mainnet)?,
+            tx_info: self.tx.clone(),
+            block_hash: self.block_hash,
+        })
+    }
+}
+
+impl HistoricalData {
+    /// Write the historical data to the database in a single database
+    /// transaction, so that nothing is imported if any of it is invalid.
+    ///
+    /// The bootstrap aggregate key is used to recognize the first sweeps
+    /// of the signers, like it is when following bitcoin blocks.
+    pub async fn import<S>(
+        &self,
+        storage: &S,
+        bootstrap_aggregate_key: Option<PublicKey>,
+        is_mainnet: bool,
+    ) -> Result<ImportSummary, Error>
+    where
+        S: Transactable,
+    {
+        let db = storage.begin_transaction().await?;
+        let summary = self.write(&db, bootstrap_aggregate_key, is_mainnet).await;
+
+        match summary {
+            Ok(summary) => {
+                db.commit().await?;
+                Ok(summary)
+            }
+            Err(error) => {
+                db.rollback().await?;
+                Err(error)
+            }
+        }
+    }
+
+    async fn write<D>(
+        &self,
+        db: &D,
+        bootstrap_aggregate_key: Option<PublicKey>,
+        is_mainnet: bool,
+    ) -> Result<ImportSummary, Error>
+    where
+        D: DbRead + DbWrite,
+    {
+        // Parents are written before their children, and transactions
+        // reference the blocks that confirm them.
+        let mut bitcoin_blocks = self.bitcoin_blocks.clone();
+        bitcoin_blocks.sort_by_key(|block| block.block_height);
+        for block in bitcoin_blocks.iter() {
+            let block = model::BitcoinBlock {
+                block_hash: block.block_hash.into(),
+                block_height: block.block_height.into(),
+                parent_hash: block.parent_hash.into(),
+            };
+            db.write_bitcoin_block(&block).await?;
+        }
+
+        let stacks_blocks = self
+            .stacks_blocks
+            .iter()
+            .map(model::StacksBlock::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        db.write_stacks_block_headers(stacks_blocks).await?;
+
+        let mut deposit_requests = Vec::new();
+        let mut deposit_request_txs = Vec::new();
+        for deposit in self.deposits.iter() {
+            let deposit = deposit.validate(is_mainnet)?;
+            deposit_request_txs.push(model::BitcoinTxRef {
+                txid: deposit.tx_info.compute_txid().into(),
+                block_hash: deposit.block_hash.into(),
+            });
+            deposit_requests.push(model::DepositRequest::from(deposit));
+        }
+        db.write_bitcoin_transactions(deposit_request_txs).await?;
+        db.write_deposit_requests(deposit_requests).await?;
+
+        // Each sweep spends the signers' output of the one before it, so
+        // they are extracted one at a time in the given order.
+        for sweep in self.sweeps.iter() {
+            let txs = std::slice::from_ref(&sweep.tx);
+            extract_sbtc_transactions(db, bootstrap_aggregate_key, sweep.block_hash, txs).await?;
+        }
+
+        for withdrawal in self.withdrawals.iter() {
+            let request = model::WithdrawalRequest::try_from(withdrawal)?;
+            db.write_withdrawal_request(&request).await?;
+        }
+
+        Ok(ImportSummary {
+            bitcoin_blocks: self.bitcoin_blocks.len(),
+            stacks_blocks: self.stacks_blocks.len(),
+            deposits: self.deposits.len(),
+            sweeps: self.sweeps.len(),
+            withdrawals: self.withdrawals.len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hashes::Hash as _;
+    use fake::Fake as _;
+
+    use crate::storage::memory::Store;
+    use crate::testing::get_rng;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn deposits_are_imported_and_validated() {
+        let mut rng = get_rng();
+        let store = Store::new_shared();
+
+        let block = ImportedBitcoinBlock {
+            block_hash: BlockHash::from_byte_array([1; 32]),
+            block_height: 100,
+            parent_hash: BlockHash::from_byte_array([0; 32]),
+        };
+
+        let setup = sbtc::testing::deposits::tx_setup(300, 10_000, &[500_000]);
+        let deposit = ImportedDeposit {
+            block_hash: block.block_hash,
+            tx: setup.tx.clone().fake_with_rng(&mut rng),
+            output_index: 0,
+            deposit_script: setup.deposits[0].deposit_script(),
+            reclaim_script: setup.reclaims[0].reclaim_script(),
+        };
+
+        // The export goes through JSON on its way to the signer.
+        let data = HistoricalData {
+            bitcoin_blocks: vec![block],
+            deposits: vec![deposit.clone()],
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&data).unwrap();
+        let data: HistoricalData = serde_json::from_str(&json).unwrap();
+
+        let summary = data.import(&store, None, false).await.unwrap();
+        assert_eq!(summary.bitcoin_blocks, 1);
+        assert_eq!(summary.deposits, 1);
+
+        let txid = deposit.tx.compute_txid().into();
+        let request = store.get_deposit_request(&txid, 0).await.unwrap();
+        assert_eq!(request.unwrap().amount, 500_000);
+        let block_hash = block.block_hash.into();
+        assert!(
+            store
+                .get_bitcoin_block(&block_hash)
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        // A deposit that does not match its scripts is rejected, and
+        // nothing else in the export is imported.
+        let mut invalid = deposit;
+        invalid.reclaim_script = ScriptBuf::new();
+        let other_block = ImportedBitcoinBlock {
+            block_hash: BlockHash::from_byte_array([2; 32]),
+            block_height: 101,
+            parent_hash: block.block_hash,
+        };
+        let data = HistoricalData {
+            bitcoin_blocks: vec![other_block],
+            deposits: vec![invalid],
+            ..Default::default()
+        };
+        assert!(data.import(&store, None, false).await.is_err());
+
+        let block_hash = other_block.block_hash.into();
+        assert!(
+            store
+                .get_bitcoin_block(&block_hash)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
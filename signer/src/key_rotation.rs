@@ -0,0 +1,396 @@
+//! Guided rotation of the key of a signer by its operator.
+//!
+//! This is about the keypair that identifies a signer in the signing set
+//! and on the p2p network, not about the aggregate key of the signer set.
+//! An operator that replaces the key of their signer goes through the
+//! following steps, which are tracked in the [`SignerKeyRotation`] record
+//! of the signer:
+//!
+//! 1. The `key-rotation start` command of the signer binary generates the
+//!    new keypair, writes its private key to a file, and records the
+//!    rotation as [`Generated`](SignerKeyRotationStatus::Generated).
+//! 2. While the signer still runs with the old key, it broadcasts a
+//!    [`SignerKeyEndorsement`] of the new key on every bitcoin block,
+//!    signed with the old key, and the rotation is
+//!    [`Announced`](SignerKeyRotationStatus::Announced). The other signers
+//!    record the endorsements that they receive from members of the
+//!    signer set, and the `key-rotation status` command shows their
+//!    operators the signing set with the endorsed keys in place.
+//! 3. Every operator replaces the old key with the new one in their
+//!    `bootstrap_signing_set`, and the rotating operator also configures
+//!    the new private key. Once the signer runs with the new key and the
+//!    updated signing set, the rotation is
+//!    [`ConfigUpdated`](SignerKeyRotationStatus::ConfigUpdated).
+//! 4. The change of the signing set has the signers run DKG with the new
+//!    membership, followed by a rotate-keys contract call. Once the signer
+//!    set in the sBTC registry contract has the new key in place of the
+//!    old one, the old key is retired and the rotation is
+//!    [`Completed`](SignerKeyRotationStatus::Completed).
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use crate::config::SignerConfig;
+use crate::context::Context;
+use crate::error::Error;
+use crate::keys::PrivateKey;
+use crate::keys::PublicKey;
+use crate::message::SignerKeyEndorsement;
+use crate::storage::DbRead;
+use crate::storage::DbWrite;
+use crate::storage::model::BitcoinBlockHash;
+use crate::storage::model::KeyEndorsement;
+use crate::storage::model::SignerKeyRotation;
+use crate::storage::model::SignerKeyRotationStatus;
+
+/// Record the rotation of the configured key of this signer to the given
+/// new key. Fails if another rotation is in progress.
+pub async fn start(
+    storage: &(impl DbRead + DbWrite),
+    config: &SignerConfig,
+    new_private_key: &PrivateKey,
+) -> Result<SignerKeyRotation, Error> {
+    let latest = storage.get_latest_signer_key_rotation().await?;
+    if let Some(rotation) = latest.filter(|rotation| !rotation.status.is_terminal()) {
+        return Err(Error::SignerKeyRotationInProgress(rotation.new_public_key));
+    }
+
+    let rotation = SignerKeyRotation {
+        old_public_key: config.public_key(),
+        new_public_key: PublicKey::from_private_key(new_private_key),
+        status: SignerKeyRotationStatus::Generated,
+    };
+    storage.write_signer_key_rotation(&rotation).await?;
+
+    Ok(rotation)
+}
+
+/// Cancel the rotation of the key of this signer that is in progress.
+/// Returns the cancelled rotation, if there was one.
+pub async fn cancel(storage: &(impl DbRead + DbWrite)) -> Result<Option<SignerKeyRotation>, Error> {
+    let latest = storage.get_latest_signer_key_rotation().await?;
+    let Some(mut rotation) = latest.filter(|rotation| !rotation.status.is_terminal()) else {
+        return Ok(None);
+    };
+
+    rotation.status = SignerKeyRotationStatus::Cancelled;
+    storage
+        .set_signer_key_rotation_status(&rotation.new_public_key, rotation.status)
+        .await?;
+
+    Ok(Some(rotation))
+}
+
+/// Move the rotation of the key of this signer along, based on the key and
+/// signing set that the signer runs with and on the signer set in the
+/// sBTC registry contract.
+///
+/// Returns the endorsement of the new key that the signer should
+/// broadcast, which is the case while it still runs with the old key.
+pub async fn advance(ctx: &impl Context) -> Result<Option<SignerKeyEndorsement>, Error> {
+    let storage = ctx.get_storage_mut();
+    let Some(rotation) = storage.get_latest_signer_key_rotation().await? else {
+        return Ok(None);
+    };
+
+    let config = &ctx.config().signer;
+    let public_key = config.public_key();
+    let new_public_key = rotation.new_public_key;
+
+    let status = match rotation.status {
+        SignerKeyRotationStatus::Generated | SignerKeyRotationStatus::Announced
+            if public_key == rotation.old_public_key =>
+        {
+            if rotation.status == SignerKeyRotationStatus::Generated {
+                let status = SignerKeyRotationStatus::Announced;
+                storage
+                    .set_signer_key_rotation_status(&new_public_key, status)
+                    .await?;
+                tracing::info!(%new_public_key, "announcing the endorsement of the new signer key");
+            }
+            return Ok(Some(SignerKeyEndorsement { new_public_key }));
+        }
+        SignerKeyRotationStatus::Generated if public_key == new_public_key => {
+            tracing::warn!(
+                %new_public_key,
+                "the signer runs with the new key before it announced the endorsement of it; \
+                 run it with the old key until the endorsement is announced"
+            );
+            return Ok(None);
+        }
+        SignerKeyRotationStatus::Announced if public_key == new_public_key => {
+            if !is_replaced_in(&config.bootstrap_signing_set, &rotation) {
+                tracing::warn!(
+                    %new_public_key,
+                    "the signer runs with the new key, but the configured signing set does not \
+                     have it in place of the old key"
+                );
+                return Ok(None);
+            }
+            SignerKeyRotationStatus::ConfigUpdated
+        }
+        SignerKeyRotationStatus::ConfigUpdated => {
+            let registry_signer_set = ctx.state().registry_signer_set_info();
+            let is_retired =
+                registry_signer_set.is_some_and(|info| is_replaced_in(&info.signer_set, &rotation));
+            if !is_retired {
+                return Ok(None);
+            }
+            SignerKeyRotationStatus::Completed
+        }
+        _ => return Ok(None),
+    };
+
+    storage
+        .set_signer_key_rotation_status(&new_public_key, status)
+        .await?;
+    tracing::info!(%new_public_key, %status, "the signer key rotation moved along");
+
+    Ok(None)
+}
+
+/// Whether the given signing set has the new key of the rotation in place
+/// of the old one.
+fn is_replaced_in(signing_set: &BTreeSet<PublicKey>, rotation: &SignerKeyRotation) -> bool {
+    signing_set.contains(&rotation.new_public_key)
+        && !signing_set.contains(&rotation.old_public_key)
+}
+
+/// Record the endorsement of a new key that the given signer sent in a
+/// message for the given bitcoin chain tip.
+pub async fn record_endorsement(
+    ctx: &impl Context,
+    endorsement: &SignerKeyEndorsement,
+    sender: PublicKey,
+    bitcoin_chain_tip: BitcoinBlockHash,
+) -> Result<(), Error> {
+    if !ctx.state().current_signer_set().is_signer(&sender) {
+        return Err(Error::KeyEndorsementSignerNotInSignerSet(sender));
+    }
+    if endorsement.new_public_key == sender {
+        return Err(Error::InvalidKeyEndorsement(sender));
+    }
+
+    let endorsement = KeyEndorsement {
+        old_public_key: sender,
+        new_public_key: endorsement.new_public_key,
+        bitcoin_chain_tip,
+    };
+    ctx.get_storage_mut()
+        .write_key_endorsement(&endorsement)
+        .await
+}
+
+/// The given signing set with the endorsed keys in place of the keys that
+/// endorsed them. When a key endorsed more than one new key, the last of
+/// the given endorsements wins.
+pub fn endorsed_signing_set(
+    signing_set: &BTreeSet<PublicKey>,
+    endorsements: &[KeyEndorsement],
+) -> BTreeSet<PublicKey> {
+    let replacements: HashMap<PublicKey, PublicKey> = endorsements
+        .iter()
+        .map(|endorsement| (endorsement.old_public_key, endorsement.new_public_key))
+        .collect();
+
+    signing_set
+        .iter()
+        .map(|key| replacements.get(key).copied().unwrap_or(*key))
+        .collect()
+}
+
+/// What the operator should do next for a rotation with the given status.
+pub fn next_step(status: SignerKeyRotationStatus) -> &'static str {
+    match status {
+        SignerKeyRotationStatus::Generated => {
+            "run the signer with the old key, so that it announces the endorsement of the new key"
+        }
+        SignerKeyRotationStatus::Announced => {
+            "once the other operators have the new key in place of the old one in their \
+             signing sets, do the same and run the signer with the new private key"
+        }
+        SignerKeyRotationStatus::ConfigUpdated => {
+            "wait for DKG with the updated signing set and for the rotate-keys contract call"
+        }
+        SignerKeyRotationStatus::Completed => {
+            "nothing; the old key is retired and its private key may be deleted"
+        }
+        SignerKeyRotationStatus::Cancelled => "nothing; the rotation was cancelled",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+
+    use crate::stacks::api::SignerSetInfo;
+    use crate::testing::context::*;
+
+    use super::*;
+
+    async fn latest_status(ctx: &impl Context) -> SignerKeyRotationStatus {
+        let storage = ctx.get_storage();
+        let rotation = storage.get_latest_signer_key_rotation().await.unwrap();
+        rotation.unwrap().status
+    }
+
+    #[tokio::test]
+    async fn rotation_moves_through_each_status() {
+        let mut ctx = TestContext::default_mocked();
+
+        let old_private_key = ctx.config().signer.private_key;
+        let old_public_key = PublicKey::from_private_key(&old_private_key);
+        let other_signer: PublicKey = fake::Faker.fake();
+        ctx.config_mut().signer.bootstrap_signing_set =
+            BTreeSet::from([old_public_key, other_signer]);
+
+        let new_private_key = PrivateKey::new(&mut rand::rngs::OsRng);
+        let new_public_key = PublicKey::from_private_key(&new_private_key);
+
+        let storage = ctx.get_storage_mut();
+        let rotation = start(&storage, &ctx.config().signer, &new_private_key)
+            .await
+            .unwrap();
+        assert_eq!(rotation.old_public_key, old_public_key);
+        assert_eq!(rotation.new_public_key, new_public_key);
+        assert_eq!(
+            latest_status(&ctx).await,
+            SignerKeyRotationStatus::Generated
+        );
+
+        // Only one rotation may be in progress at a time.
+        let another_key = PrivateKey::new(&mut rand::rngs::OsRng);
+        let result = start(&storage, &ctx.config().signer, &another_key).await;
+        assert!(matches!(
+            result,
+            Err(Error::SignerKeyRotationInProgress(key)) if key == new_public_key
+        ));
+
+        // The endorsement is broadcast on every block while the signer
+        // runs with the old key.
+        for _ in 0..2 {
+            let endorsement = advance(&ctx).await.unwrap();
+            assert_eq!(endorsement, Some(SignerKeyEndorsement { new_public_key }));
+            assert_eq!(
+                latest_status(&ctx).await,
+                SignerKeyRotationStatus::Announced
+            );
+        }
+
+        // Running with the new key is not enough; the signing set needs
+        // the new key in place of the old one.
+        ctx.config_mut().signer.private_key = new_private_key;
+        assert_eq!(advance(&ctx).await.unwrap(), None);
+        assert_eq!(
+            latest_status(&ctx).await,
+            SignerKeyRotationStatus::Announced
+        );
+
+        let updated_signing_set = BTreeSet::from([new_public_key, other_signer]);
+        ctx.config_mut().signer.bootstrap_signing_set = updated_signing_set.clone();
+        assert_eq!(advance(&ctx).await.unwrap(), None);
+        assert_eq!(
+            latest_status(&ctx).await,
+            SignerKeyRotationStatus::ConfigUpdated
+        );
+
+        // The old key is retired once the registry has the new signer set.
+        assert_eq!(advance(&ctx).await.unwrap(), None);
+        assert_eq!(
+            latest_status(&ctx).await,
+            SignerKeyRotationStatus::ConfigUpdated
+        );
+
+        ctx.state().update_registry_signer_set_info(SignerSetInfo {
+            aggregate_key: fake::Faker.fake(),
+            signer_set: updated_signing_set,
+            signatures_required: 2,
+        });
+        assert_eq!(advance(&ctx).await.unwrap(), None);
+        assert_eq!(
+            latest_status(&ctx).await,
+            SignerKeyRotationStatus::Completed
+        );
+
+        // There is nothing to cancel once the rotation has completed.
+        assert_eq!(cancel(&ctx.get_storage_mut()).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn cancelled_rotations_are_not_announced() {
+        let ctx = TestContext::default_mocked();
+        let storage = ctx.get_storage_mut();
+
+        let new_private_key = PrivateKey::new(&mut rand::rngs::OsRng);
+        start(&storage, &ctx.config().signer, &new_private_key)
+            .await
+            .unwrap();
+
+        let rotation = cancel(&storage).await.unwrap().unwrap();
+        assert_eq!(rotation.status, SignerKeyRotationStatus::Cancelled);
+        assert_eq!(advance(&ctx).await.unwrap(), None);
+
+        // A new rotation may start once the previous one is cancelled.
+        let new_private_key = PrivateKey::new(&mut rand::rngs::OsRng);
+        start(&storage, &ctx.config().signer, &new_private_key)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn endorsements_are_only_recorded_from_the_signer_set() {
+        let ctx = TestContext::default_mocked();
+        let signer: PublicKey = fake::Faker.fake();
+        let outsider: PublicKey = fake::Faker.fake();
+        ctx.state()
+            .update_current_signer_set(std::iter::once(signer).collect());
+
+        let endorsement = SignerKeyEndorsement {
+            new_public_key: fake::Faker.fake(),
+        };
+        let chain_tip: BitcoinBlockHash = fake::Faker.fake();
+
+        let result = record_endorsement(&ctx, &endorsement, outsider, chain_tip).await;
+        assert!(matches!(
+            result,
+            Err(Error::KeyEndorsementSignerNotInSignerSet(_))
+        ));
+
+        let own_key = SignerKeyEndorsement { new_public_key: signer };
+        let result = record_endorsement(&ctx, &own_key, signer, chain_tip).await;
+        assert!(matches!(result, Err(Error::InvalidKeyEndorsement(_))));
+
+        record_endorsement(&ctx, &endorsement, signer, chain_tip)
+            .await
+            .unwrap();
+        let endorsements = ctx.get_storage().get_key_endorsements().await.unwrap();
+        assert_eq!(
+            endorsements,
+            vec![KeyEndorsement {
+                old_public_key: signer,
+                new_public_key: endorsement.new_public_key,
+                bitcoin_chain_tip: chain_tip,
+            }]
+        );
+    }
+
+    #[test]
+    fn endorsed_keys_replace_the_keys_that_endorsed_them() {
+        let [kept, replaced, first, second, outsider]: [PublicKey; 5] = fake::Faker.fake();
+        let signing_set = BTreeSet::from([kept, replaced]);
+
+        let endorsement = |old_public_key, new_public_key| KeyEndorsement {
+            old_public_key,
+            new_public_key,
+            bitcoin_chain_tip: fake::Faker.fake(),
+        };
+        let endorsements = [
+            endorsement(replaced, first),
+            endorsement(replaced, second),
+            endorsement(outsider, first),
+        ];
+
+        let endorsed = endorsed_signing_set(&signing_set, &endorsements);
+        assert_eq!(endorsed, BTreeSet::from([kept, second]));
+    }
+}
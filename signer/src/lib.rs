@@ -6,32 +6,51 @@
     // clippy::expect_used, // TODO: There's 14 expects left
 )]
 
+pub mod alerts;
 pub mod api;
+pub mod attestation;
 pub mod bitcoin;
 pub mod block_observer;
+pub mod block_processing;
 pub mod blocklist_client;
+pub mod broadcaster;
+pub mod chaos;
 pub mod codec;
 pub mod config;
+pub mod consistency;
 pub mod context;
 pub mod dkg;
 pub mod ecdsa;
+pub mod emergency_recovery;
 pub mod emily_client;
 pub mod error;
+pub mod import;
+pub mod key_rotation;
 pub mod keys;
+pub mod limit_override;
 pub mod logging;
 pub mod message;
 pub mod metrics;
 pub mod network;
+pub mod policy_plugin;
 pub mod proto;
+pub mod quorum;
+pub mod reconciliation;
 pub mod request_decider;
+pub mod runtime;
+pub mod selection;
 pub mod signature;
 pub mod stacks;
+pub mod state_snapshot;
 pub mod storage;
+pub mod supervisor;
 #[cfg(any(test, feature = "testing"))]
 pub mod testing;
 pub mod transaction_coordinator;
 pub mod transaction_signer;
+pub mod transcript;
 pub mod util;
+pub mod watchdog;
 pub mod wsts_state_machine;
 
 /// Package version
@@ -94,6 +113,14 @@ pub const MAX_MEMPOOL_PACKAGE_TX_COUNT: u64 = 25;
 /// next bitcoin block. This assumes signing rounds take ~16 seconds.
 pub const DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX: u16 = 25;
 
+/// The default number of bitcoin blocks after a deposit is confirmed
+/// that the signers will wait for it to be swept before marking it as
+/// expired.
+///
+/// This is roughly three days of bitcoin blocks, which is well below the
+/// reclaim locktimes that depositors typically use.
+pub const DEFAULT_DEPOSIT_EXPIRY_BLOCKS: u16 = 432;
+
 /// This is the dust limit for deposits in the sBTC smart contracts.
 /// Deposit amounts that is less than this amount will be rejected by the
 /// smart contract.
@@ -161,6 +188,28 @@ pub const MAX_SIGNER_STATE_MACHINES: u64 = MAX_MEMPOOL_PACKAGE_SIZE
 /// bitcoin.
 pub const MIN_BITCOIN_INPUT_VSIZE: u64 = 58;
 
+/// The maximum number of times that the coordinator retries a bitcoin
+/// presign request during a tenure after leaving out the requests that the
+/// signers refused. Each retry must have fewer requests than the one
+/// before it, and the signers refuse any retries beyond this number.
+pub const MAX_PRESIGN_RETRIES: u32 = 2;
+
+/// The factor by which the coordinator raises the fee rate of its bitcoin
+/// transactions in the tenures after bitcoin-core rejected one of them
+/// for paying too little.
+pub const BROADCAST_FEE_BUMP_FACTOR: f64 = 1.5;
+
+/// The maximum number of times that the broadcaster tries to broadcast a
+/// queued sweep transaction when it cannot reach bitcoin-core, before it
+/// gives up on the transaction and leaves its requests for a later tenure.
+pub const MAX_BROADCAST_ATTEMPTS: u32 = 5;
+
+/// The number of bitcoin blocks that a sweep transaction accepted by
+/// bitcoin-core may sit in the mempool before the broadcaster raises the
+/// fee rate, so that the next coordinator replaces it with a sweep that
+/// pays more.
+pub const SWEEP_FEE_BUMP_BLOCKS: u64 = 3;
+
 // These are all build info variables. Many of them are set in build.rs.
 
 /// The name of the binary that is being run,
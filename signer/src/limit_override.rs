@@ -0,0 +1,234 @@
+//! Signer-set votes on temporary overrides of the sBTC limits.
+//!
+//! The sBTC limits are normally controlled by Emily alone. Sometimes the
+//! signer operators need to change them for a short while, say to raise
+//! the per-deposit cap during a migration of funds. To do so, each
+//! operator proposes the same [`LimitOverrideVote`] over the control API
+//! of their signer, which records the vote and broadcasts it to the other
+//! signers in a signed [`SignerMessage`](crate::message::SignerMessage).
+//!
+//! Each signer checks every vote against its own configured bounds and
+//! ignores the ones outside of them. Once identical votes from at least
+//! `signatures_required` members of the signer set are in, the signer
+//! applies the override to the [`SbtcLimits`] that it computes on each new
+//! bitcoin block, until the bitcoin block height at which the override
+//! expires.
+
+use crate::config::SignerConfig;
+use crate::context::Context;
+use crate::context::SbtcLimits;
+use crate::context::SignerState;
+use crate::error::Error;
+use crate::keys::PublicKey;
+use crate::message::LimitOverrideVote;
+use crate::storage::model::BitcoinBlockHeight;
+
+/// The votes for a limit override after recording a vote for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitOverrideTally {
+    /// The number of members of the signer set that voted for the
+    /// override.
+    pub votes: usize,
+    /// The number of votes needed for the override to take effect.
+    pub threshold: u16,
+    /// Whether the override is the one that the signer set agreed on.
+    pub active: bool,
+}
+
+/// Check that the given vote overrides at least one limit, has not
+/// expired, and is within the bounds in the given config.
+pub fn validate_vote(
+    vote: &LimitOverrideVote,
+    config: &SignerConfig,
+    chain_tip_height: BitcoinBlockHeight,
+) -> Result<(), Error> {
+    if vote.per_deposit_cap.is_none() && vote.per_withdrawal_cap.is_none() {
+        return Err(Error::InvalidLimitOverride(
+            "it does not override any limits",
+        ));
+    }
+    if vote.expires_at_height <= chain_tip_height {
+        return Err(Error::InvalidLimitOverride("it has expired"));
+    }
+    let max_blocks = u64::from(config.limit_override_max_blocks.get());
+    if vote.expires_at_height > chain_tip_height.saturating_add(max_blocks) {
+        return Err(Error::InvalidLimitOverride(
+            "it lasts longer than this signer accepts",
+        ));
+    }
+
+    let within_bounds = |cap: Option<u64>, bound: Option<u64>| match (cap, bound) {
+        (None, _) => true,
+        (Some(cap), Some(bound)) => cap <= bound,
+        (Some(_), None) => false,
+    };
+    if !within_bounds(
+        vote.per_deposit_cap,
+        config.limit_override_max_per_deposit_cap,
+    ) {
+        return Err(Error::InvalidLimitOverride(
+            "its per-deposit cap is above the bound of this signer",
+        ));
+    }
+    if !within_bounds(
+        vote.per_withdrawal_cap,
+        config.limit_override_max_per_withdrawal_cap,
+    ) {
+        return Err(Error::InvalidLimitOverride(
+            "its per-withdrawal cap is above the bound of this signer",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate the vote of the given member of the signer set and record it,
+/// activating the override once enough signers have voted for it.
+pub fn record_vote(
+    ctx: &impl Context,
+    vote: LimitOverrideVote,
+    voter: PublicKey,
+) -> Result<LimitOverrideTally, Error> {
+    let state = ctx.state();
+    let config = &ctx.config().signer;
+    let chain_tip = state.bitcoin_chain_tip().ok_or(Error::NoChainTip)?;
+
+    if !state.current_signer_set().is_signer(&voter) {
+        return Err(Error::LimitOverrideVoterNotInSignerSet(voter));
+    }
+    validate_vote(&vote, config, chain_tip.block_height)?;
+
+    let votes = state.record_limit_override_vote(voter, vote);
+    let threshold = state
+        .registry_signer_set_info()
+        .map_or(config.bootstrap_signatures_required, |info| {
+            info.signatures_required
+        });
+
+    if votes >= usize::from(threshold) && state.active_limit_override() != Some(vote) {
+        tracing::info!(?vote, %votes, "the signer set agreed on a limit override");
+        state.set_active_limit_override(vote);
+    }
+
+    Ok(LimitOverrideTally {
+        votes,
+        threshold,
+        active: state.active_limit_override() == Some(vote),
+    })
+}
+
+/// Apply the limit override that the signer set agreed on, if any, to the
+/// given limits, after dropping the votes and the override that expire at
+/// or before the given bitcoin block height.
+pub fn apply_active_override(
+    state: &SignerState,
+    limits: SbtcLimits,
+    chain_tip_height: BitcoinBlockHeight,
+) -> SbtcLimits {
+    state.prune_limit_override_votes(chain_tip_height);
+    match state.active_limit_override() {
+        Some(vote) => {
+            tracing::debug!(?vote, "applying the limit override of the signer set");
+            limits.with_override(&vote)
+        }
+        None => limits,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU16;
+
+    use bitcoin::Amount;
+    use fake::Fake as _;
+    use fake::Faker;
+
+    use super::*;
+    use crate::storage::model::BitcoinBlockRef;
+    use crate::testing::context::*;
+
+    const CHAIN_TIP_HEIGHT: u64 = 100;
+
+    fn vote(
+        per_deposit_cap: Option<u64>,
+        per_withdrawal_cap: Option<u64>,
+        expires_at_height: u64,
+    ) -> LimitOverrideVote {
+        LimitOverrideVote {
+            per_deposit_cap,
+            per_withdrawal_cap,
+            expires_at_height: expires_at_height.into(),
+        }
+    }
+
+    fn config() -> SignerConfig {
+        let mut config = crate::config::Settings::new_from_default_config()
+            .unwrap()
+            .signer;
+        config.limit_override_max_per_deposit_cap = Some(1_000_000);
+        config.limit_override_max_per_withdrawal_cap = None;
+        config.limit_override_max_blocks = NonZeroU16::new(10).unwrap();
+        config
+    }
+
+    #[test_case::test_case(vote(Some(1_000_000), None, 110), true; "within bounds")]
+    #[test_case::test_case(vote(None, None, 110), false; "no overridden limits")]
+    #[test_case::test_case(vote(Some(1_000_001), None, 110), false; "deposit cap above bound")]
+    #[test_case::test_case(vote(None, Some(1), 110), false; "withdrawal cap without bound")]
+    #[test_case::test_case(vote(Some(1), None, 100), false; "expired")]
+    #[test_case::test_case(vote(Some(1), None, 111), false; "lasts too long")]
+    fn vote_validation(vote: LimitOverrideVote, is_valid: bool) {
+        let result = validate_vote(&vote, &config(), CHAIN_TIP_HEIGHT.into());
+        assert_eq!(result.is_ok(), is_valid);
+    }
+
+    #[test]
+    fn overrides_take_effect_once_enough_signers_vote() {
+        let mut ctx = TestContext::default_mocked();
+        ctx.config_mut().signer = config();
+        ctx.config_mut().signer.bootstrap_signatures_required = 2;
+
+        let state = ctx.state();
+        let chain_tip = BitcoinBlockRef {
+            block_hash: Faker.fake(),
+            block_height: CHAIN_TIP_HEIGHT.into(),
+        };
+        state.set_bitcoin_chain_tip(chain_tip);
+
+        let signers: Vec<PublicKey> = std::iter::repeat_with(|| Faker.fake()).take(3).collect();
+        state.update_current_signer_set(signers.iter().copied().collect());
+
+        let vote = vote(Some(1_000_000), None, 105);
+        let tally = record_vote(&ctx, vote, signers[0]).unwrap();
+        assert_eq!(tally.votes, 1);
+        assert!(!tally.active);
+
+        // Votes from outside of the signer set are not counted.
+        let outsider: PublicKey = Faker.fake();
+        let result = record_vote(&ctx, vote, outsider);
+        assert!(matches!(
+            result,
+            Err(Error::LimitOverrideVoterNotInSignerSet(_))
+        ));
+
+        let tally = record_vote(&ctx, vote, signers[1]).unwrap();
+        assert_eq!(tally.votes, 2);
+        assert!(tally.active);
+
+        let limits = apply_active_override(
+            &state,
+            SbtcLimits::new_per_deposit(0, 10_000),
+            CHAIN_TIP_HEIGHT.into(),
+        );
+        assert_eq!(limits.per_deposit_cap(), Amount::from_sat(1_000_000));
+
+        // Once the override expires the limits from Emily apply again.
+        let limits = apply_active_override(
+            &state,
+            SbtcLimits::new_per_deposit(0, 10_000),
+            105u64.into(),
+        );
+        assert_eq!(limits.per_deposit_cap(), Amount::from_sat(10_000));
+        assert_eq!(state.active_limit_override(), None);
+    }
+}
@@ -1,43 +1,35 @@
+use std::io::Write as _;
+use std::path::Path;
 use std::path::PathBuf;
-use std::sync::Arc;
-use std::sync::atomic::AtomicU64;
-use std::sync::atomic::Ordering;
-use std::time::Duration;
 
-use axum::http::Request;
-use axum::http::Response;
 use cfg_if::cfg_if;
 use clap::Parser;
+use clap::Subcommand;
 use clap::ValueEnum;
-use signer::api;
-use signer::api::ApiState;
+use signer::bitcoin::esplora::EsploraBackedClient;
 use signer::bitcoin::rpc::BitcoinCoreClient;
-use signer::bitcoin::zmq::BitcoinCoreMessageStream;
-use signer::block_observer;
-use signer::blocklist_client::BlocklistClient;
+use signer::chaos::ChaosEmilyClient;
+use signer::chaos::ChaosMonkey;
 use signer::config::Settings;
+use signer::context::Clock;
 use signer::context::Context;
 use signer::context::SignerContext;
 use signer::emily_client::EmilyClient;
 use signer::error::Error;
-use signer::network::P2PNetwork;
-use signer::network::libp2p::SignerSwarmBuilder;
-use signer::request_decider::RequestDeciderEventLoop;
+use signer::import::HistoricalData;
+use signer::key_rotation;
+use signer::keys::PrivateKey;
+use signer::runtime::Signer;
+use signer::runtime::SignerRole;
 use signer::stacks::api::StacksClient;
+use signer::state_snapshot::StateArchive;
+use signer::state_snapshot::StateSnapshot;
+use signer::storage::DbRead as _;
+use signer::storage::model::BitcoinBlockHash;
 use signer::storage::postgres::PgStore;
 use signer::transaction_coordinator;
-use signer::transaction_signer;
 use signer::util::ApiFallbackClient;
 use tokio::signal;
-use tower_http::trace::TraceLayer;
-use tracing::Instrument;
-use tracing::Span;
-
-// This is how many seconds the P2P swarm will wait before attempting to
-// bootstrap (i.e. connect to other peers). Three seconds is a sane default
-// value, giving the swarm a few seconds to start up and bind listener(s)
-// before proceeding.
-const INITIAL_BOOTSTRAP_DELAY_SECS: u64 = 3;
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
 enum LogOutputFormat {
@@ -61,6 +53,100 @@ struct SignerArgs {
 
     #[clap(short = 'o', long = "output-format", default_value = "pretty")]
     output_format: Option<LogOutputFormat>,
+
+    /// If this flag is set and the signer is running on regtest, the signer
+    /// will randomly restart its event loops, delay responses from Emily
+    /// and trigger small bitcoin reorgs. This is ignored on other networks.
+    #[clap(long)]
+    chaos: bool,
+
+    /// The seed for the random decisions made in chaos mode. The seed of
+    /// every chaos run is logged on startup, so that runs can be replayed.
+    #[clap(long, requires = "chaos")]
+    chaos_seed: Option<u64>,
+
+    /// The role of this process. A watcher runs the block observer, the
+    /// request decider and the coordinator's planning against its own
+    /// database, logging and storing the decisions and transactions that
+    /// it expects from the signers, but it never joins the p2p network.
+    /// The private key in the configuration is not used to sign anything
+    /// that leaves a watcher, so any key may be used.
+    #[clap(long, value_enum, default_value = "signer", conflicts_with = "chaos")]
+    role: SignerRole,
+
+    /// Run a one-off command instead of the signer.
+    #[clap(subcommand)]
+    command: Option<SignerSubcommand>,
+}
+
+/// One-off commands that use the signer's configuration.
+#[derive(Debug, Subcommand)]
+enum SignerSubcommand {
+    /// Print the order in which the signers in the configured bootstrap
+    /// signing set take the coordinator role for a bitcoin block, starting
+    /// with the coordinator. Use this to verify which signer should have
+    /// acted on the block.
+    WhoIsCoordinator {
+        /// The hash of the bitcoin block, in the hex format used by
+        /// bitcoin core.
+        #[clap(long)]
+        block_hash: bitcoin::BlockHash,
+    },
+    /// Import historical bitcoin blocks, stacks blocks, deposits, sweeps
+    /// and withdrawals from a JSON export into the database. Use this to
+    /// set up a signer whose bitcoin-core node has pruned the blocks that
+    /// the signer would otherwise backfill.
+    Import {
+        /// The path to the JSON export.
+        #[clap(long)]
+        file: PathBuf,
+    },
+    /// Write the DKG shares, the registry of aggregate keys and the
+    /// unfulfilled deposit requests with the decisions of this signer to a
+    /// checksummed JSON archive. Use this with `import-state` to move a
+    /// signer to another host.
+    ExportState {
+        /// The path of the file to write the archive to. The file must not
+        /// exist yet.
+        #[clap(long)]
+        file: PathBuf,
+    },
+    /// Verify an archive written by `export-state` and import it into the
+    /// database, which must not hold any DKG shares yet. The config of
+    /// this signer must match the config of the signer that wrote the
+    /// archive.
+    ImportState {
+        /// The path to the archive.
+        #[clap(long)]
+        file: PathBuf,
+    },
+    /// Replace the key of this signer with a new one. See the
+    /// `key_rotation` module of the signer for the steps of a rotation.
+    KeyRotation {
+        #[clap(subcommand)]
+        command: KeyRotationCommand,
+    },
+}
+
+/// The steps of a rotation of the key of this signer that the operator
+/// takes by hand.
+#[derive(Debug, Subcommand)]
+enum KeyRotationCommand {
+    /// Generate a new key for this signer and start the rotation of the
+    /// configured key to it. The signer announces the endorsement of the
+    /// new key to the other signers while it runs with the old key.
+    Start {
+        /// The path of the file to write the new private key to. The file
+        /// must not exist yet.
+        #[clap(long)]
+        new_key_file: PathBuf,
+    },
+    /// Print the rotation of the key of this signer, the endorsements of
+    /// new keys received from the other signers, and the signing set with
+    /// the endorsed keys in place.
+    Status,
+    /// Cancel the rotation of the key of this signer that is in progress.
+    Cancel,
 }
 
 #[tokio::main]
@@ -89,6 +175,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let signer_public_key = settings.signer.public_key();
     tracing::info!(%signer_public_key, "config loaded successfully");
 
+    if let Some(SignerSubcommand::WhoIsCoordinator { block_hash }) = args.command {
+        print_coordinator_ordering(&settings, block_hash.into());
+        return Ok(());
+    }
+
     signer::metrics::setup_metrics(settings.signer.prometheus_exporter_endpoint);
 
     // Open a connection to the signer db.
@@ -105,71 +196,243 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         })?;
     }
 
-    // Initialize the signer context.
-    let context = SignerContext::<
-        _,
-        ApiFallbackClient<BitcoinCoreClient>,
-        ApiFallbackClient<StacksClient>,
-        ApiFallbackClient<EmilyClient>,
-    >::init(settings, db)
-    .inspect_err(|err| {
-        tracing::error!(%err, "failed to initialize the signer context");
-    })?;
+    if let Some(SignerSubcommand::Import { file }) = args.command {
+        let reader = std::io::BufReader::new(std::fs::File::open(&file)?);
+        let data: HistoricalData = serde_json::from_reader(reader)?;
+        let summary = data
+            .import(
+                &db,
+                settings.signer.bootstrap_aggregate_key,
+                settings.signer.network.is_mainnet(),
+            )
+            .await
+            .inspect_err(|err| {
+                tracing::error!(%err, "failed to import the historical data");
+            })?;
+        println!("imported {summary}");
+        return Ok(());
+    }
 
-    // TODO: We should first check "another source of truth" for the current
-    // signing set, and only assume we are bootstrapping if that source is
-    // empty.
-    let settings = context.config();
-    for signer in &settings.signer.bootstrap_signing_set {
-        context.state().current_signer_set().add_signer(*signer);
+    if let Some(SignerSubcommand::ExportState { file }) = args.command {
+        let archive = StateSnapshot::take(&db, &settings.signer)
+            .await
+            .and_then(StateSnapshot::into_archive)
+            .inspect_err(|err| {
+                tracing::error!(%err, "failed to take a snapshot of the signer state");
+            })?;
+        write_state_archive_file(&file, &archive)?;
+        println!("exported the signer state to {}", file.display());
+        return Ok(());
     }
 
-    // Run the application components concurrently. We're `join!`ing them
-    // here so that every component can shut itself down gracefully when
-    // the shutdown signal is received.
-    //
-    // Note that we must use `join` here instead of `select` as `select` would
-    // immediately abort the remaining tasks on the first completion, which
-    // deprives the other tasks of the opportunity to shut down gracefully. This
-    // is the reason we also use the `run_checked` helper method, which will
-    // intercept errors and send a shutdown signal to the other components if an error
-    // does occur, otherwise the `join` will continue running indefinitely.
-    let _ = tokio::join!(
-        // Our global termination signal watcher. This does not run using `run_checked`
-        // as it sends its own shutdown signal.
-        run_shutdown_signal_watcher(context.clone()),
-        // The rest of our services which run concurrently, and must all be
-        // running for the signer to be operational.
-        run_checked(run_api, &context),
-        run_checked(run_libp2p_swarm, &context),
-        run_checked(run_block_observer, &context),
-        run_checked(run_request_decider, &context),
-        run_checked(run_transaction_coordinator, &context),
-        run_checked(run_transaction_signer, &context),
-    );
+    if let Some(SignerSubcommand::ImportState { file }) = args.command {
+        let reader = std::io::BufReader::new(std::fs::File::open(&file)?);
+        let archive: StateArchive = serde_json::from_reader(reader)?;
+        let summary = archive
+            .import(&db, &settings.signer)
+            .await
+            .inspect_err(|err| {
+                tracing::error!(%err, "failed to import the signer state");
+            })?;
+        println!("imported {summary}");
+        // The signers' UTXO is found again once the signer has synced
+        // bitcoin blocks, so the operator can only check it afterwards.
+        if let Some(utxo) = archive.snapshot.signer_utxo {
+            println!(
+                "the signers' UTXO was {} holding {} sats at the time of the export",
+                utxo.outpoint, utxo.amount
+            );
+        }
+        return Ok(());
+    }
+
+    if let Some(SignerSubcommand::KeyRotation { command }) = args.command {
+        run_key_rotation_command(&settings, &db, command).await?;
+        return Ok(());
+    }
+
+    let chaos = if args.chaos {
+        ChaosMonkey::for_network(settings.signer.network, args.chaos_seed)
+    } else {
+        None
+    };
+
+    // Bitcoin-core may be backed by esplora for read-only queries, so the
+    // bitcoin client is built from the whole bitcoin configuration.
+    let bitcoin_client =
+        EsploraBackedClient::<ApiFallbackClient<BitcoinCoreClient>>::try_from(&settings.bitcoin)
+            .inspect_err(|err| {
+                tracing::error!(%err, "failed to initialize the bitcoin client");
+            })?;
+
+    // Initialize the signer context and run the signer. In chaos mode, the
+    // Emily client randomly delays its calls, which changes the type of the
+    // context.
+    match chaos {
+        None => {
+            let stacks_client = ApiFallbackClient::<StacksClient>::try_from(&settings)?;
+            let emily_client = ApiFallbackClient::<EmilyClient>::try_from(&settings.emily)?;
+            let context =
+                SignerContext::new(settings, db, bitcoin_client, stacks_client, emily_client);
+
+            run_until_shutdown(Signer::new(context).with_role(args.role)).await;
+        }
+        Some(monkey) => {
+            let clock = Clock::system();
+            let stacks_client = ApiFallbackClient::<StacksClient>::try_from(&settings)?;
+            let emily_client = ChaosEmilyClient::new(
+                ApiFallbackClient::<EmilyClient>::try_from(&settings.emily)?,
+                monkey.clone(),
+            )
+            .with_clock(clock.clone());
+            let mut context =
+                SignerContext::new(settings, db, bitcoin_client, stacks_client, emily_client);
+            context.set_clock(clock);
+
+            run_until_shutdown(Signer::new(context).with_chaos(monkey)).await;
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the coordinator selection for the given bitcoin block and the
+/// configured bootstrap signing set.
+fn print_coordinator_ordering(settings: &Settings, block_hash: BitcoinBlockHash) {
+    let signer_set = &settings.signer.bootstrap_signing_set;
+    let selection = transaction_coordinator::coordinator_selection(&block_hash, signer_set);
+    let own_public_key = settings.signer.public_key();
+
+    println!("bitcoin block hash: {block_hash}");
+    println!("block hash digest:  {}", hex::encode(selection.digest));
+    println!("selection index:    {}", selection.index);
+    println!("signing set size:   {}", signer_set.len());
+    println!();
+    for (position, public_key) in selection.ordering.iter().enumerate() {
+        let mut notes = Vec::new();
+        if position == 0 {
+            notes.push("coordinator");
+        }
+        if *public_key == own_public_key {
+            notes.push("this signer");
+        }
+        let notes = match notes.is_empty() {
+            true => String::new(),
+            false => format!(" ({})", notes.join(", ")),
+        };
+        println!("{position:>3}  {public_key}{notes}");
+    }
+}
+
+/// Run the given step of the rotation of the key of this signer.
+async fn run_key_rotation_command(
+    settings: &Settings,
+    db: &PgStore,
+    command: KeyRotationCommand,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match command {
+        KeyRotationCommand::Start { new_key_file } => {
+            // The key is written out before the rotation is recorded, so
+            // that a recorded rotation always has its private key on disk.
+            let private_key = PrivateKey::new(&mut rand::rngs::OsRng);
+            write_private_key_file(&new_key_file, &private_key)?;
+            let rotation = key_rotation::start(db, &settings.signer, &private_key)
+                .await
+                .inspect_err(|_| {
+                    let _ = std::fs::remove_file(&new_key_file);
+                })?;
+
+            println!("old public key: {}", rotation.old_public_key);
+            println!("new public key: {}", rotation.new_public_key);
+            println!("new private key written to {}", new_key_file.display());
+            println!("next step: {}", key_rotation::next_step(rotation.status));
+        }
+        KeyRotationCommand::Status => print_key_rotation_status(settings, db).await?,
+        KeyRotationCommand::Cancel => match key_rotation::cancel(db).await? {
+            Some(rotation) => println!("cancelled the rotation to {}", rotation.new_public_key),
+            None => println!("no rotation of the signer key is in progress"),
+        },
+    }
+
+    Ok(())
+}
+
+/// Write the given private key, hex encoded, to a new file that only its
+/// owner may read.
+fn write_private_key_file(path: &Path, private_key: &PrivateKey) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(path)?;
+    writeln!(file, "{}", hex::encode(private_key.to_bytes()))
+}
 
+/// Write the given state archive to a new file that only the owner can
+/// read, since it holds the encrypted DKG shares of this signer.
+fn write_state_archive_file(path: &Path, archive: &StateArchive) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let file = options.open(path)?;
+    serde_json::to_writer_pretty(std::io::BufWriter::new(file), archive)?;
     Ok(())
 }
 
-/// A helper method that captures errors from the provided future and sends a
-/// shutdown signal to the application if an error is encountered. This is needed
-/// as otherwise the application would continue running indefinitely (since no
-/// shutdown signal is sent automatically on error).
-async fn run_checked<F, Fut, C>(f: F, ctx: &C) -> Result<(), Error>
-where
-    C: Context,
-    F: FnOnce(C) -> Fut,
-    Fut: std::future::Future<Output = Result<(), Error>>,
-{
-    if let Err(error) = f(ctx.clone()).await {
-        tracing::error!(%error, "a fatal error occurred; shutting down the application");
-        ctx.get_termination_handle().signal_shutdown();
-        return Err(error);
+/// Print the rotation of the key of this signer along with the
+/// endorsements of new keys that the other signers sent.
+async fn print_key_rotation_status(settings: &Settings, db: &PgStore) -> Result<(), Error> {
+    match db.get_latest_signer_key_rotation().await? {
+        Some(rotation) => {
+            println!("old public key: {}", rotation.old_public_key);
+            println!("new public key: {}", rotation.new_public_key);
+            println!("status:         {}", rotation.status);
+            println!(
+                "next step:      {}",
+                key_rotation::next_step(rotation.status)
+            );
+        }
+        None => println!("this signer has not started a key rotation"),
+    }
+
+    let endorsements = db.get_key_endorsements().await?;
+    println!();
+    println!(
+        "endorsements received from other signers: {}",
+        endorsements.len()
+    );
+    for endorsement in &endorsements {
+        let old_public_key = endorsement.old_public_key;
+        println!("  {old_public_key} -> {}", endorsement.new_public_key);
+    }
+
+    let signing_set = &settings.signer.bootstrap_signing_set;
+    let endorsed_set = key_rotation::endorsed_signing_set(signing_set, &endorsements);
+    if endorsed_set != *signing_set {
+        println!();
+        println!("bootstrap signing set with the endorsed keys in place:");
+        for public_key in &endorsed_set {
+            println!("  {public_key}");
+        }
     }
 
     Ok(())
 }
 
+/// Start the signer and run it until it shuts down, either because a
+/// termination signal was received or because one of its components
+/// failed.
+async fn run_until_shutdown<C: Context + 'static>(mut signer: Signer<C>) {
+    signer.start();
+    let context = signer.context().clone();
+    // The signal watcher does not run using `run_checked`, as it sends its
+    // own shutdown signal.
+    let _ = tokio::join!(run_shutdown_signal_watcher(context), signer.wait());
+}
+
 /// Runs the shutdown-signal watcher. On Unix systems, this listens for SIGHUP,
 /// SIGTERM, and SIGINT. On other systems, it listens for Ctrl-C.
 #[tracing::instrument(skip(ctx), name = "shutdown-watcher")]
@@ -227,167 +490,3 @@ async fn run_shutdown_signal_watcher(ctx: impl Context) -> Result<(), Error> {
 
     Ok(())
 }
-
-/// Runs the libp2p swarm.
-#[tracing::instrument(skip_all, name = "p2p")]
-async fn run_libp2p_swarm(ctx: impl Context) -> Result<(), Error> {
-    tracing::info!("initializing the p2p network");
-
-    tracing::debug!("building the libp2p swarm");
-    let config = ctx.config();
-
-    let enable_quic = config.signer.p2p.is_quic_used();
-
-    // Limit the number of signers to the maximum number of signer pubkeys we
-    // can support. Note that this value is used as a base value for swarm
-    // connection limit calculations.
-    let num_signers = ctx
-        .state()
-        .current_signer_set()
-        .num_signers()
-        .try_into()
-        .unwrap_or(signer::MAX_KEYS);
-
-    // Build the swarm.
-    let mut swarm = SignerSwarmBuilder::new(&config.signer.private_key)
-        .add_listen_endpoints(&ctx.config().signer.p2p.listen_on)
-        .add_seed_addrs(&ctx.config().signer.p2p.seeds)
-        .add_external_addresses(&ctx.config().signer.p2p.public_endpoints)
-        .enable_mdns(config.signer.p2p.enable_mdns)
-        .enable_quic_transport(enable_quic)
-        .with_initial_bootstrap_delay(Duration::from_secs(INITIAL_BOOTSTRAP_DELAY_SECS))
-        .with_num_signers(num_signers)
-        .build()?;
-
-    // Start the libp2p swarm. This will run until either the shutdown signal is
-    // received, or an unrecoverable error has occurred.
-    tracing::info!("starting the libp2p swarm");
-    swarm
-        .start(&ctx)
-        .in_current_span()
-        .await
-        .map_err(Error::SignerSwarm)
-}
-
-/// Runs the signer's API server, which includes the Stacks event observer.
-#[tracing::instrument(skip_all, name = "api")]
-async fn run_api(ctx: impl Context + 'static) -> Result<(), Error> {
-    let socket_addr = ctx.config().signer.event_observer.bind;
-    tracing::info!(%socket_addr, "initializing the signer API server");
-
-    let state = ApiState { ctx: ctx.clone() };
-
-    let request_id = Arc::new(AtomicU64::new(0));
-
-    // Build the signer API application
-    let app = api::get_router()
-        .layer(
-            TraceLayer::new_for_http()
-                .make_span_with(|request: &Request<_>| {
-                    tracing::info_span!("api-request",
-                        uri = %request.uri(),
-                        method = %request.method(),
-                        id = tracing::field::Empty,
-                    )
-                })
-                .on_request(move |_: &Request<_>, span: &Span| {
-                    span.record("id", request_id.fetch_add(1, Ordering::SeqCst));
-                    tracing::trace!("processing request");
-                })
-                .on_response(|_: &Response<_>, duration: Duration, _: &Span| {
-                    tracing::trace!(duration_ms = duration.as_millis(), "request completed");
-                }),
-        )
-        .with_state(state);
-
-    // Bind to the configured address and port
-    let listener = tokio::net::TcpListener::bind(socket_addr)
-        .await
-        .expect("failed to bind the signer API to configured address");
-
-    // Get the termination signal handle.
-    let mut term = ctx.get_termination_handle();
-
-    // Run our app with hyper
-    axum::serve(listener, app)
-        .with_graceful_shutdown(async move {
-            // Listen for an application shutdown signal. We need to loop here
-            // because we may receive other signals (which we will ignore here).
-            term.wait_for_shutdown().await;
-            tracing::info!("stopping the signer API server");
-        })
-        .await
-        .map_err(|error| {
-            tracing::error!(%error, "error running the signer API server");
-            ctx.get_termination_handle().signal_shutdown();
-            error.into()
-        })
-}
-
-/// Run the block observer event-loop.
-async fn run_block_observer(ctx: impl Context) -> Result<(), Error> {
-    let config = ctx.config().clone();
-
-    // TODO: Need to handle multiple endpoints, so some sort of
-    // failover-stream-wrapper.
-    let endpoint = config.bitcoin.block_hash_stream_endpoints[0].as_str();
-    let stream = BitcoinCoreMessageStream::new_from_endpoint(endpoint)
-        .await
-        .unwrap();
-
-    // TODO: We should have a new() method that builds from the context
-    let block_observer = block_observer::BlockObserver {
-        context: ctx,
-        bitcoin_blocks: stream.to_block_hash_stream(),
-    };
-
-    block_observer.run().await
-}
-
-/// Run the transaction signer event-loop.
-async fn run_transaction_signer(ctx: impl Context) -> Result<(), Error> {
-    let network = P2PNetwork::new(&ctx);
-
-    let signer = transaction_signer::TxSignerEventLoop::new(ctx, network, rand::thread_rng())?;
-
-    signer.run().await
-}
-
-/// Run the transaction coordinator event-loop.
-async fn run_transaction_coordinator(ctx: impl Context) -> Result<(), Error> {
-    let config = ctx.config().clone();
-    let private_key = config.signer.private_key;
-    let network = P2PNetwork::new(&ctx);
-
-    let coord = transaction_coordinator::TxCoordinatorEventLoop {
-        network,
-        context: ctx,
-        context_window: config.signer.context_window,
-        private_key,
-        signing_round_max_duration: config.signer.signer_round_max_duration,
-        bitcoin_presign_request_max_duration: config.signer.bitcoin_presign_request_max_duration,
-        threshold: config.signer.bootstrap_signatures_required,
-        dkg_max_duration: config.signer.dkg_max_duration,
-        is_epoch3: false,
-    };
-
-    coord.run().await
-}
-
-/// Run the request decider event-loop.
-async fn run_request_decider(ctx: impl Context) -> Result<(), Error> {
-    let config = ctx.config().clone();
-    let network = P2PNetwork::new(&ctx);
-
-    let decider = RequestDeciderEventLoop {
-        network,
-        context: ctx.clone(),
-        context_window: config.signer.context_window,
-        deposit_decisions_retry_window: config.signer.deposit_decisions_retry_window,
-        withdrawal_decisions_retry_window: config.signer.withdrawal_decisions_retry_window,
-        blocklist_checker: config.blocklist_client.as_ref().map(BlocklistClient::new),
-        signer_private_key: config.signer.private_key,
-    };
-
-    decider.run().await
-}
@@ -3,12 +3,15 @@
 use secp256k1::ecdsa::RecoverableSignature;
 
 use crate::bitcoin::utxo::Fees;
+use crate::bitcoin::utxo::SweepTemplateVersion;
 use crate::bitcoin::validation::TxRequestIds;
+use crate::error::Error;
 use crate::keys::PublicKey;
 use crate::stacks::contracts::ContractCall;
 use crate::stacks::contracts::StacksTx;
 use crate::storage::model;
 use crate::storage::model::BitcoinBlockHash;
+use crate::storage::model::BitcoinTxId;
 use crate::storage::model::StacksBlockHash;
 use crate::storage::model::StacksTxId;
 
@@ -38,6 +41,30 @@ pub enum Payload {
     BitcoinPreSignRequest(BitcoinPreSignRequest),
     /// An acknowledgment of a BitconPreSignRequest
     BitcoinPreSignAck(BitcoinPreSignAck),
+    /// A summary of what the coordinator did during its tenure
+    CoordinatorAttestation(CoordinatorAttestation),
+    /// Digests of the contents of the signer's database
+    DatabaseDigest(DatabaseDigest),
+    /// A request for signers to re-send their deposit decisions
+    DepositDecisionRetryRequest(DepositDecisionRetryRequest),
+    /// A batch of decisions on deposit and withdrawal requests
+    SignerDecisionBatch(SignerDecisionBatch),
+    /// A request for signers to re-send the messages that they stored
+    MessageRedeliveryRequest(MessageRedeliveryRequest),
+    /// Stored messages that are re-sent to the signer that requested them
+    RedeliveredMessages(RedeliveredMessages),
+    /// A refusal to sign a sign request from the coordinator
+    SignRequestRefusal(SignRequestRefusal),
+    /// A vote for a temporary override of the sBTC limits
+    LimitOverrideVote(LimitOverrideVote),
+    /// An endorsement of the new key of the sending signer
+    SignerKeyEndorsement(SignerKeyEndorsement),
+    /// An approval of the emergency recovery of the funds of the signers
+    EmergencyRecoveryApproval(EmergencyRecoveryApproval),
+    /// An acknowledgement of the decisions received from another signer
+    DecisionAcknowledgement(DecisionAcknowledgement),
+    /// A revocation of an approval of the emergency recovery
+    EmergencyRecoveryRevocation(EmergencyRecoveryRevocation),
 }
 
 impl std::fmt::Display for Payload {
@@ -69,6 +96,18 @@ impl std::fmt::Display for Payload {
             }
             Self::BitcoinPreSignRequest(_) => write!(f, "BitcoinPreSignRequest(..)"),
             Self::BitcoinPreSignAck(_) => write!(f, "BitcoinPreSignAck(..)"),
+            Self::CoordinatorAttestation(_) => write!(f, "CoordinatorAttestation(..)"),
+            Self::DatabaseDigest(_) => write!(f, "DatabaseDigest(..)"),
+            Self::DepositDecisionRetryRequest(_) => write!(f, "DepositDecisionRetryRequest(..)"),
+            Self::SignerDecisionBatch(_) => write!(f, "SignerDecisionBatch(..)"),
+            Self::MessageRedeliveryRequest(_) => write!(f, "MessageRedeliveryRequest(..)"),
+            Self::RedeliveredMessages(_) => write!(f, "RedeliveredMessages(..)"),
+            Self::SignRequestRefusal(_) => write!(f, "SignRequestRefusal(..)"),
+            Self::LimitOverrideVote(_) => write!(f, "LimitOverrideVote(..)"),
+            Self::SignerKeyEndorsement(_) => write!(f, "SignerKeyEndorsement(..)"),
+            Self::EmergencyRecoveryApproval(_) => write!(f, "EmergencyRecoveryApproval(..)"),
+            Self::DecisionAcknowledgement(_) => write!(f, "DecisionAcknowledgement(..)"),
+            Self::EmergencyRecoveryRevocation(_) => write!(f, "EmergencyRecoveryRevocation(..)"),
         }
     }
 }
@@ -81,6 +120,73 @@ impl Payload {
             payload: self,
         }
     }
+
+    /// Whether the payload is one that a signer only sends while it runs
+    /// a coordinator tenure.
+    ///
+    /// Deposit decision retry requests are left out, since any signer may
+    /// ask the others to send their decisions again.
+    pub fn is_coordinator_message(&self) -> bool {
+        match self {
+            Self::StacksTransactionSignRequest(_)
+            | Self::BitcoinPreSignRequest(_)
+            | Self::CoordinatorAttestation(_) => true,
+            Self::WstsMessage(msg) => msg.is_coordinator_message(),
+            Self::DepositDecisionRetryRequest(_)
+            | Self::SignerDepositDecision(_)
+            | Self::SignerWithdrawalDecision(_)
+            | Self::StacksTransactionSignature(_)
+            | Self::BitcoinPreSignAck(_)
+            | Self::DatabaseDigest(_)
+            | Self::SignerDecisionBatch(_)
+            | Self::MessageRedeliveryRequest(_)
+            | Self::RedeliveredMessages(_)
+            | Self::SignRequestRefusal(_)
+            | Self::LimitOverrideVote(_)
+            | Self::SignerKeyEndorsement(_)
+            | Self::EmergencyRecoveryApproval(_)
+            | Self::DecisionAcknowledgement(_)
+            | Self::EmergencyRecoveryRevocation(_) => false,
+        }
+    }
+
+    /// A short name for the type of the payload, used as a label in
+    /// metrics and diagnostics. WSTS messages are named after the type of
+    /// the inner WSTS message.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::SignerDepositDecision(_) => "signer_deposit_decision",
+            Self::SignerWithdrawalDecision(_) => "signer_withdrawal_decision",
+            Self::StacksTransactionSignRequest(_) => "stacks_transaction_sign_request",
+            Self::StacksTransactionSignature(_) => "stacks_transaction_signature",
+            Self::WstsMessage(msg) => match msg.inner {
+                wsts::net::Message::DkgBegin(_) => "wsts_dkg_begin",
+                wsts::net::Message::DkgEnd(_) => "wsts_dkg_end",
+                wsts::net::Message::DkgEndBegin(_) => "wsts_dkg_end_begin",
+                wsts::net::Message::DkgPrivateBegin(_) => "wsts_dkg_private_begin",
+                wsts::net::Message::DkgPrivateShares(_) => "wsts_dkg_private_shares",
+                wsts::net::Message::DkgPublicShares(_) => "wsts_dkg_public_shares",
+                wsts::net::Message::NonceRequest(_) => "wsts_nonce_request",
+                wsts::net::Message::NonceResponse(_) => "wsts_nonce_response",
+                wsts::net::Message::SignatureShareRequest(_) => "wsts_signature_share_request",
+                wsts::net::Message::SignatureShareResponse(_) => "wsts_signature_share_response",
+            },
+            Self::BitcoinPreSignRequest(_) => "bitcoin_pre_sign_request",
+            Self::BitcoinPreSignAck(_) => "bitcoin_pre_sign_ack",
+            Self::CoordinatorAttestation(_) => "coordinator_attestation",
+            Self::DatabaseDigest(_) => "database_digest",
+            Self::DepositDecisionRetryRequest(_) => "deposit_decision_retry_request",
+            Self::SignerDecisionBatch(_) => "signer_decision_batch",
+            Self::MessageRedeliveryRequest(_) => "message_redelivery_request",
+            Self::RedeliveredMessages(_) => "redelivered_messages",
+            Self::SignRequestRefusal(_) => "sign_request_refusal",
+            Self::LimitOverrideVote(_) => "limit_override_vote",
+            Self::SignerKeyEndorsement(_) => "signer_key_endorsement",
+            Self::EmergencyRecoveryApproval(_) => "emergency_recovery_approval",
+            Self::DecisionAcknowledgement(_) => "decision_acknowledgement",
+            Self::EmergencyRecoveryRevocation(_) => "emergency_recovery_revocation",
+        }
+    }
 }
 
 impl From<SignerDepositDecision> for Payload {
@@ -125,6 +231,78 @@ impl From<BitcoinPreSignAck> for Payload {
     }
 }
 
+impl From<CoordinatorAttestation> for Payload {
+    fn from(value: CoordinatorAttestation) -> Self {
+        Self::CoordinatorAttestation(value)
+    }
+}
+
+impl From<DatabaseDigest> for Payload {
+    fn from(value: DatabaseDigest) -> Self {
+        Self::DatabaseDigest(value)
+    }
+}
+
+impl From<DepositDecisionRetryRequest> for Payload {
+    fn from(value: DepositDecisionRetryRequest) -> Self {
+        Self::DepositDecisionRetryRequest(value)
+    }
+}
+
+impl From<SignerDecisionBatch> for Payload {
+    fn from(value: SignerDecisionBatch) -> Self {
+        Self::SignerDecisionBatch(value)
+    }
+}
+
+impl From<MessageRedeliveryRequest> for Payload {
+    fn from(value: MessageRedeliveryRequest) -> Self {
+        Self::MessageRedeliveryRequest(value)
+    }
+}
+
+impl From<RedeliveredMessages> for Payload {
+    fn from(value: RedeliveredMessages) -> Self {
+        Self::RedeliveredMessages(value)
+    }
+}
+
+impl From<SignRequestRefusal> for Payload {
+    fn from(value: SignRequestRefusal) -> Self {
+        Self::SignRequestRefusal(value)
+    }
+}
+
+impl From<LimitOverrideVote> for Payload {
+    fn from(value: LimitOverrideVote) -> Self {
+        Self::LimitOverrideVote(value)
+    }
+}
+
+impl From<SignerKeyEndorsement> for Payload {
+    fn from(value: SignerKeyEndorsement) -> Self {
+        Self::SignerKeyEndorsement(value)
+    }
+}
+
+impl From<EmergencyRecoveryApproval> for Payload {
+    fn from(value: EmergencyRecoveryApproval) -> Self {
+        Self::EmergencyRecoveryApproval(value)
+    }
+}
+
+impl From<DecisionAcknowledgement> for Payload {
+    fn from(value: DecisionAcknowledgement) -> Self {
+        Self::DecisionAcknowledgement(value)
+    }
+}
+
+impl From<EmergencyRecoveryRevocation> for Payload {
+    fn from(value: EmergencyRecoveryRevocation) -> Self {
+        Self::EmergencyRecoveryRevocation(value)
+    }
+}
+
 /// Represents a decision related to signer deposit
 #[derive(Debug, Clone, PartialEq)]
 pub struct SignerDepositDecision {
@@ -139,6 +317,13 @@ pub struct SignerDepositDecision {
     /// This specifies whether the sending signer can provide signature
     /// shares for the associated deposit request.
     pub can_sign: bool,
+    /// The sending signer's signature over the receipt digest of the
+    /// deposit request, see [`crate::quorum`]. This is only set if the
+    /// signer accepted the deposit request and can sign for it.
+    pub receipt: Option<secp256k1::ecdsa::Signature>,
+    /// The version of the decision. See
+    /// [`model::DepositSigner::decision_version`].
+    pub decision_version: u32,
 }
 
 impl From<model::DepositSigner> for SignerDepositDecision {
@@ -148,6 +333,8 @@ impl From<model::DepositSigner> for SignerDepositDecision {
             output_index: signer.output_index,
             can_accept: signer.can_accept,
             can_sign: signer.can_sign,
+            receipt: None,
+            decision_version: signer.decision_version,
         }
     }
 }
@@ -165,6 +352,9 @@ pub struct SignerWithdrawalDecision {
     pub txid: StacksTxId,
     /// Whether the signer has accepted the deposit request.
     pub accepted: bool,
+    /// The version of the decision. See
+    /// [`model::WithdrawalSigner::decision_version`].
+    pub decision_version: u32,
 }
 
 impl From<model::WithdrawalSigner> for SignerWithdrawalDecision {
@@ -174,10 +364,66 @@ impl From<model::WithdrawalSigner> for SignerWithdrawalDecision {
             block_hash: signer.block_hash,
             txid: signer.txid,
             accepted: signer.is_accepted,
+            decision_version: signer.decision_version,
         }
     }
 }
 
+/// A batch of the sending signer's decisions on deposit and withdrawal
+/// requests.
+///
+/// Signers send their decisions for a chain tip in batches rather than
+/// one message per decision, which cuts down on the number of messages
+/// sent over the network when a block has many requests.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SignerDecisionBatch {
+    /// The decisions on deposit requests.
+    pub deposits: Vec<SignerDepositDecision>,
+    /// The decisions on withdrawal requests.
+    pub withdrawals: Vec<SignerWithdrawalDecision>,
+}
+
+impl SignerDecisionBatch {
+    /// Whether the batch does not contain any decisions.
+    pub fn is_empty(&self) -> bool {
+        self.deposits.is_empty() && self.withdrawals.is_empty()
+    }
+
+    /// The total number of decisions in the batch.
+    pub fn len(&self) -> usize {
+        self.deposits.len() + self.withdrawals.len()
+    }
+
+    /// Split the batch into batches with at most `max_decisions`
+    /// decisions each, keeping the order of the decisions. Deposit
+    /// decisions come before withdrawal decisions.
+    ///
+    /// An empty batch is split into no batches at all.
+    pub fn split(self, max_decisions: usize) -> Vec<SignerDecisionBatch> {
+        let max_decisions = max_decisions.max(1);
+        let mut batches = Vec::new();
+        let mut current = SignerDecisionBatch::default();
+
+        for decision in self.deposits {
+            if current.len() == max_decisions {
+                batches.push(std::mem::take(&mut current));
+            }
+            current.deposits.push(decision);
+        }
+        for decision in self.withdrawals {
+            if current.len() == max_decisions {
+                batches.push(std::mem::take(&mut current));
+            }
+            current.withdrawals.push(decision);
+        }
+
+        if !current.is_empty() {
+            batches.push(current);
+        }
+        batches
+    }
+}
+
 /// Represents a request to sign a Stacks transaction.
 #[derive(Debug, Clone, PartialEq)]
 pub struct StacksTransactionSignRequest {
@@ -201,7 +447,9 @@ impl StacksTransactionSignRequest {
     pub fn tx_kind(&self) -> &'static str {
         match &self.contract_tx {
             StacksTx::ContractCall(ContractCall::CompleteDepositV1(_)) => "complete-deposit",
+            StacksTx::ContractCall(ContractCall::CompleteDepositsV1(_)) => "complete-deposits",
             StacksTx::ContractCall(ContractCall::AcceptWithdrawalV1(_)) => "accept-withdrawal",
+            StacksTx::ContractCall(ContractCall::AcceptWithdrawalsV1(_)) => "accept-withdrawals",
             StacksTx::ContractCall(ContractCall::RejectWithdrawalV1(_)) => "reject-withdrawal",
             StacksTx::ContractCall(ContractCall::RotateKeysV1(_)) => "rotate-keys",
             StacksTx::SmartContract(_) => "smart-contract-deployment",
@@ -218,6 +466,92 @@ pub struct StacksTransactionSignature {
     pub signature: RecoverableSignature,
 }
 
+/// A refusal to sign, sent by a signer to the coordinator when it does
+/// not sign one of its sign requests.
+///
+/// Signers send these instead of staying silent so that the coordinator
+/// can stop waiting for signatures as soon as enough signers have refused
+/// for the signing threshold to be out of reach.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignRequestRefusal {
+    /// The sign request that the signer refused.
+    pub request: RefusedSignRequest,
+    /// Why the signer refused to sign.
+    pub reason: RefusalReason,
+    /// The deposit and withdrawal requests in the refused request that the
+    /// signer will not sign for. The coordinator can leave these out and
+    /// try again with the rest of the requests.
+    pub contested: TxRequestIds,
+}
+
+/// The sign request refused in a [`SignRequestRefusal`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RefusedSignRequest {
+    /// The [`BitcoinPreSignRequest`] for the bitcoin chain tip of the
+    /// enclosing [`SignerMessage`].
+    BitcoinPreSign,
+    /// The [`StacksTransactionSignRequest`] for the stacks transaction
+    /// with the given ID.
+    StacksTransaction(blockstack_lib::burnchains::Txid),
+}
+
+/// The reasons that a signer can refuse to sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub enum RefusalReason {
+    /// The request failed validation.
+    InvalidRequest,
+    /// The request would exceed one of the limits that the signer
+    /// enforces.
+    LimitExceeded,
+    /// The signer has already signed for the request, or a conflicting
+    /// one, during the tenure.
+    AlreadySigned,
+    /// The request is for a bitcoin chain tip that is no longer the
+    /// canonical one.
+    StaleRequest,
+    /// The signer has paused the processing of the requests.
+    Paused,
+    /// The signer could not process the request, for example because its
+    /// database or one of its nodes is unavailable.
+    Internal,
+}
+
+impl From<&Error> for RefusalReason {
+    fn from(error: &Error) -> Self {
+        match error {
+            Error::ExceedsSbtcSupplyCap { .. }
+            | Error::ExceedsWithdrawalCap(_)
+            | Error::StacksFeeLimitExceeded(..) => Self::LimitExceeded,
+            Error::StacksRequestAlreadySigned(..)
+            | Error::InvalidPresignRequest(_)
+            | Error::ConflictingSigHash { .. }
+            | Error::PrevoutSpendConflict { .. } => Self::AlreadySigned,
+            Error::StaleStacksSignRequest(_) => Self::StaleRequest,
+            Error::PreSignContainsPausedDeposits | Error::PreSignContainsPausedWithdrawals => {
+                Self::Paused
+            }
+            Error::BitcoinValidation(_)
+            | Error::DepositValidation(_)
+            | Error::DepositsValidation(_)
+            | Error::WithdrawalAcceptValidation(_)
+            | Error::WithdrawalsAcceptValidation(_)
+            | Error::WithdrawalRejectValidation(_)
+            | Error::RotateKeysValidation(_)
+            | Error::InvalidDepositQuorumCertificate(_)
+            | Error::PreSignContainsNoRequests
+            | Error::PreSignInvalidFeeRate(_)
+            | Error::UnsupportedSweepTemplateVersion(_)
+            | Error::SignerCoordinatorTxidMismatch(..)
+            | Error::ValidationSignerSet(_)
+            | Error::ContractAlreadyDeployed(_)
+            | Error::ContractVersionMismatch(..) => Self::InvalidRequest,
+            _ => Self::Internal,
+        }
+    }
+}
+
 /// The transaction context needed by the signers to reconstruct the transaction.
 #[derive(Debug, Clone, PartialEq)]
 pub struct BitcoinPreSignRequest {
@@ -231,12 +565,221 @@ pub struct BitcoinPreSignRequest {
     /// The total fee amount and the fee rate for the last transaction that
     /// used this UTXO as an input.
     pub last_fees: Option<Fees>,
+    /// Quorum certificates for the deposit requests in the request
+    /// package. Deposit requests without a certificate are validated
+    /// against the decisions in the signer's own database.
+    pub deposit_certificates: Vec<DepositQuorumCertificate>,
+    /// The outpoints of payments to the signers that the coordinator's
+    /// operator approved for a refund. Each one is refunded in its own
+    /// transaction, separate from the request package.
+    pub refunds: Vec<bitcoin::OutPoint>,
+    /// The version of the template that the coordinator used to construct
+    /// the sweep transactions. Coordinators that predate template
+    /// versioning do not set this, in which case signers construct the
+    /// sweeps with the version that they would propose themselves.
+    pub sweep_template_version: Option<SweepTemplateVersion>,
+    /// Whether the request is for the emergency recovery sweep of the
+    /// signers' UTXO. Such a request has no requests or refunds, see
+    /// [`crate::emergency_recovery`].
+    pub emergency_recovery: bool,
+}
+
+/// A signer's signed receipt of its decision to accept a deposit request.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecisionReceipt {
+    /// The public key of the signer that accepted the deposit request.
+    pub signer_public_key: PublicKey,
+    /// The signer's signature over the receipt digest of the deposit
+    /// request.
+    pub signature: secp256k1::ecdsa::Signature,
+}
+
+/// Proof that enough signers accepted a deposit request.
+///
+/// The certificate is a list of the decision receipts of the signers that
+/// accepted the deposit request, see [`crate::quorum`] for how these are
+/// assembled and verified.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepositQuorumCertificate {
+    /// The outpoint that uniquely identifies the deposit request.
+    pub outpoint: bitcoin::OutPoint,
+    /// The receipts of the signers that accepted the deposit request.
+    pub receipts: Vec<DecisionReceipt>,
 }
 
 /// An acknowledgment of a [`BitcoinPreSignRequest`].
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct BitcoinPreSignAck;
 
+/// A summary of what the coordinator did during its tenure.
+///
+/// The bitcoin chain tip of the tenure is the chain tip of the enclosing
+/// [`SignerMessage`], and the signature over that message binds the
+/// coordinator to the claims made here. This allows anyone holding the
+/// signed message to later compare what the coordinator claimed to have
+/// done with what actually landed on-chain.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CoordinatorAttestation {
+    /// The deposit and withdrawal requests that were included in each of
+    /// the broadcast sweep transactions.
+    pub request_package: Vec<TxRequestIds>,
+    /// The IDs of the sweep transactions that were broadcast, in the same
+    /// order as the request package.
+    pub sweep_txids: Vec<BitcoinTxId>,
+}
+
+impl CoordinatorAttestation {
+    /// Whether the coordinator did not broadcast anything during its
+    /// tenure.
+    pub fn is_empty(&self) -> bool {
+        self.sweep_txids.is_empty()
+    }
+}
+
+/// Digests of the contents of key tables in the signer's database.
+///
+/// The digests cover the range of `num_blocks` bitcoin blocks ending at
+/// the chain tip of the enclosing [`SignerMessage`]. See
+/// [`crate::consistency`] for how they are computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatabaseDigest {
+    /// The number of bitcoin blocks covered by the digests.
+    pub num_blocks: u16,
+    /// A digest of the deposit requests confirmed in the block range.
+    pub deposit_requests: [u8; 32],
+    /// A digest of the signers' decisions on the above deposit requests.
+    pub deposit_decisions: [u8; 32],
+    /// A digest of the metadata of the DKG shares known to the signer.
+    pub dkg_shares: [u8; 32],
+}
+
+/// A request from the coordinator for the given signers to re-send their
+/// decisions on the given deposit requests.
+///
+/// The coordinator sends this when it has not received enough decisions
+/// from the current signing set to include the deposits in a sweep
+/// transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepositDecisionRetryRequest {
+    /// The deposit requests with missing decisions.
+    pub deposits: Vec<bitcoin::OutPoint>,
+    /// The public keys of the signers whose decisions are missing.
+    pub signers: Vec<PublicKey>,
+}
+
+/// A request for the other signers to re-send the recent decision and
+/// presign messages that they have stored.
+///
+/// A signer sends this after it connects to the p2p network, so that a
+/// short restart does not make it miss the messages of the current
+/// tenure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageRedeliveryRequest;
+
+/// Signed messages that a signer stored and re-sends to the signer that
+/// requested them with a [`MessageRedeliveryRequest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedeliveredMessages {
+    /// The public key of the signer that requested the messages.
+    pub recipient: PublicKey,
+    /// The encoded signed messages. Each message keeps the signature of
+    /// the signer that originally sent it, so the recipient verifies them
+    /// just like messages received over the p2p network.
+    pub messages: Vec<Vec<u8>>,
+}
+
+/// A vote for a temporary override of the sBTC limits that Emily reports.
+///
+/// The operator of each signer that agrees with the override has their
+/// signer broadcast the same vote. Signers apply the override once they
+/// have identical votes from at least `signatures_required` members of
+/// the signer set, and only if it is within their own configured bounds.
+/// See [`crate::limit_override`] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LimitOverrideVote {
+    /// The maximum amount of BTC, in sats, that may be pegged-in per
+    /// deposit while the override is active. The per-deposit cap is not
+    /// overridden when this is not set.
+    pub per_deposit_cap: Option<u64>,
+    /// The maximum amount of sBTC, in sats, that may be pegged-out per
+    /// withdrawal while the override is active. The per-withdrawal cap is
+    /// not overridden when this is not set.
+    pub per_withdrawal_cap: Option<u64>,
+    /// The bitcoin block height at which the override expires.
+    pub expires_at_height: model::BitcoinBlockHeight,
+}
+
+/// An endorsement of the key that the operator of a signer is replacing
+/// the current key of the signer with.
+///
+/// The endorsement is signed with the current key of the signer, so the
+/// other signers know that the new key comes from the same operator and
+/// can update their signing sets accordingly. See [`crate::key_rotation`]
+/// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignerKeyEndorsement {
+    /// The public key that replaces the key of the sending signer.
+    pub new_public_key: PublicKey,
+}
+
+/// The approval, by the operator of the sending signer, of sweeping all
+/// the funds of the signers to the pre-agreed recovery scriptPubKey. See
+/// [`crate::emergency_recovery`] for when the sweep happens.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmergencyRecoveryApproval {
+    /// The scriptPubKey that the funds are swept to.
+    pub recovery_script_pubkey: bitcoin::ScriptBuf,
+}
+
+/// The withdrawal, by the operator of the sending signer, of its approval
+/// of the emergency recovery to the given recovery scriptPubKey.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmergencyRecoveryRevocation {
+    /// The scriptPubKey of the approved recovery.
+    pub recovery_script_pubkey: bitcoin::ScriptBuf,
+}
+
+/// An acknowledgement that the sending signer stored the decisions that
+/// another signer sent in a [`SignerDecisionBatch`].
+///
+/// Signers re-send their decisions for a while after making them, and
+/// stop re-sending a decision once every other signer in the signer set
+/// has acknowledged it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionAcknowledgement {
+    /// The public key of the signer that made the decisions.
+    pub decider: PublicKey,
+    /// The acknowledged decisions on deposit requests.
+    pub deposits: Vec<DepositDecisionAck>,
+    /// The acknowledged decisions on withdrawal requests.
+    pub withdrawals: Vec<WithdrawalDecisionAck>,
+}
+
+impl DecisionAcknowledgement {
+    /// Whether the acknowledgement does not cover any decisions.
+    pub fn is_empty(&self) -> bool {
+        self.deposits.is_empty() && self.withdrawals.is_empty()
+    }
+}
+
+/// The acknowledgement of a decision on a deposit request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DepositDecisionAck {
+    /// The outpoint of the deposit request.
+    pub outpoint: bitcoin::OutPoint,
+    /// The version of the decision that was stored.
+    pub decision_version: u32,
+}
+
+/// The acknowledgement of a decision on a withdrawal request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WithdrawalDecisionAck {
+    /// The ID of the withdrawal request.
+    pub id: model::QualifiedRequestId,
+    /// The version of the decision that was stored.
+    pub decision_version: u32,
+}
+
 /// The identifier for a WSTS message.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum WstsMessageId {
@@ -299,6 +842,19 @@ impl WstsMessage {
             wsts::net::Message::SignatureShareResponse(_) => "signature-share-response",
         }
     }
+
+    /// Whether the message is one that the coordinator of a DKG or
+    /// signing round sends.
+    pub fn is_coordinator_message(&self) -> bool {
+        matches!(
+            self.inner,
+            wsts::net::Message::DkgBegin(_)
+                | wsts::net::Message::DkgPrivateBegin(_)
+                | wsts::net::Message::DkgEndBegin(_)
+                | wsts::net::Message::NonceRequest(_)
+                | wsts::net::Message::SignatureShareRequest(_)
+        )
+    }
 }
 
 #[cfg(test)]
@@ -310,6 +866,7 @@ mod tests {
     use crate::ecdsa::{SignEcdsa, Signed};
     use crate::keys::PrivateKey;
 
+    use fake::Fake as _;
     use rand::SeedableRng;
     use test_case::test_case;
 
@@ -319,6 +876,18 @@ mod tests {
     #[test_case(PhantomData::<StacksTransactionSignature> ; "StacksTransactionSignature")]
     #[test_case(PhantomData::<WstsMessage> ; "WstsMessage")]
     #[test_case(PhantomData::<BitcoinPreSignRequest> ; "BitcoinPreSignRequest")]
+    #[test_case(PhantomData::<CoordinatorAttestation> ; "CoordinatorAttestation")]
+    #[test_case(PhantomData::<DatabaseDigest> ; "DatabaseDigest")]
+    #[test_case(PhantomData::<DepositDecisionRetryRequest> ; "DepositDecisionRetryRequest")]
+    #[test_case(PhantomData::<MessageRedeliveryRequest> ; "MessageRedeliveryRequest")]
+    #[test_case(PhantomData::<RedeliveredMessages> ; "RedeliveredMessages")]
+    #[test_case(PhantomData::<SignerDecisionBatch> ; "SignerDecisionBatch")]
+    #[test_case(PhantomData::<SignRequestRefusal> ; "SignRequestRefusal")]
+    #[test_case(PhantomData::<LimitOverrideVote> ; "LimitOverrideVote")]
+    #[test_case(PhantomData::<SignerKeyEndorsement> ; "SignerKeyEndorsement")]
+    #[test_case(PhantomData::<EmergencyRecoveryApproval> ; "EmergencyRecoveryApproval")]
+    #[test_case(PhantomData::<DecisionAcknowledgement> ; "DecisionAcknowledgement")]
+    #[test_case(PhantomData::<EmergencyRecoveryRevocation> ; "EmergencyRecoveryRevocation")]
     fn signer_messages_should_be_signable_with_type<P>(_: PhantomData<P>)
     where
         P: fake::Dummy<fake::Faker> + Into<Payload>,
@@ -338,6 +907,18 @@ mod tests {
     #[test_case(PhantomData::<StacksTransactionSignature> ; "StacksTransactionSignature")]
     #[test_case(PhantomData::<WstsMessage> ; "WstsMessage")]
     #[test_case(PhantomData::<BitcoinPreSignRequest> ; "BitcoinPreSignRequest")]
+    #[test_case(PhantomData::<CoordinatorAttestation> ; "CoordinatorAttestation")]
+    #[test_case(PhantomData::<DatabaseDigest> ; "DatabaseDigest")]
+    #[test_case(PhantomData::<DepositDecisionRetryRequest> ; "DepositDecisionRetryRequest")]
+    #[test_case(PhantomData::<MessageRedeliveryRequest> ; "MessageRedeliveryRequest")]
+    #[test_case(PhantomData::<RedeliveredMessages> ; "RedeliveredMessages")]
+    #[test_case(PhantomData::<SignerDecisionBatch> ; "SignerDecisionBatch")]
+    #[test_case(PhantomData::<SignRequestRefusal> ; "SignRequestRefusal")]
+    #[test_case(PhantomData::<LimitOverrideVote> ; "LimitOverrideVote")]
+    #[test_case(PhantomData::<SignerKeyEndorsement> ; "SignerKeyEndorsement")]
+    #[test_case(PhantomData::<EmergencyRecoveryApproval> ; "EmergencyRecoveryApproval")]
+    #[test_case(PhantomData::<DecisionAcknowledgement> ; "DecisionAcknowledgement")]
+    #[test_case(PhantomData::<EmergencyRecoveryRevocation> ; "EmergencyRecoveryRevocation")]
     fn signer_messages_should_be_encodable_with_type<P>(_: PhantomData<P>)
     where
         P: fake::Dummy<fake::Faker> + Into<Payload>,
@@ -355,4 +936,41 @@ mod tests {
 
         assert_eq!(decoded, signed_message);
     }
+
+    #[test_case(0, 0, 3, 0; "empty batch")]
+    #[test_case(2, 1, 3, 1; "fits in one batch")]
+    #[test_case(3, 3, 3, 2; "exactly two batches")]
+    #[test_case(4, 5, 2, 5; "spills over")]
+    #[test_case(1, 1, 0, 2; "zero max is treated as one")]
+    fn signer_decision_batch_split(
+        num_deposits: usize,
+        num_withdrawals: usize,
+        max_decisions: usize,
+        expected_batches: usize,
+    ) {
+        let rng = &mut rand::rngs::StdRng::seed_from_u64(42);
+        let batch = SignerDecisionBatch {
+            deposits: std::iter::repeat_with(|| fake::Faker.fake_with_rng(rng))
+                .take(num_deposits)
+                .collect(),
+            withdrawals: std::iter::repeat_with(|| fake::Faker.fake_with_rng(rng))
+                .take(num_withdrawals)
+                .collect(),
+        };
+
+        let batches = batch.clone().split(max_decisions);
+        assert_eq!(batches.len(), expected_batches);
+        assert!(batches.iter().all(|b| b.len() <= max_decisions.max(1)));
+        assert!(batches.iter().all(|b| !b.is_empty()));
+
+        // Joining the batches back together gives us the original batch.
+        let joined = batches
+            .into_iter()
+            .fold(SignerDecisionBatch::default(), |mut acc, b| {
+                acc.deposits.extend(b.deposits);
+                acc.withdrawals.extend(b.withdrawals);
+                acc
+            });
+        assert_eq!(joined, batch);
+    }
 }
@@ -4,6 +4,7 @@
 use std::net::SocketAddr;
 use std::time::Duration;
 
+use libp2p::PeerId;
 use metrics_exporter_prometheus::PrometheusBuilder;
 use reqwest::Response;
 
@@ -44,6 +45,18 @@ pub enum Metrics {
     /// The total number of tenures that this signer has served as
     /// coordinator.
     CoordinatorTenuresTotal,
+    /// The total number of tenures that this signer served as coordinator
+    /// in which there was no work to do, so that the tenure ended right
+    /// after planning it.
+    CoordinatorNoopTenuresTotal,
+    /// The height of the bitcoin block that started the latest tenure that
+    /// this signer served as coordinator. The hash of that block is the
+    /// tenure ID in the logs of the tenure.
+    CoordinatorTenureBitcoinBlockHeight,
+    /// The total number of coordinator messages received from another
+    /// signer for a bitcoin chain tip for which this signer is also the
+    /// coordinator.
+    CoordinatorConflictsTotal,
     /// The total number of sign requests received from the signer.
     SignRequestsTotal,
     /// The amount of time it took to complete a signing round in seconds.
@@ -55,6 +68,23 @@ pub enum Metrics {
     ValidationDurationSeconds,
     /// The number of peers connected in the p2p network.
     PeersConnected,
+    /// The total number of messages received from each peer in the p2p
+    /// network. We use labels to distinguish between the peers and the
+    /// types of the message payloads.
+    P2PMessagesReceivedTotal,
+    /// The total number of messages published to each peer in the p2p
+    /// network. We use labels to distinguish between the peers and the
+    /// types of the message payloads.
+    P2PMessagesSentTotal,
+    /// The total number of messages from each peer in the p2p network that
+    /// could not be decoded or verified.
+    P2PDecodeFailuresTotal,
+    /// The total number of times that a peer was disconnected because it
+    /// is not a known signer.
+    P2PPeerBansTotal,
+    /// The round trip time, in seconds, of the last ping to each peer in
+    /// the p2p network.
+    P2PPeerLatencySeconds,
     /// The amount of time, in seconds, it took for a call-read request to
     /// return from the stacks node.
     CallReadOnlyDurationSeconds,
@@ -73,6 +103,54 @@ pub enum Metrics {
     /// The total number of times that a request to read a map entry in a
     /// smart contract has been made to the stacks node.
     ReadMapEntryRequestsTotal,
+    /// The total number of times that a digest of a table in this
+    /// signer's database did not match the digest received from another
+    /// signer. We use a label to distinguish between the tables.
+    DatabaseDigestDivergencesTotal,
+    /// The total number of times that the signers' UTXO in this signer's
+    /// database did not match the UTXO set of bitcoin-core.
+    SignerUtxoMismatchesTotal,
+    /// The total number of entries evicted from the in-memory LRU caches
+    /// of the transaction signer to make room for new ones. We use a label
+    /// to distinguish between the caches.
+    LruCacheEvictionsTotal,
+    /// The number of blocks that the bitcoin chain tip of this signer is
+    /// behind the chain tip of bitcoin-core.
+    BitcoinChainTipLagBlocks,
+    /// The amount of time, in seconds, since the bitcoin chain tip of this
+    /// signer last changed.
+    BitcoinChainTipAgeSeconds,
+    /// The total number of times that the chain tip watchdog asked the
+    /// block observer to resync with bitcoin-core.
+    BitcoinChainTipResyncsTotal,
+    /// The total number of internal signals that a subscriber missed
+    /// because it fell too far behind. We use a label to distinguish
+    /// between the subscribers.
+    SignalsDroppedTotal,
+    /// The total number of times that an event loop was restarted after
+    /// exiting unexpectedly. We use a label to distinguish between the
+    /// event loops.
+    EventLoopRestartsTotal,
+    /// The total number of times that fetching the sBTC limits from Emily
+    /// failed. We use a label to distinguish between falling back to
+    /// cached limits and falling back to zero limits.
+    SbtcLimitsFetchFailuresTotal,
+    /// The total number of errors that the event loops ran into and
+    /// carried on from. We use labels to distinguish between the event
+    /// loops and the categories of the errors.
+    ErrorsTotal,
+    /// The amount of time, in seconds, from observing a bitcoin block to
+    /// reaching a later stage in its processing. We use a label to
+    /// distinguish between the stages.
+    BitcoinBlockProcessingSeconds,
+    /// The total number of verdicts of the policy plugin of the request
+    /// decider. We use labels to distinguish between deposit and
+    /// withdrawal requests and between the verdicts.
+    PolicyPluginVerdictsTotal,
+    /// The total number of bitcoin transactions broadcast by the
+    /// coordinator that bitcoin-core rejected. We use a label to
+    /// distinguish between the reasons for the rejections.
+    BitcoinBroadcastRejectionsTotal,
 }
 
 impl From<Metrics> for metrics::KeyName {
@@ -120,6 +198,45 @@ impl Metrics {
         metrics::gauge!(Metrics::PeersConnected).decrement(1.0);
     }
 
+    /// Increment the counter of messages received from the given peer.
+    pub fn increment_p2p_messages_received(peer_id: &PeerId, payload_type: &'static str) {
+        metrics::counter!(
+            Metrics::P2PMessagesReceivedTotal,
+            "peer_id" => peer_id.to_string(),
+            "payload_type" => payload_type,
+        )
+        .increment(1);
+    }
+
+    /// Increment the counter of messages published to the given peer.
+    pub fn increment_p2p_messages_sent(peer_id: &PeerId, payload_type: &'static str) {
+        metrics::counter!(
+            Metrics::P2PMessagesSentTotal,
+            "peer_id" => peer_id.to_string(),
+            "payload_type" => payload_type,
+        )
+        .increment(1);
+    }
+
+    /// Increment the counter of messages from the given peer that could
+    /// not be decoded or verified.
+    pub fn increment_p2p_decode_failures(peer_id: &PeerId) {
+        metrics::counter!(Metrics::P2PDecodeFailuresTotal, "peer_id" => peer_id.to_string())
+            .increment(1);
+    }
+
+    /// Increment the counter of times that the given peer was
+    /// disconnected for not being a known signer.
+    pub fn increment_p2p_peer_bans(peer_id: &PeerId) {
+        metrics::counter!(Metrics::P2PPeerBansTotal, "peer_id" => peer_id.to_string()).increment(1);
+    }
+
+    /// Record the round trip time of the last ping to the given peer.
+    pub fn record_p2p_peer_latency(peer_id: &PeerId, rtt: Duration) {
+        metrics::gauge!(Metrics::P2PPeerLatencySeconds, "peer_id" => peer_id.to_string())
+            .set(rtt.as_secs_f64());
+    }
+
     /// Increment number of presign requests that were processed noting
     /// whether the presign validation finished successfully. Also record
     /// the amount of time that it took to run the validation.
@@ -1,4 +1,8 @@
 //! New version of the in-memory network
+//!
+//! Like the libp2p network, messages travel on a separate channel for each
+//! [`Topic`], so that a lagging channel for one topic does not affect the
+//! delivery of messages on the others.
 
 use std::sync::atomic::AtomicU8;
 use std::time::Duration;
@@ -16,22 +20,34 @@ use crate::error::Error;
 
 use super::MessageTransfer;
 use super::Msg;
+use super::Topic;
 
 const DEFAULT_WAN_CAPACITY: usize = 10_000;
 
+/// The senders for each of the topics of the WAN network, in the order of
+/// [`Topic::ALL`]. Each sender passes the message along with the ID of the
+/// signer that sent it.
+type TopicSenders = [Sender<(u8, Vec<u8>)>; Topic::ALL.len()];
+
+/// Create the channels for each of the topics, each with the given
+/// capacity.
+fn topic_channels(capacity: usize) -> TopicSenders {
+    Topic::ALL.map(|_| tokio::sync::broadcast::channel(capacity).0)
+}
+
 /// In-memory representation of a WAN network between different signers.
 pub struct WanNetwork {
-    /// A sender that passes the message along with the ID of the signer
-    /// that sent it.
-    tx: Sender<(u8, Vec<u8>)>,
+    /// The senders for each topic.
+    tx: TopicSenders,
     /// A variable with the last ID of the signers.
     id: AtomicU8,
 }
 
 impl WanNetwork {
-    /// Create a new in-memory WAN network with the specified channel capacity.
+    /// Create a new in-memory WAN network with the specified channel
+    /// capacity for each topic.
     pub fn new(capacity: usize) -> Self {
-        let (tx, _) = tokio::sync::broadcast::channel(capacity);
+        let tx = topic_channels(capacity);
         let id = AtomicU8::new(0);
         Self { tx, id }
     }
@@ -58,7 +74,7 @@ impl Default for WanNetwork {
 /// same network.
 #[derive(Debug, Clone)]
 pub struct SignerNetwork {
-    wan_tx: Sender<(u8, Vec<u8>)>,
+    wan_tx: TopicSenders,
     signer_tx: Sender<SignerSignal>,
     id: u8,
 }
@@ -66,8 +82,16 @@ pub struct SignerNetwork {
 impl SignerNetwork {
     /// Start the in-memory signer network
     fn start(&self) {
+        for wan_tx in &self.wan_tx {
+            self.start_topic(wan_tx);
+        }
+    }
+
+    /// Start forwarding the messages of one topic of the WAN network to
+    /// the signer network.
+    fn start_topic(&self, wan_tx: &Sender<(u8, Vec<u8>)>) {
         // We listen to the WAN network and forward messages to the signer network.
-        let mut rx = BroadcastStream::new(self.wan_tx.subscribe());
+        let mut rx = BroadcastStream::new(wan_tx.subscribe());
         // We clone the sender to the signer network to be able to send messages
         let tx = self.signer_tx.clone();
 
@@ -107,23 +131,23 @@ impl SignerNetwork {
     /// Create a new in-memory signer network with a single signer instance.
     /// You can use this if you do not need to simulate multiple signers.
     pub fn single<C: Context>(ctx: &C) -> Self {
-        let (wan_tx, _) = tokio::sync::broadcast::channel(DEFAULT_WAN_CAPACITY);
-        Self::new(ctx, wan_tx, 0)
+        Self::new(ctx, topic_channels(DEFAULT_WAN_CAPACITY), 0)
     }
 
     /// Create a new in-memory signer network.
-    fn new<C: Context>(ctx: &C, wan_tx: Sender<(u8, Vec<u8>)>, id: u8) -> Self {
+    fn new<C: Context>(ctx: &C, wan_tx: TopicSenders, id: u8) -> Self {
         // We create a new broadcast channel for this signer's network.
         let signer_tx = ctx.get_signal_sender();
 
         Self { wan_tx, signer_tx, id }
     }
 
-    /// Sends a message to the WAN network.
+    /// Sends a message to the WAN network, on the topic of its payload.
     fn send(&self, msg: Msg) -> Result<(), Error> {
+        let topic = Topic::for_payload(&msg.payload);
         let encoded_msg = msg.encode_to_vec();
         // Send the message out to the WAN.
-        self.wan_tx
+        self.wan_tx[topic.index()]
             .send((self.id, encoded_msg))
             .inspect_err(|error| tracing::error!(%error, "could not send over the network"))
             .map(|_| ())
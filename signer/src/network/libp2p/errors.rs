@@ -17,6 +17,10 @@ pub enum SignerSwarmError {
     #[error("libp2p error: {0}")]
     LibP2PMessage(&'static str),
 
+    /// A connection was rejected because the remote peer is not pinned
+    #[error("rejected connection with unpinned peer {0}")]
+    UnpinnedPeer(libp2p::PeerId),
+
     /// Generic swarm error
     #[error("swarm error: {0}")]
     Generic(&'static str),
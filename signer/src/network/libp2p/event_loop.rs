@@ -1,32 +1,79 @@
+use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::Duration;
 
 use futures::StreamExt;
+use hashbrown::HashMap;
 use libp2p::kad::RoutingUpdate;
 use libp2p::swarm::SwarmEvent;
-use libp2p::{Swarm, gossipsub, identify, kad, mdns};
+use libp2p::{PeerId, Swarm, gossipsub, identify, kad, mdns};
 use tokio::sync::Mutex;
 
 use crate::codec::Encode;
 use crate::context::{Context, P2PEvent, SignerCommand, SignerSignal};
 use crate::error::Error;
 use crate::network::Msg;
+use crate::network::Topic;
+use crate::network::peers::PeerTopics;
 
-use super::TOPIC;
 use super::swarm::{SignerBehavior, SignerBehaviorEvent};
 
+/// The maximum number of messages that may wait to be published on a
+/// single topic. When the queue of a topic is full, the oldest message in
+/// it is dropped to make room for the new one.
+const MAX_OUTBOX_LEN_PER_TOPIC: usize = 1_000;
+
+/// How often the topics and gossipsub mesh of each peer are copied into
+/// the peer table of the signer state.
+const PEER_TOPICS_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Messages waiting to be published, with a separate queue for each topic
+/// so that a flood of messages on one topic doesn't hold up the messages
+/// on the others.
+#[derive(Debug, Default)]
+struct Outbox {
+    queues: [VecDeque<Msg>; Topic::ALL.len()],
+}
+
+impl Outbox {
+    /// Queue the message for publishing on the topic of its payload.
+    /// Returns the message that was dropped to make room for it, if the
+    /// queue of the topic was full.
+    fn push(&mut self, msg: Msg) -> Option<Msg> {
+        let queue = &mut self.queues[Topic::for_payload(&msg.payload).index()];
+        let dropped = if queue.len() >= MAX_OUTBOX_LEN_PER_TOPIC {
+            queue.pop_front()
+        } else {
+            None
+        };
+        queue.push_back(msg);
+        dropped
+    }
+
+    /// Take all queued messages, with the messages of higher priority
+    /// topics first.
+    fn drain(&mut self) -> Vec<(Topic, Msg)> {
+        Topic::ALL
+            .into_iter()
+            .zip(self.queues.iter_mut())
+            .flat_map(|(topic, queue)| queue.drain(..).map(move |msg| (topic, msg)))
+            .collect()
+    }
+}
+
 #[tracing::instrument(skip_all, name = "swarm")]
 pub async fn run(ctx: &impl Context, swarm: Arc<Mutex<Swarm<SignerBehavior>>>) {
-    // Subscribe to the gossipsub topic.
-    let topic = TOPIC.clone();
-    swarm
-        .lock()
-        .await
-        .behaviour_mut()
-        .gossipsub
-        .subscribe(&TOPIC)
-        // If this doesn't succeed then nothing will work. It should never fail.
-        .expect("failed to subscribe to topic");
+    // Subscribe to the gossipsub topics.
+    for topic in Topic::ALL {
+        swarm
+            .lock()
+            .await
+            .behaviour_mut()
+            .gossipsub
+            .subscribe(topic.ident())
+            // If this doesn't succeed then nothing will work. It should never fail.
+            .expect("failed to subscribe to topic");
+    }
 
     let mut term = ctx.get_termination_handle();
     let mut signal_rx = ctx.get_signal_receiver();
@@ -36,7 +83,7 @@ pub async fn run(ctx: &impl Context, swarm: Arc<Mutex<Swarm<SignerBehavior>>>) {
     // app signalling channel and pushes them into the outbound message queue.
     // This queue is then polled by the `poll_swarm` event loop to publish the
     // messages to the network.
-    let outbox = Mutex::new(Vec::<Msg>::new());
+    let outbox = Mutex::new(Outbox::default());
     let poll_outbound = async {
         tracing::debug!("p2p outbound message polling started");
         loop {
@@ -46,7 +93,11 @@ pub async fn run(ctx: &impl Context, swarm: Arc<Mutex<Swarm<SignerBehavior>>>) {
                 continue;
             };
 
-            outbox.lock().await.push(*payload);
+            if let Some(dropped) = outbox.lock().await.push(*payload) {
+                let msg_id = dropped.id();
+                tracing::warn!(?msg_id, msg = %dropped, "outbox is full; dropping message");
+                let _ = signal_tx.send(P2PEvent::PublishFailure(msg_id).into());
+            }
         }
     };
 
@@ -103,13 +154,19 @@ pub async fn run(ctx: &impl Context, swarm: Arc<Mutex<Swarm<SignerBehavior>>>) {
                         connection_id,
                         endpoint,
                         peer_id,
+                        num_established,
                         ..
                     } => {
                         if !ctx.state().current_signer_set().is_allowed_peer(&peer_id) {
                             tracing::warn!(%connection_id, %peer_id, ?endpoint, "connected to peer, however it is not a known signer; disconnecting");
+                            ctx.state().peer_table().record_ban(peer_id);
                             let _ = swarm.disconnect_peer_id(peer_id);
                         } else {
                             tracing::debug!(%peer_id, ?endpoint, "connected to peer");
+                            ctx.state().peer_table().set_connected(peer_id, true);
+                            if num_established.get() == 1 {
+                                let _ = signal_tx.send(P2PEvent::PeerConnected(peer_id).into());
+                            }
                             if endpoint.is_dialer() && swarm.behaviour().kademlia.is_enabled() {
                                 let kad_addr = endpoint.get_remote_address();
                                 tracing::debug!(%peer_id, %kad_addr, "adding address to kademlia");
@@ -123,14 +180,26 @@ pub async fn run(ctx: &impl Context, swarm: Arc<Mutex<Swarm<SignerBehavior>>>) {
                             }
                         }
                     }
-                    SwarmEvent::ConnectionClosed { peer_id, cause, endpoint, .. } => {
+                    SwarmEvent::ConnectionClosed {
+                        peer_id,
+                        cause,
+                        endpoint,
+                        num_established,
+                        ..
+                    } => {
                         tracing::trace!(%peer_id, ?cause, ?endpoint, "connection closed");
+                        if num_established == 0 {
+                            ctx.state().peer_table().set_connected(peer_id, false);
+                        }
                     }
                     SwarmEvent::IncomingConnection { local_addr, send_back_addr, .. } => {
                         tracing::trace!(%local_addr, %send_back_addr, "incoming connection");
                     }
                     SwarmEvent::Behaviour(SignerBehaviorEvent::Ping(ping)) => {
                         tracing::trace!("ping received: {:?}", ping);
+                        if let Ok(rtt) = ping.result {
+                            ctx.state().peer_table().record_latency(ping.peer, rtt);
+                        }
                     }
                     SwarmEvent::OutgoingConnectionError { connection_id, error, peer_id } => {
                         tracing::trace!(%connection_id, %error, ?peer_id, "outgoing connection error");
@@ -207,24 +276,24 @@ pub async fn run(ctx: &impl Context, swarm: Arc<Mutex<Swarm<SignerBehavior>>>) {
             }
 
             // Drain the outbox and publish the messages to the network.
-            let outbox = outbox.lock().await.drain(..).collect::<Vec<_>>();
-            for payload in outbox {
+            let outbox = outbox.lock().await.drain();
+            for (topic, payload) in outbox {
                 let msg_id = payload.id();
                 tracing::trace!(
                     message_id = hex::encode(msg_id),
                     msg = %payload,
+                    %topic,
                     "publishing message"
                 );
 
                 // Encode the message payload into bytes using the signer codec.
                 let encoded_msg = payload.encode_to_vec();
+                let payload_type = payload.payload.type_name();
 
-                let _ = swarm
-                    .lock()
-                    .await
-                    .behaviour_mut()
-                    .gossipsub
-                    .publish(topic.clone(), encoded_msg)
+                let mut swarm = swarm.lock().await;
+                let gossipsub = &mut swarm.behaviour_mut().gossipsub;
+                let _ = gossipsub
+                    .publish(topic.ident().clone(), encoded_msg)
                     .inspect_err(|error| {
                         // An error occurred while attempting to publish.
                         // Log the error and send a failure signal to the application
@@ -238,11 +307,49 @@ pub async fn run(ctx: &impl Context, swarm: Arc<Mutex<Swarm<SignerBehavior>>>) {
                         // handle the success as needed.
                         tracing::trace!(?msg_id, "message published successfully");
                         let _ = signal_tx.send(P2PEvent::PublishSuccess(msg_id).into());
+
+                        // Messages are published to all peers that are
+                        // subscribed to the topic.
+                        let topic_hash = topic.ident().hash();
+                        for (peer_id, topics) in gossipsub.all_peers() {
+                            if topics.contains(&&topic_hash) {
+                                ctx.state()
+                                    .peer_table()
+                                    .record_message_sent(*peer_id, payload_type);
+                            }
+                        }
                     });
             }
         }
     };
 
+    let snapshot_peer_topics = async {
+        loop {
+            tokio::time::sleep(PEER_TOPICS_SNAPSHOT_INTERVAL).await;
+            let swarm = swarm.lock().await;
+            let gossipsub = &swarm.behaviour().gossipsub;
+
+            let mut topics: HashMap<PeerId, PeerTopics> = HashMap::new();
+            for topic in Topic::ALL {
+                for peer_id in gossipsub.mesh_peers(&topic.ident().hash()) {
+                    topics.entry(*peer_id).or_default().mesh.insert(topic);
+                }
+            }
+            for (peer_id, peer_topics) in gossipsub.all_peers() {
+                let subscribed = Topic::ALL
+                    .into_iter()
+                    .filter(|topic| peer_topics.contains(&&topic.ident().hash()));
+                topics
+                    .entry(*peer_id)
+                    .or_default()
+                    .subscribed
+                    .extend(subscribed);
+            }
+
+            ctx.state().peer_table().update_topics(topics);
+        }
+    };
+
     let log = async {
         loop {
             tokio::time::sleep(Duration::from_secs(60)).await;
@@ -258,6 +365,7 @@ pub async fn run(ctx: &impl Context, swarm: Arc<Mutex<Swarm<SignerBehavior>>>) {
         },
         _ = poll_outbound => {},
         _ = poll_swarm => {},
+        _ = snapshot_peer_topics => {},
         _ = log => {},
     }
 
@@ -404,11 +512,26 @@ fn handle_gossipsub_event(
                         return Err(Error::InvalidSignature)
                     }
 
+                    // Messages must arrive on the topic of their payload,
+                    // otherwise a peer could get around the separation of
+                    // the topics.
+                    let topic = Topic::for_payload(&msg.payload);
+                    if message.topic != topic.ident().hash() {
+                        tracing::warn!(%origin_peer_id, %topic, received_topic = %message.topic, "message received on the wrong topic");
+                        return Err(Error::WrongP2PTopic(topic))
+                    }
+
                     if let Err(error) = msg.verify_digest(digest) {
                         tracing::error!(%origin_peer_id, "connected peer sent an invalid signature");
                         return Err(error)
                     }
 
+                    ctx.state().peer_table().record_message_received(
+                        origin_peer_id,
+                        msg.payload.type_name(),
+                        ctx.clock().now(),
+                    );
+
                     let _ = ctx.get_signal_sender()
                         .send(P2PEvent::MessageReceived(Box::new(msg)).into())
                         .inspect_err(|error| {
@@ -419,6 +542,7 @@ fn handle_gossipsub_event(
                 })
                 .unwrap_or_else(|error| {
                     tracing::warn!(%peer_id, %error, "Failed to decode message");
+                    ctx.state().peer_table().record_decode_failure(peer_id);
                 });
         }
         Event::Subscribed { peer_id, topic } => {
@@ -443,3 +567,54 @@ fn handle_gossipsub_event(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::OsRng;
+
+    use crate::ecdsa::SignEcdsa as _;
+    use crate::keys::PrivateKey;
+    use crate::message::SignerMessage;
+    use crate::message::StacksTransactionSignature;
+    use crate::message::WstsMessage;
+
+    use super::*;
+
+    fn message<P>() -> Msg
+    where
+        P: Into<crate::message::Payload> + fake::Dummy<fake::Faker>,
+    {
+        let private_key = PrivateKey::new(&mut OsRng);
+        SignerMessage::random_with_payload_type::<P, _>(&mut OsRng).sign_ecdsa(&private_key)
+    }
+
+    #[test]
+    fn full_outbox_queue_does_not_affect_other_topics() {
+        let mut outbox = Outbox::default();
+
+        let signature = message::<StacksTransactionSignature>();
+        assert!(outbox.push(signature.clone()).is_none());
+
+        for _ in 0..MAX_OUTBOX_LEN_PER_TOPIC {
+            assert!(outbox.push(message::<WstsMessage>()).is_none());
+        }
+        // The WSTS queue is full now, so the oldest WSTS message is dropped.
+        let dropped = outbox.push(message::<WstsMessage>()).unwrap();
+        assert_eq!(Topic::for_payload(&dropped.payload), Topic::Wsts);
+
+        let drained = outbox.drain();
+        assert_eq!(drained.len(), MAX_OUTBOX_LEN_PER_TOPIC + 1);
+        // WSTS messages are published first, followed by the message on
+        // the lower priority topic.
+        assert!(
+            drained[..MAX_OUTBOX_LEN_PER_TOPIC]
+                .iter()
+                .all(|(topic, _)| *topic == Topic::Wsts)
+        );
+        assert_eq!(
+            drained.last().unwrap(),
+            &(Topic::StacksSignRequests, signature)
+        );
+        assert!(outbox.drain().is_empty());
+    }
+}
@@ -4,6 +4,8 @@ use std::sync::LazyLock;
 
 use libp2p::gossipsub::IdentTopic;
 
+use crate::network::Topic;
+
 mod bootstrap;
 mod errors;
 mod event_loop;
@@ -19,6 +21,15 @@ pub use self::swarm::SignerSwarmBuilder;
 /// The default port for the libp2p network
 pub const DEFAULT_P2P_PORT: u16 = 4122;
 
-/// The topic used for signer gossipsub messages
+/// The gossipsub topics used for signer messages, in the order of
+/// [`Topic::ALL`].
 // NOTE: Using LazyLock (static) instead of LazyCell (const) as IdentTopic is interior mutable.
-pub static TOPIC: LazyLock<IdentTopic> = LazyLock::new(|| IdentTopic::new("sbtc-signer"));
+pub static TOPICS: LazyLock<[IdentTopic; Topic::ALL.len()]> =
+    LazyLock::new(|| Topic::ALL.map(|topic| IdentTopic::new(topic.name())));
+
+impl Topic {
+    /// The gossipsub topic for this class of messages.
+    pub fn ident(self) -> &'static IdentTopic {
+        &TOPICS[self.index()]
+    }
+}
@@ -717,4 +717,77 @@ mod tests {
         handle2.abort();
         handle3.abort();
     }
+
+    #[test(tokio::test)]
+    async fn pinned_swarm_rejects_connections_from_unpinned_peers() {
+        clear_env();
+
+        let mut rng = get_rng();
+        let key1 = PrivateKey::new(&mut rng);
+        let key2 = PrivateKey::new(&mut rng);
+        let key3 = PrivateKey::new(&mut rng);
+
+        let contexts = [key1, key2, key3].map(|key| {
+            let ctx = TestContext::builder()
+                .with_in_memory_storage()
+                .with_mocked_clients()
+                .modify_settings(|settings| {
+                    settings.signer.private_key = key;
+                    settings.signer.p2p.enable_mdns = false;
+                })
+                .build();
+            // Every signer knows about all the other signers, so that only
+            // the pinning prevents signer 3 from connecting to signer 1.
+            for key in [key1, key2, key3] {
+                ctx.state()
+                    .current_signer_set()
+                    .add_signer(PublicKey::from_private_key(&key));
+            }
+            ctx
+        });
+
+        let swarm1_addr = Multiaddr::random_memory();
+
+        // Signer 1 only allows connections from signers 1 and 2.
+        let swarm1 = SignerSwarmBuilder::new(&key1)
+            .enable_memory_transport(true)
+            .add_listen_endpoint(swarm1_addr.clone())
+            .with_pinned_peers([key1, key2].map(|key| PublicKey::from_private_key(&key)))
+            .build()
+            .expect("Failed to build swarm 1");
+        let swarm2 = SignerSwarmBuilder::new(&key2)
+            .enable_memory_transport(true)
+            .add_seed_addr(swarm1_addr.clone())
+            .build()
+            .expect("Failed to build swarm 2");
+        let swarm3 = SignerSwarmBuilder::new(&key3)
+            .enable_memory_transport(true)
+            .add_seed_addr(swarm1_addr)
+            .build()
+            .expect("Failed to build swarm 3");
+
+        let [context1, context2, context3] = contexts;
+        let handles = [
+            (swarm1.clone(), context1),
+            (swarm2, context2),
+            (swarm3.clone(), context3),
+        ]
+        .map(|(mut swarm, ctx)| {
+            tokio::spawn(async move {
+                swarm.start(&ctx).await.unwrap();
+            })
+        });
+
+        // Give the swarms a bit of time to connect.
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        // Signer 2 is connected to signer 1, while the connection from
+        // signer 3 was rejected during the handshake.
+        assert_eq!(swarm1.connection_counters().await.num_established(), 1);
+        assert_eq!(swarm3.connection_counters().await.num_established(), 0);
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
 }
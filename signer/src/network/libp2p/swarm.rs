@@ -1,9 +1,11 @@
+use std::collections::HashSet;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::Arc;
 use std::time::Duration;
 
 use crate::context::Context;
 use crate::keys::PrivateKey;
+use crate::keys::PublicKey;
 use libp2p::core::muxing::StreamMuxerBox;
 use libp2p::core::upgrade::Version;
 use libp2p::identity::Keypair;
@@ -210,6 +212,7 @@ pub struct SignerSwarmBuilder<'a> {
     enable_memory_transport: bool,
     initial_bootstrap_delay: Duration,
     num_signers: u16,
+    pinned_peers: Option<HashSet<PeerId>>,
 }
 
 impl<'a> SignerSwarmBuilder<'a> {
@@ -227,6 +230,7 @@ impl<'a> SignerSwarmBuilder<'a> {
             enable_memory_transport: false,
             initial_bootstrap_delay: Duration::ZERO,
             num_signers: crate::MAX_KEYS,
+            pinned_peers: None,
         }
     }
 
@@ -263,6 +267,20 @@ impl<'a> SignerSwarmBuilder<'a> {
         self
     }
 
+    /// Only allow connections with the peers whose peer IDs are derived
+    /// from the given public keys. The peer ID of the remote is
+    /// authenticated during the Noise or TLS handshake of the transport,
+    /// and connections with any other peer are rejected there, before any
+    /// of the swarm's protocols run on them.
+    pub fn with_pinned_peers<I>(mut self, public_keys: I) -> Self
+    where
+        I: IntoIterator<Item = PublicKey>,
+    {
+        let peers = public_keys.into_iter().map(PeerId::from);
+        self.pinned_peers.get_or_insert_default().extend(peers);
+        self
+    }
+
     /// Sets whether or not this swarm should use the memory transport.
     pub fn enable_memory_transport(mut self, enable: bool) -> Self {
         self.enable_memory_transport = enable;
@@ -393,6 +411,22 @@ impl<'a> SignerSwarmBuilder<'a> {
                 .boxed();
         }
 
+        // If peers are pinned, reject connections with any other peer once
+        // its identity has been authenticated.
+        if let Some(pinned_peers) = self.pinned_peers {
+            let pinned_peers = Arc::new(pinned_peers);
+            transport = transport
+                .and_then(move |(peer_id, muxer), _| {
+                    let result = if pinned_peers.contains(&peer_id) {
+                        Ok((peer_id, muxer))
+                    } else {
+                        Err(SignerSwarmError::UnpinnedPeer(peer_id))
+                    };
+                    std::future::ready(result)
+                })
+                .boxed();
+        }
+
         // Add the DNS transport to the transport.
         transport = libp2p::dns::tokio::Transport::system(transport)
             .map_err(|e| SignerSwarmError::LibP2P(Box::new(e)))?
@@ -11,6 +11,9 @@ pub mod in_memory;
 pub mod in_memory2;
 
 pub mod libp2p;
+pub mod peers;
+pub mod store_and_forward;
+pub mod watcher;
 
 use std::future::Future;
 
@@ -27,6 +30,86 @@ pub type Msg = ecdsa::Signed<message::SignerMessage>;
 /// The unique identifier for a message
 pub type MsgId = [u8; 32];
 
+/// The classes of messages that signers exchange, each of which is
+/// gossiped on its own topic.
+///
+/// Each topic has its own subscription and its own outbound queue, so
+/// that a flood of messages of one class, like decisions on a large batch
+/// of requests, cannot delay the time-critical messages of another class,
+/// like WSTS nonces and signature shares during a signing round.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, strum::Display, serde::Serialize,
+)]
+#[strum(serialize_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
+pub enum Topic {
+    /// Messages of the WSTS signing and DKG rounds.
+    Wsts,
+    /// Requests to sign stacks transactions, and their signatures.
+    StacksSignRequests,
+    /// Bitcoin pre-sign requests and their acknowledgements.
+    Presign,
+    /// Decisions on deposit and withdrawal requests, along with other
+    /// messages that are not time-critical.
+    Decisions,
+}
+
+impl Topic {
+    /// All topics, ordered from the highest publishing priority to the
+    /// lowest.
+    pub const ALL: [Topic; 4] = [
+        Topic::Wsts,
+        Topic::StacksSignRequests,
+        Topic::Presign,
+        Topic::Decisions,
+    ];
+
+    /// The name of the gossip topic.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Topic::Wsts => "sbtc-signer/wsts",
+            Topic::StacksSignRequests => "sbtc-signer/stacks-sign-requests",
+            Topic::Presign => "sbtc-signer/presign",
+            Topic::Decisions => "sbtc-signer/decisions",
+        }
+    }
+
+    /// The position of the topic in [`Topic::ALL`].
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The topic that the given message payload is gossiped on.
+    pub fn for_payload(payload: &message::Payload) -> Self {
+        use message::Payload;
+
+        match payload {
+            Payload::WstsMessage(_) => Topic::Wsts,
+            Payload::StacksTransactionSignRequest(_) | Payload::StacksTransactionSignature(_) => {
+                Topic::StacksSignRequests
+            }
+            Payload::BitcoinPreSignRequest(_) | Payload::BitcoinPreSignAck(_) => Topic::Presign,
+            Payload::SignRequestRefusal(refusal) => match refusal.request {
+                message::RefusedSignRequest::BitcoinPreSign => Topic::Presign,
+                message::RefusedSignRequest::StacksTransaction(_) => Topic::StacksSignRequests,
+            },
+            Payload::SignerDepositDecision(_)
+            | Payload::SignerWithdrawalDecision(_)
+            | Payload::SignerDecisionBatch(_)
+            | Payload::DepositDecisionRetryRequest(_)
+            | Payload::CoordinatorAttestation(_)
+            | Payload::DatabaseDigest(_)
+            | Payload::MessageRedeliveryRequest(_)
+            | Payload::RedeliveredMessages(_)
+            | Payload::LimitOverrideVote(_)
+            | Payload::SignerKeyEndorsement(_)
+            | Payload::EmergencyRecoveryApproval(_)
+            | Payload::DecisionAcknowledgement(_)
+            | Payload::EmergencyRecoveryRevocation(_) => Topic::Decisions,
+        }
+    }
+}
+
 /// Represents the interaction point between signers and the signer network,
 /// allowing signers to exchange messages with each other.
 pub trait MessageTransfer: Clone {
@@ -0,0 +1,266 @@
+//! Statistics about the peers of this signer in the p2p network.
+//!
+//! The [`PeerTable`] is updated by the network event loop and served by
+//! the signer's API, so that operators can see which messages flow to and
+//! from each peer without resorting to packet captures. Each update is
+//! also recorded in the prometheus metrics.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use hashbrown::HashMap;
+use libp2p::PeerId;
+use serde::Serialize;
+use tokio::time::Instant;
+
+use crate::metrics::Metrics;
+use crate::network::Topic;
+
+/// How long after the last message from a peer that we still count it as
+/// reachable, even if we are not connected to it right now.
+pub const RECENTLY_SEEN_WINDOW: Duration = Duration::from_secs(60);
+
+/// What this signer knows about a single peer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PeerStats {
+    /// Whether this signer currently has a connection with the peer.
+    pub connected: bool,
+    /// The topics that the peer is subscribed to.
+    pub subscribed_topics: BTreeSet<Topic>,
+    /// The topics for which the peer is in this signer's gossipsub mesh.
+    pub mesh_topics: BTreeSet<Topic>,
+    /// The number of messages published to the peer, by payload type.
+    pub messages_sent: BTreeMap<&'static str, u64>,
+    /// The number of messages received from the peer, by payload type.
+    pub messages_received: BTreeMap<&'static str, u64>,
+    /// The round trip time, in milliseconds, of the last ping to the peer.
+    pub last_latency_ms: Option<u64>,
+    /// The number of messages from the peer that could not be decoded or
+    /// verified.
+    pub decode_failures: u64,
+    /// The number of times that the peer was disconnected because it is
+    /// not a known signer.
+    pub bans: u64,
+    /// When this signer last received a message from the peer, according
+    /// to the clock of the signer's context.
+    #[serde(skip)]
+    pub last_message_received_at: Option<Instant>,
+}
+
+impl PeerStats {
+    /// Whether this signer is connected to the peer, or received a message
+    /// from it within the [`RECENTLY_SEEN_WINDOW`] before `now`.
+    pub fn is_reachable(&self, now: Instant) -> bool {
+        self.connected
+            || self
+                .last_message_received_at
+                .is_some_and(|at| now.saturating_duration_since(at) <= RECENTLY_SEEN_WINDOW)
+    }
+}
+
+/// The topics of a peer, as seen by the gossipsub behavior.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PeerTopics {
+    /// The topics that the peer is subscribed to.
+    pub subscribed: BTreeSet<Topic>,
+    /// The topics for which the peer is in this signer's mesh.
+    pub mesh: BTreeSet<Topic>,
+}
+
+/// The statistics of all peers that this signer has interacted with.
+#[derive(Debug, Default)]
+pub struct PeerTable {
+    peers: RwLock<HashMap<PeerId, PeerStats>>,
+}
+
+impl PeerTable {
+    fn update<F>(&self, peer_id: PeerId, f: F)
+    where
+        F: FnOnce(&mut PeerStats),
+    {
+        let mut peers = self
+            .peers
+            .write()
+            .expect("BUG: Failed to acquire write lock");
+        f(peers.entry(peer_id).or_default());
+    }
+
+    /// Record whether this signer is connected to the peer.
+    pub fn set_connected(&self, peer_id: PeerId, connected: bool) {
+        self.update(peer_id, |stats| stats.connected = connected);
+    }
+
+    /// Record a message that was received from the peer at the given
+    /// instant.
+    pub fn record_message_received(
+        &self,
+        peer_id: PeerId,
+        payload_type: &'static str,
+        received_at: Instant,
+    ) {
+        Metrics::increment_p2p_messages_received(&peer_id, payload_type);
+        self.update(peer_id, |stats| {
+            *stats.messages_received.entry(payload_type).or_default() += 1;
+            stats.last_message_received_at = Some(received_at);
+        });
+    }
+
+    /// Record a message that was published to the peer.
+    pub fn record_message_sent(&self, peer_id: PeerId, payload_type: &'static str) {
+        Metrics::increment_p2p_messages_sent(&peer_id, payload_type);
+        self.update(peer_id, |stats| {
+            *stats.messages_sent.entry(payload_type).or_default() += 1;
+        });
+    }
+
+    /// Record a message from the peer that could not be decoded or
+    /// verified.
+    pub fn record_decode_failure(&self, peer_id: PeerId) {
+        Metrics::increment_p2p_decode_failures(&peer_id);
+        self.update(peer_id, |stats| stats.decode_failures += 1);
+    }
+
+    /// Record that the peer was disconnected for not being a known signer.
+    pub fn record_ban(&self, peer_id: PeerId) {
+        Metrics::increment_p2p_peer_bans(&peer_id);
+        self.update(peer_id, |stats| stats.bans += 1);
+    }
+
+    /// Record the round trip time of a ping to the peer.
+    pub fn record_latency(&self, peer_id: PeerId, rtt: Duration) {
+        Metrics::record_p2p_peer_latency(&peer_id, rtt);
+        let latency_ms = u64::try_from(rtt.as_millis()).unwrap_or(u64::MAX);
+        self.update(peer_id, |stats| stats.last_latency_ms = Some(latency_ms));
+    }
+
+    /// Replace the topics of all peers with the given topics. Peers that
+    /// are missing from the input have no topics afterwards.
+    pub fn update_topics(&self, mut topics: HashMap<PeerId, PeerTopics>) {
+        let mut peers = self
+            .peers
+            .write()
+            .expect("BUG: Failed to acquire write lock");
+        for (peer_id, stats) in peers.iter_mut() {
+            let peer_topics = topics.remove(peer_id).unwrap_or_default();
+            stats.subscribed_topics = peer_topics.subscribed;
+            stats.mesh_topics = peer_topics.mesh;
+        }
+        for (peer_id, peer_topics) in topics {
+            let stats = peers.entry(peer_id).or_default();
+            stats.subscribed_topics = peer_topics.subscribed;
+            stats.mesh_topics = peer_topics.mesh;
+        }
+    }
+
+    /// Return the number of peers that match the given filter and are
+    /// reachable at the given instant, see [`PeerStats::is_reachable`].
+    pub fn num_reachable<F>(&self, filter: F, now: Instant) -> usize
+    where
+        F: Fn(&PeerId) -> bool,
+    {
+        let peers = self.peers.read().expect("BUG: Failed to acquire read lock");
+        peers
+            .iter()
+            .filter(|(peer_id, stats)| filter(peer_id) && stats.is_reachable(now))
+            .count()
+    }
+
+    /// Return the statistics of all peers, ordered by peer ID.
+    pub fn snapshot(&self) -> BTreeMap<PeerId, PeerStats> {
+        let peers = self.peers.read().expect("BUG: Failed to acquire read lock");
+        peers
+            .iter()
+            .map(|(peer_id, stats)| (*peer_id, stats.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::OsRng;
+
+    use crate::context::Clock;
+    use crate::keys::PrivateKey;
+    use crate::keys::PublicKey;
+
+    use super::*;
+
+    fn random_peer_id() -> PeerId {
+        PublicKey::from_private_key(&PrivateKey::new(&mut OsRng)).into()
+    }
+
+    #[test]
+    fn peer_table_tracks_each_peer_separately() {
+        let table = PeerTable::default();
+        let peer1 = random_peer_id();
+        let peer2 = random_peer_id();
+        let now = Instant::now();
+
+        table.set_connected(peer1, true);
+        table.record_message_received(peer1, "wsts_nonce_response", now);
+        table.record_message_received(peer1, "wsts_nonce_response", now);
+        table.record_message_sent(peer1, "wsts_nonce_request");
+        table.record_latency(peer1, Duration::from_millis(42));
+        table.record_decode_failure(peer2);
+        table.record_ban(peer2);
+
+        let topics = PeerTopics {
+            subscribed: Topic::ALL.into_iter().collect(),
+            mesh: BTreeSet::from([Topic::Wsts]),
+        };
+        table.update_topics(HashMap::from([(peer2, topics.clone())]));
+
+        let snapshot = table.snapshot();
+        assert_eq!(snapshot.len(), 2);
+
+        let stats1 = &snapshot[&peer1];
+        assert!(stats1.connected);
+        assert_eq!(stats1.messages_received["wsts_nonce_response"], 2);
+        assert_eq!(stats1.messages_sent["wsts_nonce_request"], 1);
+        assert_eq!(stats1.last_latency_ms, Some(42));
+        assert!(stats1.subscribed_topics.is_empty());
+        assert_eq!(stats1.decode_failures, 0);
+
+        let stats2 = &snapshot[&peer2];
+        assert!(!stats2.connected);
+        assert!(stats2.messages_received.is_empty());
+        assert_eq!(stats2.decode_failures, 1);
+        assert_eq!(stats2.bans, 1);
+        assert_eq!(stats2.subscribed_topics, topics.subscribed);
+        assert_eq!(stats2.mesh_topics, topics.mesh);
+
+        // Peers that are no longer known to gossipsub lose their topics.
+        table.update_topics(HashMap::new());
+        assert!(table.snapshot()[&peer2].mesh_topics.is_empty());
+    }
+
+    #[test]
+    fn peers_are_reachable_when_connected_or_recently_heard_from() {
+        let clock = Clock::manual();
+        let table = PeerTable::default();
+        let connected = random_peer_id();
+        let heard_from = random_peer_id();
+        let silent = random_peer_id();
+
+        table.set_connected(connected, true);
+        table.record_message_received(heard_from, "signer_deposit_decision", clock.now());
+        table.record_ban(silent);
+
+        assert_eq!(table.num_reachable(|_| true, clock.now()), 2);
+        let not_connected = |peer_id: &PeerId| peer_id != &connected;
+        assert_eq!(table.num_reachable(not_connected, clock.now()), 1);
+
+        table.set_connected(connected, false);
+        assert_eq!(table.num_reachable(|_| true, clock.now()), 1);
+
+        // The peer that we heard from is reachable for the whole window,
+        // and not after it.
+        clock.advance(RECENTLY_SEEN_WINDOW);
+        assert_eq!(table.num_reachable(|_| true, clock.now()), 1);
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(table.num_reachable(|_| true, clock.now()), 0);
+    }
+}
@@ -0,0 +1,475 @@
+//! # Store-and-forward of signer messages
+//!
+//! Gossipsub only delivers messages to the peers that are connected when
+//! the message is published, so a signer that restarts misses the
+//! decisions and pre-sign messages that were sent while it was offline,
+//! even though it may need them to take part in the current tenure.
+//!
+//! When enabled, each signer keeps the decision and pre-sign messages
+//! that it sees for a configured amount of time. After a signer connects
+//! to its first peer it broadcasts a [`MessageRedeliveryRequest`], and
+//! the other signers answer with [`RedeliveredMessages`] holding the
+//! messages they kept. The recipient verifies each message like any
+//! other message from the p2p network before handing it to the rest of
+//! the signer.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use futures::StreamExt as _;
+use hashbrown::HashSet;
+use tokio::time::Instant;
+
+use crate::codec::Encode as _;
+use crate::context::Context;
+use crate::context::P2PEvent;
+use crate::context::SignerCommand;
+use crate::context::SignerEvent;
+use crate::context::SignerSignal;
+use crate::ecdsa::SignEcdsa as _;
+use crate::error::Error;
+use crate::keys::PrivateKey;
+use crate::keys::PublicKey;
+use crate::message::MessageRedeliveryRequest;
+use crate::message::Payload;
+use crate::message::RedeliveredMessages;
+use crate::network::MessageTransfer;
+use crate::network::Msg;
+use crate::network::MsgId;
+
+/// The maximum number of messages that are kept at any time. The oldest
+/// messages are dropped first.
+pub const MAX_STORED_MESSAGES: usize = 10_000;
+
+/// The maximum total size, in bytes, of the messages in a single
+/// [`RedeliveredMessages`] message. This keeps the messages below the
+/// maximum size that gossipsub will transmit.
+pub const MAX_REDELIVERY_BATCH_BYTES: usize = 48 * 1024;
+
+/// How long to wait after connecting to the first peer before requesting
+/// the stored messages, so that gossipsub has time to exchange the topic
+/// subscriptions with the peers.
+pub const REDELIVERY_REQUEST_DELAY: Duration = Duration::from_secs(5);
+
+/// Whether messages with the given payload are kept for redelivery.
+pub fn is_stored_payload(payload: &Payload) -> bool {
+    matches!(
+        payload,
+        Payload::SignerDepositDecision(_)
+            | Payload::SignerWithdrawalDecision(_)
+            | Payload::SignerDecisionBatch(_)
+            | Payload::BitcoinPreSignRequest(_)
+            | Payload::BitcoinPreSignAck(_)
+            | Payload::LimitOverrideVote(_)
+            | Payload::SignerKeyEndorsement(_)
+            | Payload::EmergencyRecoveryApproval(_)
+            | Payload::EmergencyRecoveryRevocation(_)
+    )
+}
+
+/// A message kept for redelivery.
+#[derive(Debug)]
+struct StoredMessage {
+    /// When the message was first seen.
+    seen_at: Instant,
+    /// The identifier of the message.
+    id: MsgId,
+    /// The public key of the signer that sent the message.
+    sender: PublicKey,
+    /// The encoded signed message.
+    encoded: Vec<u8>,
+}
+
+/// The messages kept for redelivery, in the order that they were first
+/// seen.
+#[derive(Debug, Default)]
+pub struct MessageStore {
+    messages: VecDeque<StoredMessage>,
+    ids: HashSet<MsgId>,
+}
+
+impl MessageStore {
+    /// The number of messages in the store.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Whether the store is empty.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Whether the message with the given identifier is in the store.
+    pub fn contains(&self, id: &MsgId) -> bool {
+        self.ids.contains(id)
+    }
+
+    /// Keep the given message if it is one that is redelivered and it is
+    /// not already in the store. Returns whether the message was added.
+    pub fn insert(&mut self, seen_at: Instant, msg: &Msg) -> bool {
+        let id = msg.id();
+        if !is_stored_payload(&msg.payload) || self.ids.contains(&id) {
+            return false;
+        }
+
+        if self.messages.len() >= MAX_STORED_MESSAGES {
+            let oldest = self.messages.pop_front();
+            if let Some(oldest) = oldest {
+                self.ids.remove(&oldest.id);
+            }
+        }
+
+        self.ids.insert(id);
+        self.messages.push_back(StoredMessage {
+            seen_at,
+            id,
+            sender: msg.signer_public_key,
+            encoded: msg.clone().encode_to_vec(),
+        });
+        true
+    }
+
+    /// Drop the messages that were first seen before the given instant.
+    pub fn prune(&mut self, cutoff: Instant) {
+        while let Some(oldest) = self.messages.front() {
+            if oldest.seen_at >= cutoff {
+                break;
+            }
+            self.ids.remove(&oldest.id);
+            self.messages.pop_front();
+        }
+    }
+
+    /// Bundle the stored messages for redelivery to the given signer,
+    /// leaving out the messages that the signer sent itself.
+    pub fn redeliver_to(&self, recipient: PublicKey) -> Vec<RedeliveredMessages> {
+        let mut batches = Vec::new();
+        let mut batch = RedeliveredMessages {
+            recipient,
+            messages: Vec::new(),
+        };
+        let mut batch_bytes = 0;
+
+        for stored in self.messages.iter().filter(|msg| msg.sender != recipient) {
+            let size = stored.encoded.len();
+            if !batch.messages.is_empty() && batch_bytes + size > MAX_REDELIVERY_BATCH_BYTES {
+                let messages = std::mem::take(&mut batch.messages);
+                batches.push(RedeliveredMessages { recipient, messages });
+                batch_bytes = 0;
+            }
+            batch.messages.push(stored.encoded.clone());
+            batch_bytes += size;
+        }
+
+        if !batch.messages.is_empty() {
+            batches.push(batch);
+        }
+        batches
+    }
+}
+
+/// This function defines which messages this event loop is interested
+/// in.
+fn run_loop_message_filter(signal: &SignerSignal) -> bool {
+    matches!(
+        signal,
+        SignerSignal::Command(SignerCommand::Shutdown)
+            | SignerSignal::Command(SignerCommand::P2PPublish(_))
+            | SignerSignal::Event(SignerEvent::P2P(P2PEvent::MessageReceived(_)))
+            | SignerSignal::Event(SignerEvent::P2P(P2PEvent::PeerConnected(_)))
+    )
+}
+
+/// The event loop that keeps recent messages and redelivers them to
+/// signers that request them.
+#[derive(Debug)]
+pub struct StoreAndForwardEventLoop<C, N> {
+    /// The signer context.
+    pub context: C,
+    /// Interface to the signer network.
+    pub network: N,
+    /// Private key of the signer for network communication.
+    pub signer_private_key: PrivateKey,
+    /// How long messages are kept for redelivery.
+    pub retention: Duration,
+    /// The messages kept for redelivery.
+    pub store: MessageStore,
+}
+
+impl<C, N> StoreAndForwardEventLoop<C, N>
+where
+    C: Context,
+    N: MessageTransfer,
+{
+    /// Run the store-and-forward event loop.
+    #[tracing::instrument(skip_all, name = "store-and-forward")]
+    pub async fn run(mut self) -> Result<(), Error> {
+        if self.retention.is_zero() {
+            tracing::debug!("store-and-forward of messages is disabled");
+            return Ok(());
+        }
+
+        let mut signal_stream = self.context.as_signal_stream(run_loop_message_filter);
+        let clock = self.context.clock().clone();
+
+        // The redelivery request is sent once, some time after connecting
+        // to the first peer.
+        let mut redelivery_requested = false;
+        let mut request_at: Option<Instant> = None;
+
+        loop {
+            let request_timer = async {
+                match request_at {
+                    Some(at) => clock.sleep(at.saturating_duration_since(clock.now())).await,
+                    None => std::future::pending().await,
+                }
+            };
+
+            tokio::select! {
+                signal = signal_stream.next() => match signal {
+                    None | Some(SignerSignal::Command(SignerCommand::Shutdown)) => break,
+                    Some(SignerSignal::Command(SignerCommand::P2PPublish(msg))) => {
+                        self.store.insert(clock.now(), &msg);
+                    }
+                    Some(SignerSignal::Event(SignerEvent::P2P(P2PEvent::MessageReceived(msg)))) => {
+                        if let Err(error) = self.handle_signer_message(&msg).await {
+                            tracing::warn!(%error, "error handling signer message");
+                        }
+                    }
+                    Some(SignerSignal::Event(SignerEvent::P2P(P2PEvent::PeerConnected(_)))) => {
+                        if !redelivery_requested && request_at.is_none() {
+                            request_at = Some(clock.now() + REDELIVERY_REQUEST_DELAY);
+                        }
+                    }
+                    Some(_) => {}
+                },
+                _ = request_timer => {
+                    request_at = None;
+                    match self.request_redelivery().await {
+                        Ok(true) => redelivery_requested = true,
+                        // We do not know of a bitcoin chain tip yet, so
+                        // we try again later.
+                        Ok(false) => request_at = Some(clock.now() + REDELIVERY_REQUEST_DELAY),
+                        Err(error) => {
+                            tracing::warn!(%error, "could not request the redelivery of messages");
+                        }
+                    }
+                }
+            }
+        }
+
+        tracing::info!("store-and-forward event loop has been stopped");
+        Ok(())
+    }
+
+    fn signer_public_key(&self) -> PublicKey {
+        PublicKey::from_private_key(&self.signer_private_key)
+    }
+
+    /// Broadcast a request for the other signers to redeliver the messages
+    /// that they stored. Returns `false` if the request was not sent
+    /// because this signer does not know of a bitcoin chain tip yet.
+    async fn request_redelivery(&mut self) -> Result<bool, Error> {
+        let Some(chain_tip) = self.context.state().bitcoin_chain_tip() else {
+            return Ok(false);
+        };
+
+        tracing::info!("requesting the redelivery of recent messages from the other signers");
+        let msg = Payload::from(MessageRedeliveryRequest)
+            .to_message(chain_tip.block_hash)
+            .sign_ecdsa(&self.signer_private_key);
+        self.network.broadcast(msg).await?;
+        Ok(true)
+    }
+
+    async fn handle_signer_message(&mut self, msg: &Msg) -> Result<(), Error> {
+        let now = self.context.clock().now();
+        self.store
+            .prune(now.checked_sub(self.retention).unwrap_or(now));
+
+        match &msg.payload {
+            Payload::MessageRedeliveryRequest(_) => {
+                self.redeliver_messages(msg.signer_public_key).await
+            }
+            Payload::RedeliveredMessages(redelivered)
+                if redelivered.recipient == self.signer_public_key() =>
+            {
+                self.handle_redelivered_messages(redelivered, msg.signer_public_key);
+                Ok(())
+            }
+            _ => {
+                self.store.insert(now, msg);
+                Ok(())
+            }
+        }
+    }
+
+    /// Send the stored messages to the signer that requested them.
+    async fn redeliver_messages(&mut self, requester: PublicKey) -> Result<(), Error> {
+        if requester == self.signer_public_key() {
+            return Ok(());
+        }
+        let Some(chain_tip) = self.context.state().bitcoin_chain_tip() else {
+            return Ok(());
+        };
+
+        let batches = self.store.redeliver_to(requester);
+        tracing::debug!(%requester, num_batches = batches.len(), "redelivering stored messages");
+
+        for batch in batches {
+            let msg = Payload::from(batch)
+                .to_message(chain_tip.block_hash)
+                .sign_ecdsa(&self.signer_private_key);
+            self.network.broadcast(msg).await?;
+        }
+        Ok(())
+    }
+
+    /// Verify the redelivered messages and hand the ones that this signer
+    /// has not seen to the rest of the signer, as if they were received
+    /// over the p2p network.
+    fn handle_redelivered_messages(&mut self, redelivered: &RedeliveredMessages, from: PublicKey) {
+        let signer_set = self.context.state().current_signer_set();
+        let mut num_delivered = 0;
+
+        for encoded in &redelivered.messages {
+            let msg = match Msg::decode_with_digest(encoded) {
+                Ok((msg, digest)) if msg.verify_digest(digest).is_ok() => msg,
+                Ok(_) => {
+                    tracing::warn!(%from, "redelivered message has an invalid signature");
+                    continue;
+                }
+                Err(error) => {
+                    tracing::warn!(%from, %error, "could not decode redelivered message");
+                    continue;
+                }
+            };
+
+            // Signers only redeliver the kinds of messages that they
+            // store, and only the messages of other signers.
+            if !is_stored_payload(&msg.payload)
+                || !signer_set.is_signer(&msg.signer_public_key)
+                || msg.signer_public_key == redelivered.recipient
+                || self.store.contains(&msg.id())
+            {
+                continue;
+            }
+
+            // The message is added to the store when this event loop
+            // receives the signal below.
+            let signal = P2PEvent::MessageReceived(Box::new(msg)).into();
+            if let Err(error) = self.context.signal(signal) {
+                tracing::warn!(%error, "could not signal redelivered message");
+                return;
+            }
+            num_delivered += 1;
+        }
+
+        tracing::debug!(%from, num_delivered, "handled redelivered messages");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::OsRng;
+
+    use crate::message::BitcoinPreSignAck;
+    use crate::message::SignerDecisionBatch;
+    use crate::message::SignerMessage;
+    use crate::message::WstsMessage;
+
+    use super::*;
+
+    fn message<P>(private_key: &PrivateKey) -> Msg
+    where
+        P: Into<Payload> + fake::Dummy<fake::Faker>,
+    {
+        SignerMessage::random_with_payload_type::<P, _>(&mut OsRng).sign_ecdsa(private_key)
+    }
+
+    #[test]
+    fn store_keeps_only_new_decision_and_presign_messages() {
+        let mut store = MessageStore::default();
+        let private_key = PrivateKey::new(&mut OsRng);
+        let now = Instant::now();
+
+        let decisions = message::<SignerDecisionBatch>(&private_key);
+        assert!(store.insert(now, &decisions));
+        assert!(!store.insert(now, &decisions));
+        assert!(store.insert(now, &message::<BitcoinPreSignAck>(&private_key)));
+        assert!(!store.insert(now, &message::<WstsMessage>(&private_key)));
+
+        assert_eq!(store.len(), 2);
+        assert!(store.contains(&decisions.id()));
+    }
+
+    #[test]
+    fn store_prunes_old_messages() {
+        let mut store = MessageStore::default();
+        let private_key = PrivateKey::new(&mut OsRng);
+        let start = Instant::now();
+        let later = start + Duration::from_secs(60);
+
+        let old = message::<BitcoinPreSignAck>(&private_key);
+        let new = message::<SignerDecisionBatch>(&private_key);
+        store.insert(start, &old);
+        store.insert(later, &new);
+
+        store.prune(later);
+        assert_eq!(store.len(), 1);
+        assert!(!store.contains(&old.id()));
+        assert!(store.contains(&new.id()));
+    }
+
+    #[test]
+    fn redelivery_leaves_out_the_messages_of_the_recipient() {
+        let mut store = MessageStore::default();
+        let recipient_key = PrivateKey::new(&mut OsRng);
+        let other_key = PrivateKey::new(&mut OsRng);
+        let recipient = PublicKey::from_private_key(&recipient_key);
+        let now = Instant::now();
+
+        let own = message::<BitcoinPreSignAck>(&recipient_key);
+        let others: Vec<_> = (0..3)
+            .map(|_| message::<BitcoinPreSignAck>(&other_key))
+            .collect();
+        store.insert(now, &own);
+        for msg in &others {
+            store.insert(now, msg);
+        }
+
+        let batches = store.redeliver_to(recipient);
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].recipient, recipient);
+
+        let ids: Vec<MsgId> = batches[0]
+            .messages
+            .iter()
+            .map(|encoded| Msg::decode_with_digest(encoded).unwrap().0.id())
+            .collect();
+        let expected: Vec<MsgId> = others.iter().map(Msg::id).collect();
+        assert_eq!(ids, expected);
+    }
+
+    #[test]
+    fn redelivery_splits_messages_into_bounded_batches() {
+        let mut store = MessageStore::default();
+        let private_key = PrivateKey::new(&mut OsRng);
+        let now = Instant::now();
+
+        while store.len() < 2_000 {
+            store.insert(now, &message::<SignerDecisionBatch>(&private_key));
+        }
+
+        let recipient = PublicKey::from_private_key(&PrivateKey::new(&mut OsRng));
+        let batches = store.redeliver_to(recipient);
+        assert!(batches.len() > 1);
+
+        let total: usize = batches.iter().map(|batch| batch.messages.len()).sum();
+        assert_eq!(total, store.len());
+        for batch in batches {
+            let size: usize = batch.messages.iter().map(Vec::len).sum();
+            assert!(size <= MAX_REDELIVERY_BATCH_BYTES);
+        }
+    }
+}
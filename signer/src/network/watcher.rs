@@ -0,0 +1,26 @@
+//! A network for signers that run as watchers.
+//!
+//! A watcher runs the same pipelines as a signer, but it does not take
+//! part in the p2p network. The [`WatcherNetwork`] stands in for the p2p
+//! network in the event loops of a watcher: messages that they broadcast
+//! are dropped, and no messages are ever received.
+
+use crate::error::Error;
+
+use super::MessageTransfer;
+use super::Msg;
+
+/// A [`MessageTransfer`] that never sends or receives any messages.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WatcherNetwork;
+
+impl MessageTransfer for WatcherNetwork {
+    async fn broadcast(&mut self, msg: Msg) -> Result<(), Error> {
+        tracing::debug!(%msg, "running as a watcher; not broadcasting message");
+        Ok(())
+    }
+
+    async fn receive(&mut self) -> Result<Msg, Error> {
+        std::future::pending().await
+    }
+}
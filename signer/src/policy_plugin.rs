@@ -0,0 +1,379 @@
+//! Policy plugins for the decisions of the request decider.
+//!
+//! Signer operators sometimes have compliance rules of their own for the
+//! deposit and withdrawal requests that they accept, beyond what the
+//! blocklist client checks. A [`PolicyPlugin`] lets them apply those rules
+//! without forking the signer. The request decider hands the plugin a
+//! [`PolicyRequest`] describing each request, and the plugin returns a
+//! [`PolicyDecision`] to accept, reject or abstain, along with a reason.
+//!
+//! A plugin can only make the signer stricter. Operator overrides take
+//! precedence over the plugin, and the plugin is only consulted for
+//! requests that the signer would otherwise accept, so a rejection is the
+//! only verdict that changes the decision. Accepting and abstaining are
+//! both recorded, so that operators can tell which requests the plugin
+//! had an opinion on. If the plugin fails or runs out of time then no
+//! decision is made on the request, just like when the blocklist client
+//! cannot be reached.
+//!
+//! With the `wasm-policy` feature, plugins can be loaded from WebAssembly
+//! modules. Each decision runs in a fresh instance of the module, which
+//! may not import anything from the host and is limited in the amount of
+//! fuel that it consumes. The module must export:
+//!
+//! * `memory`: its linear memory.
+//! * `alloc(len: i32) -> i32`: allocate `len` bytes and return a pointer
+//!   to them. The signer writes the request there, as JSON.
+//! * `evaluate(ptr: i32, len: i32) -> i64`: decide on the request at the
+//!   given pointer, returning the pointer to its JSON encoded decision in
+//!   the upper 32 bits and the length of it in the lower 32 bits.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::config::SignerConfig;
+use crate::error::Error;
+use crate::storage::model;
+
+/// A deposit or withdrawal request that the policy plugin decides on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PolicyRequest {
+    /// A deposit request.
+    Deposit {
+        /// The ID of the bitcoin transaction of the deposit.
+        txid: String,
+        /// The index of the deposit output of the transaction.
+        output_index: u32,
+        /// The amount of the deposit, in sats.
+        amount: u64,
+        /// The maximum fee, in sats, that may be taken from the deposit.
+        max_fee: u64,
+        /// The stacks principal that the sBTC is minted to.
+        recipient: String,
+        /// The hex encoded scriptPubKeys of the inputs that fund the
+        /// deposit.
+        sender_script_pub_keys: Vec<String>,
+    },
+    /// A withdrawal request.
+    Withdrawal {
+        /// The ID of the request in the sBTC registry contract.
+        request_id: u64,
+        /// The ID of the stacks transaction of the request.
+        txid: String,
+        /// The amount of the withdrawal, in sats.
+        amount: u64,
+        /// The maximum fee, in sats, that may be taken from the
+        /// withdrawal.
+        max_fee: u64,
+        /// The hex encoded scriptPubKey that the bitcoin is sent to.
+        recipient: String,
+        /// The stacks principal that requested the withdrawal.
+        sender_address: String,
+    },
+}
+
+impl PolicyRequest {
+    /// The kind of the request, for logs and metrics.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            PolicyRequest::Deposit { .. } => "deposit",
+            PolicyRequest::Withdrawal { .. } => "withdrawal",
+        }
+    }
+}
+
+impl From<&model::DepositRequest> for PolicyRequest {
+    fn from(request: &model::DepositRequest) -> Self {
+        PolicyRequest::Deposit {
+            txid: request.txid.to_string(),
+            output_index: request.output_index,
+            amount: request.amount,
+            max_fee: request.max_fee,
+            recipient: request.recipient.to_string(),
+            sender_script_pub_keys: request
+                .sender_script_pub_keys
+                .iter()
+                .map(|script_pubkey| script_pubkey.to_hex_string())
+                .collect(),
+        }
+    }
+}
+
+impl From<&model::WithdrawalRequest> for PolicyRequest {
+    fn from(request: &model::WithdrawalRequest) -> Self {
+        PolicyRequest::Withdrawal {
+            request_id: request.request_id,
+            txid: request.txid.to_string(),
+            amount: request.amount,
+            max_fee: request.max_fee,
+            recipient: request.recipient.to_hex_string(),
+            sender_address: request.sender_address.to_string(),
+        }
+    }
+}
+
+/// The verdict of the policy plugin on a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::IntoStaticStr)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PolicyVerdict {
+    /// The request follows the policy.
+    Accept,
+    /// The request breaks the policy, so the signer rejects it.
+    Reject,
+    /// The policy has nothing to say about the request.
+    Abstain,
+}
+
+/// The decision of the policy plugin on a request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyDecision {
+    /// The verdict on the request.
+    pub verdict: PolicyVerdict,
+    /// Why the plugin came to its verdict.
+    #[serde(default)]
+    pub reason: String,
+}
+
+/// A policy that the request decider consults on each request that the
+/// signer would otherwise accept.
+///
+/// Deciding on a request is CPU bound, so it is run on a blocking thread.
+pub trait PolicyPlugin: Send + Sync + std::fmt::Debug {
+    /// Decide on the given request.
+    fn evaluate(&self, request: &PolicyRequest) -> Result<PolicyDecision, Error>;
+}
+
+/// Have the given plugin decide on the given request, giving up after
+/// the given amount of time.
+pub async fn evaluate(
+    plugin: Arc<dyn PolicyPlugin>,
+    request: PolicyRequest,
+    timeout: Duration,
+) -> Result<PolicyDecision, Error> {
+    let task = tokio::task::spawn_blocking(move || plugin.evaluate(&request));
+    match tokio::time::timeout(timeout, task).await {
+        Ok(Ok(result)) => result,
+        Ok(Err(error)) => Err(Error::PolicyPlugin(format!("the plugin panicked: {error}"))),
+        Err(_) => Err(Error::PolicyPluginTimeout(timeout)),
+    }
+}
+
+/// Load the policy plugin in the given config, if there is one.
+pub fn from_config(config: &SignerConfig) -> Result<Option<Arc<dyn PolicyPlugin>>, Error> {
+    let Some(path) = config.policy_plugin_path.as_ref() else {
+        return Ok(None);
+    };
+
+    #[cfg(feature = "wasm-policy")]
+    {
+        let plugin = wasm::WasmPolicyPlugin::from_file(path, config.policy_plugin_fuel.get())?;
+        tracing::info!(path = %path.display(), "loaded policy plugin");
+        Ok(Some(Arc::new(plugin)))
+    }
+    #[cfg(not(feature = "wasm-policy"))]
+    {
+        Err(Error::PolicyPlugin(format!(
+            "cannot load {}: the signer was built without the wasm-policy feature",
+            path.display()
+        )))
+    }
+}
+
+/// Policy plugins implemented as WebAssembly modules.
+#[cfg(feature = "wasm-policy")]
+pub mod wasm {
+    use std::path::Path;
+
+    use wasmi::Config;
+    use wasmi::Engine;
+    use wasmi::Linker;
+    use wasmi::Module;
+    use wasmi::Store;
+
+    use super::PolicyDecision;
+    use super::PolicyPlugin;
+    use super::PolicyRequest;
+    use crate::error::Error;
+
+    /// The maximum size, in bytes, of the decision of a plugin.
+    const MAX_DECISION_SIZE: usize = 64 * 1024;
+
+    fn plugin_error(error: impl std::fmt::Display) -> Error {
+        Error::PolicyPlugin(error.to_string())
+    }
+
+    /// A policy plugin loaded from a WebAssembly module.
+    pub struct WasmPolicyPlugin {
+        engine: Engine,
+        module: Module,
+        fuel: u64,
+    }
+
+    impl std::fmt::Debug for WasmPolicyPlugin {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("WasmPolicyPlugin")
+                .field("fuel", &self.fuel)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl WasmPolicyPlugin {
+        /// Load a plugin from the given binary WebAssembly module, which
+        /// may consume up to the given amount of fuel for each decision.
+        pub fn new(wasm: &[u8], fuel: u64) -> Result<Self, Error> {
+            let mut config = Config::default();
+            config.consume_fuel(true);
+            let engine = Engine::new(&config);
+            let module = Module::new(&engine, wasm).map_err(plugin_error)?;
+
+            // Plugins are sandboxed by not giving them access to anything
+            // on the host.
+            if module.imports().next().is_some() {
+                return Err(Error::PolicyPlugin(
+                    "the plugin must not import anything".to_string(),
+                ));
+            }
+
+            Ok(Self { engine, module, fuel })
+        }
+
+        /// Load a plugin from the WebAssembly module at the given path.
+        pub fn from_file(path: &Path, fuel: u64) -> Result<Self, Error> {
+            let wasm = std::fs::read(path).map_err(|error| {
+                Error::PolicyPlugin(format!("could not read {}: {error}", path.display()))
+            })?;
+            Self::new(&wasm, fuel)
+        }
+    }
+
+    impl PolicyPlugin for WasmPolicyPlugin {
+        fn evaluate(&self, request: &PolicyRequest) -> Result<PolicyDecision, Error> {
+            // Each decision gets a fresh instance, so that nothing carries
+            // over from one request to the next.
+            let mut store = Store::new(&self.engine, ());
+            store.set_fuel(self.fuel).map_err(plugin_error)?;
+            let instance = Linker::<()>::new(&self.engine)
+                .instantiate(&mut store, &self.module)
+                .and_then(|instance| instance.start(&mut store))
+                .map_err(plugin_error)?;
+
+            let memory = instance
+                .get_memory(&store, "memory")
+                .ok_or_else(|| plugin_error("the plugin does not export its memory"))?;
+            let alloc = instance
+                .get_typed_func::<i32, i32>(&store, "alloc")
+                .map_err(plugin_error)?;
+            let evaluate = instance
+                .get_typed_func::<(i32, i32), i64>(&store, "evaluate")
+                .map_err(plugin_error)?;
+
+            let input = serde_json::to_vec(request).map_err(Error::JsonSerialize)?;
+            let input_len = i32::try_from(input.len())
+                .map_err(|_| plugin_error("the request is too large for the plugin"))?;
+            let input_ptr = alloc.call(&mut store, input_len).map_err(plugin_error)?;
+            memory
+                .write(&mut store, input_ptr as u32 as usize, &input)
+                .map_err(plugin_error)?;
+
+            let output = evaluate
+                .call(&mut store, (input_ptr, input_len))
+                .map_err(plugin_error)?;
+            let output_ptr = (output >> 32) as u32 as usize;
+            let output_len = output as u32 as usize;
+            if output_len > MAX_DECISION_SIZE {
+                return Err(plugin_error("the decision of the plugin is too large"));
+            }
+
+            let mut decision = vec![0; output_len];
+            memory
+                .read(&store, output_ptr, &mut decision)
+                .map_err(plugin_error)?;
+            serde_json::from_slice(&decision)
+                .map_err(|error| plugin_error(format!("invalid decision: {error}")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+    use fake::Faker;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct StaticPolicy(PolicyVerdict);
+
+    impl PolicyPlugin for StaticPolicy {
+        fn evaluate(&self, _: &PolicyRequest) -> Result<PolicyDecision, Error> {
+            Ok(PolicyDecision {
+                verdict: self.0,
+                reason: "static".to_string(),
+            })
+        }
+    }
+
+    #[derive(Debug)]
+    struct SlowPolicy;
+
+    impl PolicyPlugin for SlowPolicy {
+        fn evaluate(&self, _: &PolicyRequest) -> Result<PolicyDecision, Error> {
+            std::thread::sleep(Duration::from_millis(200));
+            Err(Error::PolicyPlugin("too slow".to_string()))
+        }
+    }
+
+    #[test]
+    fn requests_are_tagged_with_their_kind() {
+        let deposit: model::DepositRequest = Faker.fake();
+        let json = serde_json::to_value(PolicyRequest::from(&deposit)).unwrap();
+        assert_eq!(json["kind"], "deposit");
+        assert_eq!(json["amount"], deposit.amount);
+
+        let withdrawal: model::WithdrawalRequest = Faker.fake();
+        let json = serde_json::to_value(PolicyRequest::from(&withdrawal)).unwrap();
+        assert_eq!(json["kind"], "withdrawal");
+        assert_eq!(json["request_id"], withdrawal.request_id);
+    }
+
+    #[test]
+    fn decisions_may_leave_out_the_reason() {
+        let decision: PolicyDecision = serde_json::from_str(r#"{"verdict":"reject"}"#).unwrap();
+        assert_eq!(decision.verdict, PolicyVerdict::Reject);
+        assert!(decision.reason.is_empty());
+    }
+
+    #[tokio::test]
+    async fn evaluations_are_time_limited() {
+        let deposit: model::DepositRequest = Faker.fake();
+        let request = PolicyRequest::from(&deposit);
+
+        let plugin = Arc::new(StaticPolicy(PolicyVerdict::Reject));
+        let decision = evaluate(plugin, request.clone(), Duration::from_secs(1))
+            .await
+            .unwrap();
+        assert_eq!(decision.verdict, PolicyVerdict::Reject);
+
+        let result = evaluate(Arc::new(SlowPolicy), request, Duration::from_millis(10)).await;
+        assert!(matches!(result, Err(Error::PolicyPluginTimeout(_))));
+    }
+
+    #[cfg(feature = "wasm-policy")]
+    #[test]
+    fn wasm_plugins_may_not_import_anything() {
+        // A module that imports the function `f` from `env`.
+        let wasm = [
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+            0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section
+            0x02, 0x09, 0x01, 0x03, 0x65, 0x6e, 0x76, 0x01, 0x66, 0x00,
+            0x00, // import section
+        ];
+        let result = wasm::WasmPolicyPlugin::new(&wasm, 1_000);
+        assert!(matches!(result, Err(Error::PolicyPlugin(_))));
+    }
+}
@@ -46,17 +46,37 @@ use crate::error::Error;
 use crate::keys::PublicKey;
 use crate::message::BitcoinPreSignAck;
 use crate::message::BitcoinPreSignRequest;
+use crate::message::CoordinatorAttestation;
+use crate::message::DatabaseDigest;
+use crate::message::DecisionAcknowledgement;
+use crate::message::DecisionReceipt;
+use crate::message::DepositDecisionAck;
+use crate::message::DepositDecisionRetryRequest;
+use crate::message::DepositQuorumCertificate;
+use crate::message::EmergencyRecoveryApproval;
+use crate::message::EmergencyRecoveryRevocation;
+use crate::message::LimitOverrideVote;
+use crate::message::MessageRedeliveryRequest;
 use crate::message::Payload;
+use crate::message::RedeliveredMessages;
+use crate::message::RefusalReason;
+use crate::message::RefusedSignRequest;
+use crate::message::SignRequestRefusal;
+use crate::message::SignerDecisionBatch;
 use crate::message::SignerDepositDecision;
+use crate::message::SignerKeyEndorsement;
 use crate::message::SignerMessage;
 use crate::message::SignerWithdrawalDecision;
 use crate::message::StacksTransactionSignRequest;
 use crate::message::StacksTransactionSignature;
+use crate::message::WithdrawalDecisionAck;
 use crate::message::WstsMessage;
 use crate::message::WstsMessageId;
 use crate::proto;
 use crate::stacks::contracts::AcceptWithdrawalV1;
+use crate::stacks::contracts::AcceptWithdrawalsV1;
 use crate::stacks::contracts::CompleteDepositV1;
+use crate::stacks::contracts::CompleteDepositsV1;
 use crate::stacks::contracts::ContractCall;
 use crate::stacks::contracts::RejectWithdrawalV1;
 use crate::stacks::contracts::RotateKeysV1;
@@ -64,10 +84,14 @@ use crate::stacks::contracts::SmartContract;
 use crate::stacks::contracts::StacksTx;
 use crate::storage::model::BitcoinBlockHash;
 use crate::storage::model::BitcoinTxId;
+use crate::storage::model::DkgBeginRefusal;
+use crate::storage::model::DkgBeginRefusalReason;
+use crate::storage::model::DkgSharesStatus;
 use crate::storage::model::QualifiedRequestId;
 use crate::storage::model::StacksBlockHash;
 use crate::storage::model::StacksPrincipal;
 use crate::storage::model::StacksTxId;
+use crate::storage::model::TenurePlan;
 
 use super::wsts_message;
 
@@ -339,6 +363,8 @@ impl From<SignerDepositDecision> for proto::SignerDepositDecision {
             }),
             can_accept: value.can_accept,
             can_sign: value.can_sign,
+            receipt: value.receipt.map(proto::EcdsaSignature::from),
+            decision_version: value.decision_version,
         }
     }
 }
@@ -352,6 +378,8 @@ impl TryFrom<proto::SignerDepositDecision> for SignerDepositDecision {
             output_index: outpoint.vout,
             can_accept: value.can_accept,
             can_sign: value.can_sign,
+            receipt: value.receipt.map(TryInto::try_into).transpose()?,
+            decision_version: value.decision_version,
         })
     }
 }
@@ -363,6 +391,7 @@ impl From<SignerWithdrawalDecision> for proto::SignerWithdrawalDecision {
             block_id: Some(value.block_hash.into()),
             accepted: value.accepted,
             txid: Some(value.txid.into()),
+            decision_version: value.decision_version,
         }
     }
 }
@@ -375,6 +404,7 @@ impl TryFrom<proto::SignerWithdrawalDecision> for SignerWithdrawalDecision {
             block_hash: StacksBlockHash::try_from(value.block_id.required()?)?,
             accepted: value.accepted,
             txid: value.txid.required()?.try_into()?,
+            decision_version: value.decision_version,
         })
     }
 }
@@ -437,6 +467,52 @@ impl TryFrom<proto::AcceptWithdrawal> for AcceptWithdrawalV1 {
     }
 }
 
+impl From<CompleteDepositsV1> for proto::CompleteDeposits {
+    fn from(value: CompleteDepositsV1) -> Self {
+        proto::CompleteDeposits {
+            deposits: value.deposits.into_iter().map(Into::into).collect(),
+            deployer: Some(value.deployer.into()),
+        }
+    }
+}
+
+impl TryFrom<proto::CompleteDeposits> for CompleteDepositsV1 {
+    type Error = Error;
+    fn try_from(value: proto::CompleteDeposits) -> Result<Self, Self::Error> {
+        Ok(CompleteDepositsV1 {
+            deposits: value
+                .deposits
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, Error>>()?,
+            deployer: value.deployer.required()?.try_into()?,
+        })
+    }
+}
+
+impl From<AcceptWithdrawalsV1> for proto::AcceptWithdrawals {
+    fn from(value: AcceptWithdrawalsV1) -> Self {
+        proto::AcceptWithdrawals {
+            withdrawals: value.withdrawals.into_iter().map(Into::into).collect(),
+            deployer: Some(value.deployer.into()),
+        }
+    }
+}
+
+impl TryFrom<proto::AcceptWithdrawals> for AcceptWithdrawalsV1 {
+    type Error = Error;
+    fn try_from(value: proto::AcceptWithdrawals) -> Result<Self, Self::Error> {
+        Ok(AcceptWithdrawalsV1 {
+            withdrawals: value
+                .withdrawals
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, Error>>()?,
+            deployer: value.deployer.required()?.try_into()?,
+        })
+    }
+}
+
 impl From<RejectWithdrawalV1> for proto::RejectWithdrawal {
     fn from(value: RejectWithdrawalV1) -> Self {
         proto::RejectWithdrawal {
@@ -528,6 +604,16 @@ impl From<StacksTransactionSignRequest> for proto::StacksTransactionSignRequest
                         (*inner).into(),
                     )
                 }
+                ContractCall::AcceptWithdrawalsV1(inner) => {
+                    proto::stacks_transaction_sign_request::ContractTx::AcceptWithdrawals(
+                        (*inner).into(),
+                    )
+                }
+                ContractCall::CompleteDepositsV1(inner) => {
+                    proto::stacks_transaction_sign_request::ContractTx::CompleteDeposits(
+                        (*inner).into(),
+                    )
+                }
                 ContractCall::RejectWithdrawalV1(inner) => {
                     proto::stacks_transaction_sign_request::ContractTx::RejectWithdrawal(
                         (*inner).into(),
@@ -563,6 +649,12 @@ impl TryFrom<proto::StacksTransactionSignRequest> for StacksTransactionSignReque
             proto::ContractTx::AcceptWithdrawal(inner) => StacksTx::ContractCall(
                 ContractCall::AcceptWithdrawalV1(Box::new(inner.try_into()?)),
             ),
+            proto::ContractTx::AcceptWithdrawals(inner) => StacksTx::ContractCall(
+                ContractCall::AcceptWithdrawalsV1(Box::new(inner.try_into()?)),
+            ),
+            proto::ContractTx::CompleteDeposits(inner) => StacksTx::ContractCall(
+                ContractCall::CompleteDepositsV1(Box::new(inner.try_into()?)),
+            ),
             proto::ContractTx::RejectWithdrawal(inner) => StacksTx::ContractCall(
                 ContractCall::RejectWithdrawalV1(Box::new(inner.try_into()?)),
             ),
@@ -1173,6 +1265,143 @@ impl TryFrom<proto::QualifiedRequestId> for QualifiedRequestId {
     }
 }
 
+impl From<TenurePlan> for proto::TenurePlan {
+    fn from(value: TenurePlan) -> Self {
+        proto::TenurePlan {
+            bitcoin_chain_tip: Some(value.bitcoin_chain_tip.into()),
+            coordinator_public_key: Some(value.coordinator_public_key.into()),
+            deploy_contracts: value.deploy_contracts,
+            rotate_keys: value.rotate_keys,
+            deposits_to_sweep: value.deposits_to_sweep,
+            withdrawals_to_sweep: value.withdrawals_to_sweep,
+            deposits_to_complete: value.deposits_to_complete,
+            withdrawals_to_accept: value.withdrawals_to_accept,
+            withdrawals_to_reject: value.withdrawals_to_reject,
+        }
+    }
+}
+
+impl TryFrom<proto::TenurePlan> for TenurePlan {
+    type Error = Error;
+    fn try_from(value: proto::TenurePlan) -> Result<Self, Self::Error> {
+        Ok(TenurePlan {
+            bitcoin_chain_tip: value.bitcoin_chain_tip.required()?.try_into()?,
+            coordinator_public_key: value.coordinator_public_key.required()?.try_into()?,
+            deploy_contracts: value.deploy_contracts,
+            rotate_keys: value.rotate_keys,
+            deposits_to_sweep: value.deposits_to_sweep,
+            withdrawals_to_sweep: value.withdrawals_to_sweep,
+            deposits_to_complete: value.deposits_to_complete,
+            withdrawals_to_accept: value.withdrawals_to_accept,
+            withdrawals_to_reject: value.withdrawals_to_reject,
+        })
+    }
+}
+
+impl From<DkgBeginRefusalReason> for proto::DkgBeginRefusalReason {
+    fn from(value: DkgBeginRefusalReason) -> Self {
+        match value {
+            DkgBeginRefusalReason::LatestSharesUnverified => {
+                proto::DkgBeginRefusalReason::LatestSharesUnverified
+            }
+            DkgBeginRefusalReason::TargetRoundsReached => {
+                proto::DkgBeginRefusalReason::TargetRoundsReached
+            }
+            DkgBeginRefusalReason::BelowMinimumHeight => {
+                proto::DkgBeginRefusalReason::BelowMinimumHeight
+            }
+            DkgBeginRefusalReason::RerunHeightNotConfigured => {
+                proto::DkgBeginRefusalReason::RerunHeightNotConfigured
+            }
+        }
+    }
+}
+
+impl TryFrom<proto::DkgBeginRefusalReason> for DkgBeginRefusalReason {
+    type Error = Error;
+    fn try_from(value: proto::DkgBeginRefusalReason) -> Result<Self, Self::Error> {
+        Ok(match value {
+            proto::DkgBeginRefusalReason::LatestSharesUnverified => {
+                DkgBeginRefusalReason::LatestSharesUnverified
+            }
+            proto::DkgBeginRefusalReason::TargetRoundsReached => {
+                DkgBeginRefusalReason::TargetRoundsReached
+            }
+            proto::DkgBeginRefusalReason::BelowMinimumHeight => {
+                DkgBeginRefusalReason::BelowMinimumHeight
+            }
+            proto::DkgBeginRefusalReason::RerunHeightNotConfigured => {
+                DkgBeginRefusalReason::RerunHeightNotConfigured
+            }
+            proto::DkgBeginRefusalReason::Unspecified => return Err(Error::TypeConversion),
+        })
+    }
+}
+
+impl From<DkgSharesStatus> for proto::DkgSharesStatus {
+    fn from(value: DkgSharesStatus) -> Self {
+        match value {
+            DkgSharesStatus::Unverified => proto::DkgSharesStatus::Unverified,
+            DkgSharesStatus::Verified => proto::DkgSharesStatus::Verified,
+            DkgSharesStatus::Failed => proto::DkgSharesStatus::Failed,
+        }
+    }
+}
+
+impl TryFrom<proto::DkgSharesStatus> for DkgSharesStatus {
+    type Error = Error;
+    fn try_from(value: proto::DkgSharesStatus) -> Result<Self, Self::Error> {
+        Ok(match value {
+            proto::DkgSharesStatus::Unverified => DkgSharesStatus::Unverified,
+            proto::DkgSharesStatus::Verified => DkgSharesStatus::Verified,
+            proto::DkgSharesStatus::Failed => DkgSharesStatus::Failed,
+            proto::DkgSharesStatus::Unspecified => return Err(Error::TypeConversion),
+        })
+    }
+}
+
+impl From<DkgBeginRefusal> for proto::DkgBeginRefusal {
+    fn from(value: DkgBeginRefusal) -> Self {
+        proto::DkgBeginRefusal {
+            reason: proto::DkgBeginRefusalReason::from(value.reason).into(),
+            bitcoin_chain_tip: Some(value.bitcoin_chain_tip.into()),
+            bitcoin_chain_tip_height: *value.bitcoin_chain_tip_height,
+            latest_dkg_shares_status: value
+                .latest_dkg_shares_status
+                .map(|status| proto::DkgSharesStatus::from(status).into()),
+            dkg_shares_count: value.dkg_shares_count,
+            dkg_target_rounds: value.dkg_target_rounds,
+            dkg_min_bitcoin_block_height: value.dkg_min_bitcoin_block_height.map(|height| *height),
+        }
+    }
+}
+
+impl TryFrom<proto::DkgBeginRefusal> for DkgBeginRefusal {
+    type Error = Error;
+    fn try_from(value: proto::DkgBeginRefusal) -> Result<Self, Self::Error> {
+        let reason = proto::DkgBeginRefusalReason::try_from(value.reason)
+            .map_err(|_| Error::TypeConversion)?
+            .try_into()?;
+        let latest_dkg_shares_status = value
+            .latest_dkg_shares_status
+            .map(|status| {
+                proto::DkgSharesStatus::try_from(status)
+                    .map_err(|_| Error::TypeConversion)?
+                    .try_into()
+            })
+            .transpose()?;
+        Ok(DkgBeginRefusal {
+            reason,
+            bitcoin_chain_tip: value.bitcoin_chain_tip.required()?.try_into()?,
+            bitcoin_chain_tip_height: value.bitcoin_chain_tip_height.into(),
+            latest_dkg_shares_status,
+            dkg_shares_count: value.dkg_shares_count,
+            dkg_target_rounds: value.dkg_target_rounds,
+            dkg_min_bitcoin_block_height: value.dkg_min_bitcoin_block_height.map(Into::into),
+        })
+    }
+}
+
 impl From<TxRequestIds> for proto::TxRequestIds {
     fn from(value: TxRequestIds) -> Self {
         proto::TxRequestIds {
@@ -1232,6 +1461,14 @@ impl From<BitcoinPreSignRequest> for proto::BitcoinPreSignRequest {
                 .collect(),
             fee_rate: value.fee_rate,
             last_fees: value.last_fees.map(|v| v.into()),
+            deposit_certificates: value
+                .deposit_certificates
+                .into_iter()
+                .map(|v| v.into())
+                .collect(),
+            refunds: value.refunds.into_iter().map(|v| v.into()).collect(),
+            sweep_template_version: value.sweep_template_version.map_or(0, u32::from),
+            emergency_recovery: value.emergency_recovery,
         }
     }
 }
@@ -1247,6 +1484,63 @@ impl TryFrom<proto::BitcoinPreSignRequest> for BitcoinPreSignRequest {
                 .collect::<Result<Vec<_>, _>>()?,
             fee_rate: value.fee_rate,
             last_fees: value.last_fees.map(|v| v.into()),
+            deposit_certificates: value
+                .deposit_certificates
+                .into_iter()
+                .map(|v| v.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+            refunds: value
+                .refunds
+                .into_iter()
+                .map(|v| v.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+            sweep_template_version: match value.sweep_template_version {
+                0 => None,
+                version => Some(version.try_into()?),
+            },
+            emergency_recovery: value.emergency_recovery,
+        })
+    }
+}
+
+impl From<DecisionReceipt> for proto::DecisionReceipt {
+    fn from(value: DecisionReceipt) -> Self {
+        proto::DecisionReceipt {
+            signer_public_key: Some(value.signer_public_key.into()),
+            signature: Some(value.signature.into()),
+        }
+    }
+}
+
+impl TryFrom<proto::DecisionReceipt> for DecisionReceipt {
+    type Error = Error;
+    fn try_from(value: proto::DecisionReceipt) -> Result<Self, Self::Error> {
+        Ok(DecisionReceipt {
+            signer_public_key: value.signer_public_key.required()?.try_into()?,
+            signature: value.signature.required()?.try_into()?,
+        })
+    }
+}
+
+impl From<DepositQuorumCertificate> for proto::DepositQuorumCertificate {
+    fn from(value: DepositQuorumCertificate) -> Self {
+        proto::DepositQuorumCertificate {
+            outpoint: Some(value.outpoint.into()),
+            receipts: value.receipts.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::DepositQuorumCertificate> for DepositQuorumCertificate {
+    type Error = Error;
+    fn try_from(value: proto::DepositQuorumCertificate) -> Result<Self, Self::Error> {
+        Ok(DepositQuorumCertificate {
+            outpoint: value.outpoint.required()?.try_into()?,
+            receipts: value
+                .receipts
+                .into_iter()
+                .map(|v| v.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
         })
     }
 }
@@ -1263,6 +1557,370 @@ impl From<proto::BitcoinPreSignAck> for BitcoinPreSignAck {
     }
 }
 
+impl From<CoordinatorAttestation> for proto::CoordinatorAttestation {
+    fn from(value: CoordinatorAttestation) -> Self {
+        proto::CoordinatorAttestation {
+            request_package: value
+                .request_package
+                .into_iter()
+                .map(|v| v.into())
+                .collect(),
+            sweep_txids: value.sweep_txids.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::CoordinatorAttestation> for CoordinatorAttestation {
+    type Error = Error;
+    fn try_from(value: proto::CoordinatorAttestation) -> Result<Self, Self::Error> {
+        Ok(CoordinatorAttestation {
+            request_package: value
+                .request_package
+                .into_iter()
+                .map(|v| v.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+            sweep_txids: value
+                .sweep_txids
+                .into_iter()
+                .map(|v| v.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl From<DatabaseDigest> for proto::DatabaseDigest {
+    fn from(value: DatabaseDigest) -> Self {
+        proto::DatabaseDigest {
+            num_blocks: value.num_blocks.into(),
+            deposit_requests: Some(value.deposit_requests.into()),
+            deposit_decisions: Some(value.deposit_decisions.into()),
+            dkg_shares: Some(value.dkg_shares.into()),
+        }
+    }
+}
+
+impl TryFrom<proto::DatabaseDigest> for DatabaseDigest {
+    type Error = Error;
+    fn try_from(value: proto::DatabaseDigest) -> Result<Self, Self::Error> {
+        Ok(DatabaseDigest {
+            num_blocks: value
+                .num_blocks
+                .try_into()
+                .map_err(|_| Error::TypeConversion)?,
+            deposit_requests: value.deposit_requests.required()?.into(),
+            deposit_decisions: value.deposit_decisions.required()?.into(),
+            dkg_shares: value.dkg_shares.required()?.into(),
+        })
+    }
+}
+
+impl From<DepositDecisionRetryRequest> for proto::DepositDecisionRetryRequest {
+    fn from(value: DepositDecisionRetryRequest) -> Self {
+        proto::DepositDecisionRetryRequest {
+            deposits: value
+                .deposits
+                .into_iter()
+                .map(proto::OutPoint::from)
+                .collect(),
+            signers: value.signers.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::DepositDecisionRetryRequest> for DepositDecisionRetryRequest {
+    type Error = Error;
+    fn try_from(value: proto::DepositDecisionRetryRequest) -> Result<Self, Self::Error> {
+        Ok(DepositDecisionRetryRequest {
+            deposits: value
+                .deposits
+                .into_iter()
+                .map(OutPoint::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            signers: value
+                .signers
+                .into_iter()
+                .map(PublicKey::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl From<MessageRedeliveryRequest> for proto::MessageRedeliveryRequest {
+    fn from(_: MessageRedeliveryRequest) -> Self {
+        proto::MessageRedeliveryRequest {}
+    }
+}
+
+impl From<proto::MessageRedeliveryRequest> for MessageRedeliveryRequest {
+    fn from(_: proto::MessageRedeliveryRequest) -> Self {
+        MessageRedeliveryRequest
+    }
+}
+
+impl From<RedeliveredMessages> for proto::RedeliveredMessages {
+    fn from(value: RedeliveredMessages) -> Self {
+        proto::RedeliveredMessages {
+            recipient: Some(value.recipient.into()),
+            messages: value.messages,
+        }
+    }
+}
+
+impl TryFrom<proto::RedeliveredMessages> for RedeliveredMessages {
+    type Error = Error;
+    fn try_from(value: proto::RedeliveredMessages) -> Result<Self, Self::Error> {
+        Ok(RedeliveredMessages {
+            recipient: value.recipient.required()?.try_into()?,
+            messages: value.messages,
+        })
+    }
+}
+
+impl From<LimitOverrideVote> for proto::LimitOverrideVote {
+    fn from(value: LimitOverrideVote) -> Self {
+        proto::LimitOverrideVote {
+            per_deposit_cap: value.per_deposit_cap,
+            per_withdrawal_cap: value.per_withdrawal_cap,
+            expires_at_height: *value.expires_at_height,
+        }
+    }
+}
+
+impl TryFrom<proto::LimitOverrideVote> for LimitOverrideVote {
+    type Error = Error;
+    fn try_from(value: proto::LimitOverrideVote) -> Result<Self, Self::Error> {
+        Ok(LimitOverrideVote {
+            per_deposit_cap: value.per_deposit_cap,
+            per_withdrawal_cap: value.per_withdrawal_cap,
+            expires_at_height: value.expires_at_height.into(),
+        })
+    }
+}
+
+impl From<SignerKeyEndorsement> for proto::SignerKeyEndorsement {
+    fn from(value: SignerKeyEndorsement) -> Self {
+        proto::SignerKeyEndorsement {
+            new_public_key: Some(value.new_public_key.into()),
+        }
+    }
+}
+
+impl TryFrom<proto::SignerKeyEndorsement> for SignerKeyEndorsement {
+    type Error = Error;
+    fn try_from(value: proto::SignerKeyEndorsement) -> Result<Self, Self::Error> {
+        Ok(SignerKeyEndorsement {
+            new_public_key: value.new_public_key.required()?.try_into()?,
+        })
+    }
+}
+
+impl From<EmergencyRecoveryApproval> for proto::EmergencyRecoveryApproval {
+    fn from(value: EmergencyRecoveryApproval) -> Self {
+        proto::EmergencyRecoveryApproval {
+            recovery_script_pubkey: value.recovery_script_pubkey.into_bytes(),
+        }
+    }
+}
+
+impl TryFrom<proto::EmergencyRecoveryApproval> for EmergencyRecoveryApproval {
+    type Error = Error;
+    fn try_from(value: proto::EmergencyRecoveryApproval) -> Result<Self, Self::Error> {
+        Ok(EmergencyRecoveryApproval {
+            recovery_script_pubkey: bitcoin::ScriptBuf::from_bytes(value.recovery_script_pubkey),
+        })
+    }
+}
+
+impl From<EmergencyRecoveryRevocation> for proto::EmergencyRecoveryRevocation {
+    fn from(value: EmergencyRecoveryRevocation) -> Self {
+        proto::EmergencyRecoveryRevocation {
+            recovery_script_pubkey: value.recovery_script_pubkey.into_bytes(),
+        }
+    }
+}
+
+impl TryFrom<proto::EmergencyRecoveryRevocation> for EmergencyRecoveryRevocation {
+    type Error = Error;
+    fn try_from(value: proto::EmergencyRecoveryRevocation) -> Result<Self, Self::Error> {
+        Ok(EmergencyRecoveryRevocation {
+            recovery_script_pubkey: bitcoin::ScriptBuf::from_bytes(value.recovery_script_pubkey),
+        })
+    }
+}
+
+impl From<DecisionAcknowledgement> for proto::DecisionAcknowledgement {
+    fn from(value: DecisionAcknowledgement) -> Self {
+        proto::DecisionAcknowledgement {
+            decider: Some(value.decider.into()),
+            deposits: value.deposits.into_iter().map(|v| v.into()).collect(),
+            withdrawals: value.withdrawals.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::DecisionAcknowledgement> for DecisionAcknowledgement {
+    type Error = Error;
+    fn try_from(value: proto::DecisionAcknowledgement) -> Result<Self, Self::Error> {
+        Ok(DecisionAcknowledgement {
+            decider: value.decider.required()?.try_into()?,
+            deposits: value
+                .deposits
+                .into_iter()
+                .map(DepositDecisionAck::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            withdrawals: value
+                .withdrawals
+                .into_iter()
+                .map(WithdrawalDecisionAck::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl From<DepositDecisionAck> for proto::DepositDecisionAck {
+    fn from(value: DepositDecisionAck) -> Self {
+        proto::DepositDecisionAck {
+            outpoint: Some(value.outpoint.into()),
+            decision_version: value.decision_version,
+        }
+    }
+}
+
+impl TryFrom<proto::DepositDecisionAck> for DepositDecisionAck {
+    type Error = Error;
+    fn try_from(value: proto::DepositDecisionAck) -> Result<Self, Self::Error> {
+        Ok(DepositDecisionAck {
+            outpoint: value.outpoint.required()?.try_into()?,
+            decision_version: value.decision_version,
+        })
+    }
+}
+
+impl From<WithdrawalDecisionAck> for proto::WithdrawalDecisionAck {
+    fn from(value: WithdrawalDecisionAck) -> Self {
+        proto::WithdrawalDecisionAck {
+            id: Some(value.id.into()),
+            decision_version: value.decision_version,
+        }
+    }
+}
+
+impl TryFrom<proto::WithdrawalDecisionAck> for WithdrawalDecisionAck {
+    type Error = Error;
+    fn try_from(value: proto::WithdrawalDecisionAck) -> Result<Self, Self::Error> {
+        Ok(WithdrawalDecisionAck {
+            id: value.id.required()?.try_into()?,
+            decision_version: value.decision_version,
+        })
+    }
+}
+
+impl From<RefusalReason> for proto::RefusalReason {
+    fn from(value: RefusalReason) -> Self {
+        match value {
+            RefusalReason::InvalidRequest => proto::RefusalReason::InvalidRequest,
+            RefusalReason::LimitExceeded => proto::RefusalReason::LimitExceeded,
+            RefusalReason::AlreadySigned => proto::RefusalReason::AlreadySigned,
+            RefusalReason::StaleRequest => proto::RefusalReason::StaleRequest,
+            RefusalReason::Paused => proto::RefusalReason::Paused,
+            RefusalReason::Internal => proto::RefusalReason::Internal,
+        }
+    }
+}
+
+impl TryFrom<proto::RefusalReason> for RefusalReason {
+    type Error = Error;
+    fn try_from(value: proto::RefusalReason) -> Result<Self, Self::Error> {
+        Ok(match value {
+            proto::RefusalReason::InvalidRequest => RefusalReason::InvalidRequest,
+            proto::RefusalReason::LimitExceeded => RefusalReason::LimitExceeded,
+            proto::RefusalReason::AlreadySigned => RefusalReason::AlreadySigned,
+            proto::RefusalReason::StaleRequest => RefusalReason::StaleRequest,
+            proto::RefusalReason::Paused => RefusalReason::Paused,
+            proto::RefusalReason::Internal => RefusalReason::Internal,
+            proto::RefusalReason::Unspecified => return Err(Error::TypeConversion),
+        })
+    }
+}
+
+impl From<SignRequestRefusal> for proto::SignRequestRefusal {
+    fn from(value: SignRequestRefusal) -> Self {
+        let stacks_txid = match value.request {
+            RefusedSignRequest::BitcoinPreSign => None,
+            RefusedSignRequest::StacksTransaction(txid) => Some(StacksTxId::from(txid).into()),
+        };
+        proto::SignRequestRefusal {
+            stacks_txid,
+            reason: proto::RefusalReason::from(value.reason).into(),
+            contested_deposits: value
+                .contested
+                .deposits
+                .into_iter()
+                .map(proto::OutPoint::from)
+                .collect(),
+            contested_withdrawals: value
+                .contested
+                .withdrawals
+                .into_iter()
+                .map(|v| v.into())
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::SignRequestRefusal> for SignRequestRefusal {
+    type Error = Error;
+    fn try_from(value: proto::SignRequestRefusal) -> Result<Self, Self::Error> {
+        let request = match value.stacks_txid {
+            None => RefusedSignRequest::BitcoinPreSign,
+            Some(txid) => RefusedSignRequest::StacksTransaction(StacksTxId::try_from(txid)?.into()),
+        };
+        let reason = proto::RefusalReason::try_from(value.reason)
+            .map_err(|_| Error::TypeConversion)?
+            .try_into()?;
+        let contested = TxRequestIds {
+            deposits: value
+                .contested_deposits
+                .into_iter()
+                .map(OutPoint::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            withdrawals: value
+                .contested_withdrawals
+                .into_iter()
+                .map(QualifiedRequestId::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+        Ok(SignRequestRefusal { request, reason, contested })
+    }
+}
+
+impl From<SignerDecisionBatch> for proto::SignerDecisionBatch {
+    fn from(value: SignerDecisionBatch) -> Self {
+        proto::SignerDecisionBatch {
+            deposits: value.deposits.into_iter().map(|v| v.into()).collect(),
+            withdrawals: value.withdrawals.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::SignerDecisionBatch> for SignerDecisionBatch {
+    type Error = Error;
+    fn try_from(value: proto::SignerDecisionBatch) -> Result<Self, Self::Error> {
+        Ok(SignerDecisionBatch {
+            deposits: value
+                .deposits
+                .into_iter()
+                .map(SignerDepositDecision::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            withdrawals: value
+                .withdrawals
+                .into_iter()
+                .map(SignerWithdrawalDecision::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
 impl From<SignerMessage> for proto::SignerMessage {
     fn from(value: SignerMessage) -> Self {
         proto::SignerMessage {
@@ -1306,6 +1964,42 @@ impl From<Payload> for proto::Payload {
             Payload::BitcoinPreSignAck(inner) => {
                 proto::signer_message::Payload::BitcoinPreSignAck(inner.into())
             }
+            Payload::CoordinatorAttestation(inner) => {
+                proto::signer_message::Payload::CoordinatorAttestation(inner.into())
+            }
+            Payload::DatabaseDigest(inner) => {
+                proto::signer_message::Payload::DatabaseDigest(inner.into())
+            }
+            Payload::DepositDecisionRetryRequest(inner) => {
+                proto::signer_message::Payload::DepositDecisionRetryRequest(inner.into())
+            }
+            Payload::SignerDecisionBatch(inner) => {
+                proto::signer_message::Payload::SignerDecisionBatch(inner.into())
+            }
+            Payload::MessageRedeliveryRequest(inner) => {
+                proto::signer_message::Payload::MessageRedeliveryRequest(inner.into())
+            }
+            Payload::RedeliveredMessages(inner) => {
+                proto::signer_message::Payload::RedeliveredMessages(inner.into())
+            }
+            Payload::SignRequestRefusal(inner) => {
+                proto::signer_message::Payload::SignRequestRefusal(inner.into())
+            }
+            Payload::LimitOverrideVote(inner) => {
+                proto::signer_message::Payload::LimitOverrideVote(inner.into())
+            }
+            Payload::SignerKeyEndorsement(inner) => {
+                proto::signer_message::Payload::SignerKeyEndorsement(inner.into())
+            }
+            Payload::EmergencyRecoveryApproval(inner) => {
+                proto::signer_message::Payload::EmergencyRecoveryApproval(inner.into())
+            }
+            Payload::DecisionAcknowledgement(inner) => {
+                proto::signer_message::Payload::DecisionAcknowledgement(inner.into())
+            }
+            Payload::EmergencyRecoveryRevocation(inner) => {
+                proto::signer_message::Payload::EmergencyRecoveryRevocation(inner.into())
+            }
         }
     }
 }
@@ -1335,6 +2029,42 @@ impl TryFrom<proto::Payload> for Payload {
             proto::signer_message::Payload::BitcoinPreSignAck(inner) => {
                 Payload::BitcoinPreSignAck(inner.into())
             }
+            proto::signer_message::Payload::CoordinatorAttestation(inner) => {
+                Payload::CoordinatorAttestation(inner.try_into()?)
+            }
+            proto::signer_message::Payload::DatabaseDigest(inner) => {
+                Payload::DatabaseDigest(inner.try_into()?)
+            }
+            proto::signer_message::Payload::DepositDecisionRetryRequest(inner) => {
+                Payload::DepositDecisionRetryRequest(inner.try_into()?)
+            }
+            proto::signer_message::Payload::SignerDecisionBatch(inner) => {
+                Payload::SignerDecisionBatch(inner.try_into()?)
+            }
+            proto::signer_message::Payload::MessageRedeliveryRequest(inner) => {
+                Payload::MessageRedeliveryRequest(inner.into())
+            }
+            proto::signer_message::Payload::RedeliveredMessages(inner) => {
+                Payload::RedeliveredMessages(inner.try_into()?)
+            }
+            proto::signer_message::Payload::SignRequestRefusal(inner) => {
+                Payload::SignRequestRefusal(inner.try_into()?)
+            }
+            proto::signer_message::Payload::LimitOverrideVote(inner) => {
+                Payload::LimitOverrideVote(inner.try_into()?)
+            }
+            proto::signer_message::Payload::SignerKeyEndorsement(inner) => {
+                Payload::SignerKeyEndorsement(inner.try_into()?)
+            }
+            proto::signer_message::Payload::EmergencyRecoveryApproval(inner) => {
+                Payload::EmergencyRecoveryApproval(inner.try_into()?)
+            }
+            proto::signer_message::Payload::DecisionAcknowledgement(inner) => {
+                Payload::DecisionAcknowledgement(inner.try_into()?)
+            }
+            proto::signer_message::Payload::EmergencyRecoveryRevocation(inner) => {
+                Payload::EmergencyRecoveryRevocation(inner.try_into()?)
+            }
         };
         Ok(payload)
     }
@@ -1643,6 +2373,18 @@ impl codec::ProtoSerializable for SignerMessage {
             Payload::WstsMessage(_) => "SBTC_WSTS_MESSAGE",
             Payload::BitcoinPreSignRequest(_) => "SBTC_BITCOIN_PRE_SIGN_REQUEST",
             Payload::BitcoinPreSignAck(_) => "SBTC_BITCOIN_PRE_SIGN_ACK",
+            Payload::CoordinatorAttestation(_) => "SBTC_COORDINATOR_ATTESTATION",
+            Payload::DatabaseDigest(_) => "SBTC_DATABASE_DIGEST",
+            Payload::DepositDecisionRetryRequest(_) => "SBTC_DEPOSIT_DECISION_RETRY_REQUEST",
+            Payload::SignerDecisionBatch(_) => "SBTC_SIGNER_DECISION_BATCH",
+            Payload::MessageRedeliveryRequest(_) => "SBTC_MESSAGE_REDELIVERY_REQUEST",
+            Payload::RedeliveredMessages(_) => "SBTC_REDELIVERED_MESSAGES",
+            Payload::SignRequestRefusal(_) => "SBTC_SIGN_REQUEST_REFUSAL",
+            Payload::LimitOverrideVote(_) => "SBTC_LIMIT_OVERRIDE_VOTE",
+            Payload::SignerKeyEndorsement(_) => "SBTC_SIGNER_KEY_ENDORSEMENT",
+            Payload::EmergencyRecoveryApproval(_) => "SBTC_EMERGENCY_RECOVERY_APPROVAL",
+            Payload::DecisionAcknowledgement(_) => "SBTC_DECISION_ACKNOWLEDGEMENT",
+            Payload::EmergencyRecoveryRevocation(_) => "SBTC_EMERGENCY_RECOVERY_REVOCATION",
         }
     }
 }
@@ -1710,7 +2452,9 @@ mod tests {
     #[test_case(PhantomData::<(SignerWithdrawalDecision, proto::SignerWithdrawalDecision)>; "SignerWithdrawalDecision")]
     #[test_case(PhantomData::<(StacksTransactionSignature, proto::StacksTransactionSignature)>; "StacksTransactionSignature")]
     #[test_case(PhantomData::<(CompleteDepositV1, proto::CompleteDeposit)>; "CompleteDeposit")]
+    #[test_case(PhantomData::<(CompleteDepositsV1, proto::CompleteDeposits)>; "CompleteDeposits")]
     #[test_case(PhantomData::<(AcceptWithdrawalV1, proto::AcceptWithdrawal)>; "AcceptWithdrawal")]
+    #[test_case(PhantomData::<(AcceptWithdrawalsV1, proto::AcceptWithdrawals)>; "AcceptWithdrawals")]
     #[test_case(PhantomData::<(RejectWithdrawalV1, proto::RejectWithdrawal)>; "RejectWithdrawal")]
     #[test_case(PhantomData::<(RotateKeysV1, proto::RotateKeys)>; "RotateKeys")]
     #[test_case(PhantomData::<(SmartContract, proto::SmartContract)>; "SmartContract")]
@@ -1721,9 +2465,25 @@ mod tests {
     #[test_case(PhantomData::<(Signed<SignerMessage>, proto::Signed)>; "Signed")]
     #[test_case(PhantomData::<(QualifiedRequestId, proto::QualifiedRequestId)>; "QualifiedRequestId")]
     #[test_case(PhantomData::<(TxRequestIds, proto::TxRequestIds)>; "TxRequestIds")]
+    #[test_case(PhantomData::<(TenurePlan, proto::TenurePlan)>; "TenurePlan")]
+    #[test_case(PhantomData::<(DkgBeginRefusal, proto::DkgBeginRefusal)>; "DkgBeginRefusal")]
     #[test_case(PhantomData::<(Fees, proto::Fees)>; "Fees")]
     #[test_case(PhantomData::<(BitcoinPreSignRequest, proto::BitcoinPreSignRequest)>; "BitcoinPreSignRequest")]
     #[test_case(PhantomData::<(BitcoinPreSignAck, proto::BitcoinPreSignAck)>; "BitcoinPreSignAck")]
+    #[test_case(PhantomData::<(DecisionReceipt, proto::DecisionReceipt)>; "DecisionReceipt")]
+    #[test_case(PhantomData::<(DepositQuorumCertificate, proto::DepositQuorumCertificate)>; "DepositQuorumCertificate")]
+    #[test_case(PhantomData::<(CoordinatorAttestation, proto::CoordinatorAttestation)>; "CoordinatorAttestation")]
+    #[test_case(PhantomData::<(DatabaseDigest, proto::DatabaseDigest)>; "DatabaseDigest")]
+    #[test_case(PhantomData::<(DepositDecisionRetryRequest, proto::DepositDecisionRetryRequest)>; "DepositDecisionRetryRequest")]
+    #[test_case(PhantomData::<(SignerDecisionBatch, proto::SignerDecisionBatch)>; "SignerDecisionBatch")]
+    #[test_case(PhantomData::<(MessageRedeliveryRequest, proto::MessageRedeliveryRequest)>; "MessageRedeliveryRequest")]
+    #[test_case(PhantomData::<(RedeliveredMessages, proto::RedeliveredMessages)>; "RedeliveredMessages")]
+    #[test_case(PhantomData::<(SignRequestRefusal, proto::SignRequestRefusal)>; "SignRequestRefusal")]
+    #[test_case(PhantomData::<(LimitOverrideVote, proto::LimitOverrideVote)>; "LimitOverrideVote")]
+    #[test_case(PhantomData::<(SignerKeyEndorsement, proto::SignerKeyEndorsement)>; "SignerKeyEndorsement")]
+    #[test_case(PhantomData::<(EmergencyRecoveryApproval, proto::EmergencyRecoveryApproval)>; "EmergencyRecoveryApproval")]
+    #[test_case(PhantomData::<(DecisionAcknowledgement, proto::DecisionAcknowledgement)>; "DecisionAcknowledgement")]
+    #[test_case(PhantomData::<(EmergencyRecoveryRevocation, proto::EmergencyRecoveryRevocation)>; "EmergencyRecoveryRevocation")]
     fn convert_protobuf_type<T, U, E>(_: PhantomData<(T, U)>)
     where
         // `.unwrap()` requires that `E` implement `std::fmt::Debug` and
@@ -42,6 +42,16 @@ pub struct SignerDepositDecision {
     /// for the associated deposit request.
     #[prost(bool, tag = "3")]
     pub can_sign: bool,
+    /// The sending signer's signature over the receipt digest of the deposit
+    /// request. This is only set if the signer accepted the deposit request
+    /// and can sign for it.
+    #[prost(message, optional, tag = "4")]
+    pub receipt: ::core::option::Option<super::super::super::crypto::EcdsaSignature>,
+    /// The version of the decision. A signer bumps it each time it changes
+    /// its decision on the request, and a decision only replaces the
+    /// signer's earlier decisions that have a lower version.
+    #[prost(uint32, tag = "5")]
+    pub decision_version: u32,
 }
 /// Represents a decision to accept or reject a withdrawal request.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
@@ -60,6 +70,22 @@ pub struct SignerWithdrawalDecision {
     /// Whether or not the signer has accepted the withdrawal request.
     #[prost(bool, tag = "4")]
     pub accepted: bool,
+    /// The version of the decision. A signer bumps it each time it changes
+    /// its decision on the request, and a decision only replaces the
+    /// signer's earlier decisions that have a lower version.
+    #[prost(uint32, tag = "5")]
+    pub decision_version: u32,
+}
+/// A batch of the sending signer's decisions on deposit and withdrawal
+/// requests, sent in a single message instead of one message per decision.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignerDecisionBatch {
+    /// The decisions on deposit requests.
+    #[prost(message, repeated, tag = "1")]
+    pub deposits: ::prost::alloc::vec::Vec<SignerDepositDecision>,
+    /// The decisions on withdrawal requests.
+    #[prost(message, repeated, tag = "2")]
+    pub withdrawals: ::prost::alloc::vec::Vec<SignerWithdrawalDecision>,
 }
 /// Represents a signature of a Stacks transaction.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
@@ -73,6 +99,81 @@ pub struct StacksTransactionSignature {
         super::super::super::crypto::RecoverableSignature,
     >,
 }
+/// A refusal to sign, sent by a signer to the coordinator when it does not
+/// sign a BitcoinPreSignRequest or a StacksTransactionSignRequest.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignRequestRefusal {
+    /// The ID of the stacks transaction in the refused
+    /// StacksTransactionSignRequest. This is not set when the refused request
+    /// is the BitcoinPreSignRequest for the chain tip of the enclosing
+    /// message.
+    #[prost(message, optional, tag = "1")]
+    pub stacks_txid: ::core::option::Option<super::super::StacksTxid>,
+    /// Why the signer refused to sign.
+    #[prost(enumeration = "RefusalReason", tag = "2")]
+    pub reason: i32,
+    /// The deposit requests in the refused request that the signer will not
+    /// sign for.
+    #[prost(message, repeated, tag = "3")]
+    pub contested_deposits: ::prost::alloc::vec::Vec<
+        super::super::super::bitcoin::OutPoint,
+    >,
+    /// The withdrawal requests in the refused request that the signer will
+    /// not sign for.
+    #[prost(message, repeated, tag = "4")]
+    pub contested_withdrawals: ::prost::alloc::vec::Vec<QualifiedRequestId>,
+}
+/// The reasons that a signer can refuse to sign.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RefusalReason {
+    Unspecified = 0,
+    /// The request failed validation.
+    InvalidRequest = 1,
+    /// The request would exceed one of the limits that the signer enforces.
+    LimitExceeded = 2,
+    /// The signer has already signed for the request, or a conflicting one,
+    /// during the tenure.
+    AlreadySigned = 3,
+    /// The request is for a bitcoin chain tip that is no longer the
+    /// canonical one.
+    StaleRequest = 4,
+    /// The signer has paused the processing of the requests.
+    Paused = 5,
+    /// The signer could not process the request, for example because its
+    /// database or one of its nodes is unavailable.
+    Internal = 6,
+}
+impl RefusalReason {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "REFUSAL_REASON_UNSPECIFIED",
+            Self::InvalidRequest => "REFUSAL_REASON_INVALID_REQUEST",
+            Self::LimitExceeded => "REFUSAL_REASON_LIMIT_EXCEEDED",
+            Self::AlreadySigned => "REFUSAL_REASON_ALREADY_SIGNED",
+            Self::StaleRequest => "REFUSAL_REASON_STALE_REQUEST",
+            Self::Paused => "REFUSAL_REASON_PAUSED",
+            Self::Internal => "REFUSAL_REASON_INTERNAL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "REFUSAL_REASON_UNSPECIFIED" => Some(Self::Unspecified),
+            "REFUSAL_REASON_INVALID_REQUEST" => Some(Self::InvalidRequest),
+            "REFUSAL_REASON_LIMIT_EXCEEDED" => Some(Self::LimitExceeded),
+            "REFUSAL_REASON_ALREADY_SIGNED" => Some(Self::AlreadySigned),
+            "REFUSAL_REASON_STALE_REQUEST" => Some(Self::StaleRequest),
+            "REFUSAL_REASON_PAUSED" => Some(Self::Paused),
+            "REFUSAL_REASON_INTERNAL" => Some(Self::Internal),
+            _ => None,
+        }
+    }
+}
 /// Represents a request to sign a Stacks transaction.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StacksTransactionSignRequest {
@@ -92,7 +193,7 @@ pub struct StacksTransactionSignRequest {
     /// The contract transaction to sign.
     #[prost(
         oneof = "stacks_transaction_sign_request::ContractTx",
-        tags = "5, 6, 7, 8, 9"
+        tags = "5, 6, 7, 8, 9, 10, 11"
     )]
     pub contract_tx: ::core::option::Option<stacks_transaction_sign_request::ContractTx>,
 }
@@ -116,6 +217,14 @@ pub mod stacks_transaction_sign_request {
         /// Ssmart contract deployment
         #[prost(enumeration = "super::SmartContract", tag = "9")]
         SmartContract(i32),
+        /// The `complete-withdrawals` contract call, accepting several
+        /// withdrawal requests at once
+        #[prost(message, tag = "10")]
+        AcceptWithdrawals(super::AcceptWithdrawals),
+        /// The `complete-deposits-wrapper` contract call, completing several
+        /// deposit requests at once
+        #[prost(message, tag = "11")]
+        CompleteDeposits(super::CompleteDeposits),
     }
 }
 /// For making a `complete-deposit` contract call in the sbtc-deposit
@@ -189,6 +298,30 @@ pub struct AcceptWithdrawal {
     #[prost(uint64, tag = "7")]
     pub sweep_block_height: u64,
 }
+/// For making a `complete-deposits-wrapper` contract call in the
+/// sbtc-deposit smart contract that completes several deposit requests at
+/// once.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompleteDeposits {
+    /// The deposit requests to complete.
+    #[prost(message, repeated, tag = "1")]
+    pub deposits: ::prost::alloc::vec::Vec<CompleteDeposit>,
+    /// The address that deployed the contract.
+    #[prost(message, optional, tag = "2")]
+    pub deployer: ::core::option::Option<super::super::StacksAddress>,
+}
+/// For making a `complete-withdrawals` contract call in the
+/// sbtc-withdrawal smart contract that accepts several withdrawal
+/// requests at once.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AcceptWithdrawals {
+    /// The withdrawal requests to accept.
+    #[prost(message, repeated, tag = "1")]
+    pub withdrawals: ::prost::alloc::vec::Vec<AcceptWithdrawal>,
+    /// The address that deployed the contract.
+    #[prost(message, optional, tag = "2")]
+    pub deployer: ::core::option::Option<super::super::StacksAddress>,
+}
 /// For making a `reject-withdrawal-request` contract call in the
 /// sbtc-withdrawal smart contract.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -284,7 +417,10 @@ pub struct SignerMessage {
         super::super::super::bitcoin::BitcoinBlockHash,
     >,
     /// The message payload
-    #[prost(oneof = "signer_message::Payload", tags = "2, 3, 4, 5, 8, 10, 11")]
+    #[prost(
+        oneof = "signer_message::Payload",
+        tags = "2, 3, 4, 5, 8, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23"
+    )]
     pub payload: ::core::option::Option<signer_message::Payload>,
 }
 /// Nested message and enum types in `SignerMessage`.
@@ -313,6 +449,42 @@ pub mod signer_message {
         /// Represents an acknowledgment of a BitcoinPreSignRequest
         #[prost(message, tag = "11")]
         BitcoinPreSignAck(super::BitcoinPreSignAck),
+        /// A summary of what the coordinator did during its tenure
+        #[prost(message, tag = "12")]
+        CoordinatorAttestation(super::CoordinatorAttestation),
+        /// Digests of the contents of the signer's database
+        #[prost(message, tag = "13")]
+        DatabaseDigest(super::DatabaseDigest),
+        /// A request for signers to re-send their deposit decisions
+        #[prost(message, tag = "14")]
+        DepositDecisionRetryRequest(super::DepositDecisionRetryRequest),
+        /// A batch of decisions on deposit and withdrawal requests
+        #[prost(message, tag = "15")]
+        SignerDecisionBatch(super::SignerDecisionBatch),
+        /// A request for signers to re-send the messages that they stored
+        #[prost(message, tag = "16")]
+        MessageRedeliveryRequest(super::MessageRedeliveryRequest),
+        /// Stored messages that are re-sent to the signer that requested them
+        #[prost(message, tag = "17")]
+        RedeliveredMessages(super::RedeliveredMessages),
+        /// A refusal to sign a sign request from the coordinator
+        #[prost(message, tag = "18")]
+        SignRequestRefusal(super::SignRequestRefusal),
+        /// A vote for a temporary override of the sBTC limits
+        #[prost(message, tag = "19")]
+        LimitOverrideVote(super::LimitOverrideVote),
+        /// An endorsement of the new key of the sending signer
+        #[prost(message, tag = "20")]
+        SignerKeyEndorsement(super::SignerKeyEndorsement),
+        /// An approval of the emergency recovery of the funds of the signers
+        #[prost(message, tag = "21")]
+        EmergencyRecoveryApproval(super::EmergencyRecoveryApproval),
+        /// An acknowledgement of the decisions received from another signer
+        #[prost(message, tag = "22")]
+        DecisionAcknowledgement(super::DecisionAcknowledgement),
+        /// A revocation of an approval of the emergency recovery
+        #[prost(message, tag = "23")]
+        EmergencyRecoveryRevocation(super::EmergencyRecoveryRevocation),
     }
 }
 /// A wsts message.
@@ -415,6 +587,41 @@ pub struct BitcoinPreSignRequest {
     /// used this UTXO as an input.
     #[prost(message, optional, tag = "3")]
     pub last_fees: ::core::option::Option<Fees>,
+    /// Quorum certificates for the deposit requests in the request package.
+    #[prost(message, repeated, tag = "4")]
+    pub deposit_certificates: ::prost::alloc::vec::Vec<DepositQuorumCertificate>,
+    /// The outpoints of the payments to the signers that should be refunded
+    /// to their senders.
+    #[prost(message, repeated, tag = "5")]
+    pub refunds: ::prost::alloc::vec::Vec<super::super::super::bitcoin::OutPoint>,
+    /// The version of the template that the coordinator used to construct
+    /// the sweep transactions. Zero means that the version is unspecified.
+    #[prost(uint32, tag = "6")]
+    pub sweep_template_version: u32,
+    /// Whether the request is for the emergency recovery sweep of the
+    /// signers' UTXO, in which case it has no requests or refunds.
+    #[prost(bool, tag = "7")]
+    pub emergency_recovery: bool,
+}
+/// A signer's signed receipt of its decision to accept a deposit request.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DecisionReceipt {
+    /// The public key of the signer that accepted the deposit request.
+    #[prost(message, optional, tag = "1")]
+    pub signer_public_key: ::core::option::Option<super::super::super::crypto::PublicKey>,
+    /// The signer's signature over the receipt digest of the deposit request.
+    #[prost(message, optional, tag = "2")]
+    pub signature: ::core::option::Option<super::super::super::crypto::EcdsaSignature>,
+}
+/// Proof that enough signers accepted a deposit request.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepositQuorumCertificate {
+    /// The bitcoin outpoint that uniquely identifies the deposit request.
+    #[prost(message, optional, tag = "1")]
+    pub outpoint: ::core::option::Option<super::super::super::bitcoin::OutPoint>,
+    /// The receipts of the signers that accepted the deposit request.
+    #[prost(message, repeated, tag = "2")]
+    pub receipts: ::prost::alloc::vec::Vec<DecisionReceipt>,
 }
 /// Represents an acknowledgment of a BitcoinPreSignRequest.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
@@ -431,3 +638,2372 @@ pub struct TxRequestIds {
     #[prost(message, repeated, tag = "2")]
     pub withdrawals: ::prost::alloc::vec::Vec<QualifiedRequestId>,
 }
+/// A summary of what the coordinator did during its tenure. The bitcoin
+/// chain tip of the tenure is the chain tip of the enclosing message, and
+/// the signature over the enclosing message binds the coordinator to these
+/// claims.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CoordinatorAttestation {
+    /// The deposit and withdrawal requests that were included in each of the
+    /// broadcast sweep transactions.
+    #[prost(message, repeated, tag = "1")]
+    pub request_package: ::prost::alloc::vec::Vec<TxRequestIds>,
+    /// The IDs of the sweep transactions that were broadcast, in the same
+    /// order as the request package.
+    #[prost(message, repeated, tag = "2")]
+    pub sweep_txids: ::prost::alloc::vec::Vec<super::super::super::bitcoin::BitcoinTxid>,
+}
+/// Digests of the contents of key tables in the signer's database. Signers
+/// exchange these so that they can detect when their databases have
+/// drifted apart. The digests cover the range of bitcoin blocks ending at
+/// the chain tip of the enclosing message.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DatabaseDigest {
+    /// The number of bitcoin blocks covered by the digests.
+    #[prost(uint32, tag = "1")]
+    pub num_blocks: u32,
+    /// A digest of the deposit requests confirmed in the block range.
+    #[prost(message, optional, tag = "2")]
+    pub deposit_requests: ::core::option::Option<super::super::super::crypto::Uint256>,
+    /// A digest of the signers' decisions on the above deposit requests.
+    #[prost(message, optional, tag = "3")]
+    pub deposit_decisions: ::core::option::Option<super::super::super::crypto::Uint256>,
+    /// A digest of the metadata of the DKG shares known to the signer.
+    #[prost(message, optional, tag = "4")]
+    pub dkg_shares: ::core::option::Option<super::super::super::crypto::Uint256>,
+}
+/// A request from the coordinator for the given signers to re-send their
+/// decisions on the given deposit requests. The coordinator sends this when
+/// it is missing the decisions that it needs to include the deposits in a
+/// sweep transaction.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepositDecisionRetryRequest {
+    /// The deposit requests with missing decisions.
+    #[prost(message, repeated, tag = "1")]
+    pub deposits: ::prost::alloc::vec::Vec<super::super::super::bitcoin::OutPoint>,
+    /// The public keys of the signers whose decisions are missing.
+    #[prost(message, repeated, tag = "2")]
+    pub signers: ::prost::alloc::vec::Vec<super::super::super::crypto::PublicKey>,
+}
+/// A request for the other signers to re-send the recent decision and
+/// presign messages that they have stored. A signer sends this after it
+/// connects to the p2p network, so that a short restart does not make it
+/// miss the messages of the current tenure.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MessageRedeliveryRequest {}
+/// Signed messages that a signer stored and re-sends to the signer that
+/// requested them.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RedeliveredMessages {
+    /// The public key of the signer that requested the messages.
+    #[prost(message, optional, tag = "1")]
+    pub recipient: ::core::option::Option<super::super::super::crypto::PublicKey>,
+    /// The encoded signed messages, each with the signature of the signer
+    /// that originally sent it.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub messages: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// A vote for a temporary override of the sBTC limits that Emily reports.
+/// Signers apply the override once they have identical votes from enough
+/// members of the signer set, and only if it is within their own bounds.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct LimitOverrideVote {
+    /// The maximum amount of BTC, in sats, that may be pegged-in per deposit
+    /// while the override is active. Not overridden when unset.
+    #[prost(uint64, optional, tag = "1")]
+    pub per_deposit_cap: ::core::option::Option<u64>,
+    /// The maximum amount of sBTC, in sats, that may be pegged-out per
+    /// withdrawal while the override is active. Not overridden when unset.
+    #[prost(uint64, optional, tag = "2")]
+    pub per_withdrawal_cap: ::core::option::Option<u64>,
+    /// The bitcoin block height at which the override expires.
+    #[prost(uint64, tag = "3")]
+    pub expires_at_height: u64,
+}
+/// An endorsement, signed with the current key of a signer, of the key
+/// that its operator is replacing it with.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SignerKeyEndorsement {
+    /// The public key that replaces the key of the sending signer.
+    #[prost(message, optional, tag = "1")]
+    pub new_public_key: ::core::option::Option<super::super::super::crypto::PublicKey>,
+}
+/// An approval, signed by a signer, of sweeping all the funds of the
+/// signers to the pre-agreed recovery scriptPubKey.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmergencyRecoveryApproval {
+    /// The scriptPubKey that the funds are swept to.
+    #[prost(bytes = "vec", tag = "1")]
+    pub recovery_script_pubkey: ::prost::alloc::vec::Vec<u8>,
+}
+/// An acknowledgement that the sending signer stored the decisions that
+/// another signer sent in a batch. Signers stop re-sending a decision once
+/// every other signer has acknowledged it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DecisionAcknowledgement {
+    /// The public key of the signer that made the decisions.
+    #[prost(message, optional, tag = "1")]
+    pub decider: ::core::option::Option<super::super::super::crypto::PublicKey>,
+    /// The acknowledged decisions on deposit requests.
+    #[prost(message, repeated, tag = "2")]
+    pub deposits: ::prost::alloc::vec::Vec<DepositDecisionAck>,
+    /// The acknowledged decisions on withdrawal requests.
+    #[prost(message, repeated, tag = "3")]
+    pub withdrawals: ::prost::alloc::vec::Vec<WithdrawalDecisionAck>,
+}
+/// The acknowledgement of a decision on a deposit request.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DepositDecisionAck {
+    /// The outpoint of the deposit request.
+    #[prost(message, optional, tag = "1")]
+    pub outpoint: ::core::option::Option<super::super::super::bitcoin::OutPoint>,
+    /// The version of the decision that was stored.
+    #[prost(uint32, tag = "2")]
+    pub decision_version: u32,
+}
+/// The acknowledgement of a decision on a withdrawal request.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct WithdrawalDecisionAck {
+    /// The ID of the withdrawal request.
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<QualifiedRequestId>,
+    /// The version of the decision that was stored.
+    #[prost(uint32, tag = "2")]
+    pub decision_version: u32,
+}
+/// The withdrawal, by a signer, of its approval of the emergency recovery to
+/// the given recovery scriptPubKey.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmergencyRecoveryRevocation {
+    /// The scriptPubKey of the approved recovery.
+    #[prost(bytes = "vec", tag = "1")]
+    pub recovery_script_pubkey: ::prost::alloc::vec::Vec<u8>,
+}
+/// The request for the status of the signer.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetStatusRequest {}
+/// A summary of the current state of the signer.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStatusResponse {
+    /// The public key of this signer.
+    #[prost(message, optional, tag = "1")]
+    pub signer_public_key: ::core::option::Option<super::super::super::crypto::PublicKey>,
+    /// The bitcoin chain tip according to this signer, if it has observed
+    /// any bitcoin blocks.
+    #[prost(message, optional, tag = "2")]
+    pub bitcoin_chain_tip: ::core::option::Option<super::super::super::bitcoin::BitcoinBlockHash>,
+    /// The height of the above bitcoin chain tip.
+    #[prost(uint64, tag = "3")]
+    pub bitcoin_chain_tip_height: u64,
+    /// Whether the sBTC smart contracts have been deployed.
+    #[prost(bool, tag = "4")]
+    pub sbtc_contracts_deployed: bool,
+    /// The number of signers in the current signing set.
+    #[prost(uint32, tag = "5")]
+    pub num_signers: u32,
+    /// Whether coordinator duties are currently paused on this signer.
+    #[prost(bool, tag = "6")]
+    pub paused: bool,
+    /// The git revision that the signer binary was built from.
+    #[prost(string, tag = "7")]
+    pub git_revision: ::prost::alloc::string::String,
+    /// The plan of the most recent tenure in which this signer was the
+    /// coordinator, if any.
+    #[prost(message, optional, tag = "8")]
+    pub latest_tenure_plan: ::core::option::Option<TenurePlan>,
+    /// Whether the sweeping of deposit requests is paused on this signer.
+    #[prost(bool, tag = "9")]
+    pub deposits_paused: bool,
+    /// Whether the servicing of withdrawal requests is paused on this signer.
+    #[prost(bool, tag = "10")]
+    pub withdrawals_paused: bool,
+    /// The most recent refusal of this signer to begin a DKG round, if any.
+    #[prost(message, optional, tag = "11")]
+    pub latest_dkg_begin_refusal: ::core::option::Option<DkgBeginRefusal>,
+}
+/// A summary of the work that a coordinator intends to do in its tenure,
+/// computed before it starts any signing rounds.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TenurePlan {
+    /// The bitcoin chain tip that started the tenure.
+    #[prost(message, optional, tag = "1")]
+    pub bitcoin_chain_tip: ::core::option::Option<super::super::super::bitcoin::BitcoinBlockHash>,
+    /// The public key of the coordinator for the tenure.
+    #[prost(message, optional, tag = "2")]
+    pub coordinator_public_key: ::core::option::Option<super::super::super::crypto::PublicKey>,
+    /// Whether the coordinator intends to deploy the sBTC smart contracts.
+    #[prost(bool, tag = "3")]
+    pub deploy_contracts: bool,
+    /// Whether the coordinator intends to submit a rotate-keys contract
+    /// call.
+    #[prost(bool, tag = "4")]
+    pub rotate_keys: bool,
+    /// The number of deposit requests that are eligible for a sweep.
+    #[prost(uint32, tag = "5")]
+    pub deposits_to_sweep: u32,
+    /// The number of withdrawal requests that are eligible for a sweep.
+    #[prost(uint32, tag = "6")]
+    pub withdrawals_to_sweep: u32,
+    /// The number of swept deposit requests that may need a
+    /// complete-deposit contract call.
+    #[prost(uint32, tag = "7")]
+    pub deposits_to_complete: u32,
+    /// The number of swept withdrawal requests that may need an
+    /// accept-withdrawal-request contract call.
+    #[prost(uint32, tag = "8")]
+    pub withdrawals_to_accept: u32,
+    /// The number of withdrawal requests that may need a
+    /// reject-withdrawal-request contract call.
+    #[prost(uint32, tag = "9")]
+    pub withdrawals_to_reject: u32,
+}
+/// A refusal of a signer to begin a DKG round, along with the state that
+/// led to the refusal.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DkgBeginRefusal {
+    /// Why the signer refused to begin the DKG round.
+    #[prost(enumeration = "DkgBeginRefusalReason", tag = "1")]
+    pub reason: i32,
+    /// The bitcoin chain tip when the DKG round was requested.
+    #[prost(message, optional, tag = "2")]
+    pub bitcoin_chain_tip: ::core::option::Option<super::super::super::bitcoin::BitcoinBlockHash>,
+    /// The height of the above bitcoin chain tip.
+    #[prost(uint64, tag = "3")]
+    pub bitcoin_chain_tip_height: u64,
+    /// The verification status of the latest DKG shares of the signer, if
+    /// it has any.
+    #[prost(enumeration = "DkgSharesStatus", optional, tag = "4")]
+    pub latest_dkg_shares_status: ::core::option::Option<i32>,
+    /// The number of DKG shares that the signer had stored.
+    #[prost(uint32, tag = "5")]
+    pub dkg_shares_count: u32,
+    /// The configured target number of DKG rounds.
+    #[prost(uint32, tag = "6")]
+    pub dkg_target_rounds: u32,
+    /// The configured minimum bitcoin block height for rerunning DKG, if
+    /// any.
+    #[prost(uint64, optional, tag = "7")]
+    pub dkg_min_bitcoin_block_height: ::core::option::Option<u64>,
+}
+/// The request for pending deposit and withdrawal requests.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetPendingRequestsRequest {}
+/// The deposit and withdrawal requests that have been accepted by enough
+/// signers but have not been fulfilled yet.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPendingRequestsResponse {
+    /// The outpoints of the pending deposit requests.
+    #[prost(message, repeated, tag = "1")]
+    pub deposits: ::prost::alloc::vec::Vec<super::super::super::bitcoin::OutPoint>,
+    /// The identifiers of the pending withdrawal requests.
+    #[prost(message, repeated, tag = "2")]
+    pub withdrawals: ::prost::alloc::vec::Vec<QualifiedRequestId>,
+}
+/// A request to override this signer's decision on an sBTC request.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetDecisionOverrideRequest {
+    /// The override to apply.
+    #[prost(enumeration = "DecisionOverride", tag = "3")]
+    pub decision: i32,
+    /// The request whose decision is being overridden.
+    #[prost(oneof = "set_decision_override_request::Request", tags = "1, 2")]
+    pub request: ::core::option::Option<set_decision_override_request::Request>,
+}
+/// Nested message and enum types in `SetDecisionOverrideRequest`.
+pub mod set_decision_override_request {
+    /// The request whose decision is being overridden.
+    #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    pub enum Request {
+        /// The outpoint of a deposit request.
+        #[prost(message, tag = "1")]
+        Deposit(super::super::super::super::bitcoin::OutPoint),
+        /// The identifier of a withdrawal request.
+        #[prost(message, tag = "2")]
+        Withdrawal(super::QualifiedRequestId),
+    }
+}
+/// The response to setting a decision override.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetDecisionOverrideResponse {}
+/// The request to pause coordinator duties.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PauseRequest {}
+/// The response to pausing coordinator duties.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PauseResponse {
+    /// Whether coordinator duties were already paused.
+    #[prost(bool, tag = "1")]
+    pub was_paused: bool,
+}
+/// The request to resume coordinator duties.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ResumeRequest {}
+/// The response to resuming coordinator duties.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ResumeResponse {
+    /// Whether coordinator duties were paused before this request.
+    #[prost(bool, tag = "1")]
+    pub was_paused: bool,
+}
+/// The request for the output descriptors of the signers' wallet.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetWalletDescriptorsRequest {}
+/// An output descriptor for the UTXO locked by one of the signers'
+/// aggregate keys.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletDescriptor {
+    /// The taproot output descriptor, including its checksum.
+    #[prost(string, tag = "1")]
+    pub descriptor: ::prost::alloc::string::String,
+    /// The aggregate key from DKG that the descriptor is for.
+    #[prost(message, optional, tag = "2")]
+    pub aggregate_key: ::core::option::Option<super::super::super::crypto::PublicKey>,
+    /// The height of the bitcoin chain tip when the DKG round for the
+    /// aggregate key started. No funds can be locked by the key before this
+    /// height, so it is a safe starting point for a wallet rescan.
+    #[prost(uint64, tag = "3")]
+    pub started_at_bitcoin_block_height: u64,
+    /// Whether the aggregate key is the one in the sbtc-registry contract.
+    #[prost(bool, tag = "4")]
+    pub active: bool,
+}
+/// The output descriptors of the signers' wallet.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetWalletDescriptorsResponse {
+    /// The descriptors, ordered from the oldest aggregate key to the most
+    /// recent one.
+    #[prost(message, repeated, tag = "1")]
+    pub descriptors: ::prost::alloc::vec::Vec<WalletDescriptor>,
+}
+/// The request to re-activate an expired deposit request.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ReactivateDepositRequest {
+    /// The outpoint of the deposit request.
+    #[prost(message, optional, tag = "1")]
+    pub deposit: ::core::option::Option<super::super::super::bitcoin::OutPoint>,
+}
+/// The response to re-activating an expired deposit request.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ReactivateDepositResponse {
+    /// Whether the deposit request had expired and has been re-activated.
+    #[prost(bool, tag = "1")]
+    pub was_expired: bool,
+}
+/// The request to pause or resume the sweeping of deposit requests.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetDepositsPausedRequest {
+    /// Whether deposits should be paused.
+    #[prost(bool, tag = "1")]
+    pub paused: bool,
+}
+/// The response to pausing or resuming the sweeping of deposit requests.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetDepositsPausedResponse {
+    /// Whether deposits were paused before this request.
+    #[prost(bool, tag = "1")]
+    pub was_paused: bool,
+}
+/// The request to pause or resume the servicing of withdrawal requests.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetWithdrawalsPausedRequest {
+    /// Whether withdrawals should be paused.
+    #[prost(bool, tag = "1")]
+    pub paused: bool,
+}
+/// The response to pausing or resuming the servicing of withdrawal
+/// requests.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetWithdrawalsPausedResponse {
+    /// Whether withdrawals were paused before this request.
+    #[prost(bool, tag = "1")]
+    pub was_paused: bool,
+}
+/// A payment to the signers' scriptPubKey that was not a deposit.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignerPayment {
+    /// The outpoint of the output that pays the signers.
+    #[prost(message, optional, tag = "1")]
+    pub outpoint: ::core::option::Option<super::super::super::bitcoin::OutPoint>,
+    /// The amount of the output, in sats.
+    #[prost(uint64, tag = "2")]
+    pub amount: u64,
+    /// The scriptPubKey that funded the transaction, if all of its inputs
+    /// were locked by the same scriptPubKey. Refunds are sent here.
+    #[prost(bytes = "vec", optional, tag = "3")]
+    pub sender_script_pubkey: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// The review status of the payment.
+    #[prost(enumeration = "SignerPaymentStatus", tag = "4")]
+    pub status: i32,
+}
+/// The request to list payments to the signers.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListSignerPaymentsRequest {
+    /// The review status of the payments to return.
+    #[prost(enumeration = "SignerPaymentStatus", tag = "1")]
+    pub status: i32,
+}
+/// The response to listing payments to the signers.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSignerPaymentsResponse {
+    /// The payments with the requested status.
+    #[prost(message, repeated, tag = "1")]
+    pub payments: ::prost::alloc::vec::Vec<SignerPayment>,
+}
+/// The request to set the review status of a payment to the signers.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetSignerPaymentStatusRequest {
+    /// The outpoint of the payment.
+    #[prost(message, optional, tag = "1")]
+    pub outpoint: ::core::option::Option<super::super::super::bitcoin::OutPoint>,
+    /// The new review status of the payment.
+    #[prost(enumeration = "SignerPaymentStatus", tag = "2")]
+    pub status: i32,
+}
+/// The response to setting the review status of a payment to the signers.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetSignerPaymentStatusResponse {}
+/// The request to vote for a temporary override of the sBTC limits.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ProposeLimitOverrideRequest {
+    /// The per-deposit cap, in sats, while the override is active. Not
+    /// overridden when unset.
+    #[prost(uint64, optional, tag = "1")]
+    pub per_deposit_cap: ::core::option::Option<u64>,
+    /// The per-withdrawal cap, in sats, while the override is active. Not
+    /// overridden when unset.
+    #[prost(uint64, optional, tag = "2")]
+    pub per_withdrawal_cap: ::core::option::Option<u64>,
+    /// The bitcoin block height at which the override expires.
+    #[prost(uint64, tag = "3")]
+    pub expires_at_height: u64,
+}
+/// The response to voting for a temporary override of the sBTC limits.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ProposeLimitOverrideResponse {
+    /// The number of signers that this signer knows to have voted for the
+    /// override, including itself.
+    #[prost(uint32, tag = "1")]
+    pub votes: u32,
+    /// The number of votes needed for the override to take effect.
+    #[prost(uint32, tag = "2")]
+    pub threshold: u32,
+    /// Whether the override has taken effect on this signer.
+    #[prost(bool, tag = "3")]
+    pub active: bool,
+}
+/// The request to approve the emergency recovery of the signers' funds.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApproveEmergencyRecoveryRequest {
+    /// The recovery scriptPubKey, which must match the one in the config of
+    /// this signer.
+    #[prost(bytes = "vec", tag = "1")]
+    pub recovery_script_pubkey: ::prost::alloc::vec::Vec<u8>,
+}
+/// The response to approving the emergency recovery of the signers' funds.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ApproveEmergencyRecoveryResponse {
+    /// The number of signers that this signer knows to have approved the
+    /// recovery, including itself.
+    #[prost(uint32, tag = "1")]
+    pub approvals: u32,
+    /// The number of approvals needed for the recovery to go ahead.
+    #[prost(uint32, tag = "2")]
+    pub threshold: u32,
+    /// The bitcoin block height from which the signers' funds will be
+    /// swept, once enough signers have approved the recovery.
+    #[prost(uint64, optional, tag = "3")]
+    pub sweep_height: ::core::option::Option<u64>,
+}
+/// The request to revoke the approval of this signer of the emergency
+/// recovery of the signers' funds.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokeEmergencyRecoveryRequest {
+    /// The recovery scriptPubKey, which must match the one in the config of
+    /// this signer.
+    #[prost(bytes = "vec", tag = "1")]
+    pub recovery_script_pubkey: ::prost::alloc::vec::Vec<u8>,
+}
+/// The response to revoking the approval of the emergency recovery of the
+/// signers' funds.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RevokeEmergencyRecoveryResponse {
+    /// The number of signers that this signer knows to have approved the
+    /// recovery, after the revocation.
+    #[prost(uint32, tag = "1")]
+    pub approvals: u32,
+    /// The number of approvals needed for the recovery to go ahead.
+    #[prost(uint32, tag = "2")]
+    pub threshold: u32,
+    /// The bitcoin block height from which the signers' funds will be
+    /// swept, if enough signers still approve the recovery.
+    #[prost(uint64, optional, tag = "3")]
+    pub sweep_height: ::core::option::Option<u64>,
+}
+/// An Emily API key, with the key itself masked.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmilyApiKey {
+    /// The name of the key.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The priority of the key. Keys with a lower priority are tried first.
+    #[prost(uint32, tag = "2")]
+    pub priority: u32,
+    /// The key, with all but its last few characters masked.
+    #[prost(string, tag = "3")]
+    pub masked_key: ::prost::alloc::string::String,
+}
+/// The request for the Emily API keys of the signer.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListEmilyApiKeysRequest {}
+/// The Emily API keys of the signer.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListEmilyApiKeysResponse {
+    /// The keys, in the order in which they are tried.
+    #[prost(message, repeated, tag = "1")]
+    pub keys: ::prost::alloc::vec::Vec<EmilyApiKey>,
+}
+/// The request to add or replace an Emily API key.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetEmilyApiKeyRequest {
+    /// The name of the key, which identifies it when it is replaced or
+    /// removed.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The key itself.
+    #[prost(string, tag = "2")]
+    pub key: ::prost::alloc::string::String,
+    /// The priority of the key. Keys with a lower priority are tried first.
+    #[prost(uint32, tag = "3")]
+    pub priority: u32,
+}
+/// The response to adding or replacing an Emily API key.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetEmilyApiKeyResponse {
+    /// Whether a key with the same name was replaced.
+    #[prost(bool, tag = "1")]
+    pub replaced: bool,
+}
+/// The request to remove an Emily API key.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveEmilyApiKeyRequest {
+    /// The name of the key.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// The response to removing an Emily API key.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RemoveEmilyApiKeyResponse {
+    /// Whether there was a key with the given name.
+    #[prost(bool, tag = "1")]
+    pub removed: bool,
+}
+/// The reasons that a signer may refuse to begin a DKG round.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DkgBeginRefusalReason {
+    /// The reason was not set.
+    Unspecified = 0,
+    /// The latest DKG shares have not been verified yet, and verifying them
+    /// takes priority over running DKG again.
+    LatestSharesUnverified = 1,
+    /// The number of DKG rounds that the signer has taken part in has
+    /// reached the configured target.
+    TargetRoundsReached = 2,
+    /// The bitcoin chain tip is below the configured minimum height for
+    /// rerunning DKG.
+    BelowMinimumHeight = 3,
+    /// DKG has already run and there is no configured minimum height for
+    /// running it again.
+    RerunHeightNotConfigured = 4,
+}
+impl DkgBeginRefusalReason {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "DKG_BEGIN_REFUSAL_REASON_UNSPECIFIED",
+            Self::LatestSharesUnverified => "DKG_BEGIN_REFUSAL_REASON_LATEST_SHARES_UNVERIFIED",
+            Self::TargetRoundsReached => "DKG_BEGIN_REFUSAL_REASON_TARGET_ROUNDS_REACHED",
+            Self::BelowMinimumHeight => "DKG_BEGIN_REFUSAL_REASON_BELOW_MINIMUM_HEIGHT",
+            Self::RerunHeightNotConfigured => {
+                "DKG_BEGIN_REFUSAL_REASON_RERUN_HEIGHT_NOT_CONFIGURED"
+            }
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DKG_BEGIN_REFUSAL_REASON_UNSPECIFIED" => Some(Self::Unspecified),
+            "DKG_BEGIN_REFUSAL_REASON_LATEST_SHARES_UNVERIFIED" => {
+                Some(Self::LatestSharesUnverified)
+            }
+            "DKG_BEGIN_REFUSAL_REASON_TARGET_ROUNDS_REACHED" => Some(Self::TargetRoundsReached),
+            "DKG_BEGIN_REFUSAL_REASON_BELOW_MINIMUM_HEIGHT" => Some(Self::BelowMinimumHeight),
+            "DKG_BEGIN_REFUSAL_REASON_RERUN_HEIGHT_NOT_CONFIGURED" => {
+                Some(Self::RerunHeightNotConfigured)
+            }
+            _ => None,
+        }
+    }
+}
+/// The verification status of a set of DKG shares.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DkgSharesStatus {
+    /// The status was not set.
+    Unspecified = 0,
+    /// The DKG shares have not passed or failed verification.
+    Unverified = 1,
+    /// The DKG shares have passed verification.
+    Verified = 2,
+    /// The DKG shares have failed verification.
+    Failed = 3,
+}
+impl DkgSharesStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "DKG_SHARES_STATUS_UNSPECIFIED",
+            Self::Unverified => "DKG_SHARES_STATUS_UNVERIFIED",
+            Self::Verified => "DKG_SHARES_STATUS_VERIFIED",
+            Self::Failed => "DKG_SHARES_STATUS_FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DKG_SHARES_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "DKG_SHARES_STATUS_UNVERIFIED" => Some(Self::Unverified),
+            "DKG_SHARES_STATUS_VERIFIED" => Some(Self::Verified),
+            "DKG_SHARES_STATUS_FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+/// The kind of override to apply to a decision.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DecisionOverride {
+    /// Remove any existing override, so that the signer makes its decision
+    /// as it normally would.
+    Unspecified = 0,
+    /// Always accept the request.
+    Accept = 1,
+    /// Always reject the request.
+    Reject = 2,
+}
+impl DecisionOverride {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "DECISION_OVERRIDE_UNSPECIFIED",
+            Self::Accept => "DECISION_OVERRIDE_ACCEPT",
+            Self::Reject => "DECISION_OVERRIDE_REJECT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DECISION_OVERRIDE_UNSPECIFIED" => Some(Self::Unspecified),
+            "DECISION_OVERRIDE_ACCEPT" => Some(Self::Accept),
+            "DECISION_OVERRIDE_REJECT" => Some(Self::Reject),
+            _ => None,
+        }
+    }
+}
+/// The review status of a payment to the signers that was not a deposit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SignerPaymentStatus {
+    /// The status was not set.
+    Unspecified = 0,
+    /// The operator has not reviewed the payment yet.
+    Unreviewed = 1,
+    /// The payment was an intended donation and the funds stay with the
+    /// signers.
+    Donation = 2,
+    /// The operator approved returning the funds to the sender.
+    RefundApproved = 3,
+}
+impl SignerPaymentStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SIGNER_PAYMENT_STATUS_UNSPECIFIED",
+            Self::Unreviewed => "SIGNER_PAYMENT_STATUS_UNREVIEWED",
+            Self::Donation => "SIGNER_PAYMENT_STATUS_DONATION",
+            Self::RefundApproved => "SIGNER_PAYMENT_STATUS_REFUND_APPROVED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SIGNER_PAYMENT_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "SIGNER_PAYMENT_STATUS_UNREVIEWED" => Some(Self::Unreviewed),
+            "SIGNER_PAYMENT_STATUS_DONATION" => Some(Self::Donation),
+            "SIGNER_PAYMENT_STATUS_REFUND_APPROVED" => Some(Self::RefundApproved),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod signer_control_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// A service for programmatically querying and controlling a running
+    /// signer. This service is meant for the operator of the signer and
+    /// should not be exposed publicly.
+    #[derive(Debug, Clone)]
+    pub struct SignerControlClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl SignerControlClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> SignerControlClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> SignerControlClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            SignerControlClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Return a summary of the current state of the signer.
+        pub async fn get_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/GetStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("stacks.signer.v1.SignerControl", "GetStatus"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Return the deposit and withdrawal requests that are still waiting to
+        /// be fulfilled on the canonical bitcoin chain.
+        pub async fn get_pending_requests(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetPendingRequestsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetPendingRequestsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/GetPendingRequests",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("stacks.signer.v1.SignerControl", "GetPendingRequests"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Set or clear an operator override for this signer's decision on a
+        /// deposit or withdrawal request.
+        pub async fn set_decision_override(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetDecisionOverrideRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetDecisionOverrideResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/SetDecisionOverride",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "SetDecisionOverride",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Pause coordinator duties on this signer. While paused, the signer
+        /// continues to observe blocks and respond to other coordinators.
+        pub async fn pause(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PauseRequest>,
+        ) -> std::result::Result<tonic::Response<super::PauseResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/Pause",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("stacks.signer.v1.SignerControl", "Pause"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Resume coordinator duties on this signer.
+        pub async fn resume(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ResumeRequest>,
+        ) -> std::result::Result<tonic::Response<super::ResumeResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/Resume",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("stacks.signer.v1.SignerControl", "Resume"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Return output descriptors for the current and historical aggregate
+        /// keys of the signers, for setting up watch-only wallets.
+        pub async fn get_wallet_descriptors(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetWalletDescriptorsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetWalletDescriptorsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/GetWalletDescriptors",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "GetWalletDescriptors",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Re-activate a deposit request that expired because it was not swept
+        /// in time, so that it is considered for sweeping again.
+        pub async fn reactivate_deposit(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReactivateDepositRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReactivateDepositResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/ReactivateDeposit",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "ReactivateDeposit",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Pause or resume the sweeping of deposit requests on this signer. While
+        /// deposits are paused, the signer only services withdrawal requests.
+        pub async fn set_deposits_paused(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetDepositsPausedRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetDepositsPausedResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/SetDepositsPaused",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "SetDepositsPaused",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Pause or resume the servicing of withdrawal requests on this signer.
+        /// While withdrawals are paused, the signer does not vote on new
+        /// withdrawal requests and only services deposit requests.
+        pub async fn set_withdrawals_paused(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetWithdrawalsPausedRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetWithdrawalsPausedResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/SetWithdrawalsPaused",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "SetWithdrawalsPaused",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Return the payments to the signers' scriptPubKey that were not
+        /// deposits and that have the given review status.
+        pub async fn list_signer_payments(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListSignerPaymentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListSignerPaymentsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/ListSignerPayments",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "ListSignerPayments",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Mark a payment to the signers as a donation or approve refunding it
+        /// to its sender. Refunds are only signed once enough signer operators
+        /// have approved them.
+        pub async fn set_signer_payment_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetSignerPaymentStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetSignerPaymentStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/SetSignerPaymentStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "SetSignerPaymentStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Vote for a temporary override of the sBTC limits and broadcast the
+        /// vote to the other signers. The override takes effect once enough
+        /// signers have voted for it, and only on the signers whose configured
+        /// bounds it is within.
+        pub async fn propose_limit_override(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProposeLimitOverrideRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProposeLimitOverrideResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/ProposeLimitOverride",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "ProposeLimitOverride",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Approve the emergency recovery sweep of all of the signers' funds to
+        /// the recovery scriptPubKey in the config of this signer, and broadcast
+        /// the approval to the other signers. The funds are swept once enough
+        /// signers have approved it and the configured delay has passed.
+        pub async fn approve_emergency_recovery(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ApproveEmergencyRecoveryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApproveEmergencyRecoveryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/ApproveEmergencyRecovery",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "ApproveEmergencyRecovery",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Revoke the approval of this signer of the emergency recovery, and
+        /// broadcast the revocation to the other signers. The recovery is
+        /// stopped if too few approvals are left.
+        pub async fn revoke_emergency_recovery(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RevokeEmergencyRecoveryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RevokeEmergencyRecoveryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/RevokeEmergencyRecovery",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "RevokeEmergencyRecovery",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Return the Emily API keys that this signer uses, with the keys
+        /// themselves masked.
+        pub async fn list_emily_api_keys(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListEmilyApiKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListEmilyApiKeysResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/ListEmilyApiKeys",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "ListEmilyApiKeys",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Add an Emily API key, or replace the one with the same name. The key
+        /// is used for all later requests to Emily, without a restart.
+        pub async fn set_emily_api_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetEmilyApiKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetEmilyApiKeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/SetEmilyApiKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "SetEmilyApiKey",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Stop using the Emily API key with the given name.
+        pub async fn remove_emily_api_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveEmilyApiKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveEmilyApiKeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/stacks.signer.v1.SignerControl/RemoveEmilyApiKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "stacks.signer.v1.SignerControl",
+                        "RemoveEmilyApiKey",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod signer_control_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with SignerControlServer.
+    #[async_trait]
+    pub trait SignerControl: std::marker::Send + std::marker::Sync + 'static {
+        /// Return a summary of the current state of the signer.
+        async fn get_status(
+            &self,
+            request: tonic::Request<super::GetStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStatusResponse>,
+            tonic::Status,
+        >;
+        /// Return the deposit and withdrawal requests that are still waiting to
+        /// be fulfilled on the canonical bitcoin chain.
+        async fn get_pending_requests(
+            &self,
+            request: tonic::Request<super::GetPendingRequestsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetPendingRequestsResponse>,
+            tonic::Status,
+        >;
+        /// Set or clear an operator override for this signer's decision on a
+        /// deposit or withdrawal request.
+        async fn set_decision_override(
+            &self,
+            request: tonic::Request<super::SetDecisionOverrideRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetDecisionOverrideResponse>,
+            tonic::Status,
+        >;
+        /// Pause coordinator duties on this signer. While paused, the signer
+        /// continues to observe blocks and respond to other coordinators.
+        async fn pause(
+            &self,
+            request: tonic::Request<super::PauseRequest>,
+        ) -> std::result::Result<tonic::Response<super::PauseResponse>, tonic::Status>;
+        /// Resume coordinator duties on this signer.
+        async fn resume(
+            &self,
+            request: tonic::Request<super::ResumeRequest>,
+        ) -> std::result::Result<tonic::Response<super::ResumeResponse>, tonic::Status>;
+        /// Return output descriptors for the current and historical aggregate
+        /// keys of the signers, for setting up watch-only wallets.
+        async fn get_wallet_descriptors(
+            &self,
+            request: tonic::Request<super::GetWalletDescriptorsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetWalletDescriptorsResponse>,
+            tonic::Status,
+        >;
+        /// Re-activate a deposit request that expired because it was not swept
+        /// in time, so that it is considered for sweeping again.
+        async fn reactivate_deposit(
+            &self,
+            request: tonic::Request<super::ReactivateDepositRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReactivateDepositResponse>,
+            tonic::Status,
+        >;
+        /// Pause or resume the sweeping of deposit requests on this signer. While
+        /// deposits are paused, the signer only services withdrawal requests.
+        async fn set_deposits_paused(
+            &self,
+            request: tonic::Request<super::SetDepositsPausedRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetDepositsPausedResponse>,
+            tonic::Status,
+        >;
+        /// Pause or resume the servicing of withdrawal requests on this signer.
+        /// While withdrawals are paused, the signer does not vote on new
+        /// withdrawal requests and only services deposit requests.
+        async fn set_withdrawals_paused(
+            &self,
+            request: tonic::Request<super::SetWithdrawalsPausedRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetWithdrawalsPausedResponse>,
+            tonic::Status,
+        >;
+        /// Return the payments to the signers' scriptPubKey that were not
+        /// deposits and that have the given review status.
+        async fn list_signer_payments(
+            &self,
+            request: tonic::Request<super::ListSignerPaymentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListSignerPaymentsResponse>,
+            tonic::Status,
+        >;
+        /// Mark a payment to the signers as a donation or approve refunding it
+        /// to its sender. Refunds are only signed once enough signer operators
+        /// have approved them.
+        async fn set_signer_payment_status(
+            &self,
+            request: tonic::Request<super::SetSignerPaymentStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetSignerPaymentStatusResponse>,
+            tonic::Status,
+        >;
+        /// Vote for a temporary override of the sBTC limits and broadcast the
+        /// vote to the other signers. The override takes effect once enough
+        /// signers have voted for it, and only on the signers whose configured
+        /// bounds it is within.
+        async fn propose_limit_override(
+            &self,
+            request: tonic::Request<super::ProposeLimitOverrideRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProposeLimitOverrideResponse>,
+            tonic::Status,
+        >;
+        /// Approve the emergency recovery sweep of all of the signers' funds to
+        /// the recovery scriptPubKey in the config of this signer, and broadcast
+        /// the approval to the other signers. The funds are swept once enough
+        /// signers have approved it and the configured delay has passed.
+        async fn approve_emergency_recovery(
+            &self,
+            request: tonic::Request<super::ApproveEmergencyRecoveryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApproveEmergencyRecoveryResponse>,
+            tonic::Status,
+        >;
+        /// Revoke the approval of this signer of the emergency recovery, and
+        /// broadcast the revocation to the other signers. The recovery is
+        /// stopped if too few approvals are left.
+        async fn revoke_emergency_recovery(
+            &self,
+            request: tonic::Request<super::RevokeEmergencyRecoveryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RevokeEmergencyRecoveryResponse>,
+            tonic::Status,
+        >;
+        /// Return the Emily API keys that this signer uses, with the keys
+        /// themselves masked.
+        async fn list_emily_api_keys(
+            &self,
+            request: tonic::Request<super::ListEmilyApiKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListEmilyApiKeysResponse>,
+            tonic::Status,
+        >;
+        /// Add an Emily API key, or replace the one with the same name. The key
+        /// is used for all later requests to Emily, without a restart.
+        async fn set_emily_api_key(
+            &self,
+            request: tonic::Request<super::SetEmilyApiKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetEmilyApiKeyResponse>,
+            tonic::Status,
+        >;
+        /// Stop using the Emily API key with the given name.
+        async fn remove_emily_api_key(
+            &self,
+            request: tonic::Request<super::RemoveEmilyApiKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveEmilyApiKeyResponse>,
+            tonic::Status,
+        >;
+    }
+    /// A service for programmatically querying and controlling a running
+    /// signer. This service is meant for the operator of the signer and
+    /// should not be exposed publicly.
+    #[derive(Debug)]
+    pub struct SignerControlServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> SignerControlServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for SignerControlServer<T>
+    where
+        T: SignerControl,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/stacks.signer.v1.SignerControl/GetStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStatusSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::GetStatusRequest>
+                    for GetStatusSvc<T> {
+                        type Response = super::GetStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::get_status(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/GetPendingRequests" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetPendingRequestsSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::GetPendingRequestsRequest>
+                    for GetPendingRequestsSvc<T> {
+                        type Response = super::GetPendingRequestsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetPendingRequestsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::get_pending_requests(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetPendingRequestsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/SetDecisionOverride" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetDecisionOverrideSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::SetDecisionOverrideRequest>
+                    for SetDecisionOverrideSvc<T> {
+                        type Response = super::SetDecisionOverrideResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetDecisionOverrideRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::set_decision_override(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetDecisionOverrideSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/Pause" => {
+                    #[allow(non_camel_case_types)]
+                    struct PauseSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::PauseRequest> for PauseSvc<T> {
+                        type Response = super::PauseResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PauseRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::pause(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PauseSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/Resume" => {
+                    #[allow(non_camel_case_types)]
+                    struct ResumeSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::ResumeRequest> for ResumeSvc<T> {
+                        type Response = super::ResumeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ResumeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::resume(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ResumeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/GetWalletDescriptors" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetWalletDescriptorsSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::GetWalletDescriptorsRequest> for GetWalletDescriptorsSvc<T> {
+                        type Response = super::GetWalletDescriptorsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetWalletDescriptorsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::get_wallet_descriptors(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetWalletDescriptorsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/ReactivateDeposit" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReactivateDepositSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::ReactivateDepositRequest> for ReactivateDepositSvc<T> {
+                        type Response = super::ReactivateDepositResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReactivateDepositRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::reactivate_deposit(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReactivateDepositSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/SetDepositsPaused" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetDepositsPausedSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::SetDepositsPausedRequest> for SetDepositsPausedSvc<T> {
+                        type Response = super::SetDepositsPausedResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetDepositsPausedRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::set_deposits_paused(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetDepositsPausedSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/SetWithdrawalsPaused" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetWithdrawalsPausedSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::SetWithdrawalsPausedRequest> for SetWithdrawalsPausedSvc<T> {
+                        type Response = super::SetWithdrawalsPausedResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetWithdrawalsPausedRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::set_withdrawals_paused(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetWithdrawalsPausedSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/ListSignerPayments" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListSignerPaymentsSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::ListSignerPaymentsRequest> for ListSignerPaymentsSvc<T> {
+                        type Response = super::ListSignerPaymentsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListSignerPaymentsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::list_signer_payments(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListSignerPaymentsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/SetSignerPaymentStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetSignerPaymentStatusSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::SetSignerPaymentStatusRequest> for SetSignerPaymentStatusSvc<T> {
+                        type Response = super::SetSignerPaymentStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetSignerPaymentStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::set_signer_payment_status(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetSignerPaymentStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/ProposeLimitOverride" => {
+                    #[allow(non_camel_case_types)]
+                    struct ProposeLimitOverrideSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::ProposeLimitOverrideRequest> for ProposeLimitOverrideSvc<T> {
+                        type Response = super::ProposeLimitOverrideResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProposeLimitOverrideRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::propose_limit_override(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ProposeLimitOverrideSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/ApproveEmergencyRecovery" => {
+                    #[allow(non_camel_case_types)]
+                    struct ApproveEmergencyRecoverySvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::ApproveEmergencyRecoveryRequest> for ApproveEmergencyRecoverySvc<T> {
+                        type Response = super::ApproveEmergencyRecoveryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ApproveEmergencyRecoveryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::approve_emergency_recovery(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ApproveEmergencyRecoverySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/RevokeEmergencyRecovery" => {
+                    #[allow(non_camel_case_types)]
+                    struct RevokeEmergencyRecoverySvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::RevokeEmergencyRecoveryRequest> for RevokeEmergencyRecoverySvc<T> {
+                        type Response = super::RevokeEmergencyRecoveryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RevokeEmergencyRecoveryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::revoke_emergency_recovery(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RevokeEmergencyRecoverySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/ListEmilyApiKeys" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListEmilyApiKeysSvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::ListEmilyApiKeysRequest> for ListEmilyApiKeysSvc<T> {
+                        type Response = super::ListEmilyApiKeysResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListEmilyApiKeysRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::list_emily_api_keys(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListEmilyApiKeysSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/SetEmilyApiKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetEmilyApiKeySvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::SetEmilyApiKeyRequest> for SetEmilyApiKeySvc<T> {
+                        type Response = super::SetEmilyApiKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetEmilyApiKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::set_emily_api_key(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetEmilyApiKeySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/stacks.signer.v1.SignerControl/RemoveEmilyApiKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveEmilyApiKeySvc<T: SignerControl>(pub Arc<T>);
+                    impl<
+                        T: SignerControl,
+                    > tonic::server::UnaryService<super::RemoveEmilyApiKeyRequest> for RemoveEmilyApiKeySvc<T> {
+                        type Response = super::RemoveEmilyApiKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RemoveEmilyApiKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SignerControl>::remove_emily_api_key(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RemoveEmilyApiKeySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for SignerControlServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "stacks.signer.v1.SignerControl";
+    impl<T> tonic::server::NamedService for SignerControlServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
@@ -0,0 +1,283 @@
+//! Decision receipts and quorum certificates for deposit requests.
+//!
+//! When a signer accepts a deposit request, and can sign for it, it
+//! attaches a signed receipt to the decision that it broadcasts to the
+//! other signers. A receipt is an ECDSA signature over a digest that
+//! commits to the deposit request, so it cannot be replayed for another
+//! request. Signers store the receipts that they receive, and once there
+//! are receipts from at least `threshold` members of the signing set, they
+//! can be bundled into a [`DepositQuorumCertificate`].
+//!
+//! The coordinator attaches the certificates for the deposit requests
+//! that it selected to its
+//! [`BitcoinPreSignRequest`](crate::message::BitcoinPreSignRequest), so
+//! that the other signers can verify that the selection was backed by a
+//! quorum of signers instead of trusting the contents of their own
+//! database.
+
+use std::collections::BTreeSet;
+
+use bitcoin::hashes::Hash as _;
+use sha2::Digest as _;
+use sha2::Sha256;
+
+use crate::error::Error;
+use crate::keys::PrivateKey;
+use crate::keys::PublicKey;
+use crate::message::DecisionReceipt;
+use crate::message::DepositQuorumCertificate;
+use crate::storage::DbRead;
+use crate::storage::model;
+
+/// The domain separator for the digest of deposit decision receipts.
+const DEPOSIT_RECEIPT_TAG: &[u8] = b"SBTC_DEPOSIT_DECISION_RECEIPT";
+
+/// The digest that a signer signs to acknowledge that it accepted the
+/// deposit request identified by the given outpoint.
+pub fn deposit_receipt_digest(outpoint: &bitcoin::OutPoint) -> [u8; 32] {
+    Sha256::new_with_prefix(DEPOSIT_RECEIPT_TAG)
+        .chain_update(outpoint.txid.to_byte_array())
+        .chain_update(outpoint.vout.to_le_bytes())
+        .finalize()
+        .into()
+}
+
+/// Sign a receipt of the decision to accept the deposit request identified
+/// by the given outpoint.
+pub fn sign_deposit_receipt(
+    private_key: &PrivateKey,
+    outpoint: &bitcoin::OutPoint,
+) -> secp256k1::ecdsa::Signature {
+    let msg = secp256k1::Message::from_digest(deposit_receipt_digest(outpoint));
+    private_key.sign_ecdsa(&msg)
+}
+
+/// Verify that the given receipt was signed by the signer in the receipt
+/// for the deposit request identified by the given outpoint.
+pub fn verify_deposit_receipt(
+    outpoint: &bitcoin::OutPoint,
+    receipt: &DecisionReceipt,
+) -> Result<(), Error> {
+    let msg = secp256k1::Message::from_digest(deposit_receipt_digest(outpoint));
+    receipt
+        .signature
+        .verify(&msg, &receipt.signer_public_key)
+        .map_err(Error::InvalidEcdsaSignature)
+}
+
+impl TryFrom<&model::DepositDecisionReceipt> for DecisionReceipt {
+    type Error = Error;
+    fn try_from(value: &model::DepositDecisionReceipt) -> Result<Self, Self::Error> {
+        Ok(DecisionReceipt {
+            signer_public_key: value.signer_pub_key,
+            signature: secp256k1::ecdsa::Signature::from_compact(&value.signature)
+                .map_err(Error::InvalidEcdsaSignatureBytes)?,
+        })
+    }
+}
+
+impl DepositQuorumCertificate {
+    /// Verify that the certificate holds valid receipts from at least
+    /// `threshold` distinct members of the given signer set, returning
+    /// those members.
+    ///
+    /// Receipts from signers outside of the signer set, and duplicate
+    /// receipts, are not counted, but an invalid signature in any receipt
+    /// fails verification.
+    pub fn verify(
+        &self,
+        signer_set: &BTreeSet<PublicKey>,
+        threshold: u16,
+    ) -> Result<BTreeSet<PublicKey>, Error> {
+        let mut signers = BTreeSet::new();
+        for receipt in self.receipts.iter() {
+            verify_deposit_receipt(&self.outpoint, receipt)
+                .map_err(|_| Error::InvalidDepositQuorumCertificate(self.outpoint))?;
+
+            if signer_set.contains(&receipt.signer_public_key) {
+                signers.insert(receipt.signer_public_key);
+            }
+        }
+
+        if signers.len() < usize::from(threshold) {
+            return Err(Error::InvalidDepositQuorumCertificate(self.outpoint));
+        }
+
+        Ok(signers)
+    }
+}
+
+/// Assemble a quorum certificate for the deposit request identified by
+/// the given outpoint from the receipts in the database.
+///
+/// Only receipts from members of the given signer set whose latest
+/// decision on the request still accepts it are included, so a receipt
+/// is dropped once its signer supersedes the decision that it came with
+/// by a rejection. `None` is returned if there are fewer than `threshold`
+/// receipts left.
+pub async fn assemble_deposit_certificate<D>(
+    db: &D,
+    outpoint: &bitcoin::OutPoint,
+    signer_set: &BTreeSet<PublicKey>,
+    threshold: u16,
+) -> Result<Option<DepositQuorumCertificate>, Error>
+where
+    D: DbRead,
+{
+    let txid = outpoint.txid.into();
+    let accepting: BTreeSet<PublicKey> = db
+        .get_deposit_signers(&txid, outpoint.vout)
+        .await?
+        .into_iter()
+        .filter(|decision| decision.can_accept && decision.can_sign)
+        .map(|decision| decision.signer_pub_key)
+        .collect();
+    let receipts = db
+        .get_deposit_decision_receipts(&txid, outpoint.vout)
+        .await?
+        .iter()
+        .filter(|receipt| signer_set.contains(&receipt.signer_pub_key))
+        .filter(|receipt| accepting.contains(&receipt.signer_pub_key))
+        .map(DecisionReceipt::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if receipts.len() < usize::from(threshold) {
+        return Ok(None);
+    }
+
+    Ok(Some(DepositQuorumCertificate {
+        outpoint: *outpoint,
+        receipts,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+    use rand::SeedableRng as _;
+
+    use super::*;
+    use crate::storage::DbWrite as _;
+    use crate::storage::memory::Store;
+
+    fn certificate(
+        private_keys: &[PrivateKey],
+        outpoint: bitcoin::OutPoint,
+    ) -> DepositQuorumCertificate {
+        let receipts = private_keys
+            .iter()
+            .map(|private_key| DecisionReceipt {
+                signer_public_key: PublicKey::from_private_key(private_key),
+                signature: sign_deposit_receipt(private_key, &outpoint),
+            })
+            .collect();
+
+        DepositQuorumCertificate { outpoint, receipts }
+    }
+
+    #[test]
+    fn certificate_verification_counts_distinct_members() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(51);
+        let private_keys: Vec<PrivateKey> = std::iter::repeat_with(|| PrivateKey::new(&mut rng))
+            .take(3)
+            .collect();
+        let signer_set: BTreeSet<PublicKey> = private_keys
+            .iter()
+            .map(PublicKey::from_private_key)
+            .collect();
+        let outpoint = bitcoin::OutPoint {
+            txid: crate::testing::dummy::txid(&fake::Faker, &mut rng),
+            vout: 1,
+        };
+
+        let mut cert = certificate(&private_keys[..2], outpoint);
+        let signers = cert.verify(&signer_set, 2).unwrap();
+        let expected: BTreeSet<PublicKey> = private_keys[..2]
+            .iter()
+            .map(PublicKey::from_private_key)
+            .collect();
+        assert_eq!(signers, expected);
+
+        // Duplicate receipts do not count towards the threshold.
+        cert.receipts.push(cert.receipts[0]);
+        assert!(cert.verify(&signer_set, 3).is_err());
+
+        // Neither do receipts from signers outside of the signer set.
+        let outsider = PrivateKey::new(&mut rng);
+        let cert = certificate(&[private_keys[0], private_keys[1], outsider], outpoint);
+        assert!(cert.verify(&signer_set, 3).is_err());
+
+        // A receipt for another deposit request is invalid.
+        let mut cert = certificate(&private_keys, outpoint);
+        cert.outpoint.vout = 2;
+        assert!(matches!(
+            cert.verify(&signer_set, 1),
+            Err(Error::InvalidDepositQuorumCertificate(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn assemble_certificate_requires_threshold_receipts() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(51);
+        let db = Store::new_shared();
+        let private_keys: Vec<PrivateKey> = std::iter::repeat_with(|| PrivateKey::new(&mut rng))
+            .take(3)
+            .collect();
+        let signer_set: BTreeSet<PublicKey> = private_keys
+            .iter()
+            .map(PublicKey::from_private_key)
+            .collect();
+        let txid: model::BitcoinTxId = fake::Faker.fake_with_rng(&mut rng);
+        let outpoint = bitcoin::OutPoint { txid: *txid, vout: 0 };
+
+        for private_key in &private_keys[..2] {
+            let decision = model::DepositSigner {
+                txid,
+                output_index: outpoint.vout,
+                signer_pub_key: PublicKey::from_private_key(private_key),
+                can_accept: true,
+                can_sign: true,
+                decision_version: 0,
+            };
+            db.write_deposit_signer_decision(&decision).await.unwrap();
+            let receipt = model::DepositDecisionReceipt {
+                txid,
+                output_index: outpoint.vout,
+                signer_pub_key: PublicKey::from_private_key(private_key),
+                signature: sign_deposit_receipt(private_key, &outpoint)
+                    .serialize_compact()
+                    .to_vec(),
+            };
+            db.write_deposit_decision_receipt(&receipt).await.unwrap();
+        }
+
+        let cert = assemble_deposit_certificate(&db, &outpoint, &signer_set, 3)
+            .await
+            .unwrap();
+        assert!(cert.is_none());
+
+        let cert = assemble_deposit_certificate(&db, &outpoint, &signer_set, 2)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cert.receipts.len(), 2);
+        cert.verify(&signer_set, 2).unwrap();
+
+        // A signer that supersedes its decision with a rejection no longer
+        // backs the certificate, even though its receipt is still stored.
+        let decision = model::DepositSigner {
+            txid,
+            output_index: outpoint.vout,
+            signer_pub_key: PublicKey::from_private_key(&private_keys[0]),
+            can_accept: false,
+            can_sign: true,
+            decision_version: 1,
+        };
+        db.write_deposit_signer_decision(&decision).await.unwrap();
+
+        let cert = assemble_deposit_certificate(&db, &outpoint, &signer_set, 2)
+            .await
+            .unwrap();
+        assert!(cert.is_none());
+    }
+}
@@ -0,0 +1,259 @@
+//! Reconciliation of the signers' UTXO with the UTXO set of bitcoin-core.
+//!
+//! The signers track their UTXO in their database by following the sweep
+//! transactions that they broadcast. If the signers miss a transaction
+//! that spends to or from their wallet, say a donation or a spend that was
+//! never recorded, or if the database gets corrupted, the stored UTXO no
+//! longer matches what is actually on chain, and the next sweep will be
+//! built from the wrong inputs.
+//!
+//! To catch this early, each signer periodically scans the UTXO set of
+//! bitcoin-core with `scantxoutset` using the output descriptors of all
+//! of the signers' aggregate keys, and compares the result with the UTXO
+//! in its database.
+
+use bitcoin::Amount;
+use bitcoin::OutPoint;
+use bitcoincore_rpc_json::ScanTxOutResult;
+
+use crate::bitcoin::BitcoinInteract as _;
+use crate::bitcoin::descriptor;
+use crate::bitcoin::utxo::SignerUtxo;
+use crate::context::Context;
+use crate::context::SignerEvent;
+use crate::error::Error;
+use crate::metrics::Metrics;
+use crate::storage::DbRead as _;
+use crate::storage::model::BitcoinBlockHash;
+use crate::storage::model::DkgSharesStatus;
+
+/// The outcome of comparing the signers' UTXO in the database with the
+/// result of scanning the UTXO set of bitcoin-core.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UtxoReconciliation {
+    /// The database and bitcoin-core agree.
+    Matched,
+    /// The signers' wallet holds a different amount than the UTXO in the
+    /// database. This happens when there are donations that the signers
+    /// have not picked up yet, so it is not necessarily a problem.
+    AmountMismatch {
+        /// The amount of the UTXO in the database.
+        stored: Amount,
+        /// The total amount locked by the signers' wallet according to
+        /// bitcoin-core.
+        scanned: Amount,
+    },
+    /// The UTXO in the database is not unspent according to bitcoin-core.
+    MissingUtxo(OutPoint),
+}
+
+/// Compare the signers' UTXO in the database with the result of scanning
+/// the UTXO set of bitcoin-core.
+pub fn reconcile(stored: Option<&SignerUtxo>, scanned: &ScanTxOutResult) -> UtxoReconciliation {
+    if let Some(utxo) = stored {
+        let is_unspent = scanned.unspents.iter().any(|unspent| {
+            unspent.txid == utxo.outpoint.txid && unspent.vout == utxo.outpoint.vout
+        });
+
+        if !is_unspent {
+            return UtxoReconciliation::MissingUtxo(utxo.outpoint);
+        }
+    }
+
+    let stored = Amount::from_sat(stored.map_or(0, |utxo| utxo.amount));
+    if stored != scanned.total_amount {
+        return UtxoReconciliation::AmountMismatch {
+            stored,
+            scanned: scanned.total_amount,
+        };
+    }
+
+    UtxoReconciliation::Matched
+}
+
+/// Periodically reconcile the signers' UTXO in the database with the UTXO
+/// set of bitcoin-core until shutdown, if reconciliation is enabled.
+///
+/// Failures to reconcile are logged and the task carries on, since
+/// bitcoin-core may be unavailable for a while.
+pub async fn run_utxo_reconciliation(ctx: impl Context) -> Result<(), Error> {
+    if !ctx.config().signer.utxo_reconciliation_enabled {
+        return Ok(());
+    }
+
+    let interval = ctx.config().signer.utxo_reconciliation_interval;
+    let mut term = ctx.get_termination_handle();
+    loop {
+        tokio::select! {
+            _ = term.wait_for_shutdown() => return Ok(()),
+            _ = ctx.clock().sleep(interval) => {}
+        }
+
+        if let Err(error) = reconcile_signer_utxo(&ctx).await {
+            tracing::warn!(%error, "could not reconcile the signers' UTXO with bitcoin-core");
+        }
+    }
+}
+
+/// Reconcile the signers' UTXO in the database with the UTXO set of
+/// bitcoin-core, alerting on a mismatch.
+///
+/// Returns `None` if the reconciliation was skipped, either because there
+/// is nothing to reconcile yet or because bitcoin-core scanned the UTXO
+/// set at a different chain tip than the one in our database.
+pub async fn reconcile_signer_utxo(
+    ctx: &impl Context,
+) -> Result<Option<UtxoReconciliation>, Error> {
+    let Some(chain_tip) = ctx.state().bitcoin_chain_tip() else {
+        tracing::debug!("no bitcoin chain tip; skipping UTXO reconciliation");
+        return Ok(None);
+    };
+
+    // Failed DKG shares never made it into the registry contract, so no
+    // funds were ever locked by their aggregate keys.
+    let descriptors: Vec<String> = ctx
+        .get_storage()
+        .get_aggregate_key_records()
+        .await?
+        .into_iter()
+        .filter(|record| record.dkg_shares_status != DkgSharesStatus::Failed)
+        .map(|record| descriptor::signers_descriptor(&record.aggregate_key))
+        .collect();
+
+    if descriptors.is_empty() {
+        tracing::debug!("no aggregate keys; skipping UTXO reconciliation");
+        return Ok(None);
+    }
+
+    let stored = ctx
+        .get_storage()
+        .get_signer_utxo(&chain_tip.block_hash)
+        .await?;
+    let scanned = ctx
+        .get_bitcoin_client()
+        .scan_tx_out_set(&descriptors)
+        .await?;
+
+    let scanned_tip = scanned.best_block_hash.map(BitcoinBlockHash::from);
+    if scanned_tip != Some(chain_tip.block_hash) {
+        tracing::debug!(
+            chain_tip = %chain_tip.block_hash,
+            scanned_tip = ?scanned_tip,
+            "bitcoin-core scanned a different chain tip; skipping UTXO reconciliation"
+        );
+        return Ok(None);
+    }
+
+    let outcome = reconcile(stored.as_ref(), &scanned);
+    match outcome {
+        UtxoReconciliation::Matched => {
+            tracing::debug!(chain_tip = %chain_tip.block_hash, "signers' UTXO matches bitcoin-core");
+        }
+        UtxoReconciliation::AmountMismatch { stored, scanned } => {
+            metrics::counter!(Metrics::SignerUtxoMismatchesTotal, "kind" => "amount").increment(1);
+            tracing::error!(
+                chain_tip = %chain_tip.block_hash,
+                stored = %stored.to_sat(),
+                scanned = %scanned.to_sat(),
+                "the signers' wallet balance does not match the UTXO in the database"
+            );
+        }
+        UtxoReconciliation::MissingUtxo(outpoint) => {
+            metrics::counter!(Metrics::SignerUtxoMismatchesTotal, "kind" => "missing").increment(1);
+            tracing::error!(
+                chain_tip = %chain_tip.block_hash,
+                %outpoint,
+                "the signers' UTXO in the database is not unspent according to bitcoin-core"
+            );
+        }
+    }
+
+    if outcome != UtxoReconciliation::Matched {
+        ctx.signal(SignerEvent::SignerUtxoMismatch(outcome).into())?;
+    }
+
+    Ok(Some(outcome))
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hashes::Hash as _;
+    use bitcoincore_rpc_json::Utxo;
+
+    use super::*;
+    use crate::keys::PrivateKey;
+    use crate::keys::PublicKey;
+
+    fn signer_utxo(amount: u64) -> SignerUtxo {
+        let public_key = PublicKey::from_private_key(&PrivateKey::new(&mut rand::rngs::OsRng));
+        SignerUtxo {
+            outpoint: OutPoint {
+                txid: bitcoin::Txid::from_byte_array([1; 32]),
+                vout: 0,
+            },
+            amount,
+            public_key: public_key.into(),
+        }
+    }
+
+    fn scan_result(utxos: &[(OutPoint, u64)]) -> ScanTxOutResult {
+        let unspents: Vec<Utxo> = utxos
+            .iter()
+            .map(|(outpoint, amount)| Utxo {
+                txid: outpoint.txid,
+                vout: outpoint.vout,
+                script_pub_key: bitcoin::ScriptBuf::new(),
+                descriptor: String::new(),
+                amount: Amount::from_sat(*amount),
+                height: 1,
+            })
+            .collect();
+
+        ScanTxOutResult {
+            success: Some(true),
+            tx_outs: Some(unspents.len() as u64),
+            height: Some(1),
+            best_block_hash: Some(bitcoin::BlockHash::from_byte_array([2; 32])),
+            total_amount: unspents.iter().map(|utxo| utxo.amount).sum(),
+            unspents,
+        }
+    }
+
+    #[test]
+    fn reconcile_detects_mismatches() {
+        let utxo = signer_utxo(10_000);
+        let other = OutPoint {
+            txid: bitcoin::Txid::from_byte_array([3; 32]),
+            vout: 1,
+        };
+
+        let scanned = scan_result(&[(utxo.outpoint, 10_000)]);
+        assert_eq!(
+            reconcile(Some(&utxo), &scanned),
+            UtxoReconciliation::Matched
+        );
+
+        // No UTXO and no funds is fine.
+        assert_eq!(
+            reconcile(None, &scan_result(&[])),
+            UtxoReconciliation::Matched
+        );
+
+        // A donation that has not been swept shows up as extra funds.
+        let scanned = scan_result(&[(utxo.outpoint, 10_000), (other, 500)]);
+        assert_eq!(
+            reconcile(Some(&utxo), &scanned),
+            UtxoReconciliation::AmountMismatch {
+                stored: Amount::from_sat(10_000),
+                scanned: Amount::from_sat(10_500),
+            }
+        );
+
+        // A spend that we missed means our UTXO is gone.
+        let scanned = scan_result(&[(other, 10_000)]);
+        assert_eq!(
+            reconcile(Some(&utxo), &scanned),
+            UtxoReconciliation::MissingUtxo(utxo.outpoint)
+        );
+    }
+}
@@ -5,10 +5,16 @@
 //!
 //! For more details, see the [`RequestDeciderEventLoop`] documentation.
 
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
 use crate::block_observer::BlockObserver;
+use crate::block_processing;
 use crate::blocklist_client::BlocklistChecker;
+use crate::consistency;
+use crate::consistency::DIGEST_BLOCK_RANGE;
 use crate::context::Context;
 use crate::context::P2PEvent;
 use crate::context::RequestDeciderEvent;
@@ -17,28 +23,67 @@ use crate::context::SignerEvent;
 use crate::context::SignerSignal;
 use crate::ecdsa::SignEcdsa as _;
 use crate::ecdsa::Signed;
+use crate::emergency_recovery;
 use crate::emily_client::EmilyInteract;
 use crate::error::Error;
+use crate::key_rotation;
 use crate::keys::PrivateKey;
 use crate::keys::PublicKey;
+use crate::limit_override;
+use crate::message::DatabaseDigest;
+use crate::message::DecisionAcknowledgement;
+use crate::message::DecisionReceipt;
+use crate::message::DepositDecisionAck;
+use crate::message::DepositDecisionRetryRequest;
 use crate::message::Payload;
+use crate::message::SignerDecisionBatch;
 use crate::message::SignerDepositDecision;
 use crate::message::SignerMessage;
 use crate::message::SignerWithdrawalDecision;
+use crate::message::WithdrawalDecisionAck;
+use crate::metrics::Metrics;
 use crate::network::MessageTransfer;
+use crate::policy_plugin;
+use crate::policy_plugin::PolicyPlugin;
+use crate::policy_plugin::PolicyRequest;
+use crate::policy_plugin::PolicyVerdict;
+use crate::quorum;
 use crate::storage::DbRead as _;
 use crate::storage::DbWrite as _;
 use crate::storage::model;
 use crate::storage::model::BitcoinBlockHash;
+use crate::storage::model::BitcoinBlockHeight;
+use crate::storage::model::BlockProcessingStage;
 use crate::storage::model::DepositSigner;
 use crate::storage::model::WithdrawalSigner;
+use crate::supervisor::report_error;
+use crate::transaction_coordinator::given_key_is_coordinator;
 
+use emily_client::models::DepositStatus;
+use emily_client::models::DepositUpdate;
 use futures::StreamExt;
 use futures::TryStreamExt;
 
+/// The maximum number of decisions sent in a single
+/// [`SignerDecisionBatch`] message. Each decision takes up less than 100
+/// bytes when encoded, so this keeps the messages well below the maximum
+/// size that the P2P network will transmit.
+pub const MAX_DECISIONS_PER_BATCH: usize = 500;
+
+/// The maximum number of previously sent decisions that are broadcast
+/// again for a single bitcoin chain tip. Signers that are still missing
+/// one of our deposit decisions after that can ask for it with a
+/// [`DepositDecisionRetryRequest`].
+pub const MAX_RETRIED_DECISIONS_PER_BLOCK: usize = 1_000;
+
+/// The name of this event loop, as used by the supervisor.
+const EVENT_LOOP: &str = "request-decider";
+
 /// This struct is responsible for deciding whether to accept or reject
-/// requests and persisting requests from other signers.
+/// requests and persisting requests from other signers. It is created
+/// through [`RequestDeciderEventLoop::builder`].
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct RequestDeciderEventLoop<C, N, B> {
     /// The signer context.
     pub context: C,
@@ -46,6 +91,9 @@ pub struct RequestDeciderEventLoop<C, N, B> {
     pub network: N,
     /// Blocklist checker.
     pub blocklist_checker: Option<B>,
+    /// A policy that is consulted on each request that the signer would
+    /// otherwise accept.
+    pub policy_plugin: Option<Arc<dyn PolicyPlugin>>,
     /// Private key of the signer for network communication.
     pub signer_private_key: PrivateKey,
     /// How many bitcoin blocks back from the chain tip the signer will look for requests.
@@ -58,6 +106,104 @@ pub struct RequestDeciderEventLoop<C, N, B> {
     pub withdrawal_decisions_retry_window: u16,
 }
 
+/// A builder for a [`RequestDeciderEventLoop`]. The settings default to
+/// the ones in the config of the context, and are validated when the
+/// event loop is built. No blocklist checker or policy plugin is used
+/// unless one is set.
+#[derive(Debug)]
+pub struct RequestDeciderEventLoopBuilder<C, N, B> {
+    event_loop: RequestDeciderEventLoop<C, N, B>,
+}
+
+impl<C, N, B> RequestDeciderEventLoopBuilder<C, N, B>
+where
+    C: Context,
+{
+    /// Create a new builder for a request decider that uses the given
+    /// context and network.
+    pub fn new(context: C, network: N) -> Self {
+        let config = &context.config().signer;
+        let event_loop = RequestDeciderEventLoop {
+            network,
+            blocklist_checker: None,
+            policy_plugin: None,
+            signer_private_key: config.private_key,
+            context_window: config.context_window,
+            deposit_decisions_retry_window: config.deposit_decisions_retry_window,
+            withdrawal_decisions_retry_window: config.withdrawal_decisions_retry_window,
+            context,
+        };
+        Self { event_loop }
+    }
+
+    /// Set the blocklist checker.
+    pub fn with_blocklist_checker(mut self, blocklist_checker: Option<B>) -> Self {
+        self.event_loop.blocklist_checker = blocklist_checker;
+        self
+    }
+
+    /// Set the policy plugin.
+    pub fn with_policy_plugin(mut self, policy_plugin: Option<Arc<dyn PolicyPlugin>>) -> Self {
+        self.event_loop.policy_plugin = policy_plugin;
+        self
+    }
+
+    /// Set the private key of the signer.
+    pub fn with_private_key(mut self, private_key: PrivateKey) -> Self {
+        self.event_loop.signer_private_key = private_key;
+        self
+    }
+
+    /// Set how many bitcoin blocks back from the chain tip the signer
+    /// looks for requests.
+    pub fn with_context_window(mut self, context_window: u16) -> Self {
+        self.event_loop.context_window = context_window;
+        self
+    }
+
+    /// Set how many bitcoin blocks back from the chain tip the signer
+    /// looks for deposit and withdrawal decisions to retry to propagate.
+    pub fn with_decisions_retry_windows(mut self, deposits: u16, withdrawals: u16) -> Self {
+        self.event_loop.deposit_decisions_retry_window = deposits;
+        self.event_loop.withdrawal_decisions_retry_window = withdrawals;
+        self
+    }
+
+    /// Validate the settings and build the event loop.
+    pub fn build(self) -> Result<RequestDeciderEventLoop<C, N, B>, Error> {
+        let event_loop = self.event_loop;
+        if event_loop.context_window == 0 {
+            return Err(Error::InvalidEventLoopSetting(
+                "the context window must not be zero",
+            ));
+        }
+        let retry_windows = [
+            event_loop.deposit_decisions_retry_window,
+            event_loop.withdrawal_decisions_retry_window,
+        ];
+        if retry_windows
+            .iter()
+            .any(|window| *window > event_loop.context_window)
+        {
+            return Err(Error::InvalidEventLoopSetting(
+                "the decisions retry windows must not be larger than the context window",
+            ));
+        }
+        Ok(event_loop)
+    }
+}
+
+impl<C, N, B> RequestDeciderEventLoop<C, N, B>
+where
+    C: Context,
+{
+    /// Create a builder for a request decider that uses the given context
+    /// and network, with the settings in the config of the context.
+    pub fn builder(context: C, network: N) -> RequestDeciderEventLoopBuilder<C, N, B> {
+        RequestDeciderEventLoopBuilder::new(context, network)
+    }
+}
+
 /// This function defines which messages this event loop is interested
 /// in.
 fn run_loop_message_filter(signal: &SignerSignal) -> bool {
@@ -93,16 +239,28 @@ where
         while let Some(message) = signal_stream.next().await {
             match message {
                 SignerSignal::Command(SignerCommand::Shutdown) => break,
-                SignerSignal::Command(SignerCommand::P2PPublish(_)) => {}
+                SignerSignal::Command(
+                    SignerCommand::P2PPublish(_) | SignerCommand::ResyncBitcoinBlocks(_),
+                ) => {}
                 SignerSignal::Event(event) => match event {
                     SignerEvent::P2P(P2PEvent::MessageReceived(msg)) => {
                         if let Err(error) = self.handle_signer_message(&msg).await {
-                            tracing::error!(%error, "error handling signer message");
+                            report_error(
+                                &self.context,
+                                EVENT_LOOP,
+                                &error,
+                                "handle signer message",
+                            );
                         }
                     }
                     SignerEvent::BitcoinBlockObserved => {
                         if let Err(error) = self.handle_new_requests().await {
-                            tracing::warn!(%error, "error handling new requests; skipping this round");
+                            let doing = "handle new requests; skipping this round";
+                            report_error(&self.context, EVENT_LOOP, &error, doing);
+                        }
+                        if let Err(error) = self.advance_key_rotation().await {
+                            let doing = "advance the signer key rotation";
+                            report_error(&self.context, EVENT_LOOP, &error, doing);
                         }
 
                         let message = RequestDeciderEvent::NewRequestsHandled.into();
@@ -127,27 +285,37 @@ where
     /// Vote on pending deposit requests
     #[tracing::instrument(skip_all, fields(chain_tip = tracing::field::Empty))]
     pub async fn handle_new_requests(&mut self) -> Result<(), Error> {
-        let requests_processing_delay = self.context.config().signer.requests_processing_delay;
+        let requests_processing_delay = self
+            .context
+            .config()
+            .signer
+            .request_decider_delay()
+            .sample(&mut rand::rngs::OsRng);
         if requests_processing_delay > Duration::ZERO {
             tracing::debug!("sleeping before processing new requests");
-            tokio::time::sleep(requests_processing_delay).await;
+            self.context.clock().sleep(requests_processing_delay).await;
         }
 
         let db = self.context.get_storage();
-        let chain_tip = self
+        let chain_tip_ref = self
             .context
             .state()
             .bitcoin_chain_tip()
-            .ok_or(Error::NoChainTip)?
-            .block_hash;
+            .ok_or(Error::NoChainTip)?;
+        let chain_tip = chain_tip_ref.block_hash;
 
         let signer_public_key = self.signer_public_key();
 
         let span = tracing::Span::current();
         span.record("chain_tip", tracing::field::display(chain_tip));
 
+        // The decisions that we make or retry for this chain tip are
+        // broadcast together, in as few messages as possible.
+        let mut decisions = SignerDecisionBatch::default();
+
         // We retry the deposit decisions because some signers' bitcoin nodes might have
-        // been running behind and ignored the previous messages.
+        // been running behind and ignored the previous messages. Decisions
+        // that every other signer has acknowledged are not sent again.
         let deposit_decisions_to_retry = db
             .get_deposit_signer_decisions(
                 &chain_tip,
@@ -155,13 +323,24 @@ where
                 &signer_public_key,
             )
             .await?;
-
-        let _ = self
-            .handle_deposit_decisions_to_retry(deposit_decisions_to_retry, &chain_tip)
-            .await
-            .inspect_err(
-                |error| tracing::warn!(%error, "error handling deposit decisions to retry"),
+        let deposit_decisions_to_retry = self
+            .unacknowledged_deposit_decisions(deposit_decisions_to_retry)
+            .await?;
+        let mut retry_budget = MAX_RETRIED_DECISIONS_PER_BLOCK;
+        if deposit_decisions_to_retry.len() > retry_budget {
+            tracing::warn!(
+                num_unacknowledged = deposit_decisions_to_retry.len(),
+                retry_budget,
+                "too many unacknowledged deposit decisions; retrying only some of them"
             );
+        }
+        decisions.deposits.extend(
+            deposit_decisions_to_retry
+                .into_iter()
+                .take(retry_budget)
+                .map(SignerDepositDecision::from),
+        );
+        retry_budget = retry_budget.saturating_sub(decisions.deposits.len());
 
         let deposit_requests = db
             .get_pending_deposit_requests(&chain_tip, self.context_window, &signer_public_key)
@@ -169,16 +348,12 @@ where
 
         for deposit_request in deposit_requests {
             let outpoint = deposit_request.outpoint();
-            let _ = self
-                .handle_pending_deposit_request(deposit_request, &chain_tip)
-                .await
-                .inspect_err(|error| {
-                    tracing::warn!(
-                        %error,
-                        %outpoint,
-                        "error handling new deposit request"
-                    )
-                });
+            match self.handle_pending_deposit_request(deposit_request).await {
+                Ok(decision) => decisions.deposits.push(decision),
+                Err(error) => {
+                    tracing::warn!(%error, %outpoint, "error handling new deposit request")
+                }
+            }
         }
 
         let withdrawal_decisions_to_retry = db
@@ -188,35 +363,415 @@ where
                 &signer_public_key,
             )
             .await?;
+        let withdrawal_decisions_to_retry = self
+            .unacknowledged_withdrawal_decisions(withdrawal_decisions_to_retry)
+            .await?;
+        if withdrawal_decisions_to_retry.len() > retry_budget {
+            tracing::warn!(
+                num_unacknowledged = withdrawal_decisions_to_retry.len(),
+                retry_budget,
+                "too many unacknowledged withdrawal decisions; retrying only some of them"
+            );
+        }
+        decisions.withdrawals.extend(
+            withdrawal_decisions_to_retry
+                .into_iter()
+                .take(retry_budget)
+                .map(SignerWithdrawalDecision::from),
+        );
+        tracing::debug!(
+            num_deposits = decisions.deposits.len(),
+            num_withdrawals = decisions.withdrawals.len(),
+            "retrying unacknowledged decisions"
+        );
+
+        // We do not vote on new withdrawal requests while withdrawals are
+        // paused. They stay pending and we vote on them once withdrawals
+        // are resumed.
+        let withdraw_requests = if self.context.state().is_withdrawals_paused() {
+            tracing::info!("withdrawals are paused; not deciding on new withdrawal requests");
+            Vec::new()
+        } else {
+            db.get_pending_withdrawal_requests(&chain_tip, self.context_window, &signer_public_key)
+                .await?
+        };
+
+        for withdraw_request in withdraw_requests {
+            let request_id = withdraw_request.request_id;
+            match self
+                .handle_pending_withdrawal_request(withdraw_request)
+                .await
+            {
+                Ok(decision) => decisions.withdrawals.push(decision),
+                Err(error) => {
+                    tracing::warn!(%error, %request_id, "error handling new withdrawal request")
+                }
+            }
+        }
+
+        match self.revalidate_withdrawal_decisions(&chain_tip_ref).await {
+            Ok(changed) => decisions.withdrawals.extend(changed),
+            Err(error) => tracing::warn!(%error, "error re-validating withdrawal decisions"),
+        }
+
+        match self.supersede_overridden_decisions().await {
+            Ok(changed) => {
+                decisions.deposits.extend(changed.deposits);
+                decisions.withdrawals.extend(changed.withdrawals);
+            }
+            Err(error) => tracing::warn!(%error, "error applying decision overrides"),
+        }
 
         let _ = self
-            .handle_withdrawal_decisions_to_retry(withdrawal_decisions_to_retry, &chain_tip)
+            .send_decisions(decisions, &chain_tip)
             .await
-            .inspect_err(
-                |error| tracing::warn!(%error, "error handling withdrawal decisions to retry"),
+            .inspect_err(|error| tracing::warn!(%error, "error broadcasting decisions"));
+        let stage = BlockProcessingStage::DecisionsCompleted;
+        block_processing::record_stage(&self.context, &chain_tip, stage).await;
+
+        let _ = self
+            .expire_stale_deposit_requests(&chain_tip_ref)
+            .await
+            .inspect_err(|error| tracing::warn!(%error, "error expiring stale deposit requests"));
+
+        let _ = self
+            .broadcast_database_digest(&chain_tip)
+            .await
+            .inspect_err(|error| tracing::warn!(%error, "error broadcasting database digest"));
+
+        Ok(())
+    }
+
+    /// Check again whether this signer accepts the withdrawal requests
+    /// that it has already decided on and that have not been swept, every
+    /// `withdrawal_revalidation_blocks` bitcoin blocks after they were
+    /// confirmed.
+    ///
+    /// A recipient may have been added to the blocklist, or the policy
+    /// plugin may have changed its mind, after we first decided on a
+    /// request. Decisions that change are stored with a bumped decision
+    /// version and returned, so that the caller can broadcast them to the
+    /// other signers. Decisions that stay the same are left alone.
+    #[tracing::instrument(skip_all)]
+    pub async fn revalidate_withdrawal_decisions(
+        &mut self,
+        chain_tip: &model::BitcoinBlockRef,
+    ) -> Result<Vec<SignerWithdrawalDecision>, Error> {
+        let config = &self.context.config().signer;
+        let Some(revalidation_blocks) = config.withdrawal_revalidation_blocks else {
+            return Ok(Vec::new());
+        };
+        let revalidation_blocks = u64::from(revalidation_blocks.get());
+
+        let db = self.context.get_storage();
+        let signer_public_key = self.signer_public_key();
+        let requests = db
+            .get_decided_withdrawal_requests(
+                &chain_tip.block_hash,
+                self.context_window,
+                &signer_public_key,
+            )
+            .await?;
+
+        let mut changed = Vec::new();
+        for request in requests {
+            let age = (*chain_tip.block_height).saturating_sub(*request.bitcoin_block_height);
+            if age == 0 || age % revalidation_blocks != 0 {
+                continue;
+            }
+
+            // Once the signers have signed a sweep of the request it is
+            // too late for our decision to make a difference.
+            let id = request.qualified_id();
+            if !db.get_withdrawal_sweep_txids(&id).await?.is_empty() {
+                continue;
+            }
+
+            let previous = db
+                .get_withdrawal_signers(request.request_id, &request.block_hash)
+                .await?
+                .into_iter()
+                .find(|decision| decision.signer_pub_key == signer_public_key);
+            let Some(previous) = previous else {
+                continue;
+            };
+
+            let is_accepted = match self.can_accept_withdrawal_request(&request).await {
+                Ok(is_accepted) => is_accepted,
+                Err(error) => {
+                    tracing::warn!(%error, %id, "error re-validating withdrawal request");
+                    continue;
+                }
+            };
+            if is_accepted == previous.is_accepted {
+                continue;
+            }
+
+            let decision = WithdrawalSigner {
+                is_accepted,
+                decision_version: previous.decision_version.saturating_add(1),
+                ..previous
+            };
+            self.context
+                .get_storage_mut()
+                .write_withdrawal_signer_decision(&decision)
+                .await?;
+
+            tracing::info!(
+                %id,
+                is_accepted,
+                decision_version = decision.decision_version,
+                "changed our decision on a withdrawal request after re-validating it"
+            );
+            changed.push(SignerWithdrawalDecision::from(decision));
+        }
+
+        Ok(changed)
+    }
+
+    /// Apply the operator overrides to the decisions that we have already
+    /// made.
+    ///
+    /// An override only affects the requests that we decide on after it
+    /// was set, so decisions that disagree with an override are replaced
+    /// here with a decision that has a bumped decision version. The new
+    /// decisions are returned so that the caller can broadcast them to
+    /// the other signers, who then let them supersede our old ones.
+    #[tracing::instrument(skip_all)]
+    pub async fn supersede_overridden_decisions(&mut self) -> Result<SignerDecisionBatch, Error> {
+        let state = self.context.state();
+        let signer_public_key = self.signer_public_key();
+        let mut changed = SignerDecisionBatch::default();
+
+        for (outpoint, can_accept) in state.deposit_decision_overrides() {
+            let previous = self
+                .context
+                .get_storage()
+                .get_deposit_signers(&outpoint.txid.into(), outpoint.vout)
+                .await?
+                .into_iter()
+                .find(|decision| decision.signer_pub_key == signer_public_key);
+            let Some(previous) = previous.filter(|prev| prev.can_accept != can_accept) else {
+                continue;
+            };
+
+            let decision = DepositSigner {
+                can_accept,
+                decision_version: previous.decision_version.saturating_add(1),
+                ..previous
+            };
+            self.context
+                .get_storage_mut()
+                .write_deposit_signer_decision(&decision)
+                .await?;
+
+            tracing::info!(
+                %outpoint,
+                can_accept,
+                decision_version = decision.decision_version,
+                "superseded our decision on a deposit request with an operator override"
             );
+            let decision = SignerDepositDecision::from(decision);
+            changed.deposits.push(self.with_receipt(decision));
+        }
+
+        for (id, is_accepted) in state.withdrawal_decision_overrides() {
+            let previous = self
+                .context
+                .get_storage()
+                .get_withdrawal_signers(id.request_id, &id.block_hash)
+                .await?
+                .into_iter()
+                .find(|decision| decision.signer_pub_key == signer_public_key);
+            let Some(previous) = previous.filter(|prev| prev.is_accepted != is_accepted) else {
+                continue;
+            };
+
+            let decision = WithdrawalSigner {
+                is_accepted,
+                decision_version: previous.decision_version.saturating_add(1),
+                ..previous
+            };
+            self.context
+                .get_storage_mut()
+                .write_withdrawal_signer_decision(&decision)
+                .await?;
+
+            tracing::info!(
+                %id,
+                is_accepted,
+                decision_version = decision.decision_version,
+                "superseded our decision on a withdrawal request with an operator override"
+            );
+            changed
+                .withdrawals
+                .push(SignerWithdrawalDecision::from(decision));
+        }
+
+        Ok(changed)
+    }
+
+    /// Mark accepted deposit requests that have not been swept within
+    /// `deposit_expiry_blocks` bitcoin blocks of being confirmed as
+    /// expired, so that they are no longer considered for sweeping.
+    ///
+    /// Every signer records the expirations in its own database, but only
+    /// the coordinator for the given chain tip reports them to Emily.
+    /// Deposit requests that were re-activated after expiring are not
+    /// expired again.
+    #[tracing::instrument(skip_all)]
+    pub async fn expire_stale_deposit_requests(
+        &mut self,
+        chain_tip: &model::BitcoinBlockRef,
+    ) -> Result<(), Error> {
+        let db = self.context.get_storage_mut();
+        let expiry_blocks = self.context.config().signer.deposit_expiry_blocks.get();
 
-        let withdraw_requests = db
-            .get_pending_withdrawal_requests(&chain_tip, self.context_window, &signer_public_key)
+        let deposit_requests = db
+            .get_pending_accepted_deposit_requests(chain_tip, self.context_window, 1)
             .await?;
 
-        for withdraw_request in withdraw_requests {
-            let request_id = withdraw_request.request_id;
-            let _ = self
-                .handle_pending_withdrawal_request(withdraw_request, &chain_tip)
-                .await
-                .inspect_err(|error| {
-                    tracing::warn!(
-                        %error,
-                        %request_id,
-                        "error handling new withdrawal request"
-                    )
-                });
+        let mut updates = Vec::new();
+        for request in deposit_requests {
+            let expiration = db
+                .get_deposit_expiration(&request.txid, request.output_index)
+                .await?;
+            if expiration.is_some() {
+                continue;
+            }
+
+            let Some(confirmed_height) = self.confirmation_height(chain_tip, &request.txid).await?
+            else {
+                continue;
+            };
+            if chain_tip.block_height < confirmed_height + u64::from(expiry_blocks) {
+                continue;
+            }
+
+            tracing::info!(
+                outpoint = %request.outpoint(),
+                %confirmed_height,
+                "marking unswept deposit request as expired"
+            );
+            let expiration = model::DepositExpiration {
+                txid: request.txid,
+                output_index: request.output_index,
+                bitcoin_block_hash: chain_tip.block_hash,
+                bitcoin_block_height: chain_tip.block_height,
+                reactivated: false,
+            };
+            db.write_deposit_expiration(&expiration).await?;
+
+            updates.push(DepositUpdate {
+                bitcoin_tx_output_index: request.output_index,
+                bitcoin_txid: request.txid.to_string(),
+                status: DepositStatus::Failed,
+                fulfillment: None,
+                status_message: format!("expired: not swept within {expiry_blocks} bitcoin blocks"),
+                replaced_by_tx: None,
+            });
+        }
+
+        let signer_set = &self.context.config().signer.bootstrap_signing_set;
+        let is_coordinator =
+            given_key_is_coordinator(self.signer_public_key(), &chain_tip.block_hash, signer_set);
+        if updates.is_empty() || !is_coordinator {
+            return Ok(());
         }
 
+        self.context
+            .get_emily_client()
+            .update_deposits(updates)
+            .await?;
+
         Ok(())
     }
 
+    /// Return the height of the block on the canonical bitcoin blockchain
+    /// identified by the given chain tip that confirmed the given
+    /// transaction, if there is one.
+    async fn confirmation_height(
+        &self,
+        chain_tip: &model::BitcoinBlockRef,
+        txid: &model::BitcoinTxId,
+    ) -> Result<Option<BitcoinBlockHeight>, Error> {
+        let db = self.context.get_storage();
+        for block_hash in db.get_bitcoin_blocks_with_transaction(txid).await? {
+            let Some(block) = db.get_bitcoin_block(&block_hash).await? else {
+                continue;
+            };
+            let block_ref = model::BitcoinBlockRef::from(&block);
+            if db
+                .in_canonical_bitcoin_blockchain(chain_tip, &block_ref)
+                .await?
+            {
+                return Ok(Some(block.block_height));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Broadcast the digest of this signer's database so that other
+    /// signers can check whether their databases agree with ours.
+    ///
+    /// The digest covers the blocks ending at the parent of the chain
+    /// tip, since decisions on requests in the chain tip block are
+    /// likely still being gossiped.
+    #[tracing::instrument(skip_all)]
+    async fn broadcast_database_digest(
+        &mut self,
+        chain_tip: &BitcoinBlockHash,
+    ) -> Result<(), Error> {
+        let db = self.context.get_storage();
+        let Some(block) = db.get_bitcoin_block(chain_tip).await? else {
+            return Ok(());
+        };
+        let digest_tip = block.parent_hash;
+
+        let digest =
+            consistency::compute_database_digest(&db, &digest_tip, DIGEST_BLOCK_RANGE).await?;
+
+        self.send_message(digest, &digest_tip).await
+    }
+
+    /// Compare the database digest received from another signer with the
+    /// digest of our own database, reporting any divergences.
+    #[tracing::instrument(skip_all, fields(sender = %msg.signer_public_key))]
+    async fn handle_database_digest(
+        &mut self,
+        digest: &DatabaseDigest,
+        msg: &Signed<SignerMessage>,
+    ) -> Result<(), Error> {
+        if digest.num_blocks != DIGEST_BLOCK_RANGE {
+            tracing::debug!(num_blocks = %digest.num_blocks, "ignoring incomparable database digest");
+            return Ok(());
+        }
+
+        let db = self.context.get_storage();
+        let digest_tip = msg.bitcoin_chain_tip;
+        if db.get_bitcoin_block(&digest_tip).await?.is_none() {
+            tracing::debug!(%digest_tip, "ignoring database digest for unknown block");
+            return Ok(());
+        }
+
+        let ours =
+            consistency::compute_database_digest(&db, &digest_tip, digest.num_blocks).await?;
+        let tables = consistency::diverging_tables(&ours, digest);
+        if tables.is_empty() {
+            return Ok(());
+        }
+
+        tracing::warn!(%digest_tip, ?tables, "database digest does not match digest from signer");
+        consistency::record_divergences(&tables);
+
+        let event = RequestDeciderEvent::DatabaseDigestDiverged {
+            sender: msg.signer_public_key,
+            tables,
+        };
+        self.context.signal(event.into())
+    }
+
     #[tracing::instrument(skip_all)]
     async fn handle_signer_message(&mut self, msg: &Signed<SignerMessage>) -> Result<(), Error> {
         tracing::trace!(payload = %msg.inner.payload, "handling message");
@@ -229,16 +784,179 @@ where
                 self.persist_received_withdraw_decision(decision, msg.signer_public_key)
                     .await?;
             }
+            Payload::DatabaseDigest(digest) => {
+                self.handle_database_digest(digest, msg).await?;
+            }
+            Payload::SignerDecisionBatch(batch) => {
+                let ack = self
+                    .persist_received_decision_batch(batch, msg.signer_public_key)
+                    .await;
+                // Let the sender know which of its decisions we have, so
+                // that it stops sending them again.
+                if !ack.is_empty() && msg.signer_public_key != self.signer_public_key() {
+                    self.send_message(ack, &msg.inner.bitcoin_chain_tip).await?;
+                }
+            }
+            Payload::DecisionAcknowledgement(ack) => {
+                self.persist_received_decision_acknowledgement(ack, msg.signer_public_key)
+                    .await?;
+            }
+            Payload::DepositDecisionRetryRequest(request) => {
+                self.handle_deposit_decision_retry_request(request, msg.signer_public_key)
+                    .await?;
+            }
+            Payload::LimitOverrideVote(vote) => {
+                let tally =
+                    limit_override::record_vote(&self.context, *vote, msg.signer_public_key)?;
+                tracing::info!(
+                    ?vote,
+                    ?tally,
+                    sender = %msg.signer_public_key,
+                    "recorded limit override vote"
+                );
+            }
+            Payload::SignerKeyEndorsement(endorsement) => {
+                let sender = msg.signer_public_key;
+                let chain_tip = msg.inner.bitcoin_chain_tip;
+                key_rotation::record_endorsement(&self.context, endorsement, sender, chain_tip)
+                    .await?;
+                tracing::info!(
+                    %sender,
+                    new_public_key = %endorsement.new_public_key,
+                    "recorded the endorsement of a new signer key"
+                );
+            }
+            Payload::EmergencyRecoveryApproval(approval) => {
+                let sender = msg.signer_public_key;
+                let (sent_at, chain_tip) = self.emergency_recovery_heights(msg).await?;
+                let tally = emergency_recovery::record_approval(
+                    &self.context,
+                    approval,
+                    sender,
+                    sent_at,
+                    chain_tip,
+                )
+                .await?;
+                tracing::warn!(%sender, ?tally, "processed an emergency recovery approval");
+            }
+            Payload::EmergencyRecoveryRevocation(revocation) => {
+                let sender = msg.signer_public_key;
+                let (sent_at, chain_tip) = self.emergency_recovery_heights(msg).await?;
+                let tally = emergency_recovery::record_revocation(
+                    &self.context,
+                    revocation,
+                    sender,
+                    sent_at,
+                    chain_tip,
+                )
+                .await?;
+                tracing::warn!(%sender, ?tally, "processed an emergency recovery revocation");
+            }
             Payload::StacksTransactionSignRequest(_)
             | Payload::BitcoinPreSignRequest(_)
             | Payload::BitcoinPreSignAck(_)
+            | Payload::CoordinatorAttestation(_)
             | Payload::WstsMessage(_)
-            | Payload::StacksTransactionSignature(_) => (),
+            | Payload::StacksTransactionSignature(_)
+            | Payload::MessageRedeliveryRequest(_)
+            | Payload::RedeliveredMessages(_)
+            | Payload::SignRequestRefusal(_) => (),
         };
 
         Ok(())
     }
 
+    /// Return the height of the bitcoin chain tip in the given emergency
+    /// recovery message, along with our own chain tip.
+    ///
+    /// The delay before the sweep counts from our own view of the chain,
+    /// so that approvals cannot be backdated, while the chain tip of the
+    /// sender tells us whether the message is recent.
+    async fn emergency_recovery_heights(
+        &self,
+        msg: &Signed<SignerMessage>,
+    ) -> Result<(BitcoinBlockHeight, model::BitcoinBlockRef), Error> {
+        let chain_tip = self
+            .context
+            .state()
+            .bitcoin_chain_tip()
+            .ok_or(Error::NoChainTip)?;
+        let sent_at = self
+            .context
+            .get_storage()
+            .get_bitcoin_block(&msg.inner.bitcoin_chain_tip)
+            .await?
+            .ok_or(Error::MissingBitcoinBlock(msg.inner.bitcoin_chain_tip))?
+            .block_height;
+
+        Ok((sent_at, chain_tip))
+    }
+
+    /// Move the rotation of the key of this signer along, broadcasting the
+    /// endorsement of the new key while we still run with the old one.
+    async fn advance_key_rotation(&mut self) -> Result<(), Error> {
+        let Some(endorsement) = key_rotation::advance(&self.context).await? else {
+            return Ok(());
+        };
+
+        let chain_tip = self
+            .context
+            .state()
+            .bitcoin_chain_tip()
+            .ok_or(Error::NoChainTip)?
+            .block_hash;
+
+        self.send_message(endorsement, &chain_tip).await
+    }
+
+    /// Re-send our decisions on the deposit requests in the given retry
+    /// request, if we are one of the signers that the request is for and
+    /// the request came from the coordinator for our chain tip.
+    ///
+    /// Deposit requests that we have not made a decision on yet are
+    /// skipped; we will broadcast those decisions once we make them.
+    #[tracing::instrument(skip_all, fields(sender = %sender))]
+    async fn handle_deposit_decision_retry_request(
+        &mut self,
+        request: &DepositDecisionRetryRequest,
+        sender: PublicKey,
+    ) -> Result<(), Error> {
+        let signer_public_key = self.signer_public_key();
+        if !request.signers.contains(&signer_public_key) {
+            return Ok(());
+        }
+
+        let chain_tip = self
+            .context
+            .state()
+            .bitcoin_chain_tip()
+            .ok_or(Error::NoChainTip)?
+            .block_hash;
+
+        // Only the coordinator for our chain tip asks for missing
+        // decisions, so anyone else is ignored.
+        let signer_set = &self.context.config().signer.bootstrap_signing_set;
+        if !given_key_is_coordinator(sender, &chain_tip, signer_set) {
+            tracing::warn!(%sender, "ignoring decision retry request from a non-coordinator");
+            return Ok(());
+        }
+
+        let decisions = self
+            .context
+            .get_storage()
+            .get_deposit_signer_decisions_for_outpoints(&request.deposits, &signer_public_key)
+            .await?;
+
+        tracing::debug!(
+            num_requested = request.deposits.len(),
+            num_decisions = decisions.len(),
+            "re-sending requested deposit decisions"
+        );
+
+        self.handle_deposit_decisions_to_retry(decisions, &chain_tip)
+            .await
+    }
+
     /// Check whether this signer accepts the deposit request. This
     /// involves:
     ///
@@ -249,13 +967,17 @@ where
     ///    public key locking the funds.
     ///
     /// If the block list client is not configured then the first check
-    /// always passes.
+    /// always passes. Deposits below the configured `min_deposit_amount`
+    /// are rejected before the blocklist client is consulted, and the
+    /// reason for the rejection is stored in the database.
+    ///
+    /// The decision is stored in the database and returned so that the
+    /// caller can broadcast it to the other signers.
     #[tracing::instrument(skip_all)]
     pub async fn handle_pending_deposit_request(
         &mut self,
         request: model::DepositRequest,
-        chain_tip: &BitcoinBlockHash,
-    ) -> Result<(), Error> {
+    ) -> Result<SignerDepositDecision, Error> {
         let db = self.context.get_storage_mut();
 
         let signer_public_key = self.signer_public_key();
@@ -272,12 +994,14 @@ where
 
         let can_accept = self.can_accept_deposit_request(&request).await?;
 
-        let msg = SignerDepositDecision {
+        let msg = self.with_receipt(SignerDepositDecision {
             txid: request.txid.into(),
             output_index: request.output_index,
             can_accept,
             can_sign,
-        };
+            receipt: None,
+            decision_version: 0,
+        });
 
         let signer_decision = DepositSigner {
             txid: request.txid,
@@ -285,34 +1009,138 @@ where
             signer_pub_key: signer_public_key,
             can_accept,
             can_sign,
+            decision_version: 0,
         };
 
         db.write_deposit_signer_decision(&signer_decision).await?;
 
-        self.send_message(msg, chain_tip).await?;
+        if let Some(signature) = msg.receipt {
+            let receipt = model::DepositDecisionReceipt {
+                txid: request.txid,
+                output_index: request.output_index,
+                signer_pub_key: signer_public_key,
+                signature: signature.serialize_compact().to_vec(),
+            };
+            db.write_deposit_decision_receipt(&receipt).await?;
+        }
 
         self.context
             .signal(RequestDeciderEvent::PendingDepositRequestRegistered.into())?;
 
-        Ok(())
+        Ok(msg)
     }
 
-    /// Send the given withdrawal decisions to the other signers for redundancy.
-    #[tracing::instrument(skip_all)]
-    pub async fn handle_withdrawal_decisions_to_retry(
-        &mut self,
-        decisions: Vec<model::WithdrawalSigner>,
-        chain_tip: &BitcoinBlockHash,
-    ) -> Result<(), Error> {
-        for decision in decisions.into_iter().map(SignerWithdrawalDecision::from) {
-            let _ = self
-                .send_message(decision, chain_tip)
-                .await
-                .inspect_err(|error| {
-                    tracing::warn!(%error, "error sending withdrawal decision to retry, skipping");
-                });
+    /// The public keys of the other signers in the current signer set.
+    fn peer_public_keys(&self) -> BTreeSet<PublicKey> {
+        let signer_public_key = self.signer_public_key();
+        self.context
+            .state()
+            .current_signer_set()
+            .get_signers()
+            .into_iter()
+            .map(|signer| *signer.public_key())
+            .filter(|public_key| public_key != &signer_public_key)
+            .collect()
+    }
+
+    /// Keep the given deposit decisions of ours that at least one of the
+    /// other signers has not acknowledged yet.
+    ///
+    /// A signer acknowledges a decision with a [`DecisionAcknowledgement`]
+    /// once it has stored it. A decision stays unacknowledged by a signer
+    /// until that signer has acknowledged the same or a later version of
+    /// it, so updated decisions are sent again.
+    async fn unacknowledged_deposit_decisions(
+        &self,
+        decisions: Vec<DepositSigner>,
+    ) -> Result<Vec<DepositSigner>, Error> {
+        if decisions.is_empty() {
+            return Ok(decisions);
         }
-        Ok(())
+        let peers = self.peer_public_keys();
+        let outpoints: Vec<bitcoin::OutPoint> = decisions
+            .iter()
+            .map(|decision| bitcoin::OutPoint::new(decision.txid.into(), decision.output_index))
+            .collect();
+
+        let acknowledged: HashMap<_, u32> = self
+            .context
+            .get_storage()
+            .get_deposit_decision_acknowledgements(&outpoints)
+            .await?
+            .into_iter()
+            .map(|ack| {
+                (
+                    (ack.txid, ack.output_index, ack.signer_pub_key),
+                    ack.decision_version,
+                )
+            })
+            .collect();
+
+        let unacknowledged = decisions
+            .into_iter()
+            .filter(|decision| {
+                peers.iter().any(|peer| {
+                    acknowledged
+                        .get(&(decision.txid, decision.output_index, *peer))
+                        .is_none_or(|version| *version < decision.decision_version)
+                })
+            })
+            .collect();
+        Ok(unacknowledged)
+    }
+
+    /// Keep the given withdrawal decisions of ours that at least one of
+    /// the other signers has not acknowledged yet. This follows the same
+    /// rules as [`Self::unacknowledged_deposit_decisions`].
+    async fn unacknowledged_withdrawal_decisions(
+        &self,
+        decisions: Vec<WithdrawalSigner>,
+    ) -> Result<Vec<WithdrawalSigner>, Error> {
+        if decisions.is_empty() {
+            return Ok(decisions);
+        }
+        let peers = self.peer_public_keys();
+        let ids: Vec<model::QualifiedRequestId> = decisions
+            .iter()
+            .map(WithdrawalSigner::qualified_id)
+            .collect();
+
+        let acknowledged: HashMap<_, u32> = self
+            .context
+            .get_storage()
+            .get_withdrawal_decision_acknowledgements(&ids)
+            .await?
+            .into_iter()
+            .map(|ack| {
+                let key = (ack.request_id, ack.block_hash, ack.signer_pub_key);
+                (key, ack.decision_version)
+            })
+            .collect();
+
+        let unacknowledged = decisions
+            .into_iter()
+            .filter(|decision| {
+                peers.iter().any(|peer| {
+                    acknowledged
+                        .get(&(decision.request_id, decision.block_hash, *peer))
+                        .is_none_or(|version| *version < decision.decision_version)
+                })
+            })
+            .collect();
+        Ok(unacknowledged)
+    }
+
+    /// Attach a signed receipt to the given decision if it accepts the
+    /// deposit request and we can sign for it. See [`crate::quorum`] for
+    /// how the receipts are used.
+    fn with_receipt(&self, mut decision: SignerDepositDecision) -> SignerDepositDecision {
+        if decision.can_accept && decision.can_sign {
+            let outpoint = bitcoin::OutPoint::new(decision.txid, decision.output_index);
+            let signature = quorum::sign_deposit_receipt(&self.signer_private_key, &outpoint);
+            decision.receipt = Some(signature);
+        }
+        decision
     }
 
     /// Send the given deposit decisions to the other signers for redundancy.
@@ -322,23 +1150,40 @@ where
         decisions: Vec<model::DepositSigner>,
         chain_tip: &BitcoinBlockHash,
     ) -> Result<(), Error> {
-        for decision in decisions.into_iter().map(SignerDepositDecision::from) {
-            let _ = self
-                .send_message(decision, chain_tip)
-                .await
-                .inspect_err(|error| {
-                    tracing::warn!(%error, "error sending deposit decision to retry, skipping");
-                });
+        let batch = SignerDecisionBatch {
+            deposits: decisions
+                .into_iter()
+                .map(SignerDepositDecision::from)
+                .map(|decision| self.with_receipt(decision))
+                .collect(),
+            withdrawals: Vec::new(),
+        };
+        self.send_decisions(batch, chain_tip).await
+    }
+
+    /// Broadcast the given decisions to the other signers, split into
+    /// batches of at most [`MAX_DECISIONS_PER_BATCH`] decisions. Nothing
+    /// is sent if there are no decisions.
+    #[tracing::instrument(skip_all, fields(num_decisions = decisions.len()))]
+    async fn send_decisions(
+        &mut self,
+        decisions: SignerDecisionBatch,
+        chain_tip: &BitcoinBlockHash,
+    ) -> Result<(), Error> {
+        for batch in decisions.split(MAX_DECISIONS_PER_BATCH) {
+            self.send_message(batch, chain_tip).await?;
         }
         Ok(())
     }
 
+    /// Decide whether this signer accepts the withdrawal request. The
+    /// decision is stored in the database and returned so that the caller
+    /// can broadcast it to the other signers.
     #[tracing::instrument(skip_all)]
     async fn handle_pending_withdrawal_request(
         &mut self,
         withdrawal_request: model::WithdrawalRequest,
-        chain_tip: &BitcoinBlockHash,
-    ) -> Result<(), Error> {
+    ) -> Result<SignerWithdrawalDecision, Error> {
         // TODO: Do we want to do this on the sender address or the
         // recipient address?
         let is_accepted = self
@@ -350,6 +1195,7 @@ where
             block_hash: withdrawal_request.block_hash,
             accepted: is_accepted,
             txid: withdrawal_request.txid,
+            decision_version: 0,
         };
 
         let signer_decision = WithdrawalSigner {
@@ -358,6 +1204,7 @@ where
             signer_pub_key: self.signer_public_key(),
             is_accepted,
             txid: withdrawal_request.txid,
+            decision_version: 0,
         };
 
         self.context
@@ -365,22 +1212,28 @@ where
             .write_withdrawal_signer_decision(&signer_decision)
             .await?;
 
-        self.send_message(msg, chain_tip).await?;
-
         self.context
             .signal(RequestDeciderEvent::PendingWithdrawalRequestRegistered.into())?;
 
-        Ok(())
+        Ok(msg)
     }
 
     async fn can_accept_withdrawal_request(
         &self,
         req: &model::WithdrawalRequest,
     ) -> Result<bool, Error> {
-        // If we have not configured a blocklist checker, then we can
-        // return early.
+        // An operator override takes precedence over the blocklist
+        // client and the policy plugin.
+        let state = self.context.state();
+        if let Some(accept) = state.withdrawal_decision_override(&req.qualified_id()) {
+            tracing::info!(accept, "using operator override for withdrawal decision");
+            return Ok(accept);
+        }
+
+        // If we have not configured a blocklist checker, then only the
+        // policy plugin is left to consult.
         let Some(client) = self.blocklist_checker.as_ref() else {
-            return Ok(true);
+            return self.policy_plugin_accepts(PolicyRequest::from(req)).await;
         };
 
         let network = bitcoin::Network::from(self.context.config().signer.network);
@@ -398,14 +1251,54 @@ where
             .await
             .inspect_err(|error| tracing::error!(%error, "blocklist client issue"))?;
 
-        Ok(can_accept)
+        if !can_accept {
+            return Ok(false);
+        }
+        self.policy_plugin_accepts(PolicyRequest::from(req)).await
     }
 
     async fn can_accept_deposit_request(&self, req: &model::DepositRequest) -> Result<bool, Error> {
-        // If we have not configured a blocklist checker, then we can
-        // return early.
+        // An operator override takes precedence over the local policy, the
+        // blocklist client and the policy plugin.
+        let state = self.context.state();
+        if let Some(can_accept) = state.deposit_decision_override(&req.outpoint()) {
+            tracing::info!(can_accept, "using operator override for deposit decision");
+            return Ok(can_accept);
+        }
+
+        // The block observer validated these scripts before writing the
+        // request, but we never sign off on a deposit whose scripts do not
+        // parse into the values that we have stored.
+        if state.stored_deposit_scripts(req).is_none() {
+            tracing::info!("rejecting deposit request with invalid deposit scripts");
+            return Ok(false);
+        }
+
+        // This check is on top of the limits in `SbtcLimits`, which are
+        // enforced for everyone when validating the sweep transaction.
+        let min_deposit_amount = self.context.config().signer.min_deposit_amount;
+        if let Some(min_amount) = min_deposit_amount.filter(|min| req.amount < *min) {
+            tracing::info!(
+                amount = %req.amount,
+                %min_amount,
+                "rejecting deposit request below the minimum amount"
+            );
+            let rejection = model::DepositRejection {
+                txid: req.txid,
+                output_index: req.output_index,
+                reason: model::DepositRejectionReason::BelowMinimumAmount,
+            };
+            self.context
+                .get_storage_mut()
+                .write_deposit_rejection(&rejection)
+                .await?;
+            return Ok(false);
+        }
+
+        // If we have not configured a blocklist checker, then only the
+        // policy plugin is left to consult.
         let Some(client) = self.blocklist_checker.as_ref() else {
-            return Ok(true);
+            return self.policy_plugin_accepts(PolicyRequest::from(req)).await;
         };
 
         // We turn all the input scriptPubKeys into addresses and check
@@ -428,12 +1321,42 @@ where
             .collect::<Result<Vec<_>, _>>()?;
 
         // If all of the inputs addresses are fine then we pass the deposit
-        // request.
+        // request on to the policy plugin.
         let can_accept = responses.into_iter().all(|res| res);
-        Ok(can_accept)
+        if !can_accept {
+            return Ok(false);
+        }
+        self.policy_plugin_accepts(PolicyRequest::from(req)).await
+    }
+
+    /// Check whether the policy plugin, if there is one, accepts the given
+    /// request. Only a rejection from the plugin stops this signer from
+    /// accepting the request; see [`crate::policy_plugin`].
+    async fn policy_plugin_accepts(&self, request: PolicyRequest) -> Result<bool, Error> {
+        let Some(plugin) = self.policy_plugin.as_ref() else {
+            return Ok(true);
+        };
+
+        let kind = request.kind();
+        let timeout = self.context.config().signer.policy_plugin_timeout;
+        let decision = policy_plugin::evaluate(Arc::clone(plugin), request, timeout)
+            .await
+            .inspect_err(|error| tracing::error!(%error, %kind, "policy plugin issue"))?;
+
+        let verdict: &'static str = decision.verdict.into();
+        tracing::info!(%kind, %verdict, reason = %decision.reason, "policy plugin decided");
+        metrics::counter!(
+            Metrics::PolicyPluginVerdictsTotal,
+            "kind" => kind,
+            "verdict" => verdict
+        )
+        .increment(1);
+
+        Ok(decision.verdict != PolicyVerdict::Reject)
     }
 
-    /// Save the given decision into the database
+    /// Save the given decision into the database, returning whether it was
+    /// stored.
     ///
     /// If we do not have a record of the associated deposit request in our
     /// database then we fetch it from Emily and then attempt to persist
@@ -443,7 +1366,7 @@ where
         &mut self,
         decision: &SignerDepositDecision,
         signer_pub_key: PublicKey,
-    ) -> Result<(), Error> {
+    ) -> Result<bool, Error> {
         let txid = decision.txid.into();
         let output_index = decision.output_index;
         let signer_decision = DepositSigner {
@@ -452,6 +1375,7 @@ where
             signer_pub_key,
             can_accept: decision.can_accept,
             can_sign: decision.can_sign,
+            decision_version: decision.decision_version,
         };
 
         let db = self.context.get_storage_mut();
@@ -485,13 +1409,133 @@ where
                 sender = %signer_pub_key,
                 "we still do not have a record of the deposit request"
             );
-            return Ok(());
+            return Ok(false);
         }
         db.write_deposit_signer_decision(&signer_decision).await?;
 
+        // Receipts are only meaningful for decisions that accept the
+        // deposit request, and we only keep the ones with a valid
+        // signature from the sender.
+        if let Some(signature) = decision
+            .receipt
+            .filter(|_| decision.can_accept && decision.can_sign)
+        {
+            let outpoint = bitcoin::OutPoint::new(decision.txid, output_index);
+            let receipt = DecisionReceipt {
+                signer_public_key: signer_pub_key,
+                signature,
+            };
+            match quorum::verify_deposit_receipt(&outpoint, &receipt) {
+                Ok(()) => {
+                    let receipt = model::DepositDecisionReceipt {
+                        txid,
+                        output_index,
+                        signer_pub_key,
+                        signature: signature.serialize_compact().to_vec(),
+                    };
+                    db.write_deposit_decision_receipt(&receipt).await?;
+                }
+                Err(error) => {
+                    tracing::warn!(%error, %txid, %output_index, "invalid deposit decision receipt");
+                }
+            }
+        }
+
         self.context
             .signal(RequestDeciderEvent::ReceivedDepositDecision.into())?;
 
+        Ok(true)
+    }
+
+    /// Save each of the decisions in the given batch into the database,
+    /// returning the acknowledgement of the decisions that were stored.
+    ///
+    /// A decision that fails to be persisted does not prevent the other
+    /// decisions in the batch from being persisted.
+    #[tracing::instrument(skip_all, fields(sender = %signer_pub_key))]
+    async fn persist_received_decision_batch(
+        &mut self,
+        batch: &SignerDecisionBatch,
+        signer_pub_key: PublicKey,
+    ) -> DecisionAcknowledgement {
+        let mut ack = DecisionAcknowledgement {
+            decider: signer_pub_key,
+            deposits: Vec::new(),
+            withdrawals: Vec::new(),
+        };
+        for decision in &batch.deposits {
+            match self
+                .persist_received_deposit_decision(decision, signer_pub_key)
+                .await
+            {
+                Ok(true) => ack.deposits.push(DepositDecisionAck {
+                    outpoint: bitcoin::OutPoint::new(decision.txid, decision.output_index),
+                    decision_version: decision.decision_version,
+                }),
+                Ok(false) => (),
+                Err(error) => tracing::warn!(
+                    %error,
+                    txid = %decision.txid,
+                    output_index = decision.output_index,
+                    "error persisting deposit decision from batch"
+                ),
+            }
+        }
+        for decision in &batch.withdrawals {
+            match self
+                .persist_received_withdraw_decision(decision, signer_pub_key)
+                .await
+            {
+                Ok(()) => ack.withdrawals.push(WithdrawalDecisionAck {
+                    id: model::QualifiedRequestId {
+                        request_id: decision.request_id,
+                        txid: decision.txid,
+                        block_hash: decision.block_hash,
+                    },
+                    decision_version: decision.decision_version,
+                }),
+                Err(error) => tracing::warn!(
+                    %error,
+                    request_id = decision.request_id,
+                    "error persisting withdrawal decision from batch"
+                ),
+            }
+        }
+        ack
+    }
+
+    /// Save the acknowledgements, from the given signer, of our decisions.
+    /// Acknowledgements of the decisions of other signers are ignored.
+    #[tracing::instrument(skip_all, fields(sender = %signer_pub_key))]
+    async fn persist_received_decision_acknowledgement(
+        &mut self,
+        ack: &DecisionAcknowledgement,
+        signer_pub_key: PublicKey,
+    ) -> Result<(), Error> {
+        if ack.decider != self.signer_public_key() {
+            return Ok(());
+        }
+
+        let db = self.context.get_storage_mut();
+        for deposit in &ack.deposits {
+            let ack = model::DepositDecisionAcknowledgement {
+                txid: deposit.outpoint.txid.into(),
+                output_index: deposit.outpoint.vout,
+                signer_pub_key,
+                decision_version: deposit.decision_version,
+            };
+            db.write_deposit_decision_acknowledgement(&ack).await?;
+        }
+        for withdrawal in &ack.withdrawals {
+            let ack = model::WithdrawalDecisionAcknowledgement {
+                request_id: withdrawal.id.request_id,
+                txid: withdrawal.id.txid,
+                block_hash: withdrawal.id.block_hash,
+                signer_pub_key,
+                decision_version: withdrawal.decision_version,
+            };
+            db.write_withdrawal_decision_acknowledgement(&ack).await?;
+        }
         Ok(())
     }
 
@@ -507,6 +1551,7 @@ where
             signer_pub_key,
             is_accepted: decision.accepted,
             txid: decision.txid,
+            decision_version: decision.decision_version,
         };
 
         // TODO: we need to check to see if we have the withdrawal request
@@ -546,13 +1591,19 @@ where
 
 #[cfg(test)]
 mod tests {
+    use fake::Fake as _;
+
     use crate::bitcoin::MockBitcoinInteract;
     use crate::emily_client::MockEmilyInteract;
+    use crate::network::in_memory2::SignerNetworkInstance;
+    use crate::network::in_memory2::WanNetwork;
     use crate::stacks::api::MockStacksInteract;
     use crate::storage::memory::SharedStore;
     use crate::testing;
     use crate::testing::context::*;
 
+    use super::*;
+
     #[allow(clippy::type_complexity)]
     fn test_environment() -> testing::request_decider::TestEnvironment<
         TestContext<
@@ -587,6 +1638,21 @@ mod tests {
         }
     }
 
+    /// A request decider for the given context, connected to the given
+    /// network, with a blocklist checker that accepts every request.
+    fn test_event_loop<C: Context>(
+        context: &C,
+        network: &WanNetwork,
+    ) -> RequestDeciderEventLoop<C, SignerNetworkInstance, ()> {
+        RequestDeciderEventLoop::builder(context.clone(), network.connect(context).spawn())
+            .with_blocklist_checker(Some(()))
+            .with_private_key(context.config().signer.private_key)
+            .with_context_window(6)
+            .with_decisions_retry_windows(1, 1)
+            .build()
+            .unwrap()
+    }
+
     #[tokio::test]
     async fn should_store_decisions_for_pending_deposit_requests() {
         test_environment()
@@ -608,4 +1674,709 @@ mod tests {
             .assert_should_store_decisions_received_from_other_signers()
             .await;
     }
+
+    #[tokio::test]
+    async fn deposits_below_the_minimum_amount_are_rejected_with_a_reason() {
+        let context = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .modify_settings(|settings| {
+                settings.signer.min_deposit_amount = Some(10_000);
+            })
+            .build();
+
+        let network = WanNetwork::default();
+        let mut event_loop = test_event_loop(&context, &network);
+
+        let storage = context.get_storage_mut();
+        let mut small: model::DepositRequest = fake::Faker.fake();
+        small.amount = 9_999;
+        let mut large: model::DepositRequest = fake::Faker.fake();
+        large.amount = 10_000;
+        storage.write_deposit_request(&small).await.unwrap();
+        storage.write_deposit_request(&large).await.unwrap();
+
+        let decision = event_loop
+            .handle_pending_deposit_request(small.clone())
+            .await
+            .unwrap();
+        assert!(!decision.can_accept);
+        let rejection = storage
+            .get_deposit_rejection(&small.txid, small.output_index)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            rejection.reason,
+            model::DepositRejectionReason::BelowMinimumAmount
+        );
+
+        let decision = event_loop
+            .handle_pending_deposit_request(large.clone())
+            .await
+            .unwrap();
+        assert!(decision.can_accept);
+        let rejection = storage
+            .get_deposit_rejection(&large.txid, large.output_index)
+            .await
+            .unwrap();
+        assert!(rejection.is_none());
+    }
+
+    #[tokio::test]
+    async fn deposits_with_invalid_scripts_are_rejected() {
+        let context = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .build();
+
+        let network = WanNetwork::default();
+        let mut event_loop = test_event_loop(&context, &network);
+
+        let storage = context.get_storage_mut();
+        let mut request: model::DepositRequest = fake::Faker.fake();
+        request.amount = 100_000;
+        storage.write_deposit_request(&request).await.unwrap();
+
+        let decision = event_loop
+            .handle_pending_deposit_request(request.clone())
+            .await
+            .unwrap();
+        assert!(decision.can_accept);
+
+        // The stored max fee no longer matches the one in the deposit
+        // script, so the request is rejected.
+        let mut mismatched: model::DepositRequest = fake::Faker.fake();
+        mismatched.amount = 100_000;
+        mismatched.max_fee += 1;
+        storage.write_deposit_request(&mismatched).await.unwrap();
+
+        let decision = event_loop
+            .handle_pending_deposit_request(mismatched)
+            .await
+            .unwrap();
+        assert!(!decision.can_accept);
+        assert_eq!(context.state().num_cached_deposit_scripts(), 2);
+    }
+
+    #[tokio::test]
+    async fn decision_retry_requests_are_only_answered_for_the_coordinator() {
+        let signer_private_key = PrivateKey::new(&mut rand::rngs::OsRng);
+        let signer_public_key = PublicKey::from_private_key(&signer_private_key);
+        let signer_set: BTreeSet<PublicKey> =
+            [signer_public_key, fake::Faker.fake(), fake::Faker.fake()]
+                .into_iter()
+                .collect();
+
+        let context = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .modify_settings(|settings| {
+                settings.signer.private_key = signer_private_key;
+                settings.signer.bootstrap_signing_set = signer_set.clone();
+            })
+            .build();
+
+        let network = WanNetwork::default();
+        let mut event_loop = test_event_loop(&context, &network);
+        let other_context = TestContext::default_mocked();
+        let mut network_rx = network.connect(&other_context).spawn();
+
+        let chain_tip: model::BitcoinBlockRef = fake::Faker.fake();
+        context.state().set_bitcoin_chain_tip(chain_tip);
+        let coordinator = crate::transaction_coordinator::coordinator_public_key(
+            &chain_tip.block_hash,
+            &signer_set,
+        )
+        .unwrap();
+        let non_coordinator = signer_set
+            .iter()
+            .copied()
+            .find(|key| *key != coordinator)
+            .unwrap();
+
+        let storage = context.get_storage_mut();
+        let request: model::DepositRequest = fake::Faker.fake();
+        storage.write_deposit_request(&request).await.unwrap();
+        let mut decision: DepositSigner = fake::Faker.fake();
+        decision.txid = request.txid;
+        decision.output_index = request.output_index;
+        decision.signer_pub_key = signer_public_key;
+        storage
+            .write_deposit_signer_decision(&decision)
+            .await
+            .unwrap();
+
+        let retry_request = DepositDecisionRetryRequest {
+            deposits: vec![request.outpoint()],
+            signers: vec![signer_public_key],
+        };
+
+        event_loop
+            .handle_deposit_decision_retry_request(&retry_request, non_coordinator)
+            .await
+            .unwrap();
+        let received = tokio::time::timeout(Duration::from_millis(100), network_rx.receive()).await;
+        assert!(received.is_err());
+
+        event_loop
+            .handle_deposit_decision_retry_request(&retry_request, coordinator)
+            .await
+            .unwrap();
+        let msg = tokio::time::timeout(Duration::from_secs(1), network_rx.receive())
+            .await
+            .expect("the decisions were not re-sent")
+            .unwrap();
+        let Payload::SignerDecisionBatch(batch) = &msg.payload else {
+            panic!("expected a decision batch, got {}", msg.payload);
+        };
+        assert_eq!(batch.deposits.len(), 1);
+        assert_eq!(batch.deposits[0].txid, *request.txid);
+        assert_eq!(batch.deposits[0].output_index, request.output_index);
+    }
+
+    #[derive(Debug)]
+    struct RejectAll;
+
+    impl PolicyPlugin for RejectAll {
+        fn evaluate(
+            &self,
+            _: &PolicyRequest,
+        ) -> Result<crate::policy_plugin::PolicyDecision, Error> {
+            Ok(crate::policy_plugin::PolicyDecision {
+                verdict: PolicyVerdict::Reject,
+                reason: "rejected by test policy".to_string(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn policy_plugin_rejections_are_respected() {
+        let context = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .build();
+
+        let network = WanNetwork::default();
+        let mut event_loop = test_event_loop(&context, &network);
+
+        let storage = context.get_storage_mut();
+        let mut request: model::DepositRequest = fake::Faker.fake();
+        request.amount = 100_000;
+        storage.write_deposit_request(&request).await.unwrap();
+
+        let decision = event_loop
+            .handle_pending_deposit_request(request.clone())
+            .await
+            .unwrap();
+        assert!(decision.can_accept);
+
+        event_loop.policy_plugin = Some(Arc::new(RejectAll));
+        let decision = event_loop
+            .handle_pending_deposit_request(request.clone())
+            .await
+            .unwrap();
+        assert!(!decision.can_accept);
+    }
+
+    #[tokio::test]
+    async fn decisions_acknowledged_by_all_peers_are_not_retried() {
+        let context = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .build();
+
+        let network = WanNetwork::default();
+        let mut event_loop = test_event_loop(&context, &network);
+
+        let signer_public_key = event_loop.signer_public_key();
+        let peer_public_key: PublicKey = fake::Faker.fake();
+        context
+            .state()
+            .update_current_signer_set([signer_public_key, peer_public_key].into());
+
+        let storage = context.get_storage_mut();
+        let request: model::DepositRequest = fake::Faker.fake();
+        storage.write_deposit_request(&request).await.unwrap();
+
+        let mut decision: DepositSigner = fake::Faker.fake();
+        decision.txid = request.txid;
+        decision.output_index = request.output_index;
+        decision.signer_pub_key = signer_public_key;
+        decision.decision_version = 1;
+        storage
+            .write_deposit_signer_decision(&decision)
+            .await
+            .unwrap();
+
+        let retried = event_loop
+            .unacknowledged_deposit_decisions(vec![decision.clone()])
+            .await
+            .unwrap();
+        assert_eq!(retried, vec![decision.clone()]);
+
+        // The peer's own decision on the request is not an
+        // acknowledgement of ours.
+        let mut peer_decision = decision.clone();
+        peer_decision.signer_pub_key = peer_public_key;
+        storage
+            .write_deposit_signer_decision(&peer_decision)
+            .await
+            .unwrap();
+
+        let retried = event_loop
+            .unacknowledged_deposit_decisions(vec![decision.clone()])
+            .await
+            .unwrap();
+        assert_eq!(retried, vec![decision.clone()]);
+
+        // An acknowledgement of an older version of our decision does not
+        // cover the current one, and acknowledgements of the decisions of
+        // other signers are ignored.
+        let mut ack = DecisionAcknowledgement {
+            decider: signer_public_key,
+            deposits: vec![DepositDecisionAck {
+                outpoint: request.outpoint(),
+                decision_version: 0,
+            }],
+            withdrawals: Vec::new(),
+        };
+        event_loop
+            .persist_received_decision_acknowledgement(&ack, peer_public_key)
+            .await
+            .unwrap();
+        let mut other_ack = ack.clone();
+        other_ack.decider = fake::Faker.fake();
+        other_ack.deposits[0].decision_version = 1;
+        event_loop
+            .persist_received_decision_acknowledgement(&other_ack, peer_public_key)
+            .await
+            .unwrap();
+
+        let retried = event_loop
+            .unacknowledged_deposit_decisions(vec![decision.clone()])
+            .await
+            .unwrap();
+        assert_eq!(retried, vec![decision.clone()]);
+
+        ack.deposits[0].decision_version = 1;
+        event_loop
+            .persist_received_decision_acknowledgement(&ack, peer_public_key)
+            .await
+            .unwrap();
+
+        let retried = event_loop
+            .unacknowledged_deposit_decisions(vec![decision])
+            .await
+            .unwrap();
+        assert!(retried.is_empty());
+    }
+
+    #[tokio::test]
+    async fn stored_decisions_from_a_batch_are_acknowledged() {
+        let context = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .build();
+
+        let network = WanNetwork::default();
+        let mut event_loop = test_event_loop(&context, &network);
+        let sender: PublicKey = fake::Faker.fake();
+
+        let storage = context.get_storage_mut();
+        let request: model::DepositRequest = fake::Faker.fake();
+        storage.write_deposit_request(&request).await.unwrap();
+
+        let known = SignerDepositDecision {
+            txid: *request.txid,
+            output_index: request.output_index,
+            can_accept: true,
+            can_sign: true,
+            receipt: None,
+            decision_version: 2,
+        };
+        let withdrawal: SignerWithdrawalDecision = fake::Faker.fake();
+        let batch = SignerDecisionBatch {
+            deposits: vec![known],
+            withdrawals: vec![withdrawal.clone()],
+        };
+
+        let ack = event_loop
+            .persist_received_decision_batch(&batch, sender)
+            .await;
+        assert_eq!(ack.decider, sender);
+        assert_eq!(
+            ack.deposits,
+            vec![DepositDecisionAck {
+                outpoint: request.outpoint(),
+                decision_version: 2,
+            }]
+        );
+        assert_eq!(ack.withdrawals.len(), 1);
+        assert_eq!(ack.withdrawals[0].id.request_id, withdrawal.request_id);
+        assert_eq!(
+            ack.withdrawals[0].decision_version,
+            withdrawal.decision_version
+        );
+    }
+
+    #[tokio::test]
+    async fn unswept_deposits_expire_and_can_be_reactivated() {
+        let context = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .modify_settings(|settings| {
+                settings.signer.deposit_expiry_blocks = std::num::NonZeroU16::new(3).unwrap();
+                let public_key = settings.signer.public_key();
+                settings.signer.bootstrap_signing_set = [public_key].into_iter().collect();
+            })
+            .build();
+
+        // We are the only signer, so we are the coordinator and report
+        // the expiration to Emily exactly once.
+        context
+            .with_emily_client(|client| {
+                client
+                    .expect_update_deposits()
+                    .withf(|updates| {
+                        updates.len() == 1 && updates[0].status == DepositStatus::Failed
+                    })
+                    .times(1)
+                    .returning(|_| {
+                        Box::pin(async {
+                            Ok(emily_client::models::UpdateDepositsResponse { deposits: vec![] })
+                        })
+                    });
+            })
+            .await;
+
+        let network = WanNetwork::default();
+        let mut event_loop = test_event_loop(&context, &network);
+
+        let storage = context.get_storage_mut();
+        let mut blocks: Vec<model::BitcoinBlock> = Vec::new();
+        for height in 0..4u64 {
+            let block = model::BitcoinBlock {
+                block_hash: fake::Faker.fake(),
+                block_height: height.into(),
+                parent_hash: blocks
+                    .last()
+                    .map(|block| block.block_hash)
+                    .unwrap_or_else(|| fake::Faker.fake()),
+            };
+            storage.write_bitcoin_block(&block).await.unwrap();
+            blocks.push(block);
+        }
+
+        let mut request: model::DepositRequest = fake::Faker.fake();
+        request.lock_time = 100;
+        storage.write_deposit_request(&request).await.unwrap();
+        let tx_ref = model::BitcoinTxRef {
+            txid: request.txid,
+            block_hash: blocks[0].block_hash,
+        };
+        storage.write_bitcoin_transaction(&tx_ref).await.unwrap();
+        let vote = DepositSigner {
+            txid: request.txid,
+            output_index: request.output_index,
+            signer_pub_key: event_loop.signer_public_key(),
+            can_accept: true,
+            can_sign: true,
+            decision_version: 0,
+        };
+        storage.write_deposit_signer_decision(&vote).await.unwrap();
+
+        // Two blocks after confirmation the deposit has not expired yet.
+        let chain_tip = model::BitcoinBlockRef::from(&blocks[2]);
+        event_loop
+            .expire_stale_deposit_requests(&chain_tip)
+            .await
+            .unwrap();
+        let expiration = storage
+            .get_deposit_expiration(&request.txid, request.output_index)
+            .await
+            .unwrap();
+        assert!(expiration.is_none());
+
+        let chain_tip = model::BitcoinBlockRef::from(&blocks[3]);
+        event_loop
+            .expire_stale_deposit_requests(&chain_tip)
+            .await
+            .unwrap();
+        let expiration = storage
+            .get_deposit_expiration(&request.txid, request.output_index)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(expiration.bitcoin_block_hash, blocks[3].block_hash);
+        assert!(!expiration.reactivated);
+
+        let pending = storage
+            .get_pending_accepted_deposit_requests(&chain_tip, 6, 1)
+            .await
+            .unwrap();
+        assert!(pending.is_empty());
+
+        // Once re-activated, the deposit is considered for sweeping again
+        // and it is not expired a second time.
+        let reactivated = storage
+            .reactivate_deposit_request(&request.txid, request.output_index)
+            .await
+            .unwrap();
+        assert!(reactivated);
+        event_loop
+            .expire_stale_deposit_requests(&chain_tip)
+            .await
+            .unwrap();
+
+        let pending = storage
+            .get_pending_accepted_deposit_requests(&chain_tip, 6, 1)
+            .await
+            .unwrap();
+        assert_eq!(pending, vec![request.clone()]);
+
+        let reactivated = storage
+            .reactivate_deposit_request(&request.txid, request.output_index)
+            .await
+            .unwrap();
+        assert!(!reactivated);
+    }
+
+    #[tokio::test]
+    async fn withdrawal_requests_are_not_decided_while_withdrawals_are_paused() {
+        let context = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .build();
+
+        let network = WanNetwork::default();
+        let mut event_loop = test_event_loop(&context, &network);
+        let signer_public_key = event_loop.signer_public_key();
+
+        let params = testing::storage::model::Params {
+            num_bitcoin_blocks: 10,
+            num_stacks_blocks_per_bitcoin_block: 3,
+            num_deposit_requests_per_block: 0,
+            num_withdraw_requests_per_block: 5,
+            num_signers_per_request: 0,
+            consecutive_blocks: false,
+        };
+        let mut rng = testing::get_rng();
+        let test_data =
+            testing::storage::model::TestData::generate(&mut rng, &[signer_public_key], &params);
+
+        let storage = context.get_storage_mut();
+        test_data.write_to(&storage).await;
+        let chain_tip = storage
+            .get_bitcoin_canonical_chain_tip_ref()
+            .await
+            .unwrap()
+            .unwrap();
+        context.state().set_bitcoin_chain_tip(chain_tip);
+
+        let pending = storage
+            .get_pending_withdrawal_requests(&chain_tip.block_hash, 6, &signer_public_key)
+            .await
+            .unwrap();
+        assert!(!pending.is_empty());
+
+        // While withdrawals are paused we leave the requests pending.
+        context.state().set_withdrawals_paused(true);
+        event_loop.handle_new_requests().await.unwrap();
+
+        let still_pending = storage
+            .get_pending_withdrawal_requests(&chain_tip.block_hash, 6, &signer_public_key)
+            .await
+            .unwrap();
+        assert_eq!(still_pending, pending);
+
+        // Once they are resumed we vote on all of them.
+        context.state().set_withdrawals_paused(false);
+        event_loop.handle_new_requests().await.unwrap();
+
+        let still_pending = storage
+            .get_pending_withdrawal_requests(&chain_tip.block_hash, 6, &signer_public_key)
+            .await
+            .unwrap();
+        assert!(still_pending.is_empty());
+    }
+
+    #[tokio::test]
+    async fn changed_withdrawal_decisions_are_superseded_after_revalidation() {
+        let context = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .modify_settings(|settings| {
+                settings.signer.withdrawal_revalidation_blocks = std::num::NonZeroU16::new(1);
+            })
+            .build();
+
+        let network = WanNetwork::default();
+        let mut event_loop = test_event_loop(&context, &network);
+        let signer_public_key = event_loop.signer_public_key();
+
+        let params = testing::storage::model::Params {
+            num_bitcoin_blocks: 10,
+            num_stacks_blocks_per_bitcoin_block: 3,
+            num_deposit_requests_per_block: 0,
+            num_withdraw_requests_per_block: 5,
+            num_signers_per_request: 0,
+            consecutive_blocks: false,
+        };
+        let mut rng = testing::get_rng();
+        let test_data =
+            testing::storage::model::TestData::generate(&mut rng, &[signer_public_key], &params);
+
+        let storage = context.get_storage_mut();
+        test_data.write_to(&storage).await;
+        let chain_tip = storage
+            .get_bitcoin_canonical_chain_tip_ref()
+            .await
+            .unwrap()
+            .unwrap();
+        context.state().set_bitcoin_chain_tip(chain_tip);
+
+        // We accept every request the first time around.
+        event_loop.handle_new_requests().await.unwrap();
+        let request = storage
+            .get_decided_withdrawal_requests(&chain_tip.block_hash, 6, &signer_public_key)
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|request| request.bitcoin_block_height < chain_tip.block_height)
+            .unwrap();
+
+        // Nothing changed, so there is nothing to tell the other signers.
+        let changed = event_loop
+            .revalidate_withdrawal_decisions(&chain_tip)
+            .await
+            .unwrap();
+        assert!(changed.is_empty());
+
+        // Now the request is no longer acceptable.
+        context
+            .state()
+            .set_withdrawal_decision_override(request.qualified_id(), Some(false));
+        let changed = event_loop
+            .revalidate_withdrawal_decisions(&chain_tip)
+            .await
+            .unwrap();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].request_id, request.request_id);
+        assert!(!changed[0].accepted);
+        assert_eq!(changed[0].decision_version, 1);
+
+        let decisions = storage
+            .get_withdrawal_signers(request.request_id, &request.block_hash)
+            .await
+            .unwrap();
+        assert_eq!(decisions.len(), 1);
+        assert!(!decisions[0].is_accepted);
+        assert_eq!(decisions[0].decision_version, 1);
+
+        // A stale decision does not replace the newer one.
+        let stale = WithdrawalSigner {
+            is_accepted: true,
+            decision_version: 0,
+            ..decisions[0].clone()
+        };
+        storage
+            .write_withdrawal_signer_decision(&stale)
+            .await
+            .unwrap();
+        let decisions = storage
+            .get_withdrawal_signers(request.request_id, &request.block_hash)
+            .await
+            .unwrap();
+        assert!(!decisions[0].is_accepted);
+    }
+
+    #[tokio::test]
+    async fn operator_overrides_supersede_existing_decisions() {
+        let context = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .build();
+
+        let network = WanNetwork::default();
+        let mut event_loop = test_event_loop(&context, &network);
+        let signer_public_key = event_loop.signer_public_key();
+
+        let mut rng = testing::get_rng();
+        let storage = context.get_storage_mut();
+        let deposit = DepositSigner {
+            signer_pub_key: signer_public_key,
+            can_accept: true,
+            can_sign: true,
+            decision_version: 0,
+            ..fake::Faker.fake_with_rng(&mut rng)
+        };
+        storage
+            .write_deposit_signer_decision(&deposit)
+            .await
+            .unwrap();
+        let withdrawal = WithdrawalSigner {
+            signer_pub_key: signer_public_key,
+            is_accepted: false,
+            decision_version: 2,
+            ..fake::Faker.fake_with_rng(&mut rng)
+        };
+        storage
+            .write_withdrawal_signer_decision(&withdrawal)
+            .await
+            .unwrap();
+
+        // Overrides that agree with our decisions change nothing.
+        let outpoint = bitcoin::OutPoint::new(*deposit.txid, deposit.output_index);
+        let state = context.state();
+        state.set_deposit_decision_override(outpoint, Some(true));
+        state.set_withdrawal_decision_override(withdrawal.qualified_id(), Some(false));
+        let changed = event_loop.supersede_overridden_decisions().await.unwrap();
+        assert!(changed.is_empty());
+
+        state.set_deposit_decision_override(outpoint, Some(false));
+        state.set_withdrawal_decision_override(withdrawal.qualified_id(), Some(true));
+        let changed = event_loop.supersede_overridden_decisions().await.unwrap();
+
+        assert_eq!(changed.deposits.len(), 1);
+        assert!(!changed.deposits[0].can_accept);
+        assert_eq!(changed.deposits[0].decision_version, 1);
+        assert!(changed.deposits[0].receipt.is_none());
+        assert_eq!(changed.withdrawals.len(), 1);
+        assert!(changed.withdrawals[0].accepted);
+        assert_eq!(changed.withdrawals[0].decision_version, 3);
+
+        let deposits = storage
+            .get_deposit_signers(&deposit.txid, deposit.output_index)
+            .await
+            .unwrap();
+        assert_eq!(deposits.len(), 1);
+        assert!(!deposits[0].can_accept);
+        assert_eq!(deposits[0].decision_version, 1);
+
+        // The overrides have now been applied, so there is nothing left
+        // to supersede.
+        let changed = event_loop.supersede_overridden_decisions().await.unwrap();
+        assert!(changed.is_empty());
+    }
+
+    #[test]
+    fn event_loop_builder_validates_retry_windows() {
+        let ctx = TestContext::default_mocked();
+        let network = crate::network::watcher::WatcherNetwork;
+
+        let event_loop = RequestDeciderEventLoop::<_, _, ()>::builder(ctx.clone(), network)
+            .with_context_window(6)
+            .with_decisions_retry_windows(1, 6)
+            .build()
+            .unwrap();
+        assert!(event_loop.blocklist_checker.is_none());
+        assert_eq!(event_loop.withdrawal_decisions_retry_window, 6);
+
+        let result = RequestDeciderEventLoop::<_, _, ()>::builder(ctx, network)
+            .with_context_window(6)
+            .with_decisions_retry_windows(7, 1)
+            .build();
+        assert!(matches!(result, Err(Error::InvalidEventLoopSetting(_))));
+    }
 }
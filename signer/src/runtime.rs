@@ -0,0 +1,709 @@
+//! Running a whole signer from a library.
+//!
+//! The [`Signer`] bundles the API servers, the P2P network and all of the
+//! event loops of a signer behind [`Signer::start`], [`Signer::stop`] and
+//! [`Signer::status`], so that the signer binary, other projects that
+//! embed a signer, and tests that need a fully running signer all wire the
+//! components together the same way. The caller constructs the
+//! [`Context`], which holds the configuration, the storage and the
+//! clients for bitcoin-core, the stacks node and Emily.
+//!
+//! The event loops run under the [`supervisor`], and the signer shuts
+//! down as a whole once one of its components fails for good. A signer
+//! can only be started once, since stopping it signals shutdown on its
+//! context.
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use axum::http::Request;
+use axum::http::Response;
+use futures::StreamExt as _;
+use serde::Serialize;
+use tokio::task::JoinHandle;
+use tower_http::trace::TraceLayer;
+use tracing::Instrument as _;
+use tracing::Span;
+
+use crate::alerts::AlertDispatcherEventLoop;
+use crate::api;
+use crate::api::ApiState;
+use crate::bitcoin::zmq::BitcoinCoreMessageStream;
+use crate::block_observer;
+use crate::blocklist_client::BlocklistClient;
+use crate::broadcaster;
+use crate::chaos::ChaosMonkey;
+use crate::context::Context;
+use crate::context::SignerCommand;
+use crate::context::SignerSignal;
+use crate::error::Error;
+use crate::network::P2PNetwork;
+use crate::network::libp2p::SignerSwarmBuilder;
+use crate::network::store_and_forward::StoreAndForwardEventLoop;
+use crate::network::watcher::WatcherNetwork;
+use crate::policy_plugin;
+use crate::reconciliation;
+use crate::request_decider::RequestDeciderEventLoop;
+use crate::storage::DbRead as _;
+use crate::supervisor;
+use crate::supervisor::RestartPolicy;
+use crate::transaction_coordinator::TxCoordinatorEventLoop;
+use crate::transaction_signer;
+use crate::watchdog;
+
+// This is how many seconds the P2P swarm will wait before attempting to
+// bootstrap (i.e. connect to other peers). Three seconds is a sane default
+// value, giving the swarm a few seconds to start up and bind listener(s)
+// before proceeding.
+const INITIAL_BOOTSTRAP_DELAY_SECS: u64 = 3;
+
+/// The role that a signer plays in the sBTC bridge.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum SignerRole {
+    /// Take part in the signing rounds as a member of the signing set.
+    #[default]
+    Signer,
+    /// Observe the bitcoin and stacks blockchains and compute what the
+    /// signers are expected to do, without taking part in the p2p network
+    /// or in any signing round.
+    Watcher,
+}
+
+/// The lifecycle status of a [`Signer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignerStatus {
+    /// The signer has not been started.
+    NotStarted,
+    /// The components of the signer are running.
+    Running,
+    /// Shutdown has been signalled, and the components of the signer are
+    /// shutting down.
+    Stopping,
+    /// All components of the signer have shut down.
+    Stopped,
+}
+
+/// A signer, with all of its components, that can be started and stopped
+/// programmatically.
+#[derive(Debug)]
+pub struct Signer<C> {
+    /// The context that the components of the signer share.
+    context: C,
+    /// The role of the signer.
+    role: SignerRole,
+    /// The chaos monkey, if the signer runs in chaos mode.
+    chaos: Option<ChaosMonkey>,
+    /// When and how often the event loops are restarted.
+    restart_policy: RestartPolicy,
+    /// Whether the signer has been started.
+    started: bool,
+    /// The task running the components of the signer, until it has been
+    /// waited on.
+    task: Option<JoinHandle<()>>,
+}
+
+impl<C> Signer<C>
+where
+    C: Context + 'static,
+{
+    /// Create a new signer, which takes part in the signing rounds, from
+    /// the given context.
+    pub fn new(context: C) -> Self {
+        Self {
+            context,
+            role: SignerRole::default(),
+            chaos: None,
+            restart_policy: RestartPolicy::default(),
+            started: false,
+            task: None,
+        }
+    }
+
+    /// Set the role of the signer.
+    pub fn with_role(mut self, role: SignerRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// Run the signer in chaos mode with the given chaos monkey. This is
+    /// ignored for watchers.
+    pub fn with_chaos(mut self, chaos: ChaosMonkey) -> Self {
+        self.chaos = Some(chaos);
+        self
+    }
+
+    /// Set when and how often the event loops of the signer are restarted.
+    pub fn with_restart_policy(mut self, policy: RestartPolicy) -> Self {
+        self.restart_policy = policy;
+        self
+    }
+
+    /// The context that the components of the signer share.
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Start all components of the signer in the background. Does nothing
+    /// if the signer has already been started.
+    ///
+    /// This must be called from within a tokio runtime.
+    pub fn start(&mut self) {
+        if self.started {
+            tracing::warn!("the signer has already been started");
+            return;
+        }
+
+        load_bootstrap_signing_set(&self.context);
+
+        let context = self.context.clone();
+        let policy = self.restart_policy.clone();
+        let task = match self.role {
+            SignerRole::Signer => {
+                let chaos = self.chaos.clone();
+                tokio::spawn(run_signer(context, chaos, policy))
+            }
+            SignerRole::Watcher => tokio::spawn(run_watcher(context, policy)),
+        };
+        self.started = true;
+        self.task = Some(task);
+    }
+
+    /// Signal shutdown to all components of the signer and wait until they
+    /// have shut down.
+    pub async fn stop(&mut self) {
+        self.context.get_termination_handle().signal_shutdown();
+        self.wait().await;
+    }
+
+    /// Wait until all components of the signer have shut down, which
+    /// happens once shutdown has been signalled on the context or one of
+    /// the components failed for good. Returns right away if the signer
+    /// has not been started.
+    pub async fn wait(&mut self) {
+        let Some(task) = self.task.take() else {
+            return;
+        };
+        if let Err(error) = task.await {
+            tracing::error!(%error, "the signer task failed");
+        }
+    }
+
+    /// The lifecycle status of the signer.
+    pub fn status(&self) -> SignerStatus {
+        if !self.started {
+            return SignerStatus::NotStarted;
+        }
+        match &self.task {
+            None => SignerStatus::Stopped,
+            Some(task) if task.is_finished() => SignerStatus::Stopped,
+            Some(_) if self.context.get_termination_handle().shutdown_signalled() => {
+                SignerStatus::Stopping
+            }
+            Some(_) => SignerStatus::Running,
+        }
+    }
+}
+
+/// Run all of the signer's components until shutdown.
+async fn run_signer<C>(context: C, chaos: Option<ChaosMonkey>, policy: RestartPolicy)
+where
+    C: Context + 'static,
+{
+    let chaos = chaos.as_ref();
+    let policy = &policy;
+
+    if run_checked(load_operator_controls, &context).await.is_err() {
+        return;
+    }
+
+    // Run the application components concurrently. We're `join!`ing them
+    // here so that every component can shut itself down gracefully when
+    // the shutdown signal is received.
+    //
+    // Note that we must use `join` here instead of `select` as `select` would
+    // immediately abort the remaining tasks on the first completion, which
+    // deprives the other tasks of the opportunity to shut down gracefully. This
+    // is the reason we also use the `run_checked` helper method, which will
+    // intercept errors and send a shutdown signal to the other components if an error
+    // does occur, otherwise the `join` will continue running indefinitely.
+    let _ = tokio::join!(
+        run_checked(run_api, &context),
+        run_checked(run_grpc_api, &context),
+        run_checked(run_libp2p_swarm, &context),
+        run_checked(
+            |ctx| run_supervised(chaos, policy, "block-observer", run_block_observer, ctx),
+            &context
+        ),
+        run_checked(
+            |ctx| run_supervised(chaos, policy, "request-decider", run_request_decider, ctx),
+            &context
+        ),
+        run_checked(
+            |ctx| run_supervised(
+                chaos,
+                policy,
+                "tx-coordinator",
+                run_transaction_coordinator,
+                ctx
+            ),
+            &context
+        ),
+        run_checked(
+            |ctx| run_supervised(chaos, policy, "tx-signer", run_transaction_signer, ctx),
+            &context
+        ),
+        run_checked(|ctx| run_chaos_reorgs(chaos, ctx), &context),
+        run_checked(broadcaster::run_sweep_broadcaster, &context),
+        run_checked(reconciliation::run_utxo_reconciliation, &context),
+        run_checked(watchdog::run_chain_tip_watchdog, &context),
+        run_checked(run_store_and_forward, &context),
+        run_checked(run_alert_dispatcher, &context),
+    );
+}
+
+/// Run the components of a watcher until shutdown. These are the
+/// components of a signer that do not take part in the p2p network.
+async fn run_watcher<C>(context: C, policy: RestartPolicy)
+where
+    C: Context + 'static,
+{
+    tracing::info!("running as a watcher; this process will not join the signer network");
+    let policy = &policy;
+
+    if run_checked(load_operator_controls, &context).await.is_err() {
+        return;
+    }
+
+    let _ = tokio::join!(
+        run_checked(run_api, &context),
+        run_checked(
+            |ctx| run_supervised(None, policy, "block-observer", run_block_observer, ctx),
+            &context
+        ),
+        run_checked(
+            |ctx| run_supervised(
+                None,
+                policy,
+                "request-decider",
+                run_watcher_request_decider,
+                ctx
+            ),
+            &context
+        ),
+        run_checked(
+            |ctx| run_supervised(
+                None,
+                policy,
+                "tx-coordinator",
+                run_watcher_transaction_coordinator,
+                ctx
+            ),
+            &context
+        ),
+        run_checked(reconciliation::run_utxo_reconciliation, &context),
+        run_checked(watchdog::run_chain_tip_watchdog, &context),
+        run_checked(run_alert_dispatcher, &context),
+    );
+}
+
+/// Add the signers in the bootstrap signing set to the current signer set.
+fn load_bootstrap_signing_set(context: &impl Context) {
+    // TODO: We should first check "another source of truth" for the current
+    // signing set, and only assume we are bootstrapping if that source is
+    // empty.
+    let settings = context.config();
+    for signer in &settings.signer.bootstrap_signing_set {
+        context.state().current_signer_set().add_signer(*signer);
+    }
+}
+
+/// Load the controls that the operator set through the control API, which
+/// are stored so that they survive a restart, into the signer state.
+async fn load_operator_controls(context: impl Context) -> Result<(), Error> {
+    let storage = context.get_storage();
+    let state = context.state();
+
+    if storage.is_coordinator_paused().await? {
+        tracing::warn!("coordinator duties were paused by the operator");
+        state.set_coordinator_paused(true);
+    }
+    for decision_override in storage.get_deposit_decision_overrides().await? {
+        let can_accept = Some(decision_override.can_accept);
+        state.set_deposit_decision_override(decision_override.outpoint(), can_accept);
+    }
+    for decision_override in storage.get_withdrawal_decision_overrides().await? {
+        let accept = Some(decision_override.accept);
+        state.set_withdrawal_decision_override(decision_override.qualified_id(), accept);
+    }
+
+    Ok(())
+}
+
+/// A helper method that captures errors from the provided future and sends a
+/// shutdown signal to the application if an error is encountered. This is needed
+/// as otherwise the application would continue running indefinitely (since no
+/// shutdown signal is sent automatically on error).
+async fn run_checked<F, Fut, C>(f: F, ctx: &C) -> Result<(), Error>
+where
+    C: Context,
+    F: FnOnce(C) -> Fut,
+    Fut: std::future::Future<Output = Result<(), Error>>,
+{
+    if let Err(error) = f(ctx.clone()).await {
+        tracing::error!(%error, "a fatal error occurred; shutting down the application");
+        ctx.get_termination_handle().signal_shutdown();
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Run the event loop, restarting it at random intervals if chaos mode is
+/// enabled.
+async fn run_chaotic<F, Fut, C>(
+    chaos: Option<&ChaosMonkey>,
+    name: &str,
+    f: F,
+    ctx: C,
+) -> Result<(), Error>
+where
+    C: Context,
+    F: Fn(C) -> Fut,
+    Fut: std::future::Future<Output = Result<(), Error>>,
+{
+    match chaos {
+        Some(monkey) => monkey.run_with_restarts(name, f, ctx).await,
+        None => f(ctx).await,
+    }
+}
+
+/// Run the event loop under a supervisor that restarts it whenever it
+/// exits unexpectedly, and shuts down the application if it keeps
+/// crashing.
+async fn run_supervised<F, Fut, C>(
+    chaos: Option<&ChaosMonkey>,
+    policy: &RestartPolicy,
+    name: &'static str,
+    f: F,
+    ctx: C,
+) -> Result<(), Error>
+where
+    C: Context,
+    F: Fn(C) -> Fut,
+    Fut: std::future::Future<Output = Result<(), Error>>,
+{
+    let event_loop = |ctx| run_chaotic(chaos, name, &f, ctx);
+    supervisor::supervise(name, policy, event_loop, ctx).await
+}
+
+/// Trigger random bitcoin reorgs if chaos mode is enabled.
+async fn run_chaos_reorgs(chaos: Option<&ChaosMonkey>, ctx: impl Context) -> Result<(), Error> {
+    match chaos {
+        Some(monkey) => monkey.run_reorgs(ctx).await,
+        None => Ok(()),
+    }
+}
+
+/// Runs the libp2p swarm.
+#[tracing::instrument(skip_all, name = "p2p")]
+async fn run_libp2p_swarm(ctx: impl Context) -> Result<(), Error> {
+    tracing::info!("initializing the p2p network");
+
+    tracing::debug!("building the libp2p swarm");
+    let config = ctx.config();
+
+    let enable_quic = config.signer.p2p.is_quic_used();
+
+    // Limit the number of signers to the maximum number of signer pubkeys we
+    // can support. Note that this value is used as a base value for swarm
+    // connection limit calculations.
+    let num_signers = ctx
+        .state()
+        .current_signer_set()
+        .num_signers()
+        .try_into()
+        .unwrap_or(crate::MAX_KEYS);
+
+    // Build the swarm.
+    let mut builder = SignerSwarmBuilder::new(&config.signer.private_key)
+        .add_listen_endpoints(&ctx.config().signer.p2p.listen_on)
+        .add_seed_addrs(&ctx.config().signer.p2p.seeds)
+        .add_external_addresses(&ctx.config().signer.p2p.public_endpoints)
+        .enable_mdns(config.signer.p2p.enable_mdns)
+        .enable_quic_transport(enable_quic)
+        .with_initial_bootstrap_delay(Duration::from_secs(INITIAL_BOOTSTRAP_DELAY_SECS))
+        .with_num_signers(num_signers);
+
+    if config.signer.p2p.pin_bootstrap_signers {
+        tracing::info!("only connecting to the signers in the bootstrap signing set");
+        let bootstrap_signing_set = config.signer.bootstrap_signing_set.iter().copied();
+        builder = builder.with_pinned_peers(bootstrap_signing_set);
+    }
+
+    let mut swarm = builder.build()?;
+
+    // Start the libp2p swarm. This will run until either the shutdown signal is
+    // received, or an unrecoverable error has occurred.
+    tracing::info!("starting the libp2p swarm");
+    swarm
+        .start(&ctx)
+        .in_current_span()
+        .await
+        .map_err(Error::SignerSwarm)
+}
+
+/// Runs the signer's API server, which includes the Stacks event observer.
+#[tracing::instrument(skip_all, name = "api")]
+async fn run_api(ctx: impl Context + 'static) -> Result<(), Error> {
+    let socket_addr = ctx.config().signer.event_observer.bind;
+    tracing::info!(%socket_addr, "initializing the signer API server");
+
+    let state = ApiState { ctx: ctx.clone() };
+
+    let request_id = Arc::new(AtomicU64::new(0));
+
+    // Build the signer API application
+    let app = api::get_router()
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(|request: &Request<_>| {
+                    tracing::info_span!("api-request",
+                        uri = %request.uri(),
+                        method = %request.method(),
+                        id = tracing::field::Empty,
+                    )
+                })
+                .on_request(move |_: &Request<_>, span: &Span| {
+                    span.record("id", request_id.fetch_add(1, Ordering::SeqCst));
+                    tracing::trace!("processing request");
+                })
+                .on_response(|_: &Response<_>, duration: Duration, _: &Span| {
+                    tracing::trace!(duration_ms = duration.as_millis(), "request completed");
+                }),
+        )
+        .with_state(state);
+
+    // Bind to the configured address and port
+    let listener = tokio::net::TcpListener::bind(socket_addr)
+        .await
+        .expect("failed to bind the signer API to configured address");
+
+    // Get the termination signal handle.
+    let mut term = ctx.get_termination_handle();
+
+    // Run our app with hyper
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            // Listen for an application shutdown signal. We need to loop here
+            // because we may receive other signals (which we will ignore here).
+            term.wait_for_shutdown().await;
+            tracing::info!("stopping the signer API server");
+        })
+        .await
+        .map_err(|error| {
+            tracing::error!(%error, "error running the signer API server");
+            ctx.get_termination_handle().signal_shutdown();
+            error.into()
+        })
+}
+
+/// Runs the signer's gRPC control service, if it has been configured.
+#[tracing::instrument(skip_all, name = "grpc-api")]
+async fn run_grpc_api(ctx: impl Context + 'static) -> Result<(), Error> {
+    let Some(socket_addr) = ctx.config().signer.grpc_endpoint else {
+        tracing::debug!("no gRPC endpoint configured; not starting the gRPC service");
+        return Ok(());
+    };
+    tracing::info!(%socket_addr, "initializing the signer gRPC service");
+
+    let service = api::SignerControlService::new(ctx.clone()).into_server();
+
+    // Get the termination signal handle.
+    let mut term = ctx.get_termination_handle();
+
+    tonic::transport::Server::builder()
+        .add_service(service)
+        .serve_with_shutdown(socket_addr, async move {
+            term.wait_for_shutdown().await;
+            tracing::info!("stopping the signer gRPC service");
+        })
+        .await
+        .map_err(|error| {
+            tracing::error!(%error, "error running the signer gRPC service");
+            Error::GrpcServer(error)
+        })
+}
+
+/// Run the block observer event-loop.
+async fn run_block_observer(ctx: impl Context) -> Result<(), Error> {
+    let config = ctx.config().clone();
+
+    // A pruned bitcoin-core node must keep the blocks that the block
+    // observer looks up transactions in.
+    block_observer::check_prune_depth(&ctx).await?;
+
+    // TODO: Need to handle multiple endpoints, so some sort of
+    // failover-stream-wrapper.
+    let endpoint = config.bitcoin.block_hash_stream_endpoints[0].as_str();
+    let stream = BitcoinCoreMessageStream::new_from_endpoint(endpoint)
+        .await
+        .unwrap();
+
+    // The chain tip watchdog asks the block observer to resync with
+    // bitcoin-core by sending the block hash of its chain tip, which we
+    // handle as if it came from bitcoin-core.
+    let resyncs = ctx
+        .as_signal_stream(|signal| {
+            matches!(
+                signal,
+                SignerSignal::Command(SignerCommand::ResyncBitcoinBlocks(_))
+            )
+        })
+        .filter_map(|signal| match signal {
+            SignerSignal::Command(SignerCommand::ResyncBitcoinBlocks(block_hash)) => {
+                std::future::ready(Some(Ok(block_hash)))
+            }
+            _ => std::future::ready(None),
+        });
+
+    // TODO: We should have a new() method that builds from the context
+    let block_observer = block_observer::BlockObserver {
+        context: ctx,
+        bitcoin_blocks: futures::stream::select(stream.to_block_hash_stream(), resyncs),
+    };
+
+    block_observer.run().await
+}
+
+/// Run the transaction signer event-loop.
+async fn run_transaction_signer(ctx: impl Context) -> Result<(), Error> {
+    let network = P2PNetwork::new(&ctx);
+
+    let signer = transaction_signer::TxSignerEventLoop::new(ctx, network, rand::rngs::OsRng)?;
+
+    signer.run().await
+}
+
+/// Run the transaction coordinator event-loop.
+async fn run_transaction_coordinator(ctx: impl Context) -> Result<(), Error> {
+    let network = P2PNetwork::new(&ctx);
+    TxCoordinatorEventLoop::builder(ctx, network)
+        .build()?
+        .run()
+        .await
+}
+
+/// Run the transaction coordinator event-loop of a watcher.
+async fn run_watcher_transaction_coordinator(ctx: impl Context) -> Result<(), Error> {
+    TxCoordinatorEventLoop::builder(ctx, WatcherNetwork)
+        .build()?
+        .run_as_watcher()
+        .await
+}
+
+/// Construct the request decider event-loop.
+fn request_decider<C, N>(
+    ctx: C,
+    network: N,
+) -> Result<RequestDeciderEventLoop<C, N, BlocklistClient>, Error>
+where
+    C: Context,
+{
+    let blocklist_checker = ctx
+        .config()
+        .blocklist_client
+        .as_ref()
+        .map(|config| BlocklistClient::new(config).with_clock(ctx.clock().clone()));
+    let policy_plugin = policy_plugin::from_config(&ctx.config().signer)?;
+
+    RequestDeciderEventLoop::builder(ctx, network)
+        .with_blocklist_checker(blocklist_checker)
+        .with_policy_plugin(policy_plugin)
+        .build()
+}
+
+/// Run the request decider event-loop.
+async fn run_request_decider(ctx: impl Context) -> Result<(), Error> {
+    let network = P2PNetwork::new(&ctx);
+    request_decider(ctx, network)?.run().await
+}
+
+/// Run the request decider event-loop of a watcher. The decisions of a
+/// watcher are stored in its database, but they are not broadcast.
+async fn run_watcher_request_decider(ctx: impl Context) -> Result<(), Error> {
+    request_decider(ctx, WatcherNetwork)?.run().await
+}
+
+/// Run the store-and-forward event-loop.
+async fn run_store_and_forward(ctx: impl Context) -> Result<(), Error> {
+    let config = ctx.config().clone();
+    let network = P2PNetwork::new(&ctx);
+
+    let store_and_forward = StoreAndForwardEventLoop {
+        network,
+        context: ctx,
+        signer_private_key: config.signer.private_key,
+        retention: config.signer.p2p.message_retention,
+        store: Default::default(),
+    };
+
+    store_and_forward.run().await
+}
+
+/// Run the alert dispatcher event-loop.
+async fn run_alert_dispatcher(ctx: impl Context) -> Result<(), Error> {
+    AlertDispatcherEventLoop::new(ctx).run().await
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+    use fake::Faker;
+
+    use super::*;
+    use crate::storage::DbWrite as _;
+    use crate::storage::model::QualifiedRequestId;
+    use crate::testing::context::*;
+
+    #[tokio::test]
+    async fn stopping_a_signer_that_was_not_started_signals_shutdown() {
+        let mut signer = Signer::new(TestContext::default_mocked()).with_role(SignerRole::Watcher);
+        assert_eq!(signer.status(), SignerStatus::NotStarted);
+
+        signer.stop().await;
+        assert_eq!(signer.status(), SignerStatus::NotStarted);
+        let term = signer.context().get_termination_handle();
+        assert!(term.shutdown_signalled());
+    }
+
+    #[tokio::test]
+    async fn operator_controls_are_loaded_from_storage() {
+        let ctx = TestContext::default_mocked();
+        let storage = ctx.get_storage_mut();
+        let outpoint = bitcoin::OutPoint::null();
+        let id = QualifiedRequestId {
+            request_id: 1,
+            txid: Faker.fake(),
+            block_hash: Faker.fake(),
+        };
+        storage.set_coordinator_paused(true).await.unwrap();
+        storage
+            .set_deposit_decision_override(&outpoint, Some(false))
+            .await
+            .unwrap();
+        storage
+            .set_withdrawal_decision_override(&id, Some(true))
+            .await
+            .unwrap();
+
+        load_operator_controls(ctx.clone()).await.unwrap();
+
+        let state = ctx.state();
+        assert!(state.is_coordinator_paused());
+        assert_eq!(state.deposit_decision_override(&outpoint), Some(false));
+        assert_eq!(state.withdrawal_decision_override(&id), Some(true));
+    }
+}
@@ -0,0 +1,355 @@
+//! Selection of the deposit and withdrawal requests to sweep.
+//!
+//! The storage layer does a first pass over the pending requests, leaving
+//! out the ones that have been swept, have expired, or that too few
+//! signers accepted. The functions in this module apply the remaining
+//! consensus rules to snapshots of those requests and the votes of the
+//! current signer set on them. They do not touch the database, so the
+//! coordinator, the watcher and the tests all make the same decisions
+//! given the same snapshots.
+
+use std::collections::BTreeSet;
+
+use bitcoin::OutPoint;
+
+use crate::WITHDRAWAL_DUST_LIMIT;
+use crate::bitcoin::utxo;
+use crate::context::SignerState;
+use crate::keys::PublicKey;
+use crate::message::DepositDecisionRetryRequest;
+use crate::storage::model;
+use crate::storage::model::BitcoinBlockHeight;
+
+/// The message logged for every request that is left out of a sweep.
+const REQUEST_SKIPPED_MESSAGE: &str = "skipping request";
+
+/// The number of votes for and against a request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VoteTally {
+    /// The number of signers that accepted the request.
+    pub accepted: u16,
+    /// The number of signers that rejected the request.
+    pub rejected: u16,
+    /// The number of signers whose decision we do not have.
+    pub missing: u16,
+}
+
+impl From<&model::SignerVotes> for VoteTally {
+    fn from(votes: &model::SignerVotes) -> Self {
+        votes
+            .iter()
+            .fold(VoteTally::default(), |tally, vote| match vote.is_accepted {
+                Some(true) => VoteTally {
+                    accepted: tally.accepted + 1,
+                    ..tally
+                },
+                Some(false) => VoteTally {
+                    rejected: tally.rejected + 1,
+                    ..tally
+                },
+                None => VoteTally {
+                    missing: tally.missing + 1,
+                    ..tally
+                },
+            })
+    }
+}
+
+/// Why a request was left out of a sweep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum SkipReason {
+    /// The withdrawal request expires too soon to be swept.
+    SoftExpiry,
+    /// The withdrawal amount is below the dust limit.
+    AmountIsDust,
+    /// The withdrawal amount is above the per-withdrawal cap.
+    PerWithdrawalCapExceeded,
+    /// The withdrawal request does not have enough confirmations.
+    InsufficientConfirmations,
+    /// Too few signers in the current signer set accepted the request.
+    InsufficientVotes,
+    /// The deposit or reclaim script of the deposit request does not
+    /// parse into the values stored for the request.
+    InvalidDepositScripts,
+}
+
+/// The rules that withdrawal requests must follow to be swept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WithdrawalRules {
+    /// The height of the bitcoin chain tip.
+    pub chain_tip_height: BitcoinBlockHeight,
+    /// The number of blocks after which a withdrawal request expires.
+    pub expiry_window: u64,
+    /// The number of blocks before the expiry of a withdrawal request
+    /// during which it is no longer swept.
+    pub expiry_buffer: u64,
+    /// The minimum number of confirmations of a withdrawal request.
+    pub min_confirmations: u64,
+    /// The maximum amount of a withdrawal request, in sats.
+    pub per_withdrawal_cap: u64,
+    /// The number of signers in the current signer set that must accept
+    /// a withdrawal request.
+    pub signature_threshold: u16,
+}
+
+impl WithdrawalRules {
+    /// Check the given withdrawal request, with the given votes of the
+    /// current signer set, against these rules.
+    pub fn check(
+        &self,
+        request: &model::WithdrawalRequest,
+        votes: &model::SignerVotes,
+    ) -> Result<(), SkipReason> {
+        let min_bitcoin_height = self.chain_tip_height.saturating_sub(self.expiry_window);
+        let min_soft_bitcoin_height = min_bitcoin_height.saturating_add(self.expiry_buffer);
+        if request.bitcoin_block_height < min_soft_bitcoin_height {
+            return Err(SkipReason::SoftExpiry);
+        }
+        if request.amount < WITHDRAWAL_DUST_LIMIT {
+            return Err(SkipReason::AmountIsDust);
+        }
+        if request.amount > self.per_withdrawal_cap {
+            return Err(SkipReason::PerWithdrawalCapExceeded);
+        }
+        let num_confirmations = self
+            .chain_tip_height
+            .blocks_since(request.bitcoin_block_height);
+        if num_confirmations < self.min_confirmations {
+            return Err(SkipReason::InsufficientConfirmations);
+        }
+        if VoteTally::from(votes).accepted < self.signature_threshold {
+            return Err(SkipReason::InsufficientVotes);
+        }
+        Ok(())
+    }
+}
+
+/// Select the withdrawal requests that follow the given rules, logging
+/// the ones that do not.
+pub fn select_withdrawals(
+    candidates: Vec<(model::WithdrawalRequest, model::SignerVotes)>,
+    rules: &WithdrawalRules,
+) -> Vec<utxo::WithdrawalRequest> {
+    candidates
+        .into_iter()
+        .filter_map(|(request, votes)| match rules.check(&request, &votes) {
+            Ok(()) => Some(utxo::WithdrawalRequest::from_model(request, votes)),
+            Err(SkipReason::InsufficientVotes) => {
+                let tally = VoteTally::from(&votes);
+                let reason: &'static str = SkipReason::InsufficientVotes.into();
+                tracing::warn!(
+                    request_id = request.request_id,
+                    num_votes_accepted = tally.accepted,
+                    num_votes_rejected = tally.rejected,
+                    num_votes_missing = tally.missing,
+                    required_votes = rules.signature_threshold,
+                    reason,
+                    message = REQUEST_SKIPPED_MESSAGE
+                );
+                None
+            }
+            Err(reason) => {
+                let reason: &'static str = reason.into();
+                tracing::debug!(
+                    request_id = request.request_id,
+                    amount = request.amount,
+                    bitcoin_block_height = *request.bitcoin_block_height,
+                    reason,
+                    message = REQUEST_SKIPPED_MESSAGE
+                );
+                None
+            }
+        })
+        .collect()
+}
+
+/// Select the deposit requests that at least `signature_threshold`
+/// signers in the current signer set accepted, logging the ones that
+/// were not.
+///
+/// The scripts of each request are parsed through the cache in the given
+/// signer `state`, and requests whose scripts are invalid are left out.
+pub fn select_deposits(
+    candidates: Vec<(model::DepositRequest, model::SignerVotes)>,
+    signature_threshold: u16,
+    state: &SignerState,
+) -> Vec<utxo::DepositRequest> {
+    candidates
+        .into_iter()
+        .filter_map(|(request, votes)| {
+            if state.stored_deposit_scripts(&request).is_none() {
+                let reason: &'static str = SkipReason::InvalidDepositScripts.into();
+                tracing::warn!(
+                    outpoint = %request.outpoint(),
+                    reason,
+                    message = REQUEST_SKIPPED_MESSAGE
+                );
+                return None;
+            }
+            let tally = VoteTally::from(&votes);
+            if tally.accepted >= signature_threshold {
+                return Some(utxo::DepositRequest::from_model(request, votes));
+            }
+            let reason: &'static str = SkipReason::InsufficientVotes.into();
+            tracing::warn!(
+                outpoint = %request.outpoint(),
+                num_votes_accepted = tally.accepted,
+                num_votes_rejected = tally.rejected,
+                num_votes_missing = tally.missing,
+                required_votes = signature_threshold,
+                reason,
+                message = REQUEST_SKIPPED_MESSAGE
+            );
+            None
+        })
+        .collect()
+}
+
+/// Find the deposit requests that too few signers in the current signer
+/// set accepted, but enough would if the signers whose decisions are
+/// missing accepted them.
+///
+/// Returns a request for those signers to re-send their decisions, or
+/// `None` if no decisions are missing.
+pub fn missing_deposit_decisions<'a>(
+    candidates: impl IntoIterator<Item = (OutPoint, &'a model::SignerVotes)>,
+    signature_threshold: u16,
+) -> Option<DepositDecisionRetryRequest> {
+    let mut deposits = Vec::new();
+    let mut signers = BTreeSet::new();
+
+    for (outpoint, votes) in candidates {
+        let tally = VoteTally::from(votes);
+        let reachable = tally.accepted.saturating_add(tally.missing) >= signature_threshold;
+        if tally.accepted >= signature_threshold || !reachable {
+            continue;
+        }
+
+        deposits.push(outpoint);
+        signers.extend(
+            votes
+                .iter()
+                .filter(|vote| vote.is_accepted.is_none())
+                .map(|vote| vote.signer_public_key),
+        );
+    }
+
+    if deposits.is_empty() {
+        return None;
+    }
+
+    Some(DepositDecisionRetryRequest {
+        deposits,
+        signers: signers.into_iter().collect::<Vec<PublicKey>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+    use fake::Faker;
+
+    use super::*;
+
+    fn votes(accepted: usize, rejected: usize, missing: usize) -> model::SignerVotes {
+        let decisions = std::iter::repeat_n(Some(true), accepted)
+            .chain(std::iter::repeat_n(Some(false), rejected))
+            .chain(std::iter::repeat_n(None, missing));
+        decisions
+            .map(|is_accepted| model::SignerVote {
+                signer_public_key: Faker.fake(),
+                is_accepted,
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn rules() -> WithdrawalRules {
+        WithdrawalRules {
+            chain_tip_height: 1000u64.into(),
+            expiry_window: 100,
+            expiry_buffer: 10,
+            min_confirmations: 6,
+            per_withdrawal_cap: 1_000_000,
+            signature_threshold: 2,
+        }
+    }
+
+    fn withdrawal(amount: u64, bitcoin_block_height: u64) -> model::WithdrawalRequest {
+        model::WithdrawalRequest {
+            amount,
+            bitcoin_block_height: bitcoin_block_height.into(),
+            ..Faker.fake()
+        }
+    }
+
+    #[test_case::test_case(withdrawal(10_000, 990), votes(2, 0, 1) => Ok(()); "eligible")]
+    #[test_case::test_case(withdrawal(10_000, 905), votes(2, 0, 1) => Err(SkipReason::SoftExpiry); "soft expiry")]
+    #[test_case::test_case(withdrawal(1, 990), votes(2, 0, 1) => Err(SkipReason::AmountIsDust); "dust")]
+    #[test_case::test_case(withdrawal(1_000_001, 990), votes(2, 0, 1) => Err(SkipReason::PerWithdrawalCapExceeded); "above cap")]
+    #[test_case::test_case(withdrawal(10_000, 995), votes(2, 0, 1) => Err(SkipReason::InsufficientConfirmations); "too few confirmations")]
+    #[test_case::test_case(withdrawal(10_000, 990), votes(1, 1, 1) => Err(SkipReason::InsufficientVotes); "too few votes")]
+    fn withdrawal_rules(
+        request: model::WithdrawalRequest,
+        votes: model::SignerVotes,
+    ) -> Result<(), SkipReason> {
+        rules().check(&request, &votes)
+    }
+
+    #[test]
+    fn deposits_need_enough_accepting_votes() {
+        let accepted: model::DepositRequest = Faker.fake();
+        let rejected: model::DepositRequest = Faker.fake();
+        let candidates = vec![
+            (accepted.clone(), votes(2, 1, 0)),
+            (rejected, votes(1, 1, 1)),
+        ];
+
+        let state = SignerState::default();
+        let selected = select_deposits(candidates, 2, &state);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].outpoint, accepted.outpoint());
+    }
+
+    #[test]
+    fn deposits_with_invalid_scripts_are_skipped() {
+        let valid: model::DepositRequest = Faker.fake();
+        let mut invalid: model::DepositRequest = Faker.fake();
+        invalid.max_fee += 1;
+        let mut unparsable: model::DepositRequest = Faker.fake();
+        unparsable.spend_script = vec![0x51];
+        let candidates = vec![
+            (valid.clone(), votes(3, 0, 0)),
+            (invalid, votes(3, 0, 0)),
+            (unparsable, votes(3, 0, 0)),
+        ];
+
+        let state = SignerState::default();
+        let selected = select_deposits(candidates, 2, &state);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].outpoint, valid.outpoint());
+    }
+
+    #[test]
+    fn missing_decisions_are_only_requested_when_they_matter() {
+        let accepted = votes(2, 0, 1);
+        let reachable = votes(1, 0, 2);
+        let unreachable = votes(0, 2, 1);
+        let reachable_outpoint: OutPoint = Faker.fake::<model::DepositRequest>().outpoint();
+        let candidates = vec![
+            (Faker.fake::<model::DepositRequest>().outpoint(), &accepted),
+            (reachable_outpoint, &reachable),
+            (
+                Faker.fake::<model::DepositRequest>().outpoint(),
+                &unreachable,
+            ),
+        ];
+
+        let request = missing_deposit_decisions(candidates, 2).unwrap();
+        assert_eq!(request.deposits, vec![reachable_outpoint]);
+        assert_eq!(request.signers.len(), 2);
+
+        assert!(missing_deposit_decisions([(reachable_outpoint, &accepted)], 2).is_none());
+    }
+}
@@ -30,6 +30,7 @@ use blockstack_lib::net::api::postfeerate::RPCFeeEstimate;
 use blockstack_lib::net::api::postfeerate::RPCFeeEstimateResponse;
 use blockstack_lib::types::chainstate::StacksAddress;
 use blockstack_lib::types::chainstate::StacksBlockId;
+use blockstack_lib::util::hash::Hash160;
 use clarity::types::StacksEpochId;
 use clarity::vm::Value;
 use clarity::vm::types::OptionalData;
@@ -50,6 +51,7 @@ use crate::storage::model::BitcoinBlockHash;
 use crate::storage::model::BitcoinBlockHeight;
 use crate::storage::model::StacksBlock;
 use crate::storage::model::StacksBlockHeight;
+use crate::storage::model::StacksTxId;
 use crate::storage::model::ToLittleEndianOrder as _;
 use crate::util::ApiFallbackClient;
 
@@ -237,6 +239,14 @@ pub trait StacksInteract: Send + Sync {
         tx: &StacksTransaction,
     ) -> impl Future<Output = Result<SubmitTxResponse, Error>> + Send;
 
+    /// Get the inclusion information of a transaction that was submitted
+    /// to the Stacks node. Returns `None` if the transaction has not been
+    /// included in a stacks block.
+    fn get_transaction(
+        &self,
+        txid: &StacksTxId,
+    ) -> impl Future<Output = Result<Option<StacksTxInfo>, Error>> + Send;
+
     /// Fetch the raw stacks nakamoto block from a Stacks node given the
     /// Stacks block ID.
     fn get_block(
@@ -340,6 +350,11 @@ pub struct TenureBlocks {
     /// The height of the bitcoin block associated with the above block
     /// hash.
     pub anchor_block_height: BitcoinBlockHeight,
+    /// The consensus hash of the tenure.
+    pub consensus_hash: ConsensusHash,
+    /// The hash of the public key of the miner that won the sortition
+    /// of the tenure, if the stacks node knows it.
+    pub miner_pk_hash160: Option<Hash160>,
 }
 
 impl TenureBlocks {
@@ -352,6 +367,8 @@ impl TenureBlocks {
             blocks,
             anchor_block_hash: info.burn_block_hash.into(),
             anchor_block_height: info.burn_block_height.into(),
+            consensus_hash: info.consensus_hash,
+            miner_pk_hash160: info.miner_pk_hash160,
         })
     }
 
@@ -536,6 +553,27 @@ pub enum RejectionReason {
     TemporarilyBlacklisted,
 }
 
+impl RejectionReason {
+    /// Whether resubmitting the same contract call is pointless after a
+    /// rejection for this reason. Temporary conditions, like a fee that
+    /// is too low or a conflicting nonce in the mempool, may be resolved
+    /// by resubmitting the contract call, possibly with a higher fee.
+    pub fn is_permanent(&self) -> bool {
+        !matches!(
+            self,
+            RejectionReason::FeeTooLow
+                | RejectionReason::BadNonce
+                | RejectionReason::NotEnoughFunds
+                | RejectionReason::ServerFailureNoSuchChainTip
+                | RejectionReason::ConflictingNonceInMempool
+                | RejectionReason::TooMuchChaining
+                | RejectionReason::ServerFailureDatabase
+                | RejectionReason::EstimatorError
+                | RejectionReason::TemporarilyBlacklisted
+        )
+    }
+}
+
 /// A rejection response from the node.
 ///
 /// The official documentation specifies what to expect when there is a
@@ -585,6 +623,30 @@ pub enum SubmitTxResponse {
     Rejection(TxRejection),
 }
 
+/// The response from a GET /v3/transaction/<txid> request.
+///
+/// The stacks node only returns this for transactions that have been
+/// included in a stacks block, and only when it has been configured to
+/// index transactions.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StacksTxInfo {
+    /// The index block hash of the stacks block that includes the
+    /// transaction.
+    pub index_block_hash: StacksBlockId,
+    /// The value returned by the transaction. Contract calls that abort
+    /// return an `(err ..)` clarity value here.
+    #[serde(deserialize_with = "clarity_value_deserializer")]
+    pub result: Value,
+}
+
+impl StacksTxInfo {
+    /// Whether the transaction was committed, rather than aborted, when
+    /// it was included in a stacks block.
+    pub fn is_success(&self) -> bool {
+        matches!(&self.result, Value::Response(response) if response.committed)
+    }
+}
+
 /// The account info for a stacks address.
 pub struct AccountInfo {
     /// The total balance of the account in micro-STX. This amount includes
@@ -939,6 +1001,41 @@ impl StacksClient {
             .map_err(Error::UnexpectedStacksResponse)
     }
 
+    /// Get the inclusion information of a transaction.
+    ///
+    /// This is done by making a GET /v3/transaction/<txid> request to the
+    /// stacks node. The stacks node returns a 404 Not Found if the
+    /// transaction has not been included in a stacks block, or if it does
+    /// not index transactions, so both cases return `None` here.
+    #[tracing::instrument(skip(self))]
+    pub async fn get_transaction(&self, txid: &StacksTxId) -> Result<Option<StacksTxInfo>, Error> {
+        let path = format!("/v3/transaction/{txid}");
+        let url = self
+            .endpoint
+            .join(&path)
+            .map_err(|err| Error::PathJoin(err, self.endpoint.clone(), Cow::Owned(path)))?;
+
+        let response = self
+            .client
+            .get(url)
+            .timeout(REQUEST_TIMEOUT)
+            .send()
+            .await
+            .map_err(Error::StacksNodeRequest)?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        response
+            .error_for_status()
+            .map_err(Error::StacksNodeResponse)?
+            .json()
+            .await
+            .map_err(Error::UnexpectedStacksResponse)
+            .map(Some)
+    }
+
     /// Estimate the current mempool transaction fees.
     ///
     /// This is done by making a POST /v2/fees/transaction request to a
@@ -1278,8 +1375,28 @@ pub async fn fetch_unknown_ancestors<S, D>(
 where
     S: StacksInteract,
     D: DbRead + Send + Sync,
+{
+    fetch_verified_unknown_ancestors(stacks, db, block_id, |_| Ok(())).await
+}
+
+/// Fetch all Nakamoto ancestor blocks that are not in the database, like
+/// [`fetch_unknown_ancestors`], passing each tenure to the given
+/// verification function before only keeping the headers of its blocks.
+/// An error from the verification function stops the fetching and is
+/// returned.
+pub async fn fetch_verified_unknown_ancestors<S, D, F>(
+    stacks: &S,
+    db: &D,
+    block_id: StacksBlockId,
+    mut verify: F,
+) -> Result<Vec<TenureBlockHeaders>, Error>
+where
+    S: StacksInteract,
+    D: DbRead + Send + Sync,
+    F: FnMut(&TenureBlocks) -> Result<(), Error> + Send,
 {
     let starting_tenure = stacks.get_tenure(block_id).await?;
+    verify(&starting_tenure)?;
     let mut headers: Vec<TenureBlockHeaders> = vec![starting_tenure.into()];
     let pox_info = stacks.get_pox_info().await?;
     let nakamoto_start_height = pox_info
@@ -1309,6 +1426,7 @@ where
         }
         // There are more blocks to fetch, so let's get them.
         let tenure_blocks = stacks.get_tenure(header.parent_block_id).await?;
+        verify(&tenure_blocks)?;
         headers.push(tenure_blocks.into());
     }
 
@@ -1528,6 +1646,10 @@ impl StacksInteract for StacksClient {
         self.submit_tx(tx).await
     }
 
+    async fn get_transaction(&self, txid: &StacksTxId) -> Result<Option<StacksTxInfo>, Error> {
+        self.get_transaction(txid).await
+    }
+
     async fn get_block(&self, block_id: StacksBlockId) -> Result<NakamotoBlock, Error> {
         self.get_block(block_id).await
     }
@@ -1754,6 +1876,10 @@ impl StacksInteract for ApiFallbackClient<StacksClient> {
         self.exec(|client, _| client.submit_tx(tx)).await
     }
 
+    async fn get_transaction(&self, txid: &StacksTxId) -> Result<Option<StacksTxInfo>, Error> {
+        self.exec(|client, _| client.get_transaction(txid)).await
+    }
+
     async fn get_block(&self, block_id: StacksBlockId) -> Result<NakamotoBlock, Error> {
         self.exec(|client, _| client.get_block(block_id)).await
     }
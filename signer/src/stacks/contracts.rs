@@ -5,9 +5,15 @@
 //! * [`CompleteDepositV1`]: Used for calling the complete-deposit-wrapper
 //!   function in the sbtc-deposit contract. This finalizes the deposit by
 //!   minting sBTC and sending it to the depositor.
+//! * [`CompleteDepositsV1`]: Used for calling the
+//!   complete-deposits-wrapper function in the sbtc-deposit contract.
+//!   This mints sBTC for several swept deposits in one transaction.
 //! * [`AcceptWithdrawalV1`]: Used for calling the
 //!   accept-withdrawal-request function in the sbtc-withdrawal contract.
 //!   This finalizes the withdrawal request by burning the locked sBTC.
+//! * [`AcceptWithdrawalsV1`]: Used for calling the complete-withdrawals
+//!   function in the sbtc-withdrawal contract. This accepts several
+//!   withdrawal requests in one transaction.
 //! * [`RejectWithdrawalV1`]: Used for calling the
 //!   reject-withdrawal-request function in the sbtc-withdrawal contract.
 //!   This finalizes the withdrawal request by returning the locked sBTC to
@@ -36,8 +42,10 @@ use blockstack_lib::clarity::vm::types::BUFF_33;
 use blockstack_lib::clarity::vm::types::BuffData;
 use blockstack_lib::clarity::vm::types::ListData;
 use blockstack_lib::clarity::vm::types::ListTypeData;
+use blockstack_lib::clarity::vm::types::OptionalData;
 use blockstack_lib::clarity::vm::types::PrincipalData;
 use blockstack_lib::clarity::vm::types::SequenceData;
+use blockstack_lib::clarity::vm::types::TupleData;
 use blockstack_lib::types::chainstate::StacksAddress;
 use blockstack_lib::util_lib::strings::StacksString;
 use clarity::vm::ClarityVersion;
@@ -46,24 +54,30 @@ use crate::DEPOSIT_DUST_LIMIT;
 use crate::WITHDRAWAL_BLOCKS_EXPIRY;
 use crate::WITHDRAWAL_MIN_CONFIRMATIONS;
 use crate::bitcoin::BitcoinInteract;
+use crate::bitcoin::get_tx_info_cached;
 use crate::bitcoin::validation::WithdrawalRequestStatus;
 use crate::context::Context;
+use crate::context::SignerState;
 use crate::error::Error;
 use crate::keys::PublicKey;
 use crate::stacks::wallet::SignerWallet;
 use crate::storage::DbRead;
+use crate::storage::DbWrite;
 use crate::storage::model::BitcoinBlockHash;
 use crate::storage::model::BitcoinBlockHeight;
 use crate::storage::model::BitcoinBlockRef;
 use crate::storage::model::BitcoinTxId;
 use crate::storage::model::DkgSharesStatus;
+use crate::storage::model::MintMismatch;
 use crate::storage::model::QualifiedRequestId;
 use crate::storage::model::StacksBlockHash;
+use crate::storage::model::StacksPrincipal;
 use crate::storage::model::ToLittleEndianOrder as _;
 
 use super::api::StacksInteract;
 
-/// The collection sBTC smart contract objects.
+/// The collection sBTC smart contract objects that make up the first
+/// release of the sBTC smart contracts.
 ///
 /// The registry and token contracts need to be deployed first and second
 /// respectively. The rest can be deployed in any order.
@@ -75,6 +89,86 @@ pub const SMART_CONTRACTS: [SmartContract; 5] = [
     SmartContract::SbtcBootstrapSigners,
 ];
 
+/// A release of the sBTC smart contracts.
+///
+/// Clarity contracts cannot be redeployed under the same name, so every
+/// release after the first deploys its contracts under new names, next to
+/// the contracts of the earlier releases.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmartContractsVersion {
+    /// The first release of the sBTC smart contracts, deployed by the
+    /// signers when bootstrapping.
+    #[default]
+    V1,
+}
+
+impl SmartContractsVersion {
+    /// All releases of the sBTC smart contracts, from the oldest to the
+    /// most recent.
+    pub const ALL: [Self; 1] = [Self::V1];
+
+    /// The most recent release of the sBTC smart contracts.
+    pub const LATEST: Self = Self::V1;
+
+    /// The smart contracts introduced in this release, in the order that
+    /// they need to be deployed.
+    pub const fn contracts(self) -> &'static [SmartContract] {
+        match self {
+            Self::V1 => &SMART_CONTRACTS,
+        }
+    }
+
+    /// Whether this release upgrades the contracts deployed during
+    /// bootstrapping.
+    pub const fn is_upgrade(self) -> bool {
+        match self {
+            Self::V1 => false,
+        }
+    }
+
+    /// The releases up to and including this one, from the oldest to the
+    /// most recent.
+    pub fn up_to(self) -> impl Iterator<Item = Self> {
+        Self::ALL
+            .into_iter()
+            .filter(move |version| *version <= self)
+    }
+
+    /// Check whether all the smart contracts introduced in this release
+    /// have been deployed.
+    pub async fn is_deployed<S>(self, stacks: &S, deployer: &StacksAddress) -> Result<bool, Error>
+    where
+        S: StacksInteract + Send + Sync,
+    {
+        for contract in self.contracts() {
+            if !contract.is_deployed(stacks, deployer).await? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+impl std::fmt::Display for SmartContractsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::V1 => write!(f, "v1"),
+        }
+    }
+}
+
+/// The maximum number of deposit requests that may be completed in one
+/// `complete-deposits-wrapper` contract call. This is the maximum length
+/// of the list argument of the clarity function.
+pub const MAX_DEPOSITS_PER_COMPLETE_CALL: usize = 500;
+
+/// The maximum number of withdrawal requests that may be accepted in one
+/// `complete-withdrawals` contract call. This is the maximum length of the
+/// list argument of the clarity function.
+pub const MAX_WITHDRAWALS_PER_ACCEPT_CALL: usize = 600;
+
 /// This struct is used as supplemental data to help validate a request to
 /// sign a contract call transaction.
 ///
@@ -244,9 +338,16 @@ pub enum ContractCall {
     /// Call the `complete-deposit-wrapper` function in the `sbtc-deposit`
     /// smart contract
     CompleteDepositV1(Box<CompleteDepositV1>),
+    /// Call the `complete-deposits-wrapper` function in the
+    /// `sbtc-deposit` smart contract, minting sBTC for several deposit
+    /// requests at once.
+    CompleteDepositsV1(Box<CompleteDepositsV1>),
     /// Call the `accept-withdrawal-request` function in the
     /// `sbtc-withdrawal` smart contract.
     AcceptWithdrawalV1(Box<AcceptWithdrawalV1>),
+    /// Call the `complete-withdrawals` function in the `sbtc-withdrawal`
+    /// smart contract, accepting several withdrawal requests at once.
+    AcceptWithdrawalsV1(Box<AcceptWithdrawalsV1>),
     /// Call the `reject-withdrawal-request` function in the
     /// `sbtc-withdrawal` smart contract.
     RejectWithdrawalV1(Box<RejectWithdrawalV1>),
@@ -259,7 +360,9 @@ impl AsTxPayload for ContractCall {
     fn tx_payload(&self) -> TransactionPayload {
         match self {
             ContractCall::AcceptWithdrawalV1(contract) => contract.tx_payload(),
+            ContractCall::AcceptWithdrawalsV1(contract) => contract.tx_payload(),
             ContractCall::CompleteDepositV1(contract) => contract.tx_payload(),
+            ContractCall::CompleteDepositsV1(contract) => contract.tx_payload(),
             ContractCall::RejectWithdrawalV1(contract) => contract.tx_payload(),
             ContractCall::RotateKeysV1(contract) => contract.tx_payload(),
         }
@@ -269,9 +372,15 @@ impl AsTxPayload for ContractCall {
             ContractCall::AcceptWithdrawalV1(contract) => {
                 AsContractCall::post_conditions(contract.deref())
             }
+            ContractCall::AcceptWithdrawalsV1(contract) => {
+                AsContractCall::post_conditions(contract.deref())
+            }
             ContractCall::CompleteDepositV1(contract) => {
                 AsContractCall::post_conditions(contract.deref())
             }
+            ContractCall::CompleteDepositsV1(contract) => {
+                AsContractCall::post_conditions(contract.deref())
+            }
             ContractCall::RejectWithdrawalV1(contract) => {
                 AsContractCall::post_conditions(contract.deref())
             }
@@ -359,7 +468,8 @@ impl AsContractCall for CompleteDepositV1 {
     ///  2. That the signer has a record of the deposit request in its list
     ///     of deposit requests.
     ///  3. That the signer sweep transaction is on the canonical bitcoin
-    ///     blockchain.
+    ///     blockchain, with at least `sweep_confirmation_depth`
+    ///     confirmations.
     ///  4. That the sweep transaction uses the indicated deposit outpoint
     ///     as an input.
     ///  5. That the recipients in the transaction matches that of the
@@ -372,6 +482,16 @@ impl AsContractCall for CompleteDepositV1 {
     ///     UTXO. This checks that the sweep transaction was generated by
     ///     the signers.
     /// 10. That sBTC has not been minted for the deposit already.
+    /// 11. That the amount to mint does not exceed the amount of the swept
+    ///     deposit request.
+    /// 12. That the amount to mint does not exceed the `max_mint_per_call`
+    ///     limit in the signer's configuration, if there is one.
+    /// 13. That the recipient matches the one in the deposit script of the
+    ///     deposit request. This guards against a coordinator redirecting
+    ///     mints even if our record of the recipient is somehow wrong.
+    ///
+    /// Whenever the recipient or the amount do not match the deposit
+    /// request, a record of the mismatch is written to the database.
     ///
     /// # Notes
     ///
@@ -398,9 +518,11 @@ impl AsContractCall for CompleteDepositV1 {
 
         // Covers points 3-4 & 9
         let fee = self.validate_sweep_tx(ctx, req_ctx).await?;
-        let db = ctx.get_storage();
-        // Covers points 1-2 & 5-8
-        self.validate_vars(&db, req_ctx, fee).await
+        let db = ctx.get_storage_mut();
+        let max_mint = ctx.config().signer.max_mint_per_call;
+        // Covers points 1-2, 5-8 & 11-13
+        self.validate_vars(&db, ctx.state(), req_ctx, fee, max_mint)
+            .await
     }
 }
 
@@ -419,12 +541,25 @@ impl CompleteDepositV1 {
     /// 6. That the amount to mint is above the dust amount.
     /// 7. That the fee matches the expected assessed fee for the outpoint.
     /// 8. That the fee is less than the specified max-fee.
+    /// 11. That the amount to mint does not exceed the amount of the swept
+    ///     deposit request.
+    /// 12. That the amount to mint does not exceed the configured maximum.
+    /// 13. That the recipient matches the one in the deposit script.
     ///
     /// The `fee` input variable is our calculation of the assessed fee for
-    /// the deposit.
-    async fn validate_vars<S>(&self, db: &S, req_ctx: &ReqContext, fee: Amount) -> Result<(), Error>
+    /// the deposit, and `max_mint` is the configured maximum amount to
+    /// mint in a single contract call, if any. The deposit scripts are
+    /// parsed through the cache in the given signer `state`.
+    async fn validate_vars<S>(
+        &self,
+        db: &S,
+        state: &SignerState,
+        req_ctx: &ReqContext,
+        fee: Amount,
+        max_mint: Option<u64>,
+    ) -> Result<(), Error>
     where
-        S: DbRead + Send + Sync,
+        S: DbRead + DbWrite + Send + Sync,
     {
         // 1. That the smart contract deployer matches the deployer in our
         //    context.
@@ -439,16 +574,61 @@ impl CompleteDepositV1 {
             return Err(DepositErrorMsg::RequestMissing.into_error(req_ctx, self));
         };
 
+        // Before checking anything else, record whether the recipient or
+        // the amount differ from the deposit request. The recipient is
+        // derived again from the deposit script, which is what the
+        // depositor actually committed to.
+        let expected_recipient = state
+            .stored_deposit_scripts(&deposit_request)
+            .map(|scripts| StacksPrincipal::from(scripts.deposit.recipient.clone()));
+        let expected_amount = deposit_request.amount.saturating_sub(fee.to_sat());
+
+        let script_recipient_matches = expected_recipient
+            .as_ref()
+            .is_some_and(|recipient| recipient.deref() == &self.recipient);
+        let is_mismatch = !script_recipient_matches
+            || &self.recipient != deposit_request.recipient.deref()
+            || fee.to_sat() + self.amount != deposit_request.amount;
+
+        if is_mismatch {
+            let mismatch = MintMismatch {
+                txid,
+                output_index,
+                chain_tip: req_ctx.chain_tip.block_hash,
+                origin: req_ctx.origin,
+                recipient: self.recipient.clone().into(),
+                expected_recipient,
+                amount: self.amount,
+                expected_amount,
+            };
+            db.write_mint_mismatch(&mismatch).await?;
+        }
+
         // 5. Check that the recipients in the transaction matches that of
         //    the deposit request.
         if &self.recipient != deposit_request.recipient.deref() {
             return Err(DepositErrorMsg::RecipientMismatch.into_error(req_ctx, self));
         }
+        // 13. Check that the recipient matches the one in the deposit
+        //     script.
+        if !script_recipient_matches {
+            return Err(DepositErrorMsg::ScriptRecipientMismatch.into_error(req_ctx, self));
+        }
         // 6. Check that the amount to mint is above the dust amount
         //    amount.
         if self.amount < DEPOSIT_DUST_LIMIT {
             return Err(DepositErrorMsg::AmountBelowDustLimit.into_error(req_ctx, self));
         }
+        // 11. Check that the amount to mint does not exceed the amount
+        //     that was swept in for the deposit request.
+        if self.amount > deposit_request.amount {
+            return Err(DepositErrorMsg::AmountExceedsDeposit.into_error(req_ctx, self));
+        }
+        // 12. Check that the amount to mint does not exceed the maximum
+        //     that this signer is willing to mint in one contract call.
+        if max_mint.is_some_and(|max_mint| self.amount > max_mint) {
+            return Err(DepositErrorMsg::AmountAboveMaxMint.into_error(req_ctx, self));
+        }
         // 7. That the fee matches the expected assessed fee for the outpoint.
         if fee.to_sat() + self.amount != deposit_request.amount {
             return Err(DepositErrorMsg::IncorrectFee.into_error(req_ctx, self));
@@ -468,29 +648,28 @@ impl CompleteDepositV1 {
     /// Specifically, this function checks the following points (from the
     /// docs of [`CompleteDepositV1::validate`]):
     /// 3. Check that the signer sweep transaction is on the canonical
-    ///    bitcoin blockchain.
+    ///    bitcoin blockchain, with at least `sweep_confirmation_depth`
+    ///    confirmations.
     /// 4. Check that the sweep transaction uses the indicated deposit
     ///    outpoint as an input.
     /// 9. That the first input into the sweep transaction is the signers'
     ///    UTXO.
+    ///
+    /// It returns the fee assessed to the deposit, which is read from the
+    /// same record in storage that the coordinator used to build the
+    /// contract call. Only sweeps recorded before we started keeping the
+    /// assessed fees are fetched from bitcoin-core.
     async fn validate_sweep_tx<C>(&self, ctx: &C, req_ctx: &ReqContext) -> Result<Amount, Error>
     where
         C: Context + Send + Sync,
     {
         let db = ctx.get_storage();
-        let rpc = ctx.get_bitcoin_client();
-        // First we check that bitcoin-core has a record of the transaction
-        // where we think it should be.
-        let txid = &self.sweep_txid;
-        let Some(sweep_tx) = rpc.get_tx_info(txid, &self.sweep_block_hash).await? else {
-            return Err(DepositErrorMsg::SweepTransactionMissing.into_error(req_ctx, self));
-        };
         // 3. Check that the signer sweep transaction is on the canonical
         //    bitcoin blockchain.
         //
-        // From the above, we know that the sweep transaction is in the
-        // `sweep_block_hash`. Now we just need to check that this block is
-        // on the canonical bitcoin blockchain.
+        // Whether the sweep transaction is in the `sweep_block_hash` is
+        // checked below. Here we just need to check that this block is on
+        // the canonical bitcoin blockchain.
         let block_ref = BitcoinBlockRef {
             block_hash: self.sweep_block_hash,
             block_height: self.sweep_block_height,
@@ -502,6 +681,28 @@ impl CompleteDepositV1 {
         if !in_canonical_bitcoin_blockchain {
             return Err(DepositErrorMsg::SweepTransactionReorged.into_error(req_ctx, self));
         }
+        // We also need the sweep to be deep enough that a shallow reorg
+        // cannot undo it after we mint.
+        let depth = ctx.config().signer.sweep_confirmation_depth.get() as u64;
+        if req_ctx.chain_tip.confirmations(self.sweep_block_height) < depth {
+            return Err(DepositErrorMsg::SweepTransactionTooShallow.into_error(req_ctx, self));
+        }
+        // We only record an assessed fee for a deposit input of a
+        // transaction that we observed in the given block and whose first
+        // input is the signers' UTXO, so a recorded fee covers checks 4
+        // and 9 too.
+        let assessed_fee = db
+            .get_deposit_assessed_fee(&self.sweep_txid, &self.sweep_block_hash, &self.outpoint)
+            .await?;
+        if let Some(fee) = assessed_fee {
+            return Ok(Amount::from_sat(fee));
+        }
+        // Otherwise we check that bitcoin-core has a record of the
+        // transaction where we think it should be.
+        let txid = &self.sweep_txid;
+        let Some(sweep_tx) = get_tx_info_cached(ctx, txid, &self.sweep_block_hash).await? else {
+            return Err(DepositErrorMsg::SweepTransactionMissing.into_error(req_ctx, self));
+        };
         // 4. Check that the sweep transaction uses the indicated deposit
         //    outpoint as an input.
         //
@@ -578,6 +779,14 @@ pub enum DepositErrorMsg {
     /// that condition here before minting.
     #[error("the amount to mint is below the dust limit in the smart contract")]
     AmountBelowDustLimit,
+    /// The amount to mint is above the maximum amount that this signer is
+    /// configured to mint in a single contract call.
+    #[error("the amount to mint is above the configured maximum mint per call")]
+    AmountAboveMaxMint,
+    /// The amount to mint is greater than the amount of the deposit
+    /// request that was swept in.
+    #[error("the amount to mint exceeds the amount of the swept deposit request")]
+    AmountExceedsDeposit,
     /// The smart contract deployer is fixed, so this should always match.
     #[error("the deployer in the transaction does not match the expected deployer")]
     DeployerMismatch,
@@ -602,6 +811,10 @@ pub enum DepositErrorMsg {
     /// records.
     #[error("recipient did not match the recipient in our deposit request")]
     RecipientMismatch,
+    /// The recipient did not match the recipient in the deposit script of
+    /// our deposit request.
+    #[error("recipient did not match the recipient in the deposit script")]
+    ScriptRecipientMismatch,
     /// We do not have a record of the deposit request in our list of
     /// pending and accepted deposit requests.
     #[error("no record of deposit request in pending and accepted deposit requests")]
@@ -614,6 +827,11 @@ pub enum DepositErrorMsg {
     /// transaction now will likely lead to a failed stacks transaction.
     #[error("sweep transaction has been affected by a reorg")]
     SweepTransactionReorged,
+    /// The sweep transaction does not have enough confirmations yet.
+    /// Submitting this transaction now risks minting sBTC for a sweep
+    /// that is undone by a shallow reorg.
+    #[error("sweep transaction does not have enough confirmations")]
+    SweepTransactionTooShallow,
 }
 
 impl DepositErrorMsg {
@@ -626,6 +844,210 @@ impl DepositErrorMsg {
     }
 }
 
+/// This struct is used to generate a properly formatted Stacks transaction
+/// for calling the complete-deposits-wrapper function in the sbtc-deposit
+/// smart contract, which mints sBTC for each of the deposit requests.
+///
+/// The contract call is all-or-nothing: if completing any one of the
+/// deposit requests fails then the whole transaction fails.
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct CompleteDepositsV1 {
+    /// The deposit requests to complete. Each of them is validated just
+    /// like a standalone `complete-deposit-wrapper` contract call.
+    pub deposits: Vec<CompleteDepositV1>,
+    /// The address that deployed the contract.
+    pub deployer: StacksAddress,
+}
+
+impl AsTxPayload for CompleteDepositsV1 {
+    fn tx_payload(&self) -> TransactionPayload {
+        TransactionPayload::ContractCall(self.as_contract_call())
+    }
+    fn post_conditions(&self) -> StacksTxPostConditions {
+        AsContractCall::post_conditions(self)
+    }
+}
+
+impl AsContractCall for CompleteDepositsV1 {
+    const CONTRACT_NAME: &'static str = "sbtc-deposit";
+    const FUNCTION_NAME: &'static str = "complete-deposits-wrapper";
+
+    fn deployer_address(&self) -> StacksAddress {
+        self.deployer
+    }
+    /// The arguments to the contract call function
+    ///
+    /// # Notes
+    ///
+    /// The signature to this function is:
+    ///
+    ///   (deposits (list 500 {txid: (buff 32), vout-index: uint,
+    ///     amount: uint, recipient: principal, burn-hash: (buff 32),
+    ///     burn-height: uint, sweep-txid: (buff 32)}))
+    fn as_contract_args(&self) -> Vec<ClarityValue> {
+        let deposits = self
+            .deposits
+            .iter()
+            .map(|deposit| {
+                let txid_data = deposit.outpoint.txid.to_le_bytes().to_vec();
+                let txid = BuffData { data: txid_data };
+                let sweep_txid_data = deposit.sweep_txid.to_le_bytes().to_vec();
+                let sweep_txid = BuffData { data: sweep_txid_data };
+                let burn_hash_data = deposit.sweep_block_hash.to_le_bytes().to_vec();
+                let burn_hash = BuffData { data: burn_hash_data };
+
+                let data = vec![
+                    (
+                        ClarityName::from("txid"),
+                        ClarityValue::Sequence(SequenceData::Buffer(txid)),
+                    ),
+                    (
+                        ClarityName::from("vout-index"),
+                        ClarityValue::UInt(deposit.outpoint.vout as u128),
+                    ),
+                    (
+                        ClarityName::from("amount"),
+                        ClarityValue::UInt(deposit.amount as u128),
+                    ),
+                    (
+                        ClarityName::from("recipient"),
+                        ClarityValue::Principal(deposit.recipient.clone()),
+                    ),
+                    (
+                        ClarityName::from("burn-hash"),
+                        ClarityValue::Sequence(SequenceData::Buffer(burn_hash)),
+                    ),
+                    (
+                        ClarityName::from("burn-height"),
+                        ClarityValue::UInt(deposit.sweep_block_height.into()),
+                    ),
+                    (
+                        ClarityName::from("sweep-txid"),
+                        ClarityValue::Sequence(SequenceData::Buffer(sweep_txid)),
+                    ),
+                ];
+                // A Result::Err is only returned if there are duplicate
+                // names in the tuple, or if the tuple is too large, which
+                // is not the case here.
+                TupleData::from_data(data)
+                    .map(ClarityValue::Tuple)
+                    .expect("Error: legal TupleData marked as invalid")
+            })
+            .collect();
+
+        // A Result::Err is returned whenever the list elements have
+        // different types or the list is too large. The recipient
+        // principals may differ in type, but clarity admits principals of
+        // either type in the same list, and the size of the list is
+        // bounded by validation.
+        let list = ClarityValue::cons_list_unsanitized(deposits)
+            .expect("Error: legal ListData marked as invalid");
+
+        vec![list]
+    }
+    /// Validates that the complete-deposits-wrapper contract call
+    /// satisfies the following criteria:
+    ///
+    /// 1. That there is at least one deposit request and no more than
+    ///    [`MAX_DEPOSITS_PER_COMPLETE_CALL`] of them.
+    /// 2. That the smart contract deployer matches the deployer in our
+    ///    context, and that it matches the deployer of each of the
+    ///    included deposit requests.
+    /// 3. That each deposit request is included at most once.
+    /// 4. That each included deposit request passes the validation in
+    ///    [`CompleteDepositV1::validate`].
+    async fn validate<C>(&self, ctx: &C, req_ctx: &ReqContext) -> Result<(), Error>
+    where
+        C: Context + Send + Sync,
+    {
+        // 1. That there is at least one deposit request and no more than
+        //    the maximum allowed by the contract.
+        let num_deposits = self.deposits.len();
+        if num_deposits == 0 || num_deposits > MAX_DEPOSITS_PER_COMPLETE_CALL {
+            return Err(CompleteDepositsErrorMsg::InvalidBatchSize.into_error(req_ctx, self));
+        }
+
+        // 2. That the smart contract deployer matches the deployer in our
+        //    context.
+        let deployer_mismatch = self.deployer != req_ctx.deployer
+            || self.deposits.iter().any(|d| d.deployer != self.deployer);
+        if deployer_mismatch {
+            return Err(CompleteDepositsErrorMsg::DeployerMismatch.into_error(req_ctx, self));
+        }
+
+        // 3. That each deposit request is included at most once.
+        let outpoints: BTreeSet<OutPoint> = self
+            .deposits
+            .iter()
+            .map(|deposit| deposit.outpoint)
+            .collect();
+        if outpoints.len() != num_deposits {
+            return Err(CompleteDepositsErrorMsg::DuplicateRequest.into_error(req_ctx, self));
+        }
+
+        // 4. That each deposit request is valid on its own.
+        for deposit in self.deposits.iter() {
+            deposit.validate(ctx, req_ctx).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A struct for a validation error containing all the necessary context.
+#[derive(Debug)]
+pub struct DepositsValidationError {
+    /// The specific error that happened during validation.
+    pub error: CompleteDepositsErrorMsg,
+    /// The additional information that was used when trying to validate
+    /// the `complete-deposits-wrapper` contract call. This includes the
+    /// public key of the signer that was attempting to generate the
+    /// `complete-deposits-wrapper` transaction.
+    pub context: ReqContext,
+    /// The specific transaction that was being validated.
+    pub tx: CompleteDepositsV1,
+}
+
+impl std::fmt::Display for DepositsValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl std::error::Error for DepositsValidationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The responses for validation of the batch level checks of a
+/// complete-deposits-wrapper smart contract call transaction. Failures of
+/// the checks on the individual deposit requests are reported with a
+/// [`DepositErrorMsg`].
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CompleteDepositsErrorMsg {
+    /// The smart contract deployer is fixed, so this should always match.
+    #[error("the deployer in the transaction does not match the expected deployer")]
+    DeployerMismatch,
+    /// A deposit request may only be completed once.
+    #[error("a deposit request is included more than once")]
+    DuplicateRequest,
+    /// The contract call must include at least one deposit request and no
+    /// more than the contract allows.
+    #[error("the number of deposit requests is zero or exceeds the maximum")]
+    InvalidBatchSize,
+}
+
+impl CompleteDepositsErrorMsg {
+    fn into_error(self, ctx: &ReqContext, tx: &CompleteDepositsV1) -> Error {
+        Error::DepositsValidation(Box::new(DepositsValidationError {
+            error: self,
+            context: *ctx,
+            tx: tx.clone(),
+        }))
+    }
+}
+
 /// This struct is used to generate a properly formatted Stacks transaction
 /// for calling the accept-withdrawal-request function in the
 /// sbtc-withdrawal smart contract.
@@ -708,7 +1130,8 @@ impl AsContractCall for AcceptWithdrawalV1 {
     ///  2. That the signer has a record of the withdrawal request in its
     ///     list of pending and accepted withdrawal requests.
     ///  3. That the signer bitcoin transaction sweeping out the users'
-    ///     funds is on the canonical bitcoin blockchain.
+    ///     funds is on the canonical bitcoin blockchain, with at least
+    ///     `sweep_confirmation_depth` confirmations.
     ///  4. That the sweep transaction has the UTXO indicated by the
     ///     `outpoint`.
     ///  5. The `scriptPubKey` of the UTXO matches the one in the
@@ -734,10 +1157,10 @@ impl AsContractCall for AcceptWithdrawalV1 {
             return Err(WithdrawalErrorMsg::RequestCompleted.into_error(req_ctx, self));
         }
 
-        // Covers points 3-4 & 8-9
-        let tx_out = self.validate_sweep(ctx, req_ctx).await?;
-        // Covers points 1-2 & 5-7, & 10
-        self.validate_utxo(ctx, req_ctx, tx_out).await
+        // Covers points 3-4 & 9
+        let (tx_out, assessed_fee) = self.validate_sweep(ctx, req_ctx).await?;
+        // Covers points 1-2 & 5-8, & 10
+        self.validate_utxo(ctx, req_ctx, tx_out, assessed_fee).await
     }
 }
 
@@ -757,11 +1180,14 @@ impl AcceptWithdrawalV1 {
     ///  6. The `amount` of the UTXO matches the one in the withdrawal
     ///     request.
     ///  7. That the fee is less than the desired max-fee.
+    ///  8. That the fee matches the expected assessed fee for the output,
+    ///     capped at the max-fee when the signers subsidized the rest.
     async fn validate_utxo<C>(
         &self,
         ctx: &C,
         req_ctx: &ReqContext,
         tx_out: TxOut,
+        assessed_fee: Amount,
     ) -> Result<(), Error>
     where
         C: Context + Send + Sync,
@@ -818,6 +1244,14 @@ impl AcceptWithdrawalV1 {
         if self.tx_fee > report.max_fee {
             return Err(WithdrawalErrorMsg::FeeTooHigh.into_error(req_ctx, self));
         }
+        // 8. That the fee matches the expected assessed fee for the output.
+        //
+        // Any part of the assessed fee above the max-fee was subsidized
+        // by the signers when they validated the sweep transaction, so the
+        // user is only charged their max-fee.
+        if assessed_fee.to_sat().min(report.max_fee) != self.tx_fee {
+            return Err(WithdrawalErrorMsg::IncorrectFee.into_error(req_ctx, self));
+        }
 
         Ok(())
     }
@@ -826,22 +1260,27 @@ impl AcceptWithdrawalV1 {
     /// Specifically, this function checks the following points (from the
     /// docs of [`AcceptWithdrawalV1::validate`]):
     /// 3. That the signer bitcoin transaction sweeping out the users'
-    ///    funds is on the canonical bitcoin blockchain.
+    ///    funds is on the canonical bitcoin blockchain, with at least
+    ///    `sweep_confirmation_depth` confirmations.
     /// 4. That the sweep transaction has the UTXO indicated by the
     ///    outpoint.
-    /// 8. That the fee matches the expected assessed fee for the output.
     /// 9. That the first input into the sweep transaction is the signers'
     ///    UTXO.
-    async fn validate_sweep<C>(&self, ctx: &C, req_ctx: &ReqContext) -> Result<TxOut, Error>
+    ///
+    /// The fee assessed to the output is returned along with the output.
+    async fn validate_sweep<C>(
+        &self,
+        ctx: &C,
+        req_ctx: &ReqContext,
+    ) -> Result<(TxOut, Amount), Error>
     where
         C: Context + Send + Sync,
     {
         let db = ctx.get_storage();
-        let rpc = ctx.get_bitcoin_client();
         // First we check that bitcoin-core has a record of the transaction
         // where we think it should be.
         let txid = &self.outpoint.txid;
-        let Some(sweep_tx) = rpc.get_tx_info(txid, &self.sweep_block_hash).await? else {
+        let Some(sweep_tx) = get_tx_info_cached(ctx, txid, &self.sweep_block_hash).await? else {
             return Err(WithdrawalErrorMsg::SweepTransactionMissing.into_error(req_ctx, self));
         };
         // 3. That the signer bitcoin transaction sweeping out the users'
@@ -861,6 +1300,10 @@ impl AcceptWithdrawalV1 {
         if !in_canonical_bitcoin_blockchain {
             return Err(WithdrawalErrorMsg::SweepTransactionReorged.into_error(req_ctx, self));
         }
+        let depth = ctx.config().signer.sweep_confirmation_depth.get() as u64;
+        if req_ctx.chain_tip.confirmations(self.sweep_block_height) < depth {
+            return Err(WithdrawalErrorMsg::SweepTransactionTooShallow.into_error(req_ctx, self));
+        }
         // 4. That the sweep transaction has the UTXO indicated by the
         //    outpoint.
         //
@@ -870,15 +1313,10 @@ impl AcceptWithdrawalV1 {
         // b) When the output index points to an output that is not in
         //    the transaction.
         // Both cases indicate that the UTXO is missing from the transaction.
-        let Some(expected_fee) = sweep_tx.assess_output_fee(self.outpoint.vout as usize) else {
+        let Some(assessed_fee) = sweep_tx.assess_output_fee(self.outpoint.vout as usize) else {
             return Err(WithdrawalErrorMsg::UtxoMissingFromSweep.into_error(req_ctx, self));
         };
 
-        // 8. That the fee matches the expected assessed fee for the output.
-        if expected_fee.to_sat() != self.tx_fee {
-            return Err(WithdrawalErrorMsg::IncorrectFee.into_error(req_ctx, self));
-        };
-
         // 9. That the first input into the sweep transaction is the
         //    signers' UTXO.
         //
@@ -896,12 +1334,166 @@ impl AcceptWithdrawalV1 {
             return Err(WithdrawalErrorMsg::InvalidSweep.into_error(req_ctx, self));
         }
 
-        sweep_tx
+        let tx_out = sweep_tx
             .tx
             .output
             .get(self.outpoint.vout as usize)
             .cloned()
-            .ok_or_else(|| WithdrawalErrorMsg::UtxoMissingFromSweep.into_error(req_ctx, self))
+            .ok_or_else(|| WithdrawalErrorMsg::UtxoMissingFromSweep.into_error(req_ctx, self))?;
+
+        Ok((tx_out, assessed_fee))
+    }
+}
+
+/// This struct is used to generate a properly formatted Stacks transaction
+/// for calling the complete-withdrawals function in the sbtc-withdrawal
+/// smart contract, where each of the withdrawal requests is accepted.
+///
+/// The contract call is all-or-nothing: if accepting any one of the
+/// withdrawal requests fails then the whole transaction fails.
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct AcceptWithdrawalsV1 {
+    /// The withdrawal requests to accept. Each of them is validated just
+    /// like a standalone `accept-withdrawal-request` contract call.
+    pub withdrawals: Vec<AcceptWithdrawalV1>,
+    /// The address that deployed the contract.
+    pub deployer: StacksAddress,
+}
+
+impl AsTxPayload for AcceptWithdrawalsV1 {
+    fn tx_payload(&self) -> TransactionPayload {
+        TransactionPayload::ContractCall(self.as_contract_call())
+    }
+    fn post_conditions(&self) -> StacksTxPostConditions {
+        AsContractCall::post_conditions(self)
+    }
+}
+
+impl AsContractCall for AcceptWithdrawalsV1 {
+    const CONTRACT_NAME: &'static str = "sbtc-withdrawal";
+    const FUNCTION_NAME: &'static str = "complete-withdrawals";
+
+    fn deployer_address(&self) -> StacksAddress {
+        self.deployer
+    }
+    /// The arguments to the contract call function
+    ///
+    /// # Notes
+    ///
+    /// The signature to this function is:
+    ///
+    ///   (withdrawals (list 600 {request-id: uint, status: bool,
+    ///     signer-bitmap: uint, bitcoin-txid: (optional (buff 32)),
+    ///     output-index: (optional uint), fee: (optional uint),
+    ///     burn-hash: (buff 32), burn-height: uint,
+    ///     sweep-txid: (optional (buff 32))}))
+    fn as_contract_args(&self) -> Vec<ClarityValue> {
+        let some = |value: ClarityValue| {
+            ClarityValue::Optional(OptionalData { data: Some(Box::new(value)) })
+        };
+
+        let withdrawals = self
+            .withdrawals
+            .iter()
+            .map(|withdrawal| {
+                let txid_data = withdrawal.outpoint.txid.to_le_bytes().to_vec();
+                let txid =
+                    ClarityValue::Sequence(SequenceData::Buffer(BuffData { data: txid_data }));
+                let burn_hash_data = withdrawal.sweep_block_hash.to_le_bytes().to_vec();
+                let burn_hash = BuffData { data: burn_hash_data };
+
+                let data = vec![
+                    (
+                        ClarityName::from("request-id"),
+                        ClarityValue::UInt(withdrawal.id.request_id as u128),
+                    ),
+                    (ClarityName::from("status"), ClarityValue::Bool(true)),
+                    // This is the signer bitmap field. See the following
+                    // for more on why this is fixed at zero.
+                    // https://github.com/stacks-network/sbtc/issues/1505
+                    (ClarityName::from("signer-bitmap"), ClarityValue::UInt(0)),
+                    (ClarityName::from("bitcoin-txid"), some(txid.clone())),
+                    (
+                        ClarityName::from("output-index"),
+                        some(ClarityValue::UInt(withdrawal.outpoint.vout as u128)),
+                    ),
+                    (
+                        ClarityName::from("fee"),
+                        some(ClarityValue::UInt(withdrawal.tx_fee as u128)),
+                    ),
+                    (
+                        ClarityName::from("burn-hash"),
+                        ClarityValue::Sequence(SequenceData::Buffer(burn_hash)),
+                    ),
+                    (
+                        ClarityName::from("burn-height"),
+                        ClarityValue::UInt(withdrawal.sweep_block_height.into()),
+                    ),
+                    (ClarityName::from("sweep-txid"), some(txid)),
+                ];
+                // A Result::Err is only returned if there are duplicate
+                // names in the tuple, or if the tuple is too large, which
+                // is not the case here.
+                TupleData::from_data(data)
+                    .map(ClarityValue::Tuple)
+                    .expect("Error: legal TupleData marked as invalid")
+            })
+            .collect();
+
+        // A Result::Err is returned whenever the list elements have
+        // different types or the list is too large. All elements have the
+        // same type and the size of the list is bounded by validation.
+        let list = ClarityValue::cons_list_unsanitized(withdrawals)
+            .expect("Error: legal ListData marked as invalid");
+
+        vec![list]
+    }
+    /// Validates that the complete-withdrawals contract call satisfies
+    /// the following criteria:
+    ///
+    /// 1. That there is at least one withdrawal request and no more than
+    ///    [`MAX_WITHDRAWALS_PER_ACCEPT_CALL`] of them.
+    /// 2. That the smart contract deployer matches the deployer in our
+    ///    context, and that it matches the deployer of each of the
+    ///    included withdrawal requests.
+    /// 3. That each withdrawal request is included at most once.
+    /// 4. That each included withdrawal request passes the validation in
+    ///    [`AcceptWithdrawalV1::validate`].
+    async fn validate<C>(&self, ctx: &C, req_ctx: &ReqContext) -> Result<(), Error>
+    where
+        C: Context + Send + Sync,
+    {
+        // 1. That there is at least one withdrawal request and no more
+        //    than the maximum allowed by the contract.
+        let num_withdrawals = self.withdrawals.len();
+        if num_withdrawals == 0 || num_withdrawals > MAX_WITHDRAWALS_PER_ACCEPT_CALL {
+            return Err(AcceptWithdrawalsErrorMsg::InvalidBatchSize.into_error(req_ctx, self));
+        }
+
+        // 2. That the smart contract deployer matches the deployer in our
+        //    context.
+        let deployer_mismatch = self.deployer != req_ctx.deployer
+            || self.withdrawals.iter().any(|w| w.deployer != self.deployer);
+        if deployer_mismatch {
+            return Err(AcceptWithdrawalsErrorMsg::DeployerMismatch.into_error(req_ctx, self));
+        }
+
+        // 3. That each withdrawal request is included at most once.
+        let request_ids: BTreeSet<u64> = self
+            .withdrawals
+            .iter()
+            .map(|withdrawal| withdrawal.id.request_id)
+            .collect();
+        if request_ids.len() != num_withdrawals {
+            return Err(AcceptWithdrawalsErrorMsg::DuplicateRequest.into_error(req_ctx, self));
+        }
+
+        // 4. That each withdrawal request is valid on its own.
+        for withdrawal in self.withdrawals.iter() {
+            withdrawal.validate(ctx, req_ctx).await?;
+        }
+
+        Ok(())
     }
 }
 
@@ -932,6 +1524,32 @@ impl std::error::Error for WithdrawalAcceptValidationError {
     }
 }
 
+/// A struct for a validation error containing all the necessary context.
+#[derive(Debug)]
+pub struct WithdrawalsAcceptValidationError {
+    /// The specific error that happened during validation.
+    pub error: AcceptWithdrawalsErrorMsg,
+    /// The additional information that was used when trying to validate
+    /// the `complete-withdrawals` contract call. This includes the public
+    /// key of the signer that was attempting to generate the
+    /// `complete-withdrawals` transaction.
+    pub context: ReqContext,
+    /// The specific transaction that was being validated.
+    pub tx: AcceptWithdrawalsV1,
+}
+
+impl std::fmt::Display for WithdrawalsAcceptValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl std::error::Error for WithdrawalsAcceptValidationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
 /// A struct for a validation error containing all the necessary context.
 #[derive(Debug)]
 pub struct WithdrawalRejectValidationError {
@@ -1005,6 +1623,9 @@ pub enum WithdrawalErrorMsg {
     /// transaction now will likely lead to a failed stacks transaction.
     #[error("sweep transaction has been affected by a reorg")]
     SweepTransactionReorged,
+    /// The sweep transaction does not have enough confirmations yet.
+    #[error("sweep transaction does not have enough confirmations")]
+    SweepTransactionTooShallow,
     /// The withdrawal outpoint is missing from the indicated sweep
     /// transaction.
     #[error("withdrawal outpoint is missing from the indicated sweep transaction")]
@@ -1021,6 +1642,34 @@ impl WithdrawalErrorMsg {
     }
 }
 
+/// The responses for validation of the batch level checks of a
+/// complete-withdrawals smart contract call transaction. Failures of the
+/// checks on the individual withdrawal requests are reported with a
+/// [`WithdrawalErrorMsg`].
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum AcceptWithdrawalsErrorMsg {
+    /// The smart contract deployer is fixed, so this should always match.
+    #[error("the deployer in the transaction does not match the expected deployer")]
+    DeployerMismatch,
+    /// A withdrawal request may only be accepted once.
+    #[error("a withdrawal request is included more than once")]
+    DuplicateRequest,
+    /// The contract call must include at least one withdrawal request and
+    /// no more than the contract allows.
+    #[error("the number of withdrawal requests is zero or exceeds the maximum")]
+    InvalidBatchSize,
+}
+
+impl AcceptWithdrawalsErrorMsg {
+    fn into_error(self, ctx: &ReqContext, tx: &AcceptWithdrawalsV1) -> Error {
+        Error::WithdrawalsAcceptValidation(Box::new(WithdrawalsAcceptValidationError {
+            error: self,
+            context: *ctx,
+            tx: tx.clone(),
+        }))
+    }
+}
+
 /// The responses for validation of a reject-withdrawal-request smart
 /// contract call transaction.
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
@@ -1053,6 +1702,14 @@ pub enum WithdrawalRejectErrorMsg {
     /// Withdrawal request unconfirmed
     #[error("Withdrawal request unconfirmed")]
     RequestUnconfirmed,
+    /// A sweep transaction fulfilling the withdrawal request has been
+    /// confirmed according to bitcoin-core.
+    #[error("bitcoin-core has a confirmed sweep fulfilling the withdrawal request")]
+    SweepConfirmed,
+    /// A sweep transaction fulfilling the withdrawal request is in the
+    /// mempool of bitcoin-core.
+    #[error("bitcoin-core has a sweep fulfilling the withdrawal request in its mempool")]
+    SweepInMempool,
 }
 
 impl WithdrawalRejectErrorMsg {
@@ -1128,6 +1785,11 @@ impl AsContractCall for RejectWithdrawalV1 {
     /// 7. Whether we need to worry about forks causing the withdrawal to
     ///    be confirmed by a sweep that was broadcast changing the status
     ///    of the request from rejected to accepted.
+    /// 8. Whether bitcoin-core knows of a sweep transaction fulfilling the
+    ///    withdrawal request, either in its mempool or confirmed.
+    ///
+    /// Points 6-8 are checked by [`validate_withdrawal_rejection`], which
+    /// the coordinator also runs before asking for signatures.
     async fn validate<C>(&self, ctx: &C, req_ctx: &ReqContext) -> Result<(), Error>
     where
         C: Context + Send + Sync,
@@ -1179,33 +1841,84 @@ impl AsContractCall for RejectWithdrawalV1 {
         let blocks_observed = req_ctx
             .chain_tip
             .block_height
-            .saturating_sub(report.bitcoin_block_height);
+            .blocks_since(report.bitcoin_block_height);
 
-        if blocks_observed <= WITHDRAWAL_BLOCKS_EXPIRY.into() {
+        if blocks_observed <= WITHDRAWAL_BLOCKS_EXPIRY {
             return Err(WithdrawalRejectErrorMsg::RequestNotFinal.into_error(req_ctx, self));
         }
 
-        // 6. Check whether the withdrawal request may be serviced by a
-        //    sweep transaction that may be in the mempool.
-        let withdrawal_is_inflight = db
-            .is_withdrawal_inflight(&self.id, &req_ctx.chain_tip.block_hash)
-            .await?;
-        if withdrawal_is_inflight {
-            return Err(WithdrawalRejectErrorMsg::RequestBeingFulfilled.into_error(req_ctx, self));
+        // 6-8. Check whether the withdrawal request may be fulfilled by
+        //      a sweep transaction, in the mempool or otherwise.
+        if let Some(error) =
+            validate_withdrawal_rejection(ctx, &self.id, &req_ctx.chain_tip).await?
+        {
+            return Err(error.into_error(req_ctx, self));
         }
 
-        // 7. Check whether the withdrawal request is still active, as in
-        //    it could still be fulfilled.
-        let withdrawal_is_active = db
-            .is_withdrawal_active(&self.id, &req_ctx.chain_tip, WITHDRAWAL_MIN_CONFIRMATIONS)
-            .await?;
+        Ok(())
+    }
+}
 
-        if withdrawal_is_active {
-            return Err(WithdrawalRejectErrorMsg::RequestStillActive.into_error(req_ctx, self));
-        }
+/// Check whether it is safe to reject the identified withdrawal request
+/// as of the given bitcoin chain tip, returning the reason that it is not
+/// safe if there is one.
+///
+/// Both the coordinator and each signer validating a
+/// `reject-withdrawal-request` sign request run these checks, using their
+/// own database and bitcoin-core:
+///
+/// 1. Whether the withdrawal request is being serviced by a sweep
+///    transaction that could be in the mempool, according to the
+///    database.
+/// 2. Whether we need to worry about forks causing the withdrawal to be
+///    confirmed by a sweep that was broadcast.
+/// 3. Whether any of the sweep transactions that we know to include an
+///    output for the withdrawal request is in the mempool of bitcoin-core
+///    or confirmed on its canonical bitcoin blockchain.
+pub async fn validate_withdrawal_rejection<C>(
+    ctx: &C,
+    id: &QualifiedRequestId,
+    chain_tip: &BitcoinBlockRef,
+) -> Result<Option<WithdrawalRejectErrorMsg>, Error>
+where
+    C: Context + Send + Sync,
+{
+    let db = ctx.get_storage();
+
+    // 1. Check whether the withdrawal request may be serviced by a sweep
+    //    transaction that may be in the mempool.
+    if db.is_withdrawal_inflight(id, &chain_tip.block_hash).await? {
+        return Ok(Some(WithdrawalRejectErrorMsg::RequestBeingFulfilled));
+    }
 
-        Ok(())
+    // 2. Check whether the withdrawal request is still active, as in it
+    //    could still be fulfilled.
+    let withdrawal_is_active = db
+        .is_withdrawal_active(id, chain_tip, WITHDRAWAL_MIN_CONFIRMATIONS)
+        .await?;
+    if withdrawal_is_active {
+        return Ok(Some(WithdrawalRejectErrorMsg::RequestStillActive));
+    }
+
+    // 3. Ask bitcoin-core about each of the sweep transactions that we
+    //    know fulfill the withdrawal request. Our database may be missing
+    //    some information, but bitcoin-core has the final word on what is
+    //    in the mempool and what has been confirmed.
+    let bitcoin_client = ctx.get_bitcoin_client();
+    for txid in db.get_withdrawal_sweep_txids(id).await? {
+        if bitcoin_client.get_mempool_entry(&txid).await?.is_some() {
+            return Ok(Some(WithdrawalRejectErrorMsg::SweepInMempool));
+        }
+        let confirmations = bitcoin_client
+            .get_tx(&txid)
+            .await?
+            .and_then(|response| response.confirmations);
+        if confirmations.is_some_and(|confirmations| confirmations > 0) {
+            return Ok(Some(WithdrawalRejectErrorMsg::SweepConfirmed));
+        }
     }
+
+    Ok(None)
 }
 
 /// This struct is used to generate a properly formatted Stacks transaction
@@ -1529,6 +2242,18 @@ impl SmartContract {
         }
     }
 
+    /// The release of the sBTC smart contracts that introduced this
+    /// contract.
+    pub const fn version(self) -> SmartContractsVersion {
+        match self {
+            SmartContract::SbtcToken
+            | SmartContract::SbtcRegistry
+            | SmartContract::SbtcDeposit
+            | SmartContract::SbtcWithdrawal
+            | SmartContract::SbtcBootstrapSigners => SmartContractsVersion::V1,
+        }
+    }
+
     /// The actual body of the clarity contract.
     pub const fn contract_body(self) -> &'static str {
         match self {
@@ -1573,11 +2298,30 @@ impl SmartContract {
         }
     }
 
-    /// Validates that The contract is not already deployed on the chain.
+    /// Validates that the contract belongs to a release that this signer
+    /// is configured to deploy, and that it is not already deployed on the
+    /// chain.
+    ///
+    /// Contracts of the first release are deployed when bootstrapping and
+    /// are always allowed. Contracts of later releases are only allowed
+    /// when contract upgrades are enabled and the release is the target
+    /// release of this signer, so that an upgrade only happens when the
+    /// signers agree on the release to upgrade to.
     pub async fn validate<C>(&self, ctx: &C, req_ctx: &ReqContext) -> Result<(), Error>
     where
         C: Context + Send + Sync,
     {
+        let config = &ctx.config().signer;
+        let version = self.version();
+        let target_version = config.contracts_target_version;
+
+        if version.is_upgrade() && !config.contract_upgrades_enabled {
+            return Err(Error::ContractUpgradesDisabled(self.contract_name()));
+        }
+        if version > target_version || (version.is_upgrade() && version != target_version) {
+            return Err(Error::ContractVersionMismatch(version, target_version));
+        }
+
         let stacks = ctx.get_stacks_client();
         if self.is_deployed(&stacks, &req_ctx.deployer).await? {
             return Err(Error::ContractAlreadyDeployed(self.contract_name()));
@@ -1617,6 +2361,47 @@ mod tests {
         let _ = call.as_contract_call();
     }
 
+    #[test]
+    fn deposits_contract_call_creation() {
+        // This is to check that this function doesn't implicitly panic. If
+        // it doesn't panic now, it can never panic at runtime.
+        let deposits = (0..MAX_DEPOSITS_PER_COMPLETE_CALL as u32)
+            .map(|vout| CompleteDepositV1 {
+                outpoint: OutPoint { vout, ..OutPoint::null() },
+                amount: 15000,
+                recipient: PrincipalData::from(StacksAddress::burn_address(vout % 2 == 0)),
+                deployer: StacksAddress::burn_address(false),
+                sweep_txid: BitcoinTxId::from([0; 32]),
+                sweep_block_hash: BitcoinBlockHash::from([0; 32]),
+                sweep_block_height: 7u64.into(),
+            })
+            .collect();
+        let call = CompleteDepositsV1 {
+            deposits,
+            deployer: StacksAddress::burn_address(false),
+        };
+
+        let contract_call = call.as_contract_call();
+        assert_eq!(contract_call.function_args.len(), 1);
+    }
+
+    #[test]
+    fn smart_contract_releases_are_consistent() {
+        // Every contract is introduced in exactly one release, and the
+        // releases list the contracts that they introduce.
+        for version in SmartContractsVersion::ALL {
+            for contract in version.contracts() {
+                assert_eq!(contract.version(), version);
+            }
+        }
+
+        let versions: Vec<_> = SmartContractsVersion::LATEST.up_to().collect();
+        assert!(versions.is_sorted());
+        assert_eq!(versions.first(), Some(&SmartContractsVersion::V1));
+        assert_eq!(versions.last(), Some(&SmartContractsVersion::LATEST));
+        assert!(!SmartContractsVersion::V1.is_upgrade());
+    }
+
     #[test]
     fn withdrawal_accept_contract_call_creation() {
         // This is to check that this function doesn't implicitly panic. If
@@ -1638,6 +2423,34 @@ mod tests {
         let _ = call.as_contract_call();
     }
 
+    #[test]
+    fn withdrawals_accept_contract_call_creation() {
+        // This is to check that this function doesn't implicitly panic. If
+        // it doesn't panic now, it can never panic at runtime.
+        let withdrawals = (0..MAX_WITHDRAWALS_PER_ACCEPT_CALL as u64)
+            .map(|request_id| AcceptWithdrawalV1 {
+                id: QualifiedRequestId {
+                    request_id,
+                    txid: StacksTxId::from([0; 32]),
+                    block_hash: StacksBlockHash::from([0; 32]),
+                },
+                outpoint: OutPoint::null(),
+                tx_fee: 125,
+                signer_bitmap: 0,
+                deployer: StacksAddress::burn_address(false),
+                sweep_block_hash: BitcoinBlockHash::from([0; 32]),
+                sweep_block_height: 7u64.into(),
+            })
+            .collect();
+        let call = AcceptWithdrawalsV1 {
+            withdrawals,
+            deployer: StacksAddress::burn_address(false),
+        };
+
+        let contract_call = call.as_contract_call();
+        assert_eq!(contract_call.function_args.len(), 1);
+    }
+
     #[test]
     fn reject_withdrawal_contract_call_creation() {
         // This is to check that this function doesn't implicitly panic. If
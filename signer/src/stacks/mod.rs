@@ -3,6 +3,12 @@
 /// Contains an interface for interacting with a stacks node.
 pub mod api;
 pub mod contracts;
+/// Contains the tracking of submitted stacks transactions and the policy
+/// for resubmitting contract calls.
+pub mod tracker;
+/// Contains the verification of the Stacks blocks fetched by the block
+/// observer.
+pub mod verification;
 /// Contains structs for signing stacks transactions using the signers'
 /// multi-sig wallet.
 pub mod wallet;
@@ -0,0 +1,197 @@
+//! Tracking of the stacks transactions that the coordinator submits, and
+//! the policy for resubmitting contract calls.
+//!
+//! Each stacks transaction that the coordinator submits is recorded in the
+//! database along with the requests that it fulfills. At the start of each
+//! tenure the coordinator follows the pending transactions through the
+//! stacks node and records whether they were confirmed, failed, or
+//! dropped. Before submitting a new contract call, the coordinator looks
+//! at the transactions that it submitted for the same requests and uses
+//! the [`ResubmissionPolicy`] to decide whether to submit the contract
+//! call, submit it with a higher fee, or skip it.
+
+use blockstack_lib::types::chainstate::StacksAddress;
+
+use crate::context::Context;
+use crate::error::Error;
+use crate::stacks::api::RejectionReason;
+use crate::stacks::api::StacksInteract as _;
+use crate::storage::DbRead as _;
+use crate::storage::DbWrite as _;
+use crate::storage::model::BitcoinBlockHash;
+use crate::storage::model::StacksTxStatus;
+use crate::storage::model::StacksTxSubmission;
+
+/// The percentage that the fee of a transaction is raised by when it is
+/// resubmitted to replace a pending transaction, or after it was rejected
+/// for having a fee that is too low.
+pub const FEE_BUMP_PERCENT: u64 = 25;
+
+/// What the coordinator should do with a contract call, given the stacks
+/// transactions that it previously submitted for the same requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resubmission {
+    /// Submit the transaction as it was constructed.
+    Submit,
+    /// Submit the transaction with a fee of at least the given amount.
+    ///
+    /// The nonce of the signers' wallet starts from the confirmed nonce of
+    /// the account in each tenure, so a contract call for requests with a
+    /// pending transaction reuses the nonce of that transaction. The
+    /// higher fee lets the stacks node replace the pending transaction.
+    BumpFee(u64),
+    /// Do not submit the transaction, for the given reason.
+    Skip(&'static str),
+}
+
+/// The policy for resubmitting contract calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResubmissionPolicy {
+    /// The maximum number of stacks transactions that may be submitted
+    /// for the same request.
+    pub max_attempts: u16,
+}
+
+impl ResubmissionPolicy {
+    /// Decide what to do with a contract call given the previously
+    /// submitted transactions for the same requests, ordered from the
+    /// oldest to the most recent submission.
+    pub fn decide(
+        &self,
+        submissions: &[StacksTxSubmission],
+        chain_tip: &BitcoinBlockHash,
+    ) -> Resubmission {
+        let has_status = |status| submissions.iter().any(|tx| tx.status == status);
+
+        if has_status(StacksTxStatus::Confirmed) {
+            return Resubmission::Skip("a transaction for the request has been confirmed");
+        }
+        // Dropped transactions without a rejection reason had their nonce
+        // used by another transaction, so trying again is fine.
+        let permanently_rejected = submissions
+            .iter()
+            .any(|tx| tx.status == StacksTxStatus::Dropped && tx.rejection_reason.is_some());
+        if permanently_rejected {
+            return Resubmission::Skip("the stacks node permanently rejected the request");
+        }
+        if submissions.len() >= self.max_attempts as usize {
+            return Resubmission::Skip("the maximum number of attempts has been reached");
+        }
+
+        let Some(latest) = submissions.last() else {
+            return Resubmission::Submit;
+        };
+
+        let fee_too_low: &'static str = RejectionReason::FeeTooLow.into();
+        match latest.status {
+            StacksTxStatus::Pending if &latest.bitcoin_block_hash == chain_tip => {
+                Resubmission::Skip("a transaction was submitted for the request in this tenure")
+            }
+            StacksTxStatus::Pending => Resubmission::BumpFee(bump_fee(latest.tx_fee)),
+            StacksTxStatus::Rejected if latest.rejection_reason.as_deref() == Some(fee_too_low) => {
+                Resubmission::BumpFee(bump_fee(latest.tx_fee))
+            }
+            _ => Resubmission::Submit,
+        }
+    }
+}
+
+/// Raise the fee by [`FEE_BUMP_PERCENT`], and by at least one microSTX.
+fn bump_fee(tx_fee: u64) -> u64 {
+    let bump = tx_fee.saturating_mul(FEE_BUMP_PERCENT) / 100;
+    tx_fee.saturating_add(bump.max(1))
+}
+
+/// Follow each pending stacks transaction through the stacks node, and
+/// record whether it was confirmed, failed, or dropped.
+///
+/// A transaction that has not been included in a stacks block is dropped
+/// once the confirmed nonce of the signers' account moves past its nonce,
+/// since it can then never be included in a block.
+#[tracing::instrument(skip_all)]
+pub async fn track_stacks_tx_submissions<C>(ctx: &C, address: &StacksAddress) -> Result<(), Error>
+where
+    C: Context,
+{
+    let db = ctx.get_storage_mut();
+    let pending = db.get_pending_stacks_tx_submissions().await?;
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    // We fetch the account nonce before looking up the transactions, so
+    // that a transaction that is confirmed in the meantime is found by the
+    // lookup rather than marked as dropped.
+    let stacks = ctx.get_stacks_client();
+    let account = stacks.get_account(address).await?;
+
+    for submission in pending {
+        let txid = submission.txid;
+        let status = match stacks.get_transaction(&txid).await? {
+            Some(info) if info.is_success() => StacksTxStatus::Confirmed,
+            Some(_) => StacksTxStatus::Failed,
+            None if submission.nonce < account.nonce => StacksTxStatus::Dropped,
+            None => continue,
+        };
+
+        tracing::info!(%txid, %status, kind = %submission.tx_kind, "stacks transaction is no longer pending");
+        db.set_stacks_tx_submission_status(&txid, status).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+    use fake::Faker;
+    use test_case::test_case;
+
+    use super::*;
+
+    fn submission(status: StacksTxStatus, rejection_reason: Option<&str>) -> StacksTxSubmission {
+        StacksTxSubmission {
+            status,
+            rejection_reason: rejection_reason.map(ToString::to_string),
+            tx_fee: 1000,
+            ..Faker.fake()
+        }
+    }
+
+    const POLICY: ResubmissionPolicy = ResubmissionPolicy { max_attempts: 3 };
+
+    #[test_case(&[], Resubmission::Submit; "first attempt")]
+    #[test_case(&[(StacksTxStatus::Failed, None)], Resubmission::Submit; "after failure")]
+    #[test_case(&[(StacksTxStatus::Dropped, None)], Resubmission::Submit; "after nonce was used")]
+    #[test_case(&[(StacksTxStatus::Rejected, Some("CONFLICTING_NONCE_IN_MEMPOOL"))], Resubmission::Submit; "after conflicting nonce")]
+    #[test_case(&[(StacksTxStatus::Pending, None)], Resubmission::BumpFee(1250); "replace pending")]
+    #[test_case(&[(StacksTxStatus::Rejected, Some("FEE_TOO_LOW"))], Resubmission::BumpFee(1250); "after fee too low")]
+    #[test_case(&[(StacksTxStatus::Failed, None), (StacksTxStatus::Confirmed, None)], Resubmission::Skip("a transaction for the request has been confirmed"); "already confirmed")]
+    #[test_case(&[(StacksTxStatus::Dropped, Some("BAD_FUNCTION_ARGUMENT"))], Resubmission::Skip("the stacks node permanently rejected the request"); "permanently rejected")]
+    #[test_case(&[(StacksTxStatus::Failed, None); 3], Resubmission::Skip("the maximum number of attempts has been reached"); "too many attempts")]
+    fn resubmission_policy_decisions(
+        statuses: &[(StacksTxStatus, Option<&str>)],
+        expected: Resubmission,
+    ) {
+        let chain_tip: BitcoinBlockHash = Faker.fake();
+        let submissions: Vec<_> = statuses
+            .iter()
+            .map(|(status, reason)| submission(*status, *reason))
+            .collect();
+
+        assert_eq!(POLICY.decide(&submissions, &chain_tip), expected);
+    }
+
+    #[test]
+    fn pending_transactions_from_this_tenure_are_not_replaced() {
+        let mut pending = submission(StacksTxStatus::Pending, None);
+        let chain_tip = pending.bitcoin_block_hash;
+
+        let decision = POLICY.decide(std::slice::from_ref(&pending), &chain_tip);
+        assert!(matches!(decision, Resubmission::Skip(_)));
+
+        pending.bitcoin_block_hash = Faker.fake();
+        let decision = POLICY.decide(&[pending], &chain_tip);
+        assert_eq!(decision, Resubmission::BumpFee(1250));
+    }
+}
@@ -0,0 +1,239 @@
+//! Verification of the Stacks blocks fetched by the block observer.
+//!
+//! The block observer stores the Stacks blocks of each tenure that it
+//! gets from the `GET /v3/tenures/<block-id>` endpoint of a stacks node,
+//! and the signers use them to decide which requests are on the canonical
+//! Stacks chain. When verification is enabled, each tenure is checked
+//! before it is stored:
+//!
+//! * Every block belongs to the tenure that the sortition info describes,
+//!   and the blocks form a chain.
+//! * The miner signature of every block recovers to the key of the miner
+//!   that won the sortition.
+//! * Every block carries signer signatures that recover to public keys.
+//!   The weight of the signers is not checked, since that would require
+//!   the reward set of the cycle.
+//! * The bitcoin block that anchors the tenure is on the bitcoin chain
+//!   that the block observer has seen.
+//!
+//! Blocks that fail verification are written to a quarantine table
+//! instead of being stored.
+
+use blockstack_lib::chainstate::nakamoto::NakamotoBlock;
+use blockstack_lib::types::chainstate::StacksBlockId;
+use blockstack_lib::util::hash::Hash160;
+use stacks_common::util::secp256k1::MessageSignature;
+use stacks_common::util::secp256k1::Secp256k1PublicKey;
+
+use crate::keys::PublicKey;
+use crate::signature::RecoverableEcdsaSignature as _;
+use crate::stacks::api::TenureBlockHeaders;
+use crate::stacks::api::TenureBlocks;
+use crate::storage::model;
+use crate::storage::model::BitcoinBlockHash;
+use crate::storage::model::BitcoinBlockHeight;
+
+/// The reasons that a Stacks block can fail verification.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum StacksBlockVerificationError {
+    /// The block does not belong to the tenure of the sortition info.
+    #[error("the block does not belong to the tenure of the sortition")]
+    ConsensusHashMismatch,
+    /// The parent of the block is not the next block in the tenure.
+    #[error("the parent of the block is not the previous block of the tenure")]
+    Discontinuity,
+    /// The miner signature of the block is not a valid signature.
+    #[error("the miner signature of the block is invalid")]
+    InvalidMinerSignature,
+    /// The miner signature does not recover to the key of the miner that
+    /// won the sortition.
+    #[error("the block was not signed by the miner that won the sortition")]
+    MinerMismatch,
+    /// The block does not carry any signer signatures.
+    #[error("the block does not have any signer signatures")]
+    MissingSignerSignatures,
+    /// One of the signer signatures of the block is not a valid signature.
+    #[error("a signer signature of the block is invalid")]
+    InvalidSignerSignature,
+    /// The bitcoin block that anchors the tenure is not one that the
+    /// block observer has seen.
+    #[error("the bitcoin anchor {0} of the tenure is unknown")]
+    UnknownAnchor(BitcoinBlockHash),
+    /// The height of the bitcoin block that anchors the tenure does not
+    /// match the height that the block observer has seen.
+    #[error("the bitcoin anchor {0} of the tenure is at height {1}, not {2}")]
+    AnchorHeightMismatch(BitcoinBlockHash, BitcoinBlockHeight, BitcoinBlockHeight),
+}
+
+/// Recover the public key that signed the given digest.
+fn recover(
+    digest: [u8; 32],
+    signature: &MessageSignature,
+) -> Result<PublicKey, crate::error::Error> {
+    let signature = secp256k1::ecdsa::RecoverableSignature::from_byte_array(&signature.0)?;
+    signature.recover_ecdsa(&secp256k1::Message::from_digest(digest))
+}
+
+/// Verify the signatures of a single Nakamoto block. The miner signature
+/// is checked against the given hash of the miner's public key, if any.
+pub fn verify_block_signatures(
+    block: &NakamotoBlock,
+    miner_pk_hash160: Option<&Hash160>,
+) -> Result<(), StacksBlockVerificationError> {
+    let header = &block.header;
+
+    let miner_key = recover(header.miner_signature_hash().0, &header.miner_signature)
+        .map_err(|_| StacksBlockVerificationError::InvalidMinerSignature)?;
+    if let Some(expected) = miner_pk_hash160 {
+        let miner_key = Secp256k1PublicKey::from(&miner_key);
+        if &Hash160::from_node_public_key(&miner_key) != expected {
+            return Err(StacksBlockVerificationError::MinerMismatch);
+        }
+    }
+
+    if header.signer_signature.is_empty() {
+        return Err(StacksBlockVerificationError::MissingSignerSignatures);
+    }
+    let digest = header.signer_signature_hash().0;
+    for signature in header.signer_signature.iter() {
+        recover(digest, signature)
+            .map_err(|_| StacksBlockVerificationError::InvalidSignerSignature)?;
+    }
+
+    Ok(())
+}
+
+/// Verify the blocks of a tenure, as returned by a stacks node. On
+/// failure, the ID of the offending block is returned with the reason.
+pub fn verify_tenure(
+    tenure: &TenureBlocks,
+) -> Result<(), (StacksBlockId, StacksBlockVerificationError)> {
+    let blocks = tenure.blocks();
+    for (index, block) in blocks.iter().enumerate() {
+        let block_id = block.block_id();
+        if block.header.consensus_hash != tenure.consensus_hash {
+            return Err((
+                block_id,
+                StacksBlockVerificationError::ConsensusHashMismatch,
+            ));
+        }
+        // The blocks in a tenure are ordered from the most recent one to
+        // the first one of the tenure.
+        if let Some(parent) = blocks.get(index + 1) {
+            if block.header.parent_block_id != parent.block_id() {
+                return Err((block_id, StacksBlockVerificationError::Discontinuity));
+            }
+        }
+        verify_block_signatures(block, tenure.miner_pk_hash160.as_ref())
+            .map_err(|error| (block_id, error))?;
+    }
+
+    Ok(())
+}
+
+/// Verify that the bitcoin block anchoring the tenure is the given block
+/// from the database.
+pub fn verify_anchor(
+    tenure: &TenureBlockHeaders,
+    anchor: Option<&model::BitcoinBlock>,
+) -> Result<(), StacksBlockVerificationError> {
+    let Some(anchor) = anchor else {
+        return Err(StacksBlockVerificationError::UnknownAnchor(
+            tenure.anchor_block_hash,
+        ));
+    };
+    if anchor.block_height != tenure.anchor_block_height {
+        return Err(StacksBlockVerificationError::AnchorHeightMismatch(
+            tenure.anchor_block_hash,
+            tenure.anchor_block_height,
+            anchor.block_height,
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use blockstack_lib::chainstate::burn::ConsensusHash;
+    use blockstack_lib::chainstate::nakamoto::NakamotoBlockHeader;
+
+    use super::*;
+    use crate::keys::PrivateKey;
+    use crate::testing::stacks::DUMMY_SORTITION_INFO;
+
+    fn sign(digest: [u8; 32], private_key: &PrivateKey) -> MessageSignature {
+        private_key
+            .sign_ecdsa_recoverable(&secp256k1::Message::from_digest(digest))
+            .as_stacks_sig()
+    }
+
+    fn signed_block(
+        parent: StacksBlockId,
+        miner: &PrivateKey,
+        signer: &PrivateKey,
+    ) -> NakamotoBlock {
+        let mut header = NakamotoBlockHeader::empty();
+        header.parent_block_id = parent;
+        header.miner_signature = sign(header.miner_signature_hash().0, miner);
+        header.signer_signature = vec![sign(header.signer_signature_hash().0, signer)];
+        NakamotoBlock { header, txs: Vec::new() }
+    }
+
+    fn miner_hash160(miner: &PrivateKey) -> Hash160 {
+        let public_key = PublicKey::from_private_key(miner);
+        Hash160::from_node_public_key(&Secp256k1PublicKey::from(&public_key))
+    }
+
+    #[test]
+    fn signed_tenure_verifies() {
+        let miner = PrivateKey::new(&mut rand::rngs::OsRng);
+        let signer = PrivateKey::new(&mut rand::rngs::OsRng);
+
+        let first = signed_block(StacksBlockId([0; 32]), &miner, &signer);
+        let second = signed_block(first.block_id(), &miner, &signer);
+
+        let mut info = DUMMY_SORTITION_INFO;
+        info.miner_pk_hash160 = Some(miner_hash160(&miner));
+        let tenure = TenureBlocks::try_new(vec![second, first], info).unwrap();
+
+        verify_tenure(&tenure).unwrap();
+    }
+
+    #[test]
+    fn block_from_another_miner_fails() {
+        let miner = PrivateKey::new(&mut rand::rngs::OsRng);
+        let other_miner = PrivateKey::new(&mut rand::rngs::OsRng);
+        let signer = PrivateKey::new(&mut rand::rngs::OsRng);
+
+        let block = signed_block(StacksBlockId([0; 32]), &other_miner, &signer);
+        let block_id = block.block_id();
+
+        let mut info = DUMMY_SORTITION_INFO;
+        info.miner_pk_hash160 = Some(miner_hash160(&miner));
+        let tenure = TenureBlocks::try_new(vec![block], info).unwrap();
+
+        let error = verify_tenure(&tenure).unwrap_err();
+        assert_eq!(
+            error,
+            (block_id, StacksBlockVerificationError::MinerMismatch)
+        );
+    }
+
+    #[test]
+    fn unsigned_and_foreign_blocks_fail() {
+        let miner = PrivateKey::new(&mut rand::rngs::OsRng);
+        let signer = PrivateKey::new(&mut rand::rngs::OsRng);
+
+        let mut block = signed_block(StacksBlockId([0; 32]), &miner, &signer);
+        block.header.signer_signature.clear();
+        let tenure = TenureBlocks::try_new(vec![block], DUMMY_SORTITION_INFO).unwrap();
+        let (_, error) = verify_tenure(&tenure).unwrap_err();
+        assert_eq!(error, StacksBlockVerificationError::MissingSignerSignatures);
+
+        let mut block = signed_block(StacksBlockId([0; 32]), &miner, &signer);
+        block.header.consensus_hash = ConsensusHash([1; 20]);
+        let tenure = TenureBlocks::try_new(vec![block], DUMMY_SORTITION_INFO).unwrap();
+        let (_, error) = verify_tenure(&tenure).unwrap_err();
+        assert_eq!(error, StacksBlockVerificationError::ConsensusHashMismatch);
+    }
+}
@@ -191,6 +191,21 @@ impl SignerWallet {
         SignerWallet::new(&public_keys, signatures_required, network_kind, 0)
     }
 
+    /// Load the wallet of the separate account that deploys the sBTC
+    /// smart contracts, if one is configured.
+    ///
+    /// The account is a one-of-one multi-sig wallet of the deployer
+    /// private key, so transactions from it are built the same way as
+    /// transactions from the signers' wallet.
+    pub fn load_deployer_wallet(config: &SignerConfig) -> Result<Option<SignerWallet>, Error> {
+        let Some(private_key) = config.deployer_private_key else {
+            return Ok(None);
+        };
+        let public_key = PublicKey::from_private_key(&private_key);
+
+        SignerWallet::new(&[public_key], 1, config.network, 0).map(Some)
+    }
+
     fn hash_mode() -> OrderIndependentMultisigHashMode {
         MULTISIG_ADDRESS_HASH_MODE
     }
@@ -256,9 +271,21 @@ impl SignerWallet {
     /// * The returned spending condition auth does not contain any
     ///   signatures.
     pub fn as_unsigned_tx_auth(&self, tx_fee: u64) -> OrderIndependentMultisigSpendingCondition {
+        let nonce = self.nonce.fetch_add(1, Ordering::Relaxed);
+        self.as_unsigned_tx_auth_with_nonce(nonce, tx_fee)
+    }
+
+    /// Like [`SignerWallet::as_unsigned_tx_auth`], but with the given
+    /// nonce instead of the next nonce of the wallet. The nonce of the
+    /// wallet is not incremented.
+    pub fn as_unsigned_tx_auth_with_nonce(
+        &self,
+        nonce: u64,
+        tx_fee: u64,
+    ) -> OrderIndependentMultisigSpendingCondition {
         OrderIndependentMultisigSpendingCondition {
             signer: self.address.bytes,
-            nonce: self.nonce.fetch_add(1, Ordering::Relaxed),
+            nonce,
             tx_fee,
             hash_mode: SignerWallet::hash_mode(),
             fields: Vec::new(),
@@ -273,7 +300,7 @@ impl SignerWallet {
 /// Only [`TransactionSpendingCondition::OrderIndependentMultisig`] auth
 /// spending conditions are currently supported, and this invariant is
 /// enforced when the struct is created.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MultisigTx {
     /// The unsigned transaction. Only transactions with a
     /// OrderIndependentMultisig auth spending condition are supported.
@@ -289,6 +316,30 @@ impl MultisigTx {
     /// Create a new Stacks transaction for a given payload that can be
     /// signed by the signers' multi-sig wallet.
     pub fn new_tx<T>(payload: &T, wallet: &SignerWallet, tx_fee: u64) -> Self
+    where
+        T: AsTxPayload,
+    {
+        let auth = wallet.as_unsigned_tx_auth(tx_fee);
+        Self::new_tx_with_auth(payload, wallet, auth)
+    }
+
+    /// Create a new Stacks transaction for a given payload that can be
+    /// signed by the signers' multi-sig wallet, using the given nonce. This
+    /// is used to replace a transaction that has already been submitted,
+    /// and does not increment the nonce of the wallet.
+    pub fn new_tx_with_nonce<T>(payload: &T, wallet: &SignerWallet, nonce: u64, tx_fee: u64) -> Self
+    where
+        T: AsTxPayload,
+    {
+        let auth = wallet.as_unsigned_tx_auth_with_nonce(nonce, tx_fee);
+        Self::new_tx_with_auth(payload, wallet, auth)
+    }
+
+    fn new_tx_with_auth<T>(
+        payload: &T,
+        wallet: &SignerWallet,
+        auth: OrderIndependentMultisigSpendingCondition,
+    ) -> Self
     where
         T: AsTxPayload,
     {
@@ -304,7 +355,6 @@ impl MultisigTx {
         };
 
         let conditions = payload.post_conditions();
-        let auth = wallet.as_unsigned_tx_auth(tx_fee);
         let spending_condition = TransactionSpendingCondition::OrderIndependentMultisig(auth);
 
         let tx = StacksTransaction {
@@ -0,0 +1,630 @@
+//! Snapshots of the state of a signer, for moving it between hosts.
+//!
+//! Most of what is in the database of a signer can be rebuilt by a fresh
+//! signer: it backfills bitcoin and stacks blocks from its nodes and
+//! fetches deposit requests from Emily. What cannot be rebuilt are the
+//! encrypted DKG shares of this signer, the history of the aggregate keys
+//! in its registry, and the decisions that it already broadcast on the
+//! deposit requests that have not been fulfilled yet. The `export-state`
+//! command of the signer binary writes those out, along with the signers'
+//! UTXO at the time of the export, as a JSON [`StateArchive`], and the
+//! `import-state` command writes them into the database on the new host.
+//!
+//! An archive holds a SHA-256 checksum of its snapshot and a fingerprint
+//! of the config of the signer that exported it. It is only imported if
+//! the checksum matches, if the config of the importing signer has the
+//! same fingerprint, and if the database on the new host does not hold
+//! any DKG shares yet.
+
+use bitcoin::OutPoint;
+use bitcoin::ScriptBuf;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest as _;
+use sha2::Sha256;
+
+use crate::config::SignerConfig;
+use crate::error::Error;
+use crate::import::parse_stacks_id;
+use crate::keys::PublicKey;
+use crate::keys::PublicKeyXOnly;
+use crate::storage::DbRead;
+use crate::storage::DbWrite;
+use crate::storage::Transactable;
+use crate::storage::TransactionHandle as _;
+use crate::storage::model;
+use crate::storage::model::BitcoinBlockHash;
+use crate::storage::model::BitcoinBlockHeight;
+use crate::storage::model::StacksTxId;
+
+/// The version of the format of [`StateSnapshot`] that this signer writes
+/// and reads.
+pub const STATE_SNAPSHOT_VERSION: u32 = 1;
+
+/// A snapshot of the state of a signer along with its checksum.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateArchive {
+    /// The hex encoded SHA-256 digest of the JSON encoding of the
+    /// snapshot.
+    pub checksum: String,
+    /// The snapshot.
+    pub snapshot: StateSnapshot,
+}
+
+/// The state of a signer that a fresh signer cannot rebuild on its own.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateSnapshot {
+    /// The version of the format of the snapshot.
+    pub version: u32,
+    /// The fingerprint of the config of the signer that took the
+    /// snapshot, see [`config_fingerprint`].
+    pub config_fingerprint: String,
+    /// The canonical bitcoin chain tip of the signer when it took the
+    /// snapshot, if it had one.
+    pub bitcoin_chain_tip: Option<BitcoinBlockHash>,
+    /// The height of the above chain tip.
+    pub bitcoin_chain_tip_height: Option<BitcoinBlockHeight>,
+    /// All of the DKG shares of the signer.
+    pub dkg_shares: Vec<ExportedDkgShares>,
+    /// The registry of aggregate keys of the signer.
+    pub aggregate_keys: Vec<ExportedAggregateKey>,
+    /// The deposit requests confirmed within the context window of the
+    /// signer, with the decision of the signer on each of them, if any.
+    pub deposit_requests: Vec<ExportedDepositRequest>,
+    /// The signers' UTXO as of the above chain tip. It is not imported,
+    /// since the signer finds it again once it has synced bitcoin blocks,
+    /// but it lets the operator check that the signer did so.
+    pub signer_utxo: Option<ExportedSignerUtxo>,
+}
+
+/// DKG shares of the signer, with their encrypted private shares.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedDkgShares {
+    /// The aggregate key of the shares.
+    pub aggregate_key: PublicKey,
+    /// The tweaked aggregate key of the shares.
+    pub tweaked_aggregate_key: PublicKey,
+    /// The scriptPubKey of the aggregate key.
+    pub script_pubkey: ScriptBuf,
+    /// The hex encoded encrypted private shares.
+    pub encrypted_private_shares: String,
+    /// The hex encoded public shares.
+    pub public_shares: String,
+    /// The public keys of the signers that took part in DKG.
+    pub signer_set_public_keys: Vec<PublicKey>,
+    /// The number of signature shares needed for a signature.
+    pub signature_share_threshold: u16,
+    /// The verification status of the shares.
+    pub dkg_shares_status: model::DkgSharesStatus,
+    /// The bitcoin chain tip when DKG started.
+    pub started_at_bitcoin_block_hash: BitcoinBlockHash,
+    /// The height of the above chain tip.
+    pub started_at_bitcoin_block_height: BitcoinBlockHeight,
+}
+
+/// The parts of an entry in the registry of aggregate keys that do not
+/// come from the DKG shares of the key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedAggregateKey {
+    /// The aggregate key.
+    pub aggregate_key: PublicKey,
+    /// The hex encoded ID of the rotate-keys transaction of the key.
+    pub rotate_keys_txid: Option<String>,
+    /// The height at which the key became the aggregate key in the
+    /// registry contract.
+    pub activation_height: Option<BitcoinBlockHeight>,
+    /// The height at which another key replaced this one.
+    pub retirement_height: Option<BitcoinBlockHeight>,
+}
+
+/// A deposit request along with the decision of the signer on it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedDepositRequest {
+    /// The deposit transaction.
+    pub txid: bitcoin::Txid,
+    /// The index of the deposit output.
+    pub output_index: u32,
+    /// The deposit script.
+    pub spend_script: ScriptBuf,
+    /// The reclaim script.
+    pub reclaim_script: ScriptBuf,
+    /// The stacks principal that receives the sBTC.
+    pub recipient: String,
+    /// The amount of the deposit, in sats.
+    pub amount: u64,
+    /// The maximum fee of the deposit, in sats.
+    pub max_fee: u64,
+    /// The lock time of the reclaim script.
+    pub lock_time: u32,
+    /// The public key in the deposit script.
+    pub signers_public_key: PublicKeyXOnly,
+    /// The scriptPubKeys of the inputs of the deposit transaction.
+    pub sender_script_pub_keys: Vec<ScriptBuf>,
+    /// The decision of the signer on the request, if it made one.
+    pub decision: Option<ExportedDepositDecision>,
+}
+
+/// The decision of the signer on a deposit request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedDepositDecision {
+    /// Whether the signer accepted the request.
+    pub can_accept: bool,
+    /// Whether the signer can sign for the request.
+    pub can_sign: bool,
+    /// The version of the decision, see
+    /// [`DepositSigner::decision_version`](model::DepositSigner::decision_version).
+    #[serde(default)]
+    pub decision_version: u32,
+}
+
+/// The signers' UTXO.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedSignerUtxo {
+    /// The outpoint of the UTXO.
+    pub outpoint: OutPoint,
+    /// The amount of the UTXO, in sats.
+    pub amount: u64,
+    /// The key that locks the UTXO.
+    pub public_key: PublicKeyXOnly,
+}
+
+/// The number of records of each kind that were imported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StateImportSummary {
+    /// The number of DKG shares.
+    pub dkg_shares: usize,
+    /// The number of entries in the registry of aggregate keys.
+    pub aggregate_keys: usize,
+    /// The number of deposit requests.
+    pub deposit_requests: usize,
+    /// The number of decisions on deposit requests.
+    pub deposit_decisions: usize,
+}
+
+impl std::fmt::Display for StateImportSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} DKG shares, {} aggregate keys, {} deposit requests, {} deposit decisions",
+            self.dkg_shares, self.aggregate_keys, self.deposit_requests, self.deposit_decisions
+        )
+    }
+}
+
+/// The fingerprint of the parts of the given config that a signer must
+/// keep when it moves to another host: its network, its public key and
+/// the bootstrap signing set with its threshold.
+pub fn config_fingerprint(config: &SignerConfig) -> String {
+    let mut hasher = Sha256::new_with_prefix("SIGNER_STATE_SNAPSHOT");
+    hasher.update(format!("{:?}", config.network));
+    hasher.update(config.public_key().serialize());
+    for public_key in config.bootstrap_signing_set.iter() {
+        hasher.update(public_key.serialize());
+    }
+    hasher.update(config.bootstrap_signatures_required.to_be_bytes());
+    hex::encode(hasher.finalize())
+}
+
+impl From<&model::EncryptedDkgShares> for ExportedDkgShares {
+    fn from(shares: &model::EncryptedDkgShares) -> Self {
+        ExportedDkgShares {
+            aggregate_key: shares.aggregate_key,
+            tweaked_aggregate_key: shares.tweaked_aggregate_key,
+            script_pubkey: shares.script_pubkey.clone().into(),
+            encrypted_private_shares: hex::encode(&shares.encrypted_private_shares),
+            public_shares: hex::encode(&shares.public_shares),
+            signer_set_public_keys: shares.signer_set_public_keys.clone(),
+            signature_share_threshold: shares.signature_share_threshold,
+            dkg_shares_status: shares.dkg_shares_status,
+            started_at_bitcoin_block_hash: shares.started_at_bitcoin_block_hash,
+            started_at_bitcoin_block_height: shares.started_at_bitcoin_block_height,
+        }
+    }
+}
+
+impl TryFrom<&ExportedDkgShares> for model::EncryptedDkgShares {
+    type Error = Error;
+    fn try_from(shares: &ExportedDkgShares) -> Result<Self, Self::Error> {
+        Ok(model::EncryptedDkgShares {
+            aggregate_key: shares.aggregate_key,
+            tweaked_aggregate_key: shares.tweaked_aggregate_key,
+            script_pubkey: shares.script_pubkey.clone().into(),
+            encrypted_private_shares: hex::decode(&shares.encrypted_private_shares)
+                .map_err(Error::DecodeHexBytes)?,
+            public_shares: hex::decode(&shares.public_shares).map_err(Error::DecodeHexBytes)?,
+            signer_set_public_keys: shares.signer_set_public_keys.clone(),
+            signature_share_threshold: shares.signature_share_threshold,
+            dkg_shares_status: shares.dkg_shares_status,
+            started_at_bitcoin_block_hash: shares.started_at_bitcoin_block_hash,
+            started_at_bitcoin_block_height: shares.started_at_bitcoin_block_height,
+        })
+    }
+}
+
+impl From<&model::AggregateKeyRecord> for ExportedAggregateKey {
+    fn from(record: &model::AggregateKeyRecord) -> Self {
+        ExportedAggregateKey {
+            aggregate_key: record.aggregate_key,
+            rotate_keys_txid: record.rotate_keys_txid.map(|txid| txid.to_string()),
+            activation_height: record.activation_height,
+            retirement_height: record.retirement_height,
+        }
+    }
+}
+
+impl ExportedDepositRequest {
+    fn new(request: &model::DepositRequest, decision: Option<&model::DepositSigner>) -> Self {
+        ExportedDepositRequest {
+            txid: request.txid.into(),
+            output_index: request.output_index,
+            spend_script: ScriptBuf::from_bytes(request.spend_script.clone()),
+            reclaim_script: ScriptBuf::from_bytes(request.reclaim_script.clone()),
+            recipient: request.recipient.to_string(),
+            amount: request.amount,
+            max_fee: request.max_fee,
+            lock_time: request.lock_time,
+            signers_public_key: request.signers_public_key,
+            sender_script_pub_keys: request
+                .sender_script_pub_keys
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            decision: decision.map(|decision| ExportedDepositDecision {
+                can_accept: decision.can_accept,
+                can_sign: decision.can_sign,
+                decision_version: decision.decision_version,
+            }),
+        }
+    }
+}
+
+impl TryFrom<&ExportedDepositRequest> for model::DepositRequest {
+    type Error = Error;
+    fn try_from(request: &ExportedDepositRequest) -> Result<Self, Self::Error> {
+        Ok(model::DepositRequest {
+            txid: request.txid.into(),
+            output_index: request.output_index,
+            spend_script: request.spend_script.to_bytes(),
+            reclaim_script: request.reclaim_script.to_bytes(),
+            reclaim_script_hash: Some(model::TaprootScriptHash::from(&request.reclaim_script)),
+            recipient: request.recipient.parse()?,
+            amount: request.amount,
+            max_fee: request.max_fee,
+            lock_time: request.lock_time,
+            signers_public_key: request.signers_public_key,
+            sender_script_pub_keys: request
+                .sender_script_pub_keys
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
+
+impl StateSnapshot {
+    /// Take a snapshot of the state of the signer with the given config
+    /// within a single database transaction.
+    pub async fn take<S>(storage: &S, config: &SignerConfig) -> Result<Self, Error>
+    where
+        S: Transactable,
+    {
+        let db = storage.begin_transaction().await?;
+        let snapshot = Self::read(&db, config).await;
+        db.rollback().await?;
+        snapshot
+    }
+
+    async fn read<D>(db: &D, config: &SignerConfig) -> Result<Self, Error>
+    where
+        D: DbRead,
+    {
+        let signer_public_key = config.public_key();
+        let chain_tip = db.get_bitcoin_canonical_chain_tip_ref().await?;
+
+        let dkg_shares = db.get_all_encrypted_dkg_shares().await?;
+        let aggregate_keys = db.get_aggregate_key_records().await?;
+
+        let mut deposit_requests = Vec::new();
+        let mut signer_utxo = None;
+        if let Some(chain_tip) = chain_tip {
+            let requests = db
+                .get_deposit_requests(&chain_tip.block_hash, config.context_window)
+                .await?;
+            for request in requests.iter() {
+                let decisions = db
+                    .get_deposit_signers(&request.txid, request.output_index)
+                    .await?;
+                let decision = decisions
+                    .iter()
+                    .find(|decision| decision.signer_pub_key == signer_public_key);
+                deposit_requests.push(ExportedDepositRequest::new(request, decision));
+            }
+
+            signer_utxo = db
+                .get_signer_utxo(&chain_tip.block_hash)
+                .await?
+                .map(|utxo| ExportedSignerUtxo {
+                    outpoint: utxo.outpoint,
+                    amount: utxo.amount,
+                    public_key: utxo.public_key.into(),
+                });
+        }
+
+        Ok(StateSnapshot {
+            version: STATE_SNAPSHOT_VERSION,
+            config_fingerprint: config_fingerprint(config),
+            bitcoin_chain_tip: chain_tip.map(|tip| tip.block_hash),
+            bitcoin_chain_tip_height: chain_tip.map(|tip| tip.block_height),
+            dkg_shares: dkg_shares.iter().map(ExportedDkgShares::from).collect(),
+            aggregate_keys: aggregate_keys
+                .iter()
+                .map(ExportedAggregateKey::from)
+                .collect(),
+            deposit_requests,
+            signer_utxo,
+        })
+    }
+
+    /// The hex encoded SHA-256 digest of the JSON encoding of the
+    /// snapshot.
+    pub fn checksum(&self) -> Result<String, Error> {
+        let json = serde_json::to_vec(self).map_err(Error::JsonSerialize)?;
+        Ok(hex::encode(Sha256::digest(json)))
+    }
+
+    /// Wrap the snapshot in an archive with its checksum.
+    pub fn into_archive(self) -> Result<StateArchive, Error> {
+        Ok(StateArchive {
+            checksum: self.checksum()?,
+            snapshot: self,
+        })
+    }
+}
+
+impl StateArchive {
+    /// Check that the snapshot in the archive is intact, that this signer
+    /// can read it, and that it was taken by a signer with the same
+    /// config fingerprint as the given config.
+    pub fn verify(&self, config: &SignerConfig) -> Result<(), Error> {
+        if self.snapshot.checksum()? != self.checksum {
+            return Err(Error::StateSnapshotChecksumMismatch);
+        }
+        if self.snapshot.version != STATE_SNAPSHOT_VERSION {
+            return Err(Error::UnsupportedStateSnapshotVersion(
+                self.snapshot.version,
+            ));
+        }
+        let fingerprint = config_fingerprint(config);
+        if self.snapshot.config_fingerprint != fingerprint {
+            return Err(Error::StateSnapshotConfigMismatch {
+                snapshot: self.snapshot.config_fingerprint.clone(),
+                config: fingerprint,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Verify the archive and write the snapshot in it to the database in
+    /// a single database transaction, so that nothing is imported if any
+    /// of it is invalid.
+    pub async fn import<S>(
+        &self,
+        storage: &S,
+        config: &SignerConfig,
+    ) -> Result<StateImportSummary, Error>
+    where
+        S: Transactable,
+    {
+        self.verify(config)?;
+
+        let db = storage.begin_transaction().await?;
+        let summary = self.write(&db, config).await;
+
+        match summary {
+            Ok(summary) => {
+                db.commit().await?;
+                Ok(summary)
+            }
+            Err(error) => {
+                db.rollback().await?;
+                Err(error)
+            }
+        }
+    }
+
+    async fn write<D>(&self, db: &D, config: &SignerConfig) -> Result<StateImportSummary, Error>
+    where
+        D: DbRead + DbWrite,
+    {
+        // Importing on top of other DKG shares could mix up the keys of
+        // two different signers.
+        if !db.get_all_encrypted_dkg_shares().await?.is_empty() {
+            return Err(Error::StateSnapshotTargetNotEmpty);
+        }
+
+        let snapshot = &self.snapshot;
+        for shares in snapshot.dkg_shares.iter() {
+            let shares = model::EncryptedDkgShares::try_from(shares)?;
+            db.write_encrypted_dkg_shares(&shares).await?;
+            db.register_aggregate_key(&shares).await?;
+        }
+
+        // Activating the keys in the order in which they were activated
+        // retires each key at the height at which the next one took over.
+        let mut aggregate_keys: Vec<&ExportedAggregateKey> =
+            snapshot.aggregate_keys.iter().collect();
+        aggregate_keys.sort_by_key(|key| key.activation_height);
+        for key in aggregate_keys {
+            if let Some(txid) = key.rotate_keys_txid.as_deref() {
+                let txid = StacksTxId::from(parse_stacks_id(txid)?);
+                db.write_aggregate_key_rotation(&key.aggregate_key, &txid)
+                    .await?;
+            }
+            if let Some(height) = key.activation_height {
+                db.activate_aggregate_key(&key.aggregate_key, height)
+                    .await?;
+            }
+        }
+
+        let deposit_requests = snapshot
+            .deposit_requests
+            .iter()
+            .map(model::DepositRequest::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        db.write_deposit_requests(deposit_requests).await?;
+
+        let signer_pub_key = config.public_key();
+        let mut deposit_decisions = 0;
+        for request in snapshot.deposit_requests.iter() {
+            let Some(decision) = request.decision else {
+                continue;
+            };
+            let decision = model::DepositSigner {
+                txid: request.txid.into(),
+                output_index: request.output_index,
+                signer_pub_key,
+                can_accept: decision.can_accept,
+                can_sign: decision.can_sign,
+                decision_version: decision.decision_version,
+            };
+            db.write_deposit_signer_decision(&decision).await?;
+            deposit_decisions += 1;
+        }
+
+        Ok(StateImportSummary {
+            dkg_shares: snapshot.dkg_shares.len(),
+            aggregate_keys: snapshot.aggregate_keys.len(),
+            deposit_requests: snapshot.deposit_requests.len(),
+            deposit_decisions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+
+    use crate::storage::memory::Store;
+    use crate::testing::get_rng;
+
+    use super::*;
+
+    fn config() -> SignerConfig {
+        crate::config::Settings::new_from_default_config()
+            .unwrap()
+            .signer
+    }
+
+    #[tokio::test]
+    async fn snapshots_move_the_state_of_a_signer() {
+        let mut rng = get_rng();
+        let config = config();
+        let source = Store::new_shared();
+
+        let block: model::BitcoinBlock = fake::Faker.fake_with_rng(&mut rng);
+        source.write_bitcoin_block(&block).await.unwrap();
+
+        let mut shares: model::EncryptedDkgShares = fake::Faker.fake_with_rng(&mut rng);
+        shares.dkg_shares_status = model::DkgSharesStatus::Verified;
+        source.write_encrypted_dkg_shares(&shares).await.unwrap();
+        source.register_aggregate_key(&shares).await.unwrap();
+        source
+            .activate_aggregate_key(&shares.aggregate_key, block.block_height)
+            .await
+            .unwrap();
+
+        let mut request: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
+        request.reclaim_script_hash = Some(model::TaprootScriptHash::from(&ScriptBuf::from_bytes(
+            request.reclaim_script.clone(),
+        )));
+        let tx_ref = model::BitcoinTxRef {
+            txid: request.txid,
+            block_hash: block.block_hash,
+        };
+        source.write_bitcoin_transaction(&tx_ref).await.unwrap();
+        source.write_deposit_request(&request).await.unwrap();
+        let decision = model::DepositSigner {
+            txid: request.txid,
+            output_index: request.output_index,
+            signer_pub_key: config.public_key(),
+            can_accept: true,
+            can_sign: true,
+            decision_version: 0,
+        };
+        source
+            .write_deposit_signer_decision(&decision)
+            .await
+            .unwrap();
+
+        // The archive goes through JSON on its way to the new host.
+        let archive = StateSnapshot::take(&source, &config)
+            .await
+            .unwrap()
+            .into_archive()
+            .unwrap();
+        let json = serde_json::to_string(&archive).unwrap();
+        let archive: StateArchive = serde_json::from_str(&json).unwrap();
+
+        let target = Store::new_shared();
+        let summary = archive.import(&target, &config).await.unwrap();
+        assert_eq!(summary.dkg_shares, 1);
+        assert_eq!(summary.aggregate_keys, 1);
+        assert_eq!(summary.deposit_requests, 1);
+        assert_eq!(summary.deposit_decisions, 1);
+
+        let imported = target.get_all_encrypted_dkg_shares().await.unwrap();
+        assert_eq!(imported, vec![shares]);
+        let records = target.get_aggregate_key_records().await.unwrap();
+        assert_eq!(records, source.get_aggregate_key_records().await.unwrap());
+        let imported = target
+            .get_deposit_request(&request.txid, request.output_index)
+            .await
+            .unwrap();
+        assert_eq!(imported, Some(request.clone()));
+        let decisions = target
+            .get_deposit_signers(&request.txid, request.output_index)
+            .await
+            .unwrap();
+        assert_eq!(decisions, vec![decision]);
+
+        // The state is not imported twice.
+        let result = archive.import(&target, &config).await;
+        assert!(matches!(result, Err(Error::StateSnapshotTargetNotEmpty)));
+    }
+
+    #[tokio::test]
+    async fn tampered_and_foreign_archives_are_rejected() {
+        let config = config();
+        let store = Store::new_shared();
+        let shares: model::EncryptedDkgShares = fake::Faker.fake_with_rng(&mut get_rng());
+        store.write_encrypted_dkg_shares(&shares).await.unwrap();
+
+        let archive = StateSnapshot::take(&store, &config)
+            .await
+            .unwrap()
+            .into_archive()
+            .unwrap();
+        assert!(archive.verify(&config).is_ok());
+
+        let mut tampered = archive.clone();
+        tampered.snapshot.dkg_shares[0].signature_share_threshold += 1;
+        let result = tampered.verify(&config);
+        assert!(matches!(result, Err(Error::StateSnapshotChecksumMismatch)));
+
+        let mut other_config = config.clone();
+        other_config.bootstrap_signatures_required += 1;
+        let result = archive.verify(&other_config);
+        assert!(matches!(
+            result,
+            Err(Error::StateSnapshotConfigMismatch { .. })
+        ));
+
+        let target = Store::new_shared();
+        assert!(archive.import(&target, &other_config).await.is_err());
+        let imported = target.get_all_encrypted_dkg_shares().await.unwrap();
+        assert!(imported.is_empty());
+    }
+}
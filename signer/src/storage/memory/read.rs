@@ -90,6 +90,15 @@ impl DbRead for SharedStore {
         Ok(result)
     }
 
+    async fn get_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        let store = self.lock().await;
+        Ok(store.get_deposit_requests(chain_tip, context_window))
+    }
+
     async fn get_pending_accepted_deposit_requests(
         &self,
         chain_tip: &model::BitcoinBlockRef,
@@ -136,6 +145,21 @@ impl DbRead for SharedStore {
                     .next()
                     .unwrap_or(false)
             })
+            .filter(|deposit_request| {
+                store
+                    .deposit_expirations
+                    .get(&(deposit_request.txid, deposit_request.output_index))
+                    .is_none_or(|expiration| expiration.reactivated)
+            })
+            .filter(|deposit_request| {
+                store
+                    .get_deposit_reclaim(
+                        &chain_tip.block_hash,
+                        &deposit_request.txid,
+                        deposit_request.output_index,
+                    )
+                    .is_none()
+            })
             .filter(|deposit_request| {
                 store
                     .deposit_request_to_signers
@@ -253,6 +277,35 @@ impl DbRead for SharedStore {
         Ok(result)
     }
 
+    async fn get_decided_withdrawal_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        let store = self.lock().await;
+        let withdrawal_requests = store.get_withdrawal_requests(chain_tip, context_window);
+
+        // These are the withdrawal requests that this signer has voted on.
+        let voted: HashSet<(u64, model::StacksBlockHash)> = store
+            .withdrawal_request_to_signers
+            .iter()
+            .filter_map(|(pk, decisions)| {
+                decisions
+                    .iter()
+                    .find(|decision| &decision.signer_pub_key == signer_public_key)
+                    .map(|_| *pk)
+            })
+            .collect();
+
+        let result = withdrawal_requests
+            .into_iter()
+            .filter(|x| voted.contains(&(x.request_id, x.block_hash)))
+            .collect();
+
+        Ok(result)
+    }
+
     async fn get_pending_accepted_withdrawal_requests(
         &self,
         _bitcoin_chain_tip: &model::BitcoinBlockHash,
@@ -329,6 +382,17 @@ impl DbRead for SharedStore {
             .map(|(_, shares)| shares.clone()))
     }
 
+    async fn get_all_encrypted_dkg_shares(&self) -> Result<Vec<model::EncryptedDkgShares>, Error> {
+        let store = self.lock().await;
+        let mut shares: Vec<_> = store.encrypted_dkg_shares.values().collect();
+        shares.sort_by_key(|(time, _)| time);
+
+        Ok(shares
+            .into_iter()
+            .map(|(_, shares)| shares.clone())
+            .collect())
+    }
+
     async fn get_latest_verified_dkg_shares(
         &self,
     ) -> Result<Option<model::EncryptedDkgShares>, Error> {
@@ -342,6 +406,19 @@ impl DbRead for SharedStore {
             .map(|(_, shares)| shares.clone()))
     }
 
+    async fn get_aggregate_key_record(
+        &self,
+        aggregate_key: &PublicKey,
+    ) -> Result<Option<model::AggregateKeyRecord>, Error> {
+        Ok(self.lock().await.aggregate_keys.get(aggregate_key).cloned())
+    }
+
+    async fn get_aggregate_key_records(&self) -> Result<Vec<model::AggregateKeyRecord>, Error> {
+        let mut records: Vec<_> = self.lock().await.aggregate_keys.values().cloned().collect();
+        records.sort_by_key(|record| (record.dkg_block_height, record.aggregate_key));
+        Ok(records)
+    }
+
     async fn get_encrypted_dkg_shares_count(&self) -> Result<u32, Error> {
         Ok(self
             .lock()
@@ -492,6 +569,22 @@ impl DbRead for SharedStore {
         }
     }
 
+    async fn get_deposit_requests_signer_votes(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+        aggregate_key: &PublicKey,
+    ) -> Result<HashMap<bitcoin::OutPoint, model::SignerVotes>, Error> {
+        let mut votes = HashMap::with_capacity(outpoints.len());
+        for outpoint in outpoints {
+            let txid = outpoint.txid.into();
+            let signer_votes = self
+                .get_deposit_request_signer_votes(&txid, outpoint.vout, aggregate_key)
+                .await?;
+            votes.insert(*outpoint, signer_votes);
+        }
+        Ok(votes)
+    }
+
     async fn get_withdrawal_request_signer_votes(
         &self,
         id: &model::QualifiedRequestId,
@@ -590,6 +683,51 @@ impl DbRead for SharedStore {
         unimplemented!()
     }
 
+    async fn get_withdrawal_requests_by_recipient(
+        &self,
+        recipient: &model::ScriptPubKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        let store = self.lock().await;
+        let mut requests: Vec<_> = store
+            .withdrawal_requests
+            .values()
+            .filter(|req| &req.recipient == recipient)
+            .cloned()
+            .collect();
+        requests.sort_by_key(|req| req.request_id);
+
+        Ok(requests)
+    }
+
+    async fn get_withdrawal_requests_by_sender(
+        &self,
+        sender: &model::StacksPrincipal,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        let store = self.lock().await;
+        let mut requests: Vec<_> = store
+            .withdrawal_requests
+            .values()
+            .filter(|req| &req.sender_address == sender)
+            .cloned()
+            .collect();
+        requests.sort_by_key(|req| req.request_id);
+
+        Ok(requests)
+    }
+
+    async fn get_withdrawal_request(
+        &self,
+        request_id: u64,
+        block_hash: &model::StacksBlockHash,
+    ) -> Result<Option<model::WithdrawalRequest>, Error> {
+        Ok(self
+            .lock()
+            .await
+            .withdrawal_requests
+            .get(&(request_id, *block_hash))
+            .cloned())
+    }
+
     async fn compute_withdrawn_total(
         &self,
         chain_tip: &model::BitcoinBlockHash,
@@ -660,6 +798,38 @@ impl DbRead for SharedStore {
             .cloned())
     }
 
+    async fn get_deposit_requests_by_recipient(
+        &self,
+        recipient: &model::StacksPrincipal,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        let store = self.lock().await;
+        let mut requests: Vec<_> = store
+            .deposit_requests
+            .values()
+            .filter(|req| &req.recipient == recipient)
+            .cloned()
+            .collect();
+        requests.sort_by_key(|req| (req.txid, req.output_index));
+
+        Ok(requests)
+    }
+
+    async fn get_completed_deposit_events(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::CompletedDepositEvent>, Error> {
+        let outpoint = bitcoin::OutPoint::new((*txid).into(), output_index);
+        Ok(self
+            .lock()
+            .await
+            .completed_deposit_events
+            .get(&outpoint)
+            .cloned()
+            .into_iter()
+            .collect())
+    }
+
     async fn will_sign_bitcoin_tx_sighash(
         &self,
         sighash: &model::SigHash,
@@ -771,128 +941,553 @@ impl DbRead for SharedStore {
 
         Ok(result)
     }
-}
 
-impl DbRead for InMemoryTransaction {
-    async fn get_bitcoin_block(
+    async fn get_deposit_signer_decisions_for_outpoints(
         &self,
-        block_hash: &model::BitcoinBlockHash,
-    ) -> Result<Option<model::BitcoinBlock>, Error> {
-        self.store.get_bitcoin_block(block_hash).await
-    }
+        outpoints: &[bitcoin::OutPoint],
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::DepositSigner>, Error> {
+        let store = self.lock().await;
 
-    async fn get_stacks_block(
-        &self,
-        block_hash: &model::StacksBlockHash,
-    ) -> Result<Option<model::StacksBlock>, Error> {
-        self.store.get_stacks_block(block_hash).await
-    }
+        let result = outpoints
+            .iter()
+            .filter_map(|outpoint| {
+                store
+                    .deposit_request_to_signers
+                    .get(&(outpoint.txid.into(), outpoint.vout))
+                    .and_then(|signers| {
+                        signers
+                            .iter()
+                            .find(|signer| signer.signer_pub_key == *signer_public_key)
+                            .cloned()
+                    })
+            })
+            .collect();
 
-    async fn get_bitcoin_canonical_chain_tip(
-        &self,
-    ) -> Result<Option<model::BitcoinBlockHash>, Error> {
-        self.store.get_bitcoin_canonical_chain_tip().await
+        Ok(result)
     }
 
-    async fn get_bitcoin_canonical_chain_tip_ref(
+    async fn get_coordinator_attestations(
         &self,
-    ) -> Result<Option<model::BitcoinBlockRef>, Error> {
-        self.store.get_bitcoin_canonical_chain_tip_ref().await
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::CoordinatorAttestation>, Error> {
+        let store = self.lock().await;
+
+        let attestations = store
+            .coordinator_attestations
+            .values()
+            .filter(|attestation| &attestation.bitcoin_chain_tip == chain_tip)
+            .cloned()
+            .collect();
+
+        Ok(attestations)
     }
 
-    async fn get_stacks_chain_tip(
+    async fn get_deposit_decision_receipts(
         &self,
-        bitcoin_chain_tip: &model::BitcoinBlockHash,
-    ) -> Result<Option<model::StacksBlock>, Error> {
-        self.store.get_stacks_chain_tip(bitcoin_chain_tip).await
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::DepositDecisionReceipt>, Error> {
+        let store = self.lock().await;
+
+        Ok(store
+            .deposit_decision_receipts
+            .get(&(*txid, output_index))
+            .cloned()
+            .unwrap_or_default())
     }
 
-    async fn get_pending_deposit_requests(
+    async fn get_deposit_rejection(
         &self,
-        chain_tip: &model::BitcoinBlockHash,
-        context_window: u16,
-        signer_public_key: &PublicKey,
-    ) -> Result<Vec<model::DepositRequest>, Error> {
-        self.store
-            .get_pending_deposit_requests(chain_tip, context_window, signer_public_key)
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositRejection>, Error> {
+        Ok(self
+            .lock()
             .await
+            .deposit_rejections
+            .get(&(*txid, output_index))
+            .cloned())
     }
 
-    async fn get_pending_accepted_deposit_requests(
+    async fn get_deposit_expiration(
         &self,
-        chain_tip: &model::BitcoinBlockRef,
-        context_window: u16,
-        signatures_required: u16,
-    ) -> Result<Vec<model::DepositRequest>, Error> {
-        self.store
-            .get_pending_accepted_deposit_requests(chain_tip, context_window, signatures_required)
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositExpiration>, Error> {
+        Ok(self
+            .lock()
             .await
+            .deposit_expirations
+            .get(&(*txid, output_index))
+            .cloned())
     }
 
-    async fn deposit_request_exists(
+    async fn get_deposit_requests_for_outpoints(
         &self,
-        txid: &model::BitcoinTxId,
-        output_index: u32,
-    ) -> Result<bool, Error> {
-        self.store.deposit_request_exists(txid, output_index).await
+        outpoints: &[bitcoin::OutPoint],
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        let store = self.lock().await;
+        Ok(outpoints
+            .iter()
+            .filter_map(|outpoint| {
+                let key = (model::BitcoinTxId::from(outpoint.txid), outpoint.vout);
+                store.deposit_requests.get(&key).cloned()
+            })
+            .collect())
     }
 
-    async fn get_deposit_request_report(
+    async fn get_deposit_reclaim(
         &self,
         chain_tip: &model::BitcoinBlockHash,
         txid: &model::BitcoinTxId,
         output_index: u32,
-        signer_public_key: &PublicKey,
-    ) -> Result<Option<DepositRequestReport>, Error> {
-        self.store
-            .get_deposit_request_report(chain_tip, txid, output_index, signer_public_key)
-            .await
+    ) -> Result<Option<model::DepositReclaim>, Error> {
+        let store = self.lock().await;
+        Ok(store.get_deposit_reclaim(chain_tip, txid, output_index))
     }
 
-    async fn get_deposit_signers(
+    async fn get_deposit_assessed_fee(
         &self,
-        txid: &model::BitcoinTxId,
-        output_index: u32,
-    ) -> Result<Vec<model::DepositSigner>, Error> {
-        self.store.get_deposit_signers(txid, output_index).await
+        sweep_txid: &model::BitcoinTxId,
+        sweep_block_hash: &model::BitcoinBlockHash,
+        outpoint: &bitcoin::OutPoint,
+    ) -> Result<Option<u64>, Error> {
+        let store = self.lock().await;
+        let in_block = store
+            .bitcoin_transactions_to_blocks
+            .get(sweep_txid)
+            .is_some_and(|block_hashes| block_hashes.contains(sweep_block_hash));
+        if !in_block {
+            return Ok(None);
+        }
+
+        let prevout_txid = model::BitcoinTxId::from(outpoint.txid);
+        let assessed_fee = store
+            .bitcoin_prevouts
+            .get(sweep_txid)
+            .into_iter()
+            .flatten()
+            .find(|prevout| {
+                prevout.prevout_type == model::TxPrevoutType::Deposit
+                    && prevout.prevout_txid == prevout_txid
+                    && prevout.prevout_output_index == outpoint.vout
+            })
+            .and_then(|prevout| prevout.assessed_fee);
+        Ok(assessed_fee)
     }
 
-    async fn get_deposit_signer_decisions(
+    async fn get_latest_tenure_plan(&self) -> Result<Option<model::TenurePlan>, Error> {
+        Ok(self.lock().await.tenure_plans.last().cloned())
+    }
+
+    async fn get_bitcoin_block_processing(
         &self,
-        chain_tip: &model::BitcoinBlockHash,
-        context_window: u16,
-        signer_public_key: &PublicKey,
-    ) -> Result<Vec<model::DepositSigner>, Error> {
-        self.store
-            .get_deposit_signer_decisions(chain_tip, context_window, signer_public_key)
-            .await
+        block_hash: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::BitcoinBlockProcessing>, Error> {
+        let store = self.lock().await;
+        Ok(store.bitcoin_block_processing.get(block_hash).cloned())
     }
 
-    async fn get_withdrawal_signer_decisions(
+    async fn get_stacks_tx_submissions(
         &self,
-        chain_tip: &model::BitcoinBlockHash,
-        context_window: u16,
-        signer_public_key: &PublicKey,
-    ) -> Result<Vec<model::WithdrawalSigner>, Error> {
-        self.store
-            .get_withdrawal_signer_decisions(chain_tip, context_window, signer_public_key)
+        request_keys: &[String],
+    ) -> Result<Vec<model::StacksTxSubmission>, Error> {
+        Ok(self
+            .lock()
             .await
+            .stacks_tx_submissions
+            .iter()
+            .filter(|submission| {
+                submission
+                    .request_keys
+                    .iter()
+                    .any(|key| request_keys.contains(key))
+            })
+            .cloned()
+            .collect())
     }
 
-    async fn can_sign_deposit_tx(
+    async fn get_pending_stacks_tx_submissions(
         &self,
-        txid: &model::BitcoinTxId,
-        output_index: u32,
-        signer_public_key: &PublicKey,
-    ) -> Result<Option<bool>, Error> {
-        self.store
-            .can_sign_deposit_tx(txid, output_index, signer_public_key)
+    ) -> Result<Vec<model::StacksTxSubmission>, Error> {
+        Ok(self
+            .lock()
             .await
+            .stacks_tx_submissions
+            .iter()
+            .filter(|submission| submission.status == model::StacksTxStatus::Pending)
+            .cloned()
+            .collect())
     }
 
-    async fn get_withdrawal_signers(
+    async fn get_unsettled_bitcoin_broadcasts(
+        &self,
+    ) -> Result<Vec<model::BitcoinBroadcast>, Error> {
+        Ok(self
+            .lock()
+            .await
+            .bitcoin_broadcasts
+            .iter()
+            .filter(|broadcast| {
+                matches!(
+                    broadcast.status,
+                    model::BitcoinBroadcastStatus::Pending
+                        | model::BitcoinBroadcastStatus::Broadcast
+                )
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn get_quarantined_stacks_blocks(
+        &self,
+    ) -> Result<Vec<model::QuarantinedStacksBlock>, Error> {
+        let store = self.lock().await;
+        Ok(store
+            .quarantined_stacks_blocks
+            .iter()
+            .rev()
+            .cloned()
+            .collect())
+    }
+
+    async fn get_conflicting_signed_sighash(
+        &self,
+        sighash: &model::SigHash,
+    ) -> Result<Option<model::SigHash>, Error> {
+        let store = self.lock().await;
+        let Some(row) = store.bitcoin_sighashes.get(sighash) else {
+            return Ok(None);
+        };
+        let key = (row.prevout_txid, row.prevout_output_index, row.chain_tip);
+        Ok(store
+            .signed_sighashes
+            .get(&key)
+            .filter(|signed| *signed != sighash)
+            .copied())
+    }
+
+    async fn get_mint_mismatches(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::MintMismatch>, Error> {
+        let store = self.lock().await;
+        Ok(store
+            .mint_mismatches
+            .iter()
+            .filter(|mismatch| &mismatch.txid == txid && mismatch.output_index == output_index)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_withdrawal_sweep_txids(
+        &self,
+        id: &model::QualifiedRequestId,
+    ) -> Result<Vec<model::BitcoinTxId>, Error> {
+        let store = self.lock().await;
+        Ok(store
+            .bitcoin_withdrawal_outputs
+            .get(&(id.request_id, id.block_hash))
+            .map(|output| output.bitcoin_txid)
+            .into_iter()
+            .collect())
+    }
+
+    async fn get_signer_payment(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::SignerPayment>, Error> {
+        let store = self.lock().await;
+        Ok(store
+            .signer_payments
+            .iter()
+            .find(|payment| &payment.txid == txid && payment.output_index == output_index)
+            .cloned())
+    }
+
+    async fn get_signer_payments(
+        &self,
+        status: model::SignerPaymentStatus,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        let store = self.lock().await;
+        Ok(store
+            .signer_payments
+            .iter()
+            .filter(|payment| payment.status == status)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_pending_refunds(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        Ok(self
+            .lock()
+            .await
+            .get_unspent_signer_payments(chain_tip)
+            .into_iter()
+            .filter(|payment| payment.status == model::SignerPaymentStatus::RefundApproved)
+            .collect())
+    }
+
+    async fn get_unspent_signer_payments(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        Ok(self.lock().await.get_unspent_signer_payments(chain_tip))
+    }
+
+    async fn get_latest_dkg_begin_refusal(&self) -> Result<Option<model::DkgBeginRefusal>, Error> {
+        Ok(self.lock().await.dkg_begin_refusals.last().cloned())
+    }
+
+    async fn get_latest_signer_key_rotation(
+        &self,
+    ) -> Result<Option<model::SignerKeyRotation>, Error> {
+        Ok(self.lock().await.signer_key_rotations.last().cloned())
+    }
+
+    async fn get_key_endorsements(&self) -> Result<Vec<model::KeyEndorsement>, Error> {
+        Ok(self.lock().await.key_endorsements.clone())
+    }
+
+    async fn get_emergency_recovery_approvals(
+        &self,
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> Result<Vec<model::EmergencyRecoveryApproval>, Error> {
+        let mut approvals: Vec<model::EmergencyRecoveryApproval> = self
+            .lock()
+            .await
+            .emergency_recovery_approvals
+            .iter()
+            .filter(|approval| &approval.recovery_script_pubkey == recovery_script_pubkey)
+            .cloned()
+            .collect();
+        // The sort is stable, so approvals recorded at the same height
+        // stay in the order that they were written.
+        approvals.sort_by_key(|approval| approval.bitcoin_block_height);
+        Ok(approvals)
+    }
+
+    async fn is_emergency_recovery_swept(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> Result<bool, Error> {
+        let store = self.lock().await;
+        let canonical_blocks = store.canonical_bitcoin_blocks(chain_tip);
+
+        Ok(store
+            .emergency_recovery_sweeps
+            .iter()
+            .filter(|sweep| &sweep.recovery_script_pubkey == recovery_script_pubkey)
+            .filter_map(|sweep| store.bitcoin_transactions_to_blocks.get(&sweep.txid))
+            .flatten()
+            .any(|block_hash| canonical_blocks.contains(block_hash)))
+    }
+
+    async fn get_deposit_decision_acknowledgements(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+    ) -> Result<Vec<model::DepositDecisionAcknowledgement>, Error> {
+        let store = self.lock().await;
+        let acks = store
+            .deposit_decision_acknowledgements
+            .values()
+            .filter(|ack| {
+                outpoints.iter().any(|outpoint| {
+                    ack.txid == outpoint.txid.into() && ack.output_index == outpoint.vout
+                })
+            })
+            .cloned()
+            .collect();
+        Ok(acks)
+    }
+
+    async fn get_withdrawal_decision_acknowledgements(
+        &self,
+        ids: &[model::QualifiedRequestId],
+    ) -> Result<Vec<model::WithdrawalDecisionAcknowledgement>, Error> {
+        let store = self.lock().await;
+        let acks = store
+            .withdrawal_decision_acknowledgements
+            .values()
+            .filter(|ack| {
+                ids.iter()
+                    .any(|id| ack.request_id == id.request_id && ack.block_hash == id.block_hash)
+            })
+            .cloned()
+            .collect();
+        Ok(acks)
+    }
+
+    async fn is_coordinator_paused(&self) -> Result<bool, Error> {
+        Ok(self.lock().await.coordinator_paused)
+    }
+
+    async fn get_deposit_decision_overrides(
+        &self,
+    ) -> Result<Vec<model::DepositDecisionOverride>, Error> {
+        let store = self.lock().await;
+        Ok(store.deposit_decision_overrides.values().cloned().collect())
+    }
+
+    async fn get_withdrawal_decision_overrides(
+        &self,
+    ) -> Result<Vec<model::WithdrawalDecisionOverride>, Error> {
+        let store = self.lock().await;
+        Ok(store
+            .withdrawal_decision_overrides
+            .values()
+            .cloned()
+            .collect())
+    }
+}
+
+impl DbRead for InMemoryTransaction {
+    async fn get_bitcoin_block(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::BitcoinBlock>, Error> {
+        self.store.get_bitcoin_block(block_hash).await
+    }
+
+    async fn get_stacks_block(
         &self,
-        request_id: u64,
+        block_hash: &model::StacksBlockHash,
+    ) -> Result<Option<model::StacksBlock>, Error> {
+        self.store.get_stacks_block(block_hash).await
+    }
+
+    async fn get_bitcoin_canonical_chain_tip(
+        &self,
+    ) -> Result<Option<model::BitcoinBlockHash>, Error> {
+        self.store.get_bitcoin_canonical_chain_tip().await
+    }
+
+    async fn get_bitcoin_canonical_chain_tip_ref(
+        &self,
+    ) -> Result<Option<model::BitcoinBlockRef>, Error> {
+        self.store.get_bitcoin_canonical_chain_tip_ref().await
+    }
+
+    async fn get_stacks_chain_tip(
+        &self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::StacksBlock>, Error> {
+        self.store.get_stacks_chain_tip(bitcoin_chain_tip).await
+    }
+
+    async fn get_pending_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        self.store
+            .get_pending_deposit_requests(chain_tip, context_window, signer_public_key)
+            .await
+    }
+
+    async fn get_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        self.store
+            .get_deposit_requests(chain_tip, context_window)
+            .await
+    }
+
+    async fn get_pending_accepted_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockRef,
+        context_window: u16,
+        signatures_required: u16,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        self.store
+            .get_pending_accepted_deposit_requests(chain_tip, context_window, signatures_required)
+            .await
+    }
+
+    async fn deposit_request_exists(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<bool, Error> {
+        self.store.deposit_request_exists(txid, output_index).await
+    }
+
+    async fn get_deposit_request_report(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        signer_public_key: &PublicKey,
+    ) -> Result<Option<DepositRequestReport>, Error> {
+        self.store
+            .get_deposit_request_report(chain_tip, txid, output_index, signer_public_key)
+            .await
+    }
+
+    async fn get_deposit_signers(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::DepositSigner>, Error> {
+        self.store.get_deposit_signers(txid, output_index).await
+    }
+
+    async fn get_deposit_signer_decisions(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::DepositSigner>, Error> {
+        self.store
+            .get_deposit_signer_decisions(chain_tip, context_window, signer_public_key)
+            .await
+    }
+
+    async fn get_deposit_signer_decisions_for_outpoints(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::DepositSigner>, Error> {
+        self.store
+            .get_deposit_signer_decisions_for_outpoints(outpoints, signer_public_key)
+            .await
+    }
+
+    async fn get_withdrawal_signer_decisions(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::WithdrawalSigner>, Error> {
+        self.store
+            .get_withdrawal_signer_decisions(chain_tip, context_window, signer_public_key)
+            .await
+    }
+
+    async fn can_sign_deposit_tx(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        signer_public_key: &PublicKey,
+    ) -> Result<Option<bool>, Error> {
+        self.store
+            .can_sign_deposit_tx(txid, output_index, signer_public_key)
+            .await
+    }
+
+    async fn get_withdrawal_signers(
+        &self,
+        request_id: u64,
         block_hash: &model::StacksBlockHash,
     ) -> Result<Vec<model::WithdrawalSigner>, Error> {
         self.store
@@ -911,6 +1506,17 @@ impl DbRead for InMemoryTransaction {
             .await
     }
 
+    async fn get_decided_withdrawal_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        self.store
+            .get_decided_withdrawal_requests(chain_tip, context_window, signer_public_key)
+            .await
+    }
+
     async fn get_pending_accepted_withdrawal_requests(
         &self,
         bitcoin_chain_tip: &model::BitcoinBlockHash,
@@ -955,6 +1561,32 @@ impl DbRead for InMemoryTransaction {
             .await
     }
 
+    async fn get_withdrawal_requests_by_recipient(
+        &self,
+        recipient: &model::ScriptPubKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        self.store
+            .get_withdrawal_requests_by_recipient(recipient)
+            .await
+    }
+
+    async fn get_withdrawal_requests_by_sender(
+        &self,
+        sender: &model::StacksPrincipal,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        self.store.get_withdrawal_requests_by_sender(sender).await
+    }
+
+    async fn get_withdrawal_request(
+        &self,
+        request_id: u64,
+        block_hash: &model::StacksBlockHash,
+    ) -> Result<Option<model::WithdrawalRequest>, Error> {
+        self.store
+            .get_withdrawal_request(request_id, block_hash)
+            .await
+    }
+
     async fn compute_withdrawn_total(
         &self,
         bitcoin_chain_tip: &model::BitcoinBlockHash,
@@ -992,12 +1624,27 @@ impl DbRead for InMemoryTransaction {
         self.store.get_latest_encrypted_dkg_shares().await
     }
 
+    async fn get_all_encrypted_dkg_shares(&self) -> Result<Vec<model::EncryptedDkgShares>, Error> {
+        self.store.get_all_encrypted_dkg_shares().await
+    }
+
     async fn get_latest_verified_dkg_shares(
         &self,
     ) -> Result<Option<model::EncryptedDkgShares>, Error> {
         self.store.get_latest_verified_dkg_shares().await
     }
 
+    async fn get_aggregate_key_record(
+        &self,
+        aggregate_key: &PublicKey,
+    ) -> Result<Option<model::AggregateKeyRecord>, Error> {
+        self.store.get_aggregate_key_record(aggregate_key).await
+    }
+
+    async fn get_aggregate_key_records(&self) -> Result<Vec<model::AggregateKeyRecord>, Error> {
+        self.store.get_aggregate_key_records().await
+    }
+
     async fn get_encrypted_dkg_shares_count(&self) -> Result<u32, Error> {
         self.store.get_encrypted_dkg_shares_count().await
     }
@@ -1043,6 +1690,16 @@ impl DbRead for InMemoryTransaction {
             .await
     }
 
+    async fn get_deposit_requests_signer_votes(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+        aggregate_key: &PublicKey,
+    ) -> Result<HashMap<bitcoin::OutPoint, model::SignerVotes>, Error> {
+        self.store
+            .get_deposit_requests_signer_votes(outpoints, aggregate_key)
+            .await
+    }
+
     async fn get_withdrawal_request_signer_votes(
         &self,
         id: &model::QualifiedRequestId,
@@ -1123,10 +1780,247 @@ impl DbRead for InMemoryTransaction {
         self.store.get_deposit_request(txid, output_index).await
     }
 
+    async fn get_deposit_requests_by_recipient(
+        &self,
+        recipient: &model::StacksPrincipal,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        self.store
+            .get_deposit_requests_by_recipient(recipient)
+            .await
+    }
+
+    async fn get_completed_deposit_events(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::CompletedDepositEvent>, Error> {
+        self.store
+            .get_completed_deposit_events(txid, output_index)
+            .await
+    }
+
     async fn will_sign_bitcoin_tx_sighash(
         &self,
         sighash: &model::SigHash,
     ) -> Result<Option<(bool, PublicKeyXOnly)>, Error> {
         self.store.will_sign_bitcoin_tx_sighash(sighash).await
     }
+
+    async fn get_coordinator_attestations(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::CoordinatorAttestation>, Error> {
+        self.store.get_coordinator_attestations(chain_tip).await
+    }
+
+    async fn get_deposit_decision_receipts(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::DepositDecisionReceipt>, Error> {
+        self.store
+            .get_deposit_decision_receipts(txid, output_index)
+            .await
+    }
+
+    async fn get_deposit_rejection(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositRejection>, Error> {
+        self.store.get_deposit_rejection(txid, output_index).await
+    }
+
+    async fn get_deposit_expiration(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositExpiration>, Error> {
+        self.store.get_deposit_expiration(txid, output_index).await
+    }
+
+    async fn get_deposit_requests_for_outpoints(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        self.store
+            .get_deposit_requests_for_outpoints(outpoints)
+            .await
+    }
+
+    async fn get_deposit_reclaim(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositReclaim>, Error> {
+        self.store
+            .get_deposit_reclaim(chain_tip, txid, output_index)
+            .await
+    }
+
+    async fn get_deposit_assessed_fee(
+        &self,
+        sweep_txid: &model::BitcoinTxId,
+        sweep_block_hash: &model::BitcoinBlockHash,
+        outpoint: &bitcoin::OutPoint,
+    ) -> Result<Option<u64>, Error> {
+        self.store
+            .get_deposit_assessed_fee(sweep_txid, sweep_block_hash, outpoint)
+            .await
+    }
+
+    async fn get_latest_tenure_plan(&self) -> Result<Option<model::TenurePlan>, Error> {
+        self.store.get_latest_tenure_plan().await
+    }
+
+    async fn get_bitcoin_block_processing(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::BitcoinBlockProcessing>, Error> {
+        self.store.get_bitcoin_block_processing(block_hash).await
+    }
+
+    async fn get_stacks_tx_submissions(
+        &self,
+        request_keys: &[String],
+    ) -> Result<Vec<model::StacksTxSubmission>, Error> {
+        self.store.get_stacks_tx_submissions(request_keys).await
+    }
+
+    async fn get_pending_stacks_tx_submissions(
+        &self,
+    ) -> Result<Vec<model::StacksTxSubmission>, Error> {
+        self.store.get_pending_stacks_tx_submissions().await
+    }
+
+    async fn get_unsettled_bitcoin_broadcasts(
+        &self,
+    ) -> Result<Vec<model::BitcoinBroadcast>, Error> {
+        self.store.get_unsettled_bitcoin_broadcasts().await
+    }
+
+    async fn get_quarantined_stacks_blocks(
+        &self,
+    ) -> Result<Vec<model::QuarantinedStacksBlock>, Error> {
+        self.store.get_quarantined_stacks_blocks().await
+    }
+
+    async fn get_conflicting_signed_sighash(
+        &self,
+        sighash: &model::SigHash,
+    ) -> Result<Option<model::SigHash>, Error> {
+        self.store.get_conflicting_signed_sighash(sighash).await
+    }
+
+    async fn get_mint_mismatches(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::MintMismatch>, Error> {
+        self.store.get_mint_mismatches(txid, output_index).await
+    }
+
+    async fn get_withdrawal_sweep_txids(
+        &self,
+        id: &model::QualifiedRequestId,
+    ) -> Result<Vec<model::BitcoinTxId>, Error> {
+        self.store.get_withdrawal_sweep_txids(id).await
+    }
+
+    async fn get_signer_payment(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::SignerPayment>, Error> {
+        self.store.get_signer_payment(txid, output_index).await
+    }
+
+    async fn get_signer_payments(
+        &self,
+        status: model::SignerPaymentStatus,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        self.store.get_signer_payments(status).await
+    }
+
+    async fn get_pending_refunds(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        self.store.get_pending_refunds(chain_tip).await
+    }
+
+    async fn get_unspent_signer_payments(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        self.store.get_unspent_signer_payments(chain_tip).await
+    }
+
+    async fn get_latest_dkg_begin_refusal(&self) -> Result<Option<model::DkgBeginRefusal>, Error> {
+        self.store.get_latest_dkg_begin_refusal().await
+    }
+
+    async fn get_latest_signer_key_rotation(
+        &self,
+    ) -> Result<Option<model::SignerKeyRotation>, Error> {
+        self.store.get_latest_signer_key_rotation().await
+    }
+
+    async fn get_key_endorsements(&self) -> Result<Vec<model::KeyEndorsement>, Error> {
+        self.store.get_key_endorsements().await
+    }
+
+    async fn get_emergency_recovery_approvals(
+        &self,
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> Result<Vec<model::EmergencyRecoveryApproval>, Error> {
+        self.store
+            .get_emergency_recovery_approvals(recovery_script_pubkey)
+            .await
+    }
+
+    async fn is_emergency_recovery_swept(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> Result<bool, Error> {
+        self.store
+            .is_emergency_recovery_swept(chain_tip, recovery_script_pubkey)
+            .await
+    }
+
+    async fn get_deposit_decision_acknowledgements(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+    ) -> Result<Vec<model::DepositDecisionAcknowledgement>, Error> {
+        self.store
+            .get_deposit_decision_acknowledgements(outpoints)
+            .await
+    }
+
+    async fn get_withdrawal_decision_acknowledgements(
+        &self,
+        ids: &[model::QualifiedRequestId],
+    ) -> Result<Vec<model::WithdrawalDecisionAcknowledgement>, Error> {
+        self.store
+            .get_withdrawal_decision_acknowledgements(ids)
+            .await
+    }
+
+    async fn is_coordinator_paused(&self) -> Result<bool, Error> {
+        self.store.is_coordinator_paused().await
+    }
+
+    async fn get_deposit_decision_overrides(
+        &self,
+    ) -> Result<Vec<model::DepositDecisionOverride>, Error> {
+        self.store.get_deposit_decision_overrides().await
+    }
+
+    async fn get_withdrawal_decision_overrides(
+        &self,
+    ) -> Result<Vec<model::WithdrawalDecisionOverride>, Error> {
+        self.store.get_withdrawal_decision_overrides().await
+    }
 }
@@ -4,6 +4,7 @@ use bitcoin::OutPoint;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use time::OffsetDateTime;
@@ -88,6 +89,9 @@ pub struct Store {
     /// Rotate keys transactions
     pub rotate_keys_transactions: HashMap<model::StacksBlockHash, Vec<model::KeyRotationEvent>>,
 
+    /// The registry of aggregate keys
+    pub aggregate_keys: HashMap<PublicKey, model::AggregateKeyRecord>,
+
     /// A mapping between request_ids and withdrawal-accept events. Note
     /// that in prod we can have a single request_id be associated with
     /// more than one withdrawal-accept event because of reorgs.
@@ -115,6 +119,105 @@ pub struct Store {
     /// Bitcoin withdrawal outputs
     pub bitcoin_withdrawal_outputs:
         HashMap<(u64, model::StacksBlockHash), model::BitcoinWithdrawalOutput>,
+
+    /// Coordinator attestations
+    pub coordinator_attestations:
+        HashMap<(model::BitcoinBlockHash, PublicKey), model::CoordinatorAttestation>,
+
+    /// Deposit decision receipts, keyed by the deposit request
+    pub deposit_decision_receipts:
+        HashMap<(model::BitcoinTxId, u32), Vec<model::DepositDecisionReceipt>>,
+
+    /// Deposit rejections due to local policy, keyed by the deposit request
+    pub deposit_rejections: HashMap<(model::BitcoinTxId, u32), model::DepositRejection>,
+
+    /// Deposit expirations, keyed by the deposit request
+    pub deposit_expirations: HashMap<(model::BitcoinTxId, u32), model::DepositExpiration>,
+
+    /// Deposit reclaims, keyed by the deposit request
+    pub deposit_reclaims: HashMap<(model::BitcoinTxId, u32), Vec<model::DepositReclaim>>,
+
+    /// Tenure plans, in the order that they were written
+    pub tenure_plans: Vec<model::TenurePlan>,
+
+    /// Refusals of this signer to begin a DKG round, in the order that
+    /// they were written
+    pub dkg_begin_refusals: Vec<model::DkgBeginRefusal>,
+
+    /// Rotations of the key of this signer, in the order that they were
+    /// written
+    pub signer_key_rotations: Vec<model::SignerKeyRotation>,
+
+    /// Endorsements of new keys received from other signers, in the
+    /// order that they were written
+    pub key_endorsements: Vec<model::KeyEndorsement>,
+
+    /// The latest approval or revocation of each signer of the emergency
+    /// recovery of the funds of the signers, in the order that they were
+    /// first written
+    pub emergency_recovery_approvals: Vec<model::EmergencyRecoveryApproval>,
+
+    /// Emergency recovery sweeps that this signer agreed to sign, in the
+    /// order that they were written
+    pub emergency_recovery_sweeps: Vec<model::EmergencyRecoverySweep>,
+
+    /// Acknowledgements of this signer's deposit decisions, keyed by the
+    /// deposit outpoint and the public key of the acknowledging signer
+    pub deposit_decision_acknowledgements:
+        HashMap<(model::BitcoinTxId, u32, PublicKey), model::DepositDecisionAcknowledgement>,
+
+    /// Acknowledgements of this signer's withdrawal decisions, keyed by
+    /// the request ID, the stacks block hash and the public key of the
+    /// acknowledging signer
+    pub withdrawal_decision_acknowledgements:
+        HashMap<(u64, model::StacksBlockHash, PublicKey), model::WithdrawalDecisionAcknowledgement>,
+
+    /// Whether the operator paused the coordinator duties of this signer
+    pub coordinator_paused: bool,
+
+    /// Operator overrides of this signer's deposit decisions, keyed by the
+    /// deposit outpoint
+    pub deposit_decision_overrides:
+        HashMap<(model::BitcoinTxId, u32), model::DepositDecisionOverride>,
+
+    /// Operator overrides of this signer's withdrawal decisions, keyed by
+    /// the request ID and the stacks block hash
+    pub withdrawal_decision_overrides:
+        HashMap<(u64, model::StacksBlockHash), model::WithdrawalDecisionOverride>,
+
+    /// The processing stages that this signer reached for each bitcoin
+    /// block, keyed by the block hash
+    pub bitcoin_block_processing: HashMap<model::BitcoinBlockHash, model::BitcoinBlockProcessing>,
+
+    /// Submitted stacks transactions, in the order that they were first
+    /// written
+    pub stacks_tx_submissions: Vec<model::StacksTxSubmission>,
+
+    /// Signed bitcoin transactions in the queue of the broadcaster, in the
+    /// order that they were first written
+    pub bitcoin_broadcasts: Vec<model::BitcoinBroadcast>,
+
+    /// Stacks blocks that failed verification, in the order that they
+    /// last failed
+    pub quarantined_stacks_blocks: Vec<model::QuarantinedStacksBlock>,
+
+    /// Sighashes that this signer has produced signature shares for,
+    /// keyed by the prevout that they spend and the chain tip
+    pub signed_sighashes:
+        HashMap<(model::BitcoinTxId, u32, model::BitcoinBlockHash), model::SigHash>,
+
+    /// Mismatched complete-deposit sign requests, in the order that they
+    /// were written
+    pub mint_mismatches: Vec<model::MintMismatch>,
+
+    /// Payments to the signers' scriptPubKey that were not deposits, in
+    /// the order that they were written
+    pub signer_payments: Vec<model::SignerPayment>,
+
+    /// Prevouts locked by the signing rounds of the coordinator, keyed by
+    /// the prevout and the chain tip of the signing round
+    pub prevout_locks:
+        HashMap<(model::BitcoinTxId, u32, model::BitcoinBlockHash), model::PrevoutLock>,
 }
 
 impl Store {
@@ -270,6 +373,74 @@ impl Store {
             .collect()
     }
 
+    /// Get the record of the given deposit request being reclaimed in a
+    /// block on the blockchain identified by the chain tip, if there is
+    /// one.
+    pub(super) fn get_deposit_reclaim(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Option<model::DepositReclaim> {
+        let reclaims = self.deposit_reclaims.get(&(*txid, output_index))?;
+        let min_block_height = reclaims
+            .iter()
+            .map(|reclaim| reclaim.bitcoin_block_height)
+            .min()?;
+
+        let first = self.bitcoin_blocks.get(chain_tip);
+        std::iter::successors(first, |block| self.bitcoin_blocks.get(&block.parent_hash))
+            .take_while(|block| block.block_height >= min_block_height)
+            .find_map(|block| {
+                reclaims
+                    .iter()
+                    .find(|reclaim| reclaim.bitcoin_block_hash == block.block_hash)
+            })
+            .cloned()
+    }
+
+    /// Get the hashes of the blocks on the blockchain identified by the
+    /// given chain tip.
+    pub(super) fn canonical_bitcoin_blocks(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> HashSet<model::BitcoinBlockHash> {
+        let first = self.bitcoin_blocks.get(chain_tip);
+        std::iter::successors(first, |block| self.bitcoin_blocks.get(&block.parent_hash))
+            .map(|block| block.block_hash)
+            .collect()
+    }
+
+    /// Get the payments to the signers that were confirmed but not spent
+    /// on the blockchain identified by the given chain tip.
+    pub(super) fn get_unspent_signer_payments(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Vec<model::SignerPayment> {
+        let canonical_blocks = self.canonical_bitcoin_blocks(chain_tip);
+
+        let is_canonical = |txid: &model::BitcoinTxId| {
+            self.bitcoin_transactions_to_blocks
+                .get(txid)
+                .is_some_and(|blocks| blocks.iter().any(|hash| canonical_blocks.contains(hash)))
+        };
+
+        let spent_outputs: HashSet<(model::BitcoinTxId, u32)> = self
+            .bitcoin_prevouts
+            .iter()
+            .filter(|(txid, _)| is_canonical(txid))
+            .flat_map(|(_, prevouts)| prevouts)
+            .map(|prevout| (prevout.prevout_txid, prevout.prevout_output_index))
+            .collect();
+
+        self.signer_payments
+            .iter()
+            .filter(|payment| is_canonical(&payment.txid))
+            .filter(|payment| !spent_outputs.contains(&(payment.txid, payment.output_index)))
+            .cloned()
+            .collect()
+    }
+
     pub(super) fn get_stacks_chain_tip(
         &self,
         bitcoin_chain_tip: &model::BitcoinBlockHash,
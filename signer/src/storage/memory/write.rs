@@ -1,6 +1,6 @@
 use crate::{
     error::Error,
-    keys::PublicKeyXOnly,
+    keys::{PublicKey, PublicKeyXOnly},
     storage::{
         DbWrite,
         model::{
@@ -117,11 +117,20 @@ impl DbWrite for SharedStore {
 
         let deposit_request_pk = (decision.txid, decision.output_index);
 
-        store
+        let decisions = store
             .deposit_request_to_signers
             .entry(deposit_request_pk)
-            .or_default()
-            .push(decision.clone());
+            .or_default();
+        let existing = decisions
+            .iter_mut()
+            .find(|existing| existing.signer_pub_key == decision.signer_pub_key);
+        if let Some(existing) = existing {
+            if decision.supersedes(existing) {
+                *existing = decision.clone();
+            }
+            return Ok(());
+        }
+        decisions.push(decision.clone());
 
         store
             .signer_to_deposit_request
@@ -139,11 +148,18 @@ impl DbWrite for SharedStore {
         let mut store = self.lock().await;
         store.version += 1;
 
-        store
+        let decisions = store
             .withdrawal_request_to_signers
             .entry((decision.request_id, decision.block_hash))
-            .or_default()
-            .push(decision.clone());
+            .or_default();
+        let existing = decisions
+            .iter_mut()
+            .find(|existing| existing.signer_pub_key == decision.signer_pub_key);
+        match existing {
+            Some(existing) if decision.supersedes(existing) => *existing = decision.clone(),
+            Some(_) => {}
+            None => decisions.push(decision.clone()),
+        }
 
         Ok(())
     }
@@ -220,6 +236,56 @@ impl DbWrite for SharedStore {
         Ok(())
     }
 
+    async fn register_aggregate_key(
+        &self,
+        shares: &model::EncryptedDkgShares,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        store
+            .aggregate_keys
+            .entry(shares.aggregate_key)
+            .and_modify(|record| record.dkg_shares_status = shares.dkg_shares_status)
+            .or_insert_with(|| model::AggregateKeyRecord::from(shares));
+
+        Ok(())
+    }
+
+    async fn write_aggregate_key_rotation(
+        &self,
+        aggregate_key: &PublicKey,
+        txid: &model::StacksTxId,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        if let Some(record) = store.aggregate_keys.get_mut(aggregate_key) {
+            record.rotate_keys_txid = Some(*txid);
+        }
+
+        Ok(())
+    }
+
+    async fn activate_aggregate_key(
+        &self,
+        aggregate_key: &PublicKey,
+        height: model::BitcoinBlockHeight,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        for record in store.aggregate_keys.values_mut() {
+            if &record.aggregate_key == aggregate_key {
+                record.activation_height.get_or_insert(height);
+            } else if record.activation_height.is_some() {
+                record.retirement_height.get_or_insert(height);
+            }
+        }
+
+        Ok(())
+    }
+
     async fn write_withdrawal_accept_event(
         &self,
         event: &WithdrawalAcceptEvent,
@@ -357,6 +423,556 @@ impl DbWrite for SharedStore {
         }
         Ok(false)
     }
+
+    async fn write_coordinator_attestation(
+        &self,
+        attestation: &model::CoordinatorAttestation,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let key = (
+            attestation.bitcoin_chain_tip,
+            attestation.coordinator_public_key,
+        );
+        store
+            .coordinator_attestations
+            .insert(key, attestation.clone());
+
+        Ok(())
+    }
+
+    async fn write_deposit_decision_receipt(
+        &self,
+        receipt: &model::DepositDecisionReceipt,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let receipts = store
+            .deposit_decision_receipts
+            .entry((receipt.txid, receipt.output_index))
+            .or_default();
+
+        if !receipts
+            .iter()
+            .any(|stored| stored.signer_pub_key == receipt.signer_pub_key)
+        {
+            receipts.push(receipt.clone());
+        }
+
+        Ok(())
+    }
+
+    async fn write_deposit_rejection(
+        &self,
+        rejection: &model::DepositRejection,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        store
+            .deposit_rejections
+            .insert((rejection.txid, rejection.output_index), rejection.clone());
+
+        Ok(())
+    }
+
+    async fn write_deposit_expiration(
+        &self,
+        expiration: &model::DepositExpiration,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        store
+            .deposit_expirations
+            .entry((expiration.txid, expiration.output_index))
+            .or_insert_with(|| expiration.clone());
+
+        Ok(())
+    }
+
+    async fn write_deposit_reclaim(&self, reclaim: &model::DepositReclaim) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let reclaims = store
+            .deposit_reclaims
+            .entry((reclaim.txid, reclaim.output_index))
+            .or_default();
+        let is_recorded = reclaims
+            .iter()
+            .any(|existing| existing.bitcoin_block_hash == reclaim.bitcoin_block_hash);
+        if !is_recorded {
+            reclaims.push(reclaim.clone());
+        }
+
+        Ok(())
+    }
+
+    async fn reactivate_deposit_request(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<bool, Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let expiration = store.deposit_expirations.get_mut(&(*txid, output_index));
+        match expiration {
+            Some(expiration) if !expiration.reactivated => {
+                expiration.reactivated = true;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn write_stacks_tx_submission(
+        &self,
+        submission: &model::StacksTxSubmission,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let existing = store
+            .stacks_tx_submissions
+            .iter_mut()
+            .find(|existing| existing.txid == submission.txid);
+        match existing {
+            Some(existing) => {
+                existing.status = submission.status;
+                existing.rejection_reason = submission.rejection_reason.clone();
+            }
+            None => store.stacks_tx_submissions.push(submission.clone()),
+        }
+
+        Ok(())
+    }
+
+    async fn set_stacks_tx_submission_status(
+        &self,
+        txid: &model::StacksTxId,
+        status: model::StacksTxStatus,
+    ) -> Result<bool, Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let submission = store
+            .stacks_tx_submissions
+            .iter_mut()
+            .find(|submission| &submission.txid == txid);
+        match submission {
+            Some(submission) => {
+                submission.status = status;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn write_bitcoin_broadcasts(
+        &self,
+        broadcasts: &[model::BitcoinBroadcast],
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        for broadcast in broadcasts {
+            let exists = store
+                .bitcoin_broadcasts
+                .iter()
+                .any(|existing| existing.txid == broadcast.txid);
+            if !exists {
+                store.bitcoin_broadcasts.push(broadcast.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn record_bitcoin_broadcast_attempt(
+        &self,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+        last_error: Option<&str>,
+    ) -> Result<bool, Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let broadcast = store
+            .bitcoin_broadcasts
+            .iter_mut()
+            .find(|broadcast| &broadcast.txid == txid);
+        match broadcast {
+            Some(broadcast) => {
+                broadcast.status = status;
+                broadcast.last_error = last_error.map(str::to_string);
+                broadcast.attempts += 1;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn set_bitcoin_broadcast_status(
+        &self,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+    ) -> Result<bool, Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let broadcast = store
+            .bitcoin_broadcasts
+            .iter_mut()
+            .find(|broadcast| &broadcast.txid == txid);
+        match broadcast {
+            Some(broadcast) => {
+                broadcast.status = status;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn write_quarantined_stacks_block(
+        &self,
+        block: &model::QuarantinedStacksBlock,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        store
+            .quarantined_stacks_blocks
+            .retain(|existing| existing.block_hash != block.block_hash);
+        store.quarantined_stacks_blocks.push(block.clone());
+
+        Ok(())
+    }
+
+    async fn write_signed_sighash(&self, sighash: &model::SigHash) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let Some(row) = store.bitcoin_sighashes.get(sighash) else {
+            return Ok(());
+        };
+        let key = (row.prevout_txid, row.prevout_output_index, row.chain_tip);
+        store.signed_sighashes.entry(key).or_insert(*sighash);
+
+        Ok(())
+    }
+
+    async fn write_mint_mismatch(&self, mismatch: &model::MintMismatch) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        store.mint_mismatches.push(mismatch.clone());
+
+        Ok(())
+    }
+
+    async fn write_signer_payment(&self, payment: &model::SignerPayment) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let exists = store.signer_payments.iter().any(|existing| {
+            existing.txid == payment.txid && existing.output_index == payment.output_index
+        });
+        if !exists {
+            store.signer_payments.push(payment.clone());
+        }
+
+        Ok(())
+    }
+
+    async fn set_signer_payment_status(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        status: model::SignerPaymentStatus,
+    ) -> Result<bool, Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let payment = store
+            .signer_payments
+            .iter_mut()
+            .find(|payment| &payment.txid == txid && payment.output_index == output_index);
+
+        match payment {
+            Some(payment) => {
+                payment.status = status;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn lock_prevouts(
+        &self,
+        locks: &[model::PrevoutLock],
+    ) -> Result<Option<model::PrevoutLock>, Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let key = |lock: &model::PrevoutLock| {
+            (lock.prevout_txid, lock.prevout_output_index, lock.chain_tip)
+        };
+        let conflict = locks.iter().find_map(|lock| {
+            store
+                .prevout_locks
+                .get(&key(lock))
+                .filter(|existing| existing.txid != lock.txid)
+        });
+        if let Some(conflict) = conflict {
+            return Ok(Some(conflict.clone()));
+        }
+
+        for lock in locks {
+            store.prevout_locks.insert(key(lock), lock.clone());
+        }
+
+        Ok(None)
+    }
+
+    async fn unlock_prevouts(&self, txid: &model::BitcoinTxId) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        store.prevout_locks.retain(|_, lock| &lock.txid != txid);
+
+        Ok(())
+    }
+
+    async fn write_tenure_plan(&self, plan: &model::TenurePlan) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        store.tenure_plans.push(plan.clone());
+
+        Ok(())
+    }
+
+    async fn write_block_processing_stage(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+        stage: model::BlockProcessingStage,
+        at: model::Timestamp,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        store
+            .bitcoin_block_processing
+            .entry(*block_hash)
+            .and_modify(|processing| processing.record(stage, at))
+            .or_insert_with(|| model::BitcoinBlockProcessing::new(*block_hash, stage, at));
+
+        Ok(())
+    }
+
+    async fn write_dkg_begin_refusal(&self, refusal: &model::DkgBeginRefusal) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        store.dkg_begin_refusals.push(refusal.clone());
+
+        Ok(())
+    }
+
+    async fn write_signer_key_rotation(
+        &self,
+        rotation: &model::SignerKeyRotation,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        store.signer_key_rotations.push(rotation.clone());
+
+        Ok(())
+    }
+
+    async fn set_signer_key_rotation_status(
+        &self,
+        new_public_key: &PublicKey,
+        status: model::SignerKeyRotationStatus,
+    ) -> Result<bool, Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let rotation = store
+            .signer_key_rotations
+            .iter_mut()
+            .find(|rotation| &rotation.new_public_key == new_public_key);
+        let Some(rotation) = rotation else {
+            return Ok(false);
+        };
+        rotation.status = status;
+
+        Ok(true)
+    }
+
+    async fn write_key_endorsement(
+        &self,
+        endorsement: &model::KeyEndorsement,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let exists = store.key_endorsements.iter().any(|existing| {
+            existing.old_public_key == endorsement.old_public_key
+                && existing.new_public_key == endorsement.new_public_key
+        });
+        if !exists {
+            store.key_endorsements.push(endorsement.clone());
+        }
+
+        Ok(())
+    }
+
+    async fn write_emergency_recovery_approval(
+        &self,
+        approval: &model::EmergencyRecoveryApproval,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let existing = store
+            .emergency_recovery_approvals
+            .iter_mut()
+            .find(|existing| {
+                existing.signer_public_key == approval.signer_public_key
+                    && existing.recovery_script_pubkey == approval.recovery_script_pubkey
+            });
+        match existing {
+            Some(existing) => *existing = approval.clone(),
+            None => store.emergency_recovery_approvals.push(approval.clone()),
+        }
+
+        Ok(())
+    }
+
+    async fn write_emergency_recovery_sweep(
+        &self,
+        sweep: &model::EmergencyRecoverySweep,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        if !store.emergency_recovery_sweeps.contains(sweep) {
+            store.emergency_recovery_sweeps.push(sweep.clone());
+        }
+
+        Ok(())
+    }
+
+    async fn write_deposit_decision_acknowledgement(
+        &self,
+        ack: &model::DepositDecisionAcknowledgement,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let key = (ack.txid, ack.output_index, ack.signer_pub_key);
+        store
+            .deposit_decision_acknowledgements
+            .entry(key)
+            .and_modify(|existing| {
+                existing.decision_version = existing.decision_version.max(ack.decision_version)
+            })
+            .or_insert_with(|| ack.clone());
+
+        Ok(())
+    }
+
+    async fn write_withdrawal_decision_acknowledgement(
+        &self,
+        ack: &model::WithdrawalDecisionAcknowledgement,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let key = (ack.request_id, ack.block_hash, ack.signer_pub_key);
+        store
+            .withdrawal_decision_acknowledgements
+            .entry(key)
+            .and_modify(|existing| {
+                existing.decision_version = existing.decision_version.max(ack.decision_version)
+            })
+            .or_insert_with(|| ack.clone());
+
+        Ok(())
+    }
+
+    async fn set_coordinator_paused(&self, paused: bool) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        store.coordinator_paused = paused;
+
+        Ok(())
+    }
+
+    async fn set_deposit_decision_override(
+        &self,
+        outpoint: &bitcoin::OutPoint,
+        can_accept: Option<bool>,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let txid = model::BitcoinTxId::from(outpoint.txid);
+        let key = (txid, outpoint.vout);
+        match can_accept {
+            Some(can_accept) => {
+                let decision_override = model::DepositDecisionOverride {
+                    txid,
+                    output_index: outpoint.vout,
+                    can_accept,
+                };
+                store
+                    .deposit_decision_overrides
+                    .insert(key, decision_override);
+            }
+            None => {
+                store.deposit_decision_overrides.remove(&key);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn set_withdrawal_decision_override(
+        &self,
+        id: &model::QualifiedRequestId,
+        accept: Option<bool>,
+    ) -> Result<(), Error> {
+        let mut store = self.lock().await;
+        store.version += 1;
+
+        let key = (id.request_id, id.block_hash);
+        match accept {
+            Some(accept) => {
+                let decision_override = model::WithdrawalDecisionOverride {
+                    request_id: id.request_id,
+                    txid: id.txid,
+                    block_hash: id.block_hash,
+                    accept,
+                };
+                store
+                    .withdrawal_decision_overrides
+                    .insert(key, decision_override);
+            }
+            None => {
+                store.withdrawal_decision_overrides.remove(&key);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl DbWrite for InMemoryTransaction {
@@ -437,6 +1053,33 @@ impl DbWrite for InMemoryTransaction {
         self.store.write_rotate_keys_transaction(key_rotation).await
     }
 
+    async fn register_aggregate_key(
+        &self,
+        shares: &model::EncryptedDkgShares,
+    ) -> Result<(), Error> {
+        self.store.register_aggregate_key(shares).await
+    }
+
+    async fn write_aggregate_key_rotation(
+        &self,
+        aggregate_key: &PublicKey,
+        txid: &model::StacksTxId,
+    ) -> Result<(), Error> {
+        self.store
+            .write_aggregate_key_rotation(aggregate_key, txid)
+            .await
+    }
+
+    async fn activate_aggregate_key(
+        &self,
+        aggregate_key: &PublicKey,
+        height: model::BitcoinBlockHeight,
+    ) -> Result<(), Error> {
+        self.store
+            .activate_aggregate_key(aggregate_key, height)
+            .await
+    }
+
     async fn write_withdrawal_reject_event(
         &self,
         event: &WithdrawalRejectEvent,
@@ -502,4 +1145,227 @@ impl DbWrite for InMemoryTransaction {
     {
         self.store.verify_dkg_shares(aggregate_key).await
     }
+
+    async fn write_coordinator_attestation(
+        &self,
+        attestation: &model::CoordinatorAttestation,
+    ) -> Result<(), Error> {
+        self.store.write_coordinator_attestation(attestation).await
+    }
+
+    async fn write_deposit_decision_receipt(
+        &self,
+        receipt: &model::DepositDecisionReceipt,
+    ) -> Result<(), Error> {
+        self.store.write_deposit_decision_receipt(receipt).await
+    }
+
+    async fn write_deposit_rejection(
+        &self,
+        rejection: &model::DepositRejection,
+    ) -> Result<(), Error> {
+        self.store.write_deposit_rejection(rejection).await
+    }
+
+    async fn write_deposit_expiration(
+        &self,
+        expiration: &model::DepositExpiration,
+    ) -> Result<(), Error> {
+        self.store.write_deposit_expiration(expiration).await
+    }
+
+    async fn write_deposit_reclaim(&self, reclaim: &model::DepositReclaim) -> Result<(), Error> {
+        self.store.write_deposit_reclaim(reclaim).await
+    }
+
+    async fn reactivate_deposit_request(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<bool, Error> {
+        self.store
+            .reactivate_deposit_request(txid, output_index)
+            .await
+    }
+
+    async fn write_stacks_tx_submission(
+        &self,
+        submission: &model::StacksTxSubmission,
+    ) -> Result<(), Error> {
+        self.store.write_stacks_tx_submission(submission).await
+    }
+
+    async fn set_stacks_tx_submission_status(
+        &self,
+        txid: &model::StacksTxId,
+        status: model::StacksTxStatus,
+    ) -> Result<bool, Error> {
+        self.store
+            .set_stacks_tx_submission_status(txid, status)
+            .await
+    }
+
+    async fn write_bitcoin_broadcasts(
+        &self,
+        broadcasts: &[model::BitcoinBroadcast],
+    ) -> Result<(), Error> {
+        self.store.write_bitcoin_broadcasts(broadcasts).await
+    }
+
+    async fn record_bitcoin_broadcast_attempt(
+        &self,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+        last_error: Option<&str>,
+    ) -> Result<bool, Error> {
+        self.store
+            .record_bitcoin_broadcast_attempt(txid, status, last_error)
+            .await
+    }
+
+    async fn set_bitcoin_broadcast_status(
+        &self,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+    ) -> Result<bool, Error> {
+        self.store.set_bitcoin_broadcast_status(txid, status).await
+    }
+
+    async fn write_quarantined_stacks_block(
+        &self,
+        block: &model::QuarantinedStacksBlock,
+    ) -> Result<(), Error> {
+        self.store.write_quarantined_stacks_block(block).await
+    }
+
+    async fn write_signed_sighash(&self, sighash: &model::SigHash) -> Result<(), Error> {
+        self.store.write_signed_sighash(sighash).await
+    }
+
+    async fn write_mint_mismatch(&self, mismatch: &model::MintMismatch) -> Result<(), Error> {
+        self.store.write_mint_mismatch(mismatch).await
+    }
+
+    async fn write_signer_payment(&self, payment: &model::SignerPayment) -> Result<(), Error> {
+        self.store.write_signer_payment(payment).await
+    }
+
+    async fn set_signer_payment_status(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        status: model::SignerPaymentStatus,
+    ) -> Result<bool, Error> {
+        self.store
+            .set_signer_payment_status(txid, output_index, status)
+            .await
+    }
+
+    async fn lock_prevouts(
+        &self,
+        locks: &[model::PrevoutLock],
+    ) -> Result<Option<model::PrevoutLock>, Error> {
+        self.store.lock_prevouts(locks).await
+    }
+
+    async fn unlock_prevouts(&self, txid: &model::BitcoinTxId) -> Result<(), Error> {
+        self.store.unlock_prevouts(txid).await
+    }
+
+    async fn write_tenure_plan(&self, plan: &model::TenurePlan) -> Result<(), Error> {
+        self.store.write_tenure_plan(plan).await
+    }
+
+    async fn write_block_processing_stage(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+        stage: model::BlockProcessingStage,
+        at: model::Timestamp,
+    ) -> Result<(), Error> {
+        self.store
+            .write_block_processing_stage(block_hash, stage, at)
+            .await
+    }
+
+    async fn write_dkg_begin_refusal(&self, refusal: &model::DkgBeginRefusal) -> Result<(), Error> {
+        self.store.write_dkg_begin_refusal(refusal).await
+    }
+
+    async fn write_signer_key_rotation(
+        &self,
+        rotation: &model::SignerKeyRotation,
+    ) -> Result<(), Error> {
+        self.store.write_signer_key_rotation(rotation).await
+    }
+
+    async fn set_signer_key_rotation_status(
+        &self,
+        new_public_key: &PublicKey,
+        status: model::SignerKeyRotationStatus,
+    ) -> Result<bool, Error> {
+        self.store
+            .set_signer_key_rotation_status(new_public_key, status)
+            .await
+    }
+
+    async fn write_key_endorsement(
+        &self,
+        endorsement: &model::KeyEndorsement,
+    ) -> Result<(), Error> {
+        self.store.write_key_endorsement(endorsement).await
+    }
+
+    async fn write_emergency_recovery_approval(
+        &self,
+        approval: &model::EmergencyRecoveryApproval,
+    ) -> Result<(), Error> {
+        self.store.write_emergency_recovery_approval(approval).await
+    }
+
+    async fn write_emergency_recovery_sweep(
+        &self,
+        sweep: &model::EmergencyRecoverySweep,
+    ) -> Result<(), Error> {
+        self.store.write_emergency_recovery_sweep(sweep).await
+    }
+
+    async fn write_deposit_decision_acknowledgement(
+        &self,
+        ack: &model::DepositDecisionAcknowledgement,
+    ) -> Result<(), Error> {
+        self.store.write_deposit_decision_acknowledgement(ack).await
+    }
+
+    async fn write_withdrawal_decision_acknowledgement(
+        &self,
+        ack: &model::WithdrawalDecisionAcknowledgement,
+    ) -> Result<(), Error> {
+        self.store
+            .write_withdrawal_decision_acknowledgement(ack)
+            .await
+    }
+
+    async fn set_coordinator_paused(&self, paused: bool) -> Result<(), Error> {
+        self.store.set_coordinator_paused(paused).await
+    }
+
+    async fn set_deposit_decision_override(
+        &self,
+        outpoint: &bitcoin::OutPoint,
+        can_accept: Option<bool>,
+    ) -> Result<(), Error> {
+        self.store
+            .set_deposit_decision_override(outpoint, can_accept)
+            .await
+    }
+
+    async fn set_withdrawal_decision_override(
+        &self,
+        id: &model::QualifiedRequestId,
+        accept: Option<bool>,
+    ) -> Result<(), Error> {
+        self.store
+            .set_withdrawal_decision_override(id, accept)
+            .await
+    }
 }
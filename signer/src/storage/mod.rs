@@ -14,6 +14,7 @@ pub mod sqlx;
 pub mod util;
 
 use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::future::Future;
 
 use blockstack_lib::types::chainstate::StacksBlockId;
@@ -96,12 +97,24 @@ pub trait DbRead {
         signer_public_key: &PublicKey,
     ) -> impl Future<Output = Result<Vec<model::DepositRequest>, Error>> + Send;
 
+    /// Get all deposit requests that have been confirmed within the
+    /// context window, regardless of whether they have been swept or
+    /// voted on.
+    fn get_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+    ) -> impl Future<Output = Result<Vec<model::DepositRequest>, Error>> + Send;
+
     /// Get pending deposit requests that have been accepted by at least
     /// `signatures_required` signers and has no responses.
     ///
     /// For an individual signer, 'accepted' means their blocklist client
     /// hasn't blocked the request and they are part of the signing set
-    /// that generated the aggregate key locking the deposit.
+    /// that generated the aggregate key locking the deposit. Deposit
+    /// requests that have expired and have not been re-activated are
+    /// excluded, and so are deposit requests that the depositor has
+    /// reclaimed on the blockchain identified by the chain tip.
     fn get_pending_accepted_deposit_requests(
         &self,
         chain_tip: &model::BitcoinBlockRef,
@@ -157,6 +170,15 @@ pub trait DbRead {
         signer_public_key: &PublicKey,
     ) -> impl Future<Output = Result<Vec<model::DepositSigner>, Error>> + Send;
 
+    /// Get the deposit decisions of the given signer for the given
+    /// deposit requests. Outpoints without a recorded decision from the
+    /// signer are omitted from the result.
+    fn get_deposit_signer_decisions_for_outpoints(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+        signer_public_key: &PublicKey,
+    ) -> impl Future<Output = Result<Vec<model::DepositSigner>, Error>> + Send;
+
     /// Get all the withdrawal decisions for the given signer in the given window
     /// of blocks.
     fn get_withdrawal_signer_decisions(
@@ -201,6 +223,16 @@ pub trait DbRead {
         signer_public_key: &PublicKey,
     ) -> impl Future<Output = Result<Vec<model::WithdrawalRequest>, Error>> + Send;
 
+    /// Get the withdrawal requests confirmed within the context window
+    /// that the given signer has made a decision on. This is the
+    /// complement of [`DbRead::get_pending_withdrawal_requests`].
+    fn get_decided_withdrawal_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> impl Future<Output = Result<Vec<model::WithdrawalRequest>, Error>> + Send;
+
     /// This function returns withdrawal requests filtered by a portion of the
     /// consensus critera defined in #741.
     ///
@@ -266,6 +298,35 @@ pub trait DbRead {
         signer_public_key: &PublicKey,
     ) -> impl Future<Output = Result<Option<WithdrawalRequestReport>, Error>> + Send;
 
+    /// Get all withdrawal requests whose withdrawal UTXO pays to the given
+    /// scriptPubKey, ordered by request ID.
+    ///
+    /// This does not take the canonical blockchain into account, so a
+    /// request that was confirmed on more than one stacks fork is
+    /// returned once for each fork.
+    fn get_withdrawal_requests_by_recipient(
+        &self,
+        recipient: &model::ScriptPubKey,
+    ) -> impl Future<Output = Result<Vec<model::WithdrawalRequest>, Error>> + Send;
+
+    /// Get all withdrawal requests initiated by the given stacks
+    /// principal, ordered by request ID.
+    ///
+    /// Like [`DbRead::get_withdrawal_requests_by_recipient`], this does
+    /// not take the canonical blockchain into account.
+    fn get_withdrawal_requests_by_sender(
+        &self,
+        sender: &model::StacksPrincipal,
+    ) -> impl Future<Output = Result<Vec<model::WithdrawalRequest>, Error>> + Send;
+
+    /// Get the withdrawal request with the given request ID that was
+    /// created in the stacks block with the given ID.
+    fn get_withdrawal_request(
+        &self,
+        request_id: u64,
+        block_hash: &model::StacksBlockHash,
+    ) -> impl Future<Output = Result<Option<model::WithdrawalRequest>, Error>> + Send;
+
     /// This function returns the total amount of BTC (in sats) that has
     /// been swept out and confirmed on the bitcoin blockchain identified
     /// by the given chain tip and context window.
@@ -302,12 +363,31 @@ pub trait DbRead {
         &self,
     ) -> impl Future<Output = Result<Option<model::EncryptedDkgShares>, Error>> + Send;
 
+    /// Return all DKG shares in the database, ordered from the oldest to
+    /// the most recent.
+    fn get_all_encrypted_dkg_shares(
+        &self,
+    ) -> impl Future<Output = Result<Vec<model::EncryptedDkgShares>, Error>> + Send;
+
     /// Return the most recent DKG shares that have passed verification,
     /// and return None if no such shares exist.
     fn get_latest_verified_dkg_shares(
         &self,
     ) -> impl Future<Output = Result<Option<model::EncryptedDkgShares>, Error>> + Send;
 
+    /// Return the entry for the given aggregate key in the registry of
+    /// aggregate keys, if there is one.
+    fn get_aggregate_key_record(
+        &self,
+        aggregate_key: &PublicKey,
+    ) -> impl Future<Output = Result<Option<model::AggregateKeyRecord>, Error>> + Send;
+
+    /// Return all entries in the registry of aggregate keys, ordered from
+    /// the oldest DKG round to the most recent one.
+    fn get_aggregate_key_records(
+        &self,
+    ) -> impl Future<Output = Result<Vec<model::AggregateKeyRecord>, Error>> + Send;
+
     /// Returns the number of non-failed DKG shares entries in the database.
     fn get_encrypted_dkg_shares_count(&self) -> impl Future<Output = Result<u32, Error>> + Send;
 
@@ -359,6 +439,16 @@ pub trait DbRead {
         aggregate_key: &PublicKey,
     ) -> impl Future<Output = Result<model::SignerVotes, Error>> + Send;
 
+    /// For each of the given outpoints and the aggregate key, get the list
+    /// of all signer votes in the signer set. This is the batched version
+    /// of [`DbRead::get_deposit_request_signer_votes`], and the returned
+    /// map has an entry for every given outpoint.
+    fn get_deposit_requests_signer_votes(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+        aggregate_key: &PublicKey,
+    ) -> impl Future<Output = Result<HashMap<bitcoin::OutPoint, model::SignerVotes>, Error>> + Send;
+
     /// For the given withdrawal request identifier, and aggregate key, get
     /// the list for how the signers voted against the request.
     fn get_withdrawal_request_signer_votes(
@@ -448,11 +538,253 @@ pub trait DbRead {
         output_index: u32,
     ) -> impl Future<Output = Result<Option<model::DepositRequest>, Error>> + Send;
 
+    /// Get all deposit requests that mint sBTC to the given stacks
+    /// principal, ordered by their outpoint.
+    fn get_deposit_requests_by_recipient(
+        &self,
+        recipient: &model::StacksPrincipal,
+    ) -> impl Future<Output = Result<Vec<model::DepositRequest>, Error>> + Send;
+
+    /// Get the `completed-deposit` events for the deposit request
+    /// identified by the given transaction id and output index.
+    ///
+    /// This does not take the canonical blockchain into account, so there
+    /// may be more than one event if the contract call was confirmed on
+    /// more than one stacks fork.
+    fn get_completed_deposit_events(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> impl Future<Output = Result<Vec<CompletedDepositEvent>, Error>> + Send;
+
     /// Get the bitcoin sighash output.
     fn will_sign_bitcoin_tx_sighash(
         &self,
         sighash: &model::SigHash,
     ) -> impl Future<Output = Result<Option<(bool, PublicKeyXOnly)>, Error>> + Send;
+
+    /// Get the coordinator attestations that were made for the given
+    /// bitcoin chain tip.
+    fn get_coordinator_attestations(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> impl Future<Output = Result<Vec<model::CoordinatorAttestation>, Error>> + Send;
+
+    /// Get the decision receipts that signers have sent us for the given
+    /// deposit request.
+    fn get_deposit_decision_receipts(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> impl Future<Output = Result<Vec<model::DepositDecisionReceipt>, Error>> + Send;
+
+    /// Get the record of this signer rejecting the given deposit request
+    /// because of its local policy, if there is one.
+    fn get_deposit_rejection(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> impl Future<Output = Result<Option<model::DepositRejection>, Error>> + Send;
+
+    /// Get the record of the given deposit request expiring, if there is
+    /// one.
+    fn get_deposit_expiration(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> impl Future<Output = Result<Option<model::DepositExpiration>, Error>> + Send;
+
+    /// Get the deposit requests for the given outpoints. Outpoints that
+    /// we do not have a record of a deposit request for are omitted from
+    /// the result.
+    fn get_deposit_requests_for_outpoints(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+    ) -> impl Future<Output = Result<Vec<model::DepositRequest>, Error>> + Send;
+
+    /// Get the record of the given deposit request being reclaimed by the
+    /// depositor in a bitcoin block on the blockchain identified by the
+    /// given chain tip, if there is one.
+    fn get_deposit_reclaim(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> impl Future<Output = Result<Option<model::DepositReclaim>, Error>> + Send;
+
+    /// Get the part of the fee of the given sweep transaction that was
+    /// assessed to the deposit with the given outpoint, when the sweep
+    /// was confirmed in the given bitcoin block.
+    ///
+    /// This returns `None` if we have no record of the sweep transaction
+    /// in that block sweeping the deposit, or if the sweep was recorded
+    /// before we started recording assessed fees.
+    fn get_deposit_assessed_fee(
+        &self,
+        sweep_txid: &model::BitcoinTxId,
+        sweep_block_hash: &model::BitcoinBlockHash,
+        outpoint: &bitcoin::OutPoint,
+    ) -> impl Future<Output = Result<Option<u64>, Error>> + Send;
+
+    /// Get the most recently written tenure plan, if any.
+    fn get_latest_tenure_plan(
+        &self,
+    ) -> impl Future<Output = Result<Option<model::TenurePlan>, Error>> + Send;
+
+    /// Get the record of when this signer reached each stage in the
+    /// processing of the bitcoin block with the given hash, if any.
+    fn get_bitcoin_block_processing(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+    ) -> impl Future<Output = Result<Option<model::BitcoinBlockProcessing>, Error>> + Send;
+
+    /// Get the records of all stacks transactions submitted by this signer
+    /// that fulfill any of the requests identified by the given keys,
+    /// ordered from the oldest submission to the most recent one.
+    fn get_stacks_tx_submissions(
+        &self,
+        request_keys: &[String],
+    ) -> impl Future<Output = Result<Vec<model::StacksTxSubmission>, Error>> + Send;
+
+    /// Get the records of all stacks transactions submitted by this signer
+    /// that are still pending, ordered from the oldest submission to the
+    /// most recent one.
+    fn get_pending_stacks_tx_submissions(
+        &self,
+    ) -> impl Future<Output = Result<Vec<model::StacksTxSubmission>, Error>> + Send;
+
+    /// Get the signed bitcoin transactions in the queue of the broadcaster
+    /// that are pending or broadcast but not confirmed, ordered from the
+    /// oldest package to the most recent one and by their position in
+    /// their package.
+    fn get_unsettled_bitcoin_broadcasts(
+        &self,
+    ) -> impl Future<Output = Result<Vec<model::BitcoinBroadcast>, Error>> + Send;
+
+    /// Get all stacks blocks that failed verification, ordered from the
+    /// most recent failure to the oldest one.
+    fn get_quarantined_stacks_blocks(
+        &self,
+    ) -> impl Future<Output = Result<Vec<model::QuarantinedStacksBlock>, Error>> + Send;
+
+    /// Get the sighash that this signer has already signed for the same
+    /// prevout and chain tip as the given sighash, if that sighash is a
+    /// different one.
+    fn get_conflicting_signed_sighash(
+        &self,
+        sighash: &model::SigHash,
+    ) -> impl Future<Output = Result<Option<model::SigHash>, Error>> + Send;
+
+    /// Get the recorded mismatches of `complete-deposit` sign requests for
+    /// the deposit request with the given outpoint, oldest first.
+    fn get_mint_mismatches(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> impl Future<Output = Result<Vec<model::MintMismatch>, Error>> + Send;
+
+    /// Get the IDs of the bitcoin transactions that the signers validated
+    /// during pre-sign requests and that include an output fulfilling the
+    /// identified withdrawal request.
+    fn get_withdrawal_sweep_txids(
+        &self,
+        id: &model::QualifiedRequestId,
+    ) -> impl Future<Output = Result<Vec<model::BitcoinTxId>, Error>> + Send;
+
+    /// Get the payment to the signers' scriptPubKey that created the
+    /// output with the given outpoint, if it was recorded.
+    fn get_signer_payment(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> impl Future<Output = Result<Option<model::SignerPayment>, Error>> + Send;
+
+    /// Get all recorded payments to the signers' scriptPubKey with the
+    /// given status, oldest first.
+    fn get_signer_payments(
+        &self,
+        status: model::SignerPaymentStatus,
+    ) -> impl Future<Output = Result<Vec<model::SignerPayment>, Error>> + Send;
+
+    /// Get the payments to the signers' scriptPubKey that the operator of
+    /// this signer approved for a refund, and that were confirmed but not
+    /// spent on the blockchain identified by the given chain tip.
+    fn get_pending_refunds(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> impl Future<Output = Result<Vec<model::SignerPayment>, Error>> + Send;
+
+    /// Get the payments to the signers' scriptPubKey, whatever their
+    /// status, that were confirmed but not spent on the blockchain
+    /// identified by the given chain tip.
+    fn get_unspent_signer_payments(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> impl Future<Output = Result<Vec<model::SignerPayment>, Error>> + Send;
+
+    /// Get the most recent refusal of this signer to begin a DKG round,
+    /// if any.
+    fn get_latest_dkg_begin_refusal(
+        &self,
+    ) -> impl Future<Output = Result<Option<model::DkgBeginRefusal>, Error>> + Send;
+
+    /// Get the most recently started rotation of the key of this signer,
+    /// if any.
+    fn get_latest_signer_key_rotation(
+        &self,
+    ) -> impl Future<Output = Result<Option<model::SignerKeyRotation>, Error>> + Send;
+
+    /// Get the endorsements of new keys that this signer received from
+    /// the other signers, oldest first.
+    fn get_key_endorsements(
+        &self,
+    ) -> impl Future<Output = Result<Vec<model::KeyEndorsement>, Error>> + Send;
+
+    /// Get the latest approval or revocation of each signer of the
+    /// emergency recovery of the funds of the signers to the given
+    /// scriptPubKey, in the order of their bitcoin block heights.
+    fn get_emergency_recovery_approvals(
+        &self,
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> impl Future<Output = Result<Vec<model::EmergencyRecoveryApproval>, Error>> + Send;
+
+    /// Whether one of the emergency recovery sweeps to the given
+    /// scriptPubKey that this signer agreed to sign was confirmed on the
+    /// blockchain identified by the given chain tip.
+    fn is_emergency_recovery_swept(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> impl Future<Output = Result<bool, Error>> + Send;
+
+    /// Get the acknowledgements, from the other signers, of this signer's
+    /// decisions on the given deposit requests.
+    fn get_deposit_decision_acknowledgements(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+    ) -> impl Future<Output = Result<Vec<model::DepositDecisionAcknowledgement>, Error>> + Send;
+
+    /// Get the acknowledgements, from the other signers, of this signer's
+    /// decisions on the given withdrawal requests.
+    fn get_withdrawal_decision_acknowledgements(
+        &self,
+        ids: &[model::QualifiedRequestId],
+    ) -> impl Future<Output = Result<Vec<model::WithdrawalDecisionAcknowledgement>, Error>> + Send;
+
+    /// Whether the operator paused the coordinator duties of this signer.
+    fn is_coordinator_paused(&self) -> impl Future<Output = Result<bool, Error>> + Send;
+
+    /// Get the operator overrides of this signer's decisions on deposit
+    /// requests.
+    fn get_deposit_decision_overrides(
+        &self,
+    ) -> impl Future<Output = Result<Vec<model::DepositDecisionOverride>, Error>> + Send;
+
+    /// Get the operator overrides of this signer's decisions on withdrawal
+    /// requests.
+    fn get_withdrawal_decision_overrides(
+        &self,
+    ) -> impl Future<Output = Result<Vec<model::WithdrawalDecisionOverride>, Error>> + Send;
 }
 
 /// Represents the ability to write data to the signer storage.
@@ -487,13 +819,18 @@ pub trait DbWrite {
         request: &model::WithdrawalRequest,
     ) -> impl Future<Output = Result<(), Error>> + Send;
 
-    /// Write a signer decision for a deposit request.
+    /// Write a signer decision for a deposit request. An existing decision
+    /// of the same signer on the request is only replaced if the given
+    /// decision supersedes it, see [`model::DepositSigner::supersedes`].
     fn write_deposit_signer_decision(
         &self,
         decision: &model::DepositSigner,
     ) -> impl Future<Output = Result<(), Error>> + Send;
 
-    /// Write a signer decision for a withdrawal request.
+    /// Write a signer decision for a withdrawal request. An existing
+    /// decision of the same signer on the request is only replaced if the
+    /// given decision supersedes it, see
+    /// [`model::WithdrawalSigner::supersedes`].
     fn write_withdrawal_signer_decision(
         &self,
         decision: &model::WithdrawalSigner,
@@ -529,6 +866,35 @@ pub trait DbWrite {
         key_rotation: &model::KeyRotationEvent,
     ) -> impl Future<Output = Result<(), Error>> + Send;
 
+    /// Add the aggregate key of the given DKG shares to the registry of
+    /// aggregate keys. If the key is already in the registry then only the
+    /// status of its DKG shares is updated.
+    fn register_aggregate_key(
+        &self,
+        shares: &model::EncryptedDkgShares,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Record the ID of the rotate-keys transaction for the given aggregate
+    /// key in the registry of aggregate keys. Nothing is written if the
+    /// key is not in the registry.
+    fn write_aggregate_key_rotation(
+        &self,
+        aggregate_key: &PublicKey,
+        txid: &model::StacksTxId,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Record that the given aggregate key is the aggregate key in the
+    /// registry contract as of the bitcoin block with the given height.
+    ///
+    /// The activation height of the key is only set the first time that
+    /// it is activated, and every other active key in the registry of
+    /// aggregate keys is retired at the given height.
+    fn activate_aggregate_key(
+        &self,
+        aggregate_key: &PublicKey,
+        height: model::BitcoinBlockHeight,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
     /// Write the withdrawal-reject event to the database.
     fn write_withdrawal_reject_event(
         &self,
@@ -577,6 +943,250 @@ pub trait DbWrite {
         withdrawals_outputs: &[model::BitcoinWithdrawalOutput],
     ) -> impl Future<Output = Result<(), Error>> + Send;
 
+    /// Write a coordinator attestation to the database.
+    fn write_coordinator_attestation(
+        &self,
+        attestation: &model::CoordinatorAttestation,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write a signer's decision receipt for a deposit request.
+    fn write_deposit_decision_receipt(
+        &self,
+        receipt: &model::DepositDecisionReceipt,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write the record of this signer rejecting a deposit request because
+    /// of its local policy, replacing any existing record for the request.
+    fn write_deposit_rejection(
+        &self,
+        rejection: &model::DepositRejection,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write the record of a deposit request expiring. This is a no-op if
+    /// the deposit request has already been marked as expired.
+    fn write_deposit_expiration(
+        &self,
+        expiration: &model::DepositExpiration,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write the record of a deposit request being reclaimed by the
+    /// depositor. This is a no-op if we already have a record of the
+    /// deposit request being reclaimed in the same bitcoin block.
+    fn write_deposit_reclaim(
+        &self,
+        reclaim: &model::DepositReclaim,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Re-activate an expired deposit request so that it is considered for
+    /// sweeping again. Returns `true` if the deposit request was expired
+    /// and has been re-activated by this call.
+    fn reactivate_deposit_request(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> impl Future<Output = Result<bool, Error>> + Send;
+
+    /// Write the record of a submitted stacks transaction. If there is
+    /// already a record for the transaction, its status and rejection
+    /// reason are replaced with the ones given here.
+    fn write_stacks_tx_submission(
+        &self,
+        submission: &model::StacksTxSubmission,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Update the status of a submitted stacks transaction. Returns `true`
+    /// if there was a record for the transaction.
+    fn set_stacks_tx_submission_status(
+        &self,
+        txid: &model::StacksTxId,
+        status: model::StacksTxStatus,
+    ) -> impl Future<Output = Result<bool, Error>> + Send;
+
+    /// Add the given signed bitcoin transactions to the queue of the
+    /// broadcaster. Transactions that are already in the queue are left
+    /// as they are.
+    fn write_bitcoin_broadcasts(
+        &self,
+        broadcasts: &[model::BitcoinBroadcast],
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Record an attempt to broadcast a queued bitcoin transaction,
+    /// updating its status and last error. Returns `true` if there was a
+    /// record for the transaction.
+    fn record_bitcoin_broadcast_attempt(
+        &self,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+        last_error: Option<&str>,
+    ) -> impl Future<Output = Result<bool, Error>> + Send;
+
+    /// Update the status of a queued bitcoin transaction. Returns `true`
+    /// if there was a record for the transaction.
+    fn set_bitcoin_broadcast_status(
+        &self,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+    ) -> impl Future<Output = Result<bool, Error>> + Send;
+
+    /// Write a stacks block that failed verification to the quarantine.
+    /// If the block is already quarantined, its reason is replaced with
+    /// the one given here.
+    fn write_quarantined_stacks_block(
+        &self,
+        block: &model::QuarantinedStacksBlock,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Record that this signer is about to produce a signature share for
+    /// the given sighash, keyed by the prevout that it spends and the
+    /// chain tip of its sign request. The sighash must already have been
+    /// written with [`DbWrite::write_bitcoin_txs_sighashes`]. If a sighash
+    /// has already been recorded for the prevout and chain tip then this
+    /// is a no-op.
+    fn write_signed_sighash(
+        &self,
+        sighash: &model::SigHash,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write the record of a `complete-deposit` sign request that did not
+    /// match the deposit request that it mints for.
+    fn write_mint_mismatch(
+        &self,
+        mismatch: &model::MintMismatch,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write a payment to the signers' scriptPubKey that was not a
+    /// deposit. Nothing is written if the payment was already recorded.
+    fn write_signer_payment(
+        &self,
+        payment: &model::SignerPayment,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Set the status of the payment to the signers' scriptPubKey that
+    /// created the output with the given outpoint. Returns `false` if the
+    /// payment was not recorded.
+    fn set_signer_payment_status(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        status: model::SignerPaymentStatus,
+    ) -> impl Future<Output = Result<bool, Error>> + Send;
+
+    /// Lock the prevouts of a bitcoin transaction for its signing rounds.
+    /// If any of the prevouts is already locked by a different
+    /// transaction at the same chain tip then none of the locks are
+    /// written and the conflicting lock is returned.
+    fn lock_prevouts(
+        &self,
+        locks: &[model::PrevoutLock],
+    ) -> impl Future<Output = Result<Option<model::PrevoutLock>, Error>> + Send;
+
+    /// Release all prevout locks held by the given bitcoin transaction.
+    fn unlock_prevouts(
+        &self,
+        txid: &model::BitcoinTxId,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write the plan of a coordinator for its tenure.
+    fn write_tenure_plan(
+        &self,
+        plan: &model::TenurePlan,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Record that this signer reached the given stage in the processing
+    /// of the bitcoin block with the given hash at the given time. If the
+    /// stage was already recorded for the block then the earlier time is
+    /// kept.
+    fn write_block_processing_stage(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+        stage: model::BlockProcessingStage,
+        at: model::Timestamp,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write a refusal of this signer to begin a DKG round.
+    fn write_dkg_begin_refusal(
+        &self,
+        refusal: &model::DkgBeginRefusal,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write a rotation of the key of this signer.
+    fn write_signer_key_rotation(
+        &self,
+        rotation: &model::SignerKeyRotation,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Set the status of the rotation of the key of this signer to the
+    /// given new key. Returns whether there was such a rotation.
+    fn set_signer_key_rotation_status(
+        &self,
+        new_public_key: &PublicKey,
+        status: model::SignerKeyRotationStatus,
+    ) -> impl Future<Output = Result<bool, Error>> + Send;
+
+    /// Write an endorsement of a new key that this signer received from
+    /// another signer. Endorsements that were already written are kept
+    /// as they are.
+    fn write_key_endorsement(
+        &self,
+        endorsement: &model::KeyEndorsement,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write an approval, or a revocation of an approval, of the
+    /// emergency recovery of the funds of the signers. This replaces the
+    /// earlier approval or revocation of the same signer for the same
+    /// recovery scriptPubKey.
+    fn write_emergency_recovery_approval(
+        &self,
+        approval: &model::EmergencyRecoveryApproval,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write an emergency recovery sweep that this signer agreed to sign.
+    fn write_emergency_recovery_sweep(
+        &self,
+        sweep: &model::EmergencyRecoverySweep,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write the acknowledgement of one of this signer's decisions on a
+    /// deposit request. Only the latest acknowledged version of the
+    /// decision is kept for each signer.
+    fn write_deposit_decision_acknowledgement(
+        &self,
+        ack: &model::DepositDecisionAcknowledgement,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Write the acknowledgement of one of this signer's decisions on a
+    /// withdrawal request. Only the latest acknowledged version of the
+    /// decision is kept for each signer.
+    fn write_withdrawal_decision_acknowledgement(
+        &self,
+        ack: &model::WithdrawalDecisionAcknowledgement,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Set whether the operator paused the coordinator duties of this
+    /// signer.
+    fn set_coordinator_paused(
+        &self,
+        paused: bool,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Set the operator override of this signer's decision on the deposit
+    /// request with the given outpoint. Passing `None` removes any
+    /// override.
+    fn set_deposit_decision_override(
+        &self,
+        outpoint: &bitcoin::OutPoint,
+        can_accept: Option<bool>,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Set the operator override of this signer's decision on the
+    /// withdrawal request with the given ID. Passing `None` removes any
+    /// override.
+    fn set_withdrawal_decision_override(
+        &self,
+        id: &model::QualifiedRequestId,
+        accept: Option<bool>,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
     /// Marks the stored DKG shares for the provided aggregate key as revoked
     /// and thus should no longer be used.
     ///
@@ -95,6 +95,14 @@ pub struct TxPrevout {
     pub amount: u64,
     /// The type prevout we are referring to.
     pub prevout_type: TxPrevoutType,
+    /// The part of the transaction fee that was assessed to this prevout.
+    /// This is only set for deposit prevouts, and only when the fee of
+    /// the transaction was known when the prevout was recorded. Use
+    /// [`DbRead::get_deposit_assessed_fee`](crate::storage::DbRead::get_deposit_assessed_fee)
+    /// to read it back.
+    #[sqlx(skip)]
+    #[cfg_attr(feature = "testing", dummy(default))]
+    pub assessed_fee: Option<u64>,
 }
 
 /// Bitcoin block.
@@ -182,12 +190,10 @@ impl StacksBlock {
 
 /// Deposit request.
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, sqlx::FromRow)]
-#[cfg_attr(feature = "testing", derive(fake::Dummy))]
 pub struct DepositRequest {
     /// Transaction ID of the deposit request transaction.
     pub txid: BitcoinTxId,
     /// Index of the deposit request UTXO.
-    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
     #[sqlx(try_from = "i32")]
     pub output_index: u32,
     /// Script spendable by the sBTC signers.
@@ -201,25 +207,18 @@ pub struct DepositRequest {
     pub recipient: StacksPrincipal,
     /// The amount in the deposit UTXO.
     #[sqlx(try_from = "i64")]
-    #[cfg_attr(feature = "testing", dummy(faker = "1_000_000..1_000_000_000"))]
     pub amount: u64,
     /// The maximum portion of the deposited amount that may
     /// be used to pay for transaction fees.
     #[sqlx(try_from = "i64")]
-    #[cfg_attr(feature = "testing", dummy(faker = "100..100_000"))]
     pub max_fee: u64,
     /// The relative lock time in the reclaim script.
     #[sqlx(try_from = "i64")]
-    #[cfg_attr(feature = "testing", dummy(faker = "3..u16::MAX as u32"))]
     pub lock_time: u32,
     /// The public key used in the deposit script. The signers public key
     /// is for Schnorr signatures.
     pub signers_public_key: PublicKeyXOnly,
     /// The addresses of the input UTXOs funding the deposit request.
-    #[cfg_attr(
-        feature = "testing",
-        dummy(faker = "crate::testing::dummy::BitcoinAddresses(1..5)")
-    )]
     pub sender_script_pub_keys: Vec<ScriptPubKey>,
 }
 
@@ -277,6 +276,32 @@ pub struct DepositSigner {
     /// This specifies whether the indicated signer_pub_key can sign for
     /// the associated deposit request.
     pub can_sign: bool,
+    /// The version of the decision. See
+    /// [`WithdrawalSigner::decision_version`].
+    #[sqlx(try_from = "i32")]
+    #[cfg_attr(feature = "testing", dummy(faker = "0..i32::MAX as u32"))]
+    pub decision_version: u32,
+}
+
+impl DepositSigner {
+    /// Whether this decision replaces the given decision of the same
+    /// signer on the same deposit request.
+    ///
+    /// The decision with the higher version wins. Two different decisions
+    /// with the same version should never be sent by an honest signer,
+    /// but if they are then the more cautious one wins, so that every
+    /// signer settles on the same decision whatever order they receive
+    /// them in.
+    pub fn supersedes(&self, other: &DepositSigner) -> bool {
+        let key = |decision: &DepositSigner| {
+            (
+                decision.decision_version,
+                !decision.can_accept,
+                !decision.can_sign,
+            )
+        };
+        key(self) > key(other)
+    }
 }
 
 /// Withdrawal request.
@@ -358,9 +383,24 @@ pub struct WithdrawalSigner {
     pub signer_pub_key: PublicKey,
     /// Signals if the signer is prepared to sign for this request.
     pub is_accepted: bool,
+    /// The version of the decision. A signer starts at zero and bumps the
+    /// version each time it changes its decision, after re-validating the
+    /// request or because an operator overrode it. See
+    /// [`WithdrawalSigner::supersedes`] for which of two decisions wins.
+    #[sqlx(try_from = "i32")]
+    #[cfg_attr(feature = "testing", dummy(faker = "0..i32::MAX as u32"))]
+    pub decision_version: u32,
 }
 
 impl WithdrawalSigner {
+    /// Whether this decision replaces the given decision of the same
+    /// signer on the same withdrawal request. This follows the same rules
+    /// as [`DepositSigner::supersedes`].
+    pub fn supersedes(&self, other: &WithdrawalSigner) -> bool {
+        let key = |decision: &WithdrawalSigner| (decision.decision_version, !decision.is_accepted);
+        key(self) > key(other)
+    }
+
     /// Return the identifier for the withdrawal request.
     pub fn qualified_id(&self) -> QualifiedRequestId {
         QualifiedRequestId {
@@ -540,6 +580,68 @@ impl From<EncryptedDkgShares> for SignerSetInfo {
     }
 }
 
+/// An entry in the registry of aggregate keys.
+///
+/// There is an entry for the aggregate key of each DKG round that this
+/// signer took part in, recording where the key is in its lifecycle: from
+/// DKG, through verification and the rotate-keys contract call, to being
+/// the aggregate key in the sBTC registry contract and then being replaced
+/// by another key.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct AggregateKeyRecord {
+    /// The aggregate key.
+    pub aggregate_key: PublicKey,
+    /// The bitcoin chain tip when the DKG round for the key started.
+    pub dkg_block_hash: BitcoinBlockHash,
+    /// The height of the bitcoin chain tip when the DKG round started.
+    pub dkg_block_height: BitcoinBlockHeight,
+    /// The verification status of the DKG shares for the key.
+    pub dkg_shares_status: DkgSharesStatus,
+    /// The ID of the rotate-keys stacks transaction for the key, if one
+    /// has been submitted or observed.
+    pub rotate_keys_txid: Option<StacksTxId>,
+    /// The height of the first bitcoin block at which the key was the
+    /// aggregate key in the registry contract.
+    pub activation_height: Option<BitcoinBlockHeight>,
+    /// The height of the first bitcoin block at which another key replaced
+    /// this one in the registry contract.
+    pub retirement_height: Option<BitcoinBlockHeight>,
+}
+
+impl AggregateKeyRecord {
+    /// Whether this is the aggregate key in the registry contract.
+    pub fn is_active(&self) -> bool {
+        self.activation_height.is_some() && self.retirement_height.is_none()
+    }
+
+    /// Whether this key was the aggregate key in the registry contract at
+    /// the given bitcoin block height.
+    pub fn was_active_at(&self, height: BitcoinBlockHeight) -> bool {
+        let Some(activation_height) = self.activation_height else {
+            return false;
+        };
+        activation_height <= height
+            && self
+                .retirement_height
+                .is_none_or(|retirement_height| height < retirement_height)
+    }
+}
+
+impl From<&EncryptedDkgShares> for AggregateKeyRecord {
+    fn from(shares: &EncryptedDkgShares) -> Self {
+        AggregateKeyRecord {
+            aggregate_key: shares.aggregate_key,
+            dkg_block_hash: shares.started_at_bitcoin_block_hash,
+            dkg_block_height: shares.started_at_bitcoin_block_height,
+            dkg_shares_status: shares.dkg_shares_status,
+            rotate_keys_txid: None,
+            activation_height: None,
+            retirement_height: None,
+        }
+    }
+}
+
 /// Persisted public DKG shares from other signers
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, sqlx::FromRow)]
 #[cfg_attr(feature = "testing", derive(fake::Dummy))]
@@ -631,8 +733,11 @@ impl From<SignerVotes> for BitArray<[u8; 16]> {
 }
 
 /// The possible states for DKG shares.
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, sqlx::Type)]
+#[derive(
+    Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, sqlx::Type, Serialize, Deserialize,
+)]
 #[sqlx(type_name = "dkg_shares_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "testing", derive(fake::Dummy))]
 pub enum DkgSharesStatus {
     /// The DKG shares have not passed or failed verification.
@@ -789,7 +894,7 @@ impl std::fmt::Display for BitcoinTxId {
 }
 
 /// Bitcoin block hash
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct BitcoinBlockHash(bitcoin::BlockHash);
 
@@ -897,6 +1002,16 @@ impl From<&BitcoinBlock> for BitcoinBlockRef {
     }
 }
 
+impl BitcoinBlockRef {
+    /// The number of confirmations that a transaction in the block at the
+    /// given height has when this block is the chain tip. A transaction in
+    /// this block has one confirmation, while a transaction in a block
+    /// above this one has none.
+    pub fn confirmations(&self, block_height: BitcoinBlockHeight) -> u64 {
+        (*self.block_height + 1).saturating_sub(*block_height)
+    }
+}
+
 impl AsRef<BitcoinBlockHash> for BitcoinBlockRef {
     fn as_ref(&self) -> &BitcoinBlockHash {
         &self.block_hash
@@ -1209,6 +1324,713 @@ pub struct BitcoinWithdrawalOutput {
     pub is_valid_tx: bool,
 }
 
+/// A signed attestation from a coordinator summarizing the sweep
+/// transactions that it broadcast during its tenure.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct CoordinatorAttestation {
+    /// The bitcoin chain tip of the coordinator's tenure.
+    pub bitcoin_chain_tip: BitcoinBlockHash,
+    /// The public key of the coordinator that made the attestation.
+    pub coordinator_public_key: PublicKey,
+    /// The IDs of the sweep transactions that the coordinator claims to
+    /// have broadcast.
+    pub sweep_txids: Vec<BitcoinTxId>,
+    /// The protobuf encoded signed message containing the attestation,
+    /// kept so that the coordinator's signature can be verified later.
+    pub message: Bytes,
+}
+
+/// A signer's signed receipt of its decision to accept a deposit request.
+///
+/// Receipts from enough signers in the signing set form a
+/// [`DepositQuorumCertificate`](crate::message::DepositQuorumCertificate)
+/// for the deposit request.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct DepositDecisionReceipt {
+    /// TxID of the deposit request.
+    pub txid: BitcoinTxId,
+    /// Output index of the deposit request.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub output_index: u32,
+    /// Public key of the signer that accepted the deposit request.
+    pub signer_pub_key: PublicKey,
+    /// The compact encoded ECDSA signature of the signer over the receipt
+    /// digest of the deposit request.
+    pub signature: Bytes,
+}
+
+/// Another signer's acknowledgement that it stored this signer's decision
+/// on a deposit request.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct DepositDecisionAcknowledgement {
+    /// TxID of the deposit request.
+    pub txid: BitcoinTxId,
+    /// Output index of the deposit request.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub output_index: u32,
+    /// Public key of the signer that acknowledged the decision.
+    pub signer_pub_key: PublicKey,
+    /// The latest version of the decision that the signer acknowledged.
+    #[sqlx(try_from = "i32")]
+    #[cfg_attr(feature = "testing", dummy(faker = "0..i32::MAX as u32"))]
+    pub decision_version: u32,
+}
+
+/// Another signer's acknowledgement that it stored this signer's decision
+/// on a withdrawal request.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct WithdrawalDecisionAcknowledgement {
+    /// Request ID of the withdrawal request.
+    #[sqlx(try_from = "i64")]
+    #[cfg_attr(feature = "testing", dummy(faker = "0..u32::MAX as u64"))]
+    pub request_id: u64,
+    /// The stacks transaction ID that lead to the creation of the
+    /// withdrawal request.
+    pub txid: StacksTxId,
+    /// Stacks block hash of the withdrawal request.
+    pub block_hash: StacksBlockHash,
+    /// Public key of the signer that acknowledged the decision.
+    pub signer_pub_key: PublicKey,
+    /// The latest version of the decision that the signer acknowledged.
+    #[sqlx(try_from = "i32")]
+    #[cfg_attr(feature = "testing", dummy(faker = "0..i32::MAX as u32"))]
+    pub decision_version: u32,
+}
+
+impl WithdrawalDecisionAcknowledgement {
+    /// The ID of the withdrawal request of the acknowledged decision.
+    pub fn qualified_id(&self) -> QualifiedRequestId {
+        QualifiedRequestId {
+            request_id: self.request_id,
+            txid: self.txid,
+            block_hash: self.block_hash,
+        }
+    }
+}
+
+/// An operator override of this signer's decision on a deposit request,
+/// set through the control API.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct DepositDecisionOverride {
+    /// TxID of the deposit request.
+    pub txid: BitcoinTxId,
+    /// Output index of the deposit request.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub output_index: u32,
+    /// Whether this signer accepts the deposit request.
+    pub can_accept: bool,
+}
+
+impl DepositDecisionOverride {
+    /// Return the outpoint of the deposit request.
+    pub fn outpoint(&self) -> bitcoin::OutPoint {
+        bitcoin::OutPoint {
+            txid: self.txid.into(),
+            vout: self.output_index,
+        }
+    }
+}
+
+/// An operator override of this signer's decision on a withdrawal
+/// request, set through the control API.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct WithdrawalDecisionOverride {
+    /// Request ID of the withdrawal request.
+    #[sqlx(try_from = "i64")]
+    #[cfg_attr(feature = "testing", dummy(faker = "0..u32::MAX as u64"))]
+    pub request_id: u64,
+    /// The stacks transaction ID that lead to the creation of the
+    /// withdrawal request.
+    pub txid: StacksTxId,
+    /// Stacks block hash of the withdrawal request.
+    pub block_hash: StacksBlockHash,
+    /// Whether this signer accepts the withdrawal request.
+    pub accept: bool,
+}
+
+impl WithdrawalDecisionOverride {
+    /// The ID of the overridden withdrawal request.
+    pub fn qualified_id(&self) -> QualifiedRequestId {
+        QualifiedRequestId {
+            request_id: self.request_id,
+            txid: self.txid,
+            block_hash: self.block_hash,
+        }
+    }
+}
+
+/// The reasons that a signer may reject a deposit request on account of
+/// its own local policy.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, sqlx::Type, strum::Display)]
+#[sqlx(type_name = "deposit_rejection_reason", rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub enum DepositRejectionReason {
+    /// The deposit amount is below the `min_deposit_amount` configured
+    /// for this signer.
+    BelowMinimumAmount,
+}
+
+/// A record of this signer rejecting a deposit request because of its own
+/// local policy, along with the reason for doing so.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct DepositRejection {
+    /// TxID of the deposit request.
+    pub txid: BitcoinTxId,
+    /// Output index of the deposit request.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub output_index: u32,
+    /// The reason that the deposit request was rejected.
+    pub reason: DepositRejectionReason,
+}
+
+/// A record of a deposit request that was not swept within the configured
+/// number of bitcoin blocks after it was confirmed.
+///
+/// Expired deposit requests are excluded from sweeping unless an operator
+/// re-activates them.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct DepositExpiration {
+    /// TxID of the deposit request.
+    pub txid: BitcoinTxId,
+    /// Output index of the deposit request.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub output_index: u32,
+    /// The bitcoin chain tip when the deposit request was marked as
+    /// expired.
+    pub bitcoin_block_hash: BitcoinBlockHash,
+    /// The height of the bitcoin chain tip when the deposit request was
+    /// marked as expired.
+    pub bitcoin_block_height: BitcoinBlockHeight,
+    /// Whether an operator has re-activated the deposit request.
+    pub reactivated: bool,
+}
+
+/// A record of a deposit request that the depositor spent through the
+/// reclaim path of the deposit script.
+///
+/// Reclaimed deposit requests can never be swept, so they are excluded
+/// from sweeping while the bitcoin block confirming the reclaim is on the
+/// canonical bitcoin blockchain.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct DepositReclaim {
+    /// TxID of the deposit request.
+    pub txid: BitcoinTxId,
+    /// Output index of the deposit request.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub output_index: u32,
+    /// The transaction ID of the transaction that reclaimed the deposit.
+    pub reclaim_txid: BitcoinTxId,
+    /// The bitcoin block that confirmed the reclaim transaction.
+    pub bitcoin_block_hash: BitcoinBlockHash,
+    /// The height of the bitcoin block that confirmed the reclaim
+    /// transaction.
+    pub bitcoin_block_height: BitcoinBlockHeight,
+}
+
+/// The status of a stacks transaction that this signer submitted to a
+/// stacks node, as far as this signer knows.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, sqlx::Type, strum::Display)]
+#[sqlx(type_name = "stacks_tx_status", rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub enum StacksTxStatus {
+    /// The stacks node accepted the transaction into its mempool, and it
+    /// has not been included in a stacks block yet.
+    Pending,
+    /// The transaction was included in a stacks block and its contract
+    /// call was committed.
+    Confirmed,
+    /// The transaction was included in a stacks block but its contract
+    /// call aborted.
+    Failed,
+    /// The stacks node rejected the transaction for a reason that may be
+    /// resolved by resubmitting, like a fee that is too low.
+    Rejected,
+    /// The transaction will never be included in a stacks block, either
+    /// because the stacks node rejected it for a reason that resubmitting
+    /// cannot resolve, or because its nonce was used by another
+    /// transaction.
+    Dropped,
+}
+
+/// A record of a stacks transaction that this signer submitted to a
+/// stacks node while it was the coordinator.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct StacksTxSubmission {
+    /// The ID of the submitted transaction.
+    pub txid: StacksTxId,
+    /// The kind of transaction, as given by
+    /// [`StacksTransactionSignRequest::tx_kind`](crate::message::StacksTransactionSignRequest::tx_kind).
+    pub tx_kind: String,
+    /// Identifiers of the requests that are fulfilled by the transaction.
+    pub request_keys: Vec<String>,
+    /// The nonce of the transaction.
+    #[sqlx(try_from = "i64")]
+    #[cfg_attr(feature = "testing", dummy(faker = "0..i64::MAX as u64"))]
+    pub nonce: u64,
+    /// The fee of the transaction, in microSTX.
+    #[sqlx(try_from = "i64")]
+    #[cfg_attr(feature = "testing", dummy(faker = "0..1_000_000"))]
+    pub tx_fee: u64,
+    /// The current status of the transaction.
+    pub status: StacksTxStatus,
+    /// The reason that the stacks node gave for rejecting the
+    /// transaction, if it was rejected.
+    pub rejection_reason: Option<String>,
+    /// The bitcoin chain tip when the transaction was submitted.
+    pub bitcoin_block_hash: BitcoinBlockHash,
+}
+
+/// A stacks block that failed verification when the block observer
+/// fetched it, and that was not stored with the other stacks blocks.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct QuarantinedStacksBlock {
+    /// The block ID of the stacks block.
+    pub block_hash: StacksBlockHash,
+    /// The bitcoin block that anchors the tenure of the stacks block,
+    /// according to the stacks node.
+    pub bitcoin_anchor: BitcoinBlockHash,
+    /// Why the stacks block failed verification.
+    pub reason: String,
+}
+
+/// A `complete-deposit` sign request whose recipient or amount did not
+/// match the deposit request that it mints for.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct MintMismatch {
+    /// TxID of the deposit request.
+    pub txid: BitcoinTxId,
+    /// Output index of the deposit request.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub output_index: u32,
+    /// The bitcoin chain tip when the sign request was validated.
+    pub chain_tip: BitcoinBlockHash,
+    /// The public key of the coordinator that sent the sign request.
+    pub origin: PublicKey,
+    /// The recipient of the mint in the sign request.
+    pub recipient: StacksPrincipal,
+    /// The recipient in the deposit script of the deposit request, if the
+    /// script could be parsed.
+    pub expected_recipient: Option<StacksPrincipal>,
+    /// The amount to mint in the sign request.
+    #[sqlx(try_from = "i64")]
+    #[cfg_attr(feature = "testing", dummy(faker = "1_000_000..1_000_000_000"))]
+    pub amount: u64,
+    /// The amount of the deposit request less the assessed fee.
+    #[sqlx(try_from = "i64")]
+    #[cfg_attr(feature = "testing", dummy(faker = "1_000_000..1_000_000_000"))]
+    pub expected_amount: u64,
+}
+
+/// What the operator of this signer decided to do with a payment to the
+/// signers' scriptPubKey.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, sqlx::Type, strum::Display)]
+#[sqlx(type_name = "signer_payment_status", rename_all = "snake_case")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "testing", derive(fake::Dummy, strum::EnumIter))]
+pub enum SignerPaymentStatus {
+    /// The operator has not reviewed the payment yet.
+    Unreviewed,
+    /// The payment was an intended donation to the signers, and the funds
+    /// stay with them.
+    Donation,
+    /// The payment was sent to the signers by accident, and the operator
+    /// approved returning the funds to the sender.
+    RefundApproved,
+}
+
+/// A payment to the signers' scriptPubKey that was not a deposit, like a
+/// donation or a payment that was sent to the signers by accident.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct SignerPayment {
+    /// The ID of the transaction that paid the signers.
+    pub txid: BitcoinTxId,
+    /// The index of the output that pays the signers' scriptPubKey.
+    #[sqlx(try_from = "i32")]
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    pub output_index: u32,
+    /// The amount paid to the signers, in sats.
+    #[sqlx(try_from = "i64")]
+    #[cfg_attr(feature = "testing", dummy(faker = "1_000_000..1_000_000_000"))]
+    pub amount: u64,
+    /// The signers' scriptPubKey that locks the output.
+    pub script_pubkey: ScriptPubKey,
+    /// The scriptPubKey that locked all of the inputs of the transaction.
+    /// This is `None` if the inputs were locked by different
+    /// scriptPubKeys, in which case we cannot tell who the sender was and
+    /// the payment cannot be refunded.
+    pub sender_script_pubkey: Option<ScriptPubKey>,
+    /// What the operator of this signer decided to do with the payment.
+    pub status: SignerPaymentStatus,
+}
+
+impl SignerPayment {
+    /// The outpoint of the output that pays the signers.
+    pub fn outpoint(&self) -> bitcoin::OutPoint {
+        bitcoin::OutPoint {
+            txid: self.txid.into(),
+            vout: self.output_index,
+        }
+    }
+}
+
+/// The status of a signed bitcoin transaction in the queue of the
+/// broadcaster, as far as this signer knows.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, sqlx::Type, strum::Display)]
+#[sqlx(type_name = "bitcoin_broadcast_status", rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub enum BitcoinBroadcastStatus {
+    /// The transaction has not been accepted by bitcoin-core yet.
+    Pending,
+    /// bitcoin-core accepted the transaction into its mempool, and it has
+    /// not been confirmed yet.
+    Broadcast,
+    /// The transaction was confirmed in a bitcoin block.
+    Confirmed,
+    /// The broadcaster gave up on the transaction, either because
+    /// bitcoin-core rejected it or dropped it from its mempool, or
+    /// because it could not be broadcast within the allowed attempts.
+    Failed,
+}
+
+/// A signed bitcoin transaction that the coordinator handed to the
+/// broadcaster.
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct BitcoinBroadcast {
+    /// The ID of the signed transaction.
+    pub txid: BitcoinTxId,
+    /// The ID of the first transaction in the package that this
+    /// transaction was signed with.
+    pub package_txid: BitcoinTxId,
+    /// The position of this transaction in its package.
+    #[sqlx(try_from = "i32")]
+    #[cfg_attr(feature = "testing", dummy(faker = "0..25"))]
+    pub package_position: u32,
+    /// The consensus encoded signed transaction.
+    pub tx: Bytes,
+    /// The fee rate that the transaction pays, in sats per vbyte.
+    pub fee_rate: f64,
+    /// The bitcoin chain tip when the transaction was signed.
+    pub bitcoin_block_hash: BitcoinBlockHash,
+    /// The current status of the transaction.
+    pub status: BitcoinBroadcastStatus,
+    /// The number of times that the broadcaster tried to broadcast the
+    /// transaction.
+    #[sqlx(try_from = "i32")]
+    #[cfg_attr(feature = "testing", dummy(faker = "0..10"))]
+    pub attempts: u32,
+    /// The error from the last failed attempt to broadcast the
+    /// transaction.
+    pub last_error: Option<String>,
+}
+
+/// A lock on a prevout held by the coordinator while it runs the signing
+/// rounds of a bitcoin transaction that spends it.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct PrevoutLock {
+    /// The txid that created the output that is being spent.
+    pub prevout_txid: BitcoinTxId,
+    /// The index of the vout from the transaction that created this
+    /// output.
+    #[sqlx(try_from = "i32")]
+    #[cfg_attr(feature = "testing", dummy(faker = "0..i32::MAX as u32"))]
+    pub prevout_output_index: u32,
+    /// The bitcoin chain tip of the signing rounds.
+    pub chain_tip: BitcoinBlockHash,
+    /// The ID of the bitcoin transaction that spends the output.
+    pub txid: BitcoinTxId,
+}
+
+/// A summary of the work that a coordinator intends to do in its tenure.
+///
+/// The coordinator computes the plan from its database before it starts
+/// on the work of the tenure, so the transactions that it ends up
+/// broadcasting may differ: requests may be left out due to fees, caps,
+/// or the tenure running out of time.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct TenurePlan {
+    /// The bitcoin chain tip that started the tenure.
+    pub bitcoin_chain_tip: BitcoinBlockHash,
+    /// The public key of the coordinator for the tenure.
+    pub coordinator_public_key: PublicKey,
+    /// Whether the coordinator intends to deploy the sBTC smart contracts.
+    pub deploy_contracts: bool,
+    /// Whether the coordinator intends to submit a rotate-keys contract
+    /// call.
+    pub rotate_keys: bool,
+    /// The number of deposit requests that are eligible for a sweep.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub deposits_to_sweep: u32,
+    /// The number of withdrawal requests that are eligible for a sweep.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub withdrawals_to_sweep: u32,
+    /// The number of swept deposit requests that may need a
+    /// complete-deposit contract call.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub deposits_to_complete: u32,
+    /// The number of swept withdrawal requests that may need an
+    /// accept-withdrawal-request contract call.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub withdrawals_to_accept: u32,
+    /// The number of withdrawal requests that may need a
+    /// reject-withdrawal-request contract call.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub withdrawals_to_reject: u32,
+}
+
+impl TenurePlan {
+    /// Whether the plan has any contract deployments, key rotations or
+    /// requests for the coordinator to work on.
+    pub fn has_work(&self) -> bool {
+        self.deploy_contracts
+            || self.rotate_keys
+            || self.deposits_to_sweep > 0
+            || self.withdrawals_to_sweep > 0
+            || self.deposits_to_complete > 0
+            || self.withdrawals_to_accept > 0
+            || self.withdrawals_to_reject > 0
+    }
+}
+
+/// The reasons that this signer may refuse to begin a DKG round.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, sqlx::Type, strum::Display)]
+#[sqlx(type_name = "dkg_begin_refusal_reason", rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub enum DkgBeginRefusalReason {
+    /// The latest DKG shares have not been verified yet, and verifying
+    /// them takes priority over running DKG again.
+    LatestSharesUnverified,
+    /// The number of DKG rounds that this signer has taken part in has
+    /// reached the configured `dkg_target_rounds`.
+    TargetRoundsReached,
+    /// The bitcoin chain tip is below the configured
+    /// `dkg_min_bitcoin_block_height`.
+    BelowMinimumHeight,
+    /// DKG has already run and there is no configured
+    /// `dkg_min_bitcoin_block_height` for running it again.
+    RerunHeightNotConfigured,
+}
+
+/// A record of this signer refusing to begin a DKG round, along with the
+/// state that led to the refusal.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct DkgBeginRefusal {
+    /// Why this signer refused to begin the DKG round.
+    pub reason: DkgBeginRefusalReason,
+    /// The bitcoin chain tip when the DKG round was requested.
+    pub bitcoin_chain_tip: BitcoinBlockHash,
+    /// The height of the above bitcoin chain tip.
+    pub bitcoin_chain_tip_height: BitcoinBlockHeight,
+    /// The verification status of the latest DKG shares of this signer,
+    /// if it has any.
+    pub latest_dkg_shares_status: Option<DkgSharesStatus>,
+    /// The number of DKG shares that this signer had stored.
+    #[cfg_attr(feature = "testing", dummy(faker = "0..100"))]
+    #[sqlx(try_from = "i32")]
+    pub dkg_shares_count: u32,
+    /// The configured target number of DKG rounds.
+    #[cfg_attr(feature = "testing", dummy(faker = "1..100"))]
+    #[sqlx(try_from = "i32")]
+    pub dkg_target_rounds: u32,
+    /// The configured minimum bitcoin block height for rerunning DKG, if
+    /// any.
+    pub dkg_min_bitcoin_block_height: Option<BitcoinBlockHeight>,
+}
+
+/// How far along a rotation of the key of this signer is.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, sqlx::Type, strum::Display)]
+#[sqlx(type_name = "signer_key_rotation_status", rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub enum SignerKeyRotationStatus {
+    /// The new key was generated, and the signer has not announced the
+    /// endorsement of it yet.
+    Generated,
+    /// The signer announced the endorsement of the new key to the other
+    /// signers, using the old key.
+    Announced,
+    /// The signer runs with the new key, and with a signing set that has
+    /// the new key in place of the old one.
+    ConfigUpdated,
+    /// The signer set in the sBTC registry contract has the new key in
+    /// place of the old one, so the old key is retired.
+    Completed,
+    /// The operator cancelled the rotation.
+    Cancelled,
+}
+
+impl SignerKeyRotationStatus {
+    /// Whether the rotation has ended, one way or another.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Cancelled)
+    }
+}
+
+/// A rotation of the key of this signer, as opposed to a rotation of the
+/// aggregate key of the signer set.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct SignerKeyRotation {
+    /// The public key that is being replaced.
+    pub old_public_key: PublicKey,
+    /// The public key that replaces the old one.
+    pub new_public_key: PublicKey,
+    /// How far along the rotation is.
+    pub status: SignerKeyRotationStatus,
+}
+
+/// An endorsement of a new key that this signer received from another
+/// signer, signed with the key that it replaces.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct KeyEndorsement {
+    /// The public key of the signer that sent the endorsement.
+    pub old_public_key: PublicKey,
+    /// The public key that the sending signer endorsed as its new key.
+    pub new_public_key: PublicKey,
+    /// The bitcoin chain tip in the message with the endorsement.
+    pub bitcoin_chain_tip: BitcoinBlockHash,
+}
+
+/// The latest approval, or revocation of an approval, of the emergency
+/// recovery of the funds of the signers, from this signer or from another
+/// member of the signer set.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct EmergencyRecoveryApproval {
+    /// The public key of the signer that approved the recovery.
+    pub signer_public_key: PublicKey,
+    /// The scriptPubKey that the approval sweeps the funds to.
+    pub recovery_script_pubkey: ScriptPubKey,
+    /// The bitcoin chain tip at which the signer approved, or revoked its
+    /// approval of, the recovery.
+    pub bitcoin_block_hash: BitcoinBlockHash,
+    /// The height of the above chain tip.
+    pub bitcoin_block_height: BitcoinBlockHeight,
+    /// Whether the signer revoked its approval.
+    pub revoked: bool,
+}
+
+/// An emergency recovery sweep that this signer agreed to sign.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+#[cfg_attr(feature = "testing", derive(fake::Dummy))]
+pub struct EmergencyRecoverySweep {
+    /// The ID of the sweep transaction.
+    pub txid: BitcoinTxId,
+    /// The scriptPubKey that the sweep pays the funds to.
+    pub recovery_script_pubkey: ScriptPubKey,
+}
+
+/// A stage in the processing of a bitcoin block by this signer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum BlockProcessingStage {
+    /// The block observer observed the block.
+    Observed,
+    /// The block observer fetched the deposit requests from Emily.
+    DepositsFetched,
+    /// The request decider sent its decisions on the pending requests.
+    DecisionsCompleted,
+    /// This signer completed its tenure as the coordinator for the block.
+    TenureCompleted,
+}
+
+/// When this signer reached each stage in the processing of a bitcoin
+/// block. Stages that have not been reached, or that this signer does not
+/// go through for the block, are `None`.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct BitcoinBlockProcessing {
+    /// The hash of the bitcoin block.
+    pub block_hash: BitcoinBlockHash,
+    /// When the block observer observed the block.
+    pub observed_at: Option<Timestamp>,
+    /// When the block observer fetched the deposit requests from Emily.
+    pub deposits_fetched_at: Option<Timestamp>,
+    /// When the request decider sent its decisions.
+    pub decisions_completed_at: Option<Timestamp>,
+    /// When this signer completed its tenure as the coordinator.
+    pub tenure_completed_at: Option<Timestamp>,
+}
+
+impl BitcoinBlockProcessing {
+    /// A record of the given block reaching the given stage at the given
+    /// time, and no other stages.
+    pub fn new(block_hash: BitcoinBlockHash, stage: BlockProcessingStage, at: Timestamp) -> Self {
+        let mut processing = Self {
+            block_hash,
+            observed_at: None,
+            deposits_fetched_at: None,
+            decisions_completed_at: None,
+            tenure_completed_at: None,
+        };
+        processing.record(stage, at);
+        processing
+    }
+
+    /// Record that the given stage was reached at the given time, unless
+    /// it was reached before.
+    pub fn record(&mut self, stage: BlockProcessingStage, at: Timestamp) {
+        let reached_at = match stage {
+            BlockProcessingStage::Observed => &mut self.observed_at,
+            BlockProcessingStage::DepositsFetched => &mut self.deposits_fetched_at,
+            BlockProcessingStage::DecisionsCompleted => &mut self.decisions_completed_at,
+            BlockProcessingStage::TenureCompleted => &mut self.tenure_completed_at,
+        };
+        reached_at.get_or_insert(at);
+    }
+
+    /// When the given stage was reached, if it was.
+    pub fn reached_at(&self, stage: BlockProcessingStage) -> Option<Timestamp> {
+        match stage {
+            BlockProcessingStage::Observed => self.observed_at,
+            BlockProcessingStage::DepositsFetched => self.deposits_fetched_at,
+            BlockProcessingStage::DecisionsCompleted => self.decisions_completed_at,
+            BlockProcessingStage::TenureCompleted => self.tenure_completed_at,
+        }
+    }
+
+    /// The amount of time from observing the block to reaching the given
+    /// stage, if both were reached in that order.
+    pub fn time_since_observed(&self, stage: BlockProcessingStage) -> Option<std::time::Duration> {
+        let observed_at = self.observed_at?;
+        let reached_at = self.reached_at(stage)?;
+        std::time::Duration::try_from(*reached_at - *observed_at).ok()
+    }
+}
+
 impl From<sbtc::events::StacksTxid> for StacksTxId {
     fn from(value: sbtc::events::StacksTxid) -> Self {
         Self(blockstack_lib::burnchains::Txid(value.0))
@@ -1356,16 +2178,11 @@ pub struct WithdrawalRejectEvent {
     pub signer_bitmap: BitArray<[u8; 16]>,
 }
 
-impl From<u8> for BitcoinBlockHeight {
-    fn from(value: u8) -> Self {
-        Self(value as u64)
-    }
-}
-impl From<u16> for BitcoinBlockHeight {
-    fn from(value: u16) -> Self {
-        Self(value as u64)
-    }
-}
+// Heights are only built from the integer types that the bitcoin and
+// stacks nodes report them in. There is intentionally no conversion
+// between bitcoin and stacks block heights, and adding two heights
+// together is not supported, since neither makes sense.
+
 impl From<u32> for BitcoinBlockHeight {
     fn from(value: u32) -> Self {
         Self(value as u64)
@@ -1376,11 +2193,6 @@ impl From<u64> for BitcoinBlockHeight {
         Self(value)
     }
 }
-impl From<usize> for BitcoinBlockHeight {
-    fn from(value: usize) -> Self {
-        Self(value as u64)
-    }
-}
 
 // Conversion BitcoinBlockHeight => u64  is not implemented intentionally.
 // Use deref instead.
@@ -1423,18 +2235,6 @@ impl Add<u64> for BitcoinBlockHeight {
         Self(self.0.add(other))
     }
 }
-impl Add<BitcoinBlockHeight> for u64 {
-    type Output = BitcoinBlockHeight;
-    fn add(self, other: BitcoinBlockHeight) -> Self::Output {
-        BitcoinBlockHeight((self).add(other.0))
-    }
-}
-impl Add for BitcoinBlockHeight {
-    type Output = BitcoinBlockHeight;
-    fn add(self, other: BitcoinBlockHeight) -> Self::Output {
-        Self(self.0.add(other.0))
-    }
-}
 
 impl Sub<u64> for BitcoinBlockHeight {
     // Height - int is still height.
@@ -1452,29 +2252,37 @@ impl Sub for BitcoinBlockHeight {
 }
 
 impl BitcoinBlockHeight {
-    /// Behaves same as u64.saturating_add
-    pub fn saturating_add(self, rhs: impl Into<BitcoinBlockHeight>) -> Self {
-        let rhs: u64 = rhs.into().0;
-        Self(self.0.saturating_add(rhs))
+    /// The height the given number of blocks above this one, or `None`
+    /// on overflow.
+    pub fn checked_add(self, blocks: u64) -> Option<Self> {
+        self.0.checked_add(blocks).map(Self)
     }
 
-    /// Behaves same as u64.saturating_sub
-    pub fn saturating_sub(self, rhs: impl Into<BitcoinBlockHeight>) -> Self {
-        let rhs: u64 = rhs.into().0;
-        Self(self.0.saturating_sub(rhs))
+    /// The height the given number of blocks below this one, or `None`
+    /// if that would be below the genesis block.
+    pub fn checked_sub(self, blocks: u64) -> Option<Self> {
+        self.0.checked_sub(blocks).map(Self)
     }
-}
 
-impl From<u8> for StacksBlockHeight {
-    fn from(value: u8) -> Self {
-        Self(value as u64)
+    /// The height the given number of blocks above this one, stopping at
+    /// the maximum height.
+    pub fn saturating_add(self, blocks: u64) -> Self {
+        Self(self.0.saturating_add(blocks))
     }
-}
-impl From<u16> for StacksBlockHeight {
-    fn from(value: u16) -> Self {
-        Self(value as u64)
+
+    /// The height the given number of blocks below this one, stopping at
+    /// the genesis block.
+    pub fn saturating_sub(self, blocks: u64) -> Self {
+        Self(self.0.saturating_sub(blocks))
+    }
+
+    /// The number of blocks between the given earlier height and this
+    /// one, or zero if the given height is not below this one.
+    pub fn blocks_since(self, earlier: Self) -> u64 {
+        self.0.saturating_sub(earlier.0)
     }
 }
+
 impl From<u32> for StacksBlockHeight {
     fn from(value: u32) -> Self {
         Self(value as u64)
@@ -1485,11 +2293,6 @@ impl From<u64> for StacksBlockHeight {
         Self(value)
     }
 }
-impl From<usize> for StacksBlockHeight {
-    fn from(value: usize) -> Self {
-        Self(value as u64)
-    }
-}
 
 // Conversion StacksBlockHeight => u64  is not implemented intentionally.
 // Use deref instead.
@@ -1532,18 +2335,6 @@ impl Add<u64> for StacksBlockHeight {
         Self(self.0.add(other))
     }
 }
-impl Add<StacksBlockHeight> for u64 {
-    type Output = StacksBlockHeight;
-    fn add(self, other: StacksBlockHeight) -> Self::Output {
-        StacksBlockHeight((self).add(other.0))
-    }
-}
-impl Add for StacksBlockHeight {
-    type Output = StacksBlockHeight;
-    fn add(self, other: StacksBlockHeight) -> Self::Output {
-        Self(self.0.add(other.0))
-    }
-}
 
 impl Sub<u64> for StacksBlockHeight {
     // Height - int is still height.
@@ -1560,16 +2351,34 @@ impl Sub for StacksBlockHeight {
     }
 }
 impl StacksBlockHeight {
-    /// Behaves same as u64.saturating_add
-    pub fn saturating_add(self, rhs: impl Into<StacksBlockHeight>) -> Self {
-        let rhs: u64 = rhs.into().0;
-        Self(self.0.saturating_add(rhs))
+    /// The height the given number of blocks above this one, or `None`
+    /// on overflow.
+    pub fn checked_add(self, blocks: u64) -> Option<Self> {
+        self.0.checked_add(blocks).map(Self)
+    }
+
+    /// The height the given number of blocks below this one, or `None`
+    /// if that would be below the genesis block.
+    pub fn checked_sub(self, blocks: u64) -> Option<Self> {
+        self.0.checked_sub(blocks).map(Self)
+    }
+
+    /// The height the given number of blocks above this one, stopping at
+    /// the maximum height.
+    pub fn saturating_add(self, blocks: u64) -> Self {
+        Self(self.0.saturating_add(blocks))
+    }
+
+    /// The height the given number of blocks below this one, stopping at
+    /// the genesis block.
+    pub fn saturating_sub(self, blocks: u64) -> Self {
+        Self(self.0.saturating_sub(blocks))
     }
 
-    /// Behaves same as u64.saturating_sub
-    pub fn saturating_sub(self, rhs: impl Into<StacksBlockHeight>) -> Self {
-        let rhs: u64 = rhs.into().0;
-        Self(self.0.saturating_sub(rhs))
+    /// The number of blocks between the given earlier height and this
+    /// one, or zero if the given height is not below this one.
+    pub fn blocks_since(self, earlier: Self) -> u64 {
+        self.0.saturating_sub(earlier.0)
     }
 }
 
@@ -1614,6 +2423,118 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn tenure_plans_have_work_when_anything_is_planned() {
+        let mut plan = TenurePlan {
+            bitcoin_chain_tip: fake::Faker.fake(),
+            coordinator_public_key: fake::Faker.fake(),
+            deploy_contracts: false,
+            rotate_keys: false,
+            deposits_to_sweep: 0,
+            withdrawals_to_sweep: 0,
+            deposits_to_complete: 0,
+            withdrawals_to_accept: 0,
+            withdrawals_to_reject: 0,
+        };
+        assert!(!plan.has_work());
+
+        plan.withdrawals_to_reject = 1;
+        assert!(plan.has_work());
+
+        plan.withdrawals_to_reject = 0;
+        plan.rotate_keys = true;
+        assert!(plan.has_work());
+    }
+
+    #[test_case::test_case(100, 100, 1; "sweep in the chain tip")]
+    #[test_case::test_case(100, 95, 6; "sweep below the chain tip")]
+    #[test_case::test_case(100, 101, 0; "sweep above the chain tip")]
+    fn block_ref_confirmations(chain_tip_height: u64, sweep_height: u64, expected: u64) {
+        let chain_tip = BitcoinBlockRef {
+            block_height: chain_tip_height.into(),
+            block_hash: fake::Faker.fake(),
+        };
+        assert_eq!(chain_tip.confirmations(sweep_height.into()), expected);
+    }
+
+    #[test_case::test_case(1, true, 0, true, true; "higher version wins")]
+    #[test_case::test_case(1, true, 2, false, false; "lower version loses")]
+    #[test_case::test_case(3, false, 3, true, true; "rejection wins a tie")]
+    #[test_case::test_case(3, true, 3, false, false; "acceptance loses a tie")]
+    #[test_case::test_case(3, true, 3, true, false; "identical decisions do not supersede")]
+    fn decisions_with_higher_versions_supersede(
+        version: u32,
+        is_accepted: bool,
+        other_version: u32,
+        other_is_accepted: bool,
+        expected: bool,
+    ) {
+        let deposit = DepositSigner {
+            can_accept: is_accepted,
+            decision_version: version,
+            ..fake::Faker.fake()
+        };
+        let other_deposit = DepositSigner {
+            can_accept: other_is_accepted,
+            can_sign: deposit.can_sign,
+            decision_version: other_version,
+            ..deposit.clone()
+        };
+        assert_eq!(deposit.supersedes(&other_deposit), expected);
+
+        let withdrawal = WithdrawalSigner {
+            is_accepted,
+            decision_version: version,
+            ..fake::Faker.fake()
+        };
+        let other_withdrawal = WithdrawalSigner {
+            is_accepted: other_is_accepted,
+            decision_version: other_version,
+            ..withdrawal.clone()
+        };
+        assert_eq!(withdrawal.supersedes(&other_withdrawal), expected);
+    }
+
+    #[test]
+    fn block_height_arithmetic_is_checked() {
+        let height = BitcoinBlockHeight::from(10u64);
+        assert_eq!(height.checked_sub(11), None);
+        assert_eq!(height.checked_sub(10), Some(0u64.into()));
+        assert_eq!(BitcoinBlockHeight::from(u64::MAX).checked_add(1), None);
+        assert_eq!(height.saturating_sub(11), 0u64.into());
+        assert_eq!(height.blocks_since(4u64.into()), 6);
+        assert_eq!(height.blocks_since(11u64.into()), 0);
+
+        let height = StacksBlockHeight::from(10u64);
+        assert_eq!(height.checked_sub(11), None);
+        assert_eq!(height.checked_add(5), Some(15u64.into()));
+        assert_eq!(height.blocks_since(12u64.into()), 0);
+    }
+
+    #[test]
+    fn block_processing_keeps_the_first_time_of_each_stage() {
+        let observed_at = Timestamp::from(time::OffsetDateTime::UNIX_EPOCH);
+        let fetched_at = Timestamp::from(*observed_at + time::Duration::seconds(3));
+
+        let mut processing = BitcoinBlockProcessing::new(
+            fake::Faker.fake(),
+            BlockProcessingStage::Observed,
+            observed_at,
+        );
+        processing.record(BlockProcessingStage::DepositsFetched, fetched_at);
+        processing.record(BlockProcessingStage::Observed, fetched_at);
+
+        assert_eq!(processing.observed_at, Some(observed_at));
+        assert_eq!(
+            processing.time_since_observed(BlockProcessingStage::DepositsFetched),
+            Some(std::time::Duration::from_secs(3))
+        );
+        assert_eq!(
+            processing.time_since_observed(BlockProcessingStage::TenureCompleted),
+            None
+        );
+    }
+
     #[test]
     fn conversion_bitcoin_header_hashes() {
         let mut rng = get_rng();
@@ -1,4 +1,5 @@
 use std::collections::BTreeSet;
+use std::collections::HashMap;
 
 use bitcoin::OutPoint;
 use clarity::types::chainstate::StacksBlockId;
@@ -105,6 +106,35 @@ impl From<PgSignerUtxo> for SignerUtxo {
         }
     }
 }
+// A convenience struct for retrieving `completed-deposit` events
+#[derive(sqlx::FromRow)]
+struct PgCompletedDepositEvent {
+    txid: model::StacksTxId,
+    block_hash: model::StacksBlockHash,
+    #[sqlx(try_from = "i64")]
+    amount: u64,
+    bitcoin_txid: model::BitcoinTxId,
+    #[sqlx(try_from = "i64")]
+    output_index: u32,
+    sweep_block_hash: model::BitcoinBlockHash,
+    sweep_block_height: BitcoinBlockHeight,
+    sweep_txid: model::BitcoinTxId,
+}
+
+impl From<PgCompletedDepositEvent> for model::CompletedDepositEvent {
+    fn from(event: PgCompletedDepositEvent) -> Self {
+        model::CompletedDepositEvent {
+            txid: event.txid,
+            block_id: event.block_hash,
+            amount: event.amount,
+            outpoint: OutPoint::new(event.bitcoin_txid.into(), event.output_index),
+            sweep_block_hash: event.sweep_block_hash,
+            sweep_block_height: event.sweep_block_height,
+            sweep_txid: event.sweep_txid,
+        }
+    }
+}
+
 /// Read-accessors to the Postgres database.
 pub struct PgRead;
 
@@ -872,6 +902,60 @@ impl PgRead {
         .map_err(Error::SqlxQuery)
     }
 
+    pub async fn get_deposit_requests<'e, E>(
+        executor: &'e mut E,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+    ) -> Result<Vec<model::DepositRequest>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::DepositRequest>(
+            r#"
+            WITH RECURSIVE context_window AS (
+                -- Anchor member: Initialize the recursion with the chain tip
+                SELECT block_hash, block_height, parent_hash, created_at, 1 AS depth
+                FROM sbtc_signer.bitcoin_blocks
+                WHERE block_hash = $1
+
+                UNION ALL
+
+                -- Recursive member: Fetch the parent block using the last block's parent_hash
+                SELECT parent.block_hash, parent.block_height, parent.parent_hash,
+                       parent.created_at, last.depth + 1
+                FROM sbtc_signer.bitcoin_blocks parent
+                JOIN context_window last ON parent.block_hash = last.parent_hash
+                WHERE last.depth < $2
+            ),
+            transactions_in_window AS (
+                SELECT DISTINCT transactions.txid
+                FROM context_window blocks_in_window
+                JOIN sbtc_signer.bitcoin_transactions transactions ON
+                    transactions.block_hash = blocks_in_window.block_hash
+            )
+            SELECT
+                deposit_requests.txid
+              , deposit_requests.output_index
+              , deposit_requests.spend_script
+              , deposit_requests.reclaim_script
+              , deposit_requests.reclaim_script_hash
+              , deposit_requests.recipient
+              , deposit_requests.amount
+              , deposit_requests.max_fee
+              , deposit_requests.lock_time
+              , deposit_requests.signers_public_key
+              , deposit_requests.sender_script_pub_keys
+            FROM transactions_in_window transactions
+            JOIN sbtc_signer.deposit_requests AS deposit_requests USING (txid)
+            "#,
+        )
+        .bind(chain_tip)
+        .bind(i32::from(context_window))
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
     pub async fn get_pending_accepted_deposit_requests<'e, E>(
         executor: &'e mut E,
         chain_tip: &model::BitcoinBlockRef,
@@ -921,6 +1005,21 @@ impl PgRead {
                     signers.can_accept
                     AND signers.can_sign
                     AND (transactions.block_height + deposit_requests.lock_time) >= $4
+                    AND NOT EXISTS (
+                        SELECT 1
+                        FROM sbtc_signer.deposit_expirations AS expirations
+                        WHERE expirations.txid = deposit_requests.txid
+                          AND expirations.output_index = deposit_requests.output_index
+                          AND NOT expirations.reactivated
+                    )
+                    AND NOT EXISTS (
+                        SELECT 1
+                        FROM sbtc_signer.deposit_reclaims AS reclaims
+                        JOIN bitcoin_blockchain_of($1, $2) AS blocks
+                          ON blocks.block_hash = reclaims.bitcoin_block_hash
+                        WHERE reclaims.txid = deposit_requests.txid
+                          AND reclaims.output_index = deposit_requests.output_index
+                    )
                 GROUP BY deposit_requests.txid, deposit_requests.output_index
                 HAVING COUNT(signers.txid) >= $3
             )
@@ -998,6 +1097,73 @@ impl PgRead {
         .map_err(Error::SqlxQuery)
     }
 
+    pub async fn get_deposit_requests_signer_votes<'e, E>(
+        executor: &'e mut E,
+        outpoints: &[OutPoint],
+        aggregate_key: &PublicKey,
+    ) -> Result<HashMap<OutPoint, model::SignerVotes>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let mut txids = Vec::with_capacity(outpoints.len());
+        let mut output_indices = Vec::with_capacity(outpoints.len());
+        for outpoint in outpoints {
+            txids.push(model::BitcoinTxId::from(outpoint.txid));
+            output_indices
+                .push(i32::try_from(outpoint.vout).map_err(Error::ConversionDatabaseInt)?);
+        }
+
+        let rows = sqlx::query_as::<_, (model::BitcoinTxId, i32, PublicKey, Option<bool>)>(
+            r#"
+            WITH signer_set_rows AS (
+                SELECT DISTINCT UNNEST(signer_set_public_keys) AS signer_public_key
+                FROM sbtc_signer.dkg_shares
+                WHERE aggregate_key = $1
+            ),
+            requests AS (
+                SELECT txid, output_index
+                FROM UNNEST($2::BYTEA[], $3::INTEGER[]) AS r(txid, output_index)
+            )
+            SELECT
+                r.txid
+              , r.output_index
+              , ss.signer_public_key
+              , ds.can_accept AND ds.can_sign AS is_accepted
+            FROM requests AS r
+            CROSS JOIN signer_set_rows AS ss
+            LEFT JOIN sbtc_signer.deposit_signers AS ds
+              ON ds.txid = r.txid
+             AND ds.output_index = r.output_index
+             AND ds.signer_pub_key = ss.signer_public_key
+            "#,
+        )
+        .bind(aggregate_key)
+        .bind(txids)
+        .bind(output_indices)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        let mut votes: HashMap<OutPoint, Vec<model::SignerVote>> = outpoints
+            .iter()
+            .map(|outpoint| (*outpoint, Vec::new()))
+            .collect();
+
+        for (txid, output_index, signer_public_key, is_accepted) in rows {
+            let vout = u32::try_from(output_index).map_err(Error::ConversionDatabaseInt)?;
+            let outpoint = OutPoint::new(txid.into(), vout);
+            votes
+                .entry(outpoint)
+                .or_default()
+                .push(model::SignerVote { signer_public_key, is_accepted });
+        }
+
+        Ok(votes
+            .into_iter()
+            .map(|(outpoint, votes)| (outpoint, model::SignerVotes::from(votes)))
+            .collect())
+    }
+
     async fn get_withdrawal_request_signer_votes<'e, E>(
         executor: &'e mut E,
         id: &model::QualifiedRequestId,
@@ -1083,9 +1249,16 @@ impl PgRead {
                     block_height,
                 );
 
+                // A deposit that we have not swept may have been
+                // reclaimed by the depositor instead.
                 match deposit_sweep_txid.await? {
                     Some(txid) => DepositConfirmationStatus::Spent(txid),
-                    None => DepositConfirmationStatus::Confirmed(block_height, block_hash),
+                    None => Self::get_deposit_reclaim(executor, chain_tip, txid, output_index)
+                        .await?
+                        .map_or(
+                            DepositConfirmationStatus::Confirmed(block_height, block_hash),
+                            |reclaim| DepositConfirmationStatus::Reclaimed(reclaim.reclaim_txid),
+                        ),
                 }
             }
             // If we didn't grab the block height in the above query, then
@@ -1129,6 +1302,7 @@ impl PgRead {
               , signer_pub_key
               , can_accept
               , can_sign
+              , decision_version
             FROM sbtc_signer.deposit_signers
             WHERE txid = $1 AND output_index = $2",
         )
@@ -1214,6 +1388,7 @@ impl PgRead {
               , block_hash
               , signer_pub_key
               , is_accepted
+              , decision_version
               , created_at
             FROM sbtc_signer.withdrawal_signers
             WHERE request_id = $1 AND block_hash = $2",
@@ -1305,6 +1480,85 @@ impl PgRead {
         .map_err(Error::SqlxQuery)
     }
 
+    async fn get_decided_withdrawal_requests<'e, E>(
+        executor: &'e mut E,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error>
+    where
+        E: 'static,
+        for<'c> &'c mut E: sqlx::PgExecutor<'c>,
+    {
+        let Some(stacks_chain_tip) = Self::get_stacks_chain_tip(executor, chain_tip).await? else {
+            return Ok(Vec::new());
+        };
+        sqlx::query_as::<_, model::WithdrawalRequest>(
+            r#"
+            WITH RECURSIVE extended_context_window AS (
+                SELECT
+                    block_hash
+                  , parent_hash
+                  , 1 AS depth
+                FROM sbtc_signer.bitcoin_blocks
+                WHERE block_hash = $1
+
+                UNION ALL
+
+                SELECT
+                    parent.block_hash
+                  , parent.parent_hash
+                  , last.depth + 1
+                FROM sbtc_signer.bitcoin_blocks parent
+                JOIN extended_context_window last ON parent.block_hash = last.parent_hash
+                WHERE last.depth <= $3
+            ),
+            stacks_context_window AS (
+                SELECT
+                    stacks_blocks.block_hash
+                  , stacks_blocks.block_height
+                  , stacks_blocks.parent_hash
+                FROM sbtc_signer.stacks_blocks stacks_blocks
+                WHERE stacks_blocks.block_hash = $2
+
+                UNION ALL
+
+                SELECT
+                    parent.block_hash
+                  , parent.block_height
+                  , parent.parent_hash
+                FROM sbtc_signer.stacks_blocks parent
+                JOIN stacks_context_window last
+                        ON parent.block_hash = last.parent_hash
+                JOIN extended_context_window block
+                        ON block.block_hash = parent.bitcoin_anchor
+            )
+            SELECT
+                wr.request_id
+              , wr.txid
+              , wr.block_hash
+              , wr.recipient
+              , wr.amount
+              , wr.max_fee
+              , wr.sender_address
+              , wr.bitcoin_block_height
+            FROM sbtc_signer.withdrawal_requests wr
+            JOIN stacks_context_window sc USING (block_hash)
+            JOIN sbtc_signer.withdrawal_signers AS ws
+              ON ws.request_id = wr.request_id
+             AND ws.block_hash = wr.block_hash
+             AND ws.signer_pub_key = $4
+            "#,
+        )
+        .bind(chain_tip)
+        .bind(stacks_chain_tip.block_hash)
+        .bind(i32::from(context_window))
+        .bind(signer_public_key)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
     async fn get_pending_accepted_withdrawal_requests<'e, E>(
         executor: &'e mut E,
         bitcoin_chain_tip: &model::BitcoinBlockHash,
@@ -1603,6 +1857,95 @@ impl PgRead {
         }))
     }
 
+    async fn get_withdrawal_requests_by_recipient<'e, E>(
+        executor: &'e mut E,
+        recipient: &model::ScriptPubKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::WithdrawalRequest>(
+            r#"
+            SELECT
+                request_id
+              , txid
+              , block_hash
+              , recipient
+              , amount
+              , max_fee
+              , sender_address
+              , bitcoin_block_height
+            FROM sbtc_signer.withdrawal_requests
+            WHERE recipient = $1
+            ORDER BY request_id ASC
+            "#,
+        )
+        .bind(recipient)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_withdrawal_requests_by_sender<'e, E>(
+        executor: &'e mut E,
+        sender: &model::StacksPrincipal,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::WithdrawalRequest>(
+            r#"
+            SELECT
+                request_id
+              , txid
+              , block_hash
+              , recipient
+              , amount
+              , max_fee
+              , sender_address
+              , bitcoin_block_height
+            FROM sbtc_signer.withdrawal_requests
+            WHERE sender_address = $1
+            ORDER BY request_id ASC
+            "#,
+        )
+        .bind(sender)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_withdrawal_request<'e, E>(
+        executor: &'e mut E,
+        request_id: u64,
+        block_hash: &model::StacksBlockHash,
+    ) -> Result<Option<model::WithdrawalRequest>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::WithdrawalRequest>(
+            r#"
+            SELECT
+                request_id
+              , txid
+              , block_hash
+              , recipient
+              , amount
+              , max_fee
+              , sender_address
+              , bitcoin_block_height
+            FROM sbtc_signer.withdrawal_requests
+            WHERE request_id = $1
+              AND block_hash = $2
+            "#,
+        )
+        .bind(i64::try_from(request_id).map_err(Error::ConversionDatabaseInt)?)
+        .bind(block_hash)
+        .fetch_optional(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
     async fn compute_withdrawn_total<'e, E>(
         executor: &'e mut E,
         bitcoin_chain_tip: &model::BitcoinBlockHash,
@@ -1739,6 +2082,34 @@ impl PgRead {
         .map_err(Error::SqlxQuery)
     }
 
+    async fn get_all_encrypted_dkg_shares<'e, E>(
+        executor: &'e mut E,
+    ) -> Result<Vec<model::EncryptedDkgShares>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::EncryptedDkgShares>(
+            r#"
+            SELECT
+                aggregate_key
+              , tweaked_aggregate_key
+              , script_pubkey
+              , encrypted_private_shares
+              , public_shares
+              , signer_set_public_keys
+              , signature_share_threshold
+              , dkg_shares_status
+              , started_at_bitcoin_block_hash
+              , started_at_bitcoin_block_height
+            FROM sbtc_signer.dkg_shares
+            ORDER BY created_at ASC;
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
     async fn get_latest_verified_dkg_shares<'e, E>(
         executor: &'e mut E,
     ) -> Result<Option<model::EncryptedDkgShares>, Error>
@@ -1769,13 +2140,65 @@ impl PgRead {
         .map_err(Error::SqlxQuery)
     }
 
-    /// Returns the number of non-failed rows in the `dkg_shares` table.
-    async fn get_encrypted_dkg_shares_count<'e, E>(executor: &'e mut E) -> Result<u32, Error>
+    async fn get_aggregate_key_record<'e, E>(
+        executor: &'e mut E,
+        aggregate_key: &PublicKey,
+    ) -> Result<Option<model::AggregateKeyRecord>, Error>
     where
         &'e mut E: sqlx::PgExecutor<'e>,
     {
-        let count: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM sbtc_signer.dkg_shares WHERE dkg_shares_status != 'failed';",
+        sqlx::query_as::<_, model::AggregateKeyRecord>(
+            r#"
+            SELECT
+                aggregate_key
+              , dkg_block_hash
+              , dkg_block_height
+              , dkg_shares_status
+              , rotate_keys_txid
+              , activation_height
+              , retirement_height
+            FROM sbtc_signer.aggregate_keys
+            WHERE aggregate_key = $1
+            "#,
+        )
+        .bind(aggregate_key)
+        .fetch_optional(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_aggregate_key_records<'e, E>(
+        executor: &'e mut E,
+    ) -> Result<Vec<model::AggregateKeyRecord>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::AggregateKeyRecord>(
+            r#"
+            SELECT
+                aggregate_key
+              , dkg_block_hash
+              , dkg_block_height
+              , dkg_shares_status
+              , rotate_keys_txid
+              , activation_height
+              , retirement_height
+            FROM sbtc_signer.aggregate_keys
+            ORDER BY dkg_block_height ASC, aggregate_key ASC
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    /// Returns the number of non-failed rows in the `dkg_shares` table.
+    async fn get_encrypted_dkg_shares_count<'e, E>(executor: &'e mut E) -> Result<u32, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM sbtc_signer.dkg_shares WHERE dkg_shares_status != 'failed';",
         )
         .fetch_one(executor)
         .await
@@ -1996,9 +2419,7 @@ impl PgRead {
     where
         &'e mut E: sqlx::PgExecutor<'e>,
     {
-        let height_diff: u64 = *chain_tip
-            .block_height
-            .saturating_sub(block_ref.block_height);
+        let height_diff = chain_tip.block_height.blocks_since(block_ref.block_height);
 
         sqlx::query_scalar::<_, bool>(
             r#"
@@ -2186,8 +2607,8 @@ impl PgRead {
         // again.
         let txo_confirmations = bitcoin_chain_tip
             .block_height
-            .saturating_sub(least_txo_height);
-        Ok(txo_confirmations <= min_confirmations.into())
+            .blocks_since(least_txo_height);
+        Ok(txo_confirmations <= min_confirmations)
     }
 
     async fn get_swept_deposit_requests<'e, E>(
@@ -2417,6 +2838,69 @@ impl PgRead {
         .map_err(Error::SqlxQuery)
     }
 
+    async fn get_deposit_requests_by_recipient<'e, E>(
+        executor: &'e mut E,
+        recipient: &model::StacksPrincipal,
+    ) -> Result<Vec<model::DepositRequest>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::DepositRequest>(
+            r#"
+            SELECT txid
+                 , output_index
+                 , spend_script
+                 , reclaim_script
+                 , reclaim_script_hash
+                 , recipient
+                 , amount
+                 , max_fee
+                 , lock_time
+                 , signers_public_key
+                 , sender_script_pub_keys
+            FROM sbtc_signer.deposit_requests
+            WHERE recipient = $1
+            ORDER BY txid, output_index
+            "#,
+        )
+        .bind(recipient)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_completed_deposit_events<'e, E>(
+        executor: &'e mut E,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::CompletedDepositEvent>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, PgCompletedDepositEvent>(
+            r#"
+            SELECT txid
+                 , block_hash
+                 , amount
+                 , bitcoin_txid
+                 , output_index
+                 , sweep_block_hash
+                 , sweep_block_height
+                 , sweep_txid
+            FROM sbtc_signer.completed_deposit_events
+            WHERE bitcoin_txid = $1
+              AND output_index = $2
+            ORDER BY id
+            "#,
+        )
+        .bind(txid)
+        .bind(i64::from(output_index))
+        .fetch_all(executor)
+        .await
+        .map(|events| events.into_iter().map(Into::into).collect())
+        .map_err(Error::SqlxQuery)
+    }
+
     async fn will_sign_bitcoin_tx_sighash<'e, E>(
         executor: &'e mut E,
         sighash: &model::SigHash,
@@ -2463,6 +2947,7 @@ impl PgRead {
               , ws.block_hash
               , ws.signer_pub_key
               , ws.is_accepted
+              , ws.decision_version
 
             FROM sbtc_signer.withdrawal_signers ws
             WHERE ws.signer_pub_key = $3
@@ -2501,6 +2986,7 @@ impl PgRead {
               , ds.signer_pub_key
               , ds.can_sign
               , ds.can_accept
+              , ds.decision_version
             FROM sbtc_signer.deposit_signers ds
             WHERE ds.signer_pub_key = $3
               AND ds.created_at >= (SELECT created_at FROM target_block)
@@ -2513,110 +2999,1048 @@ impl PgRead {
         .await
         .map_err(Error::SqlxQuery)
     }
-}
-
-impl DbRead for PgStore {
-    async fn get_bitcoin_block(
-        &self,
-        block_hash: &model::BitcoinBlockHash,
-    ) -> Result<Option<model::BitcoinBlock>, Error> {
-        PgRead::get_bitcoin_block(self.get_connection().await?.as_mut(), block_hash).await
-    }
-
-    async fn get_stacks_block(
-        &self,
-        block_hash: &model::StacksBlockHash,
-    ) -> Result<Option<model::StacksBlock>, Error> {
-        PgRead::get_stacks_block(self.get_connection().await?.as_mut(), block_hash).await
-    }
 
-    #[cfg(any(test, feature = "testing"))]
-    async fn get_bitcoin_canonical_chain_tip(
-        &self,
-    ) -> Result<Option<model::BitcoinBlockHash>, Error> {
-        PgRead::get_bitcoin_canonical_chain_tip(self.get_connection().await?.as_mut()).await
-    }
+    async fn get_deposit_signer_decisions_for_outpoints<'e, E>(
+        executor: &'e mut E,
+        outpoints: &[OutPoint],
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::DepositSigner>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let mut txids = Vec::with_capacity(outpoints.len());
+        let mut output_indices = Vec::with_capacity(outpoints.len());
+        for outpoint in outpoints {
+            txids.push(model::BitcoinTxId::from(outpoint.txid));
+            output_indices
+                .push(i32::try_from(outpoint.vout).map_err(Error::ConversionDatabaseInt)?);
+        }
 
-    #[cfg(any(test, feature = "testing"))]
-    async fn get_bitcoin_canonical_chain_tip_ref(
-        &self,
-    ) -> Result<Option<model::BitcoinBlockRef>, Error> {
-        PgRead::get_bitcoin_canonical_chain_tip_ref(self.get_connection().await?.as_mut()).await
+        sqlx::query_as::<_, model::DepositSigner>(
+            r#"
+            SELECT
+                ds.txid
+              , ds.output_index
+              , ds.signer_pub_key
+              , ds.can_sign
+              , ds.can_accept
+              , ds.decision_version
+            FROM UNNEST($1::BYTEA[], $2::INTEGER[]) AS r(txid, output_index)
+            JOIN sbtc_signer.deposit_signers AS ds
+              ON ds.txid = r.txid
+             AND ds.output_index = r.output_index
+            WHERE ds.signer_pub_key = $3
+            "#,
+        )
+        .bind(txids)
+        .bind(output_indices)
+        .bind(signer_public_key)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
     }
 
-    async fn get_stacks_chain_tip(
-        &self,
-        bitcoin_chain_tip: &model::BitcoinBlockHash,
-    ) -> Result<Option<model::StacksBlock>, Error> {
-        PgRead::get_stacks_chain_tip(self.get_connection().await?.as_mut(), bitcoin_chain_tip).await
+    async fn get_coordinator_attestations<'e, E>(
+        executor: &'e mut E,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::CoordinatorAttestation>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::CoordinatorAttestation>(
+            r#"
+            SELECT
+                bitcoin_chain_tip
+              , coordinator_public_key
+              , sweep_txids
+              , message
+            FROM sbtc_signer.coordinator_attestations
+            WHERE bitcoin_chain_tip = $1
+            "#,
+        )
+        .bind(chain_tip)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
     }
 
-    async fn get_pending_deposit_requests(
-        &self,
-        chain_tip: &model::BitcoinBlockHash,
-        context_window: u16,
-        signer_public_key: &PublicKey,
-    ) -> Result<Vec<model::DepositRequest>, Error> {
-        PgRead::get_pending_deposit_requests(
-            self.get_connection().await?.as_mut(),
-            chain_tip,
-            context_window,
-            signer_public_key,
+    async fn get_deposit_decision_receipts<'e, E>(
+        executor: &'e mut E,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::DepositDecisionReceipt>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::DepositDecisionReceipt>(
+            r#"
+            SELECT
+                txid
+              , output_index
+              , signer_pub_key
+              , signature
+            FROM sbtc_signer.deposit_decision_receipts
+            WHERE txid = $1
+              AND output_index = $2
+            "#,
         )
+        .bind(txid)
+        .bind(i32::try_from(output_index).map_err(Error::ConversionDatabaseInt)?)
+        .fetch_all(executor)
         .await
+        .map_err(Error::SqlxQuery)
     }
 
-    async fn get_pending_accepted_deposit_requests(
-        &self,
-        chain_tip: &model::BitcoinBlockRef,
-        context_window: u16,
-        threshold: u16,
-    ) -> Result<Vec<model::DepositRequest>, Error> {
-        PgRead::get_pending_accepted_deposit_requests(
-            self.get_connection().await?.as_mut(),
-            chain_tip,
-            context_window,
-            threshold,
+    async fn get_deposit_rejection<'e, E>(
+        executor: &'e mut E,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositRejection>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::DepositRejection>(
+            r#"
+            SELECT
+                txid
+              , output_index
+              , reason
+            FROM sbtc_signer.deposit_rejections
+            WHERE txid = $1
+              AND output_index = $2
+            "#,
         )
+        .bind(txid)
+        .bind(i32::try_from(output_index).map_err(Error::ConversionDatabaseInt)?)
+        .fetch_optional(executor)
         .await
+        .map_err(Error::SqlxQuery)
     }
 
-    async fn get_deposit_request_signer_votes(
-        &self,
+    async fn get_deposit_expiration<'e, E>(
+        executor: &'e mut E,
         txid: &model::BitcoinTxId,
         output_index: u32,
-        aggregate_key: &PublicKey,
-    ) -> Result<model::SignerVotes, Error> {
-        PgRead::get_deposit_request_signer_votes(
-            self.get_connection().await?.as_mut(),
-            txid,
-            output_index,
-            aggregate_key,
+    ) -> Result<Option<model::DepositExpiration>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::DepositExpiration>(
+            r#"
+            SELECT
+                txid
+              , output_index
+              , bitcoin_block_hash
+              , bitcoin_block_height
+              , reactivated
+            FROM sbtc_signer.deposit_expirations
+            WHERE txid = $1
+              AND output_index = $2
+            "#,
         )
+        .bind(txid)
+        .bind(i32::try_from(output_index).map_err(Error::ConversionDatabaseInt)?)
+        .fetch_optional(executor)
         .await
+        .map_err(Error::SqlxQuery)
     }
 
-    async fn get_withdrawal_request_signer_votes(
-        &self,
-        id: &model::QualifiedRequestId,
-        aggregate_key: &PublicKey,
-    ) -> Result<model::SignerVotes, Error> {
-        PgRead::get_withdrawal_request_signer_votes(
-            self.get_connection().await?.as_mut(),
-            id,
-            aggregate_key,
+    async fn get_deposit_requests_for_outpoints<'e, E>(
+        executor: &'e mut E,
+        outpoints: &[OutPoint],
+    ) -> Result<Vec<model::DepositRequest>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let mut txids = Vec::with_capacity(outpoints.len());
+        let mut output_indices = Vec::with_capacity(outpoints.len());
+        for outpoint in outpoints {
+            txids.push(model::BitcoinTxId::from(outpoint.txid));
+            output_indices
+                .push(i32::try_from(outpoint.vout).map_err(Error::ConversionDatabaseInt)?);
+        }
+
+        sqlx::query_as::<_, model::DepositRequest>(
+            r#"
+            SELECT dr.txid
+                 , dr.output_index
+                 , dr.spend_script
+                 , dr.reclaim_script
+                 , dr.reclaim_script_hash
+                 , dr.recipient
+                 , dr.amount
+                 , dr.max_fee
+                 , dr.lock_time
+                 , dr.signers_public_key
+                 , dr.sender_script_pub_keys
+            FROM UNNEST($1::BYTEA[], $2::INTEGER[]) AS r(txid, output_index)
+            JOIN sbtc_signer.deposit_requests AS dr
+              ON dr.txid = r.txid
+             AND dr.output_index = r.output_index
+            "#,
         )
+        .bind(txids)
+        .bind(output_indices)
+        .fetch_all(executor)
         .await
+        .map_err(Error::SqlxQuery)
     }
 
-    async fn get_deposit_request_report(
-        &self,
+    async fn get_deposit_reclaim<'e, E>(
+        executor: &'e mut E,
         chain_tip: &model::BitcoinBlockHash,
         txid: &model::BitcoinTxId,
         output_index: u32,
-        signer_public_key: &PublicKey,
-    ) -> Result<Option<DepositRequestReport>, Error> {
-        PgRead::get_deposit_request_report(
+    ) -> Result<Option<model::DepositReclaim>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        // We only need to walk the blockchain down to the lowest block
+        // that we have seen the deposit request being reclaimed in.
+        sqlx::query_as::<_, model::DepositReclaim>(
+            r#"
+            SELECT
+                reclaims.txid
+              , reclaims.output_index
+              , reclaims.reclaim_txid
+              , reclaims.bitcoin_block_hash
+              , reclaims.bitcoin_block_height
+            FROM sbtc_signer.deposit_reclaims AS reclaims
+            JOIN sbtc_signer.bitcoin_blockchain_until(
+                $1,
+                (
+                    SELECT MIN(bitcoin_block_height)
+                    FROM sbtc_signer.deposit_reclaims
+                    WHERE txid = $2
+                      AND output_index = $3
+                )
+            ) AS blocks
+              ON blocks.block_hash = reclaims.bitcoin_block_hash
+            WHERE reclaims.txid = $2
+              AND reclaims.output_index = $3
+            LIMIT 1
+            "#,
+        )
+        .bind(chain_tip)
+        .bind(txid)
+        .bind(i32::try_from(output_index).map_err(Error::ConversionDatabaseInt)?)
+        .fetch_optional(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_deposit_assessed_fee<'e, E>(
+        executor: &'e mut E,
+        sweep_txid: &model::BitcoinTxId,
+        sweep_block_hash: &model::BitcoinBlockHash,
+        outpoint: &bitcoin::OutPoint,
+    ) -> Result<Option<u64>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let assessed_fee = sqlx::query_scalar::<_, Option<i64>>(
+            r#"
+            SELECT bti.assessed_fee
+            FROM sbtc_signer.bitcoin_tx_inputs AS bti
+            JOIN sbtc_signer.bitcoin_transactions AS bt
+              ON bt.txid = bti.txid
+            WHERE bti.txid = $1
+              AND bt.block_hash = $2
+              AND bti.prevout_txid = $3
+              AND bti.prevout_output_index = $4
+              AND bti.prevout_type = 'deposit'
+            "#,
+        )
+        .bind(sweep_txid)
+        .bind(sweep_block_hash)
+        .bind(model::BitcoinTxId::from(outpoint.txid))
+        .bind(i32::try_from(outpoint.vout).map_err(Error::ConversionDatabaseInt)?)
+        .fetch_optional(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        assessed_fee
+            .flatten()
+            .map(u64::try_from)
+            .transpose()
+            .map_err(Error::ConversionDatabaseInt)
+    }
+
+    async fn get_latest_tenure_plan<'e, E>(
+        executor: &'e mut E,
+    ) -> Result<Option<model::TenurePlan>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::TenurePlan>(
+            r#"
+            SELECT
+                bitcoin_chain_tip
+              , coordinator_public_key
+              , deploy_contracts
+              , rotate_keys
+              , deposits_to_sweep
+              , withdrawals_to_sweep
+              , deposits_to_complete
+              , withdrawals_to_accept
+              , withdrawals_to_reject
+            FROM sbtc_signer.tenure_plans
+            ORDER BY id DESC
+            LIMIT 1
+            "#,
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_bitcoin_block_processing<'e, E>(
+        executor: &'e mut E,
+        block_hash: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::BitcoinBlockProcessing>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::BitcoinBlockProcessing>(
+            r#"
+            SELECT
+                block_hash
+              , observed_at
+              , deposits_fetched_at
+              , decisions_completed_at
+              , tenure_completed_at
+            FROM sbtc_signer.bitcoin_block_processing
+            WHERE block_hash = $1
+            "#,
+        )
+        .bind(block_hash)
+        .fetch_optional(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_stacks_tx_submissions<'e, E>(
+        executor: &'e mut E,
+        request_keys: &[String],
+    ) -> Result<Vec<model::StacksTxSubmission>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::StacksTxSubmission>(
+            r#"
+            SELECT
+                txid
+              , tx_kind
+              , request_keys
+              , nonce
+              , tx_fee
+              , status
+              , rejection_reason
+              , bitcoin_block_hash
+            FROM sbtc_signer.stacks_tx_submissions
+            WHERE request_keys && $1
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(request_keys)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_pending_stacks_tx_submissions<'e, E>(
+        executor: &'e mut E,
+    ) -> Result<Vec<model::StacksTxSubmission>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::StacksTxSubmission>(
+            r#"
+            SELECT
+                txid
+              , tx_kind
+              , request_keys
+              , nonce
+              , tx_fee
+              , status
+              , rejection_reason
+              , bitcoin_block_hash
+            FROM sbtc_signer.stacks_tx_submissions
+            WHERE status = 'pending'
+            ORDER BY created_at ASC
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_unsettled_bitcoin_broadcasts<'e, E>(
+        executor: &'e mut E,
+    ) -> Result<Vec<model::BitcoinBroadcast>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::BitcoinBroadcast>(
+            r#"
+            SELECT
+                txid
+              , package_txid
+              , package_position
+              , tx
+              , fee_rate
+              , bitcoin_block_hash
+              , status
+              , attempts
+              , last_error
+            FROM sbtc_signer.bitcoin_broadcasts
+            WHERE status IN ('pending', 'broadcast')
+            ORDER BY created_at ASC, package_position ASC
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_quarantined_stacks_blocks<'e, E>(
+        executor: &'e mut E,
+    ) -> Result<Vec<model::QuarantinedStacksBlock>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::QuarantinedStacksBlock>(
+            r#"
+            SELECT
+                block_hash
+              , bitcoin_anchor
+              , reason
+            FROM sbtc_signer.quarantined_stacks_blocks
+            ORDER BY updated_at DESC
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_conflicting_signed_sighash<'e, E>(
+        executor: &'e mut E,
+        sighash: &model::SigHash,
+    ) -> Result<Option<model::SigHash>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_scalar::<_, model::SigHash>(
+            r#"
+            SELECT ss.sighash
+            FROM sbtc_signer.bitcoin_tx_sighashes AS bts
+            JOIN sbtc_signer.signed_sighashes AS ss
+              ON ss.prevout_txid = bts.prevout_txid
+             AND ss.prevout_output_index = bts.prevout_output_index
+             AND ss.chain_tip = bts.chain_tip
+            WHERE bts.sighash = $1
+              AND ss.sighash <> bts.sighash
+            "#,
+        )
+        .bind(sighash)
+        .fetch_optional(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_mint_mismatches<'e, E>(
+        executor: &'e mut E,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::MintMismatch>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::MintMismatch>(
+            r#"
+            SELECT
+                txid
+              , output_index
+              , chain_tip
+              , origin
+              , recipient
+              , expected_recipient
+              , amount
+              , expected_amount
+            FROM sbtc_signer.mint_mismatches
+            WHERE txid = $1
+              AND output_index = $2
+            ORDER BY id ASC
+            "#,
+        )
+        .bind(txid)
+        .bind(i32::try_from(output_index).map_err(Error::ConversionDatabaseInt)?)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_withdrawal_sweep_txids<'e, E>(
+        executor: &'e mut E,
+        id: &model::QualifiedRequestId,
+    ) -> Result<Vec<model::BitcoinTxId>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_scalar::<_, model::BitcoinTxId>(
+            r#"
+            SELECT DISTINCT bitcoin_txid
+            FROM sbtc_signer.bitcoin_withdrawals_outputs
+            WHERE request_id = $1
+              AND stacks_block_hash = $2
+            "#,
+        )
+        .bind(i64::try_from(id.request_id).map_err(Error::ConversionDatabaseInt)?)
+        .bind(id.block_hash)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_signer_payment<'e, E>(
+        executor: &'e mut E,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::SignerPayment>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::SignerPayment>(
+            r#"
+            SELECT
+                txid
+              , output_index
+              , amount
+              , script_pubkey
+              , sender_script_pubkey
+              , status
+            FROM sbtc_signer.signer_payments
+            WHERE txid = $1
+              AND output_index = $2
+            "#,
+        )
+        .bind(txid)
+        .bind(i32::try_from(output_index).map_err(Error::ConversionDatabaseInt)?)
+        .fetch_optional(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_signer_payments<'e, E>(
+        executor: &'e mut E,
+        status: model::SignerPaymentStatus,
+    ) -> Result<Vec<model::SignerPayment>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::SignerPayment>(
+            r#"
+            SELECT
+                txid
+              , output_index
+              , amount
+              , script_pubkey
+              , sender_script_pubkey
+              , status
+            FROM sbtc_signer.signer_payments
+            WHERE status = $1
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(status)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_pending_refunds<'e, E>(
+        executor: &'e mut E,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::SignerPayment>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let status = Some(model::SignerPaymentStatus::RefundApproved);
+        Self::get_unspent_signer_payments(executor, chain_tip, status).await
+    }
+
+    /// Get the payments to the signers with the given status, or with any
+    /// status if none is given, that were confirmed but not spent on the
+    /// blockchain identified by the given chain tip.
+    async fn get_unspent_signer_payments<'e, E>(
+        executor: &'e mut E,
+        chain_tip: &model::BitcoinBlockHash,
+        status: Option<model::SignerPaymentStatus>,
+    ) -> Result<Vec<model::SignerPayment>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        // We only need to look at the blockchain down to the earliest
+        // block that confirmed one of the payments.
+        sqlx::query_as::<_, model::SignerPayment>(
+            r#"
+            WITH payments AS (
+                SELECT
+                    txid
+                  , output_index
+                  , amount
+                  , script_pubkey
+                  , sender_script_pubkey
+                  , status
+                  , created_at
+                FROM sbtc_signer.signer_payments
+                WHERE $2::sbtc_signer.signer_payment_status IS NULL
+                   OR status = $2
+            ),
+            min_block_height AS (
+                SELECT COALESCE(MIN(bb.block_height), 0) AS block_height
+                FROM payments AS p
+                JOIN sbtc_signer.bitcoin_transactions AS bt USING (txid)
+                JOIN sbtc_signer.bitcoin_blocks AS bb USING (block_hash)
+            ),
+            bitcoin_blockchain AS (
+                SELECT block_hash
+                FROM sbtc_signer.bitcoin_blockchain_until(
+                    $1,
+                    (SELECT block_height FROM min_block_height)
+                )
+            ),
+            spent_outputs AS (
+                SELECT
+                    bi.prevout_txid
+                  , bi.prevout_output_index
+                FROM sbtc_signer.bitcoin_tx_inputs AS bi
+                JOIN sbtc_signer.bitcoin_transactions AS bt USING (txid)
+                JOIN bitcoin_blockchain AS bb USING (block_hash)
+            )
+            SELECT
+                p.txid
+              , p.output_index
+              , p.amount
+              , p.script_pubkey
+              , p.sender_script_pubkey
+              , p.status
+            FROM payments AS p
+            WHERE EXISTS (
+                SELECT 1
+                FROM sbtc_signer.bitcoin_transactions AS bt
+                JOIN bitcoin_blockchain AS bb USING (block_hash)
+                WHERE bt.txid = p.txid
+            )
+              AND NOT EXISTS (
+                SELECT 1
+                FROM spent_outputs AS so
+                WHERE so.prevout_txid = p.txid
+                  AND so.prevout_output_index = p.output_index
+            )
+            ORDER BY p.created_at ASC
+            "#,
+        )
+        .bind(chain_tip)
+        .bind(status)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_latest_dkg_begin_refusal<'e, E>(
+        executor: &'e mut E,
+    ) -> Result<Option<model::DkgBeginRefusal>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::DkgBeginRefusal>(
+            r#"
+            SELECT
+                reason
+              , bitcoin_chain_tip
+              , bitcoin_chain_tip_height
+              , latest_dkg_shares_status
+              , dkg_shares_count
+              , dkg_target_rounds
+              , dkg_min_bitcoin_block_height
+            FROM sbtc_signer.dkg_begin_refusals
+            ORDER BY id DESC
+            LIMIT 1
+            "#,
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_latest_signer_key_rotation<'e, E>(
+        executor: &'e mut E,
+    ) -> Result<Option<model::SignerKeyRotation>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::SignerKeyRotation>(
+            r#"
+            SELECT
+                old_public_key
+              , new_public_key
+              , status
+            FROM sbtc_signer.signer_key_rotations
+            ORDER BY id DESC
+            LIMIT 1
+            "#,
+        )
+        .fetch_optional(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_key_endorsements<'e, E>(
+        executor: &'e mut E,
+    ) -> Result<Vec<model::KeyEndorsement>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::KeyEndorsement>(
+            r#"
+            SELECT
+                old_public_key
+              , new_public_key
+              , bitcoin_chain_tip
+            FROM sbtc_signer.signer_key_endorsements
+            ORDER BY created_at ASC
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_emergency_recovery_approvals<'e, E>(
+        executor: &'e mut E,
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> Result<Vec<model::EmergencyRecoveryApproval>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::EmergencyRecoveryApproval>(
+            r#"
+            SELECT
+                signer_public_key
+              , recovery_script_pubkey
+              , bitcoin_block_hash
+              , bitcoin_block_height
+              , revoked
+            FROM sbtc_signer.emergency_recovery_approvals
+            WHERE recovery_script_pubkey = $1
+            ORDER BY bitcoin_block_height ASC, created_at ASC
+            "#,
+        )
+        .bind(recovery_script_pubkey)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn is_emergency_recovery_swept<'e, E>(
+        executor: &'e mut E,
+        chain_tip: &model::BitcoinBlockHash,
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> Result<bool, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        // We only need to look at the blockchain down to the earliest
+        // block that confirmed one of the sweeps.
+        sqlx::query_scalar::<_, bool>(
+            r#"
+            WITH sweeps AS (
+                SELECT txid
+                FROM sbtc_signer.emergency_recovery_sweeps
+                WHERE recovery_script_pubkey = $2
+            ),
+            min_block_height AS (
+                SELECT MIN(bb.block_height) AS block_height
+                FROM sweeps AS s
+                JOIN sbtc_signer.bitcoin_transactions AS bt USING (txid)
+                JOIN sbtc_signer.bitcoin_blocks AS bb USING (block_hash)
+            )
+            SELECT EXISTS (
+                SELECT 1
+                FROM sbtc_signer.bitcoin_blockchain_until(
+                    $1,
+                    (SELECT block_height FROM min_block_height)
+                ) AS bb
+                JOIN sbtc_signer.bitcoin_transactions AS bt USING (block_hash)
+                JOIN sweeps AS s USING (txid)
+            )
+            "#,
+        )
+        .bind(chain_tip)
+        .bind(recovery_script_pubkey)
+        .fetch_one(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_deposit_decision_acknowledgements<'e, E>(
+        executor: &'e mut E,
+        outpoints: &[OutPoint],
+    ) -> Result<Vec<model::DepositDecisionAcknowledgement>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let mut txids = Vec::with_capacity(outpoints.len());
+        let mut output_indices = Vec::with_capacity(outpoints.len());
+        for outpoint in outpoints {
+            txids.push(model::BitcoinTxId::from(outpoint.txid));
+            output_indices
+                .push(i32::try_from(outpoint.vout).map_err(Error::ConversionDatabaseInt)?);
+        }
+
+        sqlx::query_as::<_, model::DepositDecisionAcknowledgement>(
+            r#"
+            SELECT
+                da.txid
+              , da.output_index
+              , da.signer_pub_key
+              , da.decision_version
+            FROM UNNEST($1::BYTEA[], $2::INTEGER[]) AS r(txid, output_index)
+            JOIN sbtc_signer.deposit_decision_acknowledgements AS da
+              ON da.txid = r.txid
+             AND da.output_index = r.output_index
+            "#,
+        )
+        .bind(txids)
+        .bind(output_indices)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_withdrawal_decision_acknowledgements<'e, E>(
+        executor: &'e mut E,
+        ids: &[model::QualifiedRequestId],
+    ) -> Result<Vec<model::WithdrawalDecisionAcknowledgement>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let mut request_ids = Vec::with_capacity(ids.len());
+        let mut block_hashes = Vec::with_capacity(ids.len());
+        for id in ids {
+            request_ids.push(i64::try_from(id.request_id).map_err(Error::ConversionDatabaseInt)?);
+            block_hashes.push(id.block_hash);
+        }
+
+        sqlx::query_as::<_, model::WithdrawalDecisionAcknowledgement>(
+            r#"
+            SELECT
+                wa.request_id
+              , wa.txid
+              , wa.block_hash
+              , wa.signer_pub_key
+              , wa.decision_version
+            FROM UNNEST($1::BIGINT[], $2::BYTEA[]) AS r(request_id, block_hash)
+            JOIN sbtc_signer.withdrawal_decision_acknowledgements AS wa
+              ON wa.request_id = r.request_id
+             AND wa.block_hash = r.block_hash
+            "#,
+        )
+        .bind(request_ids)
+        .bind(block_hashes)
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn is_coordinator_paused<'e, E>(executor: &'e mut E) -> Result<bool, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT paused
+            FROM sbtc_signer.coordinator_pause
+            "#,
+        )
+        .fetch_optional(executor)
+        .await
+        .map(|paused| paused.unwrap_or(false))
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_deposit_decision_overrides<'e, E>(
+        executor: &'e mut E,
+    ) -> Result<Vec<model::DepositDecisionOverride>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::DepositDecisionOverride>(
+            r#"
+            SELECT
+                txid
+              , output_index
+              , can_accept
+            FROM sbtc_signer.deposit_decision_overrides
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn get_withdrawal_decision_overrides<'e, E>(
+        executor: &'e mut E,
+    ) -> Result<Vec<model::WithdrawalDecisionOverride>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query_as::<_, model::WithdrawalDecisionOverride>(
+            r#"
+            SELECT
+                request_id
+              , txid
+              , block_hash
+              , accept
+            FROM sbtc_signer.withdrawal_decision_overrides
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+}
+
+impl DbRead for PgStore {
+    async fn get_bitcoin_block(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::BitcoinBlock>, Error> {
+        PgRead::get_bitcoin_block(self.get_connection().await?.as_mut(), block_hash).await
+    }
+
+    async fn get_stacks_block(
+        &self,
+        block_hash: &model::StacksBlockHash,
+    ) -> Result<Option<model::StacksBlock>, Error> {
+        PgRead::get_stacks_block(self.get_connection().await?.as_mut(), block_hash).await
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    async fn get_bitcoin_canonical_chain_tip(
+        &self,
+    ) -> Result<Option<model::BitcoinBlockHash>, Error> {
+        PgRead::get_bitcoin_canonical_chain_tip(self.get_connection().await?.as_mut()).await
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    async fn get_bitcoin_canonical_chain_tip_ref(
+        &self,
+    ) -> Result<Option<model::BitcoinBlockRef>, Error> {
+        PgRead::get_bitcoin_canonical_chain_tip_ref(self.get_connection().await?.as_mut()).await
+    }
+
+    async fn get_stacks_chain_tip(
+        &self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::StacksBlock>, Error> {
+        PgRead::get_stacks_chain_tip(self.get_connection().await?.as_mut(), bitcoin_chain_tip).await
+    }
+
+    async fn get_pending_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        PgRead::get_pending_deposit_requests(
+            self.get_connection().await?.as_mut(),
+            chain_tip,
+            context_window,
+            signer_public_key,
+        )
+        .await
+    }
+
+    async fn get_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        PgRead::get_deposit_requests(
+            self.get_connection().await?.as_mut(),
+            chain_tip,
+            context_window,
+        )
+        .await
+    }
+
+    async fn get_pending_accepted_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockRef,
+        context_window: u16,
+        threshold: u16,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        PgRead::get_pending_accepted_deposit_requests(
+            self.get_connection().await?.as_mut(),
+            chain_tip,
+            context_window,
+            threshold,
+        )
+        .await
+    }
+
+    async fn get_deposit_request_signer_votes(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        aggregate_key: &PublicKey,
+    ) -> Result<model::SignerVotes, Error> {
+        PgRead::get_deposit_request_signer_votes(
+            self.get_connection().await?.as_mut(),
+            txid,
+            output_index,
+            aggregate_key,
+        )
+        .await
+    }
+
+    async fn get_deposit_requests_signer_votes(
+        &self,
+        outpoints: &[OutPoint],
+        aggregate_key: &PublicKey,
+    ) -> Result<HashMap<OutPoint, model::SignerVotes>, Error> {
+        PgRead::get_deposit_requests_signer_votes(
+            self.get_connection().await?.as_mut(),
+            outpoints,
+            aggregate_key,
+        )
+        .await
+    }
+
+    async fn get_withdrawal_request_signer_votes(
+        &self,
+        id: &model::QualifiedRequestId,
+        aggregate_key: &PublicKey,
+    ) -> Result<model::SignerVotes, Error> {
+        PgRead::get_withdrawal_request_signer_votes(
+            self.get_connection().await?.as_mut(),
+            id,
+            aggregate_key,
+        )
+        .await
+    }
+
+    async fn get_deposit_request_report(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        signer_public_key: &PublicKey,
+    ) -> Result<Option<DepositRequestReport>, Error> {
+        PgRead::get_deposit_request_report(
             self.get_connection().await?.as_mut(),
             chain_tip,
             txid,
@@ -2686,6 +4110,21 @@ impl DbRead for PgStore {
         .await
     }
 
+    async fn get_decided_withdrawal_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        PgRead::get_decided_withdrawal_requests(
+            self.get_connection().await?.as_mut(),
+            chain_tip,
+            context_window,
+            signer_public_key,
+        )
+        .await
+    }
+
     async fn get_pending_accepted_withdrawal_requests(
         &self,
         bitcoin_chain_tip: &model::BitcoinBlockHash,
@@ -2733,6 +4172,38 @@ impl DbRead for PgStore {
         .await
     }
 
+    async fn get_withdrawal_requests_by_recipient(
+        &self,
+        recipient: &model::ScriptPubKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        PgRead::get_withdrawal_requests_by_recipient(
+            self.get_connection().await?.as_mut(),
+            recipient,
+        )
+        .await
+    }
+
+    async fn get_withdrawal_requests_by_sender(
+        &self,
+        sender: &model::StacksPrincipal,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        PgRead::get_withdrawal_requests_by_sender(self.get_connection().await?.as_mut(), sender)
+            .await
+    }
+
+    async fn get_withdrawal_request(
+        &self,
+        request_id: u64,
+        block_hash: &model::StacksBlockHash,
+    ) -> Result<Option<model::WithdrawalRequest>, Error> {
+        PgRead::get_withdrawal_request(
+            self.get_connection().await?.as_mut(),
+            request_id,
+            block_hash,
+        )
+        .await
+    }
+
     async fn compute_withdrawn_total(
         &self,
         bitcoin_chain_tip: &model::BitcoinBlockHash,
@@ -2774,12 +4245,28 @@ impl DbRead for PgStore {
         PgRead::get_latest_encrypted_dkg_shares(self.get_connection().await?.as_mut()).await
     }
 
+    async fn get_all_encrypted_dkg_shares(&self) -> Result<Vec<model::EncryptedDkgShares>, Error> {
+        PgRead::get_all_encrypted_dkg_shares(self.get_connection().await?.as_mut()).await
+    }
+
     async fn get_latest_verified_dkg_shares(
         &self,
     ) -> Result<Option<model::EncryptedDkgShares>, Error> {
         PgRead::get_latest_verified_dkg_shares(self.get_connection().await?.as_mut()).await
     }
 
+    async fn get_aggregate_key_record(
+        &self,
+        aggregate_key: &PublicKey,
+    ) -> Result<Option<model::AggregateKeyRecord>, Error> {
+        let mut conn = self.get_connection().await?;
+        PgRead::get_aggregate_key_record(conn.as_mut(), aggregate_key).await
+    }
+
+    async fn get_aggregate_key_records(&self) -> Result<Vec<model::AggregateKeyRecord>, Error> {
+        PgRead::get_aggregate_key_records(self.get_connection().await?.as_mut()).await
+    }
+
     async fn get_encrypted_dkg_shares_count(&self) -> Result<u32, Error> {
         PgRead::get_encrypted_dkg_shares_count(self.get_connection().await?.as_mut()).await
     }
@@ -2894,50 +4381,317 @@ impl DbRead for PgStore {
         .await
     }
 
-    async fn get_deposit_request(
+    async fn get_deposit_request(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositRequest>, Error> {
+        PgRead::get_deposit_request(self.get_connection().await?.as_mut(), txid, output_index).await
+    }
+
+    async fn get_deposit_requests_by_recipient(
+        &self,
+        recipient: &model::StacksPrincipal,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        PgRead::get_deposit_requests_by_recipient(self.get_connection().await?.as_mut(), recipient)
+            .await
+    }
+
+    async fn get_completed_deposit_events(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::CompletedDepositEvent>, Error> {
+        PgRead::get_completed_deposit_events(
+            self.get_connection().await?.as_mut(),
+            txid,
+            output_index,
+        )
+        .await
+    }
+
+    async fn will_sign_bitcoin_tx_sighash(
+        &self,
+        sighash: &model::SigHash,
+    ) -> Result<Option<(bool, PublicKeyXOnly)>, Error> {
+        PgRead::will_sign_bitcoin_tx_sighash(self.get_connection().await?.as_mut(), sighash).await
+    }
+
+    async fn get_withdrawal_signer_decisions(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::WithdrawalSigner>, Error> {
+        PgRead::get_withdrawal_signer_decisions(
+            self.get_connection().await?.as_mut(),
+            chain_tip,
+            context_window,
+            signer_public_key,
+        )
+        .await
+    }
+
+    async fn get_deposit_signer_decisions(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::DepositSigner>, Error> {
+        PgRead::get_deposit_signer_decisions(
+            self.get_connection().await?.as_mut(),
+            chain_tip,
+            context_window,
+            signer_public_key,
+        )
+        .await
+    }
+
+    async fn get_deposit_signer_decisions_for_outpoints(
+        &self,
+        outpoints: &[OutPoint],
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::DepositSigner>, Error> {
+        PgRead::get_deposit_signer_decisions_for_outpoints(
+            self.get_connection().await?.as_mut(),
+            outpoints,
+            signer_public_key,
+        )
+        .await
+    }
+    async fn get_coordinator_attestations(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::CoordinatorAttestation>, Error> {
+        PgRead::get_coordinator_attestations(self.get_connection().await?.as_mut(), chain_tip).await
+    }
+
+    async fn get_deposit_decision_receipts(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::DepositDecisionReceipt>, Error> {
+        PgRead::get_deposit_decision_receipts(
+            self.get_connection().await?.as_mut(),
+            txid,
+            output_index,
+        )
+        .await
+    }
+
+    async fn get_deposit_rejection(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositRejection>, Error> {
+        PgRead::get_deposit_rejection(self.get_connection().await?.as_mut(), txid, output_index)
+            .await
+    }
+
+    async fn get_deposit_expiration(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositExpiration>, Error> {
+        PgRead::get_deposit_expiration(self.get_connection().await?.as_mut(), txid, output_index)
+            .await
+    }
+
+    async fn get_deposit_requests_for_outpoints(
+        &self,
+        outpoints: &[OutPoint],
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        PgRead::get_deposit_requests_for_outpoints(self.get_connection().await?.as_mut(), outpoints)
+            .await
+    }
+
+    async fn get_deposit_reclaim(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositReclaim>, Error> {
+        PgRead::get_deposit_reclaim(
+            self.get_connection().await?.as_mut(),
+            chain_tip,
+            txid,
+            output_index,
+        )
+        .await
+    }
+
+    async fn get_deposit_assessed_fee(
+        &self,
+        sweep_txid: &model::BitcoinTxId,
+        sweep_block_hash: &model::BitcoinBlockHash,
+        outpoint: &bitcoin::OutPoint,
+    ) -> Result<Option<u64>, Error> {
+        PgRead::get_deposit_assessed_fee(
+            self.get_connection().await?.as_mut(),
+            sweep_txid,
+            sweep_block_hash,
+            outpoint,
+        )
+        .await
+    }
+
+    async fn get_latest_tenure_plan(&self) -> Result<Option<model::TenurePlan>, Error> {
+        PgRead::get_latest_tenure_plan(self.get_connection().await?.as_mut()).await
+    }
+
+    async fn get_bitcoin_block_processing(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::BitcoinBlockProcessing>, Error> {
+        let mut conn = self.get_connection().await?;
+        PgRead::get_bitcoin_block_processing(conn.as_mut(), block_hash).await
+    }
+
+    async fn get_stacks_tx_submissions(
+        &self,
+        request_keys: &[String],
+    ) -> Result<Vec<model::StacksTxSubmission>, Error> {
+        PgRead::get_stacks_tx_submissions(self.get_connection().await?.as_mut(), request_keys).await
+    }
+
+    async fn get_pending_stacks_tx_submissions(
+        &self,
+    ) -> Result<Vec<model::StacksTxSubmission>, Error> {
+        PgRead::get_pending_stacks_tx_submissions(self.get_connection().await?.as_mut()).await
+    }
+
+    async fn get_unsettled_bitcoin_broadcasts(
+        &self,
+    ) -> Result<Vec<model::BitcoinBroadcast>, Error> {
+        PgRead::get_unsettled_bitcoin_broadcasts(self.get_connection().await?.as_mut()).await
+    }
+
+    async fn get_quarantined_stacks_blocks(
+        &self,
+    ) -> Result<Vec<model::QuarantinedStacksBlock>, Error> {
+        PgRead::get_quarantined_stacks_blocks(self.get_connection().await?.as_mut()).await
+    }
+
+    async fn get_conflicting_signed_sighash(
+        &self,
+        sighash: &model::SigHash,
+    ) -> Result<Option<model::SigHash>, Error> {
+        PgRead::get_conflicting_signed_sighash(self.get_connection().await?.as_mut(), sighash).await
+    }
+
+    async fn get_mint_mismatches(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::MintMismatch>, Error> {
+        PgRead::get_mint_mismatches(self.get_connection().await?.as_mut(), txid, output_index).await
+    }
+
+    async fn get_withdrawal_sweep_txids(
+        &self,
+        id: &model::QualifiedRequestId,
+    ) -> Result<Vec<model::BitcoinTxId>, Error> {
+        PgRead::get_withdrawal_sweep_txids(self.get_connection().await?.as_mut(), id).await
+    }
+
+    async fn get_signer_payment(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::SignerPayment>, Error> {
+        PgRead::get_signer_payment(self.get_connection().await?.as_mut(), txid, output_index).await
+    }
+
+    async fn get_signer_payments(
+        &self,
+        status: model::SignerPaymentStatus,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        PgRead::get_signer_payments(self.get_connection().await?.as_mut(), status).await
+    }
+
+    async fn get_pending_refunds(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        PgRead::get_pending_refunds(self.get_connection().await?.as_mut(), chain_tip).await
+    }
+
+    async fn get_unspent_signer_payments(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        let mut conn = self.get_connection().await?;
+        PgRead::get_unspent_signer_payments(conn.as_mut(), chain_tip, None).await
+    }
+
+    async fn get_latest_dkg_begin_refusal(&self) -> Result<Option<model::DkgBeginRefusal>, Error> {
+        PgRead::get_latest_dkg_begin_refusal(self.get_connection().await?.as_mut()).await
+    }
+
+    async fn get_latest_signer_key_rotation(
         &self,
-        txid: &model::BitcoinTxId,
-        output_index: u32,
-    ) -> Result<Option<model::DepositRequest>, Error> {
-        PgRead::get_deposit_request(self.get_connection().await?.as_mut(), txid, output_index).await
+    ) -> Result<Option<model::SignerKeyRotation>, Error> {
+        PgRead::get_latest_signer_key_rotation(self.get_connection().await?.as_mut()).await
     }
 
-    async fn will_sign_bitcoin_tx_sighash(
-        &self,
-        sighash: &model::SigHash,
-    ) -> Result<Option<(bool, PublicKeyXOnly)>, Error> {
-        PgRead::will_sign_bitcoin_tx_sighash(self.get_connection().await?.as_mut(), sighash).await
+    async fn get_key_endorsements(&self) -> Result<Vec<model::KeyEndorsement>, Error> {
+        PgRead::get_key_endorsements(self.get_connection().await?.as_mut()).await
     }
 
-    async fn get_withdrawal_signer_decisions(
+    async fn get_emergency_recovery_approvals(
         &self,
-        chain_tip: &model::BitcoinBlockHash,
-        context_window: u16,
-        signer_public_key: &PublicKey,
-    ) -> Result<Vec<model::WithdrawalSigner>, Error> {
-        PgRead::get_withdrawal_signer_decisions(
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> Result<Vec<model::EmergencyRecoveryApproval>, Error> {
+        PgRead::get_emergency_recovery_approvals(
             self.get_connection().await?.as_mut(),
-            chain_tip,
-            context_window,
-            signer_public_key,
+            recovery_script_pubkey,
         )
         .await
     }
 
-    async fn get_deposit_signer_decisions(
+    async fn is_emergency_recovery_swept(
         &self,
         chain_tip: &model::BitcoinBlockHash,
-        context_window: u16,
-        signer_public_key: &PublicKey,
-    ) -> Result<Vec<model::DepositSigner>, Error> {
-        PgRead::get_deposit_signer_decisions(
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> Result<bool, Error> {
+        let mut conn = self.get_connection().await?;
+        PgRead::is_emergency_recovery_swept(conn.as_mut(), chain_tip, recovery_script_pubkey).await
+    }
+
+    async fn get_deposit_decision_acknowledgements(
+        &self,
+        outpoints: &[OutPoint],
+    ) -> Result<Vec<model::DepositDecisionAcknowledgement>, Error> {
+        PgRead::get_deposit_decision_acknowledgements(
             self.get_connection().await?.as_mut(),
-            chain_tip,
-            context_window,
-            signer_public_key,
+            outpoints,
         )
         .await
     }
+
+    async fn get_withdrawal_decision_acknowledgements(
+        &self,
+        ids: &[model::QualifiedRequestId],
+    ) -> Result<Vec<model::WithdrawalDecisionAcknowledgement>, Error> {
+        PgRead::get_withdrawal_decision_acknowledgements(self.get_connection().await?.as_mut(), ids)
+            .await
+    }
+
+    async fn is_coordinator_paused(&self) -> Result<bool, Error> {
+        PgRead::is_coordinator_paused(self.get_connection().await?.as_mut()).await
+    }
+
+    async fn get_deposit_decision_overrides(
+        &self,
+    ) -> Result<Vec<model::DepositDecisionOverride>, Error> {
+        PgRead::get_deposit_decision_overrides(self.get_connection().await?.as_mut()).await
+    }
+
+    async fn get_withdrawal_decision_overrides(
+        &self,
+    ) -> Result<Vec<model::WithdrawalDecisionOverride>, Error> {
+        PgRead::get_withdrawal_decision_overrides(self.get_connection().await?.as_mut()).await
+    }
 }
 
 impl DbRead for PgTransaction<'_> {
@@ -2996,6 +4750,15 @@ impl DbRead for PgTransaction<'_> {
         .await
     }
 
+    async fn get_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_deposit_requests(tx.as_mut(), chain_tip, context_window).await
+    }
+
     async fn get_pending_accepted_deposit_requests(
         &self,
         chain_tip: &model::BitcoinBlockRef,
@@ -3061,6 +4824,19 @@ impl DbRead for PgTransaction<'_> {
         .await
     }
 
+    async fn get_deposit_signer_decisions_for_outpoints(
+        &self,
+        outpoints: &[OutPoint],
+        signer_public_key: &crate::keys::PublicKey,
+    ) -> Result<Vec<model::DepositSigner>, Error> {
+        PgRead::get_deposit_signer_decisions_for_outpoints(
+            self.tx.lock().await.as_mut(),
+            outpoints,
+            signer_public_key,
+        )
+        .await
+    }
+
     async fn get_withdrawal_signer_decisions(
         &self,
         chain_tip: &model::BitcoinBlockHash,
@@ -3110,6 +4886,21 @@ impl DbRead for PgTransaction<'_> {
         .await
     }
 
+    async fn get_decided_withdrawal_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &crate::keys::PublicKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        PgRead::get_decided_withdrawal_requests(
+            self.tx.lock().await.as_mut(),
+            chain_tip,
+            context_window,
+            signer_public_key,
+        )
+        .await
+    }
+
     async fn get_pending_accepted_withdrawal_requests(
         &self,
         bitcoin_chain_tip: &model::BitcoinBlockHash,
@@ -3157,6 +4948,31 @@ impl DbRead for PgTransaction<'_> {
         .await
     }
 
+    async fn get_withdrawal_requests_by_recipient(
+        &self,
+        recipient: &model::ScriptPubKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_withdrawal_requests_by_recipient(tx.as_mut(), recipient).await
+    }
+
+    async fn get_withdrawal_requests_by_sender(
+        &self,
+        sender: &model::StacksPrincipal,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_withdrawal_requests_by_sender(tx.as_mut(), sender).await
+    }
+
+    async fn get_withdrawal_request(
+        &self,
+        request_id: u64,
+        block_hash: &model::StacksBlockHash,
+    ) -> Result<Option<model::WithdrawalRequest>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_withdrawal_request(tx.as_mut(), request_id, block_hash).await
+    }
+
     async fn compute_withdrawn_total(
         &self,
         bitcoin_chain_tip: &model::BitcoinBlockHash,
@@ -3200,6 +5016,11 @@ impl DbRead for PgTransaction<'_> {
         PgRead::get_latest_encrypted_dkg_shares(tx.as_mut()).await
     }
 
+    async fn get_all_encrypted_dkg_shares(&self) -> Result<Vec<model::EncryptedDkgShares>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_all_encrypted_dkg_shares(tx.as_mut()).await
+    }
+
     async fn get_latest_verified_dkg_shares(
         &self,
     ) -> Result<Option<model::EncryptedDkgShares>, Error> {
@@ -3207,6 +5028,19 @@ impl DbRead for PgTransaction<'_> {
         PgRead::get_latest_verified_dkg_shares(tx.as_mut()).await
     }
 
+    async fn get_aggregate_key_record(
+        &self,
+        aggregate_key: &PublicKey,
+    ) -> Result<Option<model::AggregateKeyRecord>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_aggregate_key_record(tx.as_mut(), aggregate_key).await
+    }
+
+    async fn get_aggregate_key_records(&self) -> Result<Vec<model::AggregateKeyRecord>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_aggregate_key_records(tx.as_mut()).await
+    }
+
     async fn get_encrypted_dkg_shares_count(&self) -> Result<u32, Error> {
         let mut tx = self.tx.lock().await;
         PgRead::get_encrypted_dkg_shares_count(tx.as_mut()).await
@@ -3265,6 +5099,19 @@ impl DbRead for PgTransaction<'_> {
         .await
     }
 
+    async fn get_deposit_requests_signer_votes(
+        &self,
+        outpoints: &[OutPoint],
+        aggregate_key: &crate::keys::PublicKey,
+    ) -> Result<HashMap<OutPoint, model::SignerVotes>, Error> {
+        PgRead::get_deposit_requests_signer_votes(
+            self.tx.lock().await.as_mut(),
+            outpoints,
+            aggregate_key,
+        )
+        .await
+    }
+
     async fn get_withdrawal_request_signer_votes(
         &self,
         id: &model::QualifiedRequestId,
@@ -3354,6 +5201,23 @@ impl DbRead for PgTransaction<'_> {
         PgRead::get_deposit_request(tx.as_mut(), txid, output_index).await
     }
 
+    async fn get_deposit_requests_by_recipient(
+        &self,
+        recipient: &model::StacksPrincipal,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_deposit_requests_by_recipient(tx.as_mut(), recipient).await
+    }
+
+    async fn get_completed_deposit_events(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::CompletedDepositEvent>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_completed_deposit_events(tx.as_mut(), txid, output_index).await
+    }
+
     async fn will_sign_bitcoin_tx_sighash(
         &self,
         sighash: &model::SigHash,
@@ -3361,4 +5225,236 @@ impl DbRead for PgTransaction<'_> {
         let mut tx = self.tx.lock().await;
         PgRead::will_sign_bitcoin_tx_sighash(tx.as_mut(), sighash).await
     }
+
+    async fn get_coordinator_attestations(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::CoordinatorAttestation>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_coordinator_attestations(tx.as_mut(), chain_tip).await
+    }
+
+    async fn get_deposit_decision_receipts(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::DepositDecisionReceipt>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_deposit_decision_receipts(tx.as_mut(), txid, output_index).await
+    }
+
+    async fn get_deposit_rejection(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositRejection>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_deposit_rejection(tx.as_mut(), txid, output_index).await
+    }
+
+    async fn get_deposit_expiration(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositExpiration>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_deposit_expiration(tx.as_mut(), txid, output_index).await
+    }
+
+    async fn get_deposit_requests_for_outpoints(
+        &self,
+        outpoints: &[OutPoint],
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_deposit_requests_for_outpoints(tx.as_mut(), outpoints).await
+    }
+
+    async fn get_deposit_reclaim(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositReclaim>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_deposit_reclaim(tx.as_mut(), chain_tip, txid, output_index).await
+    }
+
+    async fn get_deposit_assessed_fee(
+        &self,
+        sweep_txid: &model::BitcoinTxId,
+        sweep_block_hash: &model::BitcoinBlockHash,
+        outpoint: &bitcoin::OutPoint,
+    ) -> Result<Option<u64>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_deposit_assessed_fee(tx.as_mut(), sweep_txid, sweep_block_hash, outpoint).await
+    }
+
+    async fn get_latest_tenure_plan(&self) -> Result<Option<model::TenurePlan>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_latest_tenure_plan(tx.as_mut()).await
+    }
+
+    async fn get_bitcoin_block_processing(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::BitcoinBlockProcessing>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_bitcoin_block_processing(tx.as_mut(), block_hash).await
+    }
+
+    async fn get_stacks_tx_submissions(
+        &self,
+        request_keys: &[String],
+    ) -> Result<Vec<model::StacksTxSubmission>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_stacks_tx_submissions(tx.as_mut(), request_keys).await
+    }
+
+    async fn get_pending_stacks_tx_submissions(
+        &self,
+    ) -> Result<Vec<model::StacksTxSubmission>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_pending_stacks_tx_submissions(tx.as_mut()).await
+    }
+
+    async fn get_unsettled_bitcoin_broadcasts(
+        &self,
+    ) -> Result<Vec<model::BitcoinBroadcast>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_unsettled_bitcoin_broadcasts(tx.as_mut()).await
+    }
+
+    async fn get_quarantined_stacks_blocks(
+        &self,
+    ) -> Result<Vec<model::QuarantinedStacksBlock>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_quarantined_stacks_blocks(tx.as_mut()).await
+    }
+
+    async fn get_conflicting_signed_sighash(
+        &self,
+        sighash: &model::SigHash,
+    ) -> Result<Option<model::SigHash>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_conflicting_signed_sighash(tx.as_mut(), sighash).await
+    }
+
+    async fn get_mint_mismatches(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::MintMismatch>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_mint_mismatches(tx.as_mut(), txid, output_index).await
+    }
+
+    async fn get_withdrawal_sweep_txids(
+        &self,
+        id: &model::QualifiedRequestId,
+    ) -> Result<Vec<model::BitcoinTxId>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_withdrawal_sweep_txids(tx.as_mut(), id).await
+    }
+
+    async fn get_signer_payment(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::SignerPayment>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_signer_payment(tx.as_mut(), txid, output_index).await
+    }
+
+    async fn get_signer_payments(
+        &self,
+        status: model::SignerPaymentStatus,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_signer_payments(tx.as_mut(), status).await
+    }
+
+    async fn get_pending_refunds(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_pending_refunds(tx.as_mut(), chain_tip).await
+    }
+
+    async fn get_unspent_signer_payments(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_unspent_signer_payments(tx.as_mut(), chain_tip, None).await
+    }
+
+    async fn get_latest_dkg_begin_refusal(&self) -> Result<Option<model::DkgBeginRefusal>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_latest_dkg_begin_refusal(tx.as_mut()).await
+    }
+
+    async fn get_latest_signer_key_rotation(
+        &self,
+    ) -> Result<Option<model::SignerKeyRotation>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_latest_signer_key_rotation(tx.as_mut()).await
+    }
+
+    async fn get_key_endorsements(&self) -> Result<Vec<model::KeyEndorsement>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_key_endorsements(tx.as_mut()).await
+    }
+
+    async fn get_emergency_recovery_approvals(
+        &self,
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> Result<Vec<model::EmergencyRecoveryApproval>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_emergency_recovery_approvals(tx.as_mut(), recovery_script_pubkey).await
+    }
+
+    async fn is_emergency_recovery_swept(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> Result<bool, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::is_emergency_recovery_swept(tx.as_mut(), chain_tip, recovery_script_pubkey).await
+    }
+
+    async fn get_deposit_decision_acknowledgements(
+        &self,
+        outpoints: &[OutPoint],
+    ) -> Result<Vec<model::DepositDecisionAcknowledgement>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_deposit_decision_acknowledgements(tx.as_mut(), outpoints).await
+    }
+
+    async fn get_withdrawal_decision_acknowledgements(
+        &self,
+        ids: &[model::QualifiedRequestId],
+    ) -> Result<Vec<model::WithdrawalDecisionAcknowledgement>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_withdrawal_decision_acknowledgements(tx.as_mut(), ids).await
+    }
+
+    async fn is_coordinator_paused(&self) -> Result<bool, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::is_coordinator_paused(tx.as_mut()).await
+    }
+
+    async fn get_deposit_decision_overrides(
+        &self,
+    ) -> Result<Vec<model::DepositDecisionOverride>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_deposit_decision_overrides(tx.as_mut()).await
+    }
+
+    async fn get_withdrawal_decision_overrides(
+        &self,
+    ) -> Result<Vec<model::WithdrawalDecisionOverride>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgRead::get_withdrawal_decision_overrides(tx.as_mut()).await
+    }
 }
@@ -1,7 +1,7 @@
 use super::{PgStore, PgTransaction};
 use crate::{
     error::Error,
-    keys::PublicKeyXOnly,
+    keys::{PublicKey, PublicKeyXOnly},
     storage::{
         DbWrite,
         model::{self, CompletedDepositEvent, WithdrawalAcceptEvent, WithdrawalRejectEvent},
@@ -275,15 +275,30 @@ impl PgWrite {
               , signer_pub_key
               , can_accept
               , can_sign
+              , decision_version
               )
-            VALUES ($1, $2, $3, $4, $5)
-            ON CONFLICT DO NOTHING",
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (txid, output_index, signer_pub_key) DO UPDATE
+            SET can_accept = EXCLUDED.can_accept
+              , can_sign = EXCLUDED.can_sign
+              , decision_version = EXCLUDED.decision_version
+            -- This mirrors `DepositSigner::supersedes`.
+            WHERE (
+                deposit_signers.decision_version
+              , NOT deposit_signers.can_accept
+              , NOT deposit_signers.can_sign
+            ) < (
+                EXCLUDED.decision_version
+              , NOT EXCLUDED.can_accept
+              , NOT EXCLUDED.can_sign
+            )",
         )
         .bind(decision.txid)
         .bind(i32::try_from(decision.output_index).map_err(Error::ConversionDatabaseInt)?)
         .bind(decision.signer_pub_key)
         .bind(decision.can_accept)
         .bind(decision.can_sign)
+        .bind(i32::try_from(decision.decision_version).map_err(Error::ConversionDatabaseInt)?)
         .execute(executor)
         .await
         .map_err(Error::SqlxQuery)?;
@@ -305,15 +320,22 @@ impl PgWrite {
               , block_hash
               , signer_pub_key
               , is_accepted
+              , decision_version
               )
-            VALUES ($1, $2, $3, $4, $5)
-            ON CONFLICT DO NOTHING",
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (request_id, block_hash, signer_pub_key) DO UPDATE
+            SET is_accepted = EXCLUDED.is_accepted
+              , decision_version = EXCLUDED.decision_version
+            -- This mirrors `WithdrawalSigner::supersedes`.
+            WHERE (withdrawal_signers.decision_version, NOT withdrawal_signers.is_accepted)
+                < (EXCLUDED.decision_version, NOT EXCLUDED.is_accepted)",
         )
         .bind(i64::try_from(decision.request_id).map_err(Error::ConversionDatabaseInt)?)
         .bind(decision.txid)
         .bind(decision.block_hash)
         .bind(decision.signer_pub_key)
         .bind(decision.is_accepted)
+        .bind(i32::try_from(decision.decision_version).map_err(Error::ConversionDatabaseInt)?)
         .execute(executor)
         .await
         .map_err(Error::SqlxQuery)?;
@@ -711,8 +733,9 @@ impl PgWrite {
               , amount
               , script_pubkey
               , prevout_type
+              , assessed_fee
             )
-            VALUES ($1, $2, $3, $4, $5, $6)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             ON CONFLICT DO NOTHING;
             "#,
         )
@@ -722,6 +745,13 @@ impl PgWrite {
         .bind(i64::try_from(prevout.amount).map_err(Error::ConversionDatabaseInt)?)
         .bind(&prevout.script_pubkey)
         .bind(prevout.prevout_type)
+        .bind(
+            prevout
+                .assessed_fee
+                .map(i64::try_from)
+                .transpose()
+                .map_err(Error::ConversionDatabaseInt)?,
+        )
         .execute(executor)
         .await
         .map_err(Error::SqlxQuery)?;
@@ -921,6 +951,93 @@ impl PgWrite {
         Ok(())
     }
 
+    async fn register_aggregate_key<'e, E>(
+        executor: &'e mut E,
+        shares: &model::EncryptedDkgShares,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            r#"
+            INSERT INTO sbtc_signer.aggregate_keys (
+                aggregate_key
+              , dkg_block_hash
+              , dkg_block_height
+              , dkg_shares_status
+            )
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (aggregate_key) DO UPDATE SET
+                dkg_shares_status = EXCLUDED.dkg_shares_status"#,
+        )
+        .bind(shares.aggregate_key)
+        .bind(shares.started_at_bitcoin_block_hash)
+        .bind(shares.started_at_bitcoin_block_height)
+        .bind(shares.dkg_shares_status)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn write_aggregate_key_rotation<'e, E>(
+        executor: &'e mut E,
+        aggregate_key: &PublicKey,
+        txid: &model::StacksTxId,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            r#"
+            UPDATE sbtc_signer.aggregate_keys
+            SET rotate_keys_txid = $2
+            WHERE aggregate_key = $1"#,
+        )
+        .bind(aggregate_key)
+        .bind(txid)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn activate_aggregate_key<'e, E>(
+        executor: &'e mut E,
+        aggregate_key: &PublicKey,
+        height: model::BitcoinBlockHeight,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        // The given key is activated, unless it already was, and every
+        // other key that is active is retired.
+        sqlx::query(
+            r#"
+            UPDATE sbtc_signer.aggregate_keys
+            SET
+                activation_height = CASE
+                    WHEN aggregate_key = $1 THEN COALESCE(activation_height, $2)
+                    ELSE activation_height
+                END
+              , retirement_height = CASE
+                    WHEN aggregate_key = $1 THEN retirement_height
+                    ELSE COALESCE(retirement_height, $2)
+                END
+            WHERE aggregate_key = $1
+               OR (activation_height IS NOT NULL AND retirement_height IS NULL)"#,
+        )
+        .bind(aggregate_key)
+        .bind(height)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
     async fn revoke_dkg_shares<'e, X, E>(
         executor: &'e mut E,
         aggregate_key: X,
@@ -966,152 +1083,1431 @@ impl PgWrite {
         .map(|res| res.rows_affected() > 0)
         .map_err(Error::SqlxQuery)
     }
-}
 
-impl DbWrite for PgStore {
-    async fn write_bitcoin_block(&self, block: &model::BitcoinBlock) -> Result<(), Error> {
-        PgWrite::write_bitcoin_block(self.get_connection().await?.as_mut(), block).await
-    }
+    async fn write_coordinator_attestation<'e, E>(
+        executor: &'e mut E,
+        attestation: &model::CoordinatorAttestation,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.coordinator_attestations
+              ( bitcoin_chain_tip
+              , coordinator_public_key
+              , sweep_txids
+              , message
+              )
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(attestation.bitcoin_chain_tip)
+        .bind(attestation.coordinator_public_key)
+        .bind(&attestation.sweep_txids)
+        .bind(&attestation.message)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
 
-    async fn write_stacks_block(&self, block: &model::StacksBlock) -> Result<(), Error> {
-        PgWrite::write_stacks_block(self.get_connection().await?.as_mut(), block).await
+        Ok(())
     }
 
-    async fn write_deposit_request(
-        &self,
-        deposit_request: &model::DepositRequest,
-    ) -> Result<(), Error> {
-        PgWrite::write_deposit_request(self.get_connection().await?.as_mut(), deposit_request).await
-    }
+    async fn write_deposit_decision_receipt<'e, E>(
+        executor: &'e mut E,
+        receipt: &model::DepositDecisionReceipt,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.deposit_decision_receipts
+              ( txid
+              , output_index
+              , signer_pub_key
+              , signature
+              )
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(receipt.txid)
+        .bind(i32::try_from(receipt.output_index).map_err(Error::ConversionDatabaseInt)?)
+        .bind(receipt.signer_pub_key)
+        .bind(&receipt.signature)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
 
-    async fn write_deposit_requests(
-        &self,
-        deposit_requests: Vec<model::DepositRequest>,
-    ) -> Result<(), Error> {
-        PgWrite::write_deposit_requests(self.get_connection().await?.as_mut(), deposit_requests)
-            .await
+        Ok(())
     }
 
-    async fn write_withdrawal_request(
-        &self,
-        request: &model::WithdrawalRequest,
-    ) -> Result<(), Error> {
-        PgWrite::write_withdrawal_request(self.get_connection().await?.as_mut(), request).await
-    }
+    async fn write_deposit_rejection<'e, E>(
+        executor: &'e mut E,
+        rejection: &model::DepositRejection,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.deposit_rejections
+              ( txid
+              , output_index
+              , reason
+              )
+            VALUES ($1, $2, $3)
+            ON CONFLICT (txid, output_index) DO UPDATE
+            SET reason = EXCLUDED.reason",
+        )
+        .bind(rejection.txid)
+        .bind(i32::try_from(rejection.output_index).map_err(Error::ConversionDatabaseInt)?)
+        .bind(rejection.reason)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
 
-    #[tracing::instrument(skip(self))]
-    async fn write_deposit_signer_decision(
-        &self,
-        decision: &model::DepositSigner,
-    ) -> Result<(), Error> {
-        PgWrite::write_deposit_signer_decision(self.get_connection().await?.as_mut(), decision)
-            .await
+        Ok(())
     }
 
-    async fn write_withdrawal_signer_decision(
-        &self,
-        decision: &model::WithdrawalSigner,
-    ) -> Result<(), Error> {
-        PgWrite::write_withdrawal_signer_decision(self.get_connection().await?.as_mut(), decision)
-            .await
-    }
+    async fn write_deposit_expiration<'e, E>(
+        executor: &'e mut E,
+        expiration: &model::DepositExpiration,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.deposit_expirations
+              ( txid
+              , output_index
+              , bitcoin_block_hash
+              , bitcoin_block_height
+              , reactivated
+              )
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(expiration.txid)
+        .bind(i32::try_from(expiration.output_index).map_err(Error::ConversionDatabaseInt)?)
+        .bind(expiration.bitcoin_block_hash)
+        .bind(i64::try_from(expiration.bitcoin_block_height).map_err(Error::ConversionDatabaseInt)?)
+        .bind(expiration.reactivated)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
 
-    async fn write_bitcoin_transaction(&self, tx_ref: &model::BitcoinTxRef) -> Result<(), Error> {
-        PgWrite::write_bitcoin_transaction(self.get_connection().await?.as_mut(), tx_ref).await
+        Ok(())
     }
 
-    async fn write_bitcoin_transactions(&self, txs: Vec<model::BitcoinTxRef>) -> Result<(), Error> {
-        PgWrite::write_bitcoin_transactions(self.get_connection().await?.as_mut(), txs).await
-    }
+    async fn write_deposit_reclaim<'e, E>(
+        executor: &'e mut E,
+        reclaim: &model::DepositReclaim,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.deposit_reclaims
+              ( txid
+              , output_index
+              , reclaim_txid
+              , bitcoin_block_hash
+              , bitcoin_block_height
+              )
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(reclaim.txid)
+        .bind(i32::try_from(reclaim.output_index).map_err(Error::ConversionDatabaseInt)?)
+        .bind(reclaim.reclaim_txid)
+        .bind(reclaim.bitcoin_block_hash)
+        .bind(i64::try_from(reclaim.bitcoin_block_height).map_err(Error::ConversionDatabaseInt)?)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
 
-    async fn write_stacks_block_headers(
-        &self,
-        blocks: Vec<model::StacksBlock>,
-    ) -> Result<(), Error> {
-        PgWrite::write_stacks_block_headers(self.get_connection().await?.as_mut(), blocks).await
+        Ok(())
     }
 
-    async fn write_encrypted_dkg_shares(
-        &self,
-        shares: &model::EncryptedDkgShares,
-    ) -> Result<(), Error> {
-        PgWrite::write_encrypted_dkg_shares(self.get_connection().await?.as_mut(), shares).await
-    }
+    async fn reactivate_deposit_request<'e, E>(
+        executor: &'e mut E,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<bool, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let result = sqlx::query(
+            "UPDATE sbtc_signer.deposit_expirations
+            SET reactivated = TRUE
+            WHERE txid = $1
+              AND output_index = $2
+              AND NOT reactivated",
+        )
+        .bind(txid)
+        .bind(i32::try_from(output_index).map_err(Error::ConversionDatabaseInt)?)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
 
-    async fn write_rotate_keys_transaction(
-        &self,
-        key_rotation: &model::KeyRotationEvent,
-    ) -> Result<(), Error> {
-        PgWrite::write_rotate_keys_transaction(self.get_connection().await?.as_mut(), key_rotation)
-            .await
+        Ok(result.rows_affected() > 0)
     }
 
-    async fn write_completed_deposit_event(
-        &self,
-        event: &CompletedDepositEvent,
-    ) -> Result<(), Error> {
-        PgWrite::write_completed_deposit_event(self.get_connection().await?.as_mut(), event).await
-    }
+    async fn write_stacks_tx_submission<'e, E>(
+        executor: &'e mut E,
+        submission: &model::StacksTxSubmission,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.stacks_tx_submissions
+              ( txid
+              , tx_kind
+              , request_keys
+              , nonce
+              , tx_fee
+              , status
+              , rejection_reason
+              , bitcoin_block_hash
+              )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (txid) DO UPDATE
+            SET status = EXCLUDED.status
+              , rejection_reason = EXCLUDED.rejection_reason
+              , updated_at = CURRENT_TIMESTAMP",
+        )
+        .bind(submission.txid)
+        .bind(&submission.tx_kind)
+        .bind(&submission.request_keys)
+        .bind(i64::try_from(submission.nonce).map_err(Error::ConversionDatabaseInt)?)
+        .bind(i64::try_from(submission.tx_fee).map_err(Error::ConversionDatabaseInt)?)
+        .bind(submission.status)
+        .bind(&submission.rejection_reason)
+        .bind(submission.bitcoin_block_hash)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
 
-    async fn write_withdrawal_accept_event(
-        &self,
-        event: &WithdrawalAcceptEvent,
-    ) -> Result<(), Error> {
-        PgWrite::write_withdrawal_accept_event(self.get_connection().await?.as_mut(), event).await
+        Ok(())
     }
 
-    async fn write_withdrawal_reject_event(
-        &self,
-        event: &WithdrawalRejectEvent,
-    ) -> Result<(), Error> {
-        PgWrite::write_withdrawal_reject_event(self.get_connection().await?.as_mut(), event).await
+    async fn set_stacks_tx_submission_status<'e, E>(
+        executor: &'e mut E,
+        txid: &model::StacksTxId,
+        status: model::StacksTxStatus,
+    ) -> Result<bool, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let result = sqlx::query(
+            "UPDATE sbtc_signer.stacks_tx_submissions
+            SET status = $2
+              , updated_at = CURRENT_TIMESTAMP
+            WHERE txid = $1",
+        )
+        .bind(txid)
+        .bind(status)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn write_bitcoin_broadcasts<'e, E>(
+        executor: &'e mut E,
+        broadcasts: &[model::BitcoinBroadcast],
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        if broadcasts.is_empty() {
+            return Ok(());
+        }
+
+        let mut txids = Vec::with_capacity(broadcasts.len());
+        let mut package_txids = Vec::with_capacity(broadcasts.len());
+        let mut package_positions = Vec::with_capacity(broadcasts.len());
+        let mut txs = Vec::with_capacity(broadcasts.len());
+        let mut fee_rates = Vec::with_capacity(broadcasts.len());
+        let mut block_hashes = Vec::with_capacity(broadcasts.len());
+        let mut statuses = Vec::with_capacity(broadcasts.len());
+
+        for broadcast in broadcasts {
+            txids.push(broadcast.txid);
+            package_txids.push(broadcast.package_txid);
+            package_positions.push(
+                i32::try_from(broadcast.package_position).map_err(Error::ConversionDatabaseInt)?,
+            );
+            txs.push(broadcast.tx.clone());
+            fee_rates.push(broadcast.fee_rate);
+            block_hashes.push(broadcast.bitcoin_block_hash);
+            statuses.push(broadcast.status);
+        }
+
+        sqlx::query(
+            "INSERT INTO sbtc_signer.bitcoin_broadcasts
+              ( txid
+              , package_txid
+              , package_position
+              , tx
+              , fee_rate
+              , bitcoin_block_hash
+              , status
+              )
+            SELECT *
+            FROM UNNEST(
+                $1::BYTEA[]
+              , $2::BYTEA[]
+              , $3::INTEGER[]
+              , $4::BYTEA[]
+              , $5::DOUBLE PRECISION[]
+              , $6::BYTEA[]
+              , $7::sbtc_signer.bitcoin_broadcast_status[]
+            )
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(txids)
+        .bind(package_txids)
+        .bind(package_positions)
+        .bind(txs)
+        .bind(fee_rates)
+        .bind(block_hashes)
+        .bind(statuses)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn record_bitcoin_broadcast_attempt<'e, E>(
+        executor: &'e mut E,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+        last_error: Option<&str>,
+    ) -> Result<bool, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let result = sqlx::query(
+            "UPDATE sbtc_signer.bitcoin_broadcasts
+            SET status = $2
+              , last_error = $3
+              , attempts = attempts + 1
+              , updated_at = CURRENT_TIMESTAMP
+            WHERE txid = $1",
+        )
+        .bind(txid)
+        .bind(status)
+        .bind(last_error)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn set_bitcoin_broadcast_status<'e, E>(
+        executor: &'e mut E,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+    ) -> Result<bool, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let result = sqlx::query(
+            "UPDATE sbtc_signer.bitcoin_broadcasts
+            SET status = $2
+              , updated_at = CURRENT_TIMESTAMP
+            WHERE txid = $1",
+        )
+        .bind(txid)
+        .bind(status)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn write_quarantined_stacks_block<'e, E>(
+        executor: &'e mut E,
+        block: &model::QuarantinedStacksBlock,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.quarantined_stacks_blocks
+              ( block_hash
+              , bitcoin_anchor
+              , reason
+              )
+            VALUES ($1, $2, $3)
+            ON CONFLICT (block_hash) DO UPDATE
+            SET bitcoin_anchor = EXCLUDED.bitcoin_anchor
+              , reason = EXCLUDED.reason
+              , updated_at = CURRENT_TIMESTAMP",
+        )
+        .bind(block.block_hash)
+        .bind(block.bitcoin_anchor)
+        .bind(&block.reason)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn write_signed_sighash<'e, E>(
+        executor: &'e mut E,
+        sighash: &model::SigHash,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.signed_sighashes
+              ( prevout_txid
+              , prevout_output_index
+              , chain_tip
+              , sighash
+              )
+            SELECT
+                prevout_txid
+              , prevout_output_index
+              , chain_tip
+              , sighash
+            FROM sbtc_signer.bitcoin_tx_sighashes
+            WHERE sighash = $1
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(sighash)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn write_mint_mismatch<'e, E>(
+        executor: &'e mut E,
+        mismatch: &model::MintMismatch,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.mint_mismatches
+              ( txid
+              , output_index
+              , chain_tip
+              , origin
+              , recipient
+              , expected_recipient
+              , amount
+              , expected_amount
+              )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(mismatch.txid)
+        .bind(i32::try_from(mismatch.output_index).map_err(Error::ConversionDatabaseInt)?)
+        .bind(mismatch.chain_tip)
+        .bind(mismatch.origin)
+        .bind(&mismatch.recipient)
+        .bind(&mismatch.expected_recipient)
+        .bind(i64::try_from(mismatch.amount).map_err(Error::ConversionDatabaseInt)?)
+        .bind(i64::try_from(mismatch.expected_amount).map_err(Error::ConversionDatabaseInt)?)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn write_signer_payment<'e, E>(
+        executor: &'e mut E,
+        payment: &model::SignerPayment,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.signer_payments
+              ( txid
+              , output_index
+              , amount
+              , script_pubkey
+              , sender_script_pubkey
+              , status
+              )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(payment.txid)
+        .bind(i32::try_from(payment.output_index).map_err(Error::ConversionDatabaseInt)?)
+        .bind(i64::try_from(payment.amount).map_err(Error::ConversionDatabaseInt)?)
+        .bind(&payment.script_pubkey)
+        .bind(&payment.sender_script_pubkey)
+        .bind(payment.status)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn set_signer_payment_status<'e, E>(
+        executor: &'e mut E,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        status: model::SignerPaymentStatus,
+    ) -> Result<bool, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let rows_affected = sqlx::query(
+            "UPDATE sbtc_signer.signer_payments
+            SET status = $3
+            WHERE txid = $1
+              AND output_index = $2",
+        )
+        .bind(txid)
+        .bind(i32::try_from(output_index).map_err(Error::ConversionDatabaseInt)?)
+        .bind(status)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?
+        .rows_affected();
+
+        Ok(rows_affected > 0)
+    }
+
+    async fn lock_prevouts<'e, E>(
+        executor: &'e mut E,
+        locks: &[model::PrevoutLock],
+    ) -> Result<Option<model::PrevoutLock>, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        if locks.is_empty() {
+            return Ok(None);
+        }
+
+        let mut prevout_txid = Vec::with_capacity(locks.len());
+        let mut prevout_output_index = Vec::with_capacity(locks.len());
+        let mut chain_tip = Vec::with_capacity(locks.len());
+        let mut txid = Vec::with_capacity(locks.len());
+
+        for lock in locks {
+            prevout_txid.push(lock.prevout_txid);
+            prevout_output_index.push(
+                i32::try_from(lock.prevout_output_index).map_err(Error::ConversionDatabaseInt)?,
+            );
+            chain_tip.push(lock.chain_tip);
+            txid.push(lock.txid);
+        }
+
+        // The locks are only inserted if none of the prevouts are locked
+        // by another transaction, in which case that lock is returned.
+        sqlx::query_as::<_, model::PrevoutLock>(
+            r#"
+            WITH requested AS (
+                SELECT *
+                FROM UNNEST($1::BYTEA[], $2::INTEGER[], $3::BYTEA[], $4::BYTEA[])
+                  AS r(prevout_txid, prevout_output_index, chain_tip, txid)
+            )
+            , conflict AS (
+                SELECT
+                    pl.prevout_txid
+                  , pl.prevout_output_index
+                  , pl.chain_tip
+                  , pl.txid
+                FROM sbtc_signer.prevout_locks AS pl
+                JOIN requested AS r
+                  ON pl.prevout_txid = r.prevout_txid
+                 AND pl.prevout_output_index = r.prevout_output_index
+                 AND pl.chain_tip = r.chain_tip
+                WHERE pl.txid <> r.txid
+                LIMIT 1
+            )
+            , inserted AS (
+                INSERT INTO sbtc_signer.prevout_locks
+                  ( prevout_txid
+                  , prevout_output_index
+                  , chain_tip
+                  , txid
+                  )
+                SELECT
+                    prevout_txid
+                  , prevout_output_index
+                  , chain_tip
+                  , txid
+                FROM requested
+                WHERE NOT EXISTS (SELECT 1 FROM conflict)
+                ON CONFLICT DO NOTHING
+            )
+            SELECT
+                prevout_txid
+              , prevout_output_index
+              , chain_tip
+              , txid
+            FROM conflict
+            "#,
+        )
+        .bind(prevout_txid)
+        .bind(prevout_output_index)
+        .bind(chain_tip)
+        .bind(txid)
+        .fetch_optional(executor)
+        .await
+        .map_err(Error::SqlxQuery)
+    }
+
+    async fn unlock_prevouts<'e, E>(
+        executor: &'e mut E,
+        txid: &model::BitcoinTxId,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query("DELETE FROM sbtc_signer.prevout_locks WHERE txid = $1")
+            .bind(txid)
+            .execute(executor)
+            .await
+            .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn write_tenure_plan<'e, E>(
+        executor: &'e mut E,
+        plan: &model::TenurePlan,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let to_i32 = |count: u32| i32::try_from(count).map_err(Error::ConversionDatabaseInt);
+
+        sqlx::query(
+            "INSERT INTO sbtc_signer.tenure_plans
+              ( bitcoin_chain_tip
+              , coordinator_public_key
+              , deploy_contracts
+              , rotate_keys
+              , deposits_to_sweep
+              , withdrawals_to_sweep
+              , deposits_to_complete
+              , withdrawals_to_accept
+              , withdrawals_to_reject
+              )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+        )
+        .bind(plan.bitcoin_chain_tip)
+        .bind(plan.coordinator_public_key)
+        .bind(plan.deploy_contracts)
+        .bind(plan.rotate_keys)
+        .bind(to_i32(plan.deposits_to_sweep)?)
+        .bind(to_i32(plan.withdrawals_to_sweep)?)
+        .bind(to_i32(plan.deposits_to_complete)?)
+        .bind(to_i32(plan.withdrawals_to_accept)?)
+        .bind(to_i32(plan.withdrawals_to_reject)?)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn write_block_processing_stage<'e, E>(
+        executor: &'e mut E,
+        block_hash: &model::BitcoinBlockHash,
+        stage: model::BlockProcessingStage,
+        at: model::Timestamp,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let processing = model::BitcoinBlockProcessing::new(*block_hash, stage, at);
+        sqlx::query(
+            "INSERT INTO sbtc_signer.bitcoin_block_processing
+              ( block_hash
+              , observed_at
+              , deposits_fetched_at
+              , decisions_completed_at
+              , tenure_completed_at
+              )
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (block_hash) DO UPDATE SET
+                observed_at = COALESCE(
+                    bitcoin_block_processing.observed_at,
+                    EXCLUDED.observed_at
+                )
+              , deposits_fetched_at = COALESCE(
+                    bitcoin_block_processing.deposits_fetched_at,
+                    EXCLUDED.deposits_fetched_at
+                )
+              , decisions_completed_at = COALESCE(
+                    bitcoin_block_processing.decisions_completed_at,
+                    EXCLUDED.decisions_completed_at
+                )
+              , tenure_completed_at = COALESCE(
+                    bitcoin_block_processing.tenure_completed_at,
+                    EXCLUDED.tenure_completed_at
+                )",
+        )
+        .bind(processing.block_hash)
+        .bind(processing.observed_at)
+        .bind(processing.deposits_fetched_at)
+        .bind(processing.decisions_completed_at)
+        .bind(processing.tenure_completed_at)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn write_dkg_begin_refusal<'e, E>(
+        executor: &'e mut E,
+        refusal: &model::DkgBeginRefusal,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let to_i32 = |count: u32| i32::try_from(count).map_err(Error::ConversionDatabaseInt);
+
+        sqlx::query(
+            "INSERT INTO sbtc_signer.dkg_begin_refusals
+              ( reason
+              , bitcoin_chain_tip
+              , bitcoin_chain_tip_height
+              , latest_dkg_shares_status
+              , dkg_shares_count
+              , dkg_target_rounds
+              , dkg_min_bitcoin_block_height
+              )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(refusal.reason)
+        .bind(refusal.bitcoin_chain_tip)
+        .bind(refusal.bitcoin_chain_tip_height)
+        .bind(refusal.latest_dkg_shares_status)
+        .bind(to_i32(refusal.dkg_shares_count)?)
+        .bind(to_i32(refusal.dkg_target_rounds)?)
+        .bind(refusal.dkg_min_bitcoin_block_height)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn write_signer_key_rotation<'e, E>(
+        executor: &'e mut E,
+        rotation: &model::SignerKeyRotation,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.signer_key_rotations
+              ( old_public_key
+              , new_public_key
+              , status
+              )
+            VALUES ($1, $2, $3)",
+        )
+        .bind(rotation.old_public_key)
+        .bind(rotation.new_public_key)
+        .bind(rotation.status)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn set_signer_key_rotation_status<'e, E>(
+        executor: &'e mut E,
+        new_public_key: &PublicKey,
+        status: model::SignerKeyRotationStatus,
+    ) -> Result<bool, Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let result = sqlx::query(
+            "UPDATE sbtc_signer.signer_key_rotations
+            SET status = $2
+            WHERE new_public_key = $1",
+        )
+        .bind(new_public_key)
+        .bind(status)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn write_key_endorsement<'e, E>(
+        executor: &'e mut E,
+        endorsement: &model::KeyEndorsement,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.signer_key_endorsements
+              ( old_public_key
+              , new_public_key
+              , bitcoin_chain_tip
+              )
+            VALUES ($1, $2, $3)
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(endorsement.old_public_key)
+        .bind(endorsement.new_public_key)
+        .bind(endorsement.bitcoin_chain_tip)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn write_emergency_recovery_approval<'e, E>(
+        executor: &'e mut E,
+        approval: &model::EmergencyRecoveryApproval,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.emergency_recovery_approvals
+              ( signer_public_key
+              , recovery_script_pubkey
+              , bitcoin_block_hash
+              , bitcoin_block_height
+              , revoked
+              )
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (signer_public_key, recovery_script_pubkey) DO UPDATE
+            SET bitcoin_block_hash = EXCLUDED.bitcoin_block_hash
+              , bitcoin_block_height = EXCLUDED.bitcoin_block_height
+              , revoked = EXCLUDED.revoked",
+        )
+        .bind(approval.signer_public_key)
+        .bind(&approval.recovery_script_pubkey)
+        .bind(approval.bitcoin_block_hash)
+        .bind(i64::try_from(approval.bitcoin_block_height).map_err(Error::ConversionDatabaseInt)?)
+        .bind(approval.revoked)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn write_emergency_recovery_sweep<'e, E>(
+        executor: &'e mut E,
+        sweep: &model::EmergencyRecoverySweep,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.emergency_recovery_sweeps
+              ( txid
+              , recovery_script_pubkey
+              )
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(sweep.txid)
+        .bind(&sweep.recovery_script_pubkey)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn write_deposit_decision_acknowledgement<'e, E>(
+        executor: &'e mut E,
+        ack: &model::DepositDecisionAcknowledgement,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.deposit_decision_acknowledgements
+              ( txid
+              , output_index
+              , signer_pub_key
+              , decision_version
+              )
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (txid, output_index, signer_pub_key) DO UPDATE
+            SET decision_version = GREATEST(
+                deposit_decision_acknowledgements.decision_version,
+                EXCLUDED.decision_version
+            )",
+        )
+        .bind(ack.txid)
+        .bind(i32::try_from(ack.output_index).map_err(Error::ConversionDatabaseInt)?)
+        .bind(ack.signer_pub_key)
+        .bind(i32::try_from(ack.decision_version).map_err(Error::ConversionDatabaseInt)?)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn write_withdrawal_decision_acknowledgement<'e, E>(
+        executor: &'e mut E,
+        ack: &model::WithdrawalDecisionAcknowledgement,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.withdrawal_decision_acknowledgements
+              ( request_id
+              , txid
+              , block_hash
+              , signer_pub_key
+              , decision_version
+              )
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (request_id, block_hash, signer_pub_key) DO UPDATE
+            SET decision_version = GREATEST(
+                withdrawal_decision_acknowledgements.decision_version,
+                EXCLUDED.decision_version
+            )",
+        )
+        .bind(i64::try_from(ack.request_id).map_err(Error::ConversionDatabaseInt)?)
+        .bind(ack.txid)
+        .bind(ack.block_hash)
+        .bind(ack.signer_pub_key)
+        .bind(i32::try_from(ack.decision_version).map_err(Error::ConversionDatabaseInt)?)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn set_coordinator_paused<'e, E>(executor: &'e mut E, paused: bool) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        sqlx::query(
+            "INSERT INTO sbtc_signer.coordinator_pause (paused)
+            VALUES ($1)
+            ON CONFLICT (id) DO UPDATE
+            SET paused = EXCLUDED.paused
+              , updated_at = CURRENT_TIMESTAMP",
+        )
+        .bind(paused)
+        .execute(executor)
+        .await
+        .map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn set_deposit_decision_override<'e, E>(
+        executor: &'e mut E,
+        outpoint: &bitcoin::OutPoint,
+        can_accept: Option<bool>,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let txid = model::BitcoinTxId::from(outpoint.txid);
+        let output_index = i32::try_from(outpoint.vout).map_err(Error::ConversionDatabaseInt)?;
+        let query = match can_accept {
+            Some(can_accept) => sqlx::query(
+                "INSERT INTO sbtc_signer.deposit_decision_overrides
+                  ( txid
+                  , output_index
+                  , can_accept
+                  )
+                VALUES ($1, $2, $3)
+                ON CONFLICT (txid, output_index) DO UPDATE
+                SET can_accept = EXCLUDED.can_accept
+                  , updated_at = CURRENT_TIMESTAMP",
+            )
+            .bind(txid)
+            .bind(output_index)
+            .bind(can_accept),
+            None => sqlx::query(
+                "DELETE FROM sbtc_signer.deposit_decision_overrides
+                WHERE txid = $1
+                  AND output_index = $2",
+            )
+            .bind(txid)
+            .bind(output_index),
+        };
+
+        query.execute(executor).await.map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+
+    async fn set_withdrawal_decision_override<'e, E>(
+        executor: &'e mut E,
+        id: &model::QualifiedRequestId,
+        accept: Option<bool>,
+    ) -> Result<(), Error>
+    where
+        &'e mut E: sqlx::PgExecutor<'e>,
+    {
+        let request_id = i64::try_from(id.request_id).map_err(Error::ConversionDatabaseInt)?;
+        let query = match accept {
+            Some(accept) => sqlx::query(
+                "INSERT INTO sbtc_signer.withdrawal_decision_overrides
+                  ( request_id
+                  , txid
+                  , block_hash
+                  , accept
+                  )
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (request_id, block_hash) DO UPDATE
+                SET accept = EXCLUDED.accept
+                  , updated_at = CURRENT_TIMESTAMP",
+            )
+            .bind(request_id)
+            .bind(id.txid)
+            .bind(id.block_hash)
+            .bind(accept),
+            None => sqlx::query(
+                "DELETE FROM sbtc_signer.withdrawal_decision_overrides
+                WHERE request_id = $1
+                  AND block_hash = $2",
+            )
+            .bind(request_id)
+            .bind(id.block_hash),
+        };
+
+        query.execute(executor).await.map_err(Error::SqlxQuery)?;
+
+        Ok(())
+    }
+}
+
+impl DbWrite for PgStore {
+    async fn write_bitcoin_block(&self, block: &model::BitcoinBlock) -> Result<(), Error> {
+        PgWrite::write_bitcoin_block(self.get_connection().await?.as_mut(), block).await
+    }
+
+    async fn write_stacks_block(&self, block: &model::StacksBlock) -> Result<(), Error> {
+        PgWrite::write_stacks_block(self.get_connection().await?.as_mut(), block).await
+    }
+
+    async fn write_deposit_request(
+        &self,
+        deposit_request: &model::DepositRequest,
+    ) -> Result<(), Error> {
+        PgWrite::write_deposit_request(self.get_connection().await?.as_mut(), deposit_request).await
+    }
+
+    async fn write_deposit_requests(
+        &self,
+        deposit_requests: Vec<model::DepositRequest>,
+    ) -> Result<(), Error> {
+        PgWrite::write_deposit_requests(self.get_connection().await?.as_mut(), deposit_requests)
+            .await
+    }
+
+    async fn write_withdrawal_request(
+        &self,
+        request: &model::WithdrawalRequest,
+    ) -> Result<(), Error> {
+        PgWrite::write_withdrawal_request(self.get_connection().await?.as_mut(), request).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn write_deposit_signer_decision(
+        &self,
+        decision: &model::DepositSigner,
+    ) -> Result<(), Error> {
+        PgWrite::write_deposit_signer_decision(self.get_connection().await?.as_mut(), decision)
+            .await
+    }
+
+    async fn write_withdrawal_signer_decision(
+        &self,
+        decision: &model::WithdrawalSigner,
+    ) -> Result<(), Error> {
+        PgWrite::write_withdrawal_signer_decision(self.get_connection().await?.as_mut(), decision)
+            .await
+    }
+
+    async fn write_bitcoin_transaction(&self, tx_ref: &model::BitcoinTxRef) -> Result<(), Error> {
+        PgWrite::write_bitcoin_transaction(self.get_connection().await?.as_mut(), tx_ref).await
+    }
+
+    async fn write_bitcoin_transactions(&self, txs: Vec<model::BitcoinTxRef>) -> Result<(), Error> {
+        PgWrite::write_bitcoin_transactions(self.get_connection().await?.as_mut(), txs).await
+    }
+
+    async fn write_stacks_block_headers(
+        &self,
+        blocks: Vec<model::StacksBlock>,
+    ) -> Result<(), Error> {
+        PgWrite::write_stacks_block_headers(self.get_connection().await?.as_mut(), blocks).await
+    }
+
+    async fn write_encrypted_dkg_shares(
+        &self,
+        shares: &model::EncryptedDkgShares,
+    ) -> Result<(), Error> {
+        PgWrite::write_encrypted_dkg_shares(self.get_connection().await?.as_mut(), shares).await
+    }
+
+    async fn write_rotate_keys_transaction(
+        &self,
+        key_rotation: &model::KeyRotationEvent,
+    ) -> Result<(), Error> {
+        PgWrite::write_rotate_keys_transaction(self.get_connection().await?.as_mut(), key_rotation)
+            .await
+    }
+
+    async fn register_aggregate_key(
+        &self,
+        shares: &model::EncryptedDkgShares,
+    ) -> Result<(), Error> {
+        PgWrite::register_aggregate_key(self.get_connection().await?.as_mut(), shares).await
+    }
+
+    async fn write_aggregate_key_rotation(
+        &self,
+        aggregate_key: &PublicKey,
+        txid: &model::StacksTxId,
+    ) -> Result<(), Error> {
+        let mut conn = self.get_connection().await?;
+        PgWrite::write_aggregate_key_rotation(conn.as_mut(), aggregate_key, txid).await
+    }
+
+    async fn activate_aggregate_key(
+        &self,
+        aggregate_key: &PublicKey,
+        height: model::BitcoinBlockHeight,
+    ) -> Result<(), Error> {
+        let mut conn = self.get_connection().await?;
+        PgWrite::activate_aggregate_key(conn.as_mut(), aggregate_key, height).await
+    }
+
+    async fn write_completed_deposit_event(
+        &self,
+        event: &CompletedDepositEvent,
+    ) -> Result<(), Error> {
+        PgWrite::write_completed_deposit_event(self.get_connection().await?.as_mut(), event).await
+    }
+
+    async fn write_withdrawal_accept_event(
+        &self,
+        event: &WithdrawalAcceptEvent,
+    ) -> Result<(), Error> {
+        PgWrite::write_withdrawal_accept_event(self.get_connection().await?.as_mut(), event).await
+    }
+
+    async fn write_withdrawal_reject_event(
+        &self,
+        event: &WithdrawalRejectEvent,
+    ) -> Result<(), Error> {
+        PgWrite::write_withdrawal_reject_event(self.get_connection().await?.as_mut(), event).await
     }
 
     async fn write_tx_output(&self, output: &model::TxOutput) -> Result<(), Error> {
         PgWrite::write_tx_output(self.get_connection().await?.as_mut(), output).await
     }
 
-    async fn write_withdrawal_tx_output(
+    async fn write_withdrawal_tx_output(
+        &self,
+        output: &model::WithdrawalTxOutput,
+    ) -> Result<(), Error> {
+        PgWrite::write_withdrawal_tx_output(self.get_connection().await?.as_mut(), output).await
+    }
+
+    async fn write_tx_prevout(&self, prevout: &model::TxPrevout) -> Result<(), Error> {
+        PgWrite::write_tx_prevout(self.get_connection().await?.as_mut(), prevout).await
+    }
+
+    async fn write_bitcoin_txs_sighashes(
+        &self,
+        sighashes: &[model::BitcoinTxSigHash],
+    ) -> Result<(), Error> {
+        PgWrite::write_bitcoin_txs_sighashes(self.get_connection().await?.as_mut(), sighashes).await
+    }
+
+    async fn write_bitcoin_withdrawals_outputs(
+        &self,
+        withdrawal_outputs: &[model::BitcoinWithdrawalOutput],
+    ) -> Result<(), Error> {
+        PgWrite::write_bitcoin_withdrawals_outputs(
+            self.get_connection().await?.as_mut(),
+            withdrawal_outputs,
+        )
+        .await
+    }
+
+    async fn revoke_dkg_shares<X>(&self, aggregate_key: X) -> Result<bool, Error>
+    where
+        X: Into<PublicKeyXOnly>,
+    {
+        PgWrite::revoke_dkg_shares(self.get_connection().await?.as_mut(), aggregate_key).await
+    }
+
+    async fn verify_dkg_shares<X>(&self, aggregate_key: X) -> Result<bool, Error>
+    where
+        X: Into<PublicKeyXOnly>,
+    {
+        PgWrite::verify_dkg_shares(self.get_connection().await?.as_mut(), aggregate_key).await
+    }
+    async fn write_coordinator_attestation(
+        &self,
+        attestation: &model::CoordinatorAttestation,
+    ) -> Result<(), Error> {
+        PgWrite::write_coordinator_attestation(self.get_connection().await?.as_mut(), attestation)
+            .await
+    }
+    async fn write_deposit_decision_receipt(
+        &self,
+        receipt: &model::DepositDecisionReceipt,
+    ) -> Result<(), Error> {
+        PgWrite::write_deposit_decision_receipt(self.get_connection().await?.as_mut(), receipt)
+            .await
+    }
+
+    async fn write_deposit_rejection(
+        &self,
+        rejection: &model::DepositRejection,
+    ) -> Result<(), Error> {
+        PgWrite::write_deposit_rejection(self.get_connection().await?.as_mut(), rejection).await
+    }
+
+    async fn write_deposit_expiration(
+        &self,
+        expiration: &model::DepositExpiration,
+    ) -> Result<(), Error> {
+        PgWrite::write_deposit_expiration(self.get_connection().await?.as_mut(), expiration).await
+    }
+
+    async fn write_deposit_reclaim(&self, reclaim: &model::DepositReclaim) -> Result<(), Error> {
+        PgWrite::write_deposit_reclaim(self.get_connection().await?.as_mut(), reclaim).await
+    }
+
+    async fn reactivate_deposit_request(
         &self,
-        output: &model::WithdrawalTxOutput,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<bool, Error> {
+        PgWrite::reactivate_deposit_request(
+            self.get_connection().await?.as_mut(),
+            txid,
+            output_index,
+        )
+        .await
+    }
+
+    async fn write_stacks_tx_submission(
+        &self,
+        submission: &model::StacksTxSubmission,
     ) -> Result<(), Error> {
-        PgWrite::write_withdrawal_tx_output(self.get_connection().await?.as_mut(), output).await
+        PgWrite::write_stacks_tx_submission(self.get_connection().await?.as_mut(), submission).await
     }
 
-    async fn write_tx_prevout(&self, prevout: &model::TxPrevout) -> Result<(), Error> {
-        PgWrite::write_tx_prevout(self.get_connection().await?.as_mut(), prevout).await
+    async fn set_stacks_tx_submission_status(
+        &self,
+        txid: &model::StacksTxId,
+        status: model::StacksTxStatus,
+    ) -> Result<bool, Error> {
+        PgWrite::set_stacks_tx_submission_status(
+            self.get_connection().await?.as_mut(),
+            txid,
+            status,
+        )
+        .await
     }
 
-    async fn write_bitcoin_txs_sighashes(
+    async fn write_bitcoin_broadcasts(
         &self,
-        sighashes: &[model::BitcoinTxSigHash],
+        broadcasts: &[model::BitcoinBroadcast],
     ) -> Result<(), Error> {
-        PgWrite::write_bitcoin_txs_sighashes(self.get_connection().await?.as_mut(), sighashes).await
+        PgWrite::write_bitcoin_broadcasts(self.get_connection().await?.as_mut(), broadcasts).await
     }
 
-    async fn write_bitcoin_withdrawals_outputs(
+    async fn record_bitcoin_broadcast_attempt(
         &self,
-        withdrawal_outputs: &[model::BitcoinWithdrawalOutput],
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+        last_error: Option<&str>,
+    ) -> Result<bool, Error> {
+        PgWrite::record_bitcoin_broadcast_attempt(
+            self.get_connection().await?.as_mut(),
+            txid,
+            status,
+            last_error,
+        )
+        .await
+    }
+
+    async fn set_bitcoin_broadcast_status(
+        &self,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+    ) -> Result<bool, Error> {
+        PgWrite::set_bitcoin_broadcast_status(self.get_connection().await?.as_mut(), txid, status)
+            .await
+    }
+
+    async fn write_quarantined_stacks_block(
+        &self,
+        block: &model::QuarantinedStacksBlock,
     ) -> Result<(), Error> {
-        PgWrite::write_bitcoin_withdrawals_outputs(
+        PgWrite::write_quarantined_stacks_block(self.get_connection().await?.as_mut(), block).await
+    }
+
+    async fn write_signed_sighash(&self, sighash: &model::SigHash) -> Result<(), Error> {
+        PgWrite::write_signed_sighash(self.get_connection().await?.as_mut(), sighash).await
+    }
+
+    async fn write_mint_mismatch(&self, mismatch: &model::MintMismatch) -> Result<(), Error> {
+        PgWrite::write_mint_mismatch(self.get_connection().await?.as_mut(), mismatch).await
+    }
+
+    async fn write_signer_payment(&self, payment: &model::SignerPayment) -> Result<(), Error> {
+        PgWrite::write_signer_payment(self.get_connection().await?.as_mut(), payment).await
+    }
+
+    async fn set_signer_payment_status(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        status: model::SignerPaymentStatus,
+    ) -> Result<bool, Error> {
+        let mut conn = self.get_connection().await?;
+        PgWrite::set_signer_payment_status(conn.as_mut(), txid, output_index, status).await
+    }
+
+    async fn lock_prevouts(
+        &self,
+        locks: &[model::PrevoutLock],
+    ) -> Result<Option<model::PrevoutLock>, Error> {
+        PgWrite::lock_prevouts(self.get_connection().await?.as_mut(), locks).await
+    }
+
+    async fn unlock_prevouts(&self, txid: &model::BitcoinTxId) -> Result<(), Error> {
+        PgWrite::unlock_prevouts(self.get_connection().await?.as_mut(), txid).await
+    }
+
+    async fn write_tenure_plan(&self, plan: &model::TenurePlan) -> Result<(), Error> {
+        PgWrite::write_tenure_plan(self.get_connection().await?.as_mut(), plan).await
+    }
+
+    async fn write_block_processing_stage(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+        stage: model::BlockProcessingStage,
+        at: model::Timestamp,
+    ) -> Result<(), Error> {
+        let mut conn = self.get_connection().await?;
+        PgWrite::write_block_processing_stage(conn.as_mut(), block_hash, stage, at).await
+    }
+
+    async fn write_dkg_begin_refusal(&self, refusal: &model::DkgBeginRefusal) -> Result<(), Error> {
+        PgWrite::write_dkg_begin_refusal(self.get_connection().await?.as_mut(), refusal).await
+    }
+
+    async fn write_signer_key_rotation(
+        &self,
+        rotation: &model::SignerKeyRotation,
+    ) -> Result<(), Error> {
+        PgWrite::write_signer_key_rotation(self.get_connection().await?.as_mut(), rotation).await
+    }
+
+    async fn set_signer_key_rotation_status(
+        &self,
+        new_public_key: &PublicKey,
+        status: model::SignerKeyRotationStatus,
+    ) -> Result<bool, Error> {
+        PgWrite::set_signer_key_rotation_status(
             self.get_connection().await?.as_mut(),
-            withdrawal_outputs,
+            new_public_key,
+            status,
         )
         .await
     }
 
-    async fn revoke_dkg_shares<X>(&self, aggregate_key: X) -> Result<bool, Error>
-    where
-        X: Into<PublicKeyXOnly>,
-    {
-        PgWrite::revoke_dkg_shares(self.get_connection().await?.as_mut(), aggregate_key).await
+    async fn write_key_endorsement(
+        &self,
+        endorsement: &model::KeyEndorsement,
+    ) -> Result<(), Error> {
+        PgWrite::write_key_endorsement(self.get_connection().await?.as_mut(), endorsement).await
     }
 
-    async fn verify_dkg_shares<X>(&self, aggregate_key: X) -> Result<bool, Error>
-    where
-        X: Into<PublicKeyXOnly>,
-    {
-        PgWrite::verify_dkg_shares(self.get_connection().await?.as_mut(), aggregate_key).await
+    async fn write_emergency_recovery_approval(
+        &self,
+        approval: &model::EmergencyRecoveryApproval,
+    ) -> Result<(), Error> {
+        PgWrite::write_emergency_recovery_approval(self.get_connection().await?.as_mut(), approval)
+            .await
+    }
+
+    async fn write_emergency_recovery_sweep(
+        &self,
+        sweep: &model::EmergencyRecoverySweep,
+    ) -> Result<(), Error> {
+        PgWrite::write_emergency_recovery_sweep(self.get_connection().await?.as_mut(), sweep).await
+    }
+
+    async fn write_deposit_decision_acknowledgement(
+        &self,
+        ack: &model::DepositDecisionAcknowledgement,
+    ) -> Result<(), Error> {
+        PgWrite::write_deposit_decision_acknowledgement(self.get_connection().await?.as_mut(), ack)
+            .await
+    }
+
+    async fn write_withdrawal_decision_acknowledgement(
+        &self,
+        ack: &model::WithdrawalDecisionAcknowledgement,
+    ) -> Result<(), Error> {
+        PgWrite::write_withdrawal_decision_acknowledgement(
+            self.get_connection().await?.as_mut(),
+            ack,
+        )
+        .await
+    }
+
+    async fn set_coordinator_paused(&self, paused: bool) -> Result<(), Error> {
+        PgWrite::set_coordinator_paused(self.get_connection().await?.as_mut(), paused).await
+    }
+
+    async fn set_deposit_decision_override(
+        &self,
+        outpoint: &bitcoin::OutPoint,
+        can_accept: Option<bool>,
+    ) -> Result<(), Error> {
+        PgWrite::set_deposit_decision_override(
+            self.get_connection().await?.as_mut(),
+            outpoint,
+            can_accept,
+        )
+        .await
+    }
+
+    async fn set_withdrawal_decision_override(
+        &self,
+        id: &model::QualifiedRequestId,
+        accept: Option<bool>,
+    ) -> Result<(), Error> {
+        PgWrite::set_withdrawal_decision_override(self.get_connection().await?.as_mut(), id, accept)
+            .await
     }
 }
 
@@ -1203,6 +2599,32 @@ impl DbWrite for PgTransaction<'_> {
         PgWrite::write_rotate_keys_transaction(tx.as_mut(), key_rotation).await
     }
 
+    async fn register_aggregate_key(
+        &self,
+        shares: &model::EncryptedDkgShares,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::register_aggregate_key(tx.as_mut(), shares).await
+    }
+
+    async fn write_aggregate_key_rotation(
+        &self,
+        aggregate_key: &PublicKey,
+        txid: &model::StacksTxId,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_aggregate_key_rotation(tx.as_mut(), aggregate_key, txid).await
+    }
+
+    async fn activate_aggregate_key(
+        &self,
+        aggregate_key: &PublicKey,
+        height: model::BitcoinBlockHeight,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::activate_aggregate_key(tx.as_mut(), aggregate_key, height).await
+    }
+
     async fn write_withdrawal_reject_event(
         &self,
         event: &model::WithdrawalRejectEvent,
@@ -1276,4 +2698,240 @@ impl DbWrite for PgTransaction<'_> {
         let mut tx = self.tx.lock().await;
         PgWrite::verify_dkg_shares(tx.as_mut(), aggregate_key).await
     }
+
+    async fn write_coordinator_attestation(
+        &self,
+        attestation: &model::CoordinatorAttestation,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_coordinator_attestation(tx.as_mut(), attestation).await
+    }
+
+    async fn write_deposit_decision_receipt(
+        &self,
+        receipt: &model::DepositDecisionReceipt,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_deposit_decision_receipt(tx.as_mut(), receipt).await
+    }
+
+    async fn write_deposit_rejection(
+        &self,
+        rejection: &model::DepositRejection,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_deposit_rejection(tx.as_mut(), rejection).await
+    }
+
+    async fn write_deposit_expiration(
+        &self,
+        expiration: &model::DepositExpiration,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_deposit_expiration(tx.as_mut(), expiration).await
+    }
+
+    async fn write_deposit_reclaim(&self, reclaim: &model::DepositReclaim) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_deposit_reclaim(tx.as_mut(), reclaim).await
+    }
+
+    async fn reactivate_deposit_request(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<bool, Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::reactivate_deposit_request(tx.as_mut(), txid, output_index).await
+    }
+
+    async fn write_stacks_tx_submission(
+        &self,
+        submission: &model::StacksTxSubmission,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_stacks_tx_submission(tx.as_mut(), submission).await
+    }
+
+    async fn set_stacks_tx_submission_status(
+        &self,
+        txid: &model::StacksTxId,
+        status: model::StacksTxStatus,
+    ) -> Result<bool, Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::set_stacks_tx_submission_status(tx.as_mut(), txid, status).await
+    }
+
+    async fn write_bitcoin_broadcasts(
+        &self,
+        broadcasts: &[model::BitcoinBroadcast],
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_bitcoin_broadcasts(tx.as_mut(), broadcasts).await
+    }
+
+    async fn record_bitcoin_broadcast_attempt(
+        &self,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+        last_error: Option<&str>,
+    ) -> Result<bool, Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::record_bitcoin_broadcast_attempt(tx.as_mut(), txid, status, last_error).await
+    }
+
+    async fn set_bitcoin_broadcast_status(
+        &self,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+    ) -> Result<bool, Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::set_bitcoin_broadcast_status(tx.as_mut(), txid, status).await
+    }
+
+    async fn write_quarantined_stacks_block(
+        &self,
+        block: &model::QuarantinedStacksBlock,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_quarantined_stacks_block(tx.as_mut(), block).await
+    }
+
+    async fn write_signed_sighash(&self, sighash: &model::SigHash) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_signed_sighash(tx.as_mut(), sighash).await
+    }
+
+    async fn write_mint_mismatch(&self, mismatch: &model::MintMismatch) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_mint_mismatch(tx.as_mut(), mismatch).await
+    }
+
+    async fn write_signer_payment(&self, payment: &model::SignerPayment) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_signer_payment(tx.as_mut(), payment).await
+    }
+
+    async fn set_signer_payment_status(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        status: model::SignerPaymentStatus,
+    ) -> Result<bool, Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::set_signer_payment_status(tx.as_mut(), txid, output_index, status).await
+    }
+
+    async fn lock_prevouts(
+        &self,
+        locks: &[model::PrevoutLock],
+    ) -> Result<Option<model::PrevoutLock>, Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::lock_prevouts(tx.as_mut(), locks).await
+    }
+
+    async fn unlock_prevouts(&self, txid: &model::BitcoinTxId) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::unlock_prevouts(tx.as_mut(), txid).await
+    }
+
+    async fn write_tenure_plan(&self, plan: &model::TenurePlan) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_tenure_plan(tx.as_mut(), plan).await
+    }
+
+    async fn write_block_processing_stage(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+        stage: model::BlockProcessingStage,
+        at: model::Timestamp,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_block_processing_stage(tx.as_mut(), block_hash, stage, at).await
+    }
+
+    async fn write_dkg_begin_refusal(&self, refusal: &model::DkgBeginRefusal) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_dkg_begin_refusal(tx.as_mut(), refusal).await
+    }
+
+    async fn write_signer_key_rotation(
+        &self,
+        rotation: &model::SignerKeyRotation,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_signer_key_rotation(tx.as_mut(), rotation).await
+    }
+
+    async fn set_signer_key_rotation_status(
+        &self,
+        new_public_key: &PublicKey,
+        status: model::SignerKeyRotationStatus,
+    ) -> Result<bool, Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::set_signer_key_rotation_status(tx.as_mut(), new_public_key, status).await
+    }
+
+    async fn write_key_endorsement(
+        &self,
+        endorsement: &model::KeyEndorsement,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_key_endorsement(tx.as_mut(), endorsement).await
+    }
+
+    async fn write_emergency_recovery_approval(
+        &self,
+        approval: &model::EmergencyRecoveryApproval,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_emergency_recovery_approval(tx.as_mut(), approval).await
+    }
+
+    async fn write_emergency_recovery_sweep(
+        &self,
+        sweep: &model::EmergencyRecoverySweep,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_emergency_recovery_sweep(tx.as_mut(), sweep).await
+    }
+
+    async fn write_deposit_decision_acknowledgement(
+        &self,
+        ack: &model::DepositDecisionAcknowledgement,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_deposit_decision_acknowledgement(tx.as_mut(), ack).await
+    }
+
+    async fn write_withdrawal_decision_acknowledgement(
+        &self,
+        ack: &model::WithdrawalDecisionAcknowledgement,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::write_withdrawal_decision_acknowledgement(tx.as_mut(), ack).await
+    }
+
+    async fn set_coordinator_paused(&self, paused: bool) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::set_coordinator_paused(tx.as_mut(), paused).await
+    }
+
+    async fn set_deposit_decision_override(
+        &self,
+        outpoint: &bitcoin::OutPoint,
+        can_accept: Option<bool>,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::set_deposit_decision_override(tx.as_mut(), outpoint, can_accept).await
+    }
+
+    async fn set_withdrawal_decision_override(
+        &self,
+        id: &model::QualifiedRequestId,
+        accept: Option<bool>,
+    ) -> Result<(), Error> {
+        let mut tx = self.tx.lock().await;
+        PgWrite::set_withdrawal_decision_override(tx.as_mut(), id, accept).await
+    }
 }
@@ -0,0 +1,281 @@
+//! Supervision of the signer's event loops.
+//!
+//! The block observer, request decider, transaction coordinator and
+//! transaction signer event loops should only return once shutdown has
+//! been signalled. If one of them returns early, whether with an error or
+//! by panicking, the rest of the signer keeps running without it. The
+//! [`supervise`] function runs an event loop and restarts it with
+//! exponential backoff whenever it exits unexpectedly. If the event loop
+//! keeps crashing, or fails with a critical error that retrying will not
+//! fix, it gives up and returns an error, so that the whole signer shuts
+//! down instead of limping along.
+//!
+//! Event loops report the errors that they handle themselves with
+//! [`report_error`], which logs them at the level of their
+//! [`Severity`], counts them, and signals the critical ones to the alert
+//! dispatcher.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::time::Duration;
+
+use futures::FutureExt as _;
+
+use crate::context::Context;
+use crate::context::SignerEvent;
+use crate::error::Error;
+use crate::error::ErrorCode;
+use crate::error::Severity;
+use crate::metrics::Metrics;
+
+/// When and how often the supervisor restarts an event loop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestartPolicy {
+    /// The amount of time to wait before the first restart.
+    pub initial_backoff: Duration,
+    /// The maximum amount of time to wait before a restart.
+    pub max_backoff: Duration,
+    /// The window in which restarts count towards a crash loop.
+    pub crash_loop_window: Duration,
+    /// The number of restarts within the crash loop window after which
+    /// the supervisor gives up on the event loop.
+    pub max_restarts: usize,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            crash_loop_window: Duration::from_secs(600),
+            max_restarts: 5,
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// The amount of time to wait before restarting an event loop that
+    /// has already been restarted `recent_restarts` times within the crash
+    /// loop window. This doubles with each restart, up to the maximum.
+    pub fn backoff(&self, recent_restarts: usize) -> Duration {
+        let factor = u32::try_from(recent_restarts)
+            .ok()
+            .and_then(|shift| 1u32.checked_shl(shift))
+            .unwrap_or(u32::MAX);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// A critical error that an event loop ran into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorReport {
+    /// The name of the event loop.
+    pub event_loop: &'static str,
+    /// The code of the error.
+    pub code: ErrorCode,
+    /// A human readable description of the error.
+    pub message: String,
+}
+
+/// Report an error that the given event loop ran into while doing the
+/// given thing, and carried on from.
+///
+/// The error is logged at the level of its severity, counted, and
+/// signalled to the alert dispatcher if it is critical.
+pub fn report_error(ctx: &impl Context, event_loop: &'static str, error: &Error, doing: &str) {
+    let code = error.code();
+    match error.severity() {
+        Severity::Warning => tracing::warn!(%event_loop, %code, %error, "could not {doing}"),
+        Severity::Error => tracing::error!(%event_loop, %code, %error, "could not {doing}"),
+        Severity::Critical => tracing::error!(
+            %event_loop,
+            %code,
+            %error,
+            critical = true,
+            "could not {doing}"
+        ),
+    }
+
+    let category: &'static str = error.category().into();
+    metrics::counter!(
+        Metrics::ErrorsTotal,
+        "event_loop" => event_loop,
+        "category" => category
+    )
+    .increment(1);
+
+    if error.severity() == Severity::Critical {
+        let report = ErrorReport {
+            event_loop,
+            code,
+            message: format!("could not {doing}: {error}"),
+        };
+        if let Err(error) = ctx.signal(SignerEvent::CriticalError(report).into()) {
+            tracing::warn!(%error, "could not signal critical error");
+        }
+    }
+}
+
+/// Run the event loop returned by `f`, restarting it according to the
+/// given policy whenever it returns or panics before shutdown has been
+/// signalled.
+///
+/// Returns `Ok(())` once shutdown has been signalled, and an
+/// [`Error::EventLoopCrashLoop`] error once the event loop has been
+/// restarted too many times within the crash loop window of the policy.
+/// If the event loop fails with a critical error that is not retryable,
+/// that error is returned right away.
+pub async fn supervise<C, F, Fut>(
+    name: &'static str,
+    policy: &RestartPolicy,
+    f: F,
+    ctx: C,
+) -> Result<(), Error>
+where
+    C: Context,
+    F: Fn(C) -> Fut,
+    Fut: Future<Output = Result<(), Error>>,
+{
+    let mut term = ctx.get_termination_handle();
+    let mut restarts = VecDeque::new();
+    loop {
+        let outcome = AssertUnwindSafe(f(ctx.clone())).catch_unwind().await;
+        if term.shutdown_signalled() {
+            return Ok(());
+        }
+
+        match outcome {
+            Ok(Ok(())) => tracing::warn!(event_loop = %name, "event loop exited unexpectedly"),
+            Ok(Err(error)) => {
+                report_error(&ctx, name, &error, "run event loop");
+                if error.severity() == Severity::Critical && !error.is_retryable() {
+                    tracing::error!(
+                        event_loop = %name,
+                        "not restarting event loop after critical error"
+                    );
+                    return Err(error);
+                }
+            }
+            Err(_) => tracing::error!(event_loop = %name, "event loop panicked"),
+        }
+
+        let now = ctx.clock().now();
+        restarts.retain(|at| now.saturating_duration_since(*at) < policy.crash_loop_window);
+        if restarts.len() >= policy.max_restarts {
+            tracing::error!(
+                event_loop = %name,
+                restarts = %restarts.len(),
+                "event loop is crash looping; giving up on it"
+            );
+            return Err(Error::EventLoopCrashLoop(name));
+        }
+
+        let backoff = policy.backoff(restarts.len());
+        restarts.push_back(now);
+        metrics::counter!(Metrics::EventLoopRestartsTotal, "event_loop" => name).increment(1);
+        tracing::info!(
+            event_loop = %name,
+            backoff_secs = %backoff.as_secs_f64(),
+            "restarting event loop"
+        );
+
+        tokio::select! {
+            _ = term.wait_for_shutdown() => return Ok(()),
+            _ = ctx.clock().sleep(backoff) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use super::*;
+    use crate::context::SignerSignal;
+    use crate::testing::context::*;
+
+    #[test]
+    fn backoff_doubles_up_to_the_maximum() {
+        let policy = RestartPolicy::default();
+        assert_eq!(policy.backoff(0), Duration::from_secs(1));
+        assert_eq!(policy.backoff(1), Duration::from_secs(2));
+        assert_eq!(policy.backoff(5), Duration::from_secs(32));
+        assert_eq!(policy.backoff(6), Duration::from_secs(60));
+        assert_eq!(policy.backoff(100), Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn crash_looping_event_loops_are_given_up_on() {
+        let ctx = TestContext::default_mocked();
+        let policy = RestartPolicy {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(1),
+            crash_loop_window: Duration::from_secs(600),
+            max_restarts: 3,
+        };
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let event_loop = |_ctx| {
+            let runs = Arc::clone(&runs);
+            async move {
+                if runs.fetch_add(1, Ordering::SeqCst) % 2 == 0 {
+                    panic!("event loop panicked");
+                }
+                Err(Error::SignerShutdown)
+            }
+        };
+
+        let result = supervise("test", &policy, event_loop, ctx).await;
+        assert!(matches!(result, Err(Error::EventLoopCrashLoop("test"))));
+        // The first run plus three restarts.
+        assert_eq!(runs.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn event_loops_are_not_restarted_after_critical_errors() {
+        let ctx = TestContext::default_mocked();
+        let mut signals = ctx.get_signal_receiver();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let event_loop = |_ctx| {
+            let runs = Arc::clone(&runs);
+            async move {
+                runs.fetch_add(1, Ordering::SeqCst);
+                Err(Error::InvalidConfiguration)
+            }
+        };
+
+        let result = supervise("test", &RestartPolicy::default(), event_loop, ctx).await;
+        assert!(matches!(result, Err(Error::InvalidConfiguration)));
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        let signal = signals.try_recv().unwrap();
+        let SignerSignal::Event(SignerEvent::CriticalError(report)) = signal else {
+            panic!("expected a critical error signal, got {signal:?}");
+        };
+        assert_eq!(report.event_loop, "test");
+        assert_eq!(report.code, Error::InvalidConfiguration.code());
+    }
+
+    #[tokio::test]
+    async fn event_loops_are_not_restarted_after_shutdown() {
+        let ctx = TestContext::default_mocked();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let event_loop = |ctx: TestContext<_, _, _, _>| {
+            let runs = Arc::clone(&runs);
+            async move {
+                runs.fetch_add(1, Ordering::SeqCst);
+                ctx.get_termination_handle().signal_shutdown();
+                Ok(())
+            }
+        };
+
+        let result = supervise("test", &RestartPolicy::default(), event_loop, ctx).await;
+        assert!(result.is_ok());
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+}
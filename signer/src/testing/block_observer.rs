@@ -24,6 +24,7 @@ use clarity::types::chainstate::BurnchainHeaderHash;
 use clarity::types::chainstate::SortitionId;
 use clarity::vm::costs::ExecutionCost;
 use emily_client::models::DepositStatus;
+use futures::Stream;
 use rand::seq::IteratorRandom;
 use sbtc::deposits::CreateDepositRequest;
 
@@ -43,11 +44,13 @@ use crate::stacks::api::AccountInfo;
 use crate::stacks::api::FeePriority;
 use crate::stacks::api::SignerSetInfo;
 use crate::stacks::api::StacksInteract;
+use crate::stacks::api::StacksTxInfo;
 use crate::stacks::api::SubmitTxResponse;
 use crate::stacks::api::TenureBlocks;
 use crate::stacks::wallet::SignerWallet;
 use crate::storage::model;
 use crate::storage::model::BitcoinBlockHeight;
+use crate::storage::model::StacksTxId;
 use crate::testing::dummy;
 use crate::util::ApiFallbackClient;
 
@@ -222,6 +225,10 @@ impl BitcoinInteract for TestHarness {
                 height: block.height,
                 time: block.time,
                 previous_block_hash: block.previous_block_hash,
+                version: 0x2000_0000,
+                merkle_root: bitcoin::TxMerkleNode::all_zeros(),
+                bits: bitcoin::CompactTarget::from_consensus(0x207f_ffff),
+                nonce: 0,
             }))
     }
 
@@ -252,6 +259,10 @@ impl BitcoinInteract for TestHarness {
         unimplemented!()
     }
 
+    async fn broadcast_package(&self, _txs: &[bitcoin::Transaction]) -> Result<(), Error> {
+        unimplemented!()
+    }
+
     async fn find_mempool_transactions_spending_output(
         &self,
         _outpoint: &bitcoin::OutPoint,
@@ -295,6 +306,13 @@ impl BitcoinInteract for TestHarness {
     async fn get_network_info(&self) -> Result<bitcoincore_rpc_json::GetNetworkInfoResult, Error> {
         unimplemented!()
     }
+
+    async fn scan_tx_out_set(
+        &self,
+        _descriptors: &[String],
+    ) -> Result<bitcoincore_rpc_json::ScanTxOutResult, Error> {
+        unimplemented!()
+    }
 }
 
 impl StacksInteract for TestHarness {
@@ -331,6 +349,10 @@ impl StacksInteract for TestHarness {
         todo!()
     }
 
+    async fn get_transaction(&self, _txid: &StacksTxId) -> Result<Option<StacksTxInfo>, Error> {
+        unimplemented!()
+    }
+
     async fn get_block(&self, block_id: StacksBlockId) -> Result<NakamotoBlock, Error> {
         self.stacks_blocks
             .iter()
@@ -484,8 +506,8 @@ impl EmilyInteract for TestHarness {
             .cloned();
         Ok(deposit)
     }
-    async fn get_deposits(&self) -> Result<Vec<CreateDepositRequest>, Error> {
-        Ok(self.pending_deposits.clone())
+    fn get_deposits(&self) -> impl Stream<Item = Result<CreateDepositRequest, Error>> + Send {
+        futures::stream::iter(self.pending_deposits.clone().into_iter().map(Ok))
     }
 
     async fn get_deposits_with_status(
@@ -0,0 +1,359 @@
+//! An in-memory cluster of signers for multi-signer behavior tests.
+//!
+//! A [`Cluster`] wires up N signers, each with in-memory storage and
+//! mocked bitcoin, stacks and Emily clients, connected to each other over
+//! a [`WanNetwork`]. The cluster also keeps a simulated bitcoin chain that
+//! can be extended with [`Cluster::mine_block`], which writes the new
+//! block to every signer's database and notifies the signers, the same way
+//! that the block observer would. None of this requires docker, bitcoind,
+//! Postgres or Emily.
+//!
+//! ```ignore
+//! let mut cluster = ClusterBuilder::new().num_signers(3).threshold(2).build().await;
+//! cluster.spawn_tx_signers();
+//! let block = cluster.mine_block().await;
+//! ```
+
+use std::time::Duration;
+
+use rand::SeedableRng as _;
+use rand::rngs::StdRng;
+use tokio::task::JoinHandle;
+
+use crate::context::Context as _;
+use crate::context::SignerEvent;
+use crate::error::Error;
+use crate::keys::PrivateKey;
+use crate::keys::PublicKey;
+use crate::network::in_memory2::SignerNetwork;
+use crate::network::in_memory2::WanNetwork;
+use crate::request_decider::RequestDeciderEventLoop;
+use crate::storage::DbWrite as _;
+use crate::storage::memory::SharedStore;
+use crate::storage::model;
+use crate::storage::model::BitcoinBlockRef;
+use crate::testing::blocks::BitcoinChain;
+use crate::testing::context::BuildContext as _;
+use crate::testing::context::ConfigureMockedClients as _;
+use crate::testing::context::ConfigureStorage as _;
+use crate::testing::context::TestContext;
+use crate::testing::context::WrappedMockBitcoinInteract;
+use crate::testing::context::WrappedMockEmilyInteract;
+use crate::testing::context::WrappedMockStacksInteract;
+use crate::testing::wsts::generate_signer_info;
+use crate::transaction_signer::TxSignerEventLoop;
+
+/// The context of each signer in a [`Cluster`].
+pub type ClusterContext = TestContext<
+    SharedStore,
+    WrappedMockBitcoinInteract,
+    WrappedMockStacksInteract,
+    WrappedMockEmilyInteract,
+>;
+
+/// A builder for a [`Cluster`].
+#[derive(Debug, Clone)]
+pub struct ClusterBuilder {
+    num_signers: usize,
+    threshold: u16,
+    context_window: u16,
+    chain_length: usize,
+    network_capacity: usize,
+    seed: u64,
+}
+
+impl Default for ClusterBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClusterBuilder {
+    /// Create a new builder for a cluster of three signers with a signing
+    /// threshold of two.
+    pub fn new() -> Self {
+        Self {
+            num_signers: 3,
+            threshold: 2,
+            context_window: 1000,
+            chain_length: 10,
+            network_capacity: 1_000,
+            seed: 51,
+        }
+    }
+
+    /// Set the number of signers in the cluster.
+    pub fn num_signers(mut self, num_signers: usize) -> Self {
+        self.num_signers = num_signers;
+        self
+    }
+
+    /// Set the number of signatures required to sign for the signers'
+    /// aggregate key.
+    pub fn threshold(mut self, threshold: u16) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set the bitcoin context window of the signers.
+    pub fn context_window(mut self, context_window: u16) -> Self {
+        self.context_window = context_window;
+        self
+    }
+
+    /// Set the number of blocks in the simulated bitcoin chain when the
+    /// cluster starts.
+    pub fn chain_length(mut self, chain_length: usize) -> Self {
+        self.chain_length = chain_length;
+        self
+    }
+
+    /// Set the capacity of the network connecting the signers.
+    pub fn network_capacity(mut self, network_capacity: usize) -> Self {
+        self.network_capacity = network_capacity;
+        self
+    }
+
+    /// Set the seed used for generating the signers' keys.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Build the cluster.
+    ///
+    /// Each signer is configured with the cluster's signing set as its
+    /// bootstrap signing set and has the simulated bitcoin chain in its
+    /// database, with its chain tip set to the tip of that chain.
+    pub async fn build(self) -> Cluster {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let network = WanNetwork::new(self.network_capacity);
+        let chain = BitcoinChain::new_with_length(self.chain_length.max(1));
+
+        let mut signers = Vec::with_capacity(self.num_signers);
+        for info in generate_signer_info(&mut rng, self.num_signers) {
+            let mut context = TestContext::builder()
+                .with_in_memory_storage()
+                .with_mocked_clients()
+                .build();
+
+            let config = context.config_mut();
+            config.signer.private_key = info.signer_private_key;
+            config.signer.bootstrap_signing_set = info.signer_public_keys;
+            config.signer.bootstrap_signatures_required = self.threshold;
+            config.signer.context_window = self.context_window;
+
+            let storage = context.get_storage_mut();
+            for block in &chain {
+                storage
+                    .write_bitcoin_block(block)
+                    .await
+                    .expect("failed to write bitcoin block");
+            }
+            let chain_tip = BitcoinBlockRef::from(chain.chain_tip());
+            context.state().set_bitcoin_chain_tip(chain_tip);
+
+            signers.push(ClusterSigner {
+                network: network.connect(&context),
+                private_key: info.signer_private_key,
+                context,
+            });
+        }
+
+        Cluster {
+            network,
+            chain,
+            signers,
+            handles: Vec::new(),
+        }
+    }
+}
+
+/// A signer in a [`Cluster`].
+pub struct ClusterSigner {
+    /// The signer's context.
+    pub context: ClusterContext,
+    /// The signer's connection to the cluster's network.
+    pub network: SignerNetwork,
+    /// The signer's private key.
+    pub private_key: PrivateKey,
+}
+
+impl ClusterSigner {
+    /// The signer's public key.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from_private_key(&self.private_key)
+    }
+}
+
+/// N signers with in-memory storage and mocked clients, connected over an
+/// in-memory network and following a simulated bitcoin chain.
+pub struct Cluster {
+    /// The network connecting the signers.
+    pub network: WanNetwork,
+    /// The simulated bitcoin chain.
+    pub chain: BitcoinChain,
+    /// The signers in the cluster.
+    pub signers: Vec<ClusterSigner>,
+    handles: Vec<JoinHandle<Result<(), Error>>>,
+}
+
+impl Cluster {
+    /// Create a builder for a cluster.
+    pub fn builder() -> ClusterBuilder {
+        ClusterBuilder::new()
+    }
+
+    /// The contexts of the signers in the cluster.
+    pub fn contexts(&self) -> impl Iterator<Item = &ClusterContext> {
+        self.signers.iter().map(|signer| &signer.context)
+    }
+
+    /// Extend the simulated bitcoin chain by one block, write the block to
+    /// every signer's database, update their chain tips and signal that a
+    /// new bitcoin block has been observed.
+    pub async fn mine_block(&mut self) -> model::BitcoinBlock {
+        let block = self.chain.generate_blocks(1)[0].clone();
+        let chain_tip = BitcoinBlockRef::from(&block);
+
+        for context in self.contexts() {
+            context
+                .get_storage_mut()
+                .write_bitcoin_block(&block)
+                .await
+                .expect("failed to write bitcoin block");
+            context.state().set_bitcoin_chain_tip(chain_tip);
+            context
+                .signal(SignerEvent::BitcoinBlockObserved.into())
+                .expect("failed to signal the new bitcoin block");
+        }
+
+        block
+    }
+
+    /// Spawn a transaction signer event loop for each signer.
+    pub fn spawn_tx_signers(&mut self) {
+        for signer in &self.signers {
+            let rng = StdRng::from_seed(signer.private_key.to_bytes());
+            let event_loop =
+                TxSignerEventLoop::new(signer.context.clone(), signer.network.spawn(), rng)
+                    .expect("failed to create the transaction signer event loop");
+            self.handles.push(tokio::spawn(event_loop.run()));
+        }
+    }
+
+    /// Spawn a request decider event loop for each signer. The request
+    /// deciders accept every address.
+    pub fn spawn_request_deciders(&mut self) {
+        for signer in &self.signers {
+            let config = &signer.context.config().signer;
+            let event_loop =
+                RequestDeciderEventLoop::builder(signer.context.clone(), signer.network.spawn())
+                    .with_blocklist_checker(Some(()))
+                    .with_private_key(signer.private_key)
+                    .with_context_window(config.context_window)
+                    .with_decisions_retry_windows(
+                        config.deposit_decisions_retry_window,
+                        config.withdrawal_decisions_retry_window,
+                    )
+                    .build()
+                    .unwrap();
+            self.handles.push(tokio::spawn(event_loop.run()));
+        }
+    }
+
+    /// Signal every signer to shut down and wait for the spawned event
+    /// loops to stop, aborting the ones that are still running after the
+    /// given timeout.
+    pub async fn shutdown(self, timeout: Duration) {
+        for context in self.contexts() {
+            context.get_termination_handle().signal_shutdown();
+        }
+
+        for handle in self.handles {
+            let abort_handle = handle.abort_handle();
+            if tokio::time::timeout(timeout, handle).await.is_err() {
+                abort_handle.abort();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hashes::Hash as _;
+
+    use crate::ecdsa::SignEcdsa as _;
+    use crate::message::Payload;
+    use crate::message::SignerDepositDecision;
+    use crate::network::MessageTransfer as _;
+    use crate::storage::DbRead as _;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn cluster_signers_follow_the_simulated_chain() {
+        let mut cluster = Cluster::builder()
+            .num_signers(4)
+            .threshold(3)
+            .chain_length(5)
+            .build()
+            .await;
+
+        let signer_set: Vec<_> = cluster.signers.iter().map(|s| s.public_key()).collect();
+        for signer in &cluster.signers {
+            let config = &signer.context.config().signer;
+            assert_eq!(config.bootstrap_signatures_required, 3);
+            assert_eq!(config.bootstrap_signing_set.len(), 4);
+            assert!(config.bootstrap_signing_set.contains(&signer.public_key()));
+            assert!(signer_set.contains(&signer.public_key()));
+        }
+
+        let block = cluster.mine_block().await;
+        assert_eq!(*block.block_height, 5);
+
+        for context in cluster.contexts() {
+            let chain_tip = context
+                .get_storage()
+                .get_bitcoin_canonical_chain_tip()
+                .await
+                .unwrap();
+            assert_eq!(chain_tip, Some(block.block_hash));
+            assert_eq!(
+                context.state().bitcoin_chain_tip().unwrap().block_hash,
+                block.block_hash
+            );
+        }
+
+        cluster.shutdown(Duration::from_secs(1)).await;
+    }
+
+    #[tokio::test]
+    async fn cluster_signers_can_exchange_messages() {
+        let cluster = Cluster::builder().num_signers(2).build().await;
+
+        let mut sender = cluster.signers[0].network.spawn();
+        let mut receiver = cluster.signers[1].network.spawn();
+
+        let chain_tip = cluster.chain.chain_tip().block_hash;
+        let decision = SignerDepositDecision {
+            txid: bitcoin::Txid::from_byte_array([1; 32]),
+            output_index: 0,
+            can_accept: true,
+            can_sign: true,
+            receipt: None,
+            decision_version: 0,
+        };
+        let msg = Payload::from(decision)
+            .to_message(chain_tip)
+            .sign_ecdsa(&cluster.signers[0].private_key);
+
+        sender.broadcast(msg.clone()).await.unwrap();
+        let received = tokio::time::timeout(Duration::from_secs(1), receiver.receive())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(received, msg);
+    }
+}
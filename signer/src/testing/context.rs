@@ -15,6 +15,8 @@ use blockstack_lib::{
 };
 use clarity::types::chainstate::{StacksAddress, StacksBlockId};
 use emily_client::models::DepositStatus;
+use futures::Stream;
+use futures::StreamExt as _;
 use tokio::sync::{Mutex, broadcast};
 use tokio::time::error::Elapsed;
 
@@ -22,16 +24,18 @@ use crate::bitcoin::GetTransactionFeeResult;
 use crate::bitcoin::rpc::{BitcoinBlockHeader, BitcoinBlockInfo};
 use crate::context::SbtcLimits;
 use crate::stacks::api::SignerSetInfo;
+use crate::stacks::api::StacksTxInfo;
 use crate::stacks::api::TenureBlocks;
 use crate::stacks::wallet::SignerWallet;
 use crate::storage::Transactable;
 use crate::storage::model::BitcoinTxId;
+use crate::storage::model::StacksTxId;
 use crate::{
     bitcoin::{
         BitcoinInteract, MockBitcoinInteract, rpc::GetTxResponse, utxo::UnsignedTransaction,
     },
     config::Settings,
-    context::{Context, SignerContext, SignerSignal, SignerState, TerminationHandle},
+    context::{Clock, Context, SignerContext, SignerSignal, SignerState, TerminationHandle},
     emily_client::{EmilyInteract, MockEmilyInteract},
     error::Error,
     keys::PublicKey,
@@ -152,6 +156,12 @@ where
     pub fn config_mut(&mut self) -> &mut Settings {
         self.inner.config_mut()
     }
+
+    /// Replace the clock of the inner context, for example with a
+    /// [`Clock::manual`] clock so that tests control the passage of time.
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.inner.set_clock(clock);
+    }
 }
 
 impl TestContext<(), (), (), ()> {
@@ -264,6 +274,10 @@ where
         self.inner.state()
     }
 
+    fn clock(&self) -> &Clock {
+        self.inner.clock()
+    }
+
     fn get_signal_receiver(&self) -> broadcast::Receiver<SignerSignal> {
         self.inner.get_signal_receiver()
     }
@@ -276,6 +290,10 @@ where
         self.inner.signal(signal)
     }
 
+    fn get_block_observed_receiver(&self) -> tokio::sync::watch::Receiver<u64> {
+        self.inner.get_block_observed_receiver()
+    }
+
     fn get_termination_handle(&self) -> TerminationHandle {
         self.inner.get_termination_handle()
     }
@@ -375,6 +393,10 @@ impl BitcoinInteract for WrappedMockBitcoinInteract {
         self.inner.lock().await.broadcast_transaction(tx).await
     }
 
+    async fn broadcast_package(&self, txs: &[bitcoin::Transaction]) -> Result<(), Error> {
+        self.inner.lock().await.broadcast_package(txs).await
+    }
+
     async fn find_mempool_transactions_spending_output(
         &self,
         _outpoint: &bitcoin::OutPoint,
@@ -420,6 +442,13 @@ impl BitcoinInteract for WrappedMockBitcoinInteract {
     async fn get_network_info(&self) -> Result<bitcoincore_rpc_json::GetNetworkInfoResult, Error> {
         self.inner.lock().await.get_network_info().await
     }
+
+    async fn scan_tx_out_set(
+        &self,
+        descriptors: &[String],
+    ) -> Result<bitcoincore_rpc_json::ScanTxOutResult, Error> {
+        self.inner.lock().await.scan_tx_out_set(descriptors).await
+    }
 }
 
 impl StacksInteract for WrappedMockStacksInteract {
@@ -477,6 +506,10 @@ impl StacksInteract for WrappedMockStacksInteract {
         self.inner.lock().await.submit_tx(tx).await
     }
 
+    async fn get_transaction(&self, txid: &StacksTxId) -> Result<Option<StacksTxInfo>, Error> {
+        self.inner.lock().await.get_transaction(txid).await
+    }
+
     async fn get_block(&self, block_id: StacksBlockId) -> Result<NakamotoBlock, Error> {
         self.inner.lock().await.get_block(block_id).await
     }
@@ -554,8 +587,10 @@ impl EmilyInteract for WrappedMockEmilyInteract {
             .await
     }
 
-    async fn get_deposits(&self) -> Result<Vec<sbtc::deposits::CreateDepositRequest>, Error> {
-        self.inner.lock().await.get_deposits().await
+    fn get_deposits(
+        &self,
+    ) -> impl Stream<Item = Result<sbtc::deposits::CreateDepositRequest, Error>> + Send {
+        futures::stream::once(async { self.inner.lock().await.get_deposits() }).flatten()
     }
 
     async fn get_deposits_with_status(
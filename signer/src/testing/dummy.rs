@@ -1,6 +1,7 @@
 //! Utilities for generating dummy values on external types
 
 use std::collections::BTreeMap;
+use std::ops::Deref as _;
 use std::ops::Range;
 
 use bitcoin::Amount;
@@ -20,6 +21,8 @@ use p256k1::scalar::Scalar;
 use polynomial::Polynomial;
 use rand::Rng;
 use rand::seq::IteratorRandom as _;
+use sbtc::deposits::DepositScriptInputs;
+use sbtc::deposits::ReclaimScriptInputs;
 use secp256k1::ecdsa::RecoverableSignature;
 use stacks_common::address::AddressHashMode;
 use stacks_common::address::C32_ADDRESS_VERSION_TESTNET_MULTISIG;
@@ -51,9 +54,11 @@ use crate::bitcoin::rpc::BitcoinTxInfo;
 use crate::bitcoin::rpc::BitcoinTxVin;
 use crate::bitcoin::rpc::BitcoinTxVinPrevout;
 use crate::bitcoin::rpc::OutputScriptPubKey;
+use crate::bitcoin::units::FeeRate;
 use crate::bitcoin::utxo::Fees;
 use crate::bitcoin::utxo::SignerBtcState;
 use crate::bitcoin::utxo::SignerUtxo;
+use crate::bitcoin::utxo::SweepTemplateVersion;
 use crate::bitcoin::validation::TxRequestIds;
 use crate::codec::Encode;
 use crate::ecdsa::Signed;
@@ -63,9 +68,25 @@ use crate::keys::PublicKeyXOnly;
 use crate::keys::SignerScriptPubKey as _;
 use crate::message::BitcoinPreSignAck;
 use crate::message::BitcoinPreSignRequest;
+use crate::message::CoordinatorAttestation;
+use crate::message::DatabaseDigest;
+use crate::message::DecisionAcknowledgement;
+use crate::message::DecisionReceipt;
+use crate::message::DepositDecisionAck;
+use crate::message::DepositDecisionRetryRequest;
+use crate::message::DepositQuorumCertificate;
+use crate::message::EmergencyRecoveryApproval;
+use crate::message::EmergencyRecoveryRevocation;
+use crate::message::LimitOverrideVote;
+use crate::message::MessageRedeliveryRequest;
+use crate::message::RedeliveredMessages;
+use crate::message::SignerKeyEndorsement;
 use crate::message::SignerMessage;
+use crate::message::WithdrawalDecisionAck;
 use crate::stacks::contracts::AcceptWithdrawalV1;
+use crate::stacks::contracts::AcceptWithdrawalsV1;
 use crate::stacks::contracts::CompleteDepositV1;
+use crate::stacks::contracts::CompleteDepositsV1;
 use crate::stacks::contracts::RejectWithdrawalV1;
 use crate::stacks::contracts::RotateKeysV1;
 use crate::storage::model;
@@ -429,6 +450,48 @@ impl fake::Dummy<BitcoinAddresses> for Vec<ScriptPubKey> {
     }
 }
 
+impl fake::Dummy<fake::Faker> for model::DepositRequest {
+    fn dummy_with_rng<R: rand::Rng + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        let mut request = model::DepositRequest {
+            txid: config.fake_with_rng(rng),
+            output_index: (0..100).fake_with_rng(rng),
+            spend_script: Vec::new(),
+            reclaim_script: Vec::new(),
+            reclaim_script_hash: None,
+            recipient: config.fake_with_rng(rng),
+            amount: (1_000_000..1_000_000_000).fake_with_rng(rng),
+            max_fee: (100..100_000).fake_with_rng(rng),
+            lock_time: (3..u16::MAX as u32).fake_with_rng(rng),
+            signers_public_key: config.fake_with_rng(rng),
+            sender_script_pub_keys: BitcoinAddresses(1..5).fake_with_rng(rng),
+        };
+        set_deposit_scripts(&mut request);
+        request
+    }
+}
+
+/// Set the deposit and reclaim scripts, and the reclaim script hash, of
+/// the given deposit request so that they match the recipient, max fee,
+/// lock-time and signers' public key of the request.
+///
+/// Tests that change any of these fields on a fake deposit request should
+/// call this afterwards, since the signers parse the scripts when deciding
+/// on and signing for the request.
+pub fn set_deposit_scripts(request: &mut model::DepositRequest) {
+    let deposit_inputs = DepositScriptInputs {
+        signers_public_key: request.signers_public_key.into(),
+        recipient: request.recipient.deref().clone(),
+        max_fee: request.max_fee,
+    };
+    let reclaim_inputs = ReclaimScriptInputs::try_new(request.lock_time, ScriptBuf::new())
+        .expect("BUG: fake deposit requests have a valid lock-time");
+    let reclaim_script = reclaim_inputs.reclaim_script();
+
+    request.spend_script = deposit_inputs.deposit_script().into_bytes();
+    request.reclaim_script_hash = Some(TaprootScriptHash::from(&reclaim_script));
+    request.reclaim_script = reclaim_script.into_bytes();
+}
+
 impl fake::Dummy<fake::Faker> for WithdrawalAcceptEvent {
     fn dummy_with_rng<R: Rng + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
         let bitmap = rng.next_u64() as u128;
@@ -549,6 +612,7 @@ impl fake::Dummy<&[PublicKey]> for SignerBtcState {
             fee_rate: Faker.fake_with_rng(rng),
             last_fees: Faker.fake_with_rng(rng),
             magic_bytes: [1, 2],
+            metadata_chain_tip: None,
             public_key: aggregate_key_x_only,
             utxo: SignerUtxo {
                 amount: Faker.fake_with_rng(rng),
@@ -699,6 +763,43 @@ impl fake::Dummy<fake::Faker> for AcceptWithdrawalV1 {
     }
 }
 
+impl fake::Dummy<fake::Faker> for CompleteDepositsV1 {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        let num_deposits = (1..5).fake_with_rng(rng);
+        let deposits: Vec<CompleteDepositV1> = std::iter::repeat_with(|| config.fake_with_rng(rng))
+            .take(num_deposits)
+            .collect();
+        let deployer = deposits[0].deployer;
+
+        CompleteDepositsV1 {
+            deposits: deposits
+                .into_iter()
+                .map(|deposit| CompleteDepositV1 { deployer, ..deposit })
+                .collect(),
+            deployer,
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for AcceptWithdrawalsV1 {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        let num_withdrawals = (1..5).fake_with_rng(rng);
+        let withdrawals: Vec<AcceptWithdrawalV1> =
+            std::iter::repeat_with(|| config.fake_with_rng(rng))
+                .take(num_withdrawals)
+                .collect();
+        let deployer = withdrawals[0].deployer;
+
+        AcceptWithdrawalsV1 {
+            withdrawals: withdrawals
+                .into_iter()
+                .map(|withdrawal| AcceptWithdrawalV1 { deployer, ..withdrawal })
+                .collect(),
+            deployer,
+        }
+    }
+}
+
 impl fake::Dummy<fake::Faker> for RejectWithdrawalV1 {
     fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
         let public_key: PublicKey = config.fake_with_rng(rng);
@@ -764,12 +865,170 @@ impl fake::Dummy<fake::Faker> for Fees {
     }
 }
 
+impl fake::Dummy<fake::Faker> for FeeRate {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        let tenths_of_a_sat: u16 = config.fake_with_rng(rng);
+        FeeRate::new(f64::from(tenths_of_a_sat) / 10.0).expect("BUG: fee rate is not negative")
+    }
+}
+
 impl fake::Dummy<fake::Faker> for BitcoinPreSignRequest {
     fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        let num_refunds: usize = (0..5).fake_with_rng(rng);
         BitcoinPreSignRequest {
             request_package: fake::vec![TxRequestIds; 0..20],
             fee_rate: config.fake_with_rng(rng),
             last_fees: config.fake_with_rng(rng),
+            deposit_certificates: fake::vec![DepositQuorumCertificate; 0..5],
+            refunds: std::iter::repeat_with(|| Unit.fake_with_rng(rng))
+                .take(num_refunds)
+                .collect(),
+            // Zero is not a template version, so this is `None` a third
+            // of the time.
+            sweep_template_version: SweepTemplateVersion::try_from(
+                (0..3).fake_with_rng::<u32, _>(rng),
+            )
+            .ok(),
+            emergency_recovery: config.fake_with_rng(rng),
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for DecisionReceipt {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        DecisionReceipt {
+            signer_public_key: config.fake_with_rng(rng),
+            signature: Unit.fake_with_rng(rng),
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for DepositQuorumCertificate {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(_: &fake::Faker, rng: &mut R) -> Self {
+        DepositQuorumCertificate {
+            outpoint: Unit.fake_with_rng(rng),
+            receipts: fake::vec![DecisionReceipt; 0..10],
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for CoordinatorAttestation {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        let size: usize = (0..20).fake_with_rng(rng);
+        let request_package = std::iter::repeat_with(|| config.fake_with_rng(rng))
+            .take(size)
+            .collect();
+        let sweep_txids = std::iter::repeat_with(|| config.fake_with_rng(rng))
+            .take(size)
+            .collect();
+
+        CoordinatorAttestation { request_package, sweep_txids }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for DatabaseDigest {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        DatabaseDigest {
+            num_blocks: config.fake_with_rng(rng),
+            deposit_requests: config.fake_with_rng(rng),
+            deposit_decisions: config.fake_with_rng(rng),
+            dkg_shares: config.fake_with_rng(rng),
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for DepositDecisionRetryRequest {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        let take = (0..20).fake_with_rng(rng);
+        let deposits = std::iter::repeat_with(|| OutPoint {
+            txid: txid(config, rng),
+            vout: rng.next_u32(),
+        })
+        .take(take)
+        .collect();
+
+        DepositDecisionRetryRequest {
+            deposits,
+            signers: fake::vec![PublicKey; 0..20],
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for LimitOverrideVote {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        LimitOverrideVote {
+            per_deposit_cap: config.fake_with_rng(rng),
+            per_withdrawal_cap: config.fake_with_rng(rng),
+            expires_at_height: config.fake_with_rng::<u64, _>(rng).into(),
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for SignerKeyEndorsement {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        SignerKeyEndorsement {
+            new_public_key: config.fake_with_rng(rng),
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for EmergencyRecoveryApproval {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        EmergencyRecoveryApproval {
+            recovery_script_pubkey: config.fake_with_rng::<ScriptPubKey, _>(rng).into(),
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for EmergencyRecoveryRevocation {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        EmergencyRecoveryRevocation {
+            recovery_script_pubkey: config.fake_with_rng::<ScriptPubKey, _>(rng).into(),
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for DecisionAcknowledgement {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        let take = (0..20).fake_with_rng(rng);
+        let deposits = std::iter::repeat_with(|| DepositDecisionAck {
+            outpoint: OutPoint {
+                txid: txid(config, rng),
+                vout: rng.next_u32(),
+            },
+            decision_version: rng.next_u32(),
+        })
+        .take(take)
+        .collect();
+
+        DecisionAcknowledgement {
+            decider: config.fake_with_rng(rng),
+            deposits,
+            withdrawals: fake::vec![WithdrawalDecisionAck; 0..20],
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for WithdrawalDecisionAck {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        WithdrawalDecisionAck {
+            id: config.fake_with_rng(rng),
+            decision_version: config.fake_with_rng(rng),
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for MessageRedeliveryRequest {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(_config: &fake::Faker, _rng: &mut R) -> Self {
+        MessageRedeliveryRequest
+    }
+}
+
+impl fake::Dummy<fake::Faker> for RedeliveredMessages {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        RedeliveredMessages {
+            recipient: config.fake_with_rng(rng),
+            messages: fake::vec![Vec<u8>; 0..10],
         }
     }
 }
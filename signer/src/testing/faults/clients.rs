@@ -0,0 +1,422 @@
+//! Fault injection for the bitcoin, stacks, and Emily clients.
+
+use bitcoin::Amount;
+use bitcoin::BlockHash;
+use bitcoin::OutPoint;
+use bitcoin::Txid;
+use bitcoincore_rpc_json::GetMempoolEntryResult;
+use bitcoincore_rpc_json::GetTxOutResult;
+use blockstack_lib::chainstate::burn::ConsensusHash;
+use blockstack_lib::chainstate::nakamoto::NakamotoBlock;
+use blockstack_lib::chainstate::stacks::StacksTransaction;
+use blockstack_lib::net::api::getcontractsrc::ContractSrcResponse;
+use blockstack_lib::net::api::getinfo::RPCPeerInfoData;
+use blockstack_lib::net::api::getpoxinfo::RPCPoxInfoData;
+use blockstack_lib::net::api::getsortition::SortitionInfo;
+use blockstack_lib::net::api::gettenureinfo::RPCGetTenureInfo;
+use blockstack_lib::types::chainstate::StacksAddress;
+use blockstack_lib::types::chainstate::StacksBlockId;
+use emily_client::models::DepositStatus;
+use emily_client::models::DepositUpdate;
+use emily_client::models::UpdateDepositsResponse;
+use emily_client::models::UpdateWithdrawalsResponse;
+use emily_client::models::WithdrawalUpdate;
+use futures::Stream;
+use futures::StreamExt as _;
+use sbtc::deposits::CreateDepositRequest;
+
+use crate::bitcoin::BitcoinInteract;
+use crate::bitcoin::GetTransactionFeeResult;
+use crate::bitcoin::TransactionLookupHint;
+use crate::bitcoin::rpc::BitcoinBlockHeader;
+use crate::bitcoin::rpc::BitcoinBlockInfo;
+use crate::bitcoin::rpc::BitcoinTxInfo;
+use crate::bitcoin::rpc::GetTxResponse;
+use crate::bitcoin::utxo::UnsignedTransaction;
+use crate::context::SbtcLimits;
+use crate::emily_client::EmilyApiKeys;
+use crate::emily_client::EmilyInteract;
+use crate::error::Error;
+use crate::keys::PublicKey;
+use crate::stacks::api::AccountInfo;
+use crate::stacks::api::FeePriority;
+use crate::stacks::api::SignerSetInfo;
+use crate::stacks::api::StacksInteract;
+use crate::stacks::api::StacksTxInfo;
+use crate::stacks::api::SubmitTxResponse;
+use crate::stacks::api::TenureBlocks;
+use crate::stacks::contracts::AsTxPayload;
+use crate::stacks::wallet::SignerWallet;
+use crate::storage::model::BitcoinTxId;
+use crate::storage::model::StacksTxId;
+
+use super::FaultPoint;
+use super::Faulty;
+
+impl<B> BitcoinInteract for Faulty<B>
+where
+    B: BitcoinInteract,
+{
+    async fn get_block(&self, block_hash: &BlockHash) -> Result<Option<BitcoinBlockInfo>, Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin("get_block"))
+            .await?;
+        self.inner.get_block(block_hash).await
+    }
+
+    async fn get_block_header(
+        &self,
+        block_hash: &BlockHash,
+    ) -> Result<Option<BitcoinBlockHeader>, Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin("get_block_header"))
+            .await?;
+        self.inner.get_block_header(block_hash).await
+    }
+
+    async fn get_tx(&self, txid: &Txid) -> Result<Option<GetTxResponse>, Error> {
+        self.schedule.inject(FaultPoint::Bitcoin("get_tx")).await?;
+        self.inner.get_tx(txid).await
+    }
+
+    async fn get_tx_info(
+        &self,
+        txid: &Txid,
+        block_hash: &BlockHash,
+    ) -> Result<Option<BitcoinTxInfo>, Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin("get_tx_info"))
+            .await?;
+        self.inner.get_tx_info(txid, block_hash).await
+    }
+
+    async fn estimate_fee_rate(&self) -> Result<f64, Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin("estimate_fee_rate"))
+            .await?;
+        self.inner.estimate_fee_rate().await
+    }
+
+    async fn broadcast_transaction(&self, tx: &bitcoin::Transaction) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin("broadcast_transaction"))
+            .await?;
+        self.inner.broadcast_transaction(tx).await
+    }
+
+    async fn broadcast_package(&self, txs: &[bitcoin::Transaction]) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin("broadcast_package"))
+            .await?;
+        self.inner.broadcast_package(txs).await
+    }
+
+    async fn find_mempool_transactions_spending_output(
+        &self,
+        outpoint: &bitcoin::OutPoint,
+    ) -> Result<Vec<Txid>, Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin(
+                "find_mempool_transactions_spending_output",
+            ))
+            .await?;
+        self.inner
+            .find_mempool_transactions_spending_output(outpoint)
+            .await
+    }
+
+    async fn find_mempool_descendants(&self, txid: &Txid) -> Result<Vec<Txid>, Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin("find_mempool_descendants"))
+            .await?;
+        self.inner.find_mempool_descendants(txid).await
+    }
+
+    async fn get_transaction_output(
+        &self,
+        outpoint: &bitcoin::OutPoint,
+        include_mempool: bool,
+    ) -> Result<Option<GetTxOutResult>, Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin("get_transaction_output"))
+            .await?;
+        self.inner
+            .get_transaction_output(outpoint, include_mempool)
+            .await
+    }
+
+    async fn get_transaction_fee(
+        &self,
+        tx: &Txid,
+        lookup_hint: Option<TransactionLookupHint>,
+    ) -> Result<GetTransactionFeeResult, Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin("get_transaction_fee"))
+            .await?;
+        self.inner.get_transaction_fee(tx, lookup_hint).await
+    }
+
+    async fn get_mempool_entry(&self, txid: &Txid) -> Result<Option<GetMempoolEntryResult>, Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin("get_mempool_entry"))
+            .await?;
+        self.inner.get_mempool_entry(txid).await
+    }
+
+    async fn get_blockchain_info(
+        &self,
+    ) -> Result<bitcoincore_rpc_json::GetBlockchainInfoResult, Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin("get_blockchain_info"))
+            .await?;
+        self.inner.get_blockchain_info().await
+    }
+
+    async fn get_network_info(&self) -> Result<bitcoincore_rpc_json::GetNetworkInfoResult, Error> {
+        self.schedule
+            .inject(FaultPoint::Bitcoin("get_network_info"))
+            .await?;
+        self.inner.get_network_info().await
+    }
+}
+
+impl<S> StacksInteract for Faulty<S>
+where
+    S: StacksInteract,
+{
+    async fn get_current_signer_set_info(
+        &self,
+        contract_principal: &StacksAddress,
+    ) -> Result<Option<SignerSetInfo>, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("get_current_signer_set_info"))
+            .await?;
+        self.inner
+            .get_current_signer_set_info(contract_principal)
+            .await
+    }
+
+    async fn get_current_signers_aggregate_key(
+        &self,
+        contract_principal: &StacksAddress,
+    ) -> Result<Option<PublicKey>, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("get_current_signers_aggregate_key"))
+            .await?;
+        self.inner
+            .get_current_signers_aggregate_key(contract_principal)
+            .await
+    }
+
+    async fn is_deposit_completed(
+        &self,
+        contract_principal: &StacksAddress,
+        outpoint: &OutPoint,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("is_deposit_completed"))
+            .await?;
+        self.inner
+            .is_deposit_completed(contract_principal, outpoint)
+            .await
+    }
+
+    async fn is_withdrawal_completed(
+        &self,
+        contract_principal: &StacksAddress,
+        request_id: u64,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("is_withdrawal_completed"))
+            .await?;
+        self.inner
+            .is_withdrawal_completed(contract_principal, request_id)
+            .await
+    }
+
+    async fn get_account(&self, address: &StacksAddress) -> Result<AccountInfo, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("get_account"))
+            .await?;
+        self.inner.get_account(address).await
+    }
+
+    async fn submit_tx(&self, tx: &StacksTransaction) -> Result<SubmitTxResponse, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("submit_tx"))
+            .await?;
+        self.inner.submit_tx(tx).await
+    }
+
+    async fn get_transaction(&self, txid: &StacksTxId) -> Result<Option<StacksTxInfo>, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("get_transaction"))
+            .await?;
+        self.inner.get_transaction(txid).await
+    }
+
+    async fn get_block(&self, block_id: StacksBlockId) -> Result<NakamotoBlock, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("get_block"))
+            .await?;
+        self.inner.get_block(block_id).await
+    }
+
+    async fn get_tenure(&self, block_id: StacksBlockId) -> Result<TenureBlocks, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("get_tenure"))
+            .await?;
+        self.inner.get_tenure(block_id).await
+    }
+
+    async fn get_tenure_info(&self) -> Result<RPCGetTenureInfo, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("get_tenure_info"))
+            .await?;
+        self.inner.get_tenure_info().await
+    }
+
+    async fn get_sortition_info(
+        &self,
+        consensus_hash: &ConsensusHash,
+    ) -> Result<SortitionInfo, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("get_sortition_info"))
+            .await?;
+        self.inner.get_sortition_info(consensus_hash).await
+    }
+
+    async fn estimate_fees<T>(
+        &self,
+        wallet: &SignerWallet,
+        payload: &T,
+        priority: FeePriority,
+    ) -> Result<u64, Error>
+    where
+        T: AsTxPayload + Send + Sync,
+    {
+        self.schedule
+            .inject(FaultPoint::Stacks("estimate_fees"))
+            .await?;
+        self.inner.estimate_fees(wallet, payload, priority).await
+    }
+
+    async fn get_pox_info(&self) -> Result<RPCPoxInfoData, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("get_pox_info"))
+            .await?;
+        self.inner.get_pox_info().await
+    }
+
+    async fn get_node_info(&self) -> Result<RPCPeerInfoData, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("get_node_info"))
+            .await?;
+        self.inner.get_node_info().await
+    }
+
+    async fn get_contract_source(
+        &self,
+        address: &StacksAddress,
+        contract_name: &str,
+    ) -> Result<ContractSrcResponse, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("get_contract_source"))
+            .await?;
+        self.inner.get_contract_source(address, contract_name).await
+    }
+
+    async fn get_sbtc_total_supply(&self, sender: &StacksAddress) -> Result<Amount, Error> {
+        self.schedule
+            .inject(FaultPoint::Stacks("get_sbtc_total_supply"))
+            .await?;
+        self.inner.get_sbtc_total_supply(sender).await
+    }
+}
+
+impl<E> EmilyInteract for Faulty<E>
+where
+    E: EmilyInteract,
+{
+    async fn get_deposit(
+        &self,
+        txid: &BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<CreateDepositRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Emily("get_deposit"))
+            .await?;
+        self.inner.get_deposit(txid, output_index).await
+    }
+
+    /// Faults are injected before the first page of deposits is fetched.
+    fn get_deposits(&self) -> impl Stream<Item = Result<CreateDepositRequest, Error>> + Send {
+        futures::stream::once(self.schedule.inject(FaultPoint::Emily("get_deposits"))).flat_map(
+            move |result| match result {
+                Ok(()) => self.inner.get_deposits().left_stream(),
+                Err(error) => futures::stream::iter(Some(Err(error))).right_stream(),
+            },
+        )
+    }
+
+    async fn get_deposits_with_status(
+        &self,
+        status: DepositStatus,
+    ) -> Result<Vec<CreateDepositRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Emily("get_deposits_with_status"))
+            .await?;
+        self.inner.get_deposits_with_status(status).await
+    }
+
+    async fn accept_deposits<'a>(
+        &'a self,
+        transaction: &'a UnsignedTransaction<'a>,
+    ) -> Result<UpdateDepositsResponse, Error> {
+        self.schedule
+            .inject(FaultPoint::Emily("accept_deposits"))
+            .await?;
+        self.inner.accept_deposits(transaction).await
+    }
+
+    async fn accept_withdrawals<'a>(
+        &'a self,
+        transaction: &'a UnsignedTransaction<'a>,
+    ) -> Result<UpdateWithdrawalsResponse, Error> {
+        self.schedule
+            .inject(FaultPoint::Emily("accept_withdrawals"))
+            .await?;
+        self.inner.accept_withdrawals(transaction).await
+    }
+
+    async fn update_deposits(
+        &self,
+        update_deposits: Vec<DepositUpdate>,
+    ) -> Result<UpdateDepositsResponse, Error> {
+        self.schedule
+            .inject(FaultPoint::Emily("update_deposits"))
+            .await?;
+        self.inner.update_deposits(update_deposits).await
+    }
+
+    async fn update_withdrawals(
+        &self,
+        update_withdrawals: Vec<WithdrawalUpdate>,
+    ) -> Result<UpdateWithdrawalsResponse, Error> {
+        self.schedule
+            .inject(FaultPoint::Emily("update_withdrawals"))
+            .await?;
+        self.inner.update_withdrawals(update_withdrawals).await
+    }
+
+    async fn get_limits(&self) -> Result<SbtcLimits, Error> {
+        self.schedule
+            .inject(FaultPoint::Emily("get_limits"))
+            .await?;
+        self.inner.get_limits().await
+    }
+
+    fn api_keys(&self) -> Option<EmilyApiKeys> {
+        self.inner.api_keys()
+    }
+}
@@ -0,0 +1,379 @@
+//! Fault injection for tests.
+//!
+//! A [`FaultSchedule`] holds a list of [`FaultRule`]s, each of which
+//! matches operations at a [`FaultPoint`] and injects a [`Fault`] into
+//! them. The [`Faulty`] wrapper consults a schedule before each operation
+//! it forwards to the wrapped network, storage, or client, so tests can
+//! exercise failure handling paths, like coordinator handoff and retry
+//! windows, deterministically.
+//!
+//! For example, to drop all WSTS messages from a signer during the third
+//! signing round that it takes part in, where each round starts with a
+//! `NonceRequest`:
+//!
+//! ```ignore
+//! let schedule = FaultSchedule::new();
+//! let round = Arc::new(AtomicUsize::new(0));
+//! schedule.push(FaultRule::messages(Fault::Drop, move |msg| {
+//!     if is_nonce_request(msg) {
+//!         round.fetch_add(1, Ordering::SeqCst);
+//!     }
+//!     msg.signer_public_key == signer_2 && is_wsts_message(msg)
+//!         && round.load(Ordering::SeqCst) == 3
+//! }));
+//!
+//! let network = Faulty::new(network.spawn(), schedule.clone());
+//! ```
+
+mod clients;
+mod network;
+mod storage;
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::context::Clock;
+use crate::error::Error;
+use crate::message::Payload;
+use crate::network::Msg;
+use crate::testing::TestUtilityError;
+
+/// The kind of operation that a fault may be injected into.
+#[derive(Debug, Clone, Copy)]
+pub enum FaultPoint<'a> {
+    /// A message that is about to be broadcast to the network.
+    Broadcast(&'a Msg),
+    /// A message that was received from the network.
+    Receive(&'a Msg),
+    /// A call to the storage method with the given name.
+    Storage(&'static str),
+    /// A call to the bitcoin client method with the given name.
+    Bitcoin(&'static str),
+    /// A call to the stacks client method with the given name.
+    Stacks(&'static str),
+    /// A call to the Emily client method with the given name.
+    Emily(&'static str),
+}
+
+impl<'a> FaultPoint<'a> {
+    /// The message sent or received at this point, if any.
+    pub fn message(&self) -> Option<&'a Msg> {
+        match self {
+            FaultPoint::Broadcast(msg) | FaultPoint::Receive(msg) => Some(msg),
+            _ => None,
+        }
+    }
+
+    /// The name of the method called at this point, if any.
+    pub fn method(&self) -> Option<&'static str> {
+        match self {
+            FaultPoint::Storage(method)
+            | FaultPoint::Bitcoin(method)
+            | FaultPoint::Stacks(method)
+            | FaultPoint::Emily(method) => Some(method),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for FaultPoint<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FaultPoint::Broadcast(msg) => write!(f, "broadcast of {msg}"),
+            FaultPoint::Receive(msg) => write!(f, "receipt of {msg}"),
+            FaultPoint::Storage(method) => write!(f, "storage call {method}"),
+            FaultPoint::Bitcoin(method) => write!(f, "bitcoin client call {method}"),
+            FaultPoint::Stacks(method) => write!(f, "stacks client call {method}"),
+            FaultPoint::Emily(method) => write!(f, "Emily client call {method}"),
+        }
+    }
+}
+
+/// A fault to inject into an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// Delay the operation by the given duration.
+    Delay(Duration),
+    /// Drop the message. Calls to storage or clients cannot be dropped,
+    /// so they fail with an error instead.
+    Drop,
+    /// Fail the operation with an error.
+    Error,
+}
+
+/// The error returned from operations that a [`Fault::Error`] (or a
+/// [`Fault::Drop`] for calls) was injected into.
+#[derive(Debug, thiserror::Error)]
+#[error("injected fault into {0}")]
+pub struct InjectedFault(pub String);
+
+impl InjectedFault {
+    fn error_for(point: &FaultPoint) -> Error {
+        Error::TestUtility(TestUtilityError::from_err(Self(point.to_string())))
+    }
+}
+
+type Matcher = Arc<dyn Fn(&FaultPoint) -> bool + Send + Sync>;
+
+/// A rule that injects a fault into the operations that it matches.
+#[derive(Clone)]
+pub struct FaultRule {
+    matcher: Matcher,
+    fault: Fault,
+    skip: usize,
+    limit: Option<usize>,
+    matched: usize,
+    injected: usize,
+}
+
+impl std::fmt::Debug for FaultRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FaultRule")
+            .field("fault", &self.fault)
+            .field("skip", &self.skip)
+            .field("limit", &self.limit)
+            .field("matched", &self.matched)
+            .field("injected", &self.injected)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FaultRule {
+    /// Create a rule that injects the fault into every operation that the
+    /// matcher returns `true` for.
+    pub fn new<F>(fault: Fault, matcher: F) -> Self
+    where
+        F: Fn(&FaultPoint) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            matcher: Arc::new(matcher),
+            fault,
+            skip: 0,
+            limit: None,
+            matched: 0,
+            injected: 0,
+        }
+    }
+
+    /// Create a rule that injects the fault into the broadcast or receipt
+    /// of every message that the predicate returns `true` for.
+    pub fn messages<F>(fault: Fault, predicate: F) -> Self
+    where
+        F: Fn(&Msg) -> bool + Send + Sync + 'static,
+    {
+        Self::new(fault, move |point| point.message().is_some_and(&predicate))
+    }
+
+    /// Create a rule that injects the fault into the broadcast or receipt
+    /// of every WSTS message sent by the signer with the given public
+    /// key.
+    pub fn wsts_messages_from(fault: Fault, sender: crate::keys::PublicKey) -> Self {
+        Self::messages(fault, move |msg| {
+            msg.signer_public_key == sender && is_wsts_message(msg)
+        })
+    }
+
+    /// Create a rule that injects the fault into every call to the storage
+    /// method with the given name.
+    pub fn storage(fault: Fault, method: &'static str) -> Self {
+        Self::new(
+            fault,
+            move |point| matches!(point, FaultPoint::Storage(name) if *name == method),
+        )
+    }
+
+    /// Let the first `skip` matching operations through before injecting
+    /// the fault.
+    pub fn skip(mut self, skip: usize) -> Self {
+        self.skip = skip;
+        self
+    }
+
+    /// Inject the fault at most `limit` times.
+    pub fn times(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// The number of times that this rule injected its fault.
+    pub fn injected(&self) -> usize {
+        self.injected
+    }
+
+    /// Return the fault to inject if this rule applies to the operation.
+    fn apply(&mut self, point: &FaultPoint) -> Option<Fault> {
+        if self.limit.is_some_and(|limit| self.injected >= limit) || !(self.matcher)(point) {
+            return None;
+        }
+
+        self.matched += 1;
+        if self.matched <= self.skip {
+            return None;
+        }
+
+        self.injected += 1;
+        Some(self.fault)
+    }
+}
+
+/// Whether the message carries a WSTS message.
+pub fn is_wsts_message(msg: &Msg) -> bool {
+    matches!(msg.payload, Payload::WstsMessage(_))
+}
+
+/// A shared, scriptable list of fault rules.
+///
+/// Rules are consulted in the order that they were pushed, and the first
+/// rule that applies to an operation determines the fault injected into
+/// it. Rules may be pushed or cleared while the wrapped components are
+/// running.
+#[derive(Debug, Clone, Default)]
+pub struct FaultSchedule {
+    rules: Arc<Mutex<Vec<FaultRule>>>,
+    clock: Clock,
+}
+
+impl FaultSchedule {
+    /// Create an empty schedule that uses the system clock for delays.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty schedule that uses the given clock for delays.
+    pub fn with_clock(clock: Clock) -> Self {
+        Self {
+            rules: Default::default(),
+            clock,
+        }
+    }
+
+    /// Add a rule to the end of the schedule.
+    pub fn push(&self, rule: FaultRule) {
+        self.rules
+            .lock()
+            .expect("BUG: Failed to acquire lock of fault rules")
+            .push(rule);
+    }
+
+    /// Remove all rules from the schedule.
+    pub fn clear(&self) {
+        self.rules
+            .lock()
+            .expect("BUG: Failed to acquire lock of fault rules")
+            .clear();
+    }
+
+    /// A snapshot of the rules in the schedule, which can be used to check
+    /// how many times each of them injected its fault.
+    pub fn rules(&self) -> Vec<FaultRule> {
+        self.rules
+            .lock()
+            .expect("BUG: Failed to acquire lock of fault rules")
+            .clone()
+    }
+
+    /// Return the fault to inject into the operation, if any.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn next_fault(&self, point: &FaultPoint) -> Option<Fault> {
+        self.rules
+            .lock()
+            .expect("BUG: Failed to acquire lock of fault rules")
+            .iter_mut()
+            .find_map(|rule| rule.apply(point))
+    }
+
+    /// Inject the scheduled fault into a call to storage or a client.
+    ///
+    /// Delays are awaited, while drops and errors are returned as errors.
+    pub async fn inject(&self, point: FaultPoint<'_>) -> Result<(), Error> {
+        match self.next_fault(&point) {
+            None => Ok(()),
+            Some(Fault::Delay(duration)) => {
+                self.clock.sleep(duration).await;
+                Ok(())
+            }
+            Some(Fault::Drop | Fault::Error) => Err(InjectedFault::error_for(&point)),
+        }
+    }
+}
+
+/// A wrapper around a network, storage, or client that injects faults
+/// into the operations forwarded to the wrapped value according to a
+/// [`FaultSchedule`].
+#[derive(Debug, Clone)]
+pub struct Faulty<T> {
+    inner: T,
+    schedule: FaultSchedule,
+}
+
+impl<T> Faulty<T> {
+    /// Wrap the given value.
+    pub fn new(inner: T, schedule: FaultSchedule) -> Self {
+        Self { inner, schedule }
+    }
+
+    /// The wrapped value.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// The schedule consulted by this wrapper.
+    pub fn schedule(&self) -> &FaultSchedule {
+        &self.schedule
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_respect_skip_and_times() {
+        let schedule = FaultSchedule::new();
+        schedule.push(
+            FaultRule::storage(Fault::Error, "get_bitcoin_block")
+                .skip(1)
+                .times(2),
+        );
+
+        let point = FaultPoint::Storage("get_bitcoin_block");
+        let other = FaultPoint::Storage("get_stacks_block");
+
+        assert_eq!(schedule.next_fault(&other), None);
+        assert_eq!(schedule.next_fault(&point), None);
+        assert_eq!(schedule.next_fault(&point), Some(Fault::Error));
+        assert_eq!(schedule.next_fault(&point), Some(Fault::Error));
+        assert_eq!(schedule.next_fault(&point), None);
+        assert_eq!(schedule.rules()[0].injected(), 2);
+    }
+
+    #[tokio::test]
+    async fn delays_use_the_schedule_clock() {
+        let clock = Clock::manual();
+        let schedule = FaultSchedule::with_clock(clock.clone());
+        schedule.push(FaultRule::storage(
+            Fault::Delay(Duration::from_secs(60)),
+            "get_bitcoin_block",
+        ));
+
+        let handle = tokio::spawn({
+            let schedule = schedule.clone();
+            async move {
+                schedule
+                    .inject(FaultPoint::Storage("get_bitcoin_block"))
+                    .await
+            }
+        });
+
+        tokio::task::yield_now().await;
+        assert!(!handle.is_finished());
+        clock.advance(Duration::from_secs(60));
+
+        let result = tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(result.is_ok());
+    }
+}
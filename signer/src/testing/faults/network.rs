@@ -0,0 +1,84 @@
+//! Fault injection for the signer network.
+
+use crate::error::Error;
+use crate::network::MessageTransfer;
+use crate::network::Msg;
+
+use super::Fault;
+use super::FaultPoint;
+use super::Faulty;
+use super::InjectedFault;
+
+impl<N> MessageTransfer for Faulty<N>
+where
+    N: MessageTransfer + Send,
+{
+    /// Broadcast the message unless a fault is scheduled for it. Dropped
+    /// messages are silently discarded, as if they were lost on the way.
+    async fn broadcast(&mut self, msg: Msg) -> Result<(), Error> {
+        match self.schedule.next_fault(&FaultPoint::Broadcast(&msg)) {
+            None => self.inner.broadcast(msg).await,
+            Some(Fault::Delay(duration)) => {
+                self.schedule.clock.sleep(duration).await;
+                self.inner.broadcast(msg).await
+            }
+            Some(Fault::Drop) => Ok(()),
+            Some(Fault::Error) => Err(Error::SendMessage),
+        }
+    }
+
+    /// Receive the next message that is not dropped by the schedule.
+    async fn receive(&mut self) -> Result<Msg, Error> {
+        loop {
+            let msg = self.inner.receive().await?;
+            let point = FaultPoint::Receive(&msg);
+            match self.schedule.next_fault(&point) {
+                None => return Ok(msg),
+                Some(Fault::Delay(duration)) => {
+                    self.schedule.clock.sleep(duration).await;
+                    return Ok(msg);
+                }
+                Some(Fault::Drop) => continue,
+                Some(Fault::Error) => return Err(InjectedFault::error_for(&point)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::network::in_memory2::WanNetwork;
+    use crate::testing::context::TestContext;
+    use crate::testing::faults::FaultRule;
+    use crate::testing::faults::FaultSchedule;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn dropped_messages_are_not_received() {
+        let network = WanNetwork::default();
+        let ctx1 = TestContext::default_mocked();
+        let ctx2 = TestContext::default_mocked();
+
+        let mut sender = network.connect(&ctx1).spawn();
+        let schedule = FaultSchedule::new();
+        let mut receiver = Faulty::new(network.connect(&ctx2).spawn(), schedule.clone());
+
+        let msg1 = Msg::random(&mut rand::rngs::OsRng);
+        let msg2 = Msg::random(&mut rand::rngs::OsRng);
+        let dropped = msg1.clone();
+        schedule.push(FaultRule::messages(Fault::Drop, move |msg| *msg == dropped));
+
+        sender.broadcast(msg1).await.unwrap();
+        sender.broadcast(msg2.clone()).await.unwrap();
+
+        let received = tokio::time::timeout(Duration::from_secs(1), receiver.receive())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(received, msg2);
+        assert_eq!(schedule.rules()[0].injected(), 1);
+    }
+}
@@ -0,0 +1,1547 @@
+//! Fault injection for the signer storage.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use blockstack_lib::types::chainstate::StacksBlockId;
+
+use crate::bitcoin::utxo::SignerUtxo;
+use crate::bitcoin::validation::DepositRequestReport;
+use crate::bitcoin::validation::WithdrawalRequestReport;
+use crate::error::Error;
+use crate::keys::PublicKey;
+use crate::keys::PublicKeyXOnly;
+use crate::storage::DbRead;
+use crate::storage::DbWrite;
+use crate::storage::Transactable;
+use crate::storage::model;
+use crate::storage::model::BitcoinBlockHeight;
+use crate::storage::model::CompletedDepositEvent;
+use crate::storage::model::WithdrawalAcceptEvent;
+use crate::storage::model::WithdrawalRejectEvent;
+
+use super::FaultPoint;
+use super::Faulty;
+
+/// Transactions are forwarded as is, so faults are only injected into
+/// beginning a transaction and not into the operations within it.
+impl<S> Transactable for Faulty<S>
+where
+    S: Transactable + Sync,
+{
+    type Tx<'a>
+        = S::Tx<'a>
+    where
+        Self: 'a;
+
+    async fn begin_transaction(&self) -> Result<Self::Tx<'_>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("begin_transaction"))
+            .await?;
+        self.inner.begin_transaction().await
+    }
+}
+
+impl<S> DbRead for Faulty<S>
+where
+    S: DbRead + Sync,
+{
+    async fn get_bitcoin_block(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::BitcoinBlock>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_bitcoin_block"))
+            .await?;
+        self.inner.get_bitcoin_block(block_hash).await
+    }
+
+    async fn get_stacks_block(
+        &self,
+        block_hash: &model::StacksBlockHash,
+    ) -> Result<Option<model::StacksBlock>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_stacks_block"))
+            .await?;
+        self.inner.get_stacks_block(block_hash).await
+    }
+
+    async fn get_bitcoin_canonical_chain_tip(
+        &self,
+    ) -> Result<Option<model::BitcoinBlockHash>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_bitcoin_canonical_chain_tip"))
+            .await?;
+        self.inner.get_bitcoin_canonical_chain_tip().await
+    }
+
+    async fn get_bitcoin_canonical_chain_tip_ref(
+        &self,
+    ) -> Result<Option<model::BitcoinBlockRef>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_bitcoin_canonical_chain_tip_ref"))
+            .await?;
+        self.inner.get_bitcoin_canonical_chain_tip_ref().await
+    }
+
+    async fn get_stacks_chain_tip(
+        &self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::StacksBlock>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_stacks_chain_tip"))
+            .await?;
+        self.inner.get_stacks_chain_tip(bitcoin_chain_tip).await
+    }
+
+    async fn get_pending_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_pending_deposit_requests"))
+            .await?;
+        self.inner
+            .get_pending_deposit_requests(chain_tip, context_window, signer_public_key)
+            .await
+    }
+
+    async fn get_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_requests"))
+            .await?;
+        self.inner
+            .get_deposit_requests(chain_tip, context_window)
+            .await
+    }
+
+    async fn get_pending_accepted_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockRef,
+        context_window: u16,
+        signatures_required: u16,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_pending_accepted_deposit_requests"))
+            .await?;
+        self.inner
+            .get_pending_accepted_deposit_requests(chain_tip, context_window, signatures_required)
+            .await
+    }
+
+    async fn deposit_request_exists(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("deposit_request_exists"))
+            .await?;
+        self.inner.deposit_request_exists(txid, output_index).await
+    }
+
+    async fn get_deposit_request_report(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        signer_public_key: &PublicKey,
+    ) -> Result<Option<DepositRequestReport>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_request_report"))
+            .await?;
+        self.inner
+            .get_deposit_request_report(chain_tip, txid, output_index, signer_public_key)
+            .await
+    }
+
+    async fn get_deposit_signers(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::DepositSigner>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_signers"))
+            .await?;
+        self.inner.get_deposit_signers(txid, output_index).await
+    }
+
+    async fn get_deposit_signer_decisions(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::DepositSigner>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_signer_decisions"))
+            .await?;
+        self.inner
+            .get_deposit_signer_decisions(chain_tip, context_window, signer_public_key)
+            .await
+    }
+
+    async fn get_deposit_signer_decisions_for_outpoints(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::DepositSigner>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage(
+                "get_deposit_signer_decisions_for_outpoints",
+            ))
+            .await?;
+        self.inner
+            .get_deposit_signer_decisions_for_outpoints(outpoints, signer_public_key)
+            .await
+    }
+
+    async fn get_withdrawal_signer_decisions(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::WithdrawalSigner>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_withdrawal_signer_decisions"))
+            .await?;
+        self.inner
+            .get_withdrawal_signer_decisions(chain_tip, context_window, signer_public_key)
+            .await
+    }
+
+    async fn can_sign_deposit_tx(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        signer_public_key: &PublicKey,
+    ) -> Result<Option<bool>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("can_sign_deposit_tx"))
+            .await?;
+        self.inner
+            .can_sign_deposit_tx(txid, output_index, signer_public_key)
+            .await
+    }
+
+    async fn get_withdrawal_signers(
+        &self,
+        request_id: u64,
+        block_hash: &model::StacksBlockHash,
+    ) -> Result<Vec<model::WithdrawalSigner>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_withdrawal_signers"))
+            .await?;
+        self.inner
+            .get_withdrawal_signers(request_id, block_hash)
+            .await
+    }
+
+    async fn get_pending_withdrawal_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_pending_withdrawal_requests"))
+            .await?;
+        self.inner
+            .get_pending_withdrawal_requests(chain_tip, context_window, signer_public_key)
+            .await
+    }
+
+    async fn get_decided_withdrawal_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+        signer_public_key: &PublicKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_decided_withdrawal_requests"))
+            .await?;
+        self.inner
+            .get_decided_withdrawal_requests(chain_tip, context_window, signer_public_key)
+            .await
+    }
+
+    async fn get_pending_accepted_withdrawal_requests(
+        &self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+        stacks_chain_tip: &model::StacksBlockHash,
+        min_bitcoin_height: BitcoinBlockHeight,
+        signature_threshold: u16,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage(
+                "get_pending_accepted_withdrawal_requests",
+            ))
+            .await?;
+        self.inner
+            .get_pending_accepted_withdrawal_requests(
+                bitcoin_chain_tip,
+                stacks_chain_tip,
+                min_bitcoin_height,
+                signature_threshold,
+            )
+            .await
+    }
+
+    async fn get_pending_rejected_withdrawal_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockRef,
+        context_window: u16,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage(
+                "get_pending_rejected_withdrawal_requests",
+            ))
+            .await?;
+        self.inner
+            .get_pending_rejected_withdrawal_requests(chain_tip, context_window)
+            .await
+    }
+
+    async fn get_withdrawal_request_report(
+        &self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+        stacks_chain_tip: &model::StacksBlockHash,
+        id: &model::QualifiedRequestId,
+        signer_public_key: &PublicKey,
+    ) -> Result<Option<WithdrawalRequestReport>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_withdrawal_request_report"))
+            .await?;
+        self.inner
+            .get_withdrawal_request_report(
+                bitcoin_chain_tip,
+                stacks_chain_tip,
+                id,
+                signer_public_key,
+            )
+            .await
+    }
+
+    async fn get_withdrawal_requests_by_recipient(
+        &self,
+        recipient: &model::ScriptPubKey,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_withdrawal_requests_by_recipient"))
+            .await?;
+        self.inner
+            .get_withdrawal_requests_by_recipient(recipient)
+            .await
+    }
+
+    async fn get_withdrawal_requests_by_sender(
+        &self,
+        sender: &model::StacksPrincipal,
+    ) -> Result<Vec<model::WithdrawalRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_withdrawal_requests_by_sender"))
+            .await?;
+        self.inner.get_withdrawal_requests_by_sender(sender).await
+    }
+
+    async fn get_withdrawal_request(
+        &self,
+        request_id: u64,
+        block_hash: &model::StacksBlockHash,
+    ) -> Result<Option<model::WithdrawalRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_withdrawal_request"))
+            .await?;
+        self.inner
+            .get_withdrawal_request(request_id, block_hash)
+            .await
+    }
+
+    async fn compute_withdrawn_total(
+        &self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+    ) -> Result<u64, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("compute_withdrawn_total"))
+            .await?;
+        self.inner
+            .compute_withdrawn_total(bitcoin_chain_tip, context_window)
+            .await
+    }
+
+    async fn get_bitcoin_blocks_with_transaction(
+        &self,
+        txid: &model::BitcoinTxId,
+    ) -> Result<Vec<model::BitcoinBlockHash>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_bitcoin_blocks_with_transaction"))
+            .await?;
+        self.inner.get_bitcoin_blocks_with_transaction(txid).await
+    }
+
+    async fn stacks_block_exists(&self, block_id: StacksBlockId) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("stacks_block_exists"))
+            .await?;
+        self.inner.stacks_block_exists(block_id).await
+    }
+
+    async fn get_encrypted_dkg_shares<X>(
+        &self,
+        aggregate_key: X,
+    ) -> Result<Option<model::EncryptedDkgShares>, Error>
+    where
+        X: Into<PublicKeyXOnly> + Send,
+    {
+        self.schedule
+            .inject(FaultPoint::Storage("get_encrypted_dkg_shares"))
+            .await?;
+        self.inner.get_encrypted_dkg_shares(aggregate_key).await
+    }
+
+    async fn get_latest_encrypted_dkg_shares(
+        &self,
+    ) -> Result<Option<model::EncryptedDkgShares>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_latest_encrypted_dkg_shares"))
+            .await?;
+        self.inner.get_latest_encrypted_dkg_shares().await
+    }
+
+    async fn get_all_encrypted_dkg_shares(&self) -> Result<Vec<model::EncryptedDkgShares>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_all_encrypted_dkg_shares"))
+            .await?;
+        self.inner.get_all_encrypted_dkg_shares().await
+    }
+
+    async fn get_latest_verified_dkg_shares(
+        &self,
+    ) -> Result<Option<model::EncryptedDkgShares>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_latest_verified_dkg_shares"))
+            .await?;
+        self.inner.get_latest_verified_dkg_shares().await
+    }
+
+    async fn get_aggregate_key_record(
+        &self,
+        aggregate_key: &PublicKey,
+    ) -> Result<Option<model::AggregateKeyRecord>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_aggregate_key_record"))
+            .await?;
+        self.inner.get_aggregate_key_record(aggregate_key).await
+    }
+
+    async fn get_aggregate_key_records(&self) -> Result<Vec<model::AggregateKeyRecord>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_aggregate_key_records"))
+            .await?;
+        self.inner.get_aggregate_key_records().await
+    }
+
+    async fn get_encrypted_dkg_shares_count(&self) -> Result<u32, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_encrypted_dkg_shares_count"))
+            .await?;
+        self.inner.get_encrypted_dkg_shares_count().await
+    }
+
+    async fn get_last_key_rotation(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::KeyRotationEvent>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_last_key_rotation"))
+            .await?;
+        self.inner.get_last_key_rotation(chain_tip).await
+    }
+
+    async fn key_rotation_exists(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        signer_set: &BTreeSet<PublicKey>,
+        aggregate_key: &PublicKey,
+        signatures_required: u16,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("key_rotation_exists"))
+            .await?;
+        self.inner
+            .key_rotation_exists(chain_tip, signer_set, aggregate_key, signatures_required)
+            .await
+    }
+
+    async fn get_signers_script_pubkeys(&self) -> Result<Vec<model::Bytes>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_signers_script_pubkeys"))
+            .await?;
+        self.inner.get_signers_script_pubkeys().await
+    }
+
+    async fn get_signer_utxo(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Option<SignerUtxo>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_signer_utxo"))
+            .await?;
+        self.inner.get_signer_utxo(chain_tip).await
+    }
+
+    async fn get_deposit_request_signer_votes(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        aggregate_key: &PublicKey,
+    ) -> Result<model::SignerVotes, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_request_signer_votes"))
+            .await?;
+        self.inner
+            .get_deposit_request_signer_votes(txid, output_index, aggregate_key)
+            .await
+    }
+
+    async fn get_deposit_requests_signer_votes(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+        aggregate_key: &PublicKey,
+    ) -> Result<HashMap<bitcoin::OutPoint, model::SignerVotes>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_requests_signer_votes"))
+            .await?;
+        self.inner
+            .get_deposit_requests_signer_votes(outpoints, aggregate_key)
+            .await
+    }
+
+    async fn get_withdrawal_request_signer_votes(
+        &self,
+        id: &model::QualifiedRequestId,
+        aggregate_key: &PublicKey,
+    ) -> Result<model::SignerVotes, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_withdrawal_request_signer_votes"))
+            .await?;
+        self.inner
+            .get_withdrawal_request_signer_votes(id, aggregate_key)
+            .await
+    }
+
+    async fn is_known_bitcoin_block_hash(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("is_known_bitcoin_block_hash"))
+            .await?;
+        self.inner.is_known_bitcoin_block_hash(block_hash).await
+    }
+
+    async fn in_canonical_bitcoin_blockchain(
+        &self,
+        chain_tip: &model::BitcoinBlockRef,
+        block_ref: &model::BitcoinBlockRef,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("in_canonical_bitcoin_blockchain"))
+            .await?;
+        self.inner
+            .in_canonical_bitcoin_blockchain(chain_tip, block_ref)
+            .await
+    }
+
+    async fn is_signer_script_pub_key(&self, script: &model::ScriptPubKey) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("is_signer_script_pub_key"))
+            .await?;
+        self.inner.is_signer_script_pub_key(script).await
+    }
+
+    async fn is_withdrawal_inflight(
+        &self,
+        id: &model::QualifiedRequestId,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("is_withdrawal_inflight"))
+            .await?;
+        self.inner
+            .is_withdrawal_inflight(id, bitcoin_chain_tip)
+            .await
+    }
+
+    async fn is_withdrawal_active(
+        &self,
+        id: &model::QualifiedRequestId,
+        bitcoin_chain_tip: &model::BitcoinBlockRef,
+        min_confirmations: u64,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("is_withdrawal_active"))
+            .await?;
+        self.inner
+            .is_withdrawal_active(id, bitcoin_chain_tip, min_confirmations)
+            .await
+    }
+
+    async fn get_swept_deposit_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+    ) -> Result<Vec<model::SweptDepositRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_swept_deposit_requests"))
+            .await?;
+        self.inner
+            .get_swept_deposit_requests(chain_tip, context_window)
+            .await
+    }
+
+    async fn get_swept_withdrawal_requests(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        context_window: u16,
+    ) -> Result<Vec<model::SweptWithdrawalRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_swept_withdrawal_requests"))
+            .await?;
+        self.inner
+            .get_swept_withdrawal_requests(chain_tip, context_window)
+            .await
+    }
+
+    async fn get_deposit_request(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_request"))
+            .await?;
+        self.inner.get_deposit_request(txid, output_index).await
+    }
+
+    async fn get_deposit_requests_by_recipient(
+        &self,
+        recipient: &model::StacksPrincipal,
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_requests_by_recipient"))
+            .await?;
+        self.inner
+            .get_deposit_requests_by_recipient(recipient)
+            .await
+    }
+
+    async fn get_completed_deposit_events(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<CompletedDepositEvent>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_completed_deposit_events"))
+            .await?;
+        self.inner
+            .get_completed_deposit_events(txid, output_index)
+            .await
+    }
+
+    async fn will_sign_bitcoin_tx_sighash(
+        &self,
+        sighash: &model::SigHash,
+    ) -> Result<Option<(bool, PublicKeyXOnly)>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("will_sign_bitcoin_tx_sighash"))
+            .await?;
+        self.inner.will_sign_bitcoin_tx_sighash(sighash).await
+    }
+
+    async fn get_coordinator_attestations(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::CoordinatorAttestation>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_coordinator_attestations"))
+            .await?;
+        self.inner.get_coordinator_attestations(chain_tip).await
+    }
+
+    async fn get_deposit_decision_receipts(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::DepositDecisionReceipt>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_decision_receipts"))
+            .await?;
+        self.inner
+            .get_deposit_decision_receipts(txid, output_index)
+            .await
+    }
+
+    async fn get_deposit_rejection(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositRejection>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_rejection"))
+            .await?;
+        self.inner.get_deposit_rejection(txid, output_index).await
+    }
+
+    async fn get_deposit_expiration(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositExpiration>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_expiration"))
+            .await?;
+        self.inner.get_deposit_expiration(txid, output_index).await
+    }
+
+    async fn get_deposit_requests_for_outpoints(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+    ) -> Result<Vec<model::DepositRequest>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_requests_for_outpoints"))
+            .await?;
+        self.inner
+            .get_deposit_requests_for_outpoints(outpoints)
+            .await
+    }
+
+    async fn get_deposit_reclaim(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::DepositReclaim>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_reclaim"))
+            .await?;
+        self.inner
+            .get_deposit_reclaim(chain_tip, txid, output_index)
+            .await
+    }
+
+    async fn get_deposit_assessed_fee(
+        &self,
+        sweep_txid: &model::BitcoinTxId,
+        sweep_block_hash: &model::BitcoinBlockHash,
+        outpoint: &bitcoin::OutPoint,
+    ) -> Result<Option<u64>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_assessed_fee"))
+            .await?;
+        self.inner
+            .get_deposit_assessed_fee(sweep_txid, sweep_block_hash, outpoint)
+            .await
+    }
+
+    async fn get_latest_tenure_plan(&self) -> Result<Option<model::TenurePlan>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_latest_tenure_plan"))
+            .await?;
+        self.inner.get_latest_tenure_plan().await
+    }
+
+    async fn get_bitcoin_block_processing(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+    ) -> Result<Option<model::BitcoinBlockProcessing>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_bitcoin_block_processing"))
+            .await?;
+        self.inner.get_bitcoin_block_processing(block_hash).await
+    }
+
+    async fn get_stacks_tx_submissions(
+        &self,
+        request_keys: &[String],
+    ) -> Result<Vec<model::StacksTxSubmission>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_stacks_tx_submissions"))
+            .await?;
+        self.inner.get_stacks_tx_submissions(request_keys).await
+    }
+
+    async fn get_pending_stacks_tx_submissions(
+        &self,
+    ) -> Result<Vec<model::StacksTxSubmission>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_pending_stacks_tx_submissions"))
+            .await?;
+        self.inner.get_pending_stacks_tx_submissions().await
+    }
+
+    async fn get_unsettled_bitcoin_broadcasts(
+        &self,
+    ) -> Result<Vec<model::BitcoinBroadcast>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_unsettled_bitcoin_broadcasts"))
+            .await?;
+        self.inner.get_unsettled_bitcoin_broadcasts().await
+    }
+
+    async fn get_quarantined_stacks_blocks(
+        &self,
+    ) -> Result<Vec<model::QuarantinedStacksBlock>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_quarantined_stacks_blocks"))
+            .await?;
+        self.inner.get_quarantined_stacks_blocks().await
+    }
+
+    async fn get_conflicting_signed_sighash(
+        &self,
+        sighash: &model::SigHash,
+    ) -> Result<Option<model::SigHash>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_conflicting_signed_sighash"))
+            .await?;
+        self.inner.get_conflicting_signed_sighash(sighash).await
+    }
+
+    async fn get_mint_mismatches(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Vec<model::MintMismatch>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_mint_mismatches"))
+            .await?;
+        self.inner.get_mint_mismatches(txid, output_index).await
+    }
+
+    async fn get_withdrawal_sweep_txids(
+        &self,
+        id: &model::QualifiedRequestId,
+    ) -> Result<Vec<model::BitcoinTxId>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_withdrawal_sweep_txids"))
+            .await?;
+        self.inner.get_withdrawal_sweep_txids(id).await
+    }
+
+    async fn get_signer_payment(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<Option<model::SignerPayment>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_signer_payment"))
+            .await?;
+        self.inner.get_signer_payment(txid, output_index).await
+    }
+
+    async fn get_signer_payments(
+        &self,
+        status: model::SignerPaymentStatus,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_signer_payments"))
+            .await?;
+        self.inner.get_signer_payments(status).await
+    }
+
+    async fn get_pending_refunds(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_pending_refunds"))
+            .await?;
+        self.inner.get_pending_refunds(chain_tip).await
+    }
+
+    async fn get_unspent_signer_payments(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<Vec<model::SignerPayment>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_unspent_signer_payments"))
+            .await?;
+        self.inner.get_unspent_signer_payments(chain_tip).await
+    }
+
+    async fn get_latest_dkg_begin_refusal(&self) -> Result<Option<model::DkgBeginRefusal>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_latest_dkg_begin_refusal"))
+            .await?;
+        self.inner.get_latest_dkg_begin_refusal().await
+    }
+
+    async fn get_latest_signer_key_rotation(
+        &self,
+    ) -> Result<Option<model::SignerKeyRotation>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_latest_signer_key_rotation"))
+            .await?;
+        self.inner.get_latest_signer_key_rotation().await
+    }
+
+    async fn get_key_endorsements(&self) -> Result<Vec<model::KeyEndorsement>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_key_endorsements"))
+            .await?;
+        self.inner.get_key_endorsements().await
+    }
+
+    async fn get_emergency_recovery_approvals(
+        &self,
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> Result<Vec<model::EmergencyRecoveryApproval>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_emergency_recovery_approvals"))
+            .await?;
+        self.inner
+            .get_emergency_recovery_approvals(recovery_script_pubkey)
+            .await
+    }
+
+    async fn is_emergency_recovery_swept(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+        recovery_script_pubkey: &model::ScriptPubKey,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("is_emergency_recovery_swept"))
+            .await?;
+        self.inner
+            .is_emergency_recovery_swept(chain_tip, recovery_script_pubkey)
+            .await
+    }
+
+    async fn get_deposit_decision_acknowledgements(
+        &self,
+        outpoints: &[bitcoin::OutPoint],
+    ) -> Result<Vec<model::DepositDecisionAcknowledgement>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_decision_acknowledgements"))
+            .await?;
+        self.inner
+            .get_deposit_decision_acknowledgements(outpoints)
+            .await
+    }
+
+    async fn get_withdrawal_decision_acknowledgements(
+        &self,
+        ids: &[model::QualifiedRequestId],
+    ) -> Result<Vec<model::WithdrawalDecisionAcknowledgement>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage(
+                "get_withdrawal_decision_acknowledgements",
+            ))
+            .await?;
+        self.inner
+            .get_withdrawal_decision_acknowledgements(ids)
+            .await
+    }
+
+    async fn is_coordinator_paused(&self) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("is_coordinator_paused"))
+            .await?;
+        self.inner.is_coordinator_paused().await
+    }
+
+    async fn get_deposit_decision_overrides(
+        &self,
+    ) -> Result<Vec<model::DepositDecisionOverride>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_deposit_decision_overrides"))
+            .await?;
+        self.inner.get_deposit_decision_overrides().await
+    }
+
+    async fn get_withdrawal_decision_overrides(
+        &self,
+    ) -> Result<Vec<model::WithdrawalDecisionOverride>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("get_withdrawal_decision_overrides"))
+            .await?;
+        self.inner.get_withdrawal_decision_overrides().await
+    }
+}
+
+impl<S> DbWrite for Faulty<S>
+where
+    S: DbWrite + Sync,
+{
+    async fn write_bitcoin_block(&self, block: &model::BitcoinBlock) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_bitcoin_block"))
+            .await?;
+        self.inner.write_bitcoin_block(block).await
+    }
+
+    async fn write_stacks_block(&self, block: &model::StacksBlock) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_stacks_block"))
+            .await?;
+        self.inner.write_stacks_block(block).await
+    }
+
+    async fn write_deposit_request(
+        &self,
+        deposit_request: &model::DepositRequest,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_deposit_request"))
+            .await?;
+        self.inner.write_deposit_request(deposit_request).await
+    }
+
+    async fn write_deposit_requests(
+        &self,
+        deposit_requests: Vec<model::DepositRequest>,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_deposit_requests"))
+            .await?;
+        self.inner.write_deposit_requests(deposit_requests).await
+    }
+
+    async fn write_withdrawal_request(
+        &self,
+        request: &model::WithdrawalRequest,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_withdrawal_request"))
+            .await?;
+        self.inner.write_withdrawal_request(request).await
+    }
+
+    async fn write_deposit_signer_decision(
+        &self,
+        decision: &model::DepositSigner,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_deposit_signer_decision"))
+            .await?;
+        self.inner.write_deposit_signer_decision(decision).await
+    }
+
+    async fn write_withdrawal_signer_decision(
+        &self,
+        decision: &model::WithdrawalSigner,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_withdrawal_signer_decision"))
+            .await?;
+        self.inner.write_withdrawal_signer_decision(decision).await
+    }
+
+    async fn write_bitcoin_transaction(
+        &self,
+        bitcoin_transaction: &model::BitcoinTxRef,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_bitcoin_transaction"))
+            .await?;
+        self.inner
+            .write_bitcoin_transaction(bitcoin_transaction)
+            .await
+    }
+
+    async fn write_bitcoin_transactions(&self, txs: Vec<model::BitcoinTxRef>) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_bitcoin_transactions"))
+            .await?;
+        self.inner.write_bitcoin_transactions(txs).await
+    }
+
+    async fn write_stacks_block_headers(
+        &self,
+        headers: Vec<model::StacksBlock>,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_stacks_block_headers"))
+            .await?;
+        self.inner.write_stacks_block_headers(headers).await
+    }
+
+    async fn write_encrypted_dkg_shares(
+        &self,
+        shares: &model::EncryptedDkgShares,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_encrypted_dkg_shares"))
+            .await?;
+        self.inner.write_encrypted_dkg_shares(shares).await
+    }
+
+    async fn write_rotate_keys_transaction(
+        &self,
+        key_rotation: &model::KeyRotationEvent,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_rotate_keys_transaction"))
+            .await?;
+        self.inner.write_rotate_keys_transaction(key_rotation).await
+    }
+
+    async fn register_aggregate_key(
+        &self,
+        shares: &model::EncryptedDkgShares,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("register_aggregate_key"))
+            .await?;
+        self.inner.register_aggregate_key(shares).await
+    }
+
+    async fn write_aggregate_key_rotation(
+        &self,
+        aggregate_key: &PublicKey,
+        txid: &model::StacksTxId,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_aggregate_key_rotation"))
+            .await?;
+        self.inner
+            .write_aggregate_key_rotation(aggregate_key, txid)
+            .await
+    }
+
+    async fn activate_aggregate_key(
+        &self,
+        aggregate_key: &PublicKey,
+        height: model::BitcoinBlockHeight,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("activate_aggregate_key"))
+            .await?;
+        self.inner
+            .activate_aggregate_key(aggregate_key, height)
+            .await
+    }
+
+    async fn write_withdrawal_reject_event(
+        &self,
+        event: &WithdrawalRejectEvent,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_withdrawal_reject_event"))
+            .await?;
+        self.inner.write_withdrawal_reject_event(event).await
+    }
+
+    async fn write_withdrawal_accept_event(
+        &self,
+        event: &WithdrawalAcceptEvent,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_withdrawal_accept_event"))
+            .await?;
+        self.inner.write_withdrawal_accept_event(event).await
+    }
+
+    async fn write_completed_deposit_event(
+        &self,
+        event: &CompletedDepositEvent,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_completed_deposit_event"))
+            .await?;
+        self.inner.write_completed_deposit_event(event).await
+    }
+
+    async fn write_tx_output(&self, output: &model::TxOutput) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_tx_output"))
+            .await?;
+        self.inner.write_tx_output(output).await
+    }
+
+    async fn write_withdrawal_tx_output(
+        &self,
+        output: &model::WithdrawalTxOutput,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_withdrawal_tx_output"))
+            .await?;
+        self.inner.write_withdrawal_tx_output(output).await
+    }
+
+    async fn write_tx_prevout(&self, prevout: &model::TxPrevout) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_tx_prevout"))
+            .await?;
+        self.inner.write_tx_prevout(prevout).await
+    }
+
+    async fn write_bitcoin_txs_sighashes(
+        &self,
+        sighashes: &[model::BitcoinTxSigHash],
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_bitcoin_txs_sighashes"))
+            .await?;
+        self.inner.write_bitcoin_txs_sighashes(sighashes).await
+    }
+
+    async fn write_bitcoin_withdrawals_outputs(
+        &self,
+        withdrawals_outputs: &[model::BitcoinWithdrawalOutput],
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_bitcoin_withdrawals_outputs"))
+            .await?;
+        self.inner
+            .write_bitcoin_withdrawals_outputs(withdrawals_outputs)
+            .await
+    }
+
+    async fn write_coordinator_attestation(
+        &self,
+        attestation: &model::CoordinatorAttestation,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_coordinator_attestation"))
+            .await?;
+        self.inner.write_coordinator_attestation(attestation).await
+    }
+
+    async fn write_deposit_decision_receipt(
+        &self,
+        receipt: &model::DepositDecisionReceipt,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_deposit_decision_receipt"))
+            .await?;
+        self.inner.write_deposit_decision_receipt(receipt).await
+    }
+
+    async fn write_deposit_rejection(
+        &self,
+        rejection: &model::DepositRejection,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_deposit_rejection"))
+            .await?;
+        self.inner.write_deposit_rejection(rejection).await
+    }
+
+    async fn write_deposit_expiration(
+        &self,
+        expiration: &model::DepositExpiration,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_deposit_expiration"))
+            .await?;
+        self.inner.write_deposit_expiration(expiration).await
+    }
+
+    async fn write_deposit_reclaim(&self, reclaim: &model::DepositReclaim) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_deposit_reclaim"))
+            .await?;
+        self.inner.write_deposit_reclaim(reclaim).await
+    }
+
+    async fn reactivate_deposit_request(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("reactivate_deposit_request"))
+            .await?;
+        self.inner
+            .reactivate_deposit_request(txid, output_index)
+            .await
+    }
+
+    async fn write_stacks_tx_submission(
+        &self,
+        submission: &model::StacksTxSubmission,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_stacks_tx_submission"))
+            .await?;
+        self.inner.write_stacks_tx_submission(submission).await
+    }
+
+    async fn set_stacks_tx_submission_status(
+        &self,
+        txid: &model::StacksTxId,
+        status: model::StacksTxStatus,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("set_stacks_tx_submission_status"))
+            .await?;
+        self.inner
+            .set_stacks_tx_submission_status(txid, status)
+            .await
+    }
+
+    async fn write_bitcoin_broadcasts(
+        &self,
+        broadcasts: &[model::BitcoinBroadcast],
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_bitcoin_broadcasts"))
+            .await?;
+        self.inner.write_bitcoin_broadcasts(broadcasts).await
+    }
+
+    async fn record_bitcoin_broadcast_attempt(
+        &self,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+        last_error: Option<&str>,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("record_bitcoin_broadcast_attempt"))
+            .await?;
+        self.inner
+            .record_bitcoin_broadcast_attempt(txid, status, last_error)
+            .await
+    }
+
+    async fn set_bitcoin_broadcast_status(
+        &self,
+        txid: &model::BitcoinTxId,
+        status: model::BitcoinBroadcastStatus,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("set_bitcoin_broadcast_status"))
+            .await?;
+        self.inner.set_bitcoin_broadcast_status(txid, status).await
+    }
+
+    async fn write_quarantined_stacks_block(
+        &self,
+        block: &model::QuarantinedStacksBlock,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_quarantined_stacks_block"))
+            .await?;
+        self.inner.write_quarantined_stacks_block(block).await
+    }
+
+    async fn write_signed_sighash(&self, sighash: &model::SigHash) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_signed_sighash"))
+            .await?;
+        self.inner.write_signed_sighash(sighash).await
+    }
+
+    async fn write_mint_mismatch(&self, mismatch: &model::MintMismatch) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_mint_mismatch"))
+            .await?;
+        self.inner.write_mint_mismatch(mismatch).await
+    }
+
+    async fn write_signer_payment(&self, payment: &model::SignerPayment) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_signer_payment"))
+            .await?;
+        self.inner.write_signer_payment(payment).await
+    }
+
+    async fn set_signer_payment_status(
+        &self,
+        txid: &model::BitcoinTxId,
+        output_index: u32,
+        status: model::SignerPaymentStatus,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("set_signer_payment_status"))
+            .await?;
+        self.inner
+            .set_signer_payment_status(txid, output_index, status)
+            .await
+    }
+
+    async fn lock_prevouts(
+        &self,
+        locks: &[model::PrevoutLock],
+    ) -> Result<Option<model::PrevoutLock>, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("lock_prevouts"))
+            .await?;
+        self.inner.lock_prevouts(locks).await
+    }
+
+    async fn unlock_prevouts(&self, txid: &model::BitcoinTxId) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("unlock_prevouts"))
+            .await?;
+        self.inner.unlock_prevouts(txid).await
+    }
+
+    async fn write_tenure_plan(&self, plan: &model::TenurePlan) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_tenure_plan"))
+            .await?;
+        self.inner.write_tenure_plan(plan).await
+    }
+
+    async fn write_block_processing_stage(
+        &self,
+        block_hash: &model::BitcoinBlockHash,
+        stage: model::BlockProcessingStage,
+        at: model::Timestamp,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_block_processing_stage"))
+            .await?;
+        self.inner
+            .write_block_processing_stage(block_hash, stage, at)
+            .await
+    }
+
+    async fn write_dkg_begin_refusal(&self, refusal: &model::DkgBeginRefusal) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_dkg_begin_refusal"))
+            .await?;
+        self.inner.write_dkg_begin_refusal(refusal).await
+    }
+
+    async fn write_signer_key_rotation(
+        &self,
+        rotation: &model::SignerKeyRotation,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_signer_key_rotation"))
+            .await?;
+        self.inner.write_signer_key_rotation(rotation).await
+    }
+
+    async fn set_signer_key_rotation_status(
+        &self,
+        new_public_key: &PublicKey,
+        status: model::SignerKeyRotationStatus,
+    ) -> Result<bool, Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("set_signer_key_rotation_status"))
+            .await?;
+        self.inner
+            .set_signer_key_rotation_status(new_public_key, status)
+            .await
+    }
+
+    async fn write_key_endorsement(
+        &self,
+        endorsement: &model::KeyEndorsement,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_key_endorsement"))
+            .await?;
+        self.inner.write_key_endorsement(endorsement).await
+    }
+
+    async fn write_emergency_recovery_approval(
+        &self,
+        approval: &model::EmergencyRecoveryApproval,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_emergency_recovery_approval"))
+            .await?;
+        self.inner.write_emergency_recovery_approval(approval).await
+    }
+
+    async fn write_emergency_recovery_sweep(
+        &self,
+        sweep: &model::EmergencyRecoverySweep,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("write_emergency_recovery_sweep"))
+            .await?;
+        self.inner.write_emergency_recovery_sweep(sweep).await
+    }
+
+    async fn write_deposit_decision_acknowledgement(
+        &self,
+        ack: &model::DepositDecisionAcknowledgement,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage(
+                "write_deposit_decision_acknowledgement",
+            ))
+            .await?;
+        self.inner.write_deposit_decision_acknowledgement(ack).await
+    }
+
+    async fn write_withdrawal_decision_acknowledgement(
+        &self,
+        ack: &model::WithdrawalDecisionAcknowledgement,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage(
+                "write_withdrawal_decision_acknowledgement",
+            ))
+            .await?;
+        self.inner
+            .write_withdrawal_decision_acknowledgement(ack)
+            .await
+    }
+
+    async fn set_coordinator_paused(&self, paused: bool) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("set_coordinator_paused"))
+            .await?;
+        self.inner.set_coordinator_paused(paused).await
+    }
+
+    async fn set_deposit_decision_override(
+        &self,
+        outpoint: &bitcoin::OutPoint,
+        can_accept: Option<bool>,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("set_deposit_decision_override"))
+            .await?;
+        self.inner
+            .set_deposit_decision_override(outpoint, can_accept)
+            .await
+    }
+
+    async fn set_withdrawal_decision_override(
+        &self,
+        id: &model::QualifiedRequestId,
+        accept: Option<bool>,
+    ) -> Result<(), Error> {
+        self.schedule
+            .inject(FaultPoint::Storage("set_withdrawal_decision_override"))
+            .await?;
+        self.inner
+            .set_withdrawal_decision_override(id, accept)
+            .await
+    }
+
+    async fn revoke_dkg_shares<X>(&self, aggregate_key: X) -> Result<bool, Error>
+    where
+        X: Into<PublicKeyXOnly> + Send,
+    {
+        self.schedule
+            .inject(FaultPoint::Storage("revoke_dkg_shares"))
+            .await?;
+        self.inner.revoke_dkg_shares(aggregate_key).await
+    }
+
+    async fn verify_dkg_shares<X>(&self, aggregate_key: X) -> Result<bool, Error>
+    where
+        X: Into<PublicKeyXOnly> + Send,
+    {
+        self.schedule
+            .inject(FaultPoint::Storage("verify_dkg_shares"))
+            .await?;
+        self.inner.verify_dkg_shares(aggregate_key).await
+    }
+}
@@ -50,6 +50,18 @@ impl fake::Dummy<fake::Faker> for message::Payload {
             dummy_payload::<message::StacksTransactionSignature, _>,
             dummy_payload::<message::WstsMessage, _>,
             dummy_payload::<message::BitcoinPreSignRequest, _>,
+            dummy_payload::<message::CoordinatorAttestation, _>,
+            dummy_payload::<message::DatabaseDigest, _>,
+            dummy_payload::<message::DepositDecisionRetryRequest, _>,
+            dummy_payload::<message::SignerDecisionBatch, _>,
+            dummy_payload::<message::MessageRedeliveryRequest, _>,
+            dummy_payload::<message::RedeliveredMessages, _>,
+            dummy_payload::<message::SignRequestRefusal, _>,
+            dummy_payload::<message::LimitOverrideVote, _>,
+            dummy_payload::<message::SignerKeyEndorsement, _>,
+            dummy_payload::<message::EmergencyRecoveryApproval, _>,
+            dummy_payload::<message::DecisionAcknowledgement, _>,
+            dummy_payload::<message::EmergencyRecoveryRevocation, _>,
         ];
         variants.choose(rng).unwrap()(config, rng)
     }
@@ -70,6 +82,25 @@ impl fake::Dummy<fake::Faker> for message::SignerDepositDecision {
             txid: dummy::txid(config, rng),
             can_accept: config.fake_with_rng(rng),
             can_sign: config.fake_with_rng(rng),
+            receipt: config
+                .fake_with_rng::<bool, _>(rng)
+                .then(|| dummy::Unit.fake_with_rng(rng)),
+            decision_version: config.fake_with_rng(rng),
+        }
+    }
+}
+
+impl fake::Dummy<fake::Faker> for message::SignerDecisionBatch {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        let num_deposits = (0..20).fake_with_rng(rng);
+        let num_withdrawals = (0..20).fake_with_rng(rng);
+        Self {
+            deposits: std::iter::repeat_with(|| config.fake_with_rng(rng))
+                .take(num_deposits)
+                .collect(),
+            withdrawals: std::iter::repeat_with(|| config.fake_with_rng(rng))
+                .take(num_withdrawals)
+                .collect(),
         }
     }
 }
@@ -100,6 +131,21 @@ impl fake::Dummy<fake::Faker> for message::StacksTransactionSignature {
     }
 }
 
+impl fake::Dummy<fake::Faker> for message::SignRequestRefusal {
+    fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        let request = if rng.next_u32() % 2 == 0 {
+            message::RefusedSignRequest::BitcoinPreSign
+        } else {
+            message::RefusedSignRequest::StacksTransaction(dummy::stacks_txid(config, rng))
+        };
+        Self {
+            request,
+            reason: config.fake_with_rng(rng),
+            contested: config.fake_with_rng(rng),
+        }
+    }
+}
+
 impl fake::Dummy<fake::Faker> for message::WstsMessage {
     fn dummy_with_rng<R: rand::RngCore + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
         let dkg_end_begin = wsts::net::DkgEndBegin {
@@ -6,13 +6,16 @@ pub mod api_clients;
 pub mod block_observer;
 pub mod blocks;
 pub mod btc;
+pub mod cluster;
 pub mod context;
 pub mod dummy;
+pub mod faults;
 pub mod message;
 pub mod network;
 pub mod request_decider;
 pub mod stacks;
 pub mod storage;
+pub mod sweep;
 pub mod transaction_coordinator;
 pub mod transaction_signer;
 pub mod wallet;
@@ -49,15 +49,16 @@ impl<C: Context + 'static> RequestDeciderEventLoopHarness<C> {
         signer_private_key: PrivateKey,
     ) -> Self {
         Self {
-            event_loop: RequestDeciderEventLoop {
-                context: context.clone(),
-                network: network.spawn(),
-                blocklist_checker: Some(()),
-                signer_private_key,
-                context_window,
-                deposit_decisions_retry_window,
-                withdrawal_decisions_retry_window,
-            },
+            event_loop: RequestDeciderEventLoop::builder(context.clone(), network.spawn())
+                .with_blocklist_checker(Some(()))
+                .with_private_key(signer_private_key)
+                .with_context_window(context_window)
+                .with_decisions_retry_windows(
+                    deposit_decisions_retry_window,
+                    withdrawal_decisions_retry_window,
+                )
+                .build()
+                .expect("BUG: invalid request decider settings in test"),
             context,
         }
     }
@@ -229,7 +230,11 @@ where
 
         tokio::time::timeout(Duration::from_secs(1), async move {
             while let Ok(msg) = network_rx.receive().await {
-                if matches!(msg.payload, Payload::SignerDepositDecision(_)) {
+                let has_decisions = matches!(
+                    &msg.payload,
+                    Payload::SignerDecisionBatch(batch) if !batch.deposits.is_empty()
+                );
+                if has_decisions {
                     break;
                 }
             }
@@ -322,7 +327,11 @@ where
 
         tokio::time::timeout(Duration::from_secs(1), async move {
             while let Ok(msg) = network_rx.receive().await {
-                if matches!(msg.payload, Payload::SignerWithdrawalDecision(_)) {
+                let has_decisions = matches!(
+                    &msg.payload,
+                    Payload::SignerDecisionBatch(batch) if !batch.withdrawals.is_empty()
+                );
+                if has_decisions {
                     break;
                 }
             }
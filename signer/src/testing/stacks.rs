@@ -1,17 +1,53 @@
 //! Test utilities from the stacks module
 //!
+//! Apart from dummy data for mocked stacks clients, this module contains
+//! helpers for tests that run against the regtest stacks-node in devenv,
+//! such as funding the signers' multi-sig wallet and waiting for
+//! transactions and contract deployments to be confirmed.
+
+use std::str::FromStr as _;
+use std::time::Duration;
 
 use blockstack_lib::chainstate::nakamoto::NakamotoBlock;
 use blockstack_lib::chainstate::nakamoto::NakamotoBlockHeader;
+use blockstack_lib::chainstate::stacks::SinglesigHashMode;
+use blockstack_lib::chainstate::stacks::SinglesigSpendingCondition;
+use blockstack_lib::chainstate::stacks::StacksTransaction;
+use blockstack_lib::chainstate::stacks::TokenTransferMemo;
+use blockstack_lib::chainstate::stacks::TransactionAnchorMode;
+use blockstack_lib::chainstate::stacks::TransactionAuth;
+use blockstack_lib::chainstate::stacks::TransactionPayload;
+use blockstack_lib::chainstate::stacks::TransactionPostConditionMode;
+use blockstack_lib::chainstate::stacks::TransactionPublicKeyEncoding;
+use blockstack_lib::chainstate::stacks::TransactionSpendingCondition;
+use blockstack_lib::chainstate::stacks::TransactionVersion;
+use blockstack_lib::core::CHAIN_ID_TESTNET;
 use blockstack_lib::net::api::getsortition::SortitionInfo;
 use blockstack_lib::net::api::gettenureinfo::RPCGetTenureInfo;
+use blockstack_lib::types::chainstate::StacksAddress;
+use blockstack_lib::util::secp256k1::Secp256k1PublicKey;
 use clarity::types::chainstate::StacksBlockId;
+use clarity::vm::types::PrincipalData;
 use stacks_common::types::chainstate::BurnchainHeaderHash;
 use stacks_common::types::chainstate::ConsensusHash;
 use stacks_common::types::chainstate::SortitionId;
+use stacks_common::util::secp256k1::MessageSignature;
 
+use crate::config::Settings;
 use crate::error::Error;
+use crate::keys::PrivateKey;
+use crate::keys::PublicKey;
+use crate::signature::RecoverableEcdsaSignature as _;
+use crate::signature::sign_stacks_tx;
+use crate::stacks::api::StacksClient;
+use crate::stacks::api::StacksInteract;
+use crate::stacks::api::StacksTxInfo;
+use crate::stacks::api::SubmitTxResponse;
 use crate::stacks::api::TenureBlocks;
+use crate::stacks::contracts::SmartContract;
+use crate::stacks::wallet::SignerWallet;
+use crate::storage::model::StacksTxId;
+use crate::util::ApiFallbackClient;
 
 /// Some dummy sortition info
 pub const DUMMY_SORTITION_INFO: SortitionInfo = SortitionInfo {
@@ -59,3 +95,143 @@ impl TenureBlocks {
         Self::try_new(blocks, DUMMY_SORTITION_INFO)
     }
 }
+
+/// The hex encoded private key of an account that is funded with STX in
+/// the genesis block of the regtest stacks-node in devenv. It is the key
+/// of the first signer, see `docker/stacks/stacks-regtest-miner.toml`.
+pub const REGTEST_FUNDED_PRIVATE_KEY: &str =
+    "41634762d89dfa09133a4a8e9c1378d0161d29cd0a9433b51f1e3d32947a73dc";
+
+/// The fee used for the STX transfers made by the helpers here.
+const STX_TRANSFER_FEE: u64 = 1000;
+
+/// How long to sleep between polls of the stacks node.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Create a stacks client for the stacks-node in devenv, using the
+/// src/config/default.toml config.
+pub fn regtest_stacks_client() -> ApiFallbackClient<StacksClient> {
+    let settings = Settings::new_from_default_config().unwrap();
+    ApiFallbackClient::try_from(&settings).unwrap()
+}
+
+/// Return the single-sig address of the given private key on testnet.
+pub fn p2pkh_address(private_key: &PrivateKey) -> StacksAddress {
+    let public_key = PublicKey::from_private_key(private_key);
+    StacksAddress::p2pkh(false, &Secp256k1PublicKey::from(&public_key))
+}
+
+/// Transfer the given amount of microSTX from the single-sig account of
+/// the given private key to the recipient, returning the txid of the
+/// submitted transfer.
+pub async fn fund_stx<S>(
+    stacks: &S,
+    private_key: &PrivateKey,
+    recipient: &StacksAddress,
+    amount: u64,
+) -> Result<StacksTxId, Error>
+where
+    S: StacksInteract,
+{
+    let sender = p2pkh_address(private_key);
+    let nonce = stacks.get_account(&sender).await?.nonce;
+
+    let auth = SinglesigSpendingCondition {
+        signer: sender.bytes,
+        nonce,
+        tx_fee: STX_TRANSFER_FEE,
+        hash_mode: SinglesigHashMode::P2PKH,
+        key_encoding: TransactionPublicKeyEncoding::Compressed,
+        signature: MessageSignature::empty(),
+    };
+    let payload = TransactionPayload::TokenTransfer(
+        PrincipalData::from(*recipient),
+        amount,
+        TokenTransferMemo([0; 34]),
+    );
+
+    let mut tx = StacksTransaction {
+        version: TransactionVersion::Testnet,
+        chain_id: CHAIN_ID_TESTNET,
+        auth: TransactionAuth::Standard(TransactionSpendingCondition::Singlesig(auth)),
+        anchor_mode: TransactionAnchorMode::Any,
+        post_condition_mode: TransactionPostConditionMode::Allow,
+        post_conditions: Vec::new(),
+        payload,
+    };
+
+    let signature = sign_stacks_tx(&tx, private_key).as_stacks_sig();
+    if let TransactionAuth::Standard(TransactionSpendingCondition::Singlesig(auth)) = &mut tx.auth {
+        auth.set_signature(signature);
+    }
+
+    match stacks.submit_tx(&tx).await? {
+        SubmitTxResponse::Acceptance(txid) => Ok(txid.into()),
+        SubmitTxResponse::Rejection(err) => Err(err.into()),
+    }
+}
+
+/// Fund the signers' multi-sig wallet with the given amount of microSTX
+/// from the regtest account of [`REGTEST_FUNDED_PRIVATE_KEY`], and wait
+/// for the transfer to be confirmed.
+pub async fn fund_multisig<S>(
+    stacks: &S,
+    wallet: &SignerWallet,
+    amount: u64,
+    timeout: Duration,
+) -> Result<StacksTxInfo, Error>
+where
+    S: StacksInteract,
+{
+    let private_key = PrivateKey::from_str(REGTEST_FUNDED_PRIVATE_KEY)?;
+    let txid = fund_stx(stacks, &private_key, wallet.address(), amount).await?;
+    wait_for_transaction(stacks, &txid, timeout).await
+}
+
+/// Wait for the transaction with the given txid to be included in a
+/// stacks block, returning the outcome of the transaction.
+pub async fn wait_for_transaction<S>(
+    stacks: &S,
+    txid: &StacksTxId,
+    timeout: Duration,
+) -> Result<StacksTxInfo, Error>
+where
+    S: StacksInteract,
+{
+    let poll = async {
+        loop {
+            if let Some(info) = stacks.get_transaction(txid).await? {
+                return Ok(info);
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    };
+
+    tokio::time::timeout(timeout, poll)
+        .await
+        .expect("timed out waiting for the stacks transaction to be confirmed")
+}
+
+/// Wait for all the given smart contracts to be deployed by the deployer.
+pub async fn wait_for_contracts<S>(
+    stacks: &S,
+    deployer: &StacksAddress,
+    contracts: &[SmartContract],
+    timeout: Duration,
+) -> Result<(), Error>
+where
+    S: StacksInteract + Send + Sync,
+{
+    let poll = async {
+        for contract in contracts {
+            while !contract.is_deployed(stacks, deployer).await? {
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        }
+        Ok(())
+    };
+
+    tokio::time::timeout(timeout, poll)
+        .await
+        .expect("timed out waiting for the smart contracts to be deployed")
+}
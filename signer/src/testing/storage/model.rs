@@ -2,6 +2,7 @@
 
 use std::collections::HashSet;
 
+use bitcoin::Amount;
 use bitcoin::ScriptBuf;
 use fake::Dummy as _;
 use fake::Fake;
@@ -16,6 +17,7 @@ use crate::storage::model::BitcoinBlock;
 use crate::storage::model::BitcoinBlockHash;
 use crate::storage::model::BitcoinBlockRef;
 use crate::storage::model::StacksBlockHeight;
+use crate::testing::dummy::set_deposit_scripts;
 
 use rand::seq::SliceRandom;
 
@@ -72,6 +74,12 @@ impl TxDeconstructor for TestBitcoinTxInfo {
             output_index: input.previous_output.vout,
         })
     }
+
+    // The prevouts are generated independently of the outputs, so the
+    // difference between them is not a meaningful fee.
+    fn tx_fee(&self) -> Option<Amount> {
+        None
+    }
 }
 
 /// Collection of related data usable for database tests.
@@ -415,6 +423,7 @@ impl DepositData {
             raw_transaction.block_hash = bitcoin_block.block_hash;
             deposit_request.txid = raw_transaction.txid;
             deposit_request.signers_public_key = aggregate_key.into();
+            set_deposit_scripts(&mut deposit_request);
 
             let deposit_signers: Vec<_> = signer_keys
                 .iter()
@@ -426,6 +435,7 @@ impl DepositData {
                     signer_pub_key,
                     can_accept: fake::Faker.fake_with_rng(rng),
                     can_sign: true,
+                    decision_version: 0,
                 })
                 .collect();
 
@@ -494,6 +504,7 @@ impl WithdrawData {
                             txid: withdraw_request.txid,
                             signer_pub_key,
                             is_accepted: fake::Faker.fake_with_rng(rng),
+                            decision_version: 0,
                         })
                         .collect();
 
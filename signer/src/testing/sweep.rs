@@ -0,0 +1,389 @@
+//! Property-based test generators for sweep transaction construction.
+//!
+//! The strategies in this module generate random deposit and withdrawal
+//! requests, fee rates, and signer UTXO states, which can be combined into
+//! an [`SbtcRequests`] object. The [`check_sweep_invariants`] function
+//! checks the properties that every package of sweep transactions
+//! constructed from such an object must satisfy, so that it can be used
+//! from unit tests as well as from fuzzing harnesses, like the
+//! `sweep_packaging` target in `signer/fuzz`.
+
+use std::collections::BTreeSet;
+
+use bitcoin::Amount;
+use bitcoin::OutPoint;
+use bitcoin::ScriptBuf;
+use bitcoin::TxOut;
+use bitcoin::Txid;
+use bitcoin::WPubkeyHash;
+use bitcoin::WScriptHash;
+use bitcoin::hashes::Hash as _;
+use bitvec::array::BitArray;
+use clarity::vm::types::PrincipalData;
+use proptest::prelude::*;
+use sbtc::deposits::DepositScriptInputs;
+use secp256k1::SECP256K1;
+use secp256k1::SecretKey;
+use secp256k1::XOnlyPublicKey;
+use stacks_common::types::chainstate::StacksAddress;
+
+use crate::DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX;
+use crate::bitcoin::units::FeeRate;
+use crate::bitcoin::utxo::DepositRequest;
+use crate::bitcoin::utxo::FeeAssessment as _;
+use crate::bitcoin::utxo::RequestRef;
+use crate::bitcoin::utxo::SbtcRequests;
+use crate::bitcoin::utxo::SignerBtcState;
+use crate::bitcoin::utxo::SignerUtxo;
+use crate::bitcoin::utxo::UnsignedTransaction;
+use crate::bitcoin::utxo::WithdrawalFeeSubsidy;
+use crate::bitcoin::utxo::WithdrawalRequest;
+use crate::context::SbtcLimits;
+use crate::error::Error;
+use crate::storage::model::StacksBlockHash;
+use crate::storage::model::StacksTxId;
+use crate::storage::model::TaprootScriptHash;
+
+/// The maximum number of signers in the generated signing sets.
+pub const MAX_NUM_SIGNERS: u16 = 16;
+
+/// The maximum number of deposit requests generated by [`sbtc_requests`].
+pub const MAX_NUM_DEPOSITS: usize = 30;
+
+/// The maximum number of withdrawal requests generated by
+/// [`sbtc_requests`].
+pub const MAX_NUM_WITHDRAWALS: usize = 30;
+
+/// The maximum amount of a generated withdrawal request. The signers'
+/// UTXO is always large enough to service all generated withdrawals.
+pub const MAX_WITHDRAWAL_AMOUNT: u64 = 1_000_000;
+
+/// An invariant that was violated by a package of sweep transactions.
+#[derive(Debug, thiserror::Error)]
+pub enum SweepInvariantViolation {
+    /// The sweep transactions could not be constructed.
+    #[error("could not construct the sweep transactions: {0}")]
+    Construction(#[source] Error),
+    /// The inputs of the transaction do not equal its outputs plus the
+    /// transaction fee.
+    #[error("value is not conserved in {txid}: inputs {inputs}, outputs {outputs}, fee {fee}")]
+    ValueNotConserved {
+        /// The transaction ID of the offending transaction.
+        txid: Txid,
+        /// The sum of the input amounts.
+        inputs: u64,
+        /// The sum of the output amounts.
+        outputs: u64,
+        /// The transaction fee.
+        fee: u64,
+    },
+    /// An output of the transaction is below the dust limit for its
+    /// scriptPubKey.
+    #[error("output {vout} of {txid} is below the dust limit")]
+    OutputBelowDust {
+        /// The transaction ID of the offending transaction.
+        txid: Txid,
+        /// The index of the output that is below the dust limit.
+        vout: usize,
+    },
+    /// A request was assessed a fee that is greater than its max fee.
+    #[error("request in {txid} was assessed {assessed} sats, above its max fee of {max_fee}")]
+    FeeAboveMaxFee {
+        /// The transaction ID of the offending transaction.
+        txid: Txid,
+        /// The fee assessed to the request.
+        assessed: u64,
+        /// The max fee of the request.
+        max_fee: u64,
+    },
+    /// A request in the transaction does not have a corresponding input
+    /// or output.
+    #[error("a request in {0} does not have a corresponding input or output")]
+    MissingRequest(Txid),
+}
+
+/// A strategy for random x-only public keys.
+pub fn x_only_public_key() -> impl Strategy<Value = XOnlyPublicKey> {
+    any::<[u8; 32]>().prop_filter_map("invalid secret key", |bytes| {
+        let secret_key = SecretKey::from_slice(&bytes).ok()?;
+        Some(secret_key.x_only_public_key(SECP256K1).0)
+    })
+}
+
+/// A strategy for signer bitmaps with votes against from at most
+/// `num_signers` signers. Most of the generated bitmaps have no votes
+/// against, so that most requests can be included in a sweep.
+pub fn signer_bitmap(num_signers: u16) -> impl Strategy<Value = BitArray<[u8; 16]>> {
+    let mask = (1u128 << num_signers.min(128)) - 1;
+    prop_oneof![
+        3 => Just(0u128),
+        1 => any::<u128>().prop_map(move |bitmap| bitmap & mask),
+    ]
+    .prop_map(|bitmap| BitArray::new(bitmap.to_le_bytes()))
+}
+
+/// A strategy for deposit requests locked to the given signers' public
+/// key.
+///
+/// The amounts and max fees range from zero, so some of the generated
+/// requests are below the dust limit or cannot cover their fees.
+pub fn deposit_request(
+    signers_public_key: XOnlyPublicKey,
+    num_signers: u16,
+) -> impl Strategy<Value = DepositRequest> {
+    (
+        any::<[u8; 32]>(),
+        any::<u32>(),
+        0..5_000_000u64,
+        0..100_000u64,
+        signer_bitmap(num_signers),
+    )
+        .prop_map(move |(txid, vout, amount, max_fee, signer_bitmap)| {
+            let deposit_inputs = DepositScriptInputs {
+                signers_public_key,
+                max_fee,
+                recipient: PrincipalData::from(StacksAddress::burn_address(false)),
+            };
+
+            DepositRequest {
+                outpoint: OutPoint {
+                    txid: Txid::from_byte_array(txid),
+                    vout,
+                },
+                max_fee,
+                signer_bitmap,
+                amount,
+                deposit_script: deposit_inputs.deposit_script(),
+                reclaim_script: ScriptBuf::new(),
+                reclaim_script_hash: Some(TaprootScriptHash::zeros()),
+                signers_public_key,
+            }
+        })
+}
+
+/// A strategy for the scriptPubKeys of withdrawal recipients.
+pub fn withdrawal_script_pubkey() -> impl Strategy<Value = ScriptBuf> {
+    prop_oneof![
+        any::<[u8; 20]>()
+            .prop_map(|hash| ScriptBuf::new_p2wpkh(&WPubkeyHash::from_byte_array(hash))),
+        any::<[u8; 32]>()
+            .prop_map(|hash| ScriptBuf::new_p2wsh(&WScriptHash::from_byte_array(hash))),
+    ]
+}
+
+/// A strategy for withdrawal requests. The request IDs of the generated
+/// requests are all zero; [`sbtc_requests`] assigns unique IDs.
+pub fn withdrawal_request(num_signers: u16) -> impl Strategy<Value = WithdrawalRequest> {
+    (
+        any::<[u8; 32]>(),
+        any::<[u8; 32]>(),
+        0..MAX_WITHDRAWAL_AMOUNT,
+        0..100_000u64,
+        withdrawal_script_pubkey(),
+        signer_bitmap(num_signers),
+    )
+        .prop_map(
+            |(txid, block_hash, amount, max_fee, script_pubkey, signer_bitmap)| WithdrawalRequest {
+                request_id: 0,
+                txid: StacksTxId::from(txid),
+                block_hash: StacksBlockHash::from(block_hash),
+                amount,
+                max_fee,
+                script_pubkey: script_pubkey.into(),
+                signer_bitmap,
+            },
+        )
+}
+
+/// A strategy for market fee rates, in sats per vbyte.
+pub fn fee_rate() -> impl Strategy<Value = FeeRate> {
+    (1.0..100.0f64).prop_map(|rate| FeeRate::new(rate).expect("BUG: fee rate is positive"))
+}
+
+/// A strategy for the signers' UTXO state with the given public key.
+///
+/// The signers' UTXO always holds enough to service
+/// [`MAX_NUM_WITHDRAWALS`] withdrawals of [`MAX_WITHDRAWAL_AMOUNT`] and to
+/// pay for the fees of the sweep transactions.
+pub fn signer_btc_state(public_key: XOnlyPublicKey) -> impl Strategy<Value = SignerBtcState> {
+    let minimum_amount = 2 * MAX_NUM_WITHDRAWALS as u64 * MAX_WITHDRAWAL_AMOUNT;
+    (
+        any::<[u8; 32]>(),
+        minimum_amount..Amount::MAX_MONEY.to_sat(),
+        fee_rate(),
+    )
+        .prop_map(move |(txid, amount, fee_rate)| SignerBtcState {
+            utxo: SignerUtxo {
+                outpoint: OutPoint {
+                    txid: Txid::from_byte_array(txid),
+                    vout: 0,
+                },
+                amount,
+                public_key,
+            },
+            fee_rate,
+            public_key,
+            last_fees: None,
+            magic_bytes: [b'T', b'3'],
+            metadata_chain_tip: None,
+        })
+}
+
+/// A strategy for random sets of requests and signer UTXO states for
+/// constructing sweep transactions.
+pub fn sbtc_requests() -> impl Strategy<Value = SbtcRequests> {
+    (x_only_public_key(), 1..=MAX_NUM_SIGNERS)
+        .prop_flat_map(|(public_key, num_signers)| {
+            (
+                prop::collection::vec(
+                    deposit_request(public_key, num_signers),
+                    0..MAX_NUM_DEPOSITS,
+                ),
+                prop::collection::vec(withdrawal_request(num_signers), 0..MAX_NUM_WITHDRAWALS),
+                signer_btc_state(public_key),
+                1..=num_signers,
+                Just(num_signers),
+                1..=DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            )
+        })
+        .prop_map(
+            |(
+                deposits,
+                mut withdrawals,
+                signer_state,
+                accept_threshold,
+                num_signers,
+                max_deposits,
+            )| {
+                withdrawals
+                    .iter_mut()
+                    .zip(0u64..)
+                    .for_each(|(req, request_id)| req.request_id = request_id);
+
+                SbtcRequests {
+                    deposits,
+                    withdrawals,
+                    signer_state,
+                    accept_threshold,
+                    num_signers,
+                    sbtc_limits: SbtcLimits::unlimited(),
+                    max_deposits_per_bitcoin_tx: max_deposits,
+                    withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
+                }
+            },
+        )
+}
+
+/// Construct the sweep transactions for the given requests and check
+/// them with [`check_sweep_invariants`].
+pub fn construct_and_check_sweeps(requests: &SbtcRequests) -> Result<(), SweepInvariantViolation> {
+    let transactions = requests
+        .construct_transactions()
+        .map_err(SweepInvariantViolation::Construction)?;
+    check_sweep_invariants(&transactions)
+}
+
+/// Check that each of the given sweep transactions:
+/// 1. conserves value, meaning that the inputs equal the outputs plus the
+///    transaction fee,
+/// 2. has no outputs, other than the OP_RETURN output, below the dust
+///    limit for their scriptPubKey, and
+/// 3. assesses each deposit and withdrawal request a fee that is no more
+///    than its max fee.
+pub fn check_sweep_invariants(
+    transactions: &[UnsignedTransaction],
+) -> Result<(), SweepInvariantViolation> {
+    for unsigned in transactions {
+        let txid = unsigned.tx.compute_txid();
+        let tx_fee = Amount::from_sat(unsigned.tx_fee);
+
+        let inputs = unsigned.input_amounts();
+        let outputs = unsigned.output_amounts();
+        if inputs != outputs.saturating_add(unsigned.tx_fee) {
+            return Err(SweepInvariantViolation::ValueNotConserved {
+                txid,
+                inputs,
+                outputs,
+                fee: unsigned.tx_fee,
+            });
+        }
+
+        for (vout, output) in unsigned.tx.output.iter().enumerate() {
+            if output.script_pubkey.is_op_return() {
+                continue;
+            }
+            if output.value < output.script_pubkey.minimal_non_dust() {
+                return Err(SweepInvariantViolation::OutputBelowDust { txid, vout });
+            }
+        }
+
+        for deposit in unsigned.requests.iter().filter_map(RequestRef::as_deposit) {
+            let assessed = unsigned
+                .assess_input_fee(&deposit.outpoint, tx_fee)
+                .ok_or(SweepInvariantViolation::MissingRequest(txid))?;
+            check_max_fee(txid, assessed, deposit.max_fee)?;
+        }
+
+        // Find the output of each withdrawal request in the transaction
+        // instead of assuming where the outputs are placed. Two requests
+        // may pay the same amount to the same scriptPubKey, so each
+        // output is only matched once.
+        let mut withdrawal_vouts = BTreeSet::new();
+        let withdrawals = unsigned
+            .requests
+            .iter()
+            .filter_map(RequestRef::as_withdrawal);
+        for withdrawal in withdrawals {
+            let expected = TxOut {
+                value: Amount::from_sat(withdrawal.amount),
+                script_pubkey: withdrawal.script_pubkey.clone().into(),
+            };
+            let vout = unsigned
+                .tx
+                .output
+                .iter()
+                .enumerate()
+                .find(|(vout, output)| *output == &expected && !withdrawal_vouts.contains(vout))
+                .map(|(vout, _)| vout)
+                .ok_or(SweepInvariantViolation::MissingRequest(txid))?;
+            withdrawal_vouts.insert(vout);
+
+            let assessed = unsigned
+                .assess_output_fee(vout, tx_fee)
+                .ok_or(SweepInvariantViolation::MissingRequest(txid))?;
+            check_max_fee(txid, assessed, withdrawal.max_fee)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn check_max_fee(
+    txid: Txid,
+    assessed: Amount,
+    max_fee: u64,
+) -> Result<(), SweepInvariantViolation> {
+    if assessed.to_sat() > max_fee {
+        return Err(SweepInvariantViolation::FeeAboveMaxFee {
+            txid,
+            assessed: assessed.to_sat(),
+            max_fee,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn constructed_sweeps_satisfy_invariants(requests in sbtc_requests()) {
+            let result = construct_and_check_sweeps(&requests);
+            prop_assert!(result.is_ok(), "{}", result.unwrap_err());
+        }
+    }
+}
@@ -57,6 +57,7 @@ use clarity::vm::types::SequenceData;
 use fake::Fake as _;
 use fake::Faker;
 use rand::seq::IteratorRandom;
+use tokio::time::Instant;
 
 use super::context::TestContext;
 use super::context::WrappedMock;
@@ -113,17 +114,19 @@ where
         threshold: u16,
     ) -> Self {
         Self {
-            event_loop: transaction_coordinator::TxCoordinatorEventLoop {
-                context: context.clone(),
+            event_loop: transaction_coordinator::TxCoordinatorEventLoop::builder(
+                context.clone(),
                 network,
-                private_key,
-                context_window,
-                threshold,
-                signing_round_max_duration: Duration::from_secs(10),
-                bitcoin_presign_request_max_duration: Duration::from_secs(10),
-                dkg_max_duration: Duration::from_secs(10),
-                is_epoch3: true,
-            },
+            )
+            .with_private_key(private_key)
+            .with_context_window(context_window)
+            .with_threshold(threshold)
+            .with_signing_round_max_duration(Duration::from_secs(10))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .expect("BUG: invalid transaction coordinator settings in test"),
             context,
             is_started: Arc::new(AtomicBool::new(false)),
         }
@@ -266,17 +269,19 @@ where
         context.state().set_sbtc_contracts_deployed();
         let signer_network = SignerNetwork::single(&context);
 
-        let coordinator = TxCoordinatorEventLoop {
-            context: self.context,
-            network: signer_network.spawn(),
-            private_key: select_coordinator(&bitcoin_chain_tip.block_hash, &signer_info),
-            threshold: self.signing_threshold,
-            context_window: self.context_window,
-            signing_round_max_duration: Duration::from_millis(500),
-            bitcoin_presign_request_max_duration: Duration::from_millis(500),
-            dkg_max_duration: Duration::from_millis(500),
-            is_epoch3: true,
-        };
+        let coordinator = TxCoordinatorEventLoop::builder(self.context, signer_network.spawn())
+            .with_private_key(select_coordinator(
+                &bitcoin_chain_tip.block_hash,
+                &signer_info,
+            ))
+            .with_threshold(self.signing_threshold)
+            .with_context_window(self.context_window)
+            .with_signing_round_max_duration(Duration::from_millis(500))
+            .with_bitcoin_presign_request_max_duration(Duration::from_millis(500))
+            .with_dkg_max_duration(Duration::from_millis(500))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
 
         let signer_public_keys = &signer_info
             .last()
@@ -287,13 +292,12 @@ where
         let (bitcoin_chain_tip, stacks_chain_tip) = storage.get_chain_tips().await;
 
         // Get pending withdrawals from coordinator
+        let mut tenure = coordinator
+            .start_tenure(bitcoin_chain_tip, aggregate_key, Instant::now())
+            .await
+            .expect("Error starting the tenure");
         let pending_requests = coordinator
-            .get_pending_requests(
-                &bitcoin_chain_tip,
-                &stacks_chain_tip,
-                &aggregate_key,
-                signer_public_keys,
-            )
+            .get_pending_requests(&mut tenure, signer_public_keys)
             .await
             .expect("Error getting pending requests")
             .expect("Empty pending requests");
@@ -451,12 +455,24 @@ where
             tokio::spawn(async move { wait_for_transaction_rx.recv().await });
 
         // Setup the bitcoin client mock to broadcast the transaction to our
-        // channel.
+        // channel. Packages of transactions are sent one at a time.
+        let broadcasted_package_tx = broadcasted_transaction_tx.clone();
         self.context
             .with_bitcoin_client(|client| {
+                client
+                    .expect_broadcast_package()
+                    .times(0..)
+                    .returning(move |txs| {
+                        for tx in txs {
+                            broadcasted_package_tx
+                                .send(tx.clone())
+                                .expect("Failed to send result");
+                        }
+                        Box::pin(async { Ok(()) })
+                    });
                 client
                     .expect_broadcast_transaction()
-                    .times(1..)
+                    .times(0..)
                     .returning(move |tx| {
                         let tx = tx.clone();
                         let broadcasted_transaction_tx = broadcasted_transaction_tx.clone();
@@ -609,12 +625,24 @@ where
             tokio::spawn(async move { wait_for_transaction_rx.recv().await });
 
         // Setup the bitcoin client mock to broadcast the transaction to our
-        // channel.
+        // channel. Packages of transactions are sent one at a time.
+        let broadcasted_package_tx = broadcasted_transaction_tx.clone();
         self.context
             .with_bitcoin_client(|client| {
+                client
+                    .expect_broadcast_package()
+                    .times(0..)
+                    .returning(move |txs| {
+                        for tx in txs {
+                            broadcasted_package_tx
+                                .send(tx.clone())
+                                .expect("Failed to send result");
+                        }
+                        Box::pin(async { Ok(()) })
+                    });
                 client
                     .expect_broadcast_transaction()
-                    .times(1..)
+                    .times(0..)
                     .returning(move |tx| {
                         let tx = tx.clone();
                         let broadcasted_transaction_tx = broadcasted_transaction_tx.clone();
@@ -799,17 +827,16 @@ where
                 });
             })
             .await;
-        let coordinator = TxCoordinatorEventLoop {
-            context: self.context,
-            network: signer_network.spawn(),
-            private_key,
-            threshold: self.signing_threshold,
-            context_window: self.context_window,
-            signing_round_max_duration: Duration::from_millis(500),
-            bitcoin_presign_request_max_duration: Duration::from_millis(500),
-            dkg_max_duration: Duration::from_millis(500),
-            is_epoch3: true,
-        };
+        let coordinator = TxCoordinatorEventLoop::builder(self.context, signer_network.spawn())
+            .with_private_key(private_key)
+            .with_threshold(self.signing_threshold)
+            .with_context_window(self.context_window)
+            .with_signing_round_max_duration(Duration::from_millis(500))
+            .with_bitcoin_presign_request_max_duration(Duration::from_millis(500))
+            .with_dkg_max_duration(Duration::from_millis(500))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
         let (sign_request, multi_tx) = coordinator
             .construct_withdrawal_accept_stacks_sign_request(
                 withdrawal_req.clone(),
@@ -902,17 +929,16 @@ where
             })
             .await;
 
-        let coordinator = TxCoordinatorEventLoop {
-            context: self.context,
-            network: signer_network.spawn(),
-            private_key,
-            threshold: self.signing_threshold,
-            context_window: self.context_window,
-            signing_round_max_duration: Duration::from_millis(500),
-            bitcoin_presign_request_max_duration: Duration::from_millis(500),
-            dkg_max_duration: Duration::from_millis(500),
-            is_epoch3: true,
-        };
+        let coordinator = TxCoordinatorEventLoop::builder(self.context, signer_network.spawn())
+            .with_private_key(private_key)
+            .with_threshold(self.signing_threshold)
+            .with_context_window(self.context_window)
+            .with_signing_round_max_duration(Duration::from_millis(500))
+            .with_bitcoin_presign_request_max_duration(Duration::from_millis(500))
+            .with_dkg_max_duration(Duration::from_millis(500))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
 
         let (sign_request, multi_tx) = coordinator
             .construct_withdrawal_reject_stacks_sign_request(
@@ -24,7 +24,6 @@ use crate::testing::storage::model::TestData;
 use crate::transaction_signer;
 use crate::transaction_signer::STACKS_SIGN_REQUEST_LRU_SIZE;
 
-use lru::LruCache;
 use tokio::sync::broadcast;
 use tokio::time::error::Elapsed;
 
@@ -52,19 +51,20 @@ where
         rng: Rng,
     ) -> Self {
         Self {
-            event_loop: transaction_signer::TxSignerEventLoop {
-                context: context.clone(),
+            event_loop: transaction_signer::TxSignerEventLoop::builder(
+                context.clone(),
                 network,
-                signer_private_key,
-                context_window,
-                wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-                threshold,
-                last_presign_block: None,
                 rng,
-                dkg_begin_pause: None,
-                dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-                stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-            },
+            )
+            .with_private_key(signer_private_key)
+            .with_context_window(context_window)
+            .with_threshold(threshold)
+            .with_dkg_begin_pause(None)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .expect("BUG: invalid transaction signer settings in test"),
             context,
         }
     }
@@ -48,6 +48,23 @@ pub fn regtest_bootstrap_wallet() -> (SignerWallet, [Keypair; 3]) {
 /// This function creates a signing set where the aggregate key is the
 /// given controller's public key.
 pub fn create_signers_keys<R>(rng: &mut R, signer: &Recipient, num_signers: usize) -> Vec<PublicKey>
+where
+    R: rand::Rng,
+{
+    create_signers_private_keys(rng, signer, num_signers)
+        .iter()
+        .map(PublicKey::from_private_key)
+        .collect()
+}
+
+/// This function creates the private keys of a signing set where the
+/// aggregate key is the given controller's public key. The keys are
+/// sorted by their public keys.
+pub fn create_signers_private_keys<R>(
+    rng: &mut R,
+    signer: &Recipient,
+    num_signers: usize,
+) -> Vec<PrivateKey>
 where
     R: rand::Rng,
 {
@@ -55,21 +72,20 @@ where
     assert_eq!(num_signers % 2, 1);
 
     let private_key = PrivateKey::from(signer.keypair.secret_key());
-    let aggregate_key = PublicKey::from_private_key(&private_key);
     // The private keys of half of the other signers
     let pks: Vec<secp256k1::SecretKey> = std::iter::repeat_with(|| secp256k1::SecretKey::new(rng))
         .take(num_signers / 2)
         .collect();
 
-    let mut keys: Vec<PublicKey> = pks
+    let mut keys: Vec<PrivateKey> = pks
         .clone()
         .into_iter()
         .chain(pks.into_iter().map(secp256k1::SecretKey::negate))
-        .map(|sk| PublicKey::from_private_key(&PrivateKey::from(sk)))
-        .chain([aggregate_key])
+        .map(PrivateKey::from)
+        .chain([private_key])
         .collect();
 
-    keys.sort();
+    keys.sort_by_key(PublicKey::from_private_key);
     keys
 }
 
@@ -5,6 +5,7 @@
 //!
 //! For more details, see the [`TxCoordinatorEventLoop`] documentation.
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashSet;
 use std::time::Duration;
@@ -14,16 +15,23 @@ use futures::Stream;
 use futures::StreamExt as _;
 use futures::future::try_join_all;
 use sha2::Digest;
+use tokio::time::Instant;
 
+use crate::MAX_PRESIGN_RETRIES;
 use crate::WITHDRAWAL_BLOCKS_EXPIRY;
-use crate::WITHDRAWAL_DUST_LIMIT;
 use crate::WITHDRAWAL_EXPIRY_BUFFER;
 use crate::WITHDRAWAL_MIN_CONFIRMATIONS;
+use crate::attestation;
 use crate::bitcoin::BitcoinInteract;
 use crate::bitcoin::TransactionLookupHint;
+use crate::bitcoin::get_tx_info_cached;
+use crate::bitcoin::psbt_export::PsbtExporter;
+use crate::bitcoin::units::FeeRate;
 use crate::bitcoin::utxo;
 use crate::bitcoin::utxo::Fees;
 use crate::bitcoin::utxo::UnsignedMockTransaction;
+use crate::block_processing;
+use crate::broadcaster;
 use crate::context::Context;
 use crate::context::P2PEvent;
 use crate::context::RequestDeciderEvent;
@@ -31,17 +39,25 @@ use crate::context::SbtcLimits;
 use crate::context::SignerCommand;
 use crate::context::SignerEvent;
 use crate::context::SignerSignal;
+use crate::context::SignerState;
 use crate::context::TxCoordinatorEvent;
 use crate::context::TxSignerEvent;
 use crate::ecdsa::SignEcdsa as _;
 use crate::ecdsa::Signed;
+use crate::emergency_recovery;
+use crate::emergency_recovery::EmergencyRecoveryEvent;
 use crate::emily_client::EmilyInteract;
 use crate::error::Error;
 use crate::keys::PrivateKey;
 use crate::keys::PublicKey;
 use crate::message;
 use crate::message::BitcoinPreSignRequest;
+use crate::message::CoordinatorAttestation;
+use crate::message::DepositDecisionRetryRequest;
+use crate::message::DepositQuorumCertificate;
 use crate::message::Payload;
+use crate::message::RefusedSignRequest;
+use crate::message::SignRequestRefusal;
 use crate::message::SignerMessage;
 use crate::message::StacksTransactionSignRequest;
 use crate::message::WstsMessageId;
@@ -49,6 +65,9 @@ use crate::metrics::BITCOIN_BLOCKCHAIN;
 use crate::metrics::Metrics;
 use crate::metrics::STACKS_BLOCKCHAIN;
 use crate::network;
+use crate::quorum;
+use crate::selection;
+use crate::selection::WithdrawalRules;
 use crate::signature::TaprootSignature;
 use crate::stacks::api::FeePriority;
 use crate::stacks::api::GetNakamotoStartHeight;
@@ -57,28 +76,45 @@ use crate::stacks::api::StacksInteract;
 use crate::stacks::api::SubmitTxResponse;
 use crate::stacks::api::TxRejection;
 use crate::stacks::contracts::AcceptWithdrawalV1;
+use crate::stacks::contracts::AcceptWithdrawalsV1;
 use crate::stacks::contracts::AsTxPayload;
 use crate::stacks::contracts::CompleteDepositV1;
+use crate::stacks::contracts::CompleteDepositsV1;
 use crate::stacks::contracts::ContractCall;
 use crate::stacks::contracts::RejectWithdrawalV1;
 use crate::stacks::contracts::RotateKeysV1;
 use crate::stacks::contracts::SMART_CONTRACTS;
 use crate::stacks::contracts::SmartContract;
+use crate::stacks::contracts::StacksTx;
+use crate::stacks::contracts::validate_withdrawal_rejection;
+use crate::stacks::tracker;
+use crate::stacks::tracker::Resubmission;
+use crate::stacks::tracker::ResubmissionPolicy;
 use crate::stacks::wallet::MultisigTx;
 use crate::stacks::wallet::SignerWallet;
 use crate::storage::DbRead;
+use crate::storage::DbWrite as _;
 use crate::storage::model;
+use crate::storage::model::BlockProcessingStage;
 use crate::storage::model::StacksTxId;
+use crate::storage::model::StacksTxStatus;
+use crate::storage::model::StacksTxSubmission;
+use crate::supervisor::report_error;
+use crate::transaction_signer::StacksSignRequestId;
 use crate::wsts_state_machine::FireCoordinator;
 use crate::wsts_state_machine::FrostCoordinator;
 use crate::wsts_state_machine::WstsCoordinator;
 
+use bitcoin::OutPoint;
 use bitcoin::hashes::Hash as _;
 use wsts::net::SignatureType;
 use wsts::state_machine::OperationResult as WstsOperationResult;
 use wsts::state_machine::StateMachine as _;
 use wsts::state_machine::coordinator::State as WstsCoordinatorState;
 
+/// The name of this event loop, as used by the supervisor.
+const EVENT_LOOP: &str = "tx-coordinator";
+
 #[cfg_attr(doc, aquamarine::aquamarine)]
 /// # Transaction coordinator event loop
 ///
@@ -150,7 +186,10 @@ use wsts::state_machine::coordinator::State as WstsCoordinatorState;
 ///     CMS --> BST
 ///     BST --> DONE
 /// ```
+///
+/// The event loop is created through [`TxCoordinatorEventLoop::builder`].
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct TxCoordinatorEventLoop<Context, Network> {
     /// The signer context.
     pub context: Context,
@@ -178,6 +217,117 @@ pub struct TxCoordinatorEventLoop<Context, Network> {
     pub is_epoch3: bool,
 }
 
+/// A builder for a [`TxCoordinatorEventLoop`]. The settings default to
+/// the ones in the config of the context, and are validated when the
+/// event loop is built.
+#[derive(Debug)]
+pub struct TxCoordinatorEventLoopBuilder<Context, Network> {
+    event_loop: TxCoordinatorEventLoop<Context, Network>,
+}
+
+impl<C, N> TxCoordinatorEventLoopBuilder<C, N>
+where
+    C: Context,
+{
+    /// Create a new builder for a coordinator that uses the given context
+    /// and network.
+    pub fn new(context: C, network: N) -> Self {
+        let config = &context.config().signer;
+        let event_loop = TxCoordinatorEventLoop {
+            network,
+            private_key: config.private_key,
+            threshold: config.bootstrap_signatures_required,
+            context_window: config.context_window,
+            signing_round_max_duration: config.signer_round_max_duration,
+            bitcoin_presign_request_max_duration: config.bitcoin_presign_request_max_duration,
+            dkg_max_duration: config.dkg_max_duration,
+            is_epoch3: false,
+            context,
+        };
+        Self { event_loop }
+    }
+
+    /// Set the private key of the coordinator.
+    pub fn with_private_key(mut self, private_key: PrivateKey) -> Self {
+        self.event_loop.private_key = private_key;
+        self
+    }
+
+    /// Set the number of signatures required.
+    pub fn with_threshold(mut self, threshold: u16) -> Self {
+        self.event_loop.threshold = threshold;
+        self
+    }
+
+    /// Set how many bitcoin blocks back from the chain tip the coordinator
+    /// looks for requests.
+    pub fn with_context_window(mut self, context_window: u16) -> Self {
+        self.event_loop.context_window = context_window;
+        self
+    }
+
+    /// Set the maximum duration of a signing round.
+    pub fn with_signing_round_max_duration(mut self, duration: Duration) -> Self {
+        self.event_loop.signing_round_max_duration = duration;
+        self
+    }
+
+    /// Set the maximum duration of a pre-sign request.
+    pub fn with_bitcoin_presign_request_max_duration(mut self, duration: Duration) -> Self {
+        self.event_loop.bitcoin_presign_request_max_duration = duration;
+        self
+    }
+
+    /// Set the maximum duration of distributed key generation.
+    pub fn with_dkg_max_duration(mut self, duration: Duration) -> Self {
+        self.event_loop.dkg_max_duration = duration;
+        self
+    }
+
+    /// Set whether the stacks blockchain has reached Nakamoto 3.
+    pub fn with_epoch3(mut self, is_epoch3: bool) -> Self {
+        self.event_loop.is_epoch3 = is_epoch3;
+        self
+    }
+
+    /// Validate the settings and build the event loop.
+    pub fn build(self) -> Result<TxCoordinatorEventLoop<C, N>, Error> {
+        let event_loop = self.event_loop;
+        if event_loop.threshold == 0 || event_loop.threshold > crate::MAX_KEYS {
+            return Err(Error::InvalidEventLoopSetting(
+                "the threshold must be between one and the maximum number of signers",
+            ));
+        }
+        if event_loop.context_window == 0 {
+            return Err(Error::InvalidEventLoopSetting(
+                "the context window must not be zero",
+            ));
+        }
+        let durations = [
+            event_loop.signing_round_max_duration,
+            event_loop.bitcoin_presign_request_max_duration,
+            event_loop.dkg_max_duration,
+        ];
+        if durations.contains(&Duration::ZERO) {
+            return Err(Error::InvalidEventLoopSetting(
+                "the maximum durations must not be zero",
+            ));
+        }
+        Ok(event_loop)
+    }
+}
+
+impl<C, N> TxCoordinatorEventLoop<C, N>
+where
+    C: Context,
+{
+    /// Create a builder for a coordinator that uses the given context and
+    /// network, with the settings in the config of the context.
+    pub fn builder(context: C, network: N) -> TxCoordinatorEventLoopBuilder<C, N> {
+        TxCoordinatorEventLoopBuilder::new(context, network)
+    }
+}
+
 /// The parameters for the [`TxCoordinatorEventLoop::get_pending_requests`] function.
 #[derive(Debug)]
 pub struct GetPendingRequestsParams<'a> {
@@ -195,6 +345,61 @@ pub struct GetPendingRequestsParams<'a> {
     pub signature_threshold: u16,
 }
 
+/// The state that the steps of a coordinator tenure share.
+///
+/// A tenure starts when the coordinator processes a new bitcoin block and
+/// is identified by the hash of that block. The chain tips, aggregate key
+/// and sBTC limits are fixed when the tenure starts, so that every step of
+/// the tenure works off of the same view of the world, while the plan and
+/// the signers' BTC state are filled in once they are first needed.
+#[derive(Debug, Clone)]
+pub struct TenureContext {
+    /// The bitcoin chain tip of the tenure.
+    pub bitcoin_chain_tip: model::BitcoinBlockRef,
+    /// The stacks chain tip anchored to the bitcoin chain tip, if any.
+    pub stacks_chain_tip: Option<model::StacksBlock>,
+    /// The signers' aggregate key for the tenure.
+    pub aggregate_key: PublicKey,
+    /// The sBTC limits when the tenure started.
+    pub sbtc_limits: SbtcLimits,
+    /// When the tenure budget runs out.
+    pub deadline: Instant,
+    /// The plan for the tenure, once it has been published.
+    pub plan: Option<model::TenurePlan>,
+    /// The signers' BTC state, once it has been fetched.
+    pub btc_state: Option<utxo::SignerBtcState>,
+}
+
+impl TenureContext {
+    /// The ID of the tenure, which is the hash of its bitcoin chain tip.
+    pub fn tenure_id(&self) -> model::BitcoinBlockHash {
+        self.bitcoin_chain_tip.block_hash
+    }
+
+    /// The stacks chain tip of the tenure, or an error if there is none.
+    pub fn require_stacks_chain_tip(&self) -> Result<&model::StacksBlock, Error> {
+        self.stacks_chain_tip
+            .as_ref()
+            .ok_or(Error::NoStacksChainTip)
+    }
+
+    /// The parameters for fetching the pending requests of the tenure, or
+    /// `None` if the tenure has no stacks chain tip.
+    pub fn pending_requests_params(
+        &self,
+        signature_threshold: u16,
+    ) -> Option<GetPendingRequestsParams<'_>> {
+        let stacks_chain_tip = self.stacks_chain_tip.as_ref()?;
+        Some(GetPendingRequestsParams {
+            bitcoin_chain_tip: &self.bitcoin_chain_tip,
+            stacks_chain_tip: &stacks_chain_tip.block_hash,
+            aggregate_key: &self.aggregate_key,
+            sbtc_limits: &self.sbtc_limits,
+            signature_threshold,
+        })
+    }
+}
+
 /// This function defines which messages this event loop is interested
 /// in.
 fn run_loop_message_filter(signal: &SignerSignal) -> bool {
@@ -230,17 +435,17 @@ where
         while let Some(message) = signal_stream.next().await {
             match message {
                 SignerSignal::Command(SignerCommand::Shutdown) => break,
-                SignerSignal::Command(SignerCommand::P2PPublish(_)) => {}
+                SignerSignal::Command(
+                    SignerCommand::P2PPublish(_) | SignerCommand::ResyncBitcoinBlocks(_),
+                ) => {}
                 SignerSignal::Event(event) => {
                     if let SignerEvent::RequestDecider(RequestDeciderEvent::NewRequestsHandled) =
                         event
                     {
                         tracing::debug!("received signal; processing requests");
                         if let Err(error) = self.process_new_blocks().await {
-                            tracing::error!(
-                                %error,
-                                "error processing requests; skipping this round"
-                            );
+                            let doing = "process requests; skipping this round";
+                            report_error(&self.context, EVENT_LOOP, &error, doing);
                         }
                         tracing::trace!("sending tenure completed signal");
                         self.context
@@ -255,6 +460,126 @@ where
         Ok(())
     }
 
+    /// Run the coordinator event loop of a watcher. Instead of acting as
+    /// the coordinator, a watcher computes what the coordinator of each
+    /// tenure is expected to do, see [`Self::observe_new_blocks`].
+    #[tracing::instrument(skip_all, name = "tx-coordinator-watcher")]
+    pub async fn run_as_watcher(mut self) -> Result<(), Error> {
+        tracing::info!("starting transaction coordinator event loop as a watcher");
+        let mut signal_stream = self.context.as_signal_stream(run_loop_message_filter);
+
+        while let Some(message) = signal_stream.next().await {
+            match message {
+                SignerSignal::Command(SignerCommand::Shutdown) => break,
+                SignerSignal::Event(SignerEvent::RequestDecider(
+                    RequestDeciderEvent::NewRequestsHandled,
+                )) => {
+                    if let Err(error) = self.observe_new_blocks().await {
+                        let doing = "observe the new bitcoin block";
+                        report_error(&self.context, EVENT_LOOP, &error, doing);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        tracing::info!("transaction coordinator event loop is stopping");
+
+        Ok(())
+    }
+
+    /// Compute the work that the coordinator of the current tenure is
+    /// expected to do, along with the sweep transactions that it is
+    /// expected to construct, without doing any of it.
+    ///
+    /// The expected plan of the tenure is stored in the database with the
+    /// public key of the expected coordinator, so that it can be compared
+    /// with what the signers actually did.
+    #[tracing::instrument(skip_all, fields(
+        bitcoin_tip_hash = tracing::field::Empty,
+        bitcoin_tip_height = tracing::field::Empty,
+    ))]
+    pub async fn observe_new_blocks(&mut self) -> Result<(), Error> {
+        if !self.is_epoch3().await? {
+            return Ok(());
+        }
+
+        let bitcoin_chain_tip = self
+            .context
+            .state()
+            .bitcoin_chain_tip()
+            .ok_or(Error::NoChainTip)?;
+
+        let span = tracing::Span::current();
+        span.record(
+            "bitcoin_tip_hash",
+            tracing::field::display(bitcoin_chain_tip.block_hash),
+        );
+        span.record("bitcoin_tip_height", *bitcoin_chain_tip.block_height);
+
+        // A watcher has no DKG shares, so it only knows the aggregate key
+        // of the signers once it has been rotated in the registry.
+        let Some(signer_set_info) = self.context.state().registry_signer_set_info() else {
+            tracing::debug!("the signers have not rotated their keys yet; nothing to observe");
+            return Ok(());
+        };
+        let aggregate_key = signer_set_info.aggregate_key;
+
+        let coordinator = coordinator_public_key(
+            &bitcoin_chain_tip.block_hash,
+            &self.context.config().signer.bootstrap_signing_set,
+        );
+
+        // A watcher does not do the work of the tenure, so the deadline
+        // only matters for the shape of the tenure context.
+        let tenure_budget = self.context.config().signer.coordinator_tenure_budget();
+        let tenure_deadline = self.context.clock().now() + tenure_budget;
+        let tenure_fut = self.start_tenure(bitcoin_chain_tip, aggregate_key, tenure_deadline);
+        let mut tenure = tenure_fut.await?;
+
+        let mut plan = self.compute_tenure_plan(&tenure).await?;
+        if let Some(coordinator) = coordinator {
+            plan.coordinator_public_key = coordinator;
+        }
+
+        tracing::info!(
+            coordinator = %plan.coordinator_public_key,
+            deploy_contracts = %plan.deploy_contracts,
+            rotate_keys = %plan.rotate_keys,
+            deposits_to_sweep = %plan.deposits_to_sweep,
+            withdrawals_to_sweep = %plan.withdrawals_to_sweep,
+            deposits_to_complete = %plan.deposits_to_complete,
+            withdrawals_to_accept = %plan.withdrawals_to_accept,
+            withdrawals_to_reject = %plan.withdrawals_to_reject,
+            "computed the expected plan for this tenure"
+        );
+        self.context
+            .get_storage_mut()
+            .write_tenure_plan(&plan)
+            .await?;
+
+        if tenure.stacks_chain_tip.is_none() {
+            return Ok(());
+        }
+
+        let pending_requests_fut =
+            self.get_pending_requests(&mut tenure, &signer_set_info.signer_set);
+        let Some(pending_requests) = pending_requests_fut.await? else {
+            return Ok(());
+        };
+
+        for transaction in pending_requests.construct_transactions()? {
+            tracing::info!(
+                txid = %transaction.tx.compute_txid(),
+                num_requests = transaction.requests.len(),
+                tx_fee = transaction.tx_fee,
+                "expected sweep transaction for this tenure"
+            );
+        }
+
+        Ok(())
+    }
+
     /// A function that filters the [`Context::as_signal_stream`] stream
     /// for items that the coordinator might care about, which includes
     /// some network messages and transaction signer messages.
@@ -290,16 +615,27 @@ where
         public_key = %self.signer_public_key(),
         bitcoin_tip_hash = tracing::field::Empty,
         bitcoin_tip_height = tracing::field::Empty,
+        tenure_id = tracing::field::Empty,
     ))]
     pub async fn process_new_blocks(&mut self) -> Result<(), Error> {
         if !self.is_epoch3().await? {
             return Ok(());
         }
 
-        let bitcoin_processing_delay = self.context.config().signer.bitcoin_processing_delay;
+        // The tenure starts when we observe the new bitcoin block, so the
+        // processing delay below counts against the tenure budget.
+        let tenure_budget = self.context.config().signer.coordinator_tenure_budget();
+        let tenure_deadline = self.context.clock().now() + tenure_budget;
+
+        let bitcoin_processing_delay = self
+            .context
+            .config()
+            .signer
+            .coordinator_delay()
+            .sample(&mut rand::rngs::OsRng);
         if bitcoin_processing_delay > Duration::ZERO {
             tracing::debug!("sleeping before processing new bitcoin block");
-            tokio::time::sleep(bitcoin_processing_delay).await;
+            self.context.clock().sleep(bitcoin_processing_delay).await;
         }
 
         let bitcoin_chain_tip = self
@@ -330,6 +666,27 @@ where
             return Ok(());
         }
 
+        // The operator may have paused coordinator duties on this signer,
+        // in which case we sit out this tenure.
+        if self.context.state().is_coordinator_paused() {
+            tracing::warn!("we are the coordinator, but coordinator duties are paused");
+            return Ok(());
+        }
+
+        // Signing rounds with too few of the other signers reachable, say
+        // after a partial network partition, are bound to time out.
+        let min_connected_signers = self.context.config().signer.min_connected_signers;
+        let now = self.context.clock().now();
+        let reachable_signers = self.context.state().num_reachable_signers(now);
+        if reachable_signers < usize::from(min_connected_signers) {
+            tracing::warn!(
+                %reachable_signers,
+                %min_connected_signers,
+                "we are the coordinator, but too few signers are reachable"
+            );
+            return Ok(());
+        }
+
         tracing::debug!("we are the coordinator");
         metrics::counter!(Metrics::CoordinatorTenuresTotal).increment(1);
 
@@ -338,9 +695,15 @@ where
             should_coordinate_dkg(&self.context, &bitcoin_chain_tip).await?;
         let aggregate_key = if should_coordinate_dkg {
             match self.coordinate_dkg(&bitcoin_chain_tip).await {
-                Ok(key) => key,
+                Ok(key) => {
+                    self.context
+                        .signal(TxCoordinatorEvent::DkgCompleted(key).into())?;
+                    key
+                }
                 Err(error) => {
                     tracing::error!(%error, "failed to coordinate DKG; using existing aggregate key");
+                    self.context
+                        .signal(TxCoordinatorEvent::DkgFailed(error.to_string()).into())?;
                     registry_signer_set_info
                         .as_ref()
                         .map(|info| info.aggregate_key)
@@ -366,19 +729,39 @@ where
             }
         };
 
-        let chain_tip_hash = &bitcoin_chain_tip.block_hash;
+        let tenure_fut = self.start_tenure(bitcoin_chain_tip, aggregate_key, tenure_deadline);
+        let mut tenure = tenure_fut.await?;
+        span.record("tenure_id", tracing::field::display(tenure.tenure_id()));
+        metrics::gauge!(Metrics::CoordinatorTenureBitcoinBlockHeight)
+            .set(*tenure.bitcoin_chain_tip.block_height as f64);
+
+        if !self.plan_tenure(&mut tenure).await {
+            return Ok(());
+        }
+
+        let chain_tip_hash = &tenure.bitcoin_chain_tip.block_hash;
 
         tracing::debug!("loading the signer stacks wallet");
         let wallet = self.get_signer_wallet().await?;
 
-        self.deploy_smart_contracts(chain_tip_hash, &wallet, &aggregate_key)
+        // Record what happened to the stacks transactions that we
+        // submitted in earlier tenures, so that we know whether the
+        // contract calls for their requests need to be resubmitted.
+        let track_fut = tracker::track_stacks_tx_submissions(&self.context, wallet.address());
+        if let Err(error) = track_fut.await {
+            tracing::warn!(%error, "could not track the status of submitted stacks transactions");
+        }
+
+        self.deploy_smart_contracts(chain_tip_hash, &wallet, &tenure.aggregate_key)
             .await?;
 
-        let rotate_key_txid = self.check_and_submit_rotate_key_transaction(
-            &bitcoin_chain_tip,
-            &wallet,
-            &aggregate_key,
-        );
+        let aggregate_key = &tenure.aggregate_key;
+        let upgrade_fut = self.upgrade_smart_contracts(chain_tip_hash, &wallet, aggregate_key);
+        if let Err(error) = upgrade_fut.await {
+            tracing::warn!(%error, "could not upgrade the sBTC smart contracts");
+        }
+
+        let rotate_key_txid = self.check_and_submit_rotate_key_transaction(&tenure, &wallet);
 
         // If a rotate-keys contract call has been submitted, we stop our
         // tenure to make sure that all signers are up to date with the
@@ -396,36 +779,318 @@ where
             .map(|info| info.signer_set)
             .ok_or_else(|| Error::NoKeyRotationEvent)?;
 
-        let bitcoin_processing_fut = self.construct_and_sign_bitcoin_sbtc_transactions(
-            &bitcoin_chain_tip,
-            &aggregate_key,
-            &signer_public_keys,
-        );
+        self.do_tenure_work(&mut tenure, &wallet, &signer_public_keys)
+            .await?;
+
+        let stage = BlockProcessingStage::TenureCompleted;
+        block_processing::record_stage(&self.context, &tenure.tenure_id(), stage).await;
+        tracing::debug!("coordinator tenure completed successfully");
+
+        Ok(())
+    }
 
+    /// Do the work of the tenure in order of priority: first the
+    /// withdrawal rejections, then the stacks transactions for swept
+    /// requests, and finally the sweeps. If we run out of time then the
+    /// remaining work is deferred to the next tenure, rather than
+    /// overrunning into the tenure of the next coordinator.
+    async fn do_tenure_work(
+        &mut self,
+        tenure: &mut TenureContext,
+        wallet: &SignerWallet,
+        signer_public_keys: &BTreeSet<PublicKey>,
+    ) -> Result<(), Error> {
+        if self.should_stop_tenure(tenure) {
+            return Ok(());
+        }
+        let fut = self.construct_and_sign_stacks_withdrawal_reject_transactions(tenure, wallet);
+        if let Err(error) = fut.await {
+            tracing::error!(%error, "could not process withdrawal reject transactions on stacks");
+        }
+
+        if self.should_stop_tenure(tenure) {
+            return Ok(());
+        }
+        self.construct_and_sign_stacks_response_transactions(tenure, wallet)
+            .await?;
+
+        let bitcoin_processing_fut =
+            self.construct_and_sign_bitcoin_sbtc_transactions(tenure, signer_public_keys);
         if let Err(error) = bitcoin_processing_fut.await {
             tracing::error!(%error, "failed to construct and sign bitcoin transactions");
         }
 
-        self.construct_and_sign_stacks_response_transactions(
-            &bitcoin_chain_tip,
-            &wallet,
-            &aggregate_key,
-        )
-        .await?;
-        tracing::debug!("coordinator tenure completed successfully");
+        Ok(())
+    }
+
+    /// Start a tenure at the given bitcoin chain tip, loading the stacks
+    /// chain tip and the sBTC limits that its steps work off of.
+    pub async fn start_tenure(
+        &self,
+        bitcoin_chain_tip: model::BitcoinBlockRef,
+        aggregate_key: PublicKey,
+        deadline: Instant,
+    ) -> Result<TenureContext, Error> {
+        let stacks_chain_tip = self
+            .context
+            .get_storage()
+            .get_stacks_chain_tip(&bitcoin_chain_tip.block_hash)
+            .await?;
+
+        Ok(TenureContext {
+            bitcoin_chain_tip,
+            stacks_chain_tip,
+            aggregate_key,
+            sbtc_limits: self.context.state().get_current_limits(),
+            deadline,
+            plan: None,
+            btc_state: None,
+        })
+    }
+
+    /// Plan the given tenure, returning whether there is any work to do
+    /// in it.
+    ///
+    /// Most bitcoin blocks bring no work with them, and for those we skip
+    /// the planning, stacks node calls, fee estimation and Emily calls of
+    /// the rest of the tenure, see [`Self::is_noop_tenure`]. Otherwise we
+    /// publish what we intend to do this tenure before starting on any of
+    /// it, so that a tenure that ends with nothing broadcast can be
+    /// explained after the fact.
+    async fn plan_tenure(&mut self, tenure: &mut TenureContext) -> bool {
+        match self.is_noop_tenure(tenure).await {
+            Ok(true) => {
+                tracing::debug!("there is no work to do this tenure");
+                metrics::counter!(Metrics::CoordinatorNoopTenuresTotal).increment(1);
+                let stage = BlockProcessingStage::TenureCompleted;
+                block_processing::record_stage(&self.context, &tenure.tenure_id(), stage).await;
+                return false;
+            }
+            Ok(false) => {}
+            Err(error) => tracing::warn!(%error, "could not check whether there is work to do"),
+        }
+
+        if let Err(error) = self.publish_tenure_plan(tenure).await {
+            tracing::warn!(%error, "could not publish the plan for this tenure");
+        }
+        true
+    }
+
+    /// Compute the plan for this tenure, then log it, store it in the
+    /// database and signal it to the rest of the signer, keeping it in the
+    /// tenure context.
+    #[tracing::instrument(skip_all)]
+    async fn publish_tenure_plan(&mut self, tenure: &mut TenureContext) -> Result<(), Error> {
+        let plan = self.compute_tenure_plan(tenure).await?;
+
+        tracing::info!(
+            deploy_contracts = %plan.deploy_contracts,
+            rotate_keys = %plan.rotate_keys,
+            deposits_to_sweep = %plan.deposits_to_sweep,
+            withdrawals_to_sweep = %plan.withdrawals_to_sweep,
+            deposits_to_complete = %plan.deposits_to_complete,
+            withdrawals_to_accept = %plan.withdrawals_to_accept,
+            withdrawals_to_reject = %plan.withdrawals_to_reject,
+            "planned the work for this tenure"
+        );
 
+        self.context
+            .get_storage_mut()
+            .write_tenure_plan(&plan)
+            .await?;
+        self.context
+            .signal(TxCoordinatorEvent::TenurePlanned(plan.clone()).into())?;
+
+        tenure.plan = Some(plan);
         Ok(())
     }
 
+    /// Whether there is nothing for us to do in the given tenure.
+    ///
+    /// This is checked before the tenure is planned, so it only asks
+    /// whether any work exists at all and leaves the selection of the
+    /// requests to the plan. There is no work when the smart contracts are
+    /// deployed and the keys need no rotation, and there are no contract
+    /// upgrades to check for, no emergency recovery sweep or refunds to
+    /// sign, no stacks transactions to track, and no requests that any
+    /// signer accepted, that were swept or that must be rejected. We
+    /// coordinate DKG before the tenure starts, so that is not considered
+    /// here.
+    async fn is_noop_tenure(&mut self, tenure: &TenureContext) -> Result<bool, Error> {
+        if self.context.config().signer.contract_upgrades_enabled
+            || !self.all_smart_contracts_deployed().await?
+        {
+            return Ok(false);
+        }
+
+        let bitcoin_chain_tip = &tenure.bitcoin_chain_tip;
+        let storage = self.context.get_storage();
+        if let Some(last_dkg) = storage.get_latest_encrypted_dkg_shares().await? {
+            let current_aggregate_key = self
+                .context
+                .state()
+                .registry_signer_set_info()
+                .map(|info| info.aggregate_key);
+            let (needs_verification, needs_rotate_key) = assert_rotate_key_action(
+                &self.context,
+                &last_dkg,
+                current_aggregate_key,
+                bitcoin_chain_tip,
+            )?;
+            if needs_verification || needs_rotate_key {
+                return Ok(false);
+            }
+        }
+
+        let sweep_script =
+            emergency_recovery::sweep_script(&self.context, bitcoin_chain_tip).await?;
+        if sweep_script.is_some() {
+            return Ok(false);
+        }
+
+        let chain_tip_hash = &bitcoin_chain_tip.block_hash;
+        let has_refunds = !storage
+            .get_pending_refunds(chain_tip_hash)
+            .await?
+            .is_empty();
+        let has_submissions = !storage
+            .get_pending_stacks_tx_submissions()
+            .await?
+            .is_empty();
+        if has_refunds || has_submissions {
+            return Ok(false);
+        }
+
+        // Deposits that a single signer accepted are either sweepable or
+        // have decisions that we ask the other signers for.
+        let context_window = self.context_window;
+        let has_deposits = !storage
+            .get_pending_accepted_deposit_requests(bitcoin_chain_tip, context_window, 1)
+            .await?
+            .is_empty();
+        let has_withdrawals = match tenure.stacks_chain_tip.as_ref() {
+            Some(stacks_chain_tip) => {
+                let min_height = bitcoin_chain_tip
+                    .block_height
+                    .saturating_sub(WITHDRAWAL_BLOCKS_EXPIRY);
+                !storage
+                    .get_pending_accepted_withdrawal_requests(
+                        chain_tip_hash,
+                        &stacks_chain_tip.block_hash,
+                        min_height,
+                        1,
+                    )
+                    .await?
+                    .is_empty()
+            }
+            None => false,
+        };
+        if has_deposits || has_withdrawals {
+            return Ok(false);
+        }
+
+        let has_swept_deposits = !storage
+            .get_swept_deposit_requests(chain_tip_hash, context_window)
+            .await?
+            .is_empty();
+        let has_swept_withdrawals = !storage
+            .get_swept_withdrawal_requests(chain_tip_hash, context_window)
+            .await?
+            .is_empty();
+        let has_rejections = !storage
+            .get_pending_rejected_withdrawal_requests(bitcoin_chain_tip, context_window)
+            .await?
+            .is_empty();
+        Ok(!has_swept_deposits && !has_swept_withdrawals && !has_rejections)
+    }
+
+    /// Compute a summary of the work that we intend to do this tenure from
+    /// the state of the smart contracts and the requests in the database.
+    async fn compute_tenure_plan(
+        &mut self,
+        tenure: &TenureContext,
+    ) -> Result<model::TenurePlan, Error> {
+        let bitcoin_chain_tip = &tenure.bitcoin_chain_tip;
+        let storage = self.context.get_storage();
+        let count = |len: usize| u32::try_from(len).map_err(|_| Error::TypeConversion);
+
+        let deploy_contracts = !self.all_smart_contracts_deployed().await?;
+
+        // We only submit a rotate-keys contract call once the smart
+        // contracts have been deployed.
+        let last_dkg = storage.get_latest_encrypted_dkg_shares().await?;
+        let rotate_keys = match last_dkg {
+            Some(last_dkg) if !deploy_contracts => {
+                let current_aggregate_key = self
+                    .context
+                    .state()
+                    .registry_signer_set_info()
+                    .map(|info| info.aggregate_key);
+                let (_, needs_rotate_key) = assert_rotate_key_action(
+                    &self.context,
+                    &last_dkg,
+                    current_aggregate_key,
+                    bitcoin_chain_tip,
+                )?;
+                needs_rotate_key
+            }
+            _ => false,
+        };
+
+        let params = tenure.pending_requests_params(self.threshold);
+        let (deposits_to_sweep, withdrawals_to_sweep) = match params {
+            Some(params) => {
+                let deposits = self
+                    .get_sweepable_deposit_requests(&storage, &params)
+                    .await?;
+                let withdrawals = self
+                    .get_sweepable_withdrawal_requests(&storage, &params)
+                    .await?;
+                (count(deposits.len())?, count(withdrawals.len())?)
+            }
+            None => (0, 0),
+        };
+
+        let depth = self.sweep_confirmation_depth();
+        let deposits_to_complete = storage
+            .get_swept_deposit_requests(&bitcoin_chain_tip.block_hash, self.context_window)
+            .await?
+            .iter()
+            .filter(|req| bitcoin_chain_tip.confirmations(req.sweep_block_height) >= depth)
+            .count();
+        let withdrawals_to_accept = storage
+            .get_swept_withdrawal_requests(&bitcoin_chain_tip.block_hash, self.context_window)
+            .await?
+            .iter()
+            .filter(|req| bitcoin_chain_tip.confirmations(req.sweep_block_height) >= depth)
+            .count();
+        let withdrawals_to_reject = storage
+            .get_pending_rejected_withdrawal_requests(bitcoin_chain_tip, self.context_window)
+            .await?
+            .len();
+
+        Ok(model::TenurePlan {
+            bitcoin_chain_tip: bitcoin_chain_tip.block_hash,
+            coordinator_public_key: self.signer_public_key(),
+            deploy_contracts,
+            rotate_keys,
+            deposits_to_sweep,
+            withdrawals_to_sweep,
+            deposits_to_complete: count(deposits_to_complete)?,
+            withdrawals_to_accept: count(withdrawals_to_accept)?,
+            withdrawals_to_reject: count(withdrawals_to_reject)?,
+        })
+    }
+
     /// Submit the rotate key tx for the latest DKG shares, if the aggregate key
     /// differs from the one in the smart contract registry
     #[tracing::instrument(skip_all)]
     async fn check_and_submit_rotate_key_transaction(
         &mut self,
-        bitcoin_chain_tip: &model::BitcoinBlockRef,
+        tenure: &TenureContext,
         wallet: &SignerWallet,
-        aggregate_key: &PublicKey,
     ) -> Result<Option<StacksTxId>, Error> {
+        let bitcoin_chain_tip = &tenure.bitcoin_chain_tip;
         if !self.all_smart_contracts_deployed().await? {
             return Ok(None);
         }
@@ -479,7 +1144,7 @@ where
             // current_aggregate_key define which wallet can sign stacks tx interacting
             // with the registry smart contract; fallbacks to `aggregate_key` if it's
             // the first rotate key tx.
-            let signing_key = &current_aggregate_key.unwrap_or(*aggregate_key);
+            let signing_key = &current_aggregate_key.unwrap_or(tenure.aggregate_key);
 
             // Construct, sign and submit the rotate key transaction.
             tracing::info!("preparing to submit a rotate-key transaction");
@@ -496,22 +1161,76 @@ where
                 )?;
 
             tracing::info!(%txid, "rotate-key transaction submitted successfully");
+            let _ = self
+                .context
+                .get_storage_mut()
+                .write_aggregate_key_rotation(&last_dkg.aggregate_key, &txid)
+                .await
+                .inspect_err(
+                    |error| tracing::warn!(%error, "could not record the rotate-key transaction"),
+                );
             return Ok(Some(txid));
         }
 
         Ok(None)
     }
 
-    /// Constructs a BitcoinPreSignRequest from the given transaction package and
-    /// sends it to the signers. Waits for acknowledgments from the signers until
-    /// the threshold is met or a timeout occurs.
-    /// If the signal stream closes unexpectedly, triggers a shutdown.
-    #[tracing::instrument(skip_all)]
-    async fn construct_and_send_bitcoin_presign_request(
-        &mut self,
-        bitcoin_chain_tip: &model::BitcoinBlockHash,
-        signer_btc_state: &utxo::SignerBtcState,
+    /// Assemble the quorum certificates for the deposit requests in the
+    /// given transaction package from the decision receipts in our
+    /// database.
+    ///
+    /// The signers reject a pre-sign request with a deposit request that
+    /// has no certificate, so this returns an error if we do not have
+    /// enough receipts from the given signing set for any of them.
+    async fn get_deposit_certificates(
+        &self,
+        transaction_package: &[utxo::UnsignedTransaction<'_>],
+        signer_public_keys: &BTreeSet<PublicKey>,
+    ) -> Result<Vec<DepositQuorumCertificate>, Error> {
+        let storage = self.context.get_storage();
+        let mut certificates = Vec::new();
+
+        let outpoints = transaction_package
+            .iter()
+            .flat_map(|tx| tx.requests.iter())
+            .filter_map(|request| request.as_deposit())
+            .map(|deposit| deposit.outpoint);
+
+        for outpoint in outpoints {
+            let certificate = quorum::assemble_deposit_certificate(
+                &storage,
+                &outpoint,
+                signer_public_keys,
+                self.threshold,
+            )
+            .await?
+            .ok_or(Error::MissingDepositQuorumCertificate(outpoint))?;
+            certificates.push(certificate);
+        }
+
+        Ok(certificates)
+    }
+
+    /// Constructs a BitcoinPreSignRequest from the given transaction package and
+    /// sends it to the signers. Waits for acknowledgments from the signers until
+    /// the threshold is met or a timeout occurs.
+    /// If the signal stream closes unexpectedly, triggers a shutdown.
+    ///
+    /// Signers that do not accept the request send a refusal with the
+    /// reason. Once enough of them have refused for the threshold to be out
+    /// of reach, this returns [`Error::SignRequestRefused`] right away, so
+    /// that the requests can be planned again without waiting for the
+    /// timeout.
+    ///
+    /// A quorum certificate from the given signing set is attached for
+    /// each deposit request in the package.
+    #[tracing::instrument(skip_all)]
+    async fn construct_and_send_bitcoin_presign_request(
+        &mut self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+        signer_btc_state: &utxo::SignerBtcState,
         transaction_package: &[utxo::UnsignedTransaction<'_>],
+        signer_public_keys: &BTreeSet<PublicKey>,
     ) -> Result<(), Error> {
         // Constructing a pre-sign request with empty request IDs is
         // invalid. The other signers should reject the message if we send
@@ -526,10 +1245,29 @@ where
                 .iter()
                 .map(|tx| (&tx.requests).into())
                 .collect(),
-            fee_rate: signer_btc_state.fee_rate,
+            fee_rate: signer_btc_state.fee_rate.to_sat_per_vb(),
             last_fees: signer_btc_state.last_fees,
+            deposit_certificates: self
+                .get_deposit_certificates(transaction_package, signer_public_keys)
+                .await?,
+            refunds: Vec::new(),
+            sweep_template_version: Some(self.context.config().signer.proposed_sweep_template()),
+            emergency_recovery: false,
         };
 
+        self.send_bitcoin_presign_request(bitcoin_chain_tip, sbtc_requests, signer_public_keys)
+            .await
+    }
+
+    /// Sends the given BitcoinPreSignRequest to the signers and waits for
+    /// their acknowledgments until the threshold is met, enough of them
+    /// have refused, or a timeout occurs.
+    async fn send_bitcoin_presign_request(
+        &mut self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+        sbtc_requests: BitcoinPreSignRequest,
+        signer_public_keys: &BTreeSet<PublicKey>,
+    ) -> Result<(), Error> {
         let presign_ack_filter = |event: &SignerSignal| {
             matches!(
                 event,
@@ -549,6 +1287,7 @@ where
         let future = async {
             let target_tip = *bitcoin_chain_tip;
             let mut acknowledged_signers = HashSet::new();
+            let mut refusals = BTreeMap::new();
 
             while acknowledged_signers.len() < self.threshold as usize {
                 match signal_stream.next().await {
@@ -581,6 +1320,40 @@ where
                                 );
                             }
                         }
+                        Some(Signed {
+                            inner:
+                                SignerMessage {
+                                    bitcoin_chain_tip,
+                                    payload: Payload::SignRequestRefusal(refusal),
+                                },
+                            signer_public_key,
+                            ..
+                        }) if bitcoin_chain_tip == target_tip
+                            && refusal.request == RefusedSignRequest::BitcoinPreSign =>
+                        {
+                            tracing::warn!(
+                                signer = %signer_public_key,
+                                reason = %refusal.reason,
+                                num_contested_deposits = refusal.contested.deposits.len(),
+                                num_contested_withdrawals = refusal.contested.withdrawals.len(),
+                                "signer refused the bitcoin presign request"
+                            );
+                            if !signer_public_keys.contains(&signer_public_key) {
+                                continue;
+                            }
+                            refusals.insert(signer_public_key, refusal);
+                            // Once enough signers have refused, there is
+                            // no point in waiting for the rest of them.
+                            if quorum_is_unreachable(
+                                signer_public_keys.len(),
+                                refusals.len(),
+                                self.threshold,
+                            ) {
+                                return Err(Error::SignRequestRefused(
+                                    refusals.into_values().collect(),
+                                ));
+                            }
+                        }
                         // We can ignore other types of payload
                         _ => continue,
                     },
@@ -591,9 +1364,11 @@ where
         };
 
         let instant = std::time::Instant::now();
+        let clock = self.context.clock().clone();
 
         // Wait for the future to complete with a timeout
-        let res = tokio::time::timeout(self.bitcoin_presign_request_max_duration, future)
+        let res = clock
+            .timeout(self.bitcoin_presign_request_max_duration, future)
             .await
             .map_err(|_| {
                 Error::CoordinatorTimeout(self.bitcoin_presign_request_max_duration.as_secs())
@@ -623,6 +1398,48 @@ where
         res?
     }
 
+    /// Return the keys locking the pending deposits whose DKG shares have
+    /// not been verified, when the deposits are locked by more than one
+    /// key.
+    ///
+    /// The signers do not sign for deposits locked by keys with
+    /// unverified shares, so a transaction sweeping them along with
+    /// deposits locked by an older key could not be signed, and none of
+    /// its requests would be swept.
+    async fn locking_keys_to_sweep_separately(
+        &self,
+        pending_requests: &utxo::SbtcRequests,
+    ) -> Result<HashSet<bitcoin::XOnlyPublicKey>, Error> {
+        let locking_keys: HashSet<bitcoin::XOnlyPublicKey> = pending_requests
+            .deposits
+            .iter()
+            .map(|deposit| deposit.signers_public_key)
+            .collect();
+
+        if locking_keys.len() < 2 {
+            return Ok(HashSet::new());
+        }
+
+        let db = self.context.get_storage();
+        let mut separate_keys = HashSet::new();
+        for key in locking_keys {
+            let status = db
+                .get_encrypted_dkg_shares(key)
+                .await?
+                .map(|shares| shares.dkg_shares_status);
+
+            if status != Some(model::DkgSharesStatus::Verified) {
+                tracing::info!(
+                    locking_key = %key,
+                    "sweeping deposits locked by a key with unverified DKG shares separately"
+                );
+                separate_keys.insert(key);
+            }
+        }
+
+        Ok(separate_keys)
+    }
+
     /// Construct and coordinate WSTS signing rounds for sBTC transactions on Bitcoin,
     /// fulfilling pending deposit and withdraw requests.
     #[tracing::instrument(skip_all, fields(
@@ -631,60 +1448,245 @@ where
     ))]
     async fn construct_and_sign_bitcoin_sbtc_transactions(
         &mut self,
-        bitcoin_chain_tip: &model::BitcoinBlockRef,
-        aggregate_key: &PublicKey,
+        tenure: &mut TenureContext,
         signer_public_keys: &BTreeSet<PublicKey>,
     ) -> Result<(), Error> {
-        let storage = self.context.get_storage();
+        if self.should_stop_tenure(tenure) {
+            return Ok(());
+        }
 
-        // Fetch the stacks chain tip from the database.
-        let stacks_chain_tip = storage
-            .get_stacks_chain_tip(&bitcoin_chain_tip.block_hash)
-            .await?
-            .ok_or(Error::NoStacksChainTip)?;
+        // Once an emergency recovery is under way, the signers do nothing
+        // on bitcoin but sweep their funds to the recovery scriptPubKey.
+        let sweep_script =
+            emergency_recovery::sweep_script(&self.context, &tenure.bitcoin_chain_tip).await?;
+        if let Some(recovery_script_pubkey) = sweep_script {
+            return self
+                .construct_and_sign_recovery_sweep(
+                    tenure,
+                    signer_public_keys,
+                    recovery_script_pubkey,
+                )
+                .await;
+        }
 
+        // Sweeps that are still in the queue of the broadcaster spend the
+        // signers' UTXO that a new sweep would spend, so we leave the
+        // requests alone until the broadcaster gets through them.
+        if self.context.config().signer.async_sweep_broadcast {
+            let has_queued_sweeps = self
+                .context
+                .get_storage()
+                .get_unsettled_bitcoin_broadcasts()
+                .await?
+                .iter()
+                .any(|broadcast| broadcast.status == model::BitcoinBroadcastStatus::Pending);
+            if has_queued_sweeps {
+                tracing::info!("sweeps are still queued for broadcast; skipping bitcoin requests");
+                return Ok(());
+            }
+        }
+
+        let stacks_chain_tip = tenure.require_stacks_chain_tip()?;
         let span = tracing::Span::current();
         span.record("stacks_tip_hash", stacks_chain_tip.block_hash.to_hex());
         span.record("stacks_tip_height", *stacks_chain_tip.block_height);
 
+        let bitcoin_chain_tip = tenure.bitcoin_chain_tip;
+
+        // Ask the signers for any deposit decisions that we are missing.
+        // Deposits without enough decisions are left out of the sweep
+        // below, but may make it into the next one.
+        let _ = self
+            .request_missing_deposit_decisions(&bitcoin_chain_tip, &tenure.aggregate_key)
+            .await
+            .inspect_err(|error| {
+                tracing::warn!(%error, "error requesting missing deposit decisions");
+            });
+
         // Create a future that fetches pending deposit and withdrawal requests
         // from the database.
-        let pending_requests_fut = self.get_pending_requests(
-            bitcoin_chain_tip,
-            &stacks_chain_tip.block_hash,
-            aggregate_key,
-            signer_public_keys,
-        );
+        let pending_requests_fut = self.get_pending_requests(tenure, signer_public_keys);
 
         // If `get_pending_requests()` returns `Ok(None)` then there are no
-        // eligible requests to service; we can exit early.
-        let Some(pending_requests) = pending_requests_fut.await? else {
+        // eligible requests to service, so we use the tenure for refunds.
+        let Some(mut pending_requests) = pending_requests_fut.await? else {
             tracing::debug!("no requests to handle on bitcoin");
-            return Ok(());
+            return self
+                .construct_and_sign_refunds(tenure, signer_public_keys)
+                .await;
         };
 
+        // Our database may lag behind bitcoin-core, and depositors can
+        // reclaim their deposits at any time after the lock time, so we
+        // leave out deposits whose UTXOs are already spent instead of
+        // learning about it when broadcasting the sweep.
+        let spent_deposits = find_spent_sweep_inputs(
+            &self.context.get_bitcoin_client(),
+            pending_requests.signer_state.utxo.outpoint,
+            pending_requests
+                .deposits
+                .iter()
+                .map(|deposit| deposit.outpoint),
+        )
+        .await?;
+        pending_requests
+            .deposits
+            .retain(|deposit| !spent_deposits.contains(&deposit.outpoint));
+
+        if pending_requests.deposits.is_empty() && pending_requests.withdrawals.is_empty() {
+            tracing::debug!("no requests with unspent inputs to handle on bitcoin");
+            return self
+                .construct_and_sign_refunds(tenure, signer_public_keys)
+                .await;
+        }
+
         tracing::debug!(
             num_deposits = %pending_requests.deposits.len(),
             num_withdrawals = pending_requests.withdrawals.len(),
             "there are eligible requests to handle"
         );
 
-        // Construct the transaction package and store it in the database.
-        let transaction_package = pending_requests.construct_transactions()?;
+        // Deposits locked by keys that the signers may not be able to sign
+        // for are swept in transactions of their own, so that they do not
+        // hold up the other requests.
+        let separate_keys = self
+            .locking_keys_to_sweep_separately(&pending_requests)
+            .await?;
 
         // Send the pre-sign request to the signers and wait for their
-        // acknowledgments.
-        self.construct_and_send_bitcoin_presign_request(
-            bitcoin_chain_tip.as_ref(),
-            &pending_requests.signer_state,
-            &transaction_package,
-        )
-        .await?;
+        // acknowledgments. If enough of them refuse the request because
+        // of requests that they consider invalid, we try again without
+        // those requests, up to a fixed number of times.
+        let mut retries = 0;
+        loop {
+            let transaction_package =
+                pending_requests.construct_transactions_by_locking_key(&separate_keys)?;
+            let result = self
+                .construct_and_send_bitcoin_presign_request(
+                    bitcoin_chain_tip.as_ref(),
+                    &pending_requests.signer_state,
+                    &transaction_package,
+                    signer_public_keys,
+                )
+                .await;
+
+            let refusals = match result {
+                Ok(()) => break,
+                Err(Error::SignRequestRefused(refusals)) if retries < MAX_PRESIGN_RETRIES => {
+                    refusals
+                }
+                Err(error) => return Err(error),
+            };
+
+            let contested_deposits: HashSet<OutPoint> = refusals
+                .iter()
+                .flat_map(|refusal| refusal.contested.deposits.iter().copied())
+                .collect();
+            let contested_withdrawals: HashSet<model::QualifiedRequestId> = refusals
+                .iter()
+                .flat_map(|refusal| refusal.contested.withdrawals.iter().copied())
+                .collect();
+
+            if contested_deposits.is_empty() && contested_withdrawals.is_empty() {
+                return Err(Error::SignRequestRefused(refusals));
+            }
+
+            pending_requests
+                .deposits
+                .retain(|deposit| !contested_deposits.contains(&deposit.outpoint));
+            pending_requests
+                .withdrawals
+                .retain(|withdrawal| !contested_withdrawals.contains(&withdrawal.qualified_id()));
+
+            retries += 1;
+            tracing::info!(
+                retries,
+                num_contested_deposits = contested_deposits.len(),
+                num_contested_withdrawals = contested_withdrawals.len(),
+                "retrying the presign request without the contested requests"
+            );
+
+            if pending_requests.deposits.is_empty() && pending_requests.withdrawals.is_empty() {
+                tracing::debug!("no uncontested requests to handle on bitcoin");
+                return Ok(());
+            }
+        }
+
+        // Construction is deterministic, so this is the same package that
+        // the signers acknowledged.
+        let transaction_package =
+            pending_requests.construct_transactions_by_locking_key(&separate_keys)?;
+
+        // Construct and sign the bitcoin transactions. They are broadcast
+        // together afterward, so that bitcoin-core can evaluate them as a
+        // package.
+        let mut signed_transactions = Vec::new();
+        let mut result = Ok(());
 
-        // Construct, sign and broadcast the bitcoin transactions.
         for mut transaction in transaction_package {
-            self.sign_and_broadcast(bitcoin_chain_tip.as_ref(), &mut transaction)
-                .await?;
+            // Each transaction in the package spends the signers' UTXO
+            // created by the one before it, so we can stop after any of
+            // them and pick up the rest of the requests next tenure.
+            if self.should_stop_tenure(tenure) {
+                break;
+            }
+
+            // Make sure that no other signing round is spending the same
+            // prevouts. If one is, then the rest of this package conflicts
+            // with it, and we leave the requests for the next tenure to
+            // plan again.
+            let txid = transaction.tx.compute_txid();
+            let chain_tip = bitcoin_chain_tip.as_ref();
+            match self.lock_prevouts(chain_tip, &transaction.tx).await {
+                Ok(()) => {}
+                Err(error @ Error::PrevoutSpendConflict { .. }) => {
+                    tracing::warn!(%error, %txid, "skipping conflicting bitcoin transaction");
+                    break;
+                }
+                Err(error) => {
+                    result = Err(error);
+                    break;
+                }
+            }
+
+            let signed = self.sign_sweep(chain_tip, &mut transaction).await;
+            self.unlock_prevouts(&txid).await;
+            if let Err(error) = signed {
+                result = Err(error);
+                break;
+            }
+
+            signed_transactions.push(transaction);
+        }
+
+        // Broadcast the signed transactions, or hand them to the
+        // broadcaster and wait for it, keeping track of what bitcoin-core
+        // accepted so that we only attest to that.
+        let mut attestation = CoordinatorAttestation::default();
+        if !signed_transactions.is_empty() {
+            let chain_tip = bitcoin_chain_tip.as_ref();
+            let response = if self.context.config().signer.async_sweep_broadcast {
+                self.queue_sweeps(chain_tip, &signed_transactions, tenure.deadline)
+                    .await
+            } else {
+                self.broadcast_sweeps(chain_tip, &signed_transactions).await
+            };
+            match response {
+                Ok(true) => {}
+                Ok(false) => signed_transactions.clear(),
+                Err(error) => {
+                    result = Err(error);
+                    signed_transactions.clear();
+                }
+            }
+        }
+
+        for transaction in signed_transactions {
+            let txid = transaction.tx.compute_txid();
+            attestation
+                .request_package
+                .push((&transaction.requests).into());
+            attestation.sweep_txids.push(txid.into());
 
             // TODO: if this (considering also fallback clients) fails, we will
             // need to handle the inconsistency of having the sweep tx confirmed
@@ -708,11 +1710,194 @@ where
                 });
         }
 
+        // We attest to what we did even when nothing was broadcast, so
+        // that a tenure that signed no sweeps is on the record too.
+        self.send_attestation(attestation, bitcoin_chain_tip.as_ref())
+            .await?;
+
+        result
+    }
+
+    /// Sign and broadcast our attestation of the sweeps that we broadcast
+    /// this tenure, storing it the same way that the signers store the
+    /// attestations that they receive.
+    async fn send_attestation(
+        &mut self,
+        attestation: CoordinatorAttestation,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<(), Error> {
+        tracing::debug!(
+            num_transactions = attestation.sweep_txids.len(),
+            "sending coordinator attestation"
+        );
+        let msg = Payload::from(attestation.clone())
+            .to_message(*bitcoin_chain_tip)
+            .sign_ecdsa(&self.private_key);
+
+        let storage = self.context.get_storage_mut();
+        if let Err(error) = attestation::store_attestation(&storage, &attestation, &msg).await {
+            tracing::warn!(%error, "could not store our coordinator attestation");
+        }
+
+        self.send_signed_message(msg).await
+    }
+
+    /// Construct, sign and broadcast the refunds of payments to the
+    /// signers that the operator of this signer approved.
+    ///
+    /// Refunds are only handled in tenures without sweep transactions, so
+    /// that they never hold up sBTC requests. Each of the other signers
+    /// only signs the refunds that their own operator approved.
+    #[tracing::instrument(skip_all)]
+    async fn construct_and_sign_refunds(
+        &mut self,
+        tenure: &mut TenureContext,
+        signer_public_keys: &BTreeSet<PublicKey>,
+    ) -> Result<(), Error> {
+        let bitcoin_chain_tip = tenure.bitcoin_chain_tip;
+        let payments = self
+            .context
+            .get_storage()
+            .get_pending_refunds(&bitcoin_chain_tip.block_hash)
+            .await?;
+
+        if payments.is_empty() {
+            return Ok(());
+        }
+
+        let signer_state = self.tenure_btc_state(tenure).await?;
+
+        let refunds: Vec<utxo::UnsignedRefundTransaction> = payments
+            .iter()
+            .filter(|payment| payment.outpoint() != signer_state.utxo.outpoint)
+            .filter_map(|payment| {
+                let public_key = signer_state.public_key;
+                utxo::UnsignedRefundTransaction::new(payment, public_key, signer_state.fee_rate)
+                    .inspect_err(|error| {
+                        let outpoint = payment.outpoint();
+                        tracing::warn!(%error, %outpoint, "could not construct refund");
+                    })
+                    .ok()
+            })
+            .collect();
+
+        if refunds.is_empty() {
+            return Ok(());
+        }
+
+        tracing::debug!(num_refunds = refunds.len(), "there are refunds to handle");
+
+        let request = BitcoinPreSignRequest {
+            request_package: Vec::new(),
+            fee_rate: signer_state.fee_rate.to_sat_per_vb(),
+            last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: refunds.iter().map(|refund| refund.utxo.outpoint).collect(),
+            // Refunds are not sweeps, so no template applies to them.
+            sweep_template_version: None,
+            emergency_recovery: false,
+        };
+        let chain_tip = bitcoin_chain_tip.as_ref();
+        self.send_bitcoin_presign_request(chain_tip, request, signer_public_keys)
+            .await?;
+
+        for mut refund in refunds {
+            if self.should_stop_tenure(tenure) {
+                break;
+            }
+
+            let txid = refund.tx.compute_txid();
+            match self.lock_prevouts(chain_tip, &refund.tx).await {
+                Ok(()) => {}
+                Err(error @ Error::PrevoutSpendConflict { .. }) => {
+                    tracing::warn!(%error, %txid, "skipping conflicting refund");
+                    continue;
+                }
+                Err(error) => return Err(error),
+            }
+
+            // The other signers decide for themselves whether to sign each
+            // refund, so one refund failing says nothing about the rest.
+            let fee_rate = signer_state.fee_rate;
+            let result = self
+                .sign_and_broadcast_refund(chain_tip, &mut refund, fee_rate)
+                .await;
+            self.unlock_prevouts(&txid).await;
+            if let Err(error) = result {
+                tracing::warn!(%error, %txid, "could not sign and broadcast refund");
+            }
+        }
+
         Ok(())
     }
 
-    /// Construct and coordinate signing rounds for `deposit-accept`,
-    /// `withdraw-accept` and `withdraw-reject` transactions.
+    /// Construct, sign and broadcast the emergency recovery sweep of the
+    /// signers' UTXO, and of the other payments to the signers, to the
+    /// given recovery scriptPubKey.
+    ///
+    /// Each of the other signers rebuilds the sweep on its own and only
+    /// signs it if the emergency recovery is allowed from its point of
+    /// view, see [`crate::emergency_recovery`].
+    #[tracing::instrument(skip_all)]
+    async fn construct_and_sign_recovery_sweep(
+        &mut self,
+        tenure: &mut TenureContext,
+        signer_public_keys: &BTreeSet<PublicKey>,
+        recovery_script_pubkey: bitcoin::ScriptBuf,
+    ) -> Result<(), Error> {
+        let signer_state = self.tenure_btc_state(tenure).await?;
+        let max_fee_rate = self.context.config().signer.emergency_recovery_max_fee_rate;
+        let max_fee_rate = FeeRate::new(max_fee_rate.get() as f64)?;
+        // The other signers refuse to sign sweeps that pay more than their
+        // own bound, which is the same as ours if the configs agree.
+        let fee_rate = if signer_state.fee_rate > max_fee_rate {
+            max_fee_rate
+        } else {
+            signer_state.fee_rate
+        };
+
+        let chain_tip = tenure.bitcoin_chain_tip.as_ref();
+        let payments =
+            emergency_recovery::swept_payments(&self.context, chain_tip, &signer_state.utxo)
+                .await?;
+        let mut recovery = utxo::UnsignedRecoveryTransaction::new(
+            signer_state.utxo,
+            payments,
+            recovery_script_pubkey,
+            fee_rate,
+        )?;
+        let txid = recovery.tx.compute_txid();
+        tracing::warn!(
+            %txid,
+            inputs = %recovery.utxos.len(),
+            amount = %recovery.utxos.iter().map(|utxo| utxo.amount).sum::<u64>(),
+            "starting the emergency recovery sweep of the signers' funds"
+        );
+
+        let request = BitcoinPreSignRequest {
+            request_package: Vec::new(),
+            fee_rate: fee_rate.to_sat_per_vb(),
+            last_fees: None,
+            deposit_certificates: Vec::new(),
+            refunds: Vec::new(),
+            // The recovery sweep is not an sBTC sweep, so no template
+            // applies to it.
+            sweep_template_version: None,
+            emergency_recovery: true,
+        };
+        self.send_bitcoin_presign_request(chain_tip, request, signer_public_keys)
+            .await?;
+
+        self.lock_prevouts(chain_tip, &recovery.tx).await?;
+        let result = self
+            .sign_and_broadcast_recovery_sweep(chain_tip, &mut recovery, fee_rate)
+            .await;
+        self.unlock_prevouts(&txid).await;
+        result
+    }
+
+    /// Construct and coordinate signing rounds for `deposit-accept` and
+    /// `withdraw-accept` transactions.
     ///
     /// # Notes
     ///
@@ -733,24 +1918,15 @@ where
     #[tracing::instrument(skip_all)]
     async fn construct_and_sign_stacks_response_transactions(
         &mut self,
-        chain_tip: &model::BitcoinBlockRef,
+        tenure: &TenureContext,
         wallet: &SignerWallet,
-        bitcoin_aggregate_key: &PublicKey,
     ) -> Result<(), Error> {
-        let fut = self.construct_and_sign_stacks_deposit_response_transactions(
-            chain_tip,
-            wallet,
-            bitcoin_aggregate_key,
-        );
+        let fut = self.construct_and_sign_stacks_deposit_response_transactions(tenure, wallet);
         if let Err(error) = fut.await {
             tracing::error!(%error, "could not process deposit response transactions on stacks");
         }
 
-        let fut = self.construct_and_sign_stacks_withdrawal_response_transactions(
-            chain_tip,
-            wallet,
-            bitcoin_aggregate_key,
-        );
+        let fut = self.construct_and_sign_stacks_withdrawal_response_transactions(tenure, wallet);
         if let Err(error) = fut.await {
             tracing::error!(%error, "could not process withdrawal response transactions on stacks");
         }
@@ -761,10 +1937,11 @@ where
     #[tracing::instrument(skip_all)]
     async fn construct_and_sign_stacks_deposit_response_transactions(
         &mut self,
-        chain_tip: &model::BitcoinBlockRef,
+        tenure: &TenureContext,
         wallet: &SignerWallet,
-        bitcoin_aggregate_key: &PublicKey,
     ) -> Result<(), Error> {
+        let chain_tip = &tenure.bitcoin_chain_tip;
+        let bitcoin_aggregate_key = &tenure.aggregate_key;
         let db = self.context.get_storage();
         let stacks = self.context.get_stacks_client();
         let deployer = self.context.config().signer.deployer;
@@ -777,10 +1954,16 @@ where
         // on the blockchain identified by the chain tip, where an input is
         // the deposit UTXO.
 
-        let swept_deposits = db
+        let mut swept_deposits = db
             .get_swept_deposit_requests(chain_tip.as_ref(), self.context_window)
             .await?;
 
+        // We only respond once the sweep transaction is deep enough in
+        // the bitcoin blockchain, so that a shallow reorg cannot undo a
+        // sweep after we have minted sBTC for it.
+        let depth = self.sweep_confirmation_depth();
+        swept_deposits.retain(|req| chain_tip.confirmations(req.sweep_block_height) >= depth);
+
         if swept_deposits.is_empty() {
             tracing::debug!("no deposit stacks transactions to create");
             return Ok(());
@@ -791,9 +1974,24 @@ where
             "we have deposit requests that may need a response on stacks"
         );
 
+        let max_per_call = self
+            .context
+            .config()
+            .signer
+            .max_deposits_per_complete_call
+            .get();
+        if max_per_call > 1 {
+            let fut = self.construct_and_sign_complete_deposits(
+                tenure,
+                wallet,
+                swept_deposits,
+                max_per_call as usize,
+            );
+            return fut.await;
+        }
+
         for req in swept_deposits {
-            if self.context.state().bitcoin_chain_tip().as_ref() != Some(chain_tip) {
-                tracing::info!("new bitcoin chain tip, stopping coordinator activities");
+            if self.should_stop_tenure(tenure) {
                 return Ok(());
             }
 
@@ -855,51 +2053,155 @@ where
         Ok(())
     }
 
+    /// Complete the given swept deposit requests using as few stacks
+    /// transactions as possible, with at most `max_per_call` deposit
+    /// requests completed in each transaction.
+    ///
+    /// Each transaction takes one signing round, so sweeping many
+    /// deposits takes considerably less of the tenure than completing
+    /// each deposit request in its own transaction.
     #[tracing::instrument(skip_all)]
-    async fn construct_and_sign_stacks_withdrawal_response_transactions(
+    async fn construct_and_sign_complete_deposits(
         &mut self,
-        chain_tip: &model::BitcoinBlockRef,
+        tenure: &TenureContext,
         wallet: &SignerWallet,
-        bitcoin_aggregate_key: &PublicKey,
+        swept_deposits: Vec<model::SweptDepositRequest>,
+        max_per_call: usize,
     ) -> Result<(), Error> {
-        let db = self.context.get_storage();
+        let chain_tip = &tenure.bitcoin_chain_tip;
+        let bitcoin_aggregate_key = &tenure.aggregate_key;
+        let stacks = self.context.get_stacks_client();
+        let deployer = self.context.config().signer.deployer;
 
-        // Fetch withdrawal requests from the database where there has been
-        // a confirmed bitcoin transaction associated with the request.
-        let swept_withdrawals = db
-            .get_swept_withdrawal_requests(&chain_tip.block_hash, self.context_window)
-            .await
-            .inspect_err(|error| tracing::error!(%error, "could not fetch swept withdrawals"))
-            .unwrap_or_default();
+        let mut deposits = Vec::with_capacity(swept_deposits.len());
+        for req in swept_deposits {
+            if self.should_stop_tenure(tenure) {
+                return Ok(());
+            }
 
-        // Fetch withdrawal requests that have not been swept for quite
-        // some time.
-        let rejected_withdrawals = db
-            .get_pending_rejected_withdrawal_requests(chain_tip, self.context_window)
-            .await
-            .inspect_err(|error| tracing::error!(%error, "could not fetch rejected withdrawals"))
-            .unwrap_or_default();
+            let outpoint = req.deposit_outpoint();
 
-        if swept_withdrawals.is_empty() && rejected_withdrawals.is_empty() {
-            tracing::debug!("no withdrawal stacks transactions to create");
-            return Ok(());
-        }
+            let is_completed = stacks.is_deposit_completed(&deployer, &outpoint).await;
+            match is_completed {
+                Err(error) => {
+                    tracing::warn!(%error, %outpoint, "could not check deposit status");
+                    continue;
+                }
+                Ok(true) => {
+                    // The request is already completed according to the contract
+                    continue;
+                }
+                Ok(false) => (),
+            };
 
-        tracing::debug!(
-            swept_withdrawals = %swept_withdrawals.len(),
-            rejected_withdrawals = %rejected_withdrawals.len(),
-            "we have withdrawals requests that may need completion"
-        );
+            match self.complete_deposit_contract_call(req).await {
+                Ok(deposit) => deposits.push(deposit),
+                Err(error) => {
+                    tracing::error!(%error, %outpoint, "could not construct a contract call completing the deposit request");
+                }
+            }
+        }
 
-        for swept_request in swept_withdrawals {
-            if self.context.state().bitcoin_chain_tip().as_ref() != Some(chain_tip) {
-                tracing::info!("new bitcoin chain tip, stopping coordinator activities");
+        for batch in deposits.chunks(max_per_call) {
+            if self.should_stop_tenure(tenure) {
                 return Ok(());
             }
 
-            let withdrawal_id = swept_request.qualified_id();
-            let fut = self.construct_and_sign_withdrawal_accept(
-                chain_tip,
+            let num_deposits = batch.len();
+            let sign_request_fut = self.construct_deposits_stacks_sign_request(
+                batch.to_vec(),
+                bitcoin_aggregate_key,
+                wallet,
+            );
+
+            let (sign_request, multi_tx) = match sign_request_fut.await {
+                Ok(res) => res,
+                Err(error) => {
+                    tracing::error!(%error, %num_deposits, "could not construct a transaction completing the deposit requests");
+                    continue;
+                }
+            };
+
+            let process_request_fut =
+                self.process_sign_request(sign_request, chain_tip.as_ref(), multi_tx, wallet);
+
+            let status = match process_request_fut.await {
+                Ok(txid) => {
+                    tracing::info!(%txid, %num_deposits, "successfully submitted complete-deposits transaction");
+                    "success"
+                }
+                Err(error) => {
+                    tracing::warn!(%error, %num_deposits, "could not process the stacks sign request for deposits");
+                    adjust_nonce(wallet, &error);
+                    "failure"
+                }
+            };
+
+            metrics::counter!(
+                Metrics::TransactionsSubmittedTotal,
+                "blockchain" => STACKS_BLOCKCHAIN,
+                "status" => status,
+                "kind" => "complete-deposits"
+            )
+            .increment(1);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn construct_and_sign_stacks_withdrawal_response_transactions(
+        &mut self,
+        tenure: &TenureContext,
+        wallet: &SignerWallet,
+    ) -> Result<(), Error> {
+        let chain_tip = &tenure.bitcoin_chain_tip;
+        let bitcoin_aggregate_key = &tenure.aggregate_key;
+        let db = self.context.get_storage();
+
+        // Fetch withdrawal requests from the database where there has been
+        // a confirmed bitcoin transaction associated with the request.
+        let mut swept_withdrawals = db
+            .get_swept_withdrawal_requests(&chain_tip.block_hash, self.context_window)
+            .await?;
+
+        let depth = self.sweep_confirmation_depth();
+        swept_withdrawals.retain(|req| chain_tip.confirmations(req.sweep_block_height) >= depth);
+
+        if swept_withdrawals.is_empty() {
+            tracing::debug!("no withdrawal accept stacks transactions to create");
+            return Ok(());
+        }
+
+        tracing::debug!(
+            swept_withdrawals = %swept_withdrawals.len(),
+            "we have withdrawals requests that may need completion"
+        );
+
+        let max_per_call = self
+            .context
+            .config()
+            .signer
+            .max_withdrawals_per_accept_call
+            .get();
+        if max_per_call > 1 {
+            let fut = self.construct_and_sign_withdrawals_accept(
+                tenure,
+                wallet,
+                swept_withdrawals,
+                max_per_call as usize,
+            );
+            return fut.await;
+        }
+
+        for swept_request in swept_withdrawals {
+            if self.should_stop_tenure(tenure) {
+                return Ok(());
+            }
+
+            let withdrawal_id = swept_request.qualified_id();
+            let fut = self.construct_and_sign_withdrawal_accept(
+                chain_tip,
                 wallet,
                 bitcoin_aggregate_key,
                 swept_request,
@@ -914,9 +2216,40 @@ where
             }
         }
 
+        Ok(())
+    }
+
+    /// Construct and coordinate signing rounds for `withdraw-reject`
+    /// transactions, for withdrawal requests that have not been swept for
+    /// too long.
+    #[tracing::instrument(skip_all)]
+    async fn construct_and_sign_stacks_withdrawal_reject_transactions(
+        &mut self,
+        tenure: &TenureContext,
+        wallet: &SignerWallet,
+    ) -> Result<(), Error> {
+        let chain_tip = &tenure.bitcoin_chain_tip;
+        let bitcoin_aggregate_key = &tenure.aggregate_key;
+        let db = self.context.get_storage();
+
+        // Fetch withdrawal requests that have not been swept for quite
+        // some time.
+        let rejected_withdrawals = db
+            .get_pending_rejected_withdrawal_requests(chain_tip, self.context_window)
+            .await?;
+
+        if rejected_withdrawals.is_empty() {
+            tracing::debug!("no withdrawal reject stacks transactions to create");
+            return Ok(());
+        }
+
+        tracing::debug!(
+            rejected_withdrawals = %rejected_withdrawals.len(),
+            "we have withdrawals requests that need to be rejected"
+        );
+
         for withdrawal in rejected_withdrawals {
-            if self.context.state().bitcoin_chain_tip().as_ref() != Some(chain_tip) {
-                tracing::info!("new bitcoin chain tip, stopping coordinator activities");
+            if self.should_stop_tenure(tenure) {
                 return Ok(());
             }
 
@@ -1004,6 +2337,123 @@ where
         Ok(())
     }
 
+    /// Accept the given swept withdrawal requests using as few stacks
+    /// transactions as possible, with at most `max_per_call` withdrawal
+    /// requests accepted in each transaction.
+    ///
+    /// Each transaction gets a single fee estimate and a single nonce, so
+    /// large withdrawal batches cost considerably less in stacks fees than
+    /// accepting each withdrawal request in its own transaction.
+    #[tracing::instrument(skip_all)]
+    async fn construct_and_sign_withdrawals_accept(
+        &mut self,
+        tenure: &TenureContext,
+        wallet: &SignerWallet,
+        swept_withdrawals: Vec<model::SweptWithdrawalRequest>,
+        max_per_call: usize,
+    ) -> Result<(), Error> {
+        let chain_tip = &tenure.bitcoin_chain_tip;
+        let bitcoin_aggregate_key = &tenure.aggregate_key;
+        let stacks = self.context.get_stacks_client();
+        let deployer = self.context.config().signer.deployer;
+
+        let mut withdrawals = Vec::with_capacity(swept_withdrawals.len());
+        for request in swept_withdrawals {
+            if self.should_stop_tenure(tenure) {
+                return Ok(());
+            }
+
+            let withdrawal_id = request.qualified_id();
+            match stacks
+                .is_withdrawal_completed(&deployer, request.request_id)
+                .await
+            {
+                Ok(false) => {}
+                Ok(true) => {
+                    tracing::warn!(%withdrawal_id, "swept withdrawal request already processed");
+                    continue;
+                }
+                Err(error) => {
+                    tracing::warn!(
+                        %error,
+                        %withdrawal_id,
+                        "could not check whether the withdrawal request was completed"
+                    );
+                    continue;
+                }
+            }
+
+            match self.accept_withdrawal_contract_call(&request).await {
+                Ok(withdrawal) => withdrawals.push(withdrawal),
+                Err(error) => {
+                    tracing::warn!(
+                        %error,
+                        %withdrawal_id,
+                        "could not construct withdrawal accept contract call"
+                    );
+                }
+            }
+        }
+
+        for batch in withdrawals.chunks(max_per_call) {
+            if self.should_stop_tenure(tenure) {
+                return Ok(());
+            }
+
+            let num_withdrawals = batch.len();
+            let sign_request_fut = self.construct_withdrawals_accept_stacks_sign_request(
+                batch.to_vec(),
+                bitcoin_aggregate_key,
+                wallet,
+            );
+
+            let (sign_request, multi_tx) = match sign_request_fut.await {
+                Ok(value) => value,
+                Err(error) => {
+                    tracing::warn!(
+                        %error,
+                        %num_withdrawals,
+                        "could not construct withdrawals accept sign request"
+                    );
+                    continue;
+                }
+            };
+
+            let process_request_fut =
+                self.process_sign_request(sign_request, &chain_tip.block_hash, multi_tx, wallet);
+
+            let status = match process_request_fut.await {
+                Ok(txid) => {
+                    tracing::info!(
+                        %txid,
+                        %num_withdrawals,
+                        "successfully submitted accept-withdrawals transaction"
+                    );
+                    "success"
+                }
+                Err(error) => {
+                    tracing::warn!(
+                        %error,
+                        %num_withdrawals,
+                        "could not process the stacks sign request for withdrawals"
+                    );
+                    adjust_nonce(wallet, &error);
+                    "failure"
+                }
+            };
+
+            metrics::counter!(
+                Metrics::TransactionsSubmittedTotal,
+                "blockchain" => STACKS_BLOCKCHAIN,
+                "status" => status,
+                "kind" => "complete-withdrawals-accept",
+            )
+            .increment(1);
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(skip_all, fields(withdrawal_id = %request.qualified_id()))]
     async fn construct_and_sign_withdrawal_reject(
         &mut self,
@@ -1012,7 +2462,6 @@ where
         bitcoin_aggregate_key: &PublicKey,
         request: model::WithdrawalRequest,
     ) -> Result<(), Error> {
-        let db = self.context.get_storage();
         let stacks = self.context.get_stacks_client();
         let deployer = self.context.config().signer.deployer;
 
@@ -1025,26 +2474,13 @@ where
             return Ok(());
         }
 
-        // The `DbRead::is_withdrawal_inflight` function considers whether
-        // the given withdrawal has been included in a sweep transaction
-        // that could have been submitted. With this check we are more
-        // confident that it is safe to reject the withdrawal.
+        // The signers run the same checks when validating the sign
+        // request, so there is no point in asking them to sign if any of
+        // these fail.
         let qualified_id = request.qualified_id();
-        let withdrawal_inflight = db
-            .is_withdrawal_inflight(&qualified_id, &chain_tip.block_hash)
-            .await?;
-        if withdrawal_inflight {
-            return Ok(());
-        }
-
-        // The `DbRead::is_withdrawal_active` function considers whether
-        // we need to worry about a fork making a sweep fulfilling
-        // withdrawal active in the mempool.
-        let withdrawal_is_active = db
-            .is_withdrawal_active(&qualified_id, chain_tip, WITHDRAWAL_MIN_CONFIRMATIONS)
-            .await?;
-
-        if withdrawal_is_active {
+        let rejection = validate_withdrawal_rejection(&self.context, &qualified_id, chain_tip);
+        if let Some(reason) = rejection.await? {
+            tracing::debug!(%reason, "not rejecting the withdrawal request yet");
             return Ok(());
         }
 
@@ -1210,8 +2646,59 @@ where
         chain_tip: &model::BitcoinBlockHash,
         multi_tx: MultisigTx,
         wallet: &SignerWallet,
+    ) -> Result<StacksTxId, Error> {
+        let request_keys: Vec<String> = StacksSignRequestId::from_sign_request(&sign_request)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let fresh_nonce = sign_request.nonce;
+        let resubmission_fut = self.apply_resubmission_policy(
+            sign_request,
+            &request_keys,
+            chain_tip,
+            multi_tx,
+            wallet,
+        );
+        let (sign_request, multi_tx) = resubmission_fut.await?;
+        let reuses_nonce = sign_request.nonce != fresh_nonce;
+
+        let submit_fut =
+            self.sign_and_submit_stacks_tx(sign_request, request_keys, chain_tip, multi_tx, wallet);
+        let result = submit_fut.await;
+
+        // A fee bump reuses the nonce of the transaction that it replaces,
+        // so the nonce that was taken from the wallet for the sign request
+        // goes unused and we give it back. Callers give it back on the
+        // errors handled by `adjust_nonce`, so we only do it otherwise.
+        let released_by_caller = result.as_ref().is_err_and(releases_nonce);
+        if reuses_nonce && !released_by_caller {
+            wallet.set_nonce(wallet.get_nonce().saturating_sub(1));
+        }
+
+        result
+    }
+
+    /// Sign the stacks transaction with the other signers and submit it
+    /// to the stacks node, recording the submission.
+    async fn sign_and_submit_stacks_tx(
+        &mut self,
+        sign_request: StacksTransactionSignRequest,
+        request_keys: Vec<String>,
+        chain_tip: &model::BitcoinBlockHash,
+        multi_tx: MultisigTx,
+        wallet: &SignerWallet,
     ) -> Result<StacksTxId, Error> {
         let kind = sign_request.tx_kind();
+        let mut submission = StacksTxSubmission {
+            txid: sign_request.txid.into(),
+            tx_kind: kind.to_string(),
+            request_keys,
+            nonce: sign_request.nonce,
+            tx_fee: sign_request.tx_fee,
+            status: StacksTxStatus::Pending,
+            rejection_reason: None,
+            bitcoin_block_hash: *chain_tip,
+        };
 
         let instant = std::time::Instant::now();
         let tx = self
@@ -1236,12 +2723,102 @@ where
         .increment(1);
 
         // Submit the transaction to the Stacks node
-        let submit_tx_result = self.context.get_stacks_client().submit_tx(&tx?).await;
+        let response = self.context.get_stacks_client().submit_tx(&tx?).await;
+        let event = match &response {
+            Ok(SubmitTxResponse::Acceptance(_)) => {
+                TxCoordinatorEvent::StacksSubmissionAccepted { txid: submission.txid, kind }
+            }
+            Ok(SubmitTxResponse::Rejection(err)) => {
+                TxCoordinatorEvent::StacksSubmissionFailed(err.to_string())
+            }
+            Err(error) => TxCoordinatorEvent::StacksSubmissionFailed(error.to_string()),
+        };
+        self.context.signal(event.into())?;
+        let response = response?;
+
+        // Rejections that will not go away by trying again are recorded
+        // as dropped, so that the requests are not submitted again.
+        if let SubmitTxResponse::Rejection(err) = &response {
+            submission.status = if err.reason.is_permanent() {
+                StacksTxStatus::Dropped
+            } else {
+                StacksTxStatus::Rejected
+            };
+            submission.rejection_reason = Some(<&str>::from(err.reason).to_string());
+        }
 
-        match submit_tx_result {
-            Ok(SubmitTxResponse::Acceptance(txid)) => Ok(txid.into()),
-            Ok(SubmitTxResponse::Rejection(err)) => Err(err.into()),
-            Err(err) => Err(err),
+        let db = self.context.get_storage_mut();
+        if let Err(error) = db.write_stacks_tx_submission(&submission).await {
+            tracing::warn!(%error, txid = %submission.txid, "could not record the stacks transaction submission");
+        }
+
+        match response {
+            SubmitTxResponse::Acceptance(txid) => {
+                self.context
+                    .state()
+                    .transcripts()
+                    .record_stacks_transaction(chain_tip, txid);
+                Ok(txid.into())
+            }
+            SubmitTxResponse::Rejection(err) => Err(err.into()),
+        }
+    }
+
+    /// Check the stacks transactions that were previously submitted for
+    /// the requests in the sign request against the resubmission policy.
+    ///
+    /// The sign request is returned as is when it should be submitted,
+    /// rebuilt with the nonce of the latest earlier transaction and a
+    /// higher fee when it should replace that transaction, and an error is
+    /// returned when it should not be submitted at all.
+    async fn apply_resubmission_policy(
+        &self,
+        mut sign_request: StacksTransactionSignRequest,
+        request_keys: &[String],
+        chain_tip: &model::BitcoinBlockHash,
+        multi_tx: MultisigTx,
+        wallet: &SignerWallet,
+    ) -> Result<(StacksTransactionSignRequest, MultisigTx), Error> {
+        let config = &self.context.config().signer;
+        let policy = ResubmissionPolicy {
+            max_attempts: config.stacks_tx_max_attempts.get(),
+        };
+
+        let db = self.context.get_storage();
+        let submissions = db.get_stacks_tx_submissions(request_keys).await?;
+
+        match policy.decide(&submissions, chain_tip) {
+            Resubmission::Submit => Ok((sign_request, multi_tx)),
+            Resubmission::Skip(reason) => {
+                let txid = submissions
+                    .last()
+                    .map(|tx| tx.txid)
+                    .unwrap_or(sign_request.txid.into());
+                Err(Error::StacksTxResubmissionSkipped(txid, reason))
+            }
+            Resubmission::BumpFee(min_fee) => {
+                let nonce = submissions
+                    .last()
+                    .map(|tx| tx.nonce)
+                    .unwrap_or(sign_request.nonce);
+                let tx_fee = sign_request
+                    .tx_fee
+                    .max(min_fee)
+                    .min(config.stacks_fees_max_ustx.get());
+                let multi_tx =
+                    MultisigTx::new_tx_with_nonce(&sign_request.contract_tx, wallet, nonce, tx_fee);
+                sign_request.nonce = nonce;
+                sign_request.tx_fee = tx_fee;
+                sign_request.txid = multi_tx.tx().txid();
+
+                tracing::info!(
+                    txid = %sign_request.txid,
+                    nonce = sign_request.nonce,
+                    tx_fee,
+                    "resubmitting stacks transaction with a higher fee"
+                );
+                Ok((sign_request, multi_tx))
+            }
         }
     }
 
@@ -1258,34 +2835,7 @@ where
         bitcoin_aggregate_key: &PublicKey,
         wallet: &SignerWallet,
     ) -> Result<(StacksTransactionSignRequest, MultisigTx), Error> {
-        // Retrieve the Bitcoin sweep transaction from the Bitcoin node. We
-        // can't get it from the database because the transaction is
-        // only in the node's mempool at this point.
-        let tx_info = self
-            .context
-            .get_bitcoin_client()
-            .get_tx_info(&req.sweep_txid, &req.sweep_block_hash)
-            .await?
-            .ok_or_else(|| {
-                Error::BitcoinTxMissing(req.sweep_txid.into(), Some(req.sweep_block_hash.into()))
-            })?;
-
-        let outpoint = req.deposit_outpoint();
-        let assessed_bitcoin_fee = tx_info
-            .assess_input_fee(&outpoint)
-            .ok_or_else(|| Error::OutPointMissing(outpoint))?;
-
-        // TODO: we should validate the contract call before asking others
-        // to sign it.
-        let complete_deposit_v1 = CompleteDepositV1 {
-            amount: req.amount - assessed_bitcoin_fee.to_sat(),
-            outpoint,
-            recipient: req.recipient.into(),
-            deployer: self.context.config().signer.deployer,
-            sweep_txid: req.sweep_txid,
-            sweep_block_hash: req.sweep_block_hash,
-            sweep_block_height: req.sweep_block_height,
-        };
+        let complete_deposit_v1 = self.complete_deposit_contract_call(req).await?;
         let contract_call = ContractCall::CompleteDepositV1(complete_deposit_v1.into());
 
         // Complete deposit requests should be done as soon as possible, so
@@ -1308,45 +2858,105 @@ where
         Ok((sign_request, multi_tx))
     }
 
-    /// Transform the swept withdrawal request into a Stacks sign request
-    /// object.
+    /// Transform the complete-deposit contract calls into a single Stacks
+    /// sign request object. A lone contract call is submitted as a regular
+    /// `complete-deposit-wrapper` contract call, while more than one are
+    /// bundled into a `complete-deposits-wrapper` contract call.
     ///
     /// This function uses stacks-core for fee estimation of the transaction.
     #[tracing::instrument(skip_all)]
-    pub async fn construct_withdrawal_accept_stacks_sign_request(
+    async fn construct_deposits_stacks_sign_request(
         &self,
-        req: model::SweptWithdrawalRequest,
+        mut deposits: Vec<CompleteDepositV1>,
         bitcoin_aggregate_key: &PublicKey,
         wallet: &SignerWallet,
     ) -> Result<(StacksTransactionSignRequest, MultisigTx), Error> {
-        tracing::debug!("constructing withdrawal accept sign request");
-        // Retrieve the Bitcoin sweep transaction and compute the assessed fee
-        // from the Bitcoin node
-        let btc_client = self.context.get_bitcoin_client();
-
-        let tx_info = btc_client
-            .get_tx_info(&req.sweep_txid, &req.sweep_block_hash)
-            .await?
-            .ok_or_else(|| {
-                Error::BitcoinTxMissing(req.sweep_txid.into(), Some(req.sweep_block_hash.into()))
-            })?;
+        let contract_call = match deposits.len() {
+            1 => ContractCall::CompleteDepositV1(Box::new(deposits.remove(0))),
+            _ => ContractCall::CompleteDepositsV1(Box::new(CompleteDepositsV1 {
+                deposits,
+                deployer: self.context.config().signer.deployer,
+            })),
+        };
 
-        let outpoint = req.withdrawal_outpoint();
-        let qualified_id = req.qualified_id();
+        // Complete deposit requests should be done as soon as possible, so
+        // we set the fee rate to the high priority fee.
+        let tx_fee = self
+            .estimate_stacks_tx_fee(wallet, &contract_call, FeePriority::High)
+            .await?;
 
-        let assessed_bitcoin_fee = tx_info
-            .assess_output_fee(outpoint.vout as usize)
-            .ok_or_else(|| Error::VoutMissing(outpoint.txid, outpoint.vout))?;
+        let multi_tx = MultisigTx::new_tx(&contract_call, wallet, tx_fee);
+        let tx = multi_tx.tx();
 
-        let accept_withdrawal_v1 = AcceptWithdrawalV1 {
-            id: qualified_id,
+        let sign_request = StacksTransactionSignRequest {
+            aggregate_key: Some(*bitcoin_aggregate_key),
+            contract_tx: contract_call.into(),
+            nonce: tx.get_origin_nonce(),
+            tx_fee: tx.get_tx_fee(),
+            txid: tx.txid(),
+        };
+
+        Ok((sign_request, multi_tx))
+    }
+
+    /// Construct the complete-deposit contract call for the swept deposit
+    /// request. The assessed bitcoin fee is the one recorded in storage
+    /// when the block observer picked up the sweep transaction, which is
+    /// the same record that the other signers validate the call against.
+    async fn complete_deposit_contract_call(
+        &self,
+        req: model::SweptDepositRequest,
+    ) -> Result<CompleteDepositV1, Error> {
+        let outpoint = req.deposit_outpoint();
+        let assessed_bitcoin_fee = self
+            .context
+            .get_storage()
+            .get_deposit_assessed_fee(&req.sweep_txid, &req.sweep_block_hash, &outpoint)
+            .await?
+            .map(bitcoin::Amount::from_sat);
+
+        let assessed_bitcoin_fee = match assessed_bitcoin_fee {
+            Some(fee) => fee,
+            // Sweeps that were recorded before we kept the assessed fees
+            // need the sweep transaction from the bitcoin node.
+            None => get_tx_info_cached(&self.context, &req.sweep_txid, &req.sweep_block_hash)
+                .await?
+                .ok_or_else(|| {
+                    Error::BitcoinTxMissing(
+                        req.sweep_txid.into(),
+                        Some(req.sweep_block_hash.into()),
+                    )
+                })?
+                .assess_input_fee(&outpoint)
+                .ok_or_else(|| Error::OutPointMissing(outpoint))?,
+        };
+
+        // TODO: we should validate the contract call before asking others
+        // to sign it.
+        Ok(CompleteDepositV1 {
+            amount: req.amount - assessed_bitcoin_fee.to_sat(),
             outpoint,
-            tx_fee: assessed_bitcoin_fee.to_sat(),
-            signer_bitmap: 0,
+            recipient: req.recipient.into(),
             deployer: self.context.config().signer.deployer,
+            sweep_txid: req.sweep_txid,
             sweep_block_hash: req.sweep_block_hash,
             sweep_block_height: req.sweep_block_height,
-        };
+        })
+    }
+
+    /// Transform the swept withdrawal request into a Stacks sign request
+    /// object.
+    ///
+    /// This function uses stacks-core for fee estimation of the transaction.
+    #[tracing::instrument(skip_all)]
+    pub async fn construct_withdrawal_accept_stacks_sign_request(
+        &self,
+        req: model::SweptWithdrawalRequest,
+        bitcoin_aggregate_key: &PublicKey,
+        wallet: &SignerWallet,
+    ) -> Result<(StacksTransactionSignRequest, MultisigTx), Error> {
+        tracing::debug!("constructing withdrawal accept sign request");
+        let accept_withdrawal_v1 = self.accept_withdrawal_contract_call(&req).await?;
         let contract_call = ContractCall::AcceptWithdrawalV1(Box::new(accept_withdrawal_v1));
 
         // Estimate the fee for the stacks transaction
@@ -1368,6 +2978,82 @@ where
         Ok((sign_request, multi_tx))
     }
 
+    /// Transform the accept-withdrawal contract calls into a single Stacks
+    /// sign request object. A lone contract call is submitted as a regular
+    /// `accept-withdrawal-request` contract call, while more than one are
+    /// bundled into a `complete-withdrawals` contract call.
+    ///
+    /// This function uses stacks-core for fee estimation of the transaction.
+    #[tracing::instrument(skip_all)]
+    pub async fn construct_withdrawals_accept_stacks_sign_request(
+        &self,
+        mut withdrawals: Vec<AcceptWithdrawalV1>,
+        bitcoin_aggregate_key: &PublicKey,
+        wallet: &SignerWallet,
+    ) -> Result<(StacksTransactionSignRequest, MultisigTx), Error> {
+        tracing::debug!("constructing withdrawals accept sign request");
+        let contract_call = match withdrawals.len() {
+            1 => ContractCall::AcceptWithdrawalV1(Box::new(withdrawals.remove(0))),
+            _ => ContractCall::AcceptWithdrawalsV1(Box::new(AcceptWithdrawalsV1 {
+                withdrawals,
+                deployer: self.context.config().signer.deployer,
+            })),
+        };
+
+        // Estimate the fee for the stacks transaction
+        let tx_fee = self
+            .estimate_stacks_tx_fee(wallet, &contract_call, FeePriority::Medium)
+            .await?;
+
+        let multi_tx = MultisigTx::new_tx(&contract_call, wallet, tx_fee);
+        let tx = multi_tx.tx();
+
+        let sign_request = StacksTransactionSignRequest {
+            aggregate_key: Some(*bitcoin_aggregate_key),
+            contract_tx: contract_call.into(),
+            nonce: tx.get_origin_nonce(),
+            tx_fee: tx.get_tx_fee(),
+            txid: tx.txid(),
+        };
+
+        Ok((sign_request, multi_tx))
+    }
+
+    /// Construct the accept-withdrawal contract call for the swept
+    /// withdrawal request. The assessed bitcoin fee is computed using the
+    /// sweep transaction fetched from the bitcoin node, and the user is
+    /// charged at most their max fee, since the signers subsidize the
+    /// rest.
+    async fn accept_withdrawal_contract_call(
+        &self,
+        req: &model::SweptWithdrawalRequest,
+    ) -> Result<AcceptWithdrawalV1, Error> {
+        // Retrieve the Bitcoin sweep transaction and compute the assessed fee
+        // from the Bitcoin node
+        let tx_info = get_tx_info_cached(&self.context, &req.sweep_txid, &req.sweep_block_hash)
+            .await?
+            .ok_or_else(|| {
+                Error::BitcoinTxMissing(req.sweep_txid.into(), Some(req.sweep_block_hash.into()))
+            })?;
+
+        let outpoint = req.withdrawal_outpoint();
+        let qualified_id = req.qualified_id();
+
+        let assessed_bitcoin_fee = tx_info
+            .assess_output_fee(outpoint.vout as usize)
+            .ok_or_else(|| Error::VoutMissing(outpoint.txid, outpoint.vout))?;
+
+        Ok(AcceptWithdrawalV1 {
+            id: qualified_id,
+            outpoint,
+            tx_fee: assessed_bitcoin_fee.to_sat().min(req.max_fee),
+            signer_bitmap: 0,
+            deployer: self.context.config().signer.deployer,
+            sweep_block_hash: req.sweep_block_hash,
+            sweep_block_height: req.sweep_block_height,
+        })
+    }
+
     /// Construct a withdrawal reject transaction
     #[tracing::instrument(skip_all)]
     pub async fn construct_withdrawal_reject_stacks_sign_request(
@@ -1403,6 +3089,10 @@ where
     }
 
     /// Attempt to sign the stacks transaction.
+    ///
+    /// This stops waiting for signatures with
+    /// [`Error::SignRequestRefused`] once enough signers have refused to
+    /// sign for the transaction to never get the signatures it needs.
     #[tracing::instrument(skip_all)]
     async fn sign_stacks_transaction(
         &mut self,
@@ -1425,9 +3115,23 @@ where
         self.send_message(req, chain_tip).await?;
 
         let max_duration = self.signing_round_max_duration;
+        let clock = self.context.clock().clone();
+
+        // Contract upgrades need the approval of every signer, while
+        // anything else only needs enough signatures for the transaction
+        // to be valid. Signatures beyond the ones required are checked but
+        // are not added to the transaction.
+        let approvals_required = match &req.contract_tx {
+            StacksTx::SmartContract(contract) if contract.version().is_upgrade() => {
+                wallet.num_signers()
+            }
+            _ => wallet.signatures_required(),
+        };
+        let mut approvals = BTreeSet::new();
+        let mut refusals: BTreeMap<PublicKey, SignRequestRefusal> = BTreeMap::new();
 
         let future = async {
-            while multi_tx.num_signatures() < wallet.signatures_required() {
+            while (approvals.len() as u16) < approvals_required {
                 // If signal_stream.next() returns None then one of the
                 // underlying streams has closed. That means either the
                 // network stream, the internal message stream, or the
@@ -1449,31 +3153,64 @@ where
 
                 let sig = match msg.inner.payload {
                     Payload::StacksTransactionSignature(sig) if sig.txid == txid => sig,
+                    Payload::SignRequestRefusal(refusal)
+                        if refusal.request == RefusedSignRequest::StacksTransaction(txid) =>
+                    {
+                        tracing::warn!(
+                            %txid,
+                            reason = %refusal.reason,
+                            signer = %msg.signer_public_key,
+                            "signer refused to sign the stacks transaction"
+                        );
+                        if !wallet.public_keys().contains(&msg.signer_public_key) {
+                            continue;
+                        }
+                        refusals.insert(msg.signer_public_key, refusal);
+                        // Once enough signers have refused, there is no
+                        // point in waiting for the rest of them.
+                        let num_signers = wallet.num_signers() as usize;
+                        if quorum_is_unreachable(num_signers, refusals.len(), approvals_required) {
+                            return Err(Error::SignRequestRefused(
+                                refusals.values().cloned().collect(),
+                            ));
+                        }
+                        continue;
+                    }
                     _ => continue,
                 };
 
-                if let Err(error) = multi_tx.add_signature(sig.signature) {
-                    tracing::warn!(
+                let result = if multi_tx.num_signatures() < wallet.signatures_required() {
+                    multi_tx.add_signature(sig.signature)
+                } else {
+                    multi_tx.clone().add_signature(sig.signature)
+                };
+
+                match result {
+                    Ok(()) => {
+                        approvals.insert(msg.signer_public_key);
+                    }
+                    Err(error) => tracing::warn!(
                         %txid,
                         %error,
                         offending_public_key = %msg.signer_public_key,
                         "got an invalid signature"
-                    );
+                    ),
                 }
             }
 
             Ok::<_, Error>(multi_tx.finalize_transaction())
         };
 
-        tokio::time::timeout(max_duration, future)
+        clock
+            .timeout(max_duration, future)
             .await
             .map_err(|_| Error::SignatureTimeout(txid))?
     }
 
-    /// Coordinate a signing round for the given request
-    /// and broadcast it once it's signed.
-    #[tracing::instrument(skip_all)]
-    async fn sign_and_broadcast(
+    /// Coordinate the signing rounds for the given sweep transaction and
+    /// set its witness data once it is signed.
+    #[tracing::instrument(skip_all, fields(txid = %transaction.tx.compute_txid()))]
+    async fn sign_sweep(
         &mut self,
         bitcoin_chain_tip: &model::BitcoinBlockHash,
         transaction: &mut utxo::UnsignedTransaction<'_>,
@@ -1487,6 +3224,8 @@ where
         let msg = sighashes.signers.to_raw_hash().to_byte_array();
 
         let txid = transaction.tx.compute_txid();
+        self.export_psbt(transaction).await;
+
         let message_id = txid.into();
         let instant = std::time::Instant::now();
         let signature = self
@@ -1548,47 +3287,395 @@ where
             )
             .increment(1);
 
-            let witness = deposit.construct_witness_data(signature.into());
+            let witness = deposit.construct_witness_data(signature.into());
+
+            deposit_witness.push(witness);
+        }
+
+        let witness_data: Vec<bitcoin::Witness> = std::iter::once(signer_witness)
+            .chain(deposit_witness)
+            .collect();
+
+        transaction
+            .tx
+            .input
+            .iter_mut()
+            .zip(witness_data)
+            .for_each(|(tx_in, witness)| {
+                tx_in.witness = witness;
+            });
+
+        Ok(())
+    }
+
+    /// Add the given signed sweep transactions to the queue of the
+    /// broadcaster, which broadcasts them in the background, see
+    /// [`broadcaster`], and wait until the broadcaster is done with them
+    /// or the given deadline passes.
+    ///
+    /// Returns whether bitcoin-core accepted the sweeps. Sweeps that the
+    /// broadcaster has not gotten through by the deadline stay queued,
+    /// but they are not reported as broadcast.
+    #[tracing::instrument(skip_all, fields(num_transactions = transactions.len()))]
+    async fn queue_sweeps(
+        &self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+        transactions: &[utxo::UnsignedTransaction<'_>],
+        deadline: Instant,
+    ) -> Result<bool, Error> {
+        let Some(first) = transactions.first() else {
+            return Ok(false);
+        };
+
+        let package_txid = first.tx.compute_txid().into();
+        let broadcasts: Vec<model::BitcoinBroadcast> = transactions
+            .iter()
+            .zip(0..)
+            .map(|(transaction, package_position)| model::BitcoinBroadcast {
+                txid: transaction.tx.compute_txid().into(),
+                package_txid,
+                package_position,
+                tx: bitcoin::consensus::serialize(&transaction.tx),
+                fee_rate: transaction.signer_utxo.fee_rate.to_sat_per_vb(),
+                bitcoin_block_hash: *bitcoin_chain_tip,
+                status: model::BitcoinBroadcastStatus::Pending,
+                attempts: 0,
+                last_error: None,
+            })
+            .collect();
+
+        let broadcast_filter = |signal: &SignerSignal| {
+            matches!(
+                signal,
+                SignerSignal::Event(SignerEvent::TxCoordinator(
+                    TxCoordinatorEvent::SweepBroadcast(_)
+                )) | SignerSignal::Event(SignerEvent::Broadcaster(
+                    broadcaster::BroadcasterEvent::SweepDropped { .. }
+                )) | SignerSignal::Command(SignerCommand::Shutdown)
+            )
+        };
+        // Subscribe before queueing, so that we cannot miss the outcome.
+        let signal_stream = self.context.as_signal_stream(broadcast_filter);
+
+        self.context
+            .get_storage_mut()
+            .write_bitcoin_broadcasts(&broadcasts)
+            .await?;
+
+        tracing::info!("queued bitcoin transactions for broadcast");
+        self.context
+            .signal(TxCoordinatorEvent::SweepsQueued.into())?;
+
+        tokio::pin!(signal_stream);
+        let mut pending: HashSet<model::BitcoinTxId> =
+            broadcasts.iter().map(|broadcast| broadcast.txid).collect();
+        let future = async {
+            while !pending.is_empty() {
+                match signal_stream.next().await {
+                    None | Some(SignerSignal::Command(SignerCommand::Shutdown)) => {
+                        return Err(Error::SignerShutdown);
+                    }
+                    Some(SignerSignal::Event(SignerEvent::TxCoordinator(
+                        TxCoordinatorEvent::SweepBroadcast(txid),
+                    ))) => {
+                        pending.remove(&model::BitcoinTxId::from(txid));
+                    }
+                    Some(SignerSignal::Event(SignerEvent::Broadcaster(
+                        broadcaster::BroadcasterEvent::SweepDropped { txid, .. },
+                    ))) if pending.contains(&txid) => return Ok(false),
+                    Some(_) => {}
+                }
+            }
+            Ok(true)
+        };
+
+        let timeout = deadline.saturating_duration_since(self.context.clock().now());
+        match self.context.clock().timeout(timeout, future).await {
+            Ok(accepted) => accepted,
+            Err(_) => {
+                tracing::warn!(
+                    "the broadcaster did not get through the queued bitcoin transactions in time"
+                );
+                Ok(false)
+            }
+        }
+    }
+
+    /// Broadcast the given signed sweep transactions, where each one spends
+    /// the signers' UTXO created by the one before it.
+    ///
+    /// More than one sweep is broadcast as a package, so that bitcoin-core
+    /// evaluates the chained sweeps together and does not reject a parent
+    /// for its fee rate when its child pays enough for both.
+    ///
+    /// Returns whether bitcoin-core has the sweeps, see
+    /// [`broadcaster::handle_broadcast_error`].
+    #[tracing::instrument(skip_all, fields(num_transactions = transactions.len()))]
+    async fn broadcast_sweeps(
+        &self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+        transactions: &[utxo::UnsignedTransaction<'_>],
+    ) -> Result<bool, Error> {
+        let bitcoin_client = self.context.get_bitcoin_client();
+
+        tracing::info!("broadcasting bitcoin transactions");
+        // Broadcast the transactions to the Bitcoin network.
+        let response = match transactions {
+            [transaction] => bitcoin_client.broadcast_transaction(&transaction.tx).await,
+            _ => {
+                let txs: Vec<bitcoin::Transaction> = transactions
+                    .iter()
+                    .map(|transaction| transaction.tx.clone())
+                    .collect();
+                bitcoin_client.broadcast_package(&txs).await
+            }
+        };
+        let fee_rate = transactions.first().map_or(FeeRate::ZERO, |transaction| {
+            transaction.signer_utxo.fee_rate
+        });
+        let response = match response {
+            Ok(()) => Ok(true),
+            Err(error) => broadcaster::handle_broadcast_error(&self.context, error, fee_rate),
+        };
+
+        let status = if let Ok(true) = response {
+            tracing::info!("bitcoin transactions accepted by bitcoin-core");
+            self.context.state().set_min_fee_rate(None);
+            for transaction in transactions {
+                let txid = transaction.tx.compute_txid();
+                self.context
+                    .state()
+                    .transcripts()
+                    .record_bitcoin_transaction(bitcoin_chain_tip, txid);
+                self.context
+                    .signal(TxCoordinatorEvent::SweepBroadcast(txid).into())?;
+            }
+            "success"
+        } else {
+            "failure"
+        };
+        metrics::counter!(crate::metrics::Metrics::ValidationDurationSeconds).increment(1);
+        metrics::counter!(
+            Metrics::TransactionsSubmittedTotal,
+            "blockchain" => BITCOIN_BLOCKCHAIN,
+            "status" => status,
+        )
+        .increment(transactions.len() as u64);
+
+        response
+    }
+
+    /// Coordinate the signing round for the given refund and broadcast it
+    /// once it has been signed.
+    #[tracing::instrument(skip_all, fields(txid = %refund.tx.compute_txid()))]
+    async fn sign_and_broadcast_refund(
+        &mut self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+        refund: &mut utxo::UnsignedRefundTransaction,
+        fee_rate: FeeRate,
+    ) -> Result<(), Error> {
+        let db = self.context.get_storage();
+        let sighash = refund.compute_sighash()?;
+        let locking_public_key = refund.utxo.public_key.into();
+        let mut fire_coordinator =
+            FireCoordinator::load(&db, locking_public_key, self.private_key).await?;
+
+        let msg = sighash.to_raw_hash().to_byte_array();
+        let txid = refund.tx.compute_txid();
+
+        let instant = std::time::Instant::now();
+        let signature = self
+            .coordinate_signing_round(
+                bitcoin_chain_tip,
+                &mut fire_coordinator,
+                txid.into(),
+                &msg,
+                SignatureType::Taproot(None),
+            )
+            .await?;
+
+        metrics::histogram!(
+            Metrics::SigningRoundDurationSeconds,
+            "blockchain" => BITCOIN_BLOCKCHAIN,
+            "kind" => "refund",
+        )
+        .record(instant.elapsed());
+
+        refund.tx.input[0].witness = bitcoin::Witness::p2tr_key_spend(&signature.into());
+
+        tracing::info!("broadcasting refund transaction");
+        let response = self
+            .context
+            .get_bitcoin_client()
+            .broadcast_transaction(&refund.tx)
+            .await;
+        let accepted = match response {
+            Ok(()) => true,
+            Err(error) => broadcaster::handle_broadcast_error(&self.context, error, fee_rate)?,
+        };
+        if !accepted {
+            return Ok(());
+        }
+
+        tracing::info!("refund transaction accepted by bitcoin-core");
+        self.context
+            .state()
+            .transcripts()
+            .record_bitcoin_transaction(bitcoin_chain_tip, txid);
+
+        Ok(())
+    }
+
+    /// Coordinate the signing round for the given emergency recovery sweep
+    /// and broadcast it once it has been signed.
+    #[tracing::instrument(skip_all, fields(txid = %recovery.tx.compute_txid()))]
+    async fn sign_and_broadcast_recovery_sweep(
+        &mut self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+        recovery: &mut utxo::UnsignedRecoveryTransaction,
+        fee_rate: FeeRate,
+    ) -> Result<(), Error> {
+        let db = self.context.get_storage();
+        let sighashes = recovery.signature_hashes()?;
+        let txid = recovery.tx.compute_txid();
+
+        let mut witness_data = Vec::with_capacity(sighashes.len());
+        for sighash in sighashes {
+            let locking_public_key = sighash.aggregate_key.into();
+            let mut fire_coordinator =
+                FireCoordinator::load(&db, locking_public_key, self.private_key).await?;
+
+            let msg = sighash.sighash.to_raw_hash().to_byte_array();
+            let instant = std::time::Instant::now();
+            let signature = self
+                .coordinate_signing_round(
+                    bitcoin_chain_tip,
+                    &mut fire_coordinator,
+                    txid.into(),
+                    &msg,
+                    SignatureType::Taproot(None),
+                )
+                .await?;
+
+            metrics::histogram!(
+                Metrics::SigningRoundDurationSeconds,
+                "blockchain" => BITCOIN_BLOCKCHAIN,
+                "kind" => "emergency-recovery",
+            )
+            .record(instant.elapsed());
+
+            witness_data.push(bitcoin::Witness::p2tr_key_spend(&signature.into()));
+        }
+
+        recovery
+            .tx
+            .input
+            .iter_mut()
+            .zip(witness_data)
+            .for_each(|(tx_in, witness)| tx_in.witness = witness);
+
+        tracing::warn!("broadcasting the emergency recovery sweep");
+        let response = self
+            .context
+            .get_bitcoin_client()
+            .broadcast_transaction(&recovery.tx)
+            .await;
+        let accepted = match response {
+            Ok(()) => true,
+            Err(error) => broadcaster::handle_broadcast_error(&self.context, error, fee_rate)?,
+        };
+        if !accepted {
+            return Ok(());
+        }
+
+        tracing::warn!("emergency recovery sweep accepted by bitcoin-core");
+        emergency_recovery::record_sweep(&self.context, txid.into()).await?;
+        self.context
+            .state()
+            .transcripts()
+            .record_bitcoin_transaction(bitcoin_chain_tip, txid);
+
+        let event = EmergencyRecoveryEvent::SweepBroadcast { txid: txid.into() };
+        self.context
+            .signal(SignerEvent::EmergencyRecovery(event).into())?;
+
+        Ok(())
+    }
+
+    /// Lock the prevouts of the given transaction for its signing rounds,
+    /// both in memory and in the database. Returns an
+    /// [`Error::PrevoutSpendConflict`] if another signing round at the
+    /// same chain tip holds a lock on any of them.
+    async fn lock_prevouts(
+        &self,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+        tx: &bitcoin::Transaction,
+    ) -> Result<(), Error> {
+        let txid = tx.compute_txid();
+        let prevouts: Vec<OutPoint> = tx.input.iter().map(|tx_in| tx_in.previous_output).collect();
+        self.context.state().lock_prevouts(txid, &prevouts)?;
+
+        let locks: Vec<model::PrevoutLock> = prevouts
+            .iter()
+            .map(|outpoint| model::PrevoutLock {
+                prevout_txid: outpoint.txid.into(),
+                prevout_output_index: outpoint.vout,
+                chain_tip: *bitcoin_chain_tip,
+                txid: txid.into(),
+            })
+            .collect();
+
+        let conflict = self
+            .context
+            .get_storage_mut()
+            .lock_prevouts(&locks)
+            .await
+            .inspect_err(|_| self.context.state().unlock_prevouts(&txid))?;
 
-            deposit_witness.push(witness);
+        if let Some(conflict) = conflict {
+            self.context.state().unlock_prevouts(&txid);
+            return Err(Error::PrevoutSpendConflict {
+                outpoint: OutPoint::new(*conflict.prevout_txid, conflict.prevout_output_index),
+                txid: *conflict.txid,
+            });
         }
 
-        let witness_data: Vec<bitcoin::Witness> = std::iter::once(signer_witness)
-            .chain(deposit_witness)
-            .collect();
+        Ok(())
+    }
 
-        transaction
-            .tx
-            .input
-            .iter_mut()
-            .zip(witness_data)
-            .for_each(|(tx_in, witness)| {
-                tx_in.witness = witness;
+    /// Release the prevout locks taken by [`Self::lock_prevouts`] for the
+    /// given transaction.
+    async fn unlock_prevouts(&self, txid: &bitcoin::Txid) {
+        self.context.state().unlock_prevouts(txid);
+        let _ = self
+            .context
+            .get_storage_mut()
+            .unlock_prevouts(&(*txid).into())
+            .await
+            .inspect_err(|error| {
+                tracing::warn!(%error, %txid, "could not release the prevout locks");
             });
+    }
 
-        tracing::info!("broadcasting bitcoin transaction");
-        // Broadcast the transaction to the Bitcoin network.
-        let response = self
-            .context
-            .get_bitcoin_client()
-            .broadcast_transaction(&transaction.tx)
-            .await;
+    /// Export the unsigned transaction as a PSBT to the sinks in the
+    /// configuration, if there are any. Failures are logged and do not
+    /// stop the signing round.
+    async fn export_psbt(&self, transaction: &utxo::UnsignedTransaction<'_>) {
+        let exporter = PsbtExporter::new(&self.context.config().signer);
+        if !exporter.is_enabled() {
+            return;
+        }
 
-        let status = if response.is_ok() {
-            tracing::info!("bitcoin transaction accepted by bitcoin-core");
-            "success"
-        } else {
-            "failure"
+        let result = match transaction.to_psbt() {
+            Ok(psbt) => exporter.export(&psbt).await,
+            Err(error) => Err(error),
         };
-        metrics::counter!(crate::metrics::Metrics::ValidationDurationSeconds).increment(1);
-        metrics::counter!(
-            Metrics::TransactionsSubmittedTotal,
-            "blockchain" => BITCOIN_BLOCKCHAIN,
-            "status" => status,
-        )
-        .increment(1);
-
-        response
+        match result {
+            Ok(()) => tracing::debug!("exported the unsigned transaction as a PSBT"),
+            Err(error) => {
+                tracing::warn!(%error, "could not export the unsigned transaction as a PSBT")
+            }
+        }
     }
 
     #[tracing::instrument(skip_all)]
@@ -1616,10 +3703,12 @@ where
         self.send_message(msg, bitcoin_chain_tip).await?;
 
         let max_duration = self.signing_round_max_duration;
+        let clock = self.context.clock().clone();
         let run_signing_round =
             self.drive_wsts_state_machine(signal_stream, bitcoin_chain_tip, coordinator, id);
 
-        let operation_result = tokio::time::timeout(max_duration, run_signing_round)
+        let operation_result = clock
+            .timeout(max_duration, run_signing_round)
             .await
             .map_err(|_| Error::CoordinatorTimeout(max_duration.as_secs()))??;
 
@@ -1675,10 +3764,12 @@ where
 
         // Now that DKG has "begun" we need to drive it to completion.
         let max_duration = self.dkg_max_duration;
+        let clock = self.context.clock().clone();
         let dkg_fut =
             self.drive_wsts_state_machine(signal_stream, &block_hash, &mut state_machine, id);
 
-        let operation_result = tokio::time::timeout(max_duration, dkg_fut)
+        let operation_result = clock
+            .timeout(max_duration, dkg_fut)
             .await
             .map_err(|_| Error::CoordinatorTimeout(max_duration.as_secs()))??;
 
@@ -1826,6 +3917,34 @@ where
         }
     }
 
+    /// Whether we should stop the work of our tenure, because there is a
+    /// new bitcoin chain tip or because the tenure deadline has passed.
+    /// Whatever work remains is picked up by the coordinator of the next
+    /// tenure.
+    pub(crate) fn should_stop_tenure(&self, tenure: &TenureContext) -> bool {
+        let chain_tip = &tenure.bitcoin_chain_tip;
+        if self.context.state().bitcoin_chain_tip().as_ref() != Some(chain_tip) {
+            tracing::info!("new bitcoin chain tip, stopping coordinator activities");
+            return true;
+        }
+        if self.context.clock().now() >= tenure.deadline {
+            tracing::info!("tenure budget exhausted, deferring remaining work to the next tenure");
+            return true;
+        }
+        let state = self.context.state();
+        if state.lost_coordinator_tie_break(&chain_tip.block_hash) {
+            tracing::warn!("another signer won the coordinator tie-break, aborting our tenure");
+            return true;
+        }
+        false
+    }
+
+    /// The number of confirmations that a sweep transaction must have
+    /// before we respond to its requests on stacks.
+    fn sweep_confirmation_depth(&self) -> u64 {
+        self.context.config().signer.sweep_confirmation_depth.get() as u64
+    }
+
     // Determine if the current coordinator is the coordinator.
     //
     // The coordinator is decided using the hash of the bitcoin
@@ -1855,7 +3974,12 @@ where
         aggregate_key: &PublicKey,
     ) -> Result<utxo::SignerBtcState, Error> {
         let bitcoin_client = self.context.get_bitcoin_client();
-        let fee_rate = bitcoin_client.estimate_fee_rate().await?;
+        let mut fee_rate = FeeRate::new(bitcoin_client.estimate_fee_rate().await?)?;
+        // bitcoin-core rejected one of our transactions for paying too
+        // little at an earlier tenure, so we pay more than then.
+        if let Some(min_fee_rate) = self.context.state().min_fee_rate() {
+            fee_rate = fee_rate.max(min_fee_rate);
+        }
 
         // Retrieve the signer's current UTXO.
         let utxo = self
@@ -1872,10 +3996,31 @@ where
             utxo,
             public_key: bitcoin::XOnlyPublicKey::from(aggregate_key),
             last_fees,
-            magic_bytes: [b'T', b'3'], //TODO(#472): Use the correct magic bytes.
+            magic_bytes: self.context.config().signer.network.magic_bytes(),
+            metadata_chain_tip: self
+                .context
+                .config()
+                .signer
+                .proposed_sweep_template()
+                .metadata_chain_tip(*chain_tip),
         })
     }
 
+    /// Returns the [`utxo::SignerBtcState`] of the given tenure, fetching
+    /// it with [`Self::get_btc_state`] the first time that it is needed.
+    pub async fn tenure_btc_state(
+        &self,
+        tenure: &mut TenureContext,
+    ) -> Result<utxo::SignerBtcState, Error> {
+        if let Some(btc_state) = tenure.btc_state {
+            return Ok(btc_state);
+        }
+        let chain_tip = &tenure.bitcoin_chain_tip.block_hash;
+        let btc_state = self.get_btc_state(chain_tip, &tenure.aggregate_key).await?;
+        tenure.btc_state = Some(btc_state);
+        Ok(btc_state)
+    }
+
     /// Fetches pending withdrawal requests from storage and filters them based
     /// on the remaining consensus rules as defined in #741.
     ///
@@ -1922,28 +4067,14 @@ where
     where
         DB: DbRead,
     {
-        // Constants used for logging (local to this method).
-        const REQUEST_SKIPPED_MESSAGE: &str = "skipping withdrawal request";
-        const SKIP_REASON_AMOUNT_IS_DUST: &str = "amount_is_dust";
-        const SKIP_REASON_PER_WITHDRAWAL_CAP_EXCEEDED: &str = "per_withdrawal_cap_exceeded";
-        const SKIP_REASON_INSUFFICIENT_CONFIRMATIONS: &str = "insufficient_confirmations";
-        const SKIP_REASON_INSUFFICIENT_VOTES: &str = "insufficient_votes";
-        const SKIP_REASON_SOFT_EXPIRY: &str = "soft_expiry";
-
-        let mut eligible_withdrawals = Vec::new();
-
-        // Determine the minimum bitcoin block height we should consider for
-        // withdrawals.
-        let min_bitcoin_height = params
-            .bitcoin_chain_tip
-            .block_height
-            .saturating_sub(expiry_window);
-
-        // We also calculate the minimum bitcoin block height for withdrawals
-        // that are considered valid (not expired) based on the soft expiry. We
-        // will not propose these withdrawals in the sweep transaction, but we
-        // will log them as skipped.
-        let min_soft_bitcoin_height = min_bitcoin_height.saturating_add(expiry_buffer);
+        let rules = WithdrawalRules {
+            chain_tip_height: params.bitcoin_chain_tip.block_height,
+            expiry_window,
+            expiry_buffer,
+            min_confirmations,
+            per_withdrawal_cap: params.sbtc_limits.per_withdrawal_cap().to_sat(),
+            signature_threshold: params.signature_threshold,
+        };
 
         // Fetch pending withdrawal requests from storage. This method, with the
         // given inputs, performs the following filtering according to consensus
@@ -1954,6 +4085,7 @@ where
         // - [4a] Is accepted by >= `threshold` signers (pre-filter),
         // - [7]  Is not expired; we only retrieve requests whose bitcoin block
         //        height is greater than `min_bitcoin_height`.
+        let min_bitcoin_height = rules.chain_tip_height.saturating_sub(expiry_window);
         let pending_withdraw_requests = storage
             .get_pending_accepted_withdrawal_requests(
                 params.bitcoin_chain_tip.as_ref(),
@@ -1966,114 +4098,67 @@ where
         // If we didn't find any pending withdrawal requests, we can exit early.
         if pending_withdraw_requests.is_empty() {
             tracing::debug!("no pending withdrawal requests eligible for consideration found");
-            return Ok(eligible_withdrawals);
+            return Ok(Vec::new());
         }
 
-        // Iterate over the pending withdrawal requests we fetched above and
-        // validate them against the remaining consensus rules.
+        // Fetch the votes for the withdrawal requests from storage for the
+        // public keys of the signers in the current signing set, based on
+        // the current signers' aggregate key. The remaining consensus rules
+        // [3], [4b], [8] and [9] are checked against these snapshots.
+        let mut candidates = Vec::with_capacity(pending_withdraw_requests.len());
         for req in pending_withdraw_requests {
-            if req.bitcoin_block_height < min_soft_bitcoin_height {
-                tracing::debug!(
-                    request_id = req.request_id,
-                    bitcoin_block_height = *req.bitcoin_block_height,
-                    min_soft_bitcoin_height = *min_soft_bitcoin_height,
-                    reason = SKIP_REASON_SOFT_EXPIRY,
-                    message = REQUEST_SKIPPED_MESSAGE
-                );
-                continue;
-            }
-
-            // [8] Ensure that the withdrawal request amount is at or above the
-            // dust limit specified in `WITHDRAWAL_DUST_LIMIT`.
-            if req.amount < WITHDRAWAL_DUST_LIMIT {
-                tracing::debug!(
-                    request_id = req.request_id,
-                    amount = req.amount,
-                    reason = SKIP_REASON_AMOUNT_IS_DUST,
-                    message = REQUEST_SKIPPED_MESSAGE
-                );
-                continue;
-            }
-
-            // [9] Ensure that the withdrawal request amount is within the
-            // current sBTC caps.
-            let per_withdrawal_cap = params.sbtc_limits.per_withdrawal_cap().to_sat();
-            if req.amount > per_withdrawal_cap {
-                tracing::debug!(
-                    request_id = req.request_id,
-                    amount = req.amount,
-                    per_withdrawal_cap = per_withdrawal_cap,
-                    reason = SKIP_REASON_PER_WITHDRAWAL_CAP_EXCEEDED,
-                    message = REQUEST_SKIPPED_MESSAGE
-                );
-                continue;
-            }
-
-            // Calculate the number of blocks passed (confirmations) since the
-            // bitcoin anchor of the stacks block confirming the withdrawal
-            // request.
-            let num_confirmations: u64 = *params
-                .bitcoin_chain_tip
-                .block_height
-                .saturating_sub(req.bitcoin_block_height);
-
-            // [3] Ensure that we have the required number of confirmations for
-            // the withdrawal request.
-            if num_confirmations < min_confirmations {
-                tracing::debug!(
-                    request_id = req.request_id,
-                    num_confirmations,
-                    required_confirmations = min_confirmations,
-                    reason = SKIP_REASON_INSUFFICIENT_CONFIRMATIONS,
-                    message = REQUEST_SKIPPED_MESSAGE
-                );
-                continue;
-            }
-
-            // Fetch the votes for the withdrawal request from storage for the
-            // public keys of the signers in the current signing set, based on
-            // the current signers' aggregate key. Note: this could have been
-            // baked into the initial query, but we need the votes' values for
-            // our return value.
             let votes = storage
                 .get_withdrawal_request_signer_votes(&req.qualified_id(), params.aggregate_key)
                 .await?;
+            candidates.push((req, votes));
+        }
 
-            // Calculate the number of votes accepted, rejected, and missing.
-            // The vote will be `None` if we don't have a record of the signer's
-            // vote in the database, otherwise it will be `Some(bool)` where
-            // `true` = accept and `false` = reject.
-            let (num_votes_accepted, num_votes_rejected, num_votes_missing) = votes.iter().fold(
-                (0_u16, 0_u16, 0_u16),
-                |(accepted, rejected, missing), vote| match vote.is_accepted {
-                    Some(true) => (accepted + 1, rejected, missing),
-                    Some(false) => (accepted, rejected + 1, missing),
-                    None => (accepted, rejected, missing + 1),
-                },
-            );
-
-            // [4] Ensure that the withdrawal request has been accepted by the
-            // required number of signers _in the current signer set_ (the
-            // initial query only checks the total number of votes accepted by
-            // any signer).
-            if num_votes_accepted < params.signature_threshold {
-                tracing::warn!(
-                    request_id = req.request_id,
-                    num_votes_accepted,
-                    num_votes_rejected,
-                    num_votes_missing,
-                    required_votes = params.signature_threshold,
-                    reason = SKIP_REASON_INSUFFICIENT_VOTES,
-                    message = REQUEST_SKIPPED_MESSAGE
-                );
-                continue;
-            }
+        Ok(selection::select_withdrawals(candidates, &rules))
+    }
 
-            let withdrawal = utxo::WithdrawalRequest::from_model(req, votes);
-            eligible_withdrawals.push(withdrawal);
+    /// Fetch the deposit requests that are eligible for sweeping, unless
+    /// deposits have been paused on this signer, in which case there are
+    /// none.
+    async fn get_sweepable_deposit_requests<DB>(
+        &self,
+        storage: &DB,
+        params: &GetPendingRequestsParams<'_>,
+    ) -> Result<Vec<utxo::DepositRequest>, Error>
+    where
+        DB: DbRead,
+    {
+        if self.context.state().is_deposits_paused() {
+            tracing::info!("deposits are paused; only servicing withdrawal requests");
+            return Ok(Vec::new());
         }
+        let state = self.context.state();
+        Self::get_eligible_pending_deposit_requests(storage, state, self.context_window, params)
+            .await
+    }
 
-        Ok(eligible_withdrawals)
+    /// Fetch the withdrawal requests that are eligible for sweeping,
+    /// unless withdrawals have been paused on this signer, in which case
+    /// there are none.
+    async fn get_sweepable_withdrawal_requests<DB>(
+        &self,
+        storage: &DB,
+        params: &GetPendingRequestsParams<'_>,
+    ) -> Result<Vec<utxo::WithdrawalRequest>, Error>
+    where
+        DB: DbRead,
+    {
+        if self.context.state().is_withdrawals_paused() {
+            tracing::info!("withdrawals are paused; only servicing deposit requests");
+            return Ok(Vec::new());
+        }
+        Self::get_eligible_pending_withdrawal_requests(
+            storage,
+            WITHDRAWAL_BLOCKS_EXPIRY,
+            WITHDRAWAL_EXPIRY_BUFFER,
+            WITHDRAWAL_MIN_CONFIRMATIONS,
+            params,
+        )
+        .await
     }
 
     /// TODO(#742): This function needs to filter deposit requests based on
@@ -2083,6 +4168,7 @@ where
     #[tracing::instrument(skip_all)]
     pub async fn get_eligible_pending_deposit_requests<DB>(
         storage: &DB,
+        state: &SignerState,
         context_window: u16,
         params: &GetPendingRequestsParams<'_>,
     ) -> Result<Vec<utxo::DepositRequest>, Error>
@@ -2090,7 +4176,6 @@ where
         DB: DbRead,
     {
         tracing::debug!("fetching eligible deposit requests");
-        let mut eligible_deposits: Vec<utxo::DepositRequest> = Vec::new();
 
         // First, we fetch pending deposit requests with initial filtering
         // done by the storage layer.
@@ -2105,22 +4190,117 @@ where
         // If there are no pending deposit requests, we can exit early.
         if pending_deposit_requests.is_empty() {
             tracing::debug!("no pending deposit requests eligible for consideration found");
-            return Ok(eligible_deposits);
+            return Ok(Vec::new());
         }
 
-        // Iterate through each deposit request, fetch its votes from storage
-        // for the public keys of the signers in the current signing set, based
-        // on the current signers' aggregate key.
-        for req in pending_deposit_requests {
-            let votes = storage
-                .get_deposit_request_signer_votes(&req.txid, req.output_index, params.aggregate_key)
-                .await?;
+        // Fetch the votes of the signers in the current signing set, based
+        // on the current signers' aggregate key, for all of the deposit
+        // requests at once.
+        let outpoints: Vec<_> = pending_deposit_requests
+            .iter()
+            .map(model::DepositRequest::outpoint)
+            .collect();
+        let mut votes_by_outpoint = storage
+            .get_deposit_requests_signer_votes(&outpoints, params.aggregate_key)
+            .await?;
+
+        let candidates = pending_deposit_requests
+            .into_iter()
+            .map(|req| {
+                let votes = votes_by_outpoint
+                    .remove(&req.outpoint())
+                    .unwrap_or_else(|| model::SignerVotes::from(Vec::new()));
+                (req, votes)
+            })
+            .collect();
 
-            let deposit = utxo::DepositRequest::from_model(req, votes);
-            eligible_deposits.push(deposit);
+        // The initial query only checks the total number of votes accepted
+        // by any signer, while presign validation would reject a sweep
+        // with requests that too few signers in the current signing set
+        // accepted.
+        Ok(selection::select_deposits(
+            candidates,
+            params.signature_threshold,
+            state,
+        ))
+    }
+
+    /// Find the pending deposit requests that have not been accepted by
+    /// enough signers in the current signing set, but could be if the
+    /// signers that have no recorded decision on them accepted them.
+    ///
+    /// Returns a request for those signers to re-send their decisions on
+    /// these deposits, or `None` if no decisions are missing.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_missing_deposit_decisions<DB>(
+        storage: &DB,
+        bitcoin_chain_tip: &model::BitcoinBlockRef,
+        context_window: u16,
+        aggregate_key: &PublicKey,
+        signature_threshold: u16,
+    ) -> Result<Option<DepositDecisionRetryRequest>, Error>
+    where
+        DB: DbRead,
+    {
+        // We only consider deposit requests that at least one signer has
+        // accepted, the others are unlikely to be accepted by the rest.
+        let pending_deposit_requests = storage
+            .get_pending_accepted_deposit_requests(bitcoin_chain_tip, context_window, 1)
+            .await?;
+
+        if pending_deposit_requests.is_empty() {
+            return Ok(None);
         }
 
-        Ok(eligible_deposits)
+        let outpoints: Vec<_> = pending_deposit_requests
+            .iter()
+            .map(model::DepositRequest::outpoint)
+            .collect();
+        let votes_by_outpoint = storage
+            .get_deposit_requests_signer_votes(&outpoints, aggregate_key)
+            .await?;
+
+        let candidates = outpoints.into_iter().filter_map(|outpoint| {
+            let votes = votes_by_outpoint.get(&outpoint)?;
+            Some((outpoint, votes))
+        });
+
+        Ok(selection::missing_deposit_decisions(
+            candidates,
+            signature_threshold,
+        ))
+    }
+
+    /// Broadcast a [`DepositDecisionRetryRequest`] to the signers whose
+    /// decisions on pending deposit requests we are missing, if any.
+    #[tracing::instrument(skip_all)]
+    async fn request_missing_deposit_decisions(
+        &mut self,
+        bitcoin_chain_tip: &model::BitcoinBlockRef,
+        aggregate_key: &PublicKey,
+    ) -> Result<(), Error> {
+        let storage = self.context.get_storage();
+        let retry_request = Self::get_missing_deposit_decisions(
+            &storage,
+            bitcoin_chain_tip,
+            self.context_window,
+            aggregate_key,
+            self.threshold,
+        )
+        .await?;
+
+        let Some(retry_request) = retry_request else {
+            return Ok(());
+        };
+
+        tracing::info!(
+            num_deposits = retry_request.deposits.len(),
+            num_signers = retry_request.signers.len(),
+            "requesting missing deposit decisions from signers"
+        );
+
+        self.send_message(retry_request, &bitcoin_chain_tip.block_hash)
+            .await
     }
 
     /// Fetches pending deposit and withdrawal requests from storage and filters
@@ -2129,9 +4309,7 @@ where
     #[tracing::instrument(skip_all)]
     pub async fn get_pending_requests(
         &self,
-        bitcoin_chain_tip: &model::BitcoinBlockRef,
-        stacks_chain_tip: &model::StacksBlockHash,
-        aggregate_key: &PublicKey,
+        tenure: &mut TenureContext,
         signer_public_keys: &BTreeSet<PublicKey>,
     ) -> Result<Option<utxo::SbtcRequests>, Error> {
         tracing::info!("preparing pending requests for processing");
@@ -2139,32 +4317,21 @@ where
         let storage = self.context.get_storage();
         let config = self.context.config();
 
-        // Get the current sBTC limits (caps).
-        let sbtc_limits = self.context.state().get_current_limits();
-
-        // Setup the parameters for fetching pending requests.
-        let params = GetPendingRequestsParams {
-            bitcoin_chain_tip,
-            stacks_chain_tip,
-            aggregate_key,
-            signature_threshold: self.threshold,
-            sbtc_limits: &sbtc_limits,
-        };
+        // Setup the parameters for fetching pending requests, using the
+        // sBTC limits and chain tips of the tenure.
+        let params = tenure
+            .pending_requests_params(self.threshold)
+            .ok_or(Error::NoStacksChainTip)?;
 
         // Fetch eligible deposit requests from storage.
-        let deposits =
-            Self::get_eligible_pending_deposit_requests(&storage, self.context_window, &params)
-                .await?;
+        let deposits = self
+            .get_sweepable_deposit_requests(&storage, &params)
+            .await?;
 
         // Fetch eligible withdrawal requests from storage.
-        let withdrawals = Self::get_eligible_pending_withdrawal_requests(
-            &storage,
-            WITHDRAWAL_BLOCKS_EXPIRY,
-            WITHDRAWAL_EXPIRY_BUFFER,
-            WITHDRAWAL_MIN_CONFIRMATIONS,
-            &params,
-        )
-        .await?;
+        let withdrawals = self
+            .get_sweepable_withdrawal_requests(&storage, &params)
+            .await?;
 
         // If there are no pending deposit or withdrawal requests, we return
         // `None` to signal that there is no work to be done.
@@ -2173,9 +4340,7 @@ where
         }
 
         // Get the current signers' BTC state.
-        let signer_state = self
-            .get_btc_state(&bitcoin_chain_tip.block_hash, aggregate_key)
-            .await?;
+        let signer_state = self.tenure_btc_state(tenure).await?;
 
         // Count the number of signers in the current signer set.
         let num_signers = signer_public_keys
@@ -2192,8 +4357,9 @@ where
             signer_state,
             accept_threshold: self.threshold,
             num_signers,
-            sbtc_limits,
+            sbtc_limits: tenure.sbtc_limits.clone(),
             max_deposits_per_bitcoin_tx,
+            withdrawal_fee_subsidy: config.signer.withdrawal_fee_subsidy(),
         }))
     }
 
@@ -2213,7 +4379,14 @@ where
             .to_message(*bitcoin_chain_tip)
             .sign_ecdsa(&self.private_key);
 
+        self.send_signed_message(msg).await
+    }
+
+    /// Broadcast the given message, which was signed with the signer's
+    /// private key, to the network, see [`Self::send_message`].
+    async fn send_signed_message(&mut self, msg: network::Msg) -> Result<(), Error> {
         self.network.broadcast(msg.clone()).await?;
+        self.context.state().transcripts().record_message_sent(&msg);
         self.context
             .signal(TxCoordinatorEvent::MessageGenerated(Box::new(msg)).into())?;
 
@@ -2256,66 +4429,171 @@ where
         let process_request_fut =
             self.process_sign_request(sign_request, chain_tip, multi_tx, wallet);
 
-        match process_request_fut.await {
+        match process_request_fut.await {
+            Ok(txid) => {
+                tracing::info!(%txid, "successfully submitted contract deploy transaction");
+                Ok(())
+            }
+            Err(error) => {
+                tracing::warn!(
+                    %error,
+                    "could not process the stacks sign request for a contract deploy"
+                );
+                adjust_nonce(wallet, &error);
+                Err(error)
+            }
+        }
+    }
+
+    /// Estimates the fees for the contract deploy transaction, constructs the
+    /// [`StacksTransactionSignRequest`] to be broadcast to the signers for
+    /// signing and returns it along with the corresponding [`MultisigTx`] being
+    /// signed.
+    async fn construct_deploy_contracts_stacks_sign_request(
+        &self,
+        contract_deploy: SmartContract,
+        bitcoin_aggregate_key: &PublicKey,
+        wallet: &SignerWallet,
+    ) -> Result<(StacksTransactionSignRequest, MultisigTx), Error> {
+        let tx_fee = self
+            .estimate_stacks_tx_fee(wallet, &contract_deploy.tx_payload(), FeePriority::High)
+            .await?;
+
+        let multi_tx = MultisigTx::new_tx(&contract_deploy, wallet, tx_fee);
+        let tx = multi_tx.tx();
+
+        let sign_request = StacksTransactionSignRequest {
+            aggregate_key: Some(*bitcoin_aggregate_key),
+            contract_tx: contract_deploy.into(),
+            nonce: tx.get_origin_nonce(),
+            tx_fee: tx.get_tx_fee(),
+            txid: tx.txid(),
+        };
+
+        Ok((sign_request, multi_tx))
+    }
+
+    /// Deploy all sBTC smart contracts to the stacks node (if not already deployed).
+    /// If a contract fails to deploy, the function will return an error.
+    #[tracing::instrument(skip_all)]
+    pub async fn deploy_smart_contracts(
+        &mut self,
+        chain_tip: &model::BitcoinBlockHash,
+        wallet: &SignerWallet,
+        bitcoin_aggregate_key: &PublicKey,
+    ) -> Result<(), Error> {
+        if self.all_smart_contracts_deployed().await? {
+            return Ok(());
+        }
+
+        // When a separate deployer account is configured, the contracts
+        // are deployed from it instead of from the signers' wallet.
+        let deployer_private_key = self.context.config().signer.deployer_private_key;
+        if let Some(private_key) = deployer_private_key {
+            let deployer_wallet = self.get_deployer_wallet().await?;
+            for contract in SMART_CONTRACTS {
+                self.deploy_smart_contract_from_deployer(contract, &deployer_wallet, &private_key)
+                    .await?;
+            }
+            return Ok(());
+        }
+
+        for contract in SMART_CONTRACTS {
+            self.deploy_smart_contract(contract, chain_tip, bitcoin_aggregate_key, wallet)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Deploy an sBTC smart contract from the separate deployer account.
+    ///
+    /// Transactions from the deployer account are signed by this signer
+    /// alone, so there is no signing round. They use the nonce of the
+    /// deployer account, and since the other signers do not sign them,
+    /// their fee is not capped by the maximum fee that signers accept.
+    async fn deploy_smart_contract_from_deployer(
+        &self,
+        contract_deploy: SmartContract,
+        wallet: &SignerWallet,
+        private_key: &PrivateKey,
+    ) -> Result<(), Error> {
+        let stacks = self.context.get_stacks_client();
+
+        if contract_deploy
+            .is_deployed(&stacks, wallet.address())
+            .await?
+        {
+            return Ok(());
+        }
+
+        tracing::info!(
+            contract = %contract_deploy,
+            "contract not deployed yet, deploying it from the deployer account"
+        );
+
+        let payload = contract_deploy.tx_payload();
+        let tx_fee = stacks
+            .estimate_fees(wallet, &payload, FeePriority::High)
+            .await?;
+        let mut multi_tx = MultisigTx::new_tx(&contract_deploy, wallet, tx_fee);
+
+        let submit_fut = async {
+            let signature = crate::signature::sign_stacks_tx(multi_tx.tx(), private_key);
+            multi_tx.add_signature(signature)?;
+
+            match stacks.submit_tx(&multi_tx.finalize_transaction()).await? {
+                SubmitTxResponse::Acceptance(txid) => Ok(StacksTxId::from(txid)),
+                SubmitTxResponse::Rejection(err) => Err(Error::from(err)),
+            }
+        };
+
+        match submit_fut.await {
             Ok(txid) => {
                 tracing::info!(%txid, "successfully submitted contract deploy transaction");
                 Ok(())
             }
             Err(error) => {
-                tracing::warn!(
-                    %error,
-                    "could not process the stacks sign request for a contract deploy"
-                );
+                tracing::warn!(%error, "could not submit the contract deploy transaction");
                 adjust_nonce(wallet, &error);
                 Err(error)
             }
         }
     }
 
-    /// Estimates the fees for the contract deploy transaction, constructs the
-    /// [`StacksTransactionSignRequest`] to be broadcast to the signers for
-    /// signing and returns it along with the corresponding [`MultisigTx`] being
-    /// signed.
-    async fn construct_deploy_contracts_stacks_sign_request(
-        &self,
-        contract_deploy: SmartContract,
-        bitcoin_aggregate_key: &PublicKey,
-        wallet: &SignerWallet,
-    ) -> Result<(StacksTransactionSignRequest, MultisigTx), Error> {
-        let tx_fee = self
-            .estimate_stacks_tx_fee(wallet, &contract_deploy.tx_payload(), FeePriority::High)
-            .await?;
-
-        let multi_tx = MultisigTx::new_tx(&contract_deploy, wallet, tx_fee);
-        let tx = multi_tx.tx();
-
-        let sign_request = StacksTransactionSignRequest {
-            aggregate_key: Some(*bitcoin_aggregate_key),
-            contract_tx: contract_deploy.into(),
-            nonce: tx.get_origin_nonce(),
-            tx_fee: tx.get_tx_fee(),
-            txid: tx.txid(),
-        };
-
-        Ok((sign_request, multi_tx))
-    }
-
-    /// Deploy all sBTC smart contracts to the stacks node (if not already deployed).
-    /// If a contract fails to deploy, the function will return an error.
+    /// Deploy the releases of the sBTC smart contracts that come after the
+    /// first one, up to the target release, if contract upgrades are
+    /// enabled.
+    ///
+    /// Each deployment needs a signature from every signer in the signing
+    /// set, and signers only sign it if the release is their target
+    /// release, so the contracts are only deployed when all signers are
+    /// configured for the same release.
     #[tracing::instrument(skip_all)]
-    pub async fn deploy_smart_contracts(
+    pub async fn upgrade_smart_contracts(
         &mut self,
         chain_tip: &model::BitcoinBlockHash,
         wallet: &SignerWallet,
         bitcoin_aggregate_key: &PublicKey,
     ) -> Result<(), Error> {
-        if self.all_smart_contracts_deployed().await? {
+        let config = &self.context.config().signer;
+        if !config.contract_upgrades_enabled {
             return Ok(());
         }
+        let target_version = config.contracts_target_version;
+        let deployer = config.deployer;
+        let stacks = self.context.get_stacks_client();
 
-        for contract in SMART_CONTRACTS {
-            self.deploy_smart_contract(contract, chain_tip, bitcoin_aggregate_key, wallet)
-                .await?;
+        for version in target_version.up_to().filter(|v| v.is_upgrade()) {
+            if version.is_deployed(&stacks, &deployer).await? {
+                continue;
+            }
+
+            tracing::info!(%version, "upgrading the sBTC smart contracts");
+            for contract in version.contracts() {
+                self.deploy_smart_contract(*contract, chain_tip, bitcoin_aggregate_key, wallet)
+                    .await?;
+            }
         }
 
         Ok(())
@@ -2339,6 +4617,19 @@ where
         Ok(true)
     }
 
+    /// Load the wallet of the separate deployer account, with the nonce
+    /// of the account as it is known to our stacks node.
+    async fn get_deployer_wallet(&self) -> Result<SignerWallet, Error> {
+        let wallet = SignerWallet::load_deployer_wallet(&self.context.config().signer)?
+            .ok_or(Error::MissingDeployerPrivateKey)?;
+
+        let stacks = self.context.get_stacks_client();
+        let account = stacks.get_account(wallet.address()).await?;
+        wallet.set_nonce(account.nonce);
+
+        Ok(wallet)
+    }
+
     async fn get_signer_wallet(&self) -> Result<SignerWallet, Error> {
         let wallet = SignerWallet::load(&self.context).await?;
 
@@ -2489,6 +4780,85 @@ where
     }
 }
 
+/// Whether the signing threshold is out of reach once the given number of
+/// signers, out of the whole signing set, have refused to sign.
+fn quorum_is_unreachable(num_signers: usize, num_refusals: usize, threshold: u16) -> bool {
+    num_signers.saturating_sub(num_refusals) < threshold as usize
+}
+
+/// Return the deposit outpoints, out of the given ones, that are already
+/// spent by a transaction other than one of our sweeps.
+///
+/// This returns an error if the signers' UTXO has been spent in a
+/// confirmed transaction, since then our view of the signers' UTXO is
+/// stale. Spends of the signers' UTXO in the mempool are our own sweeps,
+/// which the next sweep replaces, so deposits spent by those sweeps or by
+/// their descendants are not considered spent.
+pub async fn find_spent_sweep_inputs<B, I>(
+    bitcoin_client: &B,
+    signer_outpoint: OutPoint,
+    deposit_outpoints: I,
+) -> Result<HashSet<OutPoint>, Error>
+where
+    B: BitcoinInteract,
+    I: IntoIterator<Item = OutPoint>,
+{
+    let signer_utxo = bitcoin_client
+        .get_transaction_output(&signer_outpoint, false)
+        .await?;
+    if signer_utxo.is_none() {
+        return Err(Error::SignerUtxoSpent(signer_outpoint));
+    }
+
+    let mut own_sweeps = HashSet::new();
+    let sweeps = bitcoin_client
+        .find_mempool_transactions_spending_output(&signer_outpoint)
+        .await?;
+    for txid in sweeps {
+        own_sweeps.extend(bitcoin_client.find_mempool_descendants(&txid).await?);
+        own_sweeps.insert(txid);
+    }
+
+    let mut spent = HashSet::new();
+    for outpoint in deposit_outpoints {
+        let unspent = bitcoin_client
+            .get_transaction_output(&outpoint, true)
+            .await?;
+        if unspent.is_some() {
+            continue;
+        }
+
+        let confirmed = bitcoin_client
+            .get_transaction_output(&outpoint, false)
+            .await?;
+        if confirmed.is_none() {
+            tracing::warn!(
+                %outpoint,
+                "leaving out a deposit that has been spent in a confirmed transaction"
+            );
+            spent.insert(outpoint);
+            continue;
+        }
+
+        let spenders: Vec<bitcoin::Txid> = bitcoin_client
+            .find_mempool_transactions_spending_output(&outpoint)
+            .await?
+            .into_iter()
+            .filter(|txid| !own_sweeps.contains(txid))
+            .collect();
+        if !spenders.is_empty() {
+            tracing::warn!(
+                %outpoint,
+                ?spenders,
+                "leaving out a deposit that is spent by another transaction in the mempool"
+            );
+            spent.insert(outpoint);
+        }
+    }
+
+    Ok(spent)
+}
+
 /// Check if the provided public key is the coordinator for the provided chain
 /// tip
 pub fn given_key_is_coordinator(
@@ -2499,11 +4869,66 @@ pub fn given_key_is_coordinator(
     coordinator_public_key(bitcoin_chain_tip, signer_public_keys) == Some(pub_key)
 }
 
-/// Find the coordinator public key
-pub fn coordinator_public_key(
+/// Break the tie between two signers that both act as coordinator for the
+/// given bitcoin chain tip, returning the public key of the one that
+/// keeps its tenure.
+///
+/// This happens when the signers have different views of the signing
+/// set, for example right after a network partition heals. Both signers
+/// compute the same winner: the one whose public key, hashed together with
+/// the chain tip, gives the smallest digest.
+pub fn coordinator_tie_break_winner(
+    bitcoin_chain_tip: &model::BitcoinBlockHash,
+    first: PublicKey,
+    second: PublicKey,
+) -> PublicKey {
+    let digest = |public_key: &PublicKey| -> [u8; 32] {
+        sha2::Sha256::new_with_prefix(bitcoin_chain_tip.into_bytes())
+            .chain_update(public_key.serialize())
+            .finalize()
+            .into()
+    };
+
+    if digest(&first) <= digest(&second) {
+        first
+    } else {
+        second
+    }
+}
+
+/// The inputs and outcome of the coordinator selection for a bitcoin
+/// chain tip, for auditing who should have acted on a given block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoordinatorSelection {
+    /// The SHA256 digest of the bitcoin chain tip.
+    pub digest: [u8; 32],
+    /// The big-endian integer formed by the first 4 bytes of the digest.
+    pub index: u32,
+    /// The public keys of the signing set, starting with the coordinator
+    /// and continuing in the order of the signing set, wrapping around at
+    /// the end. The position of a signer in this list is the number of
+    /// signers that come before it in the rotation.
+    pub ordering: Vec<PublicKey>,
+}
+
+impl CoordinatorSelection {
+    /// The public key of the coordinator, if the signing set is not
+    /// empty.
+    pub fn coordinator(&self) -> Option<PublicKey> {
+        self.ordering.first().copied()
+    }
+}
+
+/// Run the coordinator selection for the given bitcoin chain tip and
+/// signing set.
+///
+/// This is the function that every signer uses to decide who coordinates
+/// the signing rounds for a bitcoin block, so operators can use it to
+/// independently verify the behavior of the signers.
+pub fn coordinator_selection(
     bitcoin_chain_tip: &model::BitcoinBlockHash,
     signer_public_keys: &BTreeSet<PublicKey>,
-) -> Option<PublicKey> {
+) -> CoordinatorSelection {
     // Create a hash of the bitcoin chain tip. SHA256 will always result in
     // a 32 byte digest.
     let mut hasher = sha2::Sha256::new();
@@ -2521,11 +4946,26 @@ pub fn coordinator_public_key(
     let index = u32::from_be_bytes(u32_bytes);
 
     let num_signers = signer_public_keys.len();
+    let ordering = match num_signers {
+        0 => Vec::new(),
+        _ => signer_public_keys
+            .iter()
+            .cycle()
+            .skip((index as usize) % num_signers)
+            .take(num_signers)
+            .copied()
+            .collect(),
+    };
+
+    CoordinatorSelection { digest, index, ordering }
+}
 
-    signer_public_keys
-        .iter()
-        .nth((index as usize) % num_signers)
-        .copied()
+/// Find the coordinator public key
+pub fn coordinator_public_key(
+    bitcoin_chain_tip: &model::BitcoinBlockHash,
+    signer_public_keys: &BTreeSet<PublicKey>,
+) -> Option<PublicKey> {
+    coordinator_selection(bitcoin_chain_tip, signer_public_keys).coordinator()
 }
 
 /// Determine, according to the current state of the signer and configuration,
@@ -2643,32 +5083,47 @@ where
 
 /// Adjust the wallet nonce based on the error
 pub fn adjust_nonce(wallet: &SignerWallet, error: &Error) {
-    match error {
-        // For `ConflictingNonceInMempool` we don't want to decrement the nonce
-        // to avoid failing also the following submissions
+    if releases_nonce(error) {
+        wallet.set_nonce(wallet.get_nonce().saturating_sub(1));
+    }
+}
+
+/// Whether [`adjust_nonce`] gives the nonce of a failed transaction back
+/// to the wallet.
+fn releases_nonce(error: &Error) -> bool {
+    // For `ConflictingNonceInMempool` we don't want to decrement the nonce
+    // to avoid failing also the following submissions
+    !matches!(
+        error,
         Error::StacksTxRejection(TxRejection {
             reason: RejectionReason::ConflictingNonceInMempool,
             ..
-        }) => (),
-        _ => wallet.set_nonce(wallet.get_nonce().saturating_sub(1)),
-    }
+        })
+    )
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
     use std::num::NonZeroU32;
 
+    use bitcoin::hashes::Hash as _;
+
     use crate::bitcoin::MockBitcoinInteract;
+    use crate::config::NetworkKind;
     use crate::context::Context;
     use crate::emily_client::MockEmilyInteract;
     use crate::error::Error;
     use crate::keys::{PrivateKey, PublicKey};
+    use crate::network::MessageTransfer as _;
     use crate::stacks::api::MockStacksInteract;
+    use crate::stacks::wallet::SignerWallet;
     use crate::storage::memory::SharedStore;
     use crate::storage::model::BitcoinBlockHeight;
-    use crate::storage::{DbWrite, model};
+    use crate::storage::{DbRead as _, DbWrite, model};
     use crate::testing;
     use crate::testing::context::*;
+    use crate::testing::faults::{Fault, FaultRule, FaultSchedule, Faulty};
     use crate::testing::transaction_coordinator::TestEnvironment;
 
     use fake::{Fake, Faker};
@@ -3076,4 +5531,527 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn coordinator_tie_break_is_symmetric() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(46);
+        for _ in 0..20 {
+            let chain_tip: model::BitcoinBlockHash = Faker.fake_with_rng(&mut rng);
+            let first = PublicKey::from_private_key(&PrivateKey::new(&mut rng));
+            let second = PublicKey::from_private_key(&PrivateKey::new(&mut rng));
+
+            let winner = super::coordinator_tie_break_winner(&chain_tip, first, second);
+            assert!(winner == first || winner == second);
+            assert_eq!(
+                super::coordinator_tie_break_winner(&chain_tip, second, first),
+                winner
+            );
+        }
+    }
+
+    #[test]
+    fn coordinator_selection_orders_whole_signing_set() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(47);
+        let signer_set: BTreeSet<PublicKey> = (0..7)
+            .map(|_| PublicKey::from_private_key(&PrivateKey::new(&mut rng)))
+            .collect();
+
+        for _ in 0..20 {
+            let chain_tip: model::BitcoinBlockHash = Faker.fake_with_rng(&mut rng);
+            let selection = super::coordinator_selection(&chain_tip, &signer_set);
+
+            assert_eq!(
+                selection.coordinator(),
+                super::coordinator_public_key(&chain_tip, &signer_set)
+            );
+            assert_eq!(selection.ordering.len(), signer_set.len());
+            let ordered: BTreeSet<PublicKey> = selection.ordering.iter().copied().collect();
+            assert_eq!(ordered, signer_set);
+        }
+
+        let chain_tip: model::BitcoinBlockHash = Faker.fake_with_rng(&mut rng);
+        let selection = super::coordinator_selection(&chain_tip, &BTreeSet::new());
+        assert!(selection.ordering.is_empty());
+        assert_eq!(selection.coordinator(), None);
+    }
+
+    #[test_case(5, 0, 3 => false; "no refusals")]
+    #[test_case(5, 2, 3 => false; "threshold still reachable")]
+    #[test_case(5, 3, 3 => true; "threshold out of reach")]
+    #[test_case(5, 7, 3 => true; "more refusals than signers")]
+    fn quorum_is_unreachable(num_signers: usize, num_refusals: usize, threshold: u16) -> bool {
+        super::quorum_is_unreachable(num_signers, num_refusals, threshold)
+    }
+
+    #[tokio::test]
+    async fn missing_deposit_decisions_are_requested_from_silent_signers() {
+        let ctx = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .build();
+        let network = crate::network::in_memory2::WanNetwork::default();
+        let mut coordinator =
+            super::TxCoordinatorEventLoop::builder(ctx.clone(), network.connect(&ctx).spawn())
+                .with_threshold(2)
+                .with_context_window(6)
+                .build()
+                .unwrap();
+        let other_ctx = TestContext::default_mocked();
+        let mut network_rx = network.connect(&other_ctx).spawn();
+
+        let signer_set: Vec<PublicKey> = (0..3).map(|_| Faker.fake()).collect();
+        let aggregate_key: PublicKey = Faker.fake();
+        let storage = ctx.get_storage_mut();
+        let key_rotation = model::KeyRotationEvent {
+            txid: Faker.fake(),
+            block_hash: Faker.fake(),
+            address: Faker.fake(),
+            aggregate_key,
+            signer_set: signer_set.clone(),
+            signatures_required: 2,
+        };
+        storage
+            .write_rotate_keys_transaction(&key_rotation)
+            .await
+            .unwrap();
+
+        let block = model::BitcoinBlock {
+            block_hash: Faker.fake(),
+            block_height: 100u64.into(),
+            parent_hash: Faker.fake(),
+        };
+        storage.write_bitcoin_block(&block).await.unwrap();
+        let mut request: model::DepositRequest = Faker.fake();
+        request.lock_time = 1000;
+        testing::dummy::set_deposit_scripts(&mut request);
+        storage.write_deposit_request(&request).await.unwrap();
+        let tx_ref = model::BitcoinTxRef {
+            txid: request.txid,
+            block_hash: block.block_hash,
+        };
+        storage.write_bitcoin_transaction(&tx_ref).await.unwrap();
+
+        // Only one of the three signers has accepted the deposit, and the
+        // other two have not sent a decision, so the coordinator asks
+        // them for it.
+        let decision = model::DepositSigner {
+            txid: request.txid,
+            output_index: request.output_index,
+            signer_pub_key: signer_set[0],
+            can_accept: true,
+            can_sign: true,
+            decision_version: 0,
+        };
+        storage
+            .write_deposit_signer_decision(&decision)
+            .await
+            .unwrap();
+
+        let chain_tip = model::BitcoinBlockRef::from(&block);
+        coordinator
+            .request_missing_deposit_decisions(&chain_tip, &aggregate_key)
+            .await
+            .unwrap();
+
+        let msg = tokio::time::timeout(std::time::Duration::from_secs(1), network_rx.receive())
+            .await
+            .expect("no decision retry request was sent")
+            .unwrap();
+        let crate::message::Payload::DepositDecisionRetryRequest(retry_request) = &msg.payload
+        else {
+            panic!("expected a decision retry request, got {}", msg.payload);
+        };
+        let silent_signers: BTreeSet<PublicKey> = signer_set[1..].iter().copied().collect();
+        assert_eq!(retry_request.deposits, vec![request.outpoint()]);
+        assert_eq!(
+            retry_request.signers,
+            silent_signers.into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    /// Run the work of a tenure with a storage that records and fails
+    /// every call, advancing the clock past the tenure deadline once the
+    /// given storage method is called. Returns the storage calls made.
+    async fn tenure_work_storage_calls(expire_after: Option<&'static str>) -> Vec<&'static str> {
+        let schedule = FaultSchedule::new();
+        let storage = Faulty::new(
+            crate::storage::memory::Store::new_shared(),
+            schedule.clone(),
+        );
+        let mut ctx = TestContext::builder()
+            .with_storage(storage)
+            .with_mocked_clients()
+            .build();
+        let clock = crate::context::Clock::manual();
+        ctx.set_clock(clock.clone());
+
+        let budget = ctx.config().signer.coordinator_tenure_budget();
+        let calls = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded_calls = calls.clone();
+        schedule.push(FaultRule::new(Fault::Error, move |point| {
+            let Some(method) = point.method() else {
+                return false;
+            };
+            recorded_calls.lock().unwrap().push(method);
+            if expire_after == Some(method) {
+                clock.advance(budget);
+            }
+            true
+        }));
+
+        let network = crate::network::in_memory2::WanNetwork::default();
+        let mut coordinator =
+            super::TxCoordinatorEventLoop::builder(ctx.clone(), network.connect(&ctx).spawn())
+                .build()
+                .unwrap();
+
+        let bitcoin_chain_tip: model::BitcoinBlockRef = Faker.fake();
+        ctx.state().set_bitcoin_chain_tip(bitcoin_chain_tip);
+        let mut tenure = super::TenureContext {
+            bitcoin_chain_tip,
+            stacks_chain_tip: Some(Faker.fake()),
+            aggregate_key: Faker.fake(),
+            sbtc_limits: crate::context::SbtcLimits::unlimited(),
+            deadline: ctx.clock().now() + budget,
+            plan: None,
+            btc_state: None,
+        };
+        let signer_public_keys: BTreeSet<PublicKey> = (0..3).map(|_| Faker.fake()).collect();
+        let keys: Vec<PublicKey> = signer_public_keys.iter().copied().collect();
+        let wallet = SignerWallet::new(&keys, 2, NetworkKind::Regtest, 0).unwrap();
+
+        coordinator
+            .do_tenure_work(&mut tenure, &wallet, &signer_public_keys)
+            .await
+            .unwrap();
+
+        calls.lock().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn tenure_work_is_done_in_order_of_priority() {
+        let calls = tenure_work_storage_calls(None).await;
+
+        // The withdrawal rejections come first, then the stacks
+        // transactions for swept requests, and then the sweeps, which
+        // make storage calls of their own.
+        assert_eq!(
+            calls[..3],
+            [
+                "get_pending_rejected_withdrawal_requests",
+                "get_swept_deposit_requests",
+                "get_swept_withdrawal_requests",
+            ]
+        );
+        assert!(calls.len() > 3);
+    }
+
+    #[tokio::test]
+    async fn tenure_work_past_the_deadline_is_deferred() {
+        let reject = "get_pending_rejected_withdrawal_requests";
+        let calls = tenure_work_storage_calls(Some(reject)).await;
+
+        // The tenure budget ran out while handling the withdrawal
+        // rejections, so the rest of the work is left for the next tenure.
+        assert_eq!(calls, [reject]);
+
+        let calls = tenure_work_storage_calls(Some("get_swept_deposit_requests")).await;
+        assert_eq!(
+            calls,
+            [
+                reject,
+                "get_swept_deposit_requests",
+                "get_swept_withdrawal_requests"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn noop_tenures_are_not_planned() {
+        let schedule = FaultSchedule::new();
+        let storage = Faulty::new(
+            crate::storage::memory::Store::new_shared(),
+            schedule.clone(),
+        );
+        let ctx = TestContext::builder()
+            .with_storage(storage)
+            .with_mocked_clients()
+            .build();
+        ctx.state().set_sbtc_contracts_deployed();
+
+        let calls = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded_calls = calls.clone();
+        schedule.push(FaultRule::new(Fault::Error, move |point| {
+            if let Some(method) = point.method() {
+                recorded_calls.lock().unwrap().push(method);
+            }
+            false
+        }));
+
+        let network = crate::network::in_memory2::WanNetwork::default();
+        let mut coordinator =
+            super::TxCoordinatorEventLoop::builder(ctx.clone(), network.connect(&ctx).spawn())
+                .build()
+                .unwrap();
+
+        let bitcoin_chain_tip: model::BitcoinBlockRef = Faker.fake();
+        ctx.state().set_bitcoin_chain_tip(bitcoin_chain_tip);
+        let mut tenure = super::TenureContext {
+            bitcoin_chain_tip,
+            stacks_chain_tip: Some(Faker.fake()),
+            aggregate_key: Faker.fake(),
+            sbtc_limits: crate::context::SbtcLimits::unlimited(),
+            deadline: ctx.clock().now() + ctx.config().signer.coordinator_tenure_budget(),
+            plan: None,
+            btc_state: None,
+        };
+
+        assert!(!coordinator.plan_tenure(&mut tenure).await);
+        assert!(tenure.plan.is_none());
+
+        // The pending requests are looked up once to find out whether
+        // there are any, and the selection of the requests never runs.
+        let calls = calls.lock().unwrap().clone();
+        let count = |method: &str| calls.iter().filter(|call| **call == method).count();
+        assert_eq!(count("get_pending_accepted_deposit_requests"), 1);
+        assert_eq!(count("get_pending_accepted_withdrawal_requests"), 1);
+        assert_eq!(count("get_deposit_requests_signer_votes"), 0);
+        assert_eq!(count("get_withdrawal_request_signer_votes"), 0);
+        assert_eq!(count("write_tenure_plan"), 0);
+    }
+
+    #[tokio::test]
+    async fn empty_attestations_are_sent_and_stored() {
+        let ctx = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .build();
+
+        let network = crate::network::in_memory2::WanNetwork::default();
+        let mut coordinator =
+            super::TxCoordinatorEventLoop::builder(ctx.clone(), network.connect(&ctx).spawn())
+                .build()
+                .unwrap();
+
+        let chain_tip: model::BitcoinBlockRef = Faker.fake();
+        let attestation = super::CoordinatorAttestation::default();
+        coordinator
+            .send_attestation(attestation, &chain_tip.block_hash)
+            .await
+            .unwrap();
+
+        let storage = ctx.get_storage();
+        let attestations = storage
+            .get_coordinator_attestations(&chain_tip.block_hash)
+            .await
+            .unwrap();
+        assert_eq!(attestations.len(), 1);
+        let public_key = PublicKey::from_private_key(&coordinator.private_key);
+        assert_eq!(attestations[0].coordinator_public_key, public_key);
+        assert!(attestations[0].sweep_txids.is_empty());
+
+        let report = crate::attestation::verify_attestation(&storage, &chain_tip, &attestations[0])
+            .await
+            .unwrap();
+        assert!(report.all_confirmed());
+    }
+
+    #[test]
+    fn event_loop_builder_validates_settings() {
+        let ctx = TestContext::default_mocked();
+        let network = crate::network::watcher::WatcherNetwork;
+
+        let event_loop = super::TxCoordinatorEventLoop::builder(ctx.clone(), network)
+            .build()
+            .unwrap();
+        let config = &ctx.config().signer;
+        assert_eq!(event_loop.threshold, config.bootstrap_signatures_required);
+        assert_eq!(event_loop.context_window, config.context_window);
+        assert!(!event_loop.is_epoch3);
+
+        let result = super::TxCoordinatorEventLoop::builder(ctx.clone(), network)
+            .with_threshold(0)
+            .build();
+        assert!(matches!(result, Err(Error::InvalidEventLoopSetting(_))));
+
+        let result = super::TxCoordinatorEventLoop::builder(ctx, network)
+            .with_dkg_max_duration(std::time::Duration::ZERO)
+            .build();
+        assert!(matches!(result, Err(Error::InvalidEventLoopSetting(_))));
+    }
+
+    #[tokio::test]
+    async fn fee_bumps_reuse_the_nonce_of_the_replaced_transaction() {
+        let ctx = TestContext::default_mocked();
+        let network = crate::network::watcher::WatcherNetwork;
+        let coordinator = super::TxCoordinatorEventLoop::builder(ctx.clone(), network)
+            .build()
+            .unwrap();
+
+        let keys: Vec<PublicKey> = (0..3).map(|_| Faker.fake()).collect();
+        let wallet = SignerWallet::new(&keys, 2, NetworkKind::Regtest, 5).unwrap();
+
+        let mut sign_request: crate::message::StacksTransactionSignRequest = Faker.fake();
+        let multi_tx = super::MultisigTx::new_tx(&sign_request.contract_tx, &wallet, 1000);
+        sign_request.nonce = 5;
+        sign_request.tx_fee = 1000;
+        sign_request.txid = multi_tx.tx().txid();
+        assert_eq!(wallet.get_nonce(), 6);
+
+        // A transaction for the same request from an earlier tenure is
+        // still pending in the mempool.
+        let request_keys = vec![Faker.fake::<String>()];
+        let submission = model::StacksTxSubmission {
+            request_keys: request_keys.clone(),
+            nonce: 3,
+            tx_fee: 1000,
+            status: model::StacksTxStatus::Pending,
+            rejection_reason: None,
+            ..Faker.fake()
+        };
+        ctx.get_storage_mut()
+            .write_stacks_tx_submission(&submission)
+            .await
+            .unwrap();
+
+        let chain_tip: model::BitcoinBlockHash = Faker.fake();
+        let (sign_request, multi_tx) = coordinator
+            .apply_resubmission_policy(sign_request, &request_keys, &chain_tip, multi_tx, &wallet)
+            .await
+            .unwrap();
+
+        // The replacement uses the nonce of the pending transaction with
+        // a higher fee, and does not take another nonce from the wallet.
+        assert_eq!(sign_request.nonce, 3);
+        assert_eq!(multi_tx.tx().get_origin_nonce(), 3);
+        assert_eq!(sign_request.tx_fee, 1250);
+        assert_eq!(sign_request.txid, multi_tx.tx().txid());
+        assert_eq!(wallet.get_nonce(), 6);
+    }
+
+    #[test]
+    fn tenure_context_pending_requests_params() {
+        let mut tenure = super::TenureContext {
+            bitcoin_chain_tip: Faker.fake(),
+            stacks_chain_tip: None,
+            aggregate_key: Faker.fake(),
+            sbtc_limits: crate::context::SbtcLimits::unlimited(),
+            deadline: tokio::time::Instant::now(),
+            plan: None,
+            btc_state: None,
+        };
+        assert_eq!(tenure.tenure_id(), tenure.bitcoin_chain_tip.block_hash);
+
+        // Without a stacks chain tip there are no requests to fetch.
+        assert!(tenure.pending_requests_params(3).is_none());
+        assert!(matches!(
+            tenure.require_stacks_chain_tip(),
+            Err(Error::NoStacksChainTip)
+        ));
+
+        let stacks_chain_tip: model::StacksBlock = Faker.fake();
+        tenure.stacks_chain_tip = Some(stacks_chain_tip.clone());
+
+        let params = tenure.pending_requests_params(3).unwrap();
+        assert_eq!(params.bitcoin_chain_tip, &tenure.bitcoin_chain_tip);
+        assert_eq!(params.stacks_chain_tip, &stacks_chain_tip.block_hash);
+        assert_eq!(params.aggregate_key, &tenure.aggregate_key);
+        assert_eq!(params.sbtc_limits, &tenure.sbtc_limits);
+        assert_eq!(params.signature_threshold, 3);
+    }
+
+    fn tx_out() -> bitcoincore_rpc_json::GetTxOutResult {
+        bitcoincore_rpc_json::GetTxOutResult {
+            bestblock: bitcoin::BlockHash::all_zeros(),
+            confirmations: 1,
+            value: bitcoin::Amount::from_sat(100_000),
+            script_pub_key: bitcoincore_rpc_json::GetRawTransactionResultVoutScriptPubKey {
+                asm: String::new(),
+                hex: Vec::new(),
+                req_sigs: None,
+                type_: None,
+                addresses: Vec::new(),
+                address: None,
+            },
+            coinbase: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn spent_sweep_inputs_are_found() {
+        let outpoint =
+            |byte: u8| bitcoin::OutPoint::new(bitcoin::Txid::from_byte_array([byte; 32]), 0);
+        let signer_outpoint = outpoint(0);
+        let unspent = outpoint(1);
+        let confirmed_spend = outpoint(2);
+        let spent_by_our_sweep = outpoint(3);
+        let spent_by_descendant = outpoint(4);
+        let spent_by_other = outpoint(5);
+
+        let sweep_txid = bitcoin::Txid::from_byte_array([10; 32]);
+        let descendant_txid = bitcoin::Txid::from_byte_array([11; 32]);
+        let other_txid = bitcoin::Txid::from_byte_array([12; 32]);
+
+        let mut client = MockBitcoinInteract::new();
+        client
+            .expect_get_transaction_output()
+            .returning(move |outpoint, include_mempool| {
+                let result = if *outpoint == signer_outpoint || *outpoint == unspent {
+                    Some(tx_out())
+                } else if *outpoint == confirmed_spend {
+                    None
+                } else {
+                    (!include_mempool).then(tx_out)
+                };
+                Box::pin(async move { Ok(result) })
+            });
+        client
+            .expect_find_mempool_transactions_spending_output()
+            .returning(move |outpoint| {
+                let txids = if *outpoint == signer_outpoint || *outpoint == spent_by_our_sweep {
+                    vec![sweep_txid]
+                } else if *outpoint == spent_by_descendant {
+                    vec![descendant_txid]
+                } else if *outpoint == spent_by_other {
+                    vec![other_txid]
+                } else {
+                    Vec::new()
+                };
+                Box::pin(async move { Ok(txids) })
+            });
+        client
+            .expect_find_mempool_descendants()
+            .returning(move |_| Box::pin(async move { Ok(vec![descendant_txid]) }));
+
+        let deposits = [
+            unspent,
+            confirmed_spend,
+            spent_by_our_sweep,
+            spent_by_descendant,
+            spent_by_other,
+        ];
+        let spent = super::find_spent_sweep_inputs(&client, signer_outpoint, deposits)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            spent,
+            [confirmed_spend, spent_by_other].into_iter().collect()
+        );
+    }
+
+    #[tokio::test]
+    async fn spent_signer_utxo_is_an_error() {
+        let signer_outpoint = bitcoin::OutPoint::new(bitcoin::Txid::from_byte_array([0; 32]), 0);
+
+        let mut client = MockBitcoinInteract::new();
+        client
+            .expect_get_transaction_output()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+
+        let result = super::find_spent_sweep_inputs(&client, signer_outpoint, []).await;
+        assert!(
+            matches!(result, Err(Error::SignerUtxoSpent(outpoint)) if outpoint == signer_outpoint)
+        );
+    }
 }
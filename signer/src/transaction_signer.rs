@@ -6,12 +6,18 @@
 //! For more details, see the [`TxSignerEventLoop`] documentation.
 
 use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::collections::HashSet;
+use std::collections::VecDeque;
 use std::num::NonZeroUsize;
 use std::time::Duration;
 
+use crate::MAX_PRESIGN_RETRIES;
+use crate::attestation;
+use crate::bitcoin::get_tx_info_cached;
 use crate::bitcoin::utxo::UnsignedMockTransaction;
 use crate::bitcoin::validation::BitcoinTxContext;
+use crate::bitcoin::validation::TxRequestIds;
 use crate::context::Context;
 use crate::context::P2PEvent;
 use crate::context::SignerCommand;
@@ -28,6 +34,9 @@ use crate::keys::PublicKeyXOnly;
 use crate::message;
 use crate::message::BitcoinPreSignAck;
 use crate::message::Payload;
+use crate::message::RefusalReason;
+use crate::message::RefusedSignRequest;
+use crate::message::SignRequestRefusal;
 use crate::message::StacksTransactionSignRequest;
 use crate::message::WstsMessageId;
 use crate::metrics::Metrics;
@@ -41,28 +50,46 @@ use crate::stacks::contracts::StacksTx;
 use crate::stacks::wallet::MultisigTx;
 use crate::stacks::wallet::SignerWallet;
 use crate::storage::DbRead;
-use crate::storage::DbWrite as _;
+use crate::storage::DbWrite;
 use crate::storage::model;
 use crate::storage::model::BitcoinBlockHash;
 use crate::storage::model::DkgSharesStatus;
+use crate::storage::model::QualifiedRequestId;
 use crate::storage::model::SigHash;
+use crate::supervisor::report_error;
 use crate::wsts_state_machine::FrostCoordinator;
 use crate::wsts_state_machine::SignerStateMachine;
 use crate::wsts_state_machine::StateMachineId;
 use crate::wsts_state_machine::WstsCoordinator;
 
+use bitcoin::OutPoint;
 use bitcoin::TapSighash;
 use bitcoin::hashes::Hash as _;
+use futures::FutureExt as _;
 use futures::StreamExt;
 use lru::LruCache;
 use wsts::net::DkgEnd;
 use wsts::net::DkgStatus;
 use wsts::net::Message as WstsNetMessage;
 
+/// The name of this event loop, as used by the supervisor.
+const EVENT_LOOP: &str = "tx-signer";
+
 /// LRU cache max size for the stacks signature requests. This is the number of
 /// bitcoin tenures for which we keep track of the signed stacks transactions.
+/// The signer uses the `stacks_sign_request_cache_size` setting, which
+/// defaults to this value.
 pub const STACKS_SIGN_REQUEST_LRU_SIZE: NonZeroUsize = NonZeroUsize::new(2).expect("2 is non zero");
 
+/// The maximum number of stacks sign requests that the signer validates
+/// concurrently.
+pub const STACKS_SIGN_REQUEST_MAX_CONCURRENCY: usize = 4;
+
+/// The maximum number of stacks sign requests from a single coordinator
+/// that can be waiting to be processed. Requests that arrive while the
+/// queue of their coordinator is full are dropped.
+pub const STACKS_SIGN_REQUEST_QUEUE_SIZE: usize = 64;
+
 #[cfg_attr(doc, aquamarine::aquamarine)]
 /// # Transaction signer event loop
 ///
@@ -128,7 +155,10 @@ pub const STACKS_SIGN_REQUEST_LRU_SIZE: NonZeroUsize = NonZeroUsize::new(2).expe
 ///
 ///     SM --> |WSTS message| RWSM(Relay to WSTS state machine)
 /// ```
+///
+/// The event loop is created through [`TxSignerEventLoop::builder`].
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct TxSignerEventLoop<Context, Network, Rng> {
     /// The signer context.
     pub context: Context,
@@ -143,6 +173,10 @@ pub struct TxSignerEventLoop<Context, Network, Rng> {
     /// Last bitcoin block for which the signer has already processed
     /// presign request.
     pub last_presign_block: Option<BitcoinBlockHash>,
+    /// The requests in the last presign request that the signer
+    /// processed. The coordinator may retry that presign request for the
+    /// same bitcoin block with some of these requests left out.
+    pub last_presign_requests: PresignRequests,
     /// How many bitcoin blocks back from the chain tip the signer will look for requests.
     pub context_window: u16,
     /// Random number generator used for encryption
@@ -157,6 +191,8 @@ pub struct TxSignerEventLoop<Context, Network, Rng> {
     /// Stacks transactions signed during a bitcoin tenure. We don't allow
     /// signing for the same request multiple times in a tenure.
     pub stacks_sign_request: LruCache<model::BitcoinBlockHash, HashSet<StacksSignRequestId>>,
+    /// Stacks sign requests that are waiting to be processed.
+    pub stacks_sign_queue: StacksSignRequestQueue,
 }
 
 /// This struct represents a signature hash and the public key that locks
@@ -187,14 +223,34 @@ pub enum StacksSignRequestId {
 }
 
 impl StacksSignRequestId {
-    fn from_sign_request(request: &StacksTransactionSignRequest) -> Self {
-        match &request.contract_tx {
+    /// Return the identifiers of the requests that are fulfilled by the
+    /// transaction in the sign request. Most transactions fulfill exactly
+    /// one request, but batched complete-deposit and accept-withdrawal
+    /// transactions fulfill several.
+    pub fn from_sign_request(request: &StacksTransactionSignRequest) -> Vec<Self> {
+        let request_id = match &request.contract_tx {
             StacksTx::ContractCall(ContractCall::CompleteDepositV1(contract)) => {
                 StacksSignRequestId::CompleteDeposit(contract.outpoint)
             }
             StacksTx::ContractCall(ContractCall::AcceptWithdrawalV1(contract)) => {
                 StacksSignRequestId::CompleteWithdrawal(contract.id.request_id)
             }
+            StacksTx::ContractCall(ContractCall::CompleteDepositsV1(contract)) => {
+                return contract
+                    .deposits
+                    .iter()
+                    .map(|deposit| StacksSignRequestId::CompleteDeposit(deposit.outpoint))
+                    .collect();
+            }
+            StacksTx::ContractCall(ContractCall::AcceptWithdrawalsV1(contract)) => {
+                return contract
+                    .withdrawals
+                    .iter()
+                    .map(|withdrawal| {
+                        StacksSignRequestId::CompleteWithdrawal(withdrawal.id.request_id)
+                    })
+                    .collect();
+            }
             StacksTx::ContractCall(ContractCall::RejectWithdrawalV1(contract)) => {
                 StacksSignRequestId::CompleteWithdrawal(contract.id.request_id)
             }
@@ -202,7 +258,8 @@ impl StacksSignRequestId {
                 StacksSignRequestId::RotateKeys(contract.aggregate_key)
             }
             StacksTx::SmartContract(contract) => StacksSignRequestId::SmartContract(*contract),
-        }
+        };
+        vec![request_id]
     }
 }
 
@@ -210,19 +267,256 @@ impl std::fmt::Display for StacksSignRequestId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             StacksSignRequestId::CompleteDeposit(outpoint) => {
-                write!(f, "CompleteDeposit({outpoint}")
+                write!(f, "CompleteDeposit({outpoint})")
             }
             StacksSignRequestId::CompleteWithdrawal(request_id) => {
-                write!(f, "CompleteWithdrawal({request_id}")
+                write!(f, "CompleteWithdrawal({request_id})")
             }
-            StacksSignRequestId::RotateKeys(public_key) => write!(f, "RotateKeys({public_key}"),
+            StacksSignRequestId::RotateKeys(public_key) => write!(f, "RotateKeys({public_key})"),
             StacksSignRequestId::SmartContract(smart_contract) => {
-                write!(f, "SmartContract({smart_contract}")
+                write!(f, "SmartContract({smart_contract})")
             }
         }
     }
 }
 
+/// The requests of a bitcoin presign request that the signer processed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PresignRequests {
+    /// The deposit requests in the presign request.
+    pub deposits: HashSet<OutPoint>,
+    /// The withdrawal requests in the presign request.
+    pub withdrawals: HashSet<QualifiedRequestId>,
+    /// The number of times the coordinator has retried the presign
+    /// request for the same bitcoin block.
+    pub retries: u32,
+}
+
+impl PresignRequests {
+    /// Collect the requests in the given presign request.
+    pub fn new(request: &message::BitcoinPreSignRequest) -> Self {
+        let package = request.request_package.iter();
+        Self {
+            deposits: package
+                .clone()
+                .flat_map(|ids| ids.deposits.clone())
+                .collect(),
+            withdrawals: package.flat_map(|ids| ids.withdrawals.clone()).collect(),
+            retries: 0,
+        }
+    }
+
+    /// Whether these requests can be a retry of the given previous
+    /// requests. A retry must leave out at least one of the previous
+    /// requests and cannot add new ones, and the coordinator gets at most
+    /// [`MAX_PRESIGN_RETRIES`] of them.
+    pub fn is_retry_of(&self, previous: &PresignRequests) -> bool {
+        previous.retries < MAX_PRESIGN_RETRIES
+            && self.deposits.is_subset(&previous.deposits)
+            && self.withdrawals.is_subset(&previous.withdrawals)
+            && self.deposits.len() + self.withdrawals.len()
+                < previous.deposits.len() + previous.withdrawals.len()
+    }
+}
+
+/// A builder for a [`TxSignerEventLoop`]. The settings default to the
+/// ones in the config of the context, and are validated when the event
+/// loop is built.
+#[derive(Debug)]
+pub struct TxSignerEventLoopBuilder<Context, Network, Rng> {
+    event_loop: TxSignerEventLoop<Context, Network, Rng>,
+}
+
+impl<C, N, Rng> TxSignerEventLoopBuilder<C, N, Rng>
+where
+    C: Context,
+{
+    /// Create a new builder for a signer that uses the given context,
+    /// network and random number generator.
+    pub fn new(context: C, network: N, rng: Rng) -> Self {
+        let config = &context.config().signer;
+        let event_loop = TxSignerEventLoop {
+            network,
+            signer_private_key: config.private_key,
+            context_window: config.context_window,
+            wsts_state_machines: LruCache::new(config.wsts_state_machines_cache_size.into()),
+            threshold: config.bootstrap_signatures_required.into(),
+            last_presign_block: None,
+            last_presign_requests: PresignRequests::default(),
+            rng,
+            dkg_begin_pause: config.dkg_begin_pause.map(Duration::from_secs),
+            dkg_verification_state_machines: LruCache::new(
+                config.dkg_verification_state_machines_cache_size.into(),
+            ),
+            stacks_sign_request: LruCache::new(config.stacks_sign_request_cache_size.into()),
+            stacks_sign_queue: StacksSignRequestQueue::default(),
+            context,
+        };
+        Self { event_loop }
+    }
+
+    /// Set the private key of the signer.
+    pub fn with_private_key(mut self, private_key: PrivateKey) -> Self {
+        self.event_loop.signer_private_key = private_key;
+        self
+    }
+
+    /// Set the number of signatures required.
+    pub fn with_threshold(mut self, threshold: u32) -> Self {
+        self.event_loop.threshold = threshold;
+        self
+    }
+
+    /// Set how many bitcoin blocks back from the chain tip the signer
+    /// looks for requests.
+    pub fn with_context_window(mut self, context_window: u16) -> Self {
+        self.event_loop.context_window = context_window;
+        self
+    }
+
+    /// Set how long the signer waits before processing a DKG begin
+    /// message.
+    pub fn with_dkg_begin_pause(mut self, pause: Option<Duration>) -> Self {
+        self.event_loop.dkg_begin_pause = pause;
+        self
+    }
+
+    /// Set the number of WSTS state machines that the signer keeps around.
+    pub fn with_wsts_state_machines_cache_size(mut self, size: NonZeroUsize) -> Self {
+        self.event_loop.wsts_state_machines = LruCache::new(size);
+        self
+    }
+
+    /// Set the number of DKG verification state machines that the signer
+    /// keeps around.
+    pub fn with_dkg_verification_state_machines_cache_size(mut self, size: NonZeroUsize) -> Self {
+        self.event_loop.dkg_verification_state_machines = LruCache::new(size);
+        self
+    }
+
+    /// Set the number of bitcoin tenures for which the signer keeps track
+    /// of the stacks transactions that it signed.
+    pub fn with_stacks_sign_request_cache_size(mut self, size: NonZeroUsize) -> Self {
+        self.event_loop.stacks_sign_request = LruCache::new(size);
+        self
+    }
+
+    /// Validate the settings and build the event loop.
+    pub fn build(self) -> Result<TxSignerEventLoop<C, N, Rng>, Error> {
+        let event_loop = self.event_loop;
+        if event_loop.threshold == 0 || event_loop.threshold > u32::from(crate::MAX_KEYS) {
+            return Err(Error::InvalidEventLoopSetting(
+                "the threshold must be between one and the maximum number of signers",
+            ));
+        }
+        if event_loop.context_window == 0 {
+            return Err(Error::InvalidEventLoopSetting(
+                "the context window must not be zero",
+            ));
+        }
+        Ok(event_loop)
+    }
+}
+
+impl<C, N, Rng> TxSignerEventLoop<C, N, Rng>
+where
+    C: Context,
+{
+    /// Create a builder for a signer that uses the given context, network
+    /// and random number generator, with the settings in the config of
+    /// the context.
+    pub fn builder(context: C, network: N, rng: Rng) -> TxSignerEventLoopBuilder<C, N, Rng> {
+        TxSignerEventLoopBuilder::new(context, network, rng)
+    }
+}
+
+/// A stacks sign request that is waiting to be processed.
+#[derive(Debug, Clone)]
+pub struct QueuedStacksSignRequest {
+    /// The message that carried the sign request.
+    pub msg: network::Msg,
+    /// The sign request itself.
+    pub request: StacksTransactionSignRequest,
+    /// The canonical bitcoin chain tip when the request was received.
+    pub chain_tip: model::BitcoinBlockRef,
+}
+
+/// The stacks sign requests that are waiting to be processed.
+///
+/// Each coordinator gets its own bounded queue, and requests are taken
+/// from the coordinators in turn, so a coordinator that sends a burst of
+/// requests cannot crowd out the requests of another one.
+#[derive(Debug, Default)]
+pub struct StacksSignRequestQueue {
+    /// The queued requests of each coordinator.
+    requests: HashMap<PublicKey, VecDeque<QueuedStacksSignRequest>>,
+    /// The coordinators with queued requests, in the order in which they
+    /// are served.
+    turns: VecDeque<PublicKey>,
+}
+
+impl StacksSignRequestQueue {
+    /// Add the sign request to the queue of the coordinator that sent it.
+    pub fn push(&mut self, queued: QueuedStacksSignRequest) -> Result<(), Error> {
+        let coordinator = queued.msg.signer_public_key;
+        let requests = self.requests.entry(coordinator).or_default();
+        if requests.len() >= STACKS_SIGN_REQUEST_QUEUE_SIZE {
+            return Err(Error::StacksSignRequestQueueFull(coordinator));
+        }
+        if requests.is_empty() {
+            self.turns.push_back(coordinator);
+        }
+        requests.push_back(queued);
+        Ok(())
+    }
+
+    /// Take up to `max` sign requests from the queue, one from each
+    /// coordinator in turn.
+    pub fn pop_batch(&mut self, max: usize) -> Vec<QueuedStacksSignRequest> {
+        let mut batch = Vec::new();
+        while batch.len() < max {
+            let Some(coordinator) = self.turns.pop_front() else {
+                break;
+            };
+            let Some(requests) = self.requests.get_mut(&coordinator) else {
+                continue;
+            };
+            batch.extend(requests.pop_front());
+            if requests.is_empty() {
+                self.requests.remove(&coordinator);
+            } else {
+                self.turns.push_back(coordinator);
+            }
+        }
+        batch
+    }
+
+    /// The number of sign requests in the queue.
+    pub fn len(&self) -> usize {
+        self.requests.values().map(VecDeque::len).sum()
+    }
+
+    /// Whether there are no sign requests in the queue.
+    pub fn is_empty(&self) -> bool {
+        self.turns.is_empty()
+    }
+}
+
+/// Record a metric if inserting an entry with the given key into the LRU
+/// cache would evict another entry to make room for it.
+fn record_lru_eviction<K, V>(cache: &LruCache<K, V>, key: &K, cache_name: &'static str)
+where
+    K: std::hash::Hash + Eq,
+{
+    if !cache.contains(key) && cache.len() >= cache.cap().get() {
+        tracing::debug!(
+            cache = cache_name,
+            "evicting least recently used entry from cache"
+        );
+        metrics::counter!(Metrics::LruCacheEvictionsTotal, "cache" => cache_name).increment(1);
+    }
+}
+
 /// This function defines which messages this event loop is interested
 /// in.
 fn run_loop_message_filter(signal: &SignerSignal) -> bool {
@@ -233,6 +527,17 @@ fn run_loop_message_filter(signal: &SignerSignal) -> bool {
                 | message::Payload::SignerWithdrawalDecision(_)
                 | message::Payload::StacksTransactionSignature(_)
                 | message::Payload::BitcoinPreSignAck(_)
+                | message::Payload::DatabaseDigest(_)
+                | message::Payload::DepositDecisionRetryRequest(_)
+                | message::Payload::SignerDecisionBatch(_)
+                | message::Payload::MessageRedeliveryRequest(_)
+                | message::Payload::RedeliveredMessages(_)
+                | message::Payload::SignRequestRefusal(_)
+                | message::Payload::LimitOverrideVote(_)
+                | message::Payload::SignerKeyEndorsement(_)
+                | message::Payload::EmergencyRecoveryApproval(_)
+                | message::Payload::DecisionAcknowledgement(_)
+                | message::Payload::EmergencyRecoveryRevocation(_)
         ),
         SignerSignal::Command(SignerCommand::Shutdown)
         | SignerSignal::Event(SignerEvent::TxCoordinator(TxCoordinatorEvent::MessageGenerated(
@@ -253,33 +558,7 @@ where
     /// [`MessageTransfer`](network::MessageTransfer), and random number
     /// generator.
     pub fn new(context: C, network: N, rng: Rng) -> Result<Self, Error> {
-        // The _ as usize cast is fine, since we know that
-        // MAX_SIGNER_STATE_MACHINES is less than u32::MAX, and we only support
-        // running this binary on 32 or 64-bit CPUs.
-        let max_state_machines = NonZeroUsize::new(crate::MAX_SIGNER_STATE_MACHINES as usize)
-            .ok_or(Error::TypeConversion)?;
-
-        let config = context.config();
-        let signer_private_key = config.signer.private_key;
-        let context_window = config.signer.context_window;
-        let threshold = config.signer.bootstrap_signatures_required.into();
-        let dkg_begin_pause = config.signer.dkg_begin_pause.map(Duration::from_secs);
-
-        Ok(Self {
-            context,
-            network,
-            signer_private_key,
-            context_window,
-            wsts_state_machines: LruCache::new(max_state_machines),
-            threshold,
-            last_presign_block: None,
-            rng,
-            dkg_begin_pause,
-            dkg_verification_state_machines: LruCache::new(
-                NonZeroUsize::new(5).ok_or(Error::TypeConversion)?,
-            ),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        })
+        TxSignerEventLoopBuilder::new(context, network, rng).build()
     }
 
     /// Run the signer event loop
@@ -295,19 +574,24 @@ where
         };
         let mut signal_stream = self.context.as_signal_stream(run_loop_message_filter);
 
-        while let Some(message) = signal_stream.next().await {
-            match message {
-                SignerSignal::Command(SignerCommand::Shutdown) => break,
-                SignerSignal::Command(SignerCommand::P2PPublish(_)) => {}
-                SignerSignal::Event(event) => match event {
-                    SignerEvent::TxCoordinator(TxCoordinatorEvent::MessageGenerated(msg))
-                    | SignerEvent::P2P(P2PEvent::MessageReceived(msg)) => {
-                        if let Err(error) = self.handle_signer_message(&msg).await {
-                            tracing::error!(%error, "error processing signer message");
-                        }
-                    }
-                    _ => {}
-                },
+        let mut running = true;
+        while running {
+            let Some(signal) = signal_stream.next().await else {
+                break;
+            };
+            running = self.handle_signal(signal).await;
+
+            // Take in the messages that have already arrived, so that a
+            // burst of stacks sign requests is validated concurrently.
+            while running && self.stacks_sign_queue.len() < STACKS_SIGN_REQUEST_MAX_CONCURRENCY {
+                let Some(Some(signal)) = signal_stream.next().now_or_never() else {
+                    break;
+                };
+                running = self.handle_signal(signal).await;
+            }
+
+            if running {
+                self.process_stacks_sign_requests().await;
             }
         }
 
@@ -315,6 +599,50 @@ where
         Ok(())
     }
 
+    /// Handle a signal from the signal stream, returning whether the event
+    /// loop should keep running.
+    async fn handle_signal(&mut self, signal: SignerSignal) -> bool {
+        match signal {
+            SignerSignal::Command(SignerCommand::Shutdown) => return false,
+            SignerSignal::Command(
+                SignerCommand::P2PPublish(_) | SignerCommand::ResyncBitcoinBlocks(_),
+            ) => {}
+            SignerSignal::Event(event) => match event {
+                SignerEvent::TxCoordinator(TxCoordinatorEvent::MessageGenerated(msg)) => {
+                    self.handle_and_record_signer_message(&msg).await;
+                }
+                SignerEvent::P2P(P2PEvent::MessageReceived(msg)) => {
+                    self.context
+                        .state()
+                        .transcripts()
+                        .record_message_received(&msg);
+                    self.handle_and_record_signer_message(&msg).await;
+                }
+                _ => {}
+            },
+        }
+        true
+    }
+
+    /// Handle the given message, recording the outcome in the transcript
+    /// of the tenure.
+    async fn handle_and_record_signer_message(&mut self, msg: &network::Msg) {
+        let num_queued = self.stacks_sign_queue.len();
+        let result = self.handle_signer_message(msg).await;
+        // Stacks sign requests are recorded once they have been processed.
+        if self.stacks_sign_queue.len() > num_queued {
+            return;
+        }
+        self.context
+            .state()
+            .transcripts()
+            .record_validation(msg, &result);
+
+        if let Err(error) = result {
+            report_error(&self.context, EVENT_LOOP, &error, "process signer message");
+        }
+    }
+
     #[tracing::instrument(skip_all, fields(chain_tip = tracing::field::Empty))]
     async fn handle_signer_message(&mut self, msg: &network::Msg) -> Result<(), Error> {
         let chain_tip_report = self
@@ -337,14 +665,17 @@ where
         );
 
         let payload = &msg.inner.payload;
+        if chain_tip_status == ChainTipStatus::Canonical && payload.is_coordinator_message() {
+            self.check_for_competing_coordinator(msg.signer_public_key, &chain_tip);
+        }
+
         match (payload, sender_is_coordinator, chain_tip_status) {
             (Payload::StacksTransactionSignRequest(request), true, ChainTipStatus::Canonical) => {
-                self.handle_stacks_transaction_sign_request(
-                    request,
-                    &chain_tip,
-                    &msg.signer_public_key,
-                )
-                .await?;
+                self.stacks_sign_queue.push(QueuedStacksSignRequest {
+                    msg: msg.clone(),
+                    request: request.clone(),
+                    chain_tip,
+                })?;
             }
 
             (Payload::WstsMessage(wsts_msg), _, ChainTipStatus::Canonical) => {
@@ -359,12 +690,40 @@ where
                     .await;
 
                 Metrics::increment_presign_validation(instant.elapsed(), &presign_result);
+                if let Err(error) = &presign_result {
+                    let request = RefusedSignRequest::BitcoinPreSign;
+                    self.send_sign_request_refusal(request, error, &chain_tip.block_hash)
+                        .await;
+                }
                 presign_result?;
+
+                // Not having the sweeps at hand only makes the validation
+                // of the Stacks sign requests slower, so failing to fetch
+                // them is not an error.
+                if let Err(error) = self.prefetch_sweep_tx_infos(&chain_tip.block_hash).await {
+                    tracing::warn!(%error, "could not prefetch sweep transactions");
+                }
+            }
+
+            (Payload::CoordinatorAttestation(attestation), true, ChainTipStatus::Canonical) => {
+                self.handle_coordinator_attestation(attestation, msg)
+                    .await?;
             }
             // Message types ignored by the transaction signer
             (Payload::StacksTransactionSignature(_), _, _)
             | (Payload::SignerDepositDecision(_), _, _)
-            | (Payload::SignerWithdrawalDecision(_), _, _) => (),
+            | (Payload::SignerWithdrawalDecision(_), _, _)
+            | (Payload::DatabaseDigest(_), _, _)
+            | (Payload::DepositDecisionRetryRequest(_), _, _)
+            | (Payload::SignerDecisionBatch(_), _, _)
+            | (Payload::MessageRedeliveryRequest(_), _, _)
+            | (Payload::RedeliveredMessages(_), _, _)
+            | (Payload::SignRequestRefusal(_), _, _)
+            | (Payload::LimitOverrideVote(_), _, _)
+            | (Payload::SignerKeyEndorsement(_), _, _)
+            | (Payload::EmergencyRecoveryApproval(_), _, _)
+            | (Payload::DecisionAcknowledgement(_), _, _)
+            | (Payload::EmergencyRecoveryRevocation(_), _, _) => (),
 
             // Any other combination should be logged
             _ => {
@@ -375,6 +734,58 @@ where
         Ok(())
     }
 
+    /// Check whether the sender of a coordinator message for our chain tip
+    /// is acting as coordinator while we are the coordinator too.
+    ///
+    /// Only the messages that a signer sends while it runs a coordinator
+    /// tenure count, see [`Payload::is_coordinator_message`], and only
+    /// when they come from a member of our signing set. When this happens,
+    /// both signers apply the same deterministic tie-break, see
+    /// [`coordinator_tie_break_winner`](crate::transaction_coordinator::coordinator_tie_break_winner),
+    /// and the one that loses it aborts its tenure.
+    fn check_for_competing_coordinator(
+        &self,
+        sender: PublicKey,
+        chain_tip: &model::BitcoinBlockRef,
+    ) {
+        let public_key = self.signer_public_key();
+        let state = self.context.state();
+        let signer_set = &self.context.config().signer.bootstrap_signing_set;
+
+        let we_are_coordinator = crate::transaction_coordinator::given_key_is_coordinator(
+            public_key,
+            &chain_tip.block_hash,
+            signer_set,
+        );
+        if sender == public_key
+            || !signer_set.contains(&sender)
+            || !we_are_coordinator
+            || state.is_coordinator_paused()
+        {
+            return;
+        }
+
+        metrics::counter!(Metrics::CoordinatorConflictsTotal).increment(1);
+
+        let winner = crate::transaction_coordinator::coordinator_tie_break_winner(
+            &chain_tip.block_hash,
+            public_key,
+            sender,
+        );
+        if winner == public_key {
+            tracing::warn!(
+                other_coordinator = %sender,
+                "another signer is acting as coordinator for our chain tip; we won the tie-break"
+            );
+        } else {
+            tracing::warn!(
+                other_coordinator = %sender,
+                "another signer is acting as coordinator for our chain tip; we lost the tie-break"
+            );
+            state.set_coordinator_tie_break_lost(chain_tip.block_hash);
+        }
+    }
+
     /// Find out the status of the given chain tip
     #[tracing::instrument(skip_all)]
     async fn inspect_msg_chain_tip(
@@ -416,6 +827,22 @@ where
         })
     }
 
+    /// Persist the attestation made by the coordinator at the end of its
+    /// tenure.
+    ///
+    /// The entire signed message is stored alongside the attestation so
+    /// that the coordinator's signature can be verified later, see
+    /// [`crate::attestation::verify_attestation`].
+    #[tracing::instrument(skip_all)]
+    async fn handle_coordinator_attestation(
+        &mut self,
+        attestation: &message::CoordinatorAttestation,
+        msg: &network::Msg,
+    ) -> Result<(), Error> {
+        let storage = self.context.get_storage_mut();
+        attestation::store_attestation(&storage, attestation, msg).await
+    }
+
     /// Processes the [`BitcoinPreSignRequest`] message.
     /// The signer reconstructs the sighashes for the provided requests
     /// based on the current state of its UTXO and fee details obtained
@@ -430,10 +857,15 @@ where
     ) -> Result<(), Error> {
         let db = self.context.get_storage_mut();
 
+        let mut requests = PresignRequests::new(request);
         if self.last_presign_block == Some(chain_tip.block_hash) {
-            return Err(Error::InvalidPresignRequest(chain_tip.block_hash));
+            if !requests.is_retry_of(&self.last_presign_requests) {
+                return Err(Error::InvalidPresignRequest(chain_tip.block_hash));
+            }
+            requests.retries = self.last_presign_requests.retries + 1;
         }
         self.last_presign_block = Some(chain_tip.block_hash);
+        self.last_presign_requests = requests;
 
         let aggregate_key = self
             .context
@@ -465,9 +897,24 @@ where
             .construct_package_sighashes(&self.context, &btc_ctx)
             .await?;
 
-        let deposits_sighashes: Vec<model::BitcoinTxSigHash> =
+        let mut deposits_sighashes: Vec<model::BitcoinTxSigHash> =
             sighashes.iter().flat_map(|s| s.to_input_rows()).collect();
 
+        // Refunds are not part of the request package, so they do not
+        // affect whether we acknowledge the request. We just won't sign
+        // the ones that our operator has not approved.
+        let refund_sighashes = request
+            .construct_refund_sighashes(&self.context, &btc_ctx)
+            .await?;
+        deposits_sighashes.extend(refund_sighashes);
+
+        // Likewise, we only sign the emergency recovery sweep if the
+        // signer set approved it and the delay has passed.
+        let recovery_sighashes = request
+            .construct_recovery_sighashes(&self.context, &btc_ctx)
+            .await?;
+        deposits_sighashes.extend(recovery_sighashes);
+
         let withdrawals_outputs: Vec<model::BitcoinWithdrawalOutput> = sighashes
             .iter()
             .flat_map(|s| s.to_withdrawal_rows())
@@ -479,9 +926,88 @@ where
         db.write_bitcoin_withdrawals_outputs(&withdrawals_outputs)
             .await?;
 
-        self.send_message(BitcoinPreSignAck, &chain_tip.block_hash)
+        // We will not sign transactions with requests that fail
+        // validation, so instead of acknowledging the request we tell the
+        // coordinator which requests those are, so that it can try again
+        // without them.
+        let mut contested = TxRequestIds {
+            deposits: Vec::new(),
+            withdrawals: Vec::new(),
+        };
+        for tx_sighashes in sighashes.iter() {
+            let tx_contested = tx_sighashes.contested_requests();
+            contested.deposits.extend(tx_contested.deposits);
+            contested.withdrawals.extend(tx_contested.withdrawals);
+        }
+
+        if contested.deposits.is_empty() && contested.withdrawals.is_empty() {
+            self.send_message(BitcoinPreSignAck, &chain_tip.block_hash)
+                .await?;
+            return Ok(());
+        }
+
+        tracing::debug!(
+            num_deposits = contested.deposits.len(),
+            num_withdrawals = contested.withdrawals.len(),
+            "refusing presign request with requests that fail validation"
+        );
+        let refusal = SignRequestRefusal {
+            request: RefusedSignRequest::BitcoinPreSign,
+            reason: RefusalReason::InvalidRequest,
+            contested,
+        };
+        self.send_message(refusal, &chain_tip.block_hash).await?;
+
+        Ok(())
+    }
+
+    /// Fetch the sweep transactions of the swept requests that still need
+    /// a Stacks contract call into the cache used by
+    /// [`get_tx_info_cached`].
+    ///
+    /// The coordinator asks us to sign those contract calls after the
+    /// bitcoin transactions of its tenure, one request at a time, so this
+    /// fetches each sweep transaction once instead of once per request.
+    /// Deposits with a recorded assessed fee do not need their sweep.
+    async fn prefetch_sweep_tx_infos(
+        &self,
+        chain_tip: &model::BitcoinBlockHash,
+    ) -> Result<(), Error> {
+        if self.context.config().signer.tx_info_cache_ttl.is_zero() {
+            return Ok(());
+        }
+
+        let db = self.context.get_storage();
+        let swept_deposits = db
+            .get_swept_deposit_requests(chain_tip, self.context_window)
+            .await?;
+        let swept_withdrawals = db
+            .get_swept_withdrawal_requests(chain_tip, self.context_window)
             .await?;
 
+        let mut sweeps = HashSet::new();
+        for req in swept_deposits {
+            let outpoint = req.deposit_outpoint();
+            let assessed_fee = db
+                .get_deposit_assessed_fee(&req.sweep_txid, &req.sweep_block_hash, &outpoint)
+                .await?;
+            if assessed_fee.is_none() {
+                sweeps.insert((req.sweep_txid, req.sweep_block_hash));
+            }
+        }
+        sweeps.extend(
+            swept_withdrawals
+                .iter()
+                .map(|req| (req.sweep_txid, req.sweep_block_hash)),
+        );
+
+        tracing::debug!(num_sweeps = sweeps.len(), "prefetching sweep transactions");
+        let lookups = sweeps
+            .iter()
+            .map(|(txid, block_hash)| get_tx_info_cached(&self.context, txid, block_hash));
+        for result in futures::future::join_all(lookups).await {
+            result?;
+        }
         Ok(())
     }
 
@@ -502,6 +1028,91 @@ where
         Metrics::increment_stacks_validation(instant.elapsed(), request, &validation_result);
         validation_result?;
 
+        self.sign_stacks_transaction(request, chain_tip).await
+    }
+
+    /// Process the queued stacks sign requests. Up to
+    /// [`STACKS_SIGN_REQUEST_MAX_CONCURRENCY`] requests are validated
+    /// concurrently, and then the valid ones are signed one at a time.
+    async fn process_stacks_sign_requests(&mut self) {
+        loop {
+            let batch = self
+                .stacks_sign_queue
+                .pop_batch(STACKS_SIGN_REQUEST_MAX_CONCURRENCY);
+            if batch.is_empty() {
+                break;
+            }
+
+            let this = &*self;
+            let validations = batch.iter().map(|queued| async move {
+                let instant = std::time::Instant::now();
+                let result = this.assert_valid_queued_stacks_sign_request(queued).await;
+                (instant.elapsed(), result)
+            });
+            let results = futures::future::join_all(validations).await;
+
+            for (queued, (elapsed, result)) in batch.into_iter().zip(results) {
+                Metrics::increment_stacks_validation(elapsed, &queued.request, &result);
+                let result = match result {
+                    Ok(()) => {
+                        self.sign_stacks_transaction(&queued.request, &queued.chain_tip)
+                            .await
+                    }
+                    Err(error) => Err(error),
+                };
+
+                self.context
+                    .state()
+                    .transcripts()
+                    .record_validation(&queued.msg, &result);
+                if let Err(error) = result {
+                    tracing::error!(
+                        %error,
+                        txid = %queued.request.txid,
+                        "error processing stacks sign request"
+                    );
+                    let request = RefusedSignRequest::StacksTransaction(queued.request.txid);
+                    self.send_sign_request_refusal(request, &error, &queued.chain_tip.block_hash)
+                        .await;
+                }
+            }
+        }
+    }
+
+    /// Validate a queued stacks sign request.
+    ///
+    /// Requests that were received on a bitcoin chain tip that is no
+    /// longer the canonical one are rejected. Signing them would record
+    /// them against an old tenure, which could evict the requests signed
+    /// in the current tenure from the cache of signed requests.
+    async fn assert_valid_queued_stacks_sign_request(
+        &self,
+        queued: &QueuedStacksSignRequest,
+    ) -> Result<(), Error> {
+        let chain_tip = self.context.state().bitcoin_chain_tip();
+        if chain_tip != Some(queued.chain_tip) {
+            return Err(Error::StaleStacksSignRequest(queued.chain_tip.block_hash));
+        }
+
+        self.assert_valid_stacks_tx_sign_request(
+            &queued.request,
+            &queued.chain_tip,
+            &queued.msg.signer_public_key,
+        )
+        .await
+    }
+
+    /// Sign the transaction of a validated stacks sign request and send
+    /// the signature to the coordinator.
+    async fn sign_stacks_transaction(
+        &mut self,
+        request: &StacksTransactionSignRequest,
+        chain_tip: &model::BitcoinBlockRef,
+    ) -> Result<(), Error> {
+        // Sign requests that are validated together may fulfill the same
+        // requests, so we check again that none of them were signed.
+        self.assert_not_already_signed(request, chain_tip)?;
+
         // We need to set the nonce in order to get the exact transaction
         // that we need to sign.
         let wallet = SignerWallet::load(&self.context).await?;
@@ -521,36 +1132,50 @@ where
         self.send_message(msg, &chain_tip.block_hash).await?;
 
         // Mark the sign request as signed for this tenure
-        let request_id = StacksSignRequestId::from_sign_request(request);
+        let request_ids = StacksSignRequestId::from_sign_request(request);
+        record_lru_eviction(
+            &self.stacks_sign_request,
+            &chain_tip.block_hash,
+            "stacks_sign_request",
+        );
         self.stacks_sign_request
             .get_or_insert_mut(chain_tip.block_hash, Default::default)
-            .insert(request_id);
+            .extend(request_ids);
 
         Ok(())
     }
 
+    /// Check that we have not signed for any of the requests fulfilled by
+    /// the transaction in the sign request during this tenure.
+    fn assert_not_already_signed(
+        &self,
+        request: &StacksTransactionSignRequest,
+        chain_tip: &model::BitcoinBlockRef,
+    ) -> Result<(), Error> {
+        let signed_request_ids = self.stacks_sign_request.peek(&chain_tip.block_hash);
+        let already_signed = StacksSignRequestId::from_sign_request(request)
+            .into_iter()
+            .find(|request_id| signed_request_ids.is_some_and(|set| set.contains(request_id)));
+        match already_signed {
+            Some(request_id) => Err(Error::StacksRequestAlreadySigned(
+                request_id,
+                *chain_tip.block_hash,
+            )),
+            None => Ok(()),
+        }
+    }
+
     /// Check that the transaction is indeed valid. We specific checks that
     /// are run depend on the transaction being signed.
     #[tracing::instrument(skip_all, fields(sender = %origin_public_key, txid = %request.txid), err)]
     pub async fn assert_valid_stacks_tx_sign_request(
-        &mut self,
+        &self,
         request: &StacksTransactionSignRequest,
         chain_tip: &model::BitcoinBlockRef,
         origin_public_key: &PublicKey,
     ) -> Result<(), Error> {
-        // Ensure we didn't already sign for this request
-        let request_id = StacksSignRequestId::from_sign_request(request);
-        let already_signed = self
-            .stacks_sign_request
-            .get(&chain_tip.block_hash)
-            .map(|set| set.contains(&request_id))
-            .unwrap_or(false);
-        if already_signed {
-            return Err(Error::StacksRequestAlreadySigned(
-                request_id,
-                *chain_tip.block_hash,
-            ));
-        }
+        // Ensure we didn't already sign for any of the requests
+        self.assert_not_already_signed(request, chain_tip)?;
 
         // Ensure that the Stacks fee is within the acceptable range.
         let highest_acceptable_fee = self.context.config().signer.stacks_fees_max_ustx.get();
@@ -613,9 +1238,15 @@ where
             StacksTx::ContractCall(ContractCall::AcceptWithdrawalV1(contract)) => {
                 contract.validate(ctx, &req_ctx).await?
             }
+            StacksTx::ContractCall(ContractCall::AcceptWithdrawalsV1(contract)) => {
+                contract.validate(ctx, &req_ctx).await?
+            }
             StacksTx::ContractCall(ContractCall::CompleteDepositV1(contract)) => {
                 contract.validate(ctx, &req_ctx).await?
             }
+            StacksTx::ContractCall(ContractCall::CompleteDepositsV1(contract)) => {
+                contract.validate(ctx, &req_ctx).await?
+            }
             StacksTx::ContractCall(ContractCall::RejectWithdrawalV1(contract)) => {
                 contract.validate(ctx, &req_ctx).await?
             }
@@ -687,6 +1318,11 @@ where
                     self.signer_private_key,
                 )?;
                 let state_machine_id = StateMachineId::Dkg(*chain_tip);
+                record_lru_eviction(
+                    &self.wsts_state_machines,
+                    &state_machine_id,
+                    "wsts_state_machines",
+                );
                 self.wsts_state_machines
                     .put(state_machine_id, state_machine);
 
@@ -696,7 +1332,7 @@ where
                     tracing::debug!(
                         "sleeping a bit to give the other peers some slack to get dkg-begin"
                     );
-                    tokio::time::sleep(pause).await;
+                    self.context.clock().sleep(pause).await;
                 }
 
                 // Process the message.
@@ -889,6 +1525,11 @@ where
                     SignerStateMachine::load(&db, aggregate_key, self.signer_private_key).await?;
 
                 // Put the state machine into the cache.
+                record_lru_eviction(
+                    &self.wsts_state_machines,
+                    &state_machine_id,
+                    "wsts_state_machines",
+                );
                 self.wsts_state_machines
                     .put(state_machine_id, state_machine);
 
@@ -937,10 +1578,15 @@ where
 
                         // Validate the sighash and upon success, convert it to
                         // a state machine ID.
-                        Self::validate_bitcoin_sign_request(&db, &request.message)
+                        let sighash = Self::validate_bitcoin_sign_request(&db, &request.message)
                             .await?
-                            .sighash
-                            .into()
+                            .sighash;
+
+                        // Record the sighash before we produce a signature
+                        // share for it, so that we never sign two different
+                        // spends of the same prevout at the same chain tip.
+                        self.record_signed_bitcoin_sighash(&sighash).await?;
+                        sighash.into()
                     }
 
                     // This is a DKG verification signing round. The data
@@ -1211,6 +1857,17 @@ where
         }
     }
 
+    /// Record that we are about to sign the given bitcoin sighash, failing
+    /// if we have already signed a different sighash that spends the same
+    /// prevout at the same chain tip.
+    async fn record_signed_bitcoin_sighash(&self, sighash: &SigHash) -> Result<(), Error> {
+        let db = self.context.get_storage_mut();
+        if let Some(signed) = db.get_conflicting_signed_sighash(sighash).await? {
+            return Err(Error::ConflictingSigHash { sighash: *sighash, signed });
+        }
+        db.write_signed_sighash(sighash).await
+    }
+
     /// Persists the encrypted DKG shares stored in the state machine identified
     /// by the given state machine id.
     #[tracing::instrument(skip(self))]
@@ -1307,6 +1964,11 @@ where
                 self.signer_private_key,
             )
             .await?;
+            record_lru_eviction(
+                &self.dkg_verification_state_machines,
+                &state_machine_id,
+                "dkg_verification_state_machines",
+            );
             self.dkg_verification_state_machines
                 .put(state_machine_id, coordinator);
         } else {
@@ -1568,12 +2230,36 @@ where
             .sign_ecdsa(&self.signer_private_key);
 
         self.network.broadcast(msg.clone()).await?;
+        self.context.state().transcripts().record_message_sent(&msg);
         self.context
             .signal(TxSignerEvent::MessageGenerated(Box::new(msg)).into())?;
 
         Ok(())
     }
 
+    /// Tell the coordinator that we will not sign the given request, and
+    /// why, so that it does not wait for our response until it times out.
+    async fn send_sign_request_refusal(
+        &mut self,
+        request: RefusedSignRequest,
+        error: &Error,
+        bitcoin_chain_tip: &model::BitcoinBlockHash,
+    ) {
+        let refusal = SignRequestRefusal {
+            request,
+            reason: RefusalReason::from(error),
+            contested: TxRequestIds {
+                deposits: Vec::new(),
+                withdrawals: Vec::new(),
+            },
+        };
+        tracing::debug!(reason = %refusal.reason, "refusing to sign the sign request");
+
+        if let Err(error) = self.send_message(refusal, bitcoin_chain_tip).await {
+            tracing::warn!(%error, "could not send sign request refusal");
+        }
+    }
+
     fn signer_public_key(&self) -> PublicKey {
         PublicKey::from_private_key(&self.signer_private_key)
     }
@@ -1588,13 +2274,28 @@ pub async fn assert_allow_dkg_begin(
     let storage = context.get_storage();
     let config = context.config();
 
+    let latest_dkg_shares_status = storage
+        .get_latest_encrypted_dkg_shares()
+        .await?
+        .map(|shares| shares.dkg_shares_status);
+    let refusal = |reason, dkg_shares_count| model::DkgBeginRefusal {
+        reason,
+        bitcoin_chain_tip: bitcoin_chain_tip.block_hash,
+        bitcoin_chain_tip_height: bitcoin_chain_tip.block_height,
+        latest_dkg_shares_status,
+        dkg_shares_count,
+        dkg_target_rounds: config.signer.dkg_target_rounds.get(),
+        dkg_min_bitcoin_block_height: config.signer.dkg_min_bitcoin_block_height,
+    };
+
     // If the latest shares are unverified, we want to prioritize verifying them
     // instead of doing new DKG rounds. If we fail to do so they will eventually
     // be marked as failed, and we will resume DKG-ing.
-    let latest_dkg_shares = storage.get_latest_encrypted_dkg_shares().await?;
-    if latest_dkg_shares.map(|s| s.dkg_shares_status) == Some(model::DkgSharesStatus::Unverified) {
+    if latest_dkg_shares_status == Some(model::DkgSharesStatus::Unverified) {
         tracing::warn!("latest shares are unverified; aborting");
-        return Err(Error::DkgHasAlreadyRun);
+        let dkg_shares_count = storage.get_encrypted_dkg_shares_count().await?;
+        let reason = model::DkgBeginRefusalReason::LatestSharesUnverified;
+        return Err(refuse_dkg_begin(context, refusal(reason, dkg_shares_count)).await);
     }
 
     // If we do not have a key rotation event in the database, we will
@@ -1641,7 +2342,8 @@ pub async fn assert_allow_dkg_begin(
                     dkg_current_rounds = %dkg_shares_entry_count,
                     "The target number of DKG shares has been reached; aborting"
                 );
-                return Err(Error::DkgHasAlreadyRun);
+                let reason = model::DkgBeginRefusalReason::TargetRoundsReached;
+                return Err(refuse_dkg_begin(context, refusal(reason, current)).await);
             }
             if bitcoin_chain_tip.block_height < dkg_min_height {
                 tracing::warn!(
@@ -1650,7 +2352,8 @@ pub async fn assert_allow_dkg_begin(
                     dkg_current_rounds = %dkg_shares_entry_count,
                     "bitcoin chain tip is below the minimum height for DKG rerun; aborting"
                 );
-                return Err(Error::DkgHasAlreadyRun);
+                let reason = model::DkgBeginRefusalReason::BelowMinimumHeight;
+                return Err(refuse_dkg_begin(context, refusal(reason, current)).await);
             }
             tracing::info!(
                 ?dkg_min_bitcoin_block_height,
@@ -1660,20 +2363,32 @@ pub async fn assert_allow_dkg_begin(
             );
         }
         // Note that we account for all (0, _, _) cases above (i.e. first DKG round)
-        (_, _, None) => {
+        (current, _, None) => {
             tracing::warn!(
                 ?dkg_min_bitcoin_block_height,
                 %dkg_target_rounds,
                 dkg_current_rounds = %dkg_shares_entry_count,
                 "attempt to run multiple DKGs without a configured re-run height; aborting"
             );
-            return Err(Error::DkgHasAlreadyRun);
+            let reason = model::DkgBeginRefusalReason::RerunHeightNotConfigured;
+            return Err(refuse_dkg_begin(context, refusal(reason, current)).await);
         }
     }
 
     Ok(())
 }
 
+/// Record the refusal of this signer to begin a DKG round, so that
+/// operators can see it through the status API, and return the error for
+/// the refusal. Failing to record the refusal does not change the outcome.
+async fn refuse_dkg_begin(context: &impl Context, refusal: model::DkgBeginRefusal) -> Error {
+    let storage = context.get_storage_mut();
+    if let Err(error) = storage.write_dkg_begin_refusal(&refusal).await {
+        tracing::warn!(%error, reason = %refusal.reason, "could not record the DKG begin refusal");
+    }
+    Error::DkgHasAlreadyRun
+}
+
 /// Relevant information for validating incoming messages
 /// relating to a particular chain tip.
 #[derive(Debug, Clone, Copy)]
@@ -1766,19 +2481,19 @@ mod tests {
             .await;
     }
 
-    #[test_case(0, None, 1, 100, true; "first DKG allowed without min height")]
-    #[test_case(0, Some(100), 1, 5, true; "first DKG allowed regardless of min height")]
-    #[test_case(1, None, 2, 100, false; "subsequent DKG not allowed without min height")]
-    #[test_case(1, Some(101), 1, 100, false; "subsequent DKG not allowed with current height lower than min height")]
-    #[test_case(1, Some(100), 1, 100, false; "subsequent DKG not allowed when target rounds reached")]
-    #[test_case(1, Some(100), 2, 100, true; "subsequent DKG allowed when target rounds not reached and min height met")]
+    #[test_case(0, None, 1, 100, None; "first DKG allowed without min height")]
+    #[test_case(0, Some(100), 1, 5, None; "first DKG allowed regardless of min height")]
+    #[test_case(1, None, 2, 100, Some(model::DkgBeginRefusalReason::RerunHeightNotConfigured); "subsequent DKG not allowed without min height")]
+    #[test_case(1, Some(101), 2, 100, Some(model::DkgBeginRefusalReason::BelowMinimumHeight); "subsequent DKG not allowed with current height lower than min height")]
+    #[test_case(1, Some(100), 1, 100, Some(model::DkgBeginRefusalReason::TargetRoundsReached); "subsequent DKG not allowed when target rounds reached")]
+    #[test_case(1, Some(100), 2, 100, None; "subsequent DKG allowed when target rounds not reached and min height met")]
     #[test_log::test(tokio::test)]
     async fn test_assert_allow_dkg_begin(
         dkg_rounds_current: u32,
         dkg_min_bitcoin_block_height: Option<u64>,
         dkg_target_rounds: u32,
         chain_tip_height: u64,
-        should_allow: bool,
+        refusal_reason: Option<model::DkgBeginRefusalReason>,
     ) {
         let chain_tip_height = chain_tip_height.into();
         let dkg_min_bitcoin_block_height = dkg_min_bitcoin_block_height.map(Into::into);
@@ -1825,9 +2540,27 @@ mod tests {
         let result = assert_allow_dkg_begin(&context, &bitcoin_chain_tip).await;
 
         // Assert the result
-        match should_allow {
-            true => assert!(result.is_ok()),
-            false => assert!(matches!(result, Err(Error::DkgHasAlreadyRun))),
+        match refusal_reason {
+            None => assert!(result.is_ok()),
+            Some(_) => assert!(matches!(result, Err(Error::DkgHasAlreadyRun))),
+        }
+
+        // Refusals are recorded for operators, along with the state that
+        // led to them.
+        let refusal = storage.get_latest_dkg_begin_refusal().await.unwrap();
+        assert_eq!(
+            refusal.as_ref().map(|refusal| refusal.reason),
+            refusal_reason
+        );
+        if let Some(refusal) = refusal {
+            assert_eq!(refusal.bitcoin_chain_tip, bitcoin_chain_tip.block_hash);
+            assert_eq!(refusal.bitcoin_chain_tip_height, chain_tip_height);
+            assert_eq!(refusal.dkg_shares_count, dkg_rounds_current);
+            assert_eq!(refusal.dkg_target_rounds, dkg_target_rounds);
+            assert_eq!(
+                refusal.dkg_min_bitcoin_block_height,
+                dkg_min_bitcoin_block_height
+            );
         }
     }
 
@@ -1868,19 +2601,16 @@ mod tests {
         prevent_dkg_on_changed_signer_set_info(&context, aggregate_key);
 
         // Create our signer instance.
-        let mut signer = TxSignerEventLoop {
-            context,
-            network: network.connect(),
-            signer_private_key: PrivateKey::new(&mut rand::rngs::OsRng),
-            context_window: 1,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            threshold: 1,
-            last_presign_block: None,
-            rng: rand::rngs::OsRng,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let mut signer = TxSignerEventLoop::builder(context, network.connect(), rand::rngs::OsRng)
+            .with_private_key(PrivateKey::new(&mut rand::rngs::OsRng))
+            .with_context_window(1)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_threshold(1)
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
 
         // Create a DkgBegin message to be handled by the signer.
         let msg = message::WstsMessage {
@@ -1937,19 +2667,16 @@ mod tests {
             .unwrap();
 
         // Create our signer instance.
-        let mut signer = TxSignerEventLoop {
-            context,
-            network: network.connect(),
-            signer_private_key: PrivateKey::new(&mut rand::rngs::OsRng),
-            context_window: 1,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            last_presign_block: None,
-            threshold: 1,
-            rng: rand::rngs::OsRng,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let mut signer = TxSignerEventLoop::builder(context, network.connect(), rand::rngs::OsRng)
+            .with_private_key(PrivateKey::new(&mut rand::rngs::OsRng))
+            .with_context_window(1)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_threshold(1)
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
 
         // Create a DkgBegin message to be handled by the signer.
         let msg = message::WstsMessage {
@@ -2024,19 +2751,16 @@ mod tests {
             .unwrap();
 
         // Create our signer instance.
-        let mut signer = TxSignerEventLoop {
-            context,
-            network: network.connect(),
-            signer_private_key: PrivateKey::new(&mut rand::rngs::OsRng),
-            context_window: 1,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            threshold: 1,
-            last_presign_block: None,
-            rng: rand::rngs::OsRng,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let mut signer = TxSignerEventLoop::builder(context, network.connect(), rand::rngs::OsRng)
+            .with_private_key(PrivateKey::new(&mut rand::rngs::OsRng))
+            .with_context_window(1)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_threshold(1)
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
 
         let msg = message::WstsMessage {
             id: Txid::all_zeros().into(),
@@ -2057,4 +2781,267 @@ mod tests {
             .await
             .expect("expected success");
     }
+
+    #[tokio::test]
+    async fn only_the_loser_of_the_coordinator_tie_break_stops_its_tenure() {
+        let mut rng = testing::get_rng();
+        let keys: Vec<PrivateKey> = (0..3).map(|_| PrivateKey::new(&mut rng)).collect();
+        let public_keys: Vec<PublicKey> = keys.iter().map(PublicKey::from_private_key).collect();
+
+        // The two signers disagree on the signing set, so that each of
+        // them is the coordinator for the chain tip in its own view.
+        let signer_sets = [
+            BTreeSet::from([public_keys[0], public_keys[1]]),
+            BTreeSet::from([public_keys[0], public_keys[1], public_keys[2]]),
+        ];
+        let chain_tip = loop {
+            let chain_tip: model::BitcoinBlockRef = Faker.fake_with_rng(&mut rng);
+            let is_coordinator = |index: usize| {
+                crate::transaction_coordinator::given_key_is_coordinator(
+                    public_keys[index],
+                    &chain_tip.block_hash,
+                    &signer_sets[index],
+                )
+            };
+            if is_coordinator(0) && is_coordinator(1) {
+                break chain_tip;
+            }
+        };
+
+        let network = InMemoryNetwork::new();
+        let mut stopped = Vec::new();
+        for index in 0..2 {
+            let other = 1 - index;
+            let signer_set = signer_sets[index].clone();
+            let private_key = keys[index];
+            let context = TestContext::builder()
+                .with_in_memory_storage()
+                .with_mocked_clients()
+                .modify_settings(|settings| {
+                    settings.signer.private_key = private_key;
+                    settings.signer.bootstrap_signing_set = signer_set;
+                })
+                .build();
+            context
+                .get_storage_mut()
+                .write_bitcoin_block(&model::BitcoinBlock {
+                    block_hash: chain_tip.block_hash,
+                    block_height: chain_tip.block_height,
+                    parent_hash: Faker.fake(),
+                })
+                .await
+                .unwrap();
+            context.state().set_bitcoin_chain_tip(chain_tip);
+
+            let mut signer =
+                TxSignerEventLoop::builder(context.clone(), network.connect(), rand::rngs::OsRng)
+                    .build()
+                    .unwrap();
+            let coordinator = crate::transaction_coordinator::TxCoordinatorEventLoop::builder(
+                context.clone(),
+                network.connect(),
+            )
+            .build()
+            .unwrap();
+            let tenure = crate::transaction_coordinator::TenureContext {
+                bitcoin_chain_tip: chain_tip,
+                stacks_chain_tip: None,
+                aggregate_key: Faker.fake(),
+                sbtc_limits: crate::context::SbtcLimits::unlimited(),
+                deadline: context.clock().now() + std::time::Duration::from_secs(60),
+                plan: None,
+                btc_state: None,
+            };
+
+            // Any signer may ask for deposit decisions again, so that does
+            // not make the sender a competing coordinator.
+            let retry_request: message::DepositDecisionRetryRequest = Faker.fake();
+            let msg = Payload::from(retry_request)
+                .to_message(chain_tip.block_hash)
+                .sign_ecdsa(&keys[other]);
+            signer.handle_signer_message(&msg).await.unwrap();
+            assert!(!coordinator.should_stop_tenure(&tenure));
+
+            let attestation = message::CoordinatorAttestation::default();
+            let msg = Payload::from(attestation)
+                .to_message(chain_tip.block_hash)
+                .sign_ecdsa(&keys[other]);
+            signer.handle_signer_message(&msg).await.unwrap();
+            if coordinator.should_stop_tenure(&tenure) {
+                stopped.push(public_keys[index]);
+            }
+        }
+
+        let winner = crate::transaction_coordinator::coordinator_tie_break_winner(
+            &chain_tip.block_hash,
+            public_keys[0],
+            public_keys[1],
+        );
+        let loser = if winner == public_keys[0] {
+            public_keys[1]
+        } else {
+            public_keys[0]
+        };
+        assert_eq!(stopped, [loser]);
+    }
+
+    fn queued_sign_request(coordinator: &PrivateKey) -> QueuedStacksSignRequest {
+        let request: StacksTransactionSignRequest = Faker.fake();
+        let chain_tip: model::BitcoinBlockRef = Faker.fake();
+        let msg = Payload::from(request.clone())
+            .to_message(chain_tip.block_hash)
+            .sign_ecdsa(coordinator);
+
+        QueuedStacksSignRequest { msg, request, chain_tip }
+    }
+
+    #[test]
+    fn stacks_sign_request_queue_takes_turns_between_coordinators() {
+        let mut rng = testing::get_rng();
+        let busy = PrivateKey::new(&mut rng);
+        let quiet = PrivateKey::new(&mut rng);
+
+        let mut queue = StacksSignRequestQueue::default();
+        for _ in 0..3 {
+            queue.push(queued_sign_request(&busy)).unwrap();
+        }
+        queue.push(queued_sign_request(&quiet)).unwrap();
+        assert_eq!(queue.len(), 4);
+
+        // The request from the quiet coordinator is taken right after the
+        // first request of the busy one.
+        let batch = queue.pop_batch(2);
+        let senders: Vec<PublicKey> = batch.iter().map(|q| q.msg.signer_public_key).collect();
+        let expected = [
+            PublicKey::from_private_key(&busy),
+            PublicKey::from_private_key(&quiet),
+        ];
+        assert_eq!(senders, expected);
+
+        assert_eq!(
+            queue.pop_batch(STACKS_SIGN_REQUEST_MAX_CONCURRENCY).len(),
+            2
+        );
+        assert!(queue.is_empty());
+        assert!(
+            queue
+                .pop_batch(STACKS_SIGN_REQUEST_MAX_CONCURRENCY)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn stacks_sign_request_queue_is_bounded_per_coordinator() {
+        let mut rng = testing::get_rng();
+        let busy = PrivateKey::new(&mut rng);
+        let quiet = PrivateKey::new(&mut rng);
+
+        let mut queue = StacksSignRequestQueue::default();
+        for _ in 0..STACKS_SIGN_REQUEST_QUEUE_SIZE {
+            queue.push(queued_sign_request(&busy)).unwrap();
+        }
+
+        let result = queue.push(queued_sign_request(&busy));
+        assert!(matches!(result, Err(Error::StacksSignRequestQueueFull(_))));
+
+        // Other coordinators are not affected by the full queue.
+        queue.push(queued_sign_request(&quiet)).unwrap();
+        assert_eq!(queue.len(), STACKS_SIGN_REQUEST_QUEUE_SIZE + 1);
+    }
+
+    #[test]
+    fn presign_retries_must_shrink_the_requests() {
+        let deposits: Vec<OutPoint> = (0..4)
+            .map(|vout| OutPoint::new(Txid::all_zeros(), vout))
+            .collect();
+        let previous = PresignRequests {
+            deposits: deposits[..3].iter().copied().collect(),
+            withdrawals: HashSet::new(),
+            retries: 0,
+        };
+
+        // The same requests again are not a retry.
+        assert!(!previous.is_retry_of(&previous));
+
+        let mut retry = previous.clone();
+        retry.deposits.remove(&deposits[0]);
+        assert!(retry.is_retry_of(&previous));
+
+        // A retry cannot add a request that was not there before.
+        let mut swapped = retry.clone();
+        swapped.deposits.insert(deposits[3]);
+        assert!(!swapped.is_retry_of(&previous));
+
+        // And the coordinator only gets so many retries.
+        let exhausted = PresignRequests {
+            retries: MAX_PRESIGN_RETRIES,
+            ..previous.clone()
+        };
+        assert!(!retry.is_retry_of(&exhausted));
+    }
+
+    #[tokio::test]
+    async fn conflicting_sighashes_are_not_signed() {
+        let context = TestContext::builder()
+            .with_in_memory_storage()
+            .with_mocked_clients()
+            .build();
+        let storage = context.get_storage_mut();
+
+        // Two different sighashes spending the same prevout at the same
+        // chain tip, and one spending it at another chain tip.
+        let first: model::BitcoinTxSigHash = Faker.fake();
+        let conflicting = model::BitcoinTxSigHash {
+            sighash: Faker.fake(),
+            ..first.clone()
+        };
+        let other_tip = model::BitcoinTxSigHash {
+            sighash: Faker.fake(),
+            chain_tip: Faker.fake(),
+            ..first.clone()
+        };
+        let sighashes = [first.clone(), conflicting.clone(), other_tip.clone()];
+        storage
+            .write_bitcoin_txs_sighashes(&sighashes)
+            .await
+            .unwrap();
+
+        let network = InMemoryNetwork::new();
+        let signer = TxSignerEventLoop::builder(context, network.connect(), rand::rngs::OsRng)
+            .with_private_key(PrivateKey::new(&mut rand::rngs::OsRng))
+            .with_context_window(1)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_threshold(1)
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
+
+        signer
+            .record_signed_bitcoin_sighash(&first.sighash)
+            .await
+            .unwrap();
+        // Signing the same sighash again is fine.
+        signer
+            .record_signed_bitcoin_sighash(&first.sighash)
+            .await
+            .unwrap();
+
+        let result = signer
+            .record_signed_bitcoin_sighash(&conflicting.sighash)
+            .await;
+        match result {
+            Err(Error::ConflictingSigHash { sighash, signed }) => {
+                assert_eq!(sighash, conflicting.sighash);
+                assert_eq!(signed, first.sighash);
+            }
+            _ => panic!("expected a conflicting sighash error"),
+        }
+
+        signer
+            .record_signed_bitcoin_sighash(&other_tip.sighash)
+            .await
+            .unwrap();
+    }
 }
@@ -0,0 +1,402 @@
+//! Transcripts of the signing rounds of each tenure.
+//!
+//! When a signing round fails, the operators of the signers need to work
+//! out which signer caused the failure. To help with that, each signer
+//! records a transcript of every tenure: the messages that it sent and
+//! received, keyed by signing round, the outcome of validating each
+//! message, and the transactions that it broadcast. A transcript can be
+//! exported as a [`SignedTranscript`], which is signed with the signer's
+//! private key so that it can be shared with the other operators. The
+//! recorded messages are the original signed messages, so the signatures
+//! of their senders can be checked too.
+
+use std::collections::VecDeque;
+use std::sync::RwLock;
+
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest as _;
+
+use crate::codec::Encode as _;
+use crate::error::Error;
+use crate::keys::PrivateKey;
+use crate::keys::PublicKey;
+use crate::message::Payload;
+use crate::message::RefusedSignRequest;
+use crate::network::Msg;
+use crate::storage::model::BitcoinBlockHash;
+
+/// The maximum number of tenures that transcripts are kept for. The
+/// transcripts of the oldest tenures are dropped first.
+pub const MAX_TENURE_TRANSCRIPTS: usize = 16;
+
+/// The maximum number of entries in the transcript of a single tenure.
+/// Entries beyond this limit are dropped and the transcript is marked as
+/// truncated.
+pub const MAX_TRANSCRIPT_ENTRIES: usize = 50_000;
+
+/// Something that happened during a tenure.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TranscriptEvent {
+    /// This signer sent a message.
+    MessageSent {
+        /// The type of the payload of the message.
+        payload_type: String,
+        /// The hex encoded signed message.
+        message: String,
+    },
+    /// This signer received a message from another signer.
+    MessageReceived {
+        /// The public key of the signer that sent the message.
+        sender: PublicKey,
+        /// The type of the payload of the message.
+        payload_type: String,
+        /// The hex encoded signed message.
+        message: String,
+    },
+    /// This signer validated and handled a message.
+    Validation {
+        /// The public key of the signer that sent the message.
+        sender: PublicKey,
+        /// The type of the payload of the message.
+        payload_type: String,
+        /// The reason that the message was rejected, if it was.
+        error: Option<String>,
+    },
+    /// This signer broadcast a bitcoin transaction.
+    BitcoinTransaction {
+        /// The ID of the transaction.
+        txid: String,
+    },
+    /// This signer submitted a stacks transaction.
+    StacksTransaction {
+        /// The ID of the transaction.
+        txid: String,
+    },
+}
+
+/// An entry in the transcript of a tenure.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TranscriptEntry {
+    /// The signing round that the entry belongs to.
+    pub round: String,
+    /// What happened.
+    #[serde(flatten)]
+    pub event: TranscriptEvent,
+}
+
+/// The transcript of a single tenure, as seen by one signer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TenureTranscript {
+    /// The bitcoin chain tip that identifies the tenure.
+    pub bitcoin_chain_tip: BitcoinBlockHash,
+    /// The entries of the transcript, in the order that they were
+    /// recorded.
+    pub entries: Vec<TranscriptEntry>,
+    /// Whether entries were dropped because the transcript reached
+    /// [`MAX_TRANSCRIPT_ENTRIES`].
+    pub truncated: bool,
+}
+
+impl TenureTranscript {
+    fn new(bitcoin_chain_tip: BitcoinBlockHash) -> Self {
+        Self {
+            bitcoin_chain_tip,
+            entries: Vec::new(),
+            truncated: false,
+        }
+    }
+
+    /// The SHA256 digest of the JSON encoding of the transcript.
+    fn digest(&self) -> Result<[u8; 32], Error> {
+        let bytes = serde_json::to_vec(self).map_err(Error::JsonSerialize)?;
+        Ok(sha2::Sha256::digest(bytes).into())
+    }
+}
+
+/// A tenure transcript signed by the signer that recorded it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedTranscript {
+    /// The transcript.
+    pub transcript: TenureTranscript,
+    /// The public key of the signer that recorded the transcript.
+    pub signer_public_key: PublicKey,
+    /// The hex encoded compact ECDSA signature over the SHA256 digest of
+    /// the JSON encoding of the transcript.
+    pub signature: String,
+}
+
+impl SignedTranscript {
+    /// Sign the given transcript with the given private key.
+    pub fn new(transcript: TenureTranscript, private_key: &PrivateKey) -> Result<Self, Error> {
+        let msg = secp256k1::Message::from_digest(transcript.digest()?);
+        let signature = private_key.sign_ecdsa(&msg).serialize_compact();
+
+        Ok(Self {
+            transcript,
+            signer_public_key: PublicKey::from_private_key(private_key),
+            signature: hex::encode(signature),
+        })
+    }
+
+    /// Verify that the signature over the transcript was created with the
+    /// public key in this struct.
+    pub fn verify(&self) -> Result<(), Error> {
+        let bytes = hex::decode(&self.signature).map_err(Error::DecodeHexBytes)?;
+        let signature = secp256k1::ecdsa::Signature::from_compact(&bytes)
+            .map_err(Error::InvalidEcdsaSignatureBytes)?;
+        let msg = secp256k1::Message::from_digest(self.transcript.digest()?);
+
+        signature
+            .verify(&msg, &self.signer_public_key)
+            .map_err(Error::InvalidEcdsaSignature)
+    }
+}
+
+/// The signing round that a message with the given payload belongs to.
+pub fn round_of(payload: &Payload) -> String {
+    match payload {
+        Payload::WstsMessage(msg) => msg.id.to_string(),
+        Payload::StacksTransactionSignRequest(request) => {
+            format!("stacks-sign({})", request.txid)
+        }
+        Payload::StacksTransactionSignature(signature) => {
+            format!("stacks-sign({})", signature.txid)
+        }
+        Payload::BitcoinPreSignRequest(_) | Payload::BitcoinPreSignAck(_) => {
+            "bitcoin-presign".to_string()
+        }
+        Payload::SignRequestRefusal(refusal) => match refusal.request {
+            RefusedSignRequest::BitcoinPreSign => "bitcoin-presign".to_string(),
+            RefusedSignRequest::StacksTransaction(txid) => format!("stacks-sign({txid})"),
+        },
+        payload => payload.type_name().to_string(),
+    }
+}
+
+/// Records the transcripts of the most recent tenures.
+#[derive(Debug, Default)]
+pub struct TranscriptRecorder {
+    transcripts: RwLock<VecDeque<TenureTranscript>>,
+}
+
+impl TranscriptRecorder {
+    fn record(&self, bitcoin_chain_tip: &BitcoinBlockHash, entry: TranscriptEntry) {
+        let mut transcripts = self
+            .transcripts
+            .write()
+            .expect("BUG: Failed to acquire write lock");
+
+        let position = transcripts
+            .iter()
+            .position(|transcript| &transcript.bitcoin_chain_tip == bitcoin_chain_tip);
+        let transcript = match position {
+            Some(index) => &mut transcripts[index],
+            None => {
+                if transcripts.len() >= MAX_TENURE_TRANSCRIPTS {
+                    transcripts.pop_front();
+                }
+                transcripts.push_back(TenureTranscript::new(*bitcoin_chain_tip));
+                transcripts
+                    .back_mut()
+                    .expect("BUG: transcript was just added")
+            }
+        };
+
+        if transcript.entries.len() >= MAX_TRANSCRIPT_ENTRIES {
+            transcript.truncated = true;
+        } else {
+            transcript.entries.push(entry);
+        }
+    }
+
+    /// Record a message that this signer sent.
+    pub fn record_message_sent(&self, msg: &Msg) {
+        let entry = TranscriptEntry {
+            round: round_of(&msg.payload),
+            event: TranscriptEvent::MessageSent {
+                payload_type: msg.payload.type_name().to_string(),
+                message: hex::encode(msg.clone().encode_to_vec()),
+            },
+        };
+        self.record(&msg.bitcoin_chain_tip, entry);
+    }
+
+    /// Record a message that this signer received from another signer.
+    pub fn record_message_received(&self, msg: &Msg) {
+        let entry = TranscriptEntry {
+            round: round_of(&msg.payload),
+            event: TranscriptEvent::MessageReceived {
+                sender: msg.signer_public_key,
+                payload_type: msg.payload.type_name().to_string(),
+                message: hex::encode(msg.clone().encode_to_vec()),
+            },
+        };
+        self.record(&msg.bitcoin_chain_tip, entry);
+    }
+
+    /// Record the outcome of validating and handling a message.
+    pub fn record_validation(&self, msg: &Msg, result: &Result<(), Error>) {
+        let entry = TranscriptEntry {
+            round: round_of(&msg.payload),
+            event: TranscriptEvent::Validation {
+                sender: msg.signer_public_key,
+                payload_type: msg.payload.type_name().to_string(),
+                error: result.as_ref().err().map(ToString::to_string),
+            },
+        };
+        self.record(&msg.bitcoin_chain_tip, entry);
+    }
+
+    /// Record a bitcoin transaction that this signer broadcast.
+    pub fn record_bitcoin_transaction(
+        &self,
+        bitcoin_chain_tip: &BitcoinBlockHash,
+        txid: bitcoin::Txid,
+    ) {
+        let entry = TranscriptEntry {
+            round: format!("sweep({txid})"),
+            event: TranscriptEvent::BitcoinTransaction { txid: txid.to_string() },
+        };
+        self.record(bitcoin_chain_tip, entry);
+    }
+
+    /// Record a stacks transaction that this signer submitted.
+    pub fn record_stacks_transaction(
+        &self,
+        bitcoin_chain_tip: &BitcoinBlockHash,
+        txid: blockstack_lib::burnchains::Txid,
+    ) {
+        let entry = TranscriptEntry {
+            round: format!("stacks-sign({txid})"),
+            event: TranscriptEvent::StacksTransaction { txid: txid.to_string() },
+        };
+        self.record(bitcoin_chain_tip, entry);
+    }
+
+    /// The bitcoin chain tips of the tenures with a transcript, from the
+    /// oldest to the most recent.
+    pub fn tenures(&self) -> Vec<BitcoinBlockHash> {
+        let transcripts = self
+            .transcripts
+            .read()
+            .expect("BUG: Failed to acquire read lock");
+        transcripts
+            .iter()
+            .map(|transcript| transcript.bitcoin_chain_tip)
+            .collect()
+    }
+
+    /// Return the transcript of the tenure with the given bitcoin chain
+    /// tip, if there is one.
+    #[allow(clippy::unwrap_in_result)]
+    pub fn transcript(&self, bitcoin_chain_tip: &BitcoinBlockHash) -> Option<TenureTranscript> {
+        let transcripts = self
+            .transcripts
+            .read()
+            .expect("BUG: Failed to acquire read lock of transcripts");
+        transcripts
+            .iter()
+            .find(|transcript| &transcript.bitcoin_chain_tip == bitcoin_chain_tip)
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+    use fake::Faker;
+    use rand::rngs::OsRng;
+
+    use crate::ecdsa::SignEcdsa as _;
+    use crate::message::SignerMessage;
+    use crate::message::WstsMessage;
+    use crate::storage::model::BitcoinTxId;
+
+    use super::*;
+
+    #[test]
+    fn transcripts_are_kept_per_tenure() {
+        let recorder = TranscriptRecorder::default();
+        let private_key = PrivateKey::new(&mut OsRng);
+
+        let msg1 = SignerMessage::random_with_payload_type::<WstsMessage, _>(&mut OsRng)
+            .sign_ecdsa(&private_key);
+        let msg2 = SignerMessage::random_with_payload_type::<WstsMessage, _>(&mut OsRng)
+            .sign_ecdsa(&private_key);
+
+        recorder.record_message_received(&msg1);
+        recorder.record_validation(&msg1, &Ok(()));
+        recorder.record_message_sent(&msg2);
+        let txid: BitcoinTxId = Faker.fake_with_rng(&mut OsRng);
+        recorder.record_bitcoin_transaction(&msg2.bitcoin_chain_tip, txid.into());
+
+        assert_eq!(
+            recorder.tenures(),
+            vec![msg1.bitcoin_chain_tip, msg2.bitcoin_chain_tip]
+        );
+
+        let transcript = recorder.transcript(&msg1.bitcoin_chain_tip).unwrap();
+        assert_eq!(transcript.entries.len(), 2);
+        assert!(
+            transcript
+                .entries
+                .iter()
+                .all(|entry| entry.round == round_of(&msg1.payload))
+        );
+
+        let transcript = recorder.transcript(&msg2.bitcoin_chain_tip).unwrap();
+        assert_eq!(transcript.entries.len(), 2);
+        assert_eq!(
+            transcript.entries[1].event,
+            TranscriptEvent::BitcoinTransaction { txid: txid.to_string() }
+        );
+
+        // The recorded messages are the original signed messages.
+        let TranscriptEvent::MessageSent { message, .. } = &transcript.entries[0].event else {
+            panic!("expected a sent message");
+        };
+        let (decoded, digest) = Msg::decode_with_digest(&hex::decode(message).unwrap()).unwrap();
+        decoded.verify_digest(digest).unwrap();
+        assert_eq!(decoded, msg2);
+    }
+
+    #[test]
+    fn only_recent_tenures_are_kept() {
+        let recorder = TranscriptRecorder::default();
+        let chain_tips: Vec<BitcoinBlockHash> = (0..MAX_TENURE_TRANSCRIPTS + 2)
+            .map(|_| Faker.fake_with_rng(&mut OsRng))
+            .collect();
+
+        for chain_tip in &chain_tips {
+            let txid: BitcoinTxId = Faker.fake_with_rng(&mut OsRng);
+            recorder.record_bitcoin_transaction(chain_tip, txid.into());
+        }
+
+        assert_eq!(recorder.tenures(), chain_tips[2..]);
+        assert!(recorder.transcript(&chain_tips[0]).is_none());
+    }
+
+    #[test]
+    fn signed_transcripts_can_be_verified() {
+        let recorder = TranscriptRecorder::default();
+        let private_key = PrivateKey::new(&mut OsRng);
+        let msg = SignerMessage::random_with_payload_type::<WstsMessage, _>(&mut OsRng)
+            .sign_ecdsa(&private_key);
+        recorder.record_message_sent(&msg);
+
+        let transcript = recorder.transcript(&msg.bitcoin_chain_tip).unwrap();
+        let signed = SignedTranscript::new(transcript, &private_key).unwrap();
+        signed.verify().unwrap();
+
+        // The signature survives a round trip through JSON.
+        let json = serde_json::to_string(&signed).unwrap();
+        let decoded: SignedTranscript = serde_json::from_str(&json).unwrap();
+        decoded.verify().unwrap();
+
+        let mut tampered = decoded;
+        tampered.transcript.truncated = true;
+        assert!(tampered.verify().is_err());
+    }
+}
@@ -0,0 +1,247 @@
+//! A watchdog for the bitcoin chain tip of the signer.
+//!
+//! The block observer learns about new bitcoin blocks through the ZeroMQ
+//! interface of bitcoin-core. If that stream stalls, say because the
+//! subscription was silently dropped, the signer keeps working off of an
+//! old chain tip without noticing. To catch this, the watchdog
+//! periodically compares the chain tip of the signer with the one of
+//! bitcoin-core, and keeps track of how long it has been since the chain
+//! tip of the signer last changed.
+//!
+//! When the chain tip looks unhealthy, the watchdog first asks the block
+//! observer to resync with the chain tip of bitcoin-core. Only if the
+//! chain tip is still unhealthy at the next check does it signal a
+//! [`SignerEvent::ChainTipUnhealthy`] event, which the alert dispatcher
+//! turns into an alert.
+
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::time::Instant;
+
+use crate::bitcoin::BitcoinInteract as _;
+use crate::context::Context;
+use crate::context::SignerCommand;
+use crate::context::SignerEvent;
+use crate::error::Error;
+use crate::metrics::Metrics;
+use crate::storage::model::BitcoinBlockHeight;
+use crate::storage::model::BitcoinBlockRef;
+
+/// How often the watchdog checks the chain tip of the signer.
+pub const CHAIN_TIP_WATCHDOG_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The health of the bitcoin chain tip of the signer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ChainTipHealth {
+    /// The chain tip of the signer is up to date.
+    #[default]
+    Healthy,
+    /// The chain tip of the signer has not changed for longer than the
+    /// configured stall timeout.
+    Stalled {
+        /// The number of seconds since the chain tip last changed.
+        seconds_since_last_block: u64,
+    },
+    /// The chain tip of the signer is more than the configured number of
+    /// blocks behind the chain tip of bitcoin-core.
+    Lagging {
+        /// The height of the chain tip of the signer.
+        signer_height: BitcoinBlockHeight,
+        /// The height of the chain tip of bitcoin-core.
+        node_height: BitcoinBlockHeight,
+    },
+}
+
+impl ChainTipHealth {
+    /// Assess the health of the chain tip of the signer.
+    ///
+    /// A signer without a chain tip is treated as being at height zero,
+    /// so it is lagging as soon as bitcoin-core is far enough along.
+    pub fn assess(
+        signer_tip: Option<&BitcoinBlockRef>,
+        node_height: BitcoinBlockHeight,
+        since_last_block: Duration,
+        stall_timeout: Duration,
+        max_lag: u64,
+    ) -> Self {
+        let signer_height =
+            signer_tip.map_or(BitcoinBlockHeight::from(0u64), |tip| tip.block_height);
+        if node_height.blocks_since(signer_height) > max_lag {
+            return ChainTipHealth::Lagging { signer_height, node_height };
+        }
+        if since_last_block > stall_timeout {
+            return ChainTipHealth::Stalled {
+                seconds_since_last_block: since_last_block.as_secs(),
+            };
+        }
+        ChainTipHealth::Healthy
+    }
+
+    /// Whether the chain tip is healthy.
+    pub fn is_healthy(&self) -> bool {
+        matches!(self, ChainTipHealth::Healthy)
+    }
+}
+
+/// Periodically check the health of the bitcoin chain tip of the signer
+/// until shutdown.
+///
+/// Failures to reach bitcoin-core are logged and the task carries on.
+pub async fn run_chain_tip_watchdog(ctx: impl Context) -> Result<(), Error> {
+    let mut watchdog = ChainTipWatchdog::new(ctx.clock().now());
+    let mut term = ctx.get_termination_handle();
+    loop {
+        tokio::select! {
+            _ = term.wait_for_shutdown() => return Ok(()),
+            _ = ctx.clock().sleep(CHAIN_TIP_WATCHDOG_INTERVAL) => {}
+        }
+
+        if let Err(error) = watchdog.check(&ctx).await {
+            tracing::warn!(%error, "could not check the health of the bitcoin chain tip");
+        }
+    }
+}
+
+/// The state of the watchdog between checks.
+#[derive(Debug)]
+pub struct ChainTipWatchdog {
+    /// The chain tip of the signer at the last check.
+    last_tip: Option<BitcoinBlockRef>,
+    /// When the chain tip of the signer last changed.
+    last_change: Instant,
+    /// Whether we asked the block observer to resync since the chain tip
+    /// became unhealthy.
+    resync_requested: bool,
+    /// Whether we signalled that the chain tip is unhealthy since it
+    /// became unhealthy.
+    alerted: bool,
+}
+
+impl ChainTipWatchdog {
+    /// Create a new watchdog, starting the clock on the chain tip at the
+    /// given instant.
+    pub fn new(now: Instant) -> Self {
+        Self {
+            last_tip: None,
+            last_change: now,
+            resync_requested: false,
+            alerted: false,
+        }
+    }
+
+    /// Check the health of the chain tip of the signer, resyncing or
+    /// signalling as necessary, and return the assessment.
+    pub async fn check(&mut self, ctx: &impl Context) -> Result<ChainTipHealth, Error> {
+        let now = ctx.clock().now();
+        let signer_tip = ctx.state().bitcoin_chain_tip();
+        if signer_tip != self.last_tip {
+            self.last_tip = signer_tip;
+            self.last_change = now;
+        }
+
+        let node_info = ctx.get_bitcoin_client().get_blockchain_info().await?;
+        let node_height = BitcoinBlockHeight::from(node_info.blocks);
+        let since_last_block = now.saturating_duration_since(self.last_change);
+
+        let config = &ctx.config().signer;
+        let health = ChainTipHealth::assess(
+            signer_tip.as_ref(),
+            node_height,
+            since_last_block,
+            config.chain_tip_stall_timeout,
+            config.chain_tip_max_lag.get() as u64,
+        );
+
+        let signer_height = signer_tip.map_or(0, |tip| *tip.block_height);
+        metrics::gauge!(Metrics::BitcoinChainTipLagBlocks)
+            .set(node_info.blocks.saturating_sub(signer_height) as f64);
+        metrics::gauge!(Metrics::BitcoinChainTipAgeSeconds).set(since_last_block.as_secs_f64());
+        ctx.state().set_chain_tip_health(health);
+
+        if health.is_healthy() {
+            self.resync_requested = false;
+            self.alerted = false;
+            return Ok(health);
+        }
+
+        if !self.resync_requested {
+            tracing::warn!(
+                ?health,
+                node_tip = %node_info.best_block_hash,
+                "bitcoin chain tip is unhealthy; resyncing with bitcoin-core"
+            );
+            metrics::counter!(Metrics::BitcoinChainTipResyncsTotal).increment(1);
+            ctx.signal(SignerCommand::ResyncBitcoinBlocks(node_info.best_block_hash).into())?;
+            self.resync_requested = true;
+        } else if !self.alerted {
+            tracing::error!(
+                ?health,
+                "bitcoin chain tip is still unhealthy after resyncing"
+            );
+            ctx.signal(SignerEvent::ChainTipUnhealthy(health).into())?;
+            self.alerted = true;
+        }
+
+        Ok(health)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake as _;
+    use fake::Faker;
+
+    use super::*;
+
+    const STALL_TIMEOUT: Duration = Duration::from_secs(1800);
+
+    fn block_ref(height: u64) -> BitcoinBlockRef {
+        BitcoinBlockRef {
+            block_height: height.into(),
+            block_hash: Faker.fake(),
+        }
+    }
+
+    #[test_case::test_case(Some(100), 100, 0, ChainTipHealth::Healthy; "up to date")]
+    #[test_case::test_case(Some(100), 103, 0, ChainTipHealth::Healthy; "lag within limit")]
+    #[test_case::test_case(Some(100), 104, 0, ChainTipHealth::Lagging {
+        signer_height: 100u64.into(),
+        node_height: 104u64.into(),
+    }; "lag above limit")]
+    #[test_case::test_case(None, 4, 0, ChainTipHealth::Lagging {
+        signer_height: 0u64.into(),
+        node_height: 4u64.into(),
+    }; "no signer chain tip")]
+    #[test_case::test_case(Some(100), 100, 1801, ChainTipHealth::Stalled {
+        seconds_since_last_block: 1801,
+    }; "stalled")]
+    fn chain_tip_health_assessment(
+        signer_height: Option<u64>,
+        node_height: u64,
+        since_last_block: u64,
+        expected: ChainTipHealth,
+    ) {
+        let signer_tip = signer_height.map(block_ref);
+        let health = ChainTipHealth::assess(
+            signer_tip.as_ref(),
+            node_height.into(),
+            Duration::from_secs(since_last_block),
+            STALL_TIMEOUT,
+            3,
+        );
+        assert_eq!(health, expected);
+    }
+
+    #[test]
+    fn chain_tip_health_serializes_with_status() {
+        let json = serde_json::to_value(ChainTipHealth::Healthy).unwrap();
+        assert_eq!(json["status"], "healthy");
+
+        let health = ChainTipHealth::Stalled { seconds_since_last_block: 60 };
+        let json = serde_json::to_value(health).unwrap();
+        assert_eq!(json["status"], "stalled");
+        assert_eq!(json["seconds_since_last_block"], 60);
+    }
+}
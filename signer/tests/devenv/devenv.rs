@@ -103,7 +103,7 @@ async fn process_blocks_simple_fork() {
     ctx.with_emily_client(|client| {
         client
             .expect_get_deposits()
-            .returning(move || Box::pin(std::future::ready(Ok(vec![]))));
+            .returning(|| Box::pin(futures::stream::empty()));
 
         client
             .expect_get_limits()
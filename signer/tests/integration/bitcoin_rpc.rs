@@ -524,3 +524,67 @@ async fn get_tx_out_unconfirmed_with_mempool() {
     assert_eq!(txout.value, Amount::from_sat(10_000));
     assert_eq!(txout.confirmations, 0); // Unconfirmed txs will have 0 confirmations
 }
+
+#[tokio::test]
+async fn broadcast_package_accepts_chained_transactions() {
+    let client = BitcoinCoreClient::new(
+        "http://localhost:18443",
+        regtest::BITCOIN_CORE_RPC_USERNAME.to_string(),
+        regtest::BITCOIN_CORE_RPC_PASSWORD.to_string(),
+    )
+    .unwrap();
+
+    let (rpc, faucet) = regtest::initialize_blockchain();
+    let addr = Recipient::new(AddressType::P2wpkh);
+
+    faucet.send_to(10_000, &addr.address);
+    faucet.generate_blocks(1);
+    let mut utxo = addr.get_utxos(rpc, Some(10_000)).pop().unwrap();
+
+    // Create a chain of three transactions, where each one spends the
+    // output of the one before it.
+    let mut txs = Vec::new();
+    for _ in 0..3 {
+        let amount = utxo.amount - Amount::from_sat(1_000);
+        let mut tx = bitcoin::Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![bitcoin::TxIn {
+                previous_output: utxo.outpoint(),
+                script_sig: ScriptBuf::new(),
+                sequence: Sequence::ZERO,
+                witness: Witness::new(),
+            }],
+            output: vec![bitcoin::TxOut {
+                value: amount,
+                script_pubkey: addr.address.script_pubkey(),
+            }],
+        };
+        p2wpkh_sign_transaction(&mut tx, 0, &utxo, &addr.keypair);
+
+        utxo = Utxo {
+            amount,
+            script_pub_key: addr.address.script_pubkey(),
+            txid: tx.compute_txid(),
+            vout: 0,
+            height: 0,
+            descriptor: "".into(),
+        };
+        txs.push(tx);
+    }
+
+    client.broadcast_package(&txs).await.unwrap();
+
+    let response = client
+        .get_mempool_descendants(&txs[0].compute_txid())
+        .unwrap();
+    assert_eq!(response.len(), 2);
+    assert!(response.contains(&txs[1].compute_txid()));
+    assert!(response.contains(&txs[2].compute_txid()));
+
+    // Broadcasting the package again is fine, since bitcoin-core skips
+    // transactions that are already in its mempool.
+    if client.supports_package_relay().unwrap() {
+        client.broadcast_package(&txs).await.unwrap();
+    }
+}
@@ -8,8 +8,10 @@ use test_case::test_case;
 
 use sbtc::testing::regtest;
 use signer::WITHDRAWAL_MIN_CONFIRMATIONS;
+use signer::bitcoin::units::FeeRate;
 use signer::bitcoin::utxo::SbtcRequests;
 use signer::bitcoin::utxo::SignerBtcState;
+use signer::bitcoin::utxo::WithdrawalFeeSubsidy;
 use signer::bitcoin::validation::BitcoinTxContext;
 use signer::bitcoin::validation::BitcoinTxValidationData;
 use signer::bitcoin::validation::InputValidationResult;
@@ -49,10 +51,11 @@ where
         .unwrap();
     SignerBtcState {
         utxo: signer_utxo,
-        fee_rate: request.fee_rate,
+        fee_rate: FeeRate::new(request.fee_rate).unwrap(),
         public_key: btc_ctx.aggregate_key.into(),
         last_fees: request.last_fees,
         magic_bytes: [b'T', b'3'],
+        metadata_chain_tip: None,
     }
 }
 
@@ -135,6 +138,10 @@ async fn one_tx_per_request_set() {
         }],
         fee_rate: TEST_FEE_RATE,
         last_fees: None,
+        deposit_certificates: setup.deposit_certificates(),
+        refunds: Vec::new(),
+        sweep_template_version: None,
+        emergency_recovery: false,
     };
 
     let btc_ctx = BitcoinTxContext {
@@ -240,6 +247,10 @@ async fn one_invalid_deposit_invalidates_tx() {
         }],
         fee_rate: TEST_FEE_RATE,
         last_fees: None,
+        deposit_certificates: setup.deposit_certificates(),
+        refunds: Vec::new(),
+        sweep_template_version: None,
+        emergency_recovery: false,
     };
 
     let btc_ctx = BitcoinTxContext {
@@ -406,6 +417,10 @@ async fn withdrawals_and_deposits_can_pass_validation(amounts: Vec<SweepAmounts>
         }],
         fee_rate: TEST_FEE_RATE,
         last_fees: None,
+        deposit_certificates: setup.deposit_certificates(),
+        refunds: Vec::new(),
+        sweep_template_version: None,
+        emergency_recovery: false,
     };
 
     let btc_ctx = BitcoinTxContext {
@@ -512,6 +527,10 @@ async fn swept_withdrawals_fail_validation() {
         }],
         fee_rate: TEST_FEE_RATE,
         last_fees: None,
+        deposit_certificates: setup.deposit_certificates(),
+        refunds: Vec::new(),
+        sweep_template_version: None,
+        emergency_recovery: false,
     };
 
     let btc_ctx = BitcoinTxContext {
@@ -634,6 +653,10 @@ async fn cannot_sign_deposit_is_ok() {
         }],
         fee_rate: TEST_FEE_RATE,
         last_fees: None,
+        deposit_certificates: setup.deposit_certificates(),
+        refunds: Vec::new(),
+        sweep_template_version: None,
+        emergency_recovery: false,
     };
 
     let btc_ctx = BitcoinTxContext {
@@ -706,6 +729,7 @@ async fn cannot_sign_deposit_is_ok() {
         num_signers: 3,
         sbtc_limits: SbtcLimits::unlimited(),
         max_deposits_per_bitcoin_tx: ctx.config().signer.max_deposits_per_bitcoin_tx.get(),
+        withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
     };
     let txs = sbtc_requests.construct_transactions().unwrap();
     assert_eq!(txs.len(), 1);
@@ -772,6 +796,10 @@ async fn sighashes_match_from_sbtc_requests_object() {
         }],
         fee_rate: TEST_FEE_RATE,
         last_fees: None,
+        deposit_certificates: setup.deposit_certificates(),
+        refunds: Vec::new(),
+        sweep_template_version: None,
+        emergency_recovery: false,
     };
 
     let btc_ctx = BitcoinTxContext {
@@ -840,6 +868,7 @@ async fn sighashes_match_from_sbtc_requests_object() {
         num_signers: 3,
         sbtc_limits: SbtcLimits::unlimited(),
         max_deposits_per_bitcoin_tx: ctx.config().signer.max_deposits_per_bitcoin_tx.get(),
+        withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
     };
     let txs = sbtc_requests.construct_transactions().unwrap();
     assert_eq!(txs.len(), 1);
@@ -916,6 +945,10 @@ async fn outcome_is_independent_of_input_order() {
         }],
         fee_rate: TEST_FEE_RATE,
         last_fees: None,
+        deposit_certificates: setup.deposit_certificates(),
+        refunds: Vec::new(),
+        sweep_template_version: None,
+        emergency_recovery: false,
     };
 
     let btc_ctx = BitcoinTxContext {
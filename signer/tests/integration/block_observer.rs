@@ -27,10 +27,12 @@ use sbtc::deposits::ReclaimScriptInputs;
 use sbtc::testing::regtest;
 use sbtc::testing::regtest::Faucet;
 use sbtc::testing::regtest::Recipient;
+use signer::bitcoin::units::FeeRate;
 use signer::bitcoin::utxo::DepositRequest;
 use signer::bitcoin::utxo::SbtcRequests;
 use signer::bitcoin::utxo::SignerBtcState;
 use signer::bitcoin::utxo::SignerUtxo;
+use signer::bitcoin::utxo::WithdrawalFeeSubsidy;
 use signer::block_observer::get_signer_set_info;
 use signer::context::SbtcLimits;
 use signer::emily_client::EmilyClient;
@@ -113,10 +115,10 @@ async fn load_latest_deposit_requests_persists_requests_from_past(blocks_ago: u6
             setup0.emily_deposit_request(),
             setup1.emily_deposit_request(),
         ];
-        client
-            .expect_get_deposits()
-            .times(1..)
-            .returning(move || Box::pin(std::future::ready(Ok(emily_client_response.clone()))));
+        client.expect_get_deposits().times(1..).returning(move || {
+            let requests = emily_client_response.clone().into_iter().map(Ok);
+            Box::pin(futures::stream::iter(requests))
+        });
 
         client
             .expect_get_limits()
@@ -265,7 +267,7 @@ async fn link_blocks() {
     ctx.with_emily_client(|client| {
         client
             .expect_get_deposits()
-            .returning(move || Box::pin(std::future::ready(Ok(vec![]))));
+            .returning(|| Box::pin(futures::stream::empty()));
     })
     .await;
 
@@ -560,15 +562,17 @@ async fn block_observer_stores_donation_and_sbtc_utxos() {
         withdrawals: Vec::new(),
         signer_state: SignerBtcState {
             utxo: db.get_signer_utxo(&chain_tip).await.unwrap().unwrap(),
-            fee_rate: 10.0,
+            fee_rate: FeeRate::new(10.0).unwrap(),
             public_key: signers_public_key,
             last_fees: None,
             magic_bytes: [b'T', b'3'],
+            metadata_chain_tip: None,
         },
         accept_threshold: 4,
         num_signers: 7,
         sbtc_limits: SbtcLimits::unlimited(),
         max_deposits_per_bitcoin_tx: ctx.config().signer.max_deposits_per_bitcoin_tx.get(),
+        withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
     };
 
     let mut transactions = requests.construct_transactions().unwrap();
@@ -730,17 +734,19 @@ async fn block_observer_picks_up_chained_unordered_sweeps() {
                 amount: signers_amount,
                 public_key: signers_public_key1,
             },
-            fee_rate: 2.0,
+            fee_rate: FeeRate::new(2.0).unwrap(),
             // This ensures that the new signer UTXO is locked by the new
             // aggregate key.
             public_key: signers_public_key2,
             last_fees: None,
             magic_bytes: [b'T', b'3'],
+            metadata_chain_tip: None,
         },
         accept_threshold: 2,
         num_signers: 3,
         sbtc_limits: SbtcLimits::unlimited(),
         max_deposits_per_bitcoin_tx: 25,
+        withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
     };
 
     // By playing around with the votes above, we set things up so that we
@@ -900,7 +906,7 @@ async fn block_observer_handles_update_limits(deployed: bool, sbtc_limits: SbtcL
     ctx.with_emily_client(|client| {
         client
             .expect_get_deposits()
-            .returning(move || Box::pin(std::future::ready(Ok(vec![]))));
+            .returning(|| Box::pin(futures::stream::empty()));
     })
     .await;
 
@@ -1210,7 +1216,7 @@ async fn block_observer_updates_state_after_observing_bitcoin_block() {
     ctx.with_emily_client(|client| {
         client
             .expect_get_deposits()
-            .returning(|| Box::pin(std::future::ready(Ok(vec![]))));
+            .returning(|| Box::pin(futures::stream::empty()));
 
         client
             .expect_get_limits()
@@ -1373,7 +1379,7 @@ async fn block_observer_updates_dkg_shares_after_observing_bitcoin_block() {
     ctx.with_emily_client(|client| {
         client
             .expect_get_deposits()
-            .returning(|| Box::pin(std::future::ready(Ok(vec![]))));
+            .returning(|| Box::pin(futures::stream::empty()));
 
         client
             .expect_get_limits()
@@ -1,12 +1,14 @@
 use blockstack_lib::types::chainstate::StacksAddress;
 use rand::rngs::OsRng;
 
+use sbtc::deposits::DepositScriptInputs;
 use sbtc::testing::regtest;
 use signer::error::Error;
 use signer::stacks::contracts::AsContractCall as _;
 use signer::stacks::contracts::CompleteDepositV1;
 use signer::stacks::contracts::DepositErrorMsg;
 use signer::stacks::contracts::ReqContext;
+use signer::storage::DbRead as _;
 use signer::storage::model::BitcoinBlockRef;
 use signer::storage::model::BitcoinTxId;
 use signer::storage::model::StacksPrincipal;
@@ -474,6 +476,20 @@ async fn complete_deposit_validation_recipient_mismatch() {
         err => panic!("unexpected error during validation {err}"),
     }
 
+    // The mismatch should have been recorded in the database.
+    let outpoint = setup.deposit_request.outpoint;
+    let mismatches = db
+        .get_mint_mismatches(&outpoint.txid.into(), outpoint.vout)
+        .await
+        .unwrap();
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(*mismatches[0].recipient, complete_deposit_tx.recipient);
+    let script_inputs = DepositScriptInputs::parse(&setup.deposit_request.deposit_script).unwrap();
+    assert_eq!(
+        mismatches[0].expected_recipient.as_deref(),
+        Some(&script_inputs.recipient)
+    );
+
     testing::storage::drop_db(db).await;
 }
 
@@ -866,6 +882,60 @@ async fn complete_deposit_validation_sweep_reorged() {
     testing::storage::drop_db(db).await;
 }
 
+/// For this test we check that the `CompleteDepositV1::validate` function
+/// returns a deposit validation error with a SweepTransactionTooShallow
+/// message when the sweep transaction is on the canonical bitcoin
+/// blockchain but does not have `sweep_confirmation_depth` confirmations.
+#[tokio::test]
+async fn complete_deposit_validation_sweep_too_shallow() {
+    // Normal: this generates the blockchain as well as deposit request
+    // transactions and a transaction sweeping in the deposited funds.
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+    let (rpc, faucet) = regtest::initialize_blockchain();
+    let setup = TestSweepSetup::new_setup(rpc, faucet, 1_000_000, &mut rng);
+
+    // Normal: we set up the database the same way as in the happy path.
+    backfill_bitcoin_blocks(&db, rpc, &setup.sweep_block_hash).await;
+    setup.store_stacks_genesis_block(&db).await;
+    setup.store_deposit_tx(&db).await;
+    setup.store_sweep_tx(&db).await;
+    setup.store_dkg_shares(&db).await;
+    setup.store_deposit_request(&db).await;
+    setup.store_deposit_decisions(&db).await;
+
+    // Normal: create a properly formed complete-deposit transaction object
+    // and the corresponding request context. The chain tip in the request
+    // context is the block with the sweep transaction, so the sweep has
+    // exactly one confirmation.
+    let (complete_deposit_tx, req_ctx) = make_complete_deposit(&setup);
+
+    // Different: we require the sweep transaction to have two
+    // confirmations before we mint.
+    let mut ctx = TestContext::builder()
+        .with_storage(db.clone())
+        .with_first_bitcoin_core_client()
+        .with_mocked_stacks_client()
+        .with_mocked_emily_client()
+        .modify_settings(|settings| {
+            settings.signer.sweep_confirmation_depth = std::num::NonZeroU16::new(2).unwrap()
+        })
+        .build();
+
+    // Normal: the request is not completed in the smart contract.
+    set_deposit_incomplete(&mut ctx).await;
+
+    let validation_result = complete_deposit_tx.validate(&ctx, &req_ctx).await;
+    match validation_result.unwrap_err() {
+        Error::DepositValidation(ref err) => {
+            assert_eq!(err.error, DepositErrorMsg::SweepTransactionTooShallow)
+        }
+        err => panic!("unexpected error during validation {err}"),
+    }
+
+    testing::storage::drop_db(db).await;
+}
+
 /// For this test we check that the `CompleteDepositV1::validate` function
 /// returns a deposit validation error with a MissingFromSweep
 /// message when the sweep transaction is in our records, is on what the
@@ -1153,3 +1223,234 @@ async fn complete_deposit_validation_request_completed() {
 
     testing::storage::drop_db(db).await;
 }
+
+/// For this test we check that the `CompleteDepositV1::validate` function
+/// returns a deposit validation error with an AmountExceedsDeposit message
+/// when the amount to mint is greater than the amount of the deposit
+/// request that was swept in.
+#[tokio::test]
+async fn complete_deposit_validation_amount_exceeds_deposit() {
+    // Normal: this generates the blockchain as well as deposit request
+    // transactions and a transaction sweeping in the deposited funds.
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+    let (rpc, faucet) = regtest::initialize_blockchain();
+    let setup = TestSweepSetup::new_setup(rpc, faucet, 1_000_000, &mut rng);
+
+    // Normal: the signers' block observer should be getting new block
+    // events from bitcoin-core. We haven't hooked up our block observer,
+    // so we need to manually update the database with new bitcoin block
+    // headers and at least one stacks block.
+    backfill_bitcoin_blocks(&db, rpc, &setup.sweep_block_hash).await;
+    // Normal: This stores a genesis stacks block anchored to the bitcoin
+    // blockchain identified by setup.sweep_block_hash.
+    setup.store_stacks_genesis_block(&db).await;
+
+    // Normal: we take the deposit transaction as is from the test setup
+    // and store it in the database. This is necessary for when we fetch
+    // outstanding unfulfilled deposit requests.
+    setup.store_deposit_tx(&db).await;
+
+    // Normal: we take the sweep transaction as is from the test setup and
+    // store it in the database.
+    setup.store_sweep_tx(&db).await;
+
+    // Normal: we need to store a row in the dkg_shares table so that we
+    // have a record of the scriptPubKey that the signers control.
+    setup.store_dkg_shares(&db).await;
+
+    // Normal: the request and how the signers voted needs to be added to
+    // the database. Here the bitmap in the deposit request object
+    // corresponds to how the signers voted.
+    setup.store_deposit_request(&db).await;
+    setup.store_deposit_decisions(&db).await;
+
+    // Normal: create a properly formed complete-deposit transaction object
+    // and the corresponding request context.
+    let (mut complete_deposit_tx, req_ctx) = make_complete_deposit(&setup);
+    // Different: the amount to mint is more than what was deposited.
+    complete_deposit_tx.amount = setup.deposit_request.amount + 1;
+
+    let mut ctx = TestContext::builder()
+        .with_storage(db.clone())
+        .with_first_bitcoin_core_client()
+        .with_mocked_stacks_client()
+        .with_mocked_emily_client()
+        .build();
+
+    // Normal: the request is not completed in the smart contract.
+    set_deposit_incomplete(&mut ctx).await;
+
+    let validation_result = complete_deposit_tx.validate(&ctx, &req_ctx).await;
+    match validation_result.unwrap_err() {
+        Error::DepositValidation(ref err) => {
+            assert_eq!(err.error, DepositErrorMsg::AmountExceedsDeposit)
+        }
+        err => panic!("unexpected error during validation {err}"),
+    }
+
+    testing::storage::drop_db(db).await;
+}
+
+/// For this test we check that the `CompleteDepositV1::validate` function
+/// returns a deposit validation error with an AmountAboveMaxMint message
+/// when the amount to mint is above the `max_mint_per_call` limit in the
+/// signer's configuration.
+#[tokio::test]
+async fn complete_deposit_validation_amount_above_max_mint() {
+    // Normal: this generates the blockchain as well as deposit request
+    // transactions and a transaction sweeping in the deposited funds.
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+    let (rpc, faucet) = regtest::initialize_blockchain();
+    let setup = TestSweepSetup::new_setup(rpc, faucet, 1_000_000, &mut rng);
+
+    // Normal: the signers' block observer should be getting new block
+    // events from bitcoin-core. We haven't hooked up our block observer,
+    // so we need to manually update the database with new bitcoin block
+    // headers and at least one stacks block.
+    backfill_bitcoin_blocks(&db, rpc, &setup.sweep_block_hash).await;
+    // Normal: This stores a genesis stacks block anchored to the bitcoin
+    // blockchain identified by setup.sweep_block_hash.
+    setup.store_stacks_genesis_block(&db).await;
+
+    // Normal: we take the deposit transaction as is from the test setup
+    // and store it in the database. This is necessary for when we fetch
+    // outstanding unfulfilled deposit requests.
+    setup.store_deposit_tx(&db).await;
+
+    // Normal: we take the sweep transaction as is from the test setup and
+    // store it in the database.
+    setup.store_sweep_tx(&db).await;
+
+    // Normal: we need to store a row in the dkg_shares table so that we
+    // have a record of the scriptPubKey that the signers control.
+    setup.store_dkg_shares(&db).await;
+
+    // Normal: the request and how the signers voted needs to be added to
+    // the database. Here the bitmap in the deposit request object
+    // corresponds to how the signers voted.
+    setup.store_deposit_request(&db).await;
+    setup.store_deposit_decisions(&db).await;
+
+    // Normal: create a properly formed complete-deposit transaction object
+    // and the corresponding request context.
+    let (complete_deposit_tx, req_ctx) = make_complete_deposit(&setup);
+
+    // Different: the signer will only mint just less than the amount in
+    // the transaction.
+    let max_mint = complete_deposit_tx.amount - 1;
+    let mut ctx = TestContext::builder()
+        .with_storage(db.clone())
+        .modify_settings(|settings| settings.signer.max_mint_per_call = Some(max_mint))
+        .with_first_bitcoin_core_client()
+        .with_mocked_stacks_client()
+        .with_mocked_emily_client()
+        .build();
+
+    // Normal: the request is not completed in the smart contract.
+    set_deposit_incomplete(&mut ctx).await;
+
+    let validation_result = complete_deposit_tx.validate(&ctx, &req_ctx).await;
+    match validation_result.unwrap_err() {
+        Error::DepositValidation(ref err) => {
+            assert_eq!(err.error, DepositErrorMsg::AmountAboveMaxMint)
+        }
+        err => panic!("unexpected error during validation {err}"),
+    }
+
+    testing::storage::drop_db(db).await;
+}
+
+/// For this test we check that the `CompleteDepositV1::validate` function
+/// returns a deposit validation error with a ScriptRecipientMismatch
+/// message when our record of the recipient of the deposit request does
+/// not match the recipient in its deposit script, even if the recipient in
+/// the transaction matches our record.
+#[tokio::test]
+async fn complete_deposit_validation_script_recipient_mismatch() {
+    // Normal: this generates the blockchain as well as deposit request
+    // transactions and a transaction sweeping in the deposited funds.
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+    let (rpc, faucet) = regtest::initialize_blockchain();
+    let setup = TestSweepSetup::new_setup(rpc, faucet, 1_000_000, &mut rng);
+
+    // Normal: the signers' block observer should be getting new block
+    // events from bitcoin-core. We haven't hooked up our block observer,
+    // so we need to manually update the database with new bitcoin block
+    // headers and at least one stacks block.
+    backfill_bitcoin_blocks(&db, rpc, &setup.sweep_block_hash).await;
+    // Normal: This stores a genesis stacks block anchored to the bitcoin
+    // blockchain identified by setup.sweep_block_hash.
+    setup.store_stacks_genesis_block(&db).await;
+
+    // Normal: we take the deposit transaction as is from the test setup
+    // and store it in the database. This is necessary for when we fetch
+    // outstanding unfulfilled deposit requests.
+    setup.store_deposit_tx(&db).await;
+
+    // Normal: we take the sweep transaction as is from the test setup and
+    // store it in the database.
+    setup.store_sweep_tx(&db).await;
+
+    // Normal: we need to store a row in the dkg_shares table so that we
+    // have a record of the scriptPubKey that the signers control.
+    setup.store_dkg_shares(&db).await;
+
+    // Normal: the request and how the signers voted needs to be added to
+    // the database. Here the bitmap in the deposit request object
+    // corresponds to how the signers voted.
+    setup.store_deposit_request(&db).await;
+    setup.store_deposit_decisions(&db).await;
+
+    // Different: our record of the recipient is not the one in the
+    // deposit script, and the transaction mints to that recipient.
+    let recipient: StacksPrincipal = fake::Faker.fake_with_rng(&mut rng);
+    sqlx::query(
+        r#"
+        UPDATE deposit_requests AS dr
+        SET recipient = $1
+        WHERE
+            dr.txid = $2
+            AND dr.output_index = $3;
+    "#,
+    )
+    .bind(recipient.to_string())
+    .bind(BitcoinTxId::from(setup.deposit_request.outpoint.txid))
+    .bind(setup.deposit_request.outpoint.vout as i32)
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    let (mut complete_deposit_tx, req_ctx) = make_complete_deposit(&setup);
+    complete_deposit_tx.recipient = recipient.into();
+
+    let mut ctx = TestContext::builder()
+        .with_storage(db.clone())
+        .with_first_bitcoin_core_client()
+        .with_mocked_stacks_client()
+        .with_mocked_emily_client()
+        .build();
+
+    // Normal: the request is not completed in the smart contract.
+    set_deposit_incomplete(&mut ctx).await;
+
+    let validation_result = complete_deposit_tx.validate(&ctx, &req_ctx).await;
+    match validation_result.unwrap_err() {
+        Error::DepositValidation(ref err) => {
+            assert_eq!(err.error, DepositErrorMsg::ScriptRecipientMismatch)
+        }
+        err => panic!("unexpected error during validation {err}"),
+    }
+
+    // The mismatch should have been recorded in the database.
+    let outpoint = setup.deposit_request.outpoint;
+    let mismatches = db
+        .get_mint_mismatches(&outpoint.txid.into(), outpoint.vout)
+        .await
+        .unwrap();
+    assert_eq!(mismatches.len(), 1);
+
+    testing::storage::drop_db(db).await;
+}
@@ -10,6 +10,7 @@ use bitcoin::consensus::encode::serialize_hex;
 use bitcoin::hashes::Hash as _;
 use bitcoincore_rpc_json::Utxo;
 use fake::Fake as _;
+use futures::TryStreamExt as _;
 use futures::future::join_all;
 use signer::testing::storage::model::TestBitcoinTxInfo;
 use test_case::test_case;
@@ -384,17 +385,19 @@ async fn deposit_flow() {
 
     // Bootstrap the tx coordinator event loop
     context.state().set_sbtc_contracts_deployed();
-    let tx_coordinator = transaction_coordinator::TxCoordinatorEventLoop {
-        context: context.clone(),
-        network: network.connect(),
-        private_key,
-        context_window,
-        threshold: signing_threshold as u16,
-        signing_round_max_duration: Duration::from_secs(10),
-        dkg_max_duration: Duration::from_secs(10),
-        bitcoin_presign_request_max_duration: Duration::from_secs(10),
-        is_epoch3: true,
-    };
+    let tx_coordinator = transaction_coordinator::TxCoordinatorEventLoop::builder(
+        context.clone(),
+        network.connect(),
+    )
+    .with_private_key(private_key)
+    .with_context_window(context_window)
+    .with_threshold(signing_threshold as u16)
+    .with_signing_round_max_duration(Duration::from_secs(10))
+    .with_dkg_max_duration(Duration::from_secs(10))
+    .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+    .with_epoch3(true)
+    .build()
+    .unwrap();
     let tx_coordinator_handle = tokio::spawn(async move { tx_coordinator.run().await });
 
     // There shouldn't be any request yet
@@ -464,6 +467,7 @@ async fn deposit_flow() {
                 signer_pub_key: *signer_pub_key,
                 can_accept: true,
                 can_sign: true,
+                decision_version: 0,
             })
             .await
             .expect("failed to write deposit decision");
@@ -716,7 +720,7 @@ async fn test_get_deposits_returns_pending_and_accepted() {
     .expect("cannot update deposits");
 
     // Check that we get all deposits
-    let deposits = emily_client.get_deposits().await.unwrap();
+    let deposits: Vec<_> = emily_client.get_deposits().try_collect().await.unwrap();
     let accepted_deposits = emily_client
         .get_deposits_with_status(DepositStatus::Accepted)
         .await
@@ -14,6 +14,8 @@ mod rbf;
 mod request_decider;
 mod rotate_keys;
 mod setup;
+#[cfg(feature = "stacks-regtest")]
+mod stacks_regtest;
 mod tls_checking;
 mod transaction_coordinator;
 mod transaction_signer;
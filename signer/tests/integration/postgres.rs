@@ -564,6 +564,178 @@ async fn should_return_the_same_pending_withdraw_requests_as_in_memory_store() {
     signer::testing::storage::drop_db(pg_store).await;
 }
 
+/// Test that [`DbRead::get_decided_withdrawal_requests`] returns the
+/// requests that the signer decided on, and that a decision of a signer on
+/// a withdrawal request is only replaced by one with a higher decision
+/// version, or by a rejection with the same version.
+#[tokio::test]
+async fn withdrawal_decisions_are_superseded_by_higher_decision_versions() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let signer_public_key: PublicKey = Faker.fake_with_rng(&mut rng);
+    let params = testing::storage::model::Params {
+        num_bitcoin_blocks: 10,
+        num_stacks_blocks_per_bitcoin_block: 3,
+        num_deposit_requests_per_block: 0,
+        num_withdraw_requests_per_block: 2,
+        num_signers_per_request: 0,
+        consecutive_blocks: false,
+    };
+    let test_data = TestData::generate(&mut rng, &[signer_public_key], &params);
+    test_data.write_to(&db).await;
+
+    let chain_tip = db.get_bitcoin_canonical_chain_tip().await.unwrap().unwrap();
+    let decided = db
+        .get_decided_withdrawal_requests(&chain_tip, 1000, &signer_public_key)
+        .await
+        .unwrap();
+    assert!(decided.is_empty());
+
+    let request = db
+        .get_pending_withdrawal_requests(&chain_tip, 1000, &signer_public_key)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    let decision = WithdrawalSigner {
+        request_id: request.request_id,
+        block_hash: request.block_hash,
+        txid: request.txid,
+        signer_pub_key: signer_public_key,
+        is_accepted: true,
+        decision_version: 1,
+    };
+    db.write_withdrawal_signer_decision(&decision)
+        .await
+        .unwrap();
+
+    let decided = db
+        .get_decided_withdrawal_requests(&chain_tip, 1000, &signer_public_key)
+        .await
+        .unwrap();
+    assert_eq!(decided, vec![request.clone()]);
+
+    // Decisions with a lower version are ignored.
+    let stale = WithdrawalSigner {
+        is_accepted: false,
+        decision_version: 0,
+        ..decision.clone()
+    };
+    db.write_withdrawal_signer_decision(&stale).await.unwrap();
+    let stored = db
+        .get_withdrawal_signers(request.request_id, &request.block_hash)
+        .await
+        .unwrap();
+    assert_eq!(stored, vec![decision.clone()]);
+
+    // Conflicting decisions with the same version resolve to the
+    // rejection, whatever order they are written in.
+    let rejection = WithdrawalSigner {
+        is_accepted: false,
+        ..decision.clone()
+    };
+    db.write_withdrawal_signer_decision(&rejection)
+        .await
+        .unwrap();
+    db.write_withdrawal_signer_decision(&decision)
+        .await
+        .unwrap();
+    let stored = db
+        .get_withdrawal_signers(request.request_id, &request.block_hash)
+        .await
+        .unwrap();
+    assert_eq!(stored, vec![rejection.clone()]);
+
+    // A decision with a higher version replaces the earlier one.
+    let superseding = WithdrawalSigner {
+        is_accepted: true,
+        decision_version: 2,
+        ..rejection
+    };
+    db.write_withdrawal_signer_decision(&superseding)
+        .await
+        .unwrap();
+    let stored = db
+        .get_withdrawal_signers(request.request_id, &request.block_hash)
+        .await
+        .unwrap();
+    assert_eq!(stored, vec![superseding]);
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+/// Test that a decision of a signer on a deposit request is only replaced
+/// by one with a higher decision version, or by a more cautious one with
+/// the same version.
+#[tokio::test]
+async fn deposit_decisions_are_superseded_by_higher_decision_versions() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let signer_public_key: PublicKey = Faker.fake_with_rng(&mut rng);
+    let params = testing::storage::model::Params {
+        num_bitcoin_blocks: 10,
+        num_stacks_blocks_per_bitcoin_block: 3,
+        num_deposit_requests_per_block: 2,
+        num_withdraw_requests_per_block: 0,
+        num_signers_per_request: 0,
+        consecutive_blocks: false,
+    };
+    let test_data = TestData::generate(&mut rng, &[signer_public_key], &params);
+    test_data.write_to(&db).await;
+
+    let request = test_data.deposit_requests[0].clone();
+    let decision = model::DepositSigner {
+        txid: request.txid,
+        output_index: request.output_index,
+        signer_pub_key: signer_public_key,
+        can_accept: true,
+        can_sign: true,
+        decision_version: 1,
+    };
+    db.write_deposit_signer_decision(&decision).await.unwrap();
+
+    // Decisions with a lower version are ignored, and so are less
+    // cautious ones with the same version.
+    let stale = model::DepositSigner {
+        can_accept: false,
+        decision_version: 0,
+        ..decision.clone()
+    };
+    let cannot_sign = model::DepositSigner {
+        can_sign: false,
+        ..decision.clone()
+    };
+    db.write_deposit_signer_decision(&stale).await.unwrap();
+    db.write_deposit_signer_decision(&cannot_sign)
+        .await
+        .unwrap();
+    db.write_deposit_signer_decision(&decision).await.unwrap();
+    let stored = db
+        .get_deposit_signers(&request.txid, request.output_index)
+        .await
+        .unwrap();
+    assert_eq!(stored, vec![cannot_sign.clone()]);
+
+    // A decision with a higher version replaces the earlier one.
+    let superseding = model::DepositSigner {
+        can_accept: false,
+        decision_version: 2,
+        ..cannot_sign
+    };
+    db.write_deposit_signer_decision(&superseding)
+        .await
+        .unwrap();
+    let stored = db
+        .get_deposit_signers(&request.txid, request.output_index)
+        .await
+        .unwrap();
+    assert_eq!(stored, vec![superseding]);
+
+    signer::testing::storage::drop_db(db).await;
+}
+
 /// This ensures that the postgres store and the in memory stores returns equivalent results
 /// when fetching pending accepted deposit requests
 #[tokio::test]
@@ -1262,6 +1434,7 @@ async fn fetching_deposit_request_votes() {
             signer_pub_key: shares.signer_set_public_keys[0],
             can_accept: true,
             can_sign: true,
+            decision_version: 0,
         },
         model::DepositSigner {
             txid,
@@ -1269,6 +1442,7 @@ async fn fetching_deposit_request_votes() {
             signer_pub_key: shares.signer_set_public_keys[1],
             can_accept: false,
             can_sign: true,
+            decision_version: 0,
         },
         model::DepositSigner {
             txid,
@@ -1276,6 +1450,7 @@ async fn fetching_deposit_request_votes() {
             signer_pub_key: shares.signer_set_public_keys[2],
             can_accept: true,
             can_sign: true,
+            decision_version: 0,
         },
         model::DepositSigner {
             txid,
@@ -1283,6 +1458,7 @@ async fn fetching_deposit_request_votes() {
             signer_pub_key: shares.signer_set_public_keys[3],
             can_accept: true,
             can_sign: true,
+            decision_version: 0,
         },
     ];
 
@@ -1330,6 +1506,68 @@ async fn fetching_deposit_request_votes() {
     signer::testing::storage::drop_db(store).await;
 }
 
+/// The batched query for deposit request votes should return the same
+/// votes as the query for a single deposit request, for every outpoint
+/// that was asked for, including outpoints that we have no record of.
+#[tokio::test]
+async fn fetching_deposit_request_votes_in_batches() {
+    let store = testing::storage::new_test_database().await;
+
+    let mut rng = get_rng();
+    let signer_set_config = SignerSetConfig {
+        num_keys: 5,
+        signatures_required: 3,
+    };
+    let shares: EncryptedDkgShares = signer_set_config.fake_with_rng(&mut rng);
+    store.write_encrypted_dkg_shares(&shares).await.unwrap();
+
+    let mut outpoints = Vec::new();
+    for num_votes in 0..4 {
+        let req: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
+        store.write_deposit_request(&req).await.unwrap();
+        outpoints.push(req.outpoint());
+
+        let voters = shares.signer_set_public_keys.iter().take(num_votes);
+        for (index, signer_pub_key) in voters.enumerate() {
+            let decision = model::DepositSigner {
+                txid: req.txid,
+                output_index: req.output_index,
+                signer_pub_key: *signer_pub_key,
+                can_accept: index % 2 == 0,
+                can_sign: true,
+                decision_version: 0,
+            };
+            store
+                .write_deposit_signer_decision(&decision)
+                .await
+                .unwrap();
+        }
+    }
+    // We have no record of this deposit request.
+    let unknown_txid: BitcoinTxId = fake::Faker.fake_with_rng(&mut rng);
+    outpoints.push(bitcoin::OutPoint::new(unknown_txid.into(), 0));
+
+    let batched_votes = store
+        .get_deposit_requests_signer_votes(&outpoints, &shares.aggregate_key)
+        .await
+        .unwrap();
+    assert_eq!(batched_votes.len(), outpoints.len());
+
+    for outpoint in &outpoints {
+        let votes = store
+            .get_deposit_request_signer_votes(
+                &outpoint.txid.into(),
+                outpoint.vout,
+                &shares.aggregate_key,
+            )
+            .await
+            .unwrap();
+        assert_eq!(batched_votes[outpoint], votes);
+    }
+
+    signer::testing::storage::drop_db(store).await;
+}
+
 #[tokio::test]
 async fn fetching_deposit_signer_decisions() {
     let pg_store = testing::storage::new_test_database().await;
@@ -1443,6 +1681,57 @@ async fn fetching_deposit_signer_decisions() {
     signer::testing::storage::drop_db(pg_store).await;
 }
 
+#[tokio::test]
+async fn fetching_deposit_signer_decisions_for_outpoints() {
+    let pg_store = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let num_signers = 3;
+    let test_model_params = testing::storage::model::Params {
+        num_bitcoin_blocks: 5,
+        num_stacks_blocks_per_bitcoin_block: 0,
+        num_deposit_requests_per_block: 1,
+        num_withdraw_requests_per_block: 0,
+        num_signers_per_request: num_signers,
+        consecutive_blocks: true,
+    };
+
+    let signer_set = testing::wsts::generate_signer_set_public_keys(&mut rng, num_signers);
+
+    let test_data = TestData::generate(&mut rng, &signer_set, &test_model_params);
+    test_data.write_to(&pg_store).await;
+
+    let signer_pub_key = signer_set.first().unwrap();
+
+    // We ask for the decisions on two of the deposit requests, and on an
+    // outpoint that is not a deposit request at all.
+    let unknown_outpoint = bitcoin::OutPoint {
+        txid: Faker.fake_with_rng::<BitcoinTxId, _>(&mut rng).into(),
+        vout: 0,
+    };
+    let outpoints = [
+        test_data.deposit_requests[1].outpoint(),
+        test_data.deposit_requests[3].outpoint(),
+        unknown_outpoint,
+    ];
+
+    let deposit_decisions = pg_store
+        .get_deposit_signer_decisions_for_outpoints(&outpoints, signer_pub_key)
+        .await
+        .unwrap();
+
+    assert_eq!(deposit_decisions.len(), 2);
+    for outpoint in &outpoints[..2] {
+        assert!(deposit_decisions.iter().any(|decision| {
+            decision.txid == outpoint.txid.into()
+                && decision.output_index == outpoint.vout
+                && decision.signer_pub_key == *signer_pub_key
+        }));
+    }
+
+    signer::testing::storage::drop_db(pg_store).await;
+}
+
 #[tokio::test]
 async fn fetching_withdrawal_signer_decisions() {
     let pg_store = testing::storage::new_test_database().await;
@@ -1591,6 +1880,7 @@ async fn fetching_withdrawal_request_votes() {
             request_id,
             signer_pub_key: shares.signer_set_public_keys[0],
             is_accepted: true,
+            decision_version: 0,
         },
         WithdrawalSigner {
             txid,
@@ -1598,6 +1888,7 @@ async fn fetching_withdrawal_request_votes() {
             request_id,
             signer_pub_key: shares.signer_set_public_keys[1],
             is_accepted: false,
+            decision_version: 0,
         },
         WithdrawalSigner {
             txid,
@@ -1605,6 +1896,7 @@ async fn fetching_withdrawal_request_votes() {
             request_id,
             signer_pub_key: shares.signer_set_public_keys[2],
             is_accepted: true,
+            decision_version: 0,
         },
         WithdrawalSigner {
             txid,
@@ -1612,6 +1904,7 @@ async fn fetching_withdrawal_request_votes() {
             request_id,
             signer_pub_key: shares.signer_set_public_keys[3],
             is_accepted: true,
+            decision_version: 0,
         },
     ];
 
@@ -3835,6 +4128,7 @@ async fn withdrawal_report_with_no_withdrawal_votes() {
         txid: qualified_id.txid,
         signer_pub_key: *signer_public_key,
         is_accepted: true,
+        decision_version: 0,
     };
     db.write_withdrawal_signer_decision(&withdrawal_decision)
         .await
@@ -4096,7 +4390,7 @@ async fn withdrawal_report_with_withdrawal_request_swept_but_swept_reorged() {
     // blocks have the same height and their parents don't point to blocks
     // that exist.
     for (block_height, block) in test_data.stacks_blocks.iter_mut().enumerate() {
-        block.block_height = block_height.into();
+        block.block_height = (block_height as u64).into();
         block.parent_hash = parent_hash;
         parent_hash = block.block_hash;
     }
@@ -4242,7 +4536,7 @@ async fn withdrawal_report_with_withdrawal_request_swept_but_swept_reorged2() {
     // blocks have the same height and their parents don't point to blocks
     // that exist.
     for (block_height, block) in test_data.stacks_blocks.iter_mut().enumerate() {
-        block.block_height = block_height.into();
+        block.block_height = (block_height as u64).into();
         block.parent_hash = parent_hash;
         parent_hash = block.block_hash;
     }
@@ -4426,6 +4720,7 @@ async fn withdrawal_report_with_withdrawal_request_confirmed() {
         txid: qualified_id.txid,
         signer_pub_key: *signer_public_key,
         is_accepted: false,
+        decision_version: 0,
     };
     db.write_withdrawal_signer_decision(&withdrawal_decision)
         .await
@@ -4492,111 +4787,948 @@ async fn can_write_multiple_bitcoin_withdrawal_outputs() {
 }
 
 #[tokio::test]
-async fn get_deposit_request_returns_none_for_missing_deposit() {
+async fn can_write_and_get_coordinator_attestations() {
     let db = testing::storage::new_test_database().await;
     let mut rng = get_rng();
 
-    // Create a random txid
-    let txid: model::BitcoinTxId = fake::Faker.fake_with_rng(&mut rng);
+    let attestation: model::CoordinatorAttestation = fake::Faker.fake_with_rng(&mut rng);
+    db.write_coordinator_attestation(&attestation)
+        .await
+        .unwrap();
 
-    // Fetch the deposit request for the fake txid
-    let fetched_deposit = db.get_deposit_request(&txid, 0).await.unwrap();
+    // Writing the same attestation twice is a no-op.
+    db.write_coordinator_attestation(&attestation)
+        .await
+        .unwrap();
 
-    // Assert that the fetched fee is None
-    assert_eq!(fetched_deposit, None);
+    let attestations = db
+        .get_coordinator_attestations(&attestation.bitcoin_chain_tip)
+        .await
+        .unwrap();
+    assert_eq!(attestations, vec![attestation]);
+
+    let chain_tip: BitcoinBlockHash = fake::Faker.fake_with_rng(&mut rng);
+    let attestations = db.get_coordinator_attestations(&chain_tip).await.unwrap();
+    assert!(attestations.is_empty());
 
     signer::testing::storage::drop_db(db).await;
 }
 
 #[tokio::test]
-async fn get_deposit_request_returns_returns_inserted_deposit_request() {
+async fn can_write_and_get_deposit_decision_receipts() {
     let db = testing::storage::new_test_database().await;
     let mut rng = get_rng();
 
-    // Create multiple deposit requests
-    let deposit_request1: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
-    let deposit_request2: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
+    let req: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
+    db.write_deposit_request(&req).await.unwrap();
 
-    // Insert the deposit requests into the database
-    db.write_deposit_request(&deposit_request1).await.unwrap();
-    db.write_deposit_request(&deposit_request2).await.unwrap();
+    let receipts: Vec<model::DepositDecisionReceipt> = (0..2)
+        .map(|_| model::DepositDecisionReceipt {
+            txid: req.txid,
+            output_index: req.output_index,
+            ..fake::Faker.fake_with_rng(&mut rng)
+        })
+        .collect();
 
-    // Fetch deposit requests from the database
-    let fetched_deposit1 = db
-        .get_deposit_request(&deposit_request1.txid, deposit_request1.output_index)
+    for receipt in receipts.iter() {
+        db.write_deposit_decision_receipt(receipt).await.unwrap();
+    }
+    // Writing the same receipt twice is a no-op.
+    db.write_deposit_decision_receipt(&receipts[0])
         .await
         .unwrap();
-    let fetched_deposit2 = db
-        .get_deposit_request(&deposit_request2.txid, deposit_request2.output_index)
+
+    let mut stored = db
+        .get_deposit_decision_receipts(&req.txid, req.output_index)
         .await
         .unwrap();
+    stored.sort_by_key(|receipt| receipt.signer_pub_key);
+    let mut expected = receipts;
+    expected.sort_by_key(|receipt| receipt.signer_pub_key);
+    assert_eq!(stored, expected);
 
-    // Assert that the fetched fees match the inserted fees
-    assert_eq!(fetched_deposit1, Some(deposit_request1));
-    assert_eq!(fetched_deposit2, Some(deposit_request2));
+    let stored = db
+        .get_deposit_decision_receipts(&req.txid, req.output_index + 1)
+        .await
+        .unwrap();
+    assert!(stored.is_empty());
 
     signer::testing::storage::drop_db(db).await;
 }
 
-/// This struct is for testing different conditions when attempting to
-/// retrieve the signers' UTXO.
-struct ReorgDescription<const N: usize> {
-    /// An array that indicates the height that includes at least one sweep
-    /// transaction.
-    sweep_heights: [BitcoinBlockHeight; N],
-    /// This is the height where there is a reorg.
-    reorg_height: BitcoinBlockHeight,
-    /// This is the height of the donation. It must be less than or equal
-    /// to the minimum sweep height indicated by `sweep_heights`.
-    donation_height: BitcoinBlockHeight,
-    /// The expected height of the UTXO returned by
-    /// [`DbRead::get_signer_utxo`].
-    utxo_height: Option<BitcoinBlockHeight>,
-    /// When we create sweep package, this field controls how many
-    /// transactions are created in the package.
-    num_transactions: std::ops::Range<u8>,
+#[tokio::test]
+async fn can_write_and_get_latest_tenure_plan() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    assert!(db.get_latest_tenure_plan().await.unwrap().is_none());
+
+    let plans: Vec<model::TenurePlan> = (0..3)
+        .map(|_| fake::Faker.fake_with_rng(&mut rng))
+        .collect();
+
+    for plan in plans.iter() {
+        db.write_tenure_plan(plan).await.unwrap();
+        let latest = db.get_latest_tenure_plan().await.unwrap();
+        assert_eq!(latest.as_ref(), Some(plan));
+    }
+
+    signer::testing::storage::drop_db(db).await;
 }
 
-impl<const N: usize> ReorgDescription<N> {
-    fn num_blocks(&self) -> u64 {
-        *self.sweep_heights.into_iter().max().unwrap_or_default()
+#[tokio::test]
+async fn can_write_and_get_latest_dkg_begin_refusal() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    assert!(db.get_latest_dkg_begin_refusal().await.unwrap().is_none());
+
+    let refusals: Vec<model::DkgBeginRefusal> = (0..3)
+        .map(|_| fake::Faker.fake_with_rng(&mut rng))
+        .collect();
+
+    for refusal in refusals.iter() {
+        db.write_dkg_begin_refusal(refusal).await.unwrap();
+        let latest = db.get_latest_dkg_begin_refusal().await.unwrap();
+        assert_eq!(latest.as_ref(), Some(refusal));
     }
+
+    signer::testing::storage::drop_db(db).await;
 }
 
-/// In these tests we check that [`DbRead::get_signer_utxo`] returns the
-/// expected UTXO when there is a reorg. The test is set up as follows
-/// 1. Populate the database with some minimal bitcoin blockchain data.
-/// 2. For each block between the current block and the number-of-blocks to
-///    generate, create a random number of transactions where we spend the
-///    last output and create a new one.
-/// 3. Note the last transaction created in each bitcoin block.
-/// 4. Create a new chain starting at the height indicated by
-///    `reorg_height`, making sure that it is longer than the current
-///    blockchain in the database, so that it is the best chain.
-/// 5. Get the signers' UTXO and check that the transaction ID matches the
-///    one expected.
-#[test_case(ReorgDescription {
-    sweep_heights: [0u64.into(), 3u64.into(), 4u64.into(), 5u64.into()],
-    reorg_height: 4u64.into(),
-    donation_height: 0u64.into(),
-    utxo_height: Some(4u64.into()),
-    num_transactions: std::ops::Range { start: 1, end: 2 },
-}; "vanilla reorg")]
-#[test_case(ReorgDescription {
-    sweep_heights: [0u64.into(), 3u64.into(), 4u64.into(), 5u64.into()],
-    reorg_height: 2u64.into(),
-    donation_height: 0u64.into(),
-    utxo_height: Some(0u64.into()),
-    num_transactions: std::ops::Range { start: 1, end: 2 },
-}; "near-complete-reorg")]
-#[test_case(ReorgDescription {
-    sweep_heights: [0u64.into(), 6u64.into(), 10u64.into(), 12u64.into()],
-    reorg_height: 7u64.into(),
-    donation_height: 0u64.into(),
-    utxo_height: Some(6u64.into()),
-    num_transactions: std::ops::Range { start: 1, end: 2 },
-}; "partial-reorg")]
-#[test_case(ReorgDescription {
+#[tokio::test]
+async fn can_write_and_update_signer_key_rotations() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    assert!(db.get_latest_signer_key_rotation().await.unwrap().is_none());
+
+    let mut first: model::SignerKeyRotation = fake::Faker.fake_with_rng(&mut rng);
+    first.status = model::SignerKeyRotationStatus::Generated;
+    db.write_signer_key_rotation(&first).await.unwrap();
+
+    let status = model::SignerKeyRotationStatus::Cancelled;
+    let updated = db
+        .set_signer_key_rotation_status(&first.new_public_key, status)
+        .await
+        .unwrap();
+    assert!(updated);
+    first.status = status;
+    let latest = db.get_latest_signer_key_rotation().await.unwrap();
+    assert_eq!(latest.as_ref(), Some(&first));
+
+    let second: model::SignerKeyRotation = fake::Faker.fake_with_rng(&mut rng);
+    db.write_signer_key_rotation(&second).await.unwrap();
+    let latest = db.get_latest_signer_key_rotation().await.unwrap();
+    assert_eq!(latest.as_ref(), Some(&second));
+
+    let unknown_key: PublicKey = fake::Faker.fake_with_rng(&mut rng);
+    let updated = db
+        .set_signer_key_rotation_status(&unknown_key, status)
+        .await
+        .unwrap();
+    assert!(!updated);
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+#[tokio::test]
+async fn key_endorsements_are_returned_oldest_first_without_duplicates() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let endorsements: Vec<model::KeyEndorsement> = (0..3)
+        .map(|_| fake::Faker.fake_with_rng(&mut rng))
+        .collect();
+
+    for endorsement in endorsements.iter() {
+        db.write_key_endorsement(endorsement).await.unwrap();
+    }
+    // Endorsements are broadcast on every block, so the same endorsement
+    // is received many times.
+    let mut duplicate = endorsements[0].clone();
+    duplicate.bitcoin_chain_tip = fake::Faker.fake_with_rng(&mut rng);
+    db.write_key_endorsement(&duplicate).await.unwrap();
+
+    let stored = db.get_key_endorsements().await.unwrap();
+    assert_eq!(stored, endorsements);
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+#[tokio::test]
+async fn emergency_recovery_approvals_keep_the_latest_statement_of_each_signer() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let recovery_script_pubkey: model::ScriptPubKey = fake::Faker.fake_with_rng(&mut rng);
+    let approvals: Vec<model::EmergencyRecoveryApproval> = [105u64, 100, 103]
+        .into_iter()
+        .map(|height| model::EmergencyRecoveryApproval {
+            signer_public_key: fake::Faker.fake_with_rng(&mut rng),
+            recovery_script_pubkey: recovery_script_pubkey.clone(),
+            bitcoin_block_hash: fake::Faker.fake_with_rng(&mut rng),
+            bitcoin_block_height: height.into(),
+            revoked: false,
+        })
+        .collect();
+
+    for approval in approvals.iter() {
+        db.write_emergency_recovery_approval(approval)
+            .await
+            .unwrap();
+    }
+    // A revocation replaces the approval of the same signer.
+    let mut revocation = approvals[1].clone();
+    revocation.bitcoin_block_height = 110u64.into();
+    revocation.revoked = true;
+    db.write_emergency_recovery_approval(&revocation)
+        .await
+        .unwrap();
+
+    // Approvals of other recovery scriptPubKeys are kept apart.
+    let mut other = approvals[0].clone();
+    other.recovery_script_pubkey = fake::Faker.fake_with_rng(&mut rng);
+    db.write_emergency_recovery_approval(&other).await.unwrap();
+
+    let stored = db
+        .get_emergency_recovery_approvals(&recovery_script_pubkey)
+        .await
+        .unwrap();
+    let expected = vec![approvals[2].clone(), approvals[0].clone(), revocation];
+    assert_eq!(stored, expected);
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+/// The emergency recovery is only swept once one of the recorded sweeps
+/// is confirmed on the canonical bitcoin blockchain.
+#[tokio::test]
+async fn emergency_recovery_is_swept_once_a_sweep_is_confirmed() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let bitcoin_chain = testing::blocks::BitcoinChain::new_with_length(3);
+    let fork_block = bitcoin_chain.first_block().new_child();
+    for block in bitcoin_chain.into_iter().chain([&fork_block]) {
+        db.write_bitcoin_block(block).await.unwrap();
+    }
+    let chain_tip = bitcoin_chain.chain_tip().block_hash;
+
+    let sweep: model::EmergencyRecoverySweep = Faker.fake_with_rng(&mut rng);
+    let script = &sweep.recovery_script_pubkey;
+    db.write_emergency_recovery_sweep(&sweep).await.unwrap();
+    // Recording the same sweep twice is fine.
+    db.write_emergency_recovery_sweep(&sweep).await.unwrap();
+    assert!(
+        !db.is_emergency_recovery_swept(&chain_tip, script)
+            .await
+            .unwrap()
+    );
+
+    // A sweep confirmed on a fork does not count.
+    let tx_ref = model::BitcoinTxRef {
+        txid: sweep.txid,
+        block_hash: fork_block.block_hash,
+    };
+    db.write_bitcoin_transaction(&tx_ref).await.unwrap();
+    assert!(
+        !db.is_emergency_recovery_swept(&chain_tip, script)
+            .await
+            .unwrap()
+    );
+
+    let tx_ref = model::BitcoinTxRef {
+        txid: sweep.txid,
+        block_hash: bitcoin_chain.nth_block(1u64.into()).block_hash,
+    };
+    db.write_bitcoin_transaction(&tx_ref).await.unwrap();
+    assert!(
+        db.is_emergency_recovery_swept(&chain_tip, script)
+            .await
+            .unwrap()
+    );
+
+    // Sweeps to other scriptPubKeys are kept apart.
+    let other: model::ScriptPubKey = Faker.fake_with_rng(&mut rng);
+    assert!(
+        !db.is_emergency_recovery_swept(&chain_tip, &other)
+            .await
+            .unwrap()
+    );
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+#[tokio::test]
+async fn block_processing_stages_keep_the_first_time_recorded() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let block_hash: model::BitcoinBlockHash = fake::Faker.fake_with_rng(&mut rng);
+    let at = |unix_timestamp: i64| {
+        model::Timestamp::from(time::OffsetDateTime::from_unix_timestamp(unix_timestamp).unwrap())
+    };
+
+    let processing = db.get_bitcoin_block_processing(&block_hash).await.unwrap();
+    assert!(processing.is_none());
+
+    db.write_block_processing_stage(&block_hash, model::BlockProcessingStage::Observed, at(100))
+        .await
+        .unwrap();
+    let stage = model::BlockProcessingStage::DecisionsCompleted;
+    db.write_block_processing_stage(&block_hash, stage, at(130))
+        .await
+        .unwrap();
+    db.write_block_processing_stage(&block_hash, model::BlockProcessingStage::Observed, at(200))
+        .await
+        .unwrap();
+
+    let processing = db
+        .get_bitcoin_block_processing(&block_hash)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(processing.observed_at, Some(at(100)));
+    assert_eq!(processing.deposits_fetched_at, None);
+    assert_eq!(processing.decisions_completed_at, Some(at(130)));
+    assert_eq!(processing.tenure_completed_at, None);
+    assert_eq!(
+        processing.time_since_observed(stage),
+        Some(std::time::Duration::from_secs(30))
+    );
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+#[tokio::test]
+async fn aggregate_key_registry_tracks_activation_ranges() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let mut shares: [model::EncryptedDkgShares; 2] = fake::Faker.fake_with_rng(&mut rng);
+    shares[0].started_at_bitcoin_block_height = 10u64.into();
+    shares[0].dkg_shares_status = DkgSharesStatus::Verified;
+    shares[1].started_at_bitcoin_block_height = 20u64.into();
+    shares[1].dkg_shares_status = DkgSharesStatus::Unverified;
+    for shares in shares.iter() {
+        db.register_aggregate_key(shares).await.unwrap();
+    }
+
+    let txid: StacksTxId = fake::Faker.fake_with_rng(&mut rng);
+    db.write_aggregate_key_rotation(&shares[0].aggregate_key, &txid)
+        .await
+        .unwrap();
+    db.activate_aggregate_key(&shares[0].aggregate_key, 12u64.into())
+        .await
+        .unwrap();
+    // Activating an active key again does not move its activation height.
+    db.activate_aggregate_key(&shares[0].aggregate_key, 15u64.into())
+        .await
+        .unwrap();
+
+    let first = db
+        .get_aggregate_key_record(&shares[0].aggregate_key)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.rotate_keys_txid, Some(txid));
+    assert_eq!(first.activation_height, Some(12u64.into()));
+    assert!(first.is_active());
+
+    // Once the second key passes verification and is activated, the first
+    // one is retired.
+    shares[1].dkg_shares_status = DkgSharesStatus::Verified;
+    db.register_aggregate_key(&shares[1]).await.unwrap();
+    db.activate_aggregate_key(&shares[1].aggregate_key, 25u64.into())
+        .await
+        .unwrap();
+
+    let records = db.get_aggregate_key_records().await.unwrap();
+    assert_eq!(records.len(), 2);
+    let [first, second] = records.as_slice() else {
+        panic!("expected two aggregate keys");
+    };
+    assert_eq!(first.aggregate_key, shares[0].aggregate_key);
+    assert_eq!(first.retirement_height, Some(25u64.into()));
+    assert!(first.was_active_at(24u64.into()));
+    assert!(!first.was_active_at(25u64.into()));
+    assert_eq!(second.aggregate_key, shares[1].aggregate_key);
+    assert_eq!(second.dkg_shares_status, DkgSharesStatus::Verified);
+    assert_eq!(second.dkg_block_height, 20u64.into());
+    assert!(second.is_active());
+    assert!(second.was_active_at(25u64.into()));
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+#[tokio::test]
+async fn can_get_withdrawal_requests_by_recipient_and_sender() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let mut requests: Vec<model::WithdrawalRequest> = (0..3)
+        .map(|_| fake::Faker.fake_with_rng(&mut rng))
+        .collect();
+    requests[1].recipient = requests[0].recipient.clone();
+    requests[2].sender_address = requests[0].sender_address.clone();
+    requests.sort_by_key(|req| req.request_id);
+
+    for request in requests.iter() {
+        db.write_withdrawal_request(request).await.unwrap();
+    }
+
+    let recipient = &requests[0].recipient;
+    let by_recipient = db
+        .get_withdrawal_requests_by_recipient(recipient)
+        .await
+        .unwrap();
+    let expected: Vec<_> = requests
+        .iter()
+        .filter(|req| &req.recipient == recipient)
+        .cloned()
+        .collect();
+    assert_eq!(by_recipient, expected);
+
+    let sender = &requests[0].sender_address;
+    let by_sender = db.get_withdrawal_requests_by_sender(sender).await.unwrap();
+    let expected: Vec<_> = requests
+        .iter()
+        .filter(|req| &req.sender_address == sender)
+        .cloned()
+        .collect();
+    assert_eq!(by_sender, expected);
+
+    let request = &requests[1];
+    let fetched = db
+        .get_withdrawal_request(request.request_id, &request.block_hash)
+        .await
+        .unwrap();
+    assert_eq!(fetched.as_ref(), Some(request));
+
+    let unknown_block_hash = fake::Faker.fake_with_rng(&mut rng);
+    let fetched = db
+        .get_withdrawal_request(request.request_id, &unknown_block_hash)
+        .await
+        .unwrap();
+    assert_eq!(fetched, None);
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+#[tokio::test]
+async fn can_get_deposit_requests_by_recipient() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let mut requests: Vec<model::DepositRequest> = (0..3)
+        .map(|_| fake::Faker.fake_with_rng(&mut rng))
+        .collect();
+    requests[1].recipient = requests[0].recipient.clone();
+
+    for request in requests.iter() {
+        db.write_deposit_request(request).await.unwrap();
+    }
+
+    let recipient = &requests[0].recipient;
+    let mut expected: Vec<_> = requests[..2].to_vec();
+    expected.sort_by_key(|req| (req.txid, req.output_index));
+
+    let fetched = db
+        .get_deposit_requests_by_recipient(recipient)
+        .await
+        .unwrap();
+    assert_eq!(fetched, expected);
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+#[tokio::test]
+async fn can_get_completed_deposit_events() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let request: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
+    let events = db
+        .get_completed_deposit_events(&request.txid, request.output_index)
+        .await
+        .unwrap();
+    assert!(events.is_empty());
+
+    let event = model::CompletedDepositEvent {
+        txid: fake::Faker.fake_with_rng(&mut rng),
+        block_id: fake::Faker.fake_with_rng(&mut rng),
+        amount: request.amount,
+        outpoint: request.outpoint(),
+        sweep_block_hash: fake::Faker.fake_with_rng(&mut rng),
+        sweep_block_height: 42u64.into(),
+        sweep_txid: fake::Faker.fake_with_rng(&mut rng),
+    };
+    db.write_completed_deposit_event(&event).await.unwrap();
+
+    let events = db
+        .get_completed_deposit_events(&request.txid, request.output_index)
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].txid, event.txid);
+    assert_eq!(events[0].outpoint, event.outpoint);
+    assert_eq!(events[0].sweep_txid, event.sweep_txid);
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+#[tokio::test]
+async fn can_write_and_get_deposit_rejection() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let request: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
+    db.write_deposit_request(&request).await.unwrap();
+
+    let rejection = db
+        .get_deposit_rejection(&request.txid, request.output_index)
+        .await
+        .unwrap();
+    assert!(rejection.is_none());
+
+    let rejection = model::DepositRejection {
+        txid: request.txid,
+        output_index: request.output_index,
+        reason: model::DepositRejectionReason::BelowMinimumAmount,
+    };
+    db.write_deposit_rejection(&rejection).await.unwrap();
+    // Writing the rejection again replaces the existing record.
+    db.write_deposit_rejection(&rejection).await.unwrap();
+
+    let fetched = db
+        .get_deposit_rejection(&request.txid, request.output_index)
+        .await
+        .unwrap();
+    assert_eq!(fetched, Some(rejection));
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+/// Expired deposit requests should be excluded from the pending accepted
+/// deposit requests until they are re-activated, and the in-memory store
+/// should agree with postgres.
+#[tokio::test]
+async fn expired_deposits_are_not_pending_until_reactivated() {
+    let pg_store = testing::storage::new_test_database().await;
+    let in_memory_store = storage::memory::Store::new_shared();
+    let mut rng = get_rng();
+
+    let num_signers = 7;
+    let context_window = 9;
+    let threshold = 4;
+    let test_model_params = testing::storage::model::Params {
+        num_bitcoin_blocks: 10,
+        num_stacks_blocks_per_bitcoin_block: 3,
+        num_deposit_requests_per_block: 5,
+        num_withdraw_requests_per_block: 5,
+        num_signers_per_request: num_signers,
+        consecutive_blocks: false,
+    };
+
+    let signer_set = testing::wsts::generate_signer_set_public_keys(&mut rng, num_signers);
+    let mut test_data = TestData::generate(&mut rng, &signer_set, &test_model_params);
+    for deposit in test_data.deposit_requests.iter_mut() {
+        deposit.lock_time = u16::MAX as u32;
+    }
+    test_data.write_to(&pg_store).await;
+    test_data.write_to(&in_memory_store).await;
+
+    let chain_tip = pg_store
+        .get_bitcoin_canonical_chain_tip_ref()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let mut pending = pg_store
+        .get_pending_accepted_deposit_requests(&chain_tip, context_window, threshold)
+        .await
+        .unwrap();
+    pending.sort();
+    let expired = pending
+        .first()
+        .cloned()
+        .expect("no pending accepted deposits");
+
+    let expiration = model::DepositExpiration {
+        txid: expired.txid,
+        output_index: expired.output_index,
+        bitcoin_block_hash: chain_tip.block_hash,
+        bitcoin_block_height: chain_tip.block_height,
+        reactivated: false,
+    };
+    pg_store
+        .write_deposit_expiration(&expiration)
+        .await
+        .unwrap();
+    in_memory_store
+        .write_deposit_expiration(&expiration)
+        .await
+        .unwrap();
+    // Writing the expiration again does not change the existing record.
+    pg_store
+        .write_deposit_expiration(&expiration)
+        .await
+        .unwrap();
+
+    let fetched = pg_store
+        .get_deposit_expiration(&expired.txid, expired.output_index)
+        .await
+        .unwrap();
+    assert_eq!(fetched, Some(expiration));
+
+    let mut pg_pending = pg_store
+        .get_pending_accepted_deposit_requests(&chain_tip, context_window, threshold)
+        .await
+        .unwrap();
+    let mut in_memory_pending = in_memory_store
+        .get_pending_accepted_deposit_requests(&chain_tip, context_window, threshold)
+        .await
+        .unwrap();
+    pg_pending.sort();
+    in_memory_pending.sort();
+    assert_eq!(pg_pending, in_memory_pending);
+    assert_eq!(pg_pending.len(), pending.len() - 1);
+    assert!(!pg_pending.contains(&expired));
+
+    // Re-activating the deposit request makes it pending again, but only
+    // the first re-activation has any effect.
+    let reactivated = pg_store
+        .reactivate_deposit_request(&expired.txid, expired.output_index)
+        .await
+        .unwrap();
+    assert!(reactivated);
+    let reactivated = in_memory_store
+        .reactivate_deposit_request(&expired.txid, expired.output_index)
+        .await
+        .unwrap();
+    assert!(reactivated);
+    let reactivated = pg_store
+        .reactivate_deposit_request(&expired.txid, expired.output_index)
+        .await
+        .unwrap();
+    assert!(!reactivated);
+
+    let mut pg_pending = pg_store
+        .get_pending_accepted_deposit_requests(&chain_tip, context_window, threshold)
+        .await
+        .unwrap();
+    let mut in_memory_pending = in_memory_store
+        .get_pending_accepted_deposit_requests(&chain_tip, context_window, threshold)
+        .await
+        .unwrap();
+    pg_pending.sort();
+    in_memory_pending.sort();
+    assert_eq!(pg_pending, in_memory_pending);
+    assert_eq!(pg_pending, pending);
+
+    signer::testing::storage::drop_db(pg_store).await;
+}
+
+/// The fee assessed to a swept deposit is only returned for the sweep
+/// transaction in the block that it was recorded in, and the in-memory
+/// store should agree with postgres.
+#[tokio::test]
+async fn deposit_assessed_fees_are_read_for_the_sweep_block() {
+    let pg_store = testing::storage::new_test_database().await;
+    let in_memory_store = storage::memory::Store::new_shared();
+    let mut rng = get_rng();
+
+    let block: BitcoinBlock = Faker.fake_with_rng(&mut rng);
+    let other_block: BitcoinBlock = Faker.fake_with_rng(&mut rng);
+    let sweep_txid: BitcoinTxId = Faker.fake_with_rng(&mut rng);
+    let tx_ref = model::BitcoinTxRef {
+        txid: sweep_txid,
+        block_hash: block.block_hash,
+    };
+    let deposit_prevout = model::TxPrevout {
+        txid: sweep_txid,
+        prevout_type: model::TxPrevoutType::Deposit,
+        assessed_fee: Some(1_234),
+        ..Faker.fake_with_rng(&mut rng)
+    };
+    let signers_prevout = model::TxPrevout {
+        txid: sweep_txid,
+        prevout_type: model::TxPrevoutType::SignersInput,
+        ..Faker.fake_with_rng(&mut rng)
+    };
+
+    for block in [&block, &other_block] {
+        pg_store.write_bitcoin_block(block).await.unwrap();
+        in_memory_store.write_bitcoin_block(block).await.unwrap();
+    }
+    pg_store.write_bitcoin_transaction(&tx_ref).await.unwrap();
+    in_memory_store
+        .write_bitcoin_transaction(&tx_ref)
+        .await
+        .unwrap();
+    for prevout in [&deposit_prevout, &signers_prevout] {
+        pg_store.write_tx_prevout(prevout).await.unwrap();
+        in_memory_store.write_tx_prevout(prevout).await.unwrap();
+    }
+
+    let deposit_outpoint = bitcoin::OutPoint::new(
+        *deposit_prevout.prevout_txid,
+        deposit_prevout.prevout_output_index,
+    );
+    let signers_outpoint = bitcoin::OutPoint::new(
+        *signers_prevout.prevout_txid,
+        signers_prevout.prevout_output_index,
+    );
+    let cases = [
+        (block.block_hash, deposit_outpoint, Some(1_234)),
+        (other_block.block_hash, deposit_outpoint, None),
+        (block.block_hash, signers_outpoint, None),
+    ];
+    for (block_hash, outpoint, expected) in cases {
+        let pg_fee = pg_store
+            .get_deposit_assessed_fee(&sweep_txid, &block_hash, &outpoint)
+            .await
+            .unwrap();
+        let in_memory_fee = in_memory_store
+            .get_deposit_assessed_fee(&sweep_txid, &block_hash, &outpoint)
+            .await
+            .unwrap();
+        assert_eq!(pg_fee, expected);
+        assert_eq!(in_memory_fee, expected);
+    }
+
+    signer::testing::storage::drop_db(pg_store).await;
+}
+
+/// Deposit requests that the depositor reclaims after the signers voted
+/// to accept them should no longer be pending, as long as the block with
+/// the reclaim is on the canonical bitcoin blockchain. The in-memory
+/// store should agree with postgres.
+#[tokio::test]
+async fn reclaimed_deposits_are_not_pending_on_the_reclaiming_blockchain() {
+    let pg_store = testing::storage::new_test_database().await;
+    let in_memory_store = storage::memory::Store::new_shared();
+    let mut rng = get_rng();
+
+    let num_signers = 7;
+    let context_window = 9;
+    let threshold = 4;
+    let test_model_params = testing::storage::model::Params {
+        num_bitcoin_blocks: 10,
+        num_stacks_blocks_per_bitcoin_block: 3,
+        num_deposit_requests_per_block: 5,
+        num_withdraw_requests_per_block: 5,
+        num_signers_per_request: num_signers,
+        consecutive_blocks: false,
+    };
+
+    let signer_set = testing::wsts::generate_signer_set_public_keys(&mut rng, num_signers);
+    let mut test_data = TestData::generate(&mut rng, &signer_set, &test_model_params);
+    for deposit in test_data.deposit_requests.iter_mut() {
+        deposit.lock_time = u16::MAX as u32;
+    }
+    test_data.write_to(&pg_store).await;
+    test_data.write_to(&in_memory_store).await;
+
+    let chain_tip = pg_store
+        .get_bitcoin_canonical_chain_tip_ref()
+        .await
+        .unwrap()
+        .unwrap();
+
+    let mut pending = pg_store
+        .get_pending_accepted_deposit_requests(&chain_tip, context_window, threshold)
+        .await
+        .unwrap();
+    pending.sort();
+    let [reclaimed, orphaned, ..] = pending.as_slice() else {
+        panic!("not enough pending accepted deposits");
+    };
+
+    // One of the deposits is reclaimed in the chain tip, while the other
+    // one is reclaimed in a block that is not on the canonical bitcoin
+    // blockchain, say because it was reorged out.
+    let reclaim = model::DepositReclaim {
+        txid: reclaimed.txid,
+        output_index: reclaimed.output_index,
+        reclaim_txid: Faker.fake_with_rng(&mut rng),
+        bitcoin_block_hash: chain_tip.block_hash,
+        bitcoin_block_height: chain_tip.block_height,
+    };
+    let orphaned_reclaim = model::DepositReclaim {
+        txid: orphaned.txid,
+        output_index: orphaned.output_index,
+        reclaim_txid: Faker.fake_with_rng(&mut rng),
+        bitcoin_block_hash: Faker.fake_with_rng(&mut rng),
+        bitcoin_block_height: chain_tip.block_height,
+    };
+    for reclaim in [&reclaim, &orphaned_reclaim] {
+        pg_store.write_deposit_reclaim(reclaim).await.unwrap();
+        in_memory_store
+            .write_deposit_reclaim(reclaim)
+            .await
+            .unwrap();
+    }
+    // Writing the reclaim again does not change the existing record.
+    pg_store.write_deposit_reclaim(&reclaim).await.unwrap();
+
+    let fetched = pg_store
+        .get_deposit_reclaim(
+            &chain_tip.block_hash,
+            &reclaimed.txid,
+            reclaimed.output_index,
+        )
+        .await
+        .unwrap();
+    assert_eq!(fetched, Some(reclaim.clone()));
+    let fetched = pg_store
+        .get_deposit_reclaim(&chain_tip.block_hash, &orphaned.txid, orphaned.output_index)
+        .await
+        .unwrap();
+    assert_eq!(fetched, None);
+
+    let mut pg_pending = pg_store
+        .get_pending_accepted_deposit_requests(&chain_tip, context_window, threshold)
+        .await
+        .unwrap();
+    let mut in_memory_pending = in_memory_store
+        .get_pending_accepted_deposit_requests(&chain_tip, context_window, threshold)
+        .await
+        .unwrap();
+    pg_pending.sort();
+    in_memory_pending.sort();
+    assert_eq!(pg_pending, in_memory_pending);
+    assert_eq!(pg_pending.len(), pending.len() - 1);
+    assert!(!pg_pending.contains(reclaimed));
+    assert!(pg_pending.contains(orphaned));
+
+    // Signers validating a sweep of the reclaimed deposit see that it has
+    // been reclaimed.
+    let report = pg_store
+        .get_deposit_request_report(
+            &chain_tip.block_hash,
+            &reclaimed.txid,
+            reclaimed.output_index,
+            &signer_set[0],
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        report.status,
+        DepositConfirmationStatus::Reclaimed(reclaim.reclaim_txid)
+    );
+
+    signer::testing::storage::drop_db(pg_store).await;
+}
+
+#[tokio::test]
+async fn get_deposit_request_returns_none_for_missing_deposit() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    // Create a random txid
+    let txid: model::BitcoinTxId = fake::Faker.fake_with_rng(&mut rng);
+
+    // Fetch the deposit request for the fake txid
+    let fetched_deposit = db.get_deposit_request(&txid, 0).await.unwrap();
+
+    // Assert that the fetched fee is None
+    assert_eq!(fetched_deposit, None);
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+#[tokio::test]
+async fn get_deposit_request_returns_returns_inserted_deposit_request() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    // Create multiple deposit requests
+    let deposit_request1: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
+    let deposit_request2: model::DepositRequest = fake::Faker.fake_with_rng(&mut rng);
+
+    // Insert the deposit requests into the database
+    db.write_deposit_request(&deposit_request1).await.unwrap();
+    db.write_deposit_request(&deposit_request2).await.unwrap();
+
+    // Fetch deposit requests from the database
+    let fetched_deposit1 = db
+        .get_deposit_request(&deposit_request1.txid, deposit_request1.output_index)
+        .await
+        .unwrap();
+    let fetched_deposit2 = db
+        .get_deposit_request(&deposit_request2.txid, deposit_request2.output_index)
+        .await
+        .unwrap();
+
+    // Assert that the fetched fees match the inserted fees
+    assert_eq!(fetched_deposit1, Some(deposit_request1));
+    assert_eq!(fetched_deposit2, Some(deposit_request2));
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+/// This struct is for testing different conditions when attempting to
+/// retrieve the signers' UTXO.
+struct ReorgDescription<const N: usize> {
+    /// An array that indicates the height that includes at least one sweep
+    /// transaction.
+    sweep_heights: [BitcoinBlockHeight; N],
+    /// This is the height where there is a reorg.
+    reorg_height: BitcoinBlockHeight,
+    /// This is the height of the donation. It must be less than or equal
+    /// to the minimum sweep height indicated by `sweep_heights`.
+    donation_height: BitcoinBlockHeight,
+    /// The expected height of the UTXO returned by
+    /// [`DbRead::get_signer_utxo`].
+    utxo_height: Option<BitcoinBlockHeight>,
+    /// When we create sweep package, this field controls how many
+    /// transactions are created in the package.
+    num_transactions: std::ops::Range<u8>,
+}
+
+impl<const N: usize> ReorgDescription<N> {
+    fn num_blocks(&self) -> u64 {
+        *self.sweep_heights.into_iter().max().unwrap_or_default()
+    }
+}
+
+/// In these tests we check that [`DbRead::get_signer_utxo`] returns the
+/// expected UTXO when there is a reorg. The test is set up as follows
+/// 1. Populate the database with some minimal bitcoin blockchain data.
+/// 2. For each block between the current block and the number-of-blocks to
+///    generate, create a random number of transactions where we spend the
+///    last output and create a new one.
+/// 3. Note the last transaction created in each bitcoin block.
+/// 4. Create a new chain starting at the height indicated by
+///    `reorg_height`, making sure that it is longer than the current
+///    blockchain in the database, so that it is the best chain.
+/// 5. Get the signers' UTXO and check that the transaction ID matches the
+///    one expected.
+#[test_case(ReorgDescription {
+    sweep_heights: [0u64.into(), 3u64.into(), 4u64.into(), 5u64.into()],
+    reorg_height: 4u64.into(),
+    donation_height: 0u64.into(),
+    utxo_height: Some(4u64.into()),
+    num_transactions: std::ops::Range { start: 1, end: 2 },
+}; "vanilla reorg")]
+#[test_case(ReorgDescription {
+    sweep_heights: [0u64.into(), 3u64.into(), 4u64.into(), 5u64.into()],
+    reorg_height: 2u64.into(),
+    donation_height: 0u64.into(),
+    utxo_height: Some(0u64.into()),
+    num_transactions: std::ops::Range { start: 1, end: 2 },
+}; "near-complete-reorg")]
+#[test_case(ReorgDescription {
+    sweep_heights: [0u64.into(), 6u64.into(), 10u64.into(), 12u64.into()],
+    reorg_height: 7u64.into(),
+    donation_height: 0u64.into(),
+    utxo_height: Some(6u64.into()),
+    num_transactions: std::ops::Range { start: 1, end: 2 },
+}; "partial-reorg")]
+#[test_case(ReorgDescription {
     sweep_heights: [0u64.into(), 6u64.into(), 20u64.into(), 21u64.into()],
     reorg_height: 19u64.into(),
     donation_height: 0u64.into(),
@@ -6158,6 +7290,7 @@ mod get_pending_accepted_withdrawal_requests {
                 txid: request.txid,
                 signer_pub_key: Faker.fake(),
                 is_accepted: *vote,
+                decision_version: 0,
             };
             db.write_withdrawal_signer_decision(&signer)
                 .await
@@ -7318,6 +8451,222 @@ mod get_pending_accepted_withdrawal_requests {
     }
 }
 
+/// Check that get_pending_refunds returns the approved payments that are
+/// confirmed and unspent on the canonical bitcoin blockchain, and only
+/// those.
+#[tokio::test]
+async fn get_pending_refunds_returns_approved_unspent_canonical_payments() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    // We have a canonical chain of three blocks, and a fork off of the
+    // genesis block.
+    let bitcoin_chain = testing::blocks::BitcoinChain::new_with_length(3);
+    let fork_block = bitcoin_chain.first_block().new_child();
+    for block in bitcoin_chain.into_iter().chain([&fork_block]) {
+        db.write_bitcoin_block(block).await.unwrap();
+    }
+    let canonical: Vec<&BitcoinBlock> = bitcoin_chain.into_iter().collect();
+    let (block1, block2) = (canonical[1], canonical[2]);
+    let chain_tip = block2.block_hash;
+
+    let mut new_payment = |status| model::SignerPayment {
+        status,
+        ..Faker.fake_with_rng(&mut rng)
+    };
+    let approved = model::SignerPaymentStatus::RefundApproved;
+    let approved_payment = new_payment(approved);
+    let unreviewed_payment = new_payment(model::SignerPaymentStatus::Unreviewed);
+    let donation = new_payment(model::SignerPaymentStatus::Donation);
+    let fork_payment = new_payment(approved);
+    let refunded_payment = new_payment(approved);
+    let fork_refunded_payment = new_payment(approved);
+
+    let payments = [
+        (&approved_payment, block1),
+        (&unreviewed_payment, block1),
+        (&donation, block1),
+        (&fork_payment, &fork_block),
+        (&refunded_payment, block1),
+        (&fork_refunded_payment, block1),
+    ];
+    for (payment, block) in payments {
+        let tx_ref = model::BitcoinTxRef {
+            txid: payment.txid,
+            block_hash: block.block_hash,
+        };
+        db.write_bitcoin_transaction(&tx_ref).await.unwrap();
+        db.write_signer_payment(payment).await.unwrap();
+    }
+
+    // The refund of one payment is confirmed on the canonical chain, while
+    // the refund of the other is only confirmed on the fork.
+    let refunds = [
+        (&refunded_payment, block2),
+        (&fork_refunded_payment, &fork_block),
+    ];
+    for (payment, block) in refunds {
+        let refund_txid: BitcoinTxId = Faker.fake_with_rng(&mut rng);
+        let tx_ref = model::BitcoinTxRef {
+            txid: refund_txid,
+            block_hash: block.block_hash,
+        };
+        let prevout = model::TxPrevout {
+            txid: refund_txid,
+            prevout_txid: payment.txid,
+            prevout_output_index: payment.output_index,
+            prevout_type: model::TxPrevoutType::SignersInput,
+            ..Faker.fake_with_rng(&mut rng)
+        };
+        db.write_bitcoin_transaction(&tx_ref).await.unwrap();
+        db.write_tx_prevout(&prevout).await.unwrap();
+    }
+
+    let pending_refunds = db.get_pending_refunds(&chain_tip).await.unwrap();
+    let outpoints: HashSet<bitcoin::OutPoint> = pending_refunds
+        .iter()
+        .map(model::SignerPayment::outpoint)
+        .collect();
+    let expected = HashSet::from([
+        approved_payment.outpoint(),
+        fork_refunded_payment.outpoint(),
+    ]);
+    assert_eq!(outpoints, expected);
+
+    // The unspent payments are the same, whatever their status.
+    let unspent = db.get_unspent_signer_payments(&chain_tip).await.unwrap();
+    let outpoints: HashSet<bitcoin::OutPoint> =
+        unspent.iter().map(model::SignerPayment::outpoint).collect();
+    let expected = HashSet::from([
+        approved_payment.outpoint(),
+        unreviewed_payment.outpoint(),
+        donation.outpoint(),
+        fork_refunded_payment.outpoint(),
+    ]);
+    assert_eq!(outpoints, expected);
+
+    // From the point of view of the fork, only the payment confirmed on
+    // the fork is pending, since the others are not confirmed there.
+    let pending_refunds = db
+        .get_pending_refunds(&fork_block.block_hash)
+        .await
+        .unwrap();
+    assert_eq!(pending_refunds.len(), 1);
+    assert_eq!(pending_refunds[0].outpoint(), fork_payment.outpoint());
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+/// Only the latest acknowledged version of a decision is kept for each
+/// signer, and the acknowledgements of many decisions are fetched at
+/// once.
+#[tokio::test]
+async fn decision_acknowledgements_keep_the_latest_version() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    let deposit_ack = model::DepositDecisionAcknowledgement {
+        decision_version: 3,
+        ..Faker.fake_with_rng(&mut rng)
+    };
+    let other_deposit_ack: model::DepositDecisionAcknowledgement = Faker.fake_with_rng(&mut rng);
+    let stale_deposit_ack = model::DepositDecisionAcknowledgement {
+        decision_version: 1,
+        ..deposit_ack.clone()
+    };
+    for ack in [&deposit_ack, &other_deposit_ack, &stale_deposit_ack] {
+        db.write_deposit_decision_acknowledgement(ack)
+            .await
+            .unwrap();
+    }
+
+    let outpoint = bitcoin::OutPoint::new(deposit_ack.txid.into(), deposit_ack.output_index);
+    let acks = db
+        .get_deposit_decision_acknowledgements(&[outpoint])
+        .await
+        .unwrap();
+    assert_eq!(acks, vec![deposit_ack.clone()]);
+
+    let other_outpoint = bitcoin::OutPoint::new(
+        other_deposit_ack.txid.into(),
+        other_deposit_ack.output_index,
+    );
+    let acks = db
+        .get_deposit_decision_acknowledgements(&[outpoint, other_outpoint])
+        .await
+        .unwrap();
+    assert_eq!(acks.len(), 2);
+
+    let withdrawal_ack = model::WithdrawalDecisionAcknowledgement {
+        decision_version: 1,
+        ..Faker.fake_with_rng(&mut rng)
+    };
+    let newer_withdrawal_ack = model::WithdrawalDecisionAcknowledgement {
+        decision_version: 2,
+        ..withdrawal_ack.clone()
+    };
+    for ack in [&withdrawal_ack, &newer_withdrawal_ack] {
+        db.write_withdrawal_decision_acknowledgement(ack)
+            .await
+            .unwrap();
+    }
+
+    let acks = db
+        .get_withdrawal_decision_acknowledgements(&[withdrawal_ack.qualified_id()])
+        .await
+        .unwrap();
+    assert_eq!(acks, vec![newer_withdrawal_ack]);
+
+    signer::testing::storage::drop_db(db).await;
+}
+
+/// The operator controls set through the control API are replaced when
+/// they are set again and removed when they are cleared.
+#[tokio::test]
+async fn operator_controls_are_set_and_cleared() {
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+
+    assert!(!db.is_coordinator_paused().await.unwrap());
+    db.set_coordinator_paused(true).await.unwrap();
+    assert!(db.is_coordinator_paused().await.unwrap());
+    db.set_coordinator_paused(false).await.unwrap();
+    assert!(!db.is_coordinator_paused().await.unwrap());
+
+    let deposit_override: model::DepositDecisionOverride = Faker.fake_with_rng(&mut rng);
+    let outpoint = deposit_override.outpoint();
+    db.set_deposit_decision_override(&outpoint, Some(!deposit_override.can_accept))
+        .await
+        .unwrap();
+    db.set_deposit_decision_override(&outpoint, Some(deposit_override.can_accept))
+        .await
+        .unwrap();
+    let overrides = db.get_deposit_decision_overrides().await.unwrap();
+    assert_eq!(overrides, vec![deposit_override]);
+
+    db.set_deposit_decision_override(&outpoint, None)
+        .await
+        .unwrap();
+    let overrides = db.get_deposit_decision_overrides().await.unwrap();
+    assert!(overrides.is_empty());
+
+    let withdrawal_override: model::WithdrawalDecisionOverride = Faker.fake_with_rng(&mut rng);
+    let id = withdrawal_override.qualified_id();
+    db.set_withdrawal_decision_override(&id, Some(withdrawal_override.accept))
+        .await
+        .unwrap();
+    let overrides = db.get_withdrawal_decision_overrides().await.unwrap();
+    assert_eq!(overrides, vec![withdrawal_override]);
+
+    db.set_withdrawal_decision_override(&id, None)
+        .await
+        .unwrap();
+    let overrides = db.get_withdrawal_decision_overrides().await.unwrap();
+    assert!(overrides.is_empty());
+
+    signer::testing::storage::drop_db(db).await;
+}
+
 mod sqlx_transactions {
     use super::*;
 
@@ -12,6 +12,7 @@ use bitcoincore_rpc::jsonrpc::error::RpcError;
 use rand::Rng;
 use rand::distributions::Uniform;
 use signer::DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX;
+use signer::bitcoin::units::FeeRate;
 use signer::bitcoin::utxo::DepositRequest;
 use signer::bitcoin::utxo::Fees;
 use signer::bitcoin::utxo::RequestRef;
@@ -19,6 +20,7 @@ use signer::bitcoin::utxo::SbtcRequests;
 use signer::bitcoin::utxo::SignerBtcState;
 use signer::bitcoin::utxo::SignerUtxo;
 use signer::bitcoin::utxo::UnsignedTransaction;
+use signer::bitcoin::utxo::WithdrawalFeeSubsidy;
 use signer::bitcoin::utxo::WithdrawalRequest;
 use signer::context::SbtcLimits;
 use signer::storage::model::ScriptPubKey;
@@ -87,7 +89,7 @@ fn recreate_request_state(
     withdrawals: &[WithdrawalRequest],
     fees: Fees,
 ) -> SbtcRequests {
-    requests.signer_state.fee_rate = ctx.rbf_fee_rate;
+    requests.signer_state.fee_rate = FeeRate::new(ctx.rbf_fee_rate).unwrap();
     requests.signer_state.last_fees = Some(fees);
 
     requests.deposits = deposits.to_vec();
@@ -220,17 +222,19 @@ pub fn transaction_with_rbf(
                 amount: signer_utxo.amount.to_sat(),
                 public_key: signers_public_key,
             },
-            fee_rate: ctx.initial_fee_rate,
+            fee_rate: FeeRate::new(ctx.initial_fee_rate).unwrap(),
             public_key: signers_public_key,
             last_fees: None,
             // The value here isn't important, but it matches what happens
             // in Nakamoto testnet.
             magic_bytes: [b'T', b'3'],
+            metadata_chain_tip: None,
         },
         accept_threshold: failure_threshold,
         num_signers: 2 * failure_threshold,
         sbtc_limits: SbtcLimits::unlimited(),
         max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+        withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
     };
 
     // Okay, lets submit the transaction. We also do a sanity check where
@@ -179,25 +179,23 @@ async fn handle_pending_deposit_request_address_script_pub_key() {
     let request = requests.pop().unwrap();
 
     let network = InMemoryNetwork::new();
-    let mut tx_signer = RequestDeciderEventLoop {
-        network: network.connect(),
-        context: ctx.clone(),
-        context_window: 10000,
-        deposit_decisions_retry_window: 1,
-        withdrawal_decisions_retry_window: 1,
-        blocklist_checker: Some(()),
-        signer_private_key: setup.aggregated_signer.keypair.secret_key().into(),
-    };
+    let mut tx_signer = RequestDeciderEventLoop::builder(ctx.clone(), network.connect())
+        .with_context_window(10000)
+        .with_blocklist_checker(Some(()))
+        .with_private_key(setup.aggregated_signer.keypair.secret_key().into())
+        .with_decisions_retry_windows(1, 1)
+        .build()
+        .unwrap();
 
-    // We need this so that there is a live "network". Otherwise,
-    // TxSignerEventLoop::handle_pending_deposit_request will error when
-    // trying to send a message at the end.
+    // We need this so that there is a live signal channel. Otherwise,
+    // RequestDeciderEventLoop::handle_pending_deposit_request will error
+    // when trying to signal the new decision at the end.
     let _rec = ctx.get_signal_receiver();
 
     // We don't want this to error. There was a bug before, see
     // https://github.com/stacks-network/sbtc/issues/674.
     tx_signer
-        .handle_pending_deposit_request(request, &chain_tip)
+        .handle_pending_deposit_request(request)
         .await
         .unwrap();
 
@@ -265,25 +263,23 @@ async fn handle_pending_deposit_request_not_in_signing_set() {
     let request = requests.pop().unwrap();
 
     let network = InMemoryNetwork::new();
-    let mut tx_signer = RequestDeciderEventLoop {
-        network: network.connect(),
-        context: ctx.clone(),
-        context_window: 10000,
-        deposit_decisions_retry_window: 1,
-        withdrawal_decisions_retry_window: 1,
-        blocklist_checker: Some(()),
+    let mut tx_signer = RequestDeciderEventLoop::builder(ctx.clone(), network.connect())
+        .with_context_window(10000)
+        .with_blocklist_checker(Some(()))
         // We generate a new private key here so that we know (with very
         // high probability) that this signer is not in the signer set.
-        signer_private_key: PrivateKey::new(&mut rng),
-    };
+        .with_private_key(PrivateKey::new(&mut rng))
+        .with_decisions_retry_windows(1, 1)
+        .build()
+        .unwrap();
 
-    // We need this so that there is a live "network". Otherwise,
-    // TxSignerEventLoop::handle_pending_deposit_request will error when
-    // trying to send a message at the end.
+    // We need this so that there is a live signal channel. Otherwise,
+    // RequestDeciderEventLoop::handle_pending_deposit_request will error
+    // when trying to signal the new decision at the end.
     let _rec = ctx.get_signal_receiver();
 
     tx_signer
-        .handle_pending_deposit_request(request, &chain_tip)
+        .handle_pending_deposit_request(request)
         .await
         .unwrap();
 
@@ -349,15 +345,13 @@ async fn persist_received_deposit_decision_fetches_missing_deposit_requests() {
 
     let network = SignerNetwork::single(&ctx);
 
-    let mut decider = RequestDeciderEventLoop {
-        network: network.spawn(),
-        context: ctx.clone(),
-        context_window: 10000,
-        deposit_decisions_retry_window: 1,
-        withdrawal_decisions_retry_window: 1,
-        blocklist_checker: Some(()),
-        signer_private_key: PrivateKey::new(&mut rng),
-    };
+    let mut decider = RequestDeciderEventLoop::builder(ctx.clone(), network.spawn())
+        .with_context_window(10000)
+        .with_blocklist_checker(Some(()))
+        .with_private_key(PrivateKey::new(&mut rng))
+        .with_decisions_retry_windows(1, 1)
+        .build()
+        .unwrap();
     let txid = setup.deposit_request.outpoint.txid.into();
     let output_index = setup.deposit_request.outpoint.vout;
 
@@ -369,6 +363,8 @@ async fn persist_received_deposit_decision_fetches_missing_deposit_requests() {
         output_index,
         can_accept: true,
         can_sign: true,
+        receipt: None,
+        decision_version: 0,
     };
     let sender_pub_key: PublicKey = Faker.fake_with_rng(&mut rng);
     // Emily doesn't know about the deposit request so nothing should be
@@ -509,15 +505,13 @@ async fn blocklist_client_retry(num_failures: u8, failing_iters: u8) {
 
     let blocklist_client = BlocklistClient::with_base_url(blocklist_server.url());
 
-    let mut request_decider = RequestDeciderEventLoop {
-        network: network.connect(),
-        context: ctx.clone(),
-        context_window: 10000,
-        blocklist_checker: Some(blocklist_client),
-        signer_private_key: setup.aggregated_signer.keypair.secret_key().into(),
-        deposit_decisions_retry_window: 1,
-        withdrawal_decisions_retry_window: 1,
-    };
+    let mut request_decider = RequestDeciderEventLoop::builder(ctx.clone(), network.connect())
+        .with_context_window(10000)
+        .with_blocklist_checker(Some(blocklist_client))
+        .with_private_key(setup.aggregated_signer.keypair.secret_key().into())
+        .with_decisions_retry_windows(1, 1)
+        .build()
+        .unwrap();
 
     // We need this so that there is a live "network". Otherwise we will error
     // when trying to send a message at the end.
@@ -654,15 +648,13 @@ async fn do_not_procceed_with_blocked_addresses(is_withdrawal: bool, is_blocked:
 
     let blocklist_client = BlocklistClient::with_base_url(blocklist_server.url());
 
-    let mut request_decider = RequestDeciderEventLoop {
-        network: network.connect(),
-        context: ctx.clone(),
-        context_window: 10000,
-        blocklist_checker: Some(blocklist_client),
-        signer_private_key: setup.aggregated_signer.keypair.secret_key().into(),
-        deposit_decisions_retry_window: 1,
-        withdrawal_decisions_retry_window: 1,
-    };
+    let mut request_decider = RequestDeciderEventLoop::builder(ctx.clone(), network.connect())
+        .with_context_window(10000)
+        .with_blocklist_checker(Some(blocklist_client))
+        .with_private_key(setup.aggregated_signer.keypair.secret_key().into())
+        .with_decisions_retry_windows(1, 1)
+        .build()
+        .unwrap();
 
     // We need this so that there is a live "network". Otherwise we will error
     // when trying to send a message at the end.
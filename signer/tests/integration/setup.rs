@@ -24,12 +24,14 @@ use signer::DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX;
 use signer::bitcoin::BitcoinInteract;
 use signer::bitcoin::rpc::BitcoinCoreClient;
 use signer::bitcoin::rpc::BitcoinTxInfo;
+use signer::bitcoin::units::FeeRate;
 use signer::bitcoin::utxo;
 use signer::bitcoin::utxo::Fees;
 use signer::bitcoin::utxo::SbtcRequests;
 use signer::bitcoin::utxo::SignerBtcState;
 use signer::bitcoin::utxo::SignerUtxo;
 use signer::bitcoin::utxo::TxDeconstructor as _;
+use signer::bitcoin::utxo::WithdrawalFeeSubsidy;
 use signer::bitcoin::validation::WithdrawalValidationResult;
 use signer::block_observer;
 use signer::block_observer::Deposit;
@@ -41,6 +43,9 @@ use signer::context::SbtcLimits;
 use signer::keys::PrivateKey;
 use signer::keys::PublicKey;
 use signer::keys::SignerScriptPubKey;
+use signer::message::DecisionReceipt;
+use signer::message::DepositQuorumCertificate;
+use signer::quorum;
 use signer::stacks::api::MockStacksInteract;
 use signer::stacks::wallet::SignerWallet;
 use signer::storage::DbRead;
@@ -123,6 +128,9 @@ pub struct TestSweepSetup {
     /// The public keys of the signer set. It is effectively controlled by
     /// the above signer's private key.
     pub signer_keys: Vec<PublicKey>,
+    /// The private keys of the signer set, in the same order as the public
+    /// keys above.
+    pub signer_private_keys: Vec<PrivateKey>,
     /// The block hash of the bitcoin block that confirmed the sweep
     /// transaction.
     pub sweep_block_hash: bitcoin::BlockHash,
@@ -203,15 +211,17 @@ impl TestSweepSetup {
                     amount: signer_utxo.amount.to_sat(),
                     public_key: signers_public_key,
                 },
-                fee_rate: 10.0,
+                fee_rate: FeeRate::new(10.0).unwrap(),
                 public_key: signers_public_key,
                 last_fees: None,
                 magic_bytes: [b'T', b'3'],
+                metadata_chain_tip: None,
             },
             accept_threshold: 4,
             num_signers: 7,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         // There should only be one transaction here since there is only
@@ -246,6 +256,9 @@ impl TestSweepSetup {
             .unwrap()
             .unwrap();
 
+        let signer_private_keys =
+            signer::testing::wallet::create_signers_private_keys(rng, &signer, 7);
+
         TestSweepSetup {
             deposit_block_hash,
             deposit_info,
@@ -255,7 +268,11 @@ impl TestSweepSetup {
             sweep_tx_info,
             sweep_block_height: sweep_block_height.into(),
             sweep_block_hash,
-            signer_keys: signer::testing::wallet::create_signers_keys(rng, &signer, 7),
+            signer_keys: signer_private_keys
+                .iter()
+                .map(PublicKey::from_private_key)
+                .collect(),
+            signer_private_keys,
             aggregated_signer: signer,
             withdrawal_request: requests.withdrawals.pop().unwrap(),
             withdrawal_sender: PrincipalData::from(StacksAddress::burn_address(false)),
@@ -334,6 +351,13 @@ impl TestSweepSetup {
         db.write_deposit_request(&deposit_request).await.unwrap();
     }
 
+    /// Construct the quorum certificate for the deposit request from the
+    /// signers that accepted it according to the
+    /// `self.deposit_request.signer_bitmap` field.
+    pub fn deposit_certificate(&self) -> DepositQuorumCertificate {
+        deposit_certificate(&self.signer_private_keys, &self.deposit_request)
+    }
+
     /// Store how the signers voted on the deposit request.
     ///
     /// The deposit request must be stored in the database before this
@@ -341,8 +365,11 @@ impl TestSweepSetup {
     ///
     /// This function uses the `self.deposit_request.signer_bitmap` field
     /// to generate the corresponding deposit signer votes and then stores
-    /// these decisions in the database.
+    /// these decisions in the database, together with the receipts of the
+    /// signers that accepted it.
     pub async fn store_deposit_decisions(&self, db: &PgStore) {
+        store_deposit_receipts(db, &self.deposit_certificate()).await;
+
         let deposit_signers = self
             .signer_keys
             .iter()
@@ -354,6 +381,7 @@ impl TestSweepSetup {
                 signer_pub_key,
                 can_accept: !is_rejected,
                 can_sign: true,
+                decision_version: 0,
             });
 
         for decision in deposit_signers {
@@ -585,6 +613,41 @@ pub async fn set_withdrawal_status<S, B, E>(ctx: &mut MockedStacksContext<S, B,
     .await;
 }
 
+/// Construct a quorum certificate for the given deposit request with a
+/// receipt from each signer that accepted it according to its
+/// `signer_bitmap`. The private keys must be ordered like the bitmap.
+pub fn deposit_certificate(
+    private_keys: &[PrivateKey],
+    request: &utxo::DepositRequest,
+) -> DepositQuorumCertificate {
+    let outpoint = request.outpoint;
+    let receipts = private_keys
+        .iter()
+        .zip(request.signer_bitmap)
+        .filter(|(_, is_rejected)| !is_rejected)
+        .map(|(private_key, _)| DecisionReceipt {
+            signer_public_key: PublicKey::from_private_key(private_key),
+            signature: quorum::sign_deposit_receipt(private_key, &outpoint),
+        })
+        .collect();
+
+    DepositQuorumCertificate { outpoint, receipts }
+}
+
+/// Store the receipts in the given certificate in the database, so that
+/// the coordinator can assemble the certificate from them.
+pub async fn store_deposit_receipts(db: &PgStore, certificate: &DepositQuorumCertificate) {
+    for receipt in certificate.receipts.iter() {
+        let receipt = model::DepositDecisionReceipt {
+            txid: certificate.outpoint.txid.into(),
+            output_index: certificate.outpoint.vout,
+            signer_pub_key: receipt.signer_public_key,
+            signature: receipt.signature.serialize_compact().to_vec(),
+        };
+        db.write_deposit_decision_receipt(&receipt).await.unwrap();
+    }
+}
+
 /// The information about a sweep transaction that has been confirmed.
 #[derive(Clone)]
 pub struct TestSignerSet {
@@ -595,6 +658,9 @@ pub struct TestSignerSet {
     /// The public keys of the signer set. It is effectively controlled by
     /// the above signer's private key.
     pub keys: Vec<PublicKey>,
+    /// The private keys of the signer set, in the same order as the public
+    /// keys above.
+    pub private_keys: Vec<PrivateKey>,
 }
 
 impl TestSignerSet {
@@ -603,8 +669,12 @@ impl TestSignerSet {
         R: rand::Rng,
     {
         let signer = Recipient::new(AddressType::P2tr);
-        let keys = signer::testing::wallet::create_signers_keys(rng, &signer, 7);
-        Self { signer, keys }
+        let private_keys = signer::testing::wallet::create_signers_private_keys(rng, &signer, 7);
+        let keys = private_keys
+            .iter()
+            .map(PublicKey::from_private_key)
+            .collect();
+        Self { signer, keys, private_keys }
     }
 
     pub fn signer_keys(&self) -> &[PublicKey] {
@@ -953,15 +1023,17 @@ impl TestSweepSetup2 {
                     amount: signer_utxo.amount.to_sat(),
                     public_key: aggregated_signer.keypair.x_only_public_key().0,
                 },
-                fee_rate: 10.0,
+                fee_rate: FeeRate::new(10.0).unwrap(),
                 public_key: aggregated_signer.keypair.x_only_public_key().0,
                 last_fees,
                 magic_bytes: [b'T', b'3'],
+                metadata_chain_tip: None,
             },
             accept_threshold: 4,
             num_signers: 7,
             sbtc_limits: SbtcLimits::unlimited(),
             max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+            withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
         };
 
         // There should only be one transaction here since there is only
@@ -1132,6 +1204,16 @@ impl TestSweepSetup2 {
         }
     }
 
+    /// Construct the quorum certificates for the deposit requests from
+    /// the signers that accepted them according to their
+    /// `signer_bitmap` fields.
+    pub fn deposit_certificates(&self) -> Vec<DepositQuorumCertificate> {
+        self.deposits
+            .iter()
+            .map(|(_, request, _)| deposit_certificate(&self.signers.private_keys, request))
+            .collect()
+    }
+
     /// Store how the signers voted on the deposit request.
     ///
     /// The deposit request must be stored in the database before this
@@ -1139,8 +1221,13 @@ impl TestSweepSetup2 {
     ///
     /// This function uses the `self.deposit_request.signer_bitmap` field
     /// to generate the corresponding deposit signer votes and then stores
-    /// these decisions in the database.
+    /// these decisions in the database, together with the receipts of the
+    /// signers that accepted it.
     pub async fn store_deposit_decisions(&self, db: &PgStore) {
+        for certificate in self.deposit_certificates() {
+            store_deposit_receipts(db, &certificate).await;
+        }
+
         for (_, deposit_request, _) in self.deposits.iter() {
             let deposit_signers = self
                 .signers
@@ -1154,6 +1241,7 @@ impl TestSweepSetup2 {
                     signer_pub_key,
                     can_accept: !is_rejected,
                     can_sign: true,
+                    decision_version: 0,
                 });
 
             for decision in deposit_signers {
@@ -1179,6 +1267,7 @@ impl TestSweepSetup2 {
                     txid: withdrawal.request.txid,
                     signer_pub_key,
                     is_accepted: !is_rejected,
+                    decision_version: 0,
                 })
                 .collect();
 
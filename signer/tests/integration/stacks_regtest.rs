@@ -0,0 +1,126 @@
+//! Integration tests that run signer components against the regtest
+//! stacks-node in devenv, with a real `StacksClient` instead of a mocked
+//! one.
+//!
+//! These tests are only compiled with the `stacks-regtest` feature. To
+//! run them, start devenv and wait for nakamoto to kick in, then run:
+//! ```bash
+//! make stacks-regtest-test
+//! ```
+
+use std::time::Duration;
+
+use fake::Fake as _;
+use fake::Faker;
+use rand::rngs::OsRng;
+use signer::config::NetworkKind;
+use signer::context::Context as _;
+use signer::keys::PrivateKey;
+use signer::keys::PublicKey;
+use signer::network::in_memory::InMemoryNetwork;
+use signer::stacks::api::StacksInteract as _;
+use signer::stacks::contracts::SMART_CONTRACTS;
+use signer::stacks::wallet::SignerWallet;
+use signer::storage::model::BitcoinBlockHash;
+use signer::testing::context::*;
+use signer::testing::stacks::REGTEST_FUNDED_PRIVATE_KEY;
+use signer::testing::stacks::fund_multisig;
+use signer::testing::stacks::fund_stx;
+use signer::testing::stacks::regtest_stacks_client;
+use signer::testing::stacks::wait_for_contracts;
+use signer::testing::stacks::wait_for_transaction;
+use signer::transaction_coordinator::TxCoordinatorEventLoop;
+
+/// How long we wait for a stacks transaction to be confirmed.
+const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Check that funding a fresh multi-sig wallet works against the stacks
+/// node, which exercises transaction serialization, broadcasting, and the
+/// deserialization of the account and transaction responses.
+#[tokio::test]
+async fn multisig_wallet_is_funded_on_regtest() {
+    let stacks = regtest_stacks_client();
+
+    let public_keys: Vec<PublicKey> = (0..3).map(|_| Faker.fake()).collect();
+    let wallet = SignerWallet::new(&public_keys, 2, NetworkKind::Regtest, 0).unwrap();
+    let amount = 1_000_000;
+
+    let info = fund_multisig(&stacks, &wallet, amount, CONFIRMATION_TIMEOUT)
+        .await
+        .unwrap();
+    assert!(info.is_success());
+
+    let account = stacks.get_account(wallet.address()).await.unwrap();
+    assert_eq!(account.balance, amount as u128);
+    assert_eq!(account.nonce, 0);
+}
+
+/// Check that the coordinator deploys the sBTC smart contracts from a
+/// separate deployer account, and that the contracts get confirmed by the
+/// stacks node.
+#[tokio::test]
+async fn coordinator_deploys_contracts_on_regtest() {
+    let stacks = regtest_stacks_client();
+
+    // Each run uses a fresh deployer account, so that the contracts are
+    // not deployed already.
+    let deployer_key = PrivateKey::new(&mut OsRng);
+    let ctx = TestContext::builder()
+        .with_in_memory_storage()
+        .with_mocked_bitcoin_client()
+        .with_stacks_client(stacks.clone())
+        .with_mocked_emily_client()
+        .modify_settings(|settings| {
+            settings.signer.deployer_private_key = Some(deployer_key);
+            let deployer = SignerWallet::load_deployer_wallet(&settings.signer)
+                .unwrap()
+                .unwrap();
+            settings.signer.deployer = *deployer.address();
+        })
+        .build();
+    let deployer = ctx.config().signer.deployer;
+
+    let funder: PrivateKey = REGTEST_FUNDED_PRIVATE_KEY.parse().unwrap();
+    let txid = fund_stx(&stacks, &funder, &deployer, 100_000_000)
+        .await
+        .unwrap();
+    let info = wait_for_transaction(&stacks, &txid, CONFIRMATION_TIMEOUT)
+        .await
+        .unwrap();
+    assert!(info.is_success());
+
+    let network = InMemoryNetwork::new();
+    let mut coordinator = TxCoordinatorEventLoop::builder(ctx.clone(), network.connect())
+        .with_private_key(PrivateKey::new(&mut OsRng))
+        .with_context_window(10000)
+        .with_threshold(2)
+        .with_signing_round_max_duration(Duration::from_secs(10))
+        .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+        .with_dkg_max_duration(Duration::from_secs(10))
+        .with_epoch3(true)
+        .build()
+        .unwrap();
+
+    // The signers' wallet and the aggregate key are not used when the
+    // contracts are deployed from the deployer account.
+    let (wallet, _) = signer::testing::wallet::regtest_bootstrap_wallet();
+    let chain_tip: BitcoinBlockHash = Faker.fake();
+    let aggregate_key: PublicKey = Faker.fake();
+
+    coordinator
+        .deploy_smart_contracts(&chain_tip, &wallet, &aggregate_key)
+        .await
+        .unwrap();
+
+    wait_for_contracts(&stacks, &deployer, &SMART_CONTRACTS, CONFIRMATION_TIMEOUT)
+        .await
+        .unwrap();
+
+    // Now that all contracts are deployed, the coordinator should notice
+    // and not try to deploy them again.
+    coordinator
+        .deploy_smart_contracts(&chain_tip, &wallet, &aggregate_key)
+        .await
+        .unwrap();
+    assert!(ctx.state().sbtc_contracts_deployed());
+}
@@ -36,7 +36,6 @@ use emily_client::apis::deposit_api;
 use fake::Fake;
 use fake::Faker;
 use futures::StreamExt as _;
-use lru::LruCache;
 use more_asserts::assert_lt;
 use rand::rngs::OsRng;
 
@@ -147,6 +146,7 @@ use signer::transaction_coordinator;
 use signer::transaction_coordinator::TxCoordinatorEventLoop;
 use signer::transaction_signer::TxSignerEventLoop;
 use tokio::sync::broadcast::Sender;
+use tokio::sync::broadcast::error::RecvError;
 
 use crate::complete_deposit::make_complete_deposit;
 use crate::contracts::SignerStxState;
@@ -268,6 +268,9 @@ where
 /// Wait for all signers to finish their coordinator duties and do this
 /// concurrently so that we don't miss anything (not sure if we need to do
 /// it concurrently).
+///
+/// The waits follow the clock of each signer's context, so tests with a
+/// manual clock need to advance it for this function to time out.
 async fn wait_for_signers<S>(
     signers: &[(IntegrationTestContext<S>, PgStore, &Keypair, SignerNetwork)],
 ) where
@@ -275,11 +278,22 @@ async fn wait_for_signers<S>(
 {
     let wait_duration = Duration::from_secs(15);
 
-    let expected = TxCoordinatorEvent::TenureCompleted.into();
+    let expected: SignerSignal = TxCoordinatorEvent::TenureCompleted.into();
     signers
         .iter()
         .map(|(ctx, _, _, _)| async {
-            ctx.wait_for_signal(wait_duration, |signal| signal == &expected)
+            let mut signals = ctx.get_signal_receiver();
+            let tenure_completed = async {
+                loop {
+                    match signals.recv().await {
+                        Ok(signal) if signal == expected => break,
+                        Err(RecvError::Closed) => panic!("signal channel closed"),
+                        _ => continue,
+                    }
+                }
+            };
+            ctx.clock()
+                .timeout(wait_duration, tenure_completed)
                 .await
                 .unwrap();
         })
@@ -288,7 +302,8 @@ async fn wait_for_signers<S>(
 
     // It's not entirely clear why this sleep is helpful, but it appears to
     // be necessary in CI.
-    Sleep::for_secs(2).await;
+    let (ctx, _, _, _) = &signers[0];
+    ctx.clock().sleep(Duration::from_secs(2)).await;
 }
 
 fn mock_deploy_all_contracts() -> Box<dyn FnOnce(&mut MockStacksInteract)> {
@@ -462,17 +477,19 @@ async fn process_complete_deposit() {
 
     // Bootstrap the tx coordinator event loop
     context.state().set_sbtc_contracts_deployed();
-    let tx_coordinator = transaction_coordinator::TxCoordinatorEventLoop {
-        context: context.clone(),
-        network: network.connect(),
-        private_key,
-        context_window,
-        threshold: signing_threshold as u16,
-        signing_round_max_duration: Duration::from_secs(10),
-        bitcoin_presign_request_max_duration: Duration::from_secs(10),
-        dkg_max_duration: Duration::from_secs(10),
-        is_epoch3: true,
-    };
+    let tx_coordinator = transaction_coordinator::TxCoordinatorEventLoop::builder(
+        context.clone(),
+        network.connect(),
+    )
+    .with_private_key(private_key)
+    .with_context_window(context_window)
+    .with_threshold(signing_threshold as u16)
+    .with_signing_round_max_duration(Duration::from_secs(10))
+    .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+    .with_dkg_max_duration(Duration::from_secs(10))
+    .with_epoch3(true)
+    .build()
+    .unwrap();
     let tx_coordinator_handle = tokio::spawn(async move { tx_coordinator.run().await });
 
     // TODO: here signers use all the same storage, should we use separate ones?
@@ -725,51 +742,45 @@ async fn deploy_smart_contracts_coordinator() {
     let start_count = Arc::new(AtomicU8::new(0));
 
     for (ctx, _, kp, network) in signers.iter() {
-        let ev = TxCoordinatorEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            private_key: kp.secret_key().into(),
-            signing_round_max_duration: Duration::from_secs(10),
-            bitcoin_presign_request_max_duration: Duration::from_secs(10),
-            threshold: ctx.config().signer.bootstrap_signatures_required,
-            dkg_max_duration: Duration::from_secs(10),
-            is_epoch3: true,
-        };
+        let ev = TxCoordinatorEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_private_key(kp.secret_key().into())
+            .with_signing_round_max_duration(Duration::from_secs(10))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required)
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = TxSignerEventLoop {
-            network: network.spawn(),
-            threshold: ctx.config().signer.bootstrap_signatures_required as u32,
-            context: ctx.clone(),
-            context_window: 10000,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            signer_private_key: kp.secret_key().into(),
-            last_presign_block: None,
-            rng: rand::rngs::OsRng,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let ev = TxSignerEventLoop::builder(ctx.clone(), network.spawn(), rand::rngs::OsRng)
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required as u32)
+            .with_context_window(10000)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_private_key(kp.secret_key().into())
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = RequestDeciderEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            deposit_decisions_retry_window: 1,
-            withdrawal_decisions_retry_window: 1,
-            blocklist_checker: Some(()),
-            signer_private_key: kp.secret_key().into(),
-        };
+        let ev = RequestDeciderEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_blocklist_checker(Some(()))
+            .with_private_key(kp.secret_key().into())
+            .with_decisions_retry_windows(1, 1)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
@@ -961,17 +972,16 @@ async fn run_dkg_from_scratch() {
     //    processes for each signer.
     let tx_coordinator_processes = signers.iter().map(|(ctx, _, kp, net)| {
         ctx.state().set_sbtc_contracts_deployed(); // Skip contract deployment
-        TxCoordinatorEventLoop {
-            network: net.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            private_key: kp.secret_key().into(),
-            signing_round_max_duration: Duration::from_secs(10),
-            bitcoin_presign_request_max_duration: Duration::from_secs(10),
-            threshold: ctx.config().signer.bootstrap_signatures_required,
-            dkg_max_duration: Duration::from_secs(10),
-            is_epoch3: true,
-        }
+        TxCoordinatorEventLoop::builder(ctx.clone(), net.spawn())
+            .with_context_window(10000)
+            .with_private_key(kp.secret_key().into())
+            .with_signing_round_max_duration(Duration::from_secs(10))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required)
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .unwrap()
     });
 
     let tx_signer_processes = signers.iter().map(|(context, _, _, net)| {
@@ -1360,34 +1370,30 @@ async fn run_subsequent_dkg() {
     //    processes for each signer.
     let tx_coordinator_processes = signers.iter().map(|(ctx, _, kp, net)| {
         ctx.state().set_sbtc_contracts_deployed(); // Skip contract deployment
-        TxCoordinatorEventLoop {
-            network: net.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            private_key: kp.secret_key().into(),
-            signing_round_max_duration: Duration::from_secs(10),
-            bitcoin_presign_request_max_duration: Duration::from_secs(10),
-            threshold: ctx.config().signer.bootstrap_signatures_required,
-            dkg_max_duration: Duration::from_secs(10),
-            is_epoch3: true,
-        }
+        TxCoordinatorEventLoop::builder(ctx.clone(), net.spawn())
+            .with_context_window(10000)
+            .with_private_key(kp.secret_key().into())
+            .with_signing_round_max_duration(Duration::from_secs(10))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required)
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .unwrap()
     });
 
-    let tx_signer_processes = signers
-        .iter()
-        .map(|(context, _, kp, net)| TxSignerEventLoop {
-            network: net.spawn(),
-            threshold: context.config().signer.bootstrap_signatures_required as u32,
-            context: context.clone(),
-            context_window: 10000,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            signer_private_key: kp.secret_key().into(),
-            rng: rand::rngs::OsRng,
-            last_presign_block: None,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        });
+    let tx_signer_processes = signers.iter().map(|(context, _, kp, net)| {
+        TxSignerEventLoop::builder(context.clone(), net.spawn(), rand::rngs::OsRng)
+            .with_threshold(context.config().signer.bootstrap_signatures_required as u32)
+            .with_context_window(10000)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_private_key(kp.secret_key().into())
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap()
+    });
 
     // We only proceed with the test after all processes have started, and
     // we use this counter to notify us when that happens.
@@ -1598,51 +1604,45 @@ async fn pseudo_random_dkg() {
 
     for (ctx, _, kp, network) in signers.iter() {
         ctx.state().set_sbtc_contracts_deployed();
-        let ev = TxCoordinatorEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            private_key: kp.secret_key().into(),
-            signing_round_max_duration: Duration::from_secs(10),
-            bitcoin_presign_request_max_duration: Duration::from_secs(10),
-            threshold: ctx.config().signer.bootstrap_signatures_required,
-            dkg_max_duration: Duration::from_secs(10),
-            is_epoch3: true,
-        };
+        let ev = TxCoordinatorEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_private_key(kp.secret_key().into())
+            .with_signing_round_max_duration(Duration::from_secs(10))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required)
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = TxSignerEventLoop {
-            network: network.spawn(),
-            threshold: ctx.config().signer.bootstrap_signatures_required as u32,
-            context: ctx.clone(),
-            context_window: 10000,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            signer_private_key: kp.secret_key().into(),
-            rng: rand::rngs::OsRng,
-            dkg_begin_pause: None,
-            last_presign_block: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let ev = TxSignerEventLoop::builder(ctx.clone(), network.spawn(), rand::rngs::OsRng)
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required as u32)
+            .with_context_window(10000)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_private_key(kp.secret_key().into())
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = RequestDeciderEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            deposit_decisions_retry_window: 1,
-            withdrawal_decisions_retry_window: 1,
-            blocklist_checker: Some(()),
-            signer_private_key: kp.secret_key().into(),
-        };
+        let ev = RequestDeciderEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_blocklist_checker(Some(()))
+            .with_private_key(kp.secret_key().into())
+            .with_decisions_retry_windows(1, 1)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
@@ -1990,51 +1990,45 @@ async fn sign_bitcoin_transaction() {
 
     for (ctx, _, kp, network) in signers.iter() {
         ctx.state().set_sbtc_contracts_deployed();
-        let ev = TxCoordinatorEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            private_key: kp.secret_key().into(),
-            signing_round_max_duration: Duration::from_secs(10),
-            bitcoin_presign_request_max_duration: Duration::from_secs(10),
-            threshold: ctx.config().signer.bootstrap_signatures_required,
-            dkg_max_duration: Duration::from_secs(10),
-            is_epoch3: true,
-        };
+        let ev = TxCoordinatorEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_private_key(kp.secret_key().into())
+            .with_signing_round_max_duration(Duration::from_secs(10))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required)
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = TxSignerEventLoop {
-            network: network.spawn(),
-            threshold: ctx.config().signer.bootstrap_signatures_required as u32,
-            context: ctx.clone(),
-            context_window: 10000,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            signer_private_key: kp.secret_key().into(),
-            rng: rand::rngs::OsRng,
-            last_presign_block: None,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let ev = TxSignerEventLoop::builder(ctx.clone(), network.spawn(), rand::rngs::OsRng)
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required as u32)
+            .with_context_window(10000)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_private_key(kp.secret_key().into())
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = RequestDeciderEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            deposit_decisions_retry_window: 1,
-            withdrawal_decisions_retry_window: 1,
-            blocklist_checker: Some(()),
-            signer_private_key: kp.secret_key().into(),
-        };
+        let ev = RequestDeciderEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_blocklist_checker(Some(()))
+            .with_private_key(kp.secret_key().into())
+            .with_decisions_retry_windows(1, 1)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
@@ -2329,51 +2323,45 @@ async fn sign_bitcoin_transaction_multiple_locking_keys() {
 
     for (ctx, _, kp, network) in signers.iter() {
         ctx.state().set_sbtc_contracts_deployed();
-        let ev = TxCoordinatorEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            private_key: kp.secret_key().into(),
-            signing_round_max_duration: Duration::from_secs(10),
-            bitcoin_presign_request_max_duration: Duration::from_secs(10),
-            threshold: ctx.config().signer.bootstrap_signatures_required,
-            dkg_max_duration: Duration::from_secs(10),
-            is_epoch3: true,
-        };
+        let ev = TxCoordinatorEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_private_key(kp.secret_key().into())
+            .with_signing_round_max_duration(Duration::from_secs(10))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required)
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = TxSignerEventLoop {
-            network: network.spawn(),
-            threshold: ctx.config().signer.bootstrap_signatures_required as u32,
-            context: ctx.clone(),
-            context_window: 10000,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            signer_private_key: kp.secret_key().into(),
-            rng: rand::rngs::OsRng,
-            last_presign_block: None,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let ev = TxSignerEventLoop::builder(ctx.clone(), network.spawn(), rand::rngs::OsRng)
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required as u32)
+            .with_context_window(10000)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_private_key(kp.secret_key().into())
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = RequestDeciderEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            deposit_decisions_retry_window: 1,
-            withdrawal_decisions_retry_window: 1,
-            blocklist_checker: Some(()),
-            signer_private_key: kp.secret_key().into(),
-        };
+        let ev = RequestDeciderEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_blocklist_checker(Some(()))
+            .with_private_key(kp.secret_key().into())
+            .with_decisions_retry_windows(1, 1)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
@@ -2841,51 +2829,45 @@ async fn wsts_ids_set_during_dkg_and_signing_rounds() {
 
     for (ctx, _, kp, network) in signers.iter() {
         ctx.state().set_sbtc_contracts_deployed();
-        let ev = TxCoordinatorEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            private_key: kp.secret_key().into(),
-            signing_round_max_duration: Duration::from_secs(10),
-            bitcoin_presign_request_max_duration: Duration::from_secs(10),
-            threshold: ctx.config().signer.bootstrap_signatures_required,
-            dkg_max_duration: Duration::from_secs(10),
-            is_epoch3: true,
-        };
+        let ev = TxCoordinatorEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_private_key(kp.secret_key().into())
+            .with_signing_round_max_duration(Duration::from_secs(10))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required)
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = TxSignerEventLoop {
-            network: network.spawn(),
-            threshold: ctx.config().signer.bootstrap_signatures_required as u32,
-            context: ctx.clone(),
-            context_window: 10000,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            signer_private_key: kp.secret_key().into(),
-            rng: rand::rngs::OsRng,
-            last_presign_block: None,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let ev = TxSignerEventLoop::builder(ctx.clone(), network.spawn(), rand::rngs::OsRng)
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required as u32)
+            .with_context_window(10000)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_private_key(kp.secret_key().into())
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = RequestDeciderEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            deposit_decisions_retry_window: 1,
-            withdrawal_decisions_retry_window: 1,
-            blocklist_checker: Some(()),
-            signer_private_key: kp.secret_key().into(),
-        };
+        let ev = RequestDeciderEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_blocklist_checker(Some(()))
+            .with_private_key(kp.secret_key().into())
+            .with_decisions_retry_windows(1, 1)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
@@ -3208,51 +3190,45 @@ async fn skip_signer_activites_after_key_rotation() {
 
     for (ctx, _, kp, network) in signers.iter() {
         ctx.state().set_sbtc_contracts_deployed();
-        let ev = TxCoordinatorEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            private_key: kp.secret_key().into(),
-            signing_round_max_duration: Duration::from_secs(10),
-            bitcoin_presign_request_max_duration: Duration::from_secs(10),
-            threshold: ctx.config().signer.bootstrap_signatures_required,
-            dkg_max_duration: Duration::from_secs(10),
-            is_epoch3: true,
-        };
+        let ev = TxCoordinatorEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_private_key(kp.secret_key().into())
+            .with_signing_round_max_duration(Duration::from_secs(10))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required)
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = TxSignerEventLoop {
-            network: network.spawn(),
-            threshold: ctx.config().signer.bootstrap_signatures_required as u32,
-            context: ctx.clone(),
-            context_window: 10000,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            signer_private_key: kp.secret_key().into(),
-            rng: rand::rngs::OsRng,
-            last_presign_block: None,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let ev = TxSignerEventLoop::builder(ctx.clone(), network.spawn(), rand::rngs::OsRng)
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required as u32)
+            .with_context_window(10000)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_private_key(kp.secret_key().into())
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = RequestDeciderEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            deposit_decisions_retry_window: 1,
-            withdrawal_decisions_retry_window: 1,
-            blocklist_checker: Some(()),
-            signer_private_key: kp.secret_key().into(),
-        };
+        let ev = RequestDeciderEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_blocklist_checker(Some(()))
+            .with_private_key(kp.secret_key().into())
+            .with_decisions_retry_windows(1, 1)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
@@ -3759,51 +3735,45 @@ async fn skip_smart_contract_deployment_and_key_rotation_if_up_to_date() {
 
     for (ctx, _, kp, network) in signers.iter() {
         ctx.state().set_sbtc_contracts_deployed();
-        let ev = TxCoordinatorEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            private_key: kp.secret_key().into(),
-            signing_round_max_duration: Duration::from_secs(10),
-            bitcoin_presign_request_max_duration: Duration::from_secs(10),
-            threshold: ctx.config().signer.bootstrap_signatures_required,
-            dkg_max_duration: Duration::from_secs(10),
-            is_epoch3: true,
-        };
+        let ev = TxCoordinatorEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_private_key(kp.secret_key().into())
+            .with_signing_round_max_duration(Duration::from_secs(10))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required)
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = TxSignerEventLoop {
-            network: network.spawn(),
-            threshold: ctx.config().signer.bootstrap_signatures_required as u32,
-            context: ctx.clone(),
-            context_window: 10000,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            signer_private_key: kp.secret_key().into(),
-            rng: rand::rngs::OsRng,
-            last_presign_block: None,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let ev = TxSignerEventLoop::builder(ctx.clone(), network.spawn(), rand::rngs::OsRng)
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required as u32)
+            .with_context_window(10000)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_private_key(kp.secret_key().into())
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = RequestDeciderEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            deposit_decisions_retry_window: 1,
-            withdrawal_decisions_retry_window: 1,
-            blocklist_checker: Some(()),
-            signer_private_key: kp.secret_key().into(),
-        };
+        let ev = RequestDeciderEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_blocklist_checker(Some(()))
+            .with_private_key(kp.secret_key().into())
+            .with_decisions_retry_windows(1, 1)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
@@ -3886,17 +3856,16 @@ async fn test_get_btc_state_with_no_available_sweep_transactions() {
         })
         .await;
 
-    let coord = TxCoordinatorEventLoop {
-        context,
-        private_key: PrivateKey::new(&mut rng),
-        network: network.spawn(),
-        threshold: 5,
-        context_window: 5,
-        signing_round_max_duration: std::time::Duration::from_secs(5),
-        bitcoin_presign_request_max_duration: Duration::from_secs(5),
-        dkg_max_duration: std::time::Duration::from_secs(5),
-        is_epoch3: true,
-    };
+    let coord = TxCoordinatorEventLoop::builder(context, network.spawn())
+        .with_private_key(PrivateKey::new(&mut rng))
+        .with_threshold(5)
+        .with_context_window(5)
+        .with_signing_round_max_duration(std::time::Duration::from_secs(5))
+        .with_bitcoin_presign_request_max_duration(Duration::from_secs(5))
+        .with_dkg_max_duration(std::time::Duration::from_secs(5))
+        .with_epoch3(true)
+        .build()
+        .unwrap();
 
     let aggregate_key = &PublicKey::from_private_key(&PrivateKey::new(&mut rng));
 
@@ -3978,7 +3947,7 @@ async fn test_get_btc_state_with_no_available_sweep_transactions() {
     assert_eq!(btc_state.utxo.outpoint.txid, signer_utxo_txid);
     assert_eq!(btc_state.utxo.public_key, aggregate_key.into());
     assert_eq!(btc_state.public_key, aggregate_key.into());
-    assert_eq!(btc_state.fee_rate, 1.3);
+    assert_eq!(btc_state.fee_rate.to_sat_per_vb(), 1.3);
     assert_eq!(btc_state.last_fees, None);
     assert_eq!(btc_state.magic_bytes, [b'T', b'3']);
 
@@ -4009,17 +3978,16 @@ async fn test_get_btc_state_with_available_sweep_transactions_and_rbf() {
         .build();
     let network = SignerNetwork::single(&context);
 
-    let coord = TxCoordinatorEventLoop {
-        context,
-        private_key: PrivateKey::new(&mut rng),
-        network: network.spawn(),
-        threshold: 5,
-        context_window: 5,
-        signing_round_max_duration: std::time::Duration::from_secs(5),
-        bitcoin_presign_request_max_duration: Duration::from_secs(5),
-        dkg_max_duration: std::time::Duration::from_secs(5),
-        is_epoch3: true,
-    };
+    let coord = TxCoordinatorEventLoop::builder(context, network.spawn())
+        .with_private_key(PrivateKey::new(&mut rng))
+        .with_threshold(5)
+        .with_context_window(5)
+        .with_signing_round_max_duration(std::time::Duration::from_secs(5))
+        .with_bitcoin_presign_request_max_duration(Duration::from_secs(5))
+        .with_dkg_max_duration(std::time::Duration::from_secs(5))
+        .with_epoch3(true)
+        .build()
+        .unwrap();
 
     let aggregate_key = &PublicKey::from_private_key(&PrivateKey::new(&mut rng));
 
@@ -4217,8 +4185,9 @@ fn create_test_setup(
         .unwrap();
     let test_signers = TestSignerSet {
         keys: dkg_shares.signer_set_public_keys.clone(),
-        // We don't use `signer`
+        // We don't use `signer` or `private_keys`
         signer: Recipient::new(AddressType::P2tr),
+        private_keys: Vec::new(),
     };
     let (request, recipient) = generate_withdrawal();
     let stacks_block = model::StacksBlock {
@@ -4348,7 +4317,7 @@ async fn test_conservative_initial_sbtc_limits() {
             // We already stored the deposit, we don't need it from Emily
             client
                 .expect_get_deposits()
-                .returning(|| Box::pin(std::future::ready(Ok(vec![]))));
+                .returning(|| Box::pin(futures::stream::empty()));
 
             // We don't care about this
             client.expect_accept_deposits().returning(|_| {
@@ -4496,51 +4465,45 @@ async fn test_conservative_initial_sbtc_limits() {
 
     for (ctx, _, kp, network) in signers.iter() {
         ctx.state().set_sbtc_contracts_deployed();
-        let ev = TxCoordinatorEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            private_key: kp.secret_key().into(),
-            signing_round_max_duration: Duration::from_secs(2),
-            bitcoin_presign_request_max_duration: Duration::from_secs(2),
-            threshold: signatures_required,
-            dkg_max_duration: Duration::from_secs(10),
-            is_epoch3: true,
-        };
+        let ev = TxCoordinatorEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_private_key(kp.secret_key().into())
+            .with_signing_round_max_duration(Duration::from_secs(2))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(2))
+            .with_threshold(signatures_required)
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = TxSignerEventLoop {
-            network: network.spawn(),
-            threshold: signatures_required as u32,
-            context: ctx.clone(),
-            context_window: 10000,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            signer_private_key: kp.secret_key().into(),
-            rng: rand::rngs::OsRng,
-            last_presign_block: None,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let ev = TxSignerEventLoop::builder(ctx.clone(), network.spawn(), rand::rngs::OsRng)
+            .with_threshold(signatures_required as u32)
+            .with_context_window(10000)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_private_key(kp.secret_key().into())
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = RequestDeciderEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            blocklist_checker: Some(()),
-            signer_private_key: kp.secret_key().into(),
-            deposit_decisions_retry_window: 1,
-            withdrawal_decisions_retry_window: 1,
-        };
+        let ev = RequestDeciderEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_blocklist_checker(Some(()))
+            .with_private_key(kp.secret_key().into())
+            .with_decisions_retry_windows(1, 1)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
@@ -4716,51 +4679,45 @@ async fn sign_bitcoin_transaction_withdrawals() {
 
     for (ctx, _, kp, network) in signers.iter() {
         ctx.state().set_sbtc_contracts_deployed();
-        let ev = TxCoordinatorEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            private_key: kp.secret_key().into(),
-            signing_round_max_duration: Duration::from_secs(10),
-            bitcoin_presign_request_max_duration: Duration::from_secs(10),
-            threshold: ctx.config().signer.bootstrap_signatures_required,
-            dkg_max_duration: Duration::from_secs(10),
-            is_epoch3: true,
-        };
+        let ev = TxCoordinatorEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_private_key(kp.secret_key().into())
+            .with_signing_round_max_duration(Duration::from_secs(10))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required)
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = TxSignerEventLoop {
-            network: network.spawn(),
-            threshold: ctx.config().signer.bootstrap_signatures_required as u32,
-            context: ctx.clone(),
-            context_window: 10000,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            signer_private_key: kp.secret_key().into(),
-            last_presign_block: None,
-            rng: rand::rngs::OsRng,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let ev = TxSignerEventLoop::builder(ctx.clone(), network.spawn(), rand::rngs::OsRng)
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required as u32)
+            .with_context_window(10000)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_private_key(kp.secret_key().into())
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = RequestDeciderEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            deposit_decisions_retry_window: 1,
-            withdrawal_decisions_retry_window: 1,
-            blocklist_checker: Some(()),
-            signer_private_key: kp.secret_key().into(),
-        };
+        let ev = RequestDeciderEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_blocklist_checker(Some(()))
+            .with_private_key(kp.secret_key().into())
+            .with_decisions_retry_windows(1, 1)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
@@ -5314,17 +5271,19 @@ async fn process_rejected_withdrawal(is_completed: bool, is_in_mempool: bool) {
 
     // Bootstrap the tx coordinator event loop
     context.state().set_sbtc_contracts_deployed();
-    let tx_coordinator = transaction_coordinator::TxCoordinatorEventLoop {
-        context: context.clone(),
-        network: network.connect(),
-        private_key,
-        context_window,
-        threshold: signing_threshold as u16,
-        signing_round_max_duration: Duration::from_secs(5),
-        bitcoin_presign_request_max_duration: Duration::from_secs(5),
-        dkg_max_duration: Duration::from_secs(5),
-        is_epoch3: true,
-    };
+    let tx_coordinator = transaction_coordinator::TxCoordinatorEventLoop::builder(
+        context.clone(),
+        network.connect(),
+    )
+    .with_private_key(private_key)
+    .with_context_window(context_window)
+    .with_threshold(signing_threshold as u16)
+    .with_signing_round_max_duration(Duration::from_secs(5))
+    .with_bitcoin_presign_request_max_duration(Duration::from_secs(5))
+    .with_dkg_max_duration(Duration::from_secs(5))
+    .with_epoch3(true)
+    .build()
+    .unwrap();
     let tx_coordinator_handle = tokio::spawn(async move { tx_coordinator.run().await });
 
     // Here signers use all the same storage, but we don't care in this test
@@ -5431,6 +5390,7 @@ async fn coordinator_skip_onchain_completed_deposits(deposit_completed: bool) {
     let signers = TestSignerSet {
         signer,
         keys: vec![signer_kp.public_key().into()],
+        private_keys: vec![signer_kp.secret_key().into()],
     };
     let aggregate_key = signers.aggregate_key();
 
@@ -5505,17 +5465,16 @@ async fn coordinator_skip_onchain_completed_deposits(deposit_completed: bool) {
     let flag = start_flag.clone();
 
     let signing_round_max_duration = Duration::from_secs(2);
-    let ev = TxCoordinatorEventLoop {
-        network: signer_network.spawn(),
-        context: ctx.clone(),
-        context_window: 10000,
-        private_key: signers.private_key(),
-        signing_round_max_duration,
-        bitcoin_presign_request_max_duration: Duration::from_secs(1),
-        threshold: ctx.config().signer.bootstrap_signatures_required,
-        dkg_max_duration: Duration::from_secs(1),
-        is_epoch3: true,
-    };
+    let ev = TxCoordinatorEventLoop::builder(ctx.clone(), signer_network.spawn())
+        .with_context_window(10000)
+        .with_private_key(signers.private_key())
+        .with_signing_round_max_duration(signing_round_max_duration)
+        .with_bitcoin_presign_request_max_duration(Duration::from_secs(1))
+        .with_threshold(ctx.config().signer.bootstrap_signatures_required)
+        .with_dkg_max_duration(Duration::from_secs(1))
+        .with_epoch3(true)
+        .build()
+        .unwrap();
     tokio::spawn(async move {
         flag.store(true, Ordering::Relaxed);
         ev.run().await
@@ -5614,6 +5573,7 @@ mod get_eligible_pending_withdrawal_requests {
                 txid: request.txid,
                 signer_pub_key,
                 is_accepted,
+                decision_version: 0,
             };
 
             // Write the decision to the database.
@@ -5843,7 +5803,9 @@ mod get_eligible_pending_withdrawal_requests {
         let request = store_withdrawal_request(
             &db,
             bitcoin_chain.nth_block(params.at_block_height),
-            stacks_chain.nth_block((*params.at_block_height).into()), // Here we can cast one height to another because in this test chains are 1 to 1.
+            // Both chains have the same length in this test, so the request
+            // goes into the stacks block at the same height.
+            stacks_chain.nth_block(model::StacksBlockHeight::from(*params.at_block_height)),
             params.amount,
             1_000, // Max fee isn't validated here.
         )
@@ -5932,18 +5894,17 @@ async fn should_handle_dkg_coordination_failure() {
 
     // Create coordinator with test parameters using SignerNetwork::single
     let network = SignerNetwork::single(&context);
-    let mut coordinator = TxCoordinatorEventLoop {
-        context: context.clone(),
-        network: network.spawn(),
-        private_key: PrivateKey::new(&mut rng),
-        threshold: 3,
-        context_window: 5,
-        signing_round_max_duration: std::time::Duration::from_secs(5),
-        bitcoin_presign_request_max_duration: std::time::Duration::from_secs(5),
+    let mut coordinator = TxCoordinatorEventLoop::builder(context.clone(), network.spawn())
+        .with_private_key(PrivateKey::new(&mut rng))
+        .with_threshold(3)
+        .with_context_window(5)
+        .with_signing_round_max_duration(std::time::Duration::from_secs(5))
+        .with_bitcoin_presign_request_max_duration(std::time::Duration::from_secs(5))
         // short be short enough to broadcast, yet fail
-        dkg_max_duration: Duration::from_millis(10),
-        is_epoch3: true,
-    };
+        .with_dkg_max_duration(Duration::from_millis(10))
+        .with_epoch3(true)
+        .build()
+        .unwrap();
 
     // We're verifying that the coordinator is currently
     // processing requests correctly. Since we previously checked
@@ -6230,51 +6191,45 @@ async fn reuse_nonce_attack() {
     let start_count = Arc::new(AtomicU8::new(0));
 
     for (ctx, _, kp, network) in signers.iter() {
-        let ev = TxCoordinatorEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 10000,
-            private_key: kp.secret_key().into(),
-            signing_round_max_duration: Duration::from_secs(10),
-            bitcoin_presign_request_max_duration: Duration::from_secs(10),
-            threshold: ctx.config().signer.bootstrap_signatures_required,
-            dkg_max_duration: Duration::from_secs(10),
-            is_epoch3: true,
-        };
+        let ev = TxCoordinatorEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(10000)
+            .with_private_key(kp.secret_key().into())
+            .with_signing_round_max_duration(Duration::from_secs(10))
+            .with_bitcoin_presign_request_max_duration(Duration::from_secs(10))
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required)
+            .with_dkg_max_duration(Duration::from_secs(10))
+            .with_epoch3(true)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = TxSignerEventLoop {
-            network: network.spawn(),
-            threshold: ctx.config().signer.bootstrap_signatures_required as u32,
-            context: ctx.clone(),
-            context_window: 10000,
-            wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-            signer_private_key: kp.secret_key().into(),
-            last_presign_block: None,
-            rng: rand::rngs::OsRng,
-            dkg_begin_pause: None,
-            dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-            stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-        };
+        let ev = TxSignerEventLoop::builder(ctx.clone(), network.spawn(), rand::rngs::OsRng)
+            .with_threshold(ctx.config().signer.bootstrap_signatures_required as u32)
+            .with_context_window(10000)
+            .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+            .with_private_key(kp.secret_key().into())
+            .with_dkg_begin_pause(None)
+            .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+            .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
             ev.run().await
         });
 
-        let ev = RequestDeciderEventLoop {
-            network: network.spawn(),
-            context: ctx.clone(),
-            context_window: 1000,
-            deposit_decisions_retry_window: 1,
-            withdrawal_decisions_retry_window: 1,
-            blocklist_checker: Some(()),
-            signer_private_key: kp.secret_key().into(),
-        };
+        let ev = RequestDeciderEventLoop::builder(ctx.clone(), network.spawn())
+            .with_context_window(1000)
+            .with_blocklist_checker(Some(()))
+            .with_private_key(kp.secret_key().into())
+            .with_decisions_retry_windows(1, 1)
+            .build()
+            .unwrap();
         let counter = start_count.clone();
         tokio::spawn(async move {
             counter.fetch_add(1, Ordering::Relaxed);
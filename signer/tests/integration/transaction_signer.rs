@@ -6,7 +6,6 @@ use bitcoin::hashes::Hash;
 use bitcoincore_rpc::RpcApi;
 use fake::Fake as _;
 use fake::Faker;
-use lru::LruCache;
 use rand::SeedableRng as _;
 use rand::rngs::OsRng;
 use signer::bitcoin::MockBitcoinInteract;
@@ -25,6 +24,7 @@ use signer::testing::btc::get_canonical_chain_tip;
 use signer::transaction_signer::STACKS_SIGN_REQUEST_LRU_SIZE;
 use test_case::test_case;
 
+use signer::bitcoin::units::FeeRate;
 use signer::bitcoin::utxo::RequestRef;
 use signer::bitcoin::utxo::Requests;
 use signer::bitcoin::utxo::UnsignedTransaction;
@@ -117,19 +117,20 @@ async fn signing_set_validation_check_for_stacks_transactions() {
 
     req.deployer = ctx.config().signer.deployer;
     let network = InMemoryNetwork::new();
-    let mut tx_signer = TxSignerEventLoop {
-        network: network.connect(),
-        context: ctx.clone(),
-        context_window: 10000,
-        wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-        signer_private_key: setup.aggregated_signer.keypair.secret_key().into(),
-        threshold: 2,
-        last_presign_block: None,
-        rng: rand::rngs::StdRng::seed_from_u64(51),
-        dkg_begin_pause: None,
-        dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-        stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-    };
+    let mut tx_signer = TxSignerEventLoop::builder(
+        ctx.clone(),
+        network.connect(),
+        rand::rngs::StdRng::seed_from_u64(51),
+    )
+    .with_context_window(10000)
+    .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+    .with_private_key(setup.aggregated_signer.keypair.secret_key().into())
+    .with_threshold(2)
+    .with_dkg_begin_pause(None)
+    .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+    .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+    .build()
+    .unwrap();
 
     // Let's create a proper sign request.
     let request = StacksTransactionSignRequest {
@@ -207,19 +208,20 @@ async fn signing_set_validation_ignores_aggregate_key_in_request() {
 
     req.deployer = ctx.config().signer.deployer;
     let network = InMemoryNetwork::new();
-    let mut tx_signer = TxSignerEventLoop {
-        network: network.connect(),
-        context: ctx.clone(),
-        context_window: 10000,
-        wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-        signer_private_key: setup.aggregated_signer.keypair.secret_key().into(),
-        threshold: 2,
-        last_presign_block: None,
-        rng: rand::rngs::StdRng::seed_from_u64(51),
-        dkg_begin_pause: None,
-        dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-        stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-    };
+    let mut tx_signer = TxSignerEventLoop::builder(
+        ctx.clone(),
+        network.connect(),
+        rand::rngs::StdRng::seed_from_u64(51),
+    )
+    .with_context_window(10000)
+    .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+    .with_private_key(setup.aggregated_signer.keypair.secret_key().into())
+    .with_threshold(2)
+    .with_dkg_begin_pause(None)
+    .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+    .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+    .build()
+    .unwrap();
 
     // Let's create a proper sign request.
     let mut request = StacksTransactionSignRequest {
@@ -304,19 +306,20 @@ async fn signer_rejects_stacks_txns_with_too_high_a_fee(
 
     req.deployer = ctx.config().signer.deployer;
     let network = InMemoryNetwork::new();
-    let mut tx_signer = TxSignerEventLoop {
-        network: network.connect(),
-        context: ctx.clone(),
-        context_window: 10000,
-        wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-        signer_private_key: setup.aggregated_signer.keypair.secret_key().into(),
-        threshold: 2,
-        rng: rand::rngs::StdRng::seed_from_u64(51),
-        last_presign_block: None,
-        dkg_begin_pause: None,
-        dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-        stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-    };
+    let mut tx_signer = TxSignerEventLoop::builder(
+        ctx.clone(),
+        network.connect(),
+        rand::rngs::StdRng::seed_from_u64(51),
+    )
+    .with_context_window(10000)
+    .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+    .with_private_key(setup.aggregated_signer.keypair.secret_key().into())
+    .with_threshold(2)
+    .with_dkg_begin_pause(None)
+    .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+    .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+    .build()
+    .unwrap();
 
     // Setup the transaction fee to be the maximum fee configured plus one, so that it
     // exceeds the configured value.
@@ -391,19 +394,20 @@ async fn signer_rejects_multiple_attempts_in_tenure() {
 
     req.deployer = ctx.config().signer.deployer;
     let network = InMemoryNetwork::new();
-    let mut tx_signer = TxSignerEventLoop {
-        network: network.connect(),
-        context: ctx.clone(),
-        context_window: 10000,
-        wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-        signer_private_key: setup.aggregated_signer.keypair.secret_key().into(),
-        threshold: 2,
-        last_presign_block: None,
-        rng: rand::rngs::StdRng::seed_from_u64(51),
-        dkg_begin_pause: None,
-        dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-        stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-    };
+    let mut tx_signer = TxSignerEventLoop::builder(
+        ctx.clone(),
+        network.connect(),
+        rand::rngs::StdRng::seed_from_u64(51),
+    )
+    .with_context_window(10000)
+    .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+    .with_private_key(setup.aggregated_signer.keypair.secret_key().into())
+    .with_threshold(2)
+    .with_dkg_begin_pause(None)
+    .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+    .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+    .build()
+    .unwrap();
 
     // We need this so that there is a live "network". Otherwise will error when
     // trying to send a message at the end.
@@ -530,19 +534,20 @@ async fn assert_should_be_able_to_handle_sbtc_requests() {
     let network = WanNetwork::default();
 
     let net = network.connect(&ctx);
-    let mut tx_signer = TxSignerEventLoop {
-        network: net.spawn(),
-        context: ctx.clone(),
-        context_window: 10000,
-        wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-        signer_private_key: setup.aggregated_signer.keypair.secret_key().into(),
-        threshold: 2,
-        last_presign_block: None,
-        rng: rand::rngs::StdRng::seed_from_u64(51),
-        dkg_begin_pause: None,
-        dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-        stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-    };
+    let mut tx_signer = TxSignerEventLoop::builder(
+        ctx.clone(),
+        net.spawn(),
+        rand::rngs::StdRng::seed_from_u64(51),
+    )
+    .with_context_window(10000)
+    .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+    .with_private_key(setup.aggregated_signer.keypair.secret_key().into())
+    .with_threshold(2)
+    .with_dkg_begin_pause(None)
+    .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+    .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+    .build()
+    .unwrap();
 
     let sbtc_requests: TxRequestIds = TxRequestIds {
         deposits: vec![setup.deposit_request.outpoint],
@@ -553,6 +558,10 @@ async fn assert_should_be_able_to_handle_sbtc_requests() {
         request_package: vec![sbtc_requests],
         fee_rate,
         last_fees: None,
+        deposit_certificates: vec![setup.deposit_certificate()],
+        refunds: Vec::new(),
+        sweep_template_version: None,
+        emergency_recovery: false,
     };
 
     let sbtc_state = signer::bitcoin::utxo::SignerBtcState {
@@ -562,10 +571,11 @@ async fn assert_should_be_able_to_handle_sbtc_requests() {
             .await
             .unwrap()
             .unwrap(),
-        fee_rate,
+        fee_rate: FeeRate::new(fee_rate).unwrap(),
         last_fees: None,
         public_key: setup.aggregated_signer.keypair.public_key().into(),
         magic_bytes: [b'T', b'3'],
+        metadata_chain_tip: None,
     };
 
     // Create an unsigned transaction with the deposit request
@@ -649,7 +659,7 @@ async fn presign_requests_with_dkg_shares_status(status: DkgSharesStatus, is_ok:
         .unwrap();
     let chain_tip = BitcoinBlockRef {
         block_hash: block_header.hash.into(),
-        block_height: block_header.height.into(),
+        block_height: (block_header.height as u64).into(),
     };
 
     // Store the necessary data for passing validation
@@ -677,21 +687,22 @@ async fn presign_requests_with_dkg_shares_status(status: DkgSharesStatus, is_ok:
     let network = WanNetwork::default();
 
     let net = network.connect(&ctx);
-    let mut tx_signer = TxSignerEventLoop {
-        network: net.spawn(),
-        context: ctx.clone(),
-        context_window: 10000,
-        wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-        // We use this private key because it needs to be associated with
-        // one of the public keys that we stored in the DKG shares table.
-        signer_private_key: setup.signers.private_key(),
-        threshold: 2,
-        rng: rand::rngs::StdRng::seed_from_u64(51),
-        last_presign_block: None,
-        dkg_begin_pause: None,
-        dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-        stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-    };
+    let mut tx_signer = TxSignerEventLoop::builder(
+        ctx.clone(),
+        net.spawn(),
+        rand::rngs::StdRng::seed_from_u64(51),
+    )
+    .with_context_window(10000)
+    .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+    // We use this private key because it needs to be associated with
+    // one of the public keys that we stored in the DKG shares table.
+    .with_private_key(setup.signers.private_key())
+    .with_threshold(2)
+    .with_dkg_begin_pause(None)
+    .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+    .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+    .build()
+    .unwrap();
 
     let sbtc_requests: TxRequestIds = TxRequestIds {
         deposits: setup.deposit_outpoints(),
@@ -702,6 +713,10 @@ async fn presign_requests_with_dkg_shares_status(status: DkgSharesStatus, is_ok:
         request_package: vec![sbtc_requests],
         fee_rate: 2.0,
         last_fees: None,
+        deposit_certificates: setup.deposit_certificates(),
+        refunds: Vec::new(),
+        sweep_template_version: None,
+        emergency_recovery: false,
     };
 
     let result = tx_signer
@@ -746,7 +761,7 @@ pub async fn presign_request_ignore_request_if_already_processed_this_block() {
         .unwrap();
     let chain_tip = BitcoinBlockRef {
         block_hash: block_header.hash.into(),
-        block_height: block_header.height.into(),
+        block_height: (block_header.height as u64).into(),
     };
 
     // Store the necessary data for passing validation
@@ -774,21 +789,22 @@ pub async fn presign_request_ignore_request_if_already_processed_this_block() {
     let network = WanNetwork::default();
 
     let net = network.connect(&ctx);
-    let mut tx_signer = TxSignerEventLoop {
-        network: net.spawn(),
-        context: ctx.clone(),
-        context_window: 10000,
-        wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-        // We use this private key because it needs to be associated with
-        // one of the public keys that we stored in the DKG shares table.
-        signer_private_key: setup.signers.private_key(),
-        threshold: 2,
-        rng: rand::rngs::StdRng::seed_from_u64(51),
-        last_presign_block: None,
-        dkg_begin_pause: None,
-        dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-        stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-    };
+    let mut tx_signer = TxSignerEventLoop::builder(
+        ctx.clone(),
+        net.spawn(),
+        rand::rngs::StdRng::seed_from_u64(51),
+    )
+    .with_context_window(10000)
+    .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+    // We use this private key because it needs to be associated with
+    // one of the public keys that we stored in the DKG shares table.
+    .with_private_key(setup.signers.private_key())
+    .with_threshold(2)
+    .with_dkg_begin_pause(None)
+    .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+    .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+    .build()
+    .unwrap();
 
     let sbtc_requests: TxRequestIds = TxRequestIds {
         deposits: setup.deposit_outpoints(),
@@ -799,6 +815,10 @@ pub async fn presign_request_ignore_request_if_already_processed_this_block() {
         request_package: vec![sbtc_requests],
         fee_rate: 2.0,
         last_fees: None,
+        deposit_certificates: setup.deposit_certificates(),
+        refunds: Vec::new(),
+        sweep_template_version: None,
+        emergency_recovery: false,
     };
 
     let result = tx_signer
@@ -862,21 +882,22 @@ async fn new_state_machine_per_valid_sighash() {
     let network = WanNetwork::default();
 
     let net = network.connect(&ctx);
-    let mut tx_signer = TxSignerEventLoop {
-        network: net.spawn(),
-        context: ctx.clone(),
-        context_window: 10000,
-        wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-        // We use this private key because it needs to be associated with
-        // one of the public keys that we stored in the DKG shares table.
-        signer_private_key: setup.signers.private_key(),
-        threshold: 2,
-        last_presign_block: None,
-        rng: rand::rngs::StdRng::seed_from_u64(51),
-        dkg_begin_pause: None,
-        dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-        stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-    };
+    let mut tx_signer = TxSignerEventLoop::builder(
+        ctx.clone(),
+        net.spawn(),
+        rand::rngs::StdRng::seed_from_u64(51),
+    )
+    .with_context_window(10000)
+    .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+    // We use this private key because it needs to be associated with
+    // one of the public keys that we stored in the DKG shares table.
+    .with_private_key(setup.signers.private_key())
+    .with_threshold(2)
+    .with_dkg_begin_pause(None)
+    .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+    .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+    .build()
+    .unwrap();
 
     // We need to convince the signer event loop that it should accept the
     // message that we are going to send it.
@@ -988,21 +1009,22 @@ async fn nonce_response_unique_nonces() {
     let network = WanNetwork::default();
 
     let net = network.connect(&ctx);
-    let mut tx_signer = TxSignerEventLoop {
-        network: net.spawn(),
-        context: ctx.clone(),
-        context_window: 10000,
-        wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-        // We use this private key because it needs to be associated with
-        // one of the public keys that we stored in the DKG shares table.
-        signer_private_key: setup.signers.private_key(),
-        threshold: 2,
-        rng: rand::rngs::StdRng::seed_from_u64(51),
-        dkg_begin_pause: None,
-        last_presign_block: None,
-        dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-        stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-    };
+    let mut tx_signer = TxSignerEventLoop::builder(
+        ctx.clone(),
+        net.spawn(),
+        rand::rngs::StdRng::seed_from_u64(51),
+    )
+    .with_context_window(10000)
+    .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+    // We use this private key because it needs to be associated with
+    // one of the public keys that we stored in the DKG shares table.
+    .with_private_key(setup.signers.private_key())
+    .with_threshold(2)
+    .with_dkg_begin_pause(None)
+    .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+    .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+    .build()
+    .unwrap();
 
     // We need to convince the signer event loop that it should accept the
     // message that we are going to send it.
@@ -1164,19 +1186,20 @@ async fn max_one_state_machine_per_bitcoin_block_hash_for_dkg() {
     // Initialize the transaction signer event loop
     let network = WanNetwork::default();
     let net = network.connect(&ctx);
-    let mut tx_signer = TxSignerEventLoop {
-        network: net.spawn(),
-        context: ctx.clone(),
-        context_window: 10000,
-        wsts_state_machines: LruCache::new(NonZeroUsize::new(100).unwrap()),
-        signer_private_key: ctx.config().signer.private_key,
-        threshold: 2,
-        last_presign_block: None,
-        rng: rand::rngs::StdRng::seed_from_u64(51),
-        dkg_begin_pause: None,
-        dkg_verification_state_machines: LruCache::new(NonZeroUsize::new(5).unwrap()),
-        stacks_sign_request: LruCache::new(STACKS_SIGN_REQUEST_LRU_SIZE),
-    };
+    let mut tx_signer = TxSignerEventLoop::builder(
+        ctx.clone(),
+        net.spawn(),
+        rand::rngs::StdRng::seed_from_u64(51),
+    )
+    .with_context_window(10000)
+    .with_wsts_state_machines_cache_size(NonZeroUsize::new(100).unwrap())
+    .with_private_key(ctx.config().signer.private_key)
+    .with_threshold(2)
+    .with_dkg_begin_pause(None)
+    .with_dkg_verification_state_machines_cache_size(NonZeroUsize::new(5).unwrap())
+    .with_stacks_sign_request_cache_size(STACKS_SIGN_REQUEST_LRU_SIZE)
+    .build()
+    .unwrap();
 
     // We need to convince the signer event loop that it should accept the
     // message that we are going to send it. DkgBegin messages are only
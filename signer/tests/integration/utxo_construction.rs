@@ -28,11 +28,13 @@ use sbtc::deposits::DepositScriptInputs;
 use sbtc::deposits::ReclaimScriptInputs;
 use signer::DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX;
 use signer::bitcoin::rpc::BitcoinCoreClient;
+use signer::bitcoin::units::FeeRate;
 use signer::bitcoin::utxo::DepositRequest;
 use signer::bitcoin::utxo::SbtcRequests;
 use signer::bitcoin::utxo::SignerBtcState;
 use signer::bitcoin::utxo::SignerUtxo;
 use signer::bitcoin::utxo::TxDeconstructor;
+use signer::bitcoin::utxo::WithdrawalFeeSubsidy;
 use signer::bitcoin::utxo::WithdrawalRequest;
 use signer::config::Settings;
 use signer::context::SbtcLimits;
@@ -229,15 +231,17 @@ fn deposits_add_to_controlled_amounts() {
                 amount: signer_utxo.amount.to_sat(),
                 public_key: signers_public_key,
             },
-            fee_rate: 10.0,
+            fee_rate: FeeRate::new(10.0).unwrap(),
             public_key: signers_public_key,
             last_fees: None,
             magic_bytes: [b'T', b'3'],
+            metadata_chain_tip: None,
         },
         accept_threshold: 4,
         num_signers: 7,
         sbtc_limits: SbtcLimits::unlimited(),
         max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+        withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
     };
 
     // There should only be one transaction here since there is only one
@@ -294,15 +298,17 @@ fn withdrawals_reduce_to_signers_amounts() {
                 amount: signer_utxo.amount.to_sat(),
                 public_key: signers_public_key,
             },
-            fee_rate: FEE_RATE,
+            fee_rate: FeeRate::new(FEE_RATE).unwrap(),
             public_key: signers_public_key,
             last_fees: None,
             magic_bytes: [b'T', b'3'],
+            metadata_chain_tip: None,
         },
         accept_threshold: 4,
         num_signers: 7,
         sbtc_limits: SbtcLimits::unlimited(),
         max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+        withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
     };
 
     // There should only be one transaction here since there is only one
@@ -439,15 +445,17 @@ fn parse_withdrawal_ids(withdrawal_numbers: u64) {
                 amount: signer_utxo.amount.to_sat(),
                 public_key: signers_public_key,
             },
-            fee_rate: FEE_RATE,
+            fee_rate: FeeRate::new(FEE_RATE).unwrap(),
             public_key: signers_public_key,
             last_fees: None,
             magic_bytes: [b'T', b'3'],
+            metadata_chain_tip: None,
         },
         accept_threshold: 4,
         num_signers: 7,
         sbtc_limits: SbtcLimits::unlimited(),
         max_deposits_per_bitcoin_tx: DEFAULT_MAX_DEPOSITS_PER_BITCOIN_TX,
+        withdrawal_fee_subsidy: WithdrawalFeeSubsidy::default(),
     };
 
     // There should only be one transaction here since there are only
@@ -685,3 +685,84 @@ async fn reject_withdrawal_validation_request_still_active() {
 
     testing::storage::drop_db(db).await;
 }
+
+/// For this test we check that the `RejectWithdrawalV1::validate` function
+/// returns a withdrawal validation error with a SweepConfirmed message
+/// when bitcoin-core has confirmed a sweep transaction fulfilling the
+/// withdrawal request, even if the database does not suggest that the
+/// request is active.
+#[tokio::test]
+async fn reject_withdrawal_validation_sweep_confirmed() {
+    // Normal: this generates the blockchain as well as a transaction
+    // sweeping out the funds for a withdrawal request. This is just setup
+    // and should be essentially the same between tests.
+    let db = testing::storage::new_test_database().await;
+    let mut rng = get_rng();
+    let (rpc, faucet) = regtest::initialize_blockchain();
+
+    let signers = TestSignerSet::new(&mut rng);
+    let mut setup = new_sweep_setup(&signers, faucet);
+
+    let mut ctx = TestContext::builder()
+        .with_storage(db.clone())
+        .with_first_bitcoin_core_client()
+        .with_mocked_stacks_client()
+        .with_mocked_emily_client()
+        .build();
+
+    // Normal: the request has not been marked as completed in the smart
+    // contract.
+    set_withdrawal_incomplete(&mut ctx).await;
+
+    // Normal: the signer follows the bitcoin blockchain and event observer
+    // should be getting new block events from bitcoin-core. We haven't
+    // hooked up our block observer, so we need to manually update the
+    // database with new bitcoin block headers.
+    fetch_canonical_bitcoin_blockchain(&db, rpc).await;
+
+    // Normal: we need to store a row in the dkg_shares table so that we
+    // have a record of the scriptPubKey that the signers control.
+    setup.store_dkg_shares(&db).await;
+
+    // Normal: The signers normally have a UTXO, so we add one here too.
+    setup.store_donation(&db).await;
+
+    // Normal: the request and how the signers voted needs to be added to
+    // the database.
+    setup.store_withdrawal_requests(&db).await;
+    setup.store_withdrawal_decisions(&db).await;
+
+    // Normal: We do not reject a withdrawal requests until more than
+    // WITHDRAWAL_BLOCKS_EXPIRY blocks have been observed.
+    faucet.generate_blocks(WITHDRAWAL_BLOCKS_EXPIRY + 1);
+    fetch_canonical_bitcoin_blockchain(&db, rpc).await;
+
+    // Different: a sweep transaction fulfilling the withdrawal request is
+    // broadcast and confirmed. We record that it fulfills the request, but
+    // not its sighashes, so the database does not consider the withdrawal
+    // inflight.
+    setup.broadcast_sweep_tx(rpc);
+    setup.store_bitcoin_withdrawals_outputs(&db).await;
+    setup.submit_sweep_tx(rpc, faucet);
+    setup.store_sweep_tx(&db).await;
+
+    // Different: after this many blocks the database no longer considers
+    // the withdrawal request active, since the sweep has enough
+    // confirmations.
+    faucet.generate_blocks(WITHDRAWAL_MIN_CONFIRMATIONS + 1);
+    fetch_canonical_bitcoin_blockchain(&db, rpc).await;
+
+    let (reject_withdrawal_tx, req_ctx) = make_withdrawal_reject(&setup, &db).await;
+
+    // The database checks pass, but bitcoin-core knows that the sweep
+    // fulfilling the request has been confirmed.
+    let validation_result = reject_withdrawal_tx.validate(&ctx, &req_ctx).await;
+    match validation_result.unwrap_err() {
+        Error::WithdrawalRejectValidation(ref err) => {
+            assert_eq!(err.error, WithdrawalRejectErrorMsg::SweepConfirmed)
+        }
+        err => panic!("unexpected error during validation {err}"),
+    }
+
+    testing::storage::drop_db(db).await;
+}